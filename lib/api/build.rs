@@ -118,6 +118,9 @@ fn configure_validation(builder: Builder) -> Builder {
         .validates(&[
             ("GetCollectionInfoRequest.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("CollectionExistsRequest.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("CancelOptimizationsRequest.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("ResumeOptimizationsRequest.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("PinVectorRequest.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("CreateCollection.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name\")"),
             ("CreateCollection.hnsw_config", ""),
             ("CreateCollection.wal_config", ""),
@@ -136,6 +139,12 @@ fn configure_validation(builder: Builder) -> Builder {
             ("UpdateCollection.vectors_config", ""),
             ("UpdateCollection.quantization_config", ""),
             ("UpdateCollection.strict_mode_config", ""),
+            ("CloneCollection.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("CloneCollection.target_collection", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name\")"),
+            ("CloneCollection.hnsw_config", ""),
+            ("CloneCollection.optimizers_config", ""),
+            ("CloneCollection.quantization_config", ""),
+            ("CloneCollection.timeout", "range(min = 1)"),
             ("CollectionParamsDiff.replication_factor", "range(min = 1)"),
             ("CollectionParamsDiff.write_consistency_factor", "range(min = 1)"),
             ("DeleteCollection.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
@@ -146,6 +155,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("HnswConfigDiff.ef_construct", "range(min = 4)"),
             ("WalConfigDiff.wal_capacity_mb", "range(min = 1)"),
             ("WalConfigDiff.wal_retain_closed", "range(min = 1)"),
+            ("WalConfigDiff.wal_batch_max_points", "range(min = 1)"),
             ("OptimizersConfigDiff.deleted_threshold", "range(min = 0.0, max = 1.0)"),
             ("OptimizersConfigDiff.vacuum_min_vector_number", "range(min = 100)"),
             ("OptimizersConfigDiff.max_segment_size", "range(min = 1)"),
@@ -168,10 +178,14 @@ fn configure_validation(builder: Builder) -> Builder {
             ("StrictModeConfig.max_points_count", "range(min = 1)"),
             ("StrictModeConfig.read_rate_limit", "range(min = 1)"),
             ("StrictModeConfig.write_rate_limit", "range(min = 1)"),
+            ("StrictModeConfig.search_rate_limit", "range(min = 1)"),
+            ("StrictModeConfig.scroll_rate_limit", "range(min = 1)"),
+            ("StrictModeConfig.update_rate_limit", "range(min = 1)"),
             ("StrictModeConfig.multivector_config", ""),
             ("StrictModeConfig.sparse_config", ""),
             ("StrictModeSparseConfig.sparse_config", ""),
             ("StrictModeSparse.max_length", "range(min = 1)"),
+            ("StrictModeSparse.max_abs_value", "range(min = 0.0)"),
             ("StrictModeMultivectorConfig.multivector_config", ""),
             ("StrictModeMultivector.max_vectors", "range(min = 1)"),
         ], &[
@@ -205,6 +219,8 @@ fn configure_validation(builder: Builder) -> Builder {
             ("UpsertPoints.points", ""),
             ("UpsertPoints.update_filter", ""),
             ("DeletePoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("RestorePoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("RestorePoints.points", "length(min = 1)"),
             ("UpdatePointVectors.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("UpdatePointVectors.points", ""),
             ("UpdatePointVectors.update_filter", ""),
@@ -244,6 +260,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("SearchPointGroups.timeout", "range(min = 1)"),
             ("SearchParams.quantization", ""),
             ("SearchParams.acorn", ""),
+            ("SearchParams.score_gap_cutoff", "range(min = 0.0)"),
             ("QuantizationSearchParams.oversampling", "range(min = 1.0)"),
             ("ScrollPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("ScrollPoints.filter", ""),
@@ -306,6 +323,8 @@ fn configure_validation(builder: Builder) -> Builder {
             ("FeedbackStrategy.variant", ""),
             ("FeedbackItem.example", ""),
             ("NaiveFeedbackStrategy.b", "range(min = 0.0)"),
+            ("ArithmeticInput.terms", "length(min = 1), nested"),
+            ("ArithmeticTerm.vector", ""),
             ("Formula.expression", ""),
             ("Expression.variant", ""),
             ("MultExpression.mult", ""),
@@ -350,11 +369,36 @@ fn configure_validation(builder: Builder) -> Builder {
             ("FacetCounts.key", "length(min = 1)"),
             ("FacetCounts.filter", ""),
             ("FacetCounts.timeout", "range(min = 1)"),
+            ("FacetCounts.histogram", ""),
+            ("FacetMultiCounts.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("FacetMultiCounts.keys", "length(min = 1)"),
+            ("FacetMultiCounts.filter", ""),
+            ("FacetMultiCounts.timeout", "range(min = 1)"),
+            ("FacetHistogram.variant", ""),
+            ("AggregatePoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("AggregatePoints.filter", ""),
+            ("AggregatePoints.timeout", "range(min = 1)"),
+            ("JoinPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("JoinPoints.target_collection", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("JoinPoints.filter", ""),
+            ("JoinPoints.target_filter", ""),
+            ("JoinPoints.limit", "range(min = 1)"),
+            ("JoinPoints.params", ""),
+            ("JoinPoints.points_limit", "range(min = 1)"),
+            ("JoinPoints.timeout", "range(min = 1)"),
+            ("SampleProjectionPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("SampleProjectionPoints.filter", ""),
+            ("SampleProjectionPoints.sample", "range(min = 2, max = 10000)"),
+            ("SampleProjectionPoints.timeout", "range(min = 1)"),
             ("SearchMatrixPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("SearchMatrixPoints.filter", ""),
             ("SearchMatrixPoints.sample", "range(min = 2)"),
             ("SearchMatrixPoints.limit", "range(min = 1)"),
-            ("SearchMatrixPoints.timeout", "range(min = 1)")
+            ("SearchMatrixPoints.timeout", "range(min = 1)"),
+            ("CopyPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("CopyPoints.target_collection", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("CopyPoints.filter", ""),
+            ("CopyPoints.timeout", "range(min = 1)")
         ], &[])
         .type_attribute(".", "#[derive(serde::Serialize)]")
         // Service: points_internal_service.proto
@@ -396,6 +440,10 @@ fn configure_validation(builder: Builder) -> Builder {
             ("QueryBatchPointsInternal.timeout", "range(min = 1)"),
             ("FacetCountsInternal.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("FacetCountsInternal.timeout", "range(min = 1)"),
+            ("FacetCountsInternal.histogram", ""),
+            ("AggregatePointsInternal.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("AggregatePointsInternal.filter", ""),
+            ("AggregatePointsInternal.timeout", "range(min = 1)"),
         ], &[])
         // Service: raft_service.proto
         .validates(&[
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use segment::json_path::JsonPath;
+use segment::json_path::{JsonPath, JsonPathProjection};
 use tonic::Status;
 
 use crate::grpc::qdrant::value::Kind;
@@ -46,6 +46,11 @@ pub fn json_path_from_proto(a: &str) -> Result<JsonPath, Status> {
         .map_err(|_| Status::invalid_argument(format!("Invalid json path: \'{a}\'")))
 }
 
+pub fn json_path_projection_from_proto(a: &str) -> Result<JsonPathProjection, Status> {
+    a.parse()
+        .map_err(|_| Status::invalid_argument(format!("Invalid projection expression: \'{a}\'")))
+}
+
 pub fn proto_to_payloads(proto: HashMap<String, Value>) -> Result<segment::types::Payload, Status> {
     proto
         .into_iter()
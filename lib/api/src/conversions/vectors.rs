@@ -200,6 +200,9 @@ impl TryFrom<grpc::Vectors> for rest::VectorStruct {
                             grpc::vector::Vector::Object(object) => Ok(rest::VectorStruct::Object(
                                 rest::InferenceObject::try_from(object)?,
                             )),
+                            grpc::vector::Vector::DenseBytes(dense_bytes) => {
+                                Ok(rest::VectorStruct::Single(dense_bytes.into_floats()))
+                            }
                         };
                     }
 
@@ -236,6 +239,14 @@ impl TryFrom<grpc::Vectors> for rest::VectorStruct {
     }
 }
 
+impl grpc::DenseByteVector {
+    /// Vector elements as floats, which `uint8` vector storages convert back into bytes losslessly.
+    pub fn into_floats(self) -> Vec<f32> {
+        let grpc::DenseByteVector { data } = self;
+        data.into_iter().map(f32::from).collect()
+    }
+}
+
 impl TryFrom<grpc::Vector> for rest::Vector {
     type Error = Status;
 
@@ -272,6 +283,9 @@ impl TryFrom<grpc::Vector> for rest::Vector {
                 grpc::vector::Vector::Object(object) => Ok(rest::Vector::Object(
                     rest::InferenceObject::try_from(object)?,
                 )),
+                grpc::vector::Vector::DenseBytes(dense_bytes) => {
+                    Ok(rest::Vector::Dense(dense_bytes.into_floats()))
+                }
             };
         }
 
@@ -510,6 +524,9 @@ impl TryFrom<grpc::Vector> for VectorInternal {
                 Vector::Object(_) => Err(Status::invalid_argument(
                     "Object can't be converted to VectorInternal".to_string(),
                 )),
+                Vector::DenseBytes(dense_bytes) => {
+                    Ok(VectorInternal::Dense(dense_bytes.into_floats()))
+                }
             };
         }
 
@@ -11,7 +11,7 @@ use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use segment::common::operation_error::OperationError;
 use segment::data_types::index::{
-    BoolIndexType, DatetimeIndexType, FloatIndexType, GeoIndexType, IntegerIndexType,
+    BoolIndexType, DatetimeIndexType, FloatIndexType, GeoIndexType, IntegerIndexType, IpIndexType,
     KeywordIndexType, SnowballLanguage, TextIndexType, UuidIndexType,
 };
 use segment::data_types::modifier::Modifier;
@@ -20,6 +20,7 @@ use segment::data_types::{facets as segment_facets, vectors as segment_vectors};
 use segment::index::query_optimization::rescore_formula::parsed_formula::{
     DatetimeExpression, DecayKind, ParsedExpression, ParsedFormula,
 };
+use segment::index::sparse_index::sparse_index_config::SparseSimilarity;
 use segment::types::{DateTimePayloadType, FloatPayloadType, default_quantization_ignore_value};
 use segment::vector_storage::query::{self as segment_query, NaiveFeedbackCoefficients};
 use sparse::common::sparse_vector::validate_sparse_vector_impl;
@@ -27,14 +28,16 @@ use tonic::Status;
 use uuid::Uuid;
 
 use super::qdrant::{
-    BinaryQuantization, BoolIndexParams, CompressionRatio, DatetimeIndexParams, DatetimeRange,
-    Direction, FacetHit, FacetHitInternal, FacetValue, FacetValueInternal, FieldType,
-    FloatIndexParams, GeoIndexParams, GeoLineString, GroupId, HardwareUsage, HasVectorCondition,
+    AttributeRange, BinaryQuantization, BoolIndexParams, CompressionRatio, DatetimeIndexParams,
+    DatetimeRange, Direction, FacetHistogram, FacetHistogramBounds, FacetHit, FacetHitInternal,
+    FacetValue, FacetValueInternal, FieldType, FilterTemplateReference, FloatIndexParams,
+    GeoIndexParams, GeoLineString, GroupId, HardwareUsage, HasVectorCondition, IpIndexParams,
     KeywordIndexParams, LookupLocation, MaxOptimizationThreads, MultiVectorComparator,
-    MultiVectorConfig, OrderBy, OrderValue, Range, RawVector, RecommendStrategy, RetrievedPoint,
-    SearchMatrixPair, SearchPointGroups, SearchPoints, ShardKeySelector, StartFrom,
-    StrictModeMultivector, StrictModeMultivectorConfig, StrictModeSparse, StrictModeSparseConfig,
-    UuidIndexParams, VectorsOutput, WithLookup, raw_query, start_from,
+    MultiVectorConfig, OrderBy, OrderValue, PointAttribute, PointAttributeCondition, Range,
+    RawVector, RecommendStrategy, RetrievedPoint, SearchMatrixPair, SearchPointGroups,
+    SearchPoints, ShardKeySelector, StartFrom, StrictModeMultivector, StrictModeMultivectorConfig,
+    StrictModeSparse, StrictModeSparseConfig, UuidIndexParams, VectorsOutput, WithLookup,
+    raw_query, start_from,
 };
 use super::stemming_algorithm::StemmingParams;
 use super::{Expression, Formula, RecoQuery, SnowballParams, StemmingAlgorithm, Usage};
@@ -47,16 +50,16 @@ use crate::grpc::qdrant::with_payload_selector::SelectorOptions;
 use crate::grpc::qdrant::{
     AcornSearchParams, CollectionDescription, CollectionOperationResponse, Condition, Distance,
     FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, HasIdCondition,
-    HealthCheckReply, HnswConfigDiff, IntegerIndexParams, IsEmptyCondition, IsNullCondition,
-    ListCollectionsResponse, ListShardKeysResponse, Match, MinShould, NamedVectors,
-    NestedCondition, PayloadExcludeSelector, PayloadIncludeSelector, PayloadIndexParams,
-    PayloadSchemaInfo, PayloadSchemaType, PointId, PointStruct, PointsOperationResponse,
-    PointsOperationResponseInternal, ProductQuantization, QuantizationConfig,
-    QuantizationSearchParams, QuantizationType, RepeatedIntegers, RepeatedStrings,
-    ScalarQuantization, ScoredPoint, SearchParams, ShardKey, ShardKeyDescription, StopwordsSet,
-    StrictModeConfig, TextIndexParams, TokenizerType, UpdateResult, UpdateResultInternal,
-    ValuesCount, VectorsSelector, WithPayloadSelector, WithVectorsSelector, shard_key,
-    with_vectors_selector,
+    HealthCheckReply, HnswConfigDiff, HnswLinksCompression, IntegerIndexParams, IsEmptyCondition,
+    IsNullCondition, ListCollectionsResponse, ListShardKeysResponse, Match, MinShould,
+    NamedVectors, NestedCondition, PayloadExcludeSelector, PayloadIncludeSelector,
+    PayloadIndexParams, PayloadProjectSelector, PayloadSchemaInfo, PayloadSchemaType, PointId,
+    PointStruct, PointsOperationResponse, PointsOperationResponseInternal, ProductQuantization,
+    QuantizationConfig, QuantizationSearchParams, QuantizationType, RepeatedIntegers,
+    RepeatedStrings, ScalarQuantization, ScoredPoint, SearchParams, ShardKey, ShardKeyDescription,
+    StopwordsSet, StrictModeConfig, TextIndexParams, TokenizerType, UpdateResult,
+    UpdateResultInternal, ValuesCount, VectorsSelector, WithPayloadSelector, WithVectorsSelector,
+    shard_key, with_vectors_selector,
 };
 use crate::grpc::{
     self, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding, DecayParamsExpression,
@@ -128,6 +131,18 @@ impl TryFrom<ShardKeySelector> for rest::ShardKeySelector {
     }
 }
 
+impl TryFrom<FilterTemplateReference> for rest::FilterTemplateReference {
+    type Error = Status;
+    fn try_from(value: FilterTemplateReference) -> Result<Self, Self::Error> {
+        let FilterTemplateReference { name, params } = value;
+        let params = params
+            .into_iter()
+            .map(|(key, value)| json::proto_to_json(value).map(|value| (key, value)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { name, params })
+    }
+}
+
 impl From<(Instant, ShardKeysResponse)> for ListShardKeysResponse {
     fn from(value: (Instant, ShardKeysResponse)) -> Self {
         let (timing, response) = value;
@@ -335,6 +350,15 @@ impl From<segment::data_types::index::UuidIndexParams> for PayloadIndexParams {
     }
 }
 
+impl From<segment::data_types::index::IpIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::index::IpIndexParams) -> Self {
+        let segment::data_types::index::IpIndexParams { r#type: _, on_disk } = params;
+        PayloadIndexParams {
+            index_params: Some(IndexParams::IpIndexParams(IpIndexParams { on_disk })),
+        }
+    }
+}
+
 impl From<segment::data_types::index::DatetimeIndexParams> for PayloadIndexParams {
     fn from(params: segment::data_types::index::DatetimeIndexParams) -> Self {
         let segment::data_types::index::DatetimeIndexParams {
@@ -379,6 +403,7 @@ impl From<segment::types::PayloadSchemaType> for PayloadSchemaType {
             segment::types::PayloadSchemaType::Bool => PayloadSchemaType::Bool,
             segment::types::PayloadSchemaType::Datetime => PayloadSchemaType::Datetime,
             segment::types::PayloadSchemaType::Uuid => PayloadSchemaType::Uuid,
+            segment::types::PayloadSchemaType::Ip => PayloadSchemaType::Ip,
         }
     }
 }
@@ -394,6 +419,7 @@ impl From<segment::types::PayloadSchemaType> for FieldType {
             segment::types::PayloadSchemaType::Bool => FieldType::Bool,
             segment::types::PayloadSchemaType::Datetime => FieldType::Datetime,
             segment::types::PayloadSchemaType::Uuid => FieldType::Uuid,
+            segment::types::PayloadSchemaType::Ip => FieldType::Ip,
         }
     }
 }
@@ -473,6 +499,7 @@ impl From<segment::types::PayloadSchemaParams> for PayloadIndexParams {
             segment::types::PayloadSchemaParams::Bool(p) => p.into(),
             segment::types::PayloadSchemaParams::Datetime(p) => p.into(),
             segment::types::PayloadSchemaParams::Uuid(p) => p.into(),
+            segment::types::PayloadSchemaParams::Ip(p) => p.into(),
         }
     }
 }
@@ -697,6 +724,17 @@ impl TryFrom<UuidIndexParams> for segment::data_types::index::UuidIndexParams {
     }
 }
 
+impl TryFrom<IpIndexParams> for segment::data_types::index::IpIndexParams {
+    type Error = Status;
+    fn try_from(params: IpIndexParams) -> Result<Self, Self::Error> {
+        let IpIndexParams { on_disk } = params;
+        Ok(segment::data_types::index::IpIndexParams {
+            r#type: IpIndexType::Ip,
+            on_disk,
+        })
+    }
+}
+
 impl TryFrom<IndexParams> for segment::types::PayloadSchemaParams {
     type Error = Status;
 
@@ -726,6 +764,7 @@ impl TryFrom<IndexParams> for segment::types::PayloadSchemaParams {
             IndexParams::UuidIndexParams(p) => {
                 segment::types::PayloadSchemaParams::Uuid(p.try_into()?)
             }
+            IndexParams::IpIndexParams(p) => segment::types::PayloadSchemaParams::Ip(p.try_into()?),
         })
     }
 }
@@ -759,6 +798,7 @@ impl TryFrom<PayloadSchemaInfo> for segment::types::PayloadIndexInfo {
                     ));
                 }
                 PayloadSchemaType::Uuid => segment::types::PayloadSchemaType::Uuid,
+                PayloadSchemaType::Ip => segment::types::PayloadSchemaType::Ip,
             },
         };
         let params = match params {
@@ -819,6 +859,13 @@ impl TryFrom<WithPayloadSelector> for segment::types::WithPayloadInterface {
                         .collect::<Result<_, _>>()?,
                 )
                 .into(),
+                SelectorOptions::Project(s) => segment::types::PayloadSelectorProject::new(
+                    s.fields
+                        .iter()
+                        .map(|i| json::json_path_projection_from_proto(i))
+                        .collect::<Result<_, _>>()?,
+                )
+                .into(),
             }),
             _ => Err(Status::invalid_argument("No PayloadSelector".to_string())),
         }
@@ -845,6 +892,11 @@ impl From<segment::types::WithPayloadInterface> for WithPayloadSelector {
                         fields: s.exclude.iter().map(|f| f.to_string()).collect(),
                     })
                 }
+                segment::types::PayloadSelector::Project(s) => {
+                    SelectorOptions::Project(PayloadProjectSelector {
+                        fields: s.project.iter().map(|f| f.to_string()).collect(),
+                    })
+                }
             },
         };
         WithPayloadSelector {
@@ -917,6 +969,10 @@ impl From<SearchParams> for segment::types::SearchParams {
             quantization,
             indexed_only,
             acorn,
+            early_exit,
+            max_hnsw_ef,
+            score_gap_cutoff,
+            allow_partial_results,
         } = params;
         Self {
             hnsw_ef: hnsw_ef.map(|x| x as usize),
@@ -924,6 +980,10 @@ impl From<SearchParams> for segment::types::SearchParams {
             quantization: quantization.map(|q| q.into()),
             indexed_only: indexed_only.unwrap_or(false),
             acorn: acorn.map(segment::types::AcornSearchParams::from),
+            early_exit: early_exit.unwrap_or(false),
+            max_hnsw_ef: max_hnsw_ef.map(|x| x as usize),
+            score_gap_cutoff: score_gap_cutoff.map(OrderedFloat),
+            allow_partial_results,
         }
     }
 }
@@ -936,6 +996,10 @@ impl From<segment::types::SearchParams> for SearchParams {
             quantization,
             indexed_only,
             acorn,
+            early_exit,
+            max_hnsw_ef,
+            score_gap_cutoff,
+            allow_partial_results,
         } = params;
         Self {
             hnsw_ef: hnsw_ef.map(|x| x as u64),
@@ -943,6 +1007,10 @@ impl From<segment::types::SearchParams> for SearchParams {
             quantization: quantization.map(|q| q.into()),
             indexed_only: Some(indexed_only),
             acorn: acorn.map(AcornSearchParams::from),
+            early_exit: Some(early_exit),
+            max_hnsw_ef: max_hnsw_ef.map(|x| x as u64),
+            score_gap_cutoff: score_gap_cutoff.map(|OrderedFloat(x)| x),
+            allow_partial_results,
         }
     }
 }
@@ -966,6 +1034,7 @@ impl TryFrom<PointStruct> for rest::PointStruct {
             id,
             vectors,
             payload,
+            shard_key,
         } = value;
 
         // empty payload means None in PointStruct
@@ -986,6 +1055,7 @@ impl TryFrom<PointStruct> for rest::PointStruct {
                 .try_into()?,
             vector: vector_struct,
             payload: converted_payload,
+            shard_key: convert_shard_key_from_grpc_opt(shard_key),
         })
     }
 }
@@ -999,6 +1069,8 @@ impl TryFrom<rest::Record> for RetrievedPoint {
             vector,
             shard_key,
             order_value,
+            version,
+            version_peer_id,
         } = record;
         let retrieved_point = Self {
             id: Some(PointId::from(id)),
@@ -1006,6 +1078,8 @@ impl TryFrom<rest::Record> for RetrievedPoint {
             vectors: vector.map(VectorsOutput::try_from).transpose()?,
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(From::from),
+            version,
+            version_peer_id,
         };
         Ok(retrieved_point)
     }
@@ -1609,6 +1683,9 @@ pub fn grpc_condition_into_condition(
                 has_vector: has_vector.has_vector,
             },
         )),
+        ConditionOneOf::Attribute(attribute) => {
+            Some(segment::types::Condition::Attribute(attribute.try_into()?))
+        }
     };
 
     Ok(condition)
@@ -1643,6 +1720,9 @@ impl From<segment::types::Condition> for Condition {
                     has_vector: has_vector.has_vector,
                 }))
             }
+            segment::types::Condition::Attribute(attribute) => Some(ConditionOneOf::Attribute(
+                PointAttributeCondition::from(attribute),
+            )),
         };
 
         Self { condition_one_of }
@@ -1698,6 +1778,70 @@ impl From<segment::types::IsEmptyCondition> for IsEmptyCondition {
     }
 }
 
+impl TryFrom<PointAttributeCondition> for segment::types::PointAttributeCondition {
+    type Error = Status;
+
+    fn try_from(value: PointAttributeCondition) -> Result<Self, Status> {
+        let PointAttributeCondition { attribute, range } = value;
+        let attribute = PointAttribute::try_from(attribute).map_err(|_| {
+            Status::invalid_argument(format!("Unknown point attribute: {attribute}"))
+        })?;
+        let Some(range) = range else {
+            return Err(Status::invalid_argument(
+                "Attribute condition must have a range",
+            ));
+        };
+        Ok(segment::types::PointAttributeCondition {
+            attribute: attribute.into(),
+            range: range.into(),
+        })
+    }
+}
+
+impl From<segment::types::PointAttributeCondition> for PointAttributeCondition {
+    fn from(value: segment::types::PointAttributeCondition) -> Self {
+        let segment::types::PointAttributeCondition { attribute, range } = value;
+        Self {
+            attribute: PointAttribute::from(attribute) as i32,
+            range: Some(range.into()),
+        }
+    }
+}
+
+impl From<PointAttribute> for segment::types::PointAttribute {
+    fn from(value: PointAttribute) -> Self {
+        match value {
+            PointAttribute::Id => segment::types::PointAttribute::Id,
+            PointAttribute::Version => segment::types::PointAttribute::Version,
+            PointAttribute::VectorsCount => segment::types::PointAttribute::VectorsCount,
+        }
+    }
+}
+
+impl From<segment::types::PointAttribute> for PointAttribute {
+    fn from(value: segment::types::PointAttribute) -> Self {
+        match value {
+            segment::types::PointAttribute::Id => PointAttribute::Id,
+            segment::types::PointAttribute::Version => PointAttribute::Version,
+            segment::types::PointAttribute::VectorsCount => PointAttribute::VectorsCount,
+        }
+    }
+}
+
+impl From<AttributeRange> for segment::types::AttributeRange {
+    fn from(value: AttributeRange) -> Self {
+        let AttributeRange { lt, gt, gte, lte } = value;
+        Self { lt, gt, gte, lte }
+    }
+}
+
+impl From<segment::types::AttributeRange> for AttributeRange {
+    fn from(value: segment::types::AttributeRange) -> Self {
+        let segment::types::AttributeRange { lt, gt, gte, lte } = value;
+        Self { lt, gt, gte, lte }
+    }
+}
+
 impl TryFrom<IsNullCondition> for segment::types::IsNullCondition {
     type Error = Status;
 
@@ -2043,6 +2187,9 @@ impl TryFrom<Match> for segment::types::Match {
                 MatchValue::TextAny(text_any) => {
                     segment::types::Match::TextAny(segment::types::MatchTextAny { text_any })
                 }
+                MatchValue::Cidr(cidr) => {
+                    segment::types::Match::Cidr(segment::types::MatchCidr { cidr })
+                }
             }),
             _ => Err(Status::invalid_argument("Malformed Match condition")),
         }
@@ -2086,6 +2233,9 @@ impl From<segment::types::Match> for Match {
             segment::types::Match::TextAny(segment::types::MatchTextAny { text_any }) => {
                 MatchValue::TextAny(text_any)
             }
+            segment::types::Match::Cidr(segment::types::MatchCidr { cidr }) => {
+                MatchValue::Cidr(cidr)
+            }
         };
         Self {
             match_value: Some(match_value),
@@ -2271,6 +2421,8 @@ impl From<HnswConfigDiff> for segment::types::HnswConfig {
             on_disk,
             payload_m,
             inline_storage,
+            links_compression,
+            tenant_subgraphs,
         } = hnsw_config;
         Self {
             m: m.unwrap_or_default() as usize,
@@ -2280,12 +2432,42 @@ impl From<HnswConfigDiff> for segment::types::HnswConfig {
             on_disk,
             payload_m: payload_m.map(|x| x as usize),
             inline_storage,
+            links_compression: links_compression
+                .and_then(|value| HnswLinksCompression::try_from(value).ok())
+                .map(segment::types::HnswLinksCompression::from),
+            tenant_subgraphs,
         }
     }
 }
 
-impl From<StrictModeConfig> for segment::types::StrictModeConfig {
-    fn from(value: StrictModeConfig) -> Self {
+impl From<segment::types::HnswLinksCompression> for HnswLinksCompression {
+    fn from(value: segment::types::HnswLinksCompression) -> Self {
+        match value {
+            segment::types::HnswLinksCompression::Bitpacking => HnswLinksCompression::Bitpacking,
+            segment::types::HnswLinksCompression::DeltaVarint => HnswLinksCompression::DeltaVarint,
+            segment::types::HnswLinksCompression::DeltaVarintCached => {
+                HnswLinksCompression::DeltaVarintCached
+            }
+        }
+    }
+}
+
+impl From<HnswLinksCompression> for segment::types::HnswLinksCompression {
+    fn from(value: HnswLinksCompression) -> Self {
+        match value {
+            HnswLinksCompression::Bitpacking => segment::types::HnswLinksCompression::Bitpacking,
+            HnswLinksCompression::DeltaVarint => segment::types::HnswLinksCompression::DeltaVarint,
+            HnswLinksCompression::DeltaVarintCached => {
+                segment::types::HnswLinksCompression::DeltaVarintCached
+            }
+        }
+    }
+}
+
+impl TryFrom<StrictModeConfig> for segment::types::StrictModeConfig {
+    type Error = Status;
+
+    fn try_from(value: StrictModeConfig) -> Result<Self, Self::Error> {
         let StrictModeConfig {
             enabled,
             max_query_limit,
@@ -2306,8 +2488,12 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfig {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            tenant_filter_key,
+            search_rate_limit,
+            scroll_rate_limit,
+            update_rate_limit,
         } = value;
-        Self {
+        Ok(Self {
             enabled,
             max_query_limit: max_query_limit.map(|i| i as usize),
             max_timeout: max_timeout.map(|i| i as usize),
@@ -2329,7 +2515,13 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfig {
                 .map(segment::types::StrictModeMultivectorConfig::from),
             sparse_config: sparse_config.map(segment::types::StrictModeSparseConfig::from),
             max_payload_index_count: max_payload_index_count.map(|i| i as usize),
-        }
+            tenant_filter_key: tenant_filter_key
+                .map(|key| json::json_path_from_proto(&key))
+                .transpose()?,
+            search_rate_limit: search_rate_limit.map(|i| i as usize),
+            scroll_rate_limit: scroll_rate_limit.map(|i| i as usize),
+            update_rate_limit: update_rate_limit.map(|i| i as usize),
+        })
     }
 }
 
@@ -2363,6 +2555,8 @@ impl From<StrictModeSparseConfig> for segment::types::StrictModeSparseConfig {
                         name,
                         segment::types::StrictModeSparse {
                             max_length: config.max_length.map(|i| i as usize),
+                            max_index: config.max_index,
+                            max_abs_value: config.max_abs_value.map(OrderedFloat),
                         },
                     )
                 })
@@ -2382,6 +2576,8 @@ impl From<segment::types::StrictModeSparseConfig> for StrictModeSparseConfig {
                         name,
                         StrictModeSparse {
                             max_length: config.max_length.map(|i| i as u64),
+                            max_index: config.max_index,
+                            max_abs_value: config.max_abs_value.map(|value| value.0),
                         },
                     )
                 })
@@ -2401,6 +2597,8 @@ impl From<segment::types::StrictModeSparseConfigOutput> for StrictModeSparseConf
                         name,
                         StrictModeSparse {
                             max_length: config.max_length.map(|i| i as u64),
+                            max_index: config.max_index,
+                            max_abs_value: config.max_abs_value.map(|value| value.0),
                         },
                     )
                 })
@@ -2431,6 +2629,10 @@ impl From<segment::types::StrictModeConfigOutput> for StrictModeConfig {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            tenant_filter_key,
+            search_rate_limit,
+            scroll_rate_limit,
+            update_rate_limit,
         } = value;
         Self {
             enabled,
@@ -2452,6 +2654,10 @@ impl From<segment::types::StrictModeConfigOutput> for StrictModeConfig {
             sparse_config: sparse_config.map(StrictModeSparseConfig::from),
             max_points_count: max_points_count.map(|i| i as u64),
             max_payload_index_count: max_payload_index_count.map(|i| i as u64),
+            tenant_filter_key: tenant_filter_key.map(|key| key.to_string()),
+            search_rate_limit: search_rate_limit.map(|i| i as u32),
+            scroll_rate_limit: scroll_rate_limit.map(|i| i as u32),
+            update_rate_limit: update_rate_limit.map(|i| i as u32),
         }
     }
 }
@@ -2478,6 +2684,10 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfigOutput {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            tenant_filter_key,
+            search_rate_limit,
+            scroll_rate_limit,
+            update_rate_limit,
         } = value;
         Self {
             enabled,
@@ -2501,6 +2711,11 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfigOutput {
                 .map(segment::types::StrictModeMultivectorConfigOutput::from),
             sparse_config: sparse_config.map(segment::types::StrictModeSparseConfigOutput::from),
             max_payload_index_count: max_payload_index_count.map(|i| i as usize),
+            tenant_filter_key: tenant_filter_key
+                .and_then(|key| json::json_path_from_proto(&key).ok()),
+            search_rate_limit: search_rate_limit.map(|i| i as usize),
+            scroll_rate_limit: scroll_rate_limit.map(|i| i as usize),
+            update_rate_limit: update_rate_limit.map(|i| i as usize),
         }
     }
 }
@@ -2568,6 +2783,8 @@ impl From<StrictModeSparseConfig> for segment::types::StrictModeSparseConfigOutp
                 name,
                 segment::types::StrictModeSparseOutput {
                     max_length: strict_config.max_length.map(|i| i as usize),
+                    max_index: strict_config.max_index,
+                    max_abs_value: strict_config.max_abs_value.map(OrderedFloat),
                 },
             );
         }
@@ -2608,6 +2825,7 @@ impl TryFrom<Distance> for segment::types::Distance {
             Distance::Euclid => segment::types::Distance::Euclid,
             Distance::Dot => segment::types::Distance::Dot,
             Distance::Manhattan => segment::types::Distance::Manhattan,
+            Distance::Hamming => segment::types::Distance::Hamming,
         })
     }
 }
@@ -2710,10 +2928,12 @@ impl From<UpdateResultInternal> for UpdateResult {
             operation_id,
             status,
             clock_tag: _,
+            affected_points,
         } = res;
         Self {
             operation_id,
             status,
+            affected_points,
         }
     }
 }
@@ -2724,11 +2944,13 @@ impl From<UpdateResult> for UpdateResultInternal {
         let UpdateResult {
             operation_id,
             status,
+            affected_points,
         } = res;
         Self {
             operation_id,
             status,
             clock_tag: None,
+            affected_points,
         }
     }
 }
@@ -2970,6 +3192,8 @@ impl TryFrom<SearchPoints> for rest::SearchRequestInternal {
             timeout: _,
             shard_key_selector: _,
             sparse_indices,
+            filter_template: _,
+            explain: _,
         } = value;
 
         let vector_internal =
@@ -3024,6 +3248,7 @@ impl TryFrom<SearchPointGroups> for rest::SearchGroupsRequestInternal {
             timeout,
             shard_key_selector,
             sparse_indices,
+            filter_template: _,
         } = value;
         let search_points = SearchPoints {
             vector,
@@ -3040,6 +3265,8 @@ impl TryFrom<SearchPointGroups> for rest::SearchGroupsRequestInternal {
             timeout,
             shard_key_selector,
             sparse_indices,
+            filter_template: None,
+            explain: None,
         };
 
         if let Some(sparse_indices) = &search_points.sparse_indices {
@@ -3099,6 +3326,7 @@ impl TryFrom<WithLookup> for rest::WithLookup {
             collection,
             with_payload,
             with_vectors,
+            filter,
         } = value;
         Ok(Self {
             collection_name: collection,
@@ -3107,6 +3335,7 @@ impl TryFrom<WithLookup> for rest::WithLookup {
                 .transpose()?
                 .or_else(with_default_payload),
             with_vectors: with_vectors.map(|wv| wv.into()),
+            filter: filter.map(|f| f.try_into()).transpose()?,
         })
     }
 }
@@ -3129,6 +3358,47 @@ impl TryFrom<LookupLocation> for rest::LookupLocation {
     }
 }
 
+impl TryFrom<FacetHistogram> for segment_facets::FacetHistogram {
+    type Error = Status;
+
+    fn try_from(histogram: FacetHistogram) -> Result<Self, Self::Error> {
+        use super::qdrant::facet_histogram::Variant;
+
+        let FacetHistogram { variant } = histogram;
+        let variant = variant
+            .ok_or_else(|| Status::invalid_argument("expected FacetHistogram to have a value"))?;
+
+        Ok(match variant {
+            Variant::Interval(interval) => Self::Interval(OrderedFloat(interval)),
+            Variant::Bounds(FacetHistogramBounds { bounds }) => {
+                Self::Bounds(bounds.into_iter().map(OrderedFloat).collect())
+            }
+        })
+    }
+}
+
+impl From<segment_facets::FacetHistogram> for FacetHistogram {
+    fn from(histogram: segment_facets::FacetHistogram) -> Self {
+        use super::qdrant::facet_histogram::Variant;
+
+        let variant = match histogram {
+            segment_facets::FacetHistogram::Interval(interval) => Variant::Interval(interval.0),
+            segment_facets::FacetHistogram::Bounds(bounds) => {
+                Variant::Bounds(FacetHistogramBounds {
+                    bounds: bounds
+                        .into_iter()
+                        .map(|OrderedFloat(bound)| bound)
+                        .collect(),
+                })
+            }
+        };
+
+        Self {
+            variant: Some(variant),
+        }
+    }
+}
+
 impl TryFrom<FacetHitInternal> for segment_facets::FacetValueHit {
     type Error = Status;
 
@@ -3182,6 +3452,8 @@ impl TryFrom<FacetValueInternal> for segment_facets::FacetValue {
                 segment_facets::FacetValue::Uuid(Uuid::from_bytes(uuid_bytes).as_u128())
             }
             Variant::BoolValue(value) => segment_facets::FacetValue::Bool(value),
+            Variant::FloatValue(value) => segment_facets::FacetValue::Float(OrderedFloat(value)),
+            Variant::DatetimeValue(value) => segment_facets::FacetValue::DateTime(value),
         })
     }
 }
@@ -3199,6 +3471,8 @@ impl From<segment_facets::FacetValue> for FacetValueInternal {
                     Variant::UuidValue(uuid.as_bytes().to_vec())
                 }
                 segment_facets::FacetValue::Bool(value) => Variant::BoolValue(value),
+                segment_facets::FacetValue::Float(value) => Variant::FloatValue(value.0),
+                segment_facets::FacetValue::DateTime(value) => Variant::DatetimeValue(value),
             }),
         }
     }
@@ -3216,6 +3490,10 @@ impl From<segment_facets::FacetValue> for FacetValue {
                     Variant::StringValue(Uuid::from_u128(value).to_string())
                 }
                 segment_facets::FacetValue::Bool(value) => Variant::BoolValue(value),
+                segment_facets::FacetValue::Float(value) => Variant::FloatValue(value.0),
+                segment_facets::FacetValue::DateTime(value) => {
+                    Variant::StringValue(segment_facets::datetime_bucket_to_string(value))
+                }
             }),
         }
     }
@@ -3435,3 +3713,111 @@ impl From<Modifier> for grpc::Modifier {
         }
     }
 }
+
+impl From<grpc::SparseSimilarity> for SparseSimilarity {
+    fn from(value: grpc::SparseSimilarity) -> Self {
+        match value {
+            grpc::SparseSimilarity::Dot => SparseSimilarity::Dot,
+            grpc::SparseSimilarity::Jaccard => SparseSimilarity::Jaccard,
+        }
+    }
+}
+
+impl From<SparseSimilarity> for grpc::SparseSimilarity {
+    fn from(value: SparseSimilarity) -> Self {
+        match value {
+            SparseSimilarity::Dot => grpc::SparseSimilarity::Dot,
+            SparseSimilarity::Jaccard => grpc::SparseSimilarity::Jaccard,
+        }
+    }
+}
+
+impl From<segment::index::SearchStrategy> for grpc::SearchStrategy {
+    fn from(value: segment::index::SearchStrategy) -> Self {
+        match value {
+            segment::index::SearchStrategy::Hnsw => grpc::SearchStrategy::Hnsw,
+            segment::index::SearchStrategy::HnswFiltered => grpc::SearchStrategy::HnswFiltered,
+            segment::index::SearchStrategy::PayloadIndex => grpc::SearchStrategy::PayloadIndex,
+            segment::index::SearchStrategy::PlainScan => grpc::SearchStrategy::PlainScan,
+            segment::index::SearchStrategy::SparseIndex => grpc::SearchStrategy::SparseIndex,
+        }
+    }
+}
+
+impl From<grpc::SearchStrategy> for segment::index::SearchStrategy {
+    fn from(value: grpc::SearchStrategy) -> Self {
+        match value {
+            grpc::SearchStrategy::Hnsw => segment::index::SearchStrategy::Hnsw,
+            grpc::SearchStrategy::HnswFiltered => segment::index::SearchStrategy::HnswFiltered,
+            grpc::SearchStrategy::PayloadIndex => segment::index::SearchStrategy::PayloadIndex,
+            grpc::SearchStrategy::PlainScan => segment::index::SearchStrategy::PlainScan,
+            grpc::SearchStrategy::SparseIndex => segment::index::SearchStrategy::SparseIndex,
+        }
+    }
+}
+
+impl From<segment::index::SegmentSearchExplanation> for grpc::SegmentSearchExplanation {
+    fn from(value: segment::index::SegmentSearchExplanation) -> Self {
+        let segment::index::SegmentSearchExplanation {
+            segment_id,
+            vector,
+            explanation,
+        } = value;
+        let segment::index::SearchExplanation {
+            strategy,
+            estimated_cardinality,
+            min_cardinality,
+            max_cardinality,
+            cardinality_sampled,
+            ef,
+            max_ef,
+        } = explanation;
+        Self {
+            segment_id: segment_id.to_string(),
+            vector,
+            strategy: grpc::SearchStrategy::from(strategy) as i32,
+            estimated_cardinality: estimated_cardinality.map(|x| x as u64),
+            min_cardinality: min_cardinality.map(|x| x as u64),
+            max_cardinality: max_cardinality.map(|x| x as u64),
+            cardinality_sampled,
+            ef: ef.map(|x| x as u64),
+            max_ef: max_ef.map(|x| x as u64),
+        }
+    }
+}
+
+impl TryFrom<grpc::SegmentSearchExplanation> for segment::index::SegmentSearchExplanation {
+    type Error = Status;
+
+    fn try_from(value: grpc::SegmentSearchExplanation) -> Result<Self, Self::Error> {
+        let grpc::SegmentSearchExplanation {
+            segment_id,
+            vector,
+            strategy,
+            estimated_cardinality,
+            min_cardinality,
+            max_cardinality,
+            cardinality_sampled,
+            ef,
+            max_ef,
+        } = value;
+        let segment_id = Uuid::parse_str(&segment_id).map_err(|_| {
+            Status::invalid_argument(format!("Unable to parse segment UUID: {segment_id}"))
+        })?;
+        let strategy = grpc::SearchStrategy::try_from(strategy)
+            .map_err(|_| Status::invalid_argument("Unknown search strategy"))?;
+        Ok(Self {
+            segment_id,
+            vector,
+            explanation: segment::index::SearchExplanation {
+                strategy: strategy.into(),
+                estimated_cardinality: estimated_cardinality.map(|x| x as usize),
+                min_cardinality: min_cardinality.map(|x| x as usize),
+                max_cardinality: max_cardinality.map(|x| x as usize),
+                cardinality_sampled,
+                ef: ef.map(|x| x as usize),
+                max_ef: max_ef.map(|x| x as usize),
+            },
+        })
+    }
+}
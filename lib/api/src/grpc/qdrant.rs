@@ -191,6 +191,8 @@ pub mod condition {
         Nested(super::NestedCondition),
         #[prost(message, tag = "7")]
         HasVector(super::HasVectorCondition),
+        #[prost(message, tag = "8")]
+        Attribute(super::PointAttributeCondition),
     }
 }
 #[derive(serde::Serialize)]
@@ -221,6 +223,28 @@ pub struct HasVectorCondition {
     #[prost(string, tag = "1")]
     pub has_vector: ::prost::alloc::string::String,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AttributeRange {
+    #[prost(uint64, optional, tag = "1")]
+    pub lt: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "2")]
+    pub gt: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "3")]
+    pub gte: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "4")]
+    pub lte: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PointAttributeCondition {
+    #[prost(enumeration = "PointAttribute", tag = "1")]
+    pub attribute: i32,
+    #[prost(message, optional, tag = "2")]
+    pub range: ::core::option::Option<AttributeRange>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -272,7 +296,7 @@ pub struct FieldCondition {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Match {
-    #[prost(oneof = "r#match::MatchValue", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10")]
+    #[prost(oneof = "r#match::MatchValue", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11")]
     pub match_value: ::core::option::Option<r#match::MatchValue>,
 }
 /// Nested message and enum types in `Match`.
@@ -311,6 +335,9 @@ pub mod r#match {
         /// Match any word in the text
         #[prost(string, tag = "10")]
         TextAny(::prost::alloc::string::String),
+        /// Match IP addresses within the network, in CIDR notation
+        #[prost(string, tag = "11")]
+        Cidr(::prost::alloc::string::String),
     }
 }
 #[derive(serde::Serialize)]
@@ -422,6 +449,39 @@ pub struct ValuesCount {
     #[prost(uint64, optional, tag = "4")]
     pub lte: ::core::option::Option<u64>,
 }
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PointAttribute {
+    /// Numeric id of the point, points with UUID ids never match
+    Id = 0,
+    /// Version of the last operation which updated the point
+    Version = 1,
+    /// Number of named vectors assigned to the point
+    VectorsCount = 2,
+}
+impl PointAttribute {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PointAttribute::Id => "PointAttributeId",
+            PointAttribute::Version => "PointAttributeVersion",
+            PointAttribute::VectorsCount => "PointAttributeVectorsCount",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PointAttributeId" => Some(Self::Id),
+            "PointAttributeVersion" => Some(Self::Version),
+            "PointAttributeVectorsCount" => Some(Self::VectorsCount),
+            _ => None,
+        }
+    }
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -454,6 +514,13 @@ pub struct VectorParams {
     /// Configuration for multi-vector search
     #[prost(message, optional, tag = "7")]
     pub multivector_config: ::core::option::Option<MultiVectorConfig>,
+    /// Name of a registered vector storage backend to store vectors with
+    #[prost(string, optional, tag = "8")]
+    pub storage_backend: ::core::option::Option<::prost::alloc::string::String>,
+    /// Type of index to build for the vectors.
+    /// If `VectorIndexTypeNone` - vectors are only stored and never indexed
+    #[prost(enumeration = "VectorIndexType", optional, tag = "9")]
+    pub index: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -473,6 +540,9 @@ pub struct VectorParamsDiff {
     /// If set to false, the vectors will be loaded in RAM.
     #[prost(bool, optional, tag = "3")]
     pub on_disk: ::core::option::Option<bool>,
+    /// Update type of index to build for the vectors. If none - it is left unchanged.
+    #[prost(enumeration = "VectorIndexType", optional, tag = "4")]
+    pub index: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -751,6 +821,15 @@ pub struct HnswConfigDiff {
     /// Requires quantized vectors to be enabled. Multi-vectors are not supported.
     #[prost(bool, optional, tag = "7")]
     pub inline_storage: ::core::option::Option<bool>,
+    /// Encoding of the HNSW graph links. Default: Bitpacking.
+    /// Ignored when `inline_storage` is enabled.
+    #[prost(enumeration = "HnswLinksCompression", optional, tag = "8")]
+    pub links_compression: ::core::option::Option<i32>,
+    /// Link all points of each tenant of a payload field marked with `is_tenant` in the graph,
+    /// regardless of the tenant size. Searches with a filter matching a single tenant then stay
+    /// within the links of that tenant. Default: false.
+    #[prost(bool, optional, tag = "9")]
+    pub tenant_subgraphs: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -766,6 +845,25 @@ pub struct SparseIndexConfig {
     /// Datatype used to store weights in the index.
     #[prost(enumeration = "Datatype", optional, tag = "3")]
     pub datatype: ::core::option::Option<i32>,
+    /// Similarity function used to score vectors. Default: dot product.
+    #[prost(enumeration = "SparseSimilarity", optional, tag = "4")]
+    pub similarity: ::core::option::Option<i32>,
+    /// Pruning of posting lists, applied when building the index of optimized segments. Default: no pruning.
+    #[prost(message, optional, tag = "5")]
+    pub pruning: ::core::option::Option<SparsePruningConfig>,
+    /// Skip blocks of posting lists which cannot reach the top results during search. Default: false.
+    #[prost(bool, optional, tag = "6")]
+    pub block_max: ::core::option::Option<bool>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SparsePruningConfig {
+    /// Drop postings with absolute weight below this value.
+    #[prost(double, optional, tag = "1")]
+    pub min_weight: ::core::option::Option<f64>,
+    /// Keep at most this number of postings with the largest absolute weights per dimension.
+    #[prost(uint64, optional, tag = "2")]
+    pub max_postings_per_dimension: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -783,6 +881,13 @@ pub struct WalConfigDiff {
     #[prost(uint64, optional, tag = "3")]
     #[validate(range(min = 1))]
     pub wal_retain_closed: ::core::option::Option<u64>,
+    /// Time window in milliseconds to coalesce small upserts in, 0 to disable
+    #[prost(uint64, optional, tag = "4")]
+    pub wal_batch_window_ms: ::core::option::Option<u64>,
+    /// Maximal number of points in a coalesced upsert
+    #[prost(uint64, optional, tag = "5")]
+    #[validate(range(min = 1))]
+    pub wal_batch_max_points: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -862,6 +967,20 @@ pub struct OptimizersConfigDiff {
     /// Default is disabled.
     #[prost(bool, optional, tag = "10")]
     pub prevent_unoptimized: ::core::option::Option<bool>,
+    /// Maximum memory (in kilobytes) for intermediate point mappings while building an optimized segment.
+    /// If source segments have more points than fit into this budget, the mapping is spilled to disk
+    /// and points are copied into the new segment in chunks.
+    ///
+    /// If not set, the whole mapping is kept in memory.
+    #[prost(uint64, optional, tag = "11")]
+    pub max_builder_memory: ::core::option::Option<u64>,
+    /// Maximum amount of data (in kilobytes) written into payload and vector storages of a shard
+    /// since the last flush. Once exceeded, the shard is flushed without waiting for
+    /// `flush_interval_sec`, which bounds the amount of WAL to replay on restart.
+    ///
+    /// If not set, only `flush_interval_sec` is used.
+    #[prost(uint64, optional, tag = "12")]
+    pub flush_max_dirty: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1090,6 +1209,21 @@ pub struct StrictModeConfig {
     /// Max number of payload indexes in a collection
     #[prost(uint64, optional, tag = "19")]
     pub max_payload_index_count: ::core::option::Option<u64>,
+    /// Payload key of the tenant. Filtered requests must match a single value of it in a `must` condition. Points can't be retrieved by IDs.
+    #[prost(string, optional, tag = "20")]
+    pub tenant_filter_key: ::core::option::Option<::prost::alloc::string::String>,
+    /// Max number of search requests per minute to the collection, counted on the peer which received them
+    #[prost(uint32, optional, tag = "21")]
+    #[validate(range(min = 1))]
+    pub search_rate_limit: ::core::option::Option<u32>,
+    /// Max number of scroll requests per minute to the collection, counted on the peer which received them
+    #[prost(uint32, optional, tag = "22")]
+    #[validate(range(min = 1))]
+    pub scroll_rate_limit: ::core::option::Option<u32>,
+    /// Max number of update requests per minute to the collection, counted on the peer which received them
+    #[prost(uint32, optional, tag = "23")]
+    #[validate(range(min = 1))]
+    pub update_rate_limit: ::core::option::Option<u32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1112,6 +1246,13 @@ pub struct StrictModeSparse {
     #[prost(uint64, optional, tag = "10")]
     #[validate(range(min = 1))]
     pub max_length: ::core::option::Option<u64>,
+    /// Max allowed index (dimension id) of sparse vector
+    #[prost(uint32, optional, tag = "11")]
+    pub max_index: ::core::option::Option<u32>,
+    /// Max allowed absolute value of sparse vector weights
+    #[prost(double, optional, tag = "12")]
+    #[validate(range(min = 0.0))]
+    pub max_abs_value: ::core::option::Option<f64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1200,6 +1341,9 @@ pub struct CreateCollection {
     /// Arbitrary JSON metadata for the collection
     #[prost(map = "string, message", tag = "18")]
     pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+    /// Payload field which identifies the tenant, configures the collection for multitenancy
+    #[prost(string, optional, tag = "19")]
+    pub tenant_field: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1256,6 +1400,46 @@ pub struct UpdateCollection {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CloneCollection {
+    /// Name of the existing collection
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Name of the new collection
+    #[prost(string, tag = "2")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name")
+    )]
+    pub target_collection: ::prost::alloc::string::String,
+    /// Custom params for HNSW index of the new collection.
+    /// If none - params of the existing collection are used.
+    #[prost(message, optional, tag = "3")]
+    #[validate(nested)]
+    pub hnsw_config: ::core::option::Option<HnswConfigDiff>,
+    /// Custom params for Optimizers of the new collection.
+    /// If none - params of the existing collection are used.
+    #[prost(message, optional, tag = "4")]
+    #[validate(nested)]
+    pub optimizers_config: ::core::option::Option<OptimizersConfigDiff>,
+    /// Quantization parameters of the new collection.
+    /// If none - params of the existing collection are used.
+    #[prost(message, optional, tag = "5")]
+    #[validate(nested)]
+    pub quantization_config: ::core::option::Option<QuantizationConfigDiff>,
+    /// Wait timeout for operation commit in seconds, if not specified - default
+    /// value will be supplied
+    #[prost(uint64, optional, tag = "6")]
+    #[validate(range(min = 1))]
+    pub timeout: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteCollection {
     /// Name of the collection
     #[prost(string, tag = "1")]
@@ -1285,6 +1469,51 @@ pub struct CollectionOperationResponse {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DuplicateDetectionConfig {
+    /// Dense vector to compare points by. If not set, the default vector is used
+    #[prost(string, optional, tag = "1")]
+    pub vector: ::core::option::Option<::prost::alloc::string::String>,
+    /// Maximal distance between vectors for points to be considered duplicates
+    #[prost(double, tag = "2")]
+    pub epsilon: f64,
+    /// Only points with the same value of this payload field are compared
+    #[prost(string, optional, tag = "3")]
+    pub tenant_key: ::core::option::Option<::prost::alloc::string::String>,
+    /// What to do with detected duplicates
+    #[prost(enumeration = "DuplicateDetectionMode", optional, tag = "4")]
+    pub mode: ::core::option::Option<i32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShardKeyConfig {
+    /// Shard key, to which the configuration applies
+    #[prost(message, optional, tag = "1")]
+    pub shard_key: ::core::option::Option<ShardKey>,
+    /// If true - vectors of shards with this shard key are stored on disk
+    #[prost(bool, optional, tag = "2")]
+    pub on_disk: ::core::option::Option<bool>,
+    /// Custom params for HNSW index of shards with this shard key
+    #[prost(message, optional, tag = "3")]
+    pub hnsw_config: ::core::option::Option<HnswConfigDiff>,
+    /// Maximum number of points with this shard key
+    #[prost(uint64, optional, tag = "4")]
+    pub max_points_count: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IngestPayloadConfig {
+    /// Values of top-level payload fields, which are not set in the upserted point
+    #[prost(map = "string, message", tag = "1")]
+    pub defaults: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+    /// Top-level payload fields computed at ingest time
+    #[prost(map = "string, enumeration(ComputedPayloadValue)", tag = "2")]
+    pub computed: ::std::collections::HashMap<::prost::alloc::string::String, i32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectionParams {
     /// Number of shards in collection
     #[prost(uint32, tag = "3")]
@@ -1314,6 +1543,33 @@ pub struct CollectionParams {
     /// Define number of milliseconds to wait before attempting to read from another replica.
     #[prost(uint64, optional, tag = "11")]
     pub read_fan_out_delay_ms: ::core::option::Option<u64>,
+    /// Payload values larger than this number of bytes are stored separately from the rest of the payload
+    #[prost(uint64, optional, tag = "12")]
+    pub payload_overflow_threshold: ::core::option::Option<u64>,
+    /// Detection of near-identical points on upsert
+    #[prost(message, optional, tag = "13")]
+    pub duplicate_detection: ::core::option::Option<DuplicateDetectionConfig>,
+    /// Only accept payload fields declared by payload indexes
+    #[prost(enumeration = "StrictPayloadSchemaMode", optional, tag = "14")]
+    pub strict_payload_schema: ::core::option::Option<i32>,
+    /// Default and computed payload fields, which are set for upserted points
+    #[prost(message, optional, tag = "15")]
+    pub ingest_payload: ::core::option::Option<IngestPayloadConfig>,
+    /// Selection of remote replicas to read from
+    #[prost(enumeration = "ReadRouting", optional, tag = "16")]
+    pub read_routing: ::core::option::Option<i32>,
+    /// Configuration overrides of shards with specific shard keys
+    #[prost(message, repeated, tag = "17")]
+    pub shard_key_configs: ::prost::alloc::vec::Vec<ShardKeyConfig>,
+    /// If true - updates of the collection data are rejected
+    #[prost(bool, optional, tag = "18")]
+    pub read_only: ::core::option::Option<bool>,
+    /// If set - deleted points are kept in the trash for this number of seconds and can be restored
+    #[prost(uint64, optional, tag = "19")]
+    pub trash_retention_sec: ::core::option::Option<u64>,
+    /// If true - points expire at the time stored in their `expires_at` payload field
+    #[prost(bool, optional, tag = "20")]
+    pub points_expiration: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1337,6 +1593,30 @@ pub struct CollectionParamsDiff {
     /// Define number of milliseconds to wait before attempting to read from another replica.
     #[prost(uint64, optional, tag = "5")]
     pub read_fan_out_delay_ms: ::core::option::Option<u64>,
+    /// Payload values larger than this number of bytes are stored separately from the rest of the payload
+    #[prost(uint64, optional, tag = "6")]
+    pub payload_overflow_threshold: ::core::option::Option<u64>,
+    /// Detection of near-identical points on upsert
+    #[prost(message, optional, tag = "7")]
+    pub duplicate_detection: ::core::option::Option<DuplicateDetectionConfig>,
+    /// Only accept payload fields declared by payload indexes, `PayloadSchemaDisabled` to accept any payload again
+    #[prost(enumeration = "StrictPayloadSchemaMode", optional, tag = "8")]
+    pub strict_payload_schema: ::core::option::Option<i32>,
+    /// Default and computed payload fields, which are set for upserted points. Replaces the current config, empty config to disable
+    #[prost(message, optional, tag = "9")]
+    pub ingest_payload: ::core::option::Option<IngestPayloadConfig>,
+    /// Selection of remote replicas to read from
+    #[prost(enumeration = "ReadRouting", optional, tag = "10")]
+    pub read_routing: ::core::option::Option<i32>,
+    /// If true - updates of the collection data are rejected
+    #[prost(bool, optional, tag = "11")]
+    pub read_only: ::core::option::Option<bool>,
+    /// If set - deleted points are kept in the trash for this number of seconds and can be restored. 0 to disable the trash
+    #[prost(uint64, optional, tag = "12")]
+    pub trash_retention_sec: ::core::option::Option<u64>,
+    /// If true - points expire at the time stored in their `expires_at` payload field
+    #[prost(bool, optional, tag = "13")]
+    pub points_expiration: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1555,6 +1835,14 @@ pub struct UuidIndexParams {
     #[prost(bool, optional, tag = "3")]
     pub enable_hnsw: ::core::option::Option<bool>,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IpIndexParams {
+    /// If true - store index on disk.
+    #[prost(bool, optional, tag = "1")]
+    pub on_disk: ::core::option::Option<bool>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1562,7 +1850,7 @@ pub struct UuidIndexParams {
 pub struct PayloadIndexParams {
     #[prost(
         oneof = "payload_index_params::IndexParams",
-        tags = "3, 2, 4, 5, 1, 6, 7, 8"
+        tags = "3, 2, 4, 5, 1, 6, 7, 8, 9"
     )]
     #[validate(nested)]
     pub index_params: ::core::option::Option<payload_index_params::IndexParams>,
@@ -1597,6 +1885,9 @@ pub mod payload_index_params {
         /// Parameters for uuid index
         #[prost(message, tag = "8")]
         UuidIndexParams(super::UuidIndexParams),
+        /// Parameters for ip index
+        #[prost(message, tag = "9")]
+        IpIndexParams(super::IpIndexParams),
     }
 }
 #[derive(serde::Serialize)]
@@ -1624,6 +1915,20 @@ pub struct UpdateQueueInfo {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IndexingBacklogInfo {
+    /// Approximate number of vectors in large segments, which are not indexed yet
+    #[prost(uint64, tag = "1")]
+    pub unindexed_vectors_count: u64,
+    /// Approximate number of all vectors
+    #[prost(uint64, tag = "2")]
+    pub vectors_count: u64,
+    /// Whether the fraction of unindexed vectors exceeds the threshold
+    #[prost(bool, tag = "3")]
+    pub search_degraded: bool,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectionInfo {
     /// operating condition of the collection
     #[prost(enumeration = "CollectionStatus", tag = "1")]
@@ -1655,6 +1960,9 @@ pub struct CollectionInfo {
     /// Update queue info
     #[prost(message, optional, tag = "12")]
     pub update_queue: ::core::option::Option<UpdateQueueInfo>,
+    /// Vectors pending indexing, which are searched with a plain scan
+    #[prost(message, optional, tag = "13")]
+    pub indexing_backlog: ::core::option::Option<IndexingBacklogInfo>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1674,7 +1982,7 @@ pub struct ChangeAliases {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AliasOperations {
-    #[prost(oneof = "alias_operations::Action", tags = "1, 2, 3")]
+    #[prost(oneof = "alias_operations::Action", tags = "1, 2, 3, 4")]
     pub action: ::core::option::Option<alias_operations::Action>,
 }
 /// Nested message and enum types in `AliasOperations`.
@@ -1689,6 +1997,8 @@ pub mod alias_operations {
         RenameAlias(super::RenameAlias),
         #[prost(message, tag = "3")]
         DeleteAlias(super::DeleteAlias),
+        #[prost(message, tag = "4")]
+        SwitchAliasGroup(super::SwitchAliasGroup),
     }
 }
 #[derive(serde::Serialize)]
@@ -1721,6 +2031,14 @@ pub struct DeleteAlias {
     #[prost(string, tag = "1")]
     pub alias_name: ::prost::alloc::string::String,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwitchAliasGroup {
+    /// Aliases of the group with the collections they should point to
+    #[prost(message, repeated, tag = "2")]
+    pub aliases: ::prost::alloc::vec::Vec<CreateAlias>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1983,6 +2301,15 @@ pub struct CreateShardKey {
     /// Initial state of created replicas. Warning: use with care.
     #[prost(enumeration = "ReplicaState", optional, tag = "5")]
     pub initial_state: ::core::option::Option<i32>,
+    /// If true - vectors of created shards are stored on disk
+    #[prost(bool, optional, tag = "6")]
+    pub on_disk: ::core::option::Option<bool>,
+    /// Custom params for HNSW index of created shards
+    #[prost(message, optional, tag = "7")]
+    pub hnsw_config: ::core::option::Option<HnswConfigDiff>,
+    /// Maximum number of points with this shard key
+    #[prost(uint64, optional, tag = "8")]
+    pub max_points_count: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -2115,6 +2442,63 @@ pub struct ListShardKeysResponse {
     #[prost(double, tag = "2")]
     pub time: f64,
 }
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelOptimizationsRequest {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Name of the optimizer to cancel, or `payload_index` for payload index builds.
+    /// All are cancelled if not specified.
+    #[prost(string, optional, tag = "2")]
+    pub optimizer: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelOptimizationsResponse {
+    /// Number of cancelled optimizations
+    #[prost(uint64, tag = "1")]
+    pub cancelled: u64,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResumeOptimizationsRequest {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PinVectorRequest {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Name of the vector, if not specified - the default vector is used
+    #[prost(string, optional, tag = "2")]
+    pub vector: ::core::option::Option<::prost::alloc::string::String>,
+}
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -2151,6 +2535,35 @@ impl Datatype {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum VectorIndexType {
+    /// Build HNSW index, once segment size reaches the indexing threshold
+    Hnsw = 0,
+    /// Only store vectors, never build an index for them
+    None = 1,
+}
+impl VectorIndexType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            VectorIndexType::Hnsw => "VectorIndexTypeHnsw",
+            VectorIndexType::None => "VectorIndexTypeNone",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VectorIndexTypeHnsw" => Some(Self::Hnsw),
+            "VectorIndexTypeNone" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum Modifier {
     None = 0,
     /// Apply Inverse Document Frequency
@@ -2179,6 +2592,35 @@ impl Modifier {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum SparseSimilarity {
+    /// Dot product of weights
+    Dot = 0,
+    /// Weighted Jaccard similarity: sum of minimum weights over sum of maximum weights
+    Jaccard = 1,
+}
+impl SparseSimilarity {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SparseSimilarity::Dot => "SparseSimilarityDot",
+            SparseSimilarity::Jaccard => "SparseSimilarityJaccard",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SparseSimilarityDot" => Some(Self::Dot),
+            "SparseSimilarityJaccard" => Some(Self::Jaccard),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum MultiVectorComparator {
     MaxSim = 0,
 }
@@ -2209,6 +2651,7 @@ pub enum Distance {
     Euclid = 2,
     Dot = 3,
     Manhattan = 4,
+    Hamming = 5,
 }
 impl Distance {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2222,6 +2665,7 @@ impl Distance {
             Distance::Euclid => "Euclid",
             Distance::Dot => "Dot",
             Distance::Manhattan => "Manhattan",
+            Distance::Hamming => "Hamming",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2232,6 +2676,7 @@ impl Distance {
             "Euclid" => Some(Self::Euclid),
             "Dot" => Some(Self::Dot),
             "Manhattan" => Some(Self::Manhattan),
+            "Hamming" => Some(Self::Hamming),
             _ => None,
         }
     }
@@ -2289,6 +2734,7 @@ pub enum PayloadSchemaType {
     Bool = 6,
     Datetime = 7,
     Uuid = 8,
+    Ip = 9,
 }
 impl PayloadSchemaType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2306,6 +2752,7 @@ impl PayloadSchemaType {
             PayloadSchemaType::Bool => "Bool",
             PayloadSchemaType::Datetime => "Datetime",
             PayloadSchemaType::Uuid => "Uuid",
+            PayloadSchemaType::Ip => "Ip",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2320,6 +2767,7 @@ impl PayloadSchemaType {
             "Bool" => Some(Self::Bool),
             "Datetime" => Some(Self::Datetime),
             "Uuid" => Some(Self::Uuid),
+            "Ip" => Some(Self::Ip),
             _ => None,
         }
     }
@@ -2390,6 +2838,39 @@ impl CompressionRatio {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum HnswLinksCompression {
+    /// Links are bitpacked. Fastest to traverse.
+    Bitpacking = 0,
+    /// Links are delta and varint encoded per node. Smaller for large `m`, but slower to decode.
+    DeltaVarint = 1,
+    /// Same as `DeltaVarint`, but links are kept in RAM once decoded.
+    DeltaVarintCached = 2,
+}
+impl HnswLinksCompression {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            HnswLinksCompression::Bitpacking => "Bitpacking",
+            HnswLinksCompression::DeltaVarint => "DeltaVarint",
+            HnswLinksCompression::DeltaVarintCached => "DeltaVarintCached",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Bitpacking" => Some(Self::Bitpacking),
+            "DeltaVarint" => Some(Self::DeltaVarint),
+            "DeltaVarintCached" => Some(Self::DeltaVarintCached),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum BinaryQuantizationEncoding {
     OneBit = 0,
     TwoBits = 1,
@@ -2449,22 +2930,146 @@ impl ShardingMethod {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
-pub enum TokenizerType {
-    Unknown = 0,
-    Prefix = 1,
-    Whitespace = 2,
-    Word = 3,
-    Multilingual = 4,
-}
-impl TokenizerType {
+pub enum DuplicateDetectionMode {
+    /// Only count detected duplicates in telemetry
+    DuplicateCount = 0,
+    /// Reject upserts of duplicate points
+    DuplicateReject = 1,
+    /// Store ID of the existing point in the `duplicate_of` payload field
+    DuplicateTag = 2,
+}
+impl DuplicateDetectionMode {
     /// String value of the enum field names used in the ProtoBuf definition.
     ///
     /// The values are not transformed in any way and thus are considered stable
     /// (if the ProtoBuf definition does not change) and safe for programmatic use.
     pub fn as_str_name(&self) -> &'static str {
         match self {
-            TokenizerType::Unknown => "Unknown",
-            TokenizerType::Prefix => "Prefix",
+            DuplicateDetectionMode::DuplicateCount => "DuplicateCount",
+            DuplicateDetectionMode::DuplicateReject => "DuplicateReject",
+            DuplicateDetectionMode::DuplicateTag => "DuplicateTag",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DuplicateCount" => Some(Self::DuplicateCount),
+            "DuplicateReject" => Some(Self::DuplicateReject),
+            "DuplicateTag" => Some(Self::DuplicateTag),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ReadRouting {
+    /// Read from remote replicas in random order
+    ReadRoutingRandom = 0,
+    /// Read from remote replicas with the lowest recently observed latency first
+    ReadRoutingLatency = 1,
+}
+impl ReadRouting {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ReadRouting::ReadRoutingRandom => "ReadRoutingRandom",
+            ReadRouting::ReadRoutingLatency => "ReadRoutingLatency",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ReadRoutingRandom" => Some(Self::ReadRoutingRandom),
+            "ReadRoutingLatency" => Some(Self::ReadRoutingLatency),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StrictPayloadSchemaMode {
+    /// Reject updates with undeclared payload fields or values of wrong type
+    PayloadSchemaReject = 0,
+    /// Drop undeclared payload fields and values of wrong type
+    PayloadSchemaDrop = 1,
+    /// Accept any payload, only allowed in collection updates
+    PayloadSchemaDisabled = 2,
+}
+impl StrictPayloadSchemaMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            StrictPayloadSchemaMode::PayloadSchemaReject => "PayloadSchemaReject",
+            StrictPayloadSchemaMode::PayloadSchemaDrop => "PayloadSchemaDrop",
+            StrictPayloadSchemaMode::PayloadSchemaDisabled => "PayloadSchemaDisabled",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PayloadSchemaReject" => Some(Self::PayloadSchemaReject),
+            "PayloadSchemaDrop" => Some(Self::PayloadSchemaDrop),
+            "PayloadSchemaDisabled" => Some(Self::PayloadSchemaDisabled),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ComputedPayloadValue {
+    /// Current time as RFC 3339 datetime string
+    ComputedNow = 0,
+    /// Current time as number of seconds since Unix epoch
+    ComputedNowUnix = 1,
+}
+impl ComputedPayloadValue {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ComputedPayloadValue::ComputedNow => "ComputedNow",
+            ComputedPayloadValue::ComputedNowUnix => "ComputedNowUnix",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ComputedNow" => Some(Self::ComputedNow),
+            "ComputedNowUnix" => Some(Self::ComputedNowUnix),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TokenizerType {
+    Unknown = 0,
+    Prefix = 1,
+    Whitespace = 2,
+    Word = 3,
+    Multilingual = 4,
+}
+impl TokenizerType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TokenizerType::Unknown => "Unknown",
+            TokenizerType::Prefix => "Prefix",
             TokenizerType::Whitespace => "Whitespace",
             TokenizerType::Word => "Word",
             TokenizerType::Multilingual => "Multilingual",
@@ -3064,6 +3669,137 @@ pub mod collections_client {
                 .insert(GrpcMethod::new("qdrant.Collections", "ListShardKeys"));
             self.inner.unary(req, path, codec).await
         }
+        /// Cancel ongoing optimizations and payload index builds of the collection on this peer
+        pub async fn cancel_optimizations(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelOptimizationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CancelOptimizationsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Collections/CancelOptimizations",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "CancelOptimizations"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Resume cancelled optimizations and payload index builds of the collection on this peer
+        pub async fn resume_optimizations(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ResumeOptimizationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CollectionOperationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Collections/ResumeOptimizations",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "ResumeOptimizations"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Create a new collection with the config, payload indexes and filter
+        /// templates of the existing one, and copy all of its points
+        pub async fn clone_collection(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CloneCollection>,
+        ) -> std::result::Result<
+            tonic::Response<super::CollectionOperationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Collections/CloneCollection",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "CloneCollection"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Load the vector of the collection into memory on this peer
+        pub async fn pin_vector(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PinVectorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CollectionOperationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Collections/PinVector",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "PinVector"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Allow the vector of the collection to be evicted from memory on this peer
+        pub async fn unpin_vector(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PinVectorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CollectionOperationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Collections/UnpinVector",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "UnpinVector"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -3185,6 +3921,47 @@ pub mod collections_server {
             tonic::Response<super::ListShardKeysResponse>,
             tonic::Status,
         >;
+        /// Cancel ongoing optimizations and payload index builds of the collection on this peer
+        async fn cancel_optimizations(
+            &self,
+            request: tonic::Request<super::CancelOptimizationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CancelOptimizationsResponse>,
+            tonic::Status,
+        >;
+        /// Resume cancelled optimizations and payload index builds of the collection on this peer
+        async fn resume_optimizations(
+            &self,
+            request: tonic::Request<super::ResumeOptimizationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CollectionOperationResponse>,
+            tonic::Status,
+        >;
+        /// Create a new collection with the config, payload indexes and filter
+        /// templates of the existing one, and copy all of its points
+        async fn clone_collection(
+            &self,
+            request: tonic::Request<super::CloneCollection>,
+        ) -> std::result::Result<
+            tonic::Response<super::CollectionOperationResponse>,
+            tonic::Status,
+        >;
+        /// Load the vector of the collection into memory on this peer
+        async fn pin_vector(
+            &self,
+            request: tonic::Request<super::PinVectorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CollectionOperationResponse>,
+            tonic::Status,
+        >;
+        /// Allow the vector of the collection to be evicted from memory on this peer
+        async fn unpin_vector(
+            &self,
+            request: tonic::Request<super::PinVectorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CollectionOperationResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct CollectionsServer<T: Collections> {
@@ -3918,50 +4695,280 @@ pub mod collections_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
-            }
-        }
-    }
-    impl<T: Collections> Clone for CollectionsServer<T> {
-        fn clone(&self) -> Self {
-            let inner = self.inner.clone();
-            Self {
-                inner,
-                accept_compression_encodings: self.accept_compression_encodings,
-                send_compression_encodings: self.send_compression_encodings,
-                max_decoding_message_size: self.max_decoding_message_size,
-                max_encoding_message_size: self.max_encoding_message_size,
-            }
-        }
-    }
-    impl<T: Collections> Clone for _Inner<T> {
-        fn clone(&self) -> Self {
-            Self(Arc::clone(&self.0))
-        }
-    }
-    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "{:?}", self.0)
-        }
-    }
-    impl<T: Collections> tonic::server::NamedService for CollectionsServer<T> {
-        const NAME: &'static str = "qdrant.Collections";
-    }
-}
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
+                "/qdrant.Collections/CancelOptimizations" => {
+                    #[allow(non_camel_case_types)]
+                    struct CancelOptimizationsSvc<T: Collections>(pub Arc<T>);
+                    impl<
+                        T: Collections,
+                    > tonic::server::UnaryService<super::CancelOptimizationsRequest>
+                    for CancelOptimizationsSvc<T> {
+                        type Response = super::CancelOptimizationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CancelOptimizationsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Collections>::cancel_optimizations(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CancelOptimizationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Collections/ResumeOptimizations" => {
+                    #[allow(non_camel_case_types)]
+                    struct ResumeOptimizationsSvc<T: Collections>(pub Arc<T>);
+                    impl<
+                        T: Collections,
+                    > tonic::server::UnaryService<super::ResumeOptimizationsRequest>
+                    for ResumeOptimizationsSvc<T> {
+                        type Response = super::CollectionOperationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ResumeOptimizationsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Collections>::resume_optimizations(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ResumeOptimizationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Collections/CloneCollection" => {
+                    #[allow(non_camel_case_types)]
+                    struct CloneCollectionSvc<T: Collections>(pub Arc<T>);
+                    impl<
+                        T: Collections,
+                    > tonic::server::UnaryService<super::CloneCollection>
+                    for CloneCollectionSvc<T> {
+                        type Response = super::CollectionOperationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CloneCollection>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Collections>::clone_collection(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CloneCollectionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Collections/PinVector" => {
+                    #[allow(non_camel_case_types)]
+                    struct PinVectorSvc<T: Collections>(pub Arc<T>);
+                    impl<
+                        T: Collections,
+                    > tonic::server::UnaryService<super::PinVectorRequest>
+                    for PinVectorSvc<T> {
+                        type Response = super::CollectionOperationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PinVectorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Collections>::pin_vector(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PinVectorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Collections/UnpinVector" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnpinVectorSvc<T: Collections>(pub Arc<T>);
+                    impl<
+                        T: Collections,
+                    > tonic::server::UnaryService<super::PinVectorRequest>
+                    for UnpinVectorSvc<T> {
+                        type Response = super::CollectionOperationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PinVectorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Collections>::unpin_vector(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UnpinVectorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: Collections> Clone for CollectionsServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: Collections> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: Collections> tonic::server::NamedService for CollectionsServer<T> {
+        const NAME: &'static str = "qdrant.Collections";
+    }
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCollectionInfoRequestInternal {
     #[prost(message, optional, tag = "1")]
@@ -4023,6 +5030,9 @@ pub struct GetShardRecoveryPointRequest {
     /// Id of the shard
     #[prost(uint32, tag = "2")]
     pub shard_id: u32,
+    /// Wait until all operations of the recovery point are applied, before returning it
+    #[prost(bool, optional, tag = "3")]
+    pub applied: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -4098,6 +5108,9 @@ pub struct GetShardOptimizationsRequest {
     /// Whether to include idle segments
     #[prost(bool, tag = "5")]
     pub with_idle_segments: bool,
+    /// Whether to include latest decisions of the optimizers
+    #[prost(bool, tag = "6")]
+    pub with_decisions: bool,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -4854,7 +5867,7 @@ pub struct WriteOrdering {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReadConsistency {
-    #[prost(oneof = "read_consistency::Value", tags = "1, 2")]
+    #[prost(oneof = "read_consistency::Value", tags = "1, 2, 3")]
     pub value: ::core::option::Option<read_consistency::Value>,
 }
 /// Nested message and enum types in `ReadConsistency`.
@@ -4870,6 +5883,10 @@ pub mod read_consistency {
         /// and return points which are present on all of them
         #[prost(uint64, tag = "2")]
         Factor(u64),
+        /// Read from the local replica, if it is behind the updates it has received
+        /// by no more than the given number of milliseconds, otherwise read from the leader
+        #[prost(uint64, tag = "3")]
+        MaxStalenessMs(u64),
     }
 }
 #[derive(serde::Serialize)]
@@ -4937,7 +5954,7 @@ pub struct Vector {
     #[deprecated]
     #[prost(uint32, optional, tag = "3")]
     pub vectors_count: ::core::option::Option<u32>,
-    #[prost(oneof = "vector::Vector", tags = "101, 102, 103, 104, 105, 106")]
+    #[prost(oneof = "vector::Vector", tags = "101, 102, 103, 104, 105, 106, 107")]
     pub vector: ::core::option::Option<vector::Vector>,
 }
 /// Nested message and enum types in `Vector`.
@@ -4961,6 +5978,9 @@ pub mod vector {
         Image(super::Image),
         #[prost(message, tag = "106")]
         Object(super::InferenceObject),
+        /// Dense vector of `uint8` elements, one byte per element
+        #[prost(message, tag = "107")]
+        DenseBytes(super::DenseByteVector),
     }
 }
 #[derive(serde::Serialize)]
@@ -5009,6 +6029,13 @@ pub struct DenseVector {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DenseByteVector {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SparseVector {
     #[prost(float, repeated, tag = "1")]
     pub values: ::prost::alloc::vec::Vec<f32>,
@@ -5133,7 +6160,7 @@ pub struct DeletePoints {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetPoints {
+pub struct RestorePoints {
     /// name of the collection
     #[prost(string, tag = "1")]
     #[validate(
@@ -5141,10 +6168,36 @@ pub struct GetPoints {
         custom(function = "common::validation::validate_collection_name_legacy")
     )]
     pub collection_name: ::prost::alloc::string::String,
-    /// List of points to retrieve
-    #[prost(message, repeated, tag = "2")]
-    pub ids: ::prost::alloc::vec::Vec<PointId>,
-    /// Options for specifying which payload to include or not
+    /// Wait until the changes have been applied?
+    #[prost(bool, optional, tag = "2")]
+    pub wait: ::core::option::Option<bool>,
+    /// IDs of deleted points to restore from the trash
+    #[prost(message, repeated, tag = "3")]
+    #[validate(length(min = 1))]
+    pub points: ::prost::alloc::vec::Vec<PointId>,
+    /// Write ordering guarantees
+    #[prost(message, optional, tag = "4")]
+    pub ordering: ::core::option::Option<WriteOrdering>,
+    /// Timeout for the request in seconds
+    #[prost(uint64, optional, tag = "5")]
+    pub timeout: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPoints {
+    /// name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// List of points to retrieve
+    #[prost(message, repeated, tag = "2")]
+    pub ids: ::prost::alloc::vec::Vec<PointId>,
+    /// Options for specifying which payload to include or not
     #[prost(message, optional, tag = "4")]
     pub with_payload: ::core::option::Option<WithPayloadSelector>,
     /// Options for specifying which vectors to include into response
@@ -5159,6 +6212,10 @@ pub struct GetPoints {
     /// If set, overrides global timeout setting for this request. Unit is seconds.
     #[prost(uint64, optional, tag = "8")]
     pub timeout: ::core::option::Option<u64>,
+    /// If true, return the version of the last operation which modified each point.
+    /// Reads are served by the same replica of each shard, as long as its replica set doesn't change
+    #[prost(bool, optional, tag = "9")]
+    pub with_version: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5412,8 +6469,16 @@ pub struct PayloadExcludeSelector {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadProjectSelector {
+    /// List of projection expressions, supporting wildcard keys and array slices
+    #[prost(string, repeated, tag = "1")]
+    pub fields: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WithPayloadSelector {
-    #[prost(oneof = "with_payload_selector::SelectorOptions", tags = "1, 2, 3")]
+    #[prost(oneof = "with_payload_selector::SelectorOptions", tags = "1, 2, 3, 4")]
     pub selector_options: ::core::option::Option<with_payload_selector::SelectorOptions>,
 }
 /// Nested message and enum types in `WithPayloadSelector`.
@@ -5429,6 +6494,8 @@ pub mod with_payload_selector {
         Include(super::PayloadIncludeSelector),
         #[prost(message, tag = "3")]
         Exclude(super::PayloadExcludeSelector),
+        #[prost(message, tag = "4")]
+        Project(super::PayloadProjectSelector),
     }
 }
 #[derive(validator::Validate)]
@@ -5591,6 +6658,28 @@ pub struct SearchParams {
     #[prost(message, optional, tag = "5")]
     #[validate(nested)]
     pub acorn: ::core::option::Option<AcornSearchParams>,
+    /// If enabled, requests with `score_threshold` search segments in parallel waves of growing
+    /// size, starting from the ones which contributed to results most often, and stop once `limit` results above
+    /// the threshold are found. Speeds up search in collections with many near-empty segments,
+    /// but better scored points in segments which were not searched may be missed.
+    #[prost(bool, optional, tag = "6")]
+    pub early_exit: ::core::option::Option<bool>,
+    /// Maximal size of the beam for the dynamic ef mode. If set, HNSW search starts with
+    /// `hnsw_ef` (or `limit`) and doubles the beam until the top results stop changing between
+    /// rounds, or the beam reaches this size.
+    #[prost(uint64, optional, tag = "7")]
+    pub max_hnsw_ef: ::core::option::Option<u64>,
+    /// Truncate results at the first large score drop. If set, results are cut before the first
+    /// point which score differs from the score of the previous point by more than this fraction
+    /// of the larger absolute score of the two. Applied to the final results, before `offset`.
+    #[prost(double, optional, tag = "8")]
+    #[validate(range(min = 0.0))]
+    pub score_gap_cutoff: ::core::option::Option<f64>,
+    /// If enabled, searches which reach the timeout return the results found so far instead of
+    /// an error. Segment searches are stopped shortly before the timeout, and query responses
+    /// are marked with `timed_out: true` if results may be incomplete.
+    #[prost(bool, optional, tag = "9")]
+    pub allow_partial_results: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5646,6 +6735,12 @@ pub struct SearchPoints {
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
     #[prost(message, optional, tag = "15")]
     pub sparse_indices: ::core::option::Option<SparseIndices>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "16")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
+    /// If true, the response explains how segments of each shard executed the search
+    #[prost(bool, optional, tag = "17")]
+    pub explain: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5683,6 +6778,9 @@ pub struct WithLookup {
     /// Options for specifying which vectors to include (or not)
     #[prost(message, optional, tag = "3")]
     pub with_vectors: ::core::option::Option<WithVectorsSelector>,
+    /// Only look up points which satisfy these conditions
+    #[prost(message, optional, tag = "4")]
+    pub filter: ::core::option::Option<Filter>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5748,6 +6846,9 @@ pub struct SearchPointGroups {
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
     #[prost(message, optional, tag = "16")]
     pub sparse_indices: ::core::option::Option<SparseIndices>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "17")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5826,6 +6927,17 @@ pub struct ScrollPoints {
     /// If set, overrides global timeout setting for this request. Unit is seconds.
     #[prost(uint64, optional, tag = "11")]
     pub timeout: ::core::option::Option<u64>,
+    /// If true, return the version of the last operation which modified each point.
+    /// Reads are served by the same replica of each shard, as long as its replica set doesn't change
+    #[prost(bool, optional, tag = "12")]
+    pub with_version: ::core::option::Option<bool>,
+    /// Return only the first point for each unique value of this payload key.
+    /// Requires a keyword, integer, uuid or bool payload index on the key
+    #[prost(string, optional, tag = "13")]
+    pub distinct_by: ::core::option::Option<::prost::alloc::string::String>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "14")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5908,6 +7020,9 @@ pub struct RecommendPoints {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[prost(message, optional, tag = "20")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "21")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6011,6 +7126,9 @@ pub struct RecommendPointGroups {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[prost(message, optional, tag = "21")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "22")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6112,6 +7230,9 @@ pub struct DiscoverPoints {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[prost(message, optional, tag = "14")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "15")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6164,6 +7285,9 @@ pub struct CountPoints {
     /// If set, overrides global timeout setting for this request. Unit is seconds.
     #[prost(uint64, optional, tag = "6")]
     pub timeout: ::core::option::Option<u64>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "7")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6288,6 +7412,40 @@ pub struct NaiveFeedbackStrategy {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArithmeticInput {
+    /// Terms of the sum. Ids are resolved into vectors of the stored points before the search.
+    #[prost(message, repeated, tag = "1")]
+    #[validate(length(min = 1), nested)]
+    pub terms: ::prost::alloc::vec::Vec<ArithmeticTerm>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArithmeticTerm {
+    /// The id or vector to add
+    #[prost(message, optional, tag = "1")]
+    #[validate(nested)]
+    pub vector: ::core::option::Option<VectorInput>,
+    /// Multiplier of the vector, e.g. -1 to subtract it. Default is 1.
+    #[prost(float, optional, tag = "2")]
+    pub weight: ::core::option::Option<f32>,
+}
+/// Sample points with probability proportional to the value of a numeric payload field.
+/// Points without a positive value are never sampled.
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WeightedSample {
+    /// Payload key with numeric values, requires a range index
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Formula {
     #[prost(message, optional, tag = "1")]
     #[validate(nested)]
@@ -6508,7 +7666,7 @@ pub struct Rrf {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Query {
-    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11")]
+    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13")]
     #[validate(nested)]
     pub variant: ::core::option::Option<query::Variant>,
 }
@@ -6551,6 +7709,12 @@ pub mod query {
         /// Search with feedback from some oracle.
         #[prost(message, tag = "11")]
         RelevanceFeedback(super::RelevanceFeedbackInput),
+        /// Sample points, weighted by a numeric payload field.
+        #[prost(message, tag = "12")]
+        WeightedSample(super::WeightedSample),
+        /// Find the nearest neighbors to a weighted sum of vectors, e.g. `A - B + C`.
+        #[prost(message, tag = "13")]
+        Arithmetic(super::ArithmeticInput),
     }
 }
 #[derive(validator::Validate)]
@@ -6656,6 +7820,15 @@ pub struct QueryPoints {
     #[prost(uint64, optional, tag = "15")]
     #[validate(range(min = 1))]
     pub timeout: ::core::option::Option<u64>,
+    /// If set, shards which fail are skipped, and results of the other shards are returned.
+    #[prost(bool, optional, tag = "16")]
+    pub allow_partial: ::core::option::Option<bool>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "17")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
+    /// If true, the response explains how segments of each shard executed the searches
+    #[prost(bool, optional, tag = "18")]
+    pub explain: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6678,6 +7851,9 @@ pub struct QueryBatchPoints {
     #[prost(uint64, optional, tag = "4")]
     #[validate(range(min = 1))]
     pub timeout: ::core::option::Option<u64>,
+    /// If set, shards which fail are skipped, and results of the other shards are returned.
+    #[prost(bool, optional, tag = "5")]
+    pub allow_partial: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6752,6 +7928,9 @@ pub struct QueryPointGroups {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[prost(message, optional, tag = "17")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "18")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6789,12 +7968,51 @@ pub struct FacetCounts {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[prost(message, optional, tag = "8")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Count points in numeric ranges of the key instead of unique values. Requires a range index on the key. Requests with more than 10000 buckets are rejected.
+    #[prost(message, optional, tag = "9")]
+    #[validate(nested)]
+    pub histogram: ::core::option::Option<FacetHistogram>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "10")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FacetHistogram {
+    /// Bucket bounds are numbers, for datetime keys they are Unix timestamps in seconds
+    #[prost(oneof = "facet_histogram::Variant", tags = "1, 2")]
+    #[validate(nested)]
+    pub variant: ::core::option::Option<facet_histogram::Variant>,
+}
+/// Nested message and enum types in `FacetHistogram`.
+pub mod facet_histogram {
+    /// Bucket bounds are numbers, for datetime keys they are Unix timestamps in seconds
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Variant {
+        /// Width of buckets, which start at multiples of it
+        #[prost(double, tag = "1")]
+        Interval(f64),
+        /// Bucket bounds in ascending order, values outside of the bounds are not counted
+        #[prost(message, tag = "2")]
+        Bounds(super::FacetHistogramBounds),
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FacetHistogramBounds {
+    #[prost(double, repeated, tag = "1")]
+    pub bounds: ::prost::alloc::vec::Vec<f64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FacetValue {
-    #[prost(oneof = "facet_value::Variant", tags = "1, 2, 3")]
+    #[prost(oneof = "facet_value::Variant", tags = "1, 2, 3, 4")]
     pub variant: ::core::option::Option<facet_value::Variant>,
 }
 /// Nested message and enum types in `FacetValue`.
@@ -6812,6 +8030,9 @@ pub mod facet_value {
         /// Boolean value from the facet
         #[prost(bool, tag = "3")]
         BoolValue(bool),
+        /// Lower bound of a histogram bucket
+        #[prost(double, tag = "4")]
+        FloatValue(f64),
     }
 }
 #[derive(serde::Serialize)]
@@ -6829,6 +8050,198 @@ pub struct FacetHit {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FacetMultiCounts {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Payload keys of the facets, counts for all keys are collected at once
+    #[prost(string, repeated, tag = "2")]
+    #[validate(length(min = 1))]
+    pub keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Filter conditions - return only those points that satisfy the specified conditions.
+    #[prost(message, optional, tag = "3")]
+    #[validate(nested)]
+    pub filter: ::core::option::Option<Filter>,
+    /// Max number of facets for each key. Default is 10.
+    #[prost(uint64, optional, tag = "4")]
+    pub limit: ::core::option::Option<u64>,
+    /// If true, return exact counts, slower but useful for debugging purposes. Default is false.
+    #[prost(bool, optional, tag = "5")]
+    pub exact: ::core::option::Option<bool>,
+    /// If set, overrides global timeout setting for this request. Unit is seconds.
+    #[prost(uint64, optional, tag = "6")]
+    #[validate(range(min = 1))]
+    pub timeout: ::core::option::Option<u64>,
+    /// Options for specifying read consistency guarantees
+    #[prost(message, optional, tag = "7")]
+    pub read_consistency: ::core::option::Option<ReadConsistency>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[prost(message, optional, tag = "8")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "9")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyFacetHits {
+    /// Payload key of the facet
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub hits: ::prost::alloc::vec::Vec<FacetHit>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregatePoints {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Payload key to aggregate values of
+    #[prost(string, tag = "2")]
+    pub key: ::prost::alloc::string::String,
+    /// Filter conditions - aggregate only those points that satisfy the specified conditions.
+    #[prost(message, optional, tag = "3")]
+    #[validate(nested)]
+    pub filter: ::core::option::Option<Filter>,
+    /// If set, overrides global timeout setting for this request. Unit is seconds.
+    #[prost(uint64, optional, tag = "4")]
+    #[validate(range(min = 1))]
+    pub timeout: ::core::option::Option<u64>,
+    /// Options for specifying read consistency guarantees
+    #[prost(message, optional, tag = "5")]
+    pub read_consistency: ::core::option::Option<ReadConsistency>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[prost(message, optional, tag = "6")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "7")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JoinPoints {
+    /// Name of the collection to join points of
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Name of the collection to look for the neighbors in
+    #[prost(string, tag = "2")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub target_collection: ::prost::alloc::string::String,
+    /// Define which vector of the points to use as a query. If missing, the default vector is used.
+    #[prost(string, optional, tag = "3")]
+    pub using: ::core::option::Option<::prost::alloc::string::String>,
+    /// Define which vector of the target collection to search.
+    /// If missing, the vector with the same name as `using` is used.
+    #[prost(string, optional, tag = "4")]
+    pub target_using: ::core::option::Option<::prost::alloc::string::String>,
+    /// Join only points which satisfy these conditions
+    #[prost(message, optional, tag = "5")]
+    #[validate(nested)]
+    pub filter: ::core::option::Option<Filter>,
+    /// Look only for neighbors which satisfy these conditions
+    #[prost(message, optional, tag = "6")]
+    #[validate(nested)]
+    pub target_filter: ::core::option::Option<Filter>,
+    /// How many neighbors to find for each point. Default is 10.
+    #[prost(uint64, optional, tag = "7")]
+    #[validate(range(min = 1))]
+    pub limit: ::core::option::Option<u64>,
+    /// Additional search params
+    #[prost(message, optional, tag = "8")]
+    #[validate(nested)]
+    pub params: ::core::option::Option<SearchParams>,
+    /// Define a minimal score threshold for the neighbors
+    #[prost(float, optional, tag = "9")]
+    pub score_threshold: ::core::option::Option<f32>,
+    /// Start joining from this point id. Use `next_page_offset` of the previous response to continue a join.
+    #[prost(message, optional, tag = "10")]
+    pub offset: ::core::option::Option<PointId>,
+    /// Max number of points to join in this request. Default is 1000.
+    #[prost(uint64, optional, tag = "11")]
+    #[validate(range(min = 1))]
+    pub points_limit: ::core::option::Option<u64>,
+    /// Write the neighbors of each point into this payload key, instead of returning them in the response
+    #[prost(string, optional, tag = "12")]
+    pub payload_key: ::core::option::Option<::prost::alloc::string::String>,
+    /// Options for specifying read consistency guarantees
+    #[prost(message, optional, tag = "13")]
+    pub read_consistency: ::core::option::Option<ReadConsistency>,
+    /// Specify in which shards to look for the points to join, if not specified - look in all shards
+    #[prost(message, optional, tag = "14")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// If set, overrides global timeout setting for this request. Unit is seconds.
+    #[prost(uint64, optional, tag = "15")]
+    #[validate(range(min = 1))]
+    pub timeout: ::core::option::Option<u64>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "16")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SampleProjectionPoints {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Filter conditions - sample only those points that satisfy the specified conditions.
+    #[prost(message, optional, tag = "2")]
+    #[validate(nested)]
+    pub filter: ::core::option::Option<Filter>,
+    /// How many random points to sample and project. Default is 1000.
+    #[prost(uint64, optional, tag = "3")]
+    #[validate(range(min = 2, max = 10000))]
+    pub sample: ::core::option::Option<u64>,
+    /// Define which dense vector to project. If missing, the default vector is used.
+    #[prost(string, optional, tag = "4")]
+    pub using: ::core::option::Option<::prost::alloc::string::String>,
+    /// Options for specifying which payload to return with the projected points
+    #[prost(message, optional, tag = "5")]
+    pub with_payload: ::core::option::Option<WithPayloadSelector>,
+    /// Options for specifying read consistency guarantees
+    #[prost(message, optional, tag = "6")]
+    pub read_consistency: ::core::option::Option<ReadConsistency>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[prost(message, optional, tag = "7")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// If set, overrides global timeout setting for this request. Unit is seconds.
+    #[prost(uint64, optional, tag = "8")]
+    #[validate(range(min = 1))]
+    pub timeout: ::core::option::Option<u64>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "9")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchMatrixPoints {
     /// Name of the collection
     #[prost(string, tag = "1")]
@@ -6862,6 +8275,9 @@ pub struct SearchMatrixPoints {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[prost(message, optional, tag = "8")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "9")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6908,7 +8324,7 @@ pub struct SearchMatrixOffsets {
 pub struct PointsUpdateOperation {
     #[prost(
         oneof = "points_update_operation::Operation",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11"
     )]
     pub operation: ::core::option::Option<points_update_operation::Operation>,
 }
@@ -6985,6 +8401,23 @@ pub mod points_update_operation {
     #[derive(serde::Serialize)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PatchPayload {
+        /// JSON Merge Patch to apply, null values remove keys
+        #[prost(map = "string, message", tag = "1")]
+        pub payload: ::std::collections::HashMap<
+            ::prost::alloc::string::String,
+            super::Value,
+        >,
+        /// Affected points
+        #[prost(message, optional, tag = "2")]
+        pub points_selector: ::core::option::Option<super::PointsSelector>,
+        /// Option for custom sharding to specify used shard keys
+        #[prost(message, optional, tag = "3")]
+        pub shard_key_selector: ::core::option::Option<super::ShardKeySelector>,
+    }
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct UpdateVectors {
         /// List of points and vectors to update
         #[prost(message, repeated, tag = "1")]
@@ -7057,6 +8490,8 @@ pub mod points_update_operation {
         DeletePoints(DeletePoints),
         #[prost(message, tag = "10")]
         ClearPayload(ClearPayload),
+        #[prost(message, tag = "11")]
+        PatchPayload(PatchPayload),
     }
 }
 #[derive(validator::Validate)]
@@ -7084,6 +8519,49 @@ pub struct UpdateBatchPoints {
     #[prost(uint64, optional, tag = "5")]
     pub timeout: ::core::option::Option<u64>,
 }
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CopyPoints {
+    /// Name of the collection to copy the points from
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Name of the collection to copy the points into
+    #[prost(string, tag = "2")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub target_collection: ::prost::alloc::string::String,
+    /// Copy only points which satisfy these conditions. If missing, all points are copied.
+    #[prost(message, optional, tag = "3")]
+    #[validate(nested)]
+    pub filter: ::core::option::Option<Filter>,
+    /// Shard key to write the points into. Required if the target collection uses custom sharding.
+    #[prost(message, optional, tag = "4")]
+    pub target_shard_key: ::core::option::Option<ShardKey>,
+    /// Start copying from this point id. Use `next_page_offset` of an interrupted copy to resume it.
+    #[prost(message, optional, tag = "5")]
+    pub offset: ::core::option::Option<PointId>,
+    /// Specify in which shards to look for the points to copy, if not specified - look in all shards
+    #[prost(message, optional, tag = "6")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Options for specifying read consistency guarantees
+    #[prost(message, optional, tag = "7")]
+    pub read_consistency: ::core::option::Option<ReadConsistency>,
+    /// If set, overrides global timeout setting for this request. Unit is seconds.
+    #[prost(uint64, optional, tag = "8")]
+    #[validate(range(min = 1))]
+    pub timeout: ::core::option::Option<u64>,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "9")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
+}
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -7106,6 +8584,9 @@ pub struct UpdateResult {
     /// Operation status
     #[prost(enumeration = "UpdateStatus", tag = "2")]
     pub status: i32,
+    /// Number of points affected by the operation, only provided if the operation was completed
+    #[prost(uint64, optional, tag = "3")]
+    pub affected_points: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7209,6 +8690,53 @@ pub struct SearchResponse {
     pub time: f64,
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<Usage>,
+    /// How segments of each shard executed the search, if explanation was requested
+    #[prost(message, repeated, tag = "4")]
+    pub explain: ::prost::alloc::vec::Vec<ShardSearchExplanation>,
+}
+/// Shard which was skipped, because it failed to respond
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SkippedShard {
+    #[prost(uint32, tag = "1")]
+    pub shard_id: u32,
+    /// Error the shard failed with
+    #[prost(string, tag = "2")]
+    pub error: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SegmentSearchExplanation {
+    #[prost(string, tag = "1")]
+    pub segment_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub vector: ::prost::alloc::string::String,
+    #[prost(enumeration = "SearchStrategy", tag = "3")]
+    pub strategy: i32,
+    #[prost(uint64, optional, tag = "4")]
+    pub estimated_cardinality: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "5")]
+    pub min_cardinality: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "6")]
+    pub max_cardinality: ::core::option::Option<u64>,
+    #[prost(bool, optional, tag = "7")]
+    pub cardinality_sampled: ::core::option::Option<bool>,
+    #[prost(uint64, optional, tag = "8")]
+    pub ef: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "9")]
+    pub max_ef: ::core::option::Option<u64>,
+}
+/// How segments of a shard executed the searches of a request
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShardSearchExplanation {
+    #[prost(uint32, tag = "1")]
+    pub shard_id: u32,
+    #[prost(message, repeated, tag = "2")]
+    pub segments: ::prost::alloc::vec::Vec<SegmentSearchExplanation>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7221,6 +8749,18 @@ pub struct QueryResponse {
     pub time: f64,
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<Usage>,
+    /// Set if search quality was reduced, because the server is overloaded
+    #[prost(bool, optional, tag = "4")]
+    pub degraded: ::core::option::Option<bool>,
+    /// Set if searches were stopped at the timeout, and partial results were returned
+    #[prost(bool, optional, tag = "5")]
+    pub timed_out: ::core::option::Option<bool>,
+    /// Shards skipped because they failed, if partial results were allowed
+    #[prost(message, repeated, tag = "6")]
+    pub skipped_shards: ::prost::alloc::vec::Vec<SkippedShard>,
+    /// How segments of each shard executed the searches, if explanation was requested
+    #[prost(message, repeated, tag = "7")]
+    pub explain: ::prost::alloc::vec::Vec<ShardSearchExplanation>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7233,6 +8773,15 @@ pub struct QueryBatchResponse {
     pub time: f64,
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<Usage>,
+    /// Set if search quality was reduced, because the server is overloaded
+    #[prost(bool, optional, tag = "4")]
+    pub degraded: ::core::option::Option<bool>,
+    /// Set if searches were stopped at the timeout, and partial results were returned
+    #[prost(bool, optional, tag = "5")]
+    pub timed_out: ::core::option::Option<bool>,
+    /// Shards skipped because they failed, if partial results were allowed
+    #[prost(message, repeated, tag = "6")]
+    pub skipped_shards: ::prost::alloc::vec::Vec<SkippedShard>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7327,6 +8876,13 @@ pub struct RetrievedPoint {
     /// Order-by value
     #[prost(message, optional, tag = "6")]
     pub order_value: ::core::option::Option<OrderValue>,
+    /// Version of the last operation which modified the point.
+    /// Versions are assigned by each replica independently, compare them only between points with the same `version_peer_id`
+    #[prost(uint64, optional, tag = "7")]
+    pub version: ::core::option::Option<u64>,
+    /// Peer, which replica of the shard reported the `version`
+    #[prost(uint64, optional, tag = "8")]
+    pub version_peer_id: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7427,6 +8983,120 @@ pub struct FacetResponse {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FacetMultiResponse {
+    /// Hits for each of the requested keys, in the same order as the keys
+    #[prost(message, repeated, tag = "1")]
+    pub facets: ::prost::alloc::vec::Vec<KeyFacetHits>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+    #[prost(message, optional, tag = "3")]
+    pub usage: ::core::option::Option<Usage>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateResponse {
+    /// Number of numeric values of the key, each element of an array is counted separately
+    #[prost(uint64, tag = "1")]
+    pub count: u64,
+    /// Sum of the numeric values
+    #[prost(double, tag = "2")]
+    pub sum: f64,
+    /// Smallest numeric value, absent if there are no numeric values
+    #[prost(double, optional, tag = "3")]
+    pub min: ::core::option::Option<f64>,
+    /// Largest numeric value, absent if there are no numeric values
+    #[prost(double, optional, tag = "4")]
+    pub max: ::core::option::Option<f64>,
+    /// Average of the numeric values, absent if there are no numeric values
+    #[prost(double, optional, tag = "5")]
+    pub avg: ::core::option::Option<f64>,
+    /// Number of distinct values of the key, including non-numeric ones.
+    /// Absent if there are more than 10000 distinct values
+    #[prost(uint64, optional, tag = "6")]
+    pub distinct_count: ::core::option::Option<u64>,
+    /// Time spent to process
+    #[prost(double, tag = "7")]
+    pub time: f64,
+    #[prost(message, optional, tag = "8")]
+    pub usage: ::core::option::Option<Usage>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JoinNeighbor {
+    /// Id of the point in the target collection
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<PointId>,
+    #[prost(float, tag = "2")]
+    pub score: f32,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JoinedPoint {
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<PointId>,
+    /// Nearest neighbors of the point in the target collection
+    #[prost(message, repeated, tag = "2")]
+    pub neighbors: ::prost::alloc::vec::Vec<JoinNeighbor>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JoinResponse {
+    /// Number of points joined by this request
+    #[prost(uint64, tag = "1")]
+    pub joined: u64,
+    /// Neighbors of the joined points. Empty if the neighbors were written into the payload.
+    #[prost(message, repeated, tag = "2")]
+    pub points: ::prost::alloc::vec::Vec<JoinedPoint>,
+    /// Offset to continue the join from, if `points_limit` was reached or the join was interrupted by an error
+    #[prost(message, optional, tag = "3")]
+    pub next_page_offset: ::core::option::Option<PointId>,
+    /// Error, which interrupted the join after some points were joined
+    #[prost(string, optional, tag = "4")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    /// Time spent to process
+    #[prost(double, tag = "5")]
+    pub time: f64,
+    #[prost(message, optional, tag = "6")]
+    pub usage: ::core::option::Option<Usage>,
+}
+/// Sampled point with its 2D coordinates
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProjectedPoint {
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<PointId>,
+    #[prost(float, tag = "2")]
+    pub x: f32,
+    #[prost(float, tag = "3")]
+    pub y: f32,
+    #[prost(map = "string, message", tag = "4")]
+    pub payload: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SampleProjectionResponse {
+    /// Sampled points projected onto the first two principal components
+    #[prost(message, repeated, tag = "1")]
+    pub points: ::prost::alloc::vec::Vec<ProjectedPoint>,
+    /// Share of the sample variance explained by each of the two components
+    #[prost(float, repeated, tag = "2")]
+    pub explained_variance: ::prost::alloc::vec::Vec<f32>,
+    /// Time spent to process
+    #[prost(double, tag = "3")]
+    pub time: f64,
+    #[prost(message, optional, tag = "4")]
+    pub usage: ::core::option::Option<Usage>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchMatrixPairsResponse {
     #[prost(message, optional, tag = "1")]
     pub result: ::core::option::Option<SearchMatrixPairs>,
@@ -7448,6 +9118,41 @@ pub struct SearchMatrixOffsetsResponse {
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<Usage>,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CopyPointsResponse {
+    /// Number of points copied into the target collection
+    #[prost(uint64, tag = "1")]
+    pub copied: u64,
+    /// Offset to resume the copy from, if it was interrupted by an error
+    #[prost(message, optional, tag = "2")]
+    pub next_page_offset: ::core::option::Option<PointId>,
+    /// Error, which interrupted the copy after some points were copied
+    #[prost(string, optional, tag = "3")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    /// Time spent to process
+    #[prost(double, tag = "4")]
+    pub time: f64,
+    #[prost(message, optional, tag = "5")]
+    pub usage: ::core::option::Option<Usage>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePreviewResponse {
+    /// Number of points which would be affected by the operation
+    #[prost(uint64, tag = "1")]
+    pub matched: u64,
+    /// Sample of IDs of the affected points
+    #[prost(message, repeated, tag = "2")]
+    pub sample: ::prost::alloc::vec::Vec<PointId>,
+    /// Time spent to process
+    #[prost(double, tag = "3")]
+    pub time: f64,
+    #[prost(message, optional, tag = "4")]
+    pub usage: ::core::option::Option<Usage>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7458,6 +9163,9 @@ pub struct PointsSelector {
     pub points_selector_one_of: ::core::option::Option<
         points_selector::PointsSelectorOneOf,
     >,
+    /// Filter template of the collection to apply on top of the filter
+    #[prost(message, optional, tag = "3")]
+    pub filter_template: ::core::option::Option<FilterTemplateReference>,
 }
 /// Nested message and enum types in `PointsSelector`.
 pub mod points_selector {
@@ -7478,6 +9186,18 @@ pub struct PointsIdsList {
     #[prost(message, repeated, tag = "1")]
     pub ids: ::prost::alloc::vec::Vec<PointId>,
 }
+/// Reference to a filter template of the collection, with values of its parameters
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FilterTemplateReference {
+    /// Name of the filter template
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Values of the template parameters
+    #[prost(map = "string, message", tag = "2")]
+    pub params: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7490,6 +9210,10 @@ pub struct PointStruct {
     #[prost(message, optional, tag = "4")]
     #[validate(nested)]
     pub vectors: ::core::option::Option<Vectors>,
+    /// Shard key of the point, overrides shard key of the request.
+    /// Only for collections with custom sharding.
+    #[prost(message, optional, tag = "5")]
+    pub shard_key: ::core::option::Option<ShardKey>,
 }
 /// ---
 ///
@@ -7615,6 +9339,8 @@ pub enum ReadConsistencyType {
     Majority = 1,
     /// Send requests to half + 1 nodes, return points which are present on all of them
     Quorum = 2,
+    /// Read from a replica, which has applied all updates acknowledged by other replicas
+    Linearizable = 3,
 }
 impl ReadConsistencyType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -7626,6 +9352,7 @@ impl ReadConsistencyType {
             ReadConsistencyType::All => "All",
             ReadConsistencyType::Majority => "Majority",
             ReadConsistencyType::Quorum => "Quorum",
+            ReadConsistencyType::Linearizable => "Linearizable",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -7634,6 +9361,7 @@ impl ReadConsistencyType {
             "All" => Some(Self::All),
             "Majority" => Some(Self::Majority),
             "Quorum" => Some(Self::Quorum),
+            "Linearizable" => Some(Self::Linearizable),
             _ => None,
         }
     }
@@ -7650,6 +9378,7 @@ pub enum FieldType {
     Bool = 5,
     Datetime = 6,
     Uuid = 7,
+    Ip = 8,
 }
 impl FieldType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -7666,6 +9395,7 @@ impl FieldType {
             FieldType::Bool => "FieldTypeBool",
             FieldType::Datetime => "FieldTypeDatetime",
             FieldType::Uuid => "FieldTypeUuid",
+            FieldType::Ip => "FieldTypeIp",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -7679,6 +9409,7 @@ impl FieldType {
             "FieldTypeBool" => Some(Self::Bool),
             "FieldTypeDatetime" => Some(Self::Datetime),
             "FieldTypeUuid" => Some(Self::Uuid),
+            "FieldTypeIp" => Some(Self::Ip),
             _ => None,
         }
     }
@@ -7846,6 +9577,42 @@ impl UpdateStatus {
         }
     }
 }
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SearchStrategy {
+    Hnsw = 0,
+    HnswFiltered = 1,
+    PayloadIndex = 2,
+    PlainScan = 3,
+    SparseIndex = 4,
+}
+impl SearchStrategy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SearchStrategy::Hnsw => "Hnsw",
+            SearchStrategy::HnswFiltered => "HnswFiltered",
+            SearchStrategy::PayloadIndex => "PayloadIndex",
+            SearchStrategy::PlainScan => "PlainScan",
+            SearchStrategy::SparseIndex => "SparseIndex",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Hnsw" => Some(Self::Hnsw),
+            "HnswFiltered" => Some(Self::HnswFiltered),
+            "PayloadIndex" => Some(Self::PayloadIndex),
+            "PlainScan" => Some(Self::PlainScan),
+            "SparseIndex" => Some(Self::SparseIndex),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod points_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -8099,6 +9866,32 @@ pub mod points_client {
                 .insert(GrpcMethod::new("qdrant.Points", "OverwritePayload"));
             self.inner.unary(req, path, codec).await
         }
+        /// Apply JSON Merge Patch (RFC 7386) to payload for points
+        pub async fn patch_payload(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetPayloadPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/PatchPayload",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "PatchPayload"));
+            self.inner.unary(req, path, codec).await
+        }
         /// Delete specified key payload for points
         pub async fn delete_payload(
             &mut self,
@@ -8297,6 +10090,33 @@ pub mod points_client {
             req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Scroll"));
             self.inner.unary(req, path, codec).await
         }
+        /// Iterate over all or filtered points, streaming them in chunks of `limit` points.
+        /// Useful for exporting large collections without paginating with `Scroll`.
+        pub async fn scroll_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ScrollPoints>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ScrollResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/ScrollStream",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "ScrollStream"));
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Look for the points which are closer to stored positive examples and at
         /// the same time further to negative examples.
         pub async fn recommend(
@@ -8583,12 +10403,13 @@ pub mod points_client {
             req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Facet"));
             self.inner.unary(req, path, codec).await
         }
-        /// Compute distance matrix for sampled points with a pair based output format
-        pub async fn search_matrix_pairs(
+        /// Perform facet counts for several keys sharing the same filter.
+        /// Counts for all keys are collected in a single pass over the points.
+        pub async fn facet_multi(
             &mut self,
-            request: impl tonic::IntoRequest<super::SearchMatrixPoints>,
+            request: impl tonic::IntoRequest<super::FacetMultiCounts>,
         ) -> std::result::Result<
-            tonic::Response<super::SearchMatrixPairsResponse>,
+            tonic::Response<super::FacetMultiResponse>,
             tonic::Status,
         > {
             self.inner
@@ -8601,20 +10422,18 @@ pub mod points_client {
                     )
                 })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/SearchMatrixPairs",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/FacetMulti");
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("qdrant.Points", "SearchMatrixPairs"));
+            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "FacetMulti"));
             self.inner.unary(req, path, codec).await
         }
-        /// Compute distance matrix for sampled points with an offset based output format
-        pub async fn search_matrix_offsets(
+        /// Compute count, sum, min, max, average and number of distinct values
+        /// of a payload key over the points matching the conditions.
+        pub async fn aggregate(
             &mut self,
-            request: impl tonic::IntoRequest<super::SearchMatrixPoints>,
+            request: impl tonic::IntoRequest<super::AggregatePoints>,
         ) -> std::result::Result<
-            tonic::Response<super::SearchMatrixOffsetsResponse>,
+            tonic::Response<super::AggregateResponse>,
             tonic::Status,
         > {
             self.inner
@@ -8627,18 +10446,222 @@ pub mod points_client {
                     )
                 })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/SearchMatrixOffsets",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Aggregate");
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("qdrant.Points", "SearchMatrixOffsets"));
+            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Aggregate"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod points_server {
+        /// Compute distance matrix for sampled points with a pair based output format
+        pub async fn search_matrix_pairs(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchMatrixPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::SearchMatrixPairsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/SearchMatrixPairs",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "SearchMatrixPairs"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Compute distance matrix for sampled points with an offset based output format
+        pub async fn search_matrix_offsets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchMatrixPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::SearchMatrixOffsetsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/SearchMatrixOffsets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "SearchMatrixOffsets"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Copy points with their vectors and payload into another collection.
+        /// Interrupted copies can be resumed from the returned offset.
+        pub async fn copy_points(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CopyPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::CopyPointsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/CopyPoints");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "CopyPoints"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Restore deleted points from the trash of a collection with trash retention
+        pub async fn restore_points(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RestorePoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/RestorePoints",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "RestorePoints"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Find nearest neighbors in another collection for each point of the
+        /// collection, paginated by point id. Neighbors can be returned or written
+        /// into the payload of the points.
+        pub async fn join(
+            &mut self,
+            request: impl tonic::IntoRequest<super::JoinPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::JoinResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Join");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Join"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Preview which points would be deleted, without deleting them
+        pub async fn preview_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeletePoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdatePreviewResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/PreviewDelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "PreviewDelete"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Preview which points would be affected by setting payload, without
+        /// modifying them
+        pub async fn preview_set_payload(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetPayloadPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdatePreviewResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/PreviewSetPayload",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "PreviewSetPayload"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Sample random points and project their vectors onto the plane of the first
+        /// two principal components
+        pub async fn sample_projection(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SampleProjectionPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::SampleProjectionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/SampleProjection",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "SampleProjection"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod points_server {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
     /// Generated trait containing gRPC methods that should be implemented for use with PointsServer.
@@ -8698,6 +10721,14 @@ pub mod points_server {
             tonic::Response<super::PointsOperationResponse>,
             tonic::Status,
         >;
+        /// Apply JSON Merge Patch (RFC 7386) to payload for points
+        async fn patch_payload(
+            &self,
+            request: tonic::Request<super::SetPayloadPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponse>,
+            tonic::Status,
+        >;
         /// Delete specified key payload for points
         async fn delete_payload(
             &self,
@@ -8759,6 +10790,21 @@ pub mod points_server {
             &self,
             request: tonic::Request<super::ScrollPoints>,
         ) -> std::result::Result<tonic::Response<super::ScrollResponse>, tonic::Status>;
+        /// Server streaming response type for the ScrollStream method.
+        type ScrollStreamStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ScrollResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Iterate over all or filtered points, streaming them in chunks of `limit` points.
+        /// Useful for exporting large collections without paginating with `Scroll`.
+        async fn scroll_stream(
+            &self,
+            request: tonic::Request<super::ScrollPoints>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ScrollStreamStream>,
+            tonic::Status,
+        >;
         /// Look for the points which are closer to stored positive examples and at
         /// the same time further to negative examples.
         async fn recommend(
@@ -8867,6 +10913,24 @@ pub mod points_server {
             &self,
             request: tonic::Request<super::FacetCounts>,
         ) -> std::result::Result<tonic::Response<super::FacetResponse>, tonic::Status>;
+        /// Perform facet counts for several keys sharing the same filter.
+        /// Counts for all keys are collected in a single pass over the points.
+        async fn facet_multi(
+            &self,
+            request: tonic::Request<super::FacetMultiCounts>,
+        ) -> std::result::Result<
+            tonic::Response<super::FacetMultiResponse>,
+            tonic::Status,
+        >;
+        /// Compute count, sum, min, max, average and number of distinct values
+        /// of a payload key over the points matching the conditions.
+        async fn aggregate(
+            &self,
+            request: tonic::Request<super::AggregatePoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::AggregateResponse>,
+            tonic::Status,
+        >;
         /// Compute distance matrix for sampled points with a pair based output format
         async fn search_matrix_pairs(
             &self,
@@ -8883,6 +10947,59 @@ pub mod points_server {
             tonic::Response<super::SearchMatrixOffsetsResponse>,
             tonic::Status,
         >;
+        /// Copy points with their vectors and payload into another collection.
+        /// Interrupted copies can be resumed from the returned offset.
+        async fn copy_points(
+            &self,
+            request: tonic::Request<super::CopyPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::CopyPointsResponse>,
+            tonic::Status,
+        >;
+        /// Restore deleted points from the trash of a collection with trash retention
+        async fn restore_points(
+            &self,
+            request: tonic::Request<super::RestorePoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponse>,
+            tonic::Status,
+        >;
+        /// Find nearest neighbors in another collection for each point of the
+        /// collection, paginated by point id. Neighbors can be returned or written
+        /// into the payload of the points.
+        async fn join(
+            &self,
+            request: tonic::Request<super::JoinPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::JoinResponse>,
+            tonic::Status,
+        >;
+        /// Preview which points would be deleted, without deleting them
+        async fn preview_delete(
+            &self,
+            request: tonic::Request<super::DeletePoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdatePreviewResponse>,
+            tonic::Status,
+        >;
+        /// Preview which points would be affected by setting payload, without
+        /// modifying them
+        async fn preview_set_payload(
+            &self,
+            request: tonic::Request<super::SetPayloadPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdatePreviewResponse>,
+            tonic::Status,
+        >;
+        /// Sample random points and project their vectors onto the plane of the first
+        /// two principal components
+        async fn sample_projection(
+            &self,
+            request: tonic::Request<super::SampleProjectionPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::SampleProjectionResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct PointsServer<T: Points> {
@@ -9275,13 +11392,11 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/DeletePayload" => {
+                "/qdrant.Points/PatchPayload" => {
                     #[allow(non_camel_case_types)]
-                    struct DeletePayloadSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::DeletePayloadPoints>
-                    for DeletePayloadSvc<T> {
+                    struct PatchPayloadSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::SetPayloadPoints>
+                    for PatchPayloadSvc<T> {
                         type Response = super::PointsOperationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -9289,11 +11404,11 @@ pub mod points_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeletePayloadPoints>,
+                            request: tonic::Request<super::SetPayloadPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::delete_payload(&inner, request).await
+                                <T as Points>::patch_payload(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -9305,7 +11420,7 @@ pub mod points_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = DeletePayloadSvc(inner);
+                        let method = PatchPayloadSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -9321,13 +11436,13 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/ClearPayload" => {
+                "/qdrant.Points/DeletePayload" => {
                     #[allow(non_camel_case_types)]
-                    struct ClearPayloadSvc<T: Points>(pub Arc<T>);
+                    struct DeletePayloadSvc<T: Points>(pub Arc<T>);
                     impl<
                         T: Points,
-                    > tonic::server::UnaryService<super::ClearPayloadPoints>
-                    for ClearPayloadSvc<T> {
+                    > tonic::server::UnaryService<super::DeletePayloadPoints>
+                    for DeletePayloadSvc<T> {
                         type Response = super::PointsOperationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -9335,11 +11450,11 @@ pub mod points_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ClearPayloadPoints>,
+                            request: tonic::Request<super::DeletePayloadPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::clear_payload(&inner, request).await
+                                <T as Points>::delete_payload(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -9351,7 +11466,7 @@ pub mod points_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ClearPayloadSvc(inner);
+                        let method = DeletePayloadSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -9367,13 +11482,13 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/CreateFieldIndex" => {
+                "/qdrant.Points/ClearPayload" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateFieldIndexSvc<T: Points>(pub Arc<T>);
+                    struct ClearPayloadSvc<T: Points>(pub Arc<T>);
                     impl<
                         T: Points,
-                    > tonic::server::UnaryService<super::CreateFieldIndexCollection>
-                    for CreateFieldIndexSvc<T> {
+                    > tonic::server::UnaryService<super::ClearPayloadPoints>
+                    for ClearPayloadSvc<T> {
                         type Response = super::PointsOperationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -9381,11 +11496,57 @@ pub mod points_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CreateFieldIndexCollection>,
+                            request: tonic::Request<super::ClearPayloadPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::create_field_index(&inner, request).await
+                                <T as Points>::clear_payload(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ClearPayloadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/CreateFieldIndex" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateFieldIndexSvc<T: Points>(pub Arc<T>);
+                    impl<
+                        T: Points,
+                    > tonic::server::UnaryService<super::CreateFieldIndexCollection>
+                    for CreateFieldIndexSvc<T> {
+                        type Response = super::PointsOperationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateFieldIndexCollection>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::create_field_index(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -9635,6 +11796,53 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.Points/ScrollStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct ScrollStreamSvc<T: Points>(pub Arc<T>);
+                    impl<
+                        T: Points,
+                    > tonic::server::ServerStreamingService<super::ScrollPoints>
+                    for ScrollStreamSvc<T> {
+                        type Response = super::ScrollResponse;
+                        type ResponseStream = T::ScrollStreamStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ScrollPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::scroll_stream(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ScrollStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.Points/Recommend" => {
                     #[allow(non_camel_case_types)]
                     struct RecommendSvc<T: Points>(pub Arc<T>);
@@ -9905,23 +12113,379 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/UpdateBatch" => {
+                "/qdrant.Points/UpdateBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateBatchSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::UpdateBatchPoints>
+                    for UpdateBatchSvc<T> {
+                        type Response = super::UpdateBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateBatchPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::update_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/Query" => {
+                    #[allow(non_camel_case_types)]
+                    struct QuerySvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::QueryPoints>
+                    for QuerySvc<T> {
+                        type Response = super::QueryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::query(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = QuerySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/QueryBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryBatchSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::QueryBatchPoints>
+                    for QueryBatchSvc<T> {
+                        type Response = super::QueryBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryBatchPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::query_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = QueryBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/QueryGroups" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryGroupsSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::QueryPointGroups>
+                    for QueryGroupsSvc<T> {
+                        type Response = super::QueryGroupsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryPointGroups>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::query_groups(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = QueryGroupsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/Facet" => {
+                    #[allow(non_camel_case_types)]
+                    struct FacetSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::FacetCounts>
+                    for FacetSvc<T> {
+                        type Response = super::FacetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FacetCounts>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::facet(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FacetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/FacetMulti" => {
+                    #[allow(non_camel_case_types)]
+                    struct FacetMultiSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::FacetMultiCounts>
+                    for FacetMultiSvc<T> {
+                        type Response = super::FacetMultiResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FacetMultiCounts>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::facet_multi(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FacetMultiSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/Aggregate" => {
+                    #[allow(non_camel_case_types)]
+                    struct AggregateSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::AggregatePoints>
+                    for AggregateSvc<T> {
+                        type Response = super::AggregateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AggregatePoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::aggregate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AggregateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/SearchMatrixPairs" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchMatrixPairsSvc<T: Points>(pub Arc<T>);
+                    impl<
+                        T: Points,
+                    > tonic::server::UnaryService<super::SearchMatrixPoints>
+                    for SearchMatrixPairsSvc<T> {
+                        type Response = super::SearchMatrixPairsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchMatrixPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::search_matrix_pairs(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SearchMatrixPairsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/SearchMatrixOffsets" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateBatchSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::UpdateBatchPoints>
-                    for UpdateBatchSvc<T> {
-                        type Response = super::UpdateBatchResponse;
+                    struct SearchMatrixOffsetsSvc<T: Points>(pub Arc<T>);
+                    impl<
+                        T: Points,
+                    > tonic::server::UnaryService<super::SearchMatrixPoints>
+                    for SearchMatrixOffsetsSvc<T> {
+                        type Response = super::SearchMatrixOffsetsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateBatchPoints>,
+                            request: tonic::Request<super::SearchMatrixPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::update_batch(&inner, request).await
+                                <T as Points>::search_matrix_offsets(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -9933,7 +12497,7 @@ pub mod points_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UpdateBatchSvc(inner);
+                        let method = SearchMatrixOffsetsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -9949,23 +12513,23 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/Query" => {
+                "/qdrant.Points/CopyPoints" => {
                     #[allow(non_camel_case_types)]
-                    struct QuerySvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::QueryPoints>
-                    for QuerySvc<T> {
-                        type Response = super::QueryResponse;
+                    struct CopyPointsSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::CopyPoints>
+                    for CopyPointsSvc<T> {
+                        type Response = super::CopyPointsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::QueryPoints>,
+                            request: tonic::Request<super::CopyPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::query(&inner, request).await
+                                <T as Points>::copy_points(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -9977,7 +12541,7 @@ pub mod points_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = QuerySvc(inner);
+                        let method = CopyPointsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -9993,23 +12557,23 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/QueryBatch" => {
+                "/qdrant.Points/RestorePoints" => {
                     #[allow(non_camel_case_types)]
-                    struct QueryBatchSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::QueryBatchPoints>
-                    for QueryBatchSvc<T> {
-                        type Response = super::QueryBatchResponse;
+                    struct RestorePointsSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::RestorePoints>
+                    for RestorePointsSvc<T> {
+                        type Response = super::PointsOperationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::QueryBatchPoints>,
+                            request: tonic::Request<super::RestorePoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::query_batch(&inner, request).await
+                                <T as Points>::restore_points(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -10021,7 +12585,7 @@ pub mod points_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = QueryBatchSvc(inner);
+                        let method = RestorePointsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -10037,23 +12601,23 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/QueryGroups" => {
+                "/qdrant.Points/Join" => {
                     #[allow(non_camel_case_types)]
-                    struct QueryGroupsSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::QueryPointGroups>
-                    for QueryGroupsSvc<T> {
-                        type Response = super::QueryGroupsResponse;
+                    struct JoinSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::JoinPoints>
+                    for JoinSvc<T> {
+                        type Response = super::JoinResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::QueryPointGroups>,
+                            request: tonic::Request<super::JoinPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::query_groups(&inner, request).await
+                                <T as Points>::join(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -10065,7 +12629,7 @@ pub mod points_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = QueryGroupsSvc(inner);
+                        let method = JoinSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -10081,23 +12645,23 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/Facet" => {
+                "/qdrant.Points/PreviewDelete" => {
                     #[allow(non_camel_case_types)]
-                    struct FacetSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::FacetCounts>
-                    for FacetSvc<T> {
-                        type Response = super::FacetResponse;
+                    struct PreviewDeleteSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::DeletePoints>
+                    for PreviewDeleteSvc<T> {
+                        type Response = super::UpdatePreviewResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::FacetCounts>,
+                            request: tonic::Request<super::DeletePoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::facet(&inner, request).await
+                                <T as Points>::preview_delete(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -10109,7 +12673,7 @@ pub mod points_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = FacetSvc(inner);
+                        let method = PreviewDeleteSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -10125,25 +12689,23 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/SearchMatrixPairs" => {
+                "/qdrant.Points/PreviewSetPayload" => {
                     #[allow(non_camel_case_types)]
-                    struct SearchMatrixPairsSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::SearchMatrixPoints>
-                    for SearchMatrixPairsSvc<T> {
-                        type Response = super::SearchMatrixPairsResponse;
+                    struct PreviewSetPayloadSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::SetPayloadPoints>
+                    for PreviewSetPayloadSvc<T> {
+                        type Response = super::UpdatePreviewResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SearchMatrixPoints>,
+                            request: tonic::Request<super::SetPayloadPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::search_matrix_pairs(&inner, request).await
+                                <T as Points>::preview_set_payload(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -10155,7 +12717,7 @@ pub mod points_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SearchMatrixPairsSvc(inner);
+                        let method = PreviewSetPayloadSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -10171,25 +12733,25 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/SearchMatrixOffsets" => {
+                "/qdrant.Points/SampleProjection" => {
                     #[allow(non_camel_case_types)]
-                    struct SearchMatrixOffsetsSvc<T: Points>(pub Arc<T>);
+                    struct SampleProjectionSvc<T: Points>(pub Arc<T>);
                     impl<
                         T: Points,
-                    > tonic::server::UnaryService<super::SearchMatrixPoints>
-                    for SearchMatrixOffsetsSvc<T> {
-                        type Response = super::SearchMatrixOffsetsResponse;
+                    > tonic::server::UnaryService<super::SampleProjectionPoints>
+                    for SampleProjectionSvc<T> {
+                        type Response = super::SampleProjectionResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SearchMatrixPoints>,
+                            request: tonic::Request<super::SampleProjectionPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::search_matrix_offsets(&inner, request).await
+                                <T as Points>::sample_projection(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -10201,7 +12763,7 @@ pub mod points_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SearchMatrixOffsetsSvc(inner);
+                        let method = SampleProjectionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -10428,7 +12990,7 @@ pub struct DeleteFieldIndexCollectionInternal {
 pub struct UpdateOperation {
     #[prost(
         oneof = "update_operation::Update",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12"
     )]
     #[validate(nested)]
     pub update: ::core::option::Option<update_operation::Update>,
@@ -10461,6 +13023,8 @@ pub mod update_operation {
         CreateFieldIndex(super::CreateFieldIndexCollectionInternal),
         #[prost(message, tag = "11")]
         DeleteFieldIndex(super::DeleteFieldIndexCollectionInternal),
+        #[prost(message, tag = "12")]
+        PatchPayload(super::SetPayloadPointsInternal),
     }
 }
 #[derive(serde::Serialize)]
@@ -10500,6 +13064,9 @@ pub struct UpdateResultInternal {
     pub status: i32,
     #[prost(message, optional, tag = "3")]
     pub clock_tag: ::core::option::Option<ClockTag>,
+    /// Number of points affected by the operation, only provided if the operation was completed
+    #[prost(uint64, optional, tag = "4")]
+    pub affected_points: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -10879,7 +13446,7 @@ pub mod query_shard_points {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Query {
-        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7")]
+        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
         pub score: ::core::option::Option<query::Score>,
     }
     /// Nested message and enum types in `Query`.
@@ -10909,6 +13476,9 @@ pub mod query_shard_points {
             /// Parameterized RRF fusion
             #[prost(message, tag = "7")]
             Rrf(super::super::Rrf),
+            /// Sample points weighted by a payload field
+            #[prost(message, tag = "8")]
+            WeightedSample(super::super::WeightedSample),
         }
     }
     #[derive(serde::Serialize)]
@@ -10949,6 +13519,9 @@ pub struct QueryBatchPointsInternal {
     #[prost(uint64, optional, tag = "4")]
     #[validate(range(min = 1))]
     pub timeout: ::core::option::Option<u64>,
+    /// If true, the response explains how segments executed the searches
+    #[prost(bool, optional, tag = "5")]
+    pub explain: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -10977,6 +13550,12 @@ pub struct QueryBatchResponseInternal {
     pub hardware_usage: ::core::option::Option<HardwareUsage>,
     #[prost(message, optional, tag = "6")]
     pub inference_usage: ::core::option::Option<InferenceUsage>,
+    /// Set if searches were stopped at the deadline, and results may be incomplete
+    #[prost(bool, optional, tag = "7")]
+    pub timed_out: ::core::option::Option<bool>,
+    /// How segments executed the searches, if explanation was requested
+    #[prost(message, repeated, tag = "8")]
+    pub explanations: ::prost::alloc::vec::Vec<SegmentSearchExplanation>,
 }
 #[derive(serde::Serialize)]
 #[derive(validator::Validate)]
@@ -11002,12 +13581,15 @@ pub struct FacetCountsInternal {
     #[prost(uint64, optional, tag = "7")]
     #[validate(range(min = 1))]
     pub timeout: ::core::option::Option<u64>,
+    #[prost(message, optional, tag = "8")]
+    #[validate(nested)]
+    pub histogram: ::core::option::Option<FacetHistogram>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FacetValueInternal {
-    #[prost(oneof = "facet_value_internal::Variant", tags = "1, 2, 3, 4")]
+    #[prost(oneof = "facet_value_internal::Variant", tags = "1, 2, 3, 4, 5, 6")]
     pub variant: ::core::option::Option<facet_value_internal::Variant>,
 }
 /// Nested message and enum types in `FacetValueInternal`.
@@ -11024,6 +13606,10 @@ pub mod facet_value_internal {
         UuidValue(::prost::alloc::vec::Vec<u8>),
         #[prost(bool, tag = "4")]
         BoolValue(bool),
+        #[prost(double, tag = "5")]
+        FloatValue(f64),
+        #[prost(int64, tag = "6")]
+        DatetimeValue(i64),
     }
 }
 #[derive(serde::Serialize)]
@@ -11047,6 +13633,52 @@ pub struct FacetResponseInternal {
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<HardwareUsage>,
 }
+#[derive(serde::Serialize)]
+#[derive(validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregatePointsInternal {
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    #[validate(nested)]
+    pub filter: ::core::option::Option<Filter>,
+    #[prost(uint32, tag = "4")]
+    pub shard_id: u32,
+    #[prost(uint64, optional, tag = "5")]
+    #[validate(range(min = 1))]
+    pub timeout: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateResponseInternal {
+    #[prost(uint64, tag = "1")]
+    pub count: u64,
+    #[prost(double, tag = "2")]
+    pub sum: f64,
+    #[prost(double, optional, tag = "3")]
+    pub min: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "4")]
+    pub max: ::core::option::Option<f64>,
+    /// Distinct values, empty if there are too many of them
+    #[prost(message, repeated, tag = "5")]
+    pub distinct_values: ::prost::alloc::vec::Vec<FacetValueInternal>,
+    /// There are more distinct values than an aggregation keeps
+    #[prost(bool, tag = "6")]
+    pub distinct_values_exceeded: bool,
+    /// Time spent to process
+    #[prost(double, tag = "7")]
+    pub time: f64,
+    #[prost(message, optional, tag = "8")]
+    pub usage: ::core::option::Option<HardwareUsage>,
+}
 /// Generated client implementations.
 pub mod points_internal_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -11307,6 +13939,31 @@ pub mod points_internal_client {
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "OverwritePayload"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn patch_payload(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetPayloadPointsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponseInternal>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.PointsInternal/PatchPayload",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "PatchPayload"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn delete_payload(
             &mut self,
             request: impl tonic::IntoRequest<super::DeletePayloadPointsInternal>,
@@ -11597,6 +14254,31 @@ pub mod points_internal_client {
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "Facet"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn aggregate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AggregatePointsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::AggregateResponseInternal>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.PointsInternal/Aggregate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "Aggregate"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -11655,6 +14337,13 @@ pub mod points_internal_server {
             tonic::Response<super::PointsOperationResponseInternal>,
             tonic::Status,
         >;
+        async fn patch_payload(
+            &self,
+            request: tonic::Request<super::SetPayloadPointsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponseInternal>,
+            tonic::Status,
+        >;
         async fn delete_payload(
             &self,
             request: tonic::Request<super::DeletePayloadPointsInternal>,
@@ -11730,6 +14419,13 @@ pub mod points_internal_server {
             tonic::Response<super::FacetResponseInternal>,
             tonic::Status,
         >;
+        async fn aggregate(
+            &self,
+            request: tonic::Request<super::AggregatePointsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::AggregateResponseInternal>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct PointsInternalServer<T: PointsInternal> {
@@ -12133,6 +14829,52 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.PointsInternal/PatchPayload" => {
+                    #[allow(non_camel_case_types)]
+                    struct PatchPayloadSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<
+                        T: PointsInternal,
+                    > tonic::server::UnaryService<super::SetPayloadPointsInternal>
+                    for PatchPayloadSvc<T> {
+                        type Response = super::PointsOperationResponseInternal;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetPayloadPointsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::patch_payload(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PatchPayloadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.PointsInternal/DeletePayload" => {
                     #[allow(non_camel_case_types)]
                     struct DeletePayloadSvc<T: PointsInternal>(pub Arc<T>);
@@ -12694,6 +15436,52 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.PointsInternal/Aggregate" => {
+                    #[allow(non_camel_case_types)]
+                    struct AggregateSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<
+                        T: PointsInternal,
+                    > tonic::server::UnaryService<super::AggregatePointsInternal>
+                    for AggregateSvc<T> {
+                        type Response = super::AggregateResponseInternal;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AggregatePointsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::aggregate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AggregateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -12971,6 +15759,9 @@ pub struct LocalShardTelemetry {
         ::prost::alloc::string::String,
         u64,
     >,
+    /// Number of read requests served since the last start
+    #[prost(uint64, tag = "13")]
+    pub read_requests: u64,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -12980,6 +15771,26 @@ pub struct RemoteShardTelemetry {
     pub shard_id: u32,
     #[prost(uint64, tag = "2")]
     pub peer_id: u64,
+    /// Update traffic and lag of the replica, as observed by this peer
+    #[prost(message, optional, tag = "3")]
+    pub replication: ::core::option::Option<ReplicationTelemetry>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReplicationTelemetry {
+    /// Number of update requests sent to the replica since the last start
+    #[prost(uint64, tag = "1")]
+    pub sent_requests: u64,
+    /// Total size of update requests sent to the replica since the last start, in bytes
+    #[prost(uint64, tag = "2")]
+    pub sent_bytes: u64,
+    /// Number of updates the replica missed since it was up to date the last time
+    #[prost(uint64, tag = "3")]
+    pub lag_operations: u64,
+    /// Seconds since the replica is behind, because it missed an update or was marked dead, zero if it is up to date
+    #[prost(double, tag = "4")]
+    pub lag_seconds: f64,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -14272,6 +17083,9 @@ pub struct CreateSnapshotRequest {
         custom(function = "common::validation::validate_collection_name_legacy")
     )]
     pub collection_name: ::prost::alloc::string::String,
+    /// Name of the snapshot to create an incremental snapshot on top of
+    #[prost(string, optional, tag = "2")]
+    pub base_snapshot: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[derive(validator::Validate)]
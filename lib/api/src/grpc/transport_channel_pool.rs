@@ -1,10 +1,13 @@
 use std::collections::HashMap;
 use std::future::Future;
 use std::num::NonZeroUsize;
+use std::sync::Arc;
 use std::time::Duration;
 
+use parking_lot::Mutex;
 use rand::{Rng, rng};
 use tokio::select;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tonic::codegen::InterceptedService;
 use tonic::service::Interceptor;
 use tonic::transport::{Channel, ClientTlsConfig, Error as TonicError, Uri};
@@ -101,6 +104,10 @@ pub struct TransportChannelPool {
     grpc_timeout: Duration,
     connection_timeout: Duration,
     tls_config: Option<ClientTlsConfig>,
+    /// Maximum number of concurrent requests to each URI, if set
+    max_concurrent_requests: Option<usize>,
+    /// Limits of concurrent requests to each URI
+    uri_to_request_limit: Mutex<HashMap<Uri, Arc<Semaphore>>>,
 }
 
 impl Default for TransportChannelPool {
@@ -111,6 +118,8 @@ impl Default for TransportChannelPool {
             grpc_timeout: DEFAULT_GRPC_TIMEOUT,
             connection_timeout: DEFAULT_CONNECT_TIMEOUT,
             tls_config: None,
+            max_concurrent_requests: None,
+            uri_to_request_limit: Default::default(),
         }
     }
 }
@@ -128,9 +137,33 @@ impl TransportChannelPool {
             connection_timeout,
             pool_size: NonZeroUsize::new(pool_size).unwrap(),
             tls_config,
+            max_concurrent_requests: None,
+            uri_to_request_limit: Default::default(),
         }
     }
 
+    /// Limit the number of requests, which can be in flight to each URI at the same time.
+    /// Other requests to the URI wait until one of its running requests completes.
+    ///
+    /// Requests made with [`Self::with_unlimited_channel_timeout`] are not limited.
+    pub fn with_max_concurrent_requests(mut self, max_concurrent_requests: Option<usize>) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests;
+        self
+    }
+
+    /// Wait until a request to the URI can be made, if concurrent requests are limited
+    async fn acquire_request_permit(&self, uri: &Uri) -> Option<OwnedSemaphorePermit> {
+        let max_concurrent_requests = self.max_concurrent_requests?;
+        let request_limit = self
+            .uri_to_request_limit
+            .lock()
+            .entry(uri.clone())
+            .or_insert_with(|| Arc::new(Semaphore::new(max_concurrent_requests)))
+            .clone();
+        // Semaphore is never closed, so acquiring a permit can't fail
+        request_limit.acquire_owned().await.ok()
+    }
+
     async fn _init_pool_for_uri(&self, uri: Uri) -> Result<DynamicChannelPool, TonicError> {
         DynamicChannelPool::new(
             uri,
@@ -161,6 +194,7 @@ impl TransportChannelPool {
     pub async fn drop_pool(&self, uri: &Uri) {
         let mut guard = self.uri_to_pool.write().await;
         guard.remove(uri);
+        self.uri_to_request_limit.lock().remove(uri);
     }
 
     pub async fn drop_channel(&self, uri: &Uri, channel: CountedItem<Channel>) {
@@ -239,7 +273,14 @@ impl TransportChannelPool {
         uri: &Uri,
         f: &impl Fn(InterceptedService<Channel, AddTimeout>) -> O,
         timeout: Duration,
+        is_limited: bool,
     ) -> Result<T, RequestFailure> {
+        let _permit = if is_limited {
+            self.acquire_request_permit(uri).await
+        } else {
+            None
+        };
+
         let channel = match self.get_or_create_pooled_channel(uri).await {
             Ok(channel) => channel,
             Err(tonic_error) => {
@@ -286,6 +327,32 @@ impl TransportChannelPool {
         f: impl Fn(InterceptedService<Channel, AddTimeout>) -> O,
         timeout: Option<Duration>,
         retries: usize,
+    ) -> Result<T, RequestError<Status>> {
+        self.with_channel_timeout_impl(uri, f, timeout, retries, true)
+            .await
+    }
+
+    /// Same as [`Self::with_channel_timeout`], but the request doesn't wait for the limit of
+    /// concurrent requests. Used for consensus messages, which must not queue behind regular
+    /// internal traffic.
+    pub async fn with_unlimited_channel_timeout<T, O: Future<Output = Result<T, Status>>>(
+        &self,
+        uri: &Uri,
+        f: impl Fn(InterceptedService<Channel, AddTimeout>) -> O,
+        timeout: Option<Duration>,
+        retries: usize,
+    ) -> Result<T, RequestError<Status>> {
+        self.with_channel_timeout_impl(uri, f, timeout, retries, false)
+            .await
+    }
+
+    async fn with_channel_timeout_impl<T, O: Future<Output = Result<T, Status>>>(
+        &self,
+        uri: &Uri,
+        f: impl Fn(InterceptedService<Channel, AddTimeout>) -> O,
+        timeout: Option<Duration>,
+        retries: usize,
+        is_limited: bool,
     ) -> Result<T, RequestError<Status>> {
         let mut retries_left = retries;
         let mut attempt = 0;
@@ -296,7 +363,8 @@ impl TransportChannelPool {
             timeout.unwrap_or_else(|| self.request_timeout() + self.connection_timeout);
 
         loop {
-            let request_result: Result<T, _> = self.make_request(uri, &f, max_timeout).await;
+            let request_result: Result<T, _> =
+                self.make_request(uri, &f, max_timeout, is_limited).await;
 
             let error_result = match request_result {
                 Ok(body) => return Ok(body),
@@ -410,3 +478,67 @@ impl TransportChannelPool {
         self.grpc_timeout
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT_TIMEOUT: Duration = Duration::from_millis(200);
+
+    #[tokio::test]
+    async fn test_request_limit_per_uri() {
+        let pool = TransportChannelPool::default().with_max_concurrent_requests(Some(2));
+        let first: Uri = "http://first:6335".parse().unwrap();
+        let second: Uri = "http://second:6335".parse().unwrap();
+
+        let _permit_1 = pool.acquire_request_permit(&first).await.unwrap();
+        let permit_2 = pool.acquire_request_permit(&first).await.unwrap();
+
+        // All requests to the first URI are in flight, requests to other URIs are not limited by them
+        let blocked = tokio::time::timeout(LIMIT_TIMEOUT, pool.acquire_request_permit(&first));
+        assert!(blocked.await.is_err());
+        let other = tokio::time::timeout(LIMIT_TIMEOUT, pool.acquire_request_permit(&second));
+        assert!(other.await.unwrap().is_some());
+
+        drop(permit_2);
+        let released = tokio::time::timeout(LIMIT_TIMEOUT, pool.acquire_request_permit(&first));
+        assert!(released.await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_no_request_limit() {
+        let pool = TransportChannelPool::default();
+        let uri: Uri = "http://first:6335".parse().unwrap();
+        assert!(pool.acquire_request_permit(&uri).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unlimited_requests_are_not_queued() {
+        let pool = TransportChannelPool::new(
+            Duration::from_millis(100),
+            Duration::from_millis(100),
+            1,
+            None,
+        )
+        .with_max_concurrent_requests(Some(1));
+        // Nothing listens on the port, requests fail to connect
+        let uri: Uri = "http://127.0.0.1:1".parse().unwrap();
+
+        let _permit = pool.acquire_request_permit(&uri).await.unwrap();
+
+        let request = |channel| async move {
+            QdrantClient::new(channel)
+                .health_check(HealthCheckRequest {})
+                .await
+        };
+
+        // Regular request waits for the request in flight
+        let limited = pool.with_channel_timeout(&uri, request, None, 0);
+        assert!(tokio::time::timeout(LIMIT_TIMEOUT, limited).await.is_err());
+
+        // Consensus request is sent right away, and fails to connect
+        let unlimited = pool.with_unlimited_channel_timeout(&uri, request, None, 0);
+        let result = tokio::time::timeout(LIMIT_TIMEOUT * 5, unlimited).await;
+        assert!(result.unwrap().is_err());
+    }
+}
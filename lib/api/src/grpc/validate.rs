@@ -97,6 +97,15 @@ impl Validate for grpc::quantization_config_diff::Quantization {
     }
 }
 
+impl Validate for grpc::facet_histogram::Variant {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        segment::data_types::facets::FacetHistogram::try_from(grpc::FacetHistogram {
+            variant: Some(self.clone()),
+        })
+        .map_or(Ok(()), |histogram| histogram.validate())
+    }
+}
+
 impl Validate for grpc::update_collection_cluster_setup_request::Operation {
     fn validate(&self) -> Result<(), ValidationErrors> {
         use grpc::update_collection_cluster_setup_request::Operation;
@@ -166,6 +175,19 @@ impl Validate for grpc::CreateShardKey {
             return Err(errors);
         }
 
+        if self.max_points_count == Some(0) {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "max_points_count",
+                ValidationError::new("Max points count must be greater than 0"),
+            );
+            return Err(errors);
+        }
+
+        if let Some(hnsw_config) = &self.hnsw_config {
+            hnsw_config.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -213,6 +235,7 @@ impl Validate for grpc::condition::ConditionOneOf {
             ConditionOneOf::HasId(_) => Ok(()),
             ConditionOneOf::IsNull(_) => Ok(()),
             ConditionOneOf::HasVector(_) => Ok(()),
+            ConditionOneOf::Attribute(_) => Ok(()),
         }
     }
 }
@@ -232,6 +255,7 @@ impl Validate for grpc::update_operation::Update {
             Update::ClearPayload(op) => op.validate(),
             Update::CreateFieldIndex(op) => op.validate(),
             Update::DeleteFieldIndex(op) => op.validate(),
+            Update::PatchPayload(op) => op.validate(),
         }
     }
 }
@@ -317,6 +341,7 @@ impl Validate for grpc::vector::Vector {
             grpc::vector::Vector::Document(_document) => Ok(()),
             grpc::vector::Vector::Image(_image) => Ok(()),
             grpc::vector::Vector::Object(_obj) => Ok(()),
+            grpc::vector::Vector::DenseBytes(_dense_bytes) => Ok(()),
         }
     }
 }
@@ -368,7 +393,9 @@ impl Validate for super::qdrant::query::Variant {
             grpc::query::Variant::Formula(q) => q.validate(),
             grpc::query::Variant::Rrf(q) => q.validate(),
             grpc::query::Variant::RelevanceFeedback(q) => q.validate(),
+            grpc::query::Variant::Arithmetic(q) => q.validate(),
             grpc::query::Variant::Sample(_)
+            | grpc::query::Variant::WeightedSample(_)
             | grpc::query::Variant::Fusion(_)
             | grpc::query::Variant::OrderBy(_) => Ok(()),
         }
@@ -495,6 +522,7 @@ impl Validate for super::qdrant::payload_index_params::IndexParams {
             grpc::payload_index_params::IndexParams::BoolIndexParams(_) => Ok(()),
             grpc::payload_index_params::IndexParams::DatetimeIndexParams(_) => Ok(()),
             grpc::payload_index_params::IndexParams::UuidIndexParams(_) => Ok(()),
+            grpc::payload_index_params::IndexParams::IpIndexParams(_) => Ok(()),
         }
     }
 }
@@ -2,13 +2,17 @@ use std::collections::HashMap;
 
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::{VectorInternal, VectorStructInternal};
+use segment::json_path::JsonPath;
 use uuid::Uuid;
 
 use super::schema::{ScoredPoint, Vector};
 use super::{
-    FacetRequestInternal, FacetResponse, FacetValue, FacetValueHit, NearestQuery, OrderByInterface,
-    Query, QueryInterface, VectorOutput, VectorStructOutput,
+    AggregateRequestInternal, AggregateResponse, FacetRequestInternal, FacetResponse, FacetValue,
+    FacetValueHit, JoinedPoint, KeyFacetHits, MultiFacetRequestInternal, MultiFacetResponse,
+    NearestQuery, OrderByInterface, ProjectedPoint, Query, QueryInterface, VectorOutput,
+    VectorStructOutput,
 };
+use crate::conversions::json::payload_to_proto;
 use crate::grpc;
 use crate::rest::models::InferenceUsage;
 use crate::rest::{DenseVector, NamedVectorStruct};
@@ -152,6 +156,64 @@ impl From<QueryInterface> for Query {
     }
 }
 
+impl From<AggregateRequestInternal> for segment::data_types::aggregation::AggregateParams {
+    fn from(value: AggregateRequestInternal) -> Self {
+        let AggregateRequestInternal { key, filter } = value;
+        Self { key, filter }
+    }
+}
+
+impl From<segment::data_types::aggregation::AggregateResult> for AggregateResponse {
+    fn from(value: segment::data_types::aggregation::AggregateResult) -> Self {
+        let avg = value.avg();
+        let distinct_count = value.distinct_count();
+        let segment::data_types::aggregation::AggregateResult {
+            count,
+            sum,
+            min,
+            max,
+            distinct_values: _,
+            distinct_values_exceeded: _,
+        } = value;
+        Self {
+            count,
+            sum,
+            min,
+            max,
+            avg,
+            distinct_count,
+        }
+    }
+}
+
+impl From<JoinedPoint> for grpc::JoinedPoint {
+    fn from(value: JoinedPoint) -> Self {
+        let JoinedPoint { id, neighbors } = value;
+        Self {
+            id: Some(id.into()),
+            neighbors: neighbors
+                .into_iter()
+                .map(|neighbor| grpc::JoinNeighbor {
+                    id: Some(neighbor.id.into()),
+                    score: neighbor.score,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<ProjectedPoint> for grpc::ProjectedPoint {
+    fn from(value: ProjectedPoint) -> Self {
+        let ProjectedPoint { id, x, y, payload } = value;
+        Self {
+            id: Some(id.into()),
+            x,
+            y,
+            payload: payload.map(payload_to_proto).unwrap_or_default(),
+        }
+    }
+}
+
 impl From<segment::data_types::facets::FacetValue> for FacetValue {
     fn from(value: segment::data_types::facets::FacetValue) -> Self {
         match value {
@@ -161,6 +223,10 @@ impl From<segment::data_types::facets::FacetValue> for FacetValue {
                 Self::String(Uuid::from_u128(uuid_int).to_string())
             }
             segment::data_types::facets::FacetValue::Bool(b) => Self::Bool(b),
+            segment::data_types::facets::FacetValue::Float(float) => Self::Float(float.0),
+            segment::data_types::facets::FacetValue::DateTime(timestamp) => Self::String(
+                segment::data_types::facets::datetime_bucket_to_string(timestamp),
+            ),
         }
     }
 }
@@ -184,6 +250,41 @@ impl From<segment::data_types::facets::FacetResponse> for FacetResponse {
     }
 }
 
+impl MultiFacetResponse {
+    /// Pair the responses with the requested keys, responses are in the same order as the keys
+    pub fn from_responses(
+        keys: Vec<JsonPath>,
+        responses: Vec<segment::data_types::facets::FacetResponse>,
+    ) -> Self {
+        let facets = keys
+            .into_iter()
+            .zip(responses)
+            .map(|(key, response)| KeyFacetHits {
+                key,
+                hits: response.hits.into_iter().map(From::from).collect(),
+            })
+            .collect();
+        Self { facets }
+    }
+}
+
+impl From<MultiFacetRequestInternal> for segment::data_types::facets::MultiFacetParams {
+    fn from(value: MultiFacetRequestInternal) -> Self {
+        let MultiFacetRequestInternal {
+            keys,
+            limit,
+            filter,
+            exact,
+        } = value;
+        Self {
+            keys,
+            limit: limit.unwrap_or(segment::data_types::facets::FacetParams::DEFAULT_LIMIT),
+            filter,
+            exact: exact.unwrap_or(segment::data_types::facets::FacetParams::DEFAULT_EXACT),
+        }
+    }
+}
+
 impl From<FacetRequestInternal> for segment::data_types::facets::FacetParams {
     fn from(value: FacetRequestInternal) -> Self {
         let FacetRequestInternal {
@@ -191,12 +292,14 @@ impl From<FacetRequestInternal> for segment::data_types::facets::FacetParams {
             limit,
             filter,
             exact,
+            histogram,
         } = value;
         Self {
             key,
             limit: limit.unwrap_or(Self::DEFAULT_LIMIT),
             filter,
             exact: exact.unwrap_or(Self::DEFAULT_EXACT),
+            histogram,
         }
     }
 }
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::{Hash, Hasher};
 
 use common::types::ScoreType;
@@ -7,12 +7,13 @@ use common::validation::validate_multi_vector;
 use ordered_float::NotNan;
 use schemars::JsonSchema;
 use segment::common::utils::MaybeOneOrMany;
+use segment::data_types::facets::FacetHistogram;
 use segment::data_types::index::{StemmingAlgorithm, StopwordsInterface, TokenizerType};
 use segment::data_types::order_by::OrderBy;
 use segment::json_path::JsonPath;
 use segment::types::{
-    Condition, Filter, GeoPoint, IntPayloadType, Payload, PointIdType, SearchParams, ShardKey,
-    VectorNameBuf, WithPayloadInterface, WithVector,
+    Condition, Filter, FloatPayloadType, GeoPoint, IntPayloadType, Payload, PointIdType,
+    SearchParams, ShardKey, VectorNameBuf, WithPayloadInterface, WithVector,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -398,6 +399,10 @@ pub struct Batch {
     pub ids: Vec<PointIdType>,
     pub vectors: BatchVectorStruct,
     pub payloads: Option<Vec<Option<Payload>>>,
+    /// Shard keys of the points, override shard key of the request.
+    /// Only for collections with custom sharding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_keys: Option<Vec<Option<ShardKey>>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
@@ -483,6 +488,14 @@ pub struct Record {
     pub shard_key: Option<segment::types::ShardKey>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_value: Option<segment::data_types::order_by::OrderValue>,
+    /// Version of the last operation which modified the point.
+    /// Only returned if requested with `with_version`.
+    /// Versions are assigned by each replica independently, compare them only between points with the same `version_peer_id`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<segment::types::SeqNumberType>,
+    /// Peer, which replica of the shard reported the `version`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_peer_id: Option<u64>,
 }
 
 /// Vector data separator for named and unnamed modes
@@ -605,12 +618,26 @@ pub struct QueryRequestInternal {
     pub lookup_from: Option<LookupLocation>,
 }
 
+/// Reference to a filter template of the collection, with values of its parameters
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct FilterTemplateReference {
+    /// Name of the filter template
+    pub name: String,
+    /// Values of the template parameters
+    #[serde(default)]
+    pub params: BTreeMap<String, Value>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 pub struct QueryRequest {
     #[validate(nested)]
     #[serde(flatten)]
     pub internal: QueryRequestInternal,
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -622,6 +649,33 @@ pub struct QueryRequestBatch {
 #[derive(Debug, Serialize, JsonSchema)]
 pub struct QueryResponse {
     pub points: Vec<ScoredPoint>,
+    /// Set if search quality was reduced, because the server is overloaded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub degraded: Option<bool>,
+    /// Set if searches were stopped at the timeout, and partial results were returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timed_out: Option<bool>,
+    /// Shards skipped because they failed, if partial results were allowed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipped_shards: Option<Vec<SkippedShard>>,
+    /// How segments of each shard executed the searches, if explanation was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explain: Option<Vec<ShardSearchExplanation>>,
+}
+
+/// Shard which was skipped, because it failed to respond
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SkippedShard {
+    pub shard_id: u32,
+    /// Error the shard failed with
+    pub error: String,
+}
+
+/// How segments of a shard executed the searches of a query
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ShardSearchExplanation {
+    pub shard_id: u32,
+    pub segments: Vec<segment::index::SegmentSearchExplanation>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -664,6 +718,9 @@ pub enum Query {
 
     /// Use feedback from an oracle to improve the results
     RelevanceFeedback(RelevanceFeedbackQuery),
+
+    /// Find the nearest neighbors to a weighted sum of vectors, e.g. `A - B + C`.
+    Arithmetic(ArithmeticQuery),
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -743,6 +800,14 @@ pub struct RelevanceFeedbackQuery {
     pub relevance_feedback: RelevanceFeedbackInput,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct ArithmeticQuery {
+    /// Terms of the sum. Ids are resolved into vectors of the stored points before the search.
+    #[validate(length(min = 1), nested)]
+    pub arithmetic: Vec<ArithmeticTerm>,
+}
+
 /// Maximal Marginal Relevance (MMR) algorithm for re-ranking the points.
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
@@ -892,6 +957,15 @@ pub struct RelevanceFeedbackInput {
     pub strategy: FeedbackStrategy,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ArithmeticTerm {
+    #[validate(nested)]
+    pub vector: VectorInput,
+    /// Multiplier of the vector, e.g. `-1` to subtract it. Default is 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f32>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 pub struct FeedbackItem {
     #[validate(nested)]
@@ -1078,6 +1152,15 @@ pub struct DecayParamsExpression {
 #[serde(rename_all = "snake_case")]
 pub enum Sample {
     Random,
+    Weighted(WeightedSample),
+}
+
+/// Sample points with probability proportional to the value of a numeric payload field.
+/// Points without a positive value are never sampled.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WeightedSample {
+    /// Payload key with numeric values, requires a range index
+    pub key: JsonPath,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -1094,6 +1177,11 @@ pub struct WithLookup {
     #[serde(alias = "with_vector")]
     #[serde(default)]
     pub with_vectors: Option<WithVector>,
+
+    /// Only look up points which satisfy these conditions. Groups whose lookup point does not
+    /// match are returned without a lookup.
+    #[serde(default)]
+    pub filter: Option<Filter>,
 }
 
 #[allow(clippy::unnecessary_wraps)] // Used as serde default
@@ -1281,6 +1369,9 @@ pub struct QueryGroupsRequest {
     pub search_group_request: QueryGroupsRequestInternal,
 
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Validate, Debug, PartialEq)]
@@ -1308,6 +1399,9 @@ pub struct SearchMatrixRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 #[derive(Debug, Serialize, JsonSchema, PartialEq)]
@@ -1349,6 +1443,169 @@ pub struct SearchMatrixPairsResponse {
     pub pairs: Vec<SearchMatrixPair>,
 }
 
+#[derive(Serialize, Deserialize, JsonSchema, Validate, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct SampleProjectionRequestInternal {
+    /// Look only for points which satisfies this conditions
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+    /// How many random points to sample and project. Default is 1000.
+    #[validate(range(min = 2, max = 10000))]
+    pub sample: Option<usize>,
+    /// Define which dense vector to project. If missing, the default vector is used.
+    pub using: Option<VectorNameBuf>,
+    /// Select which payload to return with the projected points. Default is false.
+    pub with_payload: Option<WithPayloadInterface>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct SampleProjectionRequest {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub projection_request: SampleProjectionRequestInternal,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+/// Sampled point with its 2D coordinates
+pub struct ProjectedPoint {
+    pub id: PointIdType,
+    pub x: f32,
+    pub y: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<Payload>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct SampleProjectionResponse {
+    /// Sampled points projected onto the first two principal components
+    pub points: Vec<ProjectedPoint>,
+    /// Share of the sample variance explained by each of the two components
+    pub explained_variance: Vec<f32>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Validate, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct JoinRequestInternal {
+    /// Name of the collection to look for the neighbors in
+    pub target_collection: String,
+    /// Define which vector of the points to use as a query. If missing, the default vector is used.
+    pub using: Option<VectorNameBuf>,
+    /// Define which vector of the target collection to search. If missing, the vector with the same name as `using` is used.
+    pub target_using: Option<VectorNameBuf>,
+    /// Join only points which satisfy these conditions
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+    /// Look only for neighbors which satisfy these conditions
+    #[validate(nested)]
+    pub target_filter: Option<Filter>,
+    /// How many neighbors to find for each point. Default is 10.
+    #[validate(range(min = 1))]
+    pub limit: Option<usize>,
+    /// Additional search params
+    #[validate(nested)]
+    pub params: Option<SearchParams>,
+    /// Define a minimal score threshold for the neighbors.
+    pub score_threshold: Option<ScoreType>,
+    /// Start joining from this point id. Use `next_page_offset` of the previous response to continue a join.
+    pub offset: Option<PointIdType>,
+    /// Max number of points to join in this request. Default is 1000.
+    #[validate(range(min = 1))]
+    pub points_limit: Option<usize>,
+    /// Write the neighbors of each point into this payload key, instead of returning them in the response.
+    pub payload_key: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct JoinRequest {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub join_request: JoinRequestInternal,
+    /// Specify in which shards to look for the points to join, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct JoinNeighbor {
+    /// Id of the point in the target collection
+    pub id: PointIdType,
+    pub score: ScoreType,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct JoinedPoint {
+    pub id: PointIdType,
+    /// Nearest neighbors of the point in the target collection
+    pub neighbors: Vec<JoinNeighbor>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct JoinResponse {
+    /// Number of points joined by this request
+    pub joined: usize,
+    /// Neighbors of the joined points. Empty if the neighbors were written into the payload.
+    pub points: Vec<JoinedPoint>,
+    /// Offset to continue the join from, if `points_limit` was reached or the join was interrupted by an error
+    pub next_page_offset: Option<PointIdType>,
+    /// Error, which interrupted the join after some points were joined
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Validate, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct CopyPointsRequestInternal {
+    /// Name of the collection to copy the points into
+    pub target_collection: String,
+    /// Copy only points which satisfy these conditions. If missing, all points are copied.
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+    /// Shard key to write the points into. Required if the target collection uses custom sharding.
+    pub target_shard_key: Option<ShardKey>,
+    /// Start copying from this point id. Use `next_page_offset` of an interrupted copy to resume it.
+    pub offset: Option<PointIdType>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct CopyPointsRequest {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub copy_request: CopyPointsRequestInternal,
+    /// Specify in which shards to look for the points to copy, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct CopyPointsResponse {
+    /// Number of points copied into the target collection
+    pub copied: usize,
+    /// Offset to resume the copy from, if it was interrupted by an error
+    pub next_page_offset: Option<PointIdType>,
+    /// Error, which interrupted the copy after some points were copied
+    pub error: Option<String>,
+}
+
 #[derive(Debug, JsonSchema, Serialize, Deserialize, Validate)]
 pub struct FacetRequestInternal {
     /// Payload key to use for faceting.
@@ -1364,6 +1621,12 @@ pub struct FacetRequestInternal {
 
     /// Whether to do a more expensive exact count for each of the values in the facet. Default is false.
     pub exact: Option<bool>,
+
+    /// Count points in numeric ranges of the key instead of unique values.
+    /// Requires a range index on the key. Buckets are returned sorted by their lower bound.
+    /// Requests with more than 10000 buckets are rejected.
+    #[validate(nested)]
+    pub histogram: Option<FacetHistogram>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -1373,6 +1636,41 @@ pub struct FacetRequest {
     pub facet_request: FacetRequestInternal,
 
     pub shard_key: Option<ShardKeySelector>,
+
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
+}
+
+#[derive(Debug, JsonSchema, Serialize, Deserialize, Validate)]
+pub struct MultiFacetRequestInternal {
+    /// Payload keys to use for faceting. Counts for all keys are collected at once.
+    #[validate(length(min = 1))]
+    pub keys: Vec<JsonPath>,
+
+    /// Max number of hits to return for each key. Default is 10.
+    #[validate(range(min = 1))]
+    pub limit: Option<usize>,
+
+    /// Filter conditions - only consider points that satisfy these conditions.
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+
+    /// Whether to do a more expensive exact count for each of the values in the facets. Default is false.
+    pub exact: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct MultiFacetRequest {
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub facet_request: MultiFacetRequestInternal,
+
+    pub shard_key: Option<ShardKeySelector>,
+
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -1381,6 +1679,8 @@ pub enum FacetValue {
     String(String),
     Integer(IntPayloadType),
     Bool(bool),
+    /// Lower bound of a histogram bucket
+    Float(FloatPayloadType),
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -1394,6 +1694,58 @@ pub struct FacetResponse {
     pub hits: Vec<FacetValueHit>,
 }
 
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct KeyFacetHits {
+    pub key: JsonPath,
+    pub hits: Vec<FacetValueHit>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct MultiFacetResponse {
+    /// Hits for each of the requested keys, in the same order as the keys
+    pub facets: Vec<KeyFacetHits>,
+}
+
+#[derive(Debug, JsonSchema, Serialize, Deserialize, Validate)]
+pub struct AggregateRequestInternal {
+    /// Payload key to aggregate values of.
+    pub key: JsonPath,
+
+    /// Filter conditions - only consider points that satisfy these conditions.
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct AggregateRequest {
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub aggregate_request: AggregateRequestInternal,
+
+    pub shard_key: Option<ShardKeySelector>,
+
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AggregateResponse {
+    /// Number of numeric values of the key, each element of an array is counted separately
+    pub count: usize,
+    /// Sum of the numeric values
+    pub sum: FloatPayloadType,
+    /// Smallest numeric value, absent if there are no numeric values
+    pub min: Option<FloatPayloadType>,
+    /// Largest numeric value, absent if there are no numeric values
+    pub max: Option<FloatPayloadType>,
+    /// Average of the numeric values, absent if there are no numeric values
+    pub avg: Option<FloatPayloadType>,
+    /// Number of distinct values of the key, including non-numeric ones.
+    /// Absent if there are more than 10000 distinct values
+    pub distinct_count: Option<usize>,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
 pub struct PointStruct {
@@ -1405,6 +1757,10 @@ pub struct PointStruct {
     pub vector: VectorStruct,
     /// Payload values (optional)
     pub payload: Option<Payload>,
+    /// Shard key of the point, overrides shard key of the request.
+    /// Only for collections with custom sharding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKey>,
 }
 
 /// Defines the mode of the upsert operation
@@ -43,6 +43,7 @@ impl Validate for Query {
             Query::OrderBy(order_by) => order_by.validate(),
             Query::Sample(sample) => sample.validate(),
             Query::RelevanceFeedback(feedback) => feedback.validate(),
+            Query::Arithmetic(arithmetic) => arithmetic.validate(),
         }
     }
 }
@@ -156,7 +157,7 @@ impl Validate for OrderByInterface {
 impl Validate for Sample {
     fn validate(&self) -> Result<(), ValidationErrors> {
         match self {
-            Sample::Random => Ok(()),
+            Sample::Random | Sample::Weighted(_) => Ok(()),
         }
     }
 }
@@ -239,6 +240,15 @@ impl Validate for Batch {
                 payload_vector.len(),
             )));
         }
+        if let Some(shard_keys) = &batch.shard_keys
+            && shard_keys.len() != batch.ids.len()
+        {
+            return Err(create_error(format!(
+                "number of ids and shard keys must be equal ({} != {})",
+                batch.ids.len(),
+                shard_keys.len(),
+            )));
+        }
         Ok(())
     }
 }
@@ -44,6 +44,8 @@ fn setup() -> (TempDir, LocalShard, Runtime) {
         wal_capacity_mb: 1,
         wal_segments_ahead: 0,
         wal_retain_closed: 1,
+        wal_batch_window_ms: 0,
+        wal_batch_max_points: 256,
     };
 
     let collection_params = CollectionParams {
@@ -64,6 +66,8 @@ fn setup() -> (TempDir, LocalShard, Runtime) {
             flush_interval_sec: 30,
             max_optimization_threads: Some(2),
             prevent_unoptimized: None,
+            max_builder_memory: None,
+            flush_max_dirty: None,
         },
         wal_config,
         hnsw_config: Default::default(),
@@ -85,6 +89,7 @@ fn setup() -> (TempDir, LocalShard, Runtime) {
     let shard = handle
         .block_on(LocalShard::build_local(
             0,
+            None,
             "test_collection".to_string(),
             storage_dir.path(),
             shared_config,
@@ -182,7 +187,14 @@ fn batch_search_bench(c: &mut Criterion) {
 
                     let hw_acc = HwMeasurementAcc::new();
                     let result = shard
-                        .query_batch(Arc::new(searches), search_runtime_handle, None, hw_acc)
+                        .query_batch(
+                            Arc::new(searches),
+                            search_runtime_handle,
+                            None,
+                            None,
+                            None,
+                            hw_acc,
+                        )
                         .await
                         .unwrap();
                     assert!(!result.is_empty());
@@ -283,7 +295,14 @@ fn batch_rrf_query_bench(c: &mut Criterion) {
 
                     let hw_acc = HwMeasurementAcc::new();
                     let result = shard
-                        .query_batch(Arc::new(searches), search_runtime_handle, None, hw_acc)
+                        .query_batch(
+                            Arc::new(searches),
+                            search_runtime_handle,
+                            None,
+                            None,
+                            None,
+                            hw_acc,
+                        )
                         .await
                         .unwrap();
                     assert!(!result.is_empty());
@@ -340,7 +359,14 @@ fn batch_rescore_bench(c: &mut Criterion) {
 
                     let hw_acc = HwMeasurementAcc::new();
                     let result = shard
-                        .query_batch(Arc::new(searches), search_runtime_handle, None, hw_acc)
+                        .query_batch(
+                            Arc::new(searches),
+                            search_runtime_handle,
+                            None,
+                            None,
+                            None,
+                            hw_acc,
+                        )
                         .await
                         .unwrap();
                     assert!(!result.is_empty());
@@ -62,6 +62,8 @@ fn batch_search_bench(c: &mut Criterion) {
         wal_capacity_mb: 1,
         wal_segments_ahead: 0,
         wal_retain_closed: 1,
+        wal_batch_window_ms: 0,
+        wal_batch_max_points: 256,
     };
 
     let collection_params = CollectionParams {
@@ -82,6 +84,8 @@ fn batch_search_bench(c: &mut Criterion) {
             flush_interval_sec: 30,
             max_optimization_threads: Some(2),
             prevent_unoptimized: None,
+            max_builder_memory: None,
+            flush_max_dirty: None,
         },
         wal_config,
         hnsw_config: Default::default(),
@@ -103,6 +107,7 @@ fn batch_search_bench(c: &mut Criterion) {
     let shard = handle
         .block_on(LocalShard::build_local(
             0,
+            None,
             "test_collection".to_string(),
             storage_dir.path(),
             shared_config,
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use futures::TryStreamExt;
+use futures::stream::FuturesUnordered;
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
+
+use super::Collection;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::CollectionResult;
+
+impl Collection {
+    pub async fn aggregate(
+        &self,
+        request: AggregateParams,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult> {
+        let request = Arc::new(request);
+
+        let shard_holder = self.shards_holder.read().await;
+        let target_shards = shard_holder.select_shards(&shard_selection)?;
+
+        let mut shards_reads_f = target_shards
+            .iter()
+            .map(|(shard, _shard_key)| {
+                shard.aggregate(
+                    request.clone(),
+                    read_consistency,
+                    shard_selection.is_shard_id(),
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        let mut result = AggregateResult::default();
+        while let Some(shard_result) = shards_reads_f.try_next().await? {
+            result.merge(shard_result);
+        }
+
+        Ok(result)
+    }
+}
@@ -386,6 +386,7 @@ impl Collection {
         Ok(UpdateResult {
             operation_id: None,
             status,
+            affected_points: None,
             clock_tag: None,
         })
     }
@@ -3,7 +3,7 @@ use std::sync::{Arc, LazyLock};
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::{TryStreamExt as _, future};
-use segment::types::{Payload, QuantizationConfig, StrictModeConfig};
+use segment::types::{Payload, QuantizationConfig, StrictModeConfig, VectorName};
 use semver::Version;
 use shard::count::CountRequestInternal;
 
@@ -26,8 +26,20 @@ pub static ABORT_TRANSFERS_ON_SHARD_DROP_FIX_FROM_VERSION: LazyLock<Version> =
     LazyLock::new(|| Version::parse("1.16.3-dev").expect("valid version string"));
 
 impl Collection {
+    /// Reject updates of collection data, if the collection is read-only
+    pub async fn check_read_only(&self) -> CollectionResult<()> {
+        if self.collection_config.read().await.params.read_only == Some(true) {
+            return Err(CollectionError::bad_request(format!(
+                "Collection {} is read-only, updates are not allowed",
+                self.name(),
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Updates collection params:
-    /// Saves new params on disk
+    /// Saves new params on disk, creates the trash index if trash retention got enabled
     ///
     /// After this, `recreate_optimizers_blocking` must be called to create new optimizers using
     /// the updated configuration.
@@ -40,6 +52,7 @@ impl Collection {
             config.params = config.params.update(&params_diff);
         }
         self.collection_config.read().await.save(&self.path)?;
+        self.create_trash_index().await?;
         Ok(())
     }
 
@@ -71,9 +84,10 @@ impl Collection {
     ) -> CollectionResult<()> {
         let mut config = self.collection_config.write().await;
         update_vectors_diff.check_vector_names(&config.params)?;
-        config
-            .params
-            .update_vectors_from_diff(update_vectors_diff)?;
+        let mut params = config.params.clone();
+        params.update_vectors_from_diff(update_vectors_diff)?;
+        params.check_quantization(config.quantization_config.as_ref())?;
+        config.params = params;
         config.save(&self.path)?;
         Ok(())
     }
@@ -140,26 +154,18 @@ impl Collection {
     ) -> CollectionResult<()> {
         {
             let mut config = self.collection_config.write().await;
-            match quantization_config_diff {
-                QuantizationConfigDiff::Scalar(scalar) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Scalar(scalar));
-                }
+            let quantization_config = match quantization_config_diff {
+                QuantizationConfigDiff::Scalar(scalar) => Some(QuantizationConfig::Scalar(scalar)),
                 QuantizationConfigDiff::Product(product) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Product(product));
-                }
-                QuantizationConfigDiff::Binary(binary) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Binary(binary));
-                }
-                QuantizationConfigDiff::Disabled(_) => {
-                    config.quantization_config = None;
+                    Some(QuantizationConfig::Product(product))
                 }
-            }
+                QuantizationConfigDiff::Binary(binary) => Some(QuantizationConfig::Binary(binary)),
+                QuantizationConfigDiff::Disabled(_) => None,
+            };
+            config
+                .params
+                .check_quantization(quantization_config.as_ref())?;
+            config.quantization_config = quantization_config;
         }
         self.collection_config.read().await.save(&self.path)?;
         Ok(())
@@ -313,6 +319,29 @@ impl Collection {
             .clone()
     }
 
+    /// Lowest `hnsw_ef`, which searches of the vector use if it is not set in the request.
+    ///
+    /// Shards of different shard keys may override the HNSW config, so the lowest one is taken.
+    pub async fn default_hnsw_ef(&self, vector_name: &VectorName) -> usize {
+        let config = self.collection_config.read().await;
+        let vector_hnsw = config
+            .params
+            .vectors
+            .get_params(vector_name)
+            .and_then(|params| params.hnsw_config.as_ref());
+
+        config
+            .params
+            .shard_key_configs
+            .iter()
+            .filter_map(|shard_key_config| shard_key_config.hnsw_config.as_ref())
+            .map(|hnsw_diff| config.hnsw_config.update(hnsw_diff))
+            .chain([config.hnsw_config.clone()])
+            .map(|hnsw_config| hnsw_config.update_opt(vector_hnsw).ef_construct)
+            .min()
+            .unwrap_or(config.hnsw_config.ef_construct)
+    }
+
     pub async fn info(
         &self,
         shard_selection: &ShardSelectorInternal,
@@ -344,6 +373,7 @@ impl Collection {
                 config: _,
                 payload_schema,
                 update_queue,
+                indexing_backlog,
             } = response;
             info.status = cmp::max(info.status, status);
             info.optimizer_status = cmp::max(info.optimizer_status, optimizer_status);
@@ -359,6 +389,10 @@ impl Collection {
             } else {
                 info.update_queue = update_queue;
             }
+            info.indexing_backlog = match (info.indexing_backlog, indexing_backlog) {
+                (Some(a), Some(b)) => Some(a.merge(b)),
+                (a, b) => a.or(b),
+            };
             for (key, response_schema) in payload_schema {
                 info.payload_schema
                     .entry(key)
@@ -434,6 +468,7 @@ impl Collection {
             remote_shards,
             shard_transfers,
             resharding_operations,
+            resharding_error: self.resharding_error(),
         };
         Ok(info)
     }
@@ -468,9 +503,63 @@ impl Collection {
             }
         }
 
+        if let Some(decisions) = &mut merged.decisions {
+            decisions.sort_by_key(|v| cmp::Reverse(v.decided_at));
+        }
+
         Ok(merged)
     }
 
+    /// Cancel ongoing optimizations of all local shards in the collection.
+    ///
+    /// Cancelled optimizers are suspended until [`Collection::resume_optimizations`] is called,
+    /// or the node is restarted. Returns amount of cancelled optimizations.
+    pub async fn cancel_optimizations(&self, optimizer: Option<&str>) -> CollectionResult<usize> {
+        let shards_holder = self.shards_holder.read().await;
+
+        let futures: Vec<_> = shards_holder
+            .all_shards()
+            .map(|shard| shard.cancel_optimizations(optimizer))
+            .collect();
+
+        let cancelled = future::try_join_all(futures).await?;
+        Ok(cancelled.into_iter().sum())
+    }
+
+    /// Resume suspended optimizers of all local shards in the collection.
+    pub async fn resume_optimizations(&self) {
+        let shards_holder = self.shards_holder.read().await;
+
+        let futures: Vec<_> = shards_holder
+            .all_shards()
+            .map(|shard| shard.resume_optimizations())
+            .collect();
+
+        future::join_all(futures).await;
+    }
+
+    /// Load the vector of all local shards into memory if `pinned`, or evict it from memory
+    /// otherwise.
+    ///
+    /// Applies to existing segments only, and is not persisted across restarts.
+    pub async fn pin_vector(&self, vector_name: &VectorName, pinned: bool) -> CollectionResult<()> {
+        self.collection_config
+            .read()
+            .await
+            .params
+            .check_vector_exists(vector_name)?;
+
+        let shards_holder = self.shards_holder.read().await;
+
+        let futures: Vec<_> = shards_holder
+            .all_shards()
+            .map(|shard| shard.pin_vector(vector_name, pinned))
+            .collect();
+
+        future::try_join_all(futures).await?;
+        Ok(())
+    }
+
     pub async fn print_warnings(&self) {
         let warnings = self.collection_config.read().await.get_warnings();
         for warning in warnings {
@@ -0,0 +1,227 @@
+use std::time::Duration;
+
+use api::rest::{CopyPointsRequestInternal, CopyPointsResponse};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::types::{
+    Filter, PointIdType, ShardKey, StrictModeConfig, WithPayloadInterface, WithVector,
+};
+use shard::scroll::ScrollRequestInternal;
+
+use crate::collection::Collection;
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, WriteOrdering,
+};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionError, CollectionResult, ScrollResult};
+use crate::operations::verification::StrictModeVerification as _;
+
+/// Number of points, copied in a single batch
+const COPY_BATCH_SIZE: usize = 100;
+
+/// Internal representation of the copy request, used to convert from REST.
+pub struct CollectionCopyRequest {
+    pub target_collection: String,
+    pub filter: Option<Filter>,
+    pub target_shard_key: Option<ShardKey>,
+    pub offset: Option<PointIdType>,
+}
+
+impl From<CopyPointsRequestInternal> for CollectionCopyRequest {
+    fn from(request: CopyPointsRequestInternal) -> Self {
+        let CopyPointsRequestInternal {
+            target_collection,
+            filter,
+            target_shard_key,
+            offset,
+        } = request;
+        Self {
+            target_collection,
+            filter,
+            target_shard_key,
+            offset,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CollectionCopyResponse {
+    pub copied: usize,
+    /// First point, which is not copied, if the copy was interrupted by an error
+    pub next_page_offset: Option<PointIdType>,
+    pub error: Option<String>,
+}
+
+impl From<CollectionCopyResponse> for CopyPointsResponse {
+    fn from(response: CollectionCopyResponse) -> Self {
+        let CollectionCopyResponse {
+            copied,
+            next_page_offset,
+            error,
+        } = response;
+        Self {
+            copied,
+            next_page_offset,
+            error,
+        }
+    }
+}
+
+impl Collection {
+    /// Copy points of this collection into the `target` collection, including vectors and payload.
+    ///
+    /// Points are scrolled and upserted in batches, the same way as records are streamed in shard
+    /// transfers. Points with the same IDs in the target collection are overwritten. Batches are
+    /// checked against the strict mode of the target collection, like regular upserts.
+    ///
+    /// If the copy fails after some points were copied, the response contains the error, and the
+    /// offset to resume the copy from. Errors before any point is copied are returned as is.
+    pub async fn copy_points(
+        &self,
+        target: &Collection,
+        request: CollectionCopyRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<CollectionCopyResponse> {
+        let CollectionCopyRequest {
+            target_collection: _,
+            filter,
+            target_shard_key,
+            mut offset,
+        } = request;
+
+        if self.name() == target.name() {
+            return Err(CollectionError::bad_request(
+                "Can't copy points into the same collection",
+            ));
+        }
+
+        let target_strict_mode = target
+            .strict_mode_config()
+            .await
+            .filter(|config| config.enabled.unwrap_or_default());
+        let batch_size = target_strict_mode
+            .as_ref()
+            .and_then(|config| config.upsert_max_batchsize)
+            .map_or(COPY_BATCH_SIZE, |max_batch_size| {
+                max_batch_size.clamp(1, COPY_BATCH_SIZE)
+            });
+
+        let mut response = CollectionCopyResponse::default();
+
+        loop {
+            let copy_result = self
+                .copy_batch(
+                    target,
+                    filter.clone(),
+                    target_shard_key.clone(),
+                    offset,
+                    batch_size,
+                    target_strict_mode.as_ref(),
+                    &shard_selection,
+                    read_consistency,
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+                .await;
+
+            let (copied, next_page_offset) = match copy_result {
+                Ok(result) => result,
+                Err(err) if response.copied > 0 => {
+                    response.next_page_offset = offset;
+                    response.error = Some(err.to_string());
+                    return Ok(response);
+                }
+                Err(err) => return Err(err),
+            };
+
+            response.copied += copied;
+
+            offset = next_page_offset;
+            if offset.is_none() {
+                break;
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Copy a single batch of points, starting from `offset`.
+    ///
+    /// Returns the number of copied points, and the offset of the next batch.
+    #[allow(clippy::too_many_arguments)]
+    async fn copy_batch(
+        &self,
+        target: &Collection,
+        filter: Option<Filter>,
+        target_shard_key: Option<ShardKey>,
+        offset: Option<PointIdType>,
+        batch_size: usize,
+        target_strict_mode: Option<&StrictModeConfig>,
+        shard_selection: &ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<(usize, Option<PointIdType>)> {
+        let scroll_request = ScrollRequestInternal {
+            offset,
+            limit: Some(batch_size),
+            filter,
+            with_payload: Some(WithPayloadInterface::Bool(true)),
+            with_vector: WithVector::Bool(true),
+            order_by: None,
+            distinct_by: None,
+            with_version: None,
+        };
+
+        let ScrollResult {
+            points,
+            next_page_offset,
+        } = self
+            .scroll_by(
+                scroll_request,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+
+        if points.is_empty() {
+            return Ok((0, next_page_offset));
+        }
+
+        let points = points
+            .into_iter()
+            .map(PointStructPersisted::try_from)
+            .collect::<Result<Vec<_>, String>>()?;
+        let copied = points.len();
+
+        let insert_operation = PointInsertOperationsInternal::PointsList(points);
+        if let Some(strict_mode_config) = target_strict_mode {
+            insert_operation
+                .check_strict_mode(target, strict_mode_config)
+                .await?;
+        }
+
+        let operation = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+            insert_operation,
+        ));
+
+        target
+            .update_from_client(
+                operation,
+                true,
+                timeout,
+                WriteOrdering::default(),
+                target_shard_key,
+                hw_measurement_acc,
+            )
+            .await?;
+
+        Ok((copied, next_page_offset))
+    }
+}
@@ -260,6 +260,9 @@ impl Collection {
                 collection_by_name,
                 read_consistency,
                 timeout,
+                None,
+                None,
+                None,
                 hw_measurement_acc,
             )
             .await?;
@@ -0,0 +1,272 @@
+use std::mem;
+use std::sync::atomic::Ordering;
+
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::data_types::vectors::{
+    DEFAULT_VECTOR_NAME, DenseVector, NamedQuery, VectorInternal, VectorRef,
+};
+use segment::json_path::JsonPath;
+use segment::types::{
+    Condition, Distance, ExtendedPointId, FieldCondition, Filter, HasIdCondition, IsEmptyCondition,
+    Match, Payload, PayloadContainer as _, PayloadField, ShardKey, ValueVariants, WithVector,
+};
+use serde_json::Value;
+use shard::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, VectorPersisted,
+    VectorStructPersisted,
+};
+use shard::query::query_enum::QueryEnum;
+use shard::search::{CoreSearchRequest, CoreSearchRequestBatch};
+
+use super::Collection;
+use crate::config::{DuplicateDetectionConfig, DuplicateDetectionMode};
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+/// Payload field, which stores the id of the existing point in [`DuplicateDetectionMode::Tag`]
+pub const DUPLICATE_OF_PAYLOAD_KEY: &str = "duplicate_of";
+
+/// Number of nearest points by dot product, which are compared with an upserted point
+const DOT_DUPLICATE_CANDIDATES: usize = 16;
+
+impl Collection {
+    /// Look up existing near-identical points for upserted points, if duplicate detection is
+    /// configured for the collection.
+    ///
+    /// Depending on the configured mode, the whole operation is rejected, or the upserted
+    /// duplicates are tagged with the id of the existing point. Detected duplicates are counted
+    /// in any mode.
+    pub(super) async fn detect_duplicates(
+        &self,
+        operation: &mut CollectionUpdateOperations,
+        shard_keys_selection: &Option<ShardKey>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<()> {
+        let (config, distance) = {
+            let collection_config = self.collection_config.read().await;
+            let Some(config) = collection_config.params.duplicate_detection.clone() else {
+                return Ok(());
+            };
+            let vector_name = config.vector.as_deref().unwrap_or(DEFAULT_VECTOR_NAME);
+            let distance = collection_config.params.get_distance(vector_name)?;
+            (config, distance)
+        };
+
+        let points_op = match operation {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(op)) => op,
+            CollectionUpdateOperations::PointOperation(
+                PointOperations::UpsertPointsConditional(op),
+            ) => &mut op.points_op,
+            _ => return Ok(()),
+        };
+
+        let mut points = mem::replace(points_op, PointInsertOperationsInternal::PointsList(vec![]))
+            .into_point_vec();
+
+        let result = self
+            .find_duplicates(
+                &config,
+                distance,
+                &points,
+                shard_keys_selection,
+                hw_measurement_acc,
+            )
+            .await;
+
+        let result = result.and_then(|duplicates| {
+            let detected = duplicates.iter().flatten().count();
+            self.duplicates_detected
+                .fetch_add(detected, Ordering::Relaxed);
+
+            for (point, duplicate_of) in points.iter_mut().zip(duplicates) {
+                let Some(duplicate_of) = duplicate_of else {
+                    continue;
+                };
+                match config.mode {
+                    DuplicateDetectionMode::Count => {}
+                    DuplicateDetectionMode::Reject => {
+                        return Err(CollectionError::bad_request(format!(
+                            "Point {} is a duplicate of existing point {duplicate_of}",
+                            point.id,
+                        )));
+                    }
+                    DuplicateDetectionMode::Tag => {
+                        let duplicate_of = match duplicate_of {
+                            ExtendedPointId::NumId(id) => Value::from(id),
+                            ExtendedPointId::Uuid(uuid) => Value::String(uuid.to_string()),
+                        };
+                        point
+                            .payload
+                            .get_or_insert_with(Payload::default)
+                            .0
+                            .insert(DUPLICATE_OF_PAYLOAD_KEY.to_string(), duplicate_of);
+                    }
+                }
+            }
+            Ok(())
+        });
+
+        *points_op = PointInsertOperationsInternal::PointsList(points);
+        result
+    }
+
+    /// For each point, find an existing point within `epsilon` of its vector.
+    async fn find_duplicates(
+        &self,
+        config: &DuplicateDetectionConfig,
+        distance: Distance,
+        points: &[PointStructPersisted],
+        shard_keys_selection: &Option<ShardKey>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<Option<ExtendedPointId>>> {
+        let vector_name = config.vector.as_deref().unwrap_or(DEFAULT_VECTOR_NAME);
+        let epsilon = config.epsilon.into_inner() as f32;
+
+        // Points without a comparable vector or tenant are never duplicates
+        let mut searches = Vec::new();
+        let mut search_points = Vec::new();
+        let mut thresholds = Vec::new();
+        for (index, point) in points.iter().enumerate() {
+            let Some(vector) = dense_vector(&point.vector, vector_name) else {
+                continue;
+            };
+            let mut must = Vec::new();
+            if let Some(tenant_key) = &config.tenant_key {
+                let Some(condition) = tenant_condition(point.payload.as_ref(), tenant_key) else {
+                    continue;
+                };
+                must.push(condition);
+            }
+
+            // Dot product doesn't bound the distance between vectors, so candidates are searched
+            // by a necessary condition and compared by Euclidean distance afterwards
+            let (threshold, limit, with_vector) = match distance {
+                Distance::Cosine => (1.0 - epsilon, 1, None),
+                Distance::Dot => {
+                    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+                    let threshold = norm * (norm - epsilon);
+                    let with_vector = WithVector::Selector(vec![vector_name.to_owned()]);
+                    (threshold, DOT_DUPLICATE_CANDIDATES, Some(with_vector))
+                }
+                Distance::Euclid | Distance::Manhattan | Distance::Hamming => (epsilon, 1, None),
+            };
+
+            searches.push(CoreSearchRequest {
+                query: QueryEnum::Nearest(NamedQuery {
+                    query: VectorInternal::Dense(vector.clone()),
+                    using: Some(vector_name.to_owned()),
+                }),
+                filter: Some(Filter {
+                    should: None,
+                    min_should: None,
+                    must: (!must.is_empty()).then_some(must),
+                    // Re-upserting a point is not a duplicate of itself
+                    must_not: Some(vec![Condition::HasId(HasIdCondition::from(
+                        [point.id].into_iter().collect::<ahash::AHashSet<_>>(),
+                    ))]),
+                }),
+                params: None,
+                limit,
+                offset: 0,
+                with_payload: None,
+                with_vector,
+                score_threshold: None,
+            });
+            search_points.push(index);
+            thresholds.push(threshold);
+        }
+
+        let mut duplicates = vec![None; points.len()];
+        if searches.is_empty() {
+            return Ok(duplicates);
+        }
+
+        let shard_selection = match shard_keys_selection {
+            Some(shard_key) => ShardSelectorInternal::ShardKey(shard_key.clone()),
+            None => ShardSelectorInternal::All,
+        };
+
+        let results = self
+            .core_search_batch(
+                CoreSearchRequestBatch { searches },
+                None,
+                shard_selection,
+                None,
+                hw_measurement_acc,
+            )
+            .await?;
+
+        for ((index, threshold), result) in search_points.into_iter().zip(thresholds).zip(results) {
+            let duplicate = result
+                .iter()
+                .take_while(|candidate| distance.is_ordered(candidate.score, threshold))
+                .find(|candidate| match distance {
+                    Distance::Dot => {
+                        let vector = dense_vector(&points[index].vector, vector_name);
+                        let candidate_vector = candidate
+                            .vector
+                            .as_ref()
+                            .and_then(|vector| vector.get(vector_name));
+                        match (vector, candidate_vector) {
+                            (Some(vector), Some(VectorRef::Dense(candidate_vector))) => {
+                                euclid_distance(vector, candidate_vector) <= epsilon
+                            }
+                            _ => false,
+                        }
+                    }
+                    _ => true,
+                });
+            duplicates[index] = duplicate.map(|duplicate| duplicate.id);
+        }
+
+        Ok(duplicates)
+    }
+}
+
+fn euclid_distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f32>()
+        .sqrt()
+}
+
+fn dense_vector<'a>(
+    vector: &'a VectorStructPersisted,
+    vector_name: &str,
+) -> Option<&'a DenseVector> {
+    match vector {
+        VectorStructPersisted::Single(vector) if vector_name == DEFAULT_VECTOR_NAME => Some(vector),
+        VectorStructPersisted::Named(vectors) => match vectors.get(vector_name) {
+            Some(VectorPersisted::Dense(vector)) => Some(vector),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Condition to match existing points of the same tenant.
+///
+/// Returns `None` if the tenant value can't be matched exactly, e.g. a float.
+fn tenant_condition(payload: Option<&Payload>, tenant_key: &JsonPath) -> Option<Condition> {
+    let value = payload.and_then(|payload| payload.get_value(tenant_key).first().copied());
+    let value = match value {
+        None | Some(Value::Null) => {
+            return Some(Condition::IsEmpty(IsEmptyCondition {
+                is_empty: PayloadField {
+                    key: tenant_key.clone(),
+                },
+            }));
+        }
+        Some(Value::String(value)) => ValueVariants::String(value.clone()),
+        Some(Value::Bool(value)) => ValueVariants::Bool(*value),
+        Some(Value::Number(value)) => ValueVariants::Integer(value.as_i64()?),
+        Some(_) => return None,
+    };
+
+    Some(Condition::Field(FieldCondition::new_match(
+        tenant_key.clone(),
+        Match::new_value(value),
+    )))
+}
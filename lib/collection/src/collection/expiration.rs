@@ -0,0 +1,34 @@
+use chrono::Utc;
+
+use super::Collection;
+use crate::operations::types::CollectionResult;
+
+impl Collection {
+    /// Delete expired points from shards of this peer, if points expiration is enabled.
+    ///
+    /// Expired points are excluded from reads as soon as they expire, deletion only reclaims
+    /// their storage. Points are not deleted from read-only collections.
+    pub async fn delete_expired_points(&self) -> CollectionResult<()> {
+        {
+            let config = self.collection_config.read().await;
+            let points_expiration = config.params.points_expiration.unwrap_or_default();
+            let read_only = config.params.read_only.unwrap_or_default();
+            if !points_expiration || read_only {
+                return Ok(());
+            }
+        }
+
+        let now = Utc::now();
+        let shard_holder = self.shards_holder.read().await;
+        for (shard_id, replica_set) in shard_holder.get_shards() {
+            if let Err(err) = replica_set.delete_expired_points(now).await {
+                log::warn!(
+                    "Failed to delete expired points of shard {}:{shard_id}: {err}",
+                    self.id,
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -5,7 +5,10 @@ use std::time::Duration;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::TryStreamExt;
 use futures::stream::FuturesUnordered;
-use segment::data_types::facets::{FacetParams, FacetResponse, FacetValue};
+use segment::common::operation_error::OperationError;
+use segment::data_types::facets::{
+    FacetParams, FacetResponse, FacetValue, MAX_HISTOGRAM_BUCKETS, MultiFacetParams,
+};
 
 use super::Collection;
 use crate::operations::consistency_params::ReadConsistency;
@@ -26,6 +29,7 @@ impl Collection {
         }
 
         let limit = request.limit;
+        let is_histogram = request.histogram.is_some();
         let request = Arc::new(request);
 
         let shard_holder = self.shards_holder.read().await;
@@ -52,6 +56,67 @@ impl Collection {
             }
         }
 
-        Ok(FacetResponse::top_hits(aggregated_results, limit))
+        if is_histogram {
+            // Segments may have different buckets, check their union as well
+            if aggregated_results.len() > MAX_HISTOGRAM_BUCKETS {
+                return Err(OperationError::TooManyHistogramBuckets {
+                    key: request.key.to_string(),
+                    limit: MAX_HISTOGRAM_BUCKETS,
+                }
+                .into());
+            }
+            Ok(FacetResponse::histogram_hits(aggregated_results, limit))
+        } else {
+            Ok(FacetResponse::top_hits(aggregated_results, limit))
+        }
+    }
+
+    /// Facet counts for several keys sharing the same filter, in the same order as the keys.
+    pub async fn facets(
+        &self,
+        request: MultiFacetParams,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<FacetResponse>> {
+        if request.limit == 0 {
+            return Ok(vec![FacetResponse::default(); request.keys.len()]);
+        }
+
+        let limit = request.limit;
+        let request = Arc::new(request);
+
+        let shard_holder = self.shards_holder.read().await;
+        let target_shards = shard_holder.select_shards(&shard_selection)?;
+
+        let mut shards_reads_f = target_shards
+            .iter()
+            .map(|(shard, _shard_key)| {
+                shard.facets(
+                    request.clone(),
+                    read_consistency,
+                    shard_selection.is_shard_id(),
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        // Collect results from all shards into a single map for each key
+        let mut aggregated_results: Vec<HashMap<FacetValue, usize>> =
+            vec![HashMap::new(); request.keys.len()];
+        while let Some(responses) = shards_reads_f.try_next().await? {
+            for (aggregated, response) in aggregated_results.iter_mut().zip(responses) {
+                for hit in response.hits {
+                    *aggregated.entry(hit.value).or_insert(0) += hit.count;
+                }
+            }
+        }
+
+        Ok(aggregated_results
+            .into_iter()
+            .map(|counts| FacetResponse::top_hits(counts, limit))
+            .collect())
     }
 }
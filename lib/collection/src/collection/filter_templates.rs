@@ -0,0 +1,360 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use api::rest::FilterTemplateReference;
+use common::save_on_disk::SaveOnDisk;
+use itertools::Itertools;
+use schemars::JsonSchema;
+use segment::types::Filter;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use validator::Validate;
+
+use crate::collection::Collection;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+pub const FILTER_TEMPLATES_FILE: &str = "filter_templates.json";
+
+/// Prefix of a string value in a filter template, which is replaced with a parameter value
+const PARAM_PREFIX: char = '$';
+
+/// Named filter templates of a collection
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+pub struct FilterTemplates {
+    #[serde(default)]
+    pub templates: BTreeMap<String, FilterTemplate>,
+}
+
+/// Filter stored in a collection under a name, with parameters substituted on use
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct FilterTemplate {
+    /// Filter in the same format as in search requests.
+    /// String values of the form `"$param"` are replaced with the value of parameter `param`.
+    pub filter: Value,
+    /// Parameters which can be substituted into the filter
+    #[serde(default)]
+    pub params: BTreeMap<String, FilterTemplateParam>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct FilterTemplateParam {
+    /// Type of the parameter value
+    pub r#type: FilterTemplateParamType,
+    /// Value to use if the parameter is not specified. If not set, the parameter is required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterTemplateParamType {
+    Keyword,
+    Integer,
+    Float,
+    Bool,
+    Keywords,
+    Integers,
+}
+
+impl FilterTemplateParamType {
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::Keyword => value.is_string(),
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::Float => value.is_number(),
+            Self::Bool => value.is_boolean(),
+            Self::Keywords => value
+                .as_array()
+                .is_some_and(|values| values.iter().all(Value::is_string)),
+            Self::Integers => value
+                .as_array()
+                .is_some_and(|values| values.iter().all(|value| value.is_i64() || value.is_u64())),
+        }
+    }
+
+    /// Arbitrary value of this type, used to validate templates
+    fn sample(self) -> Value {
+        match self {
+            Self::Keyword => Value::from("sample"),
+            Self::Integer => Value::from(0),
+            Self::Float => Value::from(0.0),
+            Self::Bool => Value::from(true),
+            Self::Keywords => Value::from(vec!["sample"]),
+            Self::Integers => Value::from(vec![0]),
+        }
+    }
+}
+
+impl FilterTemplate {
+    /// Build a filter from the template with given parameter values.
+    pub fn instantiate(&self, params: &BTreeMap<String, Value>) -> CollectionResult<Filter> {
+        if let Some(name) = params.keys().find(|name| !self.params.contains_key(*name)) {
+            return Err(CollectionError::bad_request(format!(
+                "Unknown filter template parameter {name}",
+            )));
+        }
+
+        let mut values = BTreeMap::new();
+        for (name, param) in &self.params {
+            let Some(value) = params.get(name).or(param.default.as_ref()) else {
+                return Err(CollectionError::bad_request(format!(
+                    "Missing value for filter template parameter {name}",
+                )));
+            };
+            if !param.r#type.matches(value) {
+                return Err(CollectionError::bad_request(format!(
+                    "Filter template parameter {name} must be of type {:?}, got {value}",
+                    param.r#type,
+                )));
+            }
+            values.insert(name.as_str(), value);
+        }
+
+        let (filter, _) = self.substitute(&values)?;
+        Ok(filter)
+    }
+
+    /// Check that the template produces a valid filter and all its parameters are used.
+    pub fn validate(&self) -> CollectionResult<Filter> {
+        let mut samples = BTreeMap::new();
+        for (name, param) in &self.params {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(CollectionError::bad_input(format!(
+                    "Invalid filter template parameter name {name:?}, only alphanumeric characters and underscores are allowed",
+                )));
+            }
+
+            if let Some(default) = &param.default
+                && !param.r#type.matches(default)
+            {
+                return Err(CollectionError::bad_input(format!(
+                    "Default value of filter template parameter {name} must be of type {:?}, got {default}",
+                    param.r#type,
+                )));
+            }
+
+            samples.insert(name.as_str(), param.r#type.sample());
+        }
+
+        let values = samples.iter().map(|(name, value)| (*name, value)).collect();
+        let (filter, used) = self.substitute(&values)?;
+
+        if let Some(name) = self
+            .params
+            .keys()
+            .find(|name| !used.contains(name.as_str()))
+        {
+            return Err(CollectionError::bad_input(format!(
+                "Filter template parameter {name} is not used in the filter",
+            )));
+        }
+
+        Ok(filter)
+    }
+
+    fn substitute<'a>(
+        &'a self,
+        values: &BTreeMap<&'a str, &Value>,
+    ) -> CollectionResult<(Filter, HashSet<&'a str>)> {
+        let mut used = HashSet::new();
+        let filter = substitute_params(&self.filter, values, &mut used);
+        let filter: Filter = serde_json::from_value(filter)
+            .map_err(|err| CollectionError::bad_input(format!("Invalid filter template: {err}")))?;
+        filter
+            .validate()
+            .map_err(|err| CollectionError::bad_input(format!("Invalid filter template: {err}")))?;
+        Ok((filter, used))
+    }
+}
+
+fn substitute_params<'a>(
+    value: &Value,
+    values: &BTreeMap<&'a str, &Value>,
+    used: &mut HashSet<&'a str>,
+) -> Value {
+    match value {
+        Value::String(string) => {
+            let param = string
+                .strip_prefix(PARAM_PREFIX)
+                .and_then(|name| values.get_key_value(name));
+            match param {
+                Some((name, param_value)) => {
+                    used.insert(*name);
+                    (*param_value).clone()
+                }
+                None => value.clone(),
+            }
+        }
+        Value::Array(array) => Value::Array(
+            array
+                .iter()
+                .map(|value| substitute_params(value, values, used))
+                .collect(),
+        ),
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| (key.clone(), substitute_params(value, values, used)))
+                .collect(),
+        ),
+        Value::Null | Value::Bool(_) | Value::Number(_) => value.clone(),
+    }
+}
+
+impl Collection {
+    pub(crate) fn filter_templates_file(collection_path: &Path) -> PathBuf {
+        collection_path.join(FILTER_TEMPLATES_FILE)
+    }
+
+    pub(crate) fn load_filter_templates(
+        collection_path: &Path,
+    ) -> CollectionResult<SaveOnDisk<FilterTemplates>> {
+        let filter_templates_file = Self::filter_templates_file(collection_path);
+        let templates: SaveOnDisk<FilterTemplates> =
+            SaveOnDisk::load_or_init_default(filter_templates_file)?;
+        Ok(templates)
+    }
+
+    pub fn filter_templates(&self) -> BTreeMap<String, FilterTemplate> {
+        self.filter_templates.read().templates.clone()
+    }
+
+    pub fn filter_template(&self, name: &str) -> Option<FilterTemplate> {
+        self.filter_templates.read().templates.get(name).cloned()
+    }
+
+    /// Validate a filter template before storing it.
+    ///
+    /// Besides producing a valid filter, all fields used in it must be indexed, so the template is
+    /// cheap to use regardless of strict mode settings.
+    pub fn check_filter_template(&self, template: &FilterTemplate) -> CollectionResult<()> {
+        let filter = template.validate()?;
+
+        if let Some((key, schemas)) = self.one_unindexed_key(&filter) {
+            let possible_schemas_str = schemas
+                .iter()
+                .map(|schema| schema.to_string())
+                .sorted()
+                .dedup()
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(CollectionError::bad_input(format!(
+                "Index required but not found for \"{key}\" of one of the following types: [{possible_schemas_str}]",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Build the filter of a stored template with the parameter values of the reference.
+    pub fn instantiate_filter_template(
+        &self,
+        reference: &FilterTemplateReference,
+    ) -> CollectionResult<Filter> {
+        let templates = self.filter_templates.read();
+        let Some(template) = templates.templates.get(&reference.name) else {
+            return Err(CollectionError::not_found(format!(
+                "Filter template {}",
+                reference.name,
+            )));
+        };
+        template.instantiate(&reference.params)
+    }
+
+    pub fn set_filter_template(
+        &self,
+        name: String,
+        template: FilterTemplate,
+    ) -> CollectionResult<()> {
+        self.filter_templates.write(|templates| {
+            templates.templates.insert(name, template);
+        })?;
+        Ok(())
+    }
+
+    pub fn delete_filter_template(&self, name: &str) -> CollectionResult<()> {
+        self.filter_templates.write(|templates| {
+            templates.templates.remove(name);
+        })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn template() -> FilterTemplate {
+        serde_json::from_value(json!({
+            "filter": {
+                "must": [
+                    { "key": "city", "match": { "value": "$city" } },
+                    { "key": "price", "range": { "lte": "$max_price" } },
+                ],
+            },
+            "params": {
+                "city": { "type": "keyword" },
+                "max_price": { "type": "float", "default": 100 },
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_instantiate_filter_template() {
+        let template = template();
+        template.validate().unwrap();
+
+        let params = BTreeMap::from([("city".to_string(), json!("Berlin"))]);
+        let filter = template.instantiate(&params).unwrap();
+        let expected: Filter = serde_json::from_value(json!({
+            "must": [
+                { "key": "city", "match": { "value": "Berlin" } },
+                { "key": "price", "range": { "lte": 100 } },
+            ],
+        }))
+        .unwrap();
+        assert_eq!(filter, expected);
+
+        // missing required parameter
+        assert!(template.instantiate(&BTreeMap::new()).is_err());
+
+        // wrong parameter type
+        let params = BTreeMap::from([("city".to_string(), json!(42))]);
+        assert!(template.instantiate(&params).is_err());
+
+        // unknown parameter
+        let params = BTreeMap::from([
+            ("city".to_string(), json!("Berlin")),
+            ("country".to_string(), json!("Germany")),
+        ]);
+        assert!(template.instantiate(&params).is_err());
+    }
+
+    #[test]
+    fn test_validate_filter_template() {
+        let mut unused_param = template();
+        unused_param.params.insert(
+            "country".to_string(),
+            FilterTemplateParam {
+                r#type: FilterTemplateParamType::Keyword,
+                default: None,
+            },
+        );
+        assert!(unused_param.validate().is_err());
+
+        // `city` is substituted into a range
+        let mut wrong_type = template();
+        wrong_type.filter = json!({ "must": { "key": "price", "range": { "gte": "$city" } } });
+        wrong_type.params.remove("max_price");
+        assert!(wrong_type.validate().is_err());
+
+        let mut wrong_default = template();
+        wrong_default.params.get_mut("max_price").unwrap().default = Some(json!("cheap"));
+        assert!(wrong_default.validate().is_err());
+    }
+}
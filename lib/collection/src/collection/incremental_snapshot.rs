@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::io::BufReader;
+use std::path::Path;
+
+use common::fs::read_json;
+use fs_err::File;
+use serde::{Deserialize, Serialize};
+use shard::snapshots::snapshot_manifest::SnapshotManifest;
+
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::shards::shard::ShardId;
+
+/// File name, used to store collection snapshot manifest inside collection snapshots
+pub const COLLECTION_SNAPSHOT_MANIFEST_FILE: &str = "snapshot_manifest.json";
+
+/// Content of a collection snapshot, used to create incremental snapshots on top of it.
+///
+/// Incremental snapshot only contains segment files, which were changed since its base snapshot,
+/// and the WAL of each shard. It is recovered by recovering the base snapshot first, and then
+/// applying the increment to each shard as a partial snapshot.
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct CollectionSnapshotManifest {
+    /// Name of the snapshot this snapshot is an increment of, `None` for full snapshots
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_snapshot: Option<String>,
+    /// Manifests of local shards, collected right before the snapshot was created
+    #[serde(default)]
+    pub shards: HashMap<ShardId, SnapshotManifest>,
+}
+
+impl CollectionSnapshotManifest {
+    pub fn is_incremental(&self) -> bool {
+        self.base_snapshot.is_some()
+    }
+
+    /// Load manifest from unpacked collection snapshot
+    ///
+    /// Snapshots created without a manifest are treated as full snapshots.
+    pub fn load(snapshot_dir: &Path) -> CollectionResult<Self> {
+        let manifest_path = snapshot_dir.join(COLLECTION_SNAPSHOT_MANIFEST_FILE);
+        if !manifest_path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(read_json(&manifest_path)?)
+    }
+
+    /// Read manifest from packed collection snapshot, without unpacking the whole archive
+    ///
+    /// This method performs blocking IO.
+    pub fn read_from_archive(snapshot_path: &Path) -> CollectionResult<Option<Self>> {
+        let mut archive = tar::Archive::new(BufReader::new(File::open(snapshot_path)?));
+
+        for entry in archive.entries_with_seek()? {
+            let entry = entry?;
+            if entry.path()?.as_ref() != Path::new(COLLECTION_SNAPSHOT_MANIFEST_FILE) {
+                continue;
+            }
+
+            let manifest = serde_json::from_reader(entry).map_err(|err| {
+                CollectionError::service_error(format!(
+                    "failed to read snapshot manifest from {}: {err}",
+                    snapshot_path.display(),
+                ))
+            })?;
+            return Ok(Some(manifest));
+        }
+
+        Ok(None)
+    }
+}
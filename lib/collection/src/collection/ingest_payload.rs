@@ -0,0 +1,122 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use segment::types::Payload;
+use serde_json::{Map, Value};
+use shard::operations::point_ops::{PointInsertOperationsInternal, PointOperations};
+
+use super::Collection;
+use crate::config::{ComputedPayloadValue, IngestPayloadConfig};
+use crate::operations::CollectionUpdateOperations;
+
+impl Collection {
+    /// Set default and computed payload fields of upserted points, if configured for the
+    /// collection.
+    pub(super) async fn apply_ingest_payload(&self, operation: &mut CollectionUpdateOperations) {
+        let Some(config) = self
+            .collection_config
+            .read()
+            .await
+            .params
+            .ingest_payload
+            .clone()
+        else {
+            return;
+        };
+
+        let points_op = match operation {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(op)) => op,
+            CollectionUpdateOperations::PointOperation(
+                PointOperations::UpsertPointsConditional(op),
+            ) => &mut op.points_op,
+            _ => return,
+        };
+
+        // All points of the operation get the same computed values
+        let computed = config.compute(Utc::now());
+
+        match points_op {
+            PointInsertOperationsInternal::PointsBatch(batch) => {
+                let payloads = batch
+                    .payloads
+                    .get_or_insert_with(|| vec![None; batch.ids.len()]);
+                for payload in payloads {
+                    config.apply(payload.get_or_insert_with(Payload::default), &computed);
+                }
+            }
+            PointInsertOperationsInternal::PointsList(points) => {
+                for point in points {
+                    let payload = point.payload.get_or_insert_with(Payload::default);
+                    config.apply(payload, &computed);
+                }
+            }
+        }
+    }
+}
+
+impl IngestPayloadConfig {
+    fn compute(&self, now: DateTime<Utc>) -> Map<String, Value> {
+        self.computed
+            .iter()
+            .map(|(key, value)| (key.clone(), value.compute(now)))
+            .collect()
+    }
+
+    fn apply(&self, payload: &mut Payload, computed: &Map<String, Value>) {
+        for (key, value) in &self.defaults.0 {
+            if !payload.0.contains_key(key) {
+                payload.0.insert(key.clone(), value.clone());
+            }
+        }
+        for (key, value) in computed {
+            payload.0.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+impl ComputedPayloadValue {
+    fn compute(self, now: DateTime<Utc>) -> Value {
+        match self {
+            ComputedPayloadValue::Now => {
+                Value::String(now.to_rfc3339_opts(SecondsFormat::Micros, true))
+            }
+            ComputedPayloadValue::NowUnix => Value::from(now.timestamp()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use segment::payload_json;
+
+    use super::*;
+
+    #[test]
+    fn test_apply_ingest_payload() {
+        let config = IngestPayloadConfig {
+            defaults: payload_json! {"source": "api", "priority": 0},
+            computed: BTreeMap::from([
+                ("ingested_at".to_string(), ComputedPayloadValue::Now),
+                (
+                    "ingested_at_unix".to_string(),
+                    ComputedPayloadValue::NowUnix,
+                ),
+            ]),
+        };
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let computed = config.compute(now);
+
+        let mut payload = payload_json! {"priority": 5, "ingested_at": "yesterday"};
+        config.apply(&mut payload, &computed);
+
+        assert_eq!(
+            payload,
+            payload_json! {
+                "priority": 5,
+                "source": "api",
+                "ingested_at": "2023-11-14T22:13:20.000000Z",
+                "ingested_at_unix": 1_700_000_000,
+            },
+        );
+    }
+}
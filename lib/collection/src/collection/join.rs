@@ -0,0 +1,407 @@
+use std::time::Duration;
+
+use api::rest::{JoinNeighbor, JoinRequestInternal, JoinResponse, JoinedPoint};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::types::ScoreType;
+use futures::future::try_join_all;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorStructInternal};
+use segment::types::{
+    Condition, Filter, HasVectorCondition, Payload, PointIdType, ScoredPoint, SearchParams,
+    ShardKey, VectorNameBuf, WithPayloadInterface, WithVector,
+};
+use serde_json::{Map, Value, json};
+use shard::query::query_enum::QueryEnum;
+use shard::scroll::ScrollRequestInternal;
+use shard::search::{CoreSearchRequest, CoreSearchRequestBatch};
+
+use crate::collection::Collection;
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::payload_ops::{PayloadOps, SetPayloadOp};
+use crate::operations::point_ops::{VectorStructPersisted, WriteOrdering};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionResult, ScrollResult};
+
+/// Number of points, which neighbors are searched for in a single batch
+const JOIN_BATCH_SIZE: usize = 100;
+
+/// Internal representation of the join request, used to convert from REST.
+pub struct CollectionJoinRequest {
+    pub target_collection: String,
+    pub using: VectorNameBuf,
+    pub target_using: VectorNameBuf,
+    pub filter: Option<Filter>,
+    pub target_filter: Option<Filter>,
+    pub limit: usize,
+    pub params: Option<SearchParams>,
+    pub score_threshold: Option<ScoreType>,
+    pub offset: Option<PointIdType>,
+    pub points_limit: usize,
+    pub payload_key: Option<String>,
+}
+
+impl CollectionJoinRequest {
+    pub const DEFAULT_LIMIT: usize = 10;
+    pub const DEFAULT_POINTS_LIMIT: usize = 1000;
+}
+
+impl From<JoinRequestInternal> for CollectionJoinRequest {
+    fn from(request: JoinRequestInternal) -> Self {
+        let JoinRequestInternal {
+            target_collection,
+            using,
+            target_using,
+            filter,
+            target_filter,
+            limit,
+            params,
+            score_threshold,
+            offset,
+            points_limit,
+            payload_key,
+        } = request;
+        let using = using.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned());
+        Self {
+            target_collection,
+            target_using: target_using.unwrap_or_else(|| using.clone()),
+            using,
+            filter,
+            target_filter,
+            limit: limit.unwrap_or(CollectionJoinRequest::DEFAULT_LIMIT),
+            params,
+            score_threshold,
+            offset,
+            points_limit: points_limit.unwrap_or(CollectionJoinRequest::DEFAULT_POINTS_LIMIT),
+            payload_key,
+        }
+    }
+}
+
+/// Point with its nearest neighbors in the target collection
+type JoinedNeighbors = (PointIdType, Vec<ScoredPoint>);
+
+#[derive(Debug, Default)]
+pub struct CollectionJoinResponse {
+    pub joined: usize,
+    pub points: Vec<JoinedNeighbors>,
+    /// First point, which is not joined, if `points_limit` was reached or the join was interrupted
+    /// by an error
+    pub next_page_offset: Option<PointIdType>,
+    pub error: Option<String>,
+}
+
+impl From<CollectionJoinResponse> for JoinResponse {
+    fn from(response: CollectionJoinResponse) -> Self {
+        let CollectionJoinResponse {
+            joined,
+            points,
+            next_page_offset,
+            error,
+        } = response;
+
+        let points = points
+            .into_iter()
+            .map(|(id, neighbors)| JoinedPoint {
+                id,
+                neighbors: neighbors
+                    .into_iter()
+                    .map(|neighbor| JoinNeighbor {
+                        id: neighbor.id,
+                        score: neighbor.score,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            joined,
+            points,
+            next_page_offset,
+            error,
+        }
+    }
+}
+
+/// Parameters of the join, shared by all batches
+struct JoinBatch<'a> {
+    target: &'a Collection,
+    using: VectorNameBuf,
+    target_using: VectorNameBuf,
+    filter: Filter,
+    target_filter: Option<Filter>,
+    limit: usize,
+    params: Option<SearchParams>,
+    score_threshold: Option<ScoreType>,
+    payload_key: Option<String>,
+}
+
+impl Collection {
+    /// Find nearest neighbors in the `target` collection for each point of this collection.
+    ///
+    /// Points are scrolled in batches, vectors of each batch are searched in the target with a
+    /// single batch request. Neighbors are either returned, or written into the payload of the
+    /// points, if `payload_key` is set.
+    ///
+    /// At most `points_limit` points are joined per request, the response contains the offset to
+    /// continue the join from. If the join fails after some points were joined, the response
+    /// contains the error, and the offset of the failed batch. Errors before any point is joined
+    /// are returned as is.
+    pub async fn join(
+        &self,
+        target: &Collection,
+        request: CollectionJoinRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<CollectionJoinResponse> {
+        let CollectionJoinRequest {
+            target_collection: _,
+            using,
+            target_using,
+            filter,
+            target_filter,
+            limit,
+            params,
+            score_threshold,
+            mut offset,
+            points_limit,
+            payload_key,
+        } = request;
+
+        self.collection_config
+            .read()
+            .await
+            .params
+            .check_vector_exists(&using)?;
+        target
+            .collection_config
+            .read()
+            .await
+            .params
+            .check_vector_exists(&target_using)?;
+
+        // make sure the vector is present in the point
+        let has_vector = Filter::new_must(Condition::HasVector(HasVectorCondition::from(
+            using.clone(),
+        )));
+
+        let filter = filter
+            .map(|filter| filter.merge(&has_vector))
+            .unwrap_or(has_vector);
+
+        let batch = JoinBatch {
+            target,
+            using,
+            target_using,
+            filter,
+            target_filter,
+            limit,
+            params,
+            score_threshold,
+            payload_key,
+        };
+
+        let mut response = CollectionJoinResponse::default();
+
+        loop {
+            let batch_size = JOIN_BATCH_SIZE.min(points_limit - response.joined);
+
+            let batch_result = self
+                .join_batch(
+                    &batch,
+                    offset,
+                    batch_size,
+                    &shard_selection,
+                    read_consistency,
+                    timeout,
+                    &hw_measurement_acc,
+                )
+                .await;
+
+            let (joined, points, next_page_offset) = match batch_result {
+                Ok(result) => result,
+                Err(err) if response.joined > 0 => {
+                    response.next_page_offset = offset;
+                    response.error = Some(err.to_string());
+                    return Ok(response);
+                }
+                Err(err) => return Err(err),
+            };
+
+            response.joined += joined;
+            response.points.extend(points);
+
+            offset = next_page_offset;
+            if offset.is_none() || response.joined >= points_limit {
+                response.next_page_offset = offset;
+                break;
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Join a single batch of points, starting from `offset`.
+    ///
+    /// Returns the number of joined points, their neighbors unless they were written into the
+    /// payload, and the offset of the next batch.
+    #[allow(clippy::too_many_arguments)]
+    async fn join_batch(
+        &self,
+        batch: &JoinBatch<'_>,
+        offset: Option<PointIdType>,
+        batch_size: usize,
+        shard_selection: &ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<(usize, Vec<JoinedNeighbors>, Option<PointIdType>)> {
+        let JoinBatch {
+            target,
+            using,
+            target_using,
+            filter,
+            target_filter,
+            limit,
+            params,
+            score_threshold,
+            payload_key,
+        } = batch;
+
+        let scroll_request = ScrollRequestInternal {
+            offset,
+            limit: Some(batch_size),
+            filter: Some(filter.clone()),
+            with_payload: Some(WithPayloadInterface::Bool(false)),
+            with_vector: WithVector::Selector(vec![using.clone()]),
+            order_by: None,
+            distinct_by: None,
+            with_version: None,
+        };
+
+        let ScrollResult {
+            points,
+            next_page_offset,
+        } = self
+            .scroll_by(
+                scroll_request,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+
+        let joined = points.len();
+
+        // in a self-join points are found among their own neighbors, skip them
+        let is_self_join = self.name() == target.name();
+        let search_limit = if is_self_join { limit + 1 } else { *limit };
+
+        let mut joined_points = Vec::with_capacity(points.len());
+        let mut searches = Vec::with_capacity(points.len());
+        for point in points {
+            let vector = point
+                .vector
+                .map(VectorStructPersisted::from)
+                .map(VectorStructInternal::try_from)
+                .transpose()?;
+            let Some(vector) = vector.as_ref().and_then(|vector| vector.get(using)) else {
+                continue;
+            };
+
+            joined_points.push((point.id, point.shard_key));
+            searches.push(CoreSearchRequest {
+                query: QueryEnum::Nearest(NamedQuery::new(vector.to_owned(), target_using.clone())),
+                filter: target_filter.clone(),
+                params: *params,
+                limit: search_limit,
+                offset: 0,
+                with_payload: None,
+                with_vector: None,
+                score_threshold: *score_threshold,
+            });
+        }
+
+        if searches.is_empty() {
+            return Ok((joined, Vec::new(), next_page_offset));
+        }
+
+        let neighbors = target
+            .core_search_batch(
+                CoreSearchRequestBatch { searches },
+                read_consistency,
+                ShardSelectorInternal::All,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+
+        let joined_points =
+            joined_points
+                .into_iter()
+                .zip(neighbors)
+                .map(|((id, shard_key), mut neighbors)| {
+                    if is_self_join {
+                        neighbors.retain(|neighbor| neighbor.id != id);
+                    }
+                    neighbors.truncate(*limit);
+                    (id, shard_key, neighbors)
+                });
+
+        let points = match payload_key {
+            Some(payload_key) => {
+                self.write_join_neighbors(payload_key, joined_points, hw_measurement_acc)
+                    .await?;
+                Vec::new()
+            }
+            None => joined_points
+                .map(|(id, _shard_key, neighbors)| (id, neighbors))
+                .collect(),
+        };
+
+        Ok((joined, points, next_page_offset))
+    }
+
+    /// Write neighbors of each point into its payload, as a list of ids with scores.
+    async fn write_join_neighbors(
+        &self,
+        payload_key: &str,
+        joined_points: impl Iterator<Item = (PointIdType, Option<ShardKey>, Vec<ScoredPoint>)>,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<()> {
+        let updates = joined_points.map(|(id, shard_key, neighbors)| {
+            let neighbors = neighbors
+                .into_iter()
+                .map(|neighbor| json!({ "id": neighbor.id, "score": neighbor.score }))
+                .collect();
+            let payload = Payload::from(Map::from_iter([(
+                payload_key.to_owned(),
+                Value::Array(neighbors),
+            )]));
+
+            let operation = CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(
+                SetPayloadOp {
+                    payload,
+                    points: Some(vec![id]),
+                    filter: None,
+                    key: None,
+                },
+            ));
+
+            self.update_from_client(
+                operation,
+                true,
+                None,
+                WriteOrdering::default(),
+                shard_key,
+                hw_measurement_acc.clone(),
+            )
+        });
+
+        try_join_all(updates).await?;
+
+        Ok(())
+    }
+}
@@ -1,23 +1,38 @@
+mod aggregation;
 mod clean;
 mod collection_ops;
+pub mod copy;
 pub mod distance_matrix;
+mod duplicates;
+mod expiration;
 mod facet;
+pub mod filter_templates;
+pub mod incremental_snapshot;
+mod ingest_payload;
+pub mod join;
 pub mod mmr;
+pub mod partial_results;
 pub mod payload_index_schema;
 mod point_ops;
 pub mod query;
+pub mod rate_limiting;
 mod resharding;
+pub mod sample_projection;
 mod search;
+pub mod search_explanations;
 mod shard_transfer;
 mod sharding_keys;
 mod snapshots;
 mod state_management;
+mod strict_payload_schema;
 mod telemetry;
+pub mod trash;
 
 use std::collections::HashMap;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
 use std::time::Duration;
 
 use clean::ShardCleanTasks;
@@ -30,12 +45,15 @@ use tokio::runtime::Handle;
 use tokio::sync::{Mutex, RwLock};
 
 use crate::collection::collection_ops::ABORT_TRANSFERS_ON_SHARD_DROP_FIX_FROM_VERSION;
+use crate::collection::filter_templates::FilterTemplates;
 use crate::collection::payload_index_schema::PayloadIndexSchema;
+use crate::collection::rate_limiting::OperationRateLimiters;
 use crate::collection_state::{ShardInfo, State};
 use crate::common::collection_size_stats::{
     CollectionSizeAtomicStats, CollectionSizeStats, CollectionSizeStatsCache,
 };
 use crate::common::is_ready::IsReady;
+use crate::common::referenced_vectors_cache::ReferencedVectorsCache;
 use crate::config::{CollectionConfigInternal, ShardingMethod};
 use crate::operations::OperationWithClockTag;
 use crate::operations::config_diff::{DiffConfig, OptimizersConfigDiff};
@@ -69,6 +87,7 @@ pub struct Collection {
     pub(crate) collection_config: Arc<RwLock<CollectionConfigInternal>>,
     pub(crate) shared_storage_config: Arc<SharedStorageConfig>,
     payload_index_schema: Arc<SaveOnDisk<PayloadIndexSchema>>,
+    filter_templates: SaveOnDisk<FilterTemplates>,
     optimizers_overwrite: Option<OptimizersConfigDiff>,
     this_peer_id: PeerId,
     path: PathBuf,
@@ -91,6 +110,14 @@ pub struct Collection {
     collection_stats_cache: CollectionSizeStatsCache,
     // Background tasks to clean shards
     shard_clean_tasks: ShardCleanTasks,
+    // Number of upserted points detected as duplicates of existing points
+    duplicates_detected: AtomicUsize,
+    // Vectors of recently referenced points, e.g. recommend examples
+    referenced_vectors_cache: ReferencedVectorsCache,
+    // Strict mode rate limiters of client operations
+    operation_rate_limiters: OperationRateLimiters,
+    // Error of the last resharding to a target number of shards, driven by this peer
+    resharding_error: parking_lot::Mutex<Option<String>>,
 }
 
 pub type RequestShardTransfer = Arc<dyn Fn(ShardTransfer) + Send + Sync>;
@@ -125,6 +152,7 @@ impl Collection {
         shard_holder.set_shard_key_mappings(shard_key_mapping.clone().unwrap_or_default())?;
 
         let payload_index_schema = Arc::new(Self::load_payload_index_schema(path)?);
+        let filter_templates = Self::load_filter_templates(path)?;
 
         let shared_collection_config = Arc::new(RwLock::new(collection_config.clone()));
         for (shard_id, mut peers) in shard_distribution.shards {
@@ -182,6 +210,7 @@ impl Collection {
             collection_config: shared_collection_config,
             optimizers_overwrite,
             payload_index_schema,
+            filter_templates,
             shared_storage_config,
             this_peer_id,
             path: path.to_owned(),
@@ -198,6 +227,10 @@ impl Collection {
             optimizer_resource_budget,
             collection_stats_cache,
             shard_clean_tasks: Default::default(),
+            duplicates_detected: AtomicUsize::new(0),
+            referenced_vectors_cache: Default::default(),
+            operation_rate_limiters: Default::default(),
+            resharding_error: Default::default(),
         })
     }
 
@@ -267,6 +300,9 @@ impl Collection {
                 .expect("Can't load or initialize payload index schema"),
         );
 
+        let filter_templates =
+            Self::load_filter_templates(path).expect("Can't load or initialize filter templates");
+
         shard_holder
             .load_shards(
                 path,
@@ -299,6 +335,7 @@ impl Collection {
             collection_config: shared_collection_config,
             optimizers_overwrite,
             payload_index_schema,
+            filter_templates,
             shared_storage_config,
             this_peer_id,
             path: path.to_owned(),
@@ -315,6 +352,10 @@ impl Collection {
             optimizer_resource_budget,
             collection_stats_cache,
             shard_clean_tasks: Default::default(),
+            duplicates_detected: AtomicUsize::new(0),
+            referenced_vectors_cache: Default::default(),
+            operation_rate_limiters: Default::default(),
+            resharding_error: Default::default(),
         }
     }
 
@@ -544,6 +585,20 @@ impl Collection {
         replica_set.shard_recovery_point().await
     }
 
+    pub async fn shard_applied_recovery_point(
+        &self,
+        shard_id: ShardId,
+    ) -> CollectionResult<RecoveryPoint> {
+        let shard_holder_read = self.shards_holder.read().await;
+
+        let shard = shard_holder_read.get_shard(shard_id);
+        let replica_set = shard.ok_or_else(|| CollectionError::NotFound {
+            what: format!("Shard {shard_id}"),
+        })?;
+
+        replica_set.shard_applied_recovery_point().await
+    }
+
     pub async fn update_shard_cutoff_point(
         &self,
         shard_id: ShardId,
@@ -612,6 +667,7 @@ impl Collection {
             transfers,
             shards_key_mapping: shards_holder.get_shard_key_to_ids_mapping(),
             payload_index_schema: self.payload_index_schema.read().clone(),
+            filter_templates: self.filter_templates.read().clone(),
         }
     }
 
@@ -895,6 +951,10 @@ impl Collection {
         self.shards_holder.clone()
     }
 
+    pub(crate) fn referenced_vectors_cache(&self) -> &ReferencedVectorsCache {
+        &self.referenced_vectors_cache
+    }
+
     pub async fn trigger_optimizers(&self) {
         self.shards_holder.read().await.trigger_optimizers().await;
     }
@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use parking_lot::Mutex;
+
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::shards::shard::ShardId;
+
+/// Shards skipped while serving a request, which allows partial results.
+///
+/// Shards failing with a transient error, e.g. because none of their replicas is available, are
+/// skipped and results of the other shards are returned instead.
+#[derive(Debug, Default)]
+pub struct SkippedShards {
+    shards: Mutex<BTreeMap<ShardId, String>>,
+}
+
+impl SkippedShards {
+    /// Skip the shard which failed with `err`, if the error is transient.
+    pub(crate) fn skip(&self, shard_id: ShardId, err: CollectionError) -> CollectionResult<()> {
+        if !err.is_transient() {
+            return Err(err);
+        }
+        log::debug!("Skipping shard {shard_id} for partial results: {err}");
+        self.shards.lock().insert(shard_id, err.to_string());
+        Ok(())
+    }
+
+    /// Skipped shards for the REST response, `None` if no shards were skipped
+    pub fn to_rest_api(&self) -> Option<Vec<api::rest::SkippedShard>> {
+        let shards = self.shards.lock();
+        if shards.is_empty() {
+            return None;
+        }
+        let skipped = shards
+            .iter()
+            .map(|(shard_id, error)| api::rest::SkippedShard {
+                shard_id: *shard_id,
+                error: error.clone(),
+            })
+            .collect();
+        Some(skipped)
+    }
+
+    /// Skipped shards for the gRPC response
+    pub fn to_grpc_api(&self) -> Vec<api::grpc::qdrant::SkippedShard> {
+        self.shards
+            .lock()
+            .iter()
+            .map(|(shard_id, error)| api::grpc::qdrant::SkippedShard {
+                shard_id: *shard_id,
+                error: error.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Whether segment searches of a request, which allows partial results, were stopped at the
+/// deadline.
+///
+/// Stopped segments are skipped and results of the other segments are returned instead.
+#[derive(Debug, Clone, Default)]
+pub struct TimedOut(Arc<AtomicBool>);
+
+impl TimedOut {
+    pub(crate) fn mark(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skipped_shards() {
+        let skipped = SkippedShards::default();
+        assert!(skipped.to_rest_api().is_none());
+
+        skipped
+            .skip(2, CollectionError::shard_unavailable("no active replica"))
+            .unwrap();
+        skipped
+            .skip(1, CollectionError::service_error("connection refused"))
+            .unwrap();
+
+        // Client errors are never skipped
+        let err = skipped
+            .skip(3, CollectionError::bad_input("invalid filter"))
+            .unwrap_err();
+        assert!(matches!(err, CollectionError::BadInput { .. }));
+
+        let shards: Vec<_> = skipped
+            .to_grpc_api()
+            .into_iter()
+            .map(|shard| shard.shard_id)
+            .collect();
+        assert_eq!(shards, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_timed_out() {
+        let timed_out = TimedOut::default();
+        assert!(!timed_out.is_timed_out());
+
+        // Clones share the flag, e.g. when passed to spawned shard searches
+        timed_out.clone().mark();
+        assert!(timed_out.is_timed_out());
+    }
+}
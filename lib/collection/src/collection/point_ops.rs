@@ -6,8 +6,10 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::stream::FuturesUnordered;
 use futures::{StreamExt as _, TryFutureExt, TryStreamExt as _, future};
 use itertools::Itertools;
+use segment::data_types::facets::FacetParams;
 use segment::data_types::order_by::{Direction, OrderBy};
-use segment::types::{ShardKey, WithPayload, WithPayloadInterface};
+use segment::json_path::JsonPath;
+use segment::types::{Condition, Filter, ShardKey, WithPayload, WithPayloadInterface, WithVector};
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
 use shard::scroll::ScrollRequestInternal;
@@ -34,6 +36,7 @@ impl Collection {
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Option<UpdateResult>> {
         let shard_holder = self.shards_holder.clone().read_owned().await;
+        let point_ids = operation.point_ids();
 
         let results = self
             .update_runtime
@@ -66,7 +69,10 @@ impl Collection {
 
                 results
             })
-            .await?;
+            .await;
+
+        self.referenced_vectors_cache.invalidate(point_ids);
+        let results = results?;
 
         let mut result = None;
 
@@ -98,6 +104,7 @@ impl Collection {
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<UpdateResult> {
         let shard_holder = self.shards_holder.clone().read_owned().await;
+        let point_ids = operation.operation.point_ids();
 
         let result = self.update_runtime.spawn(async move {
             let Some(shard) = shard_holder.get_shard(shard_selection) else {
@@ -122,7 +129,10 @@ impl Collection {
                 }
             }
         })
-        .await??;
+        .await;
+
+        self.referenced_vectors_cache.invalidate(point_ids);
+        let result = result??;
 
         if let Some(result) = result {
             Ok(result)
@@ -147,16 +157,79 @@ impl Collection {
         shard_keys_selection: Option<ShardKey>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<UpdateResult> {
+        self.update_from_client_by_shard_keys(
+            vec![(shard_keys_selection, operation)],
+            wait,
+            timeout,
+            ordering,
+            hw_measurement_acc,
+        )
+        .await
+    }
+
+    /// Apply operations targeting different shard keys as a single update.
+    ///
+    /// Operations are split by shard together, so that every shard receives a single operation
+    /// with all of its points. Shard keys must be unique.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe.
+    pub async fn update_from_client_by_shard_keys(
+        &self,
+        mut operations: Vec<(Option<ShardKey>, CollectionUpdateOperations)>,
+        wait: bool,
+        timeout: Option<Duration>,
+        ordering: WriteOrdering,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<UpdateResult> {
+        self.check_read_only().await?;
+
+        if !operations
+            .iter()
+            .map(|(shard_key, _)| shard_key)
+            .all_unique()
+        {
+            return Err(CollectionError::bad_input(
+                "Shard keys of update operations must be unique",
+            ));
+        }
+
+        for (shard_key, operation) in &mut operations {
+            self.enforce_payload_schema(operation).await?;
+
+            self.apply_ingest_payload(operation).await;
+
+            self.detect_duplicates(operation, shard_key, hw_measurement_acc.clone())
+                .await?;
+
+            if let Some(shard_key) = shard_key {
+                self.check_shard_key_points_limit(operation, shard_key, hw_measurement_acc.clone())
+                    .await?;
+            }
+
+            self.move_to_trash(operation).await;
+        }
+
         let shard_holder = self.shards_holder.clone().read_owned().await;
         let start_time = std::time::Instant::now();
+        let point_ids = operations
+            .iter()
+            .map(|(_, operation)| operation.point_ids())
+            .collect::<Option<Vec<_>>>()
+            .map(|point_ids| point_ids.concat());
 
         let results = self
             .update_runtime
             .spawn(async move {
                 let updates = FuturesUnordered::new();
-                let operations = shard_holder.split_by_shard(operation, &shard_keys_selection)?;
 
-                for (shard, operation) in operations {
+                let mut shard_operations = Vec::new();
+                for (shard_key, operation) in operations {
+                    shard_operations.extend(shard_holder.split_by_shard(operation, &shard_key)?);
+                }
+
+                for (shard, operation) in shard_operations {
                     let operation = shard_holder.split_by_mode(shard.shard_id, operation);
 
                     let hw_acc = hw_measurement_acc.clone();
@@ -164,8 +237,11 @@ impl Collection {
                         let mut result = UpdateResult {
                             operation_id: None,
                             status: UpdateStatus::Acknowledged,
+                            affected_points: None,
                             clock_tag: None,
                         };
+                        // Sum of points affected by each operation, if all of them are known
+                        let mut affected_points = Some(0);
 
                         for operation in operation.update_all {
                             result = shard
@@ -178,6 +254,9 @@ impl Collection {
                                     hw_acc.clone(),
                                 )
                                 .await?;
+                            affected_points = affected_points
+                                .zip(result.affected_points)
+                                .map(|(total, affected)| total + affected);
                         }
 
                         for operation in operation.update_only_existing {
@@ -199,8 +278,12 @@ impl Collection {
                             }
 
                             result = res?;
+                            affected_points = affected_points
+                                .zip(result.affected_points)
+                                .map(|(total, affected)| total + affected);
                         }
 
+                        result.affected_points = affected_points;
                         CollectionResult::Ok(result)
                     });
                 }
@@ -209,7 +292,11 @@ impl Collection {
 
                 CollectionResult::Ok(results)
             })
-            .await??;
+            .await;
+
+        // Invalidate after the update, so that vectors retrieved in the meantime are not cached
+        self.referenced_vectors_cache.invalidate(point_ids);
+        let results = results??;
 
         if results.is_empty() {
             return Err(CollectionError::bad_request(
@@ -271,11 +358,14 @@ impl Collection {
                 });
             }
 
+            let affected_points = results.iter().map(|r| r.affected_points).sum();
+
             let max_operation_id = results.into_iter().map(|r| r.operation_id).max().unwrap(); // We checked that results is not empty above
 
             Ok(UpdateResult {
                 operation_id: max_operation_id,
                 status,
+                affected_points,
                 clock_tag: None, // clock_tag is not used in the user response
             })
         }
@@ -316,6 +406,24 @@ impl Collection {
             });
         }
 
+        if let Some(distinct_by) = request.distinct_by.take() {
+            if request.order_by.is_some() {
+                return Err(CollectionError::bad_request(
+                    "Cannot use `distinct_by` together with `order_by`",
+                ));
+            }
+            request.limit = Some(limit);
+            return Box::pin(self.scroll_distinct(
+                request,
+                distinct_by,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc,
+            ))
+            .await;
+        }
+
         let local_only = shard_selection.is_shard_id();
 
         let order_by = request.order_by.clone().map(OrderBy::from);
@@ -396,6 +504,115 @@ impl Collection {
         })
     }
 
+    /// Scroll only the first point for each unique value of the `distinct_by` key.
+    ///
+    /// Values of the key are read from its payload index, and the first point of each value is
+    /// looked up by the index as well. Selected points are paginated in the order of their IDs,
+    /// so the next page offset is enough to resume the scroll, without values being repeated.
+    async fn scroll_distinct(
+        &self,
+        request: ScrollRequestInternal,
+        distinct_by: JsonPath,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<ScrollResult> {
+        let ScrollRequestInternal {
+            offset,
+            limit,
+            filter,
+            with_payload,
+            with_vector,
+            order_by: _,
+            distinct_by: _,
+            with_version,
+        } = request;
+
+        let limit = limit.unwrap_or(ScrollRequestInternal::default_limit());
+
+        let values_request = FacetParams {
+            key: distinct_by,
+            limit: usize::MAX,
+            filter,
+            exact: false,
+            histogram: None,
+        };
+
+        let values = self
+            .facet(
+                values_request.clone(),
+                shard_selection.clone(),
+                read_consistency,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?
+            .hits;
+
+        let first_points = values.into_iter().map(|hit| {
+            let value_filter =
+                Filter::new_must(Condition::Field(values_request.value_condition(hit.value)));
+            let first_point_request = ScrollRequestInternal {
+                offset: None,
+                limit: Some(1),
+                filter: Filter::merge_opts(values_request.filter.clone(), Some(value_filter)),
+                with_payload: Some(WithPayloadInterface::Bool(false)),
+                with_vector: WithVector::Bool(false),
+                order_by: None,
+                distinct_by: None,
+                with_version: None,
+            };
+
+            self.scroll_by(
+                first_point_request,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .map_ok(|result| result.points.into_iter().next().map(|point| point.id))
+        });
+
+        // Take one more point, to return next page offset
+        let mut ids = future::try_join_all(first_points)
+            .await?
+            .into_iter()
+            .flatten()
+            .filter(|id| offset.is_none_or(|offset| *id >= offset))
+            .sorted_unstable()
+            .dedup()
+            .take(limit + 1)
+            .collect_vec();
+
+        let next_page_offset = if ids.len() > limit { ids.pop() } else { None };
+
+        let points_request = PointRequestInternal {
+            ids,
+            with_payload: with_payload.or(Some(ScrollRequestInternal::default_with_payload())),
+            with_vector,
+            with_version,
+        };
+
+        let points = self
+            .retrieve(
+                points_request,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await?
+            .into_iter()
+            .map(api::rest::Record::from)
+            .collect();
+
+        Ok(ScrollResult {
+            points,
+            next_page_offset,
+        })
+    }
+
     pub async fn count(
         &self,
         request: CountRequestInternal,
@@ -1,20 +1,25 @@
+use std::cmp;
 use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use futures::{TryFutureExt, future};
+use futures::future;
 use itertools::{Either, Itertools};
 use rand::Rng;
 use segment::common::reciprocal_rank_fusion::rrf_scoring;
 use segment::common::score_fusion::{ScoreFusion, score_fusion};
+use segment::data_types::query_context::SearchExplanations;
 use segment::data_types::vectors::VectorStructInternal;
 use segment::types::{Order, ScoredPoint, WithPayloadInterface, WithVector};
 use segment::utils::scored_point_ties::ScoredPointTies;
+use shard::query::score_gap_cutoff::truncate_at_score_gap;
 use tokio::time::Instant;
 
 use super::Collection;
 use crate::collection::mmr::mmr_from_points_with_vector;
+use crate::collection::partial_results::{SkippedShards, TimedOut};
+use crate::collection::search_explanations::ExplainedShards;
 use crate::collection_manager::probabilistic_search_sampling::find_search_sampling_over_point_distribution;
 use crate::common::batching::batch_requests;
 use crate::common::fetch_vectors::{
@@ -59,6 +64,9 @@ impl Collection {
                 read_consistency,
                 shard_selection,
                 timeout,
+                None,
+                None,
+                None,
                 hw_measurement_acc,
             )
             .await?;
@@ -143,12 +151,23 @@ impl Collection {
     }
 
     /// Returns a shape of [shard_id, batch_id, intermediate_response, points]
+    ///
+    /// If `skipped_shards` is given, failed shards are skipped and recorded there, unless all of
+    /// the shards failed.
+    ///
+    /// If `explained` is given, explanations of the searches are recorded there for each shard.
+    ///
+    /// If `timed_out` is given, searches stopped at the deadline are marked as timed out there.
+    #[allow(clippy::too_many_arguments)]
     async fn batch_query_shards_concurrently(
         &self,
         batch_request: Arc<Vec<ShardQueryRequest>>,
         read_consistency: Option<ReadConsistency>,
         shard_selection: &ShardSelectorInternal,
         timeout: Option<Duration>,
+        skipped_shards: Option<&SkippedShards>,
+        explained: Option<&ExplainedShards>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<Vec<ShardQueryResponse>>> {
         // query all shards concurrently
@@ -174,37 +193,74 @@ impl Collection {
         let all_searches = target_shards.iter().map(|(shard, shard_key)| {
             let shard_key = shard_key.cloned();
             let request_clone = Arc::clone(&batch_request);
-            shard
-                .query_batch(
-                    request_clone,
-                    read_consistency,
-                    shard_selection.is_shard_id(),
-                    timeout,
-                    hw_measurement_acc.clone(),
-                )
-                .and_then(move |mut shard_responses| async move {
-                    if shard_key.is_none() {
-                        return Ok(shard_responses);
-                    }
+            let hw_measurement_acc = hw_measurement_acc.clone();
+            async move {
+                let explanations = explained.map(|_| SearchExplanations::default());
+                let mut shard_responses = shard
+                    .query_batch(
+                        request_clone,
+                        read_consistency,
+                        shard_selection.is_shard_id(),
+                        timeout,
+                        explanations.as_ref(),
+                        timed_out,
+                        hw_measurement_acc,
+                    )
+                    .await?;
+
+                if let (Some(explained), Some(explanations)) = (explained, explanations) {
+                    explained.record(shard.shard_id, explanations.take());
+                }
+
+                if shard_key.is_some() {
                     shard_responses
                         .iter_mut()
                         .flatten()
                         .flatten()
                         .for_each(|point| point.shard_key.clone_from(&shard_key));
+                }
 
-                    Ok(shard_responses)
-                })
+                CollectionResult::Ok(shard_responses)
+            }
         });
-        future::try_join_all(all_searches).await
+
+        let Some(skipped_shards) = skipped_shards else {
+            return future::try_join_all(all_searches).await;
+        };
+
+        let results = future::join_all(all_searches).await;
+        let (responses, errors): (Vec<_>, Vec<_>) = target_shards
+            .iter()
+            .zip(results)
+            .partition_map(|((shard, _), result)| match result {
+                Ok(response) => Either::Left(response),
+                Err(err) => Either::Right((shard.shard_id, err)),
+            });
+
+        if responses.is_empty()
+            && let Some((_, err)) = errors.into_iter().next()
+        {
+            return Err(err);
+        }
+
+        for (shard_id, err) in errors {
+            skipped_shards.skip(shard_id, err)?;
+        }
+
+        Ok(responses)
     }
 
     /// This function is used to query the collection. It will return a list of scored points.
+    #[allow(clippy::too_many_arguments)]
     async fn do_query_batch(
         &self,
         requests_batch: Vec<ShardQueryRequest>,
         read_consistency: Option<ReadConsistency>,
         shard_selection: ShardSelectorInternal,
         timeout: Option<Duration>,
+        skipped_shards: Option<&SkippedShards>,
+        explained: Option<&ExplainedShards>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
         let start = Instant::now();
@@ -239,7 +295,8 @@ impl Collection {
         let is_required_transfer_large_enough =
             require_transfers > used_transfers.saturating_mul(PAYLOAD_TRANSFERS_FACTOR_THRESHOLD);
 
-        if metadata_required && is_required_transfer_large_enough {
+        // Payload can't be retrieved in a separate step, if some shards may be skipped
+        if metadata_required && is_required_transfer_large_enough && skipped_shards.is_none() {
             // If there is a significant offset, we need to retrieve the whole result
             // set without payload first and then retrieve the payload.
             // It is required to do this because the payload might be too large to send over the
@@ -258,6 +315,9 @@ impl Collection {
                     read_consistency,
                     &shard_selection,
                     timeout,
+                    None,
+                    explained,
+                    timed_out,
                     hw_measurement_acc.clone(),
                 )
                 .await?;
@@ -284,6 +344,9 @@ impl Collection {
                 read_consistency,
                 &shard_selection,
                 timeout,
+                skipped_shards,
+                explained,
+                timed_out,
                 hw_measurement_acc.clone(),
             )
             .await
@@ -291,12 +354,16 @@ impl Collection {
     }
 
     /// This function is used to query the collection. It will return a list of scored points.
+    #[allow(clippy::too_many_arguments)]
     async fn do_query_batch_impl(
         &self,
         requests_batch: Vec<ShardQueryRequest>,
         read_consistency: Option<ReadConsistency>,
         shard_selection: &ShardSelectorInternal,
         timeout: Option<Duration>,
+        skipped_shards: Option<&SkippedShards>,
+        explained: Option<&ExplainedShards>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
         let instant = Instant::now();
@@ -309,6 +376,9 @@ impl Collection {
                 read_consistency,
                 shard_selection,
                 timeout,
+                skipped_shards,
+                explained,
+                timed_out,
                 hw_measurement_acc.clone(),
             )
             .await?;
@@ -361,7 +431,7 @@ impl Collection {
             score_threshold,
             limit,
             offset,
-            params: _,
+            params,
             with_vector,
             with_payload: _,
         } = request;
@@ -435,7 +505,12 @@ impl Collection {
             }
         };
 
-        let result: Vec<ScoredPoint> = result.into_iter().skip(*offset).take(*limit).collect();
+        let mut result: Vec<ScoredPoint> = result.into_iter().take(*offset + *limit).collect();
+
+        if let Some(max_gap) = params.as_ref().and_then(|p| p.score_gap_cutoff) {
+            truncate_at_score_gap(&mut result, max_gap.0);
+        }
+        result.drain(..cmp::min(result.len(), *offset));
 
         Ok(result)
     }
@@ -443,12 +518,25 @@ impl Collection {
     /// To be called on the user-responding instance. Resolves ids into vectors, and merges the results from local and remote shards.
     ///
     /// This function is used to query the collection. It will return a list of scored points.
+    ///
+    /// If `skipped_shards` is given, shards which fail are skipped and recorded there, results
+    /// of the other shards are returned.
+    ///
+    /// If `explained` is given, explanations of how segments executed the searches are recorded
+    /// there.
+    ///
+    /// If `timed_out` is given, searches which allow partial results return results found so far
+    /// at the deadline, and are marked as timed out there.
+    #[allow(clippy::too_many_arguments)]
     pub async fn query_batch<F, Fut>(
         &self,
         requests_batch: Vec<(CollectionQueryRequest, ShardSelectorInternal)>,
         collection_by_name: F,
         read_consistency: Option<ReadConsistency>,
         timeout: Option<Duration>,
+        skipped_shards: Option<&SkippedShards>,
+        explained: Option<&ExplainedShards>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>>
     where
@@ -510,6 +598,9 @@ impl Collection {
                     read_consistency,
                     shard_selection,
                     timeout,
+                    skipped_shards,
+                    explained,
+                    timed_out,
                     hw_measurement_acc.clone(),
                 ));
 
@@ -535,6 +626,8 @@ impl Collection {
         requests: Vec<ShardQueryRequest>,
         shard_selection: &ShardSelectorInternal,
         timeout: Option<Duration>,
+        explained: Option<&ExplainedShards>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let requests_arc = Arc::new(requests);
@@ -547,6 +640,9 @@ impl Collection {
                 None,
                 shard_selection,
                 timeout,
+                None,
+                explained,
+                timed_out,
                 hw_measurement_acc,
             )
             .await?;
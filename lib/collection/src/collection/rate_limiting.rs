@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use common::rate_limiting::RateLimiter;
+use parking_lot::Mutex;
+use segment::types::StrictModeConfig;
+
+use super::Collection;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+/// Class of client operations, rate limited separately in strict mode
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OperationClass {
+    /// Search, query, recommend and discover requests
+    Search,
+    Scroll,
+    Update,
+}
+
+impl OperationClass {
+    fn rate_limit(self, config: &StrictModeConfig) -> Option<usize> {
+        match self {
+            OperationClass::Search => config.search_rate_limit,
+            OperationClass::Scroll => config.scroll_rate_limit,
+            OperationClass::Update => config.update_rate_limit,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            OperationClass::Search => "Search",
+            OperationClass::Scroll => "Scroll",
+            OperationClass::Update => "Update",
+        }
+    }
+}
+
+/// Rate limiters of operation classes of a collection.
+///
+/// Limiters are created lazily from the current strict mode configuration, and recreated if the
+/// configured limit changes.
+#[derive(Debug, Default)]
+pub(super) struct OperationRateLimiters {
+    limiters: Mutex<HashMap<OperationClass, (usize, RateLimiter)>>,
+}
+
+impl OperationRateLimiters {
+    fn try_consume(
+        &self,
+        class: OperationClass,
+        rate_limit: Option<usize>,
+        cost: usize,
+    ) -> CollectionResult<()> {
+        let mut limiters = self.limiters.lock();
+
+        let Some(rate_limit) = rate_limit else {
+            limiters.remove(&class);
+            return Ok(());
+        };
+
+        let (limit, limiter) = limiters
+            .entry(class)
+            .or_insert_with(|| (rate_limit, RateLimiter::new_per_minute(rate_limit)));
+
+        if *limit != rate_limit {
+            *limit = rate_limit;
+            *limiter = RateLimiter::new_per_minute(rate_limit);
+        }
+
+        limiter
+            .try_consume(cost as f64)
+            .map_err(|err| CollectionError::operation_rate_limit_error(err, cost, class.name()))
+    }
+}
+
+impl Collection {
+    /// Check if the strict mode rate limit of the operation class allows `cost` operations to
+    /// proceed on this collection.
+    ///
+    /// To be called once per client request, on the peer which received it.
+    pub async fn check_rate_limit(
+        &self,
+        class: OperationClass,
+        cost: usize,
+    ) -> CollectionResult<()> {
+        let rate_limit = self
+            .collection_config
+            .read()
+            .await
+            .strict_mode_config
+            .as_ref()
+            .filter(|config| config.enabled == Some(true))
+            .and_then(|config| class.rate_limit(config));
+
+        self.operation_rate_limiters
+            .try_consume(class, rate_limit, cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_rate_limiters() {
+        let limiters = OperationRateLimiters::default();
+
+        limiters
+            .try_consume(OperationClass::Search, Some(2), 2)
+            .unwrap();
+        let err = limiters
+            .try_consume(OperationClass::Search, Some(2), 1)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CollectionError::RateLimitExceeded {
+                retry_after: Some(_),
+                ..
+            }
+        ));
+
+        // Other classes are limited separately
+        limiters
+            .try_consume(OperationClass::Update, Some(2), 1)
+            .unwrap();
+
+        // Changed limit resets the limiter
+        limiters
+            .try_consume(OperationClass::Search, Some(3), 1)
+            .unwrap();
+
+        // Request can never fit into the limit
+        let err = limiters
+            .try_consume(OperationClass::Scroll, Some(2), 3)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CollectionError::RateLimitExceeded {
+                retry_after: None,
+                ..
+            }
+        ));
+
+        // Removed limit
+        limiters
+            .try_consume(OperationClass::Scroll, None, 3)
+            .unwrap();
+    }
+}
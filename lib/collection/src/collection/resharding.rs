@@ -175,9 +175,50 @@ impl Collection {
             }
         }
 
+        // Once the target number of shards is reached, remove points migrated out of local shards
+        let shard_ids = match &resharding_key.shard_key {
+            Some(shard_key) => shard_holder.get_shard_ids_by_key(shard_key)?,
+            None => shard_holder
+                .get_shards()
+                .map(|(shard_id, _)| shard_id)
+                .collect(),
+        };
+        let is_target_reached = resharding_key
+            .target
+            .is_some_and(|target| shard_ids.len() == target.shard_number as usize);
+        if !is_target_reached {
+            return Ok(());
+        }
+
+        let mut local_shard_ids = Vec::new();
+        for shard_id in shard_ids {
+            if shard_holder.is_shard_local(shard_id).await == Some(true) {
+                local_shard_ids.push(shard_id);
+            }
+        }
+        drop(shard_holder);
+
+        for shard_id in local_shard_ids {
+            if let Err(err) = self.cleanup_local_shard(shard_id, false, None).await {
+                log::error!(
+                    "Failed to clean up shard {}:{shard_id} after resharding: {err}",
+                    self.id,
+                );
+            }
+        }
+
         Ok(())
     }
 
+    /// Error of the last resharding to a target number of shards, driven by this peer
+    pub fn resharding_error(&self) -> Option<String> {
+        self.resharding_error.lock().clone()
+    }
+
+    pub fn set_resharding_error(&self, error: Option<String>) {
+        *self.resharding_error.lock() = error;
+    }
+
     pub async fn abort_resharding(
         &self,
         resharding_key: ReshardKey,
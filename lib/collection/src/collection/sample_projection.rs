@@ -0,0 +1,383 @@
+use std::time::Duration;
+
+use api::rest::{ProjectedPoint, SampleProjectionRequestInternal, SampleProjectionResponse};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorRef};
+use segment::types::{
+    Condition, Filter, HasVectorCondition, Payload, PointIdType, VectorNameBuf,
+    WithPayloadInterface, WithVector,
+};
+
+use crate::collection::Collection;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::universal_query::shard_query::{
+    SampleInternal, ScoringQuery, ShardQueryRequest,
+};
+
+/// Maximal number of power iterations per principal component
+const MAX_POWER_ITERATIONS: usize = 100;
+
+/// Power iteration stops once the component direction changes less than this
+const POWER_ITERATION_EPSILON: f64 = 1e-9;
+
+/// Internal representation of the sample projection request, used to convert from REST.
+pub struct CollectionSampleProjectionRequest {
+    pub sample_size: usize,
+    pub filter: Option<Filter>,
+    pub using: VectorNameBuf,
+    pub with_payload: WithPayloadInterface,
+}
+
+impl CollectionSampleProjectionRequest {
+    pub const DEFAULT_SAMPLE: usize = 1000;
+}
+
+impl From<SampleProjectionRequestInternal> for CollectionSampleProjectionRequest {
+    fn from(request: SampleProjectionRequestInternal) -> Self {
+        let SampleProjectionRequestInternal {
+            filter,
+            sample,
+            using,
+            with_payload,
+        } = request;
+        Self {
+            sample_size: sample.unwrap_or(CollectionSampleProjectionRequest::DEFAULT_SAMPLE),
+            filter,
+            using: using.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned()),
+            with_payload: with_payload.unwrap_or(WithPayloadInterface::Bool(false)),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CollectionSampleProjectionResponse {
+    pub ids: Vec<PointIdType>,
+    pub payloads: Vec<Option<Payload>>,
+    pub coordinates: Vec<[f32; 2]>,
+    pub explained_variance: [f32; 2],
+}
+
+impl From<CollectionSampleProjectionResponse> for SampleProjectionResponse {
+    fn from(response: CollectionSampleProjectionResponse) -> Self {
+        let CollectionSampleProjectionResponse {
+            ids,
+            payloads,
+            coordinates,
+            explained_variance,
+        } = response;
+
+        let points = ids
+            .into_iter()
+            .zip(payloads)
+            .zip(coordinates)
+            .map(|((id, payload), [x, y])| ProjectedPoint { id, x, y, payload })
+            .collect();
+
+        Self {
+            points,
+            explained_variance: explained_variance.to_vec(),
+        }
+    }
+}
+
+impl Collection {
+    /// Sample random points and project their vectors onto the plane of the first two
+    /// principal components, so the embedding space can be plotted without exporting vectors.
+    pub async fn sample_projection(
+        &self,
+        request: CollectionSampleProjectionRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<CollectionSampleProjectionResponse> {
+        let CollectionSampleProjectionRequest {
+            sample_size,
+            filter,
+            using,
+            with_payload,
+        } = request;
+        if sample_size == 0 {
+            return Ok(Default::default());
+        }
+
+        self.collection_config
+            .read()
+            .await
+            .params
+            .check_vector_exists(&using)?;
+
+        // make sure the vector is present in the point
+        let has_vector = Filter::new_must(Condition::HasVector(HasVectorCondition::from(
+            using.clone(),
+        )));
+
+        let filter = Some(
+            filter
+                .map(|filter| filter.merge(&has_vector))
+                .unwrap_or(has_vector),
+        );
+
+        let sampling_query = ShardQueryRequest {
+            prefetches: vec![],
+            query: Some(ScoringQuery::Sample(SampleInternal::Random)),
+            filter,
+            score_threshold: None,
+            limit: sample_size,
+            offset: 0,
+            params: None,
+            with_vector: WithVector::Selector(vec![using.clone()]),
+            with_payload,
+        };
+
+        let mut sampled_points = self
+            .query(
+                sampling_query,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await?;
+
+        sampled_points.truncate(sample_size);
+        // sort by id for a deterministic order
+        sampled_points.sort_unstable_by_key(|p| p.id);
+
+        let mut vectors = Vec::with_capacity(sampled_points.len());
+        for point in &sampled_points {
+            match point.vector.as_ref().and_then(|v| v.get(&using)) {
+                Some(VectorRef::Dense(vector)) => vectors.push(vector),
+                Some(VectorRef::Sparse(_) | VectorRef::MultiDense(_)) => {
+                    return Err(CollectionError::bad_request(format!(
+                        "Vector {using} is not a dense vector, only dense vectors can be projected",
+                    )));
+                }
+                None => {
+                    return Err(CollectionError::service_error(format!(
+                        "Vector {using} not found in sampled point {}",
+                        point.id,
+                    )));
+                }
+            }
+        }
+
+        let (coordinates, explained_variance) = project_pca(&vectors);
+
+        let (ids, payloads) = sampled_points
+            .into_iter()
+            .map(|point| (point.id, point.payload))
+            .unzip();
+
+        Ok(CollectionSampleProjectionResponse {
+            ids,
+            payloads,
+            coordinates,
+            explained_variance,
+        })
+    }
+}
+
+/// Project vectors onto their first two principal components.
+///
+/// Components are found with power iteration on the covariance matrix, which is never
+/// materialized: each step computes `Xᵀ(Xv)` over the centered vectors instead, so memory stays
+/// linear in the dimensionality.
+///
+/// Returns 2D coordinates for each vector, and the share of the total variance explained by each
+/// component.
+fn project_pca(vectors: &[&[f32]]) -> (Vec<[f32; 2]>, [f32; 2]) {
+    let count = vectors.len();
+    let dim = vectors.first().map_or(0, |v| v.len());
+    if count == 0 || dim == 0 {
+        return (vec![[0.0; 2]; count], [0.0; 2]);
+    }
+
+    let mut mean = vec![0.0f64; dim];
+    for vector in vectors {
+        for (m, &x) in mean.iter_mut().zip(vector.iter()) {
+            *m += f64::from(x);
+        }
+    }
+    for m in &mut mean {
+        *m /= count as f64;
+    }
+
+    let centered: Vec<Vec<f64>> = vectors
+        .iter()
+        .map(|vector| {
+            vector
+                .iter()
+                .zip(&mean)
+                .map(|(&x, m)| f64::from(x) - m)
+                .collect()
+        })
+        .collect();
+
+    let total_variance = centered.iter().flatten().map(|x| x * x).sum::<f64>() / count as f64;
+
+    let mut components: Vec<Vec<f64>> = Vec::with_capacity(2);
+    let mut explained_variance = [0.0f32; 2];
+
+    for (i, variance) in explained_variance.iter_mut().enumerate() {
+        if i >= dim {
+            break;
+        }
+
+        // deterministic, non-degenerate starting direction
+        let mut component: Vec<f64> = (0..dim)
+            .map(|j| 1.0 + ((j * 7919 + i * 104729) % 97) as f64 / 97.0)
+            .collect();
+        orthogonalize(&mut component, &components);
+        if normalize(&mut component) == 0.0 {
+            break;
+        }
+
+        let mut eigenvalue = 0.0;
+        for _ in 0..MAX_POWER_ITERATIONS {
+            let mut next = covariance_product(&centered, &component);
+            orthogonalize(&mut next, &components);
+            eigenvalue = normalize(&mut next);
+            if eigenvalue == 0.0 {
+                break;
+            }
+
+            let similarity = dot(&next, &component).abs();
+            component = next;
+            if 1.0 - similarity < POWER_ITERATION_EPSILON {
+                break;
+            }
+        }
+
+        if eigenvalue == 0.0 {
+            break;
+        }
+
+        // fix the sign, so repeated calls produce the same picture
+        let largest = component
+            .iter()
+            .copied()
+            .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+            .unwrap_or_default();
+        if largest < 0.0 {
+            component.iter_mut().for_each(|x| *x = -*x);
+        }
+
+        if total_variance > 0.0 {
+            *variance = (eigenvalue / total_variance) as f32;
+        }
+        components.push(component);
+    }
+
+    let coordinates = centered
+        .iter()
+        .map(|row| {
+            let mut point = [0.0f32; 2];
+            for (coordinate, component) in point.iter_mut().zip(&components) {
+                *coordinate = dot(row, component) as f32;
+            }
+            point
+        })
+        .collect();
+
+    (coordinates, explained_variance)
+}
+
+/// Multiply the covariance matrix of `centered` rows by `vector`.
+fn covariance_product(centered: &[Vec<f64>], vector: &[f64]) -> Vec<f64> {
+    let mut result = vec![0.0; vector.len()];
+    for row in centered {
+        let projection = dot(row, vector);
+        for (r, x) in result.iter_mut().zip(row) {
+            *r += projection * x;
+        }
+    }
+    let count = centered.len() as f64;
+    result.iter_mut().for_each(|r| *r /= count);
+    result
+}
+
+/// Remove projections onto already found (unit length) components.
+fn orthogonalize(vector: &mut [f64], components: &[Vec<f64>]) {
+    for component in components {
+        let projection = dot(vector, component);
+        for (v, c) in vector.iter_mut().zip(component) {
+            *v -= projection * c;
+        }
+    }
+}
+
+/// Scale `vector` to unit length, returning its original length.
+fn normalize(vector: &mut [f64]) -> f64 {
+    let norm = dot(vector, vector).sqrt();
+    if norm > f64::EPSILON {
+        vector.iter_mut().for_each(|x| *x /= norm);
+        norm
+    } else {
+        0.0
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_pca() {
+        // points along a line in 3D, slightly perturbed along a second direction
+        let vectors: Vec<Vec<f32>> = (0..50)
+            .map(|i| {
+                let t = i as f32 - 25.0;
+                let s = if i % 2 == 0 { 1.0 } else { -1.0 };
+                vec![t + 10.0, 2.0 * t + s, -t - 2.0 * s + 5.0]
+            })
+            .collect();
+        let refs: Vec<&[f32]> = vectors.iter().map(|v| v.as_slice()).collect();
+
+        let (coordinates, explained_variance) = project_pca(&refs);
+        assert_eq!(coordinates.len(), vectors.len());
+
+        // almost all variance is along the line
+        assert!(explained_variance[0] > 0.95, "{explained_variance:?}");
+        assert!(explained_variance[1] > 0.0);
+        assert!(explained_variance[0] + explained_variance[1] <= 1.0 + 1e-4);
+
+        // first coordinate preserves the order along the line
+        for pair in coordinates.windows(2) {
+            assert!((pair[0][0] - pair[1][0]).abs() > 1.0);
+        }
+        assert!(
+            coordinates.windows(2).all(|pair| pair[0][0] < pair[1][0])
+                || coordinates.windows(2).all(|pair| pair[0][0] > pair[1][0])
+        );
+
+        // projections are centered
+        let mean_x = coordinates.iter().map(|c| c[0]).sum::<f32>() / coordinates.len() as f32;
+        let mean_y = coordinates.iter().map(|c| c[1]).sum::<f32>() / coordinates.len() as f32;
+        assert!(mean_x.abs() < 1e-3);
+        assert!(mean_y.abs() < 1e-3);
+
+        // deterministic
+        assert_eq!(project_pca(&refs), (coordinates, explained_variance));
+    }
+
+    #[test]
+    fn test_project_pca_degenerate() {
+        assert_eq!(project_pca(&[]), (vec![], [0.0; 2]));
+
+        let same = [1.0, 2.0, 3.0];
+        let (coordinates, explained_variance) = project_pca(&[&same, &same, &same]);
+        assert_eq!(coordinates, vec![[0.0; 2]; 3]);
+        assert_eq!(explained_variance, [0.0; 2]);
+
+        let (coordinates, explained_variance) = project_pca(&[&[1.0], &[3.0]]);
+        assert_eq!(coordinates, vec![[-1.0, 0.0], [1.0, 0.0]]);
+        assert_eq!(explained_variance, [1.0, 0.0]);
+    }
+}
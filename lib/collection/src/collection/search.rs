@@ -1,6 +1,6 @@
-use std::mem;
 use std::sync::Arc;
 use std::time::Duration;
+use std::{cmp, mem};
 
 use ahash::{AHashMap, AHashSet};
 use common::counter::hardware_accumulator::HwMeasurementAcc;
@@ -9,6 +9,7 @@ use itertools::{Either, Itertools};
 use segment::types::{
     ExtendedPointId, Filter, Order, ScoredPoint, WithPayloadInterface, WithVector,
 };
+use shard::query::score_gap_cutoff::truncate_at_score_gap;
 use shard::retrieve::record_internal::RecordInternal;
 use shard::search::CoreSearchRequestBatch;
 use tokio::time::Instant;
@@ -228,6 +229,7 @@ impl Collection {
             ids: search_result.iter().map(|x| x.id).collect(),
             with_payload,
             with_vector,
+            with_version: None,
         };
         let retrieved_records = self
             .retrieve(
@@ -294,16 +296,18 @@ impl Collection {
             }
             .filter(|point| seen_ids.insert(point.id));
 
+            let mut top_res: Vec<_> = merged_iter.take(request.offset + request.limit).collect();
+
             // Skip `offset` only for client requests
             // to avoid applying `offset` twice in distributed mode.
-            let top_res = if is_client_request && request.offset > 0 {
-                merged_iter
-                    .skip(request.offset)
-                    .take(request.limit)
-                    .collect()
-            } else {
-                merged_iter.take(request.offset + request.limit).collect()
-            };
+            if is_client_request {
+                // Cut only the final results, partial results of shards may have gaps
+                // which are closed after merging
+                if let Some(max_gap) = request.params.and_then(|p| p.score_gap_cutoff) {
+                    truncate_at_score_gap(&mut top_res, max_gap.0);
+                }
+                top_res.drain(..cmp::min(top_res.len(), request.offset));
+            }
 
             top_results.push(top_res);
 
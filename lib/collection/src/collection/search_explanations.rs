@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+
+use parking_lot::Mutex;
+use segment::index::SegmentSearchExplanation;
+
+use crate::shards::shard::ShardId;
+
+/// Explanations of how segments of each shard executed the searches of a request.
+///
+/// Searches of the prefetches and of the rescoring stages are explained, in the order they were
+/// executed.
+#[derive(Debug, Default)]
+pub struct ExplainedShards {
+    shards: Mutex<BTreeMap<ShardId, Vec<SegmentSearchExplanation>>>,
+}
+
+impl ExplainedShards {
+    pub(crate) fn record(&self, shard_id: ShardId, explanations: Vec<SegmentSearchExplanation>) {
+        self.shards
+            .lock()
+            .entry(shard_id)
+            .or_default()
+            .extend(explanations);
+    }
+
+    /// Explanations for the REST response
+    pub fn to_rest_api(&self) -> Vec<api::rest::ShardSearchExplanation> {
+        self.shards
+            .lock()
+            .iter()
+            .map(|(shard_id, segments)| api::rest::ShardSearchExplanation {
+                shard_id: *shard_id,
+                segments: segments.clone(),
+            })
+            .collect()
+    }
+
+    /// Explanations for the public gRPC response
+    pub fn to_grpc_shards(&self) -> Vec<api::grpc::qdrant::ShardSearchExplanation> {
+        self.shards
+            .lock()
+            .iter()
+            .map(
+                |(shard_id, segments)| api::grpc::qdrant::ShardSearchExplanation {
+                    shard_id: *shard_id,
+                    segments: segments.iter().cloned().map(From::from).collect(),
+                },
+            )
+            .collect()
+    }
+
+    /// Explanations of all shards for the internal gRPC response
+    pub fn to_grpc_api(&self) -> Vec<api::grpc::qdrant::SegmentSearchExplanation> {
+        self.shards
+            .lock()
+            .values()
+            .flatten()
+            .cloned()
+            .map(From::from)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::index::{SearchExplanation, SearchStrategy};
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn explanation(strategy: SearchStrategy) -> SegmentSearchExplanation {
+        SegmentSearchExplanation {
+            segment_id: Uuid::new_v4(),
+            vector: String::new(),
+            explanation: SearchExplanation::new(strategy),
+        }
+    }
+
+    #[test]
+    fn test_explained_shards() {
+        let explained = ExplainedShards::default();
+        assert!(explained.to_rest_api().is_empty());
+
+        explained.record(2, vec![explanation(SearchStrategy::Hnsw)]);
+        explained.record(1, vec![explanation(SearchStrategy::PlainScan)]);
+        // Rescoring stage of the same shard
+        explained.record(2, vec![explanation(SearchStrategy::PayloadIndex)]);
+        // Shards without segments are listed too
+        explained.record(3, vec![]);
+
+        let shards = explained.to_rest_api();
+        let strategies: Vec<_> = shards
+            .iter()
+            .map(|shard| {
+                let strategies: Vec<_> = shard
+                    .segments
+                    .iter()
+                    .map(|segment| segment.explanation.strategy)
+                    .collect();
+                (shard.shard_id, strategies)
+            })
+            .collect();
+        assert_eq!(
+            strategies,
+            vec![
+                (1, vec![SearchStrategy::PlainScan]),
+                (2, vec![SearchStrategy::Hnsw, SearchStrategy::PayloadIndex]),
+                (3, vec![]),
+            ],
+        );
+
+        assert_eq!(explained.to_grpc_api().len(), 3);
+
+        let grpc_shards = explained.to_grpc_shards();
+        let grpc_segments: Vec<_> = grpc_shards
+            .iter()
+            .map(|shard| (shard.shard_id, shard.segments.len()))
+            .collect();
+        assert_eq!(grpc_segments, vec![(1, 1), (2, 2), (3, 0)]);
+    }
+}
@@ -114,6 +114,7 @@ impl Collection {
 
                 let shard = LocalShard::build(
                     to_shard_id,
+                    to_replica_set.shard_key().cloned(),
                     self.name().to_string(),
                     &to_replica_set.shard_path,
                     self.collection_config.clone(),
@@ -1,10 +1,14 @@
 use std::collections::HashSet;
 
+use ahash::AHashSet;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use segment::types::ShardKey;
+use segment::types::{Condition, Filter, HasIdCondition, ShardKey};
+use shard::count::CountRequestInternal;
+use shard::operations::point_ops::PointOperations;
 
 use crate::collection::Collection;
-use crate::config::ShardingMethod;
+use crate::config::{ShardKeyConfig, ShardingMethod};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::{
     CollectionUpdateOperations, CreateIndex, FieldIndexOperations, OperationWithClockTag,
@@ -62,6 +66,7 @@ impl Collection {
         shard_key: ShardKey,
         placement: ShardsPlacement,
         init_state: ReplicaState,
+        config: Option<ShardKeyConfig>,
     ) -> CollectionResult<()> {
         let hw_counter = HwMeasurementAcc::disposable(); // Internal operation. No measurement needed.
 
@@ -101,6 +106,9 @@ impl Collection {
             )));
         }
 
+        // Store configuration overrides before creating shards, so that shards are built with them
+        self.set_shard_key_config(&shard_key, config).await?;
+
         let max_shard_id = state.max_shard_id();
         let payload_schema = self.payload_index_schema.read().schema.clone();
 
@@ -188,7 +196,108 @@ impl Collection {
             .write()
             .await
             .remove_shard_key(&shard_key)
+            .await?;
+
+        self.set_shard_key_config(&shard_key, None).await?;
+
+        self.referenced_vectors_cache.clear();
+
+        Ok(())
+    }
+
+    /// Replace configuration overrides of the shard key, and save collection config on disk
+    async fn set_shard_key_config(
+        &self,
+        shard_key: &ShardKey,
+        config: Option<ShardKeyConfig>,
+    ) -> CollectionResult<()> {
+        let mut collection_config = self.collection_config.write().await;
+        let shard_key_configs = &mut collection_config.params.shard_key_configs;
+
+        let len_before = shard_key_configs.len();
+        shard_key_configs.retain(|config| &config.shard_key != shard_key);
+        if config.is_none() && shard_key_configs.len() == len_before {
+            return Ok(());
+        }
+
+        shard_key_configs.extend(config);
+        collection_config.save(&self.path)?;
+        Ok(())
+    }
+
+    /// Reject upserts into a shard key, which would exceed its max points count.
+    ///
+    /// Only points, which don't exist in the shard key yet, count towards the limit, so existing
+    /// points can always be overwritten.
+    pub(super) async fn check_shard_key_points_limit(
+        &self,
+        operation: &CollectionUpdateOperations,
+        shard_key: &ShardKey,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<()> {
+        let is_upsert = matches!(
+            operation,
+            CollectionUpdateOperations::PointOperation(
+                PointOperations::UpsertPoints(_) | PointOperations::UpsertPointsConditional(_),
+            ),
+        );
+        if !is_upsert {
+            return Ok(());
+        }
+
+        let max_points_count = self
+            .collection_config
+            .read()
             .await
+            .params
+            .shard_key_config(shard_key)
+            .and_then(|config| config.max_points_count);
+        let Some(max_points_count) = max_points_count else {
+            return Ok(());
+        };
+
+        let Some(point_ids) = operation.point_ids() else {
+            return Ok(());
+        };
+        let point_ids: AHashSet<_> = point_ids.into_iter().collect();
+
+        let shard_selector = ShardSelectorInternal::ShardKey(shard_key.clone());
+
+        let request = CountRequestInternal {
+            filter: None,
+            exact: true,
+        };
+        let points_count = self
+            .count(
+                request,
+                None,
+                &shard_selector,
+                None,
+                hw_measurement_acc.clone(),
+            )
+            .await?
+            .count;
+
+        let request = CountRequestInternal {
+            filter: Some(Filter::new_must(Condition::HasId(HasIdCondition::from(
+                point_ids.clone(),
+            )))),
+            exact: true,
+        };
+        let existing_points_count = self
+            .count(request, None, &shard_selector, None, hw_measurement_acc)
+            .await?
+            .count;
+
+        let new_points_count = point_ids.len().saturating_sub(existing_points_count);
+        if points_count + new_points_count > max_points_count {
+            return Err(CollectionError::bad_request(format!(
+                "Max points count limit of {max_points_count} reached for shard key {shard_key}: \
+                 it has {points_count} points, and the upsert adds {new_points_count} new points",
+            )));
+        }
+
+        Ok(())
     }
 
     pub async fn get_shard_ids(&self, shard_key: &ShardKey) -> CollectionResult<Vec<ShardId>> {
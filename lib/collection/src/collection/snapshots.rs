@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 use common::fs::read_json;
@@ -14,6 +14,10 @@ use tokio::sync::OwnedRwLockReadGuard;
 
 use super::Collection;
 use crate::collection::CollectionVersion;
+use crate::collection::filter_templates::FILTER_TEMPLATES_FILE;
+use crate::collection::incremental_snapshot::{
+    COLLECTION_SNAPSHOT_MANIFEST_FILE, CollectionSnapshotManifest,
+};
 use crate::collection::payload_index_schema::PAYLOAD_INDEX_CONFIG_FILE;
 use crate::common::snapshot_stream::SnapshotStream;
 use crate::common::snapshots_manager::SnapshotStorageManager;
@@ -46,17 +50,30 @@ impl Collection {
     /// 2. Archive the temporary directory into a single file.
     /// 3. Move the archive to the final location.
     ///
+    /// If `base_snapshot` is specified, an incremental snapshot is created. It only contains
+    /// segment files changed since the base snapshot, and the WAL of each local shard.
+    ///
     /// # Arguments
     ///
     /// * `global_temp_dir`: directory used to host snapshots while they are being created
     /// * `this_peer_id`: current peer id
+    /// * `base_snapshot`: name of the collection snapshot to create an incremental snapshot on top of
     ///
     /// returns: Result<SnapshotDescription, CollectionError>
     pub async fn create_snapshot(
         &self,
         global_temp_dir: &Path,
         this_peer_id: PeerId,
+        base_snapshot: Option<&str>,
     ) -> CollectionResult<SnapshotDescription> {
+        let base_manifest = match base_snapshot {
+            Some(base_snapshot) => Some(
+                self.base_snapshot_manifest(base_snapshot, global_temp_dir)
+                    .await?,
+            ),
+            None => None,
+        };
+
         let snapshot_name = format!(
             "{}-{this_peer_id}-{}.snapshot",
             self.name(),
@@ -81,6 +98,11 @@ impl Collection {
 
         let tar = BuilderExt::new_seekable_owned(File::create(snapshot_temp_arc_file.path())?);
 
+        let mut snapshot_manifest = CollectionSnapshotManifest {
+            base_snapshot: base_snapshot.map(ToString::to_string),
+            shards: HashMap::new(),
+        };
+
         // Create snapshot of each shard
         {
             let snapshot_temp_temp_dir = tempfile::Builder::new()
@@ -102,14 +124,42 @@ impl Collection {
                 for (shard_id, replica_set) in shards_holder.get_shards() {
                     let shard_snapshot_path = shard_path(Path::new(""), shard_id);
 
+                    let (format, manifest) = match &base_manifest {
+                        Some(base_manifest) if replica_set.has_local_shard().await => {
+                            let manifest =
+                                base_manifest.shards.get(&shard_id).ok_or_else(|| {
+                                    CollectionError::bad_request(format!(
+                                        "base snapshot has no manifest of shard {shard_id}, \
+                                         create a full snapshot instead",
+                                    ))
+                                })?;
+                            (SnapshotFormat::Streamable, Some(manifest.clone()))
+                        }
+                        _ => (SnapshotFormat::Regular, None),
+                    };
+
+                    // Manifest is collected before the snapshot, so that files changed while the
+                    // snapshot is being created are included into the next incremental snapshot
+                    if replica_set.has_local_shard().await {
+                        match replica_set.get_partial_snapshot_manifest().await {
+                            Ok(shard_manifest) => {
+                                snapshot_manifest.shards.insert(shard_id, shard_manifest);
+                            }
+                            Err(err) => log::warn!(
+                                "Failed to collect manifest of shard {shard_id} for snapshot \
+                                 {snapshot_name}, it can't be used as a base snapshot: {err}",
+                            ),
+                        }
+                    }
+
                     // If node is listener, we can save whatever currently is in the storage
                     let save_wal = self.shared_storage_config.node_type != NodeType::Listener;
                     let future = replica_set
                         .create_snapshot(
                             snapshot_temp_temp_dir.path(),
                             tar.descend(&shard_snapshot_path)?,
-                            SnapshotFormat::Regular,
-                            None,
+                            format,
+                            manifest,
                             save_wal,
                         )
                         .await?;
@@ -147,6 +197,20 @@ impl Collection {
             .save_to_tar(&tar, Path::new(PAYLOAD_INDEX_CONFIG_FILE))
             .await?;
 
+        self.filter_templates
+            .save_to_tar(&tar, Path::new(FILTER_TEMPLATES_FILE))
+            .await?;
+
+        tar.append_data(
+            serde_json::to_vec(&snapshot_manifest).map_err(|err| {
+                CollectionError::service_error(format!(
+                    "failed to serialize snapshot manifest into JSON: {err}"
+                ))
+            })?,
+            Path::new(COLLECTION_SNAPSHOT_MANIFEST_FILE),
+        )
+        .await?;
+
         tar.finish().await.map_err(|err| {
             CollectionError::service_error(format!("failed to create snapshot archive: {err}"))
         })?;
@@ -163,6 +227,32 @@ impl Collection {
             })
     }
 
+    /// Read manifest of a stored collection snapshot, to create an incremental snapshot on top of it
+    async fn base_snapshot_manifest(
+        &self,
+        base_snapshot: &str,
+        temp_dir: &Path,
+    ) -> CollectionResult<CollectionSnapshotManifest> {
+        let snapshot_manager = self.get_snapshots_storage_manager()?;
+        let snapshot_path =
+            snapshot_manager.get_snapshot_path(&self.snapshots_path, base_snapshot)?;
+        let snapshot_file = snapshot_manager
+            .get_snapshot_file(&snapshot_path, temp_dir)
+            .await?;
+
+        let manifest = tokio::task::spawn_blocking(move || {
+            CollectionSnapshotManifest::read_from_archive(&snapshot_file)
+        })
+        .await??;
+
+        manifest.ok_or_else(|| {
+            CollectionError::bad_request(format!(
+                "snapshot {base_snapshot} has no snapshot manifest, \
+                 it can't be used as a base for incremental snapshot",
+            ))
+        })
+    }
+
     /// Restore collection from snapshot
     ///
     /// This method performs blocking IO.
@@ -269,6 +359,8 @@ impl Collection {
             )
             .await?;
 
+        self.referenced_vectors_cache.clear();
+
         Ok(res)
     }
 
@@ -6,6 +6,7 @@ use futures::StreamExt as _;
 use futures::stream::FuturesUnordered;
 
 use crate::collection::Collection;
+use crate::collection::filter_templates::FilterTemplates;
 use crate::collection::payload_index_schema::PayloadIndexSchema;
 use crate::collection_state::{ShardInfo, State};
 use crate::config::CollectionConfigInternal;
@@ -42,6 +43,7 @@ impl Collection {
             transfers,
             shards_key_mapping,
             payload_index_schema,
+            filter_templates,
         } = state;
 
         self.apply_config(config).await?;
@@ -51,6 +53,7 @@ impl Collection {
         self.apply_shard_info(shards, shards_key_mapping).await?;
         self.apply_payload_index_schema(payload_index_schema)
             .await?;
+        self.apply_filter_templates(filter_templates)?;
         Ok(())
     }
 
@@ -255,6 +258,12 @@ impl Collection {
         Ok(())
     }
 
+    fn apply_filter_templates(&self, filter_templates: FilterTemplates) -> CollectionResult<()> {
+        self.filter_templates
+            .write_optional(|current| (*current != filter_templates).then_some(filter_templates))?;
+        Ok(())
+    }
+
     /// Truncate unapplied WAL records for all local shards in the collection.
     /// Returns amount of removed records.
     pub async fn truncate_unapplied_wal(&self) -> CollectionResult<usize> {
@@ -0,0 +1,316 @@
+use std::collections::HashMap;
+use std::iter;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use segment::json_path::{JsonPath, JsonPathItem};
+use segment::types::{
+    DateTimePayloadType, GeoPoint, Payload, PayloadFieldSchema, PayloadKeyType, PayloadSchemaType,
+};
+use serde_json::{Map, Value};
+use shard::operations::payload_ops::{PayloadOps, SetPayloadOp};
+use shard::operations::point_ops::{PointInsertOperationsInternal, PointOperations};
+
+use super::Collection;
+use crate::config::StrictPayloadSchemaMode;
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+type PayloadSchema = HashMap<PayloadKeyType, PayloadFieldSchema>;
+
+impl Collection {
+    /// Check payload of upserted points and set payload operations against the payload schema of
+    /// the collection, if strict payload schema is configured.
+    ///
+    /// Payload schema is defined by the payload indexes of the collection: only fields on indexed
+    /// paths are accepted, and values at indexed paths must be of the indexed type.
+    /// Depending on the configured mode, the whole operation is rejected, or violating values are
+    /// dropped from the payload.
+    pub(super) async fn enforce_payload_schema(
+        &self,
+        operation: &mut CollectionUpdateOperations,
+    ) -> CollectionResult<()> {
+        let Some(mode) = self
+            .collection_config
+            .read()
+            .await
+            .params
+            .strict_payload_schema
+        else {
+            return Ok(());
+        };
+        let schema = self.payload_index_schema.read().schema.clone();
+
+        match operation {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(op)) => {
+                enforce_points_payload(op, &schema, mode)
+            }
+            CollectionUpdateOperations::PointOperation(
+                PointOperations::UpsertPointsConditional(op),
+            ) => enforce_points_payload(&mut op.points_op, &schema, mode),
+            CollectionUpdateOperations::PayloadOperation(
+                PayloadOps::SetPayload(op)
+                | PayloadOps::OverwritePayload(op)
+                | PayloadOps::PatchPayload(op),
+            ) => enforce_set_payload(op, &schema, mode),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn enforce_points_payload(
+    op: &mut PointInsertOperationsInternal,
+    schema: &PayloadSchema,
+    mode: StrictPayloadSchemaMode,
+) -> CollectionResult<()> {
+    let points_payloads: Vec<_> = match op {
+        PointInsertOperationsInternal::PointsBatch(batch) => match &mut batch.payloads {
+            Some(payloads) => batch.ids.iter().zip(payloads.iter_mut()).collect(),
+            None => return Ok(()),
+        },
+        PointInsertOperationsInternal::PointsList(points) => points
+            .iter_mut()
+            .map(|point| (&point.id, &mut point.payload))
+            .collect(),
+    };
+
+    for (point_id, payload) in points_payloads {
+        let Some(payload) = payload else {
+            continue;
+        };
+        enforce_payload(&mut payload.0, schema, mode).map_err(|err| {
+            CollectionError::bad_input(format!("Payload of point {point_id} is invalid: {err}"))
+        })?;
+    }
+
+    Ok(())
+}
+
+fn enforce_set_payload(
+    op: &mut SetPayloadOp,
+    schema: &PayloadSchema,
+    mode: StrictPayloadSchemaMode,
+) -> CollectionResult<()> {
+    let Some(key) = &op.key else {
+        return enforce_payload(&mut op.payload.0, schema, mode)
+            .map_err(CollectionError::bad_input);
+    };
+
+    // Check the payload at the position it will be set to
+    let mut payload = Map::new();
+    JsonPath::value_set(Some(key), &mut payload, &op.payload.0);
+    enforce_payload(&mut payload, schema, mode).map_err(CollectionError::bad_input)?;
+
+    if mode == StrictPayloadSchemaMode::Drop {
+        let values = key.value_get(&payload);
+        op.payload = match values.first() {
+            Some(Value::Object(map)) => Payload(map.clone()),
+            _ => Payload::default(),
+        };
+    }
+
+    Ok(())
+}
+
+/// Check `payload` against the payload `schema`.
+///
+/// In [`StrictPayloadSchemaMode::Drop`] mode, undeclared fields and values of wrong type are
+/// removed from the payload. Otherwise, description of the first violation is returned.
+fn enforce_payload(
+    payload: &mut Map<String, Value>,
+    schema: &PayloadSchema,
+    mode: StrictPayloadSchemaMode,
+) -> Result<(), String> {
+    // Keys of declared paths, array indices are checked for all elements
+    let declared_paths: Vec<Vec<&str>> = schema
+        .keys()
+        .map(|path| {
+            let rest_keys = path.rest.iter().filter_map(|item| match item {
+                JsonPathItem::Key(key) => Some(key.as_str()),
+                JsonPathItem::Index(_) | JsonPathItem::WildcardIndex => None,
+            });
+            iter::once(path.first_key.as_str())
+                .chain(rest_keys)
+                .collect()
+        })
+        .collect();
+    enforce_declared_keys(payload, &mut Vec::new(), &declared_paths, mode)?;
+
+    for (path, field_schema) in schema {
+        let kind = field_schema.expand().kind();
+        let is_valid = path
+            .value_get(payload)
+            .iter()
+            .all(|value| value_matches_type(value, kind));
+        if is_valid {
+            continue;
+        }
+        match mode {
+            StrictPayloadSchemaMode::Reject => {
+                return Err(format!(
+                    "payload field `{path}` must be of type `{}`",
+                    kind.name(),
+                ));
+            }
+            StrictPayloadSchemaMode::Drop => {
+                path.value_remove(payload);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that keys of the `object` at `prefix` are declared, recursing into nested objects.
+///
+/// A key is declared if a declared path leads through it. Values at declared paths are accepted
+/// as a whole, their types are checked separately.
+fn enforce_declared_keys(
+    object: &mut Map<String, Value>,
+    prefix: &mut Vec<String>,
+    declared_paths: &[Vec<&str>],
+    mode: StrictPayloadSchemaMode,
+) -> Result<(), String> {
+    let keys: Vec<_> = object.keys().cloned().collect();
+    for key in keys {
+        prefix.push(key.clone());
+
+        // Declared paths either lead through the key, or the key is inside of a declared value
+        let mut is_declared = false;
+        let mut is_leaf = false;
+        for path in declared_paths {
+            if path.iter().zip(prefix.iter()).all(|(a, b)| a == b) {
+                is_declared = true;
+                is_leaf |= path.len() <= prefix.len();
+            }
+        }
+
+        if !is_declared {
+            match mode {
+                StrictPayloadSchemaMode::Reject => {
+                    return Err(format!(
+                        "payload field `{}` is not declared in the payload schema of the collection",
+                        prefix.join("."),
+                    ));
+                }
+                StrictPayloadSchemaMode::Drop => {
+                    object.remove(&key);
+                }
+            }
+        } else if !is_leaf {
+            match object.get_mut(&key) {
+                Some(Value::Object(nested)) => {
+                    enforce_declared_keys(nested, prefix, declared_paths, mode)?;
+                }
+                Some(Value::Array(values)) => {
+                    for value in values {
+                        if let Value::Object(nested) = value {
+                            enforce_declared_keys(nested, prefix, declared_paths, mode)?;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        prefix.pop();
+    }
+    Ok(())
+}
+
+/// Check if the value can be indexed by a payload index of the given type.
+/// Arrays are checked element-wise, null values are always accepted.
+fn value_matches_type(value: &Value, kind: PayloadSchemaType) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(values) => values.iter().all(|value| value_matches_type(value, kind)),
+        value => match kind {
+            PayloadSchemaType::Keyword | PayloadSchemaType::Text => value.is_string(),
+            PayloadSchemaType::Integer => value.is_i64(),
+            PayloadSchemaType::Float => value.is_number(),
+            PayloadSchemaType::Bool => value.is_boolean(),
+            PayloadSchemaType::Datetime => value
+                .as_str()
+                .is_some_and(|value| DateTimePayloadType::from_str(value).is_ok()),
+            PayloadSchemaType::Uuid => value
+                .as_str()
+                .is_some_and(|value| uuid::Uuid::parse_str(value).is_ok()),
+            PayloadSchemaType::Ip => value
+                .as_str()
+                .is_some_and(|value| value.parse::<IpAddr>().is_ok()),
+            PayloadSchemaType::Geo => serde_json::from_value::<GeoPoint>(value.clone()).is_ok(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn schema() -> PayloadSchema {
+        HashMap::from([
+            (
+                "city".parse().unwrap(),
+                PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword),
+            ),
+            (
+                "location.geo".parse().unwrap(),
+                PayloadFieldSchema::FieldType(PayloadSchemaType::Geo),
+            ),
+            (
+                "count".parse().unwrap(),
+                PayloadFieldSchema::FieldType(PayloadSchemaType::Integer),
+            ),
+        ])
+    }
+
+    fn payload(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_enforce_payload() {
+        let schema = schema();
+
+        let mut valid = payload(json!({
+            "city": ["Berlin", "London"],
+            "location": {"geo": {"lon": 13.4, "lat": 52.5}},
+            "count": null,
+        }));
+        let expected = valid.clone();
+        enforce_payload(&mut valid, &schema, StrictPayloadSchemaMode::Reject).unwrap();
+        assert_eq!(valid, expected);
+
+        let mut undeclared = payload(json!({"city": "Berlin", "country": "Germany"}));
+        let err =
+            enforce_payload(&mut undeclared, &schema, StrictPayloadSchemaMode::Reject).unwrap_err();
+        assert!(err.contains("country"), "{err}");
+        enforce_payload(&mut undeclared, &schema, StrictPayloadSchemaMode::Drop).unwrap();
+        assert_eq!(undeclared, payload(json!({"city": "Berlin"})));
+
+        // Nested fields must be declared too
+        let mut nested = payload(json!({
+            "location": [{"geo": {"lon": 13.4, "lat": 52.5}, "name": "center"}],
+        }));
+        let err =
+            enforce_payload(&mut nested, &schema, StrictPayloadSchemaMode::Reject).unwrap_err();
+        assert!(err.contains("location.name"), "{err}");
+        enforce_payload(&mut nested, &schema, StrictPayloadSchemaMode::Drop).unwrap();
+        assert_eq!(
+            nested,
+            payload(json!({"location": [{"geo": {"lon": 13.4, "lat": 52.5}}]})),
+        );
+
+        let mut wrong_type = payload(json!({"city": "Berlin", "count": 1.5}));
+        let err =
+            enforce_payload(&mut wrong_type, &schema, StrictPayloadSchemaMode::Reject).unwrap_err();
+        assert!(err.contains("integer"), "{err}");
+        enforce_payload(&mut wrong_type, &schema, StrictPayloadSchemaMode::Drop).unwrap();
+        assert_eq!(wrong_type, payload(json!({"city": "Berlin"})));
+    }
+}
@@ -1,3 +1,4 @@
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use common::types::{DetailsLevel, TelemetryDetail};
@@ -35,6 +36,15 @@ impl Collection {
 
         let shard_clean_tasks = self.clean_local_shards_statuses();
 
+        let duplicate_detection = self
+            .collection_config
+            .read()
+            .await
+            .params
+            .duplicate_detection
+            .is_some();
+        let duplicates_detected = self.duplicates_detected.load(Ordering::Relaxed);
+
         Ok(CollectionTelemetry {
             id: self.name().to_string(),
             init_time_ms: Some(self.init_time.as_millis() as u64),
@@ -45,6 +55,8 @@ impl Collection {
             transfers,
             resharding,
             shard_clean_tasks: (!shard_clean_tasks.is_empty()).then_some(shard_clean_tasks),
+            duplicates_detected: (duplicate_detection || duplicates_detected > 0)
+                .then_some(duplicates_detected),
         })
     }
 }
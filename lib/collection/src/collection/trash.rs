@@ -0,0 +1,238 @@
+use std::time::Duration;
+
+use ahash::AHashSet;
+use chrono::Utc;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use ordered_float::OrderedFloat;
+use segment::json_path::JsonPath;
+use segment::types::{
+    Condition, FieldCondition, Filter, HasIdCondition, Payload, PayloadFieldSchema,
+    PayloadSchemaType, PointIdType, Range,
+};
+use serde_json::{Map, Value};
+
+use crate::collection::Collection;
+use crate::config::ShardingMethod;
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::payload_ops::{DeletePayloadOp, PayloadOps, SetPayloadOp};
+use crate::operations::point_ops::{PointOperations, WriteOrdering};
+use crate::operations::types::{CollectionError, CollectionResult, UpdateResult};
+
+/// Payload field holding the time a point was moved to the trash, as a unix timestamp in seconds.
+///
+/// Points with this field are tombstones: they are excluded from all reads, and are permanently
+/// deleted once the trash retention period has passed. The field is indexed automatically, once
+/// trash retention is enabled for the collection.
+pub const TRASHED_AT_KEY: &str = "__trashed_at";
+
+pub fn trashed_at_key() -> JsonPath {
+    JsonPath::new(TRASHED_AT_KEY)
+}
+
+fn trashed_at_range(range: Range<OrderedFloat<f64>>) -> Condition {
+    Condition::Field(FieldCondition::new_range(trashed_at_key(), range))
+}
+
+/// Condition matching all points in the trash
+pub fn trashed_condition() -> Condition {
+    trashed_at_range(Range {
+        lt: None,
+        gt: None,
+        gte: Some(OrderedFloat(0.0)),
+        lte: None,
+    })
+}
+
+/// Condition matching points moved to the trash at or before `timestamp`
+fn trashed_before_condition(timestamp: i64) -> Condition {
+    trashed_at_range(Range {
+        lt: None,
+        gt: None,
+        gte: None,
+        lte: Some(OrderedFloat(timestamp as f64)),
+    })
+}
+
+/// Condition matching points moved to the trash after `timestamp`
+fn trashed_after_condition(timestamp: i64) -> Condition {
+    trashed_at_range(Range {
+        lt: None,
+        gt: Some(OrderedFloat(timestamp as f64)),
+        gte: None,
+        lte: None,
+    })
+}
+
+impl Collection {
+    /// Create payload index of the trash field, if trash retention is enabled.
+    ///
+    /// Must be called from consensus, whenever the collection is created or its params change.
+    pub async fn create_trash_index(&self) -> CollectionResult<()> {
+        let trash_retention = self
+            .collection_config
+            .read()
+            .await
+            .params
+            .trash_retention_sec;
+
+        let key = trashed_at_key();
+        if trash_retention.is_none() || self.payload_index_schema.read().schema.contains_key(&key) {
+            return Ok(());
+        }
+
+        self.create_payload_index(
+            key,
+            PayloadFieldSchema::FieldType(PayloadSchemaType::Integer),
+            HwMeasurementAcc::disposable(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Turn deletes of the operation into tombstones, if trash retention is configured for the
+    /// collection.
+    ///
+    /// Deleted points are marked with the time of deletion, in the same operation and on every
+    /// replica. Deletes are applied by filter, so that missing points are ignored as in a regular
+    /// delete, and points already in the trash keep their original time.
+    pub(super) async fn move_to_trash(&self, operation: &mut CollectionUpdateOperations) {
+        let trash_retention = self
+            .collection_config
+            .read()
+            .await
+            .params
+            .trash_retention_sec;
+        if trash_retention.is_none() {
+            return;
+        }
+
+        let deleted = match operation {
+            CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints { ids }) => {
+                Filter::new_must(Condition::HasId(HasIdCondition::from(
+                    ids.iter().copied().collect::<AHashSet<_>>(),
+                )))
+            }
+            CollectionUpdateOperations::PointOperation(PointOperations::DeletePointsByFilter(
+                filter,
+            )) => filter.clone(),
+            _ => return,
+        };
+        let not_trashed = Filter::new_must_not(trashed_condition());
+
+        let payload = Payload(Map::from_iter([(
+            TRASHED_AT_KEY.to_string(),
+            Value::from(Utc::now().timestamp()),
+        )]));
+
+        *operation =
+            CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(SetPayloadOp {
+                payload,
+                points: None,
+                filter: Some(deleted.merge(&not_trashed)),
+                key: None,
+            }));
+    }
+
+    /// Restore points from the trash, as they were at the moment of deletion.
+    ///
+    /// Points which are not in the trash, or which retention period has passed, are ignored.
+    pub async fn restore_points(
+        &self,
+        ids: Vec<PointIdType>,
+        wait: bool,
+        timeout: Option<Duration>,
+        ordering: WriteOrdering,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<UpdateResult> {
+        let Some(retention) = self
+            .collection_config
+            .read()
+            .await
+            .params
+            .trash_retention_sec
+        else {
+            return Err(CollectionError::bad_request(format!(
+                "Trash retention is not enabled for collection {}",
+                self.name(),
+            )));
+        };
+
+        let retained_since = Utc::now().timestamp() - retention as i64;
+        let filter = Filter {
+            must: Some(vec![
+                Condition::HasId(HasIdCondition::from(
+                    ids.into_iter().collect::<AHashSet<_>>(),
+                )),
+                trashed_after_condition(retained_since),
+            ]),
+            ..Default::default()
+        };
+
+        let operation = CollectionUpdateOperations::PayloadOperation(PayloadOps::DeletePayload(
+            DeletePayloadOp {
+                keys: vec![trashed_at_key()],
+                points: None,
+                filter: Some(filter),
+            },
+        ));
+
+        // Points are restored in the shard they were deleted from
+        let operations = match self.get_sharding_method_and_keys().await {
+            (ShardingMethod::Auto, _) => vec![(None, operation)],
+            (ShardingMethod::Custom, shard_keys) => shard_keys
+                .into_iter()
+                .map(|shard_key| (Some(shard_key), operation.clone()))
+                .collect(),
+        };
+
+        self.update_from_client_by_shard_keys(
+            operations,
+            wait,
+            timeout,
+            ordering,
+            hw_measurement_acc,
+        )
+        .await
+    }
+
+    /// Permanently delete points from shards of this peer, which retention period in the trash
+    /// has passed. If trash retention was disabled, all points in the trash are deleted.
+    ///
+    /// Points are not deleted from read-only collections.
+    pub async fn purge_trash(&self) -> CollectionResult<()> {
+        if !self
+            .payload_index_schema
+            .read()
+            .schema
+            .contains_key(&trashed_at_key())
+        {
+            return Ok(());
+        }
+
+        let purged = {
+            let config = self.collection_config.read().await;
+            if config.params.read_only.unwrap_or_default() {
+                return Ok(());
+            }
+            match config.params.trash_retention_sec {
+                Some(retention) => {
+                    trashed_before_condition(Utc::now().timestamp() - retention as i64)
+                }
+                None => trashed_condition(),
+            }
+        };
+
+        let shard_holder = self.shards_holder.read().await;
+        for (shard_id, replica_set) in shard_holder.get_shards() {
+            if let Err(err) = replica_set.purge_trash(purged.clone()).await {
+                log::warn!(
+                    "Failed to purge trash of shard {}:{shard_id}: {err}",
+                    self.id,
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use segment::types::SeqNumberType;
@@ -44,6 +45,7 @@ impl CollectionUpdater {
         operation: CollectionUpdateOperations,
         update_operation_lock: Arc<tokio::sync::RwLock<()>>,
         update_tracker: UpdateTracker,
+        index_builds_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
     ) -> CollectionResult<usize> {
         // Use block_in_place here to avoid blocking the current async executor
@@ -80,6 +82,7 @@ impl CollectionUpdater {
                         &segments_guard,
                         op_num,
                         &index_operation,
+                        index_builds_stopped,
                         hw_counter,
                     )
                 }
@@ -102,7 +105,7 @@ impl CollectionUpdater {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::{AtomicBool, Ordering};
 
     use common::counter::hardware_accumulator::HwMeasurementAcc;
     use itertools::Itertools;
@@ -130,6 +133,7 @@ mod tests {
     use crate::operations::point_ops::{
         PointOperations, PointStructPersisted, VectorStructPersisted,
     };
+    use crate::operations::{CreateIndex, FieldIndexOperations};
 
     #[test]
     fn test_sync_ops() {
@@ -390,6 +394,65 @@ mod tests {
         assert!(!res[0].payload.as_ref().unwrap().contains_key("color"));
     }
 
+    #[test]
+    fn test_patch_payload() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segments = build_test_holder(dir.path());
+        let is_stopped = AtomicBool::new(false);
+
+        let patched_point = 1.into();
+        let other_point = 2.into();
+
+        let hw_counter = HardwareCounterCell::new();
+
+        process_payload_operation(
+            &segments.read(),
+            100,
+            PayloadOps::OverwritePayload(SetPayloadOp {
+                payload: payload_json! {"meta": {"color": "red", "size": 1}, "tag": "a"},
+                points: Some(vec![patched_point, other_point]),
+                filter: None,
+                key: None,
+            }),
+            &hw_counter,
+        )
+        .unwrap();
+
+        process_payload_operation(
+            &segments.read(),
+            101,
+            PayloadOps::PatchPayload(SetPayloadOp {
+                payload: payload_json! {"meta": {"color": "blue", "size": null}, "tag": null},
+                points: Some(vec![patched_point]),
+                filter: None,
+                key: None,
+            }),
+            &hw_counter,
+        )
+        .unwrap();
+
+        let res = retrieve_blocking(
+            segments,
+            &[patched_point, other_point],
+            &WithPayload::from(true),
+            &false.into(),
+            TEST_TIMEOUT,
+            &is_stopped,
+            HwMeasurementAcc::new(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res[&patched_point].payload,
+            Some(payload_json! {"meta": {"color": "blue"}}),
+        );
+        // points not selected by the operation are not changed
+        assert_eq!(
+            res[&other_point].payload,
+            Some(payload_json! {"meta": {"color": "red", "size": 1}, "tag": "a"}),
+        );
+    }
+
     #[test]
     fn test_nested_payload_update_with_index() {
         let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
@@ -537,4 +600,46 @@ mod tests {
             },
         };
     }
+
+    #[test]
+    fn test_cancelled_field_index_build() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segments = build_test_holder(dir.path());
+
+        let key = JsonPath::new("color");
+        let operation = FieldIndexOperations::CreateIndex(CreateIndex {
+            field_name: key.clone(),
+            field_schema: Some(PayloadFieldSchema::FieldType(Keyword)),
+        });
+        let is_indexed = |segments: &LockedSegmentHolder| {
+            segments
+                .read()
+                .iter()
+                .map(|(_, segment)| segment.get().read().get_indexed_fields().contains_key(&key))
+                .collect_vec()
+        };
+
+        let hw_counter = HardwareCounterCell::new();
+
+        // Build is cancelled, the index is not created in any segment
+        let is_stopped = AtomicBool::new(true);
+        let result = process_field_index_operation(
+            &segments.read(),
+            100,
+            &operation,
+            &is_stopped,
+            &hw_counter,
+        );
+        let error = CollectionError::from(result.unwrap_err());
+        assert!(matches!(error, CollectionError::Cancelled { .. }));
+        // Cancelled operation is kept to be applied again
+        assert!(error.is_transient());
+        assert_eq!(is_indexed(&segments), vec![false, false]);
+
+        // Applied again once resumed
+        is_stopped.store(false, Ordering::Relaxed);
+        process_field_index_operation(&segments.read(), 100, &operation, &is_stopped, &hw_counter)
+            .unwrap();
+        assert_eq!(is_indexed(&segments), vec![true, true]);
+    }
 }
@@ -234,6 +234,7 @@ pub(crate) fn get_merge_optimizer(
             max_segment_size_kb: 100_000,
             memmap_threshold_kb: 1_000_000,
             indexing_threshold_kb: 1_000_000,
+            max_builder_memory_kb: None,
         }),
         segment_path.to_owned(),
         collection_temp_dir.to_owned(),
@@ -260,6 +261,7 @@ pub(crate) fn get_indexing_optimizer(
             max_segment_size_kb: 100_000,
             memmap_threshold_kb: 100,
             indexing_threshold_kb: 100,
+            max_builder_memory_kb: None,
         },
         segment_path.to_owned(),
         collection_temp_dir.to_owned(),
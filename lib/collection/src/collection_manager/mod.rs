@@ -2,6 +2,7 @@ pub mod collection_updater;
 pub mod holders;
 pub mod optimizers;
 pub mod segments_searcher;
+pub mod segments_usefulness;
 
 pub mod probabilistic_search_sampling;
 
@@ -6,14 +6,21 @@ use itertools::Itertools;
 use parking_lot::Mutex;
 use segment::common::operation_time_statistics::OperationDurationsAggregator;
 use segment::entry::NonAppendableSegmentEntry;
-use segment::index::sparse_index::sparse_index_config::SparseIndexType;
-use segment::types::{HnswConfig, HnswGlobalConfig, Indexes, QuantizationConfig, VectorName};
+use segment::index::sparse_index::sparse_index_config::{
+    SparseIndexType, SparsePruningConfig, SparseSimilarity,
+};
+use segment::types::{
+    HnswConfig, HnswGlobalConfig, Indexes, QuantizationConfig, VectorDataConfig, VectorName,
+    VectorNameBuf,
+};
 
+use crate::collection_manager::holders::segment_holder::LockedSegment;
 use crate::collection_manager::optimizers::segment_optimizer::{
     OptimizationPlanner, OptimizerThresholds, SegmentOptimizer,
 };
 use crate::config::CollectionParams;
 use crate::operations::config_diff::DiffConfig;
+use crate::operations::types::CollectionResult;
 
 /// Looks for segments having a mismatch between configured and actual parameters
 ///
@@ -71,6 +78,32 @@ impl ConfigMismatchOptimizer {
             .and_then(|index| index.on_disk)
     }
 
+    /// Check which similarity current configuration requires for sparse vectors
+    fn check_sparse_vectors_similarity(
+        &self,
+        vector_name: &VectorName,
+    ) -> Option<SparseSimilarity> {
+        self.collection_params
+            .sparse_vectors
+            .as_ref()
+            .and_then(|vector_params| vector_params.get(vector_name))
+            .and_then(|params| params.index)
+            .and_then(|index| index.similarity)
+    }
+
+    /// Check which posting list pruning current configuration requires for sparse vectors
+    fn check_sparse_vectors_pruning(
+        &self,
+        vector_name: &VectorName,
+    ) -> Option<SparsePruningConfig> {
+        self.collection_params
+            .sparse_vectors
+            .as_ref()
+            .and_then(|vector_params| vector_params.get(vector_name))
+            .and_then(|params| params.index)
+            .and_then(|index| index.pruning)
+    }
+
     fn has_config_mismatch(&self, segment: &dyn NonAppendableSegmentEntry) -> bool {
         let segment_config = segment.config();
 
@@ -90,6 +123,18 @@ impl ConfigMismatchOptimizer {
                     match &vector_data.index {
                         Indexes::Plain {} => {}
                         Indexes::Hnsw(effective_hnsw) => {
+                            // Select segment if vector is now configured to be store-only
+                            let is_index_enabled = self
+                                .collection_params
+                                .vectors
+                                .get_params(vector_name)
+                                .and_then(|vector_params| vector_params.index)
+                                .unwrap_or_default()
+                                .is_indexed();
+                            if !is_index_enabled {
+                                return true;
+                            }
+
                             // Select segment if we have an HNSW mismatch that requires rebuild
                             let target_hnsw = self.hnsw_config.update_opt(
                                 self.collection_params
@@ -154,6 +199,21 @@ impl ConfigMismatchOptimizer {
                 .sparse_vector_data
                 .iter()
                 .any(|(vector_name, vector_data)| {
+                    if let Some(target_similarity) =
+                        self.check_sparse_vectors_similarity(vector_name)
+                        && target_similarity != vector_data.index.similarity()
+                    {
+                        return true; // Rebuild if we require different similarity
+                    }
+
+                    // Only immutable indices are pruned
+                    if vector_data.index.index_type.is_immutable()
+                        && self.check_sparse_vectors_pruning(vector_name)
+                            != vector_data.index.pruning
+                    {
+                        return true; // Rebuild if we require different pruning
+                    }
+
                     let Some(is_required_on_disk) =
                         self.check_if_sparse_vectors_index_on_disk(vector_name)
                     else {
@@ -225,9 +285,54 @@ impl SegmentOptimizer for ConfigMismatchOptimizer {
         }
     }
 
+    fn trigger(&self) -> String {
+        "segment config differs from the collection config".to_string()
+    }
+
     fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator> {
         &self.telemetry_durations_aggregator
     }
+
+    /// Rebuild only mismatching dense vectors, if nothing else in the segment mismatches
+    fn vectors_to_rebuild(
+        &self,
+        segment: &LockedSegment,
+    ) -> CollectionResult<Vec<(VectorNameBuf, VectorDataConfig)>> {
+        let LockedSegment::Original(raw_segment) = segment else {
+            return Ok(Vec::new());
+        };
+
+        let target_config = self.optimized_segment_config(std::slice::from_ref(segment))?;
+        let segment = raw_segment.read();
+        let current_config = &segment.segment_config;
+
+        if segment.appendable_flag
+            || current_config.payload_storage_type != target_config.payload_storage_type
+            || current_config.sparse_vector_data != target_config.sparse_vector_data
+            || current_config.vector_data.len() != target_config.vector_data.len()
+        {
+            return Ok(Vec::new());
+        }
+
+        let mut vectors_to_rebuild = Vec::new();
+        for (vector_name, target_vector_config) in target_config.vector_data {
+            let Some(current_vector_config) = current_config.vector_data.get(&vector_name) else {
+                return Ok(Vec::new());
+            };
+            if *current_vector_config == target_vector_config {
+                continue;
+            }
+            if segment
+                .check_vector_rebuild(&vector_name, &target_vector_config)
+                .is_err()
+            {
+                return Ok(Vec::new());
+            }
+            vectors_to_rebuild.push((vector_name, target_vector_config));
+        }
+
+        Ok(vectors_to_rebuild)
+    }
 }
 
 #[cfg(test)]
@@ -275,6 +380,7 @@ mod tests {
             max_segment_size_kb: usize::MAX,
             memmap_threshold_kb: usize::MAX,
             indexing_threshold_kb: 10,
+            max_builder_memory_kb: None,
         };
         let collection_params = CollectionParams {
             vectors: VectorsConfig::Single(
@@ -301,6 +407,8 @@ mod tests {
             on_disk: None,
             payload_m: None,
             inline_storage: None,
+            links_compression: None,
+            tenant_subgraphs: None,
         };
 
         // Optimizers used in test
@@ -393,6 +501,7 @@ mod tests {
             max_segment_size_kb: usize::MAX,
             memmap_threshold_kb: usize::MAX,
             indexing_threshold_kb: 10,
+            max_builder_memory_kb: None,
         };
         let hnsw_config_vector1 = HnswConfigDiff {
             m: Some(10),
@@ -440,6 +549,8 @@ mod tests {
             on_disk: None,
             payload_m: None,
             inline_storage: None,
+            links_compression: None,
+            tenant_subgraphs: None,
         };
 
         // Optimizers used in test
@@ -545,6 +656,7 @@ mod tests {
             max_segment_size_kb: usize::MAX,
             memmap_threshold_kb: usize::MAX,
             indexing_threshold_kb: 10,
+            max_builder_memory_kb: None,
         };
         let quantization_config_vector1 =
             QuantizationConfig::Scalar(segment::types::ScalarQuantization {
@@ -675,4 +787,91 @@ mod tests {
                 );
             });
     }
+
+    /// Quantization enabled for a vector of an indexed segment is applied by rebuilding the
+    /// vector in place, the segment itself is kept.
+    #[test]
+    fn test_quantization_config_mismatch_in_place() {
+        let (point_count, dim) = (1000, 10);
+        let thresholds_config = OptimizerThresholds {
+            max_segment_size_kb: usize::MAX,
+            memmap_threshold_kb: usize::MAX,
+            indexing_threshold_kb: 10,
+            max_builder_memory_kb: None,
+        };
+        let collection_params = CollectionParams {
+            vectors: VectorsConfig::Single(
+                VectorParamsBuilder::new(dim as u64, Distance::Dot)
+                    .with_on_disk(true)
+                    .build(),
+            ),
+            ..CollectionParams::empty()
+        };
+
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let mut holder = SegmentHolder::default();
+        let segment_id = holder.add_new(random_segment(dir.path(), 100, point_count, dim));
+        let locked_holder = LockedSegmentHolder::new(holder);
+
+        let index_optimizer = IndexingOptimizer::new(
+            2,
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params.clone(),
+            Default::default(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+        let mut config_mismatch_optimizer = ConfigMismatchOptimizer::new(
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params,
+            Default::default(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+
+        let changed = index_optimizer.optimize_for_test(locked_holder.clone(), vec![segment_id]);
+        assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+        let quantization_config = QuantizationConfig::Scalar(segment::types::ScalarQuantization {
+            scalar: ScalarQuantizationConfig {
+                r#type: ScalarType::Int8,
+                quantile: None,
+                always_ram: Some(true),
+            },
+        });
+        config_mismatch_optimizer.quantization_config = Some(quantization_config.clone());
+
+        let suggested_to_optimize =
+            config_mismatch_optimizer.plan_optimizations_for_test(&locked_holder);
+        let suggested_to_optimize = suggested_to_optimize.into_iter().exactly_one().unwrap();
+        let indexed_segment_id = *suggested_to_optimize.iter().exactly_one().unwrap();
+
+        let changed = config_mismatch_optimizer
+            .optimize_for_test(locked_holder.clone(), suggested_to_optimize);
+        assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+        // Same segment, with the vector rebuilt
+        let holder = locked_holder.read();
+        assert_eq!(holder.len(), 2);
+        let LockedSegment::Original(segment) = holder.get(indexed_segment_id).unwrap() else {
+            panic!("segment must not stay proxied");
+        };
+        let segment = segment.read();
+        assert_eq!(
+            segment.config().vector_data[DEFAULT_VECTOR_NAME].quantization_config,
+            Some(quantization_config),
+        );
+        assert!(
+            segment.vector_data[DEFAULT_VECTOR_NAME]
+                .quantized_vectors
+                .borrow()
+                .is_some()
+        );
+        assert!(!config_mismatch_optimizer.has_config_mismatch(&*segment));
+    }
 }
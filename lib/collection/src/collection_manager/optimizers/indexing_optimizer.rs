@@ -80,7 +80,8 @@ impl IndexingOptimizer {
                 let is_big_for_index = storage_size_bytes >= indexing_threshold_bytes;
                 let is_big_for_mmap = storage_size_bytes >= mmap_threshold_bytes;
 
-                let optimize_for_index = is_big_for_index && !is_indexed;
+                let requires_index = vector_config.index.unwrap_or_default().is_indexed();
+                let optimize_for_index = requires_index && is_big_for_index && !is_indexed;
                 let optimize_for_mmap = if let Some(on_disk_config) = vector_config.on_disk {
                     on_disk_config && !is_on_disk
                 } else {
@@ -221,6 +222,15 @@ impl SegmentOptimizer for IndexingOptimizer {
         }
     }
 
+    fn trigger(&self) -> String {
+        format!(
+            "vectors above indexing_threshold {} KB without index, or above \
+             memmap_threshold {} KB or with `on_disk` in memory",
+            self.thresholds_config.indexing_threshold_kb,
+            self.thresholds_config.memmap_threshold_kb,
+        )
+    }
+
     fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator> {
         &self.telemetry_durations_aggregator
     }
@@ -229,6 +239,7 @@ impl SegmentOptimizer for IndexingOptimizer {
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
+    use std::sync::atomic::AtomicBool;
 
     use common::counter::hardware_counter::HardwareCounterCell;
     use fs_err as fs;
@@ -306,6 +317,7 @@ mod tests {
                 max_segment_size_kb: 300,
                 memmap_threshold_kb: 1000,
                 indexing_threshold_kb: 1000,
+                max_builder_memory_kb: None,
             },
             segments_dir.path().to_owned(),
             segments_temp_dir.path().to_owned(),
@@ -394,6 +406,7 @@ mod tests {
                 max_segment_size_kb: 300,
                 memmap_threshold_kb: 1000,
                 indexing_threshold_kb: 1000,
+                max_builder_memory_kb: None,
             },
             segments_dir.path().to_owned(),
             segments_temp_dir.path().to_owned(),
@@ -461,6 +474,7 @@ mod tests {
                 field_name: payload_field.clone(),
                 field_schema: Some(PayloadSchemaType::Integer.into()),
             }),
+            &AtomicBool::new(false),
             &hw_counter,
         )
         .unwrap();
@@ -677,6 +691,7 @@ mod tests {
                 max_segment_size_kb: 1000,
                 memmap_threshold_kb: 1000,
                 indexing_threshold_kb: 10, // Always optimize
+                max_builder_memory_kb: None,
             },
             segments_dir.path().to_owned(),
             segments_temp_dir.path().to_owned(),
@@ -743,6 +758,7 @@ mod tests {
             max_segment_size_kb: usize::MAX,
             memmap_threshold_kb: 10,
             indexing_threshold_kb: usize::MAX,
+            max_builder_memory_kb: None,
         };
         let mut collection_params = CollectionParams {
             vectors: VectorsConfig::Single(
@@ -771,6 +787,8 @@ mod tests {
             on_disk: None,
             payload_m: None,
             inline_storage: None,
+            links_compression: None,
+            tenant_subgraphs: None,
         };
 
         {
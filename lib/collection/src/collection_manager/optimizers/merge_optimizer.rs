@@ -165,6 +165,14 @@ impl SegmentOptimizer for MergeOptimizer {
         }
     }
 
+    fn trigger(&self) -> String {
+        format!(
+            "more than default_segment_number {} segments, merged while below \
+             max_segment_size {} KB",
+            self.default_segments_number, self.thresholds_config.max_segment_size_kb,
+        )
+    }
+
     fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator> {
         &self.telemetry_durations_aggregator
     }
@@ -1,5 +1,6 @@
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use chrono::{DateTime, Utc};
 use common::progress_tracker::{ProgressTracker, ProgressView, new_progress_tracker};
@@ -10,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use shard::segment_holder::SegmentId;
 use uuid::Uuid;
 
-use crate::operations::types::{Optimization, OptimizationSegmentInfo};
+use crate::operations::types::{Optimization, OptimizationDecision, OptimizationSegmentInfo};
 pub mod config_mismatch_optimizer;
 pub mod indexing_optimizer;
 pub mod merge_optimizer;
@@ -22,10 +23,22 @@ pub mod vacuum_optimizer;
 /// Will never remove older trackers for failed or still ongoing optimizations.
 const KEEP_LAST_TRACKERS: usize = 16;
 
+/// Number of last optimizer decisions to keep in tracker log
+const KEEP_LAST_DECISIONS: usize = 64;
+
+/// Name of payload index builds, which can be suspended along with the optimizers
+pub const PAYLOAD_INDEX_BUILD: &str = "payload_index";
+
 /// A log of optimizer trackers holding their status
 #[derive(Default, Clone, Debug)]
 pub struct TrackerLog {
     descriptions: VecDeque<Tracker>,
+    /// Optimizers which were cancelled, and are not started again until resumed
+    suspended: HashSet<&'static str>,
+    /// Flag to stop payload index builds, set while they are suspended
+    index_builds_stopped: Arc<AtomicBool>,
+    /// Latest decisions of the optimizers, oldest first
+    decisions: VecDeque<OptimizationDecision>,
 }
 
 impl TrackerLog {
@@ -73,6 +86,90 @@ impl TrackerLog {
     pub fn iter(&self) -> impl Iterator<Item = &Tracker> {
         self.descriptions.iter()
     }
+
+    /// Cancel ongoing optimizations of the given optimizers, and suspend these optimizers
+    /// until [`TrackerLog::resume`] is called.
+    ///
+    /// Cancelled optimizations leave their original segments in place. If
+    /// [`PAYLOAD_INDEX_BUILD`] is given, payload index builds are stopped too, see
+    /// [`TrackerLog::index_builds_stopped`].
+    ///
+    /// Returns the number of cancelled optimizations.
+    pub fn suspend(&mut self, optimizers: &[&'static str]) -> usize {
+        self.suspended.extend(optimizers);
+        if optimizers.contains(&PAYLOAD_INDEX_BUILD) {
+            self.index_builds_stopped.store(true, Ordering::Relaxed);
+        }
+        self.descriptions
+            .iter()
+            .filter(|tracker| optimizers.contains(&tracker.name))
+            .filter(|tracker| tracker.state.lock().status.is_running())
+            .inspect(|tracker| tracker.cancel())
+            .count()
+    }
+
+    /// Allow all suspended optimizers and payload index builds to start again
+    pub fn resume(&mut self) {
+        self.suspended.clear();
+        self.index_builds_stopped.store(false, Ordering::Relaxed);
+    }
+
+    /// Flag, which is set while payload index builds are suspended.
+    ///
+    /// Index builds fail as cancelled while it is set. Their operations are kept as failed, and
+    /// are applied again once resumed.
+    pub fn index_builds_stopped(&self) -> Arc<AtomicBool> {
+        self.index_builds_stopped.clone()
+    }
+
+    pub fn is_suspended(&self, optimizer: &str) -> bool {
+        self.suspended.contains(optimizer)
+    }
+
+    /// Record a decision of the optimizer, forgetting the oldest ones.
+    ///
+    /// Same decision about the same segments is made on every planning round until the
+    /// situation changes, so it is merged with the previous record instead of being repeated.
+    ///
+    /// Returns `true` if the decision was not recorded before.
+    pub fn record_decision(&mut self, decision: OptimizationDecision) -> bool {
+        let same_decision = self.decisions.iter().position(|recorded| {
+            recorded.optimizer == decision.optimizer
+                && recorded.action == decision.action
+                && recorded.uuid == decision.uuid
+                && recorded.reason == decision.reason
+                && recorded
+                    .segments
+                    .iter()
+                    .map(|segment| segment.uuid)
+                    .eq(decision.segments.iter().map(|segment| segment.uuid))
+        });
+
+        let is_new = match same_decision.and_then(|index| self.decisions.remove(index)) {
+            Some(recorded) => {
+                self.decisions.push_back(OptimizationDecision {
+                    repeated: recorded.repeated + 1,
+                    ..decision
+                });
+                false
+            }
+            None => {
+                self.decisions.push_back(decision);
+                true
+            }
+        };
+
+        while self.decisions.len() > KEEP_LAST_DECISIONS {
+            self.decisions.pop_front();
+        }
+
+        is_new
+    }
+
+    /// Latest decisions of the optimizers, newest first
+    pub fn decisions(&self) -> impl Iterator<Item = &OptimizationDecision> {
+        self.decisions.iter().rev()
+    }
 }
 
 /// Tracks the state of an optimizer
@@ -88,6 +185,8 @@ pub struct Tracker {
     pub state: Arc<Mutex<TrackerState>>,
     /// A read-only view to progress tracker
     pub progress_view: ProgressView,
+    /// Flag to stop the optimization
+    stopped: Arc<AtomicBool>,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -124,10 +223,21 @@ impl Tracker {
             segments,
             state: Default::default(),
             progress_view,
+            stopped: Arc::new(AtomicBool::new(false)),
         };
         (tracker, progress_tracker)
     }
 
+    /// Flag to stop the optimization, to be checked by the optimizer
+    pub fn stopped(&self) -> Arc<AtomicBool> {
+        self.stopped.clone()
+    }
+
+    /// Ask the optimization to stop
+    pub fn cancel(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
     /// Get handle to this tracker, allows updating state
     pub fn handle(&self) -> TrackerHandle {
         self.state.clone().into()
@@ -242,3 +352,98 @@ impl TrackerStatus {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools as _;
+
+    use super::*;
+    use crate::operations::types::{OptimizationAction, OptimizationCandidateInfo};
+
+    #[test]
+    fn test_suspend_cancels_running_trackers() {
+        let mut log = TrackerLog::default();
+
+        let (running, _progress) = Tracker::start("vacuum", Uuid::new_v4(), vec![]);
+        let (done, _progress) = Tracker::start("vacuum", Uuid::new_v4(), vec![]);
+        done.handle().update(TrackerStatus::Done);
+        let (other, _progress) = Tracker::start("indexing", Uuid::new_v4(), vec![]);
+        let (running_stopped, done_stopped, other_stopped) =
+            (running.stopped(), done.stopped(), other.stopped());
+        log.register(running);
+        log.register(done);
+        log.register(other);
+
+        assert_eq!(log.suspend(&["vacuum"]), 1);
+        assert!(running_stopped.load(Ordering::Relaxed));
+        assert!(!done_stopped.load(Ordering::Relaxed));
+        assert!(!other_stopped.load(Ordering::Relaxed));
+        assert!(log.is_suspended("vacuum"));
+        assert!(!log.is_suspended("indexing"));
+
+        log.resume();
+        assert!(!log.is_suspended("vacuum"));
+    }
+
+    #[test]
+    fn test_suspend_index_builds() {
+        let mut log = TrackerLog::default();
+        let index_builds_stopped = log.index_builds_stopped();
+
+        log.suspend(&["vacuum"]);
+        assert!(!index_builds_stopped.load(Ordering::Relaxed));
+
+        assert_eq!(log.suspend(&[PAYLOAD_INDEX_BUILD]), 0);
+        assert!(index_builds_stopped.load(Ordering::Relaxed));
+
+        log.resume();
+        assert!(!index_builds_stopped.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_record_repeated_decisions() {
+        let segment_uuid = Uuid::new_v4();
+        let decision = |optimizer: &str, uuid: Option<Uuid>| OptimizationDecision {
+            optimizer: optimizer.to_string(),
+            trigger: format!("{optimizer} condition"),
+            segments: vec![OptimizationCandidateInfo {
+                uuid: segment_uuid,
+                points_count: 10,
+                deleted_count: 10,
+                deleted_ratio: 0.5,
+                vectors_size_bytes: 1024,
+            }],
+            action: match uuid {
+                Some(_) => OptimizationAction::Started,
+                None => OptimizationAction::Skipped,
+            },
+            reason: uuid.is_none().then(|| "optimizer is suspended".to_string()),
+            uuid,
+            estimated_points: 10,
+            estimated_vectors_size_bytes: 1024,
+            decided_at: Utc::now(),
+            repeated: 1,
+        };
+
+        let mut log = TrackerLog::default();
+        assert!(log.record_decision(decision("vacuum", None)));
+        assert!(log.record_decision(decision("merge", None)));
+        assert!(!log.record_decision(decision("vacuum", None)));
+        assert!(log.record_decision(decision("vacuum", Some(Uuid::new_v4()))));
+        assert!(log.record_decision(decision("vacuum", Some(Uuid::new_v4()))));
+
+        let decisions = log.decisions().collect_vec();
+        assert_eq!(decisions.len(), 4);
+        // repeated decision is merged with the recorded one
+        assert_eq!(decisions[2].optimizer, "vacuum");
+        assert_eq!(decisions[2].repeated, 2);
+        assert_eq!(decisions[3].optimizer, "merge");
+        assert!(decisions[..2].iter().all(|d| d.repeated == 1));
+
+        for _ in 0..KEEP_LAST_DECISIONS {
+            log.record_decision(decision("indexing", Some(Uuid::new_v4())));
+        }
+        assert_eq!(log.decisions().count(), KEEP_LAST_DECISIONS);
+        assert!(log.decisions().all(|d| d.optimizer == "indexing"));
+    }
+}
@@ -20,10 +20,11 @@ use segment::common::operation_time_statistics::{
 use segment::entry::entry_point::NonAppendableSegmentEntry;
 use segment::index::sparse_index::sparse_index_config::SparseIndexType;
 use segment::segment::{Segment, SegmentVersion};
-use segment::segment_constructor::build_segment;
 use segment::segment_constructor::segment_builder::SegmentBuilder;
+use segment::segment_constructor::{VectorIndexBuildArgs, build_segment};
 use segment::types::{
-    HnswConfig, HnswGlobalConfig, Indexes, QuantizationConfig, SegmentConfig, VectorStorageType,
+    HnswConfig, HnswGlobalConfig, Indexes, QuantizationConfig, SegmentConfig, VectorDataConfig,
+    VectorNameBuf, VectorStorageType,
 };
 use shard::proxy_segment::{DeletedPoints, ProxyIndexChanges};
 use shard::segment_holder::locked::LockedSegmentHolder;
@@ -43,6 +44,8 @@ pub struct OptimizerThresholds {
     pub max_segment_size_kb: usize,
     pub memmap_threshold_kb: usize,
     pub indexing_threshold_kb: usize,
+    /// Memory budget for point mappings in the segment builder, unlimited if not set
+    pub max_builder_memory_kb: Option<usize>,
 }
 
 /// SegmentOptimizer - trait implementing common functionality of the optimizers
@@ -81,6 +84,9 @@ pub trait SegmentOptimizer {
     /// Find segments that require optimization and write them into `planner`.
     fn plan_optimizations(&self, planner: &mut OptimizationPlanner);
 
+    /// Condition, with its current thresholds, under which this optimizer selects segments
+    fn trigger(&self) -> String;
+
     /// Wrapper around [`SegmentOptimizer::plan_optimizations`].
     /// Simplified interface and extra checks.
     #[cfg(test)]
@@ -119,6 +125,17 @@ pub trait SegmentOptimizer {
 
     fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator>;
 
+    /// Vectors of the segment, which should be rebuilt in place with the given configs, instead
+    /// of building a new segment.
+    ///
+    /// Only used if a single segment is optimized. Empty if the segment must be rebuilt entirely.
+    fn vectors_to_rebuild(
+        &self,
+        _segment: &LockedSegment,
+    ) -> CollectionResult<Vec<(VectorNameBuf, VectorDataConfig)>> {
+        Ok(Vec::new())
+    }
+
     /// Build temp segment
     fn temp_segment(&self, save_version: bool) -> CollectionResult<LockedSegment> {
         let collection_params = self.collection_params();
@@ -127,6 +144,7 @@ pub trait SegmentOptimizer {
             vector_data: collection_params.to_base_vector_data(quantization_config.as_ref())?,
             sparse_vector_data: collection_params.to_sparse_vector_data()?,
             payload_storage_type: collection_params.payload_storage_type(),
+            payload_overflow_threshold: collection_params.payload_overflow_threshold,
         };
         Ok(LockedSegment::new(build_segment(
             self.segments_path(),
@@ -225,6 +243,28 @@ pub trait SegmentOptimizer {
         &self,
         optimizing_segments: &[LockedSegment],
     ) -> CollectionResult<SegmentBuilder> {
+        let optimized_config = self.optimized_segment_config(optimizing_segments)?;
+
+        let mut segment_builder = SegmentBuilder::new(
+            self.temp_path(),
+            &optimized_config,
+            self.hnsw_global_config(),
+        )?;
+
+        segment_builder.set_memory_budget(
+            self.threshold_config()
+                .max_builder_memory_kb
+                .map(|kb| kb.saturating_mul(BYTES_IN_KB)),
+        );
+
+        Ok(segment_builder)
+    }
+
+    /// Config of the segment, optimized from the given segments
+    fn optimized_segment_config(
+        &self,
+        optimizing_segments: &[LockedSegment],
+    ) -> CollectionResult<SegmentConfig> {
         // Example:
         //
         // S1: {
@@ -285,13 +325,17 @@ pub trait SegmentOptimizer {
         if threshold_is_indexed {
             let collection_hnsw = self.hnsw_config();
             vector_data.iter_mut().for_each(|(vector_name, config)| {
-                // Assign HNSW index
-                let param_hnsw = collection_params
-                    .vectors
-                    .get_params(vector_name)
-                    .and_then(|params| params.hnsw_config);
-                let vector_hnsw = collection_hnsw.update_opt(param_hnsw.as_ref());
-                config.index = Indexes::Hnsw(vector_hnsw);
+                // Assign HNSW index, unless vector is configured to be store-only
+                let vector_params = collection_params.vectors.get_params(vector_name);
+                let is_index_enabled = vector_params
+                    .and_then(|params| params.index)
+                    .unwrap_or_default()
+                    .is_indexed();
+                if is_index_enabled {
+                    let param_hnsw = vector_params.and_then(|params| params.hnsw_config);
+                    let vector_hnsw = collection_hnsw.update_opt(param_hnsw.as_ref());
+                    config.index = Indexes::Hnsw(vector_hnsw);
+                }
 
                 // Assign quantization config
                 let param_quantization = collection_params
@@ -339,6 +383,16 @@ pub trait SegmentOptimizer {
             });
         }
 
+        // Read-only storage backends can only store vectors of immutable segments
+        vector_data.iter_mut().for_each(|(vector_name, config)| {
+            if config.storage_backend.is_none() && !config.is_appendable() {
+                config.storage_backend = collection_params
+                    .vectors
+                    .get_params(vector_name)
+                    .and_then(|params| params.storage_backend.clone());
+            }
+        });
+
         sparse_vector_data
             .iter_mut()
             .for_each(|(vector_name, config)| {
@@ -364,17 +418,12 @@ pub trait SegmentOptimizer {
                 }
             });
 
-        let optimized_config = SegmentConfig {
+        Ok(SegmentConfig {
             vector_data,
             sparse_vector_data,
             payload_storage_type: collection_params.payload_storage_type(),
-        };
-
-        Ok(SegmentBuilder::new(
-            self.temp_path(),
-            &optimized_config,
-            self.hnsw_global_config(),
-        )?)
+            payload_overflow_threshold: collection_params.payload_overflow_threshold,
+        })
     }
 
     /// Restores original segments from proxies
@@ -681,6 +730,11 @@ pub trait SegmentOptimizer {
         // Check that we have enough disk space for optimization
         self.check_segments_size(&input_segments)?;
 
+        let vectors_to_rebuild = match input_segments.as_slice() {
+            [segment] => self.vectors_to_rebuild(segment)?,
+            _ => Vec::new(),
+        };
+
         check_process_stopped(stopped)?;
 
         on_successful_start();
@@ -758,6 +812,34 @@ pub trait SegmentOptimizer {
             (proxy_ids, cow_segment_id_opt, counter_handler)
         };
 
+        if !vectors_to_rebuild.is_empty() {
+            let result = self.rebuild_vectors_in_place(
+                &segment_holder,
+                &input_segments[0],
+                vectors_to_rebuild,
+                locked_proxies,
+                &proxy_ids,
+                cow_segment_id_opt,
+                permit,
+                resource_budget,
+                stopped,
+                &hw_counter,
+                progress,
+            );
+
+            let points_count = match result {
+                Ok(points_count) => points_count,
+                Err(err) => {
+                    self.unwrap_proxy(&segment_holder, &proxy_ids)?;
+                    return Err(err);
+                }
+            };
+
+            drop(counter_handler);
+            timer.set_success(true);
+            return Ok(points_count);
+        }
+
         // SLOW PART: create single optimized segment and propagate all new changes to it
         let result = self.optimize_segment_propagate_changes(
             input_segments,
@@ -913,6 +995,137 @@ pub trait SegmentOptimizer {
         Ok((optimized_segment, already_remove_points))
     }
 
+    /// Rebuild vectors of a single proxied segment in place, propagate changes made through the
+    /// proxy, and put the segment back instead of the proxy.
+    ///
+    /// Payload storage, payload index and other vectors of the segment are left untouched.
+    #[allow(clippy::too_many_arguments)]
+    fn rebuild_vectors_in_place(
+        &self,
+        segment_holder: &LockedSegmentHolder,
+        segment: &LockedSegment,
+        vectors_to_rebuild: Vec<(VectorNameBuf, VectorDataConfig)>,
+        locked_proxies: Vec<LockedSegment>,
+        proxy_ids: &[SegmentId],
+        cow_segment_id_opt: Option<SegmentId>,
+        permit: ResourcePermit,
+        resource_budget: ResourceBudget,
+        stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+        progress: ProgressTracker,
+    ) -> CollectionResult<usize> {
+        let LockedSegment::Original(segment) = segment else {
+            return Err(CollectionError::service_error(
+                "Proxy segment is not expected here".to_string(),
+            ));
+        };
+
+        // ---- SLOW PART -----
+
+        let desired_cpus = permit.num_io as usize;
+        let indexing_permit = resource_budget
+            .replace_with(permit, desired_cpus, 0, stopped)
+            .map_err(|_| {
+                CollectionError::cancelled("optimization cancelled while waiting for budget")
+            })?;
+        let indexing_permit = Arc::new(indexing_permit);
+
+        let mut rng = rand::rng();
+        let mut rebuilt_vectors = Vec::with_capacity(vectors_to_rebuild.len());
+        {
+            // Segment is proxied, it is not changed until the rebuilt vectors are applied
+            let segment_read = segment.read();
+            for (vector_name, vector_config) in vectors_to_rebuild {
+                let old_index = segment_read.vector_data[&vector_name].vector_index.clone();
+                let rebuilt = segment_read.rebuild_vector(
+                    &vector_name,
+                    vector_config,
+                    VectorIndexBuildArgs {
+                        permit: indexing_permit.clone(),
+                        old_indices: &[old_index],
+                        gpu_device: None,
+                        rng: &mut rng,
+                        stopped,
+                        hnsw_global_config: self.hnsw_global_config(),
+                        feature_flags: common::flags::feature_flags(),
+                        progress: progress.running_subtask(vector_name.as_str()),
+                    },
+                )?;
+                rebuilt_vectors.push(rebuilt);
+            }
+        }
+
+        // ---- SLOW PART ENDS HERE -----
+
+        check_process_stopped(stopped)?;
+
+        // This block locks all write operations with collection. It should be fast.
+        let upgradable_segment_holder = segment_holder.upgradable_read();
+
+        // This mutex prevents update operations, which could create inconsistency during transition.
+        let update_guard = segment_holder.acquire_updates_lock();
+
+        let point_count = {
+            let mut segment_write = segment.write();
+
+            // Apply index changes before point deletions
+            // Point deletions bump the segment version, can cause index changes to be ignored
+            let proxy_index_changes = self.proxy_index_changes(&locked_proxies);
+            for (field_name, change) in proxy_index_changes.iter_ordered() {
+                match change {
+                    ProxyIndexChange::Create(schema, version) => {
+                        segment_write.create_field_index(
+                            *version,
+                            field_name,
+                            Some(schema),
+                            hw_counter,
+                        )?;
+                    }
+                    ProxyIndexChange::Delete(version) => {
+                        segment_write.delete_field_index(*version, field_name)?;
+                    }
+                    ProxyIndexChange::DeleteIfIncompatible(version, schema) => {
+                        segment_write
+                            .delete_field_index_if_incompatible(*version, field_name, schema)?;
+                    }
+                }
+            }
+
+            for (point_id, versions) in self.proxy_deleted_points(&locked_proxies) {
+                segment_write.delete_point(versions.operation_version, point_id, hw_counter)?;
+            }
+
+            // Deleted vectors are carried over into the rebuilt storages
+            for rebuilt in rebuilt_vectors {
+                segment_write.apply_vector_rebuild(rebuilt)?;
+            }
+
+            segment_write.available_point_count()
+        };
+
+        // Replace proxy with the rebuilt segment
+        let mut writable_segment_holder =
+            RwLockUpgradableReadGuard::upgrade(upgradable_segment_holder);
+
+        for &proxy_id in proxy_ids {
+            writable_segment_holder.replace(proxy_id, LockedSegment::Original(segment.clone()))?;
+        }
+
+        if let Some(cow_segment_id) = cow_segment_id_opt {
+            // Temp segment might be taken into another parallel optimization
+            // so it is not necessary exist by this time
+            writable_segment_holder.remove_segment_if_not_needed(cow_segment_id)?;
+        }
+
+        drop(writable_segment_holder);
+        // Allow updates again
+        drop(update_guard);
+
+        drop(locked_proxies);
+
+        Ok(point_count)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn finish_optimization(
         &self,
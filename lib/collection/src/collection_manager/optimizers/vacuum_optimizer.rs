@@ -170,6 +170,14 @@ impl SegmentOptimizer for VacuumOptimizer {
         }
     }
 
+    fn trigger(&self) -> String {
+        format!(
+            "ratio of deleted points or indexed vectors above deleted_threshold {}, \
+             vacuum_min_vector_number {}",
+            self.deleted_threshold, self.min_vectors_number,
+        )
+    }
+
     fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator> {
         &self.telemetry_durations_aggregator
     }
@@ -284,6 +292,7 @@ mod tests {
                 max_segment_size_kb: 1000000,
                 memmap_threshold_kb: 1000000,
                 indexing_threshold_kb: 1000000,
+                max_builder_memory_kb: None,
             },
             dir.path().to_owned(),
             temp_dir.path().to_owned(),
@@ -365,6 +374,7 @@ mod tests {
             max_segment_size_kb: usize::MAX,
             memmap_threshold_kb: usize::MAX,
             indexing_threshold_kb: 10,
+            max_builder_memory_kb: None,
         };
         let collection_params = CollectionParams {
             vectors: VectorsConfig::Multi(BTreeMap::from([
@@ -415,6 +425,8 @@ mod tests {
             on_disk: None,
             payload_m: None,
             inline_storage: None,
+            links_compression: None,
+            tenant_subgraphs: None,
         };
 
         // Optimizers used in test
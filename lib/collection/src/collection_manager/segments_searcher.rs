@@ -2,7 +2,7 @@ use std::collections::BTreeSet;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::types::ScoreType;
 use futures::stream::FuturesUnordered;
@@ -14,8 +14,8 @@ use segment::data_types::modifier::Modifier;
 use segment::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use segment::data_types::vectors::QueryVector;
 use segment::types::{
-    Filter, Indexes, PointIdType, ScoredPoint, SearchParams, SegmentConfig, VectorName,
-    WithPayload, WithPayloadInterface, WithVector,
+    Filter, Indexes, PointIdType, ScoredPoint, SearchParams, SegmentConfig, SeqNumberType,
+    VectorName, WithPayload, WithPayloadInterface, WithVector,
 };
 use shard::common::stopping_guard::StoppingGuard;
 use shard::query::query_context::{fill_query_context, init_query_context};
@@ -24,12 +24,15 @@ use shard::retrieve::record_internal::RecordInternal;
 use shard::retrieve::retrieve_blocking::retrieve_blocking;
 use shard::search::CoreSearchRequestBatch;
 use shard::search_result_aggregator::BatchResultAggregator;
+use shard::segment_holder::SegmentId;
 use shard::segment_holder::locked::LockedSegmentHolder;
 use tokio::runtime::Handle;
 use tokio_util::task::AbortOnDropHandle;
 
+use crate::collection::partial_results::TimedOut;
 use crate::collection_manager::holders::segment_holder::LockedSegment;
 use crate::collection_manager::probabilistic_search_sampling::find_search_sampling_over_point_distribution;
+use crate::collection_manager::segments_usefulness::SegmentsUsefulness;
 use crate::config::CollectionConfigInternal;
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::optimizers_builder::DEFAULT_INDEXING_THRESHOLD_KB;
@@ -45,6 +48,11 @@ type BatchSearchResult = Vec<SegmentBatchSearchResult>;
 // Result of batch search in one segment
 type SegmentSearchExecutedResult = CollectionResult<(SegmentBatchSearchResult, Vec<bool>)>;
 
+/// Fraction of the timeout, after which searches allowing partial results are stopped.
+///
+/// The rest of the timeout is left to merge and complete the results found so far.
+pub(crate) const PARTIAL_RESULTS_TIMEOUT_FRACTION: f64 = 0.9;
+
 /// Simple implementation of segment manager
 ///  - rebuild segment for memory optimization purposes
 #[derive(Default)]
@@ -52,8 +60,12 @@ pub struct SegmentsSearcher;
 
 impl SegmentsSearcher {
     /// Execute searches in parallel and return results in the same order as the searches were provided
+    ///
+    /// If `timed_out` is set, segments which searches were stopped are skipped, and the request
+    /// is marked as timed out.
     async fn execute_searches(
         searches: Vec<AbortOnDropHandle<SegmentSearchExecutedResult>>,
+        timed_out: Option<&TimedOut>,
     ) -> CollectionResult<(BatchSearchResult, Vec<Vec<bool>>)> {
         let results_len = searches.len();
 
@@ -68,7 +80,14 @@ impl SegmentsSearcher {
         let mut further_searches_per_segment = vec![Vec::new(); results_len];
         // process results as they come in and store them in the correct order
         while let Some((idx, search_result)) = search_results_per_segment_res.try_next().await? {
-            let (search_results, further_searches) = search_result?;
+            let (search_results, further_searches) = match (search_result, timed_out) {
+                (Ok(result), _) => result,
+                (Err(CollectionError::Cancelled { .. }), Some(timed_out)) => {
+                    timed_out.mark();
+                    continue;
+                }
+                (Err(err), _) => return Err(err),
+            };
             debug_assert!(search_results.len() == further_searches.len());
             search_results_per_segment[idx] = search_results;
             further_searches_per_segment[idx] = further_searches;
@@ -144,7 +163,11 @@ impl SegmentsSearcher {
                 for segment_id in 0..number_segments {
                     let segment_lowest_score = lowest_scores_per_request[segment_id][batch_id];
                     let retrieved_points = retrieved_points_per_request[segment_id][batch_id];
-                    let have_further_results = further_results[segment_id][batch_id];
+                    // Results of stopped segments are empty
+                    let have_further_results = further_results[segment_id]
+                        .get(batch_id)
+                        .copied()
+                        .unwrap_or(false);
 
                     if have_further_results
                         && retrieved_points < required_limit
@@ -196,6 +219,13 @@ impl SegmentsSearcher {
                     .map(|params| params.modifier == Some(Modifier::Idf))
                     .unwrap_or(false)
             },
+            |vector_name| {
+                collection_config
+                    .params
+                    .get_sparse_vector_params_opt(vector_name)
+                    .and_then(|params| params.index)
+                    .and_then(|index| index.block_max)
+            },
         );
         let is_stopped = is_stopped_guard.get_is_stopped().clone();
         // Do blocking calls in a blocking task: `segment.get().read()` calls might block async runtime
@@ -206,6 +236,8 @@ impl SegmentsSearcher {
         Ok(task)
     }
 
+    /// If searches allow partial results and `timed_out` is given, segments which searches were
+    /// stopped are skipped, and the request is marked as timed out there.
     pub async fn search(
         segments: LockedSegmentHolder,
         batch_request: Arc<CoreSearchRequestBatch>,
@@ -213,10 +245,13 @@ impl SegmentsSearcher {
         sampling_enabled: bool,
         query_context: QueryContext,
         timeout: Duration,
+        timed_out: Option<&TimedOut>,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
         let start = Instant::now();
         let query_context_arc = Arc::new(query_context);
 
+        let timed_out = timed_out.filter(|_| allow_partial_results(&batch_request));
+
         // Using block to ensure `segments` variable is dropped in the end of it
         let (locked_segments, searches): (Vec<_>, Vec<_>) = {
             let segments: Vec<_> = {
@@ -280,7 +315,7 @@ impl SegmentsSearcher {
         // perform search on all segments concurrently
         // the resulting Vec is in the same order as the segment searches were provided.
         let (all_search_results_per_segment, further_results) =
-            Self::execute_searches(searches).await?;
+            Self::execute_searches(searches, timed_out).await?;
         debug_assert!(all_search_results_per_segment.len() == locked_segments.len());
 
         let (mut result_aggregator, searches_to_rerun) = Self::process_search_result_step1(
@@ -339,7 +374,7 @@ impl SegmentsSearcher {
             };
 
             let (secondary_search_results_per_segment, _) =
-                Self::execute_searches(secondary_searches).await?;
+                Self::execute_searches(secondary_searches, timed_out).await?;
 
             result_aggregator.update_point_versions(
                 secondary_search_results_per_segment
@@ -365,6 +400,175 @@ impl SegmentsSearcher {
         Ok(top_scores)
     }
 
+    /// Search segments from the most useful to the least useful one, until each request of the
+    /// batch has found `limit + offset` points above its score threshold.
+    ///
+    /// Segments are searched in parallel, in waves of growing size: one segment first, then two,
+    /// four, and so on. `passes_threshold` tells if a score, as returned by segments, passes the
+    /// threshold of the request with the given index in the batch.
+    ///
+    /// Segments which hold newer versions of the found points are always searched, so outdated
+    /// versions of points are never returned.
+    ///
+    /// If searches allow partial results and `timed_out` is given, results found so far are
+    /// returned once a segment search is stopped, and the request is marked as timed out there.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn search_with_early_exit(
+        segments: LockedSegmentHolder,
+        batch_request: Arc<CoreSearchRequestBatch>,
+        runtime_handle: &Handle,
+        segments_usefulness: &SegmentsUsefulness,
+        query_context: QueryContext,
+        timeout: Duration,
+        timed_out: Option<&TimedOut>,
+        passes_threshold: impl Fn(BatchOffset, ScoreType) -> bool,
+    ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
+        let start = Instant::now();
+        let query_context = Arc::new(query_context);
+
+        let timed_out = timed_out.filter(|_| allow_partial_results(&batch_request));
+
+        let mut segments: Vec<(SegmentId, LockedSegment)> = {
+            let Some(segments_lock) = segments.try_read_for(timeout) else {
+                return Err(CollectionError::timeout(timeout, "search"));
+            };
+            segments_lock
+                .iter()
+                .map(|(segment_id, segment)| (segment_id, segment.clone()))
+                .collect()
+        };
+        segments_usefulness.sort(&mut segments);
+
+        let limits: Vec<_> = batch_request
+            .searches
+            .iter()
+            .map(|request| request.limit + request.offset)
+            .collect();
+
+        let mut found_points = vec![AHashSet::new(); limits.len()];
+        let mut results_per_segment: BatchSearchResult = Vec::with_capacity(segments.len());
+        let mut searched = 0;
+        let mut wave_size = 1;
+
+        while searched < segments.len() {
+            let wave_end = (searched + wave_size).min(segments.len());
+            let searches = segments[searched..wave_end]
+                .iter()
+                .map(|(_, segment)| {
+                    let search = runtime_handle.spawn_blocking({
+                        let segment = segment.clone();
+                        let batch_request = batch_request.clone();
+                        let query_context = query_context.clone();
+                        let timeout = timeout.saturating_sub(start.elapsed());
+                        move || {
+                            let segment_query_context = query_context.get_segment_query_context();
+                            search_in_segment(
+                                segment,
+                                batch_request,
+                                false,
+                                &segment_query_context,
+                                timeout,
+                            )
+                        }
+                    });
+                    AbortOnDropHandle::new(search)
+                })
+                .collect();
+
+            // Stopped searches have no results, so segments and results stay aligned
+            let (wave_results, _) = Self::execute_searches(searches, timed_out).await?;
+
+            for segment_results in &wave_results {
+                for (batch_id, (found, points)) in
+                    found_points.iter_mut().zip(segment_results).enumerate()
+                {
+                    found.extend(
+                        points
+                            .iter()
+                            .filter(|point| passes_threshold(batch_id, point.score))
+                            .map(|point| point.id),
+                    );
+                }
+            }
+            results_per_segment.extend(wave_results);
+            searched = wave_end;
+            wave_size *= 2;
+
+            if timed_out.is_some_and(TimedOut::is_timed_out) {
+                break;
+            }
+
+            let found_enough = found_points
+                .iter()
+                .zip(&limits)
+                .all(|(found, limit)| found.len() >= *limit);
+
+            if !found_enough || searched == segments.len() {
+                continue;
+            }
+
+            // Stop, unless some of the remaining segments hold newer versions of found points
+            let point_versions: AHashMap<PointIdType, SeqNumberType> = results_per_segment
+                .iter()
+                .flatten()
+                .flatten()
+                .fold(AHashMap::new(), |mut versions, point| {
+                    let version = versions.entry(point.id).or_default();
+                    *version = (*version).max(point.version);
+                    versions
+                });
+            let remaining: Vec<_> = segments[searched..]
+                .iter()
+                .map(|(_, segment)| segment.clone())
+                .collect();
+            let outdated: Vec<usize> =
+                AbortOnDropHandle::new(runtime_handle.spawn_blocking(move || {
+                    remaining
+                        .iter()
+                        .positions(|segment| {
+                            let segment = segment.get().read();
+                            point_versions.iter().any(|(point_id, version)| {
+                                segment
+                                    .point_version(*point_id)
+                                    .is_some_and(|segment_version| segment_version > *version)
+                            })
+                        })
+                        .collect()
+                }))
+                .await?;
+
+            if outdated.is_empty() {
+                break;
+            }
+
+            // Search segments with newer versions in the next wave
+            for (position, offset) in outdated.iter().enumerate() {
+                segments.swap(searched + position, searched + offset);
+            }
+            wave_size = wave_size.max(outdated.len());
+        }
+
+        segments_usefulness.record(
+            segments
+                .iter()
+                .zip(&results_per_segment)
+                .map(|((segment_id, _), results)| {
+                    (*segment_id, results.iter().map(Vec::len).sum())
+                }),
+            segments.iter().map(|(segment_id, _)| *segment_id),
+        );
+
+        let mut result_aggregator = BatchResultAggregator::new(limits);
+        result_aggregator.update_point_versions(results_per_segment.iter().flatten().flatten());
+        for segment_results in results_per_segment {
+            for (batch_id, points) in segment_results.into_iter().enumerate() {
+                result_aggregator.update_batch_results(batch_id, points);
+            }
+        }
+
+        Ok(result_aggregator.into_topk())
+    }
+
     /// Retrieve records for the given points ids from the segments
     /// - if payload is enabled, payload will be fetched
     /// - if vector is enabled, vector will be fetched
@@ -541,6 +745,25 @@ struct BatchSearchParams<'a> {
     pub params: Option<&'a SearchParams>,
 }
 
+/// Check if all searches of the batch have a score threshold and allow early exit.
+pub(crate) fn use_early_exit(batch_request: &CoreSearchRequestBatch) -> bool {
+    !batch_request.searches.is_empty()
+        && batch_request.searches.iter().all(|request| {
+            request.score_threshold.is_some()
+                && request.params.is_some_and(|params| params.early_exit)
+        })
+}
+
+/// Check if all searches of the batch allow partial results, if stopped at the deadline.
+pub(crate) fn allow_partial_results(batch_request: &CoreSearchRequestBatch) -> bool {
+    !batch_request.searches.is_empty()
+        && batch_request.searches.iter().all(|request| {
+            request
+                .params
+                .is_some_and(|params| params.allow_partial_results.unwrap_or_default())
+        })
+}
+
 /// Returns suggested search sampling size for a given number of points and required limit.
 fn sampling_limit(
     limit: usize,
@@ -737,7 +960,6 @@ fn get_hnsw_ef_construct(config: &SegmentConfig, vector_name: &VectorName) -> Op
 mod tests {
     use std::sync::atomic::AtomicBool;
 
-    use ahash::AHashSet;
     use api::rest::SearchRequestInternal;
     use common::counter::hardware_counter::HardwareCounterCell;
     use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
@@ -824,6 +1046,7 @@ mod tests {
             true,
             QueryContext::new(DEFAULT_INDEXING_THRESHOLD_KB, hw_acc),
             TEST_TIMEOUT,
+            None,
         )
         .await
         .unwrap()
@@ -839,6 +1062,130 @@ mod tests {
         assert!(result[1].id == 3.into() || result[1].id == 11.into());
     }
 
+    #[tokio::test]
+    async fn test_segments_search_partial_results() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+        let segment_holder = build_test_holder(dir.path());
+
+        let search = |is_stopped: bool| {
+            let req = CoreSearchRequest {
+                query: vec![1.0, 1.0, 1.0, 1.0].into(),
+                with_payload: None,
+                with_vector: None,
+                filter: None,
+                params: Some(SearchParams {
+                    allow_partial_results: Some(true),
+                    ..Default::default()
+                }),
+                limit: 5,
+                score_threshold: None,
+                offset: 0,
+            };
+            let batch_request = CoreSearchRequestBatch {
+                searches: vec![req],
+            };
+
+            let query_context =
+                QueryContext::new(DEFAULT_INDEXING_THRESHOLD_KB, HwMeasurementAcc::new())
+                    .with_is_stopped(Arc::new(AtomicBool::new(is_stopped)));
+            let segment_holder = segment_holder.clone();
+            async move {
+                let timed_out = TimedOut::default();
+                let result = SegmentsSearcher::search(
+                    segment_holder,
+                    Arc::new(batch_request),
+                    &Handle::current(),
+                    true,
+                    query_context,
+                    TEST_TIMEOUT,
+                    Some(&timed_out),
+                )
+                .await
+                .unwrap()
+                .pop()
+                .unwrap();
+                (result, timed_out.is_timed_out())
+            }
+        };
+
+        // Complete searches are not marked
+        let (result, timed_out) = search(false).await;
+        assert_eq!(result.len(), 5);
+        assert!(!timed_out);
+
+        // Stopped segments are skipped, and results are marked as timed out
+        let (result, timed_out) = search(true).await;
+        assert!(result.is_empty());
+        assert!(timed_out);
+    }
+
+    #[tokio::test]
+    async fn test_segments_search_early_exit() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+        let segment_holder = build_test_holder(dir.path());
+        let segments_usefulness = SegmentsUsefulness::default();
+        let runtime_handle = Handle::current();
+
+        let search = |limit, score_threshold: ScoreType| {
+            let req = CoreSearchRequest {
+                query: vec![1.0, 1.0, 1.0, 1.0].into(),
+                with_payload: None,
+                with_vector: None,
+                filter: None,
+                params: Some(SearchParams {
+                    early_exit: true,
+                    ..Default::default()
+                }),
+                limit,
+                score_threshold: Some(score_threshold),
+                offset: 0,
+            };
+            let batch_request = CoreSearchRequestBatch {
+                searches: vec![req],
+            };
+            assert!(use_early_exit(&batch_request));
+
+            SegmentsSearcher::search_with_early_exit(
+                segment_holder.clone(),
+                Arc::new(batch_request),
+                &runtime_handle,
+                &segments_usefulness,
+                QueryContext::new(DEFAULT_INDEXING_THRESHOLD_KB, HwMeasurementAcc::new()),
+                TEST_TIMEOUT,
+                None,
+                move |_, score| score > score_threshold,
+            )
+        };
+
+        // Enough points are found in the first segment, the second one is not searched
+        let result = search(1, 0.5).await.unwrap().pop().unwrap();
+        let ids: Vec<_> = result.iter().map(|point| point.id).collect();
+        assert_eq!(ids, vec![3.into()]);
+
+        // Segment which was never searched goes first
+        let result = search(1, 0.5).await.unwrap().pop().unwrap();
+        let ids: Vec<_> = result.iter().map(|point| point.id).collect();
+        assert_eq!(ids, vec![11.into()]);
+
+        // Points below the threshold don't count, so both segments are searched
+        let result = search(2, 3.5).await.unwrap().pop().unwrap();
+        let ids: AHashSet<_> = result.iter().take(2).map(|point| point.id).collect();
+        assert_eq!(ids, AHashSet::from_iter([3.into(), 11.into()]));
+
+        // Second segment holds newer versions of points 4 and 5, so it's searched as well
+        let result = search(5, 0.5).await.unwrap().pop().unwrap();
+        assert_eq!(result.len(), 5);
+        assert!(result.iter().any(|point| point.id == 11.into()));
+        for point in &result {
+            if point.id == 4.into() {
+                assert_eq!(point.version, 7);
+            }
+            assert_ne!(point.id, 5.into());
+        }
+    }
+
     #[tokio::test]
     async fn test_segments_search_sampling() {
         let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
@@ -894,6 +1241,7 @@ mod tests {
                 false,
                 query_context,
                 TEST_TIMEOUT,
+                None,
             )
             .await
             .unwrap();
@@ -913,6 +1261,7 @@ mod tests {
                 true,
                 query_context,
                 TEST_TIMEOUT,
+                None,
             )
             .await
             .unwrap();
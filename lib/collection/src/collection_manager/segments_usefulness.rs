@@ -0,0 +1,88 @@
+use ahash::{AHashMap, AHashSet};
+use parking_lot::Mutex;
+use shard::segment_holder::SegmentId;
+
+#[derive(Debug, Default, Clone, Copy)]
+struct SegmentHits {
+    /// Number of searches executed in the segment
+    searches: u64,
+    /// Total number of results the segment returned
+    results: u64,
+}
+
+impl SegmentHits {
+    fn average(&self) -> f64 {
+        self.results as f64 / self.searches as f64
+    }
+}
+
+/// Statistics of how many results segments of a shard return, used to search segments in order
+/// of their usefulness once early exit is possible.
+#[derive(Debug, Default)]
+pub struct SegmentsUsefulness {
+    hits: Mutex<AHashMap<SegmentId, SegmentHits>>,
+}
+
+impl SegmentsUsefulness {
+    /// Sort segments from the most to the least useful one.
+    ///
+    /// Segments which were never searched come first, so we learn about them. Order of segments
+    /// with equal usefulness is preserved.
+    pub fn sort<T>(&self, segments: &mut [(SegmentId, T)]) {
+        let hits = self.hits.lock();
+        segments.sort_by(|(a, _), (b, _)| {
+            let a = hits.get(a).map(SegmentHits::average);
+            let b = hits.get(b).map(SegmentHits::average);
+            match (a, b) {
+                (None, None) => std::cmp::Ordering::Equal,
+                (None, Some(_)) => std::cmp::Ordering::Less,
+                (Some(_), None) => std::cmp::Ordering::Greater,
+                (Some(a), Some(b)) => b.total_cmp(&a),
+            }
+        });
+    }
+
+    /// Record number of results returned by searched segments.
+    ///
+    /// Statistics of segments which are not in `existing` anymore is dropped.
+    pub fn record(
+        &self,
+        searched: impl IntoIterator<Item = (SegmentId, usize)>,
+        existing: impl IntoIterator<Item = SegmentId>,
+    ) {
+        let mut hits = self.hits.lock();
+
+        let existing: AHashSet<_> = existing.into_iter().collect();
+        hits.retain(|segment_id, _| existing.contains(segment_id));
+
+        for (segment_id, results) in searched {
+            let segment_hits = hits.entry(segment_id).or_default();
+            segment_hits.searches += 1;
+            segment_hits.results += results as u64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments_usefulness_order() {
+        let usefulness = SegmentsUsefulness::default();
+        usefulness.record([(1, 0), (2, 10), (3, 5)], [1, 2, 3, 4]);
+        usefulness.record([(1, 2), (3, 5)], [1, 2, 3, 4]);
+
+        let mut segments = [(1, ()), (2, ()), (3, ()), (4, ())];
+        usefulness.sort(&mut segments);
+        let order: Vec<_> = segments.iter().map(|(id, _)| *id).collect();
+        assert_eq!(order, vec![4, 2, 3, 1]);
+
+        // Statistics of removed segments is dropped
+        usefulness.record([], [1, 3]);
+        let mut segments = [(3, ()), (2, ()), (1, ())];
+        usefulness.sort(&mut segments);
+        let order: Vec<_> = segments.iter().map(|(id, _)| *id).collect();
+        assert_eq!(order, vec![2, 3, 1]);
+    }
+}
@@ -306,6 +306,8 @@ fn test_delete_all_point_versions() {
                 payload: None,
                 shard_key: None,
                 order_value: None,
+                version: Some(101),
+                version_peer_id: None,
             }
         )])
     );
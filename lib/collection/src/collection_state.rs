@@ -3,6 +3,7 @@ use std::collections::{HashMap, HashSet};
 use ahash::AHashMap;
 use serde::{Deserialize, Serialize};
 
+use crate::collection::filter_templates::FilterTemplates;
 use crate::collection::payload_index_schema::PayloadIndexSchema;
 use crate::config::CollectionConfigInternal;
 use crate::shards::replica_set::replica_set_state::ReplicaState;
@@ -27,6 +28,8 @@ pub struct State {
     pub shards_key_mapping: ShardKeyMapping,
     #[serde(default)]
     pub payload_index_schema: PayloadIndexSchema,
+    #[serde(default)]
+    pub filter_templates: FilterTemplates,
 }
 
 impl State {
@@ -32,19 +32,71 @@ pub async fn retrieve_points(
     timeout: Option<Duration>,
     hw_measurement_acc: HwMeasurementAcc,
 ) -> CollectionResult<Vec<RecordInternal>> {
-    collection
+    // Cache is only used for default lookups, explicit consistency or shard selection bypass it
+    let use_cache =
+        read_consistency.is_none() && matches!(shard_selector, ShardSelectorInternal::All);
+
+    if !use_cache {
+        return collection
+            .retrieve(
+                PointRequestInternal {
+                    ids,
+                    with_payload: Some(WithPayloadInterface::Bool(false)),
+                    with_vector: WithVector::Selector(vector_names),
+                    with_version: None,
+                },
+                read_consistency,
+                shard_selector,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await;
+    }
+
+    // Versions are cheap to retrieve, vectors are only retrieved if the cached ones are outdated
+    let versions = collection
         .retrieve(
             PointRequestInternal {
                 ids,
                 with_payload: Some(WithPayloadInterface::Bool(false)),
-                with_vector: WithVector::Selector(vector_names),
+                with_vector: WithVector::Bool(false),
+                with_version: Some(true),
             },
             read_consistency,
             shard_selector,
             timeout,
-            hw_measurement_acc,
+            hw_measurement_acc.clone(),
         )
-        .await
+        .await?;
+
+    let cache = collection.referenced_vectors_cache();
+    let (mut records, missing_ids) = cache.get_many(&versions, &vector_names);
+
+    if !missing_ids.is_empty() {
+        let retrieved = collection
+            .retrieve(
+                PointRequestInternal {
+                    ids: missing_ids,
+                    with_payload: Some(WithPayloadInterface::Bool(false)),
+                    with_vector: WithVector::Selector(vector_names.clone()),
+                    with_version: Some(true),
+                },
+                read_consistency,
+                shard_selector,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await?;
+        cache.insert_many(&retrieved, &vector_names);
+        records.extend(retrieved);
+    }
+
+    for record in &mut records {
+        record.version = None;
+        record.version_peer_id = None;
+    }
+
+    Ok(records)
 }
 
 pub enum CollectionRefHolder<'a> {
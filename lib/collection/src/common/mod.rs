@@ -4,8 +4,10 @@ pub mod eta_calculator;
 pub mod fetch_vectors;
 pub mod file_utils;
 pub mod is_ready;
+pub mod referenced_vectors_cache;
 pub mod retrieve_request_trait;
 pub mod sha_256;
+pub mod snapshot_bandwidth;
 pub mod snapshot_stream;
 pub mod snapshots_manager;
 pub mod stoppable_task;
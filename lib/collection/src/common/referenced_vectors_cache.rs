@@ -0,0 +1,204 @@
+use ahash::{AHashMap, AHashSet};
+use parking_lot::Mutex;
+use segment::data_types::vectors::{VectorInternal, VectorStructInternal};
+use segment::types::{PointIdType, SeqNumberType, VectorNameBuf};
+use shard::retrieve::record_internal::RecordInternal;
+
+use crate::shards::shard::PeerId;
+
+/// Maximum number of cached vectors per collection.
+const CACHE_CAPACITY: usize = 1024;
+
+/// Version of a point, as reported by a replica.
+///
+/// Versions are assigned by each replica independently, so they are only compared together with
+/// the peer of the replica.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct PointVersion {
+    version: SeqNumberType,
+    peer_id: PeerId,
+}
+
+impl PointVersion {
+    fn of(record: &RecordInternal) -> Option<Self> {
+        Some(Self {
+            version: record.version?,
+            peer_id: record.version_peer_id?,
+        })
+    }
+}
+
+struct CachedVector {
+    /// Version of the point at the time the vector was retrieved
+    version: PointVersion,
+    /// `None` if the point has no vector with this name
+    vector: Option<VectorInternal>,
+}
+
+/// Small cache of vectors of recently referenced points, e.g. recommend examples.
+///
+/// Hot seed points are often used in many consecutive requests, this cache allows to resolve them
+/// without retrieving the same vectors from shards again. Cached vectors are keyed by the version
+/// of their point, so only versions have to be retrieved to check if cached vectors are current.
+#[derive(Default)]
+pub(crate) struct ReferencedVectorsCache {
+    vectors: Mutex<AHashMap<(PointIdType, VectorNameBuf), CachedVector>>,
+}
+
+impl ReferencedVectorsCache {
+    /// Split points into records, which have all of the `vector_names` cached for the current
+    /// point version, and IDs which have to be retrieved from the shards.
+    ///
+    /// `versions` are records with current versions of the points, points without a record don't
+    /// exist and are skipped.
+    pub fn get_many(
+        &self,
+        versions: &[RecordInternal],
+        vector_names: &[VectorNameBuf],
+    ) -> (Vec<RecordInternal>, Vec<PointIdType>) {
+        let vectors = self.vectors.lock();
+
+        let mut records = Vec::new();
+        let mut missing_ids = Vec::new();
+
+        'points: for version_record in versions {
+            let id = version_record.id;
+            let Some(version) = PointVersion::of(version_record) else {
+                missing_ids.push(id);
+                continue;
+            };
+
+            let mut named_vectors = Vec::with_capacity(vector_names.len());
+            for vector_name in vector_names {
+                let cached = vectors
+                    .get(&(id, vector_name.clone()))
+                    .filter(|cached| cached.version == version);
+                let Some(cached) = cached else {
+                    missing_ids.push(id);
+                    continue 'points;
+                };
+                if let Some(vector) = &cached.vector {
+                    named_vectors.push((vector_name.clone(), vector.clone()));
+                }
+            }
+
+            let mut record = RecordInternal::new_empty(id);
+            record.shard_key = version_record.shard_key.clone();
+            record.vector = Some(VectorStructInternal::Named(
+                named_vectors.into_iter().collect(),
+            ));
+            records.push(record);
+        }
+
+        (records, missing_ids)
+    }
+
+    /// Cache `vector_names` of retrieved records, which have a version.
+    pub fn insert_many(&self, records: &[RecordInternal], vector_names: &[VectorNameBuf]) {
+        let mut vectors = self.vectors.lock();
+
+        if vectors.len() + records.len() * vector_names.len() > CACHE_CAPACITY {
+            vectors.clear();
+        }
+
+        for record in records {
+            let Some(version) = PointVersion::of(record) else {
+                continue;
+            };
+            for vector_name in vector_names {
+                let vector = record
+                    .get_vector_by_name(vector_name)
+                    .map(|vector| vector.to_owned());
+                vectors.insert(
+                    (record.id, vector_name.clone()),
+                    CachedVector { version, vector },
+                );
+            }
+        }
+    }
+
+    /// Drop cached vectors of modified points, they won't match new point versions anyway.
+    ///
+    /// `None` means that any point could be modified, e.g. by an operation with a filter.
+    /// See [`CollectionUpdateOperations::point_ids`](crate::operations::CollectionUpdateOperations::point_ids).
+    pub fn invalidate(&self, point_ids: Option<Vec<PointIdType>>) {
+        let mut vectors = self.vectors.lock();
+        if vectors.is_empty() {
+            return;
+        }
+
+        match point_ids {
+            Some(point_ids) => {
+                let point_ids: AHashSet<_> = point_ids.into_iter().collect();
+                vectors.retain(|(point_id, _), _| !point_ids.contains(point_id));
+            }
+            None => vectors.clear(),
+        }
+    }
+
+    pub fn clear(&self) {
+        self.vectors.lock().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+
+    use super::*;
+
+    fn version(id: u64, version: SeqNumberType, peer_id: PeerId) -> RecordInternal {
+        let mut record = RecordInternal::new_empty(PointIdType::NumId(id));
+        record.version = Some(version);
+        record.version_peer_id = Some(peer_id);
+        record
+    }
+
+    fn record(id: u64, version: SeqNumberType, vector: Vec<f32>) -> RecordInternal {
+        let mut record = self::version(id, version, 1);
+        record.vector = Some(VectorStructInternal::Single(vector));
+        record
+    }
+
+    #[test]
+    fn test_referenced_vectors_cache() {
+        let cache = ReferencedVectorsCache::default();
+        let vector_names = vec![VectorNameBuf::from(DEFAULT_VECTOR_NAME)];
+
+        let versions = [version(1, 5, 1), version(2, 1, 1)];
+        let ids = vec![PointIdType::NumId(1), PointIdType::NumId(2)];
+        let (records, missing) = cache.get_many(&versions, &vector_names);
+        assert!(records.is_empty());
+        assert_eq!(missing, ids);
+
+        cache.insert_many(&[record(1, 5, vec![1.0, 2.0])], &vector_names);
+
+        let (records, missing) = cache.get_many(&versions, &vector_names);
+        assert_eq!(missing, vec![PointIdType::NumId(2)]);
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0]
+                .get_vector_by_name(DEFAULT_VECTOR_NAME)
+                .map(|vector| vector.to_owned()),
+            Some(VectorInternal::from(vec![1.0, 2.0])),
+        );
+
+        // Point was modified since the vector was cached
+        let (records, missing) = cache.get_many(&[version(1, 6, 1)], &vector_names);
+        assert!(records.is_empty());
+        assert_eq!(missing, vec![PointIdType::NumId(1)]);
+
+        // Version reported by another replica is not comparable
+        let (records, _) = cache.get_many(&[version(1, 5, 2)], &vector_names);
+        assert!(records.is_empty());
+
+        // Point doesn't exist anymore
+        let (records, missing) = cache.get_many(&[], &vector_names);
+        assert!(records.is_empty());
+        assert!(missing.is_empty());
+
+        cache.invalidate(Some(vec![PointIdType::NumId(1)]));
+        let (records, _) = cache.get_many(&[version(1, 5, 1)], &vector_names);
+        assert!(records.is_empty());
+    }
+}
@@ -0,0 +1,45 @@
+use common::bandwidth_limiter::BandwidthLimiter;
+use segment::common::BYTES_IN_MB;
+
+use crate::common::snapshots_manager::SnapshotsConfig;
+
+/// Limits snapshot uploads to the object storage.
+///
+/// Shared by all collections of this peer.
+pub static SNAPSHOT_UPLOAD_LIMITER: BandwidthLimiter = BandwidthLimiter::unlimited();
+
+/// Limits snapshot downloads from the object storage and from remote URLs,
+/// including snapshots received during shard snapshot transfers.
+///
+/// Shared by all collections of this peer.
+pub static SNAPSHOT_DOWNLOAD_LIMITER: BandwidthLimiter = BandwidthLimiter::unlimited();
+
+/// Apply bandwidth limits from the snapshots config
+pub fn init_snapshot_bandwidth_limits(config: &SnapshotsConfig) {
+    set_snapshot_bandwidth_limits(config.max_upload_mb_per_sec, config.max_download_mb_per_sec);
+}
+
+/// Change bandwidth limits at runtime, in megabytes per second
+pub fn set_snapshot_bandwidth_limits(
+    upload_mb_per_sec: Option<usize>,
+    download_mb_per_sec: Option<usize>,
+) {
+    SNAPSHOT_UPLOAD_LIMITER.set_limit(upload_mb_per_sec.map(mb_to_bytes));
+    SNAPSHOT_DOWNLOAD_LIMITER.set_limit(download_mb_per_sec.map(mb_to_bytes));
+}
+
+/// Current bandwidth limits for uploads and downloads, in megabytes per second
+pub fn snapshot_bandwidth_limits() -> (Option<usize>, Option<usize>) {
+    (
+        SNAPSHOT_UPLOAD_LIMITER.limit().map(bytes_to_mb),
+        SNAPSHOT_DOWNLOAD_LIMITER.limit().map(bytes_to_mb),
+    )
+}
+
+fn mb_to_bytes(mb: usize) -> u64 {
+    mb.saturating_mul(BYTES_IN_MB) as u64
+}
+
+fn bytes_to_mb(bytes: u64) -> usize {
+    bytes as usize / BYTES_IN_MB
+}
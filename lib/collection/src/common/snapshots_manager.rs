@@ -22,6 +22,10 @@ use crate::operations::types::{CollectionError, CollectionResult};
 pub struct SnapshotsConfig {
     pub snapshots_storage: SnapshotsStorageConfig,
     pub s3_config: Option<S3Config>,
+    /// Maximum throughput of snapshot uploads to the object storage, in megabytes per second
+    pub max_upload_mb_per_sec: Option<usize>,
+    /// Maximum throughput of snapshot downloads, in megabytes per second
+    pub max_download_mb_per_sec: Option<usize>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize)]
@@ -57,6 +57,15 @@ impl<T> StoppableTaskHandle<T> {
 
 /// Spawn stoppable task `f`
 pub fn spawn_stoppable<F, T>(f: F) -> StoppableTaskHandle<T>
+where
+    F: FnOnce(&AtomicBool) -> T + Send + 'static,
+    T: Send + 'static,
+{
+    spawn_stoppable_with_flag(Arc::new(AtomicBool::new(false)), f)
+}
+
+/// Spawn stoppable task `f`, which may also be stopped externally through the `stopped` flag
+pub fn spawn_stoppable_with_flag<F, T>(stopped: Arc<AtomicBool>, f: F) -> StoppableTaskHandle<T>
 where
     F: FnOnce(&AtomicBool) -> T + Send + 'static,
     T: Send + 'static,
@@ -64,7 +73,6 @@ where
     let started = Arc::new(AtomicBool::new(false));
     let started_c = started.clone();
 
-    let stopped = Arc::new(AtomicBool::new(false));
     // We are OK if original value is destroyed with the thread
     // Weak reference is sufficient
     let stopped_w = Arc::downgrade(&stopped);
@@ -1,26 +1,33 @@
+use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::{Read, Write as _};
 use std::num::{NonZeroU32, NonZeroUsize};
 use std::path::Path;
+use std::time::Duration;
 
 use atomicwrites::AtomicFile;
 use atomicwrites::OverwriteBehavior::AllowOverwrite;
 use fs_err::File;
+use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
 use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
 use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
+use segment::json_path::JsonPath;
 use segment::types::{
     Distance, HnswConfig, Indexes, Payload, PayloadStorageType, QuantizationConfig, SegmentConfig,
-    SparseVectorDataConfig, StrictModeConfig, VectorDataConfig, VectorName, VectorNameBuf,
-    VectorStorageDatatype, VectorStorageType,
+    ShardKey, SparseVectorDataConfig, StrictModeConfig, VectorDataConfig, VectorName,
+    VectorNameBuf, VectorStorageDatatype, VectorStorageType,
+};
+use segment::vector_storage::backend_registry::{
+    check_vector_storage_backend, is_vector_storage_backend_appendable,
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 use wal::WalOptions;
 
-use crate::operations::config_diff::{DiffConfig, QuantizationConfigDiff};
+use crate::operations::config_diff::{DiffConfig, HnswConfigDiff, QuantizationConfigDiff};
 use crate::operations::types::{
     CollectionError, CollectionResult, CollectionWarning, SparseVectorParams, SparseVectorsConfig,
     VectorParams, VectorParamsDiff, VectorsConfig, VectorsConfigDiff,
@@ -42,18 +49,39 @@ pub struct WalConfig {
     #[validate(range(min = 1))]
     #[serde(default = "default_wal_retain_closed")]
     pub wal_retain_closed: usize,
+    /// Time window in milliseconds, during which small upserts into the same shard are coalesced
+    /// into a single WAL record and a single segment update. 0 to disable batching
+    #[serde(default)]
+    pub wal_batch_window_ms: u64,
+    /// Maximal number of points in a single coalesced upsert
+    #[validate(range(min = 1))]
+    #[serde(default = "default_wal_batch_max_points")]
+    pub wal_batch_max_points: usize,
 }
 
 fn default_wal_retain_closed() -> usize {
     1
 }
 
+pub(crate) fn default_wal_batch_max_points() -> usize {
+    256
+}
+
+impl WalConfig {
+    /// Time window to coalesce small upserts in, `None` if batching is disabled
+    pub fn batch_window(&self) -> Option<Duration> {
+        (self.wal_batch_window_ms > 0).then(|| Duration::from_millis(self.wal_batch_window_ms))
+    }
+}
+
 impl From<&WalConfig> for WalOptions {
     fn from(config: &WalConfig) -> Self {
         let WalConfig {
             wal_capacity_mb,
             wal_segments_ahead,
             wal_retain_closed,
+            wal_batch_window_ms: _,
+            wal_batch_max_points: _,
         } = config;
         WalOptions {
             segment_capacity: wal_capacity_mb * 1024 * 1024,
@@ -69,6 +97,8 @@ impl Default for WalConfig {
             wal_capacity_mb: 32,
             wal_segments_ahead: 0,
             wal_retain_closed: default_wal_retain_closed(),
+            wal_batch_window_ms: 0,
+            wal_batch_max_points: default_wal_batch_max_points(),
         }
     }
 }
@@ -124,6 +154,11 @@ pub struct CollectionParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
     pub read_fan_out_delay_ms: Option<u64>,
+    /// Define how remote replicas are selected to read from, if the local replica can't serve
+    /// the read.
+    /// Default is None, which means remote replicas are selected randomly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_routing: Option<ReadRouting>,
     /// If true - point's payload will not be stored in memory.
     /// It will be read from the disk every time it is requested.
     /// This setting saves RAM by (slightly) increasing the response time.
@@ -132,12 +167,215 @@ pub struct CollectionParams {
     /// Default: true
     #[serde(default = "default_on_disk_payload")]
     pub on_disk_payload: bool,
+    /// Top-level payload values larger than this number of bytes are stored separately from the
+    /// rest of the payload, so that occasional large values don't slow down reading of others.
+    /// Only applies to newly written segments.
+    /// Default is None, which means all payload values are stored together.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub payload_overflow_threshold: Option<usize>,
+    /// Check upserted points for near-identical vectors of existing points.
+    /// Default is None, which means no check is performed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub duplicate_detection: Option<DuplicateDetectionConfig>,
+    /// Only accept payload fields declared by payload indexes of the collection, with values of
+    /// the indexed type. Checked for upserted points and set payload operations.
+    /// Default is None, which means any payload is accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict_payload_schema: Option<StrictPayloadSchemaMode>,
+    /// Default and computed payload fields, which are set for upserted points.
+    /// Default is None, which means payload of upserted points is stored as is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ingest_payload: Option<IngestPayloadConfig>,
+    /// If true, all updates of points, vectors, payloads and payload indexes are rejected, while
+    /// the collection stays available for reading. Used to freeze the collection for migrations and
+    /// maintenance.
+    /// Default is None, which means updates are accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// If set, deleted points are kept in the trash for this number of seconds, and can be
+    /// restored until the retention period expires. Protects against accidental deletes.
+    /// Points in the trash are marked with the reserved `__trashed_at` payload field.
+    /// Default is None, which means deleted points are dropped immediately.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    #[anonymize(false)]
+    pub trash_retention_sec: Option<u64>,
+    /// If true, points expire at the time stored in their `expires_at` payload field: an RFC 3339
+    /// datetime, or a unix timestamp in seconds if the field is indexed as `integer` or `float`.
+    /// Expired points are excluded from reads and deleted in the background.
+    /// Default is None, which means points never expire.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub points_expiration: Option<bool>,
+    /// Configuration overrides of shard keys, applied to shards of the shard key.
+    /// Set when the shard key is created.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[validate(nested)]
+    pub shard_key_configs: Vec<ShardKeyConfig>,
     /// Configuration of the sparse vector storage
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[validate(nested)]
     pub sparse_vectors: Option<BTreeMap<VectorNameBuf, SparseVectorParams>>,
 }
 
+/// Check of upserted points for near-identical vectors of existing points
+///
+/// Existing points are looked up with a regular search, using the vector index of the
+/// collection. Duplicates within a single update are not detected.
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[anonymize(false)]
+pub struct DuplicateDetectionConfig {
+    /// Name of the dense vector to compare. Default: the unnamed vector
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vector: Option<VectorNameBuf>,
+    /// Maximum difference for vectors to be considered duplicates:
+    ///
+    /// - `Cosine` - difference of the cosine similarity to 1.
+    /// - `Dot` - Euclidean distance between vectors.
+    /// - `Euclid`, `Manhattan`, `Hamming` - distance between vectors.
+    #[validate(range(min = 0.0))]
+    pub epsilon: OrderedFloat<f64>,
+    /// Only compare with existing points with the same value of this payload field, e.g. a
+    /// tenant id. Upserted points without the field are compared with existing points without it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_key: Option<JsonPath>,
+    /// What to do with detected duplicates. Default: count
+    #[serde(default)]
+    pub mode: DuplicateDetectionMode,
+}
+
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Anonymize, PartialEq, Eq, Hash, Clone, Copy, Default,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateDetectionMode {
+    /// Count duplicates in collection telemetry, upsert them as usual
+    #[default]
+    Count,
+    /// Reject the whole update, if any upserted point is a duplicate
+    Reject,
+    /// Set the `duplicate_of` payload field of upserted duplicates to the id of the existing point
+    Tag,
+}
+
+/// Selection of remote replicas to read from
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Anonymize, PartialEq, Eq, Hash, Clone, Copy,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadRouting {
+    /// Read from remote replicas in random order
+    Random,
+    /// Read from remote replicas with the lowest recently observed latency first.
+    /// Together with `read_fan_out_delay_ms`, slow reads are hedged to the next fastest replica.
+    Latency,
+}
+
+/// Handling of payload, which doesn't match the payload schema of the collection
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Anonymize, PartialEq, Eq, Hash, Clone, Copy,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StrictPayloadSchemaMode {
+    /// Reject the whole update, if any payload field is undeclared or has a value of wrong type
+    Reject,
+    /// Drop undeclared payload fields and values of wrong type, apply the rest of the update
+    Drop,
+}
+
+/// Payload fields, which are filled in by the collection for each upserted point
+///
+/// Applied after the strict payload schema check, so these fields don't have to be declared in
+/// the payload schema.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Anonymize, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct IngestPayloadConfig {
+    /// Values of top-level payload fields, which are not set in the upserted point
+    #[serde(default, skip_serializing_if = "Payload::is_empty")]
+    #[anonymize(value = Payload::default())]
+    pub defaults: Payload,
+    /// Top-level payload fields computed at ingest time. Override values of the upserted point
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub computed: BTreeMap<String, ComputedPayloadValue>,
+}
+
+impl IngestPayloadConfig {
+    /// Config without any fields, which doesn't change upserted points
+    pub fn is_empty(&self) -> bool {
+        self.defaults.is_empty() && self.computed.is_empty()
+    }
+}
+
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Anonymize, PartialEq, Eq, Hash, Clone, Copy,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ComputedPayloadValue {
+    /// Current time as RFC 3339 datetime string
+    Now,
+    /// Current time as number of seconds since Unix epoch
+    NowUnix,
+}
+
+/// Configuration overrides for shards of a single shard key, e.g. for a tenant with custom
+/// requirements in a multitenant collection
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct ShardKeyConfig {
+    pub shard_key: ShardKey,
+    /// If true, vectors of the shard key are served from disk. Overrides `on_disk` of all dense
+    /// vectors of the collection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_disk: Option<bool>,
+    /// Overrides of the HNSW index params of the collection.
+    /// Vector specific HNSW params still apply on top of them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    #[anonymize(false)]
+    pub hnsw_config: Option<HnswConfigDiff>,
+    /// Maximum number of points in shards of the shard key.
+    /// Upserts of new points are rejected once it is reached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    #[anonymize(false)]
+    pub max_points_count: Option<usize>,
+}
+
+impl ShardKeyConfig {
+    /// Whether the config overrides anything
+    pub fn is_empty(&self) -> bool {
+        let Self {
+            shard_key: _,
+            on_disk,
+            hnsw_config,
+            max_points_count,
+        } = self;
+
+        on_disk.is_none() && hnsw_config.is_none() && max_points_count.is_none()
+    }
+
+    fn apply(&self, config: &mut CollectionConfigInternal) {
+        if let Some(on_disk) = self.on_disk {
+            match &mut config.params.vectors {
+                VectorsConfig::Single(params) => params.on_disk = Some(on_disk),
+                VectorsConfig::Multi(params) => params
+                    .values_mut()
+                    .for_each(|params| params.on_disk = Some(on_disk)),
+            }
+        }
+
+        if let Some(hnsw_config) = &self.hnsw_config {
+            config.hnsw_config = config.hnsw_config.update(hnsw_config);
+        }
+    }
+}
+
 impl CollectionParams {
     pub fn payload_storage_type(&self) -> PayloadStorageType {
         #[cfg(feature = "rocksdb")]
@@ -166,7 +404,16 @@ impl CollectionParams {
             write_consistency_factor: _, // May be changed
             read_fan_out_factor: _, // May be changed
             read_fan_out_delay_ms: _, // May be changed,
+            read_routing: _, // May be changed
             on_disk_payload: _, // May be changed
+            payload_overflow_threshold: _, // May be changed
+            duplicate_detection: _, // May be changed
+            strict_payload_schema: _, // May be changed
+            ingest_payload: _, // May be changed
+            read_only: _,    // May be changed
+            trash_retention_sec: _, // May be changed
+            points_expiration: _, // May be changed
+            shard_key_configs: _, // May be changed
             sparse_vectors,  // Parameters may be changes, but not the structure
         } = other;
 
@@ -321,6 +568,20 @@ impl CollectionConfigInternal {
         self.params
             .to_base_segment_config(self.quantization_config.as_ref())
     }
+
+    /// Config of shards of the given shard key, with configuration overrides of the shard key
+    /// applied
+    pub fn for_shard_key(&self, shard_key: Option<&ShardKey>) -> Cow<'_, Self> {
+        let Some(shard_key_config) =
+            shard_key.and_then(|shard_key| self.params.shard_key_config(shard_key))
+        else {
+            return Cow::Borrowed(self);
+        };
+
+        let mut config = self.clone();
+        shard_key_config.apply(&mut config);
+        Cow::Owned(config)
+    }
 }
 
 impl CollectionParams {
@@ -333,11 +594,27 @@ impl CollectionParams {
             write_consistency_factor: default_write_consistency_factor(),
             read_fan_out_factor: None,
             read_fan_out_delay_ms: None,
+            read_routing: None,
             on_disk_payload: default_on_disk_payload(),
+            payload_overflow_threshold: None,
+            duplicate_detection: None,
+            strict_payload_schema: None,
+            ingest_payload: None,
+            read_only: None,
+            trash_retention_sec: None,
+            points_expiration: None,
+            shard_key_configs: Vec::new(),
             sparse_vectors: None,
         }
     }
 
+    /// Configuration overrides of the shard key, if any
+    pub fn shard_key_config(&self, shard_key: &ShardKey) -> Option<&ShardKeyConfig> {
+        self.shard_key_configs
+            .iter()
+            .find(|config| &config.shard_key == shard_key)
+    }
+
     fn missing_vector_error(&self, vector_name: &VectorName) -> CollectionError {
         let mut available_names = vec![];
 
@@ -471,6 +748,7 @@ impl CollectionParams {
                 hnsw_config,
                 quantization_config,
                 on_disk,
+                index,
             } = update_params.clone();
 
             if let Some(hnsw_diff) = hnsw_config {
@@ -499,6 +777,10 @@ impl CollectionParams {
             if let Some(on_disk) = on_disk {
                 vector_params.on_disk = Some(on_disk);
             }
+
+            if let Some(index) = index {
+                vector_params.index = Some(index);
+            }
         }
         Ok(())
     }
@@ -527,6 +809,43 @@ impl CollectionParams {
         Ok(())
     }
 
+    /// Check that vector storage backends used by the collection are registered on this node
+    /// and support its vectors.
+    pub fn check_storage_backends(&self) -> CollectionResult<()> {
+        for (name, mut vector_data) in self.to_base_vector_data(None)? {
+            // Read-only backends are not set for appendable segments
+            vector_data.storage_backend = self
+                .vectors
+                .get_params(&name)
+                .and_then(|params| params.storage_backend.clone());
+            if let Some(backend) = &vector_data.storage_backend {
+                check_vector_storage_backend(backend, &vector_data)
+                    .map_err(|err| CollectionError::bad_input(format!("Vector {name}: {err}")))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that vectors with Hamming distance are not quantized, as there is no quantized
+    /// scorer for it.
+    pub fn check_quantization(
+        &self,
+        collection_quantization: Option<&QuantizationConfig>,
+    ) -> CollectionResult<()> {
+        for (name, params) in self.vectors.params_iter() {
+            let quantization = params
+                .quantization_config
+                .as_ref()
+                .or(collection_quantization);
+            if params.distance == Distance::Hamming && quantization.is_some() {
+                return Err(CollectionError::bad_input(format!(
+                    "Vector {name}: quantization is not supported for Hamming distance",
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Convert into unoptimized named vector data configs
     ///
     /// It is the job of the segment optimizer to change this configuration with optimized settings
@@ -567,6 +886,11 @@ impl CollectionParams {
                         },
                         multivector_config: params.multivector_config,
                         datatype: params.datatype.map(VectorStorageDatatype::from),
+                        // Read-only backends are set by the optimizer for immutable segments
+                        storage_backend: params
+                            .storage_backend
+                            .clone()
+                            .filter(|backend| is_vector_storage_backend_appendable(backend)),
                     },
                 )
             })
@@ -596,6 +920,9 @@ impl CollectionParams {
                                     .index
                                     .and_then(|index| index.datatype)
                                     .map(VectorStorageDatatype::from),
+                                similarity: params.index.and_then(|index| index.similarity),
+                                pruning: params.index.and_then(|index| index.pruning),
+                                block_max: params.index.and_then(|index| index.block_max),
                             },
                             storage_type: params.storage_type(),
                             modifier: params.modifier,
@@ -636,6 +963,7 @@ impl CollectionParams {
             vector_data,
             sparse_vector_data,
             payload_storage_type,
+            payload_overflow_threshold: self.payload_overflow_threshold,
         };
 
         Ok(segment_config)
@@ -4,12 +4,16 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
+use ahash::AHashSet;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::Future;
 use itertools::Itertools;
-use segment::types::{PointIdType, WithPayloadInterface, WithVector};
+use segment::types::{
+    Condition, Filter, HasIdCondition, PointIdType, WithPayloadInterface, WithVector,
+};
 use serde::Serialize;
 use shard::retrieve::record_internal::RecordInternal;
+use shard::scroll::ScrollRequestInternal;
 use types::PseudoId;
 
 use crate::collection::Collection;
@@ -27,6 +31,9 @@ pub struct WithLookup {
 
     /// Options for specifying which vectors to include (or not)
     pub with_vectors: Option<WithVector>,
+
+    /// Only look up points which satisfy these conditions, others are left out of the response
+    pub filter: Option<Filter>,
 }
 
 pub async fn lookup_ids<F, Fut>(
@@ -48,7 +55,7 @@ where
             what: format!("Collection {}", request.collection_name),
         })?;
 
-    let ids = values
+    let mut ids = values
         .into_iter()
         .filter_map(|v| PointIdType::try_from(v).ok())
         .collect_vec();
@@ -57,10 +64,47 @@ where
         return Ok(HashMap::new());
     }
 
+    if let Some(filter) = request.filter {
+        // Resolve which of the requested points match the filter first, retrieve only those
+        let has_id = Filter::new_must(Condition::HasId(HasIdCondition::from(
+            ids.iter().copied().collect::<AHashSet<_>>(),
+        )));
+
+        let scroll_request = ScrollRequestInternal {
+            offset: None,
+            limit: Some(ids.len()),
+            filter: Some(filter.merge(&has_id)),
+            with_payload: Some(WithPayloadInterface::Bool(false)),
+            with_vector: WithVector::Bool(false),
+            order_by: None,
+            distinct_by: None,
+            with_version: None,
+        };
+
+        ids = collection
+            .scroll_by(
+                scroll_request,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?
+            .points
+            .into_iter()
+            .map(|point| point.id)
+            .collect();
+
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+    }
+
     let point_request = PointRequestInternal {
         ids,
         with_payload: request.with_payload,
         with_vector: request.with_vectors.unwrap_or_default(),
+        with_version: None,
     };
 
     let result = collection
@@ -32,6 +32,7 @@ impl From<api::rest::WithLookupInterface> for WithLookup {
                 collection_name,
                 with_payload: Some(true.into()),
                 with_vectors: Some(false.into()),
+                filter: None,
             },
             api::rest::WithLookupInterface::WithLookup(with_lookup) => {
                 WithLookup::from(with_lookup)
@@ -46,6 +47,7 @@ impl From<api::rest::WithLookup> for WithLookup {
             collection_name: with_lookup.collection_name,
             with_payload: with_lookup.with_payload,
             with_vectors: with_lookup.with_vectors,
+            filter: with_lookup.filter,
         }
     }
 }
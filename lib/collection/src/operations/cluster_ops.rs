@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::{Validate, ValidationErrors};
 
+use crate::config::ShardKeyConfig;
+use crate::operations::config_diff::HnswConfigDiff;
 use crate::shards::replica_set::replica_set_state::ReplicaState;
 use crate::shards::shard::{PeerId, ShardId};
 use crate::shards::transfer::ShardTransferMethod;
@@ -45,6 +47,8 @@ pub enum ClusterOperations {
     FinishResharding(FinishReshardingOperation),
     /// Abort resharding
     AbortResharding(AbortReshardingOperation),
+    /// Change number of shards, by resharding one shard at a time
+    Reshard(ReshardOperation),
     /// Trigger replication of points between two shards
     ReplicatePoints(ReplicatePointsOperation),
 
@@ -56,6 +60,7 @@ pub enum ClusterOperations {
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct CreateShardingKeyOperation {
+    #[validate(nested)]
     pub create_sharding_key: CreateShardingKey,
 }
 
@@ -91,6 +96,33 @@ pub struct CreateShardingKey {
     /// If not specified, will be `Initializing` first and then `Active`
     /// Warning: do not change this unless you know what you are doing
     pub initial_state: Option<ReplicaState>,
+    /// If true, vectors of the shards for this key are served from disk
+    /// If not specified, will use `on_disk` of the collection vectors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_disk: Option<bool>,
+    /// Custom params for HNSW index of the shards for this key
+    /// If not specified, will use HNSW params of the collection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub hnsw_config: Option<HnswConfigDiff>,
+    /// Maximum number of points for this key
+    /// If not specified, the number of points is not limited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_points_count: Option<usize>,
+}
+
+impl CreateShardingKey {
+    /// Configuration overrides of the shards for this key, if any are specified
+    pub fn shard_key_config(&self) -> Option<ShardKeyConfig> {
+        let config = ShardKeyConfig {
+            shard_key: self.shard_key.clone(),
+            on_disk: self.on_disk,
+            hnsw_config: self.hnsw_config,
+            max_points_count: self.max_points_count,
+        };
+        (!config.is_empty()).then_some(config)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -127,6 +159,7 @@ impl Validate for ClusterOperations {
             ClusterOperations::CommitWriteHashRing(op) => op.validate(),
             ClusterOperations::FinishResharding(op) => op.validate(),
             ClusterOperations::AbortResharding(op) => op.validate(),
+            ClusterOperations::Reshard(op) => op.validate(),
             ClusterOperations::ReplicatePoints(op) => op.validate(),
             #[cfg(feature = "staging")]
             ClusterOperations::TestSlowDown(op) => op.validate(),
@@ -168,6 +201,12 @@ pub struct StartReshardingOperation {
     pub start_resharding: StartResharding,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, Validate)]
+pub struct ReshardOperation {
+    #[validate(nested)]
+    pub reshard: Reshard,
+}
+
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, JsonSchema, Validate)]
 pub struct FinishMigratingPointsOperation {
     #[validate(nested)]
@@ -327,6 +366,15 @@ pub struct StartResharding {
     pub shard_key: Option<ShardKey>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, Validate)]
+pub struct Reshard {
+    /// Target number of shards
+    #[validate(range(min = 1))]
+    pub shard_number: u32,
+    /// Shard key to reshard, required for collections with custom sharding
+    pub shard_key: Option<ShardKey>,
+}
+
 /// Resharding direction, scale up or down in number of shards
 ///
 /// - `up` - Scale up, add a new shard
@@ -7,12 +7,16 @@ use std::num::NonZeroU32;
 use api::rest::MaxOptimizationThreads;
 use schemars::JsonSchema;
 use segment::types::{
-    BinaryQuantization, HnswConfig, ProductQuantization, ScalarQuantization, StrictModeConfig,
+    BinaryQuantization, HnswConfig, HnswLinksCompression, ProductQuantization, ScalarQuantization,
+    StrictModeConfig,
 };
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationErrors};
 
-use crate::config::{CollectionParams, WalConfig};
+use crate::config::{
+    CollectionParams, DuplicateDetectionConfig, IngestPayloadConfig, ReadRouting,
+    StrictPayloadSchemaMode, WalConfig,
+};
 use crate::optimizers_builder::OptimizersConfig;
 
 pub trait DiffConfig<Diff>: Clone {
@@ -73,6 +77,16 @@ pub struct HnswConfigDiff {
     /// Requires quantized vectors to be enabled. Multi-vectors are not supported.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inline_storage: Option<bool>,
+    /// Encoding of the HNSW graph links. Default: `bitpacking`.
+    /// Ignored when `inline_storage` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links_compression: Option<HnswLinksCompression>,
+    /// Link all points of each tenant of a payload field marked with `is_tenant` in the graph,
+    /// regardless of the tenant size. Searches with a filter matching a single tenant then stay
+    /// within the links of that tenant. Requires `payload_m` or `m` > 0.
+    /// Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_subgraphs: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
@@ -84,9 +98,17 @@ pub struct WalConfigDiff {
     pub wal_segments_ahead: Option<usize>,
     /// Number of closed WAL segments to retain
     pub wal_retain_closed: Option<usize>,
+    /// Time window in milliseconds, during which small upserts into the same shard are coalesced
+    /// into a single WAL record and a single segment update. 0 to disable batching
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wal_batch_window_ms: Option<u64>,
+    /// Maximal number of points in a single coalesced upsert
+    #[validate(range(min = 1))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wal_batch_max_points: Option<usize>,
 }
 
-#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
 pub struct CollectionParamsDiff {
     /// Number of replicas for each shard
     pub replication_factor: Option<NonZeroU32>,
@@ -96,12 +118,41 @@ pub struct CollectionParamsDiff {
     pub read_fan_out_factor: Option<u32>,
     ///  Delay in milliseconds before sending read requests to remote nodes
     pub read_fan_out_delay_ms: Option<u64>,
+    /// Selection of remote replicas to read from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_routing: Option<ReadRouting>,
     /// If true - point's payload will not be stored in memory.
     /// It will be read from the disk every time it is requested.
     /// This setting saves RAM by (slightly) increasing the response time.
     /// Note: those payload values that are involved in filtering and are indexed - remain in RAM.
     #[serde(default)]
     pub on_disk_payload: Option<bool>,
+    /// Top-level payload values larger than this number of bytes are stored separately from the
+    /// rest of the payload
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_overflow_threshold: Option<usize>,
+    /// Check upserted points for near-identical vectors of existing points
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub duplicate_detection: Option<DuplicateDetectionConfig>,
+    /// Only accept payload fields declared by payload indexes of the collection.
+    /// `Disabled` to accept any payload again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict_payload_schema: Option<StrictPayloadSchemaDiff>,
+    /// Default and computed payload fields, which are set for upserted points.
+    /// Replaces the current config, empty config to disable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ingest_payload: Option<IngestPayloadConfig>,
+    /// If true, all updates of the collection data are rejected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// If set, deleted points are kept in the trash for this number of seconds and can be restored.
+    /// 0 to disable the trash, points in the trash are purged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trash_retention_sec: Option<u64>,
+    /// If true, points expire at the time stored in their `expires_at` payload field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub points_expiration: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
@@ -168,6 +219,22 @@ pub struct OptimizersConfigDiff {
     /// Default is disabled.
     #[serde(default)]
     pub prevent_unoptimized: Option<bool>,
+    /// Maximum memory (in kilobytes) for intermediate point mappings while building an optimized segment.
+    /// If source segments have more points than fit into this budget, the mapping is spilled to disk
+    /// and points are copied into the new segment in chunks.
+    ///
+    /// If not set, the whole mapping is kept in memory.
+    #[serde(alias = "max_builder_memory_kb")]
+    #[validate(range(min = 1))]
+    pub max_builder_memory: Option<usize>,
+    /// Maximum amount of data (in kilobytes) written into payload and vector storages of a shard
+    /// since the last flush. Once exceeded, the shard is flushed without waiting for
+    /// `flush_interval_sec`, which bounds the amount of WAL to replay on restart.
+    ///
+    /// If not set, only `flush_interval_sec` is used.
+    #[serde(alias = "flush_max_dirty_kb")]
+    #[validate(range(min = 1))]
+    pub flush_max_dirty: Option<usize>,
 }
 
 impl std::hash::Hash for OptimizersConfigDiff {
@@ -183,6 +250,8 @@ impl std::hash::Hash for OptimizersConfigDiff {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            max_builder_memory,
+            flush_max_dirty,
         } = self;
 
         deleted_threshold.map(f64::to_le_bytes).hash(state);
@@ -194,6 +263,8 @@ impl std::hash::Hash for OptimizersConfigDiff {
         flush_interval_sec.hash(state);
         max_optimization_threads.hash(state);
         prevent_unoptimized.hash(state);
+        max_builder_memory.hash(state);
+        flush_max_dirty.hash(state);
     }
 }
 
@@ -209,6 +280,8 @@ impl DiffConfig<HnswConfigDiff> for HnswConfig {
             on_disk,
             payload_m,
             inline_storage,
+            links_compression,
+            tenant_subgraphs,
         } = diff;
 
         HnswConfig {
@@ -219,6 +292,8 @@ impl DiffConfig<HnswConfigDiff> for HnswConfig {
             on_disk: on_disk.or(self.on_disk),
             payload_m: payload_m.or(self.payload_m),
             inline_storage: inline_storage.or(self.inline_storage),
+            links_compression: links_compression.or(self.links_compression),
+            tenant_subgraphs: tenant_subgraphs.or(self.tenant_subgraphs),
         }
     }
 }
@@ -233,6 +308,8 @@ impl DiffConfig<HnswConfigDiff> for HnswConfigDiff {
             on_disk,
             payload_m,
             inline_storage,
+            links_compression,
+            tenant_subgraphs,
         } = diff;
 
         HnswConfigDiff {
@@ -243,6 +320,8 @@ impl DiffConfig<HnswConfigDiff> for HnswConfigDiff {
             on_disk: on_disk.or(self.on_disk),
             payload_m: payload_m.or(self.payload_m),
             inline_storage: inline_storage.or(self.inline_storage),
+            links_compression: links_compression.or(self.links_compression),
+            tenant_subgraphs: tenant_subgraphs.or(self.tenant_subgraphs),
         }
     }
 }
@@ -259,6 +338,8 @@ impl DiffConfig<OptimizersConfigDiff> for OptimizersConfig {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            max_builder_memory,
+            flush_max_dirty,
         } = diff;
 
         OptimizersConfig {
@@ -273,6 +354,8 @@ impl DiffConfig<OptimizersConfigDiff> for OptimizersConfig {
             max_optimization_threads: max_optimization_threads
                 .map_or(self.max_optimization_threads, From::from),
             prevent_unoptimized: prevent_unoptimized.or(self.prevent_unoptimized),
+            max_builder_memory: max_builder_memory.or(self.max_builder_memory),
+            flush_max_dirty: flush_max_dirty.or(self.flush_max_dirty),
         }
     }
 }
@@ -283,12 +366,16 @@ impl DiffConfig<WalConfigDiff> for WalConfig {
             wal_capacity_mb,
             wal_segments_ahead,
             wal_retain_closed,
+            wal_batch_window_ms,
+            wal_batch_max_points,
         } = diff;
 
         WalConfig {
             wal_capacity_mb: wal_capacity_mb.unwrap_or(self.wal_capacity_mb),
             wal_segments_ahead: wal_segments_ahead.unwrap_or(self.wal_segments_ahead),
             wal_retain_closed: wal_retain_closed.unwrap_or(self.wal_retain_closed),
+            wal_batch_window_ms: wal_batch_window_ms.unwrap_or(self.wal_batch_window_ms),
+            wal_batch_max_points: wal_batch_max_points.unwrap_or(self.wal_batch_max_points),
         }
     }
 }
@@ -300,7 +387,15 @@ impl DiffConfig<CollectionParamsDiff> for CollectionParams {
             write_consistency_factor,
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            read_routing,
             on_disk_payload,
+            payload_overflow_threshold,
+            duplicate_detection,
+            strict_payload_schema,
+            ingest_payload,
+            read_only,
+            trash_retention_sec,
+            points_expiration,
         } = diff;
 
         CollectionParams {
@@ -309,7 +404,31 @@ impl DiffConfig<CollectionParamsDiff> for CollectionParams {
                 .unwrap_or(self.write_consistency_factor),
             read_fan_out_factor: read_fan_out_factor.or(self.read_fan_out_factor),
             read_fan_out_delay_ms: read_fan_out_delay_ms.or(self.read_fan_out_delay_ms),
+            read_routing: read_routing.or(self.read_routing),
             on_disk_payload: on_disk_payload.unwrap_or(self.on_disk_payload),
+            payload_overflow_threshold: payload_overflow_threshold
+                .or(self.payload_overflow_threshold),
+            duplicate_detection: duplicate_detection
+                .clone()
+                .or_else(|| self.duplicate_detection.clone()),
+            strict_payload_schema: match strict_payload_schema {
+                Some(StrictPayloadSchemaDiff::Mode(mode)) => Some(*mode),
+                Some(StrictPayloadSchemaDiff::Disabled(_)) => None,
+                None => self.strict_payload_schema,
+            },
+            ingest_payload: match ingest_payload {
+                Some(config) if config.is_empty() => None,
+                Some(config) => Some(config.clone()),
+                None => self.ingest_payload.clone(),
+            },
+            read_only: read_only.or(self.read_only),
+            trash_retention_sec: match trash_retention_sec {
+                Some(0) => None,
+                Some(retention) => Some(*retention),
+                None => self.trash_retention_sec,
+            },
+            points_expiration: points_expiration.or(self.points_expiration),
+            shard_key_configs: self.shard_key_configs.clone(),
             shard_number: self.shard_number,
             sharding_method: self.sharding_method,
             sparse_vectors: self.sparse_vectors.clone(),
@@ -340,6 +459,10 @@ impl DiffConfig<StrictModeConfig> for StrictModeConfig {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            tenant_filter_key,
+            search_rate_limit,
+            scroll_rate_limit,
+            update_rate_limit,
         } = diff;
 
         StrictModeConfig {
@@ -372,6 +495,13 @@ impl DiffConfig<StrictModeConfig> for StrictModeConfig {
                 .or(self.sparse_config.as_ref())
                 .cloned(),
             max_payload_index_count: max_payload_index_count.or(self.max_payload_index_count),
+            tenant_filter_key: tenant_filter_key
+                .as_ref()
+                .or(self.tenant_filter_key.as_ref())
+                .cloned(),
+            search_rate_limit: search_rate_limit.or(self.search_rate_limit),
+            scroll_rate_limit: scroll_rate_limit.or(self.scroll_rate_limit),
+            update_rate_limit: update_rate_limit.or(self.update_rate_limit),
         }
     }
 }
@@ -386,6 +516,8 @@ impl From<HnswConfig> for HnswConfigDiff {
             on_disk,
             payload_m,
             inline_storage,
+            links_compression,
+            tenant_subgraphs,
         } = config;
 
         HnswConfigDiff {
@@ -396,6 +528,8 @@ impl From<HnswConfig> for HnswConfigDiff {
             on_disk,
             payload_m,
             inline_storage,
+            links_compression,
+            tenant_subgraphs,
         }
     }
 }
@@ -406,12 +540,16 @@ impl From<WalConfig> for WalConfigDiff {
             wal_capacity_mb,
             wal_segments_ahead,
             wal_retain_closed,
+            wal_batch_window_ms,
+            wal_batch_max_points,
         } = config;
 
         WalConfigDiff {
             wal_capacity_mb: Some(wal_capacity_mb),
             wal_segments_ahead: Some(wal_segments_ahead),
             wal_retain_closed: Some(wal_retain_closed),
+            wal_batch_window_ms: Some(wal_batch_window_ms),
+            wal_batch_max_points: Some(wal_batch_max_points),
         }
     }
 }
@@ -423,7 +561,16 @@ impl From<CollectionParams> for CollectionParamsDiff {
             write_consistency_factor,
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            read_routing,
             on_disk_payload,
+            payload_overflow_threshold,
+            duplicate_detection,
+            strict_payload_schema,
+            ingest_payload,
+            read_only,
+            trash_retention_sec,
+            points_expiration,
+            shard_key_configs: _,
             shard_number: _,
             sharding_method: _,
             sparse_vectors: _,
@@ -435,7 +582,15 @@ impl From<CollectionParams> for CollectionParamsDiff {
             write_consistency_factor: Some(write_consistency_factor),
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            read_routing,
             on_disk_payload: Some(on_disk_payload),
+            payload_overflow_threshold,
+            duplicate_detection,
+            strict_payload_schema: strict_payload_schema.map(StrictPayloadSchemaDiff::Mode),
+            ingest_payload,
+            read_only,
+            trash_retention_sec,
+            points_expiration,
         }
     }
 }
@@ -453,6 +608,8 @@ impl From<OptimizersConfig> for OptimizersConfigDiff {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            max_builder_memory,
+            flush_max_dirty,
         } = config;
 
         Self {
@@ -466,11 +623,13 @@ impl From<OptimizersConfig> for OptimizersConfigDiff {
             flush_interval_sec: Some(flush_interval_sec),
             max_optimization_threads: max_optimization_threads.map(MaxOptimizationThreads::Threads),
             prevent_unoptimized,
+            max_builder_memory,
+            flush_max_dirty,
         }
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Disabled {
     Disabled,
 }
@@ -502,6 +661,19 @@ impl Validate for QuantizationConfigDiff {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+pub enum StrictPayloadSchemaDiff {
+    Mode(StrictPayloadSchemaMode),
+    Disabled(Disabled),
+}
+
+impl StrictPayloadSchemaDiff {
+    pub fn new_disabled() -> Self {
+        StrictPayloadSchemaDiff::Disabled(Disabled::Disabled)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -525,7 +697,15 @@ mod tests {
             write_consistency_factor: Some(NonZeroU32::new(2).unwrap()),
             read_fan_out_factor: None,
             read_fan_out_delay_ms: None,
+            read_routing: None,
             on_disk_payload: None,
+            payload_overflow_threshold: None,
+            duplicate_detection: None,
+            strict_payload_schema: None,
+            ingest_payload: None,
+            read_only: None,
+            trash_retention_sec: None,
+            points_expiration: None,
         };
 
         let new_params = params.update(&diff);
@@ -535,6 +715,72 @@ mod tests {
         assert!(new_params.on_disk_payload);
     }
 
+    #[test]
+    fn test_disable_strict_payload_schema() {
+        let params = CollectionParams {
+            strict_payload_schema: Some(StrictPayloadSchemaMode::Reject),
+            ..CollectionParams::empty()
+        };
+
+        let diff: CollectionParamsDiff =
+            serde_json::from_str(r#"{ "strict_payload_schema": "drop" }"#).unwrap();
+        let params = params.update(&diff);
+        assert_eq!(
+            params.strict_payload_schema,
+            Some(StrictPayloadSchemaMode::Drop),
+        );
+
+        // Not set in the diff, kept as is
+        let diff: CollectionParamsDiff = serde_json::from_str("{}").unwrap();
+        let params = params.update(&diff);
+        assert_eq!(
+            params.strict_payload_schema,
+            Some(StrictPayloadSchemaMode::Drop),
+        );
+
+        let diff: CollectionParamsDiff =
+            serde_json::from_str(r#"{ "strict_payload_schema": "Disabled" }"#).unwrap();
+        let params = params.update(&diff);
+        assert_eq!(params.strict_payload_schema, None);
+    }
+
+    #[test]
+    fn test_disable_ingest_payload() {
+        let params = CollectionParams::empty();
+
+        let diff: CollectionParamsDiff = serde_json::from_str(
+            r#"{ "ingest_payload": { "computed": { "created_at": "now" } } }"#,
+        )
+        .unwrap();
+        let params = params.update(&diff);
+        assert!(params.ingest_payload.is_some());
+
+        let diff: CollectionParamsDiff = serde_json::from_str("{}").unwrap();
+        let params = params.update(&diff);
+        assert!(params.ingest_payload.is_some());
+
+        let diff: CollectionParamsDiff =
+            serde_json::from_str(r#"{ "ingest_payload": {} }"#).unwrap();
+        let params = params.update(&diff);
+        assert_eq!(params.ingest_payload, None);
+    }
+
+    #[test]
+    fn test_disable_trash() {
+        let params = CollectionParams::empty();
+
+        let diff: CollectionParamsDiff =
+            serde_json::from_str(r#"{ "trash_retention_sec": 3600 }"#).unwrap();
+        let params = params.update(&diff);
+        assert_eq!(params.trash_retention_sec, Some(3600));
+
+        let diff: CollectionParamsDiff =
+            serde_json::from_str(r#"{ "trash_retention_sec": 0 }"#).unwrap();
+        assert!(diff.validate().is_ok());
+        let params = params.update(&diff);
+        assert_eq!(params.trash_retention_sec, None);
+    }
+
     #[test]
     fn test_hnsw_update() {
         let base_config = HnswConfig::default();
@@ -555,6 +801,8 @@ mod tests {
             flush_interval_sec: 30,
             max_optimization_threads: Some(1),
             prevent_unoptimized: None,
+            max_builder_memory: None,
+            flush_max_dirty: None,
         };
         let update: OptimizersConfigDiff =
             serde_json::from_str(r#"{ "indexing_threshold": 10000 }"#).unwrap();
@@ -580,6 +828,8 @@ mod tests {
             flush_interval_sec: 30,
             max_optimization_threads: Some(1),
             prevent_unoptimized: None,
+            max_builder_memory: None,
+            flush_max_dirty: None,
         };
 
         let update: OptimizersConfigDiff = serde_json::from_str(json_diff).unwrap();
@@ -20,6 +20,12 @@ use validator::{Validate, ValidationError as ValidatorError, ValidationErrors};
 ///
 /// * `all` - send requests to all nodes and return points which present on all of them
 ///
+/// * `linearizable` - find a replica which has applied all updates acknowledged by any other
+///   replica, and read from it
+///
+/// * `{"max_staleness_ms": N}` - read from local replica, if it is behind the updates it has
+///   received by no more than N milliseconds, otherwise read from the leader replica
+///
 /// Default value is `Factor(1)`
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
@@ -27,6 +33,8 @@ pub enum ReadConsistency {
     // send N random request and return points, which present on all of them
     Factor(#[serde(deserialize_with = "deserialize_factor")] usize),
     Type(ReadConsistencyType),
+    // read from local replica, if it is not behind received updates by more than the bound
+    MaxStaleness { max_staleness_ms: u64 },
 }
 
 impl Validate for ReadConsistency {
@@ -42,7 +50,9 @@ impl Validate for ReadConsistency {
                 });
                 Err(errors)
             }
-            ReadConsistency::Factor(_) | ReadConsistency::Type(_) => Ok(()),
+            ReadConsistency::Factor(_)
+            | ReadConsistency::Type(_)
+            | ReadConsistency::MaxStaleness { .. } => Ok(()),
         }
     }
 }
@@ -89,6 +99,9 @@ impl TryFrom<ReadConsistencyGrpc> for ReadConsistency {
                     .map_err(|err| tonic::Status::invalid_argument(err.to_string()))?,
             ),
             read_consistency::Value::Type(consistency) => Self::Type(consistency.try_into()?),
+            read_consistency::Value::MaxStalenessMs(max_staleness_ms) => {
+                Self::MaxStaleness { max_staleness_ms }
+            }
         };
 
         Ok(consistency)
@@ -102,6 +115,9 @@ impl From<ReadConsistency> for ReadConsistencyGrpc {
                 read_consistency::Value::Factor(factor.try_into().unwrap())
             }
             ReadConsistency::Type(consistency) => read_consistency::Value::Type(consistency.into()),
+            ReadConsistency::MaxStaleness { max_staleness_ms } => {
+                read_consistency::Value::MaxStalenessMs(max_staleness_ms)
+            }
         };
 
         ReadConsistencyGrpc { value: Some(value) }
@@ -146,6 +162,9 @@ where
 /// * `quorum` - send requests to all nodes and return points which present on majority of nodes
 ///
 /// * `all` - send requests to all nodes and return points which present on all nodes
+///
+/// * `linearizable` - find a replica which has applied all updates acknowledged by any other
+///   replica, and read from it
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ReadConsistencyType {
@@ -155,6 +174,8 @@ pub enum ReadConsistencyType {
     Quorum,
     // send requests to all nodes and return points which present on all nodes
     All,
+    // read from a replica, which is not behind any other active replica
+    Linearizable,
 }
 
 impl TryFrom<i32> for ReadConsistencyType {
@@ -177,6 +198,7 @@ impl From<ReadConsistencyTypeGrpc> for ReadConsistencyType {
             ReadConsistencyTypeGrpc::Majority => Self::Majority,
             ReadConsistencyTypeGrpc::Quorum => Self::Quorum,
             ReadConsistencyTypeGrpc::All => Self::All,
+            ReadConsistencyTypeGrpc::Linearizable => Self::Linearizable,
         }
     }
 }
@@ -193,6 +215,7 @@ impl From<ReadConsistencyType> for ReadConsistencyTypeGrpc {
             ReadConsistencyType::Majority => ReadConsistencyTypeGrpc::Majority,
             ReadConsistencyType::Quorum => ReadConsistencyTypeGrpc::Quorum,
             ReadConsistencyType::All => ReadConsistencyTypeGrpc::All,
+            ReadConsistencyType::Linearizable => ReadConsistencyTypeGrpc::Linearizable,
         }
     }
 }
@@ -240,6 +263,22 @@ mod tests {
         let consistency: ReadConsistency = serde_json::from_str(json).unwrap();
         assert_eq!(consistency, ReadConsistency::Type(ReadConsistencyType::All));
 
+        let json = "\"linearizable\"";
+        let consistency: ReadConsistency = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            consistency,
+            ReadConsistency::Type(ReadConsistencyType::Linearizable)
+        );
+
+        let json = r#"{"max_staleness_ms": 500}"#;
+        let consistency: ReadConsistency = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            consistency,
+            ReadConsistency::MaxStaleness {
+                max_staleness_ms: 500
+            }
+        );
+
         let schema = schema_for!(ReadConsistency);
         let schema_str = serde_json::to_string_pretty(&schema).unwrap();
         println!("{schema_str}")
@@ -17,26 +17,31 @@ use api::rest::{
     BaseGroupRequest, LookupLocation, MaxOptimizationThreads, ShardKeyWithFallback, schema as rest,
 };
 use itertools::Itertools;
+use ordered_float::OrderedFloat;
 use segment::common::operation_error::OperationError;
 use segment::data_types::modifier::Modifier;
 use segment::data_types::vectors::{VectorInternal, VectorStructInternal};
+use segment::index::sparse_index::sparse_index_config::{SparsePruningConfig, SparseSimilarity};
 use segment::types::{
-    Distance, Filter, HnswConfig, MultiVectorConfig, QuantizationConfig, StrictModeConfigOutput,
-    WithPayloadInterface,
+    Distance, Filter, HnswConfig, HnswLinksCompression, MultiVectorConfig, QuantizationConfig,
+    StrictModeConfigOutput, WithPayloadInterface,
 };
 use shard::retrieve::record_internal::RecordInternal;
 use tonic::Status;
+use validator::Validate;
 
 use super::cluster_ops::{ReplicatePoints, ReplicatePointsOperation, ReshardingDirection};
 use super::consistency_params::ReadConsistency;
 use super::types::{
     CollectionConfig, ContextExamplePair, CoreSearchRequest, Datatype, DiscoverRequestInternal,
     GroupsResult, PointGroup, RecommendExample, RecommendGroupsRequestInternal, ReshardingInfo,
-    SparseIndexParams, SparseVectorParams, SparseVectorsConfig, VectorParamsDiff,
+    SparseIndexParams, SparseVectorParams, SparseVectorsConfig, VectorIndexType, VectorParamsDiff,
     VectorsConfigDiff,
 };
 use crate::config::{
-    CollectionParams, ShardingMethod, WalConfig, default_replication_factor,
+    CollectionParams, ComputedPayloadValue, DuplicateDetectionConfig, DuplicateDetectionMode,
+    IngestPayloadConfig, ReadRouting, ShardKeyConfig, ShardingMethod, StrictPayloadSchemaMode,
+    WalConfig, default_replication_factor, default_wal_batch_max_points,
     default_write_consistency_factor,
 };
 use crate::lookup::WithLookup;
@@ -50,14 +55,15 @@ use crate::operations::cluster_ops::{
 };
 use crate::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
-    WalConfigDiff,
+    StrictPayloadSchemaDiff, WalConfigDiff,
 };
 use crate::operations::point_ops::{FilterSelector, PointIdsList, PointsSelector, WriteOrdering};
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{
     AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionStatus, CollectionWarning,
-    CountResult, LocalShardInfo, OptimizersStatus, RecommendRequestInternal, RemoteShardInfo,
-    ShardTransferInfo, UpdateQueueInfo, UpdateResult, UpdateStatus, VectorParams, VectorsConfig,
+    CountResult, IndexingBacklogInfo, LocalShardInfo, OptimizersStatus, RecommendRequestInternal,
+    RemoteShardInfo, ShardTransferInfo, UpdateQueueInfo, UpdateResult, UpdateStatus, VectorParams,
+    VectorsConfig,
 };
 use crate::operations::universal_query::collection_query::FeedbackStrategy;
 use crate::optimizers_builder::OptimizersConfig;
@@ -84,6 +90,58 @@ pub fn sharding_method_from_proto(sharding_method: i32) -> Result<ShardingMethod
     }
 }
 
+pub fn strict_payload_schema_mode_to_proto(mode: StrictPayloadSchemaMode) -> i32 {
+    match mode {
+        StrictPayloadSchemaMode::Reject => {
+            api::grpc::qdrant::StrictPayloadSchemaMode::PayloadSchemaReject as i32
+        }
+        StrictPayloadSchemaMode::Drop => {
+            api::grpc::qdrant::StrictPayloadSchemaMode::PayloadSchemaDrop as i32
+        }
+    }
+}
+
+pub fn strict_payload_schema_mode_from_proto(mode: i32) -> Result<StrictPayloadSchemaMode, Status> {
+    match api::grpc::qdrant::StrictPayloadSchemaMode::try_from(mode) {
+        Ok(api::grpc::qdrant::StrictPayloadSchemaMode::PayloadSchemaReject) => {
+            Ok(StrictPayloadSchemaMode::Reject)
+        }
+        Ok(api::grpc::qdrant::StrictPayloadSchemaMode::PayloadSchemaDrop) => {
+            Ok(StrictPayloadSchemaMode::Drop)
+        }
+        Ok(api::grpc::qdrant::StrictPayloadSchemaMode::PayloadSchemaDisabled) => Err(
+            Status::invalid_argument("PayloadSchemaDisabled is only allowed in collection updates"),
+        ),
+        Err(err) => Err(Status::invalid_argument(format!(
+            "Cannot convert StrictPayloadSchemaMode: {mode}, error: {err}"
+        ))),
+    }
+}
+
+pub fn strict_payload_schema_diff_from_proto(mode: i32) -> Result<StrictPayloadSchemaDiff, Status> {
+    if mode == api::grpc::qdrant::StrictPayloadSchemaMode::PayloadSchemaDisabled as i32 {
+        return Ok(StrictPayloadSchemaDiff::new_disabled());
+    }
+    strict_payload_schema_mode_from_proto(mode).map(StrictPayloadSchemaDiff::Mode)
+}
+
+pub fn read_routing_to_proto(routing: ReadRouting) -> i32 {
+    match routing {
+        ReadRouting::Random => api::grpc::qdrant::ReadRouting::ReadRoutingRandom as i32,
+        ReadRouting::Latency => api::grpc::qdrant::ReadRouting::ReadRoutingLatency as i32,
+    }
+}
+
+pub fn read_routing_from_proto(routing: i32) -> Result<ReadRouting, Status> {
+    match api::grpc::qdrant::ReadRouting::try_from(routing) {
+        Ok(api::grpc::qdrant::ReadRouting::ReadRoutingRandom) => Ok(ReadRouting::Random),
+        Ok(api::grpc::qdrant::ReadRouting::ReadRoutingLatency) => Ok(ReadRouting::Latency),
+        Err(err) => Err(Status::invalid_argument(format!(
+            "Cannot convert ReadRouting: {routing}, error: {err}"
+        ))),
+    }
+}
+
 pub fn write_ordering_to_proto(ordering: WriteOrdering) -> api::grpc::qdrant::WriteOrdering {
     api::grpc::qdrant::WriteOrdering {
         r#type: match ordering {
@@ -129,6 +187,8 @@ pub fn try_record_from_grpc(
         vectors,
         shard_key,
         order_value,
+        version,
+        version_peer_id,
     } = point;
     let id = id
         .ok_or_else(|| Status::invalid_argument("retrieved point does not have an ID"))?
@@ -154,6 +214,8 @@ pub fn try_record_from_grpc(
         vector,
         shard_key: convert_shard_key_from_grpc_opt(shard_key),
         order_value,
+        version,
+        version_peer_id,
     })
 }
 
@@ -185,6 +247,7 @@ pub fn try_discover_request_from_grpc(
         read_consistency,
         timeout,
         shard_key_selector,
+        filter_template: _,
     } = value;
 
     let target = target.map(RecommendExample::try_from).transpose()?;
@@ -247,6 +310,8 @@ impl From<api::grpc::qdrant::HnswConfigDiff> for HnswConfigDiff {
             on_disk,
             payload_m,
             inline_storage,
+            links_compression,
+            tenant_subgraphs,
         } = value;
         Self {
             m: m.map(|v| v as usize),
@@ -256,6 +321,10 @@ impl From<api::grpc::qdrant::HnswConfigDiff> for HnswConfigDiff {
             on_disk,
             payload_m: payload_m.map(|v| v as usize),
             inline_storage,
+            links_compression: links_compression
+                .and_then(|v| api::grpc::qdrant::HnswLinksCompression::try_from(v).ok())
+                .map(HnswLinksCompression::from),
+            tenant_subgraphs,
         }
     }
 }
@@ -270,6 +339,8 @@ impl From<HnswConfigDiff> for api::grpc::qdrant::HnswConfigDiff {
             on_disk,
             payload_m,
             inline_storage,
+            links_compression,
+            tenant_subgraphs,
         } = value;
         Self {
             m: m.map(|v| v as u64),
@@ -279,6 +350,9 @@ impl From<HnswConfigDiff> for api::grpc::qdrant::HnswConfigDiff {
             on_disk,
             payload_m: payload_m.map(|v| v as u64),
             inline_storage,
+            links_compression: links_compression
+                .map(|v| i32::from(api::grpc::qdrant::HnswLinksCompression::from(v))),
+            tenant_subgraphs,
         }
     }
 }
@@ -289,11 +363,176 @@ impl From<api::grpc::qdrant::WalConfigDiff> for WalConfigDiff {
             wal_capacity_mb,
             wal_segments_ahead,
             wal_retain_closed,
+            wal_batch_window_ms,
+            wal_batch_max_points,
         } = value;
         Self {
             wal_capacity_mb: wal_capacity_mb.map(|v| v as usize),
             wal_segments_ahead: wal_segments_ahead.map(|v| v as usize),
             wal_retain_closed: wal_retain_closed.map(|v| v as usize),
+            wal_batch_window_ms,
+            wal_batch_max_points: wal_batch_max_points.map(|v| v as usize),
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::DuplicateDetectionConfig> for DuplicateDetectionConfig {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::DuplicateDetectionConfig) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::DuplicateDetectionConfig {
+            vector,
+            epsilon,
+            tenant_key,
+            mode,
+        } = value;
+        let mode = match mode.map(api::grpc::qdrant::DuplicateDetectionMode::try_from) {
+            None => DuplicateDetectionMode::default(),
+            Some(Ok(api::grpc::qdrant::DuplicateDetectionMode::DuplicateCount)) => {
+                DuplicateDetectionMode::Count
+            }
+            Some(Ok(api::grpc::qdrant::DuplicateDetectionMode::DuplicateReject)) => {
+                DuplicateDetectionMode::Reject
+            }
+            Some(Ok(api::grpc::qdrant::DuplicateDetectionMode::DuplicateTag)) => {
+                DuplicateDetectionMode::Tag
+            }
+            Some(Err(err)) => {
+                return Err(Status::invalid_argument(format!(
+                    "Cannot convert DuplicateDetectionMode: {err}"
+                )));
+            }
+        };
+        let config = Self {
+            vector,
+            epsilon: OrderedFloat(epsilon),
+            tenant_key: tenant_key
+                .as_deref()
+                .map(json_path_from_proto)
+                .transpose()?,
+            mode,
+        };
+        config.validate().map_err(|err| {
+            Status::invalid_argument(format!("Invalid duplicate detection config: {err}"))
+        })?;
+        Ok(config)
+    }
+}
+
+impl From<DuplicateDetectionConfig> for api::grpc::qdrant::DuplicateDetectionConfig {
+    fn from(value: DuplicateDetectionConfig) -> Self {
+        let DuplicateDetectionConfig {
+            vector,
+            epsilon,
+            tenant_key,
+            mode,
+        } = value;
+        let mode = match mode {
+            DuplicateDetectionMode::Count => {
+                api::grpc::qdrant::DuplicateDetectionMode::DuplicateCount
+            }
+            DuplicateDetectionMode::Reject => {
+                api::grpc::qdrant::DuplicateDetectionMode::DuplicateReject
+            }
+            DuplicateDetectionMode::Tag => api::grpc::qdrant::DuplicateDetectionMode::DuplicateTag,
+        };
+        Self {
+            vector,
+            epsilon: epsilon.into_inner(),
+            tenant_key: tenant_key.map(|key| key.to_string()),
+            mode: Some(mode as i32),
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::IngestPayloadConfig> for IngestPayloadConfig {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::IngestPayloadConfig) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::IngestPayloadConfig { defaults, computed } = value;
+        let computed = computed
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match api::grpc::qdrant::ComputedPayloadValue::try_from(value) {
+                    Ok(api::grpc::qdrant::ComputedPayloadValue::ComputedNow) => {
+                        ComputedPayloadValue::Now
+                    }
+                    Ok(api::grpc::qdrant::ComputedPayloadValue::ComputedNowUnix) => {
+                        ComputedPayloadValue::NowUnix
+                    }
+                    Err(err) => {
+                        return Err(Status::invalid_argument(format!(
+                            "Cannot convert ComputedPayloadValue: {value}, error: {err}"
+                        )));
+                    }
+                };
+                Ok((key, value))
+            })
+            .collect::<Result<_, Status>>()?;
+        Ok(Self {
+            defaults: api::conversions::json::proto_to_payloads(defaults)?,
+            computed,
+        })
+    }
+}
+
+impl From<IngestPayloadConfig> for api::grpc::qdrant::IngestPayloadConfig {
+    fn from(value: IngestPayloadConfig) -> Self {
+        let IngestPayloadConfig { defaults, computed } = value;
+        Self {
+            defaults: api::conversions::json::payload_to_proto(defaults),
+            computed: computed
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        ComputedPayloadValue::Now => {
+                            api::grpc::qdrant::ComputedPayloadValue::ComputedNow
+                        }
+                        ComputedPayloadValue::NowUnix => {
+                            api::grpc::qdrant::ComputedPayloadValue::ComputedNowUnix
+                        }
+                    };
+                    (key, value as i32)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::ShardKeyConfig> for ShardKeyConfig {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::ShardKeyConfig) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::ShardKeyConfig {
+            shard_key,
+            on_disk,
+            hnsw_config,
+            max_points_count,
+        } = value;
+        Ok(Self {
+            shard_key: shard_key
+                .and_then(convert_shard_key_from_grpc)
+                .ok_or_else(|| Status::invalid_argument("Shard key is not specified"))?,
+            on_disk,
+            hnsw_config: hnsw_config.map(HnswConfigDiff::from),
+            max_points_count: max_points_count.map(|v| v as usize),
+        })
+    }
+}
+
+impl From<ShardKeyConfig> for api::grpc::qdrant::ShardKeyConfig {
+    fn from(value: ShardKeyConfig) -> Self {
+        let ShardKeyConfig {
+            shard_key,
+            on_disk,
+            hnsw_config,
+            max_points_count,
+        } = value;
+        Self {
+            shard_key: Some(convert_shard_key_to_grpc(shard_key)),
+            on_disk,
+            hnsw_config: hnsw_config.map(From::from),
+            max_points_count: max_points_count.map(|v| v as u64),
         }
     }
 }
@@ -308,6 +547,14 @@ impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
             read_fan_out_factor,
             on_disk_payload,
             read_fan_out_delay_ms,
+            read_routing,
+            payload_overflow_threshold,
+            duplicate_detection,
+            strict_payload_schema,
+            ingest_payload,
+            read_only,
+            trash_retention_sec,
+            points_expiration,
         } = value;
         Ok(Self {
             replication_factor: replication_factor
@@ -325,7 +572,21 @@ impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
                 .transpose()?,
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            read_routing: read_routing.map(read_routing_from_proto).transpose()?,
             on_disk_payload,
+            payload_overflow_threshold: payload_overflow_threshold.map(|v| v as usize),
+            duplicate_detection: duplicate_detection
+                .map(DuplicateDetectionConfig::try_from)
+                .transpose()?,
+            strict_payload_schema: strict_payload_schema
+                .map(strict_payload_schema_diff_from_proto)
+                .transpose()?,
+            ingest_payload: ingest_payload
+                .map(IngestPayloadConfig::try_from)
+                .transpose()?,
+            read_only,
+            trash_retention_sec,
+            points_expiration,
         })
     }
 }
@@ -345,6 +606,8 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfigDiff {
             deprecated_max_optimization_threads,
             max_optimization_threads,
             prevent_unoptimized,
+            max_builder_memory,
+            flush_max_dirty,
         } = value;
         Ok(Self {
             deleted_threshold,
@@ -362,6 +625,8 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfigDiff {
                     .map(TryFrom::try_from)
                     .transpose()?),
             prevent_unoptimized,
+            max_builder_memory: max_builder_memory.map(|v| v as usize),
+            flush_max_dirty: flush_max_dirty.map(|v| v as usize),
         })
     }
 }
@@ -397,6 +662,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             config,
             payload_schema,
             update_queue,
+            indexing_backlog,
         } = value;
 
         let CollectionConfig {
@@ -420,6 +686,8 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            max_builder_memory,
+            flush_max_dirty,
         } = optimizer_config;
 
         let HnswConfig {
@@ -430,6 +698,8 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             on_disk,
             payload_m,
             inline_storage,
+            links_compression,
+            tenant_subgraphs,
         } = hnsw_config;
 
         let CollectionParams {
@@ -437,11 +707,20 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             shard_number,
             replication_factor,
             read_fan_out_delay_ms,
+            read_routing,
             on_disk_payload,
             write_consistency_factor,
             read_fan_out_factor,
             sharding_method,
             sparse_vectors,
+            payload_overflow_threshold,
+            duplicate_detection,
+            strict_payload_schema,
+            ingest_payload,
+            read_only,
+            trash_retention_sec,
+            points_expiration,
+            shard_key_configs,
         } = params;
 
         api::grpc::qdrant::CollectionInfo {
@@ -505,6 +784,16 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                         }
                     }),
                     read_fan_out_delay_ms,
+                    read_routing: read_routing.map(read_routing_to_proto),
+                    payload_overflow_threshold: payload_overflow_threshold.map(|v| v as u64),
+                    duplicate_detection: duplicate_detection.map(From::from),
+                    strict_payload_schema: strict_payload_schema
+                        .map(strict_payload_schema_mode_to_proto),
+                    ingest_payload: ingest_payload.map(From::from),
+                    shard_key_configs: shard_key_configs.into_iter().map(From::from).collect(),
+                    read_only,
+                    trash_retention_sec,
+                    points_expiration,
                 }),
                 hnsw_config: Some(api::grpc::qdrant::HnswConfigDiff {
                     m: Some(m as u64),
@@ -514,6 +803,9 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     on_disk,
                     payload_m: payload_m.map(|v| v as u64),
                     inline_storage,
+                    links_compression: links_compression
+                        .map(|v| i32::from(api::grpc::qdrant::HnswLinksCompression::from(v))),
+                    tenant_subgraphs,
                 }),
                 optimizer_config: Some(api::grpc::qdrant::OptimizersConfigDiff {
                     deleted_threshold: Some(deleted_threshold),
@@ -526,18 +818,24 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     deprecated_max_optimization_threads: max_optimization_threads.map(|x| x as u64),
                     max_optimization_threads: Some(From::from(max_optimization_threads)),
                     prevent_unoptimized,
+                    max_builder_memory: max_builder_memory.map(|x| x as u64),
+                    flush_max_dirty: flush_max_dirty.map(|x| x as u64),
                 }),
                 wal_config: wal_config.map(|wal_config| {
                     let WalConfig {
                         wal_capacity_mb,
                         wal_segments_ahead,
                         wal_retain_closed,
+                        wal_batch_window_ms,
+                        wal_batch_max_points,
                     } = wal_config;
 
                     api::grpc::qdrant::WalConfigDiff {
                         wal_capacity_mb: Some(wal_capacity_mb as u64),
                         wal_segments_ahead: Some(wal_segments_ahead as u64),
                         wal_retain_closed: Some(wal_retain_closed as u64),
+                        wal_batch_window_ms: Some(wal_batch_window_ms),
+                        wal_batch_max_points: Some(wal_batch_max_points as u64),
                     }
                 }),
                 quantization_config: quantization_config.map(|x| x.into()),
@@ -556,6 +854,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                 .map(api::grpc::qdrant::CollectionWarning::from)
                 .collect(),
             update_queue: update_queue.map(api::grpc::qdrant::UpdateQueueInfo::from),
+            indexing_backlog: indexing_backlog.map(api::grpc::qdrant::IndexingBacklogInfo::from),
         }
     }
 }
@@ -583,6 +882,32 @@ impl From<UpdateQueueInfo> for api::grpc::qdrant::UpdateQueueInfo {
     }
 }
 
+impl From<IndexingBacklogInfo> for api::grpc::qdrant::IndexingBacklogInfo {
+    fn from(value: IndexingBacklogInfo) -> Self {
+        let IndexingBacklogInfo {
+            unindexed_vectors_count,
+            vectors_count,
+            search_degraded,
+        } = value;
+        Self {
+            unindexed_vectors_count: unindexed_vectors_count as u64,
+            vectors_count: vectors_count as u64,
+            search_degraded,
+        }
+    }
+}
+
+impl From<api::grpc::qdrant::IndexingBacklogInfo> for IndexingBacklogInfo {
+    fn from(value: api::grpc::qdrant::IndexingBacklogInfo) -> Self {
+        let api::grpc::qdrant::IndexingBacklogInfo {
+            unindexed_vectors_count,
+            vectors_count,
+            search_degraded: _, // recomputed from the counts
+        } = value;
+        Self::new(unindexed_vectors_count as usize, vectors_count as usize)
+    }
+}
+
 impl From<api::grpc::qdrant::UpdateQueueInfo> for UpdateQueueInfo {
     fn from(value: api::grpc::qdrant::UpdateQueueInfo) -> Self {
         let api::grpc::qdrant::UpdateQueueInfo { length } = value;
@@ -629,6 +954,8 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfig {
             deprecated_max_optimization_threads,
             max_optimization_threads,
             prevent_unoptimized,
+            max_builder_memory,
+            flush_max_dirty,
         } = optimizer_config;
 
         let converted_max_optimization_threads: Option<usize> =
@@ -651,6 +978,8 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfig {
             flush_interval_sec: flush_interval_sec.unwrap_or_default(),
             max_optimization_threads: converted_max_optimization_threads,
             prevent_unoptimized,
+            max_builder_memory: max_builder_memory.map(|x| x as usize),
+            flush_max_dirty: flush_max_dirty.map(|x| x as usize),
         })
     }
 }
@@ -661,11 +990,16 @@ impl From<api::grpc::qdrant::WalConfigDiff> for WalConfig {
             wal_capacity_mb,
             wal_segments_ahead,
             wal_retain_closed,
+            wal_batch_window_ms,
+            wal_batch_max_points,
         } = wal_config;
         Self {
             wal_capacity_mb: wal_capacity_mb.unwrap_or_default() as usize,
             wal_segments_ahead: wal_segments_ahead.unwrap_or_default() as usize,
             wal_retain_closed: wal_retain_closed.unwrap_or_default() as usize,
+            wal_batch_window_ms: wal_batch_window_ms.unwrap_or_default(),
+            wal_batch_max_points: wal_batch_max_points
+                .map_or_else(default_wal_batch_max_points, |v| v as usize),
         }
     }
 }
@@ -723,6 +1057,8 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
             on_disk,
             datatype,
             multivector_config,
+            storage_backend,
+            index,
         } = vector_params;
         Ok(Self {
             size: NonZeroU64::new(size).ok_or_else(|| {
@@ -738,6 +1074,8 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
             multivector_config: multivector_config
                 .map(MultiVectorConfig::try_from)
                 .transpose()?,
+            storage_backend,
+            index: convert_vector_index_type_from_proto(index)?,
         })
     }
 }
@@ -762,6 +1100,22 @@ fn convert_datatype_from_proto(datatype: Option<i32>) -> Result<Option<Datatype>
     }
 }
 
+fn convert_vector_index_type_from_proto(
+    index: Option<i32>,
+) -> Result<Option<VectorIndexType>, Status> {
+    index
+        .map(|index_int| {
+            api::grpc::qdrant::VectorIndexType::try_from(index_int)
+                .map(VectorIndexType::from)
+                .map_err(|_| {
+                    Status::invalid_argument(format!(
+                        "Cannot convert vector index type: {index_int}"
+                    ))
+                })
+        })
+        .transpose()
+}
+
 impl TryFrom<api::grpc::qdrant::VectorParamsDiff> for VectorParamsDiff {
     type Error = Status;
 
@@ -770,11 +1124,13 @@ impl TryFrom<api::grpc::qdrant::VectorParamsDiff> for VectorParamsDiff {
             hnsw_config,
             quantization_config,
             on_disk,
+            index,
         } = vector_params;
         Ok(Self {
             hnsw_config: hnsw_config.map(Into::into),
             quantization_config: quantization_config.map(TryInto::try_into).transpose()?,
             on_disk,
+            index: convert_vector_index_type_from_proto(index)?,
         })
     }
 }
@@ -793,6 +1149,39 @@ impl TryFrom<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
                         full_scan_threshold: index_config.full_scan_threshold.map(|v| v as usize),
                         on_disk: index_config.on_disk,
                         datatype: convert_datatype_from_proto(index_config.datatype)?,
+                        similarity: index_config
+                            .similarity
+                            .map(|similarity| {
+                                api::grpc::qdrant::SparseSimilarity::try_from(similarity)
+                                    .map(SparseSimilarity::from)
+                                    .map_err(|_| {
+                                        Status::invalid_argument(format!(
+                                            "Cannot convert sparse similarity: {similarity}"
+                                        ))
+                                    })
+                            })
+                            .transpose()?,
+                        pruning: index_config
+                            .pruning
+                            .map(|pruning| -> Result<_, Status> {
+                                let api::grpc::qdrant::SparsePruningConfig {
+                                    min_weight,
+                                    max_postings_per_dimension,
+                                } = pruning;
+                                let pruning = SparsePruningConfig {
+                                    min_weight: min_weight.map(OrderedFloat),
+                                    max_postings_per_dimension: max_postings_per_dimension
+                                        .map(|v| v as usize),
+                                };
+                                pruning.validate().map_err(|err| {
+                                    Status::invalid_argument(format!(
+                                        "Invalid sparse pruning config: {err}"
+                                    ))
+                                })?;
+                                Ok(pruning)
+                            })
+                            .transpose()?,
+                        block_max: index_config.block_max,
                     })
                 })
                 .transpose()?,
@@ -814,11 +1203,29 @@ impl From<SparseVectorParams> for api::grpc::qdrant::SparseVectorParams {
                     full_scan_threshold,
                     on_disk,
                     datatype,
+                    similarity,
+                    pruning,
+                    block_max,
                 } = index_config;
                 api::grpc::qdrant::SparseIndexConfig {
                     full_scan_threshold: full_scan_threshold.map(|v| v as u64),
                     on_disk,
                     datatype: datatype.map(|dt| api::grpc::qdrant::Datatype::from(dt).into()),
+                    similarity: similarity.map(|similarity| {
+                        api::grpc::qdrant::SparseSimilarity::from(similarity).into()
+                    }),
+                    pruning: pruning.map(|pruning| {
+                        let SparsePruningConfig {
+                            min_weight,
+                            max_postings_per_dimension,
+                        } = pruning;
+                        api::grpc::qdrant::SparsePruningConfig {
+                            min_weight: min_weight.map(OrderedFloat::into_inner),
+                            max_postings_per_dimension: max_postings_per_dimension
+                                .map(|v| v as u64),
+                        }
+                    }),
+                    block_max,
                 }
             }),
             modifier: modifier.map(|modifier| api::grpc::qdrant::Modifier::from(modifier) as i32),
@@ -866,6 +1273,7 @@ impl TryFrom<api::grpc::qdrant::GetCollectionInfoResponse> for CollectionInfo {
                     payload_schema,
                     warnings,
                     update_queue,
+                    indexing_backlog,
                 } = collection_info_response;
                 Ok(Self {
                     status: CollectionStatus::try_from(status)?,
@@ -898,6 +1306,7 @@ impl TryFrom<api::grpc::qdrant::GetCollectionInfoResponse> for CollectionInfo {
                         .try_collect()?,
                     warnings: warnings.into_iter().map(CollectionWarning::from).collect(),
                     update_queue: update_queue.map(UpdateQueueInfo::from),
+                    indexing_backlog: indexing_backlog.map(IndexingBacklogInfo::from),
                 })
             }
         }
@@ -910,8 +1319,19 @@ pub fn try_points_selector_from_grpc(
 ) -> Result<PointsSelector, Status> {
     let api::grpc::qdrant::PointsSelector {
         points_selector_one_of,
+        filter_template,
     } = value;
+    let filter_template = filter_template
+        .map(rest::FilterTemplateReference::try_from)
+        .transpose()?;
     match points_selector_one_of {
+        Some(api::grpc::qdrant::points_selector::PointsSelectorOneOf::Points(_))
+            if filter_template.is_some() =>
+        {
+            Err(Status::invalid_argument(
+                "Filter template can't be combined with a list of point ids",
+            ))
+        }
         Some(api::grpc::qdrant::points_selector::PointsSelectorOneOf::Points(points)) => {
             let api::grpc::qdrant::PointsIdsList { ids } = points;
             Ok(PointsSelector::PointIdsSelector(PointIdsList {
@@ -930,8 +1350,17 @@ pub fn try_points_selector_from_grpc(
                 shard_key: shard_key_selector
                     .map(ShardKeySelector::try_from)
                     .transpose()?,
+                filter_template,
             }))
         }
+        // Points are selected by the filter template only
+        None if filter_template.is_some() => Ok(PointsSelector::FilterSelector(FilterSelector {
+            filter: Filter::default(),
+            shard_key: shard_key_selector
+                .map(ShardKeySelector::try_from)
+                .transpose()?,
+            filter_template,
+        })),
         _ => Err(Status::invalid_argument("Malformed PointsSelector type")),
     }
 }
@@ -941,12 +1370,14 @@ impl From<UpdateResult> for api::grpc::qdrant::UpdateResultInternal {
         let UpdateResult {
             operation_id,
             status,
+            affected_points,
             clock_tag,
         } = res;
         Self {
             operation_id,
             status: status.into(),
             clock_tag: clock_tag.map(Into::into),
+            affected_points: affected_points.map(|count| count as u64),
         }
     }
 }
@@ -965,10 +1396,12 @@ impl TryFrom<api::grpc::qdrant::UpdateResultInternal> for UpdateResult {
             operation_id,
             status,
             clock_tag,
+            affected_points,
         } = res;
         let res = Self {
             operation_id,
             status: status.try_into()?,
+            affected_points: affected_points.map(|count| count as usize),
             clock_tag: clock_tag.map(ClockTag::from),
         };
 
@@ -1075,6 +1508,7 @@ impl TryFrom<api::grpc::qdrant::WithLookup> for WithLookup {
             collection,
             with_payload,
             with_vectors,
+            filter,
         } = value;
         Ok(Self {
             collection_name: collection,
@@ -1083,6 +1517,7 @@ impl TryFrom<api::grpc::qdrant::WithLookup> for WithLookup {
                 .transpose()?
                 .or_else(with_default_payload),
             with_vectors: with_vectors.map(|wv| wv.into()),
+            filter: filter.map(|f| f.try_into()).transpose()?,
         })
     }
 }
@@ -1243,6 +1678,7 @@ impl TryFrom<api::grpc::qdrant::RecommendPoints> for RecommendRequestInternal {
             negative_vectors,
             timeout: _,
             shard_key_selector: _,
+            filter_template: _,
         } = value;
         let positive_ids = positive
             .into_iter()
@@ -1310,6 +1746,7 @@ impl TryFrom<api::grpc::qdrant::RecommendPointGroups> for RecommendGroupsRequest
             negative_vectors: value.negative_vectors,
             timeout: None, // Passed as query param
             shard_key_selector: None,
+            filter_template: None,
         };
 
         let RecommendRequestInternal {
@@ -1372,6 +1809,8 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             on_disk,
             datatype,
             multivector_config,
+            storage_backend,
+            index,
         } = value;
         api::grpc::qdrant::VectorParams {
             size: size.get(),
@@ -1380,6 +1819,7 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
                 Distance::Euclid => api::grpc::qdrant::Distance::Euclid,
                 Distance::Dot => api::grpc::qdrant::Distance::Dot,
                 Distance::Manhattan => api::grpc::qdrant::Distance::Manhattan,
+                Distance::Hamming => api::grpc::qdrant::Distance::Hamming,
             }
             .into(),
             hnsw_config: hnsw_config.map(Into::into),
@@ -1387,6 +1827,26 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             on_disk,
             datatype: datatype.map(|dt| api::grpc::qdrant::Datatype::from(dt).into()),
             multivector_config: multivector_config.map(api::grpc::qdrant::MultiVectorConfig::from),
+            storage_backend,
+            index: index.map(|index| api::grpc::qdrant::VectorIndexType::from(index).into()),
+        }
+    }
+}
+
+impl From<VectorIndexType> for api::grpc::qdrant::VectorIndexType {
+    fn from(value: VectorIndexType) -> Self {
+        match value {
+            VectorIndexType::Hnsw => api::grpc::qdrant::VectorIndexType::Hnsw,
+            VectorIndexType::None => api::grpc::qdrant::VectorIndexType::None,
+        }
+    }
+}
+
+impl From<api::grpc::qdrant::VectorIndexType> for VectorIndexType {
+    fn from(value: api::grpc::qdrant::VectorIndexType) -> Self {
+        match value {
+            api::grpc::qdrant::VectorIndexType::Hnsw => VectorIndexType::Hnsw,
+            api::grpc::qdrant::VectorIndexType::None => VectorIndexType::None,
         }
     }
 }
@@ -1456,6 +1916,7 @@ impl From<ReshardingInfo> for api::grpc::qdrant::ReshardingInfo {
             shard_id,
             peer_id,
             shard_key,
+            target_shard_number: _,
             stage: _, // only communicated for ReshardingTelemetry (internal service)
         } = value;
         Self {
@@ -1515,6 +1976,7 @@ impl From<CollectionClusterInfo> for api::grpc::qdrant::CollectionClusterInfoRes
             remote_shards,
             shard_transfers,
             resharding_operations,
+            resharding_error: _,
         } = value;
         Self {
             peer_id,
@@ -1652,6 +2114,9 @@ impl TryFrom<api::grpc::qdrant::CreateShardKey> for CreateShardingKey {
             replication_factor,
             placement,
             initial_state,
+            on_disk,
+            hnsw_config,
+            max_points_count,
         } = op;
 
         let res = CreateShardingKey {
@@ -1672,6 +2137,9 @@ impl TryFrom<api::grpc::qdrant::CreateShardKey> for CreateShardingKey {
                 })?,
             placement: (!placement.is_empty()).then_some(placement),
             initial_state: initial_state.map(ReplicaState::try_from).transpose()?,
+            on_disk,
+            hnsw_config: hnsw_config.map(HnswConfigDiff::from),
+            max_points_count: max_points_count.map(|v| v as usize),
         };
         Ok(res)
     }
@@ -1846,6 +2314,15 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                         sharding_method,
                         sparse_vectors_config,
                         read_fan_out_delay_ms,
+                        read_routing,
+                        payload_overflow_threshold,
+                        duplicate_detection,
+                        strict_payload_schema,
+                        ingest_payload,
+                        shard_key_configs,
+                        read_only,
+                        trash_retention_sec,
+                        points_expiration,
                     } = params;
                     CollectionParams {
                         vectors: match vectors_config {
@@ -1903,6 +2380,24 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                             .map(sharding_method_from_proto)
                             .transpose()?,
                         read_fan_out_delay_ms,
+                        read_routing: read_routing.map(read_routing_from_proto).transpose()?,
+                        payload_overflow_threshold: payload_overflow_threshold.map(|v| v as usize),
+                        duplicate_detection: duplicate_detection
+                            .map(DuplicateDetectionConfig::try_from)
+                            .transpose()?,
+                        strict_payload_schema: strict_payload_schema
+                            .map(strict_payload_schema_mode_from_proto)
+                            .transpose()?,
+                        ingest_payload: ingest_payload
+                            .map(IngestPayloadConfig::try_from)
+                            .transpose()?,
+                        shard_key_configs: shard_key_configs
+                            .into_iter()
+                            .map(ShardKeyConfig::try_from)
+                            .collect::<Result<_, Status>>()?,
+                        read_only,
+                        trash_retention_sec,
+                        points_expiration,
                     }
                 }
             },
@@ -9,6 +9,7 @@ impl Generalizer for FacetRequestInternal {
             limit,
             filter,
             exact,
+            histogram,
         } = self;
 
         Self {
@@ -16,6 +17,7 @@ impl Generalizer for FacetRequestInternal {
             limit: *limit,
             filter: filter.clone(),
             exact: *exact,
+            histogram: histogram.clone(),
         }
     }
 }
@@ -12,6 +12,8 @@ impl Generalizer for ScrollRequestInternal {
             with_payload,
             with_vector,
             order_by,
+            distinct_by,
+            with_version,
         } = self;
 
         Self {
@@ -21,6 +23,8 @@ impl Generalizer for ScrollRequestInternal {
             with_payload: with_payload.clone(),
             with_vector: with_vector.clone(),
             order_by: order_by.clone(),
+            distinct_by: distinct_by.clone(),
+            with_version: *with_version,
         }
     }
 }
@@ -31,12 +35,14 @@ impl Generalizer for PointRequestInternal {
             ids,
             with_payload,
             with_vector,
+            with_version,
         } = self;
 
         Self {
             ids: ids.clone(),
             with_payload: with_payload.clone(),
             with_vector: with_vector.clone(),
+            with_version: *with_version,
         }
     }
 }
@@ -275,6 +275,9 @@ impl Generalizer for PayloadOps {
             PayloadOps::OverwritePayload(overwrite_payload) => {
                 PayloadOps::OverwritePayload(overwrite_payload.remove_details())
             }
+            PayloadOps::PatchPayload(patch_payload) => {
+                PayloadOps::PatchPayload(patch_payload.remove_details())
+            }
         }
     }
 }
@@ -1,7 +1,8 @@
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
 
-use segment::data_types::facets::FacetParams;
+use segment::data_types::aggregation::AggregateParams;
+use segment::data_types::facets::{FacetParams, MultiFacetParams};
 use serde_json::Value;
 use shard::count::CountRequestInternal;
 use shard::operations::CollectionUpdateOperations;
@@ -101,6 +102,40 @@ impl Loggable for FacetParams {
     }
 }
 
+impl Loggable for MultiFacetParams {
+    fn to_log_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    fn request_name(&self) -> &'static str {
+        "facet-multi"
+    }
+
+    fn request_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.request_name().hash(&mut hasher);
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl Loggable for AggregateParams {
+    fn to_log_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    fn request_name(&self) -> &'static str {
+        "aggregate"
+    }
+
+    fn request_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.request_name().hash(&mut hasher);
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
 impl Loggable for CountRequestInternal {
     fn to_log_value(&self) -> Value {
         serde_json::to_value(self).unwrap_or_default()
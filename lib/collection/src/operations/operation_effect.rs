@@ -129,7 +129,7 @@ impl EstimateOperationEffectArea for PayloadOps {
                 OperationEffectArea::Points(Cow::Borrowed(points))
             }
             PayloadOps::ClearPayloadByFilter(filter) => OperationEffectArea::Filter(filter),
-            PayloadOps::OverwritePayload(set_payload) => {
+            PayloadOps::OverwritePayload(set_payload) | PayloadOps::PatchPayload(set_payload) => {
                 if let Some(points) = &set_payload.points {
                     OperationEffectArea::Points(Cow::Borrowed(points))
                 } else if let Some(filter) = &set_payload.filter {
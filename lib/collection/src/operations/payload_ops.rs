@@ -18,6 +18,9 @@ impl SplitByShard for PayloadOps {
             PayloadOps::OverwritePayload(operation) => operation
                 .split_by_shard(ring)
                 .map(PayloadOps::OverwritePayload),
+            PayloadOps::PatchPayload(operation) => {
+                operation.split_by_shard(ring).map(PayloadOps::PatchPayload)
+            }
         }
     }
 }
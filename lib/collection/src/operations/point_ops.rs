@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use ahash::AHashMap;
-use api::rest::ShardKeySelector;
+use api::rest::{FilterTemplateReference, ShardKeySelector};
 use itertools::izip;
 use schemars::JsonSchema;
 use segment::common::utils::transpose_map_into_named_vector;
@@ -40,6 +40,9 @@ pub struct FilterSelector {
     pub filter: Filter,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 /// Defines write ordering guarantees for collection operations
@@ -50,7 +53,7 @@ pub struct FilterSelector {
 ///
 /// * `strong` - Write operations go through the permanent leader, consistent, but may be unavailable if leader is down
 ///
-#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, Default)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, Default, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum WriteOrdering {
     #[default]
@@ -395,6 +398,7 @@ mod tests {
                 ids: vec![PointIdType::NumId(0)],
                 vectors: BatchVectorStruct::Single(vec![]),
                 payloads: None,
+                shard_keys: None,
             },
             shard_key: None,
             update_filter: None,
@@ -407,6 +411,7 @@ mod tests {
                 ids: vec![PointIdType::NumId(0)],
                 vectors: BatchVectorStruct::Single(vec![vec![0.1]]),
                 payloads: None,
+                shard_keys: None,
             },
             shard_key: None,
             update_filter: None,
@@ -419,6 +424,20 @@ mod tests {
                 ids: vec![PointIdType::NumId(0)],
                 vectors: BatchVectorStruct::Single(vec![vec![0.1]]),
                 payloads: Some(vec![]),
+                shard_keys: None,
+            },
+            shard_key: None,
+            update_filter: None,
+            update_mode: None,
+        });
+        assert!(batch.validate().is_err());
+
+        let batch = PointInsertOperations::PointsBatch(PointsBatch {
+            batch: Batch {
+                ids: vec![PointIdType::NumId(0)],
+                vectors: BatchVectorStruct::Single(vec![vec![0.1]]),
+                payloads: None,
+                shard_keys: Some(vec![]),
             },
             shard_key: None,
             update_filter: None,
@@ -8,6 +8,7 @@ use segment::types::HnswGlobalConfig;
 
 use crate::common::snapshots_manager::SnapshotsConfig;
 use crate::operations::types::NodeType;
+use crate::shards::local_shard::disk_usage_watcher::DiskWatermarksConfig;
 use crate::shards::transfer::ShardTransferMethod;
 
 /// Default timeout for search requests.
@@ -42,6 +43,7 @@ pub struct SharedStorageConfig {
     pub hnsw_global_config: HnswGlobalConfig,
     pub load_concurrency_config: LoadConcurrencyConfig,
     pub search_thread_count: usize,
+    pub disk_watermarks: DiskWatermarksConfig,
 }
 
 impl Default for SharedStorageConfig {
@@ -62,6 +64,7 @@ impl Default for SharedStorageConfig {
             hnsw_global_config: HnswGlobalConfig::default(),
             load_concurrency_config: LoadConcurrencyConfig::default(),
             search_thread_count: common::defaults::search_thread_count(common::cpu::get_num_cpus()),
+            disk_watermarks: DiskWatermarksConfig::default(),
         }
     }
 }
@@ -84,6 +87,7 @@ impl SharedStorageConfig {
         hnsw_global_config: HnswGlobalConfig,
         load_concurrency_config: LoadConcurrencyConfig,
         search_thread_count: usize,
+        disk_watermarks: DiskWatermarksConfig,
     ) -> Self {
         let update_queue_size = update_queue_size.unwrap_or(match node_type {
             NodeType::Normal => DEFAULT_UPDATE_QUEUE_SIZE,
@@ -105,6 +109,7 @@ impl SharedStorageConfig {
             hnsw_global_config,
             load_concurrency_config,
             search_thread_count,
+            disk_watermarks,
         }
     }
 }
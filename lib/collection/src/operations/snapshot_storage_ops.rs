@@ -11,6 +11,7 @@ use tokio::io::AsyncWriteExt;
 
 use super::snapshot_ops::SnapshotDescription;
 use super::types::{CollectionError, CollectionResult};
+use crate::common::snapshot_bandwidth::{SNAPSHOT_DOWNLOAD_LIMITER, SNAPSHOT_UPLOAD_LIMITER};
 
 pub(crate) fn trim_dot_slash(path: &Path) -> CollectionResult<object_store::path::Path> {
     // Get file name by trimming the path.
@@ -130,6 +131,8 @@ pub async fn multipart_upload(
         }
         let buffer = &buffer[..bytes_read];
 
+        SNAPSHOT_UPLOAD_LIMITER.consume(bytes_read).await;
+
         // Wait for capacity before writing the buffer
         write
             .wait_for_capacity(max_concurrency)
@@ -224,6 +227,7 @@ pub async fn download_snapshot(
         let data = data.map_err(|e| {
             CollectionError::service_error(format!("Failed to get data from stream: {e}"))
         })?;
+        SNAPSHOT_DOWNLOAD_LIMITER.consume(data.len()).await;
         file.write_all(&data)
             .await
             .map_err(|e| CollectionError::service_error(format!("Failed to write to file: {e}")))?;
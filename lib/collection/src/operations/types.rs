@@ -8,9 +8,10 @@ use std::time::{Duration, SystemTimeError};
 
 use api::grpc::transport_channel_pool::RequestError;
 use api::rest::{
-    BaseGroupRequest, LookupLocation, RecommendStrategy, SearchGroupsRequestInternal,
-    SearchRequestInternal, ShardKeySelector, VectorStructOutput,
+    BaseGroupRequest, FilterTemplateReference, LookupLocation, RecommendStrategy,
+    SearchGroupsRequestInternal, SearchRequestInternal, ShardKeySelector, VectorStructOutput,
 };
+use chrono::{DateTime, Utc};
 use common::ext::OptionExt;
 use common::fs::FileStorageError;
 use common::progress_tracker::ProgressTree;
@@ -25,6 +26,7 @@ use segment::common::operation_error::{CancelledError, OperationError};
 use segment::data_types::groups::GroupId;
 use segment::data_types::modifier::Modifier;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, DenseVector};
+use segment::index::sparse_index::sparse_index_config::{SparsePruningConfig, SparseSimilarity};
 use segment::types::{
     Distance, Filter, HnswConfig, MultiVectorConfig, Payload, PayloadIndexInfo, PayloadKeyType,
     PointIdType, QuantizationConfig, SearchParams, SeqNumberType, ShardKey,
@@ -153,6 +155,45 @@ pub struct UpdateQueueInfo {
     pub length: usize,
 }
 
+/// Fraction of vectors pending indexing, above which search is considered degraded.
+pub const SEARCH_DEGRADED_THRESHOLD: f64 = 0.1;
+
+/// Vectors which are searched with a plain scan, because indexing of their segments is pending
+#[derive(Debug, Clone, Copy, Serialize, JsonSchema, Default, PartialEq, Eq, Anonymize)]
+pub struct IndexingBacklogInfo {
+    /// Approximate number of vectors in large segments, which are not indexed yet.
+    /// Such vectors are searched with a plain scan, instead of a vector index.
+    #[anonymize(false)]
+    pub unindexed_vectors_count: usize,
+    /// Approximate number of all vectors
+    #[anonymize(false)]
+    pub vectors_count: usize,
+    /// Whether the fraction of unindexed vectors exceeds the threshold, so searches are
+    /// considerably slower than they are going to be once indexing is done.
+    #[anonymize(false)]
+    pub search_degraded: bool,
+}
+
+impl IndexingBacklogInfo {
+    pub fn new(unindexed_vectors_count: usize, vectors_count: usize) -> Self {
+        let search_degraded = vectors_count > 0
+            && unindexed_vectors_count as f64 / vectors_count as f64 > SEARCH_DEGRADED_THRESHOLD;
+        Self {
+            unindexed_vectors_count,
+            vectors_count,
+            search_degraded,
+        }
+    }
+
+    /// Combine backlogs of two sets of vectors, e.g. of two shards
+    pub fn merge(self, other: Self) -> Self {
+        Self::new(
+            self.unindexed_vectors_count + other.unindexed_vectors_count,
+            self.vectors_count + other.vectors_count,
+        )
+    }
+}
+
 // Version of the collection config we can present to the user
 /// Information about the collection configuration
 #[derive(Debug, Serialize, JsonSchema)]
@@ -225,6 +266,9 @@ pub struct CollectionInfo {
     /// Update queue info
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_queue: Option<UpdateQueueInfo>,
+    /// Vectors pending indexing, which are searched with a plain scan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexing_backlog: Option<IndexingBacklogInfo>,
 }
 
 impl CollectionInfo {
@@ -246,6 +290,7 @@ impl CollectionInfo {
                 .map(|(k, v)| (k, PayloadIndexInfo::new(v, 0)))
                 .collect(),
             update_queue: Some(UpdateQueueInfo::default()),
+            indexing_backlog: Some(IndexingBacklogInfo::default()),
         }
     }
 }
@@ -261,6 +306,7 @@ impl From<ShardInfoInternal> for CollectionInfo {
             config,
             payload_schema,
             update_queue,
+            indexing_backlog,
         } = info;
         Self {
             status: status.into(),
@@ -272,6 +318,7 @@ impl From<ShardInfoInternal> for CollectionInfo {
             config: CollectionConfig::from(config),
             payload_schema,
             update_queue: Some(UpdateQueueInfo::from(update_queue)),
+            indexing_backlog: Some(indexing_backlog),
         }
     }
 }
@@ -307,6 +354,8 @@ pub struct ShardInfoInternal {
     pub payload_schema: HashMap<PayloadKeyType, PayloadIndexInfo>,
     /// Update queue state
     pub update_queue: ShardUpdateQueueInfo,
+    /// Vectors pending indexing, which are searched with a plain scan
+    pub indexing_backlog: IndexingBacklogInfo,
 }
 
 /// Current clustering distribution for the collection
@@ -326,6 +375,10 @@ pub struct CollectionClusterInfo {
     // TODO(resharding): remove this skip when releasing resharding
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resharding_operations: Option<Vec<ReshardingInfo>>,
+    /// Error of the last resharding to a target number of shards. Available only on the peer
+    /// driving it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resharding_error: Option<String>,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -336,6 +389,8 @@ pub struct OptimizationsRequestOptions {
     pub completed_limit: Option<usize>,
     /// `?with=idle_segments`
     pub idle_segments: bool,
+    /// `?with=decisions`
+    pub decisions: bool,
 }
 
 /// Optimizations progress for the collection
@@ -356,6 +411,10 @@ pub struct OptimizationsResponse {
     /// Requires `?with=idle_segments`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub idle_segments: Option<Vec<OptimizationSegmentInfo>>,
+    /// Latest decisions of the optimizers, to start or skip optimizations.
+    /// Requires `?with=decisions`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decisions: Option<Vec<OptimizationDecision>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Default)]
@@ -398,6 +457,60 @@ pub struct PendingOptimization {
     pub segments: Vec<OptimizationSegmentInfo>,
 }
 
+/// Record of an optimizer selecting segments, and what was done with them.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OptimizationDecision {
+    /// Name of the optimizer that selected the segments.
+    ///
+    /// It defines what triggered the optimization: `vacuum` - deleted points,
+    /// `merge` - too many segments, `indexing` - indexing or mmap thresholds,
+    /// `config mismatch` - changed collection configuration.
+    pub optimizer: String,
+    /// Condition of the optimizer, with its thresholds at the time of the decision.
+    #[serde(default)]
+    pub trigger: String,
+    /// Segments selected by the optimizer.
+    pub segments: Vec<OptimizationCandidateInfo>,
+    pub action: OptimizationAction,
+    /// Why the optimization was skipped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Unique identifier of the started optimization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<Uuid>,
+    /// Estimated cost of the optimization: number of points to rewrite.
+    pub estimated_points: usize,
+    /// Estimated cost of the optimization: size of vectors to rebuild, in bytes.
+    pub estimated_vectors_size_bytes: usize,
+    /// When the decision was made last time.
+    pub decided_at: DateTime<Utc>,
+    /// How many times the same decision was made in a row.
+    pub repeated: usize,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OptimizationAction {
+    /// Optimization of the selected segments was started
+    Started,
+    /// Selected segments were left as is, for now
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OptimizationCandidateInfo {
+    /// Unique identifier of the segment.
+    pub uuid: Uuid,
+    /// Number of non-deleted points in the segment.
+    pub points_count: usize,
+    /// Number of deleted points in the segment.
+    pub deleted_count: usize,
+    /// Ratio of deleted points to all points in the segment.
+    pub deleted_ratio: f64,
+    /// Size of vectors in the segment, in bytes.
+    pub vectors_size_bytes: usize,
+}
+
 impl OptimizationsResponse {
     /// Merge another `OptimizationsResponse` into this one.
     pub fn merge(&mut self, other: OptimizationsResponse) {
@@ -413,6 +526,7 @@ impl OptimizationsResponse {
             queued,
             completed,
             idle_segments,
+            decisions,
         } = other;
 
         self.running.extend(running);
@@ -423,6 +537,7 @@ impl OptimizationsResponse {
         merge_optional_vec(&mut self.completed, completed);
         merge_optional_vec(&mut self.queued, queued);
         merge_optional_vec(&mut self.idle_segments, idle_segments);
+        merge_optional_vec(&mut self.decisions, decisions);
     }
 }
 
@@ -495,6 +610,11 @@ pub struct ReshardingInfo {
 
     pub shard_key: Option<ShardKey>,
 
+    /// Number of shards to reach, if resharding is done in multiple operations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub target_shard_number: Option<u32>,
+
     /// Only included in peer telemetry
     #[serde(skip)]
     #[anonymize(false)]
@@ -576,12 +696,27 @@ pub struct UpdateResult {
     /// Update status
     pub status: UpdateStatus,
 
+    /// Number of points affected by the operation.
+    /// Only provided if the operation was completed, i.e. with `wait=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affected_points: Option<usize>,
+
     /// Updated value for the external clock tick
     /// Provided if incoming update request also specify clock tick
     #[serde(skip)]
     pub clock_tag: Option<ClockTag>,
 }
 
+/// Preview of an update operation, computed without applying any changes
+#[derive(Debug, Default, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct UpdatePreview {
+    /// Number of points which would be affected by the operation
+    pub matched: usize,
+    /// Sample of IDs of the affected points
+    pub sample: Vec<PointIdType>,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct ScrollRequest {
@@ -591,6 +726,9 @@ pub struct ScrollRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 fn points_example() -> Vec<api::rest::Record> {
@@ -609,6 +747,8 @@ fn points_example() -> Vec<api::rest::Record> {
             vector: Some(VectorStructOutput::Single(vec![0.875, 0.140625, 0.897_6])),
             shard_key: Some("region_1".into()),
             order_value: None,
+            version: None,
+            version_peer_id: None,
         },
         api::rest::Record {
             id: PointIdType::NumId(41),
@@ -616,6 +756,8 @@ fn points_example() -> Vec<api::rest::Record> {
             vector: Some(VectorStructOutput::Single(vec![0.75, 0.640625, 0.8945])),
             shard_key: Some("region_1".into()),
             order_value: None,
+            version: None,
+            version_peer_id: None,
         },
     ]
 }
@@ -640,6 +782,9 @@ pub struct SearchRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -657,6 +802,9 @@ pub struct SearchGroupsRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate)]
@@ -679,6 +827,10 @@ pub struct PointRequestInternal {
     /// Options for specifying which vectors to include into response. Default is false.
     #[serde(default, alias = "with_vectors")]
     pub with_vector: WithVector,
+    /// If true, return the version of the last operation which modified each point, together
+    /// with the peer which replica reported it. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub with_version: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
@@ -743,6 +895,9 @@ pub struct RecommendRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 /// Recommendation request.
@@ -825,6 +980,9 @@ pub struct RecommendGroupsRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
@@ -897,6 +1055,9 @@ pub struct DiscoverRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 /// Use context and a target to find the most similar points, constrained by the context.
@@ -991,6 +1152,9 @@ pub struct CountRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 #[derive(Debug, Default, Serialize, JsonSchema)]
@@ -1049,6 +1213,8 @@ pub enum CollectionError {
     },
     #[error("Shard temporarily unavailable: {description}")]
     ShardUnavailable { description: String },
+    #[error("Insufficient disk space: {description}")]
+    InsufficientDiskSpace { description: String },
 }
 
 impl CollectionError {
@@ -1140,6 +1306,14 @@ impl CollectionError {
         write_limit_type: bool, // false = read rate limit; true = write rate limit.
     ) -> Self {
         let rate_limiter_type = if write_limit_type { "Write" } else { "Read" };
+        Self::operation_rate_limit_error(rate_limit_error, cost, rate_limiter_type)
+    }
+
+    pub fn operation_rate_limit_error(
+        rate_limit_error: RateLimitError,
+        cost: usize,
+        rate_limiter_type: &str,
+    ) -> Self {
         let (description, retry_after) = match rate_limit_error {
             RateLimitError::AlwaysOverBudget(msg) => {
                 let description = format!("{rate_limiter_type} rate limit exceeded, {msg}",);
@@ -1170,6 +1344,12 @@ impl CollectionError {
         }
     }
 
+    pub fn insufficient_disk_space(description: impl Into<String>) -> Self {
+        Self::InsufficientDiskSpace {
+            description: description.into(),
+        }
+    }
+
     /// Returns true if the error is transient and the operation can be retried.
     /// Returns false if the error is not transient and the operation should fail on all replicas.
     pub fn is_transient(&self) -> bool {
@@ -1181,6 +1361,7 @@ impl CollectionError {
             Self::OutOfMemory { .. } => true,
             Self::PreConditionFailed { .. } => true,
             Self::ShardUnavailable { .. } => true,
+            Self::InsufficientDiskSpace { .. } => true,
             // Not transient
             Self::BadInput { .. } => false,
             Self::NotFound { .. } => false,
@@ -1271,7 +1452,10 @@ impl From<OperationError> for CollectionError {
                 description: "Conversion between multi and regular vectors failed".to_string(),
             },
             OperationError::MissingRangeIndexForOrderBy { .. } => Self::bad_input(format!("{err}")),
+            OperationError::MissingRangeIndexForSample { .. } => Self::bad_input(format!("{err}")),
             OperationError::MissingMapIndexForFacet { .. } => Self::bad_input(format!("{err}")),
+            OperationError::MissingRangeIndexForFacet { .. } => Self::bad_input(format!("{err}")),
+            OperationError::TooManyHistogramBuckets { .. } => Self::bad_input(format!("{err}")),
             OperationError::VariableTypeError { .. } => Self::bad_input(format!("{err}")),
             OperationError::NonFiniteNumber { .. } => Self::bad_input(format!("{err}")),
             OperationError::RocksDbColumnFamilyNotFound { .. } => Self::ServiceError {
@@ -1513,6 +1697,29 @@ impl From<Datatype> for VectorStorageDatatype {
     }
 }
 
+/// Type of index to build for a dense vector
+#[derive(
+    Default, Debug, Deserialize, Serialize, JsonSchema, Anonymize, Eq, PartialEq, Copy, Clone, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorIndexType {
+    /// Build HNSW index, once segment size reaches the indexing threshold
+    #[default]
+    Hnsw,
+    /// Only store vectors, never build an index for them.
+    /// Vectors can be retrieved and used for rescoring, searches on them use a plain scan.
+    None,
+}
+
+impl VectorIndexType {
+    pub fn is_indexed(self) -> bool {
+        match self {
+            VectorIndexType::Hnsw => true,
+            VectorIndexType::None => false,
+        }
+    }
+}
+
 /// Params of single vector data storage
 #[derive(
     Debug, Hash, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone, PartialEq, Eq,
@@ -1556,6 +1763,18 @@ pub struct VectorParams {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub multivector_config: Option<MultiVectorConfig>,
+
+    /// Name of a vector storage backend to store vectors with, instead of the built-in storage.
+    /// The backend must be registered on all nodes of the cluster.
+    /// Not supported for vectors with multivector config.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_backend: Option<String>,
+
+    /// Type of index to build for this vector. If `none` - vectors are only stored and never
+    /// indexed, saving index build time and RAM for vectors which are not used for search.
+    /// Default: `hnsw`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<VectorIndexType>,
 }
 
 /// Validate the value is in `[1, 65536]` or `None`.
@@ -1578,6 +1797,7 @@ fn is_hnsw_diff_empty(hnsw_config: &Option<HnswConfigDiff>) -> bool {
 pub struct SparseVectorParams {
     /// Custom params for index. If none - values from collection configuration are used.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
     pub index: Option<SparseIndexParams>,
 
     /// Configures addition value modifications for sparse vectors.
@@ -1594,7 +1814,18 @@ impl SparseVectorParams {
 
 /// Configuration for sparse inverted index.
 #[derive(
-    Debug, Hash, Deserialize, Serialize, JsonSchema, Anonymize, Copy, Clone, PartialEq, Eq, Default,
+    Debug,
+    Hash,
+    Deserialize,
+    Serialize,
+    JsonSchema,
+    Validate,
+    Anonymize,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
 )]
 #[serde(rename_all = "snake_case")]
 pub struct SparseIndexParams {
@@ -1619,6 +1850,28 @@ pub struct SparseIndexParams {
     ///   actual vector data does not need to conform to this range.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub datatype: Option<Datatype>,
+    /// Similarity function used to score vectors.
+    ///
+    /// - `dot` - dot product of weights, default.
+    /// - `jaccard` - weighted Jaccard similarity: sum of minimum weights over sum of maximum
+    ///   weights of each dimension. For binary weights it is the size of intersection over the
+    ///   size of union of dimensions, suitable for set-like features.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub similarity: Option<SparseSimilarity>,
+    /// Pruning of posting lists, applied when building the index of optimized segments.
+    ///
+    /// Bounds the memory of the index for vectors with heavy-tailed dimensions, such as SPLADE,
+    /// at the cost of recall. Vectors themselves are stored unchanged. Default: no pruning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub pruning: Option<SparsePruningConfig>,
+    /// Skip blocks of posting lists which cannot reach the top results during search,
+    /// based on the max weight of each block.
+    ///
+    /// Reduces latency of top-k search on large collections without affecting results.
+    /// Only applies to queries without negative values. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_max: Option<bool>,
 }
 
 impl SparseIndexParams {
@@ -1627,12 +1880,18 @@ impl SparseIndexParams {
             full_scan_threshold,
             on_disk,
             datatype,
+            similarity,
+            pruning,
+            block_max,
         } = other;
 
         self.full_scan_threshold
             .replace_if_some(full_scan_threshold);
         self.on_disk.replace_if_some(on_disk);
         self.datatype.replace_if_some(datatype);
+        self.similarity.replace_if_some(similarity);
+        self.pruning.replace_if_some(pruning);
+        self.block_max.replace_if_some(block_max);
     }
 }
 
@@ -1853,6 +2112,8 @@ impl From<&VectorParams> for VectorParamsBase {
             on_disk: _,
             datatype: _,
             multivector_config: _,
+            storage_backend: _,
+            index: _,
         } = params;
         Self {
             size: size.get() as _, // TODO!?
@@ -1871,6 +2132,7 @@ impl From<&segment::types::VectorDataConfig> for VectorParamsBase {
             quantization_config: _,
             multivector_config: _,
             datatype: _,
+            storage_backend: _,
         } = config;
         Self { size, distance }
     }
@@ -1894,6 +2156,9 @@ pub struct VectorParamsDiff {
     /// If true, vectors are served from disk, improving RAM usage at the cost of latency
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
+    /// Update type of index to build for this vector. If none - it is left unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<VectorIndexType>,
 }
 
 /// Vector update params for multiple vectors
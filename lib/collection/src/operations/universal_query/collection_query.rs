@@ -25,7 +25,7 @@ use crate::common::fetch_vectors::ReferencedVectors;
 use crate::lookup::WithLookup;
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::universal_query::shard_query::MmrInternal;
-use crate::recommendations::avg_vector_for_recommendation;
+use crate::recommendations::{avg_vector_for_recommendation, weighted_sum_of_vectors};
 
 const DEFAULT_MMR_LAMBDA: f32 = 0.5;
 
@@ -168,6 +168,8 @@ pub enum VectorQuery<T> {
     Discover(DiscoveryQuery<T>),
     Context(ContextQuery<T>),
     Feedback(FeedbackInternal<T>),
+    /// Weighted sum of vectors, e.g. `A - B + C`
+    Arithmetic(Vec<ArithmeticTerm<T>>),
 }
 
 impl<T> VectorQuery<T> {
@@ -182,6 +184,7 @@ impl<T> VectorQuery<T> {
             VectorQuery::Discover(query) => Box::new(query.flat_iter()),
             VectorQuery::Context(query) => Box::new(query.flat_iter()),
             VectorQuery::Feedback(query) => Box::new(query.flat_iter()),
+            VectorQuery::Arithmetic(terms) => Box::new(terms.iter().map(|term| &term.vector)),
         }
     }
 }
@@ -214,6 +217,12 @@ impl<T> FeedbackInternal<T> {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArithmeticTerm<T> {
+    pub vector: T,
+    pub weight: f32,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum FeedbackStrategy {
     Naive { a: f32, b: f32, c: f32 },
@@ -359,6 +368,21 @@ impl VectorQuery<VectorInputInternal> {
                     strategy,
                 }))
             }
+            VectorQuery::Arithmetic(terms) => {
+                let terms = terms
+                    .into_iter()
+                    .map(|ArithmeticTerm { vector, weight }| {
+                        Ok(ArithmeticTerm {
+                            vector: ids_to_vectors
+                                .resolve_reference(lookup_collection, lookup_vector_name, vector)
+                                .ok_or_else(|| vector_not_found_error(lookup_vector_name))?,
+                            weight,
+                        })
+                    })
+                    .collect::<CollectionResult<_>>()?;
+
+                Ok(VectorQuery::Arithmetic(terms))
+            }
         }
     }
 
@@ -443,6 +467,9 @@ impl VectorQuery<VectorInternal> {
                     .iter_mut()
                     .for_each(|item| item.vector.preprocess());
             }
+            VectorQuery::Arithmetic(terms) => {
+                terms.iter_mut().for_each(|term| term.vector.preprocess());
+            }
         }
         self
     }
@@ -503,6 +530,14 @@ impl VectorQuery<VectorInternal> {
                     using,
                 )),
             },
+            VectorQuery::Arithmetic(terms) => {
+                let search_vector = weighted_sum_of_vectors(
+                    terms
+                        .iter()
+                        .map(|term| (VectorRef::from(&term.vector), term.weight)),
+                )?;
+                QueryEnum::Nearest(NamedQuery::new(search_vector, using))
+            }
         };
 
         Ok(ScoringQuery::Vector(query_enum))
@@ -690,9 +725,7 @@ impl CollectionQueryRequest {
         )?;
 
         let mut offset = self.offset;
-        if matches!(self.query, Some(Query::Sample(SampleInternal::Random)))
-            && self.prefetch.is_empty()
-        {
+        if matches!(self.query, Some(Query::Sample(_))) && self.prefetch.is_empty() {
             // Shortcut: Ignore offset with random query, since output is not stable.
             offset = 0;
         }
@@ -32,7 +32,7 @@ pub fn query_result_order(
             ScoringQuery::Formula(_formula) => Some(Order::LargeBetter),
             ScoringQuery::OrderBy(order_by) => Some(Order::from(order_by.direction())),
             // Random sample does not require ordering
-            ScoringQuery::Sample(SampleInternal::Random) => None,
+            ScoringQuery::Sample(SampleInternal::Random | SampleInternal::Weighted(_)) => None,
             // MMR cannot be reordered
             ScoringQuery::Mmr(_) => None,
         },
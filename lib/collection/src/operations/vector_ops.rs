@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use ahash::AHashMap;
-use api::rest::{PointVectors, ShardKeySelector};
+use api::rest::{FilterTemplateReference, PointVectors, ShardKeySelector};
 use schemars::JsonSchema;
 use segment::types::{Filter, PointIdType, VectorNameBuf};
 use serde::{Deserialize, Serialize};
@@ -24,6 +24,9 @@ pub struct DeleteVectors {
     pub vector: HashSet<VectorNameBuf>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 impl SplitByShard for Vec<PointVectors> {
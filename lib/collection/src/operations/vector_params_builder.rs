@@ -3,7 +3,7 @@ use std::num::NonZeroU64;
 use segment::types::{Distance, MultiVectorConfig, QuantizationConfig};
 
 use crate::operations::config_diff::HnswConfigDiff;
-use crate::operations::types::{Datatype, VectorParams};
+use crate::operations::types::{Datatype, VectorIndexType, VectorParams};
 
 pub struct VectorParamsBuilder {
     vector_params: VectorParams,
@@ -20,6 +20,8 @@ impl VectorParamsBuilder {
                 on_disk: None,
                 datatype: None,
                 multivector_config: None,
+                storage_backend: None,
+                index: None,
             },
         }
     }
@@ -49,6 +51,16 @@ impl VectorParamsBuilder {
         self
     }
 
+    pub fn with_storage_backend(mut self, storage_backend: impl Into<String>) -> Self {
+        self.vector_params.storage_backend = Some(storage_backend.into());
+        self
+    }
+
+    pub fn with_index(mut self, index: VectorIndexType) -> Self {
+        self.vector_params.index = Some(index);
+        self
+    }
+
     pub fn build(self) -> VectorParams {
         self.vector_params
     }
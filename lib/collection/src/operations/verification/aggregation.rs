@@ -0,0 +1,57 @@
+use api::rest::AggregateRequestInternal;
+use segment::data_types::aggregation::AggregateParams;
+use segment::types::{Filter, SearchParams};
+
+use super::StrictModeVerification;
+
+impl StrictModeVerification for AggregateRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+}
+
+impl StrictModeVerification for AggregateParams {
+    fn query_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+}
@@ -0,0 +1,30 @@
+use api::rest::CopyPointsRequestInternal;
+use segment::types::{Filter, SearchParams};
+
+use super::StrictModeVerification;
+
+impl StrictModeVerification for CopyPointsRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+}
@@ -13,6 +13,10 @@ impl StrictModeVerification for DiscoverRequestInternal {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&Filter> {
         None
     }
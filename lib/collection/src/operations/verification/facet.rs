@@ -1,5 +1,5 @@
-use api::rest::FacetRequestInternal;
-use segment::data_types::facets::FacetParams;
+use api::rest::{FacetRequestInternal, MultiFacetRequestInternal};
+use segment::data_types::facets::{FacetParams, MultiFacetParams};
 use segment::types::{Filter, SearchParams};
 
 use super::StrictModeVerification;
@@ -13,6 +13,10 @@ impl StrictModeVerification for FacetRequestInternal {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&Filter> {
         None
     }
@@ -35,6 +39,62 @@ impl StrictModeVerification for FacetParams {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+}
+
+impl StrictModeVerification for MultiFacetRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        self.exact
+    }
+
+    fn request_search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+}
+
+impl StrictModeVerification for MultiFacetParams {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&Filter> {
         None
     }
@@ -0,0 +1,56 @@
+use api::rest::JoinRequestInternal;
+
+use super::StrictModeVerification;
+use crate::collection::join::CollectionJoinRequest;
+
+impl StrictModeVerification for JoinRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    fn indexed_filter_read(&self) -> Option<&segment::types::Filter> {
+        self.filter.as_ref()
+    }
+
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
+    fn indexed_filter_write(&self) -> Option<&segment::types::Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        self.params.as_ref().map(|params| params.exact)
+    }
+
+    fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
+        self.params.as_ref()
+    }
+}
+
+impl StrictModeVerification for CollectionJoinRequest {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn indexed_filter_read(&self) -> Option<&segment::types::Filter> {
+        self.filter.as_ref()
+    }
+
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
+    fn indexed_filter_write(&self) -> Option<&segment::types::Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        self.params.as_ref().map(|params| params.exact)
+    }
+
+    fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
+        self.params.as_ref()
+    }
+}
@@ -1,7 +1,9 @@
+use segment::types::StrictModeConfig;
 use shard::scroll::ScrollRequestInternal;
 
 use super::StrictModeVerification;
-use crate::operations::types::PointRequestInternal;
+use crate::collection::Collection;
+use crate::operations::types::{CollectionError, CollectionResult, PointRequestInternal};
 
 impl StrictModeVerification for ScrollRequestInternal {
     fn query_limit(&self) -> Option<usize> {
@@ -12,6 +14,10 @@ impl StrictModeVerification for ScrollRequestInternal {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&segment::types::Filter> {
         None
     }
@@ -26,6 +32,21 @@ impl StrictModeVerification for ScrollRequestInternal {
 }
 
 impl StrictModeVerification for PointRequestInternal {
+    async fn check_custom(
+        &self,
+        _collection: &Collection,
+        strict_mode_config: &StrictModeConfig,
+    ) -> CollectionResult<()> {
+        // Points of other tenants could be retrieved by their IDs
+        if let Some(tenant_key) = &strict_mode_config.tenant_filter_key {
+            return Err(CollectionError::strict_mode(
+                format!("Retrieving points by ID is not allowed with tenant key \"{tenant_key}\""),
+                "Use scroll with a `has_id` condition and a filter by the tenant key.",
+            ));
+        }
+        Ok(())
+    }
+
     fn query_limit(&self) -> Option<usize> {
         None
     }
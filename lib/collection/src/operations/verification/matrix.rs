@@ -17,6 +17,10 @@ impl StrictModeVerification for SearchMatrixRequestInternal {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&segment::types::Filter> {
         None
     }
@@ -39,6 +43,10 @@ impl StrictModeVerification for CollectionSearchMatrixRequest {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&segment::types::Filter> {
         None
     }
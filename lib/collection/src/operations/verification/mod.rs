@@ -1,10 +1,14 @@
+mod aggregation;
+mod copy;
 mod count;
 mod discovery;
 mod facet;
+mod join;
 mod local_shard;
 mod matrix;
 mod query;
 mod recommend;
+mod sample_projection;
 mod search;
 mod update;
 
@@ -12,7 +16,7 @@ use std::fmt::Display;
 
 use itertools::Itertools;
 use segment::json_path::JsonPath;
-use segment::types::{Filter, SearchParams, StrictModeConfig};
+use segment::types::{Condition, Filter, Match, SearchParams, StrictModeConfig};
 pub use shard::operation_rate_cost;
 
 use super::types::{CollectionError, CollectionResult};
@@ -59,6 +63,12 @@ pub trait StrictModeVerification {
     /// For read only filters implement `request_indexed_filter_read`!
     fn indexed_filter_write(&self) -> Option<&Filter>;
 
+    /// Return `true` if the READ-operation has to be scoped to a single tenant by the filter
+    /// returned from `indexed_filter_read`, once a tenant key is configured.
+    fn tenant_scoped_read(&self) -> bool {
+        false
+    }
+
     fn request_exact(&self) -> Option<bool>;
 
     fn request_search_params(&self) -> Option<&SearchParams>;
@@ -148,6 +158,31 @@ pub trait StrictModeVerification {
         Ok(())
     }
 
+    /// Checks the read filter and the update filter match the configured tenant key.
+    fn check_tenant_filter(&self, strict_mode_config: &StrictModeConfig) -> CollectionResult<()> {
+        let Some(tenant_key) = &strict_mode_config.tenant_filter_key else {
+            return Ok(());
+        };
+
+        let scoped_filters = [
+            self.tenant_scoped_read()
+                .then(|| self.indexed_filter_read()),
+            // Filtered updates may affect points of any tenant
+            self.indexed_filter_write().map(Some),
+        ];
+
+        for filter in scoped_filters.into_iter().flatten() {
+            if !filter.is_some_and(|filter| has_tenant_condition(filter, tenant_key)) {
+                return Err(CollectionError::strict_mode(
+                    format!("Filter by tenant key \"{tenant_key}\" is required"),
+                    "Add a `must` condition matching a single value of the tenant key to the filter.",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Does the verification of all configured parameters. Only implement this function if you know what
     /// you are doing. In most cases implementing `check_custom` is sufficient.
     #[allow(async_fn_in_trait)]
@@ -159,6 +194,7 @@ pub trait StrictModeVerification {
         self.check_custom(collection, strict_mode_config).await?;
         self.check_request_query_limit(strict_mode_config)?;
         self.check_request_filter(collection, strict_mode_config)?;
+        self.check_tenant_filter(strict_mode_config)?;
         self.check_request_exact(strict_mode_config)?;
         self.check_search_params(collection, strict_mode_config)
             .await?;
@@ -201,6 +237,22 @@ fn check_filter_limits(
     Ok(())
 }
 
+/// Whether the filter requires an exact match of a single value of the tenant key, possibly in
+/// a nested `must` filter
+fn has_tenant_condition(filter: &Filter, tenant_key: &JsonPath) -> bool {
+    filter
+        .must
+        .iter()
+        .flatten()
+        .any(|condition| match condition {
+            Condition::Field(field) => {
+                &field.key == tenant_key && matches!(field.r#match, Some(Match::Value(_)))
+            }
+            Condition::Filter(filter) => has_tenant_condition(filter, tenant_key),
+            _ => false,
+        })
+}
+
 pub fn check_timeout(
     timeout: usize,
     strict_mode_config: &StrictModeConfig,
@@ -275,6 +327,12 @@ impl StrictModeVerification for SearchParams {
             strict_mode_config.search_max_hnsw_ef,
             "hnsw_ef",
         )?;
+
+        check_limit_opt(
+            self.max_hnsw_ef,
+            strict_mode_config.search_max_hnsw_ef,
+            "max_hnsw_ef",
+        )?;
         Ok(())
     }
 
@@ -332,18 +390,18 @@ mod test {
     use common::budget::ResourceBudget;
     use common::counter::hardware_accumulator::HwMeasurementAcc;
     use segment::types::{
-        Condition, FieldCondition, Filter, Match, PayloadFieldSchema, PayloadSchemaType,
-        SearchParams, StrictModeConfig, ValueVariants,
+        Condition, ExtendedPointId, FieldCondition, Filter, Match, PayloadFieldSchema,
+        PayloadSchemaType, SearchParams, StrictModeConfig, ValueVariants,
     };
     use tempfile::Builder;
 
     use super::StrictModeVerification;
     use crate::collection::{Collection, RequestShardTransfer};
     use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
-    use crate::operations::point_ops::{FilterSelector, PointsSelector};
+    use crate::operations::point_ops::{FilterSelector, PointIdsList, PointsSelector};
     use crate::operations::shared_storage_config::SharedStorageConfig;
     use crate::operations::types::{
-        CollectionError, CountRequestInternal, DiscoverRequestInternal,
+        CollectionError, CountRequestInternal, DiscoverRequestInternal, PointRequestInternal,
     };
     use crate::optimizers_builder::OptimizersConfig;
     use crate::shards::channel_service::ChannelService;
@@ -364,6 +422,96 @@ mod test {
         test_request_exact(&collection).await;
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_strict_mode_tenant_filter() {
+        let strict_mode_config = StrictModeConfig {
+            enabled: Some(true),
+            tenant_filter_key: Some(INDEXED_KEY.try_into().unwrap()),
+            ..Default::default()
+        };
+        let collection = fixture_collection(&strict_mode_config).await;
+
+        // Read requests must filter by the tenant
+        let request = CountRequestInternal {
+            filter: None,
+            exact: true,
+        };
+        assert_strict_mode_error(request, &collection).await;
+
+        let request = CountRequestInternal {
+            filter: Some(filter_fixture(UNINDEXED_KEY)),
+            exact: true,
+        };
+        assert_strict_mode_error(request, &collection).await;
+
+        let request = CountRequestInternal {
+            filter: Some(filter_fixture(INDEXED_KEY)),
+            exact: true,
+        };
+        assert_strict_mode_success(request, &collection).await;
+
+        // Nested `must` filter is accepted
+        let request = CountRequestInternal {
+            filter: Some(Filter::new_must(Condition::Filter(filter_fixture(
+                INDEXED_KEY,
+            )))),
+            exact: true,
+        };
+        assert_strict_mode_success(request, &collection).await;
+
+        // Tenant condition in `should` does not restrict the request to a tenant
+        let request = CountRequestInternal {
+            filter: Some(Filter::new_should(Condition::Filter(filter_fixture(
+                INDEXED_KEY,
+            )))),
+            exact: true,
+        };
+        assert_strict_mode_error(request, &collection).await;
+
+        // Tenant condition must match a single tenant
+        let request = CountRequestInternal {
+            filter: Some(Filter::new_must(Condition::Field(
+                FieldCondition::new_match(
+                    INDEXED_KEY.try_into().unwrap(),
+                    Match::from(vec![1_i64, 2]),
+                ),
+            ))),
+            exact: true,
+        };
+        assert_strict_mode_error(request, &collection).await;
+
+        // Filtered updates must filter by the tenant
+        let request = PointsSelector::FilterSelector(FilterSelector {
+            filter: filter_fixture(UNINDEXED_KEY),
+            shard_key: None,
+            filter_template: None,
+        });
+        assert_strict_mode_error(request, &collection).await;
+
+        let request = PointsSelector::FilterSelector(FilterSelector {
+            filter: filter_fixture(INDEXED_KEY),
+            shard_key: None,
+            filter_template: None,
+        });
+        assert_strict_mode_success(request, &collection).await;
+
+        // Updates by IDs are not affected
+        let request = PointsSelector::PointIdsSelector(PointIdsList {
+            points: vec![ExtendedPointId::NumId(1)],
+            shard_key: None,
+        });
+        assert_strict_mode_success(request, &collection).await;
+
+        // Points can't be retrieved by IDs
+        let request = PointRequestInternal {
+            ids: vec![ExtendedPointId::NumId(1)],
+            with_payload: None,
+            with_vector: false.into(),
+            with_version: None,
+        };
+        assert_strict_mode_error(request, &collection).await;
+    }
+
     async fn test_query_limit(collection: &Collection) {
         assert_strict_mode_error(discovery_fixture(Some(10), None, None), collection).await;
         assert_strict_mode_success(discovery_fixture(Some(4), None, None), collection).await;
@@ -397,12 +545,14 @@ mod test {
         let restricted_request = PointsSelector::FilterSelector(FilterSelector {
             filter: filter_fixture(UNINDEXED_KEY),
             shard_key: None,
+            filter_template: None,
         });
         assert_strict_mode_error(restricted_request, collection).await;
 
         let allowed_request = PointsSelector::FilterSelector(FilterSelector {
             filter: filter_fixture(INDEXED_KEY),
             shard_key: None,
+            filter_template: None,
         });
         assert_strict_mode_success(allowed_request, collection).await;
     }
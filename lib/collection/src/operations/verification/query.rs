@@ -63,12 +63,32 @@ impl Query {
                         return Ok(());
                     }
 
+                    // specialized error message if not default vector
+                    let vector_error_label = if using == DEFAULT_VECTOR_NAME {
+                        ""
+                    } else {
+                        &format!(" on '{using}'")
+                    };
+
+                    let vector_params = config.params.vectors.get_params(using);
+
+                    // store-only vectors are never indexed
+                    let is_index_enabled = vector_params
+                        .and_then(|param| param.index)
+                        .unwrap_or_default()
+                        .is_indexed();
+                    if !is_index_enabled {
+                        return Err(CollectionError::strict_mode(
+                            format!(
+                                "Request is forbidden{vector_error_label} because vector indexing is disabled (index = none)"
+                            ),
+                            "Use this vector for rescoring only, enable vector indexing or enable strict mode `search_allow_exact` option",
+                        ));
+                    }
+
                     // check HNSW configuration for vector
-                    let vector_hnsw_config = &config
-                        .params
-                        .vectors
-                        .get_params(using)
-                        .and_then(|param| param.hnsw_config.as_ref());
+                    let vector_hnsw_config =
+                        &vector_params.and_then(|param| param.hnsw_config.as_ref());
 
                     let vector_hnsw_m = vector_hnsw_config
                         .map(|hnsw_config| hnsw_config.m)
@@ -85,13 +105,6 @@ impl Query {
                         return Ok(());
                     }
 
-                    // specialized error message if not default vector
-                    let vector_error_label = if using == DEFAULT_VECTOR_NAME {
-                        ""
-                    } else {
-                        &format!(" on '{using}'")
-                    };
-
                     // check hnsw.payload_m if there is a filter
                     let uses_multitenant_filter = if let Some(filter) = filter {
                         filter
@@ -171,6 +184,10 @@ impl StrictModeVerification for CollectionQueryRequest {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&segment::types::Filter> {
         None
     }
@@ -267,6 +284,10 @@ impl StrictModeVerification for CollectionQueryGroupsRequest {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&segment::types::Filter> {
         None
     }
@@ -12,6 +12,10 @@ impl StrictModeVerification for RecommendRequestInternal {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&Filter> {
         None
     }
@@ -34,6 +38,10 @@ impl StrictModeVerification for RecommendGroupsRequestInternal {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&Filter> {
         None
     }
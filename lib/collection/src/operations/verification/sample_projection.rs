@@ -0,0 +1,56 @@
+use api::rest::SampleProjectionRequestInternal;
+
+use super::StrictModeVerification;
+use crate::collection::sample_projection::CollectionSampleProjectionRequest;
+
+impl StrictModeVerification for SampleProjectionRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        self.sample
+    }
+
+    fn indexed_filter_read(&self) -> Option<&segment::types::Filter> {
+        self.filter.as_ref()
+    }
+
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
+    fn indexed_filter_write(&self) -> Option<&segment::types::Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
+        None
+    }
+}
+
+impl StrictModeVerification for CollectionSampleProjectionRequest {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.sample_size)
+    }
+
+    fn indexed_filter_read(&self) -> Option<&segment::types::Filter> {
+        self.filter.as_ref()
+    }
+
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
+    fn indexed_filter_write(&self) -> Option<&segment::types::Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
+        None
+    }
+}
@@ -10,6 +10,10 @@ impl StrictModeVerification for SearchRequestInternal {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn query_limit(&self) -> Option<usize> {
         Some(self.limit)
     }
@@ -36,6 +40,10 @@ impl StrictModeVerification for CoreSearchRequest {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn indexed_filter_write(&self) -> Option<&Filter> {
         None
     }
@@ -104,6 +112,10 @@ impl StrictModeVerification for SearchGroupsRequestInternal {
         self.filter.as_ref()
     }
 
+    fn tenant_scoped_read(&self) -> bool {
+        true
+    }
+
     fn request_exact(&self) -> Option<bool> {
         // We already check this in `request_search_params`
         None
@@ -2,18 +2,22 @@ use api::rest::{
     BatchVectorStruct, MultiDenseVector, PointInsertOperations, PointsBatch, PointsList,
     UpdateVectors, Vector, VectorStruct,
 };
+use ordered_float::OrderedFloat;
 use segment::data_types::tiny_map::TinyMap;
 use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
 use segment::types::{
-    Filter, StrictModeConfig, StrictModeMultivectorConfig, StrictModeSparseConfig, VectorName,
-    VectorNameBuf,
+    Filter, StrictModeConfig, StrictModeMultivectorConfig, StrictModeSparse,
+    StrictModeSparseConfig, VectorName, VectorNameBuf,
 };
 
 use super::{StrictModeVerification, check_limit_opt};
 use crate::collection::Collection;
 use crate::common::collection_size_stats::CollectionSizeAtomicStats;
 use crate::operations::payload_ops::{DeletePayload, SetPayload};
-use crate::operations::point_ops::PointsSelector;
+use crate::operations::point_ops::{
+    BatchVectorStructPersisted, PointInsertOperationsInternal, PointsSelector, VectorPersisted,
+    VectorStructPersisted,
+};
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::vector_ops::DeleteVectors;
 
@@ -184,6 +188,49 @@ impl StrictModeVerification for PointInsertOperations {
     }
 }
 
+/// Points, written by the server itself, e.g. when copying points from another collection
+impl StrictModeVerification for PointInsertOperationsInternal {
+    async fn check_custom(
+        &self,
+        collection: &Collection,
+        strict_mode_config: &StrictModeConfig,
+    ) -> CollectionResult<()> {
+        let points_count = match self {
+            PointInsertOperationsInternal::PointsBatch(batch) => batch.ids.len(),
+            PointInsertOperationsInternal::PointsList(points) => points.len(),
+        };
+        check_limit_opt(
+            Some(points_count),
+            strict_mode_config.upsert_max_batchsize,
+            "upsert limit",
+        )?;
+
+        check_collection_size_limit(collection, strict_mode_config).await?;
+
+        check_persisted_vector_limits_insert(self, strict_mode_config)
+    }
+
+    fn query_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
+        None
+    }
+}
+
 impl StrictModeVerification for UpdateVectors {
     async fn check_custom(
         &self,
@@ -370,34 +417,40 @@ fn check_multivectors_limits_update(
     Ok(())
 }
 
-fn sparse_limits(sparse_config: &StrictModeSparseConfig) -> Option<TinyMap<&VectorName, usize>> {
+fn sparse_limits(
+    sparse_config: &StrictModeSparseConfig,
+) -> Option<TinyMap<&VectorName, &StrictModeSparse>> {
     if sparse_config.config.is_empty() {
         return None;
     }
 
-    let sparse_max_size: TinyMap<&VectorName, usize> = sparse_config
+    let sparse_limits: TinyMap<&VectorName, &StrictModeSparse> = sparse_config
         .config
         .iter()
-        .filter_map(|(name, config)| {
-            config
-                .max_length
-                .map(|max_length| (name.as_ref(), max_length))
+        .filter(|(_, config)| {
+            let StrictModeSparse {
+                max_length,
+                max_index,
+                max_abs_value,
+            } = config;
+            max_length.is_some() || max_index.is_some() || max_abs_value.is_some()
         })
+        .map(|(name, config)| (name.as_ref(), config))
         .collect();
 
-    (!sparse_max_size.is_empty()).then_some(sparse_max_size)
+    (!sparse_limits.is_empty()).then_some(sparse_limits)
 }
 
 fn check_sparse_vector_limits_update(
     point_insert: &UpdateVectors,
     sparse_config: &StrictModeSparseConfig,
 ) -> CollectionResult<()> {
-    let Some(sparse_max_size_by_name) = sparse_limits(sparse_config) else {
+    let Some(sparse_limits_by_name) = sparse_limits(sparse_config) else {
         return Ok(());
     };
 
     for point in &point_insert.points {
-        check_sparse_vecstruct_limit(&point.vector, &sparse_max_size_by_name)?;
+        check_sparse_vecstruct_limit(&point.vector, &sparse_limits_by_name)?;
     }
 
     Ok(())
@@ -407,7 +460,7 @@ fn check_sparse_vector_limits_insert(
     point_insert: &PointInsertOperations,
     sparse_config: &StrictModeSparseConfig,
 ) -> CollectionResult<()> {
-    let Some(sparse_max_size_by_name) = sparse_limits(sparse_config) else {
+    let Some(sparse_limits_by_name) = sparse_limits(sparse_config) else {
         return Ok(());
     };
 
@@ -416,7 +469,7 @@ fn check_sparse_vector_limits_insert(
             BatchVectorStruct::Named(named_batch_vectors) => {
                 for (name, vectors) in named_batch_vectors {
                     for vector in vectors {
-                        check_named_sparse_vec_limit(name, vector, &sparse_max_size_by_name)?;
+                        check_named_sparse_vec_limit(name, vector, &sparse_limits_by_name)?;
                     }
                 }
             }
@@ -432,7 +485,7 @@ fn check_sparse_vector_limits_insert(
                 match &point_struct.vector {
                     VectorStruct::Named(named_vectors) => {
                         for (name, vector) in named_vectors {
-                            check_named_sparse_vec_limit(name, vector, &sparse_max_size_by_name)?;
+                            check_named_sparse_vec_limit(name, vector, &sparse_limits_by_name)?;
                         }
                     }
                     VectorStruct::Single(_) => {}
@@ -448,14 +501,86 @@ fn check_sparse_vector_limits_insert(
     Ok(())
 }
 
+fn check_persisted_vector_limits_insert(
+    point_insert: &PointInsertOperationsInternal,
+    strict_mode_config: &StrictModeConfig,
+) -> CollectionResult<()> {
+    let multivector_max_size_by_name = strict_mode_config
+        .multivector_config
+        .as_ref()
+        .and_then(multivector_limits_by_name);
+    let sparse_limits_by_name = strict_mode_config
+        .sparse_config
+        .as_ref()
+        .and_then(sparse_limits);
+
+    if multivector_max_size_by_name.is_none() && sparse_limits_by_name.is_none() {
+        return Ok(());
+    }
+
+    let check_vector = |name: &VectorName, vector: &VectorPersisted| match vector {
+        VectorPersisted::Dense(_) => Ok(()),
+        VectorPersisted::Sparse(sparse) => match sparse_limits_by_name
+            .as_ref()
+            .and_then(|limits| limits.get(name))
+        {
+            Some(limits) => check_sparse_vector_limit(name, sparse, limits),
+            None => Ok(()),
+        },
+        VectorPersisted::MultiDense(multi) => match &multivector_max_size_by_name {
+            Some(limits) => check_named_multivector_limit(name, multi, limits),
+            None => Ok(()),
+        },
+    };
+
+    match point_insert {
+        PointInsertOperationsInternal::PointsBatch(batch) => match &batch.vectors {
+            BatchVectorStructPersisted::Single(_) => {}
+            BatchVectorStructPersisted::MultiDense(multis) => {
+                if let Some(limits) = &multivector_max_size_by_name {
+                    for multi in multis {
+                        check_named_multivector_limit(DEFAULT_VECTOR_NAME, multi, limits)?;
+                    }
+                }
+            }
+            BatchVectorStructPersisted::Named(named_batch_vectors) => {
+                for (name, vectors) in named_batch_vectors {
+                    for vector in vectors {
+                        check_vector(name, vector)?;
+                    }
+                }
+            }
+        },
+        PointInsertOperationsInternal::PointsList(points) => {
+            for point in points {
+                match &point.vector {
+                    VectorStructPersisted::Single(_) => {}
+                    VectorStructPersisted::MultiDense(multi) => {
+                        if let Some(limits) = &multivector_max_size_by_name {
+                            check_named_multivector_limit(DEFAULT_VECTOR_NAME, multi, limits)?;
+                        }
+                    }
+                    VectorStructPersisted::Named(named_vectors) => {
+                        for (name, vector) in named_vectors {
+                            check_vector(name, vector)?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn check_sparse_vecstruct_limit(
     vector: &VectorStruct,
-    sparse_max_size_by_name: &TinyMap<&VectorName, usize>,
+    sparse_limits_by_name: &TinyMap<&VectorName, &StrictModeSparse>,
 ) -> CollectionResult<()> {
     match vector {
         VectorStruct::Named(named) => {
             for (name, vec) in named {
-                check_named_sparse_vec_limit(name, vec, sparse_max_size_by_name)?;
+                check_named_sparse_vec_limit(name, vec, sparse_limits_by_name)?;
             }
             Ok(())
         }
@@ -470,12 +595,12 @@ fn check_sparse_vecstruct_limit(
 fn check_named_sparse_vec_limit(
     name: &VectorName,
     vector: &Vector,
-    sparse_max_size_by_name: &TinyMap<&VectorName, usize>,
+    sparse_limits_by_name: &TinyMap<&VectorName, &StrictModeSparse>,
 ) -> CollectionResult<()> {
     if let Vector::Sparse(sparse) = vector
-        && let Some(strict_sparse_limit) = sparse_max_size_by_name.get(name)
+        && let Some(strict_sparse_limits) = sparse_limits_by_name.get(name)
     {
-        check_sparse_vector_limit(name, sparse, *strict_sparse_limit)?;
+        check_sparse_vector_limit(name, sparse, strict_sparse_limits)?;
     }
     Ok(())
 }
@@ -483,15 +608,48 @@ fn check_named_sparse_vec_limit(
 fn check_sparse_vector_limit(
     name: &VectorName,
     sparse: &sparse::common::sparse_vector::SparseVector,
-    max_size: usize,
+    limits: &StrictModeSparse,
 ) -> CollectionResult<()> {
-    let vector_len = sparse.indices.len();
+    let StrictModeSparse {
+        max_length,
+        max_index,
+        max_abs_value,
+    } = limits;
+
+    if let Some(max_size) = *max_length {
+        let vector_len = sparse.indices.len();
+
+        if vector_len > max_size || sparse.values.len() > max_size {
+            return Err(CollectionError::bad_request(format!(
+                "Sparse vector '{name}' has a limit of {max_size} indices, but {vector_len} were provided!"
+            )));
+        }
+    }
+
+    if let Some(max_index) = *max_index
+        && let Some(index) = sparse
+            .indices
+            .iter()
+            .copied()
+            .find(|index| *index > max_index)
+    {
+        return Err(CollectionError::bad_request(format!(
+            "Sparse vector '{name}' has a limit of {max_index} for index value, but {index} was provided!"
+        )));
+    }
 
-    if vector_len > max_size || sparse.values.len() > max_size {
+    if let Some(OrderedFloat(max_abs_value)) = *max_abs_value
+        && let Some(value) = sparse
+            .values
+            .iter()
+            .copied()
+            .find(|value| f64::from(value.abs()) > max_abs_value)
+    {
         return Err(CollectionError::bad_request(format!(
-            "Sparse vector '{name}' has a limit of {max_size} indices, but {vector_len} were provided!"
+            "Sparse vector '{name}' has a limit of {max_abs_value} for absolute weight value, but {value} was provided!"
         )));
     }
+
     Ok(())
 }
 
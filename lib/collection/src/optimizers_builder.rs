@@ -90,6 +90,24 @@ pub struct OptimizersConfig {
     /// Default is disabled.
     #[serde(default)]
     pub prevent_unoptimized: Option<bool>,
+    /// Maximum memory (in kilobytes) for intermediate point mappings while building an optimized segment.
+    /// If source segments have more points than fit into this budget, the mapping is spilled to disk
+    /// and points are copied into the new segment in chunks.
+    ///
+    /// If not set, the whole mapping is kept in memory.
+    #[serde(alias = "max_builder_memory_kb")]
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_builder_memory: Option<usize>,
+    /// Maximum amount of data (in kilobytes) written into payload and vector storages of a shard
+    /// since the last flush. Once exceeded, the shard is flushed without waiting for
+    /// `flush_interval_sec`, which bounds the amount of WAL to replay on restart.
+    ///
+    /// If not set, only `flush_interval_sec` is used.
+    #[serde(alias = "flush_max_dirty_kb")]
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub flush_max_dirty: Option<usize>,
 }
 
 impl OptimizersConfig {
@@ -106,6 +124,8 @@ impl OptimizersConfig {
             flush_interval_sec: 60,
             max_optimization_threads: Some(0),
             prevent_unoptimized: None,
+            max_builder_memory: None,
+            flush_max_dirty: None,
         }
     }
 
@@ -144,6 +164,7 @@ impl OptimizersConfig {
             memmap_threshold_kb,
             indexing_threshold_kb,
             max_segment_size_kb: self.get_max_segment_size_in_kilobytes(num_indexing_threads),
+            max_builder_memory_kb: self.max_builder_memory,
         }
     }
 
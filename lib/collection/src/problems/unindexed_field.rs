@@ -235,6 +235,7 @@ fn infer_index_from_field_condition(field_condition: &FieldCondition) -> Vec<Fie
             Match::Any(match_any) => infer_index_from_any_variants(&match_any.any),
             Match::Except(match_except) => infer_index_from_any_variants(&match_except.except),
             Match::TextAny(_match_text_any) => vec![FieldIndexType::Text],
+            Match::Cidr(_match_cidr) => vec![FieldIndexType::Ip],
         })
     }
     if let Some(range_interface) = range {
@@ -386,6 +387,7 @@ impl<'a> Extractor<'a> {
             Condition::HasId(_) => return,
             Condition::CustomIdChecker(_) => return,
             Condition::HasVector(_) => return,
+            Condition::Attribute(_) => return,
         };
 
         let full_key = JsonPath::extend_or_new(nested_prefix, key);
@@ -542,6 +544,7 @@ enum FieldIndexType {
     UuidRange,
     DatetimeRange,
     Geo,
+    Ip,
 }
 
 fn schema_capabilities(value: &PayloadFieldSchema) -> HashSet<FieldIndexType> {
@@ -562,6 +565,7 @@ fn schema_capabilities(value: &PayloadFieldSchema) -> HashSet<FieldIndexType> {
             PayloadSchemaType::Geo => index_types.insert(FieldIndexType::Geo),
             PayloadSchemaType::Text => index_types.insert(FieldIndexType::Text),
             PayloadSchemaType::Datetime => index_types.insert(FieldIndexType::DatetimeRange),
+            PayloadSchemaType::Ip => index_types.insert(FieldIndexType::Ip),
         },
         PayloadFieldSchema::FieldParams(payload_schema_params) => match payload_schema_params {
             PayloadSchemaParams::Keyword(_) => index_types.insert(FieldIndexType::KeywordMatch),
@@ -595,6 +599,7 @@ fn schema_capabilities(value: &PayloadFieldSchema) -> HashSet<FieldIndexType> {
                 index_types.insert(FieldIndexType::Text)
             }
             PayloadSchemaParams::Datetime(_) => index_types.insert(FieldIndexType::DatetimeRange),
+            PayloadSchemaParams::Ip(_) => index_types.insert(FieldIndexType::Ip),
         },
     };
 
@@ -625,6 +630,7 @@ impl From<FieldIndexType> for PayloadFieldSchema {
                 PayloadFieldSchema::FieldType(PayloadSchemaType::Datetime)
             }
             FieldIndexType::Geo => PayloadFieldSchema::FieldType(PayloadSchemaType::Geo),
+            FieldIndexType::Ip => PayloadFieldSchema::FieldType(PayloadSchemaType::Ip),
         }
     }
 }
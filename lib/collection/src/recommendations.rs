@@ -144,6 +144,53 @@ pub fn avg_vector_for_recommendation<'a>(
     Ok(search_vector)
 }
 
+/// Sum up vectors, multiplied by their weights, e.g. `A - B + C`.
+///
+/// Only dense and sparse vectors are supported, dense vectors must have the same dimensionality.
+pub fn weighted_sum_of_vectors<'a>(
+    vectors: impl IntoIterator<Item = (VectorRef<'a>, f32)>,
+) -> CollectionResult<VectorInternal> {
+    let mut sum: Option<VectorInternal> = None;
+    for (vector, weight) in vectors {
+        sum = Some(match (sum, vector) {
+            (None, VectorRef::Dense(vector)) => {
+                VectorInternal::from(vector.iter().map(|x| x * weight).collect::<DenseVector>())
+            }
+            (None, VectorRef::Sparse(vector)) => VectorInternal::from(
+                vector.combine_aggregate(&SparseVector::default(), |x, _| x * weight),
+            ),
+            (Some(VectorInternal::Dense(mut sum)), VectorRef::Dense(vector)) => {
+                if sum.len() != vector.len() {
+                    return Err(CollectionError::bad_input(format!(
+                        "Can't sum dense vectors of different dimensions: {} and {}",
+                        sum.len(),
+                        vector.len(),
+                    )));
+                }
+                for (item, value) in sum.iter_mut().zip(vector) {
+                    *item += value * weight;
+                }
+                VectorInternal::from(sum)
+            }
+            (Some(VectorInternal::Sparse(sum)), VectorRef::Sparse(vector)) => {
+                VectorInternal::from(sum.combine_aggregate(vector, |x, y| x + y * weight))
+            }
+            (_, VectorRef::MultiDense(_)) | (Some(VectorInternal::MultiDense(_)), _) => {
+                return Err(CollectionError::bad_input(
+                    "Vector arithmetic is not supported for multivectors",
+                ));
+            }
+            (Some(_), _) => {
+                return Err(CollectionError::bad_input(
+                    "Can't sum vectors of different types, all vectors should be either dense or sparse",
+                ));
+            }
+        });
+    }
+
+    sum.ok_or_else(|| CollectionError::bad_input("Vectors to sum should not be empty"))
+}
+
 pub async fn recommend_by<F, Fut>(
     request: RecommendRequestInternal,
     collection: &Collection,
@@ -472,7 +519,7 @@ mod tests {
     use segment::data_types::vectors::{VectorInternal, VectorRef};
     use sparse::common::sparse_vector::SparseVector;
 
-    use super::avg_vectors;
+    use super::{avg_vectors, weighted_sum_of_vectors};
 
     #[test]
     fn test_avg_vectors() {
@@ -509,4 +556,41 @@ mod tests {
         ];
         assert!(avg_vectors(vectors.iter().map(VectorRef::from)).is_err());
     }
+
+    #[test]
+    fn test_weighted_sum_of_vectors() {
+        let vectors: Vec<VectorInternal> = vec![
+            vec![1.0, 2.0, 3.0].into(),
+            vec![1.0, 1.0, 1.0].into(),
+            vec![0.0, 0.0, 2.0].into(),
+        ];
+        let weights = [1.0, -1.0, 0.5];
+        assert_eq!(
+            weighted_sum_of_vectors(vectors.iter().map(VectorRef::from).zip(weights)).unwrap(),
+            vec![0.0, 1.0, 3.0].into(),
+        );
+
+        let vectors: Vec<VectorInternal> = vec![
+            SparseVector::new(vec![0, 2], vec![1.0, 2.0])
+                .unwrap()
+                .into(),
+            SparseVector::new(vec![1, 2], vec![1.0, 1.0])
+                .unwrap()
+                .into(),
+        ];
+        let weights = [1.0, -1.0];
+        assert_eq!(
+            weighted_sum_of_vectors(vectors.iter().map(VectorRef::from).zip(weights)).unwrap(),
+            SparseVector::new(vec![0, 1, 2], vec![1.0, -1.0, 1.0])
+                .unwrap()
+                .into(),
+        );
+
+        let vectors: Vec<VectorInternal> = vec![vec![1.0, 2.0].into(), vec![1.0, 2.0, 3.0].into()];
+        assert!(
+            weighted_sum_of_vectors(vectors.iter().map(VectorRef::from).zip([1.0, 1.0])).is_err()
+        );
+
+        assert!(weighted_sum_of_vectors(std::iter::empty::<(VectorRef, f32)>()).is_err());
+    }
 }
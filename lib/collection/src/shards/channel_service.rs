@@ -14,6 +14,7 @@ use tonic::{Request, Status};
 use url::Url;
 
 use crate::operations::types::{CollectionError, CollectionResult, PeerMetadata};
+use crate::shards::peer_latencies::PeerLatencies;
 use crate::shards::shard::PeerId;
 
 #[derive(Clone)]
@@ -23,6 +24,13 @@ pub struct ChannelService {
     // Shared with consensus_state
     pub id_to_metadata: Arc<parking_lot::RwLock<HashMap<PeerId, PeerMetadata>>>,
     pub channel_pool: Arc<TransportChannelPool>,
+    /// Separate pool for bulk shard transfer traffic, so that it doesn't delay internal searches.
+    pub transfer_channel_pool: Arc<TransportChannelPool>,
+    /// Internal port of peers, dedicated to shard transfer traffic. Same for all peers.
+    /// If not set, transfers use the regular internal port of a peer.
+    pub transfer_port: Option<u16>,
+    /// Port to connect to instead of the port in the peer address.
+    peer_port: Option<u16>,
     /// Port at which the public REST API is exposed for the current peer.
     pub current_rest_port: u16,
     /// Indicates whether the TLS is enabled for the public REST API.
@@ -33,6 +41,9 @@ pub struct ChannelService {
 
     /// Alternative API key, works the same as `api_key`. Intended for rolling key updates.
     pub alt_api_key: Option<String>,
+
+    /// Observed latencies of reads from other peers, used to route reads to fast replicas.
+    pub peer_latencies: Arc<PeerLatencies>,
 }
 
 impl ChannelService {
@@ -47,17 +58,45 @@ impl ChannelService {
             id_to_address: Default::default(),
             id_to_metadata: Default::default(),
             channel_pool: Default::default(),
+            transfer_channel_pool: Default::default(),
+            transfer_port: None,
+            peer_port: None,
             current_rest_port,
             rest_tls_enabled,
             api_key,
             alt_api_key,
+            peer_latencies: Default::default(),
+        }
+    }
+
+    /// Channel service, which sends requests to the transfer channel pool and transfer port of
+    /// peers. Used for bulk shard transfer traffic.
+    pub fn transfer_lane(&self) -> Self {
+        Self {
+            channel_pool: self.transfer_channel_pool.clone(),
+            peer_port: self.transfer_port,
+            ..self.clone()
+        }
+    }
+
+    /// Get the address to send requests to the given peer.
+    pub fn peer_address(&self, peer_id: PeerId) -> Option<Uri> {
+        let address = self.id_to_address.read().get(&peer_id).cloned()?;
+        match self.peer_port {
+            Some(port) => Some(with_port(&address, port)),
+            None => Some(address),
         }
     }
 
     pub async fn remove_peer(&self, peer_id: PeerId) {
         let removed = self.id_to_address.write().remove(&peer_id);
         if let Some(uri) = removed {
+            let transfer_uri = match self.transfer_port {
+                Some(port) => with_port(&uri, port),
+                None => uri.clone(),
+            };
             self.channel_pool.drop_pool(&uri).await;
+            self.transfer_channel_pool.drop_pool(&transfer_uri).await;
         }
     }
 
@@ -156,11 +195,8 @@ impl ChannelService {
         f: impl Fn(QdrantInternalClient<InterceptedService<Channel, AddTimeout>>) -> O,
     ) -> CollectionResult<T> {
         let address = self
-            .id_to_address
-            .read()
-            .get(&peer_id)
-            .ok_or_else(|| CollectionError::service_error("Address for peer ID is not found."))?
-            .clone();
+            .peer_address(peer_id)
+            .ok_or_else(|| CollectionError::service_error("Address for peer ID is not found."))?;
         self.channel_pool
             .with_channel(&address, |channel| {
                 let client = QdrantInternalClient::new(channel);
@@ -254,6 +290,14 @@ impl ChannelService {
     }
 }
 
+/// Replace the port of the URI.
+fn with_port(uri: &Uri, port: u16) -> Uri {
+    let mut parts = uri.clone().into_parts();
+    let host = uri.host().unwrap_or_default();
+    parts.authority = format!("{host}:{port}").parse().ok();
+    Uri::from_parts(parts).unwrap_or_else(|_| uri.clone())
+}
+
 #[cfg(test)]
 impl Default for ChannelService {
     fn default() -> Self {
@@ -261,10 +305,14 @@ impl Default for ChannelService {
             id_to_address: Default::default(),
             id_to_metadata: Default::default(),
             channel_pool: Default::default(),
+            transfer_channel_pool: Default::default(),
+            transfer_port: None,
+            peer_port: None,
             current_rest_port: 6333,
             rest_tls_enabled: false,
             api_key: None,
             alt_api_key: None,
+            peer_latencies: Default::default(),
         }
     }
 }
@@ -153,6 +153,7 @@ pub fn internal_delete_points(
                 points_selector_one_of: Some(PointsSelectorOneOf::Points(PointsIdsList {
                     ids: ids.into_iter().map(|id| id.into()).collect(),
                 })),
+                filter_template: None,
             }),
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
@@ -178,6 +179,7 @@ pub fn internal_delete_points_by_filter(
             wait: Some(wait),
             points: Some(PointsSelector {
                 points_selector_one_of: Some(PointsSelectorOneOf::Filter(filter.into())),
+                filter_template: None,
             }),
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
@@ -245,6 +247,7 @@ pub fn internal_delete_vectors(
                 points_selector_one_of: Some(PointsSelectorOneOf::Points(PointsIdsList {
                     ids: ids.into_iter().map(|id| id.into()).collect(),
                 })),
+                filter_template: None,
             }),
             vectors: Some(VectorsSelector {
                 names: vector_names,
@@ -275,6 +278,7 @@ pub fn internal_delete_vectors_by_filter(
             wait: Some(wait),
             points_selector: Some(PointsSelector {
                 points_selector_one_of: Some(PointsSelectorOneOf::Filter(filter.into())),
+                filter_template: None,
             }),
             vectors: Some(VectorsSelector {
                 names: vector_names,
@@ -300,10 +304,12 @@ pub fn internal_set_payload(
             points_selector_one_of: Some(PointsSelectorOneOf::Points(PointsIdsList {
                 ids: points.into_iter().map(|id| id.into()).collect(),
             })),
+            filter_template: None,
         })
     } else {
         set_payload.filter.map(|filter| PointsSelector {
             points_selector_one_of: Some(PointsSelectorOneOf::Filter(filter.into())),
+            filter_template: None,
         })
     };
 
@@ -337,10 +343,12 @@ pub fn internal_delete_payload(
             points_selector_one_of: Some(PointsSelectorOneOf::Points(PointsIdsList {
                 ids: points.into_iter().map(|id| id.into()).collect(),
             })),
+            filter_template: None,
         })
     } else {
         delete_payload.filter.map(|filter| PointsSelector {
             points_selector_one_of: Some(PointsSelectorOneOf::Filter(filter.into())),
+            filter_template: None,
         })
     };
 
@@ -382,6 +390,7 @@ pub fn internal_clear_payload(
                 points_selector_one_of: Some(PointsSelectorOneOf::Points(PointsIdsList {
                     ids: points.into_iter().map(|id| id.into()).collect(),
                 })),
+                filter_template: None,
             }),
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
@@ -407,6 +416,7 @@ pub fn internal_clear_payload_by_filter(
             wait: Some(wait),
             points: Some(PointsSelector {
                 points_selector_one_of: Some(PointsSelectorOneOf::Filter(filter.into())),
+                filter_template: None,
             }),
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
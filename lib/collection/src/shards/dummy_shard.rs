@@ -3,7 +3,9 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
 use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::query_context::SearchExplanations;
 use segment::index::field_index::CardinalityEstimation;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, SizeStats, WithPayload, WithPayloadInterface, WithVector,
@@ -16,6 +18,7 @@ use shard::search::CoreSearchRequestBatch;
 use shard::snapshots::snapshot_manifest::SnapshotManifest;
 use tokio::runtime::Handle;
 
+use crate::collection::partial_results::TimedOut;
 use crate::operations::OperationWithClockTag;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CountResult, OptimizersStatus,
@@ -52,6 +55,7 @@ impl DummyShard {
             variant_name: Some("dummy shard".into()),
             status: Some(ShardStatus::Green),
             total_optimized_points: 0,
+            read_requests: 0,
             vectors_size_bytes: None,
             payloads_size_bytes: None,
             num_points: None,
@@ -108,6 +112,7 @@ impl ShardOperation for DummyShard {
             CollectionUpdateOperations::FieldIndexOperation(_) => Ok(UpdateResult {
                 operation_id: None,
                 status: UpdateStatus::Acknowledged,
+                affected_points: None,
                 clock_tag: None,
             }),
             // Allow (and ignore) staging operations on dummy shards
@@ -115,6 +120,7 @@ impl ShardOperation for DummyShard {
             CollectionUpdateOperations::StagingOperation(_) => Ok(UpdateResult {
                 operation_id: None,
                 status: UpdateStatus::Acknowledged,
+                affected_points: None,
                 clock_tag: None,
             }),
         }
@@ -186,6 +192,8 @@ impl ShardOperation for DummyShard {
         _requests: Arc<Vec<ShardQueryRequest>>,
         _search_runtime_handle: &Handle,
         _timeout: Option<Duration>,
+        _explanations: Option<&SearchExplanations>,
+        _timed_out: Option<&TimedOut>,
         _: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         self.dummy()
@@ -201,5 +209,15 @@ impl ShardOperation for DummyShard {
         self.dummy()
     }
 
+    async fn aggregate(
+        &self,
+        _: Arc<AggregateParams>,
+        _search_runtime_handle: &Handle,
+        _: Option<Duration>,
+        _: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult> {
+        self.dummy()
+    }
+
     async fn stop_gracefully(self) {}
 }
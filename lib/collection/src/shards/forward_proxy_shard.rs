@@ -8,7 +8,9 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::tar_ext;
 use common::types::TelemetryDetail;
 use parking_lot::Mutex as ParkingMutex;
-use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
+use segment::data_types::facets::{FacetParams, FacetResponse, MultiFacetParams};
+use segment::data_types::query_context::SearchExplanations;
 use segment::index::field_index::CardinalityEstimation;
 use segment::types::{
     ExtendedPointId, Filter, PointIdType, ScoredPoint, SizeStats, SnapshotFormat, WithPayload,
@@ -24,6 +26,7 @@ use tokio::sync::Mutex;
 
 use super::shard::ShardId;
 use super::update_tracker::UpdateTracker;
+use crate::collection::partial_results::TimedOut;
 use crate::collection_manager::optimizers::TrackerLog;
 use crate::hash_ring::HashRingRouter;
 use crate::operations::point_ops::{
@@ -328,6 +331,7 @@ impl ForwardProxyShard {
             ids,
             with_payload: Some(WithPayloadInterface::Bool(true)),
             with_vector: WithVector::Bool(true),
+            with_version: None,
         };
         let batch = self
             .wrapped_shard
@@ -657,11 +661,20 @@ impl ShardOperation for ForwardProxyShard {
         requests: Arc<Vec<ShardQueryRequest>>,
         search_runtime_handle: &Handle,
         timeout: Option<Duration>,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let local_shard = &self.wrapped_shard;
         local_shard
-            .query_batch(requests, search_runtime_handle, timeout, hw_measurement_acc)
+            .query_batch(
+                requests,
+                search_runtime_handle,
+                timeout,
+                explanations,
+                timed_out,
+                hw_measurement_acc,
+            )
             .await
     }
 
@@ -678,6 +691,32 @@ impl ShardOperation for ForwardProxyShard {
             .await
     }
 
+    async fn facets(
+        &self,
+        request: Arc<MultiFacetParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<FacetResponse>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .facets(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .await
+    }
+
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .aggregate(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .await
+    }
+
     async fn stop_gracefully(self) {
         self.wrapped_shard.stop_gracefully().await
     }
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::counter::hardware_counter::HardwareCounterCell;
+use futures::future::try_join_all;
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
+use shard::common::stopping_guard::StoppingGuard;
+use tokio::runtime::Handle;
+use tokio::time::error::Elapsed;
+use tokio_util::task::AbortOnDropHandle;
+
+use super::LocalShard;
+use crate::collection_manager::holders::segment_holder::LockedSegment;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+impl LocalShard {
+    /// Aggregates values of the key over the filtered points of all segments.
+    pub async fn aggregate_segments(
+        &self,
+        request: Arc<AggregateParams>,
+        search_runtime_handle: &Handle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult> {
+        let stopping_guard = StoppingGuard::new();
+
+        let spawn_read = |segment: LockedSegment, hw_counter: &HardwareCounterCell| {
+            let request = Arc::clone(&request);
+            let is_stopped = stopping_guard.get_is_stopped();
+
+            let hw_counter = hw_counter.fork();
+            let task = search_runtime_handle.spawn_blocking(move || {
+                let get_segment = segment.get();
+                let read_segment = get_segment.read();
+
+                read_segment.aggregate(&request, &is_stopped, &hw_counter)
+            });
+            AbortOnDropHandle::new(task)
+        };
+
+        let all_reads = {
+            let segments_lock = self.segments.read();
+
+            let hw_counter = hw_measurement_acc.get_counter_cell();
+
+            tokio::time::timeout(
+                timeout,
+                try_join_all(
+                    segments_lock
+                        .non_appendable_then_appendable_segments()
+                        .map(|segment| spawn_read(segment, &hw_counter)),
+                ),
+            )
+        }
+        .await
+        .map_err(|_: Elapsed| CollectionError::timeout(timeout, "aggregate"))??;
+
+        let mut result = AggregateResult::default();
+        for read in all_reads {
+            result.merge(read?);
+        }
+
+        Ok(result)
+    }
+}
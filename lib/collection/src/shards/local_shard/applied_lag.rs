@@ -0,0 +1,94 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use segment::types::SeqNumberType;
+
+use super::LocalShard;
+
+/// Time, by which segments of a shard are behind the updates written into its WAL.
+///
+/// Replicas receive each update before the leader acknowledges it, so the time the oldest
+/// received, but not yet applied, update is waiting in the update queue is the staleness of reads
+/// from the replica.
+#[derive(Debug, Default)]
+pub struct AppliedLag {
+    /// Operations, which may not be applied yet, with the time they were received, ordered by
+    /// `op_num`
+    pending: Mutex<VecDeque<(SeqNumberType, Instant)>>,
+}
+
+impl AppliedLag {
+    /// Track operation `op_num`, received at `now`.
+    ///
+    /// Operations up to `applied_op_num` are applied, and are not tracked anymore.
+    pub fn received(&self, op_num: SeqNumberType, applied_op_num: SeqNumberType, now: Instant) {
+        let mut pending = self.pending.lock();
+        Self::forget_applied(&mut pending, applied_op_num);
+        pending.push_back((op_num, now));
+    }
+
+    /// Time the oldest operation after `applied_op_num` is waiting to be applied at `now`
+    pub fn lag(&self, applied_op_num: SeqNumberType, now: Instant) -> Duration {
+        let mut pending = self.pending.lock();
+        Self::forget_applied(&mut pending, applied_op_num);
+        pending.front().map_or(Duration::ZERO, |&(_, received_at)| {
+            now.saturating_duration_since(received_at)
+        })
+    }
+
+    fn forget_applied(
+        pending: &mut VecDeque<(SeqNumberType, Instant)>,
+        applied_op_num: SeqNumberType,
+    ) {
+        while pending
+            .front()
+            .is_some_and(|&(op_num, _)| op_num <= applied_op_num)
+        {
+            pending.pop_front();
+        }
+    }
+}
+
+impl LocalShard {
+    /// Track the WAL operation `op_num`, which is sent to the update worker
+    pub(super) fn track_received(&self, op_num: SeqNumberType) {
+        let applied_op_num = self.applied_seq_handler.op_num().unwrap_or_default();
+        self.applied_lag
+            .received(op_num, applied_op_num, Instant::now());
+    }
+
+    /// Time the oldest update, received by the shard, is waiting to be applied.
+    ///
+    /// Returns `None` if applied updates are not tracked for the shard.
+    pub fn applied_lag(&self) -> Option<Duration> {
+        let applied_op_num = self.applied_seq_handler.op_num()?;
+        Some(self.applied_lag.lag(applied_op_num, Instant::now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_applied_lag() {
+        let start = Instant::now();
+        let lag = AppliedLag::default();
+        assert_eq!(lag.lag(0, start), Duration::ZERO);
+
+        lag.received(1, 0, start);
+        lag.received(2, 0, start + Duration::from_millis(10));
+        lag.received(3, 0, start + Duration::from_millis(20));
+
+        let now = start + Duration::from_millis(50);
+        assert_eq!(lag.lag(0, now), Duration::from_millis(50));
+        // Lag is measured from the oldest operation, which is not applied yet
+        assert_eq!(lag.lag(1, now), Duration::from_millis(40));
+        assert_eq!(lag.lag(3, now), Duration::ZERO);
+
+        // Applied operations are forgotten when new ones are received
+        lag.received(4, 3, now);
+        assert_eq!(lag.pending.lock().len(), 1);
+    }
+}
@@ -297,6 +297,30 @@ impl RecoveryPoint {
         })
     }
 
+    /// Check whether this recovery point has seen all operations seen by the `other`: it has every
+    /// clock of the `other`, and none of them is older.
+    pub fn covers(&self, other: &Self) -> bool {
+        other.clocks.iter().all(|(key, &(other_tick, _token))| {
+            self.clocks
+                .get(key)
+                .is_some_and(|&(tick, _token)| tick >= other_tick)
+        })
+    }
+
+    /// Extend this recovery point with clocks of the `other`, keeping the newest tick of each
+    /// clock.
+    ///
+    /// The result covers both recovery points.
+    pub fn join(&mut self, other: &Self) {
+        for (&key, &(other_tick, other_token)) in &other.clocks {
+            let (tick, token) = self.clocks.entry(key).or_insert((other_tick, other_token));
+            if *tick < other_tick {
+                *tick = other_tick;
+                *token = other_token;
+            }
+        }
+    }
+
     /// Extend this recovery point with clocks that are only present in the `other`.
     ///
     /// Clocks that are not present in this recovery point are initialized to the tick 1,
@@ -526,6 +550,35 @@ mod test {
         assert_eq!(input, output);
     }
 
+    #[test]
+    fn recovery_point_join_covers() {
+        let mut a = RecoveryPoint::default();
+        a.insert(1, 0, 5);
+        a.insert(2, 0, 3);
+
+        let mut b = RecoveryPoint::default();
+        b.insert(1, 0, 4);
+        b.insert(3, 0, 7);
+
+        // Neither is ahead of the other
+        assert!(!a.covers(&b));
+        assert!(!b.covers(&a));
+
+        let mut joined = a.clone();
+        joined.join(&b);
+
+        assert!(joined.covers(&a));
+        assert!(joined.covers(&b));
+        assert!(!a.covers(&joined));
+        assert_eq!(joined.clocks[&Key::new(1, 0)].0, 5);
+        assert_eq!(joined.clocks[&Key::new(2, 0)].0, 3);
+        assert_eq!(joined.clocks[&Key::new(3, 0)].0, 7);
+
+        // Everything covers an empty recovery point
+        assert!(RecoveryPoint::default().covers(&RecoveryPoint::default()));
+        assert!(a.covers(&RecoveryPoint::default()));
+    }
+
     #[test]
     fn clock_map_accept_last_operation_multiple_times() {
         let mut helper = Helper::empty();
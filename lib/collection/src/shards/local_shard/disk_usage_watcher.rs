@@ -1,5 +1,7 @@
 use std::path::PathBuf;
+use std::time::Duration;
 
+use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 use tokio::time::Instant;
 use tokio_util::task::AbortOnDropHandle;
@@ -21,28 +23,65 @@ const FREE_SPACE_TO_CHECK_FREQUENCY_HEURISTIC_MB: &[(usize, usize); 5] =
 /// because some external process could have consumed the disk space
 const MIN_DISK_CHECK_INTERVAL_MILLIS: usize = 2000;
 
+/// How often paused optimizations check if disk space was freed
+pub const FLOOD_RECHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+const BYTES_IN_MB: usize = 1024 * 1024;
+
+/// Free disk space thresholds of shard storage paths
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiskWatermarksConfig {
+    /// If free disk space drops below this number of megabytes, the shard rejects new updates.
+    /// If not set, double of the WAL capacity is used.
+    #[serde(default)]
+    pub high_free_mb: Option<usize>,
+    /// If free disk space drops below this number of megabytes, the shard also pauses
+    /// optimizations. Should be lower than the high watermark.
+    /// If not set, optimizations are not paused.
+    #[serde(default)]
+    pub flood_free_mb: Option<usize>,
+}
+
+/// Disk usage of a shard storage path relative to the watermarks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum DiskUsageLevel {
+    #[default]
+    Normal,
+    /// Above the high watermark, updates are rejected
+    High,
+    /// Above the flood watermark, optimizations are paused as well
+    Flood,
+}
+
 #[derive(Default)]
 struct LastCheck {
     last_check_time: Option<Instant>,
     next_check_count: usize,
+    level: DiskUsageLevel,
 }
 
 pub struct DiskUsageWatcher {
     disk_path: PathBuf,
     disabled: bool,
-    min_free_disk_size_mb: usize,
+    high_free_disk_size_mb: usize,
+    flood_free_disk_size_mb: Option<usize>,
     last_check: Mutex<LastCheck>,
 }
 
 impl DiskUsageWatcher {
-    pub async fn new(disk_path: PathBuf, min_free_disk_size_mb: usize) -> Self {
+    pub async fn new(
+        disk_path: PathBuf,
+        high_free_disk_size_mb: usize,
+        flood_free_disk_size_mb: Option<usize>,
+    ) -> Self {
         let mut watcher = Self {
             disk_path,
             disabled: false,
-            min_free_disk_size_mb,
+            high_free_disk_size_mb,
+            flood_free_disk_size_mb,
             last_check: Default::default(),
         };
-        match watcher.is_disk_full().await {
+        match watcher.get_free_space_bytes().await {
             Ok(Some(_)) => {} // do nothing
             Ok(None) => watcher.disabled = true,
             Err(_) => {
@@ -51,9 +90,13 @@ impl DiskUsageWatcher {
         };
         watcher
     }
-    /// Returns true if the disk free space is less than the `disk_buffer_threshold_mb`
-    /// As the side effect, it updates the disk usage every `update_count_threshold` calls
-    pub async fn is_disk_full(&self) -> CollectionResult<Option<bool>> {
+
+    /// Returns the disk usage level of the storage path, `None` if it can't be determined
+    ///
+    /// As the side effect, it updates the disk usage every `update_count_threshold` calls,
+    /// otherwise the level of the last check is returned. Level goes back to normal as soon as
+    /// a check finds enough free space.
+    pub async fn disk_usage_level(&self) -> CollectionResult<Option<DiskUsageLevel>> {
         if self.disabled {
             return Ok(None);
         }
@@ -71,19 +114,35 @@ impl DiskUsageWatcher {
 
             last_check_guard.last_check_time = Some(Instant::now());
 
-            let is_full = match free_space {
+            let level = match free_space {
                 Some(free_space) => {
                     let free_space = free_space as usize;
                     let mut next_check = DEFAULT_FREQUENCY;
                     for (threshold_mb, interval) in FREE_SPACE_TO_CHECK_FREQUENCY_HEURISTIC_MB {
-                        if free_space < (*threshold_mb * 1024 * 1024) {
+                        if free_space < (*threshold_mb * BYTES_IN_MB) {
                             next_check = *interval;
                             break;
                         }
                     }
-                    last_check_guard.next_check_count = next_check;
 
-                    Some(free_space < self.min_free_disk_size_mb * 1024 * 1024)
+                    let level = self.level_for_free_space(free_space);
+                    if level != last_check_guard.level {
+                        log::warn!(
+                            "Disk usage level of {} changed from {:?} to {level:?}, {} MB free",
+                            self.disk_path.display(),
+                            last_check_guard.level,
+                            free_space / BYTES_IN_MB,
+                        );
+                    }
+
+                    // Check every time while above watermark, to recover as soon as possible
+                    last_check_guard.next_check_count = match level {
+                        DiskUsageLevel::Normal => next_check,
+                        DiskUsageLevel::High | DiskUsageLevel::Flood => 0,
+                    };
+                    last_check_guard.level = level;
+
+                    Some(level)
                 }
                 None => {
                     last_check_guard.next_check_count = 0;
@@ -91,10 +150,51 @@ impl DiskUsageWatcher {
                 }
             };
 
-            Ok(is_full)
+            Ok(level)
         } else {
             last_check_guard.next_check_count = last_check_guard.next_check_count.saturating_sub(1);
-            Ok(None)
+            Ok(Some(last_check_guard.level))
+        }
+    }
+
+    /// Fails with an insufficient disk space error, if the disk usage is above the high watermark
+    pub async fn check_updates_allowed(&self) -> CollectionResult<()> {
+        match self.disk_usage_level().await? {
+            None | Some(DiskUsageLevel::Normal) => Ok(()),
+            Some(DiskUsageLevel::High | DiskUsageLevel::Flood) => {
+                Err(CollectionError::insufficient_disk_space(format!(
+                    "No space left on device: free disk space of {} is below {} MB, updates are rejected until space is freed",
+                    self.disk_path.display(),
+                    self.high_free_disk_size_mb,
+                )))
+            }
+        }
+    }
+
+    /// Returns true if the disk usage is above the flood watermark and optimizations should pause
+    pub async fn is_flooded(&self) -> bool {
+        if self.flood_free_disk_size_mb.is_none() {
+            return false;
+        }
+
+        match self.disk_usage_level().await {
+            Ok(level) => level == Some(DiskUsageLevel::Flood),
+            Err(err) => {
+                log::debug!("Failed to check disk usage: {err}");
+                false
+            }
+        }
+    }
+
+    fn level_for_free_space(&self, free_space: usize) -> DiskUsageLevel {
+        let below = |threshold_mb: usize| free_space < threshold_mb.saturating_mul(BYTES_IN_MB);
+
+        if self.flood_free_disk_size_mb.is_some_and(below) {
+            DiskUsageLevel::Flood
+        } else if below(self.high_free_disk_size_mb) {
+            DiskUsageLevel::High
+        } else {
+            DiskUsageLevel::Normal
         }
     }
 
@@ -124,3 +224,46 @@ impl DiskUsageWatcher {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_watcher(
+        high_free_disk_size_mb: usize,
+        flood_free_disk_size_mb: Option<usize>,
+    ) -> DiskUsageWatcher {
+        DiskUsageWatcher {
+            disk_path: PathBuf::from("."),
+            disabled: true,
+            high_free_disk_size_mb,
+            flood_free_disk_size_mb,
+            last_check: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_disk_usage_levels() {
+        let watcher = new_watcher(100, Some(10));
+        assert_eq!(
+            watcher.level_for_free_space(200 * BYTES_IN_MB),
+            DiskUsageLevel::Normal
+        );
+        assert_eq!(
+            watcher.level_for_free_space(100 * BYTES_IN_MB),
+            DiskUsageLevel::Normal
+        );
+        assert_eq!(
+            watcher.level_for_free_space(50 * BYTES_IN_MB),
+            DiskUsageLevel::High
+        );
+        assert_eq!(
+            watcher.level_for_free_space(5 * BYTES_IN_MB),
+            DiskUsageLevel::Flood
+        );
+
+        // Without flood watermark optimizations are never paused
+        let watcher = new_watcher(100, None);
+        assert_eq!(watcher.level_for_free_space(0), DiskUsageLevel::High);
+    }
+}
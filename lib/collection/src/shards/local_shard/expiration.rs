@@ -0,0 +1,192 @@
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+
+use chrono::{DateTime, Utc};
+use common::counter::hardware_counter::HardwareCounterCell;
+use segment::segment::expiration::{
+    EXPIRES_AT_KEY, earliest_expiration, expired_condition, expires_at_key, parse_expires_at,
+};
+use segment::types::{Condition, Payload, PayloadSchemaType};
+use shard::operations::CollectionUpdateOperations;
+use shard::operations::payload_ops::PayloadOps;
+use shard::operations::point_ops::{PointInsertOperationsInternal, PointOperations};
+use shard::segment_holder::locked::LockedSegmentHolder;
+
+use super::LocalShard;
+
+/// Minimal interval between recomputations of the expiration watermark
+const WATERMARK_REFRESH_INTERVAL_SEC: i64 = 10;
+
+/// Lower bound of expiration times of points in a shard.
+///
+/// Lets reads skip the expiration filter, and the background cleanup skip the shard, until the
+/// earliest point expires. The bound is lowered by every update setting an expiration time, and
+/// recomputed from payload indexes of segments once it is reached.
+#[derive(Debug)]
+pub struct ExpirationWatermark {
+    /// Unix timestamp in seconds, `i64::MIN` if unknown
+    earliest: AtomicI64,
+    /// Lowest expiration time set by updates since the start of the last recomputation
+    lowered: AtomicI64,
+    /// Time of the last recomputation, unix timestamp in seconds
+    refreshed_at: AtomicI64,
+}
+
+impl Default for ExpirationWatermark {
+    fn default() -> Self {
+        Self {
+            earliest: AtomicI64::new(i64::MIN),
+            lowered: AtomicI64::new(i64::MAX),
+            refreshed_at: AtomicI64::new(i64::MIN),
+        }
+    }
+}
+
+impl ExpirationWatermark {
+    fn lower(&self, expires_at: DateTime<Utc>) {
+        let timestamp = expires_at.timestamp();
+        self.lowered.fetch_min(timestamp, Ordering::SeqCst);
+        self.earliest.fetch_min(timestamp, Ordering::SeqCst);
+    }
+
+    /// Lower the watermark by expiration times set by the update `operation`
+    pub fn observe(
+        &self,
+        operation: &CollectionUpdateOperations,
+        schema: Option<PayloadSchemaType>,
+    ) {
+        let observe_payload = |payload: Option<&Payload>| {
+            let expires_at = payload
+                .and_then(|payload| payload.0.get(EXPIRES_AT_KEY))
+                .and_then(|value| parse_expires_at(schema, value));
+            if let Some(expires_at) = expires_at {
+                self.lower(expires_at);
+            }
+        };
+
+        let points_op = match operation {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(op)) => op,
+            CollectionUpdateOperations::PointOperation(
+                PointOperations::UpsertPointsConditional(op),
+            ) => &op.points_op,
+            CollectionUpdateOperations::PointOperation(PointOperations::SyncPoints(op)) => {
+                op.points
+                    .iter()
+                    .for_each(|point| observe_payload(point.payload.as_ref()));
+                return;
+            }
+            // Payload set at a nested key can't be a valid expiration time
+            CollectionUpdateOperations::PayloadOperation(
+                PayloadOps::SetPayload(op)
+                | PayloadOps::OverwritePayload(op)
+                | PayloadOps::PatchPayload(op),
+            ) if op.key.is_none() => {
+                observe_payload(Some(&op.payload));
+                return;
+            }
+            _ => return,
+        };
+
+        match points_op {
+            PointInsertOperationsInternal::PointsBatch(batch) => batch
+                .payloads
+                .iter()
+                .flatten()
+                .for_each(|payload| observe_payload(payload.as_ref())),
+            PointInsertOperationsInternal::PointsList(points) => points
+                .iter()
+                .for_each(|point| observe_payload(point.payload.as_ref())),
+        }
+    }
+
+    /// Whether the shard may contain points expired at `now`.
+    ///
+    /// Recomputes the watermark once it is reached, unless it was recomputed recently.
+    pub fn may_have_expired(&self, segments: &LockedSegmentHolder, now: DateTime<Utc>) -> bool {
+        let now = now.timestamp();
+        if self.earliest.load(Ordering::SeqCst) > now {
+            return false;
+        }
+
+        let refreshed_at = self.refreshed_at.load(Ordering::SeqCst);
+        let is_refresh_due = refreshed_at.saturating_add(WATERMARK_REFRESH_INTERVAL_SEC) <= now;
+        // Only one reader recomputes the watermark, others assume there are expired points
+        if is_refresh_due
+            && self
+                .refreshed_at
+                .compare_exchange(refreshed_at, now, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+        {
+            self.refresh(segments);
+        }
+
+        self.earliest.load(Ordering::SeqCst) <= now
+    }
+
+    /// Force recomputation of the watermark on the next check, e.g. after expired points are deleted
+    pub fn invalidate(&self) {
+        self.refreshed_at.store(i64::MIN, Ordering::SeqCst);
+    }
+
+    fn refresh(&self, segments: &LockedSegmentHolder) {
+        self.lowered.store(i64::MAX, Ordering::SeqCst);
+
+        let is_stopped = AtomicBool::new(false);
+        let hw_counter = HardwareCounterCell::disposable();
+
+        let mut earliest = i64::MAX;
+        for (_, segment) in segments.read().iter() {
+            let segment_earliest =
+                earliest_expiration(&*segment.get().read(), &is_stopped, &hw_counter);
+            earliest = match segment_earliest {
+                Ok(None) => earliest,
+                Ok(Some(expires_at)) => earliest.min(expires_at.timestamp()),
+                // Expiration times can't be looked up without an index, always filter
+                Err(_) => i64::MIN,
+            };
+        }
+
+        // Updates, applied concurrently with the recomputation, might be missed in segments
+        self.earliest.store(earliest, Ordering::SeqCst);
+        self.earliest
+            .fetch_min(self.lowered.load(Ordering::SeqCst), Ordering::SeqCst);
+    }
+}
+
+impl LocalShard {
+    /// Type of the payload index of the `expires_at` field
+    pub(super) fn expires_at_schema(&self) -> Option<PayloadSchemaType> {
+        self.payload_index_schema
+            .read()
+            .schema
+            .get(&expires_at_key())
+            .map(|schema| schema.kind())
+    }
+
+    /// Condition matching points, which are expired at `now`.
+    ///
+    /// Returns `None` if points expiration is disabled, or if the shard has no expired points.
+    pub async fn expired_condition(&self, now: DateTime<Utc>) -> Option<Condition> {
+        let points_expiration = self
+            .collection_config
+            .read()
+            .await
+            .params
+            .points_expiration
+            .unwrap_or_default();
+
+        if !points_expiration
+            || !self
+                .expiration_watermark
+                .may_have_expired(&self.segments, now)
+        {
+            return None;
+        }
+
+        Some(expired_condition(self.expires_at_schema(), now))
+    }
+
+    /// Recompute the expiration watermark on the next read, e.g. after expired points are deleted
+    pub fn invalidate_expiration_watermark(&self) {
+        self.expiration_watermark.invalidate();
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -7,8 +7,8 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use futures::future;
 use futures::future::try_join_all;
 use itertools::{Itertools, process_results};
-use segment::data_types::facets::{FacetParams, FacetValue, FacetValueHit};
-use segment::types::{Condition, FieldCondition, Filter, Match};
+use segment::data_types::facets::{FacetParams, FacetValue, FacetValueHit, MultiFacetParams};
+use segment::types::{Condition, Filter};
 use shard::common::stopping_guard::StoppingGuard;
 use tokio::runtime::Handle;
 use tokio::time::error::Elapsed;
@@ -86,6 +86,70 @@ impl LocalShard {
         Ok(top_hits)
     }
 
+    /// Returns values with approximate counts for each key of the facet request.
+    ///
+    /// Each segment collects the counts of all keys in a single pass over the filtered points.
+    pub async fn approx_facets(
+        &self,
+        request: Arc<MultiFacetParams>,
+        search_runtime_handle: &Handle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<Vec<FacetValueHit>>> {
+        let stopping_guard = StoppingGuard::new();
+
+        let spawn_read = |segment: LockedSegment, hw_counter: &HardwareCounterCell| {
+            let request = Arc::clone(&request);
+            let is_stopped = stopping_guard.get_is_stopped();
+
+            let hw_counter = hw_counter.fork();
+            let task = search_runtime_handle.spawn_blocking(move || {
+                let get_segment = segment.get();
+                let read_segment = get_segment.read();
+
+                read_segment.facets(&request, &is_stopped, &hw_counter)
+            });
+            AbortOnDropHandle::new(task)
+        };
+
+        let all_reads = {
+            let segments_lock = self.segments.read();
+
+            let hw_counter = hw_measurement_acc.get_counter_cell();
+
+            tokio::time::timeout(
+                timeout,
+                try_join_all(
+                    segments_lock
+                        .non_appendable_then_appendable_segments()
+                        .map(|segment| spawn_read(segment, &hw_counter)),
+                ),
+            )
+        }
+        .await
+        .map_err(|_: Elapsed| CollectionError::timeout(timeout, "facet"))??;
+
+        let mut merged_hits = vec![HashMap::<FacetValue, usize>::new(); request.keys.len()];
+        for read in all_reads {
+            for (merged, map) in merged_hits.iter_mut().zip(read?) {
+                map.into_iter()
+                    .for_each(|(value, count)| *merged.entry(value).or_insert(0) += count);
+            }
+        }
+
+        // All values are needed to aggregate correctly across segments, same as in `approx_facet`
+        let top_hits = merged_hits
+            .into_iter()
+            .map(|map| {
+                map.into_iter()
+                    .map(|(value, count)| FacetValueHit { value, count })
+                    .collect_vec()
+            })
+            .collect();
+
+        Ok(top_hits)
+    }
+
     /// Returns values with exact counts for a given facet request.
     pub async fn exact_facet(
         &self,
@@ -101,21 +165,32 @@ impl LocalShard {
         let instant = std::time::Instant::now();
 
         // Get unique values for the field
-        let unique_values = self
-            .unique_values(
+        let unique_values = if request.histogram.is_some() {
+            // Histogram buckets are not stored values, collect buckets from approximate counts
+            self.approx_facet(
                 Arc::clone(&request),
                 search_runtime_handle,
                 timeout,
                 hw_measurement_acc.clone(),
             )
-            .await?;
+            .await?
+            .into_iter()
+            .map(|hit| hit.value)
+            .collect()
+        } else {
+            self.unique_values(
+                Arc::clone(&request),
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?
+        };
 
         // Make an exact count for each value
         let hits_futures = unique_values.into_iter().map(|value| {
-            let match_value = Filter::new_must(Condition::Field(FieldCondition::new_match(
-                request.key.clone(),
-                Match::new_value(From::from(value.clone())),
-            )));
+            let match_value =
+                Filter::new_must(Condition::Field(request.value_condition(value.clone())));
 
             let filter = Filter::merge_opts(request.filter.clone(), Some(match_value));
 
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use chrono::{DateTime, Utc};
+use common::counter::hardware_counter::HardwareCounterCell;
+use segment::entry::entry_point::NonAppendableSegmentEntry as _;
+use segment::types::{Condition, Filter};
+
+use super::LocalShard;
+use crate::collection::trash::{trashed_at_key, trashed_condition};
+
+impl LocalShard {
+    /// Condition matching points in the trash.
+    ///
+    /// Returns `None` if the trash was never enabled for the collection, so it can't have points.
+    pub fn trashed_condition(&self) -> Option<Condition> {
+        self.payload_index_schema
+            .read()
+            .schema
+            .contains_key(&trashed_at_key())
+            .then(trashed_condition)
+    }
+
+    /// Condition matching points, which must not be visible to reads at `now`: expired points and
+    /// points in the trash.
+    ///
+    /// Returns `None` if the shard has no such points.
+    pub async fn hidden_condition(&self, now: DateTime<Utc>) -> Option<Condition> {
+        match (self.expired_condition(now).await, self.trashed_condition()) {
+            (None, None) => None,
+            (Some(condition), None) | (None, Some(condition)) => Some(condition),
+            (Some(expired), Some(trashed)) => Some(Condition::Filter(Filter {
+                should: Some(vec![expired, trashed]),
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Whether any point of the shard matches `condition`.
+    ///
+    /// Stops at the first matching point, cheap for conditions on indexed fields.
+    pub fn has_points_matching(&self, condition: Condition) -> bool {
+        let filter = Filter::new_must(condition);
+        let is_stopped = AtomicBool::new(false);
+        let hw_counter = HardwareCounterCell::disposable();
+
+        self.segments.read().iter().any(|(_, segment)| {
+            !segment
+                .get()
+                .read()
+                .read_filtered(None, Some(1), Some(&filter), &is_stopped, &hw_counter)
+                .is_empty()
+        })
+    }
+
+    /// Exclude hidden points from a read `request`, by extending its filters with `exclude`.
+    ///
+    /// The request is only cloned if the shard may contain hidden points.
+    pub(super) async fn exclude_hidden<T: Clone>(
+        &self,
+        request: Arc<T>,
+        exclude: impl FnOnce(&mut T, &Condition),
+    ) -> Arc<T> {
+        let Some(hidden) = self.hidden_condition(Utc::now()).await else {
+            return request;
+        };
+
+        let mut request = Arc::unwrap_or_clone(request);
+        exclude(&mut request, &hidden);
+        Arc::new(request)
+    }
+}
+
+/// Extend `filter` to exclude points matching the `hidden` condition
+pub(super) fn exclude_hidden_points(filter: &mut Option<Filter>, hidden: &Condition) {
+    let not_hidden = Filter::new_must_not(hidden.clone());
+    *filter = Some(match filter.take() {
+        None => not_hidden,
+        Some(filter) => filter.merge_owned(not_hidden),
+    });
+}
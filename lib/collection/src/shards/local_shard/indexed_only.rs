@@ -47,6 +47,18 @@ pub fn get_index_only_excluded_vectors(
                         return Some((vector_name, None, points));
                     }
 
+                    // Skip store-only vectors, they are not supposed to be indexed at all.
+                    let is_index_enabled = collection_config
+                        .params
+                        .vectors
+                        .get_params(&vector_name)
+                        .and_then(|params| params.index)
+                        .unwrap_or_default()
+                        .is_indexed();
+                    if !is_index_enabled {
+                        return Some((vector_name, None, points));
+                    }
+
                     let vector_storage_size =
                         segment_guard.available_vectors_size_in_bytes(&vector_name);
 
@@ -1,7 +1,11 @@
+pub(super) mod aggregation;
+pub(super) mod applied_lag;
 pub mod clock_map;
 pub mod disk_usage_watcher;
+pub(super) mod expiration;
 pub(super) mod facet;
 pub(super) mod formula_rescore;
+pub(super) mod hidden_points;
 pub(super) mod query;
 pub(super) mod scroll;
 pub(super) mod search;
@@ -10,6 +14,7 @@ pub(super) mod shard_ops;
 mod snapshot;
 mod telemetry;
 pub(super) mod updaters;
+pub(super) mod vector_loading;
 
 #[cfg(test)]
 mod snapshot_tests;
@@ -23,7 +28,7 @@ mod wal_ops;
 use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicUsize};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 use std::{cmp, thread};
 
@@ -46,7 +51,7 @@ use segment::index::field_index::{CardinalityEstimation, EstimationMerge};
 use segment::segment_constructor::{build_segment, load_segment, normalize_segment_dir};
 use segment::types::{
     Filter, PayloadIndexInfo, PayloadKeyType, PointIdType, SegmentConfig, SegmentType,
-    SeqNumberType,
+    SeqNumberType, ShardKey,
 };
 use shard::files::{NEWEST_CLOCKS_PATH, OLDEST_CLOCKS_PATH, ShardDataFiles};
 use shard::operations::CollectionUpdateOperations;
@@ -64,20 +69,23 @@ use super::update_tracker::UpdateTracker;
 use crate::collection::payload_index_schema::PayloadIndexSchema;
 use crate::collection_manager::collection_updater::CollectionUpdater;
 use crate::collection_manager::holders::segment_holder::{LockedSegment, SegmentHolder};
-use crate::collection_manager::optimizers::TrackerLog;
 use crate::collection_manager::optimizers::segment_optimizer::plan_optimizations;
+use crate::collection_manager::optimizers::{PAYLOAD_INDEX_BUILD, TrackerLog};
 use crate::collection_manager::segments_searcher::SegmentsSearcher;
+use crate::collection_manager::segments_usefulness::SegmentsUsefulness;
 use crate::common::file_utils::{move_dir, move_file};
 use crate::config::CollectionConfigInternal;
 use crate::operations::OperationWithClockTag;
 use crate::operations::shared_storage_config::SharedStorageConfig;
 use crate::operations::types::{
-    CollectionError, CollectionResult, OptimizationSegmentInfo, OptimizersStatus,
-    PendingOptimization, ShardInfoInternal, ShardStatus, ShardUpdateQueueInfo,
+    CollectionError, CollectionResult, IndexingBacklogInfo, OptimizationSegmentInfo,
+    OptimizersStatus, PendingOptimization, ShardInfoInternal, ShardStatus, ShardUpdateQueueInfo,
     check_sparse_compatible_with_segment_config,
 };
 use crate::optimizers_builder::{OptimizersConfig, build_optimizers, clear_temp_segments};
 use crate::shards::CollectionId;
+use crate::shards::local_shard::applied_lag::AppliedLag;
+use crate::shards::local_shard::expiration::ExpirationWatermark;
 use crate::shards::shard::ShardId;
 use crate::shards::shard_config::ShardConfig;
 use crate::update_handler::{OperationData, Optimizer, UpdateHandler, UpdateSignal};
@@ -95,6 +103,8 @@ const WAL_LOAD_REPORT_EVERY: Duration = Duration::from_secs(60);
 #[must_use = "Local Shard must be explicitly handled"]
 pub struct LocalShard {
     collection_name: CollectionId,
+    /// Shard key of the shard, used to apply configuration overrides of the shard key
+    pub(super) shard_key: Option<ShardKey>,
     pub(super) segments: LockedSegmentHolder,
     pub(super) collection_config: Arc<TokioRwLock<CollectionConfigInternal>>,
     pub(super) shared_storage_config: Arc<SharedStorageConfig>,
@@ -109,8 +119,16 @@ pub struct LocalShard {
     pub(super) optimizers_log: Arc<ParkingMutex<TrackerLog>>,
     pub(super) total_optimized_points: Arc<AtomicUsize>,
     pub(super) search_runtime: Handle,
-    disk_usage_watcher: DiskUsageWatcher,
+    disk_usage_watcher: Arc<DiskUsageWatcher>,
     read_rate_limiter: Option<ParkingMutex<RateLimiter>>,
+    /// Number of read requests served since the last start, excluding internal ones
+    read_requests: AtomicUsize,
+    /// Statistics of segments usefulness for searches with early exit
+    segments_usefulness: SegmentsUsefulness,
+    /// Lower bound of expiration times of points, see [`ExpirationWatermark`]
+    expiration_watermark: ExpirationWatermark,
+    /// Receive times of updates, which are not applied yet, see [`AppliedLag`]
+    applied_lag: AppliedLag,
 
     is_gracefully_stopped: bool,
 
@@ -227,6 +245,7 @@ impl LocalShard {
     #[allow(clippy::too_many_arguments)]
     pub async fn new(
         collection_name: String,
+        shard_key: Option<ShardKey>,
         segment_holder: SegmentHolder,
         collection_config: Arc<TokioRwLock<CollectionConfigInternal>>,
         shared_storage_config: Arc<SharedStorageConfig>,
@@ -245,15 +264,21 @@ impl LocalShard {
         let optimizers_log = Arc::new(ParkingMutex::new(Default::default()));
         let total_optimized_points = Arc::new(AtomicUsize::new(0));
 
-        // default to 2x the WAL capacity
-        let disk_buffer_threshold_mb =
-            2 * (collection_config.read().await.wal_config.wal_capacity_mb);
+        let disk_watermarks = shared_storage_config.disk_watermarks;
 
-        let disk_usage_watcher = disk_usage_watcher::DiskUsageWatcher::new(
-            shard_path.to_owned(),
-            disk_buffer_threshold_mb,
-        )
-        .await;
+        // default to 2x the WAL capacity
+        let disk_buffer_threshold_mb = disk_watermarks
+            .high_free_mb
+            .unwrap_or(2 * config.wal_config.wal_capacity_mb);
+
+        let disk_usage_watcher = Arc::new(
+            disk_usage_watcher::DiskUsageWatcher::new(
+                shard_path.to_owned(),
+                disk_buffer_threshold_mb,
+                disk_watermarks.flood_free_mb,
+            )
+            .await,
+        );
 
         let scroll_read_lock = Arc::new(tokio::sync::RwLock::new(()));
         let update_tracker = UpdateTracker::default();
@@ -280,6 +305,7 @@ impl LocalShard {
             segment_holder.clone(),
             locked_wal.clone(),
             config.optimizer_config.flush_interval_sec,
+            config.optimizer_config.flush_max_dirty,
             config.optimizer_config.max_optimization_threads,
             prevent_unoptimized_threshold_kb,
             clocks.clone(),
@@ -287,6 +313,7 @@ impl LocalShard {
             scroll_read_lock.clone(),
             update_tracker.clone(),
             applied_seq_handler.clone(),
+            disk_usage_watcher.clone(),
         );
 
         let (update_sender, update_receiver) =
@@ -304,6 +331,7 @@ impl LocalShard {
 
         Self {
             collection_name,
+            shard_key,
             segments: segment_holder,
             collection_config,
             shared_storage_config,
@@ -320,6 +348,10 @@ impl LocalShard {
             total_optimized_points,
             disk_usage_watcher,
             read_rate_limiter,
+            read_requests: AtomicUsize::new(0),
+            segments_usefulness: Default::default(),
+            expiration_watermark: Default::default(),
+            applied_lag: Default::default(),
             is_gracefully_stopped: false,
             update_operation_lock: scroll_read_lock,
             applied_seq_handler,
@@ -335,6 +367,7 @@ impl LocalShard {
     #[allow(clippy::too_many_arguments)]
     pub async fn load(
         shard_id: ShardId,
+        shard_key: Option<ShardKey>,
         collection_id: CollectionId,
         shard_path: &Path,
         collection_config: Arc<TokioRwLock<CollectionConfigInternal>>,
@@ -461,14 +494,16 @@ impl LocalShard {
         }
 
         clear_temp_segments(shard_path);
+        let shard_config = collection_config_read.for_shard_key(shard_key.as_ref());
         let optimizers = build_optimizers(
             shard_path,
-            &collection_config_read.params,
+            &shard_config.params,
             &effective_optimizers_config,
-            &collection_config_read.hnsw_config,
+            &shard_config.hnsw_config,
             &shared_storage_config.hnsw_global_config,
-            &collection_config_read.quantization_config,
+            &shard_config.quantization_config,
         );
+        drop(shard_config);
 
         drop(collection_config_read); // release `shared_config` from borrow checker
 
@@ -484,7 +519,11 @@ impl LocalShard {
                 "Shard has no appendable segments, this should never happen. Creating new appendable segment now",
             );
             let segments_path = LocalShard::segments_path(shard_path);
-            let segment_config = collection_config.read().await.to_base_segment_config()?;
+            let segment_config = collection_config
+                .read()
+                .await
+                .for_shard_key(shard_key.as_ref())
+                .to_base_segment_config()?;
             segment_holder.create_appendable_segment(
                 &segments_path,
                 segment_config,
@@ -494,6 +533,7 @@ impl LocalShard {
 
         let local_shard = LocalShard::new(
             collection_id.clone(),
+            shard_key,
             segment_holder,
             collection_config,
             shared_storage_config,
@@ -529,6 +569,7 @@ impl LocalShard {
     #[allow(clippy::too_many_arguments)]
     pub async fn build_local(
         id: ShardId,
+        shard_key: Option<ShardKey>,
         collection_id: CollectionId,
         shard_path: &Path,
         collection_config: Arc<TokioRwLock<CollectionConfigInternal>>,
@@ -543,6 +584,7 @@ impl LocalShard {
         let local_shard_config = ShardConfig::new_replica_set();
         let shard = Self::build(
             id,
+            shard_key,
             collection_id,
             shard_path,
             collection_config,
@@ -562,6 +604,7 @@ impl LocalShard {
     #[allow(clippy::too_many_arguments)]
     pub async fn build(
         id: ShardId,
+        shard_key: Option<ShardKey>,
         collection_id: CollectionId,
         shard_path: &Path,
         collection_config: Arc<TokioRwLock<CollectionConfigInternal>>,
@@ -572,7 +615,8 @@ impl LocalShard {
         optimizer_resource_budget: ResourceBudget,
         effective_optimizers_config: OptimizersConfig,
     ) -> CollectionResult<LocalShard> {
-        let config = collection_config.read().await;
+        let collection_config_read = collection_config.read().await;
+        let config = collection_config_read.for_shard_key(shard_key.as_ref());
 
         let wal_path = Self::wal_path(shard_path);
 
@@ -607,6 +651,7 @@ impl LocalShard {
                 vector_data: vector_params.clone(),
                 sparse_vector_data: sparse_vector_params.clone(),
                 payload_storage_type: config.params.payload_storage_type(),
+                payload_overflow_threshold: config.params.payload_overflow_threshold,
             };
             let segment = thread::Builder::new()
                 .name(format!("shard-build-{collection_id}-{id}"))
@@ -645,10 +690,12 @@ impl LocalShard {
             &config.quantization_config,
         );
 
-        drop(config); // release `shared_config` from borrow checker
+        drop(config);
+        drop(collection_config_read); // release `shared_config` from borrow checker
 
         let local_shard = LocalShard::new(
             collection_id,
+            shard_key,
             segment_holder,
             collection_config,
             shared_storage_config,
@@ -753,6 +800,8 @@ impl LocalShard {
         // (`SerdeWal::read_all` may even start reading WAL from some already truncated
         // index *occasionally*), but the storage can handle it.
 
+        let index_builds_stopped = self.optimizers_log.lock().index_builds_stopped();
+
         for (op_num, update) in wal.read_range(from..to) {
             if let Some(clock_tag) = update.clock_tag {
                 newest_clocks.advance_clock(clock_tag);
@@ -765,6 +814,7 @@ impl LocalShard {
                 update.operation,
                 self.update_operation_lock.clone(),
                 self.update_tracker.clone(),
+                &index_builds_stopped,
                 &HardwareCounterCell::disposable(), // Internal operation, no measurement needed.
             ) {
                 Err(err @ CollectionError::ServiceError { error, backtrace }) => {
@@ -835,6 +885,7 @@ impl LocalShard {
             // TODO use proper collection's hardware measurement
             let hw_measurements = HwMeasurementAcc::disposable();
             for op_num in to..=last_wal_index {
+                self.track_received(op_num);
                 update_sender
                     .send(UpdateSignal::Operation(OperationData {
                         op_num,
@@ -950,9 +1001,23 @@ impl LocalShard {
     pub async fn local_shard_status(&self) -> (ShardStatus, OptimizersStatus) {
         {
             let segments = self.segments.clone();
+            let index_builds_suspended = self
+                .optimizers_log
+                .lock()
+                .index_builds_stopped()
+                .load(Ordering::Relaxed);
 
             let has_errored_optimizers = tokio::task::spawn_blocking(move || {
                 let segments = segments.read(); // blocking sync lock
+
+                // Yellow status on payload index builds, cancelled by the user, until resumed
+                if index_builds_suspended
+                    && !segments.failed_operation.is_empty()
+                    && segments.optimizer_errors.is_none()
+                {
+                    return Some((ShardStatus::Yellow, OptimizersStatus::Ok));
+                }
+
                 // Red status on failed operation or optimizer error
                 if !segments.failed_operation.is_empty() || segments.optimizer_errors.is_some() {
                     let optimizer_status = segments
@@ -1010,6 +1075,7 @@ impl LocalShard {
         let collection_config = self.collection_config.read().await.clone();
 
         let segments = self.segments.clone();
+        let config = collection_config.clone();
         let segment_info = tokio::task::spawn_blocking(move || {
             // Collect the segments first so we don't lock the segment holder during the operations.
             let segments = segments
@@ -1020,15 +1086,17 @@ impl LocalShard {
 
             let mut schema: HashMap<PayloadKeyType, PayloadIndexInfo> = Default::default();
             let mut indexed_vectors_count = 0;
+            let mut vectors_count = 0;
             let mut points_count = 0;
             let mut segments_count = 0;
 
-            for segment in segments {
+            for segment in &segments {
                 segments_count += 1;
 
                 let segment_info = segment.get().read().info();
 
                 indexed_vectors_count += segment_info.num_indexed_vectors;
+                vectors_count += segment_info.num_vectors;
                 points_count += segment_info.num_points;
                 for (key, val) in segment_info.index_schema {
                     schema
@@ -1037,7 +1105,20 @@ impl LocalShard {
                         .or_insert(val);
                 }
             }
-            (schema, indexed_vectors_count, points_count, segments_count)
+
+            let unindexed_vectors_count =
+                indexed_only::get_index_only_excluded_vectors(&segments, &config)
+                    .values()
+                    .sum();
+            let indexing_backlog = IndexingBacklogInfo::new(unindexed_vectors_count, vectors_count);
+
+            (
+                schema,
+                indexed_vectors_count,
+                points_count,
+                segments_count,
+                indexing_backlog,
+            )
         });
         let segment_info = AbortOnDropHandle::new(segment_info).await;
 
@@ -1045,7 +1126,7 @@ impl LocalShard {
             log::error!("Failed to get local shard info: {err}");
         }
 
-        let (schema, indexed_vectors_count, points_count, segments_count) =
+        let (schema, indexed_vectors_count, points_count, segments_count, indexing_backlog) =
             segment_info.unwrap_or_default();
 
         let (status, optimizer_status) = self.local_shard_status().await;
@@ -1061,6 +1142,7 @@ impl LocalShard {
             config: collection_config,
             payload_schema: schema,
             update_queue,
+            indexing_backlog,
         }
     }
 
@@ -1072,6 +1154,41 @@ impl LocalShard {
         Arc::clone(&self.optimizers_log)
     }
 
+    /// Cancel ongoing optimizations of the given optimizer, or of all optimizers if `None`.
+    ///
+    /// Payload index builds are cancelled as [`PAYLOAD_INDEX_BUILD`] optimizer.
+    ///
+    /// Cancelled optimizers are not started again until [`LocalShard::resume_optimizations`].
+    /// Returns the number of cancelled optimizations.
+    pub fn cancel_optimizations(&self, optimizer: Option<&str>) -> CollectionResult<usize> {
+        let optimizers = self.optimizers.load();
+        let names = optimizers
+            .iter()
+            .map(|optimizer| optimizer.name())
+            .chain([PAYLOAD_INDEX_BUILD])
+            .filter(|name| optimizer.is_none_or(|optimizer| optimizer == *name))
+            .collect_vec();
+
+        if let Some(optimizer) = optimizer
+            && names.is_empty()
+        {
+            return Err(CollectionError::bad_input(format!(
+                "Unknown optimizer: {optimizer}"
+            )));
+        }
+
+        Ok(self.optimizers_log.lock().suspend(&names))
+    }
+
+    /// Allow cancelled optimizers to start again, and trigger them.
+    ///
+    /// Cancelled payload index builds are applied again by the optimization worker.
+    pub fn resume_optimizations(&self) {
+        self.optimizers_log.lock().resume();
+        // If the channel is full, optimizers are triggered by pending updates anyway
+        let _ = self.update_sender.load().try_send(UpdateSignal::Nop);
+    }
+
     /// Call [`plan_optimizations`] and return summary.
     pub fn optimizations(&self) -> LocalShardOptimizations {
         let segments = self.segments.read();
@@ -1117,6 +1234,17 @@ impl LocalShard {
         self.wal.recovery_point().await
     }
 
+    /// Get the recovery point, once all operations included in it are applied to segments.
+    ///
+    /// Unlike [`Self::recovery_point`], it never includes operations still waiting in the update
+    /// queue, so all of them are visible to reads.
+    pub async fn applied_recovery_point(&self) -> CollectionResult<RecoveryPoint> {
+        let recovery_point = self.wal.written_recovery_point().await;
+        // Included operations are already in the update queue, ahead of the plunger
+        self.plunge_async().await?.await?;
+        Ok(recovery_point)
+    }
+
     /// Take snapshot of newest clocks, if not snapshotted already
     ///
     /// Also immediately persists clocks to disk.
@@ -1197,6 +1325,7 @@ impl LocalShard {
         if hw_measurement_acc.is_disposable() {
             return Ok(());
         }
+        self.read_requests.fetch_add(1, Ordering::Relaxed);
         if let Some(rate_limiter) = &self.read_rate_limiter {
             let cost = cost_fn();
             rate_limiter
@@ -10,6 +10,7 @@ use ordered_float::OrderedFloat;
 use parking_lot::Mutex;
 use segment::common::reciprocal_rank_fusion::rrf_scoring;
 use segment::common::score_fusion::{ScoreFusion, score_fusion};
+use segment::data_types::query_context::SearchExplanations;
 use segment::types::{Filter, HasIdCondition, ScoredPoint, WithPayloadInterface, WithVector};
 use shard::query::planned_query::RescoreStages;
 use shard::search::CoreSearchRequestBatch;
@@ -17,6 +18,7 @@ use tokio::runtime::Handle;
 
 use super::LocalShard;
 use crate::collection::mmr::mmr_from_points_with_vector;
+use crate::collection::partial_results::TimedOut;
 use crate::collection_manager::segments_searcher::SegmentsSearcher;
 use crate::operations::types::{
     CollectionError, CollectionResult, CoreSearchRequest, QueryScrollRequestInternal, ScrollOrder,
@@ -61,6 +63,8 @@ impl LocalShard {
         request: PlannedQuery,
         search_runtime_handle: &Handle,
         timeout: Duration,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_counter_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let start_time = std::time::Instant::now();
@@ -70,6 +74,8 @@ impl LocalShard {
             }),
             search_runtime_handle,
             timeout,
+            explanations,
+            timed_out,
             hw_counter_acc.clone(),
         );
 
@@ -93,6 +99,8 @@ impl LocalShard {
                 &prefetch_holder,
                 search_runtime_handle,
                 timeout,
+                explanations,
+                timed_out,
                 hw_counter_acc.clone(),
             )
         });
@@ -165,6 +173,8 @@ impl LocalShard {
         prefetch_holder: &PrefetchResults,
         search_runtime_handle: &Handle,
         timeout: Duration,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
         let RootPlan {
@@ -181,6 +191,8 @@ impl LocalShard {
                 search_runtime_handle,
                 timeout,
                 0,
+                explanations,
+                timed_out,
                 hw_measurement_acc.clone(),
             )
             .await?;
@@ -196,6 +208,7 @@ impl LocalShard {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn recurse_prefetch<'a>(
         &'a self,
         merge_plan: MergePlan,
@@ -203,6 +216,8 @@ impl LocalShard {
         search_runtime_handle: &'a Handle,
         timeout: Duration,
         depth: usize,
+        explanations: Option<&'a SearchExplanations>,
+        timed_out: Option<&'a TimedOut>,
         hw_counter_acc: HwMeasurementAcc,
     ) -> BoxFuture<'a, CollectionResult<Vec<Vec<ScoredPoint>>>> {
         async move {
@@ -232,6 +247,8 @@ impl LocalShard {
                                 search_runtime_handle,
                                 timeout,
                                 depth + 1,
+                                explanations,
+                                timed_out,
                                 hw_counter_acc.clone(),
                             )
                             .await?
@@ -257,6 +274,8 @@ impl LocalShard {
                             rescore_params,
                             search_runtime_handle,
                             timeout,
+                            explanations,
+                            timed_out,
                             hw_counter_acc,
                         )
                         .await?;
@@ -286,6 +305,8 @@ impl LocalShard {
         rescore_params: RescoreParams,
         search_runtime_handle: &Handle,
         timeout: Duration,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_counter_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ScoredPoint>> {
         let RescoreParams {
@@ -352,6 +373,8 @@ impl LocalShard {
                     Arc::new(rescoring_core_search_request),
                     search_runtime_handle,
                     timeout,
+                    explanations,
+                    timed_out,
                     hw_counter_acc,
                 )
                 .await?
@@ -374,35 +397,38 @@ impl LocalShard {
                 )
                 .await
             }
-            ScoringQuery::Sample(sample) => match sample {
-                SampleInternal::Random => {
-                    // create single scroll request for rescoring query
-                    let filter = filter_with_sources_ids(sources.into_iter());
-
-                    // Note: score_threshold is not used in this case, as all results will have same score and order_value
-                    let scroll_request = QueryScrollRequestInternal {
-                        limit,
-                        filter: Some(filter),
-                        with_payload: false.into(),
-                        with_vector: false.into(),
-                        scroll_order: ScrollOrder::Random,
-                    };
-
-                    self.query_scroll_batch(
-                        Arc::new(vec![scroll_request]),
-                        search_runtime_handle,
-                        timeout,
-                        hw_counter_acc.clone(),
+            ScoringQuery::Sample(sample) => {
+                let scroll_order = match sample {
+                    SampleInternal::Random => ScrollOrder::Random,
+                    SampleInternal::Weighted(key) => ScrollOrder::WeightedRandom(key),
+                };
+
+                // create single scroll request for rescoring query
+                let filter = filter_with_sources_ids(sources.into_iter());
+
+                // Note: score_threshold is not used in this case, as all results will have same score and order_value
+                let scroll_request = QueryScrollRequestInternal {
+                    limit,
+                    filter: Some(filter),
+                    with_payload: false.into(),
+                    with_vector: false.into(),
+                    scroll_order,
+                };
+
+                self.query_scroll_batch(
+                    Arc::new(vec![scroll_request]),
+                    search_runtime_handle,
+                    timeout,
+                    hw_counter_acc.clone(),
+                )
+                .await?
+                .pop()
+                .ok_or_else(|| {
+                    CollectionError::service_error(
+                        "Rescoring with order-by query didn't return expected batch of results",
                     )
-                    .await?
-                    .pop()
-                    .ok_or_else(|| {
-                        CollectionError::service_error(
-                            "Rescoring with order-by query didn't return expected batch of results",
-                        )
-                    })
-                }
-            },
+                })
+            }
             ScoringQuery::Mmr(mmr) => {
                 self.mmr_rescore(
                     sources,
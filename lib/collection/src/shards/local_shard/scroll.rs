@@ -9,7 +9,9 @@ use itertools::Itertools as _;
 use rand::distr::weighted::WeightedIndex;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
+use segment::common::operation_error::OperationResult;
 use segment::data_types::order_by::{Direction, OrderBy};
+use segment::json_path::JsonPath;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -110,6 +112,20 @@ impl LocalShard {
                     with_payload,
                     with_vector,
                     filter.as_ref(),
+                    None,
+                    search_runtime_handle,
+                    timeout,
+                    hw_measurement_acc,
+                )
+                .await?
+            }
+            ScrollOrder::WeightedRandom(key) => {
+                self.scroll_randomly(
+                    limit,
+                    with_payload,
+                    with_vector,
+                    filter.as_ref(),
+                    Some(key),
                     search_runtime_handle,
                     timeout,
                     hw_measurement_acc,
@@ -328,6 +344,7 @@ impl LocalShard {
         Ok(ordered_records)
     }
 
+    /// Sample random points, weighted by the value of `weight_by` field if given.
     #[allow(clippy::too_many_arguments)]
     async fn scroll_randomly(
         &self,
@@ -335,6 +352,7 @@ impl LocalShard {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        weight_by: Option<&JsonPath>,
         search_runtime_handle: &Handle,
         timeout: Duration,
         hw_measurement_acc: HwMeasurementAcc,
@@ -354,22 +372,40 @@ impl LocalShard {
         let read_filtered = |segment: LockedSegment, hw_counter: &HardwareCounterCell| {
             let is_stopped = stopping_guard.get_is_stopped();
             let filter = filter.cloned();
+            let weight_by = weight_by.cloned();
 
             let hw_counter = hw_counter.fork();
-            let task = search_runtime_handle.spawn_blocking(move || {
-                let get_segment = segment.get();
-                let read_segment = get_segment.read();
-
-                (
-                    read_segment.available_point_count(),
-                    read_segment.read_random_filtered(
-                        limit,
-                        filter.as_ref(),
-                        &is_stopped,
-                        &hw_counter,
-                    ),
-                )
-            });
+            let task = search_runtime_handle.spawn_blocking(
+                move || -> OperationResult<(f64, Vec<ExtendedPointId>)> {
+                    let get_segment = segment.get();
+                    let read_segment = get_segment.read();
+
+                    match weight_by {
+                        None => Ok((
+                            read_segment.available_point_count() as f64,
+                            read_segment.read_random_filtered(
+                                limit,
+                                filter.as_ref(),
+                                &is_stopped,
+                                &hw_counter,
+                            ),
+                        )),
+                        Some(key) => {
+                            let (total_weight, mut points) = read_segment
+                                .read_weighted_random_filtered(
+                                    limit,
+                                    &key,
+                                    filter.as_ref(),
+                                    &is_stopped,
+                                    &hw_counter,
+                                )?;
+                            // Points are popped from the end, most likely ones go first
+                            points.reverse();
+                            Ok((total_weight, points))
+                        }
+                    }
+                },
+            );
             AbortOnDropHandle::new(task)
         };
 
@@ -387,13 +423,18 @@ impl LocalShard {
         .await
         .map_err(|_| CollectionError::timeout(timeout, "scroll_randomly"))??;
 
-        let (availability, mut segments_reads): (Vec<_>, Vec<_>) = all_reads.into_iter().unzip();
+        let (availability, mut segments_reads): (Vec<_>, Vec<_>) = all_reads
+            .into_iter()
+            .collect::<OperationResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
 
         // Shortcut if all segments are empty
-        if availability.iter().all(|&count| count == 0) {
+        if availability.iter().all(|&weight| weight <= 0.0) {
             return Ok(Vec::new());
         }
-        // Select points in a weighted fashion from each segment, depending on how many points each segment has.
+        // Select points in a weighted fashion from each segment, depending on how many points
+        // (or how much total weight) each segment has.
         let distribution = WeightedIndex::new(availability).map_err(|err| {
             CollectionError::service_error(format!(
                 "Failed to create weighted index for random scroll: {err:?}"
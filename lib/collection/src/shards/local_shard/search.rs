@@ -1,15 +1,21 @@
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::data_types::query_context::SearchExplanations;
 use segment::types::ScoredPoint;
 use shard::common::stopping_guard::StoppingGuard;
 use shard::query::query_enum::QueryEnum;
 use shard::search::CoreSearchRequestBatch;
 use tokio::runtime::Handle;
+use tokio_util::task::AbortOnDropHandle;
 
 use super::LocalShard;
-use crate::collection_manager::segments_searcher::SegmentsSearcher;
+use crate::collection::partial_results::TimedOut;
+use crate::collection_manager::segments_searcher::{
+    PARTIAL_RESULTS_TIMEOUT_FRACTION, SegmentsSearcher, allow_partial_results, use_early_exit,
+};
 use crate::operations::types::{CollectionError, CollectionResult};
 
 // Chunk requests for parallelism in certain scenarios
@@ -27,11 +33,18 @@ use crate::operations::types::{CollectionError, CollectionResult};
 const CHUNK_SIZE: usize = 16;
 
 impl LocalShard {
+    /// If `explanations` is given, explanations of how segments executed the searches are
+    /// collected there.
+    ///
+    /// If `timed_out` is given and the searches allow partial results, segment searches are
+    /// stopped at the deadline, and the request is marked as timed out there.
     pub async fn do_search(
         &self,
         core_request: Arc<CoreSearchRequestBatch>,
         search_runtime_handle: &Handle,
         timeout: Duration,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_counter_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
         if core_request.searches.is_empty() {
@@ -56,12 +69,26 @@ impl LocalShard {
 
         let is_stopped_guard = StoppingGuard::new();
 
+        // Stop segment searches at the deadline, even if they are still polled, so that results
+        // found so far can be returned in time
+        let allow_partial = timed_out.is_some() && allow_partial_results(&core_request);
+        let _partial_results_deadline = allow_partial.then(|| {
+            let is_stopped = is_stopped_guard.get_is_stopped();
+            let deadline = timeout.mul_f64(PARTIAL_RESULTS_TIMEOUT_FRACTION);
+            AbortOnDropHandle::new(search_runtime_handle.spawn(async move {
+                tokio::time::sleep(deadline).await;
+                is_stopped.store(true, Ordering::Relaxed);
+            }))
+        });
+
         if skip_batching {
             return self
                 .do_search_impl(
                     core_request,
                     search_runtime_handle,
                     timeout,
+                    explanations,
+                    timed_out,
                     hw_counter_acc,
                     &is_stopped_guard,
                 )
@@ -81,6 +108,8 @@ impl LocalShard {
                     Arc::new(core_request),
                     search_runtime_handle,
                     timeout,
+                    explanations,
+                    timed_out,
                     hw_counter_acc.clone(),
                     &is_stopped_guard,
                 )
@@ -96,11 +125,14 @@ impl LocalShard {
         Ok(results)
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn do_search_impl(
         &self,
         core_request: Arc<CoreSearchRequestBatch>,
         search_runtime_handle: &Handle,
         timeout: Duration,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_counter_acc: HwMeasurementAcc,
         is_stopped_guard: &StoppingGuard,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
@@ -123,20 +155,59 @@ impl LocalShard {
                 return Ok(vec![]);
             };
 
+            let query_context = query_context.with_explanations(explanations.cloned());
             (query_context, collection_config.params.clone())
         };
 
         // update timeout
         let timeout = timeout.saturating_sub(start.elapsed());
 
-        let search_request = SegmentsSearcher::search(
-            self.segments.clone(),
-            core_request.clone(),
-            search_runtime_handle,
-            true,
-            query_context,
-            timeout,
-        );
+        let search_request = async {
+            if !use_early_exit(&core_request) {
+                return SegmentsSearcher::search(
+                    self.segments.clone(),
+                    core_request.clone(),
+                    search_runtime_handle,
+                    true,
+                    query_context,
+                    timeout,
+                    timed_out,
+                )
+                .await;
+            }
+
+            // Scores returned by segments are not post-processed yet
+            let passes_threshold = |batch_id: usize, score| {
+                let request = &core_request.searches[batch_id];
+                let Some(threshold) = request.score_threshold else {
+                    return true;
+                };
+                let distance = collection_params
+                    .get_distance(request.query.get_vector_name())
+                    .unwrap();
+                let score = match request.query {
+                    QueryEnum::Nearest(_) => distance.postprocess_score(score),
+                    QueryEnum::RecommendBestScore(_)
+                    | QueryEnum::RecommendSumScores(_)
+                    | QueryEnum::Discover(_)
+                    | QueryEnum::Context(_)
+                    | QueryEnum::FeedbackNaive(_) => score,
+                };
+                distance.check_threshold(score, threshold)
+            };
+
+            SegmentsSearcher::search_with_early_exit(
+                self.segments.clone(),
+                core_request.clone(),
+                search_runtime_handle,
+                &self.segments_usefulness,
+                query_context,
+                timeout,
+                timed_out,
+                passes_threshold,
+            )
+            .await
+        };
 
         let res = tokio::time::timeout(timeout, search_request)
             .await
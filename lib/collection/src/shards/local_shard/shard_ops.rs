@@ -1,12 +1,16 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use ahash::AHashSet;
 use async_trait::async_trait;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use segment::data_types::facets::{FacetParams, FacetResponse};
+use futures::future::try_join_all;
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
+use segment::data_types::facets::{FacetParams, FacetResponse, MultiFacetParams};
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::query_context::SearchExplanations;
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    Condition, ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
@@ -17,6 +21,7 @@ use tokio::sync::oneshot;
 use tokio::time::Instant;
 use tokio::time::error::Elapsed;
 
+use crate::collection::partial_results::TimedOut;
 use crate::collection_manager::segments_searcher::SegmentsSearcher;
 use crate::operations::OperationWithClockTag;
 use crate::operations::generalizer::Generalizer;
@@ -30,6 +35,7 @@ use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQu
 use crate::operations::verification::operation_rate_cost::{BASE_COST, filter_rate_cost};
 use crate::profiling::interface::log_request_to_collector;
 use crate::shards::local_shard::LocalShard;
+use crate::shards::local_shard::hidden_points::exclude_hidden_points;
 use crate::shards::shard_trait::ShardOperation;
 use crate::update_handler::{OperationData, UpdateSignal};
 
@@ -58,16 +64,11 @@ impl ShardOperation for LocalShard {
             (None, None)
         };
 
-        if self
-            .disk_usage_watcher
-            .is_disk_full()
-            .await?
-            .unwrap_or(false)
-        {
-            return Err(CollectionError::service_error(
-                "No space left on device: WAL buffer size exceeds available disk space".to_string(),
-            ));
-        }
+        // Reject before writing into the WAL, it must never run out of disk space
+        self.disk_usage_watcher.check_updates_allowed().await?;
+
+        self.expiration_watermark
+            .observe(&operation.operation, self.expires_at_schema());
 
         let operation_id = {
             let _update_lock = self.update_lock.read().await;
@@ -87,6 +88,7 @@ impl ShardOperation for LocalShard {
                     return Ok(UpdateResult {
                         operation_id: None,
                         status: UpdateStatus::ClockRejected,
+                        affected_points: None,
                         clock_tag: operation.clock_tag,
                     });
                 }
@@ -99,6 +101,7 @@ impl ShardOperation for LocalShard {
             let keep_operation_in_ram = pending_operations_count < DEFAULT_UPDATE_QUEUE_RAM_BUFFER;
             let operation = keep_operation_in_ram.then_some(Box::new(operation.operation));
 
+            self.track_received(operation_id);
             channel_permit.send(UpdateSignal::Operation(OperationData {
                 op_num: operation_id,
                 operation,
@@ -112,10 +115,11 @@ impl ShardOperation for LocalShard {
         match (callback_receiver, timeout) {
             // Wait indefinitely
             (Some(receiver), None) => {
-                let _ = receiver.await??;
+                let affected_points = receiver.await??;
                 Ok(UpdateResult {
                     operation_id: Some(operation_id),
                     status: UpdateStatus::Completed,
+                    affected_points: Some(affected_points),
                     clock_tag: operation.clock_tag,
                 })
             }
@@ -123,16 +127,18 @@ impl ShardOperation for LocalShard {
             (Some(receiver), Some(timeout)) => {
                 match tokio::time::timeout(timeout, receiver).await {
                     Ok(res) => {
-                        res??;
+                        let affected_points = res??;
                         Ok(UpdateResult {
                             operation_id: Some(operation_id),
                             status: UpdateStatus::Completed,
+                            affected_points: Some(affected_points),
                             clock_tag: operation.clock_tag,
                         })
                     }
                     Err(_) => Ok(UpdateResult {
                         operation_id: Some(operation_id),
                         status: UpdateStatus::WaitTimeout,
+                        affected_points: None,
                         clock_tag: operation.clock_tag,
                     }),
                 }
@@ -141,6 +147,7 @@ impl ShardOperation for LocalShard {
             (None, _) => Ok(UpdateResult {
                 operation_id: Some(operation_id),
                 status: UpdateStatus::Acknowledged,
+                affected_points: None,
                 clock_tag: operation.clock_tag,
             }),
         }
@@ -154,6 +161,12 @@ impl ShardOperation for LocalShard {
         timeout: Option<Duration>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<RecordInternal>> {
+        let request = self
+            .exclude_hidden(request, |request, hidden| {
+                exclude_hidden_points(&mut request.filter, hidden)
+            })
+            .await;
+
         let ScrollRequestInternal {
             offset,
             limit,
@@ -161,6 +174,8 @@ impl ShardOperation for LocalShard {
             with_payload,
             with_vector,
             order_by,
+            distinct_by: _,
+            with_version: _,
         } = request.as_ref();
 
         let default_with_payload = ScrollRequestInternal::default_with_payload();
@@ -259,9 +274,23 @@ impl ShardOperation for LocalShard {
         self.check_read_rate_limiter(&hw_measurement_acc, "core_search", || {
             request.searches.iter().map(|s| s.search_rate_cost()).sum()
         })?;
+        let request = self
+            .exclude_hidden(request, |request, hidden| {
+                for search in &mut request.searches {
+                    exclude_hidden_points(&mut search.filter, hidden);
+                }
+            })
+            .await;
         let timeout = self.timeout_or_default_search_timeout(timeout);
-        self.do_search(request, search_runtime_handle, timeout, hw_measurement_acc)
-            .await
+        self.do_search(
+            request,
+            search_runtime_handle,
+            timeout,
+            None,
+            None,
+            hw_measurement_acc,
+        )
+        .await
     }
 
     /// This call is rate limited by the read rate limiter.
@@ -280,6 +309,11 @@ impl ShardOperation for LocalShard {
             }
             cost
         })?;
+        let request = self
+            .exclude_hidden(request, |request, hidden| {
+                exclude_hidden_points(&mut request.filter, hidden)
+            })
+            .await;
         let start_time = Instant::now();
         let total_count = if request.exact {
             let timeout = self.timeout_or_default_search_timeout(timeout);
@@ -320,7 +354,7 @@ impl ShardOperation for LocalShard {
         let timeout = self.timeout_or_default_search_timeout(timeout);
 
         let start_time = Instant::now();
-        let records_map = tokio::time::timeout(
+        let mut records_map = tokio::time::timeout(
             timeout,
             SegmentsSearcher::retrieve(
                 self.segments.clone(),
@@ -329,12 +363,28 @@ impl ShardOperation for LocalShard {
                 with_vector,
                 search_runtime_handle,
                 timeout,
-                hw_measurement_acc,
+                hw_measurement_acc.clone(),
             ),
         )
         .await
         .map_err(|_: Elapsed| CollectionError::timeout(timeout, "retrieve"))??;
 
+        // Expired and trashed points are not returned, even if they are requested by id
+        if let Some(hidden) = self.hidden_condition(chrono::Utc::now()).await {
+            let has_id: AHashSet<_> = records_map.keys().copied().collect();
+            let filter = Filter::new_must(Condition::HasId(has_id.into()))
+                .merge_owned(Filter::new_must(hidden));
+            let hidden_ids = self
+                .read_filtered(
+                    Some(&filter),
+                    search_runtime_handle,
+                    hw_measurement_acc,
+                    Some(timeout),
+                )
+                .await?;
+            records_map.retain(|point_id, _| !hidden_ids.contains(point_id));
+        }
+
         let ordered_records = request
             .ids
             .iter()
@@ -353,9 +403,18 @@ impl ShardOperation for LocalShard {
         requests: Arc<Vec<ShardQueryRequest>>,
         search_runtime_handle: &Handle,
         timeout: Option<Duration>,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let start_time = Instant::now();
+        let requests = self
+            .exclude_hidden(requests, |requests, hidden| {
+                for request in requests {
+                    exclude_hidden_points(&mut request.filter, hidden);
+                }
+            })
+            .await;
         let planned_query = PlannedQuery::try_from(requests.as_ref().to_owned())?;
 
         // Check read rate limiter before proceeding
@@ -373,6 +432,8 @@ impl ShardOperation for LocalShard {
                 planned_query,
                 search_runtime_handle,
                 timeout,
+                explanations,
+                timed_out,
                 hw_measurement_acc,
             )
             .await;
@@ -399,6 +460,11 @@ impl ShardOperation for LocalShard {
             }
             cost
         })?;
+        let request = self
+            .exclude_hidden(request, |request, hidden| {
+                exclude_hidden_points(&mut request.filter, hidden)
+            })
+            .await;
 
         let start_time = Instant::now();
         let timeout = self.timeout_or_default_search_timeout(timeout);
@@ -424,6 +490,100 @@ impl ShardOperation for LocalShard {
         Ok(FacetResponse { hits })
     }
 
+    /// Approximate counts for all keys are collected in a single pass over the segments.
+    ///
+    /// This call is rate limited by the read rate limiter.
+    async fn facets(
+        &self,
+        request: Arc<MultiFacetParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<FacetResponse>> {
+        if request.exact {
+            // Exact counts are made per value, there is nothing to share between the keys
+            return try_join_all(request.facet_params().map(|params| {
+                self.facet(
+                    Arc::new(params),
+                    search_runtime_handle,
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+            }))
+            .await;
+        }
+
+        // Check read rate limiter before proceeding
+        self.check_read_rate_limiter(&hw_measurement_acc, "facet", || {
+            let mut cost = BASE_COST;
+            if let Some(filter) = &request.filter {
+                cost += filter_rate_cost(filter);
+            }
+            cost
+        })?;
+        let request = self
+            .exclude_hidden(request, |request, hidden| {
+                exclude_hidden_points(&mut request.filter, hidden)
+            })
+            .await;
+
+        let start_time = Instant::now();
+        let timeout = self.timeout_or_default_search_timeout(timeout);
+        let hits = self
+            .approx_facets(
+                request.clone(),
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await?;
+        let elapsed = start_time.elapsed();
+        log_request_to_collector(&self.collection_name, elapsed, || request);
+        Ok(hits
+            .into_iter()
+            .map(|hits| FacetResponse { hits })
+            .collect())
+    }
+
+    /// Values are aggregated by each segment, using payload indices where possible.
+    ///
+    /// This call is rate limited by the read rate limiter.
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult> {
+        // Check read rate limiter before proceeding
+        self.check_read_rate_limiter(&hw_measurement_acc, "aggregate", || {
+            let mut cost = BASE_COST;
+            if let Some(filter) = &request.filter {
+                cost += filter_rate_cost(filter);
+            }
+            cost
+        })?;
+        let request = self
+            .exclude_hidden(request, |request, hidden| {
+                exclude_hidden_points(&mut request.filter, hidden)
+            })
+            .await;
+
+        let start_time = Instant::now();
+        let timeout = self.timeout_or_default_search_timeout(timeout);
+        let result = self
+            .aggregate_segments(
+                request.clone(),
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await?;
+        let elapsed = start_time.elapsed();
+        log_request_to_collector(&self.collection_name, elapsed, || request);
+        Ok(result)
+    }
+
     /// Finishes ongoing update tasks
     async fn stop_gracefully(mut self) {
         {
@@ -65,6 +65,7 @@ impl LocalShard {
             .collection_config
             .read()
             .await
+            .for_shard_key(self.shard_key.as_ref())
             .to_base_segment_config()?;
 
         let applied_seq_path = self.applied_seq_handler.path().to_path_buf();
@@ -64,6 +64,7 @@ impl LocalShard {
 
         let (segments, index_only_excluded_vectors) = segments_data?;
         let total_optimized_points = self.total_optimized_points.load(Ordering::Relaxed);
+        let read_requests = self.read_requests.load(Ordering::Relaxed);
 
         let optimizations: OperationDurationStatistics = self
             .optimizers
@@ -94,6 +95,7 @@ impl LocalShard {
             variant_name: None,
             status: None,
             total_optimized_points,
+            read_requests,
             vectors_size_bytes: Some(vectors_size_bytes),
             payloads_size_bytes: Some(payloads_size_bytes),
             num_points: Some(num_points),
@@ -37,7 +37,8 @@ impl LocalShard {
     ///
     /// This function is **not** cancel safe.
     pub async fn on_optimizer_config_update(&self) -> CollectionResult<()> {
-        let config = self.collection_config.read().await;
+        let collection_config = self.collection_config.read().await;
+        let config = collection_config.for_shard_key(self.shard_key.as_ref());
         let mut update_handler = self.update_handler.lock().await;
 
         // Signal all workers to stop
@@ -81,6 +82,7 @@ impl LocalShard {
 
         update_handler.optimizers = new_optimizers.clone();
         update_handler.flush_interval_sec = config.optimizer_config.flush_interval_sec;
+        update_handler.flush_max_dirty_kb = config.optimizer_config.flush_max_dirty;
         update_handler.max_optimization_threads = config.optimizer_config.max_optimization_threads;
         update_handler.prevent_unoptimized_threshold_kb = prevent_unoptimized_threshold_kb;
         update_handler.run_workers(update_receiver);
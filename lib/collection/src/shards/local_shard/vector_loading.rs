@@ -0,0 +1,30 @@
+use segment::segment::vector_loading::{clear_files_cache, populate_files};
+use segment::types::VectorName;
+
+use super::LocalShard;
+use crate::operations::types::CollectionResult;
+
+impl LocalShard {
+    /// Load files of `vector_name` into memory if `pinned`, or evict them from memory otherwise.
+    ///
+    /// Applies to segments existing at the time of the call, segments created later by
+    /// optimizations are loaded according to the collection configuration.
+    pub async fn pin_vector(&self, vector_name: &VectorName, pinned: bool) -> CollectionResult<()> {
+        let mut files = Vec::new();
+        for (_, segment) in self.segments.read().iter_original() {
+            files.extend(segment.read().take_vector_files(vector_name)?);
+        }
+
+        // Reading files may take a while, it is done without holding segment locks
+        tokio::task::spawn_blocking(move || {
+            if pinned {
+                populate_files(&files)
+            } else {
+                clear_files_cache(&files)
+            }
+        })
+        .await??;
+
+        Ok(())
+    }
+}
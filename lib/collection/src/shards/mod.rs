@@ -4,10 +4,12 @@ mod conversions;
 pub mod dummy_shard;
 pub mod forward_proxy_shard;
 pub mod local_shard;
+pub mod peer_latencies;
 pub mod proxy_shard;
 pub mod queue_proxy_shard;
 pub mod remote_shard;
 pub mod replica_set;
+pub mod replication_tracker;
 pub mod resharding;
 pub mod resolve;
 pub mod shard;
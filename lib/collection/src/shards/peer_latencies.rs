@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use parking_lot::RwLock;
+
+use crate::shards::shard::PeerId;
+
+/// Weight of the latest observation in the moving average of latencies
+const LATENCY_EWMA_ALPHA: f64 = 0.2;
+
+/// Latency observations older than this are considered outdated, so that a peer which was slow
+/// once is eventually tried again
+const LATENCY_EXPIRATION: Duration = Duration::from_secs(30);
+
+/// Latency assumed for a failed read, so that failing peers are not preferred
+const FAILED_READ_LATENCY: Duration = Duration::from_secs(1);
+
+/// Exponentially weighted moving average of read latencies of remote peers.
+///
+/// Shared by all replica sets of this peer.
+#[derive(Debug, Default)]
+pub struct PeerLatencies {
+    latencies: RwLock<HashMap<PeerId, PeerLatency>>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct PeerLatency {
+    /// Moving average of latencies, in seconds
+    average: f64,
+    last_observed: Instant,
+}
+
+impl PeerLatencies {
+    /// Start measuring latency of a read from the given peer.
+    ///
+    /// Latency is observed when the read is completed, see [`LatencyTimer::complete`], or when
+    /// the returned timer is dropped because the read was cancelled.
+    pub fn start(&self, peer_id: PeerId) -> LatencyTimer<'_> {
+        LatencyTimer {
+            latencies: self,
+            peer_id,
+            start: Instant::now(),
+            is_completed: false,
+        }
+    }
+
+    pub fn observe(&self, peer_id: PeerId, latency: Duration) {
+        let latency = latency.as_secs_f64();
+        let now = Instant::now();
+
+        self.latencies
+            .write()
+            .entry(peer_id)
+            .and_modify(|peer_latency| {
+                if now.duration_since(peer_latency.last_observed) > LATENCY_EXPIRATION {
+                    peer_latency.average = latency;
+                } else {
+                    peer_latency.average += LATENCY_EWMA_ALPHA * (latency - peer_latency.average);
+                }
+                peer_latency.last_observed = now;
+            })
+            .or_insert(PeerLatency {
+                average: latency,
+                last_observed: now,
+            });
+    }
+
+    /// Observe a lower bound of the latency, e.g. of a read which was cancelled before completion.
+    ///
+    /// Only raises the average, a cancelled read says nothing about how fast the peer is.
+    pub fn observe_at_least(&self, peer_id: PeerId, latency: Duration) {
+        let latency = self
+            .latency(peer_id)
+            .map_or(latency, |average| average.max(latency));
+        self.observe(peer_id, latency);
+    }
+
+    /// Recently observed latency of the peer, if any
+    pub fn latency(&self, peer_id: PeerId) -> Option<Duration> {
+        self.latencies
+            .read()
+            .get(&peer_id)
+            .filter(|peer_latency| peer_latency.last_observed.elapsed() <= LATENCY_EXPIRATION)
+            .map(|peer_latency| Duration::from_secs_f64(peer_latency.average))
+    }
+
+    /// Sort items by recently observed latency of their peers, lowest first.
+    ///
+    /// Peers without recent observations go first, so that their latency gets measured.
+    /// Sorting is stable, order of items with equal latency is preserved.
+    pub fn sort_by_latency<T>(&self, items: &mut [T], peer_id: impl Fn(&T) -> PeerId) {
+        items.sort_by_cached_key(|item| self.latency(peer_id(item)));
+    }
+}
+
+/// Measures latency of a single read, see [`PeerLatencies::start`]
+pub struct LatencyTimer<'a> {
+    latencies: &'a PeerLatencies,
+    peer_id: PeerId,
+    start: Instant,
+    is_completed: bool,
+}
+
+impl LatencyTimer<'_> {
+    /// Observe latency of the completed read, failed reads observe a penalty latency
+    pub fn complete(mut self, is_failed: bool) {
+        self.is_completed = true;
+
+        let mut latency = self.start.elapsed();
+        if is_failed {
+            latency = latency.max(FAILED_READ_LATENCY);
+        }
+        self.latencies.observe(self.peer_id, latency);
+    }
+}
+
+impl Drop for LatencyTimer<'_> {
+    fn drop(&mut self) {
+        if !self.is_completed {
+            self.latencies
+                .observe_at_least(self.peer_id, self.start.elapsed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peer_latencies() {
+        let latencies = PeerLatencies::default();
+
+        latencies.observe(1, Duration::from_millis(100));
+        latencies.observe(2, Duration::from_millis(10));
+        latencies.observe(2, Duration::from_millis(60));
+
+        assert_eq!(latencies.latency(1), Some(Duration::from_millis(100)));
+        let latency = latencies.latency(2).unwrap().as_secs_f64();
+        assert!((latency - 0.02).abs() < 1e-6, "{latency}");
+        assert_eq!(latencies.latency(3), None);
+
+        let mut peers = vec![1, 2, 3];
+        latencies.sort_by_latency(&mut peers, |peer_id| *peer_id);
+        assert_eq!(peers, vec![3, 2, 1]);
+
+        latencies.start(3).complete(true);
+        assert!(latencies.latency(3).unwrap() >= FAILED_READ_LATENCY);
+
+        // Cancelled read doesn't lower the latency
+        drop(latencies.start(1));
+        assert_eq!(latencies.latency(1), Some(Duration::from_millis(100)));
+    }
+}
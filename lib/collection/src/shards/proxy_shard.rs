@@ -9,7 +9,9 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::tar_ext;
 use common::types::TelemetryDetail;
 use parking_lot::Mutex as ParkingMutex;
-use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
+use segment::data_types::facets::{FacetParams, FacetResponse, MultiFacetParams};
+use segment::data_types::query_context::SearchExplanations;
 use segment::index::field_index::CardinalityEstimation;
 use segment::types::{
     ExtendedPointId, Filter, PointIdType, ScoredPoint, SizeStats, SnapshotFormat, WithPayload,
@@ -25,6 +27,7 @@ use tokio::sync::RwLock;
 use tokio::time::timeout;
 
 use super::update_tracker::UpdateTracker;
+use crate::collection::partial_results::TimedOut;
 use crate::collection_manager::optimizers::TrackerLog;
 use crate::operations::OperationWithClockTag;
 use crate::operations::operation_effect::{
@@ -359,11 +362,20 @@ impl ShardOperation for ProxyShard {
         request: Arc<Vec<ShardQueryRequest>>,
         search_runtime_handle: &Handle,
         timeout: Option<Duration>,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let local_shard = &self.wrapped_shard;
         local_shard
-            .query_batch(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .query_batch(
+                request,
+                search_runtime_handle,
+                timeout,
+                explanations,
+                timed_out,
+                hw_measurement_acc,
+            )
             .await
     }
 
@@ -380,6 +392,32 @@ impl ShardOperation for ProxyShard {
             .await
     }
 
+    async fn facets(
+        &self,
+        request: Arc<MultiFacetParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<FacetResponse>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .facets(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .await
+    }
+
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .aggregate(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .await
+    }
+
     async fn stop_gracefully(self) {
         let local_shard = self.wrapped_shard;
         local_shard.stop_gracefully().await;
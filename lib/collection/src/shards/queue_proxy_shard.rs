@@ -8,7 +8,9 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::tar_ext;
 use common::types::TelemetryDetail;
 use parking_lot::Mutex as ParkingMutex;
-use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
+use segment::data_types::facets::{FacetParams, FacetResponse, MultiFacetParams};
+use segment::data_types::query_context::SearchExplanations;
 use segment::index::field_index::CardinalityEstimation;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, SizeStats, SnapshotFormat, WithPayload,
@@ -27,6 +29,7 @@ use super::remote_shard::RemoteShard;
 use super::transfer::driver::MAX_RETRY_COUNT;
 use super::transfer::transfer_tasks_pool::TransferTaskProgress;
 use super::update_tracker::UpdateTracker;
+use crate::collection::partial_results::TimedOut;
 use crate::collection_manager::optimizers::TrackerLog;
 use crate::operations::OperationWithClockTag;
 use crate::operations::point_ops::WriteOrdering;
@@ -401,11 +404,20 @@ impl ShardOperation for QueueProxyShard {
         requests: Arc<Vec<ShardQueryRequest>>,
         search_runtime_handle: &Handle,
         timeout: Option<Duration>,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         self.inner_unchecked()
             .wrapped_shard
-            .query_batch(requests, search_runtime_handle, timeout, hw_measurement_acc)
+            .query_batch(
+                requests,
+                search_runtime_handle,
+                timeout,
+                explanations,
+                timed_out,
+                hw_measurement_acc,
+            )
             .await
     }
 
@@ -422,6 +434,32 @@ impl ShardOperation for QueueProxyShard {
             .await
     }
 
+    async fn facets(
+        &self,
+        request: Arc<MultiFacetParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<FacetResponse>> {
+        self.inner_unchecked()
+            .wrapped_shard
+            .facets(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .await
+    }
+
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult> {
+        self.inner_unchecked()
+            .wrapped_shard
+            .aggregate(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .await
+    }
+
     async fn stop_gracefully(mut self) {
         if let Some(inner) = self.inner.take() {
             debug_assert!(
@@ -756,11 +794,20 @@ impl ShardOperation for Inner {
         request: Arc<Vec<ShardQueryRequest>>,
         search_runtime_handle: &Handle,
         timeout: Option<Duration>,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let local_shard = &self.wrapped_shard;
         local_shard
-            .query_batch(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .query_batch(
+                request,
+                search_runtime_handle,
+                timeout,
+                explanations,
+                timed_out,
+                hw_measurement_acc,
+            )
             .await
     }
 
@@ -777,6 +824,32 @@ impl ShardOperation for Inner {
             .await
     }
 
+    async fn facets(
+        &self,
+        request: Arc<MultiFacetParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<FacetResponse>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .facets(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .await
+    }
+
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .aggregate(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .await
+    }
+
     async fn stop_gracefully(self) {
         self.wrapped_shard.stop_gracefully().await
     }
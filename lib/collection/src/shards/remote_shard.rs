@@ -9,13 +9,14 @@ use api::grpc::qdrant::qdrant_client::QdrantClient;
 use api::grpc::qdrant::shard_snapshot_location::Location;
 use api::grpc::qdrant::shard_snapshots_client::ShardSnapshotsClient;
 use api::grpc::qdrant::{
-    CollectionOperationResponse, CoreSearchBatchPointsInternal, CountPoints, CountPointsInternal,
-    CountResponse, FacetCountsInternal, GetCollectionInfoRequest, GetCollectionInfoRequestInternal,
-    GetPoints, GetPointsInternal, GetShardOptimizationsRequest, GetShardRecoveryPointRequest,
-    HealthCheckRequest, InitiateShardTransferRequest, QueryBatchPointsInternal,
-    QueryBatchResponseInternal, QueryShardPoints, RecoverShardSnapshotRequest,
-    RecoverSnapshotResponse, ScrollPoints, ScrollPointsInternal, SearchBatchResponse,
-    ShardSnapshotLocation, UpdateShardCutoffPointRequest, WaitForShardStateRequest,
+    AggregatePointsInternal, CollectionOperationResponse, CoreSearchBatchPointsInternal,
+    CountPoints, CountPointsInternal, CountResponse, FacetCountsInternal, GetCollectionInfoRequest,
+    GetCollectionInfoRequestInternal, GetPoints, GetPointsInternal, GetShardOptimizationsRequest,
+    GetShardRecoveryPointRequest, HealthCheckRequest, InitiateShardTransferRequest,
+    QueryBatchPointsInternal, QueryBatchResponseInternal, QueryShardPoints,
+    RecoverShardSnapshotRequest, RecoverSnapshotResponse, ScrollPoints, ScrollPointsInternal,
+    SearchBatchResponse, ShardSnapshotLocation, UpdateShardCutoffPointRequest,
+    WaitForShardStateRequest,
 };
 use api::grpc::transport_channel_pool::{AddTimeout, MAX_GRPC_CHANNEL_TIMEOUT};
 use api::grpc::update_operation::Update;
@@ -28,8 +29,11 @@ use parking_lot::Mutex;
 use segment::common::operation_time_statistics::{
     OperationDurationsAggregator, ScopeDurationMeasurer,
 };
-use segment::data_types::facets::{FacetParams, FacetResponse, FacetValueHit};
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
+use segment::data_types::facets::{FacetParams, FacetResponse, FacetValue, FacetValueHit};
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::query_context::SearchExplanations;
+use segment::index::SegmentSearchExplanation;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -49,6 +53,7 @@ use super::conversions::{
     internal_update_vectors,
 };
 use super::local_shard::clock_map::RecoveryPoint;
+use crate::collection::partial_results::TimedOut;
 use crate::operations::conversions::try_record_from_grpc;
 use crate::operations::payload_ops::PayloadOps;
 use crate::operations::point_ops::{PointOperations, WriteOrdering};
@@ -69,6 +74,7 @@ use crate::shards::conversions::{
     internal_upsert_points, try_scored_point_from_grpc,
 };
 use crate::shards::replica_set::replica_set_state::ReplicaState;
+use crate::shards::replication_tracker::ReplicationTracker;
 use crate::shards::shard::{PeerId, ShardId};
 use crate::shards::shard_trait::ShardOperation;
 use crate::shards::telemetry::RemoteShardTelemetry;
@@ -87,6 +93,7 @@ pub struct RemoteShard {
     pub channel_service: ChannelService,
     telemetry_search_durations: Arc<Mutex<OperationDurationsAggregator>>,
     telemetry_update_durations: Arc<Mutex<OperationDurationsAggregator>>,
+    pub(crate) replication: ReplicationTracker,
 }
 
 impl RemoteShard {
@@ -104,6 +111,7 @@ impl RemoteShard {
             channel_service,
             telemetry_search_durations: OperationDurationsAggregator::new(),
             telemetry_update_durations: OperationDurationsAggregator::new(),
+            replication: ReplicationTracker::default(),
         }
     }
 
@@ -120,8 +128,7 @@ impl RemoteShard {
     }
 
     fn current_address(&self) -> CollectionResult<Uri> {
-        let guard_peer_address = self.channel_service.id_to_address.read();
-        let peer_address = guard_peer_address.get(&self.peer_id).cloned();
+        let peer_address = self.channel_service.peer_address(self.peer_id);
         match peer_address {
             None => Err(CollectionError::service_error(format!(
                 "no address found for peer {}",
@@ -147,6 +154,20 @@ impl RemoteShard {
             .map_err(|err| err.into())
     }
 
+    /// Same as [`Self::with_points_client`], but accounts the update request in replication telemetry
+    async fn with_points_client_update<
+        R: prost::Message,
+        T,
+        O: Future<Output = Result<T, Status>>,
+    >(
+        &self,
+        request: &R,
+        f: impl Fn(PointsInternalClient<InterceptedService<Channel, AddTimeout>>) -> O,
+    ) -> CollectionResult<T> {
+        self.replication.sent(request.encoded_len());
+        self.with_points_client(f).await
+    }
+
     async fn with_collections_client<T, O: Future<Output = Result<T, Status>>>(
         &self,
         f: impl Fn(CollectionsInternalClient<InterceptedService<Channel, AddTimeout>>) -> O,
@@ -215,6 +236,7 @@ impl RemoteShard {
                     .lock()
                     .get_statistics(detail),
             ),
+            replication: Some(self.replication.get_telemetry_data()),
         }
     }
 
@@ -414,6 +436,18 @@ impl RemoteShard {
                         );
                         Update::OverwritePayload(request)
                     }
+                    PayloadOps::PatchPayload(set_payload) => {
+                        let request = internal_set_payload(
+                            shard_id,
+                            operation.clock_tag,
+                            collection_name.clone(),
+                            set_payload,
+                            wait,
+                            timeout,
+                            ordering,
+                        );
+                        Update::PatchPayload(request)
+                    }
                 },
                 CollectionUpdateOperations::FieldIndexOperation(field_index_op) => {
                     match field_index_op {
@@ -459,7 +493,7 @@ impl RemoteShard {
         };
 
         let point_operation_response = self
-            .with_points_client(|mut client| async move {
+            .with_points_client_update(batch_request, |mut client| async move {
                 client
                     .update_batch(tonic::Request::new(batch_request.clone()))
                     .await
@@ -542,7 +576,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     )?;
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client.upsert(tonic::Request::new(request.clone())).await
                     })
                     .await?
@@ -558,7 +592,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     )?;
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client.upsert(tonic::Request::new(request.clone())).await
                     })
                     .await?
@@ -574,7 +608,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client.delete(tonic::Request::new(request.clone())).await
                     })
                     .await?
@@ -590,7 +624,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client.delete(tonic::Request::new(request.clone())).await
                     })
                     .await?
@@ -606,7 +640,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     )?;
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client.sync(tonic::Request::new(request.clone())).await
                     })
                     .await?
@@ -624,7 +658,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     )?;
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client
                             .update_vectors(tonic::Request::new(request.clone()))
                             .await
@@ -643,7 +677,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client
                             .delete_vectors(tonic::Request::new(request.clone()))
                             .await
@@ -662,7 +696,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client
                             .delete_vectors(tonic::Request::new(request.clone()))
                             .await
@@ -682,7 +716,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client
                             .set_payload(tonic::Request::new(request.clone()))
                             .await
@@ -700,7 +734,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client
                             .delete_payload(tonic::Request::new(request.clone()))
                             .await
@@ -718,7 +752,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client
                             .clear_payload(tonic::Request::new(request.clone()))
                             .await
@@ -736,7 +770,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client
                             .clear_payload(tonic::Request::new(request.clone()))
                             .await
@@ -754,7 +788,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client
                             .overwrite_payload(tonic::Request::new(request.clone()))
                             .await
@@ -762,6 +796,24 @@ impl RemoteShard {
                     .await?
                     .into_inner()
                 }
+                PayloadOps::PatchPayload(set_payload) => {
+                    let request = &internal_set_payload(
+                        shard_id,
+                        operation.clock_tag,
+                        collection_name,
+                        set_payload,
+                        wait,
+                        timeout,
+                        ordering,
+                    );
+                    self.with_points_client_update(request, |mut client| async move {
+                        client
+                            .patch_payload(tonic::Request::new(request.clone()))
+                            .await
+                    })
+                    .await?
+                    .into_inner()
+                }
             },
             CollectionUpdateOperations::FieldIndexOperation(field_index_op) => match field_index_op
             {
@@ -775,7 +827,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client
                             .create_field_index(tonic::Request::new(request.clone()))
                             .await
@@ -793,7 +845,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(request, |mut client| async move {
                         client
                             .delete_field_index(tonic::Request::new(request.clone()))
                             .await
@@ -820,6 +872,7 @@ impl RemoteShard {
                 return Ok(UpdateResult {
                     operation_id: None,
                     status: crate::operations::types::UpdateStatus::Completed,
+                    affected_points: None,
                     clock_tag: operation.clock_tag,
                 });
             }
@@ -855,6 +908,7 @@ impl RemoteShard {
         shard_id: ShardId,
         url: &Url,
         snapshot_priority: SnapshotPriority,
+        checksum: Option<&str>,
         api_key: Option<&str>,
     ) -> CollectionResult<RecoverSnapshotResponse> {
         let res = self
@@ -870,7 +924,7 @@ impl RemoteShard {
                             snapshot_priority: api::grpc::qdrant::ShardSnapshotPriority::from(
                                 snapshot_priority,
                             ) as i32,
-                            checksum: None,
+                            checksum: checksum.map(Into::into),
                             api_key: api_key.map(Into::into),
                         })
                         .await
@@ -912,6 +966,27 @@ impl RemoteShard {
         &self,
         collection_name: &str,
         shard_id: ShardId,
+    ) -> CollectionResult<RecoveryPoint> {
+        self.get_shard_recovery_point(collection_name, shard_id, false)
+            .await
+    }
+
+    /// Request the recovery point on the remote shard, once all operations included in it are
+    /// applied
+    pub async fn shard_applied_recovery_point(
+        &self,
+        collection_name: &str,
+        shard_id: ShardId,
+    ) -> CollectionResult<RecoveryPoint> {
+        self.get_shard_recovery_point(collection_name, shard_id, true)
+            .await
+    }
+
+    async fn get_shard_recovery_point(
+        &self,
+        collection_name: &str,
+        shard_id: ShardId,
+        applied: bool,
     ) -> CollectionResult<RecoveryPoint> {
         let res = self
             .with_collections_client(|mut client| async move {
@@ -919,6 +994,7 @@ impl RemoteShard {
                     .get_shard_recovery_point(GetShardRecoveryPointRequest {
                         collection_name: collection_name.into(),
                         shard_id,
+                        applied: Some(applied),
                     })
                     .await
             })
@@ -968,6 +1044,7 @@ impl RemoteShard {
                         with_queued: options.queued,
                         completed_limit: options.completed_limit.map(|l| l as u32),
                         with_idle_segments: options.idle_segments,
+                        with_decisions: options.decisions,
                     })
                     .await
             })
@@ -1062,6 +1139,8 @@ impl ShardOperation for RemoteShard {
             with_payload,
             with_vector,
             order_by,
+            distinct_by: _,
+            with_version,
         } = request.as_ref();
 
         let with_payload = with_payload
@@ -1084,6 +1163,9 @@ impl ShardOperation for RemoteShard {
             shard_key_selector: None,
             order_by: order_by.map(api::grpc::qdrant::OrderBy::from),
             timeout: processed_timeout.map(|t| t.as_secs()),
+            with_version: *with_version,
+            distinct_by: None,
+            filter_template: None,
         };
         let scroll_request = &ScrollPointsInternal {
             scroll_points: Some(scroll_points),
@@ -1234,6 +1316,7 @@ impl ShardOperation for RemoteShard {
             read_consistency: None,
             shard_key_selector: None,
             timeout: processed_timeout.map(|t| t.as_secs()),
+            filter_template: None,
         };
 
         let count_request = &CountPointsInternal {
@@ -1289,6 +1372,7 @@ impl ShardOperation for RemoteShard {
             read_consistency: None,
             shard_key_selector: None,
             timeout: processed_timeout.map(|t| t.as_secs()),
+            with_version: request.with_version,
         };
         let get_request = &GetPointsInternal {
             get_points: Some(get_points),
@@ -1324,6 +1408,8 @@ impl ShardOperation for RemoteShard {
         requests: Arc<Vec<ShardQueryRequest>>,
         _search_runtime_handle: &Handle,
         timeout: Option<Duration>,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let processed_timeout = Self::process_read_timeout(timeout, "query_batch")?;
@@ -1344,6 +1430,7 @@ impl ShardOperation for RemoteShard {
                     query_points,
                     shard_id: Some(self.id),
                     timeout: processed_timeout.map(|t| t.as_secs()),
+                    explain: explanations.is_some().then_some(true),
                 };
 
                 let mut request = tonic::Request::new(request.clone());
@@ -1362,11 +1449,25 @@ impl ShardOperation for RemoteShard {
             time: _,
             hardware_usage,
             inference_usage: _, // Remote shards don't have inference usage, so we can ignore it
+            timed_out: remote_timed_out,
+            explanations: segment_explanations,
         } = batch_response;
 
         if let Some(hw_usage) = hardware_usage {
             hw_measurement_acc.accumulate_request(hw_usage);
         }
+        if let Some(timed_out) = timed_out
+            && remote_timed_out.unwrap_or_default()
+        {
+            timed_out.mark();
+        }
+        if let Some(explanations) = explanations {
+            let segment_explanations: Vec<_> = segment_explanations
+                .into_iter()
+                .map(SegmentSearchExplanation::try_from)
+                .try_collect()?;
+            explanations.extend(segment_explanations);
+        }
 
         let result = results
             .into_iter()
@@ -1409,6 +1510,7 @@ impl ShardOperation for RemoteShard {
             limit,
             filter,
             exact,
+            histogram,
         } = request.as_ref();
 
         let response = self
@@ -1421,6 +1523,9 @@ impl ShardOperation for RemoteShard {
                     exact: *exact,
                     shard_id: self.id,
                     timeout: processed_timeout.map(|t| t.as_secs()),
+                    histogram: histogram
+                        .clone()
+                        .map(api::grpc::qdrant::FacetHistogram::from),
                 };
 
                 let mut request = tonic::Request::new(request.clone());
@@ -1451,6 +1556,62 @@ impl ShardOperation for RemoteShard {
         Ok(result)
     }
 
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateParams>,
+        _search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult> {
+        let processed_timeout = Self::process_read_timeout(timeout, "aggregate")?;
+        let mut timer = ScopeDurationMeasurer::new(&self.telemetry_search_durations);
+        timer.set_success(false);
+
+        let AggregateParams { key, filter } = request.as_ref();
+
+        let response = self
+            .with_points_client(|mut client| async move {
+                let request = &AggregatePointsInternal {
+                    collection_name: self.collection_id.clone(),
+                    key: key.to_string(),
+                    filter: filter.clone().map(api::grpc::qdrant::Filter::from),
+                    shard_id: self.id,
+                    timeout: processed_timeout.map(|t| t.as_secs()),
+                };
+
+                let mut request = tonic::Request::new(request.clone());
+
+                if let Some(timeout) = timeout {
+                    request.set_timeout(timeout);
+                }
+
+                client.aggregate(request).await
+            })
+            .await?
+            .into_inner();
+
+        if let Some(hw_usage) = response.usage {
+            hw_measurement_acc.accumulate_request(hw_usage);
+        }
+
+        let result = AggregateResult {
+            count: response.count as usize,
+            sum: response.sum,
+            min: response.min,
+            max: response.max,
+            distinct_values: response
+                .distinct_values
+                .into_iter()
+                .map(FacetValue::try_from)
+                .try_collect()?,
+            distinct_values_exceeded: response.distinct_values_exceeded,
+        };
+
+        timer.set_success(true);
+
+        Ok(result)
+    }
+
     async fn stop_gracefully(self) {
         // No background operations to stop on RemoteShard
     }
@@ -1,19 +1,30 @@
 use std::cmp;
 use std::fmt::Write as _;
 use std::ops::Deref as _;
+use std::time::Duration;
 
 use futures::future::{self, BoxFuture};
 use futures::stream::FuturesUnordered;
 use futures::{FutureExt as _, StreamExt as _};
 use rand::seq::SliceRandom as _;
+use shard::retrieve::record_internal::RecordInternal;
 
 use super::ShardReplicaSet;
+use crate::config::ReadRouting;
 use crate::operations::consistency_params::{ReadConsistency, ReadConsistencyType};
 use crate::operations::types::{CollectionError, CollectionResult};
+use crate::shards::local_shard::clock_map::RecoveryPoint;
 use crate::shards::remote_shard::RemoteShard;
 use crate::shards::resolve::{Resolve, ResolveCondition};
+use crate::shards::shard::{PeerId, ShardId};
 use crate::shards::shard_trait::ShardOperation;
 
+/// Maximum time to wait for a replica to catch up with all the others for a linearizable read
+const LINEARIZABLE_READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Delay between read-index rounds, while no replica is caught up
+const LINEARIZABLE_READ_RETRY_DELAY: Duration = Duration::from_millis(20);
+
 impl ShardReplicaSet {
     /// Execute read op. on replica set:
     /// 1 - Prefer local replica
@@ -55,6 +66,21 @@ impl ShardReplicaSet {
 
         let read_consistency = read_consistency.unwrap_or_default();
 
+        if read_consistency == ReadConsistency::Type(ReadConsistencyType::Linearizable) {
+            return self
+                .execute_linearizable_read_operation(read_operation)
+                .await;
+        }
+
+        if let ReadConsistency::MaxStaleness { max_staleness_ms } = read_consistency {
+            return self
+                .execute_bounded_staleness_read_operation(
+                    read_operation,
+                    Duration::from_millis(max_staleness_ms),
+                )
+                .await;
+        }
+
         let local_count = usize::from(self.peer_state(self.this_peer_id()).is_some());
         let active_local_count = usize::from(self.peer_is_readable(self.this_peer_id()));
         let initializing_local_count = usize::from(self.peer_is_initializing(self.this_peer_id()));
@@ -91,6 +117,14 @@ impl ShardReplicaSet {
             ReadConsistency::Factor(factor) => {
                 (factor.clamp(1, total_count), ResolveCondition::All)
             }
+
+            ReadConsistency::Type(ReadConsistencyType::Linearizable) => {
+                unreachable!("linearizable reads are executed separately")
+            }
+
+            ReadConsistency::MaxStaleness { .. } => {
+                unreachable!("bounded staleness reads are executed separately")
+            }
         };
 
         if active_count + initializing_count < required_successful_results {
@@ -125,6 +159,271 @@ impl ShardReplicaSet {
         }
     }
 
+    /// Execute read op. on a replica, which has applied all updates accepted by any readable
+    /// replica before the read started.
+    ///
+    /// A single read-index round is performed first: the read index is the join of recovery points
+    /// of all readable replicas. The read is then served by the local replica, once it has applied
+    /// all updates of the read index. Without a readable local replica, it is served by the first
+    /// remote replica, which has applied them.
+    ///
+    /// Fails, if any readable replica doesn't report its recovery point, e.g. during shard
+    /// transfer, or if no replica applies the read index in time.
+    async fn execute_linearizable_read_operation<Res, F>(
+        &self,
+        read_operation: F,
+    ) -> CollectionResult<Res>
+    where
+        F: Fn(&(dyn ShardOperation + Send + Sync)) -> BoxFuture<'_, CollectionResult<Res>>,
+    {
+        let deadline = tokio::time::Instant::now() + LINEARIZABLE_READ_TIMEOUT;
+
+        let read_index = self.read_index().await?;
+
+        loop {
+            let local_guard = self.local.read().await;
+            let remotes = self.remotes.read().await;
+
+            let local = local_guard
+                .as_ref()
+                .filter(|_| self.peer_is_readable(self.this_peer_id()));
+
+            if let Some(local) = local {
+                let applied = local.applied_recovery_point().await?;
+                if applied.covers(&read_index) {
+                    let _partial_snapshot_search_lock =
+                        self.partial_snapshot_meta.try_take_search_read_lock()?;
+                    return read_operation(local.get()).await;
+                }
+            } else {
+                let readable_remotes: Vec<_> = remotes
+                    .iter()
+                    .filter(|remote| self.peer_is_readable(remote.peer_id))
+                    .collect();
+
+                let applied = future::join_all(readable_remotes.iter().map(|remote| {
+                    remote.shard_applied_recovery_point(&self.collection_id, self.shard_id)
+                }))
+                .await;
+
+                // Replica failing to report its recovery point can't serve the read
+                let caught_up = applied.iter().position(|applied| {
+                    applied
+                        .as_ref()
+                        .is_ok_and(|applied| applied.covers(&read_index))
+                });
+
+                if let Some(index) = caught_up {
+                    return read_operation(readable_remotes[index]).await;
+                }
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return Err(CollectionError::timeout(
+                    LINEARIZABLE_READ_TIMEOUT,
+                    format!(
+                        "linearizable read on shard {}, no replica applied the read index",
+                        self.shard_id,
+                    ),
+                ));
+            }
+
+            drop(remotes);
+            drop(local_guard);
+            tokio::time::sleep(LINEARIZABLE_READ_RETRY_DELAY).await;
+        }
+    }
+
+    /// Join recovery points of all readable replicas.
+    ///
+    /// The result covers all updates accepted by any readable replica at this moment, including
+    /// updates not applied yet.
+    async fn read_index(&self) -> CollectionResult<RecoveryPoint> {
+        let local_guard = self.local.read().await;
+        let remotes = self.remotes.read().await;
+
+        let local = local_guard
+            .as_ref()
+            .filter(|_| self.peer_is_readable(self.this_peer_id()));
+        let readable_remotes: Vec<_> = remotes
+            .iter()
+            .filter(|remote| self.peer_is_readable(remote.peer_id))
+            .collect();
+
+        if local.is_none() && readable_remotes.is_empty() {
+            return Err(CollectionError::service_error(format!(
+                "The replica set for shard {} on peer {} does not have active replicas",
+                self.shard_id,
+                self.this_peer_id(),
+            )));
+        }
+
+        let local_recovery_point = async {
+            match local {
+                Some(local) => local.shard_recovery_point().await.map(Some),
+                None => Ok(None),
+            }
+        };
+        let remote_recovery_points = future::try_join_all(
+            readable_remotes
+                .iter()
+                .map(|remote| remote.shard_recovery_point(&self.collection_id, self.shard_id)),
+        );
+
+        let (local_recovery_point, remote_recovery_points) = future::try_join(
+            local_recovery_point,
+            remote_recovery_points,
+        )
+        .await
+        .map_err(|err| {
+            CollectionError::service_error(format!(
+                "Failed to perform read-index round for linearizable read on shard {}: {err}",
+                self.shard_id,
+            ))
+        })?;
+
+        let mut read_index = RecoveryPoint::default();
+        for recovery_point in local_recovery_point.iter().chain(&remote_recovery_points) {
+            read_index.join(recovery_point);
+        }
+
+        Ok(read_index)
+    }
+
+    /// Execute read op. on local replica, if it is not behind the updates it has received by more
+    /// than `max_staleness`, otherwise proxy it to the leader replica.
+    ///
+    /// Staleness of local replica is tracked locally, so the read is never delayed to let the
+    /// replica catch up.
+    async fn execute_bounded_staleness_read_operation<Res, F>(
+        &self,
+        read_operation: F,
+        max_staleness: Duration,
+    ) -> CollectionResult<Res>
+    where
+        F: Fn(&(dyn ShardOperation + Send + Sync)) -> BoxFuture<'_, CollectionResult<Res>>,
+    {
+        if self.peer_is_readable(self.this_peer_id()) {
+            let local = self.local.read().await;
+            let applied_lag = local.as_ref().and_then(|local| local.applied_lag());
+
+            if let Some(local) = local.as_ref()
+                && applied_lag.is_some_and(|lag| lag <= max_staleness)
+            {
+                let _partial_snapshot_search_lock =
+                    self.partial_snapshot_meta.try_take_search_read_lock()?;
+                return read_operation(local.get()).await;
+            }
+
+            log::debug!(
+                "Local replica of shard {} is behind by {applied_lag:?}, more than {}ms allowed",
+                self.shard_id,
+                max_staleness.as_millis(),
+            );
+        }
+
+        let Some(leader_peer_id) = self.highest_alive_replica_peer_id() else {
+            return Err(CollectionError::service_error(format!(
+                "The replica set for shard {} on peer {} does not have active replicas",
+                self.shard_id,
+                self.this_peer_id(),
+            )));
+        };
+
+        self.execute_leader_read_operation(read_operation, leader_peer_id)
+            .await
+    }
+
+    /// Execute read op. returning records, which have point versions only if `with_version` is
+    /// requested.
+    ///
+    /// Point versions are assigned by each replica independently. So reads with versions are
+    /// pinned to a single replica, which is returned with each record, and `read_consistency` is
+    /// ignored for them.
+    pub(super) async fn execute_records_read_operation<F>(
+        &self,
+        read_operation: F,
+        with_version: bool,
+        read_consistency: Option<ReadConsistency>,
+        local_only: bool,
+    ) -> CollectionResult<Vec<RecordInternal>>
+    where
+        F: Fn(
+            &(dyn ShardOperation + Send + Sync),
+        ) -> BoxFuture<'_, CollectionResult<Vec<RecordInternal>>>,
+    {
+        if with_version {
+            let (mut records, peer_id) = self
+                .execute_pinned_read_operation(read_operation, local_only)
+                .await?;
+            for record in &mut records {
+                record.version_peer_id = Some(peer_id);
+            }
+            return Ok(records);
+        }
+
+        let mut records = self
+            .execute_and_resolve_read_operation(read_operation, read_consistency, local_only)
+            .await?;
+        for record in &mut records {
+            record.version = None;
+            record.version_peer_id = None;
+        }
+        Ok(records)
+    }
+
+    /// Execute read op. on the leader replica, or on the local replica if `local_only`.
+    ///
+    /// Repeated reads are served by the same replica, as long as the replica set doesn't change.
+    /// Returns the peer of the replica, which served the read.
+    async fn execute_pinned_read_operation<Res, F>(
+        &self,
+        read_operation: F,
+        local_only: bool,
+    ) -> CollectionResult<(Res, PeerId)>
+    where
+        F: Fn(&(dyn ShardOperation + Send + Sync)) -> BoxFuture<'_, CollectionResult<Res>>,
+    {
+        if local_only {
+            let res = self.execute_local_read_operation(read_operation).await?;
+            return Ok((res, self.this_peer_id()));
+        }
+
+        let Some(leader_peer_id) = self.highest_alive_replica_peer_id() else {
+            return Err(CollectionError::service_error(format!(
+                "The replica set for shard {} on peer {} does not have active replicas",
+                self.shard_id,
+                self.this_peer_id(),
+            )));
+        };
+
+        let res = self
+            .execute_leader_read_operation(read_operation, leader_peer_id)
+            .await?;
+        Ok((res, leader_peer_id))
+    }
+
+    async fn execute_leader_read_operation<Res, F>(
+        &self,
+        read_operation: F,
+        leader_peer_id: PeerId,
+    ) -> CollectionResult<Res>
+    where
+        F: Fn(&(dyn ShardOperation + Send + Sync)) -> BoxFuture<'_, CollectionResult<Res>>,
+    {
+        if leader_peer_id == self.this_peer_id() {
+            return self.execute_local_read_operation(read_operation).await;
+        }
+
+        let remotes = self.remotes.read().await;
+        let leader = remotes
+            .iter()
+            .find(|remote| remote.peer_id == leader_peer_id)
+            .ok_or_else(|| leader_not_found(self.shard_id, leader_peer_id))?;
+
+        read_operation(leader).await
+    }
+
     async fn execute_local_read_operation<Res, F>(&self, read_operation: F) -> CollectionResult<Res>
     where
         F: Fn(&(dyn ShardOperation + Send + Sync)) -> BoxFuture<'_, CollectionResult<Res>>,
@@ -196,22 +495,6 @@ impl ShardReplicaSet {
             None
         };
 
-        // TODO(resharding): Handle resharded shard?
-        let mut readable_remotes: Vec<_> = remotes
-            .iter()
-            .filter(|remote| self.peer_is_readable(remote.peer_id))
-            .collect();
-
-        readable_remotes.shuffle(&mut rand::rng());
-
-        let remote_operations = readable_remotes.into_iter().map(|remote| {
-            read_operation(remote)
-                .map(|result| (result, false))
-                .right_future()
-        });
-
-        let mut operations = local_operation.into_iter().chain(remote_operations);
-
         // Possible scenarios:
         //
         // - Local is available: default fan-out is 0 (no fan-out, unless explicitly requested)
@@ -224,7 +507,7 @@ impl ShardReplicaSet {
             1
         };
 
-        let (read_fan_out_factor, fan_out_delay) = {
+        let (read_fan_out_factor, fan_out_delay, read_routing) = {
             let guard = self.collection_config.read().await;
             let params = &guard.params;
 
@@ -237,9 +520,42 @@ impl ShardReplicaSet {
                     Some(tokio::time::Duration::from_millis(delay))
                 }
             });
-            (read_fan_out_factor, read_fan_out_delay)
+            let read_routing = params.read_routing.unwrap_or(ReadRouting::Random);
+            (read_fan_out_factor, read_fan_out_delay, read_routing)
         };
 
+        // TODO(resharding): Handle resharded shard?
+        let mut readable_remotes: Vec<_> = remotes
+            .iter()
+            .filter(|remote| self.peer_is_readable(remote.peer_id))
+            .collect();
+
+        let peer_latencies = &self.channel_service.peer_latencies;
+
+        readable_remotes.shuffle(&mut rand::rng());
+
+        // Shuffled first, so that remotes with equal latency are still selected randomly
+        match read_routing {
+            ReadRouting::Random => {}
+            ReadRouting::Latency => {
+                peer_latencies.sort_by_latency(&mut readable_remotes, |remote| remote.peer_id);
+            }
+        }
+
+        let read_operation = &read_operation;
+
+        let remote_operations = readable_remotes.into_iter().map(|remote| {
+            async move {
+                let latency_timer = peer_latencies.start(remote.peer_id);
+                let result = read_operation(remote).await;
+                latency_timer.complete(result.as_ref().is_err_and(CollectionError::is_transient));
+                (result, false)
+            }
+            .right_future()
+        });
+
+        let mut operations = local_operation.into_iter().chain(remote_operations);
+
         let initial_concurrent_operations = required_successful_results + read_fan_out_factor;
 
         let mut pending_operations: FuturesUnordered<_> = operations
@@ -351,3 +667,9 @@ impl ShardReplicaSet {
         }
     }
 }
+
+fn leader_not_found(shard_id: ShardId, leader_peer_id: PeerId) -> CollectionError {
+    CollectionError::service_error(format!(
+        "Leader replica of shard {shard_id} on peer {leader_peer_id} not found",
+    ))
+}
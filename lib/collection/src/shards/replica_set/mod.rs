@@ -8,6 +8,7 @@ mod shard_transfer;
 pub mod snapshots;
 mod telemetry;
 mod update;
+mod update_batcher;
 
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref as _;
@@ -20,7 +21,7 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::rate_limiting::RateLimiter;
 use common::save_on_disk::SaveOnDisk;
 use replica_set_state::{ReplicaSetState, ReplicaState};
-use segment::types::{ExtendedPointId, Filter, SeqNumberType, ShardKey};
+use segment::types::{ExtendedPointId, Filter, SeqNumberType, ShardKey, VectorName};
 use serde::{Deserialize, Serialize};
 use tokio::runtime::Handle;
 use tokio::sync::{Mutex, RwLock};
@@ -28,6 +29,7 @@ use tokio::task::spawn_blocking;
 use tokio_util::task::AbortOnDropHandle;
 
 use self::partial_snapshot_meta::PartialSnapshotMeta;
+use self::update_batcher::UpdateBatcher;
 use super::CollectionId;
 use super::local_shard::clock_map::RecoveryPoint;
 use super::local_shard::{LocalShard, LocalShardOptimizations};
@@ -120,6 +122,8 @@ pub struct ShardReplicaSet {
     /// Local clock set, used to tag new operations on this shard.
     clock_set: Mutex<ClockSet>,
     write_rate_limiter: Option<parking_lot::Mutex<RateLimiter>>,
+    /// Coalesces small upserts into this shard, if enabled in the WAL config
+    update_batcher: UpdateBatcher,
     pub partial_snapshot_meta: PartialSnapshotMeta,
 }
 
@@ -156,6 +160,7 @@ impl ShardReplicaSet {
         let local = if local {
             let shard = LocalShard::build(
                 shard_id,
+                shard_key.clone(),
                 collection_id.clone(),
                 &shard_path,
                 collection_config.clone(),
@@ -230,6 +235,7 @@ impl ShardReplicaSet {
             write_ordering_lock: Mutex::new(()),
             clock_set: Default::default(),
             write_rate_limiter,
+            update_batcher: Default::default(),
             partial_snapshot_meta: PartialSnapshotMeta::default(),
         })
     }
@@ -299,6 +305,7 @@ impl ShardReplicaSet {
             } else {
                 let res = LocalShard::load(
                     shard_id,
+                    shard_key.clone(),
                     collection_id.clone(),
                     shard_path,
                     collection_config.clone(),
@@ -372,6 +379,7 @@ impl ShardReplicaSet {
             write_ordering_lock: Mutex::new(()),
             clock_set: Default::default(),
             write_rate_limiter,
+            update_batcher: Default::default(),
             partial_snapshot_meta: PartialSnapshotMeta::default(),
         };
 
@@ -612,6 +620,7 @@ impl ShardReplicaSet {
 
         let local_shard_res = LocalShard::build(
             self.shard_id,
+            self.shard_key.clone(),
             self.collection_id.clone(),
             &self.shard_path,
             self.collection_config.clone(),
@@ -709,12 +718,14 @@ impl ShardReplicaSet {
             return Ok(());
         }
 
-        remotes.push(RemoteShard::new(
+        let remote = RemoteShard::new(
             self.shard_id,
             self.collection_id.clone(),
             peer_id,
             self.channel_service.clone(),
-        ));
+        );
+        remote.replication.state_updated(state);
+        remotes.push(remote);
 
         Ok(())
     }
@@ -798,7 +809,9 @@ impl ShardReplicaSet {
 
     /// Called when a peer state is changed (except local peer).
     ///
-    async fn on_remote_state_updated(&self, _peer_id: PeerId, _new_state: ReplicaState) {
+    async fn on_remote_state_updated(&self, peer_id: PeerId, new_state: ReplicaState) {
+        self.track_remote_state(peer_id, new_state).await;
+
         let mut is_any_remote_dead = false;
         let mut is_local_active = false;
         let this_peer_id = self.this_peer_id();
@@ -825,6 +838,14 @@ impl ShardReplicaSet {
         }
     }
 
+    /// Track the new state of the remote replica in its replication telemetry
+    async fn track_remote_state(&self, peer_id: PeerId, state: ReplicaState) {
+        let remotes = self.remotes.read().await;
+        if let Some(remote) = remotes.iter().find(|remote| remote.peer_id == peer_id) {
+            remote.replication.state_updated(state);
+        }
+    }
+
     pub async fn remove_peer(&self, peer_id: PeerId) -> CollectionResult<()> {
         if self.this_peer_id() == peer_id {
             self.remove_local().await?;
@@ -864,8 +885,8 @@ impl ShardReplicaSet {
             let peer_already_exists = old_peers.contains_key(&peer_id);
 
             if peer_already_exists {
-                // do nothing
                 // We only need to change state and it is already saved
+                self.track_remote_state(peer_id, state).await;
                 continue;
             }
 
@@ -873,6 +894,7 @@ impl ShardReplicaSet {
                 // Consensus wants a local replica on this peer
                 let local_shard = LocalShard::build(
                     self.shard_id,
+                    shard_key.clone(),
                     self.collection_id.clone(),
                     &self.shard_path,
                     self.collection_config.clone(),
@@ -924,6 +946,7 @@ impl ShardReplicaSet {
                 peer_id,
                 self.channel_service.clone(),
             );
+            new_remote.replication.state_updated(state);
             self.remotes.write().await.push(new_remote);
         }
 
@@ -1087,6 +1110,7 @@ impl ShardReplicaSet {
             return Ok(UpdateResult {
                 operation_id: None,
                 status: UpdateStatus::Completed,
+                affected_points: Some(0),
                 clock_tag: None,
             });
         }
@@ -1122,13 +1146,15 @@ impl ShardReplicaSet {
             .peers()
             .iter()
             .filter(|(peer, _)| **peer != state.this_peer_id)
-            .map(|(peer_id, _is_active)| {
-                RemoteShard::new(
+            .map(|(peer_id, peer_state)| {
+                let remote = RemoteShard::new(
                     shard_id,
                     collection_id.clone(),
                     *peer_id,
                     channel_service.clone(),
-                )
+                );
+                remote.replication.state_updated(*peer_state);
+                remote
             })
             .collect()
     }
@@ -1287,6 +1313,18 @@ impl ShardReplicaSet {
         local_shard.shard_recovery_point().await
     }
 
+    /// Get the recovery point of the local shard, once all operations included in it are applied.
+    pub(crate) async fn shard_applied_recovery_point(&self) -> CollectionResult<RecoveryPoint> {
+        let local_shard = self.local.read().await;
+        let Some(local_shard) = local_shard.as_ref() else {
+            return Err(CollectionError::NotFound {
+                what: "Peer does not have local shard".into(),
+            });
+        };
+
+        local_shard.applied_recovery_point().await
+    }
+
     /// Update the cutoff point for the local shard.
     pub(crate) async fn update_shard_cutoff_point(
         &self,
@@ -1393,6 +1431,7 @@ impl ShardReplicaSet {
             queued: _,
             completed_limit,
             idle_segments: _,
+            decisions: _,
         } = options;
 
         // Collect from local and remote shards concurrently
@@ -1426,6 +1465,10 @@ impl ShardReplicaSet {
             }
         }
 
+        if let Some(decisions) = &mut response.decisions {
+            decisions.sort_by_key(|v| std::cmp::Reverse(v.decided_at));
+        }
+
         Ok(response)
     }
 
@@ -1441,12 +1484,14 @@ impl ShardReplicaSet {
             queued: with_queued,
             completed_limit,
             idle_segments: with_idle_segments,
+            decisions: with_decisions,
         } = options;
 
         let mut running = Vec::new();
         let mut completed = Vec::new();
         let mut idle_segments = with_idle_segments.then_some(Vec::new());
         let mut queued = with_queued.then_some(Vec::new());
+        let mut decisions = with_decisions.then_some(Vec::new());
 
         let mut queued_optimizations = 0;
         let mut queued_segments = 0;
@@ -1461,13 +1506,17 @@ impl ShardReplicaSet {
             && is_updatable
         {
             if let Some(log) = shard.optimizers_log() {
-                for tracker in log.lock().iter() {
+                let log = log.lock();
+                for tracker in log.iter() {
                     if tracker.state.lock().status.is_running() {
                         running.push(tracker.to_optimization());
                     } else if completed_limit.is_some() {
                         completed.push(tracker.to_optimization());
                     }
                 }
+                if let Some(decisions) = &mut decisions {
+                    decisions.extend(log.decisions().cloned());
+                }
             }
             if let Some(shard_optimizations) = shard.optimizations() {
                 let LocalShardOptimizations {
@@ -1510,9 +1559,44 @@ impl ShardReplicaSet {
                 completed
             }),
             idle_segments,
+            decisions,
         })
     }
 
+    /// Cancel ongoing optimizations in the local shard (if present).
+    /// Returns amount of cancelled optimizations.
+    pub async fn cancel_optimizations(&self, optimizer: Option<&str>) -> CollectionResult<usize> {
+        let local = self.local.read().await;
+        let Some(local) = local.as_ref() else {
+            return Ok(0);
+        };
+
+        let cancelled = local.cancel_optimizations(optimizer)?;
+        if cancelled > 0 {
+            log::debug!(
+                "Cancelled {cancelled} optimizations in shard {}:{}",
+                self.collection_id,
+                self.shard_id,
+            );
+        }
+        Ok(cancelled)
+    }
+
+    /// Allow cancelled optimizations in the local shard (if present) to start again.
+    pub async fn resume_optimizations(&self) {
+        if let Some(local) = self.local.read().await.as_ref() {
+            local.resume_optimizations();
+        }
+    }
+
+    /// Load the vector of the local shard (if present) into memory, or evict it from memory.
+    pub async fn pin_vector(&self, vector_name: &VectorName, pinned: bool) -> CollectionResult<()> {
+        match self.local.read().await.as_ref() {
+            Some(local) => local.pin_vector(vector_name, pinned).await,
+            None => Ok(()),
+        }
+    }
+
     /// Truncate unapplied WAL records for the local shard (if present).
     /// Returns amount of removed records.
     pub async fn truncate_unapplied_wal(&self) -> CollectionResult<usize> {
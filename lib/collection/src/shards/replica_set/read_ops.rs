@@ -3,7 +3,9 @@ use std::time::Duration;
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::FutureExt as _;
-use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
+use segment::data_types::facets::{FacetParams, FacetResponse, MultiFacetParams};
+use segment::data_types::query_context::SearchExplanations;
 use segment::types::*;
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
@@ -11,6 +13,7 @@ use shard::scroll::ScrollRequestInternal;
 use shard::search::CoreSearchRequestBatch;
 
 use super::ShardReplicaSet;
+use crate::collection::partial_results::TimedOut;
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::types::*;
 use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
@@ -25,7 +28,9 @@ impl ShardReplicaSet {
         timeout: Option<Duration>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<RecordInternal>> {
-        self.execute_and_resolve_read_operation(
+        let with_version = request.with_version.unwrap_or_default();
+
+        self.execute_records_read_operation(
             |shard| {
                 let request = request.clone();
                 let search_runtime = self.search_runtime.clone();
@@ -39,6 +44,7 @@ impl ShardReplicaSet {
                 }
                 .boxed()
             },
+            with_version,
             read_consistency,
             local_only,
         )
@@ -157,8 +163,9 @@ impl ShardReplicaSet {
     ) -> CollectionResult<Vec<RecordInternal>> {
         let with_payload = Arc::new(with_payload.clone());
         let with_vector = Arc::new(with_vector.clone());
+        let with_version = request.with_version.unwrap_or_default();
 
-        self.execute_and_resolve_read_operation(
+        self.execute_records_read_operation(
             |shard| {
                 let request = request.clone();
                 let with_payload = with_payload.clone();
@@ -181,6 +188,7 @@ impl ShardReplicaSet {
                 }
                 .boxed()
             },
+            with_version,
             read_consistency,
             local_only,
         )
@@ -216,22 +224,34 @@ impl ShardReplicaSet {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn query_batch(
         &self,
         requests: Arc<Vec<ShardQueryRequest>>,
         read_consistency: Option<ReadConsistency>,
         local_only: bool,
         timeout: Option<Duration>,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         self.execute_and_resolve_read_operation(
             |shard| {
                 let requests = Arc::clone(&requests);
                 let search_runtime = self.search_runtime.clone();
+                let explanations = explanations.cloned();
+                let timed_out = timed_out.cloned();
                 let hw_measurement_acc_clone = hw_measurement_acc.clone();
                 async move {
                     shard
-                        .query_batch(requests, &search_runtime, timeout, hw_measurement_acc_clone)
+                        .query_batch(
+                            requests,
+                            &search_runtime,
+                            timeout,
+                            explanations.as_ref(),
+                            timed_out.as_ref(),
+                            hw_measurement_acc_clone,
+                        )
                         .await
                 }
                 .boxed()
@@ -263,4 +283,58 @@ impl ShardReplicaSet {
         )
         .await
     }
+
+    pub async fn facets(
+        &self,
+        request: Arc<MultiFacetParams>,
+        read_consistency: Option<ReadConsistency>,
+        local_only: bool,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<FacetResponse>> {
+        self.execute_and_resolve_read_operation(
+            |shard| {
+                let request = request.clone();
+                let search_runtime = self.search_runtime.clone();
+
+                let hw_acc = hw_measurement_acc.clone();
+                async move {
+                    shard
+                        .facets(request, &search_runtime, timeout, hw_acc)
+                        .await
+                }
+                .boxed()
+            },
+            read_consistency,
+            local_only,
+        )
+        .await
+    }
+
+    pub async fn aggregate(
+        &self,
+        request: Arc<AggregateParams>,
+        read_consistency: Option<ReadConsistency>,
+        local_only: bool,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult> {
+        self.execute_and_resolve_read_operation(
+            |shard| {
+                let request = request.clone();
+                let search_runtime = self.search_runtime.clone();
+
+                let hw_acc = hw_measurement_acc.clone();
+                async move {
+                    shard
+                        .aggregate(request, &search_runtime, timeout, hw_acc)
+                        .await
+                }
+                .boxed()
+            },
+            read_consistency,
+            local_only,
+        )
+        .await
+    }
 }
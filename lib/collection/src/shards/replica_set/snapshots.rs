@@ -273,6 +273,7 @@ impl ShardReplicaSet {
 
             LocalShard::load(
                 self.shard_id,
+                self.shard_key.clone(),
                 self.collection_id.clone(),
                 &self.shard_path,
                 self.collection_config.clone(),
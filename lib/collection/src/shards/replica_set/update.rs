@@ -1,14 +1,18 @@
 use std::ops::Deref as _;
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::stream::FuturesUnordered;
 use futures::{FutureExt as _, StreamExt as _};
 use itertools::Itertools as _;
+use segment::types::{Condition, Filter};
+use shard::operations::point_ops::PointOperations;
 use tokio::sync::oneshot;
 use tokio::task::yield_now;
 use tokio_util::task::AbortOnDropHandle;
 
+use super::update_batcher::{BatchKey, Batched, CollectedBatch, batch_result};
 use super::{ShardReplicaSet, clock_set};
 use crate::operations::point_ops::WriteOrdering;
 use crate::operations::types::{CollectionError, CollectionResult, UpdateResult, UpdateStatus};
@@ -131,10 +135,134 @@ impl ShardReplicaSet {
         result.map(Some)
     }
 
+    /// Small upserts may be coalesced with concurrent ones, if batching is enabled in the WAL
+    /// config. Hardware usage of a batch is reported to the request which opened it.
+    ///
     /// # Cancel safety
     ///
     /// This method is *not* cancel safe.
     pub async fn update_with_consistency(
+        &self,
+        operation: CollectionUpdateOperations,
+        wait: bool,
+        timeout: Option<Duration>,
+        ordering: WriteOrdering,
+        update_only_existing: bool,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<UpdateResult> {
+        let batching = {
+            let config = self.collection_config.read().await;
+            config
+                .wal_config
+                .batch_window()
+                .map(|window| (window, config.wal_config.wal_batch_max_points))
+        };
+
+        let operation = match batching {
+            Some((window, max_points)) => {
+                let key = BatchKey {
+                    wait,
+                    timeout,
+                    ordering,
+                    update_only_existing,
+                };
+
+                match self.update_batcher.add(operation, key, max_points) {
+                    Batched::Joined(receiver) => return batch_result(receiver).await,
+                    Batched::Opened(leader) => {
+                        let batch = leader.collect(window).await;
+                        let result = self
+                            .update_with_consistency_unbatched(
+                                batch.operation(),
+                                wait,
+                                timeout,
+                                ordering,
+                                update_only_existing,
+                                hw_measurement_acc.clone(),
+                            )
+                            .await;
+
+                        return match result {
+                            // One of the requests might be invalid, don't fail the others
+                            Err(err) if !err.is_transient() && batch.requests_count() > 1 => {
+                                log::debug!(
+                                    "Batch of {} updates failed, applying them one by one: {err}",
+                                    batch.requests_count(),
+                                );
+                                self.update_split_batch(
+                                    batch,
+                                    wait,
+                                    timeout,
+                                    ordering,
+                                    update_only_existing,
+                                    hw_measurement_acc,
+                                )
+                                .await
+                            }
+                            // Affected points are counted for the whole batch, not per request
+                            result => batch.notify(result.map(|result| UpdateResult {
+                                affected_points: None,
+                                ..result
+                            })),
+                        };
+                    }
+                    Batched::Rejected(operation) => operation,
+                }
+            }
+            None => operation,
+        };
+
+        self.update_with_consistency_unbatched(
+            operation,
+            wait,
+            timeout,
+            ordering,
+            update_only_existing,
+            hw_measurement_acc,
+        )
+        .await
+    }
+
+    /// Apply requests of a failed batch one by one, and report each its own result.
+    ///
+    /// Returns the result of the leader of the batch.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is *not* cancel safe.
+    async fn update_split_batch(
+        &self,
+        batch: CollectedBatch,
+        wait: bool,
+        timeout: Option<Duration>,
+        ordering: WriteOrdering,
+        update_only_existing: bool,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<UpdateResult> {
+        let mut leader_result = None;
+        for (operation, follower) in batch.split() {
+            let result = self
+                .update_with_consistency_unbatched(
+                    operation,
+                    wait,
+                    timeout,
+                    ordering,
+                    update_only_existing,
+                    hw_measurement_acc.clone(),
+                )
+                .await;
+            match follower {
+                Some(follower) => follower.notify(result),
+                None => leader_result = Some(result),
+            }
+        }
+        leader_result.expect("batch contains the request of its leader")
+    }
+
+    /// # Cancel safety
+    ///
+    /// This method is *not* cancel safe.
+    async fn update_with_consistency_unbatched(
         &self,
         operation: CollectionUpdateOperations,
         wait: bool,
@@ -207,7 +335,7 @@ impl ShardReplicaSet {
         }
     }
 
-    fn highest_alive_replica_peer_id(&self) -> Option<PeerId> {
+    pub(super) fn highest_alive_replica_peer_id(&self) -> Option<PeerId> {
         let read_lock = self.replica_state.read();
         let peer_ids = read_lock.peers().keys().cloned().collect::<Vec<_>>();
         drop(read_lock);
@@ -403,6 +531,17 @@ impl ShardReplicaSet {
             None => FuturesUnordered::from_iter(update_futures).collect().await,
         };
 
+        // Track replication lag, remotes which were not updated have missed this update
+        for remote in remotes.iter() {
+            let applied = all_res
+                .iter()
+                .any(|res| matches!(res, Ok((peer_id, _)) if *peer_id == remote.peer_id));
+
+            if !applied {
+                remote.replication.missed();
+            }
+        }
+
         drop(local);
         drop(remotes);
 
@@ -742,6 +881,7 @@ impl ShardReplicaSet {
     /// Pick a successful update result to return from a replica set.
     ///
     /// We pick the reply from the highest peer ID. This makes the returned response deterministic.
+    /// Number of affected points is taken from any replica which completed the operation.
     fn merge_successful_update_results(successes: &[(PeerId, UpdateResult)]) -> UpdateResult {
         debug_assert!(!successes.is_empty());
         debug_assert!(
@@ -765,9 +905,86 @@ impl ShardReplicaSet {
             .expect("successes is not empty");
 
         result.status = status;
+        result.affected_points = successes
+            .iter()
+            .filter_map(|(_, res)| res.affected_points)
+            .max();
         result
     }
 
+    /// Delete points of the shard, which are expired at `now`, if points expiration is enabled.
+    ///
+    /// Delete is applied through the regular update path, so it is written into the WAL and
+    /// replicated to all replicas. To avoid concurrent deletes of the same points, expired points
+    /// are only deleted by the highest alive replica, the same one used for `medium` ordering.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is *not* cancel safe.
+    pub async fn delete_expired_points(&self, now: DateTime<Utc>) -> CollectionResult<()> {
+        if self.highest_alive_replica_peer_id() != Some(self.this_peer_id()) {
+            return Ok(());
+        }
+
+        let expired = match self.local.read().await.deref() {
+            Some(Shard::Local(local)) => local.expired_condition(now).await,
+            _ => None,
+        };
+        let Some(expired) = expired else {
+            return Ok(());
+        };
+
+        self.delete_points_by_condition(expired).await?;
+
+        if let Some(Shard::Local(local)) = self.local.read().await.deref() {
+            local.invalidate_expiration_watermark();
+        }
+
+        Ok(())
+    }
+
+    /// Permanently delete points of the shard, which match the `purged` trash condition.
+    ///
+    /// Same as for expired points, the delete is replicated from the highest alive replica.
+    /// Nothing is written into the WAL, if the local replica has no such points.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is *not* cancel safe.
+    pub async fn purge_trash(&self, purged: Condition) -> CollectionResult<()> {
+        if self.highest_alive_replica_peer_id() != Some(self.this_peer_id()) {
+            return Ok(());
+        }
+
+        let has_purged_points = match self.local.read().await.deref() {
+            Some(Shard::Local(local)) => local.has_points_matching(purged.clone()),
+            _ => false,
+        };
+        if !has_purged_points {
+            return Ok(());
+        }
+
+        self.delete_points_by_condition(purged).await
+    }
+
+    /// Delete points matching `condition` through the regular update path, so it is written into
+    /// the WAL and replicated to all replicas.
+    async fn delete_points_by_condition(&self, condition: Condition) -> CollectionResult<()> {
+        let operation = CollectionUpdateOperations::PointOperation(
+            PointOperations::DeletePointsByFilter(Filter::new_must(condition)),
+        );
+        self.update_with_consistency(
+            operation,
+            true,
+            None,
+            WriteOrdering::Medium,
+            false,
+            HwMeasurementAcc::disposable(),
+        )
+        .await?;
+        Ok(())
+    }
+
     /// Send plunger operation
     ///
     /// Returns oneshot channel receiver that will be notified once the plunger operation is
@@ -817,6 +1034,7 @@ mod tests {
                 UpdateResult {
                     operation_id: Some(10),
                     status: UpdateStatus::Completed,
+                    affected_points: Some(3),
                     clock_tag: Some(local_tag),
                 },
             ),
@@ -825,6 +1043,7 @@ mod tests {
                 UpdateResult {
                     operation_id: Some(20),
                     status: UpdateStatus::WaitTimeout,
+                    affected_points: None,
                     clock_tag: Some(remote_tag),
                 },
             ),
@@ -835,6 +1054,8 @@ mod tests {
         assert_eq!(merged.status, UpdateStatus::WaitTimeout);
         assert_eq!(merged.operation_id, Some(20));
         assert_eq!(merged.clock_tag.unwrap().clock_tick, 12);
+        // affected points are known from the replica which completed the operation
+        assert_eq!(merged.affected_points, Some(3));
     }
 
     #[test]
@@ -850,6 +1071,7 @@ mod tests {
                 UpdateResult {
                     operation_id: Some(10),
                     status: UpdateStatus::Acknowledged,
+                    affected_points: None,
                     clock_tag: Some(local_tag),
                 },
             ),
@@ -858,6 +1080,7 @@ mod tests {
                 UpdateResult {
                     operation_id: Some(20),
                     status: UpdateStatus::Completed,
+                    affected_points: Some(3),
                     clock_tag: Some(remote_tag),
                 },
             ),
@@ -901,6 +1124,8 @@ mod tests {
         flush_interval_sec: 30,
         max_optimization_threads: Some(2),
         prevent_unoptimized: None,
+        max_builder_memory: None,
+        flush_max_dirty: None,
     };
 
     async fn new_shard_replica_set(collection_dir: &TempDir) -> ShardReplicaSet {
@@ -911,6 +1136,8 @@ mod tests {
             wal_capacity_mb: 1,
             wal_segments_ahead: 0,
             wal_retain_closed: 1,
+            wal_batch_window_ms: 0,
+            wal_batch_max_points: 256,
         };
 
         let collection_params = CollectionParams {
@@ -0,0 +1,389 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tokio::sync::{Notify, oneshot};
+
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, WriteOrdering,
+};
+use crate::operations::types::{CollectionError, CollectionResult, UpdateResult};
+
+type BatchResultSender = oneshot::Sender<CollectionResult<UpdateResult>>;
+
+/// Parameters of an update, which must be the same for all upserts coalesced into one batch
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) struct BatchKey {
+    pub wait: bool,
+    pub timeout: Option<Duration>,
+    pub ordering: WriteOrdering,
+    pub update_only_existing: bool,
+}
+
+struct PendingBatch {
+    key: BatchKey,
+    /// Points of each request in the batch, the first one is of the leader
+    requests: Vec<Vec<PointStructPersisted>>,
+    /// Requests, which joined the batch after it was opened
+    followers: Vec<BatchResultSender>,
+    /// Notified once the batch reaches the maximal size
+    full: Arc<Notify>,
+}
+
+impl PendingBatch {
+    fn points_count(&self) -> usize {
+        self.requests.iter().map(Vec::len).sum()
+    }
+}
+
+/// Coalesces small concurrent upserts into a shard into a single update operation.
+///
+/// At most one batch is collected at a time. The request which opens the batch waits for the
+/// batch window to pass, or for the batch to get full, and then applies all collected points as
+/// one operation, which results in a single WAL record and a single segment update pass.
+/// Requests joining the batch receive the result of this operation.
+///
+/// Points of later requests override points of earlier ones with the same ID, same as within a
+/// single upsert operation.
+///
+/// Requests are usually unrelated to each other, so if the batch fails with a non-transient
+/// error, e.g. because of an invalid point, requests of the batch are applied one by one, and
+/// each receives its own result. See [`CollectedBatch`].
+#[derive(Default)]
+pub(super) struct UpdateBatcher {
+    pending: Mutex<Option<PendingBatch>>,
+}
+
+pub(super) enum Batched<'a> {
+    /// Operation was added to the batch opened by another request
+    Joined(oneshot::Receiver<CollectionResult<UpdateResult>>),
+    /// Operation opened a new batch, which must be applied by the caller
+    Opened(BatchLeader<'a>),
+    /// Operation can't be batched and must be applied on its own
+    Rejected(CollectionUpdateOperations),
+}
+
+impl UpdateBatcher {
+    /// Try to add `operation` to a batch.
+    ///
+    /// Only plain upserts of less than `max_points` points are batched.
+    pub fn add(
+        &self,
+        operation: CollectionUpdateOperations,
+        key: BatchKey,
+        max_points: usize,
+    ) -> Batched<'_> {
+        let points = match operation {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsList(points),
+            )) if points.len() < max_points => points,
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsBatch(batch),
+            )) if batch.ids.len() < max_points => Vec::from(batch),
+            operation => return Batched::Rejected(operation),
+        };
+
+        let mut pending = self.pending.lock();
+
+        match pending.as_mut() {
+            Some(batch)
+                if batch.key == key && batch.points_count() + points.len() <= max_points =>
+            {
+                batch.requests.push(points);
+                if batch.points_count() >= max_points {
+                    batch.full.notify_one();
+                }
+
+                let (sender, receiver) = oneshot::channel();
+                batch.followers.push(sender);
+                Batched::Joined(receiver)
+            }
+            // Another batch is being collected, don't wait for it
+            Some(_) => Batched::Rejected(upsert_operation(points)),
+            None => {
+                let full = Arc::new(Notify::new());
+                *pending = Some(PendingBatch {
+                    key,
+                    requests: vec![points],
+                    followers: Vec::new(),
+                    full: full.clone(),
+                });
+                Batched::Opened(BatchLeader {
+                    batcher: self,
+                    full,
+                    taken: false,
+                })
+            }
+        }
+    }
+}
+
+/// Handle of the request, which opened the pending batch
+///
+/// If dropped before the batch is taken, the batch is discarded and requests waiting for it
+/// receive an error.
+pub(super) struct BatchLeader<'a> {
+    batcher: &'a UpdateBatcher,
+    full: Arc<Notify>,
+    taken: bool,
+}
+
+impl BatchLeader<'_> {
+    /// Wait until the batch window passes or the batch gets full, and take the collected batch.
+    pub async fn collect(mut self, window: Duration) -> CollectedBatch {
+        tokio::select! {
+            _ = tokio::time::sleep(window) => {}
+            _ = self.full.notified() => {}
+        }
+
+        let batch = self
+            .take()
+            .expect("pending batch is taken by its leader only");
+
+        CollectedBatch {
+            requests: batch.requests,
+            followers: batch.followers,
+        }
+    }
+
+    fn take(&mut self) -> Option<PendingBatch> {
+        if self.taken {
+            return None;
+        }
+        self.taken = true;
+        self.batcher.pending.lock().take()
+    }
+}
+
+impl Drop for BatchLeader<'_> {
+    fn drop(&mut self) {
+        if let Some(batch) = self.take() {
+            log::debug!(
+                "Discarding batch of {} points, which was not applied",
+                batch.points_count(),
+            );
+        }
+    }
+}
+
+/// Batch, taken by its leader to be applied
+pub(super) struct CollectedBatch {
+    /// Points of each request in the batch, the first one is of the leader
+    requests: Vec<Vec<PointStructPersisted>>,
+    /// Requests, which joined the batch, in the order of `requests[1..]`
+    followers: Vec<BatchResultSender>,
+}
+
+impl CollectedBatch {
+    /// Number of requests in the batch, including the leader
+    pub fn requests_count(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Single operation upserting points of all requests.
+    ///
+    /// Points are copied, so that requests can be applied one by one with [`Self::split`] if
+    /// the operation fails.
+    pub fn operation(&self) -> CollectionUpdateOperations {
+        upsert_operation(self.requests.iter().flatten().cloned().collect())
+    }
+
+    /// Report result of the whole batch to all requests which joined it, and return the result
+    /// for the leader
+    pub fn notify(self, result: CollectionResult<UpdateResult>) -> CollectionResult<UpdateResult> {
+        for follower in self.followers {
+            send_result(follower, result.clone());
+        }
+        result
+    }
+
+    /// Operations of individual requests of the batch, in the order they joined it.
+    ///
+    /// The operation of the leader comes first, without a follower to report its result to.
+    pub fn split(
+        self,
+    ) -> impl Iterator<Item = (CollectionUpdateOperations, Option<BatchFollower>)> {
+        let followers = std::iter::once(None).chain(self.followers.into_iter().map(Some));
+        self.requests
+            .into_iter()
+            .map(upsert_operation)
+            .zip(followers.map(|follower| follower.map(BatchFollower)))
+    }
+}
+
+/// Request, which joined a batch, applied on its own after the batch failed
+pub(super) struct BatchFollower(BatchResultSender);
+
+impl BatchFollower {
+    pub fn notify(self, result: CollectionResult<UpdateResult>) {
+        send_result(self.0, result);
+    }
+}
+
+fn send_result(follower: BatchResultSender, result: CollectionResult<UpdateResult>) {
+    follower.send(result).unwrap_or_else(|_| {
+        log::debug!("Can't report batched update result, assume already not required");
+    });
+}
+
+/// Receive result of the batch, which the operation joined
+pub(super) async fn batch_result(
+    receiver: oneshot::Receiver<CollectionResult<UpdateResult>>,
+) -> CollectionResult<UpdateResult> {
+    receiver.await.unwrap_or_else(|_| {
+        Err(CollectionError::service_error(
+            "Batched update was cancelled before being applied",
+        ))
+    })
+}
+
+fn upsert_operation(points: Vec<PointStructPersisted>) -> CollectionUpdateOperations {
+    CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+        PointInsertOperationsInternal::PointsList(points),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::ExtendedPointId;
+    use shard::operations::point_ops::{
+        BatchPersisted, BatchVectorStructPersisted, VectorStructPersisted,
+    };
+
+    use super::*;
+    use crate::operations::types::UpdateStatus;
+
+    const KEY: BatchKey = BatchKey {
+        wait: true,
+        timeout: None,
+        ordering: WriteOrdering::Weak,
+        update_only_existing: false,
+    };
+
+    fn upsert(ids: impl IntoIterator<Item = u64>) -> CollectionUpdateOperations {
+        upsert_operation(
+            ids.into_iter()
+                .map(|id| PointStructPersisted {
+                    id: ExtendedPointId::NumId(id),
+                    vector: VectorStructPersisted::Single(vec![id as f32]),
+                    payload: None,
+                })
+                .collect(),
+        )
+    }
+
+    fn point_ids(operation: &CollectionUpdateOperations) -> Vec<ExtendedPointId> {
+        match operation {
+            CollectionUpdateOperations::PointOperation(operation) => operation.point_ids().unwrap(),
+            _ => panic!("unexpected operation"),
+        }
+    }
+
+    fn update_result(operation_id: u64) -> UpdateResult {
+        UpdateResult {
+            operation_id: Some(operation_id),
+            status: UpdateStatus::Completed,
+            affected_points: None,
+            clock_tag: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_batcher() {
+        let batcher = UpdateBatcher::default();
+
+        let Batched::Opened(leader) = batcher.add(upsert([1]), KEY, 4) else {
+            panic!("first upsert must open a batch");
+        };
+        let Batched::Joined(follower) = batcher.add(upsert([2, 3]), KEY, 4) else {
+            panic!("upsert must join the pending batch");
+        };
+
+        // Different parameters and too large upserts are not batched
+        let other_key = BatchKey { wait: false, ..KEY };
+        assert!(matches!(
+            batcher.add(upsert([4]), other_key, 4),
+            Batched::Rejected(_),
+        ));
+        assert!(matches!(
+            batcher.add(upsert([4, 5, 6, 7]), KEY, 4),
+            Batched::Rejected(_),
+        ));
+
+        // Batch is full, so it's collected without waiting for the window
+        let Batched::Joined(_) = batcher.add(upsert([4]), KEY, 4) else {
+            panic!("upsert must join the pending batch");
+        };
+        let batch = leader.collect(Duration::from_secs(3600)).await;
+        assert_eq!(batch.requests_count(), 3);
+        assert_eq!(
+            point_ids(&batch.operation()),
+            (1..=4).map(ExtendedPointId::NumId).collect::<Vec<_>>(),
+        );
+
+        let result = batch.notify(Ok(update_result(1)));
+        assert_eq!(result.unwrap().operation_id, Some(1));
+        assert_eq!(batch_result(follower).await.unwrap().operation_id, Some(1));
+
+        // Dropped leader discards its batch
+        let Batched::Opened(leader) = batcher.add(upsert([1]), KEY, 4) else {
+            panic!("upsert must open a new batch");
+        };
+        let Batched::Joined(follower) = batcher.add(upsert([2]), KEY, 4) else {
+            panic!("upsert must join the pending batch");
+        };
+        drop(leader);
+        assert!(batch_result(follower).await.is_err());
+        assert!(matches!(
+            batcher.add(upsert([1]), KEY, 4),
+            Batched::Opened(_),
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_split_batch() {
+        let batcher = UpdateBatcher::default();
+
+        let Batched::Opened(leader) = batcher.add(upsert([1]), KEY, 8) else {
+            panic!("first upsert must open a batch");
+        };
+        // Upserts in the batch format are batched too
+        let batch_upsert =
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsBatch(BatchPersisted {
+                    ids: vec![ExtendedPointId::NumId(2), ExtendedPointId::NumId(3)],
+                    vectors: BatchVectorStructPersisted::Single(vec![vec![2.0], vec![3.0]]),
+                    payloads: None,
+                }),
+            ));
+        let Batched::Joined(first) = batcher.add(batch_upsert, KEY, 8) else {
+            panic!("batch upsert must join the pending batch");
+        };
+        let Batched::Joined(second) = batcher.add(upsert([4]), KEY, 8) else {
+            panic!("upsert must join the pending batch");
+        };
+
+        let batch = leader.collect(Duration::ZERO).await;
+        assert_eq!(
+            point_ids(&batch.operation()),
+            (1..=4).map(ExtendedPointId::NumId).collect::<Vec<_>>(),
+        );
+
+        // Each request gets the result of its own operation
+        let mut leader_ids = None;
+        for (operation_id, (operation, follower)) in (1..).zip(batch.split()) {
+            match follower {
+                Some(follower) if operation_id == 2 => {
+                    follower.notify(Err(CollectionError::bad_input("invalid point")));
+                }
+                Some(follower) => follower.notify(Ok(update_result(operation_id))),
+                None => leader_ids = Some(point_ids(&operation)),
+            }
+        }
+
+        assert_eq!(leader_ids, Some(vec![ExtendedPointId::NumId(1)]));
+        assert!(batch_result(first).await.is_err());
+        assert_eq!(batch_result(second).await.unwrap().operation_id, Some(3));
+    }
+}
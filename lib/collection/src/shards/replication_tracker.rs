@@ -0,0 +1,123 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use parking_lot::Mutex;
+
+use crate::shards::replica_set::replica_set_state::ReplicaState;
+use crate::shards::telemetry::ReplicationTelemetry;
+
+/// Tracks update traffic sent to a remote replica and how far behind the replica is.
+///
+/// Lag is observed from the perspective of this peer. It only accounts updates of the shard,
+/// which were coordinated by this peer, but were not applied on the remote replica.
+///
+/// Missed updates are never applied later by the update path, so the replica stays behind until
+/// it is recovered by a shard transfer and becomes active again.
+#[derive(Clone, Debug, Default)]
+pub struct ReplicationTracker {
+    stats: Arc<Mutex<ReplicationStats>>,
+}
+
+#[derive(Debug, Default)]
+struct ReplicationStats {
+    sent_requests: u64,
+    sent_bytes: u64,
+    /// Updates missed by the replica since it was up to date the last time
+    missed_updates: u64,
+    /// Time since the replica is behind, either because it missed an update or was marked dead
+    behind_since: Option<Instant>,
+}
+
+impl ReplicationTracker {
+    /// Account an update request of the given size, sent to the replica
+    pub fn sent(&self, bytes: usize) {
+        let mut stats = self.stats.lock();
+        stats.sent_requests += 1;
+        stats.sent_bytes += bytes as u64;
+    }
+
+    /// Replica missed an update, because it failed to apply it or was not able to receive it
+    pub fn missed(&self) {
+        let mut stats = self.stats.lock();
+        stats.missed_updates += 1;
+        stats.behind_since.get_or_insert_with(Instant::now);
+    }
+
+    /// Track the state of the replica.
+    ///
+    /// A dead replica is behind from the moment it is marked dead, even if no update was missed
+    /// yet. An active replica has been recovered, and is up to date.
+    pub fn state_updated(&self, state: ReplicaState) {
+        let mut stats = self.stats.lock();
+        if state.requires_recovery() {
+            stats.behind_since.get_or_insert_with(Instant::now);
+        } else if state.is_active() {
+            stats.missed_updates = 0;
+            stats.behind_since = None;
+        }
+    }
+
+    pub fn get_telemetry_data(&self) -> ReplicationTelemetry {
+        let stats = self.stats.lock();
+        ReplicationTelemetry {
+            sent_requests: stats.sent_requests,
+            sent_bytes: stats.sent_bytes,
+            lag_operations: stats.missed_updates,
+            lag_seconds: stats
+                .behind_since
+                .map_or(0.0, |since| since.elapsed().as_secs_f64()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replication_lag() {
+        let tracker = ReplicationTracker::default();
+        tracker.sent(100);
+
+        let telemetry = tracker.get_telemetry_data();
+        assert_eq!(telemetry.sent_requests, 1);
+        assert_eq!(telemetry.sent_bytes, 100);
+        assert_eq!(telemetry.lag_operations, 0);
+        assert_eq!(telemetry.lag_seconds, 0.0);
+
+        tracker.sent(50);
+        tracker.missed();
+        tracker.missed();
+
+        let telemetry = tracker.get_telemetry_data();
+        assert_eq!(telemetry.sent_requests, 2);
+        assert_eq!(telemetry.sent_bytes, 150);
+        assert_eq!(telemetry.lag_operations, 2);
+
+        // Replica is still behind while it is recovered
+        tracker.state_updated(ReplicaState::Dead);
+        tracker.state_updated(ReplicaState::Partial);
+        tracker.sent(50);
+        let telemetry = tracker.get_telemetry_data();
+        assert_eq!(telemetry.lag_operations, 2);
+        assert!(tracker.stats.lock().behind_since.is_some());
+
+        tracker.state_updated(ReplicaState::Active);
+        let telemetry = tracker.get_telemetry_data();
+        assert_eq!(telemetry.lag_operations, 0);
+        assert_eq!(telemetry.lag_seconds, 0.0);
+    }
+
+    #[test]
+    fn test_dead_replica_lag() {
+        let tracker = ReplicationTracker::default();
+
+        // Dead replica is behind, even before it misses any update
+        tracker.state_updated(ReplicaState::Dead);
+        assert_eq!(tracker.get_telemetry_data().lag_operations, 0);
+        assert!(tracker.stats.lock().behind_since.is_some());
+
+        tracker.missed();
+        assert_eq!(tracker.get_telemetry_data().lag_operations, 1);
+    }
+}
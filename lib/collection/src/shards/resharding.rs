@@ -16,6 +16,8 @@ pub struct ReshardState {
     pub shard_key: Option<ShardKey>,
     pub direction: ReshardingDirection,
     pub stage: ReshardingStage,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<ReshardTarget>,
 }
 
 impl ReshardState {
@@ -25,6 +27,7 @@ impl ReshardState {
         peer_id: PeerId,
         shard_id: ShardId,
         shard_key: Option<ShardKey>,
+        target: Option<ReshardTarget>,
     ) -> Self {
         Self {
             uuid,
@@ -33,6 +36,7 @@ impl ReshardState {
             shard_id,
             shard_key,
             stage: ReshardingStage::MigratingPoints,
+            target,
         }
     }
 
@@ -51,6 +55,7 @@ impl ReshardState {
             peer_id: self.peer_id,
             shard_id: self.shard_id,
             shard_key: self.shard_key.clone(),
+            target: self.target,
         }
     }
 }
@@ -79,6 +84,22 @@ pub struct ReshardKey {
     pub peer_id: PeerId,
     pub shard_id: ShardId,
     pub shard_key: Option<ShardKey>,
+    /// Not part of the identity, not compared by `ReshardState::matches`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(skip)]
+    pub target: Option<ReshardTarget>,
+}
+
+/// Number of shards to reach by consecutive resharding operations, each adding or removing one
+/// shard
+///
+/// Persisted in consensus together with the ongoing resharding operation, so that the peer driving
+/// them can resume after restart.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct ReshardTarget {
+    pub shard_number: u32,
+    /// Peer driving resharding operations
+    pub driver_peer_id: PeerId,
 }
 
 impl fmt::Display for ReshardKey {
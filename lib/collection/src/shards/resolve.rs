@@ -4,6 +4,7 @@ use std::iter::Peekable;
 use std::rc::Rc;
 
 use itertools::Itertools;
+use segment::data_types::aggregation::AggregateResult;
 use segment::data_types::facets::{FacetResponse, FacetValue};
 use segment::types::{Payload, ScoredPoint};
 use shard::retrieve::record_internal::RecordInternal;
@@ -57,6 +58,23 @@ impl Resolve for CountResult {
     }
 }
 
+impl Resolve for AggregateResult {
+    /// Select the result aggregated over the fewest values for `All`, or the median for `Majority`
+    fn resolve(mut results: Vec<Self>, condition: ResolveCondition) -> Self {
+        results.sort_unstable_by_key(|result| result.count);
+        let index = match condition {
+            ResolveCondition::All => 0,
+            ResolveCondition::Majority => results.len() / 2,
+        };
+
+        if index < results.len() {
+            results.swap_remove(index)
+        } else {
+            Self::default()
+        }
+    }
+}
+
 impl Resolve for FacetResponse {
     /// Resolve the counts for each value using the CountResult implementation
     fn resolve(responses: Vec<Self>, condition: ResolveCondition) -> Self {
@@ -133,6 +151,20 @@ impl Resolve for FacetResponse {
     }
 }
 
+impl Resolve for Vec<FacetResponse> {
+    fn resolve(batches: Vec<Self>, condition: ResolveCondition) -> Self {
+        // batches: <replica_id, <key_id, FacetResponse>>
+        // transpose to <key_id, <replica_id, FacetResponse>>
+
+        let batches = transposed_iter(batches);
+
+        batches
+            .into_iter()
+            .map(|responses| FacetResponse::resolve(responses, condition))
+            .collect()
+    }
+}
+
 impl Resolve for Vec<RecordInternal> {
     fn resolve(records: Vec<Self>, condition: ResolveCondition) -> Self {
         Resolver::resolve(records, |record| record.id, record_eq, condition)
@@ -10,7 +10,7 @@ use common::types::TelemetryDetail;
 use futures::future::Either;
 use parking_lot::Mutex as ParkingMutex;
 use segment::index::field_index::CardinalityEstimation;
-use segment::types::{Filter, SeqNumberType, SizeStats, SnapshotFormat};
+use segment::types::{Filter, SeqNumberType, SizeStats, SnapshotFormat, VectorName};
 use shard::snapshots::snapshot_manifest::SnapshotManifest;
 use tokio::sync::oneshot;
 
@@ -261,6 +261,58 @@ impl Shard {
         })
     }
 
+    pub fn cancel_optimizations(&self, optimizer: Option<&str>) -> CollectionResult<usize> {
+        match self {
+            Self::Local(local_shard) => local_shard.cancel_optimizations(optimizer),
+            Self::Proxy(proxy_shard) => proxy_shard.wrapped_shard.cancel_optimizations(optimizer),
+            Self::ForwardProxy(proxy_shard) => {
+                proxy_shard.wrapped_shard.cancel_optimizations(optimizer)
+            }
+            Self::QueueProxy(proxy_shard) => match proxy_shard.wrapped_shard() {
+                Some(local_shard) => local_shard.cancel_optimizations(optimizer),
+                None => Ok(0),
+            },
+            Self::Dummy(_) => Ok(0),
+        }
+    }
+
+    pub fn resume_optimizations(&self) {
+        match self {
+            Self::Local(local_shard) => local_shard.resume_optimizations(),
+            Self::Proxy(proxy_shard) => proxy_shard.wrapped_shard.resume_optimizations(),
+            Self::ForwardProxy(proxy_shard) => proxy_shard.wrapped_shard.resume_optimizations(),
+            Self::QueueProxy(proxy_shard) => {
+                if let Some(local_shard) = proxy_shard.wrapped_shard() {
+                    local_shard.resume_optimizations();
+                }
+            }
+            Self::Dummy(_) => {}
+        }
+    }
+
+    pub async fn pin_vector(&self, vector_name: &VectorName, pinned: bool) -> CollectionResult<()> {
+        match self {
+            Self::Local(local_shard) => local_shard.pin_vector(vector_name, pinned).await,
+            Self::Proxy(proxy_shard) => {
+                proxy_shard
+                    .wrapped_shard
+                    .pin_vector(vector_name, pinned)
+                    .await
+            }
+            Self::ForwardProxy(proxy_shard) => {
+                proxy_shard
+                    .wrapped_shard
+                    .pin_vector(vector_name, pinned)
+                    .await
+            }
+            Self::QueueProxy(proxy_shard) => match proxy_shard.wrapped_shard() {
+                Some(local_shard) => local_shard.pin_vector(vector_name, pinned).await,
+                None => Ok(()),
+            },
+            Self::Dummy(_) => Ok(()),
+        }
+    }
+
     pub async fn truncate_unapplied_wal(&self) -> CollectionResult<usize> {
         match self {
             Self::Local(local_shard) => local_shard.truncate_unapplied_wal().await,
@@ -279,6 +331,17 @@ impl Shard {
         }
     }
 
+    /// Time the oldest update, received by the local shard, is waiting to be applied.
+    ///
+    /// Returns `None` if it is unknown, e.g. while the shard is being transferred.
+    pub fn applied_lag(&self) -> Option<Duration> {
+        match self {
+            Self::Local(local_shard) => local_shard.applied_lag(),
+            Self::ForwardProxy(proxy_shard) => proxy_shard.wrapped_shard.applied_lag(),
+            Self::Proxy(_) | Self::QueueProxy(_) | Self::Dummy(_) => None,
+        }
+    }
+
     pub async fn shard_recovery_point(&self) -> CollectionResult<RecoveryPoint> {
         match self {
             Self::Local(local_shard) => Ok(local_shard.recovery_point().await),
@@ -293,6 +356,22 @@ impl Shard {
         }
     }
 
+    pub async fn applied_recovery_point(&self) -> CollectionResult<RecoveryPoint> {
+        match self {
+            Self::Local(local_shard) => local_shard.applied_recovery_point().await,
+            Self::ForwardProxy(proxy_shard) => {
+                proxy_shard.wrapped_shard.applied_recovery_point().await
+            }
+
+            Self::Proxy(_) | Self::QueueProxy(_) | Self::Dummy(_) => {
+                Err(CollectionError::service_error(format!(
+                    "Recovery point not supported on {}",
+                    self.variant_name(),
+                )))
+            }
+        }
+    }
+
     pub async fn take_newest_clocks_snapshot(&self) -> CollectionResult<()> {
         match self {
             Self::Local(local_shard) => local_shard.take_newest_clocks_snapshot().await,
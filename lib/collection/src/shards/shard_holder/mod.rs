@@ -589,6 +589,7 @@ impl ShardHolder {
             peer_id: resharding_state.peer_id,
             direction: resharding_state.direction,
             shard_key: resharding_state.shard_key.clone(),
+            target_shard_number: resharding_state.target.map(|target| target.shard_number),
             stage: resharding_state.stage,
         });
 
@@ -31,6 +31,7 @@ impl ShardHolder {
             peer_id: _,
             shard_id,
             shard_key,
+            target: _,
         } = resharding_key;
 
         // Additional shard key check
@@ -126,6 +127,7 @@ impl ShardHolder {
             peer_id,
             shard_id,
             shard_key,
+            target,
         } = resharding_key;
 
         // TODO(resharding): Delete shard on error!?
@@ -156,7 +158,7 @@ impl ShardHolder {
             );
 
             *state = Some(ReshardState::new(
-                uuid, direction, peer_id, shard_id, shard_key,
+                uuid, direction, peer_id, shard_id, shard_key, target,
             ));
         })?;
 
@@ -307,6 +309,7 @@ impl ShardHolder {
             peer_id: _,
             shard_id,
             ref shard_key,
+            target: _,
         } = resharding_key;
 
         // Cleanup existing shards if resharding down
@@ -3,7 +3,10 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use segment::data_types::facets::{FacetParams, FacetResponse};
+use futures::future::try_join_all;
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
+use segment::data_types::facets::{FacetParams, FacetResponse, MultiFacetParams};
+use segment::data_types::query_context::SearchExplanations;
 use segment::types::*;
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
@@ -11,6 +14,7 @@ use shard::scroll::ScrollRequestInternal;
 use shard::search::CoreSearchRequestBatch;
 use tokio::runtime::Handle;
 
+use crate::collection::partial_results::TimedOut;
 use crate::operations::OperationWithClockTag;
 use crate::operations::types::*;
 use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
@@ -77,11 +81,18 @@ pub trait ShardOperation {
         hardware_accumulator: HwMeasurementAcc,
     ) -> CollectionResult<Vec<RecordInternal>>;
 
+    /// If `explanations` is given, explanations of how segments executed the searches are
+    /// collected there.
+    ///
+    /// If `timed_out` is given, searches which allow partial results return results found so far
+    /// at the deadline, and are marked as timed out there.
     async fn query_batch(
         &self,
         requests: Arc<Vec<ShardQueryRequest>>,
         search_runtime_handle: &Handle,
         timeout: Option<Duration>,
+        explanations: Option<&SearchExplanations>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>>;
 
@@ -93,6 +104,36 @@ pub trait ShardOperation {
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<FacetResponse>;
 
+    /// Facet counts for each key of the request, in the same order as the keys.
+    ///
+    /// By default, makes a separate facet request for each key.
+    async fn facets(
+        &self,
+        request: Arc<MultiFacetParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<FacetResponse>> {
+        try_join_all(request.facet_params().map(|params| {
+            self.facet(
+                Arc::new(params),
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+        }))
+        .await
+    }
+
+    /// Aggregate values of the key over the filtered points.
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateParams>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<AggregateResult>;
+
     /// Signal `Stop` to all background operations gracefully
     /// and wait till they are finished.
     async fn stop_gracefully(self);
@@ -33,6 +33,22 @@ pub struct RemoteShardTelemetry {
     pub peer_id: PeerId,
     pub searches: Option<OperationDurationStatistics>,
     pub updates: Option<OperationDurationStatistics>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replication: Option<ReplicationTelemetry>,
+}
+
+/// Update traffic and lag of a remote replica, as observed by this peer
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize, Default, PartialEq)]
+#[anonymize(false)]
+pub struct ReplicationTelemetry {
+    /// Number of update requests sent to the replica since the last start
+    pub sent_requests: u64,
+    /// Total size of update requests sent to the replica since the last start, in bytes
+    pub sent_bytes: u64,
+    /// Number of updates the replica missed since it was up to date the last time
+    pub lag_operations: u64,
+    /// Seconds since the replica is behind, because it missed an update or was marked dead, zero if it is up to date
+    pub lag_seconds: f64,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize, Default)]
@@ -42,6 +58,8 @@ pub struct LocalShardTelemetry {
     pub status: Option<ShardStatus>,
     /// Total number of optimized points since the last start.
     pub total_optimized_points: usize,
+    /// Number of read requests served since the last start.
+    pub read_requests: usize,
     /// An ESTIMATION of effective amount of bytes used for vectors
     /// Do NOT rely on this number unless you know what you are doing
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -47,11 +47,12 @@ pub async fn transfer_shard(
     let remote_shard_id = transfer_config.to_shard_id.unwrap_or(local_shard_id);
 
     // Initiate shard on a remote peer
+    // Transfer traffic goes through its own channels, so it doesn't delay internal searches
     let remote_shard = RemoteShard::new(
         remote_shard_id,
         collection_id.clone(),
         transfer_config.to,
-        channel_service.clone(),
+        channel_service.transfer_lane(),
     );
 
     // Prepare the remote for receiving the shard, waits for the correct state on the remote
@@ -17,6 +17,9 @@ use crate::shards::replica_set::replica_set_state::ReplicaState;
 use crate::shards::shard::ShardId;
 use crate::shards::shard_holder::SharedShardHolder;
 
+/// Resumable download of the shard snapshot file with checksum verification is introduced in 1.17.
+const RESUMABLE_SNAPSHOT_MIN_VERSION: Version = Version::new(1, 17, 0);
+
 /// Orchestrate shard snapshot transfer
 ///
 /// This is called on the sender and will arrange all that is needed for the shard snapshot
@@ -43,6 +46,8 @@ use crate::shards::shard_holder::SharedShardHolder;
 ///   the shard into the same state on the remote.
 /// - Recover shard snapshot on remote
 ///   Instruct the remote to download the snapshot from this node over HTTP, then recover it.
+///   If supported by the remote, the snapshot file is downloaded in chunks and resumed after
+///   connection failures. The checksum of the whole file is verified before recovery.
 /// - Set shard state to `Partial`
 ///   After recovery, we set the shard state from `PartialSnapshot` to `Partial`. We propose an
 ///   operation to consensus for this. Our logic explicitly confirms that the remote reaches the
@@ -193,10 +198,15 @@ pub(super) async fn transfer_snapshot(
         "Local shard must be a queue proxy",
     );
 
+    // Resumable download requires a snapshot file, which doesn't change between requests
+    let use_resumable_download =
+        channel_service.peer_is_at_version(remote_peer_id, &RESUMABLE_SNAPSHOT_MIN_VERSION);
+
     // The ability to read streaming snapshot format is introduced in 1.12 (#5179).
-    let use_streaming_endpoint =
-        channel_service.peer_is_at_version(remote_peer_id, &Version::new(1, 12, 0));
+    let use_streaming_endpoint = !use_resumable_download
+        && channel_service.peer_is_at_version(remote_peer_id, &Version::new(1, 12, 0));
 
+    let mut snapshot_checksum = None;
     let mut snapshot_temp_paths = Vec::new();
     let mut shard_download_url = local_rest_address;
 
@@ -230,6 +240,10 @@ pub(super) async fn transfer_snapshot(
         snapshot_temp_paths.push(snapshot_temp_path);
         snapshot_temp_paths.push(snapshot_checksum_temp_path);
 
+        if use_resumable_download {
+            snapshot_checksum = snapshot_description.checksum.clone();
+        }
+
         let encoded_snapshot_name = urlencoding::encode(&snapshot_description.name);
 
         shard_download_url.set_path(&format!(
@@ -253,6 +267,7 @@ pub(super) async fn transfer_snapshot(
             shard_id,
             &shard_download_url,
             SnapshotPriority::ShardTransfer,
+            snapshot_checksum.as_deref(),
             // Provide API key here so the remote can access our snapshot
             local_api_key,
         )
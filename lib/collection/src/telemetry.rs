@@ -41,6 +41,11 @@ pub struct CollectionTelemetry {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
     pub shard_clean_tasks: Option<HashMap<ShardId, ShardCleanStatusTelemetry>>,
+
+    /// Number of upserted points detected as duplicates of existing points
+    #[anonymize(false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicates_detected: Option<usize>,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
@@ -194,7 +199,7 @@ mod internal_conversions {
     use super::*;
     use crate::operations::cluster_ops::ReshardingDirection;
     use crate::shards::resharding::ReshardingStage;
-    use crate::shards::telemetry::RemoteShardTelemetry;
+    use crate::shards::telemetry::{RemoteShardTelemetry, ReplicationTelemetry};
     use crate::shards::transfer::ShardTransferMethod;
 
     impl TryFrom<grpc::ShardTransferTelemetry> for ShardTransferInfo {
@@ -279,6 +284,7 @@ mod internal_conversions {
                 shard_id: value.shard_id,
                 peer_id: value.peer_id,
                 shard_key: convert_shard_key_from_grpc_opt(value.shard_key),
+                target_shard_number: None,
                 stage: ReshardingStage::from(
                     grpc::ReshardingStage::try_from(value.stage).map_err(|err| {
                         Status::invalid_argument(format!("cannot decode ReshardingStage {err}"))
@@ -409,6 +415,7 @@ mod internal_conversions {
                 transfers,
                 resharding,
                 shard_clean_tasks,
+                duplicates_detected: None, // Not provided in internal service
             })
         }
     }
@@ -423,6 +430,7 @@ mod internal_conversions {
                 transfers,
                 resharding,
                 shard_clean_tasks,
+                duplicates_detected: _,
             } = value;
 
             grpc::CollectionTelemetry {
@@ -489,6 +497,7 @@ mod internal_conversions {
                 variant_name: _, // not included in grpc
                 status,
                 total_optimized_points,
+                read_requests,
                 vectors_size_bytes,
                 payloads_size_bytes,
                 num_points,
@@ -504,6 +513,7 @@ mod internal_conversions {
             grpc::LocalShardTelemetry {
                 status: status.map(|s| grpc::ShardStatus::from(s) as i32),
                 total_optimized_points: total_optimized_points as u64,
+                read_requests: read_requests as u64,
                 vectors_size_bytes: vectors_size_bytes.map(|v| v as u64),
                 payloads_size_bytes: payloads_size_bytes.map(|v| v as u64),
                 num_points: num_points.map(|v| v as u64),
@@ -541,8 +551,31 @@ mod internal_conversions {
                 peer_id,
                 searches: _, // not included in grpc
                 updates: _,  // not included in grpc
+                replication,
             } = value;
-            grpc::RemoteShardTelemetry { shard_id, peer_id }
+            grpc::RemoteShardTelemetry {
+                shard_id,
+                peer_id,
+                replication: replication.map(grpc::ReplicationTelemetry::from),
+            }
+        }
+    }
+
+    impl From<ReplicationTelemetry> for grpc::ReplicationTelemetry {
+        fn from(value: ReplicationTelemetry) -> Self {
+            let ReplicationTelemetry {
+                sent_requests,
+                sent_bytes,
+                lag_operations,
+                lag_seconds,
+            } = value;
+
+            grpc::ReplicationTelemetry {
+                sent_requests,
+                sent_bytes,
+                lag_operations,
+                lag_seconds,
+            }
         }
     }
 
@@ -635,6 +668,7 @@ mod internal_conversions {
                 num_vectors,
                 num_vectors_by_name,
                 indexed_only_excluded_vectors,
+                read_requests,
             } = value;
 
             Ok(LocalShardTelemetry {
@@ -647,6 +681,7 @@ mod internal_conversions {
                     })?
                     .map(ShardStatus::from),
                 total_optimized_points: total_optimized_points as usize,
+                read_requests: read_requests as usize,
                 vectors_size_bytes: vectors_size_bytes.map(|v| v as usize),
                 payloads_size_bytes: payloads_size_bytes.map(|v| v as usize),
                 num_points: num_points.map(|v| v as usize),
@@ -686,13 +721,36 @@ mod internal_conversions {
 
     impl From<grpc::RemoteShardTelemetry> for RemoteShardTelemetry {
         fn from(value: grpc::RemoteShardTelemetry) -> Self {
-            let grpc::RemoteShardTelemetry { shard_id, peer_id } = value;
+            let grpc::RemoteShardTelemetry {
+                shard_id,
+                peer_id,
+                replication,
+            } = value;
 
             RemoteShardTelemetry {
                 shard_id,
                 peer_id,
                 searches: None, // not included in grpc
                 updates: None,  // not included in grpc
+                replication: replication.map(ReplicationTelemetry::from),
+            }
+        }
+    }
+
+    impl From<grpc::ReplicationTelemetry> for ReplicationTelemetry {
+        fn from(value: grpc::ReplicationTelemetry) -> Self {
+            let grpc::ReplicationTelemetry {
+                sent_requests,
+                sent_bytes,
+                lag_operations,
+                lag_seconds,
+            } = value;
+
+            ReplicationTelemetry {
+                sent_requests,
+                sent_bytes,
+                lag_operations,
+                lag_seconds,
             }
         }
     }
@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use common::budget::ResourceBudget;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use ordered_float::OrderedFloat;
+use segment::types::{Distance, ExtendedPointId, WithPayloadInterface};
+use tempfile::Builder;
+
+use super::points_dedup::{
+    dummy_abort_shard_transfer, dummy_on_replica_failure, dummy_request_shard_transfer,
+};
+use crate::collection::Collection;
+use crate::config::{
+    CollectionConfigInternal, CollectionParams, DuplicateDetectionConfig, DuplicateDetectionMode,
+    WalConfig,
+};
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, VectorStructPersisted,
+    WriteOrdering,
+};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::shared_storage_config::SharedStorageConfig;
+use crate::operations::types::{CollectionResult, PointRequestInternal, VectorsConfig};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+use crate::optimizers_builder::OptimizersConfig;
+use crate::shards::channel_service::ChannelService;
+use crate::shards::collection_shard_distribution::CollectionShardDistribution;
+use crate::shards::replica_set::replica_set_state::ReplicaState;
+use crate::shards::shard::{PeerId, ShardId};
+
+const DIM: u64 = 4;
+const PEER_ID: u64 = 1;
+
+async fn fixture(
+    collection_dir: &std::path::Path,
+    distance: Distance,
+    mode: DuplicateDetectionMode,
+) -> Collection {
+    let collection_params = CollectionParams {
+        vectors: VectorsConfig::Single(VectorParamsBuilder::new(DIM, distance).build()),
+        duplicate_detection: Some(DuplicateDetectionConfig {
+            vector: None,
+            epsilon: OrderedFloat(0.01),
+            tenant_key: None,
+            mode,
+        }),
+        ..CollectionParams::empty()
+    };
+
+    let config = CollectionConfigInternal {
+        params: collection_params,
+        optimizer_config: OptimizersConfig::fixture(),
+        wal_config: WalConfig::default(),
+        hnsw_config: Default::default(),
+        quantization_config: Default::default(),
+        strict_mode_config: Default::default(),
+        uuid: None,
+        metadata: None,
+    };
+
+    let snapshots_path = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let shards: AHashMap<ShardId, HashSet<PeerId>> =
+        AHashMap::from_iter([(0, HashSet::from([PEER_ID]))]);
+
+    let collection = Collection::new(
+        "test".to_string(),
+        PEER_ID,
+        collection_dir,
+        snapshots_path.path(),
+        &config,
+        Arc::new(SharedStorageConfig::default()),
+        CollectionShardDistribution { shards },
+        None,
+        ChannelService::default(),
+        dummy_on_replica_failure(),
+        dummy_request_shard_transfer(),
+        dummy_abort_shard_transfer(),
+        None,
+        None,
+        ResourceBudget::default(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    collection
+        .set_shard_replica_state(0, PEER_ID, ReplicaState::Active, None)
+        .await
+        .unwrap();
+
+    collection
+}
+
+async fn upsert(collection: &Collection, id: u64, vector: Vec<f32>) -> CollectionResult<()> {
+    let point = PointStructPersisted {
+        id: ExtendedPointId::NumId(id),
+        vector: VectorStructPersisted::Single(vector),
+        payload: None,
+    };
+    collection
+        .update_from_client_simple(
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsList(vec![point]),
+            )),
+            true,
+            None,
+            WriteOrdering::default(),
+            HwMeasurementAcc::new(),
+        )
+        .await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dot_duplicates_compared_by_distance() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(
+        collection_dir.path(),
+        Distance::Dot,
+        DuplicateDetectionMode::Reject,
+    )
+    .await;
+
+    upsert(&collection, 1, vec![10.0, 0.0, 0.0, 0.0])
+        .await
+        .unwrap();
+
+    // Dot product with the existing point is larger than with itself, but it is not a duplicate
+    upsert(&collection, 2, vec![1.0, 0.0, 0.0, 0.0])
+        .await
+        .unwrap();
+
+    // Re-upserting a point is not a duplicate of itself
+    upsert(&collection, 1, vec![10.0, 0.0, 0.0, 0.0])
+        .await
+        .unwrap();
+
+    let result = upsert(&collection, 3, vec![10.0, 0.0, 0.0, 0.001]).await;
+    assert!(result.is_err());
+
+    let result = upsert(&collection, 4, vec![1.0, 0.0, 0.001, 0.0]).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tag_cosine_duplicates() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(
+        collection_dir.path(),
+        Distance::Cosine,
+        DuplicateDetectionMode::Tag,
+    )
+    .await;
+
+    upsert(&collection, 1, vec![1.0, 0.0, 0.0, 0.0])
+        .await
+        .unwrap();
+    // Same direction, different length
+    upsert(&collection, 2, vec![2.0, 0.0, 0.0, 0.0])
+        .await
+        .unwrap();
+    upsert(&collection, 3, vec![0.0, 1.0, 0.0, 0.0])
+        .await
+        .unwrap();
+
+    let request = PointRequestInternal {
+        ids: vec![ExtendedPointId::NumId(2), ExtendedPointId::NumId(3)],
+        with_payload: Some(WithPayloadInterface::Bool(true)),
+        with_vector: false.into(),
+        with_version: None,
+    };
+    let records = collection
+        .retrieve(
+            request,
+            None,
+            &ShardSelectorInternal::All,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    let duplicate_of = |id| {
+        records
+            .iter()
+            .find(|record| record.id == ExtendedPointId::NumId(id))
+            .unwrap()
+            .payload
+            .as_ref()
+            .and_then(|payload| payload.0.get("duplicate_of").cloned())
+    };
+    assert_eq!(duplicate_of(2), Some(serde_json::Value::from(1)));
+    assert_eq!(duplicate_of(3), None);
+}
@@ -29,6 +29,7 @@ async fn test_fix_payload_indices() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -82,6 +83,7 @@ async fn test_fix_payload_indices() {
 
     let shard = LocalShard::load(
         0,
+        None,
         collection_name,
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -24,6 +24,8 @@ pub const TEST_OPTIMIZERS_CONFIG: OptimizersConfig = OptimizersConfig {
     flush_interval_sec: 30,
     max_optimization_threads: Some(2),
     prevent_unoptimized: None,
+    max_builder_memory: None,
+    flush_max_dirty: None,
 };
 
 pub fn create_collection_config_with_dim(dim: usize) -> CollectionConfigInternal {
@@ -31,6 +33,8 @@ pub fn create_collection_config_with_dim(dim: usize) -> CollectionConfigInternal
         wal_capacity_mb: 1,
         wal_segments_ahead: 0,
         wal_retain_closed: 1,
+        wal_batch_window_ms: 0,
+        wal_batch_max_points: 256,
     };
 
     let collection_params = CollectionParams {
@@ -40,6 +40,7 @@ async fn test_hw_metrics_cancellation() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -85,6 +86,8 @@ async fn test_hw_metrics_cancellation() {
                 Arc::new(req),
                 &current_runtime,
                 Duration::from_millis(10), // Very short duration to hit timeout before the search finishes
+                None,
+                None,
                 hw_counter,
             )
             .await;
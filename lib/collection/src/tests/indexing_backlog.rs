@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use common::budget::ResourceBudget;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::types::{Distance, ExtendedPointId, HnswConfig};
+use tempfile::Builder;
+
+use super::points_dedup::{
+    dummy_abort_shard_transfer, dummy_on_replica_failure, dummy_request_shard_transfer,
+};
+use crate::collection::Collection;
+use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, VectorStructPersisted,
+    WriteOrdering,
+};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::shared_storage_config::SharedStorageConfig;
+use crate::operations::types::{IndexingBacklogInfo, VectorsConfig};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+use crate::optimizers_builder::OptimizersConfig;
+use crate::shards::channel_service::ChannelService;
+use crate::shards::collection_shard_distribution::CollectionShardDistribution;
+use crate::shards::replica_set::replica_set_state::ReplicaState;
+use crate::shards::shard::{PeerId, ShardId};
+
+const DIM: u64 = 4;
+const PEER_ID: u64 = 1;
+const POINTS_COUNT: u64 = 2000;
+
+/// Create a collection without optimizations and insert points into it
+async fn fixture(collection_dir: &std::path::Path, indexing_threshold_kb: usize) -> Collection {
+    let collection_params = CollectionParams {
+        vectors: VectorsConfig::Single(VectorParamsBuilder::new(DIM, Distance::Dot).build()),
+        ..CollectionParams::empty()
+    };
+
+    let config = CollectionConfigInternal {
+        params: collection_params,
+        optimizer_config: OptimizersConfig {
+            indexing_threshold: Some(indexing_threshold_kb),
+            ..OptimizersConfig::fixture()
+        },
+        wal_config: WalConfig::default(),
+        hnsw_config: HnswConfig {
+            full_scan_threshold: indexing_threshold_kb,
+            ..Default::default()
+        },
+        quantization_config: Default::default(),
+        strict_mode_config: Default::default(),
+        uuid: None,
+        metadata: None,
+    };
+
+    let snapshots_path = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let shards: AHashMap<ShardId, HashSet<PeerId>> =
+        AHashMap::from_iter([(0, HashSet::from([PEER_ID]))]);
+
+    let collection = Collection::new(
+        "test".to_string(),
+        PEER_ID,
+        collection_dir,
+        snapshots_path.path(),
+        &config,
+        Arc::new(SharedStorageConfig::default()),
+        CollectionShardDistribution { shards },
+        None,
+        ChannelService::default(),
+        dummy_on_replica_failure(),
+        dummy_request_shard_transfer(),
+        dummy_abort_shard_transfer(),
+        None,
+        None,
+        ResourceBudget::default(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    collection
+        .set_shard_replica_state(0, PEER_ID, ReplicaState::Active, None)
+        .await
+        .unwrap();
+
+    let points = (0..POINTS_COUNT)
+        .map(|id| PointStructPersisted {
+            id: ExtendedPointId::NumId(id),
+            vector: VectorStructPersisted::Single(vec![1.0; DIM as usize]),
+            payload: None,
+        })
+        .collect();
+    collection
+        .update_from_client_simple(
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsList(points),
+            )),
+            true,
+            None,
+            WriteOrdering::default(),
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    collection
+}
+
+async fn indexing_backlog(collection: &Collection) -> IndexingBacklogInfo {
+    collection
+        .info(&ShardSelectorInternal::All)
+        .await
+        .unwrap()
+        .indexing_backlog
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_indexing_backlog() {
+    // Segments are larger than the indexing threshold, but not indexed
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), 1).await;
+
+    let backlog = indexing_backlog(&collection).await;
+    assert_eq!(backlog.unindexed_vectors_count, POINTS_COUNT as usize);
+    assert_eq!(backlog.vectors_count, POINTS_COUNT as usize);
+    assert!(backlog.search_degraded);
+
+    // Small segments are searched with a plain scan anyway
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), 100_000).await;
+
+    let backlog = indexing_backlog(&collection).await;
+    assert_eq!(backlog.unindexed_vectors_count, 0);
+    assert_eq!(backlog.vectors_count, POINTS_COUNT as usize);
+    assert!(!backlog.search_degraded);
+}
+
+#[test]
+fn test_merge_indexing_backlog() {
+    let indexed = IndexingBacklogInfo::new(0, 1000);
+    let unindexed = IndexingBacklogInfo::new(500, 500);
+    assert!(!indexed.search_degraded);
+    assert!(unindexed.search_degraded);
+
+    let merged = indexed.merge(unindexed);
+    assert_eq!(merged, IndexingBacklogInfo::new(500, 1500));
+    assert!(merged.search_degraded);
+
+    // Below the threshold
+    let merged = IndexingBacklogInfo::new(0, 10_000).merge(unindexed);
+    assert_eq!(merged.unindexed_vectors_count, 500);
+    assert!(!merged.search_degraded);
+}
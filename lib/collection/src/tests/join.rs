@@ -0,0 +1,225 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use common::budget::ResourceBudget;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+use segment::types::{Distance, ExtendedPointId, PointIdType, WithPayloadInterface};
+use tempfile::Builder;
+
+use super::points_dedup::{
+    dummy_abort_shard_transfer, dummy_on_replica_failure, dummy_request_shard_transfer,
+};
+use crate::collection::Collection;
+use crate::collection::join::CollectionJoinRequest;
+use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, VectorStructPersisted,
+    WriteOrdering,
+};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::shared_storage_config::SharedStorageConfig;
+use crate::operations::types::{ScrollRequestInternal, VectorsConfig};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+use crate::optimizers_builder::OptimizersConfig;
+use crate::shards::channel_service::ChannelService;
+use crate::shards::collection_shard_distribution::CollectionShardDistribution;
+use crate::shards::replica_set::replica_set_state::ReplicaState;
+use crate::shards::shard::{PeerId, ShardId};
+
+const DIM: u64 = 4;
+const PEER_ID: u64 = 1;
+const POINTS_COUNT: u64 = 7;
+
+/// Create a collection with points 1 to `POINTS_COUNT`
+async fn fixture(collection_dir: &std::path::Path) -> Collection {
+    let collection_params = CollectionParams {
+        vectors: VectorsConfig::Single(VectorParamsBuilder::new(DIM, Distance::Euclid).build()),
+        ..CollectionParams::empty()
+    };
+
+    let config = CollectionConfigInternal {
+        params: collection_params,
+        optimizer_config: OptimizersConfig::fixture(),
+        wal_config: WalConfig::default(),
+        hnsw_config: Default::default(),
+        quantization_config: Default::default(),
+        strict_mode_config: Default::default(),
+        uuid: None,
+        metadata: None,
+    };
+
+    let snapshots_path = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let shards: AHashMap<ShardId, HashSet<PeerId>> =
+        AHashMap::from_iter([(0, HashSet::from([PEER_ID]))]);
+
+    let collection = Collection::new(
+        "test".to_string(),
+        PEER_ID,
+        collection_dir,
+        snapshots_path.path(),
+        &config,
+        Arc::new(SharedStorageConfig::default()),
+        CollectionShardDistribution { shards },
+        None,
+        ChannelService::default(),
+        dummy_on_replica_failure(),
+        dummy_request_shard_transfer(),
+        dummy_abort_shard_transfer(),
+        None,
+        None,
+        ResourceBudget::default(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    collection
+        .set_shard_replica_state(0, PEER_ID, ReplicaState::Active, None)
+        .await
+        .unwrap();
+
+    let points = (1..=POINTS_COUNT)
+        .map(|id| PointStructPersisted {
+            id: ExtendedPointId::NumId(id),
+            vector: VectorStructPersisted::Single(vec![id as f32; DIM as usize]),
+            payload: None,
+        })
+        .collect();
+    collection
+        .update_from_client_simple(
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsList(points),
+            )),
+            true,
+            None,
+            WriteOrdering::default(),
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    collection
+}
+
+fn self_join_request(
+    collection: &Collection,
+    offset: Option<PointIdType>,
+    points_limit: usize,
+    payload_key: Option<String>,
+) -> CollectionJoinRequest {
+    CollectionJoinRequest {
+        target_collection: collection.name().to_string(),
+        using: DEFAULT_VECTOR_NAME.to_owned(),
+        target_using: DEFAULT_VECTOR_NAME.to_owned(),
+        filter: None,
+        target_filter: None,
+        limit: 2,
+        params: None,
+        score_threshold: None,
+        offset,
+        points_limit,
+        payload_key,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_join_pages() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(collection_dir.path()).await;
+
+    let mut joined_ids = Vec::new();
+    let mut offset = None;
+    loop {
+        let response = collection
+            .join(
+                &collection,
+                self_join_request(&collection, offset, 3, None),
+                ShardSelectorInternal::All,
+                None,
+                None,
+                HwMeasurementAcc::new(),
+            )
+            .await
+            .unwrap();
+
+        assert!(response.joined <= 3);
+        assert_eq!(response.joined, response.points.len());
+        assert_eq!(response.error, None);
+
+        for (id, neighbors) in response.points {
+            // Points are not their own neighbors in a self-join
+            assert_eq!(neighbors.len(), 2);
+            assert!(neighbors.iter().all(|neighbor| neighbor.id != id));
+            joined_ids.push(id);
+        }
+
+        offset = response.next_page_offset;
+        if offset.is_none() {
+            break;
+        }
+    }
+
+    let expected_ids: Vec<_> = (1..=POINTS_COUNT).map(ExtendedPointId::NumId).collect();
+    assert_eq!(joined_ids, expected_ids);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_join_into_payload() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(collection_dir.path()).await;
+
+    let response = collection
+        .join(
+            &collection,
+            self_join_request(&collection, None, 5, Some("neighbors".to_string())),
+            ShardSelectorInternal::All,
+            None,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.joined, 5);
+    assert!(response.points.is_empty());
+    assert_eq!(response.next_page_offset, Some(ExtendedPointId::NumId(6)));
+
+    let request = ScrollRequestInternal {
+        offset: None,
+        limit: Some(100),
+        filter: None,
+        with_payload: Some(WithPayloadInterface::Bool(true)),
+        with_vector: false.into(),
+        order_by: None,
+        distinct_by: None,
+        with_version: None,
+    };
+    let records = collection
+        .scroll_by(
+            request,
+            None,
+            &ShardSelectorInternal::All,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap()
+        .points;
+
+    // Only the points of the first page have their neighbors written
+    for record in records {
+        let neighbors = record
+            .payload
+            .as_ref()
+            .and_then(|payload| payload.0.get("neighbors"));
+        match record.id {
+            ExtendedPointId::NumId(id) if id <= 5 => {
+                assert_eq!(neighbors.unwrap().as_array().unwrap().len(), 2);
+            }
+            _ => assert!(neighbors.is_none()),
+        }
+    }
+}
@@ -1,14 +1,19 @@
+mod duplicates;
 mod fix_payload_indices;
 pub mod fixtures;
 mod hw_metrics;
+mod indexing_backlog;
+mod join;
 mod payload;
 mod points_dedup;
+mod points_expiration;
 mod query_prefetch_offset_limit;
 mod sha_256_test;
 mod shard_query;
 mod shard_telemetry;
 mod snapshot_test;
 mod sparse_vectors_validation_tests;
+mod trash;
 mod wal_recovery_test;
 
 use std::sync::Arc;
@@ -75,6 +80,7 @@ async fn test_optimization_process() {
     let total_optimized_points = Arc::new(AtomicUsize::new(0));
     let segments = LockedSegmentHolder::new(holder);
     let handles = UpdateWorkers::launch_optimization(
+        "test",
         optimizers.clone(),
         optimizers_log.clone(),
         total_optimized_points.clone(),
@@ -118,6 +124,7 @@ async fn test_optimization_process() {
     }
 
     let handles = UpdateWorkers::launch_optimization(
+        "test",
         optimizers.clone(),
         optimizers_log.clone(),
         total_optimized_points.clone(),
@@ -175,6 +182,7 @@ async fn test_cancel_optimization() {
     let total_optimized_points = Arc::new(AtomicUsize::new(0));
     let segments = LockedSegmentHolder::new(holder);
     let handles = UpdateWorkers::launch_optimization(
+        "test",
         optimizers.clone(),
         optimizers_log.clone(),
         total_optimized_points.clone(),
@@ -241,6 +249,7 @@ async fn test_new_segment_when_all_over_capacity() {
         max_segment_size_kb: 1,
         memmap_threshold_kb: 1_000_000,
         indexing_threshold_kb: 1_000_000,
+        max_builder_memory_kb: None,
     };
 
     let payload_schema_file = dir.path().join("payload.schema");
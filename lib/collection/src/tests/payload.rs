@@ -37,6 +37,7 @@ async fn test_payload_missing_index_check() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -45,6 +45,8 @@ async fn fixture() -> Collection {
         wal_capacity_mb: 1,
         wal_segments_ahead: 0,
         wal_retain_closed: 1,
+        wal_batch_window_ms: 0,
+        wal_batch_max_points: 256,
     };
 
     let collection_params = CollectionParams {
@@ -168,6 +170,8 @@ async fn test_scroll_dedup() {
                 with_payload: Some(false.into()),
                 with_vector: false.into(),
                 order_by: None,
+                distinct_by: None,
+                with_version: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -196,6 +200,8 @@ async fn test_scroll_dedup() {
                 with_payload: Some(false.into()),
                 with_vector: false.into(),
                 order_by: Some(OrderByInterface::Key("num".parse().unwrap())),
+                distinct_by: None,
+                with_version: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -231,6 +237,7 @@ async fn test_retrieve_dedup() {
                     .collect(),
                 with_payload: Some(false.into()),
                 with_vector: false.into(),
+                with_version: None,
             },
             None,
             &ShardSelectorInternal::All,
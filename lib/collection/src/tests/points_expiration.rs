@@ -0,0 +1,229 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use chrono::Utc;
+use common::budget::ResourceBudget;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::types::{
+    Distance, ExtendedPointId, Payload, PayloadFieldSchema, PayloadSchemaType, WithPayloadInterface,
+};
+use serde_json::{Map, Value};
+use tempfile::Builder;
+
+use super::points_dedup::{
+    dummy_abort_shard_transfer, dummy_on_replica_failure, dummy_request_shard_transfer,
+};
+use crate::collection::Collection;
+use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::config_diff::CollectionParamsDiff;
+use crate::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, VectorStructPersisted,
+    WriteOrdering,
+};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::shared_storage_config::SharedStorageConfig;
+use crate::operations::types::{PointRequestInternal, ScrollRequestInternal, VectorsConfig};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+use crate::optimizers_builder::OptimizersConfig;
+use crate::shards::channel_service::ChannelService;
+use crate::shards::collection_shard_distribution::CollectionShardDistribution;
+use crate::shards::replica_set::replica_set_state::ReplicaState;
+use crate::shards::shard::{PeerId, ShardId};
+
+const DIM: u64 = 4;
+const PEER_ID: u64 = 1;
+
+const EXPIRED_POINT_ID: ExtendedPointId = ExtendedPointId::NumId(1);
+const ALIVE_POINT_ID: ExtendedPointId = ExtendedPointId::NumId(2);
+const PERMANENT_POINT_ID: ExtendedPointId = ExtendedPointId::NumId(3);
+
+/// Create a collection with points expiration, and insert an expired point, a point which expires
+/// in the future, and a point without expiration time
+async fn fixture(collection_dir: &std::path::Path) -> Collection {
+    let collection_params = CollectionParams {
+        vectors: VectorsConfig::Single(VectorParamsBuilder::new(DIM, Distance::Dot).build()),
+        points_expiration: Some(true),
+        ..CollectionParams::empty()
+    };
+
+    let config = CollectionConfigInternal {
+        params: collection_params,
+        optimizer_config: OptimizersConfig::fixture(),
+        wal_config: WalConfig::default(),
+        hnsw_config: Default::default(),
+        quantization_config: Default::default(),
+        strict_mode_config: Default::default(),
+        uuid: None,
+        metadata: None,
+    };
+
+    let snapshots_path = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let shards: AHashMap<ShardId, HashSet<PeerId>> =
+        AHashMap::from_iter([(0, HashSet::from([PEER_ID]))]);
+
+    let collection = Collection::new(
+        "test".to_string(),
+        PEER_ID,
+        collection_dir,
+        snapshots_path.path(),
+        &config,
+        Arc::new(SharedStorageConfig::default()),
+        CollectionShardDistribution { shards },
+        None,
+        ChannelService::default(),
+        dummy_on_replica_failure(),
+        dummy_request_shard_transfer(),
+        dummy_abort_shard_transfer(),
+        None,
+        None,
+        ResourceBudget::default(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    collection
+        .set_shard_replica_state(0, PEER_ID, ReplicaState::Active, None)
+        .await
+        .unwrap();
+
+    collection
+        .create_payload_index(
+            "expires_at".parse().unwrap(),
+            PayloadFieldSchema::FieldType(PayloadSchemaType::Integer),
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    let now = Utc::now().timestamp();
+    let point = |id: ExtendedPointId, expires_at: Option<i64>| PointStructPersisted {
+        id,
+        vector: VectorStructPersisted::Single(vec![1.0; DIM as usize]),
+        payload: expires_at.map(|expires_at| {
+            Payload(Map::from_iter([(
+                "expires_at".to_string(),
+                Value::from(expires_at),
+            )]))
+        }),
+    };
+
+    let operation = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+        PointInsertOperationsInternal::PointsList(vec![
+            point(EXPIRED_POINT_ID, Some(now - 60)),
+            point(ALIVE_POINT_ID, Some(now + 3600)),
+            point(PERMANENT_POINT_ID, None),
+        ]),
+    ));
+    collection
+        .update_from_client_simple(
+            operation,
+            true,
+            None,
+            WriteOrdering::default(),
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    collection
+}
+
+async fn scroll_ids(collection: &Collection) -> Vec<ExtendedPointId> {
+    let request = ScrollRequestInternal {
+        offset: None,
+        limit: Some(100),
+        filter: None,
+        with_payload: Some(WithPayloadInterface::Bool(false)),
+        with_vector: false.into(),
+        order_by: None,
+        distinct_by: None,
+        with_version: None,
+    };
+
+    collection
+        .scroll_by(
+            request,
+            None,
+            &ShardSelectorInternal::All,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap()
+        .points
+        .into_iter()
+        .map(|record| record.id)
+        .collect()
+}
+
+async fn disable_expiration(collection: &Collection) {
+    let params = collection.collection_config.read().await.params.clone();
+    let diff = CollectionParamsDiff {
+        points_expiration: Some(false),
+        ..CollectionParamsDiff::from(params)
+    };
+    collection.update_params_from_diff(diff).await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_expired_points_are_not_readable() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(collection_dir.path()).await;
+
+    assert_eq!(
+        scroll_ids(&collection).await,
+        vec![ALIVE_POINT_ID, PERMANENT_POINT_ID],
+    );
+
+    // Expired point is not returned, even if requested by id
+    let records = collection
+        .retrieve(
+            PointRequestInternal {
+                ids: vec![EXPIRED_POINT_ID, ALIVE_POINT_ID],
+                with_payload: None,
+                with_vector: false.into(),
+                with_version: None,
+            },
+            None,
+            &ShardSelectorInternal::All,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+    let ids: Vec<_> = records.into_iter().map(|record| record.id).collect();
+    assert_eq!(ids, vec![ALIVE_POINT_ID]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_delete_expired_points() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(collection_dir.path()).await;
+
+    collection.delete_expired_points().await.unwrap();
+
+    // Without expiration, the point would be readable again, if it was not deleted
+    disable_expiration(&collection).await;
+
+    assert_eq!(
+        scroll_ids(&collection).await,
+        vec![ALIVE_POINT_ID, PERMANENT_POINT_ID],
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_disabled_expiration() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(collection_dir.path()).await;
+
+    disable_expiration(&collection).await;
+    collection.delete_expired_points().await.unwrap();
+
+    assert_eq!(
+        scroll_ids(&collection).await,
+        vec![EXPIRED_POINT_ID, ALIVE_POINT_ID, PERMANENT_POINT_ID],
+    );
+}
@@ -43,6 +43,8 @@ async fn fixture() -> Collection {
         wal_capacity_mb: 1,
         wal_segments_ahead: 0,
         wal_retain_closed: 1,
+        wal_batch_window_ms: 0,
+        wal_batch_max_points: 256,
     };
 
     let collection_params = CollectionParams {
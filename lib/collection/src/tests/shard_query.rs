@@ -36,6 +36,7 @@ async fn test_shard_query_rrf_rescoring() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -74,7 +75,14 @@ async fn test_shard_query_rrf_rescoring() {
 
     let hw_acc = HwMeasurementAcc::new();
     let sources_scores = shard
-        .query_batch(Arc::new(vec![query]), &current_runtime, None, hw_acc)
+        .query_batch(
+            Arc::new(vec![query]),
+            &current_runtime,
+            None,
+            None,
+            None,
+            hw_acc,
+        )
         .await;
     let expected_error = CollectionError::bad_input(
         "Validation failed: cannot apply Fusion without prefetches".to_string(),
@@ -113,7 +121,14 @@ async fn test_shard_query_rrf_rescoring() {
 
     let hw_acc = HwMeasurementAcc::new();
     let sources_scores = shard
-        .query_batch(Arc::new(vec![query]), &current_runtime, None, hw_acc)
+        .query_batch(
+            Arc::new(vec![query]),
+            &current_runtime,
+            None,
+            None,
+            None,
+            hw_acc,
+        )
         .await
         .unwrap()
         .pop()
@@ -163,7 +178,14 @@ async fn test_shard_query_rrf_rescoring() {
 
     let hw_acc = HwMeasurementAcc::new();
     let sources_scores = shard
-        .query_batch(Arc::new(vec![query]), &current_runtime, None, hw_acc)
+        .query_batch(
+            Arc::new(vec![query]),
+            &current_runtime,
+            None,
+            None,
+            None,
+            hw_acc,
+        )
         .await
         .unwrap()
         .pop()
@@ -210,7 +232,14 @@ async fn test_shard_query_rrf_rescoring() {
 
     let hw_acc = HwMeasurementAcc::new();
     let sources_scores = shard
-        .query_batch(Arc::new(vec![query]), &current_runtime, None, hw_acc)
+        .query_batch(
+            Arc::new(vec![query]),
+            &current_runtime,
+            None,
+            None,
+            None,
+            hw_acc,
+        )
         .await
         .unwrap()
         .pop()
@@ -241,6 +270,7 @@ async fn test_shard_query_vector_rescoring() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -292,7 +322,14 @@ async fn test_shard_query_vector_rescoring() {
 
     let hw_acc = HwMeasurementAcc::new();
     let sources_scores = shard
-        .query_batch(Arc::new(vec![query]), &current_runtime, None, hw_acc)
+        .query_batch(
+            Arc::new(vec![query]),
+            &current_runtime,
+            None,
+            None,
+            None,
+            hw_acc,
+        )
         .await
         .unwrap()
         .pop()
@@ -319,7 +356,14 @@ async fn test_shard_query_vector_rescoring() {
 
     let hw_acc = HwMeasurementAcc::new();
     let sources_scores = shard
-        .query_batch(Arc::new(vec![query]), &current_runtime, None, hw_acc)
+        .query_batch(
+            Arc::new(vec![query]),
+            &current_runtime,
+            None,
+            None,
+            None,
+            hw_acc,
+        )
         .await
         .unwrap()
         .pop()
@@ -349,7 +393,14 @@ async fn test_shard_query_vector_rescoring() {
 
     let hw_acc = HwMeasurementAcc::new();
     let sources_scores = shard
-        .query_batch(Arc::new(vec![query]), &current_runtime, None, hw_acc)
+        .query_batch(
+            Arc::new(vec![query]),
+            &current_runtime,
+            None,
+            None,
+            None,
+            hw_acc,
+        )
         .await
         .unwrap()
         .pop()
@@ -379,6 +430,7 @@ async fn test_shard_query_payload_vector() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -420,7 +472,14 @@ async fn test_shard_query_payload_vector() {
 
     let hw_acc = HwMeasurementAcc::new();
     let sources_scores = shard
-        .query_batch(Arc::new(vec![query]), &current_runtime, None, hw_acc)
+        .query_batch(
+            Arc::new(vec![query]),
+            &current_runtime,
+            None,
+            None,
+            None,
+            hw_acc,
+        )
         .await
         .unwrap()
         .pop()
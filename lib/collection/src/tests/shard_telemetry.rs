@@ -31,6 +31,7 @@ async fn test_shard_telemetry() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -39,6 +39,8 @@ async fn _test_snapshot_collection(node_type: NodeType) {
         wal_capacity_mb: 1,
         wal_segments_ahead: 0,
         wal_retain_closed: 1,
+        wal_batch_window_ms: 0,
+        wal_batch_max_points: 256,
     };
 
     let collection_params = CollectionParams {
@@ -99,7 +101,7 @@ async fn _test_snapshot_collection(node_type: NodeType) {
 
     let snapshots_temp_dir = Builder::new().prefix("temp_dir").tempdir().unwrap();
     let snapshot_description = collection
-        .create_snapshot(snapshots_temp_dir.path(), 0)
+        .create_snapshot(snapshots_temp_dir.path(), 0, None)
         .await
         .unwrap();
 
@@ -33,6 +33,7 @@ fn wrong_point_struct() -> PointStruct {
         id: 0.into(),
         vector: VectorStruct::Named(vector_data),
         payload: None,
+        shard_key: None,
     }
 }
 
@@ -61,6 +62,7 @@ fn validate_error_sparse_vector_points_batch() {
         ids: vec![1.into()],
         vectors: BatchVectorStruct::Named(vector_data),
         payloads: None,
+        shard_keys: None,
     });
 }
 
@@ -0,0 +1,271 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use ahash::{AHashMap, AHashSet};
+use common::budget::ResourceBudget;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::types::{Condition, Distance, ExtendedPointId, Filter, WithPayloadInterface};
+use tempfile::Builder;
+
+use super::points_dedup::{
+    dummy_abort_shard_transfer, dummy_on_replica_failure, dummy_request_shard_transfer,
+};
+use crate::collection::Collection;
+use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, VectorStructPersisted,
+    WriteOrdering,
+};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::shared_storage_config::SharedStorageConfig;
+use crate::operations::types::{ScrollRequestInternal, VectorsConfig};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+use crate::optimizers_builder::OptimizersConfig;
+use crate::shards::channel_service::ChannelService;
+use crate::shards::collection_shard_distribution::CollectionShardDistribution;
+use crate::shards::replica_set::replica_set_state::ReplicaState;
+use crate::shards::shard::{PeerId, ShardId};
+
+const DIM: u64 = 4;
+const PEER_ID: u64 = 1;
+
+/// Create a collection with trash retention, and insert points 1, 2 and 3
+async fn fixture(collection_dir: &std::path::Path, trash_retention_sec: u64) -> Collection {
+    let collection_params = CollectionParams {
+        vectors: VectorsConfig::Single(VectorParamsBuilder::new(DIM, Distance::Dot).build()),
+        trash_retention_sec: Some(trash_retention_sec),
+        ..CollectionParams::empty()
+    };
+
+    let config = CollectionConfigInternal {
+        params: collection_params,
+        optimizer_config: OptimizersConfig::fixture(),
+        wal_config: WalConfig::default(),
+        hnsw_config: Default::default(),
+        quantization_config: Default::default(),
+        strict_mode_config: Default::default(),
+        uuid: None,
+        metadata: None,
+    };
+
+    let snapshots_path = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let shards: AHashMap<ShardId, HashSet<PeerId>> =
+        AHashMap::from_iter([(0, HashSet::from([PEER_ID]))]);
+
+    let collection = Collection::new(
+        "test".to_string(),
+        PEER_ID,
+        collection_dir,
+        snapshots_path.path(),
+        &config,
+        Arc::new(SharedStorageConfig::default()),
+        CollectionShardDistribution { shards },
+        None,
+        ChannelService::default(),
+        dummy_on_replica_failure(),
+        dummy_request_shard_transfer(),
+        dummy_abort_shard_transfer(),
+        None,
+        None,
+        ResourceBudget::default(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    collection
+        .set_shard_replica_state(0, PEER_ID, ReplicaState::Active, None)
+        .await
+        .unwrap();
+
+    // Done by the table of contents on collection creation
+    collection.create_trash_index().await.unwrap();
+
+    let points = (1..=3)
+        .map(|id| PointStructPersisted {
+            id: ExtendedPointId::NumId(id),
+            vector: VectorStructPersisted::Single(vec![1.0; DIM as usize]),
+            payload: None,
+        })
+        .collect();
+    update(
+        &collection,
+        PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsList(points)),
+    )
+    .await;
+
+    collection
+}
+
+async fn update(collection: &Collection, operation: PointOperations) {
+    collection
+        .update_from_client_simple(
+            CollectionUpdateOperations::PointOperation(operation),
+            true,
+            None,
+            WriteOrdering::default(),
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+}
+
+async fn restore(collection: &Collection, ids: Vec<ExtendedPointId>) {
+    collection
+        .restore_points(
+            ids,
+            true,
+            None,
+            WriteOrdering::default(),
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+}
+
+async fn scroll_ids(collection: &Collection) -> Vec<ExtendedPointId> {
+    let request = ScrollRequestInternal {
+        offset: None,
+        limit: Some(100),
+        filter: None,
+        with_payload: Some(WithPayloadInterface::Bool(false)),
+        with_vector: false.into(),
+        order_by: None,
+        distinct_by: None,
+        with_version: None,
+    };
+
+    collection
+        .scroll_by(
+            request,
+            None,
+            &ShardSelectorInternal::All,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap()
+        .points
+        .into_iter()
+        .map(|record| record.id)
+        .collect()
+}
+
+/// Number of points in shards, including hidden ones
+async fn stored_points_count(collection: &Collection) -> usize {
+    collection
+        .info(&ShardSelectorInternal::All)
+        .await
+        .unwrap()
+        .points_count
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_restore_deleted_points() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), 3600).await;
+
+    update(
+        &collection,
+        PointOperations::DeletePoints {
+            ids: vec![ExtendedPointId::NumId(1)],
+        },
+    )
+    .await;
+    update(
+        &collection,
+        PointOperations::DeletePointsByFilter(Filter::new_must(Condition::HasId(
+            AHashSet::from_iter([ExtendedPointId::NumId(2)]).into(),
+        ))),
+    )
+    .await;
+
+    assert_eq!(
+        scroll_ids(&collection).await,
+        vec![ExtendedPointId::NumId(3)]
+    );
+
+    // Point 3 is not in the trash, it is ignored
+    restore(
+        &collection,
+        vec![
+            ExtendedPointId::NumId(1),
+            ExtendedPointId::NumId(2),
+            ExtendedPointId::NumId(3),
+        ],
+    )
+    .await;
+
+    assert_eq!(
+        scroll_ids(&collection).await,
+        vec![
+            ExtendedPointId::NumId(1),
+            ExtendedPointId::NumId(2),
+            ExtendedPointId::NumId(3),
+        ],
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_delete_missing_points() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), 3600).await;
+
+    // Missing points are ignored, as without the trash
+    update(
+        &collection,
+        PointOperations::DeletePoints {
+            ids: vec![ExtendedPointId::NumId(42), ExtendedPointId::NumId(1)],
+        },
+    )
+    .await;
+
+    // Deleting a point in the trash again succeeds
+    update(
+        &collection,
+        PointOperations::DeletePoints {
+            ids: vec![ExtendedPointId::NumId(1)],
+        },
+    )
+    .await;
+
+    assert_eq!(
+        scroll_ids(&collection).await,
+        vec![ExtendedPointId::NumId(2), ExtendedPointId::NumId(3)],
+    );
+    assert_eq!(stored_points_count(&collection).await, 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_purge_trash() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), 1).await;
+
+    update(
+        &collection,
+        PointOperations::DeletePoints {
+            ids: vec![ExtendedPointId::NumId(1)],
+        },
+    )
+    .await;
+
+    // Point in the trash is still stored
+    assert_eq!(stored_points_count(&collection).await, 3);
+
+    // Let the retention period pass
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    collection.purge_trash().await.unwrap();
+    assert_eq!(stored_points_count(&collection).await, 2);
+
+    // Point is deleted permanently, it can't be restored anymore
+    restore(&collection, vec![ExtendedPointId::NumId(1)]).await;
+
+    assert_eq!(
+        scroll_ids(&collection).await,
+        vec![ExtendedPointId::NumId(2), ExtendedPointId::NumId(3)],
+    );
+}
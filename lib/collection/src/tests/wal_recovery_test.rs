@@ -39,6 +39,7 @@ async fn test_delete_from_indexed_payload() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -94,6 +95,7 @@ async fn test_delete_from_indexed_payload() {
 
     let shard = LocalShard::load(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -121,6 +123,7 @@ async fn test_delete_from_indexed_payload() {
 
     let shard = LocalShard::load(
         0,
+        None,
         collection_name,
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -167,6 +170,7 @@ async fn test_partial_flush_recovery() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -222,6 +226,7 @@ async fn test_partial_flush_recovery() {
 
     let shard = LocalShard::load(
         0,
+        None,
         collection_name,
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -269,6 +274,7 @@ async fn test_truncate_unapplied_wal() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -335,6 +341,7 @@ async fn test_truncate_unapplied_wal() {
         ids: all_point_ids,
         with_payload: None,
         with_vector: WithVector::Bool(false),
+        with_version: None,
     });
 
     let retrieved = shard
@@ -432,6 +439,7 @@ async fn test_wal_replay_loads_pending_to_queue() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -504,6 +512,7 @@ async fn test_wal_replay_loads_pending_to_queue() {
     // Reload the shard
     let shard = LocalShard::load(
         0,
+        None,
         collection_name,
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -587,6 +596,7 @@ async fn test_wal_replay_with_smaller_queue_size() {
 
     let shard = LocalShard::build(
         0,
+        None,
         collection_name.clone(),
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -662,6 +672,7 @@ async fn test_wal_replay_with_smaller_queue_size() {
     // Reload the shard
     let shard = LocalShard::load(
         0,
+        None,
         collection_name,
         collection_dir.path(),
         Arc::new(RwLock::new(config.clone())),
@@ -7,6 +7,7 @@ use common::budget::ResourceBudget;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::save_on_disk::SaveOnDisk;
 use parking_lot::Mutex;
+use segment::common::BYTES_IN_KB;
 use segment::types::SeqNumberType;
 use shard::operations::CollectionUpdateOperations;
 use shard::segment_holder::locked::LockedSegmentHolder;
@@ -25,9 +26,11 @@ use crate::operations::shared_storage_config::SharedStorageConfig;
 use crate::operations::types::CollectionResult;
 use crate::shards::CollectionId;
 use crate::shards::local_shard::LocalShardClocks;
+use crate::shards::local_shard::disk_usage_watcher::DiskUsageWatcher;
 use crate::shards::update_tracker::UpdateTracker;
 use crate::update_workers::UpdateWorkers;
 use crate::update_workers::applied_seq::AppliedSeqHandler;
+use crate::update_workers::flush_workers::DirtyBytes;
 use crate::wal_delta::LockedWal;
 
 pub type Optimizer = dyn SegmentOptimizer + Sync + Send;
@@ -85,6 +88,12 @@ pub struct UpdateHandler {
     /// How frequent can we flush data
     /// This parameter depends on the optimizer config and should be updated accordingly.
     pub flush_interval_sec: u64,
+    /// If specified, segments are flushed as soon as this amount of data (in kilobytes) is
+    /// written into them since the last flush, without waiting for `flush_interval_sec`.
+    /// This parameter depends on the optimizer config and should be updated accordingly.
+    pub flush_max_dirty_kb: Option<usize>,
+    /// Amount of data written into segments since the last flush
+    dirty_bytes: Arc<DirtyBytes>,
     segments: LockedSegmentHolder,
     /// Process, that listens updates signals and perform updates.
     /// Returns the receiver with pending updates when stopped.
@@ -133,6 +142,9 @@ pub struct UpdateHandler {
 
     /// Persist the applied op_num sequence number
     applied_seq_handler: Arc<AppliedSeqHandler>,
+
+    /// Disk usage of the shard path, optimizations are paused above the flood watermark
+    disk_usage_watcher: Arc<DiskUsageWatcher>,
 }
 
 impl UpdateHandler {
@@ -149,6 +161,7 @@ impl UpdateHandler {
         segments: LockedSegmentHolder,
         wal: LockedWal,
         flush_interval_sec: u64,
+        flush_max_dirty_kb: Option<usize>,
         max_optimization_threads: Option<usize>,
         prevent_unoptimized_threshold_kb: Option<usize>,
         clocks: LocalShardClocks,
@@ -156,6 +169,7 @@ impl UpdateHandler {
         scroll_read_lock: Arc<tokio::sync::RwLock<()>>,
         update_tracker: UpdateTracker,
         applied_seq_handler: Arc<AppliedSeqHandler>,
+        disk_usage_watcher: Arc<DiskUsageWatcher>,
     ) -> Self {
         UpdateHandler {
             collection_name,
@@ -175,6 +189,8 @@ impl UpdateHandler {
             wal,
             wal_keep_from: Arc::new(u64::MAX.into()),
             flush_interval_sec,
+            flush_max_dirty_kb,
+            dirty_bytes: Default::default(),
             optimization_handles: Arc::new(TokioMutex::new(vec![])),
             max_optimization_threads,
             prevent_unoptimized_threshold_kb,
@@ -184,6 +200,7 @@ impl UpdateHandler {
             scroll_read_lock,
             update_tracker,
             applied_seq_handler,
+            disk_usage_watcher,
         }
     }
 
@@ -193,8 +210,13 @@ impl UpdateHandler {
         // Optimization notifier is triggered when a new optimization is finished
         let (optimization_finished_sender, optimization_finished_receiver) = watch::channel(());
 
+        let flush_max_dirty_bytes = self
+            .flush_max_dirty_kb
+            .map(|kb| kb.saturating_mul(BYTES_IN_KB));
+
         self.optimizer_worker = Some(self.runtime_handle.spawn(
             UpdateWorkers::optimization_worker_fn(
+                self.collection_name.clone(),
                 self.optimizers.clone(),
                 tx.clone(),
                 rx,
@@ -210,6 +232,7 @@ impl UpdateHandler {
                 self.scroll_read_lock.clone(),
                 self.update_tracker.clone(),
                 optimization_finished_sender,
+                self.disk_usage_watcher.clone(),
             ),
         ));
 
@@ -217,6 +240,7 @@ impl UpdateHandler {
         let segments = self.segments.clone();
         let scroll_read_lock = self.scroll_read_lock.clone();
         let update_tracker = self.update_tracker.clone();
+        let index_builds_stopped = self.optimizers_log.lock().index_builds_stopped();
         let collection_name = self.collection_name.clone();
         let applied_seq_handler = self.applied_seq_handler.clone();
 
@@ -232,7 +256,10 @@ impl UpdateHandler {
             segments,
             scroll_read_lock,
             update_tracker,
+            index_builds_stopped,
             self.prevent_unoptimized_threshold_kb,
+            self.dirty_bytes.clone(),
+            flush_max_dirty_bytes,
             self.optimization_handles.clone(),
             optimization_finished_receiver,
             applied_seq_handler,
@@ -244,6 +271,7 @@ impl UpdateHandler {
         let wal_keep_from = self.wal_keep_from.clone();
         let clocks = self.clocks.clone();
         let flush_interval_sec = self.flush_interval_sec;
+        let dirty_bytes = self.dirty_bytes.clone();
         let shard_path = self.shard_path.clone();
         let (flush_tx, flush_rx) = oneshot::channel();
         self.flush_worker = Some(self.runtime_handle.spawn(UpdateWorkers::flush_worker_fn(
@@ -252,6 +280,8 @@ impl UpdateHandler {
             wal_keep_from,
             clocks,
             flush_interval_sec,
+            dirty_bytes,
+            flush_max_dirty_bytes,
             flush_rx,
             shard_path,
         )));
@@ -1,7 +1,7 @@
 use std::cmp::min;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::Duration;
 
 use common::panic;
@@ -9,12 +9,38 @@ use segment::common::operation_error::OperationResult;
 use segment::types::SeqNumberType;
 use shard::segment_holder::locked::LockedSegmentHolder;
 use shard::wal::WalError;
-use tokio::sync::oneshot;
+use tokio::sync::{Notify, oneshot};
 
 use crate::shards::local_shard::LocalShardClocks;
 use crate::update_workers::UpdateWorkers;
 use crate::wal_delta::LockedWal;
 
+/// Amount of data written into segments of a shard since the last flush
+#[derive(Debug, Default)]
+pub struct DirtyBytes {
+    bytes: AtomicUsize,
+    exceeded: Notify,
+}
+
+impl DirtyBytes {
+    /// Record `bytes` written into segments.
+    /// Wakes up the flush worker once the written amount reaches `max_dirty_bytes`.
+    pub fn add(&self, bytes: usize, max_dirty_bytes: Option<usize>) {
+        let dirty_bytes = self.bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        if max_dirty_bytes.is_some_and(|max_dirty_bytes| dirty_bytes >= max_dirty_bytes) {
+            self.exceeded.notify_one();
+        }
+    }
+
+    pub fn get(&self) -> usize {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    fn reset(&self) {
+        self.bytes.store(0, Ordering::Relaxed);
+    }
+}
+
 impl UpdateWorkers {
     /// Returns confirmed version after flush of all segments
     ///
@@ -106,6 +132,8 @@ impl UpdateWorkers {
         wal_keep_from: Arc<AtomicU64>,
         clocks: LocalShardClocks,
         flush_interval_sec: u64,
+        dirty_bytes: Arc<DirtyBytes>,
+        flush_max_dirty_bytes: Option<usize>,
         mut stop_receiver: oneshot::Receiver<()>,
         shard_path: PathBuf,
     ) {
@@ -119,8 +147,19 @@ impl UpdateWorkers {
                 },
                 // Flush at the configured flush interval
                 _ = tokio::time::sleep(Duration::from_secs(flush_interval_sec)) => {},
+                // Flush early if too much data was written since the last flush
+                _ = dirty_bytes.exceeded.notified(), if flush_max_dirty_bytes.is_some() => {
+                    log::trace!(
+                        "Flushing shard {} after {} dirty bytes",
+                        shard_path.display(),
+                        dirty_bytes.get(),
+                    );
+                },
             };
 
+            // Everything written so far is persisted by this flush
+            dirty_bytes.reset();
+
             let segments_clone = segments.clone();
             let wal_clone = wal.clone();
             let wal_keep_from_clone = wal_keep_from.clone();
@@ -143,3 +182,31 @@ impl UpdateWorkers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_dirty_bytes_notify() {
+        let dirty_bytes = DirtyBytes::default();
+
+        // No threshold, never wakes up the flush worker
+        dirty_bytes.add(1000, None);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), dirty_bytes.exceeded.notified())
+                .await
+                .is_err(),
+        );
+
+        dirty_bytes.add(10, Some(2000));
+        assert_eq!(dirty_bytes.get(), 1010);
+        dirty_bytes.add(1000, Some(2000));
+        tokio::time::timeout(Duration::from_secs(1), dirty_bytes.exceeded.notified())
+            .await
+            .expect("flush worker must be woken up");
+
+        dirty_bytes.reset();
+        assert_eq!(dirty_bytes.get(), 0);
+    }
+}
@@ -30,9 +30,14 @@ use crate::collection_manager::optimizers::segment_optimizer::{
 use crate::collection_manager::optimizers::{
     Tracker, TrackerLog, TrackerSegmentInfo, TrackerStatus,
 };
-use crate::common::stoppable_task::{StoppableTaskHandle, spawn_stoppable};
+use crate::common::stoppable_task::{StoppableTaskHandle, spawn_stoppable_with_flag};
 use crate::config::CollectionParams;
-use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::types::{
+    CollectionError, CollectionResult, OptimizationAction, OptimizationCandidateInfo,
+    OptimizationDecision,
+};
+use crate::shards::CollectionId;
+use crate::shards::local_shard::disk_usage_watcher::{DiskUsageWatcher, FLOOD_RECHECK_INTERVAL};
 use crate::shards::update_tracker::UpdateTracker;
 use crate::update_handler::{Optimizer, OptimizerSignal};
 use crate::update_workers::UpdateWorkers;
@@ -46,6 +51,7 @@ const OPTIMIZER_CLEANUP_INTERVAL: Duration = Duration::from_secs(5);
 impl UpdateWorkers {
     #[allow(clippy::too_many_arguments)]
     pub async fn optimization_worker_fn(
+        collection_name: CollectionId,
         optimizers: Arc<Vec<Arc<Optimizer>>>,
         sender: Sender<OptimizerSignal>,
         mut receiver: Receiver<OptimizerSignal>,
@@ -61,8 +67,10 @@ impl UpdateWorkers {
         update_operation_lock: Arc<tokio::sync::RwLock<()>>,
         update_tracker: UpdateTracker,
         optimization_finished_sender: watch::Sender<()>,
+        disk_usage_watcher: Arc<DiskUsageWatcher>,
     ) {
         let max_handles = max_handles.unwrap_or(usize::MAX);
+        let index_builds_stopped = optimizers_log.lock().index_builds_stopped();
         let max_indexing_threads = optimizers
             .first()
             .map(|optimizer| optimizer.hnsw_config().max_indexing_threads)
@@ -71,6 +79,9 @@ impl UpdateWorkers {
         // Asynchronous task to trigger optimizers once CPU budget is available again
         let mut resource_available_trigger: Option<JoinHandle<()>> = None;
 
+        // Asynchronous task to trigger optimizers once disk space is freed
+        let mut disk_space_available_trigger: Option<JoinHandle<()>> = None;
+
         loop {
             let result = timeout(OPTIMIZER_CLEANUP_INTERVAL, receiver.recv()).await;
 
@@ -132,24 +143,46 @@ impl UpdateWorkers {
                 continue;
             }
 
-            if Self::try_recover(
+            match Self::try_recover(
                 segments.clone(),
                 wal.clone(),
                 update_operation_lock.clone(),
                 update_tracker.clone(),
+                &index_builds_stopped,
             )
             .await
-            .is_err()
             {
+                Ok(_) => {}
+                // Cancelled payload index builds are recovered once resumed, don't hold up
+                // optimizations until then
+                Err(CollectionError::Cancelled { .. })
+                    if index_builds_stopped.load(Ordering::Relaxed) => {}
+                Err(_) => continue,
+            }
+
+            // Optimizations need extra disk space, pause them above the flood watermark
+            if disk_usage_watcher.is_flooded().await {
+                let trigger_active = disk_space_available_trigger
+                    .as_ref()
+                    .is_some_and(|t| !t.is_finished());
+                if !trigger_active {
+                    disk_space_available_trigger.replace(Self::trigger_optimizers_on_disk_space(
+                        disk_usage_watcher.clone(),
+                        sender.clone(),
+                    ));
+                }
                 continue;
             }
 
-            // Continue if we have enough resource budget available to start an optimization
+            // Continue if we have enough resource budget and a free node-wide job slot available
+            // to start an optimization
             // Otherwise skip now and start a task to trigger the optimizer again once resource
             // budget becomes available
             let desired_cpus = 0;
             let desired_io = num_rayon_threads(max_indexing_threads);
-            if !optimizer_resource_budget.has_budget(desired_cpus, desired_io) {
+            if !optimizer_resource_budget.has_budget(desired_cpus, desired_io)
+                || !optimizer_resource_budget.has_job_slot(&collection_name)
+            {
                 let trigger_active = resource_available_trigger
                     .as_ref()
                     .is_some_and(|t| !t.is_finished());
@@ -157,6 +190,7 @@ impl UpdateWorkers {
                     resource_available_trigger.replace(
                         Self::trigger_optimizers_on_resource_budget(
                             optimizer_resource_budget.clone(),
+                            collection_name.clone(),
                             desired_cpus,
                             desired_io,
                             sender.clone(),
@@ -178,6 +212,7 @@ impl UpdateWorkers {
             }
 
             Self::process_optimization(
+                &collection_name,
                 optimizers.clone(),
                 segments.clone(),
                 optimization_handles.clone(),
@@ -226,6 +261,7 @@ impl UpdateWorkers {
 
     #[allow(clippy::too_many_arguments)]
     pub(crate) async fn process_optimization(
+        collection_name: &str,
         optimizers: Arc<Vec<Arc<Optimizer>>>,
         segments: LockedSegmentHolder,
         optimization_handles: Arc<TokioMutex<Vec<StoppableTaskHandle<bool>>>>,
@@ -237,6 +273,7 @@ impl UpdateWorkers {
         limit: usize,
     ) {
         let mut new_handles = Self::launch_optimization(
+            collection_name,
             optimizers.clone(),
             optimizers_log,
             total_optimized_points,
@@ -262,7 +299,11 @@ impl UpdateWorkers {
     /// Checks conditions for all optimizers until there is no suggested segment
     /// Starts a task for each optimization
     /// Returns handles for started tasks
+    ///
+    /// Each task takes a node-wide job slot on behalf of `collection_name`.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn launch_optimization<F>(
+        collection_name: &str,
         optimizers: Arc<Vec<Arc<Optimizer>>>,
         optimizers_log: Arc<Mutex<TrackerLog>>,
         total_optimized_points: Arc<AtomicUsize>,
@@ -276,22 +317,13 @@ impl UpdateWorkers {
     {
         let mut handles = vec![];
         let is_optimization_failed = Arc::new(AtomicBool::new(false));
+        let mut is_waiting_for_job = false;
+        // Reason to postpone all remaining optimizations, they are still recorded as skipped
+        let mut postponed = None;
 
         let scheduled = plan_optimizations(&segments.read(), &optimizers);
         for (optimizer, segments_to_merge) in scheduled {
-            // Return early if we reached the optimization job limit
-            if limit.map(|extra| handles.len() >= extra).unwrap_or(false) {
-                log::trace!("Reached optimization job limit, postponing other optimizations");
-                break;
-            }
-
-            // If optimization failed, we should not endlessly try to optimize same segments
-            if is_optimization_failed.load(Ordering::Relaxed) {
-                log::debug!("Skipping further optimizations due to previous failure");
-                break;
-            }
-
-            let segment_infos = {
+            let (segment_infos, candidates): (Vec<_>, Vec<_>) = {
                 let segments = segments.read();
                 segments_to_merge
                     .iter()
@@ -301,29 +333,94 @@ impl UpdateWorkers {
                             return None;
                         };
                         let segment = segment.get().read();
-                        Some(TrackerSegmentInfo {
+                        let points_count = segment.available_point_count();
+                        let deleted_count = segment.deleted_point_count();
+                        let total_count = points_count + deleted_count;
+                        let segment_info = TrackerSegmentInfo {
                             id,
                             uuid: segment.segment_uuid(),
-                            points_count: segment.available_point_count(),
-                        })
+                            points_count,
+                        };
+                        let candidate = OptimizationCandidateInfo {
+                            uuid: segment.segment_uuid(),
+                            points_count,
+                            deleted_count,
+                            deleted_ratio: if total_count > 0 {
+                                deleted_count as f64 / total_count as f64
+                            } else {
+                                0.0
+                            },
+                            vectors_size_bytes: segment
+                                .max_available_vectors_size_in_bytes()
+                                .unwrap_or_default(),
+                        };
+                        Some((segment_info, candidate))
                     })
-                    .collect_vec()
+                    .unzip()
+            };
+            let skip = |reason: &str| {
+                Self::record_decision(
+                    &optimizers_log,
+                    optimizer.as_ref(),
+                    candidates.clone(),
+                    Some(reason),
+                    None,
+                );
             };
 
-            log::debug!(
-                "Optimizer '{}' running on segments: {uuids}",
-                optimizer.name(),
-                uuids = segment_infos.iter().format_with(", ", |segment_info, f| {
-                    f(&format_args!("{}", segment_info.uuid))
-                })
-            );
+            if let Some(reason) = postponed {
+                skip(reason);
+                continue;
+            }
+
+            // Postpone remaining optimizations if we reached the optimization job limit
+            if limit.map(|extra| handles.len() >= extra).unwrap_or(false) {
+                log::trace!("Reached optimization job limit, postponing other optimizations");
+                postponed = Some("optimization job limit reached");
+                skip("optimization job limit reached");
+                continue;
+            }
+
+            // Don't start optimizers which were cancelled by the user, until they are resumed
+            if optimizers_log.lock().is_suspended(optimizer.name()) {
+                log::trace!("Optimizer '{}' is suspended, skipping", optimizer.name());
+                skip("optimizer is suspended");
+                continue;
+            }
+
+            // If optimization failed, we should not endlessly try to optimize same segments
+            if is_optimization_failed.load(Ordering::Relaxed) {
+                log::debug!("Skipping further optimizations due to previous failure");
+                postponed = Some("previous optimization failed");
+                skip("previous optimization failed");
+                continue;
+            }
+
+            // Take a node-wide job slot, shared fairly between all collections on this node
+            let Some(job_permit) = optimizer_resource_budget.try_acquire_job(collection_name)
+            else {
+                // If there is no free slot, postpone remaining optimizations
+                // If we have no handles (no optimizations) trigger callback so that we wake up
+                // our optimization worker to try again later, otherwise it could get stuck
+                log::trace!(
+                    "No available optimization job slot for {} optimizer, postponing",
+                    optimizer.name(),
+                );
+                if handles.is_empty() {
+                    callback();
+                }
+                postponed = Some("no available optimization job slot");
+                skip("no available optimization job slot");
+                is_waiting_for_job = true;
+                continue;
+            };
 
             // Determine how many Resources we prefer for optimization task, acquire permit for it
             // And use same amount of IO threads as CPUs
             let max_indexing_threads = optimizer.hnsw_config().max_indexing_threads;
             let desired_io = num_rayon_threads(max_indexing_threads);
             let Some(mut permit) = optimizer_resource_budget.try_acquire(0, desired_io) else {
-                // If there is no Resource budget, break and return early
+                // If there is no Resource budget, postpone remaining optimizations
                 // If we have no handles (no optimizations) trigger callback so that we wake up
                 // our optimization worker to try again later, otherwise it could get stuck
                 log::trace!(
@@ -333,7 +430,9 @@ impl UpdateWorkers {
                 if handles.is_empty() {
                     callback();
                 }
-                break;
+                postponed = Some("no available IO budget");
+                skip("no available IO budget");
+                continue;
             };
             log::trace!(
                 "Acquired {} IO permit for {} optimizer",
@@ -358,11 +457,18 @@ impl UpdateWorkers {
 
             // Track optimizer status
             let new_segment_uuid = Uuid::new_v4();
+            Self::record_decision(
+                &optimizers_log,
+                optimizer.as_ref(),
+                candidates,
+                None,
+                Some(new_segment_uuid),
+            );
             let (tracker, progress) =
                 Tracker::start(optimizer.name(), new_segment_uuid, segment_infos);
             let tracker_handle = tracker.handle();
 
-            let handle = spawn_stoppable(move |stopped| {
+            let handle = spawn_stoppable_with_flag(tracker.stopped(), move |stopped| {
                 let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     optimizer.as_ref().optimize(
                         segments.clone(),
@@ -379,6 +485,8 @@ impl UpdateWorkers {
                         }),
                     )
                 }));
+                // Give back job slot before triggering next optimizations
+                drop(job_permit);
                 let is_optimized;
                 let status;
                 let reported_error;
@@ -430,9 +538,67 @@ impl UpdateWorkers {
             handles.push(handle);
         }
 
+        // Don't hold up other collections in the job queue, if there is nothing left to start
+        if !is_waiting_for_job {
+            optimizer_resource_budget.cancel_job_wait(collection_name);
+        }
+
         handles
     }
 
+    /// Record a decision of the optimizer about the selected segments in the optimizers log.
+    ///
+    /// Optimization is started if `uuid` is set, otherwise it is skipped for the given `reason`.
+    fn record_decision(
+        optimizers_log: &Mutex<TrackerLog>,
+        optimizer: &Optimizer,
+        candidates: Vec<OptimizationCandidateInfo>,
+        reason: Option<&str>,
+        uuid: Option<Uuid>,
+    ) {
+        let action = match uuid {
+            Some(_) => OptimizationAction::Started,
+            None => OptimizationAction::Skipped,
+        };
+        let optimizer_name = optimizer.name();
+        let decision = OptimizationDecision {
+            optimizer: optimizer_name.to_string(),
+            trigger: optimizer.trigger(),
+            estimated_points: candidates.iter().map(|c| c.points_count).sum(),
+            estimated_vectors_size_bytes: candidates.iter().map(|c| c.vectors_size_bytes).sum(),
+            segments: candidates,
+            action,
+            reason: reason.map(str::to_string),
+            uuid,
+            decided_at: chrono::Utc::now(),
+            repeated: 1,
+        };
+
+        let description = format!(
+            "Optimizer '{optimizer_name}' {action:?} on segments [{segments}] ({trigger}), \
+             estimated {points} points, {size} bytes of vectors{reason}",
+            segments = decision.segments.iter().format_with(", ", |segment, f| {
+                f(&format_args!(
+                    "{} ({} points, {:.2} deleted)",
+                    segment.uuid, segment.points_count, segment.deleted_ratio,
+                ))
+            }),
+            trigger = decision.trigger,
+            points = decision.estimated_points,
+            size = decision.estimated_vectors_size_bytes,
+            reason = reason
+                .map(|reason| format!(": {reason}"))
+                .unwrap_or_default(),
+        );
+
+        // Repeated decisions are only logged on the trace level, not to clutter the log
+        if optimizers_log.lock().record_decision(decision) {
+            log::debug!("{description}");
+        } else {
+            log::trace!("{description}");
+        }
+    }
+
     /// Ensure there is at least one appendable segment with enough capacity
     ///
     /// If there is no appendable segment, or all are at or over capacity, a new empty one is
@@ -488,9 +654,10 @@ impl UpdateWorkers {
         Ok(())
     }
 
-    /// Trigger optimizers when CPU budget is available
+    /// Trigger optimizers when CPU budget and a job slot are available
     fn trigger_optimizers_on_resource_budget(
         optimizer_resource_budget: ResourceBudget,
+        collection_name: CollectionId,
         desired_cpus: usize,
         desired_io: usize,
         sender: Sender<OptimizerSignal>,
@@ -500,8 +667,30 @@ impl UpdateWorkers {
             optimizer_resource_budget
                 .notify_on_budget_available(desired_cpus, desired_io)
                 .await;
+            optimizer_resource_budget
+                .notify_on_job_slot_available(&collection_name)
+                .await;
             log::trace!("Continue optimization checks, new CPU budget available");
 
+            // Trigger optimizers with Nop operation
+            if sender.send(OptimizerSignal::Nop).await.is_err() {
+                log::info!("Can't notify optimizers, assume process is dead. Restart is required");
+                optimizer_resource_budget.cancel_job_wait(&collection_name);
+            }
+        })
+    }
+
+    fn trigger_optimizers_on_disk_space(
+        disk_usage_watcher: Arc<DiskUsageWatcher>,
+        sender: Sender<OptimizerSignal>,
+    ) -> JoinHandle<()> {
+        task::spawn(async move {
+            log::warn!("Pausing optimizations, free disk space is below the flood watermark");
+            while disk_usage_watcher.is_flooded().await {
+                tokio::time::sleep(FLOOD_RECHECK_INTERVAL).await;
+            }
+            log::info!("Resuming optimizations, free disk space is above the flood watermark");
+
             // Trigger optimizers with Nop operation
             sender.send(OptimizerSignal::Nop).await.unwrap_or_else(|_| {
                 log::info!("Can't notify optimizers, assume process is dead. Restart is required")
@@ -516,6 +705,7 @@ impl UpdateWorkers {
         wal: LockedWal,
         update_operation_lock: Arc<tokio::sync::RwLock<()>>,
         update_tracker: UpdateTracker,
+        index_builds_stopped: &AtomicBool,
     ) -> CollectionResult<usize> {
         // Try to re-apply everything starting from the first failed operation
         let first_failed_operation_option = segments.read().failed_operation.iter().cloned().min();
@@ -530,6 +720,7 @@ impl UpdateWorkers {
                         operation.operation,
                         update_operation_lock.clone(),
                         update_tracker.clone(),
+                        index_builds_stopped,
                         &HardwareCounterCell::disposable(), // Internal operation, no measurement needed
                     )?;
                 }
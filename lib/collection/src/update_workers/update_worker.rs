@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 use std::time::Instant;
 
 use cancel::CancellationToken;
@@ -20,6 +21,7 @@ use crate::shards::update_tracker::UpdateTracker;
 use crate::update_handler::{OperationData, OptimizerSignal, UpdateSignal};
 use crate::update_workers::UpdateWorkers;
 use crate::update_workers::applied_seq::AppliedSeqHandler;
+use crate::update_workers::flush_workers::DirtyBytes;
 use crate::wal_delta::LockedWal;
 
 const BYTES_IN_KB: usize = 1024;
@@ -51,7 +53,10 @@ impl UpdateWorkers {
         segments: LockedSegmentHolder,
         update_operation_lock: Arc<tokio::sync::RwLock<()>>,
         update_tracker: UpdateTracker,
+        index_builds_stopped: Arc<AtomicBool>,
         prevent_unoptimized_threshold_kb: Option<usize>,
+        dirty_bytes: Arc<DirtyBytes>,
+        flush_max_dirty_bytes: Option<usize>,
         optimization_handles: Arc<TokioMutex<Vec<StoppableTaskHandle<bool>>>>,
         mut optimization_finished_receiver: watch::Receiver<()>,
         applied_seq_handler: Arc<AppliedSeqHandler>,
@@ -81,6 +86,8 @@ impl UpdateWorkers {
                     let segments_clone = segments.clone();
                     let update_operation_lock_clone = update_operation_lock.clone();
                     let update_tracker_clone = update_tracker.clone();
+                    let index_builds_stopped_clone = index_builds_stopped.clone();
+                    let dirty_bytes_clone = dirty_bytes.clone();
 
                     let operation = if let Some(operation) = operation {
                         *operation
@@ -145,7 +152,10 @@ impl UpdateWorkers {
                             segments_clone,
                             update_operation_lock_clone,
                             update_tracker_clone,
+                            &index_builds_stopped_clone,
                             hw_measurements,
+                            &dirty_bytes_clone,
+                            flush_max_dirty_bytes,
                         )
                     })
                     .await;
@@ -257,7 +267,10 @@ impl UpdateWorkers {
         segments: LockedSegmentHolder,
         update_operation_lock: Arc<tokio::sync::RwLock<()>>,
         update_tracker: UpdateTracker,
+        index_builds_stopped: &AtomicBool,
         hw_measurements: HwMeasurementAcc,
+        dirty_bytes: &DirtyBytes,
+        flush_max_dirty_bytes: Option<usize>,
     ) -> CollectionResult<usize> {
         // If wait flag is set, explicitly flush WAL first
         if wait {
@@ -274,15 +287,22 @@ impl UpdateWorkers {
         // Do not use for anything else
         let loggable_operation = operation.remove_details();
 
+        let hw_counter = hw_measurements.get_counter_cell();
         let result = CollectionUpdater::update(
             &segments,
             op_num,
             operation,
             update_operation_lock.clone(),
             update_tracker.clone(),
-            &hw_measurements.get_counter_cell(),
+            index_builds_stopped,
+            &hw_counter,
         );
 
+        let written_bytes = hw_counter.payload_io_write_counter().get()
+            + hw_counter.payload_index_io_write_counter().get()
+            + hw_counter.vector_io_write_counter().get();
+        dirty_bytes.add(written_bytes, flush_max_dirty_bytes);
+
         let duration = start_time.elapsed();
 
         log_request_to_collector(&collection_name, duration, move || loggable_operation);
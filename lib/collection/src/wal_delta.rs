@@ -128,6 +128,17 @@ impl RecoverableWal {
         self.newest_clocks.lock().await.to_recovery_point()
     }
 
+    /// Get the recovery point, once all operations included in it are written to the WAL, and
+    /// released the WAL lock.
+    ///
+    /// Writers queue on the WAL lock right after advancing the newest clocks, and the lock is
+    /// fair. So all writers of included operations are done, once we acquired the lock.
+    pub async fn written_recovery_point(&self) -> RecoveryPoint {
+        let recovery_point = self.recovery_point().await;
+        drop(self.wal.lock().await);
+        recovery_point
+    }
+
     pub async fn resolve_wal_delta(
         &self,
         recovery_point: RecoveryPoint,
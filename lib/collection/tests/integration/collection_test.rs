@@ -295,6 +295,7 @@ async fn test_collection_loading_with_shards(shard_number: u32) {
         ids: vec![1.into(), 2.into()],
         with_payload: Some(WithPayloadInterface::Bool(true)),
         with_vector: true.into(),
+        with_version: None,
     };
     let retrieved = loaded_collection
         .retrieve(
@@ -493,6 +494,8 @@ async fn test_read_api_with_shards(shard_number: u32) {
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vector: false.into(),
                 order_by: None,
+                distinct_by: None,
+                with_version: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -633,6 +636,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         direction: Some(Direction::Asc),
                         start_from: None,
                     })),
+                    distinct_by: None,
+                    with_version: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -665,6 +670,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         direction: Some(Direction::Desc),
                         start_from: None,
                     })),
+                    distinct_by: None,
+                    with_version: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -706,6 +713,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         direction: Some(Direction::Asc),
                         start_from: None,
                     })),
+                    distinct_by: None,
+                    with_version: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -746,6 +755,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         direction: Some(Direction::Desc),
                         start_from: None,
                     })),
+                    distinct_by: None,
+                    with_version: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -783,6 +794,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vector: false.into(),
                 order_by: Some(OrderByInterface::Key(MULTI_VALUE_KEY.parse().unwrap())),
+                distinct_by: None,
+                with_version: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -892,6 +905,8 @@ async fn test_collection_delete_points_by_filter_with_shards(shard_number: u32)
                 with_payload: Some(WithPayloadInterface::Bool(false)),
                 with_vector: false.into(),
                 order_by: None,
+                distinct_by: None,
+                with_version: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -33,6 +33,8 @@ pub const TEST_OPTIMIZERS_CONFIG: OptimizersConfig = OptimizersConfig {
     flush_interval_sec: 30,
     max_optimization_threads: Some(2),
     prevent_unoptimized: None,
+    max_builder_memory: None,
+    flush_max_dirty: None,
 };
 
 #[cfg(test)]
@@ -41,6 +43,8 @@ pub async fn simple_collection_fixture(collection_path: &Path, shard_number: u32
         wal_capacity_mb: 1,
         wal_segments_ahead: 0,
         wal_retain_closed: 1,
+        wal_batch_window_ms: 0,
+        wal_batch_max_points: 256,
     };
 
     let collection_params = CollectionParams {
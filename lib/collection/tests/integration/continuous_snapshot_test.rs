@@ -156,6 +156,7 @@ async fn test_continuous_snapshot() {
                         ids: vec![i.into()],
                         with_payload: None,
                         with_vector: WithVector::Bool(false),
+                        with_version: None,
                     };
                     let hw_counter = HwMeasurementAcc::new();
                     let retrieve_result = collection
@@ -202,6 +203,7 @@ async fn test_continuous_snapshot() {
                         ids: vec![i.into()],
                         with_payload: Some(true.into()),
                         with_vector: WithVector::Bool(true),
+                        with_version: None,
                     };
                     let hw_counter = HwMeasurementAcc::new();
                     let retrieve_result = collection
@@ -231,7 +233,7 @@ async fn test_continuous_snapshot() {
             while !stop_flag.load(Ordering::Relaxed) {
                 // Take snapshot
                 let _snapshot = collection
-                    .create_snapshot(snapshots_temp_dir.path(), 0)
+                    .create_snapshot(snapshots_temp_dir.path(), 0, None)
                     .await?;
             }
             CollectionResult::Ok(())
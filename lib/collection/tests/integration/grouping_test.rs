@@ -643,6 +643,7 @@ mod group_by_builder {
             collection_name: "test".to_string(),
             with_payload: Some(true.into()),
             with_vectors: Some(true.into()),
+            filter: None,
         });
 
         let collection_by_name = |_: String| async { Some(lookup_collection.clone()) };
@@ -17,7 +17,7 @@ use rand::{self, Rng, SeedableRng};
 use rstest::*;
 use segment::data_types::vectors::VectorStructInternal;
 use segment::payload_json;
-use segment::types::PointIdType;
+use segment::types::{Condition, FieldCondition, Filter, PointIdType};
 use tempfile::Builder;
 use uuid::Uuid;
 
@@ -37,6 +37,7 @@ async fn setup() -> Resources {
         collection_name: "test".to_string(),
         with_payload: None,
         with_vectors: None,
+        filter: None,
     };
 
     let collection_dir = Builder::new().prefix("storage").tempdir().unwrap();
@@ -165,6 +166,53 @@ async fn happy_lookup_ids() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn filtered_lookup_ids() {
+    let Resources {
+        mut request,
+        collection,
+        read_consistency,
+        shard_selection,
+    } = setup().await;
+
+    let collection = collection.clone();
+
+    let collection_by_name = |_: String| async { Some(collection) };
+
+    let shard_selection = match shard_selection {
+        Some(shard_id) => ShardSelectorInternal::ShardId(shard_id),
+        None => ShardSelectorInternal::All,
+    };
+
+    let values: Vec<PseudoId> = (0..10u64).map_into().collect();
+    request.with_payload = Some(true.into());
+    request.with_vectors = Some(false.into());
+    request.filter = Some(Filter::new_must(Condition::Field(
+        FieldCondition::new_match("foo".parse().unwrap(), "bar 3".to_string().into()),
+    )));
+
+    let result = lookup_ids(
+        request,
+        values,
+        collection_by_name,
+        read_consistency,
+        &shard_selection,
+        None,
+        HwMeasurementAcc::new(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.len(), 1);
+
+    let record = result
+        .get(&PseudoId::NumberU64(3))
+        .expect("Expected to find record for id 3");
+    assert_eq!(record.id, PointIdType::from(3));
+    assert_eq!(record.payload, Some(payload_json! { "foo": "bar 3" }));
+    assert_eq!(record.vector, None);
+}
+
 fn first_uuid() -> String {
     let mut rng = SmallRng::seed_from_u64(SEED);
     Uuid::from_u128(rng.random()).to_string()
@@ -39,6 +39,8 @@ pub async fn multi_vec_collection_fixture(collection_path: &Path, shard_number:
         wal_capacity_mb: 1,
         wal_segments_ahead: 0,
         wal_retain_closed: 1,
+        wal_batch_window_ms: 0,
+        wal_batch_max_points: 256,
     };
 
     let vector_params1 = VectorParamsBuilder::new(4, Distance::Dot).build();
@@ -234,6 +236,7 @@ async fn test_multi_vec_with_shards(shard_number: u32) {
                 ids: vec![6.into()],
                 with_payload: Some(WithPayloadInterface::Bool(false)),
                 with_vector: WithVector::Selector(vec![VECTOR1_NAME.to_owned()]),
+                with_version: None,
             },
             None,
             &ShardSelectorInternal::All,
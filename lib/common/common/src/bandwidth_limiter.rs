@@ -0,0 +1,150 @@
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+/// Limits the throughput of data transfers, shared by all transfers using the same limiter.
+///
+/// Based on the token bucket algorithm, where a token is a byte. The bucket holds up to one
+/// second worth of tokens to allow for small bursts.
+///
+/// Consumers never fail, instead they are delayed until the consumed bytes fit into the limit.
+/// Bytes are reserved before waiting, so concurrent consumers are served in order.
+#[derive(Debug)]
+pub struct BandwidthLimiter {
+    bucket: Mutex<Option<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    bytes_per_sec: u64,
+    /// Available bytes, negative if bytes are reserved by waiting consumers
+    available: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec,
+            available: bytes_per_sec as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Reserve bytes, returns how long the consumer has to wait before transferring them
+    fn reserve(&mut self, bytes: usize) -> Duration {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill);
+        self.last_refill = now;
+
+        let capacity = self.bytes_per_sec as f64;
+        self.available = (self.available + capacity * elapsed.as_secs_f64()).min(capacity);
+        self.available -= bytes as f64;
+
+        if self.available >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.available / capacity)
+        }
+    }
+}
+
+impl BandwidthLimiter {
+    /// Create a limiter without a limit
+    pub const fn unlimited() -> Self {
+        Self {
+            bucket: parking_lot::const_mutex(None),
+        }
+    }
+
+    pub fn new(bytes_per_sec: Option<u64>) -> Self {
+        let limiter = Self::unlimited();
+        limiter.set_limit(bytes_per_sec);
+        limiter
+    }
+
+    /// Current limit in bytes per second, `None` if unlimited
+    pub fn limit(&self) -> Option<u64> {
+        self.bucket
+            .lock()
+            .as_ref()
+            .map(|bucket| bucket.bytes_per_sec)
+    }
+
+    /// Change the limit, takes effect for all following transfers
+    ///
+    /// Zero limit is treated as unlimited.
+    pub fn set_limit(&self, bytes_per_sec: Option<u64>) {
+        let mut bucket = self.bucket.lock();
+        match bytes_per_sec.filter(|&limit| limit > 0) {
+            Some(limit) if bucket.as_ref().is_some_and(|b| b.bytes_per_sec == limit) => {}
+            limit => *bucket = limit.map(Bucket::new),
+        }
+    }
+
+    /// How long the consumer has to wait before transferring the given number of bytes
+    fn reserve(&self, bytes: usize) -> Duration {
+        self.bucket
+            .lock()
+            .as_mut()
+            .map_or(Duration::ZERO, |bucket| bucket.reserve(bytes))
+    }
+
+    /// Wait until the given number of bytes can be transferred
+    pub async fn consume(&self, bytes: usize) {
+        let delay = self.reserve(bytes);
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+impl Default for BandwidthLimiter {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlimited() {
+        let limiter = BandwidthLimiter::unlimited();
+        assert_eq!(limiter.limit(), None);
+        assert_eq!(limiter.reserve(usize::MAX), Duration::ZERO);
+
+        limiter.set_limit(Some(0));
+        assert_eq!(limiter.limit(), None);
+    }
+
+    #[test]
+    fn test_limited() {
+        let limiter = BandwidthLimiter::new(Some(1000));
+        assert_eq!(limiter.limit(), Some(1000));
+
+        // Initial burst fits into the bucket
+        assert_eq!(limiter.reserve(1000), Duration::ZERO);
+
+        // Bucket is empty, next bytes have to wait
+        let delay = limiter.reserve(500);
+        assert!(delay > Duration::from_millis(400), "{delay:?}");
+        assert!(delay <= Duration::from_millis(500), "{delay:?}");
+
+        // Reserved bytes delay the following consumers
+        let delay = limiter.reserve(500);
+        assert!(delay > Duration::from_millis(900), "{delay:?}");
+
+        // Same limit keeps the reservations
+        limiter.set_limit(Some(1000));
+        assert!(limiter.reserve(1) > Duration::from_millis(900));
+
+        // New limit starts with a full bucket
+        limiter.set_limit(Some(2000));
+        assert_eq!(limiter.reserve(2000), Duration::ZERO);
+
+        limiter.set_limit(None);
+        assert_eq!(limiter.reserve(usize::MAX), Duration::ZERO);
+    }
+}
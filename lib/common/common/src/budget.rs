@@ -1,8 +1,10 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use tokio::sync::{OwnedSemaphorePermit, Semaphore, TryAcquireError};
+use parking_lot::Mutex;
+use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore, TryAcquireError};
 use tokio::time;
 
 use crate::cpu;
@@ -25,27 +27,65 @@ pub fn get_io_budget(io_budget: usize, cpu_budget: usize) -> usize {
 #[derive(Debug, Clone)]
 pub struct ResourceBudget {
     cpu_semaphore: Arc<Semaphore>,
-    /// Total CPU budget, available and leased out.
-    cpu_budget: usize,
+    /// Total CPU budget, available and leased out. Can be changed at runtime.
+    cpu_budget: Arc<Mutex<CpuBudget>>,
 
     io_semaphore: Arc<Semaphore>,
     /// Total IO budget, available and leased out.
     io_budget: usize,
+
+    /// Optional limit on the number of concurrent optimization jobs on this node.
+    jobs: Option<Arc<JobLimit>>,
 }
 
 impl ResourceBudget {
     pub fn new(cpu_budget: usize, io_budget: usize) -> Self {
         Self {
             cpu_semaphore: Arc::new(Semaphore::new(cpu_budget)),
-            cpu_budget,
+            cpu_budget: Arc::new(Mutex::new(CpuBudget {
+                budget: cpu_budget,
+                total: cpu_budget,
+                reserved: None,
+            })),
             io_semaphore: Arc::new(Semaphore::new(io_budget)),
             io_budget,
+            jobs: None,
         }
     }
 
+    /// Limit the number of concurrent optimization jobs, shared fairly between job owners.
+    ///
+    /// `None` or `0` means no limit.
+    pub fn with_max_concurrent_jobs(mut self, max_jobs: Option<usize>) -> Self {
+        self.jobs = max_jobs
+            .filter(|&max_jobs| max_jobs > 0)
+            .map(|max_jobs| Arc::new(JobLimit::new(max_jobs)));
+        self
+    }
+
     /// Returns the total CPU budget.
     pub fn available_cpu_budget(&self) -> usize {
-        self.cpu_budget
+        self.cpu_budget.lock().budget
+    }
+
+    /// Change the total CPU budget at runtime.
+    ///
+    /// Increased budget is available immediately. Reduced budget takes effect as running tasks
+    /// release their CPU permits, tasks are not interrupted.
+    pub fn set_cpu_budget(&self, cpu_budget: usize) {
+        let cpu_budget = cpu_budget.max(1);
+        let mut state = self.cpu_budget.lock();
+        if cpu_budget > state.total {
+            self.cpu_semaphore.add_permits(cpu_budget - state.total);
+            state.total = cpu_budget;
+        }
+        state.budget = cpu_budget;
+        state.reclaim(&self.cpu_semaphore);
+    }
+
+    /// Take CPU permits above the current budget out of circulation, as they are released.
+    fn reclaim_cpu(&self) {
+        self.cpu_budget.lock().reclaim(&self.cpu_semaphore);
     }
 
     /// Returns the total IO budget.
@@ -55,7 +95,7 @@ impl ResourceBudget {
 
     /// For the given desired number of CPUs, return the minimum number of required CPUs.
     fn min_cpu_permits(&self, desired_cpus: usize) -> usize {
-        desired_cpus.min(self.cpu_budget).div_ceil(2)
+        desired_cpus.min(self.available_cpu_budget()).div_ceil(2)
     }
 
     fn min_io_permits(&self, desired_io: usize) -> usize {
@@ -66,6 +106,7 @@ impl ResourceBudget {
         &self,
         desired_cpus: usize,
     ) -> Option<(usize, Option<OwnedSemaphorePermit>)> {
+        self.reclaim_cpu();
         let min_required_cpus = self.min_cpu_permits(desired_cpus) as u32;
         let num_cpus = self.cpu_semaphore.available_permits().min(desired_cpus) as u32;
         if num_cpus < min_required_cpus {
@@ -163,7 +204,7 @@ impl ResourceBudget {
         stopped: &AtomicBool,
     ) -> Result<ResourcePermit, ResourcePermit> {
         // Make sure we don't exceed the budget, otherwise we might deadlock
-        let new_desired_cpus = new_desired_cpus.min(self.cpu_budget);
+        let new_desired_cpus = new_desired_cpus.min(self.available_cpu_budget());
         let new_desired_io = new_desired_io.min(self.io_budget);
 
         // Acquire extra resources we don't have yet
@@ -202,6 +243,7 @@ impl ResourceBudget {
     ///
     /// A budget of `0` will always return `true`.
     pub fn has_budget_exact(&self, cpu_budget: usize, io_budget: usize) -> bool {
+        self.reclaim_cpu();
         self.cpu_semaphore.available_permits() >= cpu_budget
             && self.io_semaphore.available_permits() >= io_budget
     }
@@ -233,6 +275,64 @@ impl ResourceBudget {
             delay = (delay * 2).min(Duration::from_secs(10));
         }
     }
+
+    /// Try to acquire a job slot for an optimization job of the given `owner`, e.g. a collection.
+    ///
+    /// Free slots are handed out fairly: an owner only gets a slot, if no other waiting owner
+    /// has fewer running jobs. Owners that failed to acquire a slot are queued, and keep their
+    /// place as long as they keep retrying or waiting, see [`Self::notify_on_job_slot_available`].
+    ///
+    /// Must only be called by owners with a pending job, otherwise they hold up other owners.
+    ///
+    /// Always succeeds if there is no job limit.
+    pub fn try_acquire_job(&self, owner: &str) -> Option<JobPermit> {
+        match &self.jobs {
+            Some(jobs) => jobs.try_acquire(owner),
+            None => Some(JobPermit {
+                jobs: None,
+                owner: String::new(),
+            }),
+        }
+    }
+
+    /// Check if the given `owner` would get a job slot right now.
+    ///
+    /// Does not queue the owner.
+    pub fn has_job_slot(&self, owner: &str) -> bool {
+        self.jobs.as_ref().is_none_or(|jobs| jobs.has_slot(owner))
+    }
+
+    /// Remove the given `owner` from the job queue, once it has no pending jobs.
+    pub fn cancel_job_wait(&self, owner: &str) {
+        if let Some(jobs) = &self.jobs {
+            jobs.cancel_wait(owner);
+        }
+    }
+
+    /// Notify when a job slot is available for the given `owner`.
+    ///
+    /// Woken up whenever a slot is released or an owner leaves the queue. A queued owner keeps
+    /// its place while waiting.
+    pub async fn notify_on_job_slot_available(&self, owner: &str) {
+        let Some(jobs) = &self.jobs else {
+            return;
+        };
+
+        loop {
+            // Register for notifications before checking, so that no release is missed
+            let changed = jobs.changed.notified();
+            if jobs.has_slot(owner) {
+                return;
+            }
+            jobs.keep_waiting(owner);
+            let _ = time::timeout(JOB_WAITER_REFRESH_INTERVAL, changed).await;
+        }
+    }
+
+    /// Current state of the job limit, `None` if there is no limit.
+    pub fn job_limit_status(&self) -> Option<JobLimitStatus> {
+        self.jobs.as_ref().map(|jobs| jobs.status())
+    }
 }
 
 impl Default for ResourceBudget {
@@ -243,6 +343,222 @@ impl Default for ResourceBudget {
     }
 }
 
+/// CPU budget, which can be changed at runtime.
+///
+/// Semaphore permits above the budget are reserved, so that tasks can't acquire them. Permits
+/// leased out at the time of the budget reduction are reserved later, once tasks release them.
+#[derive(Debug)]
+struct CpuBudget {
+    /// Current CPU budget.
+    budget: usize,
+    /// Total number of permits of the CPU semaphore.
+    total: usize,
+    /// Permits above the budget, taken out of circulation.
+    reserved: Option<OwnedSemaphorePermit>,
+}
+
+impl CpuBudget {
+    fn num_reserved(&self) -> usize {
+        self.reserved
+            .as_ref()
+            .map_or(0, |permit| permit.num_permits())
+    }
+
+    /// Reserve or release CPU permits, to match the current budget.
+    fn reclaim(&mut self, semaphore: &Arc<Semaphore>) {
+        let target = self.total - self.budget;
+        let num_reserved = self.num_reserved();
+
+        if num_reserved > target {
+            if let Some(reserved) = &mut self.reserved {
+                drop(reserved.split(num_reserved - target));
+            }
+            return;
+        }
+
+        let missing = (target - num_reserved).min(semaphore.available_permits());
+        if missing == 0 {
+            return;
+        }
+
+        if let Ok(permit) = Semaphore::try_acquire_many_owned(semaphore.clone(), missing as u32) {
+            match &mut self.reserved {
+                Some(reserved) => reserved.merge(permit),
+                None => self.reserved = Some(permit),
+            }
+        }
+    }
+}
+
+/// Time after which an owner that stopped retrying loses its place in the job queue.
+const JOB_WAITER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Interval in which an owner waiting for a job slot confirms its place in the job queue.
+const JOB_WAITER_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Node-wide limit on the number of concurrent optimization jobs.
+///
+/// Free slots go to the waiting owner with the fewest running jobs, ties are broken by waiting
+/// time. This prevents a few busy owners from starving all others.
+#[derive(Debug)]
+struct JobLimit {
+    max_jobs: usize,
+    state: Mutex<JobLimitState>,
+    /// Notified when a slot is released, or an owner leaves the queue.
+    changed: Notify,
+}
+
+#[derive(Debug, Default)]
+struct JobLimitState {
+    /// Number of running jobs per owner.
+    running: HashMap<String, usize>,
+    /// Owners waiting for a free slot.
+    waiting: HashMap<String, JobWaiter>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct JobWaiter {
+    since: Instant,
+    last_seen: Instant,
+}
+
+/// Current state of the job limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobLimitStatus {
+    pub max_jobs: usize,
+    pub running_jobs: usize,
+    pub waiting_owners: usize,
+}
+
+impl JobLimit {
+    fn new(max_jobs: usize) -> Self {
+        Self {
+            max_jobs,
+            state: Mutex::new(JobLimitState::default()),
+            changed: Notify::new(),
+        }
+    }
+
+    fn try_acquire(self: &Arc<Self>, owner: &str) -> Option<JobPermit> {
+        let now = Instant::now();
+        let mut state = self.state.lock();
+        if !state.is_next(owner, self.max_jobs, now) {
+            state.wait(owner, now);
+            return None;
+        }
+
+        state.waiting.remove(owner);
+        *state.running.entry(owner.to_string()).or_default() += 1;
+
+        Some(JobPermit {
+            jobs: Some(self.clone()),
+            owner: owner.to_string(),
+        })
+    }
+
+    fn has_slot(&self, owner: &str) -> bool {
+        self.state
+            .lock()
+            .is_next(owner, self.max_jobs, Instant::now())
+    }
+
+    /// Confirm that a queued `owner` is still waiting, has no effect on owners not in the queue.
+    fn keep_waiting(&self, owner: &str) {
+        if let Some(waiter) = self.state.lock().waiting.get_mut(owner) {
+            waiter.last_seen = Instant::now();
+        }
+    }
+
+    fn cancel_wait(&self, owner: &str) {
+        let removed = self.state.lock().waiting.remove(owner).is_some();
+        if removed {
+            self.changed.notify_waiters();
+        }
+    }
+
+    fn release(&self, owner: &str) {
+        {
+            let mut state = self.state.lock();
+            if let Some(running) = state.running.get_mut(owner) {
+                *running -= 1;
+                if *running == 0 {
+                    state.running.remove(owner);
+                }
+            }
+        }
+        self.changed.notify_waiters();
+    }
+
+    fn status(&self) -> JobLimitStatus {
+        let now = Instant::now();
+        let state = self.state.lock();
+        JobLimitStatus {
+            max_jobs: self.max_jobs,
+            running_jobs: state.running.values().sum(),
+            waiting_owners: state
+                .waiting
+                .values()
+                .filter(|waiter| waiter.is_alive(now))
+                .count(),
+        }
+    }
+}
+
+impl JobWaiter {
+    fn is_alive(&self, now: Instant) -> bool {
+        now.duration_since(self.last_seen) < JOB_WAITER_TIMEOUT
+    }
+}
+
+impl JobLimitState {
+    fn running(&self, owner: &str) -> usize {
+        self.running.get(owner).copied().unwrap_or(0)
+    }
+
+    /// Check if `owner` is next in line for a free slot.
+    ///
+    /// An owner, which is not queued, is considered to start waiting `now`.
+    fn is_next(&self, owner: &str, max_jobs: usize, now: Instant) -> bool {
+        if self.running.values().sum::<usize>() >= max_jobs {
+            return false;
+        }
+
+        let since = self.waiting.get(owner).map_or(now, |waiter| waiter.since);
+        let priority = (self.running(owner), since);
+
+        self.waiting
+            .iter()
+            .filter(|(waiting_owner, waiter)| *waiting_owner != owner && waiter.is_alive(now))
+            .all(|(waiting_owner, waiter)| priority <= (self.running(waiting_owner), waiter.since))
+    }
+
+    /// Queue `owner` for a free slot, keeping its place if it is already queued.
+    fn wait(&mut self, owner: &str, now: Instant) {
+        self.waiting.retain(|_, waiter| waiter.is_alive(now));
+
+        let waiter = self.waiting.entry(owner.to_string()).or_insert(JobWaiter {
+            since: now,
+            last_seen: now,
+        });
+        waiter.last_seen = now;
+    }
+}
+
+/// Slot of a single optimization job, gives the slot back when dropped.
+#[derive(Debug)]
+pub struct JobPermit {
+    jobs: Option<Arc<JobLimit>>,
+    owner: String,
+}
+
+impl Drop for JobPermit {
+    fn drop(&mut self) {
+        if let Some(jobs) = self.jobs.take() {
+            jobs.release(&self.owner);
+        }
+    }
+}
+
 /// Resource permit, used to limit number of concurrent resource-intensive operations.
 /// For example HNSW indexing (which is CPU-bound) can be limited to a certain number of CPUs.
 /// Or an I/O-bound operations like segment moving can be limited by I/O permits.
@@ -406,3 +722,122 @@ impl Drop for ResourcePermit {
         let _ = io_permit.take();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_cpu_budget() {
+        let budget = ResourceBudget::new(4, 4);
+        let permit = budget.try_acquire(4, 0).unwrap();
+        assert_eq!(permit.num_cpus, 4);
+
+        // Reduced budget is reserved once the permit is released
+        budget.set_cpu_budget(2);
+        assert_eq!(budget.available_cpu_budget(), 2);
+        drop(permit);
+        assert!(!budget.has_budget_exact(3, 0));
+        assert_eq!(budget.try_acquire(4, 0).unwrap().num_cpus, 2);
+
+        // Increased budget is available immediately
+        budget.set_cpu_budget(6);
+        assert!(budget.has_budget_exact(6, 0));
+        assert_eq!(budget.try_acquire(6, 0).unwrap().num_cpus, 6);
+    }
+
+    #[test]
+    fn test_job_slots_prefer_owner_with_fewer_jobs() {
+        let budget = ResourceBudget::new(4, 4).with_max_concurrent_jobs(Some(2));
+
+        let a1 = budget.try_acquire_job("a").unwrap();
+        let _a2 = budget.try_acquire_job("a").unwrap();
+
+        // No free slots, `b` is queued
+        assert!(budget.try_acquire_job("b").is_none());
+        assert_eq!(budget.job_limit_status().unwrap().waiting_owners, 1);
+
+        // Released slot goes to `b`, which has no running jobs
+        drop(a1);
+        assert!(!budget.has_job_slot("a"));
+        assert!(budget.try_acquire_job("a").is_none());
+        assert!(budget.has_job_slot("b"));
+        let _b1 = budget.try_acquire_job("b").unwrap();
+
+        let status = budget.job_limit_status().unwrap();
+        assert_eq!(status.running_jobs, 2);
+        assert_eq!(status.waiting_owners, 1);
+    }
+
+    #[test]
+    fn test_job_slots_no_starvation() {
+        let budget = ResourceBudget::new(4, 4).with_max_concurrent_jobs(Some(1));
+
+        // `a` keeps submitting jobs, while `b` and `c` wait in the queue
+        let mut a = budget.try_acquire_job("a").unwrap();
+        assert!(budget.try_acquire_job("b").is_none());
+        assert!(budget.try_acquire_job("c").is_none());
+
+        let mut served = Vec::new();
+        for _ in 0..2 {
+            drop(a);
+            // `a` only gets queued behind the owners already waiting
+            assert!(budget.try_acquire_job("a").is_none());
+            let (owner, permit) = ["b", "c"]
+                .into_iter()
+                .find_map(|owner| Some((owner, budget.try_acquire_job(owner)?)))
+                .unwrap();
+            served.push(owner);
+            a = permit;
+        }
+        assert_eq!(served, ["b", "c"]);
+
+        drop(a);
+        assert!(budget.try_acquire_job("a").is_some());
+    }
+
+    #[test]
+    fn test_job_slot_check_does_not_queue() {
+        let budget = ResourceBudget::new(4, 4).with_max_concurrent_jobs(Some(1));
+
+        let a = budget.try_acquire_job("a").unwrap();
+
+        // Owners without pending jobs only check for a slot, and don't hold up others
+        assert!(!budget.has_job_slot("b"));
+        assert_eq!(budget.job_limit_status().unwrap().waiting_owners, 0);
+
+        assert!(budget.try_acquire_job("c").is_none());
+        drop(a);
+        assert!(!budget.has_job_slot("b"));
+        assert!(budget.has_job_slot("c"));
+
+        // Owner without pending jobs leaves the queue
+        budget.cancel_job_wait("c");
+        assert!(budget.has_job_slot("b"));
+        assert_eq!(budget.job_limit_status().unwrap().waiting_owners, 0);
+    }
+
+    #[tokio::test]
+    async fn test_notify_on_job_slot_available() {
+        let budget = ResourceBudget::new(4, 4).with_max_concurrent_jobs(Some(1));
+
+        let a = budget.try_acquire_job("a").unwrap();
+        assert!(budget.try_acquire_job("b").is_none());
+
+        let waiter = tokio::spawn({
+            let budget = budget.clone();
+            async move { budget.notify_on_job_slot_available("b").await }
+        });
+
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished());
+
+        // Released slot wakes up the waiting owner right away, without polling
+        drop(a);
+        time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(budget.try_acquire_job("b").is_some());
+    }
+}
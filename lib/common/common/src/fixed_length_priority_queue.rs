@@ -96,6 +96,16 @@ impl<T: Ord> FixedLengthPriorityQueue<T> {
         self.heap.len() >= self.length.into()
     }
 
+    /// Changes the maximal length of the queue.
+    /// If the queue shrinks, the smallest values are dropped.
+    /// Panics if length is 0
+    pub fn set_length(&mut self, length: usize) {
+        self.length = NonZeroUsize::new(length).expect("length must be greater than zero");
+        while self.heap.len() > length {
+            self.heap.pop();
+        }
+    }
+
     pub fn retain<F>(&mut self, mut f: F)
     where
         F: FnMut(&T) -> bool,
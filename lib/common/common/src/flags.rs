@@ -70,6 +70,11 @@ pub struct FeatureFlags {
     ///
     /// Enabled by default in Qdrant 1.17.1+
     pub single_file_mmap_vector_storage: bool,
+
+    /// Defer loading in-RAM vector storages into memory until a vector is first used.
+    ///
+    /// Speeds up startup and reduces memory usage of collections with rarely queried named vectors.
+    pub lazy_vector_loading: bool,
 }
 
 impl Default for FeatureFlags {
@@ -86,6 +91,7 @@ impl Default for FeatureFlags {
             migrate_rocksdb_payload_indices: true,
             appendable_quantization: true,
             single_file_mmap_vector_storage: false,
+            lazy_vector_loading: false,
         }
     }
 }
@@ -112,6 +118,7 @@ pub fn init_feature_flags(mut flags: FeatureFlags) {
         migrate_rocksdb_payload_indices,
         appendable_quantization,
         single_file_mmap_vector_storage,
+        lazy_vector_loading,
     } = &mut flags;
 
     // If all is set, explicitly set all feature flags
@@ -126,6 +133,7 @@ pub fn init_feature_flags(mut flags: FeatureFlags) {
         *migrate_rocksdb_payload_indices = true;
         *appendable_quantization = true;
         *single_file_mmap_vector_storage = true;
+        *lazy_vector_loading = true;
     }
 
     let res = FEATURE_FLAGS.set(flags);
@@ -1,3 +1,4 @@
+pub mod bandwidth_limiter;
 pub mod bitpacking;
 pub mod bitpacking_links;
 pub mod bitpacking_ordered;
@@ -40,4 +41,5 @@ pub mod toposort;
 pub mod typelevel;
 pub mod types;
 pub mod validation;
+pub mod varint_links;
 pub mod zeros;
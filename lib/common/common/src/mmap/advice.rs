@@ -39,7 +39,7 @@ pub fn get_global() -> Advice {
 /// See [`memmap2::Advice`] and [`madvise(2)`] man page.
 ///
 /// [`madvise(2)`]: https://man7.org/linux/man-pages/man2/madvise.2.html
-#[derive(Copy, Clone, Debug, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Advice {
     /// See [`memmap2::Advice::Normal`].
@@ -89,6 +89,97 @@ impl AdviceSetting {
     }
 }
 
+/// Per-component overrides of [`Advice`] and populate-on-load.
+///
+/// See [`set_components`] and [`get_component`].
+static COMPONENTS: parking_lot::RwLock<ComponentsAdvice> =
+    parking_lot::RwLock::new(ComponentsAdvice::DEFAULT);
+
+/// Component of a segment, which memmaps can be tuned individually.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MmapComponent {
+    GraphLinks,
+    Vectors,
+    QuantizedVectors,
+    Payload,
+}
+
+/// Overrides of [`Advice`] and populate-on-load for on-disk memmaps of a [`MmapComponent`].
+///
+/// Memmaps of components loaded into RAM are not affected, they are always populated.
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ComponentAdvice {
+    /// Advice to use, instead of the one hard-coded for the component.
+    #[serde(default)]
+    pub advice: Option<Advice>,
+    /// Whether to read the whole memmap into the page cache once it is opened.
+    #[serde(default)]
+    pub populate: Option<bool>,
+}
+
+impl ComponentAdvice {
+    const DEFAULT: Self = Self {
+        advice: None,
+        populate: None,
+    };
+
+    /// Get the overridden [`AdviceSetting`], or `default` if not overridden.
+    pub fn advice_or(self, default: AdviceSetting) -> AdviceSetting {
+        self.advice.map_or(default, AdviceSetting::Advice)
+    }
+
+    /// Get the overridden populate flag, or `default` if not overridden.
+    pub fn populate_or(self, default: bool) -> bool {
+        self.populate.unwrap_or(default)
+    }
+}
+
+/// [`ComponentAdvice`] of all [`MmapComponent`]s.
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ComponentsAdvice {
+    #[serde(default)]
+    pub graph_links: ComponentAdvice,
+    #[serde(default)]
+    pub vectors: ComponentAdvice,
+    #[serde(default)]
+    pub quantized_vectors: ComponentAdvice,
+    #[serde(default)]
+    pub payload: ComponentAdvice,
+}
+
+impl ComponentsAdvice {
+    const DEFAULT: Self = Self {
+        graph_links: ComponentAdvice::DEFAULT,
+        vectors: ComponentAdvice::DEFAULT,
+        quantized_vectors: ComponentAdvice::DEFAULT,
+        payload: ComponentAdvice::DEFAULT,
+    };
+
+    pub fn get(&self, component: MmapComponent) -> ComponentAdvice {
+        match component {
+            MmapComponent::GraphLinks => self.graph_links,
+            MmapComponent::Vectors => self.vectors,
+            MmapComponent::QuantizedVectors => self.quantized_vectors,
+            MmapComponent::Payload => self.payload,
+        }
+    }
+}
+
+/// Set per-component overrides of [`Advice`] and populate-on-load.
+///
+/// Same as with [`set_global`], it is recommended to set them before calling any other function
+/// from the `segment` crate and not to change them afterwards.
+///
+/// By default, nothing is overridden.
+pub fn set_components(components: ComponentsAdvice) {
+    *COMPONENTS.write() = components;
+}
+
+/// Get current overrides of a single [`MmapComponent`].
+pub fn get_component(component: MmapComponent) -> ComponentAdvice {
+    COMPONENTS.read().get(component)
+}
+
 /// Advise OS how given memory map will be accessed. On non-Unix platforms this is a no-op.
 pub fn madvise(madviseable: &impl Madviseable, advice: Advice) -> io::Result<()> {
     madviseable.madvise(advice)
@@ -230,3 +321,107 @@ fn get_page_mask() -> Result<usize, String> {
     }
     Ok(page_size - 1)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_component_advice_fallback() {
+        let not_overridden = ComponentAdvice::default();
+        assert!(matches!(
+            not_overridden.advice_or(AdviceSetting::Global),
+            AdviceSetting::Global,
+        ));
+        assert_eq!(
+            not_overridden.advice_or(AdviceSetting::Global).resolve(),
+            get_global(),
+        );
+        assert_eq!(
+            not_overridden
+                .advice_or(AdviceSetting::Advice(Advice::Normal))
+                .resolve(),
+            Advice::Normal,
+        );
+        assert!(not_overridden.populate_or(true));
+        assert!(!not_overridden.populate_or(false));
+
+        let overridden = ComponentAdvice {
+            advice: Some(Advice::Sequential),
+            populate: Some(false),
+        };
+        assert_eq!(
+            overridden.advice_or(AdviceSetting::Global).resolve(),
+            Advice::Sequential,
+        );
+        assert_eq!(
+            overridden
+                .advice_or(AdviceSetting::Advice(Advice::Normal))
+                .resolve(),
+            Advice::Sequential,
+        );
+        assert!(!overridden.populate_or(true));
+    }
+
+    #[test]
+    fn test_components_advice_config() {
+        let components: ComponentsAdvice = serde_json::from_value(serde_json::json!({
+            "vectors": { "advice": "sequential" },
+            "quantized_vectors": { "populate": true },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            components.get(MmapComponent::Vectors),
+            ComponentAdvice {
+                advice: Some(Advice::Sequential),
+                populate: None,
+            },
+        );
+        assert_eq!(
+            components.get(MmapComponent::QuantizedVectors),
+            ComponentAdvice {
+                advice: None,
+                populate: Some(true),
+            },
+        );
+        assert_eq!(
+            components.get(MmapComponent::GraphLinks),
+            ComponentAdvice::default(),
+        );
+        assert_eq!(
+            components.get(MmapComponent::Payload),
+            ComponentAdvice::default(),
+        );
+        assert_eq!(ComponentsAdvice::default(), ComponentsAdvice::DEFAULT);
+    }
+
+    #[test]
+    fn test_set_components() {
+        let components = ComponentsAdvice {
+            graph_links: ComponentAdvice {
+                advice: Some(Advice::Normal),
+                populate: Some(true),
+            },
+            ..ComponentsAdvice::DEFAULT
+        };
+        set_components(components);
+
+        let graph_links = get_component(MmapComponent::GraphLinks);
+        assert_eq!(
+            graph_links.advice_or(AdviceSetting::Global).resolve(),
+            Advice::Normal,
+        );
+        assert!(graph_links.populate_or(false));
+        assert_eq!(
+            get_component(MmapComponent::Vectors),
+            ComponentAdvice::default(),
+        );
+
+        set_components(ComponentsAdvice::DEFAULT);
+        assert_eq!(
+            get_component(MmapComponent::GraphLinks),
+            ComponentAdvice::default(),
+        );
+    }
+}
@@ -4,7 +4,9 @@ mod mmap_readonly;
 mod mmap_rw;
 mod ops;
 
-pub use advice::{Advice, AdviceSetting, Madviseable};
+pub use advice::{
+    Advice, AdviceSetting, ComponentAdvice, ComponentsAdvice, Madviseable, MmapComponent,
+};
 pub use chunked::UniversalMmapChunk;
 pub use mmap_readonly::{MmapSliceReadOnly, MmapTypeReadOnly};
 pub use mmap_rw::{Error, MmapBitSlice, MmapFlusher, MmapSlice, MmapType};
@@ -0,0 +1,178 @@
+//! Delta + varint encoding of HNSW graph links.
+//!
+//! Unlike [`crate::bitpacking_links`], the width of each value is chosen
+//! individually, which gives better compression for graphs with a large `m`,
+//! as deltas between sorted links get smaller the more links a node has.
+//!
+//! Links of a single node are encoded as:
+//! ```text
+//! [#][ssssssss][uuuu]
+//! ```
+//! Where:
+//! 1. `#` is a varint-encoded number of links.
+//! 2. `s` are the first `sorted_count` links, sorted, delta-encoded, and
+//!    varint-encoded.
+//! 3. `u` are the rest of the links, varint-encoded.
+//!
+//! Nodes without links are encoded as an empty slice.
+
+/// Number of payload bits in each byte of a varint.
+const VARINT_BITS: u32 = 7;
+
+/// Marks that more bytes of the varint follow.
+const VARINT_CONTINUATION: u8 = 1 << VARINT_BITS;
+
+/// Pack HNSW graph links using delta + varint encoding.
+///
+/// Parameters:
+/// - `sorted_count` is `m` (or `m0`) for this layer.
+/// - `raw_links` is in/out parameter. Input: links to pack, output: same links,
+///   but re-ordered.
+pub fn pack_varint_links(links: &mut Vec<u8>, raw_links: &mut [u32], sorted_count: usize) {
+    if raw_links.is_empty() {
+        return;
+    }
+
+    write_varint(links, raw_links.len() as u32);
+
+    let sorted_count = raw_links.len().min(sorted_count);
+    raw_links[..sorted_count].sort_unstable();
+
+    let mut previous = 0;
+    for &value in &raw_links[..sorted_count] {
+        write_varint(links, value - previous);
+        previous = value;
+    }
+
+    for &value in &raw_links[sorted_count..] {
+        write_varint(links, value);
+    }
+}
+
+/// Returns an iterator over links packed with [`pack_varint_links`].
+#[inline]
+pub fn iterate_varint_links(links: &[u8], sorted_count: usize) -> VarintLinksIterator<'_> {
+    let mut data = links;
+    let remaining = if data.is_empty() {
+        0
+    } else {
+        read_varint(&mut data) as usize
+    };
+
+    VarintLinksIterator {
+        data,
+        remaining,
+        remaining_sorted: remaining.min(sorted_count),
+        current: 0,
+    }
+}
+
+/// Iterator over links packed with [`pack_varint_links`].
+/// Created by [`iterate_varint_links`].
+pub struct VarintLinksIterator<'a> {
+    data: &'a [u8],
+    remaining: usize,
+    remaining_sorted: usize,
+    current: u32,
+}
+
+impl Iterator for VarintLinksIterator<'_> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<u32> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let value = read_varint(&mut self.data);
+        if self.remaining_sorted > 0 {
+            self.remaining_sorted -= 1;
+            self.current = self.current.wrapping_add(value);
+            Some(self.current)
+        } else {
+            Some(value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for VarintLinksIterator<'_> {}
+
+#[inline]
+fn write_varint(output: &mut Vec<u8>, mut value: u32) {
+    while value >= u32::from(VARINT_CONTINUATION) {
+        output.push(value as u8 | VARINT_CONTINUATION);
+        value >>= VARINT_BITS;
+    }
+    output.push(value as u8);
+}
+
+#[inline]
+fn read_varint(data: &mut &[u8]) -> u32 {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let (&byte, rest) = data.split_first().expect("truncated varint-encoded links");
+        *data = rest;
+        value |= u32::from(byte & !VARINT_CONTINUATION) << shift;
+        if byte & VARINT_CONTINUATION == 0 {
+            return value;
+        }
+        shift += VARINT_BITS;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools as _;
+    use rand::rngs::StdRng;
+    use rand::{Rng as _, SeedableRng as _};
+
+    use super::*;
+    use crate::iterator_ext::{check_exact_size_iterator_len, check_iterator_fold};
+
+    #[test]
+    fn test_random() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..1_000 {
+            let sorted_count = rng.random_range(0..100);
+            let total_count = rng.random_range(0..200);
+            let max_value = rng.random_range(1..=u32::MAX);
+
+            let mut raw_links_orig: Vec<u32> =
+                std::iter::repeat_with(|| rng.random_range(0..=max_value))
+                    .unique()
+                    .take(total_count)
+                    .collect();
+            let mut raw_links_updated = raw_links_orig.clone();
+            let mut links = Vec::new();
+            pack_varint_links(&mut links, &mut raw_links_updated, sorted_count);
+
+            let unpacked: Vec<_> = iterate_varint_links(&links, sorted_count).collect();
+
+            raw_links_orig[..sorted_count.min(total_count)].sort_unstable();
+            assert_eq!(raw_links_orig, unpacked);
+            assert_eq!(raw_links_updated, unpacked);
+
+            check_iterator_fold(|| iterate_varint_links(&links, sorted_count));
+            check_exact_size_iterator_len(iterate_varint_links(&links, sorted_count));
+        }
+    }
+
+    #[test]
+    fn test_varint_roundtrip() {
+        for value in [0, 1, 127, 128, 16_383, 16_384, u32::MAX - 1, u32::MAX] {
+            let mut output = Vec::new();
+            write_varint(&mut output, value);
+            let mut data = output.as_slice();
+            assert_eq!(read_varint(&mut data), value);
+            assert!(data.is_empty());
+        }
+    }
+}
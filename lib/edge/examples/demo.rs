@@ -87,6 +87,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         with_payload: Some(WithPayloadInterface::Bool(false)),
         with_vector: WithVector::Bool(false),
         order_by: None,
+        distinct_by: None,
+        with_version: None,
     })?;
     for r in &records {
         println!("ID: {}", r.id);
@@ -110,6 +112,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         limit: 10,
         filter: None,
         exact: false,
+        histogram: None,
     });
     match facet_result {
         Ok(response) => {
@@ -161,12 +164,14 @@ fn load_new_shard() -> Result<EdgeShard, Box<dyn Error>> {
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    storage_backend: None,
                 },
             );
             m
         },
         sparse_vector_data: HashMap::new(),
         payload_storage_type: PayloadStorageType::Mmap,
+        payload_overflow_threshold: None,
     };
 
     Ok(EdgeShard::load(Path::new(DATA_DIR), Some(config))?)
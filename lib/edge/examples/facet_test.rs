@@ -29,6 +29,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         limit: 10,
         filter: None,
         exact: false,
+        histogram: None,
     })?;
 
     println!("Facet results for 'color':");
@@ -42,6 +43,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         limit: 10,
         filter: None,
         exact: false,
+        histogram: None,
     })?;
 
     println!("Facet results for 'city':");
@@ -62,6 +64,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         limit: 10,
         filter: Some(filter),
         exact: false,
+        histogram: None,
     })?;
 
     println!("Facet results for 'city' where color='red':");
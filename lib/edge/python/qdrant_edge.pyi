@@ -17,7 +17,7 @@ Payload = Dict[str, Any]
 JsonPath = str
 WithPayloadType = Union[bool, List[str], "PayloadSelector"]
 WithVectorType = Union[bool, List[str]]
-ScoringQueryType = Union["Query", "Fusion", "OrderBy", "Formula", "Sample", "Mmr"]
+ScoringQueryType = Union["Query", "Fusion", "OrderBy", "Formula", "Sample", "WeightedSample", "Mmr"]
 ConditionType = Union[
     "FieldCondition",
     "IsEmptyCondition",
@@ -28,7 +28,7 @@ ConditionType = Union[
     "Filter",
 ]
 MatchType = Union[
-    "MatchValue", "MatchText", "MatchTextAny", "MatchPhrase", "MatchAny", "MatchExcept"
+    "MatchValue", "MatchText", "MatchTextAny", "MatchPhrase", "MatchAny", "MatchExcept", "MatchCidr"
 ]
 RangeType = Union["RangeFloat", "RangeDateTime"]
 QuantizationConfigType = Union[
@@ -246,6 +246,11 @@ class EdgeConfig:
         """Payload storage type."""
         ...
 
+    @property
+    def payload_overflow_threshold(self) -> Optional[int]:
+        """Payload values larger than this number of bytes are stored separately."""
+        ...
+
 
 class VectorDataConfig:
     """Configuration for dense vector storage."""
@@ -346,6 +351,9 @@ class SparseIndexConfig:
             self,
             full_scan_threshold: Optional[int] = None,
             datatype: Optional["VectorStorageDatatype"] = None,
+            similarity: Optional["SparseSimilarity"] = None,
+            pruning: Optional["SparsePruningConfig"] = None,
+            block_max: Optional[bool] = None,
     ) -> None:
         """
         Create a SparseIndexConfig.
@@ -353,6 +361,9 @@ class SparseIndexConfig:
         Args:
             full_scan_threshold: Threshold for full scan vs index search.
             datatype: Storage datatype.
+            similarity: Similarity function used to score vectors.
+            pruning: Pruning of posting lists applied when building the index.
+            block_max: Skip blocks of posting lists which cannot reach the top results.
         """
         ...
 
@@ -371,6 +382,48 @@ class SparseIndexConfig:
         """Storage datatype."""
         ...
 
+    @property
+    def similarity(self) -> Optional["SparseSimilarity"]:
+        """Similarity function."""
+        ...
+
+    @property
+    def pruning(self) -> Optional["SparsePruningConfig"]:
+        """Posting list pruning."""
+        ...
+
+    @property
+    def block_max(self) -> Optional[bool]:
+        """Block max search."""
+        ...
+
+
+class SparsePruningConfig:
+    """Pruning of posting lists, applied when building an immutable sparse index."""
+
+    def __init__(
+            self,
+            min_weight: Optional[float] = None,
+            max_postings_per_dimension: Optional[int] = None,
+    ) -> None:
+        """
+        Create a SparsePruningConfig.
+
+        Args:
+            min_weight: Drop postings with absolute weight below this value.
+            max_postings_per_dimension: Keep at most this number of postings with the largest absolute weights per dimension.
+        """
+        ...
+
+    @property
+    def min_weight(self) -> Optional[float]:
+        """Minimum absolute weight of postings."""
+        ...
+
+    @property
+    def max_postings_per_dimension(self) -> Optional[int]:
+        """Maximum number of postings per dimension."""
+        ...
 
 class PlainIndexConfig:
     """Configuration for plain (brute-force) index."""
@@ -569,6 +622,7 @@ class Distance(Enum):
     Euclid = ...
     Dot = ...
     Manhattan = ...
+    Hamming = ...
 
 
 class VectorStorageType(Enum):
@@ -643,6 +697,13 @@ class SparseIndexType(Enum):
     Mmap = ...
 
 
+class SparseSimilarity(Enum):
+    """Sparse vector similarity functions."""
+
+    Dot = ...
+    Jaccard = ...
+
+
 class SparseVectorStorageType(Enum):
     """Sparse vector storage types."""
 
@@ -699,6 +760,7 @@ class PayloadSchemaType(Enum):
     Bool = ...
     Datetime = ...
     Uuid = ...
+    Ip = ...
 
 
 # ============================================================================
@@ -846,6 +908,11 @@ class Record:
         """Order value for order_by queries."""
         ...
 
+    @property
+    def version(self) -> Optional[int]:
+        """Version of the last operation which modified the point."""
+        ...
+
 
 class ShardInfo:
     """Information about a shard."""
@@ -1109,6 +1176,7 @@ class ScrollRequest:
             with_payload: Optional[WithPayloadType] = None,
             with_vector: Optional[WithVectorType] = None,
             order_by: Optional["OrderBy"] = None,
+            distinct_by: Optional[JsonPath] = None,
     ) -> None:
         """
         Create a ScrollRequest.
@@ -1120,6 +1188,8 @@ class ScrollRequest:
             with_payload: Whether to include payload.
             with_vector: Whether to include vectors.
             order_by: Order by configuration.
+            distinct_by: Return only the first point for each unique value of this payload key.
+                Requires a keyword, integer, uuid or bool payload index on the key.
         """
         ...
 
@@ -1153,6 +1223,11 @@ class ScrollRequest:
         """Order by configuration."""
         ...
 
+    @property
+    def distinct_by(self) -> Optional[JsonPath]:
+        """Payload key to deduplicate points by."""
+        ...
+
 
 class CountRequest:
     """Request for count operation."""
@@ -1265,6 +1340,8 @@ class SearchParams:
             quantization: Optional["QuantizationSearchParams"] = None,
             indexed_only: bool = False,
             acorn: Optional["AcornSearchParams"] = None,
+            max_hnsw_ef: Optional[int] = None,
+            score_gap_cutoff: Optional[float] = None,
     ) -> None:
         """
         Create SearchParams.
@@ -1275,6 +1352,8 @@ class SearchParams:
             quantization: Quantization search parameters.
             indexed_only: Whether to search only indexed vectors.
             acorn: Acorn search parameters.
+            max_hnsw_ef: Maximal ef for HNSW search, grown from `hnsw_ef` until results are stable.
+            score_gap_cutoff: Truncate results at the first score drop larger than this fraction.
         """
         ...
 
@@ -1303,6 +1382,16 @@ class SearchParams:
         """Acorn parameters."""
         ...
 
+    @property
+    def max_hnsw_ef(self) -> Optional[int]:
+        """Maximal HNSW ef parameter."""
+        ...
+
+    @property
+    def score_gap_cutoff(self) -> Optional[float]:
+        """Relative score gap to truncate results at."""
+        ...
+
 
 class QuantizationSearchParams:
     """Parameters for quantization during search."""
@@ -1486,6 +1575,24 @@ class OrderBy:
         ...
 
 
+class WeightedSample:
+    """Random sample with probability proportional to a numeric payload field."""
+
+    def __init__(self, key: JsonPath) -> None:
+        """
+        Create a WeightedSample.
+
+        Args:
+            key: Payload field path with numeric values, requires a range index.
+        """
+        ...
+
+    @property
+    def key(self) -> str:
+        """Field key."""
+        ...
+
+
 class Mmr:
     """Maximal Marginal Relevance for result diversification."""
 
@@ -2204,6 +2311,24 @@ class MatchExcept:
         ...
 
 
+class MatchCidr:
+    """Match IP addresses within a network."""
+
+    def __init__(self, cidr: str) -> None:
+        """
+        Create a MatchCidr.
+
+        Args:
+            cidr: Network in CIDR notation, like `10.0.0.0/8`.
+        """
+        ...
+
+    @property
+    def cidr(self) -> str:
+        """Network."""
+        ...
+
+
 # ============================================================================
 # Range Conditions
 # ============================================================================
@@ -2458,6 +2583,11 @@ class PayloadSelector(Enum):
         """Exclude specified fields."""
         ...
 
+    @staticmethod
+    def Project(expressions: List[str]) -> "PayloadSelector":
+        """Include only parts of the payload, selected by projection expressions, e.g. `items[0:3].name`."""
+        ...
+
 
 # ============================================================================
 # Update Operation
@@ -2659,3 +2789,33 @@ class UpdateOperation:
             key: Optional nested key path.
         """
         ...
+
+    @staticmethod
+    def patch_payload(
+            point_ids: List[PointId],
+            patch: Payload,
+    ) -> "UpdateOperation":
+        """
+        Apply JSON Merge Patch (RFC 7386) to payload on points.
+        Nested objects are merged recursively, `None` values remove keys.
+
+        Args:
+            point_ids: Point IDs.
+            patch: Payload patch.
+        """
+        ...
+
+    @staticmethod
+    def patch_payload_by_filter(
+            filter: Filter,
+            patch: Payload,
+    ) -> "UpdateOperation":
+        """
+        Apply JSON Merge Patch (RFC 7386) to payload on points matching a filter.
+        Nested objects are merged recursively, `None` values remove keys.
+
+        Args:
+            filter: Filter for points.
+            patch: Payload patch.
+        """
+        ...
@@ -39,6 +39,7 @@ impl PyEdgeConfig {
                 sparse_vector_data.unwrap_or_default(),
             ),
             payload_storage_type: PayloadStorageType::Mmap,
+            payload_overflow_threshold: None,
         })
     }
 
@@ -57,6 +58,11 @@ impl PyEdgeConfig {
         PyPayloadStorageType::from(self.0.payload_storage_type)
     }
 
+    #[getter]
+    pub fn payload_overflow_threshold(&self) -> Option<usize> {
+        self.0.payload_overflow_threshold
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -69,6 +75,7 @@ impl PyEdgeConfig {
             vector_data: _,
             sparse_vector_data: _,
             payload_storage_type: _,
+            payload_overflow_threshold: _,
         } = self.0;
     }
 }
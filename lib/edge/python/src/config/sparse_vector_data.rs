@@ -3,9 +3,12 @@ use std::{fmt, mem};
 
 use bytemuck::TransparentWrapper;
 use derive_more::Into;
+use ordered_float::OrderedFloat;
 use pyo3::prelude::*;
 use segment::data_types::modifier::Modifier;
-use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
+use segment::index::sparse_index::sparse_index_config::{
+    SparseIndexConfig, SparseIndexType, SparsePruningConfig, SparseSimilarity,
+};
 use segment::types::*;
 
 use super::vector_data::*;
@@ -95,15 +98,27 @@ pub struct PySparseIndexConfig(SparseIndexConfig);
 #[pymethods]
 impl PySparseIndexConfig {
     #[new]
-    #[pyo3(signature = (full_scan_threshold = None, datatype = None))]
+    #[pyo3(signature = (
+        full_scan_threshold = None,
+        datatype = None,
+        similarity = None,
+        pruning = None,
+        block_max = None,
+    ))]
     pub fn new(
         full_scan_threshold: Option<usize>,
         datatype: Option<PyVectorStorageDatatype>,
+        similarity: Option<PySparseSimilarity>,
+        pruning: Option<PySparsePruningConfig>,
+        block_max: Option<bool>,
     ) -> Self {
         Self(SparseIndexConfig {
             index_type: SparseIndexType::MutableRam,
             full_scan_threshold,
             datatype: datatype.map(VectorStorageDatatype::from),
+            similarity: similarity.map(SparseSimilarity::from),
+            pruning: pruning.map(SparsePruningConfig::from),
+            block_max,
         })
     }
 
@@ -122,6 +137,21 @@ impl PySparseIndexConfig {
         self.0.datatype.map(PyVectorStorageDatatype::from)
     }
 
+    #[getter]
+    pub fn similarity(&self) -> Option<PySparseSimilarity> {
+        self.0.similarity.map(PySparseSimilarity::from)
+    }
+
+    #[getter]
+    pub fn pruning(&self) -> Option<PySparsePruningConfig> {
+        self.0.pruning.map(PySparsePruningConfig)
+    }
+
+    #[getter]
+    pub fn block_max(&self) -> Option<bool> {
+        self.0.block_max
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -134,6 +164,51 @@ impl PySparseIndexConfig {
             full_scan_threshold: _,
             index_type: _,
             datatype: _,
+            similarity: _,
+            pruning: _,
+            block_max: _,
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "SparsePruningConfig", from_py_object)]
+#[derive(Copy, Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PySparsePruningConfig(SparsePruningConfig);
+
+#[pyclass_repr]
+#[pymethods]
+impl PySparsePruningConfig {
+    #[new]
+    #[pyo3(signature = (min_weight = None, max_postings_per_dimension = None))]
+    pub fn new(min_weight: Option<f64>, max_postings_per_dimension: Option<usize>) -> Self {
+        Self(SparsePruningConfig {
+            min_weight: min_weight.map(OrderedFloat),
+            max_postings_per_dimension,
+        })
+    }
+
+    #[getter]
+    pub fn min_weight(&self) -> Option<f64> {
+        self.0.min_weight.map(OrderedFloat::into_inner)
+    }
+
+    #[getter]
+    pub fn max_postings_per_dimension(&self) -> Option<usize> {
+        self.0.max_postings_per_dimension
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PySparsePruningConfig {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let SparsePruningConfig {
+            min_weight: _,
+            max_postings_per_dimension: _,
         } = self.0;
     }
 }
@@ -185,6 +260,49 @@ impl From<PySparseIndexType> for SparseIndexType {
     }
 }
 
+#[pyclass(name = "SparseSimilarity", from_py_object)]
+#[derive(Copy, Clone, Debug)]
+pub enum PySparseSimilarity {
+    Dot,
+    Jaccard,
+}
+
+#[pymethods]
+impl PySparseSimilarity {
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Repr for PySparseSimilarity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::Dot => "Dot",
+            Self::Jaccard => "Jaccard",
+        };
+
+        f.simple_enum::<Self>(repr)
+    }
+}
+
+impl From<SparseSimilarity> for PySparseSimilarity {
+    fn from(similarity: SparseSimilarity) -> Self {
+        match similarity {
+            SparseSimilarity::Dot => PySparseSimilarity::Dot,
+            SparseSimilarity::Jaccard => PySparseSimilarity::Jaccard,
+        }
+    }
+}
+
+impl From<PySparseSimilarity> for SparseSimilarity {
+    fn from(similarity: PySparseSimilarity) -> Self {
+        match similarity {
+            PySparseSimilarity::Dot => SparseSimilarity::Dot,
+            PySparseSimilarity::Jaccard => SparseSimilarity::Jaccard,
+        }
+    }
+}
+
 #[pyclass(name = "SparseVectorStorageType", from_py_object)]
 #[derive(Copy, Clone, Debug)]
 pub enum PySparseVectorStorageType {
@@ -51,6 +51,7 @@ impl PyVectorDataConfig {
             quantization_config: quantization_config.map(QuantizationConfig::from),
             multivector_config: multivector_config.map(MultiVectorConfig::from),
             datatype: datatype.map(VectorStorageDatatype::from),
+            storage_backend: None,
         })
     }
 
@@ -105,6 +106,7 @@ impl PyVectorDataConfig {
             quantization_config: _,
             multivector_config: _,
             datatype: _,
+            storage_backend: _,
         } = self.0;
     }
 }
@@ -126,6 +128,7 @@ pub enum PyDistance {
     Euclid,
     Dot,
     Manhattan,
+    Hamming,
 }
 
 #[pymethods]
@@ -142,6 +145,7 @@ impl Repr for PyDistance {
             Self::Euclid => "Euclid",
             Self::Dot => "Dot",
             Self::Manhattan => "Manhattan",
+            Self::Hamming => "Hamming",
         };
 
         f.simple_enum::<Self>(repr)
@@ -155,6 +159,7 @@ impl From<Distance> for PyDistance {
             Distance::Euclid => PyDistance::Euclid,
             Distance::Dot => PyDistance::Dot,
             Distance::Manhattan => PyDistance::Manhattan,
+            Distance::Hamming => PyDistance::Hamming,
         }
     }
 }
@@ -166,6 +171,7 @@ impl From<PyDistance> for Distance {
             PyDistance::Euclid => Distance::Euclid,
             PyDistance::Dot => Distance::Dot,
             PyDistance::Manhattan => Distance::Manhattan,
+            PyDistance::Hamming => Distance::Hamming,
         }
     }
 }
@@ -320,6 +326,8 @@ impl PyHnswIndexConfig {
             on_disk,
             payload_m,
             inline_storage,
+            links_compression: None,
+            tenant_subgraphs: None,
         })
     }
 
@@ -369,6 +377,8 @@ impl PyHnswIndexConfig {
             on_disk: _,
             payload_m: _,
             inline_storage: _,
+            links_compression: _, // not exposed in Qdrant Edge yet
+            tenant_subgraphs: _,  // not exposed in Qdrant Edge yet
         } = self.0;
     }
 }
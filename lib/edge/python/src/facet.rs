@@ -2,7 +2,9 @@ use bytemuck::{TransparentWrapper, TransparentWrapperAlloc as _};
 use derive_more::Into;
 use pyo3::IntoPyObjectExt as _;
 use pyo3::prelude::*;
-use segment::data_types::facets::{FacetResponse, FacetValue, FacetValueHit};
+use segment::data_types::facets::{
+    FacetResponse, FacetValue, FacetValueHit, datetime_bucket_to_string,
+};
 use segment::types::Filter;
 use shard::facet::FacetRequestInternal;
 
@@ -24,6 +26,7 @@ impl PyFacetRequest {
             limit,
             filter: filter.map(Filter::from),
             exact,
+            histogram: None,
         })
     }
 
@@ -143,5 +146,9 @@ fn facet_value_into_py<'py>(value: &FacetValue, py: Python<'py>) -> PyResult<Bou
             .to_string()
             .into_bound_py_any(py),
         FacetValue::Bool(b) => b.into_bound_py_any(py),
+        FacetValue::Float(float) => float.0.into_bound_py_any(py),
+        FacetValue::DateTime(timestamp) => {
+            datetime_bucket_to_string(*timestamp).into_bound_py_any(py)
+        }
     }
 }
@@ -41,8 +41,8 @@ mod qdrant_edge {
     };
     #[pymodule_export]
     use super::config::sparse_vector_data::{
-        PyModifier, PySparseIndexConfig, PySparseIndexType, PySparseVectorDataConfig,
-        PySparseVectorStorageType,
+        PyModifier, PySparseIndexConfig, PySparseIndexType, PySparsePruningConfig,
+        PySparseSimilarity, PySparseVectorDataConfig, PySparseVectorStorageType,
     };
     #[pymodule_export]
     use super::config::vector_data::{
@@ -58,6 +58,7 @@ mod qdrant_edge {
     #[pymodule_export]
     use super::query::{
         PyDirection, PyFusion, PyMmr, PyOrderBy, PyPrefetch, PyQueryRequest, PySample,
+        PyWeightedSample,
     };
     #[pymodule_export]
     use super::scroll::PyScrollRequest;
@@ -69,8 +70,8 @@ mod qdrant_edge {
     use super::types::filter::{
         PyFieldCondition, PyFilter, PyGeoBoundingBox, PyGeoPoint, PyGeoPolygon, PyGeoRadius,
         PyHasIdCondition, PyHasVectorCondition, PyIsEmptyCondition, PyIsNullCondition, PyMatchAny,
-        PyMatchExcept, PyMatchPhrase, PyMatchText, PyMatchTextAny, PyMatchValue, PyMinShould,
-        PyNestedCondition, PyRangeDateTime, PyRangeFloat, PyValuesCount,
+        PyMatchCidr, PyMatchExcept, PyMatchPhrase, PyMatchText, PyMatchTextAny, PyMatchValue,
+        PyMinShould, PyNestedCondition, PyRangeDateTime, PyRangeFloat, PyValuesCount,
     };
     #[pymodule_export]
     use super::types::formula::{PyDecayKind, PyExpressionInterface, PyFormula};
@@ -243,6 +243,7 @@ impl FromPyObject<'_, '_> for PyScoringQuery {
             OrderBy(PyOrderBy),
             Formula(PyFormula),
             Sample(PySample),
+            WeightedSample(PyWeightedSample),
             Mmr(PyMmr),
         }
 
@@ -263,6 +264,9 @@ impl FromPyObject<'_, '_> for PyScoringQuery {
             Helper::OrderBy(order_by) => ScoringQuery::OrderBy(OrderBy::from(order_by)),
             Helper::Formula(formula) => ScoringQuery::Formula(ParsedFormula::from(formula)),
             Helper::Sample(sample) => ScoringQuery::Sample(SampleInternal::from(sample)),
+            Helper::WeightedSample(sample) => {
+                ScoringQuery::Sample(SampleInternal::Weighted(JsonPath::from(sample)))
+            }
             Helper::Mmr(mmr) => ScoringQuery::Mmr(MmrInternal::from(mmr)),
         };
 
@@ -281,7 +285,10 @@ impl<'py> IntoPyObject<'py> for PyScoringQuery {
             ScoringQuery::Fusion(fusion) => PyFusion::from(fusion).into_bound_py_any(py),
             ScoringQuery::OrderBy(order_by) => PyOrderBy(order_by).into_bound_py_any(py),
             ScoringQuery::Formula(formula) => PyFormula(formula).into_bound_py_any(py),
-            ScoringQuery::Sample(sample) => PySample::from(sample).into_bound_py_any(py),
+            ScoringQuery::Sample(SampleInternal::Random) => PySample::Random.into_bound_py_any(py),
+            ScoringQuery::Sample(SampleInternal::Weighted(key)) => {
+                PyWeightedSample(key).into_bound_py_any(py)
+            }
             ScoringQuery::Mmr(mmr) => PyMmr(mmr).into_bound_py_any(py),
         }
     }
@@ -304,7 +311,10 @@ impl Repr for PyScoringQuery {
             ScoringQuery::Fusion(fusion) => PyFusion::from(fusion.clone()).fmt(f),
             ScoringQuery::OrderBy(order_by) => PyOrderBy::wrap_ref(order_by).fmt(f),
             ScoringQuery::Formula(_formula) => f.unimplemented(), // TODO!
-            ScoringQuery::Sample(sample) => PySample::from(*sample).fmt(f),
+            ScoringQuery::Sample(SampleInternal::Random) => PySample::Random.fmt(f),
+            ScoringQuery::Sample(SampleInternal::Weighted(key)) => {
+                PyWeightedSample::wrap_ref(key).fmt(f)
+            }
             ScoringQuery::Mmr(mmr) => PyMmr::wrap_ref(mmr).fmt(f),
         }
     }
@@ -572,14 +582,6 @@ impl Repr for PySample {
     }
 }
 
-impl From<SampleInternal> for PySample {
-    fn from(sample: SampleInternal) -> Self {
-        match sample {
-            SampleInternal::Random => PySample::Random,
-        }
-    }
-}
-
 impl From<PySample> for SampleInternal {
     fn from(sample: PySample) -> Self {
         match sample {
@@ -588,6 +590,29 @@ impl From<PySample> for SampleInternal {
     }
 }
 
+#[pyclass(name = "WeightedSample", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyWeightedSample(JsonPath);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyWeightedSample {
+    #[new]
+    pub fn new(key: PyJsonPath) -> Self {
+        Self(JsonPath::from(key))
+    }
+
+    #[getter]
+    pub fn key(&self) -> &PyJsonPath {
+        PyJsonPath::wrap_ref(&self.0)
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
 #[pyclass(name = "Mmr", from_py_object)]
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
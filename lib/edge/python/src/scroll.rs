@@ -1,6 +1,7 @@
 use bytemuck::TransparentWrapper as _;
 use derive_more::Into;
 use pyo3::prelude::*;
+use segment::json_path::JsonPath;
 use segment::types::*;
 use shard::scroll::*;
 
@@ -23,6 +24,7 @@ impl PyScrollRequest {
         with_payload = None,
         with_vector = None,
         order_by = None,
+        distinct_by = None,
     ))]
     pub fn new(
         offset: Option<PyPointId>,
@@ -31,6 +33,7 @@ impl PyScrollRequest {
         with_payload: Option<PyWithPayload>,
         with_vector: Option<PyWithVector>,
         order_by: Option<PyOrderBy>,
+        distinct_by: Option<PyJsonPath>,
     ) -> Self {
         Self(ScrollRequestInternal {
             offset: offset.map(PointIdType::from),
@@ -39,6 +42,8 @@ impl PyScrollRequest {
             with_payload: with_payload.map(WithPayloadInterface::from),
             with_vector: with_vector.map(WithVector::from).unwrap_or_default(),
             order_by: order_by.map(OrderByInterface::from),
+            distinct_by: distinct_by.map(JsonPath::from),
+            with_version: None,
         })
     }
 
@@ -72,6 +77,11 @@ impl PyScrollRequest {
         self.0.order_by.clone().map(PyOrderBy::from)
     }
 
+    #[getter]
+    pub fn distinct_by(&self) -> Option<&PyJsonPath> {
+        self.0.distinct_by.as_ref().map(PyJsonPath::wrap_ref)
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -87,6 +97,9 @@ impl PyScrollRequest {
             with_payload: _,
             with_vector: _,
             order_by: _,
+            distinct_by: _,
+            // Edge shards always return point versions
+            with_version: _,
         } = self.0;
     }
 }
@@ -124,6 +124,8 @@ impl PySearchParams {
         quantization = None,
         indexed_only = false,
         acorn = None,
+        max_hnsw_ef = None,
+        score_gap_cutoff = None,
     ))]
     pub fn new(
         hnsw_ef: Option<usize>,
@@ -131,6 +133,8 @@ impl PySearchParams {
         quantization: Option<PyQuantizationSearchParams>,
         indexed_only: bool,
         acorn: Option<PyAcornSearchParams>,
+        max_hnsw_ef: Option<usize>,
+        score_gap_cutoff: Option<f64>,
     ) -> Self {
         Self(SearchParams {
             hnsw_ef,
@@ -138,6 +142,10 @@ impl PySearchParams {
             quantization: quantization.map(QuantizationSearchParams::from),
             indexed_only,
             acorn: acorn.map(AcornSearchParams::from),
+            early_exit: false,
+            max_hnsw_ef,
+            score_gap_cutoff: score_gap_cutoff.map(OrderedFloat),
+            allow_partial_results: None,
         })
     }
 
@@ -166,6 +174,16 @@ impl PySearchParams {
         self.0.acorn.map(PyAcornSearchParams)
     }
 
+    #[getter]
+    pub fn max_hnsw_ef(&self) -> Option<usize> {
+        self.0.max_hnsw_ef
+    }
+
+    #[getter]
+    pub fn score_gap_cutoff(&self) -> Option<f64> {
+        self.0.score_gap_cutoff.map(|OrderedFloat(gap)| gap)
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -180,6 +198,10 @@ impl PySearchParams {
             quantization: _,
             indexed_only: _,
             acorn: _,
+            early_exit: _, // Edge searches all segments at once
+            max_hnsw_ef: _,
+            score_gap_cutoff: _,
+            allow_partial_results: _, // Edge searches have no timeout
         } = self.0;
     }
 }
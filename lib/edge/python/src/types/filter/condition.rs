@@ -64,6 +64,9 @@ impl<'py> IntoPyObject<'py> for PyCondition {
             Condition::CustomIdChecker(_) => {
                 unreachable!("CustomIdChecker condition is not expected in Python bindings")
             }
+            Condition::Attribute(_) => {
+                unreachable!("Attribute condition is not expected in Python bindings")
+            }
         }
     }
 }
@@ -91,6 +94,9 @@ impl Repr for PyCondition {
             Condition::CustomIdChecker(_) => {
                 unreachable!("CustomIdChecker condition is not expected in Python bindings")
             }
+            Condition::Attribute(_) => {
+                unreachable!("Attribute condition is not expected in Python bindings")
+            }
         }
     }
 }
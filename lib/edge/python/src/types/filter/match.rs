@@ -26,6 +26,7 @@ impl FromPyObject<'_, '_> for PyMatch {
             Phrase(PyMatchPhrase),
             Any(PyMatchAny),
             Except(PyMatchExcept),
+            Cidr(PyMatchCidr),
         }
 
         fn _variants(filter: Match) {
@@ -36,6 +37,7 @@ impl FromPyObject<'_, '_> for PyMatch {
                 Match::Phrase(_) => {}
                 Match::Any(_) => {}
                 Match::Except(_) => {}
+                Match::Cidr(_) => {}
             }
         }
 
@@ -46,6 +48,7 @@ impl FromPyObject<'_, '_> for PyMatch {
             Helper::Phrase(phrase) => Match::Phrase(MatchPhrase::from(phrase)),
             Helper::Any(any) => Match::Any(MatchAny::from(any)),
             Helper::Except(except) => Match::Except(MatchExcept::from(except)),
+            Helper::Cidr(cidr) => Match::Cidr(MatchCidr::from(cidr)),
         };
 
         Ok(Self(filter))
@@ -65,6 +68,7 @@ impl<'py> IntoPyObject<'py> for PyMatch {
             Match::Phrase(phrase) => PyMatchPhrase(phrase).into_bound_py_any(py),
             Match::Any(any) => PyMatchAny(any).into_bound_py_any(py),
             Match::Except(except) => PyMatchExcept(except).into_bound_py_any(py),
+            Match::Cidr(cidr) => PyMatchCidr(cidr).into_bound_py_any(py),
         }
     }
 }
@@ -78,6 +82,7 @@ impl Repr for PyMatch {
             Match::Phrase(phrase) => PyMatchPhrase::wrap_ref(phrase).fmt(f),
             Match::Any(any) => PyMatchAny::wrap_ref(any).fmt(f),
             Match::Except(except) => PyMatchExcept::wrap_ref(except).fmt(f),
+            Match::Cidr(cidr) => PyMatchCidr::wrap_ref(cidr).fmt(f),
         }
     }
 }
@@ -331,6 +336,36 @@ impl PyMatchExcept {
     }
 }
 
+#[pyclass(name = "MatchCidr", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyMatchCidr(pub MatchCidr);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyMatchCidr {
+    #[new]
+    pub fn new(cidr: String) -> Self {
+        Self(MatchCidr { cidr })
+    }
+
+    #[getter]
+    pub fn cidr(&self) -> &str {
+        &self.0.cidr
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyMatchCidr {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let MatchCidr { cidr: _ } = self.0;
+    }
+}
+
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
 pub struct PyAnyVariants(AnyVariants);
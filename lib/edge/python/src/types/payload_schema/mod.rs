@@ -23,6 +23,7 @@ pub enum PyPayloadSchemaType {
     Bool,
     Datetime,
     Uuid,
+    Ip,
 }
 
 impl Repr for PyPayloadSchemaType {
@@ -36,6 +37,7 @@ impl Repr for PyPayloadSchemaType {
             Self::Bool => "Bool",
             Self::Datetime => "Datetime",
             Self::Uuid => "Uuid",
+            Self::Ip => "Ip",
         };
 
         f.simple_enum::<Self>(repr)
@@ -53,6 +55,7 @@ impl From<PayloadSchemaType> for PyPayloadSchemaType {
             PayloadSchemaType::Bool => PyPayloadSchemaType::Bool,
             PayloadSchemaType::Datetime => PyPayloadSchemaType::Datetime,
             PayloadSchemaType::Uuid => PyPayloadSchemaType::Uuid,
+            PayloadSchemaType::Ip => PyPayloadSchemaType::Ip,
         }
     }
 }
@@ -68,6 +71,7 @@ impl From<PyPayloadSchemaType> for PayloadSchemaType {
             PyPayloadSchemaType::Bool => PayloadSchemaType::Bool,
             PyPayloadSchemaType::Datetime => PayloadSchemaType::Datetime,
             PyPayloadSchemaType::Uuid => PayloadSchemaType::Uuid,
+            PyPayloadSchemaType::Ip => PayloadSchemaType::Ip,
         }
     }
 }
@@ -90,6 +94,7 @@ impl FromPyObject<'_, '_> for PyPayloadSchemaParams {
             Bool(PyBoolIndexParams),
             Datetime(PyDatetimeIndexParams),
             Uuid(PyUuidIndexParams),
+            Ip(PyIpIndexParams),
         }
 
         fn _variants(schema_params: PayloadSchemaParams) {
@@ -102,6 +107,7 @@ impl FromPyObject<'_, '_> for PyPayloadSchemaParams {
                 PayloadSchemaParams::Bool(_) => {}
                 PayloadSchemaParams::Datetime(_) => {}
                 PayloadSchemaParams::Uuid(_) => {}
+                PayloadSchemaParams::Ip(_) => {}
             }
         }
 
@@ -114,6 +120,7 @@ impl FromPyObject<'_, '_> for PyPayloadSchemaParams {
             Helper::Bool(bool) => PayloadSchemaParams::Bool(bool.into()),
             Helper::Datetime(date_time) => PayloadSchemaParams::Datetime(date_time.into()),
             Helper::Uuid(uuid) => PayloadSchemaParams::Uuid(uuid.into()),
+            Helper::Ip(ip) => PayloadSchemaParams::Ip(ip.into()),
         };
 
         Ok(Self(schema_params))
@@ -139,6 +146,7 @@ impl<'py> IntoPyObject<'py> for PyPayloadSchemaParams {
                 PyDatetimeIndexParams(date_time).into_bound_py_any(py)
             }
             PayloadSchemaParams::Uuid(uuid) => PyUuidIndexParams(uuid).into_bound_py_any(py),
+            PayloadSchemaParams::Ip(ip) => PyIpIndexParams(ip).into_bound_py_any(py),
         }
     }
 }
@@ -166,6 +174,7 @@ impl Repr for PyPayloadSchemaParams {
                 PyDatetimeIndexParams::wrap_ref(date_time).fmt(f)
             }
             PayloadSchemaParams::Uuid(uuid) => PyUuidIndexParams::wrap_ref(uuid).fmt(f),
+            PayloadSchemaParams::Ip(ip) => PyIpIndexParams::wrap_ref(ip).fmt(f),
         }
     }
 }
@@ -421,3 +430,27 @@ impl PyUuidIndexParams {
         } = self.0;
     }
 }
+
+#[pyclass(name = "IpIndexParams", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+struct PyIpIndexParams(IpIndexParams);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyIpIndexParams {
+    #[getter]
+    pub fn on_disk(&self) -> Option<bool> {
+        self.0.on_disk
+    }
+}
+
+impl PyIpIndexParams {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let IpIndexParams {
+            r#type: _, // not relevant for Qdrant Edge
+            on_disk: _,
+        } = self.0;
+    }
+}
@@ -1,9 +1,12 @@
 use bytemuck::{TransparentWrapper, TransparentWrapperAlloc as _};
 use derive_more::Into;
 use pyo3::IntoPyObjectExt as _;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use segment::json_path::JsonPathProjection;
 use segment::types::{
-    PayloadSelector, PayloadSelectorExclude, PayloadSelectorInclude, WithPayloadInterface,
+    PayloadSelector, PayloadSelectorExclude, PayloadSelectorInclude, PayloadSelectorProject,
+    WithPayloadInterface,
 };
 
 use crate::repr::*;
@@ -103,6 +106,19 @@ impl FromPyObject<'_, '_> for PyPayloadSelector {
                     exclude: PyJsonPath::peel_vec(keys),
                 })
             }
+            PyPayloadSelectorInterface::Project { expressions } => {
+                let project = expressions
+                    .iter()
+                    .map(|expression| {
+                        expression.parse::<JsonPathProjection>().map_err(|_| {
+                            PyValueError::new_err(format!(
+                                "invalid projection expression {expression}"
+                            ))
+                        })
+                    })
+                    .collect::<PyResult<_>>()?;
+                PayloadSelector::Project(PayloadSelectorProject { project })
+            }
         };
 
         Ok(Self(selector))
@@ -126,6 +142,11 @@ impl<'py> IntoPyObject<'py> for PyPayloadSelector {
                     keys: PyJsonPath::wrap_vec(exclude),
                 }
             }
+            PayloadSelector::Project(PayloadSelectorProject { project }) => {
+                PyPayloadSelectorInterface::Project {
+                    expressions: project.iter().map(ToString::to_string).collect(),
+                }
+            }
         };
 
         Bound::new(py, selector)
@@ -141,6 +162,13 @@ impl Repr for PyPayloadSelector {
             PayloadSelector::Exclude(PayloadSelectorExclude { exclude }) => {
                 ("Exclude", PyJsonPath::wrap_slice(exclude))
             }
+            PayloadSelector::Project(PayloadSelectorProject { project }) => {
+                let expressions: Vec<_> = project.iter().map(ToString::to_string).collect();
+                return f.complex_enum::<PyPayloadSelectorInterface>(
+                    "Project",
+                    &[("expressions", &expressions)],
+                );
+            }
         };
 
         f.complex_enum::<PyPayloadSelectorInterface>(repr, &[("keys", &keys)])
@@ -152,6 +180,7 @@ impl Repr for PyPayloadSelector {
 pub enum PyPayloadSelectorInterface {
     Include { keys: Vec<PyJsonPath> },
     Exclude { keys: Vec<PyJsonPath> },
+    Project { expressions: Vec<String> },
 }
 
 impl Repr for PyPayloadSelectorInterface {
@@ -159,6 +188,9 @@ impl Repr for PyPayloadSelectorInterface {
         let (repr, keys) = match self {
             PyPayloadSelectorInterface::Include { keys } => ("Include", keys),
             PyPayloadSelectorInterface::Exclude { keys } => ("Exclude", keys),
+            PyPayloadSelectorInterface::Project { expressions } => {
+                return f.complex_enum::<Self>("Project", &[("expressions", expressions)]);
+            }
         };
 
         f.complex_enum::<Self>(repr, &[("keys", keys)])
@@ -34,6 +34,11 @@ impl PyRecord {
         self.0.order_value.map(PyOrderValue::from)
     }
 
+    #[getter]
+    pub fn version(&self) -> Option<u64> {
+        self.0.version
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -48,6 +53,8 @@ impl PyRecord {
             vector: _,
             shard_key: _, // not relevant for Qdrant Edge
             order_value: _,
+            version: _,
+            version_peer_id: _, // not relevant for Qdrant Edge
         } = self.0;
     }
 }
@@ -218,4 +218,28 @@ impl PyUpdateOperation {
 
         Self(CollectionUpdateOperations::PayloadOperation(operation))
     }
+
+    #[staticmethod]
+    pub fn patch_payload(point_ids: Vec<PyPointId>, patch: PyPayload) -> Self {
+        let operation = payload_ops::PayloadOps::PatchPayload(payload_ops::SetPayloadOp {
+            payload: Payload::from(patch),
+            points: Some(PyPointId::peel_vec(point_ids)),
+            filter: None,
+            key: None,
+        });
+
+        Self(CollectionUpdateOperations::PayloadOperation(operation))
+    }
+
+    #[staticmethod]
+    pub fn patch_payload_by_filter(filter: PyFilter, patch: PyPayload) -> Self {
+        let operation = payload_ops::PayloadOps::PatchPayload(payload_ops::SetPayloadOp {
+            payload: Payload::from(patch),
+            points: None,
+            filter: Some(Filter::from(filter)),
+            key: None,
+        });
+
+        Self(CollectionUpdateOperations::PayloadOperation(operation))
+    }
 }
@@ -19,6 +19,7 @@ impl EdgeShard {
             limit,
             filter,
             exact,
+            histogram,
         } = request;
 
         let (non_appendable, appendable) = self.segments.read().split_segments();
@@ -32,6 +33,7 @@ impl EdgeShard {
             limit,
             filter,
             exact,
+            histogram,
         };
 
         // Collect and merge facet results from all segments
@@ -48,6 +50,10 @@ impl EdgeShard {
             }
         }
 
-        Ok(FacetResponse::top_hits(merged_counts, limit))
+        if facet_params.histogram.is_some() {
+            Ok(FacetResponse::histogram_hits(merged_counts, limit))
+        } else {
+            Ok(FacetResponse::top_hits(merged_counts, limit))
+        }
     }
 }
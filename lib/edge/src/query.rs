@@ -16,6 +16,7 @@ use segment::types::{
 };
 use shard::query::mmr::mmr_from_points_with_vector;
 use shard::query::planned_query::*;
+use shard::query::score_gap_cutoff::truncate_at_score_gap;
 use shard::query::scroll::{QueryScrollRequestInternal, ScrollOrder};
 use shard::query::*;
 use shard::retrieve::retrieve_blocking::retrieve_blocking;
@@ -27,6 +28,7 @@ use crate::DEFAULT_EDGE_TIMEOUT;
 
 impl EdgeShard {
     pub fn query(&self, request: ShardQueryRequest) -> OperationResult<Vec<ScoredPoint>> {
+        let score_gap_cutoff = request.params.and_then(|params| params.score_gap_cutoff);
         let planned_query = PlannedQuery::try_from(vec![request])?;
 
         let PlannedQuery {
@@ -37,7 +39,12 @@ impl EdgeShard {
 
         let mut search_results = Vec::new();
         for search in &searches {
-            search_results.push(self.search(search.clone())?);
+            let mut search = search.clone();
+            // Only the final results are cut at a score gap
+            if let Some(params) = &mut search.params {
+                params.score_gap_cutoff = None;
+            }
+            search_results.push(self.search(search)?);
         }
 
         let mut scroll_results = Vec::new();
@@ -57,14 +64,19 @@ impl EdgeShard {
             scored_points_batch.push(scored_points)
         }
 
-        let [scored_points] = scored_points_batch
-            .try_into()
-            .map_err(|unconverted: Vec<_>| {
-                OperationError::service_error(format!(
-                    "unexpected scored points batch size: expected 1, received {}",
-                    unconverted.len(),
-                ))
-            })?;
+        let [mut scored_points] =
+            scored_points_batch
+                .try_into()
+                .map_err(|unconverted: Vec<_>| {
+                    OperationError::service_error(format!(
+                        "unexpected scored points batch size: expected 1, received {}",
+                        unconverted.len(),
+                    ))
+                })?;
+
+        if let Some(max_gap) = score_gap_cutoff {
+            truncate_at_score_gap(&mut scored_points, max_gap.0);
+        }
 
         Ok(scored_points)
     }
@@ -252,23 +264,26 @@ impl EdgeShard {
                 hw_counter_acc,
             ),
 
-            ScoringQuery::Sample(sample) => match sample {
-                SampleInternal::Random => {
-                    // create single scroll request for rescoring query
-                    let filter = filter_by_point_ids(&sources);
-
-                    // Note: score_threshold is not used in this case, as all results will have same score and order_value
-                    let scroll_request = QueryScrollRequestInternal {
-                        limit,
-                        filter: Some(filter),
-                        with_payload: false.into(),
-                        with_vector: false.into(),
-                        scroll_order: ScrollOrder::Random,
-                    };
-
-                    self.query_scroll(&scroll_request)
-                }
-            },
+            ScoringQuery::Sample(sample) => {
+                let scroll_order = match sample {
+                    SampleInternal::Random => ScrollOrder::Random,
+                    SampleInternal::Weighted(key) => ScrollOrder::WeightedRandom(key),
+                };
+
+                // create single scroll request for rescoring query
+                let filter = filter_by_point_ids(&sources);
+
+                // Note: score_threshold is not used in this case, as all results will have same score and order_value
+                let scroll_request = QueryScrollRequestInternal {
+                    limit,
+                    filter: Some(filter),
+                    with_payload: false.into(),
+                    with_vector: false.into(),
+                    scroll_order,
+                };
+
+                self.query_scroll(&scroll_request)
+            }
 
             ScoringQuery::Mmr(mmr) => self.mmr_rescore(sources, mmr, limit, hw_counter_acc),
         }
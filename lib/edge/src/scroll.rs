@@ -7,8 +7,11 @@ use rand::distr::weighted::WeightedIndex;
 use rand::rngs::StdRng;
 use rand::{Rng as _, SeedableRng as _};
 use segment::common::operation_error::{OperationError, OperationResult};
+use segment::data_types::facets::FacetParams;
 use segment::data_types::order_by::{Direction, OrderBy};
+use segment::json_path::JsonPath;
 use segment::types::*;
+use shard::facet::FacetRequestInternal;
 use shard::query::scroll::{QueryScrollRequestInternal, ScrollOrder};
 use shard::retrieve::record_internal::RecordInternal;
 use shard::retrieve::retrieve_blocking::retrieve_blocking;
@@ -29,11 +32,30 @@ impl EdgeShard {
             with_payload,
             with_vector,
             order_by,
+            distinct_by,
+            with_version: _,
         } = request;
 
         let limit = limit.unwrap_or(ScrollRequestInternal::default_limit());
         let with_payload = with_payload.unwrap_or(ScrollRequestInternal::default_with_payload());
 
+        if let Some(distinct_by) = distinct_by {
+            if order_by.is_some() {
+                return Err(OperationError::validation_error(
+                    "Cannot use `distinct_by` together with `order_by`",
+                ));
+            }
+            return self.scroll_distinct(
+                offset,
+                limit,
+                &with_payload,
+                &with_vector,
+                filter,
+                distinct_by,
+                HwMeasurementAcc::disposable_edge(),
+            );
+        }
+
         match order_by.map(OrderBy::from) {
             None => {
                 let limit_plus_one = limit.saturating_add(1);
@@ -107,6 +129,15 @@ impl EdgeShard {
                 with_payload,
                 with_vector,
                 filter.as_ref(),
+                None,
+                HwMeasurementAcc::disposable_edge(),
+            )?,
+            ScrollOrder::WeightedRandom(key) => self.scroll_randomly(
+                *limit,
+                with_payload,
+                with_vector,
+                filter.as_ref(),
+                Some(key),
                 HwMeasurementAcc::disposable_edge(),
             )?,
         };
@@ -179,6 +210,89 @@ impl EdgeShard {
         Ok(ordered_points)
     }
 
+    /// Scroll only the first point for each unique value of the `distinct_by` key.
+    ///
+    /// Values and their first points are looked up by the payload index of the key. Selected
+    /// points are paginated in the order of their IDs.
+    #[expect(clippy::too_many_arguments)]
+    fn scroll_distinct(
+        &self,
+        offset: Option<ExtendedPointId>,
+        limit: usize,
+        with_payload_interface: &WithPayloadInterface,
+        with_vector: &WithVector,
+        filter: Option<Filter>,
+        distinct_by: JsonPath,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> OperationResult<(Vec<RecordInternal>, Option<PointIdType>)> {
+        let value_params = FacetParams {
+            key: distinct_by,
+            limit: usize::MAX,
+            filter,
+            exact: false,
+            histogram: None,
+        };
+
+        let values = self
+            .facet(FacetRequestInternal {
+                key: value_params.key.clone(),
+                limit: value_params.limit,
+                filter: value_params.filter.clone(),
+                exact: value_params.exact,
+                histogram: None,
+            })?
+            .hits;
+
+        let mut point_ids = Vec::with_capacity(values.len());
+        for hit in values {
+            let value_filter =
+                Filter::new_must(Condition::Field(value_params.value_condition(hit.value)));
+            let filter = Filter::merge_opts(value_params.filter.clone(), Some(value_filter));
+
+            let first_point = self.scroll_by_id(
+                None,
+                1,
+                &WithPayloadInterface::Bool(false),
+                &WithVector::Bool(false),
+                filter.as_ref(),
+                hw_measurement_acc.clone(),
+            )?;
+            point_ids.extend(first_point.into_iter().map(|record| record.id));
+        }
+
+        // Take one more point, to return next page offset
+        let mut point_ids = point_ids
+            .into_iter()
+            .filter(|id| offset.is_none_or(|offset| *id >= offset))
+            .sorted_unstable()
+            .dedup()
+            .take(limit + 1)
+            .collect_vec();
+
+        let next_offset = if point_ids.len() > limit {
+            point_ids.pop()
+        } else {
+            None
+        };
+
+        let mut points = retrieve_blocking(
+            self.segments.clone(),
+            &point_ids,
+            &WithPayload::from(with_payload_interface),
+            with_vector,
+            DEFAULT_EDGE_TIMEOUT,
+            &AtomicBool::new(false),
+            hw_measurement_acc,
+        )?;
+
+        let ordered_points = point_ids
+            .iter()
+            .filter_map(|point_id| points.remove(point_id))
+            .collect();
+
+        Ok((ordered_points, next_offset))
+    }
+
     fn scroll_by_field(
         &self,
         limit: usize,
@@ -238,12 +352,14 @@ impl EdgeShard {
         Ok(ordered_points)
     }
 
+    /// Sample random points, weighted by the value of `weight_by` field if given.
     fn scroll_randomly(
         &self,
         limit: usize,
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        weight_by: Option<&JsonPath>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> OperationResult<Vec<RecordInternal>> {
         let (non_appendable, appendable) = self.segments.read().split_segments();
@@ -256,24 +372,40 @@ impl EdgeShard {
                 let segment = segment.get();
                 let segment = segment.read();
 
-                let point_count = segment.available_point_count();
-                let point_ids = segment.read_random_filtered(
+                let Some(key) = weight_by else {
+                    let point_count = segment.available_point_count() as f64;
+                    let point_ids = segment.read_random_filtered(
+                        limit,
+                        filter,
+                        &AtomicBool::new(false),
+                        &hw_counter,
+                    );
+                    return Ok((point_count, point_ids));
+                };
+
+                let (total_weight, mut point_ids) = segment.read_weighted_random_filtered(
                     limit,
+                    key,
                     filter,
                     &AtomicBool::new(false),
                     &hw_counter,
-                );
+                )?;
+                // Points are popped from the end, most likely ones go first
+                point_ids.reverse();
 
-                (point_count, point_ids)
+                Ok((total_weight, point_ids))
             })
+            .collect::<OperationResult<Vec<_>>>()?
+            .into_iter()
             .unzip();
 
         // Shortcut if all segments are empty
-        if point_count.iter().all(|&count| count == 0) {
+        if point_count.iter().all(|&weight| weight <= 0.0) {
             return Ok(Vec::new());
         }
 
-        // Select points in a weighted fashion from each segment, depending on how many points each segment has.
+        // Select points in a weighted fashion from each segment, depending on how many points
+        // (or how much total weight) each segment has.
         let distribution = WeightedIndex::new(point_count).map_err(|err| {
             OperationError::service_error(format!(
                 "failed to create weighted index for random scroll: {err:?}"
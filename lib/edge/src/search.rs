@@ -7,6 +7,7 @@ use segment::data_types::vectors::QueryVector;
 use segment::types::{DEFAULT_FULL_SCAN_THRESHOLD, ScoredPoint, WithPayload};
 use shard::common::stopping_guard::StoppingGuard;
 use shard::query::query_context::{fill_query_context, init_query_context};
+use shard::query::score_gap_cutoff::truncate_at_score_gap;
 use shard::search::CoreSearchRequest;
 use shard::search_result_aggregator::BatchResultAggregator;
 
@@ -28,6 +29,12 @@ impl EdgeShard {
                     .get(vector_name)
                     .is_some_and(|v| v.modifier == Some(Modifier::Idf))
             },
+            |vector_name| {
+                self.config
+                    .sparse_vector_data
+                    .get(vector_name)
+                    .and_then(|v| v.index.block_max)
+            },
         );
         let [search] = searches;
         let Some(context) = fill_query_context(
@@ -133,6 +140,10 @@ impl EdgeShard {
             }
         }
 
+        if let Some(max_gap) = params.and_then(|params| params.score_gap_cutoff) {
+            truncate_at_score_gap(&mut points, max_gap.0);
+        }
+
         let _ = points.drain(..cmp::min(points.len(), offset));
 
         Ok(points)
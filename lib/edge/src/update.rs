@@ -1,4 +1,5 @@
 use std::fmt;
+use std::sync::atomic::AtomicBool;
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use segment::common::operation_error::{OperationError, OperationResult};
@@ -44,6 +45,7 @@ impl EdgeShard {
                     &segments_guard,
                     operation_id,
                     &index_operation,
+                    &AtomicBool::new(false),
                     &hw_counter,
                 )
             }
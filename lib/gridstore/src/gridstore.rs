@@ -7,6 +7,7 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::counter::referenced_counter::HwMetricRefCounter;
 use common::fs::atomic_save_json;
 use common::is_alive_lock::IsAliveLock;
+use common::mmap::Advice;
 use fs_err as fs;
 use fs_err::File;
 use itertools::Itertools;
@@ -23,6 +24,8 @@ use crate::tracker::{BlockOffset, PageId, PointOffset, Tracker, ValuePointer};
 
 const CONFIG_FILENAME: &str = "config.json";
 
+const DEFAULT_PAGE_ADVICE: Advice = Advice::Random;
+
 pub type Flusher = Box<dyn FnOnce() -> std::result::Result<(), GridstoreError> + Send>;
 
 /// Storage for values of type `V`.
@@ -44,6 +47,8 @@ pub struct Gridstore<V> {
     bitmask: Arc<RwLock<Bitmask>>,
     /// Path of the directory where the storage files are stored
     base_path: PathBuf,
+    /// Advice for the main mmap of pages, which serves random reads
+    page_advice: Advice,
     _value_type: std::marker::PhantomData<V>,
 
     /// Lock to prevent concurrent flushes and used for waiting for ongoing flushes to finish.
@@ -149,6 +154,7 @@ impl<V: Blob> Gridstore<V> {
             pages: Default::default(),
             bitmask: Arc::new(RwLock::new(Bitmask::create(&base_path, config)?)),
             base_path,
+            page_advice: DEFAULT_PAGE_ADVICE,
             config,
             _value_type: std::marker::PhantomData,
             is_alive_flush_lock: IsAliveLock::new(),
@@ -157,7 +163,7 @@ impl<V: Blob> Gridstore<V> {
         // create first page to be covered by the bitmask
         let new_page_id = storage.next_page_id();
         let path = storage.page_path(new_page_id);
-        let page = Page::new(&path, storage.config.page_size_bytes)?;
+        let page = Page::new(&path, storage.config.page_size_bytes, storage.page_advice)?;
         storage.pages.write().push(page);
 
         // lastly, write config to disk to use as a signal that the storage has been created correctly
@@ -198,6 +204,7 @@ impl<V: Blob> Gridstore<V> {
             pages: Arc::new(RwLock::new(Vec::with_capacity(num_pages))),
             bitmask: Arc::new(RwLock::new(bitmask)),
             base_path,
+            page_advice: DEFAULT_PAGE_ADVICE,
             _value_type: std::marker::PhantomData,
             is_alive_flush_lock: IsAliveLock::new(),
         };
@@ -205,13 +212,24 @@ impl<V: Blob> Gridstore<V> {
         let mut pages = storage.pages.write();
         for page_id in 0..num_pages as PageId {
             let page_path = storage.page_path(page_id);
-            let page = Page::open(&page_path)?;
+            let page = Page::open(&page_path, storage.page_advice)?;
             pages.push(page);
         }
         drop(pages);
         Ok(storage)
     }
 
+    /// Use `advice` for random reads of pages, instead of [`Advice::Random`].
+    ///
+    /// Applies to already opened pages, as well as to pages created later.
+    pub fn set_page_advice(&mut self, advice: Advice) -> Result<()> {
+        for page in self.pages.read().iter() {
+            page.madvise(advice)?;
+        }
+        self.page_advice = advice;
+        Ok(())
+    }
+
     /// Get the path for a given page id
     fn page_path(&self, page_id: u32) -> PathBuf {
         self.base_path.join(format!("page_{page_id}.dat"))
@@ -289,7 +307,7 @@ impl<V: Blob> Gridstore<V> {
     fn create_new_page(&mut self) -> Result<u32> {
         let new_page_id = self.next_page_id();
         let path = self.page_path(new_page_id);
-        let page = Page::new(&path, self.config.page_size_bytes)?;
+        let page = Page::new(&path, self.config.page_size_bytes, self.page_advice)?;
         self.pages.write().push(page);
 
         self.bitmask.write().cover_new_page()?;
@@ -33,9 +33,11 @@ impl Page {
     }
 
     /// Create a new page at the given path
-    pub fn new(path: &Path, size: usize) -> Result<Page> {
+    ///
+    /// `advice` is used for the main mmap, which serves random reads.
+    pub fn new(path: &Path, size: usize, advice: Advice) -> Result<Page> {
         create_and_ensure_length(path, size)?;
-        let mmap = open_write_mmap(path, AdviceSetting::from(Advice::Random), false)?;
+        let mmap = open_write_mmap(path, AdviceSetting::from(advice), false)?;
 
         // Only open second mmap for sequential reads if supported
         let mmap_seq = if *MULTI_MMAP_IS_SUPPORTED {
@@ -59,14 +61,16 @@ impl Page {
 
     /// Open an existing page at the given path
     /// If the file does not exist, return None
-    pub fn open(path: &Path) -> Result<Page> {
+    ///
+    /// `advice` is used for the main mmap, which serves random reads.
+    pub fn open(path: &Path, advice: Advice) -> Result<Page> {
         if !path.exists() {
             return Err(GridstoreError::service_error(format!(
                 "Page file does not exist: {}",
                 path.display()
             )));
         }
-        let mmap = open_write_mmap(path, AdviceSetting::from(Advice::Random), false)?;
+        let mmap = open_write_mmap(path, AdviceSetting::from(advice), false)?;
 
         // Only open second mmap for sequential reads if supported
         let mmap_seq = if *MULTI_MMAP_IS_SUPPORTED {
@@ -87,6 +91,12 @@ impl Page {
         })
     }
 
+    /// Change advice of the main mmap, which serves random reads
+    pub fn madvise(&self, advice: Advice) -> Result<()> {
+        self.mmap.madvise(advice)?;
+        Ok(())
+    }
+
     /// Helper to get a slice suited for sequential reads if available, otherwise use the main mmap
     #[inline]
     fn mmap_seq(&self) -> &[u8] {
@@ -373,6 +373,8 @@ fn build_hnsw_index<R: Rng + ?Sized>(
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let open_args = HnswIndexOpenArgs {
@@ -62,10 +62,22 @@ pub enum OperationError {
         "No range index for `order_by` key: `{key}`. Please create one to use `order_by`. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index to see which payload schemas support Range conditions"
     )]
     MissingRangeIndexForOrderBy { key: String },
+    #[error(
+        "No range index for weighted sample key: `{key}`. Please create one to use weighted sampling. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index to see which payload schemas support Range conditions"
+    )]
+    MissingRangeIndexForSample { key: String },
     #[error(
         "No appropriate index for faceting: `{key}`. Please create one to facet on this field. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index to see which payload schemas support Match conditions"
     )]
     MissingMapIndexForFacet { key: String },
+    #[error(
+        "No range index for histogram facet key: `{key}`. Please create one to facet on this field. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index to see which payload schemas support Range conditions"
+    )]
+    MissingRangeIndexForFacet { key: String },
+    #[error(
+        "Histogram facet of key `{key}` has more than {limit} buckets. Please use a larger interval or a filter"
+    )]
+    TooManyHistogramBuckets { key: String, limit: usize },
     #[error(
         "Expected {expected_type} value for {field_name} in the payload and/or in the formula defaults. Error: {description}"
     )]
@@ -48,6 +48,35 @@ pub fn merge_map(
     }
 }
 
+/// Apply `patch` to the destination map, following JSON Merge Patch (RFC 7386) semantics.
+///
+/// Unlike [`merge_map`], nested objects are merged recursively instead of being replaced,
+/// and `null` removes a key at any level.
+pub fn merge_patch_map(
+    dest: &mut serde_json::Map<String, Value>,
+    patch: &serde_json::Map<String, Value>,
+) {
+    for (key, value) in patch {
+        match value {
+            Value::Null => {
+                dest.remove(key);
+            }
+            Value::Object(patch) => match dest.get_mut(key) {
+                Some(Value::Object(dest)) => merge_patch_map(dest, patch),
+                // non-object values are replaced, `null`s are not kept in the new object
+                _ => {
+                    let mut value = serde_json::Map::new();
+                    merge_patch_map(&mut value, patch);
+                    dest.insert(key.to_owned(), Value::Object(value));
+                }
+            },
+            _ => {
+                dest.insert(key.to_owned(), value.to_owned());
+            }
+        }
+    }
+}
+
 pub fn transpose_map_into_named_vector<TVector: Into<VectorInternal>>(
     map: HashMap<VectorNameBuf, Vec<TVector>>,
 ) -> Vec<NamedVectors<'static>> {
@@ -135,8 +164,38 @@ impl<T: JsonSchema> JsonSchema for MaybeOneOrMany<T> {
 mod tests {
     use schemars::{JsonSchema, schema_for};
     use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::common::utils::{MaybeOneOrMany, merge_patch_map};
+
+    #[test]
+    fn test_merge_patch_map() {
+        let mut dest = json!({
+            "a": "b",
+            "c": {"d": "e", "f": "g"},
+            "h": [1, 2],
+            "i": 1,
+        });
+        let patch = json!({
+            "a": "z",
+            "c": {"f": null, "x": {"y": null, "z": 1}},
+            "h": [3],
+            "i": {"j": 2},
+            "k": null,
+        });
+
+        merge_patch_map(dest.as_object_mut().unwrap(), patch.as_object().unwrap());
 
-    use crate::common::utils::MaybeOneOrMany;
+        assert_eq!(
+            dest,
+            json!({
+                "a": "z",
+                "c": {"d": "e", "x": {"z": 1}},
+                "h": [3],
+                "i": {"j": 2},
+            }),
+        );
+    }
 
     #[test]
     fn test_deserialize_one_or_many() {
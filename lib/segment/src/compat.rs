@@ -56,6 +56,7 @@ impl From<SegmentConfigV5> for SegmentConfig {
                     },
                     multivector_config: None,
                     datatype: None,
+                    storage_backend: None,
                 };
 
                 (vector_name, new_data)
@@ -76,6 +77,7 @@ impl From<SegmentConfigV5> for SegmentConfig {
             payload_storage_type: old_segment
                 .payload_storage_type
                 .unwrap_or(default_storage_type),
+            payload_overflow_threshold: None,
         }
     }
 }
@@ -167,6 +169,8 @@ mod tests {
                             on_disk: None,
                             payload_m: Some(10),
                             inline_storage: None,
+                            links_compression: None,
+                            tenant_subgraphs: None,
                         }),
                         quantization_config: None,
                         on_disk: None,
@@ -199,6 +203,8 @@ mod tests {
                 on_disk: None,
                 payload_m: None,
                 inline_storage: None,
+                links_compression: None,
+                tenant_subgraphs: None,
             }),
             storage_type: StorageTypeV5::InMemory,
             payload_storage_type: None,
@@ -275,6 +281,8 @@ mod tests {
                 on_disk: None,
                 payload_m: None,
                 inline_storage: None,
+                links_compression: None,
+                tenant_subgraphs: None,
             }),
             storage_type: StorageTypeV5::InMemory,
             payload_storage_type: None,
@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+
+use ordered_float::OrderedFloat;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use validator::Validate;
+
+use crate::data_types::facets::FacetValue;
+use crate::data_types::order_by::OrderValue;
+use crate::json_path::JsonPath;
+use crate::types::{Filter, FloatPayloadType};
+
+/// Maximum number of distinct values counted by an aggregation.
+///
+/// Distinct values are kept to count them exactly across segments and shards, the limit bounds
+/// memory of the aggregation and size of responses of remote shards.
+pub const MAX_DISTINCT_VALUES: usize = 10_000;
+
+#[derive(Clone, Debug, JsonSchema, Serialize, Deserialize, Validate, Hash)]
+pub struct AggregateParams {
+    pub key: JsonPath,
+
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+}
+
+/// Aggregates over values of a payload key.
+///
+/// Partial aggregates of segments and shards are combined with [`AggregateResult::merge`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AggregateResult {
+    /// Number of numeric values, each element of an array is counted
+    pub count: usize,
+    pub sum: FloatPayloadType,
+    pub min: Option<FloatPayloadType>,
+    pub max: Option<FloatPayloadType>,
+    /// All distinct values, up to [`MAX_DISTINCT_VALUES`]
+    pub distinct_values: HashSet<FacetValue>,
+    /// There are more than [`MAX_DISTINCT_VALUES`] distinct values, they are not tracked anymore
+    pub distinct_values_exceeded: bool,
+}
+
+impl AggregateResult {
+    pub fn add_number(&mut self, value: FloatPayloadType) {
+        if !value.is_finite() {
+            return;
+        }
+
+        self.count += 1;
+        self.sum += value;
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+    }
+
+    pub fn add_distinct(&mut self, value: FacetValue) {
+        if self.distinct_values_exceeded {
+            return;
+        }
+        self.distinct_values.insert(value);
+        self.check_distinct_values_limit();
+    }
+
+    fn check_distinct_values_limit(&mut self) {
+        if self.distinct_values.len() > MAX_DISTINCT_VALUES {
+            self.distinct_values = HashSet::new();
+            self.distinct_values_exceeded = true;
+        }
+    }
+
+    /// Add an indexed numeric value, counting it as both a number and a distinct value
+    pub fn add_order_value(&mut self, value: OrderValue) {
+        match value {
+            OrderValue::Int(integer) => {
+                self.add_number(integer as FloatPayloadType);
+                self.add_distinct(FacetValue::Int(integer));
+            }
+            OrderValue::Float(float) => {
+                self.add_number(float);
+                self.add_distinct(FacetValue::Float(OrderedFloat(float)));
+            }
+        }
+    }
+
+    /// Add a value read from the payload, elements of arrays are added separately
+    pub fn add_payload_value(&mut self, value: &Value) {
+        match value {
+            Value::Number(number) => {
+                if let Some(integer) = number.as_i64() {
+                    self.add_order_value(OrderValue::Int(integer));
+                } else if let Some(float) = number.as_f64() {
+                    self.add_order_value(OrderValue::Float(float));
+                }
+            }
+            Value::String(keyword) => self.add_distinct(FacetValue::Keyword(keyword.clone())),
+            Value::Bool(boolean) => self.add_distinct(FacetValue::Bool(*boolean)),
+            Value::Array(values) => values
+                .iter()
+                .for_each(|value| self.add_payload_value(value)),
+            Value::Null | Value::Object(_) => {}
+        }
+    }
+
+    pub fn merge(&mut self, other: AggregateResult) {
+        let AggregateResult {
+            count,
+            sum,
+            min,
+            max,
+            distinct_values,
+            distinct_values_exceeded,
+        } = other;
+
+        self.count += count;
+        self.sum += sum;
+        self.min = self
+            .min
+            .into_iter()
+            .chain(min)
+            .reduce(FloatPayloadType::min);
+        self.max = self
+            .max
+            .into_iter()
+            .chain(max)
+            .reduce(FloatPayloadType::max);
+
+        if distinct_values_exceeded {
+            self.distinct_values = HashSet::new();
+            self.distinct_values_exceeded = true;
+        }
+        if !self.distinct_values_exceeded {
+            self.distinct_values.extend(distinct_values);
+            self.check_distinct_values_limit();
+        }
+    }
+
+    pub fn avg(&self) -> Option<FloatPayloadType> {
+        (self.count > 0).then(|| self.sum / self.count as FloatPayloadType)
+    }
+
+    /// Number of distinct values, `None` if there are more than [`MAX_DISTINCT_VALUES`]
+    pub fn distinct_count(&self) -> Option<usize> {
+        (!self.distinct_values_exceeded).then_some(self.distinct_values.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_aggregate_payload_values() {
+        let mut first = AggregateResult::default();
+        first.add_payload_value(&json!([1, 2.5, "a"]));
+        first.add_payload_value(&json!(true));
+
+        let mut second = AggregateResult::default();
+        second.add_payload_value(&json!(-3));
+        second.add_payload_value(&json!("a"));
+        second.add_payload_value(&json!({"nested": 1}));
+
+        first.merge(second);
+
+        assert_eq!(first.count, 3);
+        assert_eq!(first.sum, 0.5);
+        assert_eq!(first.min, Some(-3.0));
+        assert_eq!(first.max, Some(2.5));
+        assert_eq!(first.avg(), Some(0.5 / 3.0));
+        // 1, 2.5, "a", true, -3
+        assert_eq!(first.distinct_count(), Some(5));
+
+        assert_eq!(AggregateResult::default().avg(), None);
+    }
+
+    #[test]
+    fn test_aggregate_distinct_values_limit() {
+        let mut first = AggregateResult::default();
+        let mut second = AggregateResult::default();
+        for value in 0..MAX_DISTINCT_VALUES as i64 {
+            first.add_payload_value(&json!(value));
+            second.add_payload_value(&json!(-value));
+        }
+        assert_eq!(first.distinct_count(), Some(MAX_DISTINCT_VALUES));
+
+        // Values are still aggregated, only distinct values are not counted anymore
+        first.merge(second);
+        assert_eq!(first.count, 2 * MAX_DISTINCT_VALUES);
+        assert_eq!(first.distinct_count(), None);
+        assert!(first.distinct_values.is_empty());
+
+        first.add_payload_value(&json!("a"));
+        first.merge(AggregateResult::default());
+        assert_eq!(first.distinct_count(), None);
+    }
+}
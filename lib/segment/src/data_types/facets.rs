@@ -1,15 +1,27 @@
+use std::borrow::Cow;
 use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::hash::Hash;
 
 use itertools::Itertools;
+use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors};
 
 use crate::json_path::JsonPath;
-use crate::types::{Filter, IntPayloadType, UuidIntType, ValueVariants};
+use crate::types::{
+    DateTimePayloadType, FieldCondition, Filter, FloatPayloadType, IntPayloadType, Match, Range,
+    UuidIntType, ValueVariants,
+};
+
+/// Datetime values are indexed in microseconds, but histogram buckets are defined in seconds
+const MICROS_IN_SECOND: f64 = 1_000_000.0;
+
+/// Maximal number of histogram buckets of a facet request, also checked for each segment when
+/// buckets are defined by an interval
+pub const MAX_HISTOGRAM_BUCKETS: usize = 10_000;
 
 #[derive(Clone, Debug, JsonSchema, Serialize, Deserialize, Validate, Hash)]
 pub struct FacetParams {
@@ -21,11 +33,202 @@ pub struct FacetParams {
     pub filter: Option<Filter>,
     #[serde(default)]
     pub exact: bool,
+    #[serde(default)]
+    #[validate(nested)]
+    pub histogram: Option<FacetHistogram>,
 }
 
 impl FacetParams {
     pub const DEFAULT_LIMIT: usize = 10;
     pub const DEFAULT_EXACT: bool = false;
+
+    /// Condition, which matches points counted under the given facet value
+    pub fn value_condition(&self, value: FacetValue) -> FieldCondition {
+        let key = self.key.clone();
+        let bucket_end = |lower| {
+            self.histogram
+                .as_ref()
+                .and_then(|histogram| histogram.bucket_end(lower))
+        };
+
+        match value {
+            FacetValue::Keyword(keyword) => {
+                FieldCondition::new_match(key, Match::new_value(ValueVariants::String(keyword)))
+            }
+            FacetValue::Int(integer) => {
+                FieldCondition::new_match(key, Match::new_value(ValueVariants::Integer(integer)))
+            }
+            FacetValue::Uuid(uuid) => FieldCondition::new_match(
+                key,
+                Match::new_value(ValueVariants::String(Uuid::from_u128(uuid).to_string())),
+            ),
+            FacetValue::Bool(b) => {
+                FieldCondition::new_match(key, Match::new_value(ValueVariants::Bool(b)))
+            }
+            FacetValue::Float(lower) => FieldCondition::new_range(
+                key,
+                Range {
+                    gte: Some(lower),
+                    lt: bucket_end(lower.0).map(OrderedFloat),
+                    gt: None,
+                    lte: None,
+                },
+            ),
+            FacetValue::DateTime(lower) => {
+                let lower_secs = lower as f64 / MICROS_IN_SECOND;
+                FieldCondition::new_datetime_range(
+                    key,
+                    Range {
+                        gte: DateTimePayloadType::from_timestamp(lower),
+                        lt: bucket_end(lower_secs).and_then(|upper| {
+                            DateTimePayloadType::from_timestamp(seconds_to_micros(upper))
+                        }),
+                        gt: None,
+                        lte: None,
+                    },
+                )
+            }
+        }
+    }
+}
+
+/// Facet counts for several keys, sharing the same filter
+#[derive(Clone, Debug, JsonSchema, Serialize, Deserialize, Validate, Hash)]
+pub struct MultiFacetParams {
+    #[validate(length(min = 1))]
+    pub keys: Vec<JsonPath>,
+
+    #[validate(range(min = 1))]
+    pub limit: usize,
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+    #[serde(default)]
+    pub exact: bool,
+}
+
+impl MultiFacetParams {
+    /// Single key facet request for each of the keys, in the same order
+    pub fn facet_params(&self) -> impl Iterator<Item = FacetParams> + '_ {
+        self.keys.iter().map(|key| FacetParams {
+            key: key.clone(),
+            limit: self.limit,
+            filter: self.filter.clone(),
+            exact: self.exact,
+            histogram: None,
+        })
+    }
+}
+
+/// Count points in numeric ranges of the key instead of unique values.
+///
+/// Bucket bounds are numbers, for datetime keys they are Unix timestamps in seconds.
+#[derive(Clone, Debug, PartialEq, JsonSchema, Serialize, Deserialize, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum FacetHistogram {
+    /// Width of buckets, which start at multiples of it
+    Interval(OrderedFloat<FloatPayloadType>),
+    /// Bucket bounds in ascending order. Bucket `i` counts values from `bounds[i]` (inclusive)
+    /// to `bounds[i + 1]` (exclusive), values outside of the bounds are not counted
+    Bounds(Vec<OrderedFloat<FloatPayloadType>>),
+}
+
+impl FacetHistogram {
+    /// Lower bound of the bucket containing the value, `None` if no bucket contains it
+    pub fn bucket(&self, value: FloatPayloadType) -> Option<FloatPayloadType> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        match self {
+            FacetHistogram::Interval(interval) => Some((value / interval.0).floor() * interval.0),
+            FacetHistogram::Bounds(bounds) => {
+                let next_bound = bounds.partition_point(|bound| bound.0 <= value);
+                (next_bound > 0 && next_bound < bounds.len()).then(|| bounds[next_bound - 1].0)
+            }
+        }
+    }
+
+    /// Upper (exclusive) bound of the bucket with the given lower bound
+    pub fn bucket_end(&self, lower: FloatPayloadType) -> Option<FloatPayloadType> {
+        match self {
+            FacetHistogram::Interval(interval) => Some(lower + interval.0),
+            FacetHistogram::Bounds(bounds) => bounds
+                .iter()
+                .map(|bound| bound.0)
+                .find(|&bound| bound > lower),
+        }
+    }
+
+    /// Facet value of the bucket containing an indexed numeric value
+    pub fn float_bucket(&self, value: FloatPayloadType) -> Option<FacetValue> {
+        self.bucket(value)
+            .map(|lower| FacetValue::Float(OrderedFloat(lower)))
+    }
+
+    /// Facet value of the bucket containing an indexed datetime, given in microseconds
+    pub fn datetime_bucket(&self, timestamp: IntPayloadType) -> Option<FacetValue> {
+        self.bucket(timestamp as f64 / MICROS_IN_SECOND)
+            .map(|lower| FacetValue::DateTime(seconds_to_micros(lower)))
+    }
+}
+
+/// Format lower bound of a datetime histogram bucket as RFC 3339 string
+pub fn datetime_bucket_to_string(timestamp: IntPayloadType) -> String {
+    DateTimePayloadType::from_timestamp(timestamp)
+        .map(|datetime| {
+            datetime
+                .0
+                .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+        })
+        .unwrap_or_default()
+}
+
+fn seconds_to_micros(seconds: FloatPayloadType) -> IntPayloadType {
+    (seconds * MICROS_IN_SECOND).round() as IntPayloadType
+}
+
+impl Validate for FacetHistogram {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        match self {
+            FacetHistogram::Interval(interval) => {
+                if !(interval.0.is_finite() && interval.0 > 0.0) {
+                    errors.add(
+                        "interval",
+                        ValidationError::new("interval must be a positive number"),
+                    );
+                }
+            }
+            FacetHistogram::Bounds(bounds) => {
+                if bounds.len() < 2 {
+                    errors.add(
+                        "bounds",
+                        ValidationError::new("at least two bounds are required"),
+                    );
+                } else if bounds.len() > MAX_HISTOGRAM_BUCKETS + 1 {
+                    errors.add(
+                        "bounds",
+                        ValidationError::new("too many bounds").with_message(Cow::Owned(format!(
+                            "at most {MAX_HISTOGRAM_BUCKETS} buckets are allowed",
+                        ))),
+                    );
+                } else if !bounds.iter().all(|bound| bound.0.is_finite())
+                    || !bounds.is_sorted_by(|a, b| a < b)
+                {
+                    errors.add(
+                        "bounds",
+                        ValidationError::new("bounds must be finite and strictly ascending"),
+                    );
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -71,9 +274,10 @@ pub enum FacetValue {
     Int(IntPayloadType),
     Uuid(UuidIntType),
     Bool(bool),
-    // other types to add?
-    // Bool(bool),
-    // FloatRange(FloatRange),
+    /// Lower bound of a histogram bucket
+    Float(OrderedFloat<FloatPayloadType>),
+    /// Lower bound of a datetime histogram bucket, Unix timestamp in microseconds
+    DateTime(IntPayloadType),
 }
 
 pub trait FacetValueTrait: Clone + PartialEq + Eq + Hash + Ord {}
@@ -107,6 +311,18 @@ impl FacetResponse {
 
         Self { hits }
     }
+
+    /// Convert a count map of histogram buckets to the first `limit` buckets, sorted by their
+    /// lower bound.
+    pub fn histogram_hits(counts: HashMap<FacetValue, usize>, limit: usize) -> Self {
+        let hits = counts
+            .into_iter()
+            .map(|(value, count)| FacetValueHit { value, count })
+            .k_smallest_by(limit, |a, b| a.value.cmp(&b.value))
+            .collect();
+
+        Self { hits }
+    }
 }
 
 impl<T: FacetValueTrait> Ord for FacetHit<T> {
@@ -123,14 +339,3 @@ impl<T: FacetValueTrait> PartialOrd for FacetHit<T> {
         Some(self.cmp(other))
     }
 }
-
-impl From<FacetValue> for ValueVariants {
-    fn from(value: FacetValue) -> Self {
-        match value {
-            FacetValue::Keyword(s) => ValueVariants::String(s),
-            FacetValue::Int(i) => ValueVariants::Integer(i),
-            FacetValue::Uuid(uuid) => ValueVariants::String(Uuid::from_u128(uuid).to_string()),
-            FacetValue::Bool(b) => ValueVariants::Bool(b),
-        }
-    }
-}
@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
@@ -7,12 +8,29 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::cow::SimpleCow;
 use common::types::ScoreType;
+use parking_lot::Mutex;
 use sparse::common::types::{DimId, DimWeight};
+use uuid::Uuid;
 
 use crate::data_types::tiny_map;
 use crate::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
+use crate::index::{SearchExplanation, SegmentSearchExplanation};
 use crate::types::{ScoredPoint, VectorName, VectorNameBuf};
 
+/// Explanations of how segments executed searches, shared by all searches of a request
+#[derive(Debug, Clone, Default)]
+pub struct SearchExplanations(Arc<Mutex<Vec<SegmentSearchExplanation>>>);
+
+impl SearchExplanations {
+    pub fn extend(&self, explanations: impl IntoIterator<Item = SegmentSearchExplanation>) {
+        self.0.lock().extend(explanations);
+    }
+
+    pub fn take(&self) -> Vec<SegmentSearchExplanation> {
+        std::mem::take(&mut *self.0.lock())
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct QueryIdfStats {
     /// Statistics of the element frequency,
@@ -42,9 +60,15 @@ pub struct QueryContext {
     /// Required for processing sparse vector search with `idf-dot` similarity.
     idf_stats: QueryIdfStats,
 
+    /// Block-max search setting of sparse vectors, overriding the one of segment indices.
+    sparse_block_max: tiny_map::TinyMap<VectorNameBuf, bool>,
+
     /// Structure to accumulate and report hardware usage.
     /// Holds reference to the shared drain, which is used to accumulate the values.
     hardware_usage_accumulator: HwMeasurementAcc,
+
+    /// Explanations of executed segment searches, collected only if requested
+    explanations: Option<SearchExplanations>,
 }
 
 impl QueryContext {
@@ -57,7 +81,9 @@ impl QueryContext {
             search_optimized_threshold_kb,
             is_stopped: Arc::new(AtomicBool::new(false)),
             idf_stats: QueryIdfStats::default(),
+            sparse_block_max: tiny_map::TinyMap::default(),
             hardware_usage_accumulator,
+            explanations: None,
         }
     }
 
@@ -70,6 +96,12 @@ impl QueryContext {
         self
     }
 
+    /// Collect explanations of how segments execute the searches into `explanations`
+    pub fn with_explanations(mut self, explanations: Option<SearchExplanations>) -> Self {
+        self.explanations = explanations;
+        self
+    }
+
     pub fn available_point_count(&self) -> usize {
         self.available_point_count
     }
@@ -104,6 +136,12 @@ impl QueryContext {
         }
     }
 
+    /// Search sparse vector `vector_name` with block-max skipping, or without it
+    pub fn set_sparse_block_max(&mut self, vector_name: &VectorName, block_max: bool) {
+        self.sparse_block_max
+            .insert(vector_name.to_owned(), block_max);
+    }
+
     pub fn mut_idf_stats(&mut self) -> &mut QueryIdfStats {
         &mut self.idf_stats
     }
@@ -152,8 +190,34 @@ impl<'a> SegmentQueryContext<'a> {
                 .indexed_vectors
                 .get(vector_name)
                 .copied(),
+            block_max: self
+                .query_context
+                .sparse_block_max
+                .get(vector_name)
+                .copied(),
             deleted_points: self.deleted_points,
             hardware_counter: self.hardware_counter.fork(),
+            explanation: self
+                .query_context
+                .explanations
+                .is_some()
+                .then(RefCell::default),
+        }
+    }
+
+    /// Record how the segment `segment_id` executed a search of `vector_name`
+    pub fn record_explanation(
+        &self,
+        segment_id: Uuid,
+        vector_name: &VectorName,
+        explanation: SearchExplanation,
+    ) {
+        if let Some(explanations) = &self.query_context.explanations {
+            explanations.0.lock().push(SegmentSearchExplanation {
+                segment_id,
+                vector: vector_name.to_owned(),
+                explanation,
+            });
         }
     }
 
@@ -188,9 +252,14 @@ pub struct VectorQueryContext<'a> {
 
     indexed_vectors: Option<usize>,
 
+    block_max: Option<bool>,
+
     deleted_points: Option<&'a BitSlice>,
 
     hardware_counter: HardwareCounterCell,
+
+    /// How the vector index executed the search, recorded only if explanation is requested
+    explanation: Option<RefCell<Option<SearchExplanation>>>,
 }
 
 impl VectorQueryContext<'_> {
@@ -198,6 +267,17 @@ impl VectorQueryContext<'_> {
         self.hardware_counter.fork()
     }
 
+    /// Record how the vector index executed the search, if explanation is requested
+    pub fn explain(&self, explanation: impl FnOnce() -> SearchExplanation) {
+        if let Some(cell) = &self.explanation {
+            cell.replace(Some(explanation()));
+        }
+    }
+
+    pub fn take_explanation(&self) -> Option<SearchExplanation> {
+        self.explanation.as_ref().and_then(RefCell::take)
+    }
+
     pub fn search_optimized_threshold_kb(&self) -> usize {
         self.search_optimized_threshold_kb
     }
@@ -241,6 +321,11 @@ impl VectorQueryContext<'_> {
     pub fn is_require_idf(&self) -> bool {
         self.idf.is_some() && self.indexed_vectors.is_some()
     }
+
+    /// Block-max search setting of the sparse vector, if it overrides the one of the index
+    pub fn block_max(&self) -> Option<bool> {
+        self.block_max
+    }
 }
 
 #[cfg(feature = "testing")]
@@ -251,8 +336,10 @@ impl Default for VectorQueryContext<'_> {
             is_stopped: None,
             idf: None,
             indexed_vectors: None,
+            block_max: None,
             deleted_points: None,
             hardware_counter: HardwareCounterCell::new(),
+            explanation: None,
         }
     }
 }
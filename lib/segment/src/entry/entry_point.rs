@@ -10,8 +10,9 @@ use uuid::Uuid;
 
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
+use crate::data_types::aggregation::{AggregateParams, AggregateResult};
 use crate::data_types::build_index_result::BuildFieldIndexResult;
-use crate::data_types::facets::{FacetParams, FacetValue};
+use crate::data_types::facets::{FacetParams, FacetValue, MultiFacetParams};
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::order_by::{OrderBy, OrderValue};
 use crate::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
@@ -142,6 +143,21 @@ pub trait NonAppendableSegmentEntry: SnapshotEntry {
         hw_counter: &HardwareCounterCell,
     ) -> Vec<PointIdType>;
 
+    /// Return random points which satisfies filtering condition, sampled with probability
+    /// proportional to the value of the numeric `key` field, along with total weight of the
+    /// sampled population.
+    ///
+    /// Will fail if there is no range index for the key.
+    /// Cancelled by `is_stopped` flag.
+    fn read_weighted_random_filtered(
+        &self,
+        limit: usize,
+        key: &JsonPath,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<(f64, Vec<PointIdType>)>;
+
     /// Read points in [from; to) range
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType>;
 
@@ -162,6 +178,22 @@ pub trait NonAppendableSegmentEntry: SnapshotEntry {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<HashMap<FacetValue, usize>>;
 
+    /// Return counts for each key of the facet request, in the same order as the keys.
+    fn facets(
+        &self,
+        request: &MultiFacetParams,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<HashMap<FacetValue, usize>>>;
+
+    /// Aggregate values of the given key over the filtered points.
+    fn aggregate(
+        &self,
+        request: &AggregateParams,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<AggregateResult>;
+
     /// Check if there is point with `point_id` in this segment.
     ///
     /// Soft deleted points are excluded.
@@ -264,11 +296,14 @@ pub trait NonAppendableSegmentEntry: SnapshotEntry {
     ) -> OperationResult<bool>;
 
     /// Build the field index for the key and schema, if not built before.
+    ///
+    /// Building is cancelled with an error, once `is_stopped` is set.
     fn build_field_index(
         &self,
         op_num: SeqNumberType,
         key: PayloadKeyTypeRef,
         field_type: &PayloadFieldSchema,
+        is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<BuildFieldIndexResult>;
 
@@ -299,8 +334,9 @@ pub trait NonAppendableSegmentEntry: SnapshotEntry {
 
         self.delete_field_index_if_incompatible(op_num, key, field_schema)?;
 
+        let is_stopped = AtomicBool::new(false);
         let (schema, indexes) =
-            match self.build_field_index(op_num, key, field_schema, hw_counter)? {
+            match self.build_field_index(op_num, key, field_schema, &is_stopped, hw_counter)? {
                 BuildFieldIndexResult::SkippedByVersion => {
                     return Ok(false);
                 }
@@ -9,7 +9,7 @@ use byteorder::LittleEndian;
 #[cfg(test)]
 use common::bitpacking::make_bitmask;
 use common::types::PointOffsetType;
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 #[cfg(test)]
 use rand::Rng as _;
 use rand::distr::Distribution;
@@ -20,6 +20,7 @@ use rand::seq::SliceRandom as _;
 #[cfg(test)]
 use uuid::Uuid;
 
+use crate::id_tracker::compressed::dense_mappings::DenseIdMappings;
 use crate::id_tracker::compressed::external_to_internal::CompressedExternalToInternal;
 use crate::id_tracker::compressed::internal_to_external::CompressedInternalToExternal;
 use crate::id_tracker::point_mappings::PointMappings;
@@ -33,10 +34,33 @@ pub struct CompressedPointMappings {
     /// `deleted` specifies which points of internal_to_external was deleted.
     /// Its size is exactly the same as `internal_to_external`.
     deleted: BitVec,
-    internal_to_external: CompressedInternalToExternal,
+    ids: CompressedIds,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum CompressedIds {
+    Sparse {
+        internal_to_external: CompressedInternalToExternal,
 
-    // Having two separate maps allows us iterating only over one type at a time without having to filter.
-    external_to_internal: CompressedExternalToInternal,
+        // Having two separate maps allows us iterating only over one type at a time without having to filter.
+        external_to_internal: CompressedExternalToInternal,
+    },
+    /// Used automatically if external ids are contiguous numbers.
+    ///
+    /// Deleted points are only tracked in `deleted`.
+    Dense {
+        mappings: DenseIdMappings,
+        deleted_count: usize,
+    },
+}
+
+impl Default for CompressedIds {
+    fn default() -> Self {
+        Self::Sparse {
+            internal_to_external: CompressedInternalToExternal::default(),
+            external_to_internal: CompressedExternalToInternal::default(),
+        }
+    }
 }
 
 impl CompressedPointMappings {
@@ -49,10 +73,29 @@ impl CompressedPointMappings {
         // Not all structures we may source this from enforce the same size
         deleted.resize(internal_to_external.len(), false);
 
+        if let Some(dense) = DenseIdMappings::from_external_ids(internal_to_external.iter()) {
+            return Self::from_dense(deleted, dense);
+        }
+
         Self {
             deleted,
-            internal_to_external,
-            external_to_internal,
+            ids: CompressedIds::Sparse {
+                internal_to_external,
+                external_to_internal,
+            },
+        }
+    }
+
+    pub fn from_dense(mut deleted: BitVec, mappings: DenseIdMappings) -> Self {
+        deleted.resize(mappings.len(), false);
+        let deleted_count = deleted.count_ones();
+
+        Self {
+            deleted,
+            ids: CompressedIds::Dense {
+                mappings,
+                deleted_count,
+            },
         }
     }
 
@@ -60,6 +103,12 @@ impl CompressedPointMappings {
         let (deleted, internal_to_external, external_to_internal_num, external_to_internal_uuid) =
             mapping.deconstruct();
 
+        if let Some(dense) =
+            DenseIdMappings::from_external_ids(internal_to_external.iter().copied())
+        {
+            return Self::from_dense(deleted, dense);
+        }
+
         let compressed_internal_to_external =
             CompressedInternalToExternal::from_slice(&internal_to_external);
 
@@ -69,14 +118,33 @@ impl CompressedPointMappings {
         );
         Self {
             deleted,
-            internal_to_external: compressed_internal_to_external,
-            external_to_internal: compressed_external_to_internal,
+            ids: CompressedIds::Sparse {
+                internal_to_external: compressed_internal_to_external,
+                external_to_internal: compressed_external_to_internal,
+            },
+        }
+    }
+
+    /// Dense mappings, if external ids are contiguous numbers.
+    pub(crate) fn dense(&self) -> Option<&DenseIdMappings> {
+        match &self.ids {
+            CompressedIds::Sparse { .. } => None,
+            CompressedIds::Dense { mappings, .. } => Some(mappings),
         }
     }
 
     /// Number of points, excluding deleted ones.
     pub(crate) fn available_point_count(&self) -> usize {
-        self.external_to_internal.len()
+        match &self.ids {
+            CompressedIds::Sparse {
+                external_to_internal,
+                ..
+            } => external_to_internal.len(),
+            CompressedIds::Dense {
+                mappings,
+                deleted_count,
+            } => mappings.len() - deleted_count,
+        }
     }
 
     pub(crate) fn deleted(&self) -> &BitSlice {
@@ -84,7 +152,26 @@ impl CompressedPointMappings {
     }
 
     pub(crate) fn internal_id(&self, external_id: &PointIdType) -> Option<PointOffsetType> {
-        self.external_to_internal.get(external_id)
+        match &self.ids {
+            CompressedIds::Sparse {
+                external_to_internal,
+                ..
+            } => external_to_internal.get(external_id),
+            CompressedIds::Dense { mappings, .. } => mappings
+                .internal_id(external_id)
+                .filter(|&internal_id| !self.is_deleted_point(internal_id)),
+        }
+    }
+
+    /// External id of `internal_id`, regardless of whether the point is deleted.
+    fn external_id_raw(&self, internal_id: PointOffsetType) -> Option<PointIdType> {
+        match &self.ids {
+            CompressedIds::Sparse {
+                internal_to_external,
+                ..
+            } => internal_to_external.get(internal_id),
+            CompressedIds::Dense { mappings, .. } => mappings.external_id(internal_id),
+        }
     }
 
     pub(crate) fn external_id(&self, internal_id: PointOffsetType) -> Option<PointIdType> {
@@ -92,11 +179,27 @@ impl CompressedPointMappings {
             return None;
         }
 
-        self.internal_to_external.get(internal_id)
+        self.external_id_raw(internal_id)
     }
 
     pub(crate) fn drop(&mut self, external_id: PointIdType) -> Option<PointOffsetType> {
-        let internal_id = self.external_to_internal.remove(&external_id);
+        let internal_id = match &mut self.ids {
+            CompressedIds::Sparse {
+                external_to_internal,
+                ..
+            } => external_to_internal.remove(&external_id),
+            CompressedIds::Dense {
+                mappings,
+                deleted_count,
+            } => {
+                let internal_id = mappings.internal_id(&external_id)?;
+                if self.deleted[internal_id as usize] {
+                    return None;
+                }
+                *deleted_count += 1;
+                Some(internal_id)
+            }
+        };
 
         if let Some(internal_id) = &internal_id {
             self.deleted.set(*internal_id as usize, true);
@@ -109,7 +212,7 @@ impl CompressedPointMappings {
         &self,
     ) -> Box<dyn Iterator<Item = (PointIdType, PointOffsetType)> + '_> {
         let rng = rand::rng();
-        let max_internal = self.internal_to_external.len();
+        let max_internal = self.total_point_count();
         if max_internal == 0 {
             return Box::new(iter::empty());
         }
@@ -127,10 +230,7 @@ impl CompressedPointMappings {
                     None
                 } else {
                     let point_offset = i as PointOffsetType;
-                    Some((
-                        self.internal_to_external.get(point_offset).unwrap(),
-                        point_offset,
-                    ))
+                    Some((self.external_id_raw(point_offset).unwrap(), point_offset))
                 }
             });
 
@@ -141,23 +241,29 @@ impl CompressedPointMappings {
         &self,
         external_id: Option<PointIdType>,
     ) -> Box<dyn Iterator<Item = (PointIdType, PointOffsetType)> + '_> {
-        match external_id {
-            None => Box::new(self.external_to_internal.iter()),
-            Some(point_id) => Box::new(self.external_to_internal.iter_from(point_id)),
+        match &self.ids {
+            CompressedIds::Sparse {
+                external_to_internal,
+                ..
+            } => match external_id {
+                None => Box::new(external_to_internal.iter()),
+                Some(point_id) => Box::new(external_to_internal.iter_from(point_id)),
+            },
+            CompressedIds::Dense { mappings, .. } => Box::new(
+                mappings
+                    .iter_from(external_id)
+                    .filter(|(_, internal_id)| !self.deleted[*internal_id as usize]),
+            ),
         }
     }
 
     pub(crate) fn iter_external(&self) -> Box<dyn Iterator<Item = PointIdType> + '_> {
-        Box::new(
-            self.external_to_internal
-                .iter()
-                .map(|(point_id, _)| point_id),
-        )
+        Box::new(self.iter_from(None).map(|(point_id, _)| point_id))
     }
 
     pub(crate) fn iter_internal(&self) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
         Box::new(
-            (0..self.internal_to_external.len() as PointOffsetType)
+            (0..self.total_point_count() as PointOffsetType)
                 .filter(move |i| !self.deleted[*i as usize]),
         )
     }
@@ -165,10 +271,18 @@ impl CompressedPointMappings {
     pub(crate) fn iter_internal_raw(
         &self,
     ) -> impl Iterator<Item = (PointOffsetType, PointIdType)> + '_ {
-        self.internal_to_external
-            .iter()
-            .enumerate()
-            .map(|(offset, point_id)| (offset as _, point_id))
+        match &self.ids {
+            CompressedIds::Sparse {
+                internal_to_external,
+                ..
+            } => Either::Left(
+                internal_to_external
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, point_id)| (offset as _, point_id)),
+            ),
+            CompressedIds::Dense { mappings, .. } => Either::Right(mappings.iter_internal_raw()),
+        }
     }
 
     pub(crate) fn is_deleted_point(&self, key: PointOffsetType) -> bool {
@@ -180,7 +294,13 @@ impl CompressedPointMappings {
     }
 
     pub(crate) fn total_point_count(&self) -> usize {
-        self.internal_to_external.len()
+        match &self.ids {
+            CompressedIds::Sparse {
+                internal_to_external,
+                ..
+            } => internal_to_external.len(),
+            CompressedIds::Dense { mappings, .. } => mappings.len(),
+        }
     }
 
     /// Generate a random [`PointMappings`].
@@ -250,10 +370,10 @@ impl CompressedPointMappings {
             external_to_internal_uuid,
         );
 
-        Self {
+        Self::new(
             deleted,
-            internal_to_external: compressed_internal_to_external,
+            compressed_internal_to_external,
             external_to_internal,
-        }
+        )
     }
 }
@@ -0,0 +1,198 @@
+use common::types::PointOffsetType;
+
+use crate::types::PointIdType;
+
+/// Sentinel for offsets without a point, only used during construction.
+const NO_POINT: PointOffsetType = PointOffsetType::MAX;
+
+/// A dense representation of mappings between internal ids and contiguous numeric external ids.
+///
+/// If external ids of all points are numbers `base..base + len` (which is common for sequentially
+/// assigned ids), each of them is stored as a 4 byte offset from `base` instead of a 16 byte entry
+/// in [`CompressedInternalToExternal`] and [`CompressedExternalToInternal`] each.
+///
+/// [`CompressedInternalToExternal`]: super::internal_to_external::CompressedInternalToExternal
+/// [`CompressedExternalToInternal`]: super::external_to_internal::CompressedExternalToInternal
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct DenseIdMappings {
+    /// Smallest external id.
+    base: u64,
+    /// Offset of the external id from `base`, for each internal id.
+    internal_to_offset: Vec<u32>,
+    /// Internal id, for each offset from `base`.
+    offset_to_internal: Vec<PointOffsetType>,
+}
+
+impl DenseIdMappings {
+    /// Build dense mappings from external ids of all internal ids, including deleted ones.
+    ///
+    /// Returns `None` if external ids are not unique contiguous numbers.
+    pub fn from_external_ids(
+        internal_to_external: impl Iterator<Item = PointIdType> + Clone,
+    ) -> Option<Self> {
+        let mut len = 0usize;
+        let mut min = u64::MAX;
+        let mut max = u64::MIN;
+        for external_id in internal_to_external.clone() {
+            let PointIdType::NumId(num) = external_id else {
+                return None;
+            };
+            min = min.min(num);
+            max = max.max(num);
+            len += 1;
+        }
+
+        if len == 0 || max - min != len as u64 - 1 {
+            return None;
+        }
+
+        let offsets = internal_to_external.map(|external_id| match external_id {
+            PointIdType::NumId(num) => Some((num - min) as u32),
+            PointIdType::Uuid(_) => None,
+        });
+
+        Self::from_offsets(min, offsets.collect::<Option<_>>()?)
+    }
+
+    /// Build dense mappings from offsets of external ids from `base`, for each internal id.
+    ///
+    /// Returns `None` if offsets are not unique or out of range.
+    pub fn from_offsets(base: u64, internal_to_offset: Vec<u32>) -> Option<Self> {
+        let len = internal_to_offset.len();
+        base.checked_add(len.saturating_sub(1) as u64)?;
+
+        let mut offset_to_internal = vec![NO_POINT; len];
+        for (internal_id, &offset) in internal_to_offset.iter().enumerate() {
+            let slot = offset_to_internal.get_mut(offset as usize)?;
+            if *slot != NO_POINT {
+                return None;
+            }
+            *slot = internal_id as PointOffsetType;
+        }
+
+        Some(Self {
+            base,
+            internal_to_offset,
+            offset_to_internal,
+        })
+    }
+
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    pub fn len(&self) -> usize {
+        self.internal_to_offset.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.internal_to_offset.is_empty()
+    }
+
+    pub fn offsets(&self) -> &[u32] {
+        &self.internal_to_offset
+    }
+
+    pub fn internal_id(&self, external_id: &PointIdType) -> Option<PointOffsetType> {
+        let PointIdType::NumId(num) = external_id else {
+            return None;
+        };
+        let offset = num.checked_sub(self.base)?;
+        self.offset_to_internal
+            .get(usize::try_from(offset).ok()?)
+            .copied()
+    }
+
+    pub fn external_id(&self, internal_id: PointOffsetType) -> Option<PointIdType> {
+        let offset = self.internal_to_offset.get(internal_id as usize)?;
+        Some(PointIdType::NumId(self.base + u64::from(*offset)))
+    }
+
+    /// Iterate over `(external id, internal id)` pairs in order of external ids, starting
+    /// from `external_id`.
+    pub fn iter_from(
+        &self,
+        external_id: Option<PointIdType>,
+    ) -> impl Iterator<Item = (PointIdType, PointOffsetType)> + '_ {
+        let start = match external_id {
+            None => 0,
+            Some(PointIdType::NumId(num)) => {
+                num.saturating_sub(self.base).min(self.len() as u64) as usize
+            }
+            // Any UUID is bigger than any num id
+            Some(PointIdType::Uuid(_)) => self.len(),
+        };
+
+        self.offset_to_internal[start..]
+            .iter()
+            .zip(start as u64..)
+            .map(|(&internal_id, offset)| (PointIdType::NumId(self.base + offset), internal_id))
+    }
+
+    /// Iterate over `(internal id, external id)` pairs in order of internal ids.
+    pub fn iter_internal_raw(&self) -> impl Iterator<Item = (PointOffsetType, PointIdType)> + '_ {
+        self.internal_to_offset
+            .iter()
+            .enumerate()
+            .map(|(internal_id, &offset)| {
+                (
+                    internal_id as PointOffsetType,
+                    PointIdType::NumId(self.base + u64::from(offset)),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn dense_from_contiguous_ids() {
+        let ids = [12, 10, 13, 11].map(PointIdType::NumId);
+        let mappings = DenseIdMappings::from_external_ids(ids.iter().copied()).unwrap();
+
+        assert_eq!(mappings.base(), 10);
+        assert_eq!(mappings.len(), 4);
+        for (internal_id, external_id) in ids.iter().enumerate() {
+            assert_eq!(
+                mappings.internal_id(external_id),
+                Some(internal_id as PointOffsetType),
+            );
+            assert_eq!(
+                mappings.external_id(internal_id as PointOffsetType),
+                Some(*external_id),
+            );
+        }
+        assert_eq!(mappings.internal_id(&PointIdType::NumId(9)), None);
+        assert_eq!(mappings.internal_id(&PointIdType::NumId(14)), None);
+
+        let from_11 = mappings
+            .iter_from(Some(PointIdType::NumId(11)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            from_11,
+            vec![
+                (PointIdType::NumId(11), 3),
+                (PointIdType::NumId(12), 0),
+                (PointIdType::NumId(13), 2),
+            ],
+        );
+    }
+
+    #[test]
+    fn dense_rejects_sparse_ids() {
+        let gap = [1, 2, 4].map(PointIdType::NumId);
+        assert!(DenseIdMappings::from_external_ids(gap.iter().copied()).is_none());
+
+        let duplicate = [1, 1, 3].map(PointIdType::NumId);
+        assert!(DenseIdMappings::from_external_ids(duplicate.iter().copied()).is_none());
+
+        let uuid = [PointIdType::NumId(0), PointIdType::Uuid(Uuid::new_v4())];
+        assert!(DenseIdMappings::from_external_ids(uuid.iter().copied()).is_none());
+
+        assert!(DenseIdMappings::from_external_ids(std::iter::empty()).is_none());
+    }
+}
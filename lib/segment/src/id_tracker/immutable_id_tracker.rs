@@ -18,6 +18,7 @@ use crate::common::mmap_bitslice_buffered_update_wrapper::MmapBitSliceBufferedUp
 use crate::common::mmap_slice_buffered_update_wrapper::MmapSliceBufferedUpdateWrapper;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::id_tracker::compressed::compressed_point_mappings::CompressedPointMappings;
+use crate::id_tracker::compressed::dense_mappings::DenseIdMappings;
 use crate::id_tracker::compressed::external_to_internal::CompressedExternalToInternal;
 use crate::id_tracker::compressed::internal_to_external::CompressedInternalToExternal;
 use crate::id_tracker::compressed::versions_store::CompressedVersions;
@@ -28,6 +29,7 @@ use crate::types::{ExtendedPointId, PointIdType, SeqNumberType};
 
 pub const DELETED_FILE_NAME: &str = "id_tracker.deleted";
 pub const MAPPINGS_FILE_NAME: &str = "id_tracker.mappings";
+pub const DENSE_MAPPINGS_FILE_NAME: &str = "id_tracker.dense_mappings";
 pub const VERSION_MAPPING_FILE_NAME: &str = "id_tracker.versions";
 
 #[derive(Copy, Clone)]
@@ -245,6 +247,67 @@ impl ImmutableIdTracker {
         Ok(())
     }
 
+    /// Loads dense `CompressedPointMappings` from the given reader, see [`Self::store_dense_mapping`].
+    fn load_dense_mapping<R: BufRead>(
+        mut reader: R,
+        deleted: Option<BitVec>,
+    ) -> OperationResult<CompressedPointMappings> {
+        let read_err = |err: std::io::Error| {
+            OperationError::inconsistent_storage(format!(
+                "Immutable ID tracker failed to read dense mappings, assuming malformed storage: {err}"
+            ))
+        };
+
+        // Deserialize the header
+        let base = reader.read_u64::<FileEndianess>().map_err(read_err)?;
+        let len = reader.read_u64::<FileEndianess>().map_err(read_err)? as usize;
+
+        let mut offsets = vec![0; len];
+        reader
+            .read_u32_into::<FileEndianess>(&mut offsets)
+            .map_err(read_err)?;
+
+        // Check that the file has been fully read.
+        #[cfg(debug_assertions)] // Only for dev builds
+        {
+            debug_assert_eq!(reader.bytes().map(Result::unwrap).count(), 0,);
+        }
+
+        let dense = DenseIdMappings::from_offsets(base, offsets).ok_or_else(|| {
+            OperationError::inconsistent_storage(
+                "Immutable ID tracker dense mappings contain duplicate or out of range IDs",
+            )
+        })?;
+
+        let mut deleted = deleted.unwrap_or_else(|| BitVec::repeat(false, len));
+        deleted.truncate(len);
+
+        Ok(CompressedPointMappings::from_dense(deleted, dense))
+    }
+
+    /// Serializes dense mappings into the given writer using the file format specified below.
+    ///
+    /// ## File format
+    /// +------------------+---------------------------+-----------------+
+    /// | Base ID: u64     | Header (list length: u64) | List of offsets |
+    /// +------------------+---------------------------+-----------------+
+    ///
+    /// The external ID of each internal ID is its offset (u32) added to the base ID.
+    /// A single entry is thus 4 bytes in size.
+    fn store_dense_mapping<W: Write>(
+        mappings: &DenseIdMappings,
+        mut writer: W,
+    ) -> OperationResult<()> {
+        writer.write_u64::<FileEndianess>(mappings.base())?;
+        writer.write_u64::<FileEndianess>(mappings.len() as u64)?;
+
+        for offset in mappings.offsets() {
+            writer.write_u32::<FileEndianess>(*offset)?;
+        }
+
+        Ok(())
+    }
+
     pub fn open(segment_path: &Path) -> OperationResult<Self> {
         let deleted_raw = open_write_mmap(
             &Self::deleted_file_path(segment_path),
@@ -266,8 +329,14 @@ impl ImmutableIdTracker {
         let internal_to_version_wrapper =
             MmapSliceBufferedUpdateWrapper::new(internal_to_version_mapslice);
 
-        let reader = BufReader::new(File::open(Self::mappings_file_path(segment_path))?);
-        let mappings = Self::load_mapping(reader, Some(deleted_bitvec))?;
+        let dense_mappings_path = Self::dense_mappings_file_path(segment_path);
+        let mappings = if dense_mappings_path.is_file() {
+            let reader = BufReader::new(File::open(dense_mappings_path)?);
+            Self::load_dense_mapping(reader, Some(deleted_bitvec))?
+        } else {
+            let reader = BufReader::new(File::open(Self::mappings_file_path(segment_path))?);
+            Self::load_mapping(reader, Some(deleted_bitvec))?
+        };
 
         Ok(Self {
             path: segment_path.to_path_buf(),
@@ -334,10 +403,13 @@ impl ImmutableIdTracker {
         let internal_to_version_wrapper =
             MmapSliceBufferedUpdateWrapper::new(internal_to_version_wrapper);
 
-        // Write mappings to disk.
-        let file = File::create(Self::mappings_file_path(path))?;
+        // Write mappings to disk, in dense format if possible.
+        let file = File::create(Self::current_mappings_file_path(path, &mappings))?;
         let mut writer = BufWriter::new(file);
-        Self::store_mapping(&mappings, &mut writer)?;
+        match mappings.dense() {
+            Some(dense) => Self::store_dense_mapping(dense, &mut writer)?,
+            None => Self::store_mapping(&mappings, &mut writer)?,
+        }
 
         // Explicitly fsync file contents to ensure durability
         writer.flush()?;
@@ -367,6 +439,23 @@ impl ImmutableIdTracker {
     pub(crate) fn mappings_file_path(base: &Path) -> PathBuf {
         base.join(MAPPINGS_FILE_NAME)
     }
+
+    fn dense_mappings_file_path(base: &Path) -> PathBuf {
+        base.join(DENSE_MAPPINGS_FILE_NAME)
+    }
+
+    fn current_mappings_file_path(base: &Path, mappings: &CompressedPointMappings) -> PathBuf {
+        if mappings.dense().is_some() {
+            Self::dense_mappings_file_path(base)
+        } else {
+            Self::mappings_file_path(base)
+        }
+    }
+
+    /// Whether an immutable ID tracker is stored in the given segment directory.
+    pub(crate) fn exists(base: &Path) -> bool {
+        Self::mappings_file_path(base).is_file() || Self::dense_mappings_file_path(base).is_file()
+    }
 }
 
 /// Returns the required mmap filesize for a given length of a slice of type `T`.
@@ -504,13 +593,13 @@ impl IdTracker for ImmutableIdTracker {
     fn files(&self) -> Vec<PathBuf> {
         vec![
             Self::deleted_file_path(&self.path),
-            Self::mappings_file_path(&self.path),
+            Self::current_mappings_file_path(&self.path, &self.mappings),
             Self::version_mapping_file_path(&self.path),
         ]
     }
 
     fn immutable_files(&self) -> Vec<PathBuf> {
-        vec![Self::mappings_file_path(&self.path)]
+        vec![Self::current_mappings_file_path(&self.path, &self.mappings)]
     }
 }
 
@@ -688,6 +777,81 @@ pub(super) mod test {
         }
     }
 
+    /// Contiguous numeric IDs are stored densely, and load back with the same mappings.
+    #[test]
+    fn test_dense_store_load() {
+        const SIZE: u64 = 1000;
+        const BASE: u64 = 5000;
+
+        let mut rng = StdRng::seed_from_u64(RAND_SEED);
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+
+        let mut external_ids = (BASE..BASE + SIZE).collect_vec();
+        external_ids.shuffle(&mut rng);
+
+        let dropped_points = {
+            let mut in_memory_id_tracker = InMemoryIdTracker::new();
+            for (internal_id, external_id) in external_ids.iter().enumerate() {
+                in_memory_id_tracker
+                    .set_link((*external_id).into(), internal_id as PointOffsetType)
+                    .unwrap();
+                in_memory_id_tracker
+                    .set_internal_version(internal_id as PointOffsetType, DEFAULT_VERSION)
+                    .unwrap();
+            }
+
+            let mut id_tracker =
+                ImmutableIdTracker::from_in_memory_tracker(in_memory_id_tracker, dir.path())
+                    .unwrap();
+            assert!(id_tracker.mappings.dense().is_some());
+            assert!(ImmutableIdTracker::dense_mappings_file_path(dir.path()).is_file());
+            assert!(!ImmutableIdTracker::mappings_file_path(dir.path()).exists());
+
+            let dropped_points = external_ids
+                .iter()
+                .step_by(7)
+                .map(|id| PointIdType::NumId(*id))
+                .collect_vec();
+            for point in &dropped_points {
+                id_tracker.drop(*point).unwrap();
+            }
+            id_tracker.mapping_flusher()().unwrap();
+            id_tracker.versions_flusher()().unwrap();
+
+            dropped_points
+        };
+
+        let id_tracker = ImmutableIdTracker::open(dir.path()).unwrap();
+        assert!(id_tracker.mappings.dense().is_some());
+        assert_eq!(
+            id_tracker.available_point_count(),
+            SIZE as usize - dropped_points.len(),
+        );
+
+        for (internal_id, external_id) in external_ids.iter().enumerate() {
+            let internal_id = internal_id as PointOffsetType;
+            let external_id = PointIdType::NumId(*external_id);
+            if dropped_points.contains(&external_id) {
+                assert!(id_tracker.is_deleted_point(internal_id));
+                assert_eq!(id_tracker.internal_id(external_id), None);
+                assert_eq!(id_tracker.external_id(internal_id), None);
+            } else {
+                assert_eq!(id_tracker.internal_id(external_id), Some(internal_id));
+                assert_eq!(id_tracker.external_id(internal_id), Some(external_id));
+            }
+        }
+
+        let iterated = id_tracker
+            .iter_from(Some(PointIdType::NumId(BASE + SIZE / 2)))
+            .map(|(external_id, _)| external_id)
+            .collect_vec();
+        let expected = (BASE + SIZE / 2..BASE + SIZE)
+            .map(PointIdType::NumId)
+            .filter(|id| !dropped_points.contains(id))
+            .collect_vec();
+        assert_eq!(iterated, expected);
+    }
+
     #[test]
     fn test_all_points_have_version() {
         let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
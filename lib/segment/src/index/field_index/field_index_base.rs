@@ -16,6 +16,7 @@ use super::map_index::MapIndexBuilder;
 use super::map_index::{MapIndex, MapIndexGridstoreBuilder, MapIndexMmapBuilder};
 #[cfg(feature = "rocksdb")]
 use super::numeric_index::NumericIndexBuilder;
+use super::numeric_index::alias_table::{AliasTable, point_weight};
 use super::numeric_index::{
     NumericIndex, NumericIndexGridstoreBuilder, NumericIndexMmapBuilder, StreamRange,
 };
@@ -32,8 +33,9 @@ use crate::index::payload_config::{
 };
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, Match, MatchPhrase,
-    MatchText, PayloadKeyType, RangeInterface, UuidIntType, UuidPayloadType,
+    DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, IpIntType,
+    IpPayloadType, Match, MatchPhrase, MatchText, PayloadKeyType, RangeInterface, UuidIntType,
+    UuidPayloadType,
 };
 
 pub trait PayloadFieldIndex {
@@ -139,6 +141,7 @@ pub enum FieldIndex {
     BoolIndex(BoolIndex),
     UuidIndex(NumericIndex<UuidIntType, UuidPayloadType>),
     UuidMapIndex(MapIndex<UuidIntType>),
+    IpIndex(NumericIndex<IpIntType, IpPayloadType>),
     NullIndex(MutableNullIndex),
 }
 
@@ -147,6 +150,7 @@ impl std::fmt::Debug for FieldIndex {
         match self {
             FieldIndex::IntIndex(_index) => write!(f, "IntIndex"),
             FieldIndex::DatetimeIndex(_index) => write!(f, "DatetimeIndex"),
+            FieldIndex::IpIndex(_index) => write!(f, "IpIndex"),
             FieldIndex::IntMapIndex(_index) => write!(f, "IntMapIndex"),
             FieldIndex::KeywordIndex(_index) => write!(f, "KeywordIndex"),
             FieldIndex::FloatIndex(_index) => write!(f, "FloatIndex"),
@@ -177,6 +181,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(_) => None,
             FieldIndex::DatetimeIndex(_) => None,
+            FieldIndex::IpIndex(_) => None,
             FieldIndex::IntMapIndex(_) => None,
             FieldIndex::KeywordIndex(_) => None,
             FieldIndex::FloatIndex(_) => None,
@@ -201,6 +206,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(payload_field_index) => payload_field_index.inner(),
             FieldIndex::DatetimeIndex(payload_field_index) => payload_field_index.inner(),
+            FieldIndex::IpIndex(payload_field_index) => payload_field_index.inner(),
             FieldIndex::IntMapIndex(payload_field_index) => payload_field_index,
             FieldIndex::KeywordIndex(payload_field_index) => payload_field_index,
             FieldIndex::FloatIndex(payload_field_index) => payload_field_index.inner(),
@@ -217,6 +223,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.wipe(),
             FieldIndex::DatetimeIndex(index) => index.wipe(),
+            FieldIndex::IpIndex(index) => index.wipe(),
             FieldIndex::IntMapIndex(index) => index.wipe(),
             FieldIndex::KeywordIndex(index) => index.wipe(),
             FieldIndex::FloatIndex(index) => index.wipe(),
@@ -250,7 +257,12 @@ impl FieldIndex {
         condition: &'a FieldCondition,
         hw_counter: &'a HardwareCounterCell,
     ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
-        self.get_payload_field_index().filter(condition, hw_counter)
+        match self {
+            // IP index only serves IP address conditions, which generic numeric filtering
+            // doesn't know about
+            FieldIndex::IpIndex(index) => index.filter_ip(condition, hw_counter),
+            _ => self.get_payload_field_index().filter(condition, hw_counter),
+        }
     }
 
     pub fn estimate_cardinality(
@@ -258,8 +270,12 @@ impl FieldIndex {
         condition: &FieldCondition,
         hw_counter: &HardwareCounterCell,
     ) -> Option<CardinalityEstimation> {
-        self.get_payload_field_index()
-            .estimate_cardinality(condition, hw_counter)
+        match self {
+            FieldIndex::IpIndex(index) => index.estimate_ip_cardinality(condition),
+            _ => self
+                .get_payload_field_index()
+                .estimate_cardinality(condition, hw_counter),
+        }
     }
 
     pub fn payload_blocks(
@@ -267,8 +283,13 @@ impl FieldIndex {
         threshold: usize,
         key: PayloadKeyType,
     ) -> Box<dyn Iterator<Item = PayloadBlockCondition> + '_> {
-        self.get_payload_field_index()
-            .payload_blocks(threshold, key)
+        match self {
+            // Numeric payload blocks are float ranges, which can't represent IP addresses
+            FieldIndex::IpIndex(_) => Box::new(std::iter::empty()),
+            _ => self
+                .get_payload_field_index()
+                .payload_blocks(threshold, key),
+        }
     }
 
     pub fn add_point(
@@ -284,6 +305,9 @@ impl FieldIndex {
             FieldIndex::DatetimeIndex(payload_field_index) => {
                 payload_field_index.add_point(id, payload, hw_counter)
             }
+            FieldIndex::IpIndex(payload_field_index) => {
+                payload_field_index.add_point(id, payload, hw_counter)
+            }
             FieldIndex::IntMapIndex(payload_field_index) => {
                 payload_field_index.add_point(id, payload, hw_counter)
             }
@@ -318,6 +342,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.mut_inner().remove_point(point_id),
             FieldIndex::DatetimeIndex(index) => index.mut_inner().remove_point(point_id),
+            FieldIndex::IpIndex(index) => index.mut_inner().remove_point(point_id),
             FieldIndex::IntMapIndex(index) => index.remove_point(point_id),
             FieldIndex::KeywordIndex(index) => index.remove_point(point_id),
             FieldIndex::FloatIndex(index) => index.mut_inner().remove_point(point_id),
@@ -334,6 +359,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.get_telemetry_data(),
             FieldIndex::DatetimeIndex(index) => index.get_telemetry_data(),
+            FieldIndex::IpIndex(index) => index.get_telemetry_data(),
             FieldIndex::IntMapIndex(index) => index.get_telemetry_data(),
             FieldIndex::KeywordIndex(index) => index.get_telemetry_data(),
             FieldIndex::FloatIndex(index) => index.get_telemetry_data(),
@@ -350,6 +376,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.values_count(point_id),
             FieldIndex::DatetimeIndex(index) => index.values_count(point_id),
+            FieldIndex::IpIndex(index) => index.values_count(point_id),
             FieldIndex::IntMapIndex(index) => index.values_count(point_id),
             FieldIndex::KeywordIndex(index) => index.values_count(point_id),
             FieldIndex::FloatIndex(index) => index.values_count(point_id),
@@ -366,6 +393,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.values_is_empty(point_id),
             FieldIndex::DatetimeIndex(index) => index.values_is_empty(point_id),
+            FieldIndex::IpIndex(index) => index.values_is_empty(point_id),
             FieldIndex::IntMapIndex(index) => index.values_is_empty(point_id),
             FieldIndex::KeywordIndex(index) => index.values_is_empty(point_id),
             FieldIndex::FloatIndex(index) => index.values_is_empty(point_id),
@@ -389,6 +417,7 @@ impl FieldIndex {
             | FieldIndex::BoolIndex(_)
             | FieldIndex::UuidMapIndex(_)
             | FieldIndex::UuidIndex(_)
+            | FieldIndex::IpIndex(_)
             | FieldIndex::FullTextIndex(_)
             | FieldIndex::NullIndex(_) => None,
         }
@@ -403,6 +432,7 @@ impl FieldIndex {
             FieldIndex::UuidIndex(_)
             | FieldIndex::IntIndex(_)
             | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::IpIndex(_)
             | FieldIndex::FloatIndex(_)
             | FieldIndex::GeoIndex(_)
             | FieldIndex::FullTextIndex(_)
@@ -414,6 +444,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.is_on_disk(),
             FieldIndex::DatetimeIndex(index) => index.is_on_disk(),
+            FieldIndex::IpIndex(index) => index.is_on_disk(),
             FieldIndex::IntMapIndex(index) => index.is_on_disk(),
             FieldIndex::KeywordIndex(index) => index.is_on_disk(),
             FieldIndex::FloatIndex(index) => index.is_on_disk(),
@@ -431,6 +462,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.is_rocksdb(),
             FieldIndex::DatetimeIndex(index) => index.is_rocksdb(),
+            FieldIndex::IpIndex(index) => index.is_rocksdb(),
             FieldIndex::IntMapIndex(index) => index.is_rocksdb(),
             FieldIndex::KeywordIndex(index) => index.is_rocksdb(),
             FieldIndex::FloatIndex(index) => index.is_rocksdb(),
@@ -449,6 +481,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.populate(),
             FieldIndex::DatetimeIndex(index) => index.populate(),
+            FieldIndex::IpIndex(index) => index.populate(),
             FieldIndex::IntMapIndex(index) => index.populate(),
             FieldIndex::KeywordIndex(index) => index.populate(),
             FieldIndex::FloatIndex(index) => index.populate(),
@@ -466,6 +499,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.clear_cache(),
             FieldIndex::DatetimeIndex(index) => index.clear_cache(),
+            FieldIndex::IpIndex(index) => index.clear_cache(),
             FieldIndex::IntMapIndex(index) => index.clear_cache(),
             FieldIndex::KeywordIndex(index) => index.clear_cache(),
             FieldIndex::FloatIndex(index) => index.clear_cache(),
@@ -482,6 +516,7 @@ impl FieldIndex {
         let index_type = match self {
             FieldIndex::IntIndex(_) => PayloadIndexType::IntIndex,
             FieldIndex::DatetimeIndex(_) => PayloadIndexType::DatetimeIndex,
+            FieldIndex::IpIndex(_) => PayloadIndexType::IpIndex,
             FieldIndex::IntMapIndex(_) => PayloadIndexType::IntMapIndex,
             FieldIndex::KeywordIndex(_) => PayloadIndexType::KeywordIndex,
             FieldIndex::FloatIndex(_) => PayloadIndexType::FloatIndex,
@@ -504,6 +539,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.get_mutability_type(),
             FieldIndex::DatetimeIndex(index) => index.get_mutability_type(),
+            FieldIndex::IpIndex(index) => index.get_mutability_type(),
             FieldIndex::IntMapIndex(index) => index.get_mutability_type(),
             FieldIndex::KeywordIndex(index) => index.get_mutability_type(),
             FieldIndex::FloatIndex(index) => index.get_mutability_type(),
@@ -520,6 +556,7 @@ impl FieldIndex {
         match self {
             FieldIndex::IntIndex(index) => index.get_storage_type(),
             FieldIndex::DatetimeIndex(index) => index.get_storage_type(),
+            FieldIndex::IpIndex(index) => index.get_storage_type(),
             FieldIndex::IntMapIndex(index) => index.get_storage_type(),
             FieldIndex::KeywordIndex(index) => index.get_storage_type(),
             FieldIndex::FloatIndex(index) => index.get_storage_type(),
@@ -599,6 +636,10 @@ pub enum FieldIndexBuilder {
     UuidIndex(MapIndexBuilder<UuidIntType>),
     UuidMmapIndex(MapIndexMmapBuilder<UuidIntType>),
     UuidGridstoreIndex(MapIndexGridstoreBuilder<UuidIntType>),
+    #[cfg(feature = "rocksdb")]
+    IpIndex(NumericIndexBuilder<IpIntType, IpPayloadType>),
+    IpMmapIndex(NumericIndexMmapBuilder<IpIntType, IpPayloadType>),
+    IpGridstoreIndex(NumericIndexGridstoreBuilder<IpIntType, IpPayloadType>),
     NullIndex(MutableNullIndexBuilder),
 }
 
@@ -642,6 +683,10 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::UuidIndex(index) => index.init(),
             Self::UuidMmapIndex(index) => index.init(),
             Self::UuidGridstoreIndex(index) => index.init(),
+            #[cfg(feature = "rocksdb")]
+            Self::IpIndex(index) => index.init(),
+            Self::IpMmapIndex(index) => index.init(),
+            Self::IpGridstoreIndex(index) => index.init(),
             Self::NullIndex(index) => index.init(),
         }
     }
@@ -692,6 +737,10 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::UuidIndex(index) => index.add_point(id, payload, hw_counter),
             Self::UuidMmapIndex(index) => index.add_point(id, payload, hw_counter),
             Self::UuidGridstoreIndex(index) => index.add_point(id, payload, hw_counter),
+            #[cfg(feature = "rocksdb")]
+            Self::IpIndex(index) => index.add_point(id, payload, hw_counter),
+            Self::IpMmapIndex(index) => index.add_point(id, payload, hw_counter),
+            Self::IpGridstoreIndex(index) => index.add_point(id, payload, hw_counter),
             Self::NullIndex(index) => index.add_point(id, payload, hw_counter),
         }
     }
@@ -733,6 +782,10 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::UuidIndex(index) => FieldIndex::UuidMapIndex(index.finalize()?),
             Self::UuidMmapIndex(index) => FieldIndex::UuidMapIndex(index.finalize()?),
             Self::UuidGridstoreIndex(index) => FieldIndex::UuidMapIndex(index.finalize()?),
+            #[cfg(feature = "rocksdb")]
+            Self::IpIndex(index) => FieldIndex::IpIndex(index.finalize()?),
+            Self::IpMmapIndex(index) => FieldIndex::IpIndex(index.finalize()?),
+            Self::IpGridstoreIndex(index) => FieldIndex::IpIndex(index.finalize()?),
             Self::NullIndex(index) => FieldIndex::NullIndex(index.finalize()?),
         })
     }
@@ -764,6 +817,26 @@ impl<'a> StreamRange<OrderValue> for NumericFieldIndex<'a> {
 }
 
 impl<'a> NumericFieldIndex<'a> {
+    /// Alias table for weighted sampling, if the index keeps one.
+    pub fn alias_table(&self) -> Option<&'a AliasTable> {
+        match self {
+            NumericFieldIndex::IntIndex(index) => index.alias_table(),
+            NumericFieldIndex::FloatIndex(index) => index.alias_table(),
+        }
+    }
+
+    /// Weight of the point for weighted sampling, see [`point_weight`].
+    pub fn get_weight(&self, idx: PointOffsetType) -> f64 {
+        match self {
+            NumericFieldIndex::IntIndex(index) => {
+                point_weight(index.get_values(idx).into_iter().flatten())
+            }
+            NumericFieldIndex::FloatIndex(index) => {
+                point_weight(index.get_values(idx).into_iter().flatten())
+            }
+        }
+    }
+
     pub fn get_ordering_values(
         &self,
         idx: PointOffsetType,
@@ -183,6 +183,17 @@ impl GeoHash {
         self_shifted == other_shifted
     }
 
+    /// Sub-regions of this geo-hash, which are one character longer
+    pub fn sub_regions(self) -> impl Iterator<Item = GeoHash> {
+        let len = self.len();
+        assert!(len < GEOHASH_MAX_LENGTH);
+        // Clear length bits
+        let prefix = (self.packed >> 4) << 4;
+        (0..BASE32_CODES.len() as u64).map(move |code| Self {
+            packed: prefix | (code << Self::shift_value(len)) | (len as u64 + 1),
+        })
+    }
+
     // Returns the shift value. If we apply this shift to the packed value, we get the value of the `i`-th character.
     fn shift_value(i: usize) -> usize {
         assert!(i < GEOHASH_MAX_LENGTH);
@@ -452,50 +463,6 @@ pub fn rectangle_hashes(
     create_hashes(mapping_fn)
 }
 
-/// Return as-high-as-possible with maximum of `max_regions`
-/// number of geo-hash guaranteed to contain a boundary defined by closed LineString.
-fn boundary_hashes(boundary: &LineString, max_regions: usize) -> OperationResult<Vec<GeoHash>> {
-    let geo_bounding_box = minimum_bounding_rectangle_for_boundary(boundary);
-    let full_geohash_bounding_box: GeohashBoundingBox = geo_bounding_box.into();
-    let polygon = Polygon::new(boundary.clone(), vec![]);
-
-    let mapping_fn = |precision| {
-        full_geohash_bounding_box
-            .geohash_regions(precision, max_regions)
-            .map(|hashes| {
-                hashes
-                    .into_iter()
-                    .filter(|hash| {
-                        check_polygon_intersection(EcoString::from(*hash).as_str(), &polygon)
-                    })
-                    .collect_vec()
-            })
-    };
-    create_hashes(mapping_fn)
-}
-
-/// A function used for cardinality estimation.
-///
-/// The first return value is as-high-as-possible with maximum of `max_regions`
-/// number of geo-hash guaranteed to contain the polygon's exterior.
-/// The second return value is all as-high-as-possible with maximum of `max_regions`
-/// number of geo-hash guaranteed to contain each polygon's interior.
-pub fn polygon_hashes_estimation(
-    polygon: &GeoPolygon,
-    max_regions: usize,
-) -> (Vec<GeoHash>, Vec<Vec<GeoHash>>) {
-    assert_ne!(max_regions, 0, "max_regions cannot be equal to zero");
-    let polygon_wrapper = polygon.convert().polygon;
-    let exterior_hashes = boundary_hashes(&polygon_wrapper.exterior().clone(), max_regions);
-    let interiors_hashes = polygon_wrapper
-        .interiors()
-        .iter()
-        .map(|interior| boundary_hashes(interior, max_regions).unwrap())
-        .collect_vec();
-
-    (exterior_hashes.unwrap(), interiors_hashes)
-}
-
 /// Return as-high-as-possible with maximum of `max_regions`
 /// number of geo-hash guaranteed to contain the whole polygon.
 pub fn polygon_hashes(polygon: &GeoPolygon, max_regions: usize) -> OperationResult<Vec<GeoHash>> {
@@ -526,6 +493,90 @@ pub fn polygon_hashes(polygon: &GeoPolygon, max_regions: usize) -> OperationResu
     create_hashes(mapping_fn)
 }
 
+/// Relation of a geo-hash region to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegionRelation {
+    Outside,
+    Boundary,
+    Inside,
+}
+
+fn polygon_region_relation(geo_hash: GeoHash, polygon: &Polygon) -> RegionRelation {
+    if geo_hash.is_empty() {
+        return RegionRelation::Boundary;
+    }
+    let rect = decode_bbox(EcoString::from(geo_hash).as_str()).unwrap();
+
+    if !rect.intersects(polygon) {
+        return RegionRelation::Outside;
+    }
+
+    // A region touched by no ring of the polygon is either fully inside or fully outside of it
+    let crosses_boundary = rect.intersects(polygon.exterior())
+        || polygon
+            .interiors()
+            .iter()
+            .any(|interior| rect.intersects(interior));
+
+    if crosses_boundary {
+        RegionRelation::Boundary
+    } else {
+        RegionRelation::Inside
+    }
+}
+
+/// Geo-hash regions covering a polygon, split by their relation to the polygon
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PolygonCovering {
+    /// Regions lying strictly inside the polygon, all points in them match
+    pub interior: Vec<GeoHash>,
+    /// Regions crossed by the polygon boundary, points in them have to be checked
+    pub boundary: Vec<GeoHash>,
+}
+
+/// Cover the polygon with geo-hash regions of varying precision, similar to an s2 cell covering.
+///
+/// Starts from [`polygon_hashes`] with `initial_regions` and keeps splitting regions crossed
+/// by the polygon boundary into finer sub-regions, while the covering fits into `max_regions`.
+/// Regions for which `is_populated` returns `false` are dropped, as they can't contain matches.
+pub fn polygon_covering(
+    polygon: &GeoPolygon,
+    initial_regions: usize,
+    max_regions: usize,
+    is_populated: impl Fn(&GeoHash) -> bool,
+) -> OperationResult<PolygonCovering> {
+    let polygon_wrapper = polygon.convert().polygon;
+    let mut covering = PolygonCovering::default();
+    let mut candidates = polygon_hashes(polygon, initial_regions)?;
+
+    loop {
+        let mut boundary = Vec::new();
+        for geo_hash in candidates {
+            if !is_populated(&geo_hash) {
+                continue;
+            }
+            match polygon_region_relation(geo_hash, &polygon_wrapper) {
+                RegionRelation::Outside => {}
+                RegionRelation::Boundary => boundary.push(geo_hash),
+                RegionRelation::Inside => covering.interior.push(geo_hash),
+            }
+        }
+
+        let can_split = boundary.iter().all(|hash| hash.len() < GEOHASH_MAX_LENGTH)
+            && covering.interior.len() + boundary.len() * BASE32_CODES.len() <= max_regions;
+
+        if boundary.is_empty() || !can_split {
+            covering.boundary = boundary;
+            return Ok(covering);
+        }
+
+        candidates = boundary
+            .into_iter()
+            .flat_map(|geo_hash| geo_hash.sub_regions())
+            .collect();
+    }
+}
+
 /// A globally-average value is usually considered to be 6,371 kilometres (3,959 mi) with a 0.3% variability (±10 km).
 /// <https://en.wikipedia.org/wiki/Earth_radius>.
 const EARTH_RADIUS_METERS: f64 = 6371.0 * 1000.;
@@ -697,6 +748,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn geohash_sub_regions() {
+        let geo_hash = GeoHash::new(b"dr5ru").unwrap();
+        let sub_regions = geo_hash.sub_regions().collect_vec();
+        assert_eq!(sub_regions.len(), 32);
+        assert_eq!(sub_regions[0], GeoHash::new(b"dr5ru0").unwrap());
+        assert_eq!(sub_regions[31], GeoHash::new(b"dr5ruz").unwrap());
+        assert!(sub_regions.is_sorted());
+        assert!(
+            sub_regions
+                .iter()
+                .all(|sub_region| sub_region.len() == 6 && sub_region.starts_with(geo_hash)),
+        );
+
+        let top_level = GeoHash::default().sub_regions().collect_vec();
+        assert_eq!(top_level[12], GeoHash::new(b"d").unwrap());
+    }
+
     #[test]
     fn geohash_encode_longitude_first() {
         let center_hash = GeoHash::new(encode(Coord::from(NYC), GEOHASH_MAX_LENGTH).unwrap());
@@ -958,6 +1027,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn polygon_covering_with_hole() {
+        let polygon = build_polygon_with_interiors(
+            vec![
+                (-60.0, 37.0),
+                (-60.0, 45.0),
+                (-50.0, 45.0),
+                (-50.0, 37.0),
+                (-60.0, 37.0),
+            ],
+            vec![vec![
+                (-56.0, 40.0),
+                (-56.0, 42.0),
+                (-54.0, 42.0),
+                (-54.0, 40.0),
+                (-56.0, 40.0),
+            ]],
+        );
+        let polygon_wrapper = polygon.convert();
+
+        let covering = polygon_covering(&polygon, 12, 512, |_| true).unwrap();
+        assert!(!covering.interior.is_empty());
+        assert!(!covering.boundary.is_empty());
+        assert!(covering.interior.len() + covering.boundary.len() <= 512);
+
+        // Boundary regions are refined beyond the initial precision
+        let initial_precision = polygon_hashes(&polygon, 12).unwrap()[0].len();
+        assert!(
+            covering
+                .boundary
+                .iter()
+                .all(|geo_hash| geo_hash.len() > initial_precision),
+        );
+
+        // Corners of the interior regions are inside of the polygon, holes are excluded
+        for geo_hash in &covering.interior {
+            let GeoBoundingBox {
+                top_left,
+                bottom_right,
+            } = geo_hash_to_box(*geo_hash);
+            for corner in [
+                top_left,
+                bottom_right,
+                GeoPoint::new_unchecked(top_left.lon.0, bottom_right.lat.0),
+                GeoPoint::new_unchecked(bottom_right.lon.0, top_left.lat.0),
+            ] {
+                assert!(polygon_wrapper.check_point(&corner), "{geo_hash}");
+            }
+        }
+
+        // Every point of the polygon is covered
+        let mut rnd = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let point = GeoPoint::new_unchecked(
+                rnd.random_range(-60.0..-50.0),
+                rnd.random_range(37.0..45.0),
+            );
+            if !polygon_wrapper.check_point(&point) {
+                continue;
+            }
+            let point_hash = encode_max_precision(point.lon.0, point.lat.0).unwrap();
+            assert!(
+                covering
+                    .interior
+                    .iter()
+                    .chain(&covering.boundary)
+                    .any(|geo_hash| point_hash.starts_with(*geo_hash)),
+            );
+        }
+
+        // Unpopulated regions are dropped
+        let populated = encode_max_precision(-52.0, 38.0).unwrap().truncate(3);
+        let is_populated = |geo_hash: &GeoHash| {
+            geo_hash.starts_with(populated) || populated.starts_with(*geo_hash)
+        };
+        let covering = polygon_covering(&polygon, 12, 512, is_populated).unwrap();
+        assert!(!covering.interior.is_empty());
+        assert!(
+            covering
+                .interior
+                .iter()
+                .chain(&covering.boundary)
+                .all(is_populated),
+        );
+    }
+
     #[test]
     fn random_circles() {
         let mut rnd = StdRng::seed_from_u64(42);
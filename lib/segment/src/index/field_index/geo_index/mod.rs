@@ -26,8 +26,8 @@ use super::FieldIndexBuilderTrait;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::field_index::geo_hash::{
-    GeoHash, circle_hashes, common_hash_prefix, geo_hash_to_box, polygon_hashes,
-    polygon_hashes_estimation, rectangle_hashes,
+    GeoHash, PolygonCovering, circle_hashes, common_hash_prefix, geo_hash_to_box, polygon_covering,
+    rectangle_hashes,
 };
 use crate::index::field_index::stat_tools::estimate_multi_value_selection_cardinality;
 use crate::index::field_index::{
@@ -35,7 +35,7 @@ use crate::index::field_index::{
 };
 use crate::index::payload_config::{IndexMutability, StorageType};
 use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{FieldCondition, GeoPoint, PayloadKeyType};
+use crate::types::{FieldCondition, GeoPoint, GeoPolygon, PayloadKeyType};
 
 pub mod immutable_geo_index;
 pub mod mmap_geo_index;
@@ -45,6 +45,9 @@ pub mod mutable_geo_index;
 // TODO discuss value, should it be dynamically computed?
 const GEO_QUERY_MAX_REGION: usize = 12;
 
+/// Max number of regions in a refined polygon covering
+const GEO_POLYGON_MAX_COVERING_REGIONS: usize = 512;
+
 pub enum GeoMapIndex {
     Mutable(MutableGeoMapIndex),
     Immutable(ImmutableGeoMapIndex),
@@ -318,6 +321,41 @@ impl GeoMapIndex {
         }
     }
 
+    /// Cover the polygon with populated geo-hash regions, splitting the regions on its boundary
+    fn polygon_covering(
+        &self,
+        polygon: &GeoPolygon,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<PolygonCovering> {
+        polygon_covering(
+            polygon,
+            GEO_QUERY_MAX_REGION,
+            GEO_POLYGON_MAX_COVERING_REGIONS,
+            |hash| self.points_of_hash(hash, hw_counter) > 0,
+        )
+    }
+
+    /// Points in the interior regions are known to match, so they bound the estimation from below.
+    /// About half of the points in the boundary regions are expected to match.
+    fn polygon_cardinality(
+        &self,
+        covering: &PolygonCovering,
+        hw_counter: &HardwareCounterCell,
+    ) -> CardinalityEstimation {
+        let interior = self.match_cardinality(&covering.interior, hw_counter);
+        let boundary = self.match_cardinality(&covering.boundary, hw_counter);
+
+        let max = min(interior.max + boundary.max, self.points_count());
+        let exp = interior.exp + boundary.exp / 2;
+
+        CardinalityEstimation {
+            primary_clauses: vec![],
+            min: interior.min,
+            exp: exp.clamp(interior.min, max),
+            max,
+        }
+    }
+
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
         PayloadIndexTelemetry {
             field_name: None,
@@ -728,13 +766,18 @@ impl PayloadFieldIndex for GeoMapIndex {
         }
 
         if let Some(geo_polygon) = &condition.geo_polygon {
-            let geo_hashes = polygon_hashes(geo_polygon, GEO_QUERY_MAX_REGION).ok()?;
+            let PolygonCovering { interior, boundary } =
+                self.polygon_covering(geo_polygon, hw_counter).ok()?;
             let geo_condition_copy = geo_polygon.convert();
-            return Some(Box::new(self.iterator(geo_hashes).filter(move |point| {
+            // Points of the interior regions are inside of the polygon, no need to check them
+            let boundary_points = self.iterator(boundary).filter(move |point| {
                 self.check_values_any(*point, hw_counter, |geo_point| {
                     geo_condition_copy.check_point(geo_point)
                 })
-            })));
+            });
+            return Some(Box::new(
+                self.iterator(interior).chain(boundary_points).unique(),
+            ));
         }
 
         None
@@ -764,29 +807,12 @@ impl PayloadFieldIndex for GeoMapIndex {
         }
 
         if let Some(geo_polygon) = &condition.geo_polygon {
-            let (exterior_hashes, interior_hashes) =
-                polygon_hashes_estimation(geo_polygon, GEO_QUERY_MAX_REGION);
-            // The polygon cardinality estimation should consider its exterior and interiors.
-            // Therefore, we compute exterior estimation first and then subtract all interior estimation.
-            let mut exterior_estimation = self.match_cardinality(&exterior_hashes, hw_counter);
-
-            for interior in &interior_hashes {
-                let interior_estimation = self.match_cardinality(interior, hw_counter);
-                exterior_estimation.min = max(0, exterior_estimation.min - interior_estimation.max);
-                exterior_estimation.max = max(
-                    exterior_estimation.min,
-                    exterior_estimation.max - interior_estimation.min,
-                );
-                exterior_estimation.exp = max(
-                    exterior_estimation.exp - interior_estimation.exp,
-                    exterior_estimation.min,
-                );
-            }
-
-            exterior_estimation
+            let covering = self.polygon_covering(geo_polygon, hw_counter).ok()?;
+            let mut estimation = self.polygon_cardinality(&covering, hw_counter);
+            estimation
                 .primary_clauses
                 .push(PrimaryCondition::Condition(Box::new(condition.clone())));
-            return Some(exterior_estimation);
+            return Some(estimation);
         }
 
         None
@@ -828,8 +854,9 @@ mod tests {
     #[cfg(feature = "rocksdb")]
     use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
     use crate::fixtures::payload_fixtures::random_geo_payload;
+    use crate::index::field_index::geo_hash::polygon_hashes;
     use crate::json_path::JsonPath;
-    use crate::types::test_utils::build_polygon;
+    use crate::types::test_utils::{build_polygon, build_polygon_with_interiors};
     use crate::types::{GeoBoundingBox, GeoLineString, GeoPolygon, GeoRadius};
 
     #[cfg(feature = "rocksdb")]
@@ -1029,6 +1056,35 @@ mod tests {
         ])
     }
 
+    /// Polygon estimation must bound the number of points actually matching the polygon
+    fn check_polygon_cardinality(geo_polygon: GeoPolygon, index_type: IndexType) {
+        let (field_index, _, _) = build_random_index(500, 20, index_type);
+        let hw_counter = HardwareCounterCell::new();
+
+        let polygon_wrapper = geo_polygon.convert();
+        let real_cardinality = (0..field_index.count_indexed_points() as PointOffsetType)
+            .filter(|idx| {
+                field_index.check_values_any(*idx, &hw_counter, |geo_point| {
+                    polygon_wrapper.check_point(geo_point)
+                })
+            })
+            .count();
+
+        let field_condition = condition_for_geo_polygon("test", geo_polygon);
+        let card = field_index
+            .estimate_cardinality(&field_condition, &hw_counter)
+            .unwrap();
+
+        eprintln!("real_cardinality = {real_cardinality:#?}");
+        eprintln!("card = {card:#?}");
+
+        assert!(card.min <= real_cardinality);
+        assert!(card.max >= real_cardinality);
+
+        assert!(card.exp >= card.min);
+        assert!(card.exp <= card.max);
+    }
+
     #[rstest]
     #[cfg_attr(feature = "rocksdb", case(IndexType::Mutable))]
     #[case(IndexType::MutableGridstore)]
@@ -1036,29 +1092,6 @@ mod tests {
     #[case(IndexType::Mmap)]
     #[case(IndexType::RamMmap)]
     fn test_polygon_with_exclusion(#[case] index_type: IndexType) {
-        fn check_cardinality_match(
-            hashes: Vec<GeoHash>,
-            field_condition: FieldCondition,
-            index_type: IndexType,
-        ) {
-            let (field_index, _, _) = build_random_index(500, 20, index_type);
-            let exact_points_for_hashes = field_index.iterator(hashes).collect_vec();
-            let real_cardinality = exact_points_for_hashes.len();
-
-            let hw_counter = HardwareCounterCell::new();
-            let card = field_index.estimate_cardinality(&field_condition, &hw_counter);
-            let card = card.unwrap();
-
-            eprintln!("real_cardinality = {real_cardinality:#?}");
-            eprintln!("card = {card:#?}");
-
-            assert!(card.min <= real_cardinality);
-            assert!(card.max >= real_cardinality);
-
-            assert!(card.exp >= card.min);
-            assert!(card.exp <= card.max);
-        }
-
         let europe = GeoLineString {
             points: vec![
                 GeoPoint::new_unchecked(19.415558242000287, 69.18533258102943),
@@ -1096,11 +1129,7 @@ mod tests {
             exterior: europe,
             interiors: Some(vec![berlin]),
         };
-        check_cardinality_match(
-            polygon_hashes(&europe_no_berlin, GEO_QUERY_MAX_REGION).unwrap(),
-            condition_for_geo_polygon("test", europe_no_berlin.clone()),
-            index_type,
-        );
+        check_polygon_cardinality(europe_no_berlin, index_type);
     }
 
     #[rstest]
@@ -1149,12 +1178,7 @@ mod tests {
 
         // geo_polygon cardinality check
         let geo_polygon = radius_to_polygon(&geo_radius);
-        let polygon_hashes = polygon_hashes(&geo_polygon, GEO_QUERY_MAX_REGION).unwrap();
-        check_cardinality_match(
-            polygon_hashes,
-            condition_for_geo_polygon("test", geo_polygon),
-            index_type,
-        );
+        check_polygon_cardinality(geo_polygon, index_type);
     }
 
     #[rstest]
@@ -1222,6 +1246,29 @@ mod tests {
             |geo_point| geo_polygon.convert().check_point(geo_point),
             index_type,
         );
+
+        // large polygon with a hole, mostly matched by interior regions of the covering
+        let geo_polygon: GeoPolygon = build_polygon_with_interiors(
+            vec![
+                (-80.0, 20.0),
+                (-80.0, 60.0),
+                (-20.0, 60.0),
+                (-20.0, 20.0),
+                (-80.0, 20.0),
+            ],
+            vec![vec![
+                (-60.0, 30.0),
+                (-60.0, 50.0),
+                (-40.0, 50.0),
+                (-40.0, 30.0),
+                (-60.0, 30.0),
+            ]],
+        );
+        check_geo_indexed_filtering(
+            condition_for_geo_polygon("test", geo_polygon.clone()),
+            |geo_point| geo_polygon.convert().check_point(geo_point),
+            index_type,
+        );
     }
 
     #[rstest]
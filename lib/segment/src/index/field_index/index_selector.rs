@@ -129,6 +129,10 @@ impl IndexSelector<'_> {
                 .map_new(field, create_if_missing)?
                 .map(FieldIndex::UuidMapIndex),
 
+            (PayloadIndexType::IpIndex, PayloadSchemaParams::Ip(_)) => self
+                .numeric_new(field, create_if_missing)?
+                .map(FieldIndex::IpIndex),
+
             (PayloadIndexType::NullIndex, _) => MutableNullIndex::open(
                 &null_dir(path, field),
                 total_point_count,
@@ -199,6 +203,9 @@ impl IndexSelector<'_> {
             PayloadSchemaParams::Uuid(_) => self
                 .map_new(field, create_if_missing)?
                 .map(|index| vec![FieldIndex::UuidMapIndex(index)]),
+            PayloadSchemaParams::Ip(_) => self
+                .numeric_new(field, create_if_missing)?
+                .map(|index| vec![FieldIndex::IpIndex(index)]),
         };
 
         Ok(indexes)
@@ -292,6 +299,15 @@ impl IndexSelector<'_> {
                     FieldIndexBuilder::UuidGridstoreIndex,
                 )?]
             }
+            PayloadSchemaParams::Ip(_) => {
+                vec![self.numeric_builder(
+                    field,
+                    #[cfg(feature = "rocksdb")]
+                    FieldIndexBuilder::IpIndex,
+                    FieldIndexBuilder::IpMmapIndex,
+                    FieldIndexBuilder::IpGridstoreIndex,
+                )?]
+            }
         };
 
         Ok(builders)
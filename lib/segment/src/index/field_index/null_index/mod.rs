@@ -1,3 +1,4 @@
 pub mod mutable_null_index;
+mod values_count_flags;
 
 pub use mutable_null_index::MutableNullIndex;
@@ -5,6 +5,7 @@ use common::types::PointOffsetType;
 use fs_err as fs;
 use serde_json::Value;
 
+use super::values_count_flags::ValuesCountFlags;
 use crate::common::Flusher;
 use crate::common::flags::dynamic_mmap_flags::DynamicMmapFlags;
 use crate::common::flags::roaring_flags::RoaringFlags;
@@ -15,10 +16,11 @@ use crate::index::field_index::{
 };
 use crate::index::payload_config::{IndexMutability, StorageType};
 use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{FieldCondition, PayloadKeyType};
+use crate::types::{FieldCondition, PayloadKeyType, ValuesCount};
 
 const HAS_VALUES_DIRNAME: &str = "has_values";
 const IS_NULL_DIRNAME: &str = "is_null";
+const VALUES_COUNT_DIRNAME: &str = "values_count";
 
 /// Mutable variant of null index that uses roaring bitmaps for in-memory operations
 /// and buffers updates before persisting them to DynamicMmapFlags.
@@ -33,6 +35,9 @@ struct Storage {
     has_values_flags: RoaringFlags,
     /// Points which have null values
     is_null_flags: RoaringFlags,
+    /// Number of values of each point.
+    /// Absent in indexes created before values counting was introduced.
+    values_count_flags: Option<ValuesCountFlags>,
 }
 
 impl MutableNullIndex {
@@ -65,10 +70,21 @@ impl MutableNullIndex {
             return Ok(None);
         }
 
-        Ok(Some(Self::open_or_create(path, total_point_count)?))
+        // Existing index without values count can't start counting, as it misses older points
+        let count_values = !has_values_dir.is_dir() || path.join(VALUES_COUNT_DIRNAME).is_dir();
+
+        Ok(Some(Self::open_or_create(
+            path,
+            total_point_count,
+            count_values,
+        )?))
     }
 
-    fn open_or_create(path: &Path, total_point_count: usize) -> OperationResult<Self> {
+    fn open_or_create(
+        path: &Path,
+        total_point_count: usize,
+        count_values: bool,
+    ) -> OperationResult<Self> {
         fs::create_dir_all(path).map_err(|err| {
             OperationError::service_error(format!(
                 "Failed to create mutable-null-index directory: {err}, path: {path:?}"
@@ -83,9 +99,18 @@ impl MutableNullIndex {
         let is_null_mmap = DynamicMmapFlags::open(&is_null_path, false)?;
         let is_null_flags = RoaringFlags::new(is_null_mmap);
 
+        let values_count_flags = if count_values {
+            Some(ValuesCountFlags::open_or_create(
+                &path.join(VALUES_COUNT_DIRNAME),
+            )?)
+        } else {
+            None
+        };
+
         let storage = Storage {
             has_values_flags,
             is_null_flags,
+            values_count_flags,
         };
 
         Ok(Self {
@@ -137,6 +162,9 @@ impl MutableNullIndex {
 
         self.storage.has_values_flags.set(id, has_values);
         self.storage.is_null_flags.set(id, is_null);
+        if let Some(values_count_flags) = &mut self.storage.values_count_flags {
+            values_count_flags.set(id, payload)?;
+        }
 
         // Bump total points
         self.total_point_count = std::cmp::max(self.total_point_count, id as usize + 1);
@@ -151,6 +179,9 @@ impl MutableNullIndex {
         // Update bitmaps immediately
         self.storage.has_values_flags.set(id, false);
         self.storage.is_null_flags.set(id, false);
+        if let Some(values_count_flags) = &mut self.storage.values_count_flags {
+            values_count_flags.remove(id);
+        }
 
         // Bump total points
         // We MUST bump the total point count when removing a point too
@@ -178,6 +209,25 @@ impl MutableNullIndex {
         self.storage.is_null_flags.get(id)
     }
 
+    /// Whether the index can be used to check `values_count` conditions
+    pub fn counts_values(&self) -> bool {
+        self.storage.values_count_flags.is_some()
+    }
+
+    /// Check `values_count` condition for the point.
+    ///
+    /// Returns `None` if it can't be decided by the index, and payload must be checked instead.
+    pub fn check_values_count(
+        &self,
+        id: PointOffsetType,
+        values_count: &ValuesCount,
+    ) -> Option<bool> {
+        self.storage
+            .values_count_flags
+            .as_ref()?
+            .check(id, values_count)
+    }
+
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
         let points_count = self.storage.has_values_flags.len();
 
@@ -199,6 +249,9 @@ impl MutableNullIndex {
 
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
+        if let Some(values_count_flags) = &self.storage.values_count_flags {
+            values_count_flags.clear_cache()?;
+        }
         self.storage.is_null_flags.clear_cache()?;
         self.storage.has_values_flags.clear_cache()
     }
@@ -232,10 +285,18 @@ impl PayloadFieldIndex for MutableNullIndex {
     fn flusher(&self) -> Flusher {
         let flush_has_values = self.storage.has_values_flags.flusher();
         let flush_is_null = self.storage.is_null_flags.flusher();
+        let flush_values_count = self
+            .storage
+            .values_count_flags
+            .as_ref()
+            .map(|values_count_flags| values_count_flags.flusher());
 
         Box::new(move || {
             flush_has_values()?;
             flush_is_null()?;
+            if let Some(flush_values_count) = flush_values_count {
+                flush_values_count()?;
+            }
             Ok(())
         })
     }
@@ -243,6 +304,9 @@ impl PayloadFieldIndex for MutableNullIndex {
     fn files(&self) -> Vec<PathBuf> {
         let mut files = self.storage.has_values_flags.files();
         files.extend(self.storage.is_null_flags.files());
+        if let Some(values_count_flags) = &self.storage.values_count_flags {
+            files.extend(values_count_flags.files());
+        }
         files
     }
 
@@ -262,7 +326,7 @@ impl PayloadFieldIndex for MutableNullIndex {
             geo_bounding_box: _,
             geo_radius: _,
             geo_polygon: _,
-            values_count: _,
+            values_count,
             is_empty,
             is_null,
         } = condition;
@@ -287,6 +351,14 @@ impl PayloadFieldIndex for MutableNullIndex {
                 let iter = self.storage.is_null_flags.iter_falses();
                 Some(Box::new(iter))
             }
+        } else if let Some(values_count) = values_count {
+            // Return points that match, and multi-valued points to check against payload
+            let (candidates, _) = self
+                .storage
+                .values_count_flags
+                .as_ref()?
+                .candidates(values_count, self.total_point_count);
+            Some(Box::new(candidates.into_iter()))
         } else {
             None
         }
@@ -304,7 +376,7 @@ impl PayloadFieldIndex for MutableNullIndex {
             geo_bounding_box: _,
             geo_radius: _,
             geo_polygon: _,
-            values_count: _,
+            values_count,
             is_empty,
             is_null,
         } = condition;
@@ -349,6 +421,24 @@ impl PayloadFieldIndex for MutableNullIndex {
                     ))],
                 })
             }
+        } else if let Some(values_count) = values_count {
+            let (candidates, multi_valued) = self
+                .storage
+                .values_count_flags
+                .as_ref()?
+                .candidates(values_count, self.total_point_count);
+            let max = candidates.len() as usize;
+            let min = max.saturating_sub(multi_valued);
+
+            Some(CardinalityEstimation {
+                min,
+                exp: min + multi_valued / 2,
+                max,
+                primary_clauses: vec![PrimaryCondition::from(FieldCondition::new_values_count(
+                    key.clone(),
+                    *values_count,
+                ))],
+            })
         } else {
             None
         }
@@ -0,0 +1,295 @@
+use std::path::{Path, PathBuf};
+
+use common::types::PointOffsetType;
+use fs_err as fs;
+use roaring::RoaringBitmap;
+use serde_json::Value;
+
+use crate::common::Flusher;
+use crate::common::flags::dynamic_mmap_flags::DynamicMmapFlags;
+use crate::common::flags::roaring_flags::RoaringFlags;
+use crate::common::operation_error::OperationResult;
+use crate::types::ValuesCount;
+
+const MULTI_VALUES_DIRNAME: &str = "multi_values";
+const BIT_DIRNAME_PREFIX: &str = "bit_";
+
+/// Number of values of each point, stored as a bit-sliced index.
+///
+/// Bit `k` of the values count of a point is stored in the `k`-th flags.
+/// This allows to check a count of a single point, as well as to select all points with a count
+/// in a given range using a few bitmap operations.
+///
+/// Values count has the same meaning as in the `values_count` condition: it is the length of an
+/// array, or 1 for any other non-null value. If there are several values for the key,
+/// the condition matches if any of them matches, which can't be represented by a single count.
+/// Such points are only marked as multi-valued and must be checked against the payload.
+pub struct ValuesCountFlags {
+    base_dir: PathBuf,
+    /// Points which have several values for the key
+    multi_values_flags: RoaringFlags,
+    /// `bits[k]` contains points, which have bit `k` of their values count set
+    bits: Vec<RoaringFlags>,
+}
+
+impl ValuesCountFlags {
+    pub fn open_or_create(path: &Path) -> OperationResult<Self> {
+        fs::create_dir_all(path)?;
+
+        let multi_values_mmap = DynamicMmapFlags::open(&path.join(MULTI_VALUES_DIRNAME), false)?;
+        let multi_values_flags = RoaringFlags::new(multi_values_mmap);
+
+        let mut bits = Vec::new();
+        loop {
+            let bit_path = bit_dir(path, bits.len());
+            if !bit_path.is_dir() {
+                break;
+            }
+            bits.push(RoaringFlags::new(DynamicMmapFlags::open(&bit_path, false)?));
+        }
+
+        Ok(Self {
+            base_dir: path.to_path_buf(),
+            multi_values_flags,
+            bits,
+        })
+    }
+
+    pub fn set(&mut self, id: PointOffsetType, payload: &[&Value]) -> OperationResult<()> {
+        let (count, is_multi_valued) = match payload {
+            [] => (0, false),
+            [value] => (value_count(value), false),
+            _ => (0, true),
+        };
+
+        self.multi_values_flags.set(id, is_multi_valued);
+
+        let required_bits = (usize::BITS - count.leading_zeros()) as usize;
+        while self.bits.len() < required_bits {
+            let bit_path = bit_dir(&self.base_dir, self.bits.len());
+            self.bits
+                .push(RoaringFlags::new(DynamicMmapFlags::open(&bit_path, false)?));
+        }
+
+        for (bit, flags) in self.bits.iter_mut().enumerate() {
+            flags.set(id, count & (1 << bit) != 0);
+        }
+
+        Ok(())
+    }
+
+    pub fn remove(&mut self, id: PointOffsetType) {
+        self.multi_values_flags.set(id, false);
+        for flags in &mut self.bits {
+            flags.set(id, false);
+        }
+    }
+
+    /// Check values count of the point.
+    ///
+    /// Returns `None` if the point has several values for the key, so the payload must be checked.
+    pub fn check(&self, id: PointOffsetType, values_count: &ValuesCount) -> Option<bool> {
+        if self.multi_values_flags.get(id) {
+            return None;
+        }
+
+        let count = self
+            .bits
+            .iter()
+            .enumerate()
+            .filter(|(_, flags)| flags.get(id))
+            .fold(0usize, |count, (bit, _)| count | (1 << bit));
+
+        Some(values_count.check_count(count))
+    }
+
+    /// Select points which might match the condition.
+    ///
+    /// Contains all matching points and all multi-valued points, which have to be checked
+    /// against the payload.
+    ///
+    /// Returns the candidates and the number of multi-valued points among them.
+    pub fn candidates(
+        &self,
+        values_count: &ValuesCount,
+        total_point_count: usize,
+    ) -> (RoaringBitmap, usize) {
+        let ValuesCount { lt, gt, gte, lte } = *values_count;
+
+        let min = gt
+            .map(|gt| gt.saturating_add(1))
+            .into_iter()
+            .chain(gte)
+            .max()
+            .unwrap_or(0);
+        let max_exclusive = lt
+            .into_iter()
+            .chain(lte.map(|lte| lte.saturating_add(1)))
+            .min();
+
+        let mut all_points = RoaringBitmap::new();
+        all_points.insert_range(0..total_point_count as PointOffsetType);
+
+        let mut candidates = self.count_at_least(min, &all_points);
+        if let Some(max_exclusive) = max_exclusive {
+            candidates -= self.count_at_least(max_exclusive, &all_points);
+        }
+        candidates -= self.multi_values_flags.get_bitmap();
+
+        let multi_valued = self.multi_values_flags.count_trues();
+        candidates |= self.multi_values_flags.get_bitmap();
+
+        (candidates, multi_valued)
+    }
+
+    /// Select points with values count of at least `count`, using the bit-sliced comparison.
+    fn count_at_least(&self, count: usize, all_points: &RoaringBitmap) -> RoaringBitmap {
+        let required_bits = (usize::BITS - count.leading_zeros()) as usize;
+        if required_bits > self.bits.len() {
+            // No point has such a large count
+            return RoaringBitmap::new();
+        }
+
+        // Points with a larger count, judging by the already compared bits
+        let mut greater = RoaringBitmap::new();
+        // Points with an equal count, judging by the already compared bits
+        let mut equal = all_points.clone();
+
+        for (bit, flags) in self.bits.iter().enumerate().rev() {
+            let bitmap = flags.get_bitmap();
+            if count & (1 << bit) != 0 {
+                equal &= bitmap;
+            } else {
+                greater |= &equal & bitmap;
+                equal -= bitmap;
+            }
+        }
+
+        greater | equal
+    }
+
+    pub fn flusher(&self) -> Flusher {
+        let mut flushers = vec![self.multi_values_flags.flusher()];
+        flushers.extend(self.bits.iter().map(|flags| flags.flusher()));
+
+        Box::new(move || {
+            for flusher in flushers {
+                flusher()?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.multi_values_flags.files();
+        for flags in &self.bits {
+            files.extend(flags.files());
+        }
+        files
+    }
+
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        self.multi_values_flags.clear_cache()?;
+        for flags in &self.bits {
+            flags.clear_cache()?;
+        }
+        Ok(())
+    }
+}
+
+fn bit_dir(path: &Path, bit: usize) -> PathBuf {
+    path.join(format!("{BIT_DIRNAME_PREFIX}{bit}"))
+}
+
+/// Same as [`ValuesCount::check_count_from`]
+fn value_count(value: &Value) -> usize {
+    match value {
+        Value::Null => 0,
+        Value::Array(array) => array.len(),
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_values_count_flags() {
+        let dir = TempDir::with_prefix("test_values_count_flags").unwrap();
+
+        let payloads: Vec<Vec<Value>> = vec![
+            vec![],
+            vec![json!(null)],
+            vec![json!("a")],
+            vec![json!([1, 2])],
+            vec![json!([1, 2, 3, 4, 5])],
+            vec![json!([]), json!([1, 2, 3])],
+            vec![json!(["a", "b", "c"])],
+        ];
+
+        let conditions = [
+            ValuesCount::from(0..1),
+            ValuesCount::from(1..3),
+            ValuesCount::from(3..100),
+            ValuesCount {
+                lt: None,
+                gt: Some(2),
+                gte: None,
+                lte: Some(5),
+            },
+            ValuesCount {
+                lt: Some(1),
+                gt: None,
+                gte: None,
+                lte: None,
+            },
+        ];
+
+        let check_all = |flags: &ValuesCountFlags| {
+            for condition in &conditions {
+                let (candidates, multi_valued) = flags.candidates(condition, payloads.len());
+                assert_eq!(multi_valued, 1);
+
+                for (id, payload) in payloads.iter().enumerate() {
+                    let id = id as PointOffsetType;
+                    let expected = if payload.is_empty() {
+                        condition.check_count(0)
+                    } else {
+                        payload
+                            .iter()
+                            .any(|value| condition.check_count_from(value))
+                    };
+
+                    match flags.check(id, condition) {
+                        Some(matches) => {
+                            assert_eq!(matches, expected, "{payload:?} {condition:?}");
+                            assert_eq!(candidates.contains(id), expected);
+                        }
+                        None => {
+                            assert!(payload.len() > 1);
+                            assert!(candidates.contains(id));
+                        }
+                    }
+                }
+            }
+        };
+
+        {
+            let mut flags = ValuesCountFlags::open_or_create(dir.path()).unwrap();
+            for (id, payload) in payloads.iter().enumerate() {
+                let payload: Vec<_> = payload.iter().collect();
+                flags.set(id as PointOffsetType, &payload).unwrap();
+            }
+            check_all(&flags);
+            flags.flusher()().unwrap();
+        }
+
+        // Check persistence
+        let flags = ValuesCountFlags::open_or_create(dir.path()).unwrap();
+        check_all(&flags);
+    }
+}
@@ -0,0 +1,146 @@
+use common::types::PointOffsetType;
+use rand::Rng;
+
+use crate::index::field_index::histogram::Numericable;
+
+/// Weight of a point for weighted sampling: the largest of its values.
+///
+/// Non-positive and non-finite values are ignored, points without any valid value have weight 0.
+pub fn point_weight<T: Numericable>(values: impl Iterator<Item = T>) -> f64 {
+    values
+        .map(T::to_f64)
+        .filter(|weight| weight.is_finite() && *weight > 0.0)
+        .fold(0.0, f64::max)
+}
+
+/// Alias method structure for sampling points proportionally to their weights in `O(1)`.
+///
+/// Built once over all points of an immutable index, points removed afterwards are still
+/// sampled and have to be rejected by the caller.
+///
+/// See <https://en.wikipedia.org/wiki/Alias_method>
+#[derive(Debug, Default)]
+pub struct AliasTable {
+    /// Points with positive weight, one bucket per point.
+    points: Vec<PointOffsetType>,
+    /// Probability to pick the point of the bucket rather than its alias.
+    probabilities: Vec<f64>,
+    /// Bucket of the alternative point for each bucket.
+    aliases: Vec<u32>,
+    total_weight: f64,
+}
+
+impl AliasTable {
+    /// Build alias table from `(point, weight)` pairs using Vose's algorithm.
+    ///
+    /// Points with non-positive or non-finite weight are never sampled.
+    pub fn new(weights: impl IntoIterator<Item = (PointOffsetType, f64)>) -> Self {
+        let (points, weights): (Vec<_>, Vec<_>) = weights
+            .into_iter()
+            .filter(|(_, weight)| weight.is_finite() && *weight > 0.0)
+            .unzip();
+
+        let total_weight: f64 = weights.iter().sum();
+        let buckets = points.len();
+        if buckets == 0 || !total_weight.is_finite() {
+            return Self::default();
+        }
+
+        let mut probabilities: Vec<f64> = weights
+            .iter()
+            .map(|weight| weight * buckets as f64 / total_weight)
+            .collect();
+        let mut aliases: Vec<u32> = (0..buckets as u32).collect();
+
+        let (mut small, mut large): (Vec<u32>, Vec<u32>) =
+            (0..buckets as u32).partition(|&bucket| probabilities[bucket as usize] < 1.0);
+
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            aliases[less as usize] = more;
+            probabilities[more as usize] -= 1.0 - probabilities[less as usize];
+            if probabilities[more as usize] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+
+        // Leftovers differ from 1.0 only due to rounding errors
+        for bucket in small.into_iter().chain(large) {
+            probabilities[bucket as usize] = 1.0;
+        }
+
+        Self {
+            points,
+            probabilities,
+            aliases,
+            total_weight,
+        }
+    }
+
+    /// Draw a single point, or `None` if there are no points with positive weight.
+    pub fn sample(&self, rng: &mut impl Rng) -> Option<PointOffsetType> {
+        if self.points.is_empty() {
+            return None;
+        }
+
+        let bucket = rng.random_range(0..self.points.len());
+        let bucket = if rng.random::<f64>() < self.probabilities[bucket] {
+            bucket
+        } else {
+            self.aliases[bucket] as usize
+        };
+
+        Some(self.points[bucket])
+    }
+
+    /// Sum of weights of all points in the table.
+    pub fn total_weight(&self) -> f64 {
+        self.total_weight
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    #[test]
+    fn test_alias_table_distribution() {
+        let table = AliasTable::new([(0, 1.0), (1, 2.0), (2, 0.0), (3, 7.0), (4, -1.0)]);
+        assert_eq!(table.total_weight(), 10.0);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counts = [0usize; 5];
+        let samples = 100_000;
+        for _ in 0..samples {
+            counts[table.sample(&mut rng).unwrap() as usize] += 1;
+        }
+
+        assert_eq!(counts[2], 0);
+        assert_eq!(counts[4], 0);
+        for (point, expected) in [(0, 0.1), (1, 0.2), (3, 0.7)] {
+            let frequency = counts[point] as f64 / samples as f64;
+            assert!(
+                (frequency - expected).abs() < 0.01,
+                "point {point}: {frequency} vs {expected}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_alias_table_empty() {
+        let table = AliasTable::new([(0, 0.0), (1, f64::NAN)]);
+        assert!(table.is_empty());
+        assert_eq!(table.sample(&mut rand::rng()), None);
+
+        assert_eq!(point_weight([3i64, -5, 7, 1].into_iter()), 7.0);
+        assert_eq!(point_weight([-1.0, f64::NAN].into_iter()), 0.0);
+    }
+}
@@ -3,6 +3,7 @@ use std::ops::Bound;
 use std::path::PathBuf;
 #[cfg(feature = "rocksdb")]
 use std::sync::Arc;
+use std::sync::OnceLock;
 
 use bitvec::vec::BitVec;
 use common::ext::BitSliceExt as _;
@@ -14,6 +15,7 @@ use parking_lot::RwLock;
 use rocksdb::DB;
 
 use super::Encodable;
+use super::alias_table::{AliasTable, point_weight};
 use super::mmap_numeric_index::MmapNumericIndex;
 use super::mutable_numeric_index::InMemoryNumericIndex;
 use crate::common::Flusher;
@@ -33,6 +35,8 @@ pub struct ImmutableNumericIndex<T: Encodable + Numericable + MmapValue + Defaul
     points_count: usize,
     max_values_per_point: usize,
     point_to_values: ImmutablePointToValues<T>,
+    // Alias table for weighted sampling, built on first use
+    alias_table: OnceLock<AliasTable>,
     // Backing storage, source of state, persists deletions
     storage: Storage<T>,
 }
@@ -194,6 +198,7 @@ where
             points_count,
             max_values_per_point,
             point_to_values: ImmutablePointToValues::new(point_to_values),
+            alias_table: OnceLock::new(),
             storage: Storage::RocksDb(db_wrapper),
         }))
     }
@@ -220,6 +225,7 @@ where
             points_count,
             max_values_per_point,
             point_to_values: ImmutablePointToValues::new(point_to_values),
+            alias_table: OnceLock::new(),
             storage: Storage::Mmap(Box::new(index)),
         }
     }
@@ -301,6 +307,18 @@ where
         self.point_to_values.get_values_count(idx)
     }
 
+    /// Alias table for sampling points proportionally to their values.
+    ///
+    /// Built on first use, points removed afterwards are not excluded from it.
+    pub(super) fn alias_table(&self) -> &AliasTable {
+        self.alias_table.get_or_init(|| {
+            AliasTable::new((0..).map_while(|idx| {
+                let values = self.point_to_values.get_values(idx)?;
+                Some((idx, point_weight(values.copied())))
+            }))
+        })
+    }
+
     pub(super) fn total_unique_values_count(&self) -> usize {
         self.map.len()
     }
@@ -1,3 +1,4 @@
+pub mod alias_table;
 pub mod immutable_numeric_index;
 pub mod mmap_numeric_index;
 pub mod mutable_numeric_index;
@@ -7,6 +8,7 @@ mod tests;
 
 use std::cmp::{max, min};
 use std::marker::PhantomData;
+use std::net::IpAddr;
 use std::ops::Bound;
 use std::ops::Bound::{Excluded, Included, Unbounded};
 use std::path::{Path, PathBuf};
@@ -31,6 +33,7 @@ use serde::de::DeserializeOwned;
 use serde_json::Value;
 use uuid::Uuid;
 
+use self::alias_table::AliasTable;
 use self::immutable_numeric_index::ImmutableNumericIndex;
 use super::FieldIndexBuilderTrait;
 use super::histogram::Point;
@@ -50,8 +53,9 @@ use crate::index::key_encoding::{
 use crate::index::payload_config::{IndexMutability, StorageType};
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, Match, MatchValue,
-    PayloadKeyType, Range, RangeInterface, UuidIntType, UuidPayloadType, ValueVariants,
+    DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, IpIntType,
+    IpPayloadType, Match, MatchValue, PayloadKeyType, Range, RangeInterface, UuidIntType,
+    UuidPayloadType, ValueVariants, ip_to_int,
 };
 
 const HISTOGRAM_MAX_BUCKET_SIZE: usize = 10_000;
@@ -320,11 +324,6 @@ where
     }
 
     fn range_cardinality(&self, range: &RangeInterface) -> CardinalityEstimation {
-        let max_values_per_point = self.max_values_per_point();
-        if max_values_per_point == 0 {
-            return CardinalityEstimation::exact(0);
-        }
-
         let range = match range {
             RangeInterface::Float(float_range) => float_range.map(|float| T::from_f64(float.0)),
             RangeInterface::DateTime(datetime_range) => {
@@ -332,6 +331,15 @@ where
             }
         };
 
+        self.typed_range_cardinality(&range)
+    }
+
+    fn typed_range_cardinality(&self, range: &Range<T>) -> CardinalityEstimation {
+        let max_values_per_point = self.max_values_per_point();
+        if max_values_per_point == 0 {
+            return CardinalityEstimation::exact(0);
+        }
+
         let lbound = if let Some(lte) = range.lte {
             Included(lte)
         } else if let Some(lt) = range.lt {
@@ -405,6 +413,33 @@ where
         self.values_count(idx) == 0
     }
 
+    /// Iterate over points with at least one value within `range`
+    fn point_ids_by_range<'a>(
+        &'a self,
+        range: &Range<T>,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
+        let (start_bound, end_bound) = range.as_index_key_bounds();
+
+        // map.range
+        // Panics if range start > end. Panics if range start == end and both bounds are Excluded.
+        if !check_boundaries(&start_bound, &end_bound) {
+            return Box::new(std::iter::empty());
+        }
+
+        match self {
+            NumericIndexInner::Mutable(index) => {
+                Box::new(index.values_range(start_bound, end_bound))
+            }
+            NumericIndexInner::Immutable(index) => {
+                Box::new(index.values_range(start_bound, end_bound))
+            }
+            NumericIndexInner::Mmap(index) => {
+                Box::new(index.values_range(start_bound, end_bound, hw_counter))
+            }
+        }
+    }
+
     pub fn point_ids_by_value<'a>(
         &'a self,
         value: T,
@@ -462,6 +497,15 @@ where
         }
     }
 
+    /// Alias table for weighted sampling by values, only kept for immutable in-memory index.
+    pub fn alias_table(&self) -> Option<&AliasTable> {
+        match self {
+            NumericIndexInner::Mutable(_) => None,
+            NumericIndexInner::Immutable(index) => Some(index.alias_table()),
+            NumericIndexInner::Mmap(_) => None,
+        }
+    }
+
     pub fn is_on_disk(&self) -> bool {
         match self {
             NumericIndexInner::Mutable(_) => false,
@@ -922,31 +966,14 @@ where
 
         let range_cond = condition.range.as_ref()?;
 
-        let (start_bound, end_bound) = match range_cond {
+        let range = match range_cond {
             RangeInterface::Float(float_range) => float_range.map(|float| T::from_f64(float.0)),
             RangeInterface::DateTime(datetime_range) => {
                 datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
             }
-        }
-        .as_index_key_bounds();
-
-        // map.range
-        // Panics if range start > end. Panics if range start == end and both bounds are Excluded.
-        if !check_boundaries(&start_bound, &end_bound) {
-            return Some(Box::new(std::iter::empty()));
-        }
+        };
 
-        Some(match self {
-            NumericIndexInner::Mutable(index) => {
-                Box::new(index.values_range(start_bound, end_bound))
-            }
-            NumericIndexInner::Immutable(index) => {
-                Box::new(index.values_range(start_bound, end_bound))
-            }
-            NumericIndexInner::Mmap(index) => {
-                Box::new(index.values_range(start_bound, end_bound, hw_counter))
-            }
-        })
+        Some(self.point_ids_by_range(&range, hw_counter))
     }
 
     fn estimate_cardinality(
@@ -1207,6 +1234,101 @@ impl NumericIndexIntoInnerValue<UuidIntType, UuidPayloadType>
     }
 }
 
+impl ValueIndexer for NumericIndex<IpIntType, IpPayloadType> {
+    type ValueType = IpPayloadType;
+
+    fn add_many(
+        &mut self,
+        id: PointOffsetType,
+        values: Vec<Self::ValueType>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        match &mut self.inner {
+            NumericIndexInner::Mutable(index) => {
+                let values: Vec<IpIntType> = values.into_iter().map(ip_to_int).collect();
+                index.add_many_to_list(id, values, hw_counter)
+            }
+            NumericIndexInner::Immutable(_) => Err(OperationError::service_error(
+                "Can't add values to immutable numeric index",
+            )),
+            NumericIndexInner::Mmap(_) => Err(OperationError::service_error(
+                "Can't add values to mmap numeric index",
+            )),
+        }
+    }
+
+    fn get_value(value: &Value) -> Option<Self::ValueType> {
+        IpAddr::from_str(value.as_str()?).ok()
+    }
+
+    fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        self.inner.remove_point(id)
+    }
+}
+
+impl NumericIndexIntoInnerValue<IpIntType, IpPayloadType>
+    for NumericIndex<IpIntType, IpPayloadType>
+{
+    fn into_inner_value(value: IpPayloadType) -> IpIntType {
+        ip_to_int(value)
+    }
+}
+
+impl NumericIndex<IpIntType, IpPayloadType> {
+    /// Range of keys matched by an IP address `condition`: exact address or CIDR network.
+    ///
+    /// Returns `None` if the condition can't be served by IP index.
+    fn ip_condition_range(condition: &FieldCondition) -> Option<Range<IpIntType>> {
+        let keys = match condition.r#match.as_ref()? {
+            Match::Cidr(match_cidr) => match_cidr.ip_range(),
+            Match::Value(MatchValue {
+                value: ValueVariants::String(address),
+            }) => IpAddr::from_str(address).ok().map(|address| {
+                let key = ip_to_int(address);
+                key..=key
+            }),
+            _ => return None,
+        };
+
+        Some(match keys {
+            Some(keys) => Range {
+                lt: None,
+                gt: None,
+                gte: Some(*keys.start()),
+                lte: Some(*keys.end()),
+            },
+            // Malformed address, matches nothing
+            None => Range {
+                lt: Some(IpIntType::MIN),
+                gt: None,
+                gte: None,
+                lte: None,
+            },
+        })
+    }
+
+    pub fn filter_ip<'a>(
+        &'a self,
+        condition: &FieldCondition,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        let range = Self::ip_condition_range(condition)?;
+        Some(self.inner.point_ids_by_range(&range, hw_counter))
+    }
+
+    pub fn estimate_ip_cardinality(
+        &self,
+        condition: &FieldCondition,
+    ) -> Option<CardinalityEstimation> {
+        let range = Self::ip_condition_range(condition)?;
+        let mut cardinality = self.inner.typed_range_cardinality(&range);
+        cardinality
+            .primary_clauses
+            .push(PrimaryCondition::Condition(Box::new(condition.clone())));
+        Some(cardinality)
+    }
+}
+
 impl<T> StreamRange<T> for NumericIndexInner<T>
 where
     T: Encodable + Numericable + MmapValue + Send + Sync + Default,
@@ -110,6 +110,9 @@ impl ShaderBuilderParameters for GpuVectorStorage {
             Distance::Manhattan => {
                 defines.insert("MANHATTAN_DISTANCE".to_owned(), None);
             }
+            Distance::Hamming => {
+                unreachable!("Hamming distance is rejected on GPU storage creation")
+            }
         }
 
         if let Some(quantization) = &self.quantization {
@@ -138,6 +141,12 @@ impl GpuVectorStorage {
         force_half_precision: bool,
         stopped: &AtomicBool,
     ) -> OperationResult<Self> {
+        if vector_storage.distance() == Distance::Hamming {
+            return Err(OperationError::from(gpu::GpuError::NotSupported(
+                "Hamming distance is not supported on GPU".to_string(),
+            )));
+        }
+
         if let Some(quantized_storage) = quantized_storage {
             Self::new_quantized(
                 device,
@@ -433,6 +442,18 @@ impl GpuVectorStorage {
             VectorStorageEnum::DenseAppendableMemmapHalf(vector_storage) => {
                 Self::new_dense_f16(device, vector_storage.as_ref(), stopped)
             }
+            VectorStorageEnum::DenseCustom(vector_storage) => Self::new_dense_f32(
+                device,
+                vector_storage.as_ref(),
+                force_half_precision,
+                stopped,
+            ),
+            VectorStorageEnum::DenseCustomByte(vector_storage) => {
+                Self::new_dense(device, vector_storage.as_ref(), stopped)
+            }
+            VectorStorageEnum::DenseCustomHalf(vector_storage) => {
+                Self::new_dense_f16(device, vector_storage.as_ref(), stopped)
+            }
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => Err(OperationError::from(
                 gpu::GpuError::NotSupported("Sparse vectors are not supported on GPU".to_string()),
@@ -484,6 +505,18 @@ impl GpuVectorStorage {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(vector_storage) => {
                 Self::new_multi_f16(device, vector_storage.as_ref(), stopped)
             }
+            VectorStorageEnum::MultiDenseMemmap(vector_storage) => Self::new_multi_f32(
+                device.clone(),
+                vector_storage.as_ref(),
+                force_half_precision,
+                stopped,
+            ),
+            VectorStorageEnum::MultiDenseMemmapByte(vector_storage) => {
+                Self::new_multi(device, vector_storage.as_ref(), stopped)
+            }
+            VectorStorageEnum::MultiDenseMemmapHalf(vector_storage) => {
+                Self::new_multi_f16(device, vector_storage.as_ref(), stopped)
+            }
         }
     }
 
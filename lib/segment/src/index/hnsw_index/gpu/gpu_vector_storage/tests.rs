@@ -512,6 +512,7 @@ fn get_precision(storage_type: TestStorageType, dim: usize, distance: Distance)
         Distance::Dot => 0.01,
         Distance::Euclid => dim as f32 * 0.001,
         Distance::Manhattan => dim as f32 * 0.001,
+        Distance::Hamming => unreachable!("Hamming distance is not supported on GPU"),
     };
     match storage_type.element_type() {
         TestElementType::Float32 => distance_persision,
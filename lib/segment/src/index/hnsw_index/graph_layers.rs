@@ -8,6 +8,14 @@
 //!   Regular search, as described in the original HNSW paper.
 //!   Usually used on layer 0.
 //!
+//! - [`GraphLayersBase::search_on_level_batch`]
+//!   Runs `search_on_level` for multiple queries in lockstep. Usually used on
+//!   layer 0.
+//!
+//! - [`GraphLayersBase::search_on_level_auto_ef`]
+//!   Variation of `search_on_level` that grows the beam size until results
+//!   stop changing. Usually used on layer 0.
+//!
 //! - [`GraphLayersBase::search_on_level_acorn`]
 //!   Variation of `search_on_level` that implements the ACORN-1 algorithm.
 //!   Usually used on layer 0.
@@ -59,6 +67,7 @@ pub const HNSW_GRAPH_FILE: &str = "graph.bin";
 pub const HNSW_LINKS_FILE: &str = "links.bin";
 pub const COMPRESSED_HNSW_LINKS_FILE: &str = "links_compressed.bin";
 pub const COMPRESSED_WITH_VECTORS_HNSW_LINKS_FILE: &str = "links_comp_vec.bin";
+pub const DELTA_VARINT_HNSW_LINKS_FILE: &str = "links_delta.bin";
 
 /// Contents of the `graph.bin` file.
 #[derive(Deserialize, Serialize, Debug)]
@@ -147,6 +156,155 @@ pub trait GraphLayersBase {
         Ok(search_context.nearest)
     }
 
+    /// Variation of [`GraphLayersBase::search_on_level`] for a batch of queries.
+    ///
+    /// Each step, every query expands its best candidate. Queries expanding the same point read
+    /// its links once. Results are the same as of searching each query separately.
+    ///
+    /// See [module docs](self) for comparison with other search functions.
+    fn search_on_level_batch(
+        &self,
+        level_entries: &[ScoredPointOffset],
+        level: usize,
+        ef: usize,
+        points_scorers: &mut [FilteredScorer],
+        is_stopped: &AtomicBool,
+    ) -> CancellableResult<Vec<FixedLengthPriorityQueue<ScoredPointOffset>>> {
+        debug_assert_eq!(level_entries.len(), points_scorers.len());
+
+        let mut visited_lists = Vec::with_capacity(level_entries.len());
+        let mut search_contexts = Vec::with_capacity(level_entries.len());
+        for &level_entry in level_entries {
+            let mut visited_list = self.get_visited_list_from_pool();
+            visited_list.check_and_update_visited(level_entry.idx);
+            visited_lists.push(visited_list);
+
+            let mut search_context = SearchContext::new(ef);
+            search_context.process_candidate(level_entry);
+            search_contexts.push(search_context);
+        }
+
+        let limit = self.get_m(level);
+        let mut links: Vec<PointOffsetType> = Vec::with_capacity(2 * limit);
+        let mut points_ids: Vec<PointOffsetType> = Vec::with_capacity(2 * limit);
+        // Points expanded in the current step, along with the queries expanding them
+        let mut expanded: Vec<(PointOffsetType, usize)> = Vec::with_capacity(level_entries.len());
+
+        loop {
+            check_process_stopped(is_stopped)?;
+
+            expanded.clear();
+            for (query, search_context) in search_contexts.iter_mut().enumerate() {
+                if let Some(candidate) = search_context.candidates.pop() {
+                    if candidate.score < search_context.lower_bound() {
+                        // Search of this query is done
+                        search_context.candidates.clear();
+                    } else {
+                        expanded.push((candidate.idx, query));
+                    }
+                }
+            }
+            if expanded.is_empty() {
+                break;
+            }
+
+            expanded.sort_unstable();
+            for queries in expanded.chunk_by(|(a, _), (b, _)| a == b) {
+                let point_id = queries[0].0;
+                links.clear();
+                self.for_each_link(point_id, level, |link| links.push(link));
+
+                for &(_, query) in queries {
+                    let visited_list = &mut visited_lists[query];
+                    let search_context = &mut search_contexts[query];
+
+                    points_ids.clear();
+                    points_ids.extend(links.iter().filter(|&&link| !visited_list.check(link)));
+
+                    points_scorers[query]
+                        .score_points(&mut points_ids, limit)
+                        .for_each(|score_point| {
+                            search_context.process_candidate(score_point);
+                            visited_list.check_and_update_visited(score_point.idx);
+                        });
+                }
+            }
+        }
+
+        Ok(search_contexts
+            .into_iter()
+            .map(|search_context| search_context.nearest)
+            .collect())
+    }
+
+    /// Variation of [`GraphLayersBase::search_on_level`] with dynamic ef.
+    ///
+    /// Runs the search with `ef`, then repeatedly doubles ef and continues the
+    /// search until the `top` nearest points stop changing between rounds, or
+    /// ef reaches `max_ef`.
+    ///
+    /// See [module docs](self) for comparison with other search functions.
+    #[allow(clippy::too_many_arguments)]
+    fn search_on_level_auto_ef(
+        &self,
+        level_entry: ScoredPointOffset,
+        level: usize,
+        top: usize,
+        ef: usize,
+        max_ef: usize,
+        points_scorer: &mut FilteredScorer,
+        is_stopped: &AtomicBool,
+    ) -> CancellableResult<FixedLengthPriorityQueue<ScoredPointOffset>> {
+        let mut visited_list = self.get_visited_list_from_pool();
+        visited_list.check_and_update_visited(level_entry.idx);
+
+        let mut ef = ef;
+        let mut search_context = SearchContext::new_growable(ef);
+        search_context.process_candidate(level_entry);
+
+        let limit = self.get_m(level);
+        let mut points_ids: Vec<PointOffsetType> = Vec::with_capacity(2 * limit);
+
+        let mut previous_top_ids = None;
+        loop {
+            while let Some(candidate) = search_context.candidates.pop() {
+                check_process_stopped(is_stopped)?;
+
+                if candidate.score < search_context.lower_bound() {
+                    break;
+                }
+
+                points_ids.clear();
+                self.for_each_link(candidate.idx, level, |link| {
+                    if !visited_list.check(link) {
+                        points_ids.push(link);
+                    }
+                });
+
+                points_scorer
+                    .score_points(&mut points_ids, limit)
+                    .for_each(|score_point| {
+                        search_context.process_candidate(score_point);
+                        visited_list.check_and_update_visited(score_point.idx);
+                    });
+            }
+
+            if ef >= max_ef {
+                break;
+            }
+            let top_ids = search_context.top_ids(top);
+            if previous_top_ids.as_ref() == Some(&top_ids) {
+                break;
+            }
+            previous_top_ids = Some(top_ids);
+
+            ef = ef.saturating_mul(2).min(max_ef);
+            search_context.grow(ef);
+        }
+
+        Ok(search_context.nearest)
+    }
+
     /// Variation of [`GraphLayersBase::search_on_level`] that implements the
     /// ACORN-1 algorithm.
     ///
@@ -527,27 +685,43 @@ impl GraphLayers {
             })
     }
 
-    pub fn search(
+    /// Find the entry point on the zero level, if the graph has any suitable points.
+    fn search_zero_level_entry(
         &self,
-        top: usize,
-        ef: usize,
-        algorithm: SearchAlgorithm,
-        mut points_scorer: FilteredScorer,
+        points_scorer: &mut FilteredScorer,
         custom_entry_points: Option<&[PointOffsetType]>,
         is_stopped: &AtomicBool,
-    ) -> CancellableResult<Vec<ScoredPointOffset>> {
+    ) -> CancellableResult<Option<ScoredPointOffset>> {
         let Some(entry_point) = self.get_entry_point(points_scorer.filters(), custom_entry_points)
         else {
-            return Ok(Vec::default());
+            return Ok(None);
         };
 
         let zero_level_entry = self.search_entry(
             entry_point.point_id,
             entry_point.level,
             0,
-            &mut points_scorer,
+            points_scorer,
             is_stopped,
         )?;
+        Ok(Some(zero_level_entry))
+    }
+
+    pub fn search(
+        &self,
+        top: usize,
+        ef: usize,
+        algorithm: SearchAlgorithm,
+        mut points_scorer: FilteredScorer,
+        custom_entry_points: Option<&[PointOffsetType]>,
+        is_stopped: &AtomicBool,
+    ) -> CancellableResult<Vec<ScoredPointOffset>> {
+        let Some(zero_level_entry) =
+            self.search_zero_level_entry(&mut points_scorer, custom_entry_points, is_stopped)?
+        else {
+            return Ok(Vec::default());
+        };
+
         let ef = max(ef, top);
         let nearest = match algorithm {
             SearchAlgorithm::Hnsw => {
@@ -560,6 +734,75 @@ impl GraphLayers {
         Ok(nearest.into_iter_sorted().take(top).collect_vec())
     }
 
+    /// Search for each of the `points_scorers` at once, see
+    /// [`GraphLayersBase::search_on_level_batch`].
+    ///
+    /// Unlike [`GraphLayers::search`], only the regular HNSW algorithm is supported.
+    pub fn search_batch(
+        &self,
+        top: usize,
+        ef: usize,
+        points_scorers: Vec<FilteredScorer>,
+        custom_entry_points: Option<&[PointOffsetType]>,
+        is_stopped: &AtomicBool,
+    ) -> CancellableResult<Vec<Vec<ScoredPointOffset>>> {
+        let mut results = vec![Vec::new(); points_scorers.len()];
+
+        // Queries without suitable entry points have no results
+        let mut queries = Vec::with_capacity(points_scorers.len());
+        let mut level_entries = Vec::with_capacity(points_scorers.len());
+        let mut searched_scorers = Vec::with_capacity(points_scorers.len());
+        for (query, mut points_scorer) in points_scorers.into_iter().enumerate() {
+            if let Some(zero_level_entry) =
+                self.search_zero_level_entry(&mut points_scorer, custom_entry_points, is_stopped)?
+            {
+                queries.push(query);
+                level_entries.push(zero_level_entry);
+                searched_scorers.push(points_scorer);
+            }
+        }
+
+        let ef = max(ef, top);
+        let nearest =
+            self.search_on_level_batch(&level_entries, 0, ef, &mut searched_scorers, is_stopped)?;
+        for (query, nearest) in queries.into_iter().zip(nearest) {
+            results[query] = nearest.into_iter_sorted().take(top).collect_vec();
+        }
+        Ok(results)
+    }
+
+    /// Search with dynamic ef, see [`GraphLayersBase::search_on_level_auto_ef`].
+    ///
+    /// Unlike [`GraphLayers::search`], only the regular HNSW algorithm is supported.
+    pub fn search_auto_ef(
+        &self,
+        top: usize,
+        ef: usize,
+        max_ef: usize,
+        mut points_scorer: FilteredScorer,
+        custom_entry_points: Option<&[PointOffsetType]>,
+        is_stopped: &AtomicBool,
+    ) -> CancellableResult<Vec<ScoredPointOffset>> {
+        let Some(zero_level_entry) =
+            self.search_zero_level_entry(&mut points_scorer, custom_entry_points, is_stopped)?
+        else {
+            return Ok(Vec::default());
+        };
+
+        let ef = max(ef, top);
+        let max_ef = max(max_ef, ef);
+        let nearest = self.search_on_level_auto_ef(
+            zero_level_entry,
+            0,
+            top,
+            ef,
+            max_ef,
+            &mut points_scorer,
+            is_stopped,
+        )?;
+        Ok(nearest.into_iter_sorted().take(top).collect_vec())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn search_with_vectors(
         &self,
@@ -606,6 +849,7 @@ impl GraphLayers {
             GraphLinksFormat::CompressedWithVectors => {
                 path.join(COMPRESSED_WITH_VECTORS_HNSW_LINKS_FILE)
             }
+            GraphLinksFormat::DeltaVarint => path.join(DELTA_VARINT_HNSW_LINKS_FILE),
         }
     }
 
@@ -641,6 +885,7 @@ impl GraphLayers {
         for format in [
             GraphLinksFormat::CompressedWithVectors,
             GraphLinksFormat::Compressed,
+            GraphLinksFormat::DeltaVarint,
             GraphLinksFormat::Plain,
         ] {
             let path = GraphLayers::get_links_path(dir, format);
@@ -661,8 +906,12 @@ impl GraphLayers {
         let compressed_path = Self::get_links_path(dir, GraphLinksFormat::Compressed);
         let compressed_with_vectors_path =
             Self::get_links_path(dir, GraphLinksFormat::CompressedWithVectors);
+        let delta_varint_path = Self::get_links_path(dir, GraphLinksFormat::DeltaVarint);
 
-        if compressed_path.exists() || compressed_with_vectors_path.exists() {
+        if compressed_path.exists()
+            || compressed_with_vectors_path.exists()
+            || delta_varint_path.exists()
+        {
             return Ok(());
         }
 
@@ -705,6 +954,11 @@ impl GraphLayers {
         .unwrap();
     }
 
+    /// See [`GraphLinks::enable_decode_cache`].
+    pub fn enable_links_decode_cache(&mut self) {
+        self.links.enable_decode_cache();
+    }
+
     pub fn populate(&self) -> OperationResult<()> {
         self.links.populate()?;
         Ok(())
@@ -756,6 +1010,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::delta_varint(GraphLinksFormat::DeltaVarint)]
     fn test_search_on_level(#[case] format: GraphLinksFormat) {
         let dim = 8;
         let hnsw_m = HnswM::new2(8);
@@ -818,6 +1073,7 @@ mod tests {
     #[case::compressed((GraphLinksFormat::Compressed, false))]
     #[case::recompressed((GraphLinksFormat::Compressed, true))]
     #[case::compressed_with_vectors((GraphLinksFormat::CompressedWithVectors, false))]
+    #[case::delta_varint((GraphLinksFormat::DeltaVarint, false))]
     fn test_save_and_load(#[case] (initial_format, compress): (GraphLinksFormat, bool)) {
         let distance = Distance::Cosine;
         let num_vectors = 100;
@@ -866,6 +1122,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::delta_varint(GraphLinksFormat::DeltaVarint)]
     fn test_add_points(#[case] format: GraphLinksFormat) {
         type M = CosineMetric;
         let distance = <M as Metric<VectorElementType>>::distance();
@@ -921,4 +1178,115 @@ mod tests {
 
         assert_eq!(reference_top.into_sorted_vec(), graph_search);
     }
+
+    #[test]
+    fn test_search_batch() {
+        let distance = Distance::Cosine;
+        let num_vectors = 1000;
+        let dim = 8;
+        let top = 5;
+        let ef = 16;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let (vector_holder, graph_layers) = create_graph_layer_fixture(
+            num_vectors,
+            M,
+            dim,
+            GraphLinksFormat::Compressed,
+            false,
+            false,
+            distance,
+            &mut rng,
+        );
+
+        let queries: Vec<_> = (0..10)
+            .map(|_| distance.preprocess_vector::<VectorElementType>(random_vector(&mut rng, dim)))
+            .collect();
+
+        let batch_search = graph_layers
+            .search_batch(
+                top,
+                ef,
+                queries
+                    .iter()
+                    .map(|query| vector_holder.scorer(query.clone()))
+                    .collect(),
+                None,
+                &DEFAULT_STOPPED,
+            )
+            .unwrap();
+
+        assert_eq!(batch_search.len(), queries.len());
+        for (query, batch_result) in queries.iter().zip(batch_search) {
+            let graph_search = search_in_graph(query, top, &vector_holder, &graph_layers);
+            assert_eq!(batch_result, graph_search);
+        }
+    }
+
+    #[test]
+    fn test_search_auto_ef() {
+        let distance = Distance::Cosine;
+        let num_vectors = 1000;
+        let dim = 8;
+        let top = 5;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let (vector_holder, graph_layers) = create_graph_layer_fixture(
+            num_vectors,
+            M,
+            dim,
+            GraphLinksFormat::Compressed,
+            false,
+            false,
+            distance,
+            &mut rng,
+        );
+
+        let query = distance.preprocess_vector::<VectorElementType>(random_vector(&mut rng, dim));
+        let scorer = vector_holder.scorer(query.clone());
+        let mut reference_top = FixedLengthPriorityQueue::new(top);
+        for idx in 0..vector_holder.storage().total_vector_count() as PointOffsetType {
+            let score = scorer.score_point(idx);
+            reference_top.push(ScoredPointOffset { idx, score });
+        }
+
+        // Starting from the smallest beam, ef grows until results are stable
+        let graph_search = graph_layers
+            .search_auto_ef(
+                top,
+                top,
+                num_vectors,
+                vector_holder.scorer(query.clone()),
+                None,
+                &DEFAULT_STOPPED,
+            )
+            .unwrap();
+        assert_eq!(reference_top.into_sorted_vec(), graph_search);
+
+        // Without room to grow, it is the same as the regular search
+        let ef = 8;
+        let auto_ef_search = graph_layers
+            .search_auto_ef(
+                top,
+                ef,
+                ef,
+                vector_holder.scorer(query.clone()),
+                None,
+                &DEFAULT_STOPPED,
+            )
+            .unwrap();
+        let regular_search = graph_layers
+            .search(
+                top,
+                ef,
+                SearchAlgorithm::Hnsw,
+                vector_holder.scorer(query),
+                None,
+                &DEFAULT_STOPPED,
+            )
+            .unwrap();
+        assert_eq!(auto_ef_search, regular_search);
+    }
 }
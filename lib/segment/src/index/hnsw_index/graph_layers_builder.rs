@@ -700,6 +700,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::delta_varint(GraphLinksFormat::DeltaVarint)]
     fn test_parallel_graph_build(#[case] format: GraphLinksFormat) {
         let distance = Distance::Cosine;
         let num_vectors = 1000;
@@ -781,6 +782,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::delta_varint(GraphLinksFormat::DeltaVarint)]
     fn test_add_points(#[case] format: GraphLinksFormat) {
         let distance = Distance::Cosine;
         let num_vectors = 1000;
@@ -824,7 +826,9 @@ mod tests {
             let link_container_from_builder = links_builder.links().to_vec();
             let m = match format {
                 GraphLinksFormat::Plain => 0,
-                GraphLinksFormat::Compressed | GraphLinksFormat::CompressedWithVectors => M * 2,
+                GraphLinksFormat::Compressed
+                | GraphLinksFormat::CompressedWithVectors
+                | GraphLinksFormat::DeltaVarint => M * 2,
             };
             assert_eq!(
                 normalize_links(m, links_orig.clone()),
@@ -893,6 +897,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::delta_varint(GraphLinksFormat::DeltaVarint)]
     fn test_hnsw_graph_properties(#[case] format: GraphLinksFormat) {
         const NUM_VECTORS: usize = 5_000;
         const DIM: usize = 16;
@@ -3,7 +3,7 @@ use std::io::Cursor;
 use std::path::Path;
 use std::sync::Arc;
 
-use common::mmap::{Advice, AdviceSetting, Madviseable, open_read_mmap};
+use common::mmap::{Advice, AdviceSetting, Madviseable, MmapComponent, advice, open_read_mmap};
 use common::types::PointOffsetType;
 use memmap2::Mmap;
 
@@ -57,6 +57,7 @@ pub enum GraphLinksFormat {
     Plain,
     Compressed,
     CompressedWithVectors,
+    DeltaVarint,
 }
 
 /// Similar to [`GraphLinksFormat`], won't let you use `CompressedWithVectors`
@@ -66,6 +67,7 @@ pub enum GraphLinksFormatParam<'a> {
     Plain,
     Compressed,
     CompressedWithVectors(&'a dyn GraphLinksVectors),
+    DeltaVarint,
 }
 
 /// This trait lets the [`serialize_graph_links`] to access vector values.
@@ -154,6 +156,7 @@ impl GraphLinksFormat {
                 Some(v) => GraphLinksFormatParam::CompressedWithVectors(v),
                 None => panic!(),
             },
+            GraphLinksFormat::DeltaVarint => GraphLinksFormatParam::DeltaVarint,
         }
     }
 
@@ -172,12 +175,15 @@ impl GraphLinksFormat {
                 Some(v) => GraphLinksFormatParam::CompressedWithVectors(v),
                 None => GraphLinksFormatParam::Compressed,
             },
+            GraphLinksFormat::DeltaVarint => GraphLinksFormatParam::DeltaVarint,
         }
     }
 
     pub fn is_with_vectors(&self) -> bool {
         match self {
-            GraphLinksFormat::Plain | GraphLinksFormat::Compressed => false,
+            GraphLinksFormat::Plain
+            | GraphLinksFormat::Compressed
+            | GraphLinksFormat::DeltaVarint => false,
             GraphLinksFormat::CompressedWithVectors => true,
         }
     }
@@ -191,6 +197,7 @@ impl<'a> GraphLinksFormatParam<'a> {
             GraphLinksFormatParam::CompressedWithVectors(_) => {
                 GraphLinksFormat::CompressedWithVectors
             }
+            GraphLinksFormatParam::DeltaVarint => GraphLinksFormat::DeltaVarint,
         }
     }
 }
@@ -226,8 +233,18 @@ impl GraphLinks {
         on_disk: bool,
         format: GraphLinksFormat,
     ) -> OperationResult<Self> {
-        let populate = !on_disk;
-        let mmap = open_read_mmap(path, AdviceSetting::Advice(Advice::Random), populate)?;
+        let (advice, populate) = if on_disk {
+            let component = advice::get_component(MmapComponent::GraphLinks);
+            (
+                component.advice_or(AdviceSetting::Advice(Advice::Random)),
+                component.populate_or(false),
+            )
+        } else {
+            // Loaded on first use instead, see `Segment::ensure_vector_loaded`
+            let populate = !common::flags::feature_flags().lazy_vector_loading;
+            (AdviceSetting::Advice(Advice::Random), populate)
+        };
+        let mmap = open_read_mmap(path, advice, populate)?;
         Self::try_new(GraphLinksEnum::Mmap(Arc::new(mmap)), |x| {
             GraphLinksView::load(x.as_bytes(), format)
         })
@@ -262,9 +279,18 @@ impl GraphLinks {
             CompressionInfo::CompressedWithVectors { .. } => {
                 GraphLinksFormat::CompressedWithVectors
             }
+            CompressionInfo::DeltaVarint { .. } => GraphLinksFormat::DeltaVarint,
         }
     }
 
+    /// Keep links decoded on first access in RAM, so they are decoded only once.
+    ///
+    /// Only affects the [`GraphLinksFormat::DeltaVarint`] format, which is slower to decode
+    /// than the others. Trades RAM for search speed.
+    pub fn enable_decode_cache(&mut self) {
+        self.with_dependent_mut(|_, view| view.enable_decode_cache());
+    }
+
     pub fn num_points(&self) -> usize {
         self.view().reindex.len()
     }
@@ -464,6 +490,7 @@ mod tests {
     #[case::comp_vec_1_16(GraphLinksFormat::CompressedWithVectors, 1, 16)]
     #[case::comp_vec_4_1(GraphLinksFormat::CompressedWithVectors, 4, 1)]
     #[case::comp_vec_4_16(GraphLinksFormat::CompressedWithVectors, 4, 16)]
+    #[case::delta_varint(GraphLinksFormat::DeltaVarint, 8, 8)]
     fn test_save_load(
         #[case] format: GraphLinksFormat,
         #[case] base_align: usize,
@@ -491,10 +518,26 @@ mod tests {
         check_links(links, &cmp_links, &vectors);
     }
 
+    #[test]
+    fn test_decode_cache() {
+        let hnsw_m = HnswM::new2(8);
+        let links = random_links(1000, 10, &hnsw_m);
+
+        let mut cmp_links =
+            GraphLinks::new_from_edges(links.clone(), GraphLinksFormatParam::DeltaVarint, hnsw_m)
+                .unwrap();
+        cmp_links.enable_decode_cache();
+
+        // First pass fills the cache, second pass reads from it
+        check_links(links.clone(), &cmp_links, &None);
+        check_links(links, &cmp_links, &None);
+    }
+
     #[rstest]
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::delta_varint(GraphLinksFormat::DeltaVarint)]
     fn test_graph_links_construction(#[case] format: GraphLinksFormat) {
         let hnsw_m = HnswM::new2(8);
 
@@ -1,6 +1,7 @@
 use std::alloc::Layout;
 use std::iter::{Copied, Zip};
 use std::num::NonZero;
+use std::sync::OnceLock;
 
 use common::bitpacking::packed_bits;
 use common::bitpacking_links::{
@@ -8,13 +9,16 @@ use common::bitpacking_links::{
 };
 use common::bitpacking_ordered;
 use common::types::PointOffsetType;
+use common::varint_links::{VarintLinksIterator, iterate_varint_links};
 use integer_encoding::VarInt as _;
 use itertools::{Either, Itertools as _};
 use zerocopy::native_endian::U64 as NativeU64;
 use zerocopy::{FromBytes, Immutable};
 
 use super::GraphLinksFormat;
-use super::header::{HEADER_VERSION_COMPRESSED, HeaderCompressed, HeaderPlain};
+use super::header::{
+    HEADER_VERSION_COMPRESSED, HEADER_VERSION_DELTA_VARINT, HeaderCompressed, HeaderPlain,
+};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::hnsw_index::HnswM;
 use crate::index::hnsw_index::graph_links::header::{
@@ -35,7 +39,10 @@ pub(super) struct GraphLinksView<'a> {
 }
 
 /// An iterator type returned by [`GraphLinksView::links`].
-pub type LinksIterator<'a> = Either<Copied<std::slice::Iter<'a, u32>>, PackedLinksIterator<'a>>;
+pub type LinksIterator<'a> = Either<
+    Copied<std::slice::Iter<'a, u32>>,
+    Either<PackedLinksIterator<'a>, VarintLinksIterator<'a>>,
+>;
 
 /// An iterator type returned by [`super::GraphLinks::links_with_vectors`].
 /// Iterates over pairs of ([`PointOffsetType`], `&[u8]`). The second element is
@@ -104,6 +111,29 @@ pub(super) enum CompressionInfo<'a> {
         link_vector_size: NonZero<usize>,
         link_vector_alignment: u8,
     },
+    DeltaVarint {
+        /// Delta + varint encoded links.
+        ///
+        /// Similar to [`CompressionInfo::Compressed`], but each value is
+        /// varint-encoded instead of being bitpacked.
+        ///
+        /// ```text
+        /// [#vvvvvvvvvvvvvvvvvvv][#vvvvvvvvvvvvvvvvvvv][#vvvvvvvvvvvvvvvvvvv]...
+        /// [neighbors for node 0][neighbors for node 1][neighbors for node 2]...
+        /// ```
+        /// Where:
+        /// 1. `#` is a varint-encoded length.
+        /// 2. `v` are varint-encoded links, first `m` (or `m0`) of them sorted
+        ///    and delta-encoded.
+        ///
+        /// See [`common::varint_links`] for details.
+        neighbors: &'a [u8],
+        offsets: bitpacking_ordered::Reader<'a>,
+        hnsw_m: HnswM,
+        /// Links decoded on first access, one entry per node per level.
+        /// Enabled by [`GraphLinksView::enable_decode_cache`].
+        decode_cache: Option<Box<[OnceLock<Box<[PointOffsetType]>>]>>,
+    },
 }
 
 impl GraphLinksView<'_> {
@@ -115,6 +145,7 @@ impl GraphLinksView<'_> {
             GraphLinksFormat::Compressed => Self::load_compressed(data),
             GraphLinksFormat::Plain => Self::load_plain(data),
             GraphLinksFormat::CompressedWithVectors => Self::load_compressed_with_vectors(data),
+            GraphLinksFormat::DeltaVarint => Self::load_delta_varint(data),
         }
     }
 
@@ -165,6 +196,33 @@ impl GraphLinksView<'_> {
         })
     }
 
+    fn load_delta_varint(data: &[u8]) -> OperationResult<GraphLinksView<'_>> {
+        let (header, data) =
+            HeaderCompressed::ref_from_prefix(data).map_err(|_| error_unsufficent_size())?;
+        debug_assert_eq!(header.version.get(), HEADER_VERSION_DELTA_VARINT);
+        let (level_offsets, data) = read_level_offsets(
+            data,
+            header.levels_count.get(),
+            header.offsets_parameters.length.get(),
+        )?;
+        let (reindex, data) = get_slice::<PointOffsetType>(data, header.point_count.get())?;
+        let (neighbors, data) = get_slice::<u8>(data, header.total_neighbors_bytes.get())?;
+        let (offsets, _bytes) = bitpacking_ordered::Reader::new(header.offsets_parameters, data)
+            .map_err(|e| {
+                OperationError::service_error(format!("Can't create decompressor: {e}"))
+            })?;
+        Ok(GraphLinksView {
+            reindex,
+            compression: CompressionInfo::DeltaVarint {
+                neighbors,
+                offsets,
+                hnsw_m: HnswM::new(header.m.get() as usize, header.m0.get() as usize),
+                decode_cache: None,
+            },
+            level_offsets,
+        })
+    }
+
     fn load_compressed_with_vectors(data: &[u8]) -> OperationResult<GraphLinksView<'_>> {
         let total_len = data.len();
 
@@ -230,7 +288,8 @@ impl GraphLinksView<'_> {
             CompressionInfo::Uncompressed { offsets, .. } => {
                 offsets[idx].get() == offsets[idx + 1].get()
             }
-            CompressionInfo::Compressed { ref offsets, .. } => {
+            CompressionInfo::Compressed { ref offsets, .. }
+            | CompressionInfo::DeltaVarint { ref offsets, .. } => {
                 offsets.get(idx + 1).unwrap() == offsets.get(idx).unwrap()
             }
             CompressionInfo::CompressedWithVectors { .. } => {
@@ -255,15 +314,34 @@ impl GraphLinksView<'_> {
             } => {
                 let neighbors_range =
                     offsets.get(idx).unwrap() as usize..offsets.get(idx + 1).unwrap() as usize;
-                Either::Right(iterate_packed_links(
+                Either::Right(Either::Left(iterate_packed_links(
                     &neighbors[neighbors_range],
                     bits_per_unsorted,
                     hnsw_m.level_m(level),
-                ))
+                )))
             }
             CompressionInfo::CompressedWithVectors { .. } => {
                 // Not intended to be used outside of tests.
-                Either::Right(self.links_with_vectors(point_id, level).1)
+                Either::Right(Either::Left(self.links_with_vectors(point_id, level).1))
+            }
+            CompressionInfo::DeltaVarint {
+                neighbors,
+                ref offsets,
+                ref hnsw_m,
+                ref decode_cache,
+            } => {
+                let neighbors_range =
+                    offsets.get(idx).unwrap() as usize..offsets.get(idx + 1).unwrap() as usize;
+                let links = &neighbors[neighbors_range];
+                let sorted_count = hnsw_m.level_m(level);
+                match decode_cache {
+                    Some(decode_cache) => {
+                        let decoded = decode_cache[idx]
+                            .get_or_init(|| iterate_varint_links(links, sorted_count).collect());
+                        Either::Left(decoded.iter().copied())
+                    }
+                    None => Either::Right(Either::Right(iterate_varint_links(links, sorted_count))),
+                }
             }
         }
     }
@@ -292,6 +370,7 @@ impl GraphLinksView<'_> {
         match self.compression {
             CompressionInfo::Uncompressed { .. } => unimplemented!(),
             CompressionInfo::Compressed { .. } => unimplemented!(),
+            CompressionInfo::DeltaVarint { .. } => unimplemented!(),
             CompressionInfo::CompressedWithVectors {
                 neighbors,
                 ref offsets,
@@ -357,6 +436,19 @@ impl GraphLinksView<'_> {
         }
     }
 
+    /// See [`super::GraphLinks::enable_decode_cache`].
+    pub(super) fn enable_decode_cache(&mut self) {
+        // See the doc comment on `level_offsets`.
+        let total_links_count = *self.level_offsets.last().unwrap() as usize;
+        if let CompressionInfo::DeltaVarint { decode_cache, .. } = &mut self.compression {
+            decode_cache.get_or_insert_with(|| {
+                std::iter::repeat_with(OnceLock::new)
+                    .take(total_links_count)
+                    .collect()
+            });
+        }
+    }
+
     pub(super) fn point_level(&self, point_id: PointOffsetType) -> usize {
         let reindexed_point_id = u64::from(self.reindex[point_id as usize]);
         for (level, (&a, &b)) in self
@@ -380,6 +472,7 @@ impl GraphLinksView<'_> {
             CompressionInfo::Uncompressed { .. } => 0,
             CompressionInfo::Compressed { hnsw_m, .. } => hnsw_m.level_m(level),
             CompressionInfo::CompressedWithVectors { hnsw_m, .. } => hnsw_m.level_m(level),
+            CompressionInfo::DeltaVarint { hnsw_m, .. } => hnsw_m.level_m(level),
         }
     }
 }
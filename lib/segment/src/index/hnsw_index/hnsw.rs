@@ -21,6 +21,7 @@ use itertools::EitherOrBoth;
 use log::{debug, trace};
 use parking_lot::Mutex;
 use rand::Rng;
+use rand::seq::IteratorRandom as _;
 use rayon::ThreadPool;
 use rayon::prelude::*;
 
@@ -38,6 +39,7 @@ use crate::common::operation_time_statistics::{
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorInternal, VectorRef};
 use crate::id_tracker::IdTrackerSS;
+use crate::index::field_index::CardinalityEstimation;
 use crate::index::hnsw_index::HnswM;
 use crate::index::hnsw_index::build_condition_checker::BuildConditionChecker;
 use crate::index::hnsw_index::config::HnswGraphConfig;
@@ -52,14 +54,15 @@ use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
 use crate::index::hnsw_index::graph_layers_healer::GraphLayersHealer;
 use crate::index::hnsw_index::graph_links::{GraphLinksFormatParam, StorageGraphLinksVectors};
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::hnsw_index::tenant_subgraphs::TenantSubgraphs;
 use crate::index::query_estimator::adjust_to_available_vectors;
-use crate::index::sample_estimation::sample_check_cardinality;
+use crate::index::sample_estimation::sample_estimate_cardinality;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::vector_index_search_common::{
     get_oversampled_top, is_quantized_search, postprocess_search_result,
 };
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
-use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
+use crate::index::{PayloadIndex, SearchExplanation, SearchStrategy, VectorIndex, VectorIndexEnum};
 use crate::json_path::JsonPath;
 use crate::payload_storage::FilterContext;
 use crate::segment_constructor::VectorIndexBuildArgs;
@@ -67,7 +70,7 @@ use crate::telemetry::VectorIndexSearchesTelemetry;
 use crate::types::Condition::Field;
 use crate::types::{
     ACORN_MAX_SELECTIVITY_DEFAULT, FieldCondition, Filter, HnswConfig, HnswGlobalConfig,
-    QuantizationSearchParams, SearchParams,
+    HnswLinksCompression, QuantizationSearchParams, SearchParams,
 };
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::query::DiscoveryQuery;
@@ -85,6 +88,16 @@ pub const SINGLE_THREADED_HNSW_BUILD_THRESHOLD: usize = 256;
 
 const LINK_COMPRESSION_CONVERT_EXISTING: bool = false;
 
+/// Filters matching at most this fraction of vectors start graph traversal from points picked
+/// among matching ones, instead of the global entry point.
+const FILTERED_ENTRY_POINTS_MAX_SELECTIVITY: f64 = 0.05;
+
+/// Number of matching points, sampled to pick the entry point from.
+const FILTERED_ENTRY_POINTS_SAMPLE_SIZE: usize = 32;
+
+/// Max number of queries traversing the graph in lockstep, each of them holds a visited list.
+const GRAPH_SEARCH_BATCH_SIZE: usize = 16;
+
 #[derive(Debug)]
 pub struct HNSWIndex {
     id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
@@ -94,6 +107,8 @@ pub struct HNSWIndex {
     config: HnswGraphConfig,
     path: PathBuf,
     graph: GraphLayers,
+    /// Tenants fully linked in `graph`
+    tenant_subgraphs: TenantSubgraphs,
     searches_telemetry: HNSWSearchesTelemetry,
     is_on_disk: bool,
 }
@@ -123,6 +138,25 @@ impl HNSWSearchesTelemetry {
     }
 }
 
+/// Strategy of a filtered search, see [`HNSWIndex::plan_filtered_search`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilteredSearchStrategy {
+    /// Retrieve points matching the filter and score all of them
+    Plain,
+    /// Search the HNSW graph, checking the filter on the way
+    Graph,
+}
+
+/// Query planner output for a filtered search
+#[derive(Debug, Clone)]
+pub struct FilteredSearchPlan {
+    pub strategy: FilteredSearchStrategy,
+    /// Cardinality of the filter among available vectors, the strategy is chosen by
+    pub cardinality: CardinalityEstimation,
+    /// Whether the cardinality was refined by checking a sample of points
+    pub sampled: bool,
+}
+
 pub struct HnswIndexOpenArgs<'a> {
     pub path: &'a Path,
     pub id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
@@ -174,7 +208,11 @@ impl HNSWIndex {
 
         let is_on_disk = hnsw_config.on_disk.unwrap_or(false);
 
-        let graph = GraphLayers::load(path, is_on_disk, do_convert)?;
+        let mut graph = GraphLayers::load(path, is_on_disk, do_convert)?;
+        let tenant_subgraphs = TenantSubgraphs::load(path)?;
+        if hnsw_config.links_compression == Some(HnswLinksCompression::DeltaVarintCached) {
+            graph.enable_links_decode_cache();
+        }
 
         Ok(HNSWIndex {
             id_tracker,
@@ -184,6 +222,7 @@ impl HNSWIndex {
             config,
             path: path.to_owned(),
             graph,
+            tenant_subgraphs,
             searches_telemetry: HNSWSearchesTelemetry::new(),
             is_on_disk,
         })
@@ -275,6 +314,8 @@ impl HNSWIndex {
             config.payload_m.unwrap_or(config.m),
             config.payload_m0.unwrap_or(config.m0),
         );
+        let build_tenant_subgraphs = hnsw_config.tenant_subgraphs.unwrap_or(false);
+        let mut tenant_subgraphs = TenantSubgraphs::default();
 
         // Progress subtasks
         let progress_migrate = build_main_graph.then(|| progress.subtask("migrate"));
@@ -575,6 +616,8 @@ impl HNSWIndex {
                 debug!("building additional index for field {}", &field);
 
                 let is_tenant = payload_index_ref.is_tenant(&field);
+                // Tenants are linked completely, regardless of their size
+                let is_tenant_subgraph = is_tenant && build_tenant_subgraphs;
 
                 // It is expected, that graph will become disconnected less than
                 // $1/m$ points left.
@@ -592,12 +635,13 @@ impl HNSWIndex {
                 for payload_block in payload_index_ref.payload_blocks(&field, full_scan_threshold) {
                     check_process_stopped(stopped)?;
 
-                    if payload_block.cardinality > max_block_size {
+                    // Links of a tenant must be complete, whatever the main graph is
+                    if payload_block.cardinality > max_block_size && !is_tenant_subgraph {
                         continue;
                     }
 
                     let points_to_index = Self::condition_points(
-                        payload_block.condition,
+                        payload_block.condition.clone(),
                         id_tracker_ref.deref(),
                         &payload_index_ref,
                         &vector_storage_ref,
@@ -658,6 +702,9 @@ impl HNSWIndex {
                         &counter,
                     )?;
                     graph_layers_builder.merge_from_other(additional_graph);
+                    if is_tenant_subgraph {
+                        tenant_subgraphs.add(payload_block.condition);
+                    }
                 }
             }
 
@@ -687,13 +734,21 @@ impl HNSWIndex {
                 )
             })
             .flatten();
-        let format_param = match graph_links_vectors.as_ref() {
-            Some(v) => GraphLinksFormatParam::CompressedWithVectors(v),
-            None => GraphLinksFormatParam::Compressed,
+        let links_compression = hnsw_config.links_compression.unwrap_or_default();
+        let format_param = match (graph_links_vectors.as_ref(), links_compression) {
+            (Some(v), _) => GraphLinksFormatParam::CompressedWithVectors(v),
+            (None, HnswLinksCompression::Bitpacking) => GraphLinksFormatParam::Compressed,
+            (None, HnswLinksCompression::DeltaVarint | HnswLinksCompression::DeltaVarintCached) => {
+                GraphLinksFormatParam::DeltaVarint
+            }
         };
 
-        let graph: GraphLayers =
+        let mut graph: GraphLayers =
             graph_layers_builder.into_graph_layers(path, format_param, is_on_disk)?;
+        tenant_subgraphs.save(path)?;
+        if links_compression == HnswLinksCompression::DeltaVarintCached {
+            graph.enable_links_decode_cache();
+        }
 
         #[cfg(debug_assertions)]
         {
@@ -723,6 +778,7 @@ impl HNSWIndex {
             config,
             path: path.to_owned(),
             graph,
+            tenant_subgraphs,
             searches_telemetry: HNSWSearchesTelemetry::new(),
             is_on_disk,
         })
@@ -1007,7 +1063,6 @@ impl HNSWIndex {
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
     fn search_with_graph(
         &self,
         vector: &QueryVector,
@@ -1017,6 +1072,33 @@ impl HNSWIndex {
         custom_entry_points: Option<&[PointOffsetType]>,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let mut results = self.search_batch_with_graph(
+            &[vector],
+            filter,
+            top,
+            params,
+            custom_entry_points,
+            vector_query_context,
+        )?;
+        Ok(results.pop().unwrap_or_default())
+    }
+
+    /// Search the graph for each of `vectors` with the same filter and params.
+    ///
+    /// Work that only depends on the filter is done once for the whole batch: storages are
+    /// borrowed, the filter context is built and its selectivity is estimated a single time.
+    /// Regular HNSW searches traverse the graph in lockstep, in chunks of
+    /// [`GRAPH_SEARCH_BATCH_SIZE`] queries, so links of points expanded by multiple queries
+    /// are read once.
+    fn search_batch_with_graph(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        custom_entry_points: Option<&[PointOffsetType]>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
         let ef = params
             .and_then(|params| params.hnsw_ef)
             .unwrap_or(self.config.ef);
@@ -1042,9 +1124,14 @@ impl HNSWIndex {
         let hw_counter = vector_query_context.hardware_counter();
         let oversampled_top = get_oversampled_top(quantized_vectors.as_ref(), params, top);
 
+        // Graph is connected within a linked tenant, ACORN is not needed to reach its points
+        let is_single_tenant =
+            filter.is_some_and(|filter| self.tenant_subgraphs.is_single_tenant(filter));
+
         let mut algorithm = SearchAlgorithm::Hnsw;
         if acorn_enabled
             && self.config.m0 != 0
+            && !is_single_tenant
             && let Some(filter) = filter
         {
             // NOTE: technically we also might want to use ACORN for unfiltered
@@ -1070,76 +1157,72 @@ impl HNSWIndex {
             }
         }
 
-        let search_with_vectors = || -> OperationResult<Option<Vec<ScoredPointOffset>>> {
-            match algorithm {
-                SearchAlgorithm::Hnsw => (),
-                // ACORN is not implemented for graph with vectors yet (but possible)
-                SearchAlgorithm::Acorn => return Ok(None),
-            }
-            if !self.graph.has_inline_vectors()
-                || !is_quantized_search(quantized_vectors.as_ref(), params)
-            {
-                return Ok(None);
-            }
-            let Some(quantized_vectors) = quantized_vectors.as_ref() else {
-                return Ok(None);
-            };
+        // Shared by all queries of the batch
+        let filter_context = filter.map(|f| payload_index.filter_context(f, &hw_counter));
 
-            // Quantized vectors are "link vectors"
-            let link_scorer_filtered = FilteredScorer::new(
-                vector.to_owned(),
-                &vector_storage,
-                Some(quantized_vectors),
-                filter.map(|f| BoxCow::Owned(payload_index.filter_context(f, &hw_counter))),
-                deleted_points,
-                vector_query_context.hardware_counter(),
-            )?;
-            let Some(link_scorer_filtered_bytes) = link_scorer_filtered.scorer_bytes() else {
-                return Ok(None);
-            };
+        let search_with_vectors =
+            |vector: &QueryVector| -> OperationResult<Option<Vec<ScoredPointOffset>>> {
+                match algorithm {
+                    SearchAlgorithm::Hnsw => (),
+                    // ACORN is not implemented for graph with vectors yet (but possible)
+                    SearchAlgorithm::Acorn => return Ok(None),
+                }
+                if !self.graph.has_inline_vectors()
+                    || !is_quantized_search(quantized_vectors.as_ref(), params)
+                {
+                    return Ok(None);
+                }
+                let Some(quantized_vectors) = quantized_vectors.as_ref() else {
+                    return Ok(None);
+                };
 
-            // Full vectors are "base vectors"
-            let base_scorer = new_raw_scorer(
-                vector.to_owned(),
-                &vector_storage,
-                vector_query_context.hardware_counter(),
-            )?;
-            let Some(base_scorer_bytes) = base_scorer.scorer_bytes() else {
-                return Ok(None);
-            };
+                // Quantized vectors are "link vectors"
+                let link_scorer_filtered = FilteredScorer::new(
+                    vector.to_owned(),
+                    &vector_storage,
+                    Some(quantized_vectors),
+                    filter_context.as_deref().map(BoxCow::Borrowed),
+                    deleted_points,
+                    vector_query_context.hardware_counter(),
+                )?;
+                let Some(link_scorer_filtered_bytes) = link_scorer_filtered.scorer_bytes() else {
+                    return Ok(None);
+                };
 
-            Ok(Some(self.graph.search_with_vectors(
-                top,
-                std::cmp::max(ef, oversampled_top),
-                &link_scorer_filtered,
-                &link_scorer_filtered_bytes,
-                base_scorer_bytes,
-                custom_entry_points,
-                &vector_query_context.is_stopped(),
-            )?))
-        };
+                // Full vectors are "base vectors"
+                let base_scorer = new_raw_scorer(
+                    vector.to_owned(),
+                    &vector_storage,
+                    vector_query_context.hardware_counter(),
+                )?;
+                let Some(base_scorer_bytes) = base_scorer.scorer_bytes() else {
+                    return Ok(None);
+                };
 
-        let regular_search = || -> OperationResult<Vec<ScoredPointOffset>> {
-            let filter_context = filter.map(|f| payload_index.filter_context(f, &hw_counter));
-            let points_scorer = Self::construct_search_scorer(
+                Ok(Some(self.graph.search_with_vectors(
+                    top,
+                    std::cmp::max(ef, oversampled_top),
+                    &link_scorer_filtered,
+                    &link_scorer_filtered_bytes,
+                    base_scorer_bytes,
+                    custom_entry_points,
+                    &vector_query_context.is_stopped(),
+                )?))
+            };
+
+        let construct_scorer = |vector: &QueryVector| {
+            Self::construct_search_scorer(
                 vector,
                 &vector_storage,
                 quantized_vectors.as_ref(),
                 deleted_points,
                 params,
                 vector_query_context.hardware_counter(),
-                filter_context,
-            )?;
-
-            let search_result = self.graph.search(
-                oversampled_top,
-                ef,
-                algorithm,
-                points_scorer,
-                custom_entry_points,
-                &is_stopped,
-            )?;
+                filter_context.as_deref().map(BoxCow::Borrowed),
+            )
+        };
 
+        let postprocess = |vector: &QueryVector, search_result: Vec<ScoredPointOffset>| {
             postprocess_search_result(
                 search_result,
                 id_tracker.deleted_point_bitslice(),
@@ -1152,13 +1235,121 @@ impl HNSWIndex {
             )
         };
 
-        // Try to use graph with vectors first.
-        if let Some(search_result) = search_with_vectors()? {
-            Ok(search_result)
-        } else {
-            // Graph with vectors is not available, fallback to regular graph search.
-            regular_search()
+        let max_ef = params.and_then(|params| params.max_hnsw_ef);
+
+        let regular_search = |vector: &QueryVector| -> OperationResult<Vec<ScoredPointOffset>> {
+            let points_scorer = construct_scorer(vector)?;
+
+            let search_result = match (algorithm, max_ef) {
+                // Dynamic ef, grow the beam until results are stable
+                (SearchAlgorithm::Hnsw, Some(max_ef)) => self.graph.search_auto_ef(
+                    oversampled_top,
+                    params
+                        .and_then(|params| params.hnsw_ef)
+                        .unwrap_or(oversampled_top),
+                    max_ef,
+                    points_scorer,
+                    custom_entry_points,
+                    &is_stopped,
+                )?,
+                _ => self.graph.search(
+                    oversampled_top,
+                    ef,
+                    algorithm,
+                    points_scorer,
+                    custom_entry_points,
+                    &is_stopped,
+                )?,
+            };
+
+            postprocess(vector, search_result)
+        };
+
+        let mut results = Vec::with_capacity(vectors.len());
+        // Queries, which need the regular graph search
+        let mut regular_queries = Vec::new();
+        for &vector in vectors {
+            // Try to use graph with vectors first.
+            if let Some(search_result) = search_with_vectors(vector)? {
+                results.push(search_result);
+            } else {
+                // Graph with vectors is not available, fallback to regular graph search.
+                regular_queries.push(results.len());
+                results.push(Vec::new());
+            }
+        }
+
+        // Dynamic ef and ACORN are not supported by the lockstep traversal
+        if regular_queries.len() == 1 || max_ef.is_some() || algorithm != SearchAlgorithm::Hnsw {
+            for query in regular_queries {
+                results[query] = regular_search(vectors[query])?;
+            }
+            return Ok(results);
+        }
+
+        for queries in regular_queries.chunks(GRAPH_SEARCH_BATCH_SIZE) {
+            let points_scorers = queries
+                .iter()
+                .map(|&query| construct_scorer(vectors[query]))
+                .collect::<OperationResult<Vec<_>>>()?;
+            let search_results = self.graph.search_batch(
+                oversampled_top,
+                ef,
+                points_scorers,
+                custom_entry_points,
+                &is_stopped,
+            )?;
+            for (&query, search_result) in queries.iter().zip(search_results) {
+                results[query] = postprocess(vectors[query], search_result)?;
+            }
         }
+
+        Ok(results)
+    }
+
+    /// Pick candidate entry points among points matching a highly selective `filter`.
+    ///
+    /// With such filters, the global entry point is likely to be far from any matching point,
+    /// and a lot of hops are wasted to reach them. Returns `None` if the filter is not selective
+    /// enough, or matching points can't be retrieved from the payload index cheaply.
+    fn sample_filtered_entry_points(
+        &self,
+        filter: &Filter,
+        plan: &FilteredSearchPlan,
+        hw_counter: &HardwareCounterCell,
+        is_stopped: &AtomicBool,
+    ) -> Option<Vec<PointOffsetType>> {
+        // Without primary clauses, matching points are found with a full scan
+        if plan.cardinality.primary_clauses.is_empty() {
+            return None;
+        }
+
+        let available_vector_count = self.vector_storage.borrow().available_vector_count();
+        if available_vector_count == 0 {
+            return None;
+        }
+
+        let selectivity = plan.cardinality.exp as f64 / available_vector_count as f64;
+        if selectivity > FILTERED_ENTRY_POINTS_MAX_SELECTIVITY {
+            return None;
+        }
+
+        // Points come from the payload index ordered by value or id, sample them uniformly to
+        // spread entry points over the graph. The filter is selective, so iterating over all
+        // matching points is cheap.
+        let payload_index = self.payload_index.borrow();
+        let id_tracker = self.id_tracker.borrow();
+        let entry_points = payload_index
+            .iter_filtered_points(
+                filter,
+                &*id_tracker,
+                &plan.cardinality,
+                hw_counter,
+                is_stopped,
+            )
+            .choose_multiple(&mut rand::rng(), FILTERED_ENTRY_POINTS_SAMPLE_SIZE);
+
+        (!entry_points.is_empty()).then_some(entry_points)
     }
 
     fn search_vectors_with_graph(
@@ -1167,8 +1358,24 @@ impl HNSWIndex {
         filter: Option<&Filter>,
         top: usize,
         params: Option<&SearchParams>,
+        custom_entry_points: Option<&[PointOffsetType]>,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        // Discovery needs its own entry points for each query, other queries are batched
+        if vectors
+            .iter()
+            .all(|vector| !matches!(vector, QueryVector::Discovery(_)))
+        {
+            return self.search_batch_with_graph(
+                vectors,
+                filter,
+                top,
+                params,
+                custom_entry_points,
+                vector_query_context,
+            );
+        }
+
         vectors
             .iter()
             .map(|&vector| match vector {
@@ -1179,9 +1386,14 @@ impl HNSWIndex {
                     params,
                     vector_query_context,
                 ),
-                other => {
-                    self.search_with_graph(other, filter, top, params, None, vector_query_context)
-                }
+                other => self.search_with_graph(
+                    other,
+                    filter,
+                    top,
+                    params,
+                    custom_entry_points,
+                    vector_query_context,
+                ),
             })
             .collect()
     }
@@ -1329,14 +1541,14 @@ impl HNSWIndex {
         deleted_points: &'a BitSlice,
         params: Option<&SearchParams>,
         hardware_counter: HardwareCounterCell,
-        filter_context: Option<Box<dyn FilterContext + 'a>>,
+        filter_context: Option<BoxCow<'a, dyn FilterContext + 'a>>,
     ) -> OperationResult<FilteredScorer<'a>> {
         let quantization_enabled = is_quantized_search(quantized_storage, params);
         FilteredScorer::new(
             vector.to_owned(),
             vector_storage,
             quantization_enabled.then_some(quantized_storage).flatten(),
-            filter_context.map(BoxCow::Owned),
+            filter_context,
             deleted_points,
             hardware_counter,
         )
@@ -1365,6 +1577,84 @@ impl HNSWIndex {
         )
     }
 
+    /// Decide whether a search with the `filter` should use the HNSW graph, or score all
+    /// matching points instead.
+    pub fn plan_filtered_search(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> FilteredSearchPlan {
+        let payload_index = self.payload_index.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let id_tracker = self.id_tracker.borrow();
+        let available_vector_count = vector_storage.available_vector_count();
+
+        let query_point_cardinality = payload_index.estimate_cardinality(filter, hw_counter);
+        let mut cardinality = adjust_to_available_vectors(
+            query_point_cardinality,
+            available_vector_count,
+            id_tracker.available_point_count(),
+        );
+        let mut sampled = false;
+
+        let strategy = if cardinality.max < self.config.full_scan_threshold {
+            // if cardinality is small - use plain index
+            FilteredSearchStrategy::Plain
+        } else if cardinality.min > self.config.full_scan_threshold {
+            // if cardinality is high enough - use HNSW index
+            FilteredSearchStrategy::Graph
+        } else {
+            // Fast cardinality estimation is not enough, do sample estimation of cardinality.
+            // Unlike the payload index, sampling captures correlations between conditions.
+            let filter_context = payload_index.filter_context(filter, hw_counter);
+            let sampled_cardinality = sample_estimate_cardinality(
+                id_tracker.sample_ids(Some(vector_storage.deleted_vector_bitslice())),
+                |idx| filter_context.check(idx),
+                self.config.full_scan_threshold,
+                available_vector_count, // Check cardinality among available vectors
+            );
+            cardinality = sampled_cardinality.refine(cardinality);
+            sampled = true;
+            if cardinality.exp > self.config.full_scan_threshold {
+                FilteredSearchStrategy::Graph
+            } else {
+                FilteredSearchStrategy::Plain
+            }
+        };
+
+        let plan = FilteredSearchPlan {
+            strategy,
+            cardinality,
+            sampled,
+        };
+        trace!("Filtered search plan: {plan:?}");
+        plan
+    }
+
+    /// Add the beam size of a graph search with `params` to the `explanation`
+    fn explain_graph_search(
+        &self,
+        explanation: SearchExplanation,
+        top: usize,
+        params: Option<&SearchParams>,
+    ) -> SearchExplanation {
+        // Same beam size, as in `search_batch_with_graph`
+        let oversampled_top =
+            get_oversampled_top(self.quantized_vectors.borrow().as_ref(), params, top);
+        let hnsw_ef = params.and_then(|params| params.hnsw_ef);
+        let max_ef = params.and_then(|params| params.max_hnsw_ef);
+        let ef = match max_ef {
+            Some(_) => hnsw_ef.unwrap_or(oversampled_top),
+            None => std::cmp::max(hnsw_ef.unwrap_or(self.config.ef), oversampled_top),
+        };
+
+        SearchExplanation {
+            ef: Some(ef),
+            max_ef,
+            ..explanation
+        }
+    }
+
     /// Read underlying data from disk into disk cache.
     pub fn populate(&self) -> OperationResult<()> {
         self.graph.populate()
@@ -1426,6 +1716,7 @@ impl VectorIndex for HNSWIndex {
 
                 // Do plain or graph search
                 if plain_search {
+                    query_context.explain(|| SearchExplanation::new(SearchStrategy::PlainScan));
                     let _timer = ScopeDurationMeasurer::new(if exact {
                         &self.searches_telemetry.exact_unfiltered
                     } else {
@@ -1435,9 +1726,16 @@ impl VectorIndex for HNSWIndex {
                     let params_ref = if exact { exact_params.as_ref() } else { params };
                     self.search_plain_unfiltered_batched(vectors, top, params_ref, query_context)
                 } else {
+                    query_context.explain(|| {
+                        self.explain_graph_search(
+                            SearchExplanation::new(SearchStrategy::Hnsw),
+                            top,
+                            params,
+                        )
+                    });
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_hnsw);
-                    self.search_vectors_with_graph(vectors, None, top, params, query_context)
+                    self.search_vectors_with_graph(vectors, None, top, params, None, query_context)
                 }
             }
             Some(query_filter) => {
@@ -1447,6 +1745,14 @@ impl VectorIndex for HNSWIndex {
 
                 // if exact search is requested, we should not use HNSW index
                 if exact || is_hnsw_disabled {
+                    query_context.explain(|| {
+                        let cardinality = self
+                            .payload_index
+                            .borrow()
+                            .estimate_cardinality(query_filter, &query_context.hardware_counter());
+                        SearchExplanation::new(SearchStrategy::PayloadIndex)
+                            .with_cardinality(&cardinality)
+                    });
                     let _timer = ScopeDurationMeasurer::new(if exact {
                         &self.searches_telemetry.exact_filtered
                     } else {
@@ -1464,66 +1770,45 @@ impl VectorIndex for HNSWIndex {
                     );
                 }
 
-                let payload_index = self.payload_index.borrow();
-                let vector_storage = self.vector_storage.borrow();
-                let id_tracker = self.id_tracker.borrow();
-                let available_vector_count = vector_storage.available_vector_count();
-
                 let hw_counter = query_context.hardware_counter();
-
-                let query_point_cardinality =
-                    payload_index.estimate_cardinality(query_filter, &hw_counter);
-                let query_cardinality = adjust_to_available_vectors(
-                    query_point_cardinality,
-                    available_vector_count,
-                    id_tracker.available_point_count(),
-                );
-
-                if query_cardinality.max < self.config.full_scan_threshold {
-                    // if cardinality is small - use plain index
-                    let _timer =
-                        ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
-                    return self.search_vectors_plain(
-                        vectors,
-                        query_filter,
-                        top,
-                        params,
-                        query_context,
-                    );
-                }
-
-                if query_cardinality.min > self.config.full_scan_threshold {
-                    // if cardinality is high enough - use HNSW index
-                    let _timer =
-                        ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
-                    return self.search_vectors_with_graph(
-                        vectors,
-                        filter,
-                        top,
-                        params,
-                        query_context,
-                    );
-                }
-
-                let filter_context = payload_index.filter_context(query_filter, &hw_counter);
-
-                // Fast cardinality estimation is not enough, do sample estimation of cardinality
-                let id_tracker = self.id_tracker.borrow();
-                if sample_check_cardinality(
-                    id_tracker.sample_ids(Some(vector_storage.deleted_vector_bitslice())),
-                    |idx| filter_context.check(idx),
-                    self.config.full_scan_threshold,
-                    available_vector_count, // Check cardinality among available vectors
-                ) {
-                    // if cardinality is high enough - use HNSW index
-                    let _timer =
-                        ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
-                    self.search_vectors_with_graph(vectors, filter, top, params, query_context)
-                } else {
-                    // if cardinality is small - use plain index
-                    let _timer =
-                        ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
-                    self.search_vectors_plain(vectors, query_filter, top, params, query_context)
+                let plan = self.plan_filtered_search(query_filter, &hw_counter);
+
+                match plan.strategy {
+                    FilteredSearchStrategy::Graph => {
+                        query_context.explain(|| {
+                            self.explain_graph_search(
+                                SearchExplanation::new(SearchStrategy::HnswFiltered)
+                                    .with_filtered_search_plan(&plan),
+                                top,
+                                params,
+                            )
+                        });
+                        let _timer =
+                            ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
+                        let entry_points = self.sample_filtered_entry_points(
+                            query_filter,
+                            &plan,
+                            &hw_counter,
+                            &query_context.is_stopped(),
+                        );
+                        self.search_vectors_with_graph(
+                            vectors,
+                            filter,
+                            top,
+                            params,
+                            entry_points.as_deref(),
+                            query_context,
+                        )
+                    }
+                    FilteredSearchStrategy::Plain => {
+                        query_context.explain(|| {
+                            SearchExplanation::new(SearchStrategy::PayloadIndex)
+                                .with_filtered_search_plan(&plan)
+                        });
+                        let _timer =
+                            ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
+                        self.search_vectors_plain(vectors, query_filter, top, params, query_context)
+                    }
                 }
             }
         }
@@ -1547,6 +1832,7 @@ impl VectorIndex for HNSWIndex {
 
     fn files(&self) -> Vec<PathBuf> {
         let mut files = self.graph.files(&self.path);
+        files.extend(self.tenant_subgraphs.files(&self.path));
         let config_path = HnswGraphConfig::get_config_path(&self.path);
         if config_path.exists() {
             files.push(config_path);
@@ -12,6 +12,7 @@ pub mod hnsw;
 mod links_container;
 pub mod point_scorer;
 mod search_context;
+mod tenant_subgraphs;
 
 #[cfg(feature = "gpu")]
 pub mod gpu;
@@ -1,7 +1,7 @@
 use std::collections::BinaryHeap;
 
 use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
-use common::types::{ScoreType, ScoredPointOffset};
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
 use num_traits::float::FloatCore;
 
 /// Structure that holds context of the search
@@ -10,6 +10,9 @@ pub struct SearchContext {
     pub nearest: FixedLengthPriorityQueue<ScoredPointOffset>,
     /// Current candidates to process
     pub candidates: BinaryHeap<ScoredPointOffset>,
+    /// Points which didn't fit into `nearest`, to reconsider them once ef grows.
+    /// Only collected by contexts created with [`SearchContext::new_growable`].
+    discarded: Option<Vec<ScoredPointOffset>>,
 }
 
 impl SearchContext {
@@ -17,6 +20,15 @@ impl SearchContext {
         SearchContext {
             nearest: FixedLengthPriorityQueue::new(ef),
             candidates: BinaryHeap::new(),
+            discarded: None,
+        }
+    }
+
+    /// Create a context, which ef can be increased with [`SearchContext::grow`]
+    pub fn new_growable(ef: usize) -> Self {
+        SearchContext {
+            discarded: Some(Vec::new()),
+            ..Self::new(ef)
         }
     }
 
@@ -32,10 +44,47 @@ impl SearchContext {
     pub fn process_candidate(&mut self, score_point: ScoredPointOffset) {
         let was_added = match self.nearest.push(score_point) {
             None => true,
-            Some(removed) => removed.idx != score_point.idx,
+            Some(removed) => {
+                if let Some(discarded) = self.discarded.as_mut() {
+                    discarded.push(removed);
+                }
+                removed.idx != score_point.idx
+            }
         };
         if was_added {
             self.candidates.push(score_point);
         }
     }
+
+    /// Increase ef of the search, so the search can continue further.
+    ///
+    /// Must be called once the search has converged, i.e. when all remaining candidates are
+    /// worse than [`SearchContext::lower_bound`]. Points which didn't fit into `nearest` so far
+    /// are considered again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the context is not created with [`SearchContext::new_growable`].
+    pub fn grow(&mut self, ef: usize) {
+        let discarded = self
+            .discarded
+            .replace(Vec::new())
+            .expect("search context must be growable");
+
+        // All remaining candidates are discarded too, avoid processing them twice
+        self.candidates.clear();
+        self.nearest.set_length(ef);
+        for score_point in discarded {
+            self.process_candidate(score_point);
+        }
+    }
+
+    /// Ids of the `top` nearest points found so far, sorted by id
+    pub fn top_ids(&self, top: usize) -> Vec<PointOffsetType> {
+        let mut nearest: Vec<_> = self.nearest.iter_unsorted().copied().collect();
+        nearest.sort_unstable_by(|a, b| b.cmp(a));
+        let mut ids: Vec<_> = nearest.iter().take(top).map(|point| point.idx).collect();
+        ids.sort_unstable();
+        ids
+    }
 }
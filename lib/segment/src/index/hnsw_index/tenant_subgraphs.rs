@@ -0,0 +1,151 @@
+use std::path::{Path, PathBuf};
+
+use common::fs::{atomic_save_json, read_json};
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::OperationResult;
+use crate::types::{Condition, FieldCondition, Filter};
+
+pub const TENANT_SUBGRAPHS_CONFIG_FILE: &str = "tenant_subgraphs.json";
+
+/// Tenants, which points are fully linked with each other in the HNSW graph.
+///
+/// Links of each tenant are built as an additional graph and merged into the main graph, together
+/// with its entry points, same as for other payload blocks. Unlike other payload blocks, tenants
+/// are linked regardless of their size, so the graph is connected within a single tenant and
+/// a search restricted to it doesn't need to look beyond the direct links.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct TenantSubgraphs {
+    tenants: Vec<FieldCondition>,
+}
+
+impl TenantSubgraphs {
+    fn get_config_path(path: &Path) -> PathBuf {
+        path.join(TENANT_SUBGRAPHS_CONFIG_FILE)
+    }
+
+    pub fn add(&mut self, tenant: FieldCondition) {
+        self.tenants.push(tenant);
+    }
+
+    /// Persist the list of linked tenants into the index directory at `path`.
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        if self.tenants.is_empty() {
+            return Ok(());
+        }
+        atomic_save_json(&Self::get_config_path(path), self)?;
+        Ok(())
+    }
+
+    /// Load the list of linked tenants from the index directory at `path`, if any were built.
+    pub fn load(path: &Path) -> OperationResult<Self> {
+        let config_path = Self::get_config_path(path);
+        if !config_path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(read_json(&config_path)?)
+    }
+
+    /// Whether all points matching `filter` belong to a single linked tenant.
+    ///
+    /// The tenant condition may be anywhere in the conjunctive part of the filter: in `must`,
+    /// in a nested filter of `must`, or as the only `should` condition.
+    pub fn is_single_tenant(&self, filter: &Filter) -> bool {
+        self.tenants
+            .iter()
+            .any(|tenant| is_required_condition(filter, tenant))
+    }
+
+    pub fn files(&self, path: &Path) -> Vec<PathBuf> {
+        if self.tenants.is_empty() {
+            return Vec::new();
+        }
+        vec![Self::get_config_path(path)]
+    }
+}
+
+/// Whether every point matching `filter` must satisfy `required`
+fn is_required_condition(filter: &Filter, required: &FieldCondition) -> bool {
+    let is_required = |condition: &Condition| match condition {
+        Condition::Field(field) => field == required,
+        Condition::Filter(filter) => is_required_condition(filter, required),
+        _ => false,
+    };
+
+    let in_must = filter
+        .must
+        .as_ref()
+        .is_some_and(|must| must.iter().any(is_required));
+
+    let in_should = match filter.should.as_deref() {
+        Some([condition]) => is_required(condition),
+        _ => false,
+    };
+
+    let in_min_should = filter.min_should.as_ref().is_some_and(|min_should| {
+        min_should.min_count > 0
+            && min_should.conditions.len() == 1
+            && is_required(&min_should.conditions[0])
+    });
+
+    in_must || in_should || in_min_should
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_path::JsonPath;
+    use crate::types::MinShould;
+
+    fn tenant(name: &str) -> FieldCondition {
+        FieldCondition::new_match(JsonPath::new("tenant"), name.to_string().into())
+    }
+
+    #[test]
+    fn test_tenant_condition_anywhere_in_conjunction() {
+        let mut subgraphs = TenantSubgraphs::default();
+        subgraphs.add(tenant("a"));
+
+        let other = Condition::Field(FieldCondition::new_match(
+            JsonPath::new("color"),
+            "red".to_string().into(),
+        ));
+
+        let must = Filter::new_must(Condition::Field(tenant("a")));
+        assert!(subgraphs.is_single_tenant(&must));
+
+        let nested = Filter {
+            must: Some(vec![
+                other.clone(),
+                Condition::Filter(Filter::new_must(Condition::Field(tenant("a")))),
+            ]),
+            ..Default::default()
+        };
+        assert!(subgraphs.is_single_tenant(&nested));
+
+        let single_should = Filter::new_should(Condition::Field(tenant("a")));
+        assert!(subgraphs.is_single_tenant(&single_should));
+
+        let min_should = Filter {
+            min_should: Some(MinShould {
+                conditions: vec![Condition::Field(tenant("a"))],
+                min_count: 1,
+            }),
+            ..Default::default()
+        };
+        assert!(subgraphs.is_single_tenant(&min_should));
+
+        // Points of other tenants may match
+        let either = Filter {
+            should: Some(vec![Condition::Field(tenant("a")), other]),
+            ..Default::default()
+        };
+        assert!(!subgraphs.is_single_tenant(&either));
+
+        let excluded = Filter::new_must_not(Condition::Field(tenant("a")));
+        assert!(!subgraphs.is_single_tenant(&excluded));
+
+        let unknown = Filter::new_must(Condition::Field(tenant("b")));
+        assert!(!subgraphs.is_single_tenant(&unknown));
+    }
+}
@@ -55,6 +55,7 @@ fn search_in_builder(
 #[case::uncompressed(GraphLinksFormat::Plain)]
 #[case::compressed(GraphLinksFormat::Compressed)]
 #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+#[case::delta_varint(GraphLinksFormat::DeltaVarint)]
 fn test_compact_graph_layers(#[case] format: GraphLinksFormat) {
     let num_vectors = 1000;
     let num_queries = 100;
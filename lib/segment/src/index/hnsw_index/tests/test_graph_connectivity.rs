@@ -61,6 +61,8 @@ fn test_graph_connectivity() {
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
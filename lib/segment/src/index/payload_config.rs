@@ -170,6 +170,7 @@ pub enum PayloadIndexType {
     BoolIndex,
     UuidIndex,
     UuidMapIndex,
+    IpIndex,
     NullIndex,
 }
 
@@ -80,6 +80,7 @@ impl PayloadIndex for PlainPayloadIndex {
         &self,
         _field: PayloadKeyTypeRef,
         _payload_schema: &PayloadFieldSchema,
+        _is_stopped: &AtomicBool,
         _hw_counter: &HardwareCounterCell,
     ) -> OperationResult<BuildIndexResult> {
         Ok(BuildIndexResult::AlreadyBuilt) // No index to build
@@ -19,7 +19,7 @@ use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::vector_index_search_common::{
     get_oversampled_top, is_quantized_search, postprocess_search_result,
 };
-use crate::index::{PayloadIndex, VectorIndex};
+use crate::index::{PayloadIndex, SearchExplanation, SearchStrategy, VectorIndex};
 use crate::telemetry::VectorIndexSearchesTelemetry;
 use crate::types::{Filter, SearchParams};
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
@@ -136,10 +136,18 @@ impl VectorIndex for PlainVectorIndex {
         let mut search_results = match filter {
             Some(filter) => {
                 let payload_index = self.payload_index.borrow();
+                query_context.explain(|| {
+                    let cardinality = payload_index.estimate_cardinality(filter, &hw_counter);
+                    SearchExplanation::new(SearchStrategy::PayloadIndex)
+                        .with_cardinality(&cardinality)
+                });
                 let filtered_ids_vec = payload_index.query_points(filter, &hw_counter, &is_stopped);
                 batch_searcher.peek_top_iter(&mut filtered_ids_vec.iter().copied(), &is_stopped)?
             }
-            None => batch_searcher.peek_top_all(&is_stopped)?,
+            None => {
+                query_context.explain(|| SearchExplanation::new(SearchStrategy::PlainScan));
+                batch_searcher.peek_top_all(&is_stopped)?
+            }
         };
 
         for (search_result, query_vector) in search_results.iter_mut().zip(query_vectors) {
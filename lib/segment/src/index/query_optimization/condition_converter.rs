@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::ops::Deref;
 
 use ahash::AHashSet;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
@@ -15,7 +16,7 @@ use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::payload_storage::query_checker::{
     check_field_condition, check_is_empty_condition, check_is_null_condition, check_payload,
-    select_nested_indexes,
+    check_point_attribute_condition, select_nested_indexes,
 };
 use crate::types::{
     Condition, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoPolygon,
@@ -35,6 +36,39 @@ impl StructPayloadIndex {
         let id_tracker = self.id_tracker.borrow();
         let field_indexes = &self.field_indexes;
         match condition {
+            // Use dedicated null index for `values_count` check if it is available.
+            // Other indexes only count values of their own type and might deduplicate them.
+            Condition::Field(
+                field_condition @ FieldCondition {
+                    values_count: Some(values_count),
+                    ..
+                },
+            ) => {
+                let hw = hw_counter.fork();
+                let fallback: ConditionCheckerFn = Box::new(move |point_id| {
+                    payload_provider.with_payload(
+                        point_id,
+                        |payload| {
+                            check_field_condition(field_condition, &payload, field_indexes, &hw)
+                        },
+                        &hw,
+                    )
+                });
+
+                let null_index = field_indexes
+                    .get(&field_condition.key)
+                    .and_then(|indexes| get_values_count_index(indexes));
+
+                if let Some(null_index) = null_index {
+                    Box::new(move |point_id| {
+                        null_index
+                            .check_values_count(point_id, values_count)
+                            .unwrap_or_else(|| fallback(point_id))
+                    })
+                } else {
+                    fallback
+                }
+            }
             Condition::Field(field_condition) => field_indexes
                 .get(&field_condition.key)
                 .and_then(|indexes| {
@@ -127,6 +161,17 @@ impl StructPayloadIndex {
                     Box::new(|_point_id| false)
                 }
             }
+            Condition::Attribute(attribute) => {
+                let vector_storages = &self.vector_storages;
+                Box::new(move |point_id| {
+                    check_point_attribute_condition(
+                        attribute,
+                        self.id_tracker.borrow().deref(),
+                        vector_storages,
+                        point_id,
+                    )
+                })
+            }
             Condition::Nested(nested) => {
                 // Select indexes for nested fields. Trim nested part from key, so
                 // that nested condition can address fields without nested part.
@@ -244,10 +289,10 @@ pub fn field_condition_index<'a>(
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
-            // We can't use index for this condition, since some indices don't count values,
+            // We can't use value indices for this condition, since some indices don't count values,
             // like boolean index, where [true, true, true] is the same as [true]. Count should be 3 but they think is 1.
             //
-            // TODO: Try to use the indices that actually support counting values.
+            // Null index counts all values, it is used for this condition in the condition converter.
             values_count: _,
             is_empty: None,
             is_null: None,
@@ -276,6 +321,7 @@ pub fn get_geo_polygon_checkers(
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
+        | FieldIndex::IpIndex(_)
         | FieldIndex::UuidMapIndex(_)
         | FieldIndex::NullIndex(_) => None,
     }
@@ -299,6 +345,7 @@ pub fn get_geo_radius_checkers(
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
+        | FieldIndex::IpIndex(_)
         | FieldIndex::UuidMapIndex(_)
         | FieldIndex::NullIndex(_) => None,
     }
@@ -324,6 +371,7 @@ pub fn get_geo_bounding_box_checkers(
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
+        | FieldIndex::IpIndex(_)
         | FieldIndex::UuidMapIndex(_)
         | FieldIndex::NullIndex(_) => None,
     }
@@ -367,6 +415,7 @@ pub fn get_float_range_checkers(
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
+        | FieldIndex::IpIndex(_)
         | FieldIndex::UuidMapIndex(_)
         | FieldIndex::NullIndex(_) => None,
     }
@@ -393,11 +442,19 @@ pub fn get_datetime_range_checkers(
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
+        | FieldIndex::IpIndex(_)
         | FieldIndex::UuidMapIndex(_)
         | FieldIndex::NullIndex(_) => None,
     }
 }
 
+fn get_values_count_index(indexes: &[FieldIndex]) -> Option<&MutableNullIndex> {
+    indexes.iter().find_map(|index| match index {
+        FieldIndex::NullIndex(null_index) if null_index.counts_values() => Some(null_index),
+        _ => None,
+    })
+}
+
 fn get_is_empty_indexes(
     indexes: &[FieldIndex],
 ) -> (Option<&MutableNullIndex>, Option<&FieldIndex>) {
@@ -460,6 +517,7 @@ fn get_is_empty_checker(index: &FieldIndex, is_empty: bool) -> Option<ConditionC
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::BoolIndex(_)
         | FieldIndex::UuidIndex(_)
+        | FieldIndex::IpIndex(_)
         | FieldIndex::UuidMapIndex(_) => None,
     }
 }
@@ -478,6 +536,7 @@ fn get_is_null_checker(index: &FieldIndex, is_null: bool) -> Option<ConditionChe
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::BoolIndex(_)
         | FieldIndex::UuidIndex(_)
+        | FieldIndex::IpIndex(_)
         | FieldIndex::UuidMapIndex(_) => None,
     }
 }
@@ -1,3 +1,6 @@
+use std::net::IpAddr;
+use std::str::FromStr;
+
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::types::PointOffsetType;
 use indexmap::IndexSet;
@@ -7,8 +10,8 @@ use crate::index::field_index::FieldIndex;
 use crate::index::query_optimization::optimized_filter::ConditionCheckerFn;
 use crate::payload_storage::condition_checker::INDEXSET_ITER_THRESHOLD;
 use crate::types::{
-    AnyVariants, Match, MatchAny, MatchExcept, MatchPhrase, MatchText, MatchTextAny, MatchValue,
-    ValueVariants,
+    AnyVariants, Match, MatchAny, MatchCidr, MatchExcept, MatchPhrase, MatchText, MatchTextAny,
+    MatchValue, ValueVariants, ip_to_int,
 };
 
 pub fn get_match_checkers(
@@ -29,6 +32,36 @@ pub fn get_match_checkers(
         }
         Match::Any(MatchAny { any }) => get_match_any_checker(any, index, hw_acc),
         Match::Except(MatchExcept { except }) => get_match_except_checker(except, index, hw_acc),
+        Match::Cidr(match_cidr) => get_match_cidr_checker(match_cidr, index, hw_acc),
+    }
+}
+
+fn get_match_cidr_checker(
+    match_cidr: MatchCidr,
+    index: &FieldIndex,
+    hw_acc: HwMeasurementAcc,
+) -> Option<ConditionCheckerFn<'_>> {
+    match index {
+        FieldIndex::IpIndex(ip_index) => {
+            let Some(range) = match_cidr.ip_range() else {
+                return Some(Box::new(|_| false));
+            };
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                ip_index.check_values_any(point_id, |key| range.contains(key), &hw_counter)
+            }))
+        }
+        FieldIndex::BoolIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::KeywordIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_)
+        | FieldIndex::NullIndex(_) => None,
     }
 }
 
@@ -51,6 +84,16 @@ fn get_match_value_checker(
                 index.check_values_any(point_id, &hw_counter, |i| i == &uuid)
             }))
         }
+        (ValueVariants::String(value), FieldIndex::IpIndex(index)) => {
+            let Ok(address) = IpAddr::from_str(&value) else {
+                return Some(Box::new(|_| false));
+            };
+            let key = ip_to_int(address);
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_values_any(point_id, |i| *i == key, &hw_counter)
+            }))
+        }
         (ValueVariants::Integer(value), FieldIndex::IntMapIndex(index)) => {
             let hw_counter = hw_acc.get_counter_cell();
             Some(Box::new(move |point_id: PointOffsetType| {
@@ -71,6 +114,7 @@ fn get_match_value_checker(
         | (ValueVariants::Bool(_), FieldIndex::IntMapIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::KeywordIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::UuidIndex(_))
+        | (ValueVariants::Bool(_), FieldIndex::IpIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::UuidMapIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::NullIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::BoolIndex(_))
@@ -81,6 +125,7 @@ fn get_match_value_checker(
         | (ValueVariants::Integer(_), FieldIndex::IntIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::KeywordIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::UuidIndex(_))
+        | (ValueVariants::Integer(_), FieldIndex::IpIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::UuidMapIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::NullIndex(_))
         | (ValueVariants::String(_), FieldIndex::BoolIndex(_))
@@ -157,6 +202,7 @@ fn get_match_any_checker(
         | (AnyVariants::Integers(_), FieldIndex::IntIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::KeywordIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::UuidIndex(_))
+        | (AnyVariants::Integers(_), FieldIndex::IpIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::UuidMapIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::NullIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::BoolIndex(_))
@@ -167,6 +213,7 @@ fn get_match_any_checker(
         | (AnyVariants::Strings(_), FieldIndex::IntIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::IntMapIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::UuidIndex(_))
+        | (AnyVariants::Strings(_), FieldIndex::IpIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::NullIndex(_)) => None,
     }
 }
@@ -232,6 +279,7 @@ fn get_match_except_checker(
         | (AnyVariants::Strings(_), FieldIndex::FullTextIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::BoolIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::UuidIndex(_))
+        | (AnyVariants::Strings(_), FieldIndex::IpIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::NullIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::IntIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::DatetimeIndex(_))
@@ -241,6 +289,7 @@ fn get_match_except_checker(
         | (AnyVariants::Integers(_), FieldIndex::FullTextIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::BoolIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::UuidIndex(_))
+        | (AnyVariants::Integers(_), FieldIndex::IpIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::UuidMapIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::NullIndex(_)) => None,
     };
@@ -292,6 +341,7 @@ fn get_match_text_checker(
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
+        | FieldIndex::IpIndex(_)
         | FieldIndex::UuidMapIndex(_)
         | FieldIndex::NullIndex(_) => None,
     }
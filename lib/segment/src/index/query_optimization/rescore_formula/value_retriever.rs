@@ -10,7 +10,7 @@ use crate::index::field_index::FieldIndex;
 use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::json_path::JsonPath;
-use crate::types::{DateTimePayloadType, PayloadContainer, UuidPayloadType};
+use crate::types::{DateTimePayloadType, PayloadContainer, UuidPayloadType, int_to_ip};
 
 pub type VariableRetrieverFn<'a> = Box<dyn Fn(PointOffsetType) -> MultiValue<Value> + 'a>;
 
@@ -206,6 +206,17 @@ fn indexed_variable_retriever(index: &FieldIndex) -> Option<VariableRetrieverFn<
             };
             Some(Box::new(extract_fn))
         }
+        FieldIndex::IpIndex(ip_index) => {
+            let extract_fn = move |point_id: PointOffsetType| -> MultiValue<Value> {
+                ip_index
+                    .get_values(point_id)
+                    .into_iter()
+                    .flatten()
+                    .map(|value| Value::String(int_to_ip(value).to_string()))
+                    .collect()
+            };
+            Some(Box::new(extract_fn))
+        }
         FieldIndex::FullTextIndex(_) => None, // Better get it from the payload
         FieldIndex::NullIndex(_) => None,     // There should be other index for the same field
     }
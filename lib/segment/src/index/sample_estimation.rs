@@ -2,6 +2,8 @@ use std::cmp::{max, min};
 
 use common::types::PointOffsetType;
 
+use crate::index::field_index::CardinalityEstimation;
+
 const MAX_ESTIMATED_POINTS: usize = 1000;
 
 /// How many points do we need to check in order to estimate expected query cardinality.
@@ -29,38 +31,73 @@ fn confidence_agresti_coull_interval(trials: usize, positive: usize, total: usiz
     (expected, delta)
 }
 
-/// Tests if given `query` have cardinality higher than the `threshold`
-/// Iteratively samples points until the decision could be made with confidence
-pub fn sample_check_cardinality(
+/// Cardinality of a query, estimated by checking a sample of points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampledCardinality {
+    /// Lower bound of the confidence interval
+    pub min: usize,
+    pub exp: usize,
+    /// Upper bound of the confidence interval
+    pub max: usize,
+}
+
+impl SampledCardinality {
+    /// Narrow down the `estimation` of the payload index with the sampled one.
+    ///
+    /// Bounds of the payload index are guaranteed, so the sampled interval is clamped to them.
+    pub fn refine(self, estimation: CardinalityEstimation) -> CardinalityEstimation {
+        let CardinalityEstimation {
+            primary_clauses,
+            min,
+            exp: _,
+            max,
+        } = estimation;
+
+        let refined_min = self.min.clamp(min, max);
+        let refined_max = self.max.clamp(min, max);
+        CardinalityEstimation {
+            primary_clauses,
+            min: refined_min,
+            exp: self.exp.clamp(refined_min, refined_max),
+            max: refined_max,
+        }
+    }
+}
+
+/// Estimates cardinality of the `query` by checking a sample of points.
+///
+/// Sampling stops once the confidence interval is entirely above or below the `threshold`, as
+/// that is enough to decide how to execute the query. Unlike estimations based on the payload
+/// index, it captures correlations between conditions of the query.
+pub fn sample_estimate_cardinality(
     sample_points: impl Iterator<Item = PointOffsetType>,
     checker: impl Fn(PointOffsetType) -> bool,
     threshold: usize,
     total_points: usize,
-) -> bool {
+) -> SampledCardinality {
     let mut matched_points = 0;
     let mut total_checked = 0;
 
     let mut exp = 0;
-    let mut interval;
+    let mut interval = total_points as i64;
     for idx in sample_points.take(MAX_ESTIMATED_POINTS) {
         matched_points += usize::from(checker(idx));
         total_checked += 1;
 
-        let estimation =
+        (exp, interval) =
             confidence_agresti_coull_interval(total_checked, matched_points, total_points);
-        exp = estimation.0;
-        interval = estimation.1;
 
-        if exp - interval > threshold as i64 {
-            return true;
-        }
-
-        if exp + interval < threshold as i64 {
-            return false;
+        if exp - interval > threshold as i64 || exp + interval < threshold as i64 {
+            break;
         }
     }
 
-    exp > threshold as i64
+    let clamp = |value: i64| value.clamp(0, total_points as i64) as usize;
+    SampledCardinality {
+        min: clamp(exp - interval),
+        exp: clamp(exp),
+        max: clamp(exp + interval),
+    }
 }
 
 #[cfg(test)]
@@ -92,14 +129,59 @@ mod tests {
     }
 
     #[test]
-    fn test_sample_check_cardinality() {
-        let res = sample_check_cardinality(
+    fn test_sample_estimate_cardinality() {
+        let estimation = sample_estimate_cardinality(
             vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12].into_iter(),
             |idx| idx % 2 == 0,
             10_000,
             100_000,
         );
+        assert!(estimation.exp > 10_000);
+        assert!(estimation.min <= estimation.exp && estimation.exp <= estimation.max);
+
+        // Sampling stops once the interval is above the threshold
+        let checked = std::cell::Cell::new(0);
+        let estimation = sample_estimate_cardinality(
+            (0..10_000).step_by(7),
+            |idx| {
+                checked.set(checked.get() + 1);
+                idx % 4 == 0
+            },
+            1_000,
+            100_000,
+        );
+        assert!(estimation.min > 1_000, "{estimation:?}");
+        assert!(checked.get() < MAX_ESTIMATED_POINTS);
 
-        assert!(res)
+        // Without samples nothing is known
+        let estimation = sample_estimate_cardinality(std::iter::empty(), |_| true, 0, 100_000);
+        assert_eq!((estimation.min, estimation.max), (0, 100_000));
+    }
+
+    #[test]
+    fn test_refine_estimation() {
+        let estimation = CardinalityEstimation {
+            primary_clauses: vec![],
+            min: 10,
+            exp: 50,
+            max: 100,
+        };
+
+        let sampled = SampledCardinality {
+            min: 80,
+            exp: 90,
+            max: 95,
+        };
+        let refined = sampled.refine(estimation.clone());
+        assert_eq!((refined.min, refined.exp, refined.max), (80, 90, 95));
+
+        // Bounds of the index are respected
+        let sampled = SampledCardinality {
+            min: 300,
+            exp: 400,
+            max: 500,
+        };
+        let refined = sampled.refine(estimation);
+        assert_eq!((refined.min, refined.exp, refined.max), (100, 100, 100));
     }
 }
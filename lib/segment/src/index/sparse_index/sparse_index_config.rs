@@ -1,8 +1,10 @@
 use std::path::{Path, PathBuf};
 
 use common::fs::{atomic_save_json, read_json};
+use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
 use crate::common::anonymize::Anonymize;
 use crate::common::operation_error::OperationResult;
@@ -42,6 +44,52 @@ impl SparseIndexType {
     }
 }
 
+/// Similarity function used to score sparse vectors
+#[derive(
+    Default, Hash, Debug, Deserialize, Serialize, JsonSchema, Anonymize, Eq, PartialEq, Copy, Clone,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SparseSimilarity {
+    /// Dot product of weights
+    #[default]
+    Dot,
+    /// Weighted Jaccard similarity: sum of minimum weights over sum of maximum weights of each
+    /// dimension, negative weights are treated as zero.
+    /// For binary weights it is the size of intersection over the size of union of dimensions.
+    Jaccard,
+}
+
+/// Pruning of posting lists, applied when building an immutable sparse index.
+///
+/// Bounds the size of the index for vectors with heavy-tailed dimensions, such as SPLADE.
+/// Pruned postings are not found through the index, which may reduce recall.
+#[derive(
+    Debug,
+    Deserialize,
+    Serialize,
+    JsonSchema,
+    Anonymize,
+    Validate,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+)]
+#[serde(rename_all = "snake_case")]
+#[anonymize(false)]
+pub struct SparsePruningConfig {
+    /// Drop postings with absolute weight below this value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0))]
+    pub min_weight: Option<OrderedFloat<f64>>,
+    /// Keep at most this number of postings with the largest absolute weights per dimension.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_postings_per_dimension: Option<usize>,
+}
+
 /// Configuration for sparse inverted index.
 #[derive(
     Debug, Deserialize, Serialize, JsonSchema, Anonymize, Copy, Clone, PartialEq, Eq, Default,
@@ -59,6 +107,19 @@ pub struct SparseIndexConfig {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub datatype: Option<VectorStorageDatatype>,
+    /// Similarity function used to score vectors. Default: dot product.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub similarity: Option<SparseSimilarity>,
+    /// Pruning of posting lists applied when building the index. Default: no pruning.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pruning: Option<SparsePruningConfig>,
+    /// Skip blocks of posting lists which cannot reach top results, based on block max weights.
+    /// Default: false.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_max: Option<bool>,
 }
 
 impl SparseIndexConfig {
@@ -71,9 +132,16 @@ impl SparseIndexConfig {
             full_scan_threshold,
             index_type,
             datatype,
+            similarity: None,
+            pruning: None,
+            block_max: None,
         }
     }
 
+    pub fn similarity(&self) -> SparseSimilarity {
+        self.similarity.unwrap_or_default()
+    }
+
     pub fn get_config_path(path: &Path) -> PathBuf {
         path.join(SPARSE_INDEX_CONFIG_FILE)
     }
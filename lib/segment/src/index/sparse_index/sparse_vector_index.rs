@@ -7,19 +7,21 @@ use std::sync::atomic::AtomicBool;
 use atomic_refcell::AtomicRefCell;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::storage_version::{StorageVersion as _, VERSION_FILE};
+use common::top_k::TopK;
 use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
 use fs_err as fs;
 use itertools::Itertools;
 use semver::Version;
 use sparse::common::scores_memory_pool::ScoresMemoryPool;
 use sparse::common::sparse_vector::SparseVector;
-use sparse::common::types::DimId;
+use sparse::common::types::{DimId, DimWeight};
 use sparse::index::inverted_index::inverted_index_ram_builder::InvertedIndexBuilder;
 use sparse::index::inverted_index::{INDEX_FILE_NAME, InvertedIndex, OLD_INDEX_FILE_NAME};
+use sparse::index::posting_list_common::PostingListIter as _;
 use sparse::index::search_context::SearchContext;
 
 use super::indices_tracker::IndicesTracker;
-use super::sparse_index_config::SparseIndexType;
+use super::sparse_index_config::{SparseIndexType, SparsePruningConfig, SparseSimilarity};
 use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
 use crate::common::operation_time_statistics::ScopeDurationMeasurer;
 use crate::data_types::named_vectors::CowVector;
@@ -32,7 +34,7 @@ use crate::index::query_estimator::adjust_to_available_vectors;
 use crate::index::sparse_index::sparse_index_config::SparseIndexConfig;
 use crate::index::sparse_index::sparse_search_telemetry::SparseSearchesTelemetry;
 use crate::index::struct_payload_index::StructPayloadIndex;
-use crate::index::{PayloadIndex, VectorIndex};
+use crate::index::{PayloadIndex, SearchExplanation, SearchStrategy, VectorIndex};
 use crate::telemetry::VectorIndexSearchesTelemetry;
 use crate::types::{DEFAULT_SPARSE_FULL_SCAN_THRESHOLD, Filter, SearchParams};
 use crate::vector_storage::query::TransformInto;
@@ -106,10 +108,12 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         let (config, inverted_index, indices_tracker) = if !config.index_type.is_persisted() {
             // RAM mutable case - build inverted index from scratch and use provided config
             fs::create_dir_all(path)?;
+            // Mutable index is not pruned, as it receives updates of individual vectors
             let (inverted_index, indices_tracker) = Self::build_inverted_index(
                 &id_tracker,
                 &vector_storage,
                 path,
+                None,
                 stopped,
                 tick_progress,
             )?;
@@ -129,6 +133,7 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                     &id_tracker,
                     &vector_storage,
                     path,
+                    config.pruning,
                     stopped,
                     tick_progress,
                 )?;
@@ -196,6 +201,7 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         id_tracker: &AtomicRefCell<IdTrackerSS>,
         vector_storage: &AtomicRefCell<VectorStorageEnum>,
         path: &Path,
+        pruning: Option<SparsePruningConfig>,
         stopped: &AtomicBool,
         mut tick_progress: impl FnMut(),
     ) -> OperationResult<(TInvertedIndex, IndicesTracker)> {
@@ -233,6 +239,16 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             }
             tick_progress();
         }
+        if let Some(SparsePruningConfig {
+            min_weight,
+            max_postings_per_dimension,
+        }) = pruning
+        {
+            ram_index_builder.prune(
+                min_weight.map(|min_weight| min_weight.into_inner() as DimWeight),
+                max_postings_per_dimension,
+            );
+        }
         Ok((
             TInvertedIndex::from_ram_index(Cow::Owned(ram_index_builder.build()), path)?,
             indices_tracker,
@@ -247,8 +263,6 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
     /// Warning: the cost of this function grows with the number of dimensions in the query vector
     #[cfg(feature = "testing")]
     pub fn max_result_count(&self, query_vector: &SparseVector) -> usize {
-        use sparse::index::posting_list_common::PostingListIter as _;
-
         // For tests only
         let hw_counter = HardwareCounterCell::disposable();
 
@@ -362,6 +376,10 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         .filter(|&idx| check_deleted_condition(idx, deleted_vectors, deleted_point_bitslice))
         .collect_vec();
 
+        if self.config.similarity() == SparseSimilarity::Jaccard {
+            return Ok(self.search_jaccard(sparse_vector, ids, top, vector_query_context));
+        }
+
         let sparse_vector = self.indices_tracker.remap_vector(sparse_vector.clone());
         let memory_handle = self.scores_memory_pool.get();
         let mut hw_counter = vector_query_context.hardware_counter();
@@ -405,8 +423,6 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
 
         let is_stopped = vector_query_context.is_stopped();
 
-        let sparse_vector = self.indices_tracker.remap_vector(sparse_vector.clone());
-        let memory_handle = self.scores_memory_pool.get();
         let mut hw_counter = vector_query_context.hardware_counter();
         let is_index_on_disk = self.config.index_type.is_on_disk();
         if is_index_on_disk {
@@ -415,6 +431,28 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             hw_counter.set_vector_io_read_multiplier(0);
         }
 
+        if self.config.similarity() == SparseSimilarity::Jaccard {
+            let candidates = self.overlapping_points(sparse_vector, &hw_counter);
+            let candidates = match filter {
+                Some(filter) => {
+                    let payload_index = self.payload_index.borrow();
+                    let filter_context = payload_index.filter_context(filter, &hw_counter);
+                    candidates
+                        .into_iter()
+                        .filter(|&idx| not_deleted_condition(idx) && filter_context.check(idx))
+                        .collect_vec()
+                }
+                None => candidates
+                    .into_iter()
+                    .filter(|&idx| not_deleted_condition(idx))
+                    .collect_vec(),
+            };
+            return self.search_jaccard(sparse_vector, candidates, top, vector_query_context);
+        }
+
+        let sparse_vector = self.indices_tracker.remap_vector(sparse_vector.clone());
+        let memory_handle = self.scores_memory_pool.get();
+
         let mut search_context = SearchContext::new(
             sparse_vector,
             top,
@@ -422,6 +460,12 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             memory_handle,
             &is_stopped,
             &hw_counter,
+        )
+        .with_block_max(
+            vector_query_context
+                .block_max()
+                .or(self.config.block_max)
+                .unwrap_or_default(),
         );
 
         match filter {
@@ -437,6 +481,82 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         }
     }
 
+    /// Sorted ids of points sharing at least one dimension with `sparse_vector`
+    fn overlapping_points(
+        &self,
+        sparse_vector: &SparseVector,
+        hw_counter: &HardwareCounterCell,
+    ) -> Vec<PointOffsetType> {
+        let mut point_ids = Vec::new();
+        for dim_id in sparse_vector.indices.iter() {
+            if let Some(dim_id) = self.indices_tracker.remap_index(*dim_id)
+                && let Some(posting_list_iter) = self.inverted_index.get(dim_id, hw_counter)
+            {
+                point_ids.extend(
+                    posting_list_iter
+                        .into_std_iter()
+                        .map(|element| element.record_id),
+                );
+            }
+        }
+        point_ids.sort_unstable();
+        point_ids.dedup();
+        point_ids
+    }
+
+    /// Score `candidates` by weighted Jaccard similarity, see [`SparseSimilarity::Jaccard`].
+    ///
+    /// Unlike dot product, Jaccard similarity depends on dimensions missing in the query, so
+    /// candidates are scored against their full vectors from the storage.
+    fn search_jaccard(
+        &self,
+        sparse_vector: &SparseVector,
+        candidates: impl IntoIterator<Item = PointOffsetType>,
+        top: usize,
+        vector_query_context: &VectorQueryContext,
+    ) -> Vec<ScoredPointOffset> {
+        let vector_storage = self.vector_storage.borrow();
+        let is_stopped = vector_query_context.is_stopped();
+
+        let mut hw_counter = vector_query_context.hardware_counter();
+        hw_counter.set_cpu_multiplier(size_of::<DimWeight>());
+        if vector_storage.is_on_disk() {
+            hw_counter.set_vector_io_read_multiplier(size_of::<DimId>());
+        } else {
+            hw_counter.set_vector_io_read_multiplier(0);
+        }
+
+        let mut query = sparse_vector.clone();
+        query.sort_by_indices();
+
+        let mut top_results = TopK::new(top);
+        for idx in candidates {
+            // check for cancellation
+            if is_stopped.load(std::sync::atomic::Ordering::Relaxed) {
+                break;
+            }
+
+            let Some(stored) = vector_storage.get_vector_opt::<Random>(idx) else {
+                continue;
+            };
+            let Ok(stored) = <&SparseVector>::try_from(stored.as_vec_ref()) else {
+                continue;
+            };
+
+            hw_counter
+                .vector_io_read()
+                .incr_delta(stored.indices.len() + stored.values.len());
+            hw_counter
+                .cpu_counter()
+                .incr_delta(query.indices.len() + stored.indices.len());
+
+            if let Some(score) = query.jaccard(stored) {
+                top_results.push(ScoredPointOffset { idx, score });
+            }
+        }
+        top_results.into_vec()
+    }
+
     fn search_nearest_query(
         &self,
         vector: &SparseVector,
@@ -458,6 +578,14 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                     .config
                     .full_scan_threshold
                     .unwrap_or(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD);
+                let strategy = if query_cardinality.max < threshold {
+                    SearchStrategy::PayloadIndex
+                } else {
+                    SearchStrategy::SparseIndex
+                };
+                vector_query_context.explain(|| {
+                    SearchExplanation::new(strategy).with_cardinality(&query_cardinality)
+                });
                 if query_cardinality.max < threshold {
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
@@ -475,6 +603,8 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                 }
             }
             None => {
+                vector_query_context
+                    .explain(|| SearchExplanation::new(SearchStrategy::SparseIndex));
                 let _timer = ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_sparse);
                 Ok(self.search_sparse(vector, filter, top, vector_query_context))
             }
@@ -506,6 +636,16 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             | QueryVector::Discovery(_)
             | QueryVector::Context(_)
             | QueryVector::FeedbackNaive(_) => {
+                if self.config.similarity() == SparseSimilarity::Jaccard {
+                    return Err(OperationError::validation_error(
+                        "Only nearest search is supported for sparse vectors with Jaccard similarity",
+                    ));
+                }
+
+                vector_query_context.explain(|| match filter {
+                    Some(_) => SearchExplanation::new(SearchStrategy::PayloadIndex),
+                    None => SearchExplanation::new(SearchStrategy::PlainScan),
+                });
                 let _timer = if filter.is_some() {
                     ScopeDurationMeasurer::new(&self.searches_telemetry.filtered_plain)
                 } else {
@@ -21,7 +21,7 @@ use super::field_index::index_selector::{
 use super::field_index::{FieldIndexBuilderTrait as _, ResolvedHasId};
 use super::payload_config::{FullPayloadIndexType, PayloadFieldSchemaWithIndexType};
 use crate::common::Flusher;
-use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
 use crate::common::utils::IndexesMap;
 use crate::id_tracker::IdTrackerSS;
 use crate::index::field_index::{
@@ -288,6 +288,7 @@ impl StructPayloadIndex {
             indexes = self.build_field_indexes(
                 field,
                 &payload_schema.schema,
+                &AtomicBool::new(false),
                 &HardwareCounterCell::disposable(), // Internal operation
             )?;
 
@@ -450,6 +451,7 @@ impl StructPayloadIndex {
         &self,
         field: PayloadKeyTypeRef,
         payload_schema: &PayloadFieldSchema,
+        is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<FieldIndex>> {
         let payload_storage = self.payload.borrow();
@@ -467,6 +469,7 @@ impl StructPayloadIndex {
 
         payload_storage.iter(
             |point_id, point_payload| {
+                check_process_stopped(is_stopped)?;
                 let field_value = &point_payload.get_value(field);
                 for builder in builders.iter_mut() {
                     builder.add_point(point_id, field_value, hw_counter)?;
@@ -562,6 +565,7 @@ impl StructPayloadIndex {
                     CardinalityEstimation::exact(0)
                 }
             }
+            Condition::Attribute(_) => CardinalityEstimation::unknown(self.available_point_count()),
             Condition::Field(field_condition) => self
                 .estimate_field_condition(field_condition, nested_path, hw_counter)
                 .unwrap_or_else(|| CardinalityEstimation::unknown(self.available_point_count())),
@@ -822,6 +826,7 @@ impl PayloadIndex for StructPayloadIndex {
         &self,
         field: PayloadKeyTypeRef,
         payload_schema: &PayloadFieldSchema,
+        is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<BuildIndexResult> {
         if let Some(prev_schema) = self.config.indices.get(field) {
@@ -833,7 +838,7 @@ impl PayloadIndex for StructPayloadIndex {
                 Ok(BuildIndexResult::IncompatibleSchema)
             };
         }
-        let indexes = self.build_field_indexes(field, payload_schema, hw_counter)?;
+        let indexes = self.build_field_indexes(field, payload_schema, is_stopped, hw_counter)?;
         Ok(BuildIndexResult::Built(indexes))
     }
 
@@ -869,7 +874,8 @@ impl PayloadIndex for StructPayloadIndex {
 
         self.drop_index_if_incompatible(field, &payload_schema)?;
 
-        let field_index = match self.build_index(field, &payload_schema, hw_counter)? {
+        let is_stopped = AtomicBool::new(false);
+        let field_index = match self.build_index(field, &payload_schema, &is_stopped, hw_counter)? {
             BuildIndexResult::Built(field_index) => field_index,
             BuildIndexResult::AlreadyBuilt => {
                 // Index already built, no need to do anything
@@ -4,6 +4,8 @@ use std::path::PathBuf;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
 use half::f16;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use sparse::common::types::{DimId, QuantizedU8};
 use sparse::index::inverted_index::InvertedIndex;
 use sparse::index::inverted_index::inverted_index_compressed_immutable_ram::InvertedIndexCompressedImmutableRam;
@@ -11,15 +13,97 @@ use sparse::index::inverted_index::inverted_index_compressed_mmap::InvertedIndex
 use sparse::index::inverted_index::inverted_index_immutable_ram::InvertedIndexImmutableRam;
 use sparse::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
+use uuid::Uuid;
 
-use super::hnsw_index::hnsw::HNSWIndex;
+use super::field_index::CardinalityEstimation;
+use super::hnsw_index::hnsw::{FilteredSearchPlan, HNSWIndex};
 use super::plain_vector_index::PlainVectorIndex;
 use super::sparse_index::sparse_vector_index::SparseVectorIndex;
 use crate::common::operation_error::OperationResult;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorRef};
 use crate::telemetry::VectorIndexSearchesTelemetry;
-use crate::types::{Filter, SearchParams};
+use crate::types::{Filter, SearchParams, VectorNameBuf};
+
+/// Strategy, which a vector index used to execute a search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchStrategy {
+    /// Traverse the HNSW graph
+    Hnsw,
+    /// Traverse the HNSW graph, checking the filter for each visited point
+    HnswFiltered,
+    /// Score points selected by the payload index, ignoring the vector index
+    PayloadIndex,
+    /// Score all points of the segment
+    PlainScan,
+    /// Traverse posting lists of the sparse inverted index
+    SparseIndex,
+}
+
+/// How a vector index executed a search
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SearchExplanation {
+    pub strategy: SearchStrategy,
+    /// Estimated number of points, matching the filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_cardinality: Option<usize>,
+    /// Lower bound of the number of points, matching the filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_cardinality: Option<usize>,
+    /// Upper bound of the number of points, matching the filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_cardinality: Option<usize>,
+    /// Whether the estimation was refined by checking a sample of points, because bounds given
+    /// by the payload index were not enough to choose the strategy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardinality_sampled: Option<bool>,
+    /// Size of the candidates beam of the HNSW search
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ef: Option<usize>,
+    /// Upper bound of the beam, if the HNSW search grows it dynamically
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ef: Option<usize>,
+}
+
+impl SearchExplanation {
+    pub fn new(strategy: SearchStrategy) -> Self {
+        Self {
+            strategy,
+            estimated_cardinality: None,
+            min_cardinality: None,
+            max_cardinality: None,
+            cardinality_sampled: None,
+            ef: None,
+            max_ef: None,
+        }
+    }
+
+    pub fn with_cardinality(self, cardinality: &CardinalityEstimation) -> Self {
+        Self {
+            estimated_cardinality: Some(cardinality.exp),
+            min_cardinality: Some(cardinality.min),
+            max_cardinality: Some(cardinality.max),
+            ..self
+        }
+    }
+
+    pub fn with_filtered_search_plan(self, plan: &FilteredSearchPlan) -> Self {
+        Self {
+            cardinality_sampled: Some(plan.sampled),
+            ..self.with_cardinality(&plan.cardinality)
+        }
+    }
+}
+
+/// How a segment executed a search of the vector
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SegmentSearchExplanation {
+    pub segment_id: Uuid,
+    pub vector: VectorNameBuf,
+    #[serde(flatten)]
+    pub explanation: SearchExplanation,
+}
 
 /// Trait for vector searching
 pub trait VectorIndex {
@@ -14,6 +14,9 @@ use crate::common::anonymize::Anonymize;
 use crate::common::utils::{MultiValue, merge_map};
 
 mod parse;
+mod projection;
+
+pub use projection::{JsonPathProjection, ProjectionItem};
 
 #[derive(Debug, Clone, PartialEq, Eq, Anonymize, Ord, Hash, PartialOrd)]
 pub struct JsonPath {
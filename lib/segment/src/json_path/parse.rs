@@ -39,21 +39,21 @@ fn json_path(input: &str) -> IResult<&str, JsonPath> {
     Ok((input, JsonPath { first_key, rest }))
 }
 
-fn raw_str(input: &str) -> IResult<&str, &str> {
+pub(super) fn raw_str(input: &str) -> IResult<&str, &str> {
     recognize(many1(
         satisfy(|c: char| c.is_alphanumeric() || c == '_' || c == '-').map(|_: char| ()),
     ))
     .parse(input)
 }
 
-fn quoted_str(input: &str) -> IResult<&str, String> {
+pub(super) fn quoted_str(input: &str) -> IResult<&str, String> {
     let (input, _) = char('"')(input)?;
     let (input, rest) = many0(none_of("\\\"")).parse(input)?;
     let (input, _) = char('"')(input)?;
     Ok((input, rest.iter().collect()))
 }
 
-fn number(input: &str) -> IResult<&str, usize> {
+pub(super) fn number(input: &str) -> IResult<&str, usize> {
     map_res(recognize(digit1), str::parse).parse(input)
 }
 
@@ -0,0 +1,367 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, digit1};
+use nom::combinator::{map_res, opt, recognize};
+use nom::multi::many0;
+use nom::sequence::{delimited, preceded};
+use nom::{IResult, Parser};
+use schemars::JsonSchema;
+use schemars::r#gen::SchemaGenerator;
+use schemars::schema::Schema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use super::parse::{key_needs_quoting, number, quoted_str, raw_str};
+
+/// Projection expression, selecting parts of the payload, e.g. `items[0:3].name` or `meta.*.id`.
+///
+/// Unlike [`JsonPath`](super::JsonPath), it can match multiple keys of an object and ranges of
+/// array elements.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonPathProjection {
+    /// First item is always [`ProjectionItem::Key`] or [`ProjectionItem::WildcardKey`]
+    pub items: Vec<ProjectionItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProjectionItem {
+    /// A key in a JSON object, e.g. `.foo`
+    Key(String),
+    /// All keys in a JSON object, i.e. `.*`
+    WildcardKey,
+    /// An index in a JSON array, e.g. `[3]`
+    Index(usize),
+    /// A range of indices in a JSON array, e.g. `[1:3]`, `[2:]` or `[-2:]`.
+    /// Negative bounds are counted from the end of the array.
+    Slice {
+        start: Option<isize>,
+        end: Option<isize>,
+    },
+    /// All indices in a JSON array, i.e. `[]` or `[*]`
+    WildcardIndex,
+}
+
+impl ProjectionItem {
+    fn matches_key(&self, key: &str) -> bool {
+        match self {
+            ProjectionItem::Key(item_key) => item_key == key,
+            ProjectionItem::WildcardKey => true,
+            ProjectionItem::Index(_)
+            | ProjectionItem::Slice { .. }
+            | ProjectionItem::WildcardIndex => false,
+        }
+    }
+
+    fn matches_index(&self, index: usize, len: usize) -> bool {
+        match self {
+            ProjectionItem::Index(item_index) => *item_index == index,
+            ProjectionItem::Slice { start, end } => {
+                let start = start.map_or(0, |start| slice_bound(start, len));
+                let end = end.map_or(len, |end| slice_bound(end, len));
+                (start..end).contains(&index)
+            }
+            ProjectionItem::WildcardIndex => true,
+            ProjectionItem::Key(_) | ProjectionItem::WildcardKey => false,
+        }
+    }
+}
+
+/// Convert a possibly negative slice bound into an index within `0..=len`.
+fn slice_bound(bound: isize, len: usize) -> usize {
+    if bound < 0 {
+        len.saturating_sub(bound.unsigned_abs())
+    } else {
+        bound.unsigned_abs().min(len)
+    }
+}
+
+impl JsonPathProjection {
+    /// Select parts of the payload, matched by any of the `projections`.
+    ///
+    /// Nesting of the selected values is preserved, arrays only keep the selected elements.
+    /// Objects and arrays without any selected values are omitted.
+    pub fn project(
+        projections: &[JsonPathProjection],
+        payload: &Map<String, Value>,
+    ) -> Map<String, Value> {
+        let paths: Vec<&[ProjectionItem]> = projections
+            .iter()
+            .map(|projection| projection.items.as_slice())
+            .collect();
+        project_object(payload, &paths).unwrap_or_default()
+    }
+}
+
+fn project_value(value: &Value, paths: &[&[ProjectionItem]]) -> Option<Value> {
+    // one of the paths ends here, the whole value is selected
+    if paths.iter().any(|path| path.is_empty()) {
+        return Some(value.clone());
+    }
+
+    match value {
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => None,
+        Value::Array(array) => {
+            let selected: Vec<_> = array
+                .iter()
+                .enumerate()
+                .filter_map(|(index, value)| {
+                    let tails =
+                        matching_tails(paths, |item| item.matches_index(index, array.len()));
+                    if tails.is_empty() {
+                        return None;
+                    }
+                    project_value(value, &tails)
+                })
+                .collect();
+            (!selected.is_empty()).then_some(Value::Array(selected))
+        }
+        Value::Object(object) => project_object(object, paths).map(Value::Object),
+    }
+}
+
+fn project_object(
+    object: &Map<String, Value>,
+    paths: &[&[ProjectionItem]],
+) -> Option<Map<String, Value>> {
+    let mut selected = Map::new();
+    for (key, value) in object {
+        let tails = matching_tails(paths, |item| item.matches_key(key));
+        if tails.is_empty() {
+            continue;
+        }
+        if let Some(value) = project_value(value, &tails) {
+            selected.insert(key.clone(), value);
+        }
+    }
+    (!selected.is_empty()).then_some(selected)
+}
+
+/// Remainders of the paths, which first item is matched by `matches`.
+fn matching_tails<'a>(
+    paths: &[&'a [ProjectionItem]],
+    matches: impl Fn(&ProjectionItem) -> bool,
+) -> Vec<&'a [ProjectionItem]> {
+    paths
+        .iter()
+        .filter_map(|path| match path.split_first() {
+            Some((item, tail)) if matches(item) => Some(tail),
+            _ => None,
+        })
+        .collect()
+}
+
+impl FromStr for JsonPathProjection {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match projection(s) {
+            Ok(("", projection)) => Ok(projection),
+            _ => Err(()),
+        }
+    }
+}
+
+fn projection(input: &str) -> IResult<&str, JsonPathProjection> {
+    let (input, first_item) = object_item(input)?;
+
+    let (input, rest) = many0(alt((
+        preceded(char('.'), object_item),
+        delimited(char('['), slice, char(']')),
+        delimited(char('['), number, char(']')).map(ProjectionItem::Index),
+        alt((tag("[]"), tag("[*]"))).map(|_| ProjectionItem::WildcardIndex),
+    )))
+    .parse(input)?;
+
+    let mut items = Vec::with_capacity(rest.len() + 1);
+    items.push(first_item);
+    items.extend(rest);
+
+    Ok((input, JsonPathProjection { items }))
+}
+
+fn object_item(input: &str) -> IResult<&str, ProjectionItem> {
+    alt((
+        raw_str.map(|s| ProjectionItem::Key(s.to_string())),
+        quoted_str.map(ProjectionItem::Key),
+        char('*').map(|_| ProjectionItem::WildcardKey),
+    ))
+    .parse(input)
+}
+
+fn slice(input: &str) -> IResult<&str, ProjectionItem> {
+    (opt(signed_number), char(':'), opt(signed_number))
+        .map(|(start, _, end)| ProjectionItem::Slice { start, end })
+        .parse(input)
+}
+
+fn signed_number(input: &str) -> IResult<&str, isize> {
+    map_res(recognize((opt(char('-')), digit1)), str::parse).parse(input)
+}
+
+impl Display for JsonPathProjection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            match item {
+                ProjectionItem::Key(key) => {
+                    if i > 0 {
+                        f.write_str(".")?;
+                    }
+                    if key_needs_quoting(key) {
+                        write!(f, "\"{key}\"")?;
+                    } else {
+                        f.write_str(key)?;
+                    }
+                }
+                ProjectionItem::WildcardKey => {
+                    if i > 0 {
+                        f.write_str(".")?;
+                    }
+                    f.write_str("*")?;
+                }
+                ProjectionItem::Index(index) => write!(f, "[{index}]")?,
+                ProjectionItem::Slice { start, end } => {
+                    f.write_str("[")?;
+                    if let Some(start) = start {
+                        write!(f, "{start}")?;
+                    }
+                    f.write_str(":")?;
+                    if let Some(end) = end {
+                        write!(f, "{end}")?;
+                    }
+                    f.write_str("]")?;
+                }
+                ProjectionItem::WildcardIndex => f.write_str("[]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for JsonPathProjection {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonPathProjection {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        string.parse().map_err(|_| {
+            serde::de::Error::custom(format!("Invalid projection expression: \'{string}\'"))
+        })
+    }
+}
+
+impl JsonSchema for JsonPathProjection {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "JsonPathProjection".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        String::json_schema(generator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn project(projections: &[&str], payload: Value) -> Value {
+        let projections: Vec<JsonPathProjection> =
+            projections.iter().map(|s| s.parse().unwrap()).collect();
+        let Value::Object(payload) = payload else {
+            panic!("Payload must be an object");
+        };
+        Value::Object(JsonPathProjection::project(&projections, &payload))
+    }
+
+    #[test]
+    fn test_parse_projection() {
+        assert!("".parse::<JsonPathProjection>().is_err());
+        assert!("a[1:2:3]".parse::<JsonPathProjection>().is_err());
+        assert!("a.".parse::<JsonPathProjection>().is_err());
+
+        assert_eq!(
+            "foo.*[1:3][-2:][:5][][*][4].\"b a r\"".parse(),
+            Ok(JsonPathProjection {
+                items: vec![
+                    ProjectionItem::Key("foo".to_string()),
+                    ProjectionItem::WildcardKey,
+                    ProjectionItem::Slice {
+                        start: Some(1),
+                        end: Some(3),
+                    },
+                    ProjectionItem::Slice {
+                        start: Some(-2),
+                        end: None,
+                    },
+                    ProjectionItem::Slice {
+                        start: None,
+                        end: Some(5),
+                    },
+                    ProjectionItem::WildcardIndex,
+                    ProjectionItem::WildcardIndex,
+                    ProjectionItem::Index(4),
+                    ProjectionItem::Key("b a r".to_string()),
+                ],
+            })
+        );
+
+        for expression in ["*", "a.*.b", "a[1:3].b", "a[-2:]", "a[:]", "a[].\"b c\""] {
+            let projection: JsonPathProjection = expression.parse().unwrap();
+            assert_eq!(projection.to_string(), expression);
+        }
+    }
+
+    #[test]
+    fn test_project_nested() {
+        let payload = json!({
+            "a": 1,
+            "b": {
+                "c": {"id": 1, "name": "x"},
+                "d": {"id": 2, "name": "y"},
+                "e": 3,
+            },
+            "f": [
+                {"id": 1, "tags": ["t1", "t2", "t3"]},
+                {"id": 2, "tags": ["t4"]},
+                {"id": 3, "tags": []},
+            ],
+        });
+
+        assert_eq!(project(&["a"], payload.clone()), json!({"a": 1}));
+        assert_eq!(project(&["x.y"], payload.clone()), json!({}));
+        assert_eq!(
+            project(&["b.*.id"], payload.clone()),
+            json!({"b": {"c": {"id": 1}, "d": {"id": 2}}}),
+        );
+        assert_eq!(
+            project(&["f[0:2].id"], payload.clone()),
+            json!({"f": [{"id": 1}, {"id": 2}]}),
+        );
+        assert_eq!(
+            project(&["f[].tags[-1:]"], payload.clone()),
+            json!({"f": [{"tags": ["t3"]}, {"tags": ["t4"]}]}),
+        );
+        assert_eq!(
+            project(&["f[1]", "a", "b.c.name"], payload.clone()),
+            json!({"a": 1, "b": {"c": {"name": "x"}}, "f": [{"id": 2, "tags": ["t4"]}]}),
+        );
+        // overlapping projections are merged
+        assert_eq!(
+            project(&["f[0].id", "f[:1].tags[0]"], payload.clone()),
+            json!({"f": [{"id": 1, "tags": ["t1"]}]}),
+        );
+        // keys don't match array elements implicitly
+        assert_eq!(project(&["f.id"], payload), json!({}));
+    }
+}
@@ -7,8 +7,8 @@ use serde_json::Value;
 
 use crate::types::{
     AnyVariants, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoPoint,
-    GeoPolygon, GeoRadius, Match, MatchAny, MatchExcept, MatchPhrase, MatchText, MatchTextAny,
-    MatchValue, Range, RangeInterface, ValueVariants, ValuesCount,
+    GeoPolygon, GeoRadius, IpPayloadType, Match, MatchAny, MatchExcept, MatchPhrase, MatchText,
+    MatchTextAny, MatchValue, Range, RangeInterface, ValueVariants, ValuesCount, ip_to_int,
 };
 
 /// Threshold representing the point to which iterating through an IndexSet is more efficient than using hashing.
@@ -217,6 +217,12 @@ impl ValueChecker for Match {
                 (Value::Number(_), _) => true,
                 (Value::String(_), _) => true,
             },
+            Match::Cidr(match_cidr) => match (payload, match_cidr.ip_range()) {
+                (Value::String(stored), Some(range)) => IpPayloadType::from_str(stored)
+                    .map(|address| range.contains(&ip_to_int(address)))
+                    .unwrap_or(false),
+                _ => false,
+            },
         }
     }
 }
@@ -1,11 +1,12 @@
 use std::path::{Path, PathBuf};
 
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::mmap::Advice;
 use common::types::PointOffsetType;
 use fs_err as fs;
 use gridstore::config::StorageOptions;
 use gridstore::{Blob, Gridstore};
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -14,6 +15,7 @@ use crate::payload_storage::PayloadStorage;
 use crate::types::{Payload, PayloadKeyTypeRef};
 
 const STORAGE_PATH: &str = "payload_storage";
+const OVERFLOW_STORAGE_PATH: &str = "payload_overflow";
 
 impl Blob for Payload {
     fn to_bytes(&self) -> Vec<u8> {
@@ -28,21 +30,71 @@ impl Blob for Payload {
 #[derive(Debug)]
 pub struct MmapPayloadStorage {
     storage: Gridstore<Payload>,
+    overflow: Option<PayloadOverflow>,
     populate: bool,
 }
 
+/// Storage for large top-level payload values, kept apart from the rest of the payload.
+///
+/// Keeps pages of the main storage compact, as occasional huge values don't fragment them.
+/// Values are stored under the same point offset as the rest of the payload.
+#[derive(Debug)]
+struct PayloadOverflow {
+    /// Values with a larger serialized size in bytes are moved into overflow storage.
+    threshold: usize,
+    storage: Gridstore<Payload>,
+}
+
+impl PayloadOverflow {
+    fn is_large(&self, value: &Value) -> bool {
+        serde_json::to_vec(value).is_ok_and(|bytes| bytes.len() > self.threshold)
+    }
+}
+
 impl MmapPayloadStorage {
-    pub fn open_or_create(path: PathBuf, populate: bool) -> OperationResult<Self> {
+    /// Open payload storage of the segment at `path`, or create a new one.
+    ///
+    /// If `overflow_threshold` is set, top-level values larger than this number of bytes are
+    /// stored in a separate overflow storage.
+    pub fn open_or_create(
+        path: PathBuf,
+        populate: bool,
+        overflow_threshold: Option<usize>,
+    ) -> OperationResult<Self> {
+        let overflow_path = overflow_storage_dir(&path);
         let path = storage_dir(path);
-        if path.exists() {
-            Self::open(path, populate)
+        let mut storage = if path.exists() {
+            Self::open(path, populate)?
         } else {
             // create folder if it does not exist
             fs::create_dir_all(&path).map_err(|_| {
                 OperationError::service_error("Failed to create mmap payload storage directory")
             })?;
-            Ok(Self::new(path, populate)?)
+            Self::new(path, populate)?
+        };
+
+        // Overflow storage must be opened if it exists, to not lose values stored in it already
+        if overflow_threshold.is_some() || overflow_path.exists() {
+            let overflow_storage =
+                Gridstore::open_or_create(overflow_path, StorageOptions::default()).map_err(
+                    |err| {
+                        OperationError::service_error(format!(
+                            "Failed to open mmap payload overflow storage: {err}"
+                        ))
+                    },
+                )?;
+
+            if populate {
+                overflow_storage.populate()?;
+            }
+
+            storage.overflow = Some(PayloadOverflow {
+                threshold: overflow_threshold.unwrap_or(usize::MAX),
+                storage: overflow_storage,
+            });
         }
+
+        Ok(storage)
     }
 
     fn open(path: PathBuf, populate: bool) -> OperationResult<Self> {
@@ -54,7 +106,11 @@ impl MmapPayloadStorage {
             storage.populate()?;
         }
 
-        Ok(Self { storage, populate })
+        Ok(Self {
+            storage,
+            overflow: None,
+            populate,
+        })
     }
 
     fn new(path: PathBuf, populate: bool) -> OperationResult<Self> {
@@ -64,19 +120,101 @@ impl MmapPayloadStorage {
             storage.populate()?;
         }
 
-        Ok(Self { storage, populate })
+        Ok(Self {
+            storage,
+            overflow: None,
+            populate,
+        })
     }
 
     /// Populate all pages in the mmap.
     /// Block until all pages are populated.
     pub fn populate(&self) -> OperationResult<()> {
         self.storage.populate()?;
+        if let Some(overflow) = &self.overflow {
+            overflow.storage.populate()?;
+        }
         Ok(())
     }
 
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         self.storage.clear_cache()?;
+        if let Some(overflow) = &self.overflow {
+            overflow.storage.clear_cache()?;
+        }
+        Ok(())
+    }
+
+    /// Override the mmap advice of the storage pages.
+    pub fn set_page_advice(&mut self, advice: Advice) -> OperationResult<()> {
+        self.storage.set_page_advice(advice)?;
+        if let Some(overflow) = &mut self.overflow {
+            overflow.storage.set_page_advice(advice)?;
+        }
+        Ok(())
+    }
+
+    /// Read full payload of the point, including values in overflow storage.
+    fn get_payload<const READ_SEQUENTIAL: bool>(
+        &self,
+        point_id: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<Payload> {
+        let payload = self
+            .storage
+            .get_value::<READ_SEQUENTIAL>(point_id, hw_counter);
+
+        let Some(overflow) = &self.overflow else {
+            return payload;
+        };
+
+        match (
+            payload,
+            overflow
+                .storage
+                .get_value::<READ_SEQUENTIAL>(point_id, hw_counter),
+        ) {
+            (Some(mut payload), Some(large_values)) => {
+                payload.0.extend(large_values.0);
+                Some(payload)
+            }
+            (payload, None) => payload,
+            (None, large_values) => large_values,
+        }
+    }
+
+    /// Write full payload of the point, moving large values into overflow storage.
+    fn put_payload(
+        &mut self,
+        point_id: PointOffsetType,
+        payload: &Payload,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let write_counter = hw_counter.ref_payload_io_write_counter();
+
+        let Some(overflow) = &mut self.overflow else {
+            self.storage.put_value(point_id, payload, write_counter)?;
+            return Ok(());
+        };
+
+        if !payload.0.values().any(|value| overflow.is_large(value)) {
+            self.storage.put_value(point_id, payload, write_counter)?;
+            overflow.storage.delete_value(point_id);
+            return Ok(());
+        }
+
+        let (large_values, small_values): (Map<_, _>, Map<_, _>) = payload
+            .0
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .partition(|(_, value)| overflow.is_large(value));
+
+        self.storage
+            .put_value(point_id, &Payload(small_values), write_counter)?;
+        overflow
+            .storage
+            .put_value(point_id, &Payload(large_values), write_counter)?;
         Ok(())
     }
 }
@@ -88,9 +226,7 @@ impl PayloadStorage for MmapPayloadStorage {
         payload: &Payload,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
-        self.storage
-            .put_value(point_id, payload, hw_counter.ref_payload_io_write_counter())?;
-        Ok(())
+        self.put_payload(point_id, payload, hw_counter)
     }
 
     fn set(
@@ -99,21 +235,13 @@ impl PayloadStorage for MmapPayloadStorage {
         payload: &Payload,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
-        match self.storage.get_value::<false>(point_id, hw_counter) {
+        match self.get_payload::<false>(point_id, hw_counter) {
             Some(mut point_payload) => {
                 point_payload.merge(payload);
-                self.storage.put_value(
-                    point_id,
-                    &point_payload,
-                    hw_counter.ref_payload_io_write_counter(),
-                )?;
+                self.put_payload(point_id, &point_payload, hw_counter)?;
             }
             None => {
-                self.storage.put_value(
-                    point_id,
-                    payload,
-                    hw_counter.ref_payload_io_write_counter(),
-                )?;
+                self.put_payload(point_id, payload, hw_counter)?;
             }
         }
         Ok(())
@@ -126,23 +254,15 @@ impl PayloadStorage for MmapPayloadStorage {
         key: &JsonPath,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
-        match self.storage.get_value::<false>(point_id, hw_counter) {
+        match self.get_payload::<false>(point_id, hw_counter) {
             Some(mut point_payload) => {
                 point_payload.merge_by_key(payload, key);
-                self.storage.put_value(
-                    point_id,
-                    &point_payload,
-                    hw_counter.ref_payload_io_write_counter(),
-                )?;
+                self.put_payload(point_id, &point_payload, hw_counter)?;
             }
             None => {
                 let mut dest_payload = Payload::default();
                 dest_payload.merge_by_key(payload, key);
-                self.storage.put_value(
-                    point_id,
-                    &dest_payload,
-                    hw_counter.ref_payload_io_write_counter(),
-                )?;
+                self.put_payload(point_id, &dest_payload, hw_counter)?;
             }
         }
         Ok(())
@@ -153,7 +273,7 @@ impl PayloadStorage for MmapPayloadStorage {
         point_id: PointOffsetType,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Payload> {
-        match self.storage.get_value::<false>(point_id, hw_counter) {
+        match self.get_payload::<false>(point_id, hw_counter) {
             Some(payload) => Ok(payload),
             None => Ok(Default::default()),
         }
@@ -164,7 +284,7 @@ impl PayloadStorage for MmapPayloadStorage {
         point_id: PointOffsetType,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Payload> {
-        match self.storage.get_value::<true>(point_id, hw_counter) {
+        match self.get_payload::<true>(point_id, hw_counter) {
             Some(payload) => Ok(payload),
             None => Ok(Default::default()),
         }
@@ -176,15 +296,11 @@ impl PayloadStorage for MmapPayloadStorage {
         key: PayloadKeyTypeRef,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<Value>> {
-        match self.storage.get_value::<false>(point_id, hw_counter) {
+        match self.get_payload::<false>(point_id, hw_counter) {
             Some(mut payload) => {
                 let res = payload.remove(key);
                 if !res.is_empty() {
-                    self.storage.put_value(
-                        point_id,
-                        &payload,
-                        hw_counter.ref_payload_io_write_counter(),
-                    )?;
+                    self.put_payload(point_id, &payload, hw_counter)?;
                 }
                 Ok(res)
             }
@@ -198,24 +314,56 @@ impl PayloadStorage for MmapPayloadStorage {
         _: &HardwareCounterCell,
     ) -> OperationResult<Option<Payload>> {
         let res = self.storage.delete_value(point_id);
-        Ok(res)
+
+        let Some(overflow) = &mut self.overflow else {
+            return Ok(res);
+        };
+
+        match (res, overflow.storage.delete_value(point_id)) {
+            (Some(mut payload), Some(large_values)) => {
+                payload.0.extend(large_values.0);
+                Ok(Some(payload))
+            }
+            (res, None) => Ok(res),
+            (None, large_values) => Ok(large_values),
+        }
     }
 
     #[cfg(test)]
     fn clear_all(&mut self, _: &HardwareCounterCell) -> OperationResult<()> {
         self.storage.clear().map_err(|err| {
             OperationError::service_error(format!("Failed to clear mmap payload storage: {err}"))
-        })
+        })?;
+        if let Some(overflow) = &mut self.overflow {
+            overflow.storage.clear().map_err(|err| {
+                OperationError::service_error(format!(
+                    "Failed to clear mmap payload overflow storage: {err}"
+                ))
+            })?;
+        }
+        Ok(())
     }
 
     fn flusher(&self) -> Flusher {
         let storage_flusher = self.storage.flusher();
+        let overflow_flusher = self
+            .overflow
+            .as_ref()
+            .map(|overflow| overflow.storage.flusher());
         Box::new(move || {
             storage_flusher().map_err(|err| {
                 OperationError::service_error(format!(
                     "Failed to flush mmap payload gridstore: {err}"
                 ))
-            })
+            })?;
+            if let Some(overflow_flusher) = overflow_flusher {
+                overflow_flusher().map_err(|err| {
+                    OperationError::service_error(format!(
+                        "Failed to flush mmap payload overflow gridstore: {err}"
+                    ))
+                })?;
+            }
+            Ok(())
         })
     }
 
@@ -224,7 +372,14 @@ impl PayloadStorage for MmapPayloadStorage {
         F: FnMut(PointOffsetType, &Payload) -> OperationResult<bool>,
     {
         self.storage.iter(
-            |point_id, payload| {
+            |point_id, mut payload| {
+                if let Some(overflow) = &self.overflow
+                    && let Some(large_values) =
+                        overflow.storage.get_value::<true>(point_id, hw_counter)
+                {
+                    payload.0.extend(large_values.0);
+                }
+
                 callback(point_id, &payload).map_err(|e|
                     // TODO return proper error
                     std::io::Error::other(
@@ -237,15 +392,27 @@ impl PayloadStorage for MmapPayloadStorage {
     }
 
     fn files(&self) -> Vec<PathBuf> {
-        self.storage.files()
+        let mut files = self.storage.files();
+        if let Some(overflow) = &self.overflow {
+            files.extend(overflow.storage.files());
+        }
+        files
     }
 
     fn immutable_files(&self) -> Vec<PathBuf> {
-        self.storage.immutable_files()
+        let mut files = self.storage.immutable_files();
+        if let Some(overflow) = &self.overflow {
+            files.extend(overflow.storage.immutable_files());
+        }
+        files
     }
 
     fn get_storage_size_bytes(&self) -> OperationResult<usize> {
-        Ok(self.storage.get_storage_size_bytes())
+        let overflow_size = self
+            .overflow
+            .as_ref()
+            .map_or(0, |overflow| overflow.storage.get_storage_size_bytes());
+        Ok(self.storage.get_storage_size_bytes() + overflow_size)
     }
 
     fn is_on_disk(&self) -> bool {
@@ -257,3 +424,8 @@ impl PayloadStorage for MmapPayloadStorage {
 pub fn storage_dir<P: AsRef<Path>>(segment_path: P) -> PathBuf {
     segment_path.as_ref().join(STORAGE_PATH)
 }
+
+/// Get directory of the overflow storage for large payload values
+fn overflow_storage_dir<P: AsRef<Path>>(segment_path: P) -> PathBuf {
+    segment_path.as_ref().join(OVERFLOW_STORAGE_PATH)
+}
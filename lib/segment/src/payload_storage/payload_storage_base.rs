@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use common::counter::hardware_counter::HardwareCounterCell;
@@ -9,6 +10,25 @@ use crate::common::operation_error::OperationResult;
 use crate::json_path::JsonPath;
 use crate::types::{Filter, Payload};
 
+/// Serialized size statistics of values of a single top-level payload field.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PayloadFieldSizeStats {
+    /// Number of points which have a value for this field.
+    pub count: usize,
+    /// Total serialized size of all values in bytes.
+    pub total_bytes: usize,
+    /// Largest serialized size of a single value in bytes.
+    pub max_bytes: usize,
+}
+
+impl PayloadFieldSizeStats {
+    fn add(&mut self, bytes: usize) {
+        self.count += 1;
+        self.total_bytes += bytes;
+        self.max_bytes = self.max_bytes.max(bytes);
+    }
+}
+
 /// Trait for payload data storage. Should allow filter checks
 pub trait PayloadStorage {
     /// Overwrite payload for point_id. If payload already exists, replace it
@@ -92,6 +112,30 @@ pub trait PayloadStorage {
 
     /// Whether this storage is on-disk or in-memory.
     fn is_on_disk(&self) -> bool;
+
+    /// Collect serialized size statistics of each top-level payload field.
+    ///
+    /// Reads all stored payloads, so this is expensive on large storages.
+    fn payload_size_stats(
+        &self,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<HashMap<String, PayloadFieldSizeStats>> {
+        let mut stats = HashMap::<String, PayloadFieldSizeStats>::new();
+        self.iter(
+            |_, payload| {
+                for (key, value) in payload.0.iter() {
+                    let bytes = serde_json::to_vec(value)?.len();
+                    match stats.get_mut(key.as_str()) {
+                        Some(field_stats) => field_stats.add(bytes),
+                        None => stats.entry(key.clone()).or_default().add(bytes),
+                    }
+                }
+                Ok(true)
+            },
+            hw_counter,
+        )?;
+        Ok(stats)
+    }
 }
 
 pub trait ConditionChecker {
@@ -344,7 +344,7 @@ mod tests {
         let hw_counter = HardwareCounterCell::new();
 
         let mut storage: PayloadStorageEnum =
-            MmapPayloadStorage::open_or_create(dir.path().to_path_buf(), populate)
+            MmapPayloadStorage::open_or_create(dir.path().to_path_buf(), populate, None)
                 .unwrap()
                 .into();
         let payload: Payload = serde_json::from_str(r#"{"name": "John Doe"}"#).unwrap();
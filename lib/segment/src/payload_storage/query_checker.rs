@@ -16,8 +16,9 @@ use crate::payload_storage::condition_checker::ValueChecker;
 use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::payload_storage::{ConditionChecker, PayloadStorage};
 use crate::types::{
-    Condition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition, MinShould,
-    OwnedPayloadRef, Payload, PayloadContainer, PayloadKeyType, VectorNameBuf,
+    Condition, ExtendedPointId, FieldCondition, Filter, IsEmptyCondition, IsNullCondition,
+    MinShould, OwnedPayloadRef, Payload, PayloadContainer, PayloadKeyType, PointAttribute,
+    PointAttributeCondition, VectorNameBuf,
 };
 use crate::vector_storage::{VectorStorage, VectorStorageEnum};
 
@@ -143,6 +144,9 @@ where
                 false
             }
         }
+        Condition::Attribute(attribute) => id_tracker.is_some_and(|id_tracker| {
+            check_point_attribute_condition(attribute, id_tracker, vector_storages, point_id)
+        }),
         Condition::Nested(nested) => {
             let nested_path = nested.array_key();
             let nested_indexes = select_nested_indexes(&nested_path, field_indexes);
@@ -153,7 +157,7 @@ where
                 .any(|object| {
                     check_payload(
                         Box::new(|| OwnedPayloadRef::from(object)),
-                        None,            // HasId check in nested fields is not supported
+                        None, // HasId and Attribute checks in nested fields are not supported
                         &HashMap::new(), // HasVector check in nested fields is not supported
                         &nested.nested.filter,
                         point_id,
@@ -173,6 +177,30 @@ where
     check_filter(&checker, query)
 }
 
+pub fn check_point_attribute_condition(
+    condition: &PointAttributeCondition,
+    id_tracker: &IdTrackerSS,
+    vector_storages: &HashMap<VectorNameBuf, Arc<AtomicRefCell<VectorStorageEnum>>>,
+    point_id: PointOffsetType,
+) -> bool {
+    let value = match condition.attribute {
+        PointAttribute::Id => match id_tracker.external_id(point_id) {
+            Some(ExtendedPointId::NumId(id)) => Some(id),
+            Some(ExtendedPointId::Uuid(_)) | None => None,
+        },
+        PointAttribute::Version => id_tracker.internal_version(point_id),
+        PointAttribute::VectorsCount => {
+            let count = vector_storages
+                .values()
+                .filter(|vector_storage| !vector_storage.borrow().is_deleted_vector(point_id))
+                .count();
+            Some(count as u64)
+        }
+    };
+
+    value.is_some_and(|value| condition.range.check(value))
+}
+
 pub fn check_is_empty_condition(
     is_empty: &IsEmptyCondition,
     payload: &impl PayloadContainer,
@@ -331,6 +359,7 @@ mod tests {
     use ordered_float::OrderedFloat;
 
     use super::*;
+    use crate::data_types::vectors::VectorRef;
     use crate::id_tracker::IdTracker;
     use crate::id_tracker::in_memory_id_tracker::InMemoryIdTracker;
     use crate::json_path::JsonPath;
@@ -338,8 +367,10 @@ mod tests {
     use crate::payload_storage::PayloadStorage;
     use crate::payload_storage::in_memory_payload_storage::InMemoryPayloadStorage;
     use crate::types::{
-        DateTimeWrapper, FieldCondition, GeoBoundingBox, GeoPoint, PayloadField, Range, ValuesCount,
+        AttributeRange, DateTimeWrapper, Distance, FieldCondition, GeoBoundingBox, GeoPoint,
+        PayloadField, Range, ValuesCount,
     };
+    use crate::vector_storage::dense::volatile_dense_vector_storage::new_volatile_dense_vector_storage;
 
     #[test]
     fn test_condition_checker() {
@@ -649,4 +680,96 @@ mod tests {
         let query = Filter::new_must(Condition::HasId(ids.into()));
         assert!(payload_checker.check(2, &query));
     }
+
+    #[test]
+    fn test_point_attribute_condition() {
+        let hw_counter = HardwareCounterCell::new();
+
+        let mut id_tracker = InMemoryIdTracker::new();
+        id_tracker.set_link(10.into(), 0).unwrap();
+        id_tracker.set_link(20.into(), 1).unwrap();
+        id_tracker
+            .set_link(
+                ExtendedPointId::from_str("ecf4ba7c-8e6b-4f1e-9bd2-7a3f2c9b4d10").unwrap(),
+                2,
+            )
+            .unwrap();
+        id_tracker.set_internal_version(0, 5).unwrap();
+        id_tracker.set_internal_version(1, 10).unwrap();
+        id_tracker.set_internal_version(2, 15).unwrap();
+
+        let mut vector_storages = HashMap::new();
+        for (name, deleted) in [("image", vec![]), ("text", vec![1, 2])] {
+            let mut vector_storage = new_volatile_dense_vector_storage(2, Distance::Dot);
+            for point_id in 0..3 {
+                vector_storage
+                    .insert_vector(point_id, VectorRef::from(&[1.0, 0.0][..]), &hw_counter)
+                    .unwrap();
+            }
+            for point_id in deleted {
+                vector_storage.delete_vector(point_id).unwrap();
+            }
+            vector_storages.insert(
+                VectorNameBuf::from(name),
+                Arc::new(AtomicRefCell::new(vector_storage)),
+            );
+        }
+
+        let payload_checker = SimpleConditionChecker::new(
+            Arc::new(AtomicRefCell::new(
+                PayloadStorageEnum::InMemoryPayloadStorage(InMemoryPayloadStorage::default()),
+            )),
+            Arc::new(AtomicRefCell::new(id_tracker)),
+            vector_storages,
+        );
+
+        let matching = |attribute, range| {
+            let query = Filter::new_must(Condition::Attribute(PointAttributeCondition {
+                attribute,
+                range,
+            }));
+            (0..3)
+                .filter(|&point_id| payload_checker.check(point_id, &query))
+                .collect::<Vec<_>>()
+        };
+
+        // UUID ids never match id ranges
+        let ids = AttributeRange {
+            gte: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(matching(PointAttribute::Id, ids), vec![0, 1]);
+
+        let ids = AttributeRange {
+            gt: Some(10),
+            lte: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(matching(PointAttribute::Id, ids), vec![1]);
+
+        // Points not updated since version 10
+        let versions = AttributeRange {
+            lt: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(matching(PointAttribute::Version, versions), vec![0]);
+
+        let versions = AttributeRange {
+            gte: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(matching(PointAttribute::Version, versions), vec![1, 2]);
+
+        let vectors = AttributeRange {
+            gte: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(matching(PointAttribute::VectorsCount, vectors), vec![0]);
+
+        let vectors = AttributeRange {
+            lte: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(matching(PointAttribute::VectorsCount, vectors), vec![1, 2]);
+    }
 }
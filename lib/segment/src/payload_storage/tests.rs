@@ -142,10 +142,52 @@ fn test_in_memory_storage() {
 #[rstest]
 fn test_mmap_storage(#[values(false, true)] populate: bool) {
     test_trait_impl(|path| {
-        MmapPayloadStorage::open_or_create(path.to_path_buf(), populate).unwrap()
+        MmapPayloadStorage::open_or_create(path.to_path_buf(), populate, None).unwrap()
     });
 }
 
+#[rstest]
+fn test_mmap_storage_overflow(#[values(false, true)] populate: bool) {
+    test_trait_impl(|path| {
+        MmapPayloadStorage::open_or_create(path.to_path_buf(), populate, Some(8)).unwrap()
+    });
+
+    let dir = tempfile::tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+    let mut storage =
+        MmapPayloadStorage::open_or_create(dir.path().to_path_buf(), populate, Some(8)).unwrap();
+
+    let payload = payload_json! {
+        "small": 1,
+        "large": "a value longer than the threshold",
+    };
+    storage.overwrite(0, &payload, &hw_counter).unwrap();
+    storage
+        .set(0, &payload_json! {"small": 2}, &hw_counter)
+        .unwrap();
+
+    let expected = payload_json! {
+        "small": 2,
+        "large": "a value longer than the threshold",
+    };
+    assert_eq!(storage.get(0, &hw_counter).unwrap(), expected);
+
+    let stats = storage.payload_size_stats(&hw_counter).unwrap();
+    assert_eq!(stats["small"].count, 1);
+    assert_eq!(stats["small"].max_bytes, 1);
+    assert_eq!(stats["large"].total_bytes, 35);
+
+    // Values in overflow storage must be found without an overflow threshold
+    storage.flusher()().unwrap();
+    drop(storage);
+    let mut storage =
+        MmapPayloadStorage::open_or_create(dir.path().to_path_buf(), populate, None).unwrap();
+    assert_eq!(storage.get(0, &hw_counter).unwrap(), expected);
+
+    assert_eq!(storage.clear(0, &hw_counter).unwrap(), Some(expected));
+    assert!(storage.get(0, &hw_counter).unwrap().is_empty());
+}
+
 #[test]
 #[cfg(feature = "rocksdb")]
 fn test_on_disk_storage() {
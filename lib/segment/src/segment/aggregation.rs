@@ -0,0 +1,77 @@
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::iterator_ext::IteratorExt;
+use itertools::Either;
+
+use super::Segment;
+use crate::common::operation_error::OperationResult;
+use crate::data_types::aggregation::{AggregateParams, AggregateResult};
+use crate::data_types::facets::FacetValueRef;
+use crate::index::PayloadIndex;
+use crate::index::field_index::FieldIndex;
+use crate::types::PayloadContainer;
+
+impl Segment {
+    /// Aggregate values of the key over the filtered points.
+    ///
+    /// Values are read from the range index or the map index of the key if there is one,
+    /// otherwise from the payload of each point.
+    pub(super) fn aggregate_values(
+        &self,
+        request: &AggregateParams,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<AggregateResult> {
+        let payload_index = self.payload_index.borrow();
+        let id_tracker = self.id_tracker.borrow();
+
+        let field_indexes = payload_index.field_indexes.get(&request.key);
+
+        // Datetimes are indexed as timestamps, they are not aggregated as numbers
+        let numeric_index = field_indexes.and_then(|indexes| {
+            indexes
+                .iter()
+                .filter(|index| !matches!(index, FieldIndex::DatetimeIndex(_)))
+                .find_map(|index| index.as_numeric())
+        });
+        let facet_index = field_indexes
+            .and_then(|indexes| indexes.iter().find_map(|index| index.as_facet_index()));
+
+        let point_ids = if let Some(filter) = &request.filter {
+            let filter_cardinality = payload_index.estimate_cardinality(filter, hw_counter);
+            Either::Left(payload_index.iter_filtered_points(
+                filter,
+                &*id_tracker,
+                &filter_cardinality,
+                hw_counter,
+                is_stopped,
+            ))
+        } else {
+            Either::Right(id_tracker.iter_internal().stop_if(is_stopped))
+        };
+
+        let mut result = AggregateResult::default();
+        for point_id in point_ids.filter(|&point_id| !id_tracker.is_deleted_point(point_id)) {
+            if let Some(numeric_index) = &numeric_index {
+                numeric_index
+                    .get_ordering_values(point_id)
+                    .for_each(|value| result.add_order_value(value));
+            } else if let Some(facet_index) = &facet_index {
+                for value in facet_index.get_point_values(point_id) {
+                    if let FacetValueRef::Int(integer) = value {
+                        result.add_number(*integer as f64);
+                    }
+                    result.add_distinct(value.to_owned());
+                }
+            } else {
+                let payload = payload_index.get_payload(point_id, hw_counter)?;
+                for value in payload.get_value(&request.key) {
+                    result.add_payload_value(value);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -14,8 +14,9 @@ use crate::common::operation_error::{OperationError, OperationResult, SegmentFai
 use crate::common::{
     Flusher, check_named_vectors, check_query_vectors, check_stopped, check_vector_name,
 };
+use crate::data_types::aggregation::{AggregateParams, AggregateResult};
 use crate::data_types::build_index_result::BuildFieldIndexResult;
-use crate::data_types::facets::{FacetParams, FacetValue};
+use crate::data_types::facets::{FacetParams, FacetValue, MultiFacetParams};
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::order_by::{OrderBy, OrderValue};
 use crate::data_types::query_context::{
@@ -28,7 +29,7 @@ use crate::index::field_index::{CardinalityEstimation, FieldIndex};
 use crate::index::{BuildIndexResult, PayloadIndex, VectorIndex};
 use crate::json_path::JsonPath;
 use crate::payload_storage::PayloadStorage;
-use crate::telemetry::SegmentTelemetry;
+use crate::telemetry::{PayloadFieldSizeTelemetry, SegmentTelemetry};
 use crate::types::{
     ExtendedPointId, Filter, Payload, PayloadFieldSchema, PayloadIndexInfo, PayloadKeyType,
     PayloadKeyTypeRef, PointIdType, ScoredPoint, SearchParams, SegmentConfig, SegmentInfo,
@@ -74,6 +75,7 @@ impl NonAppendableSegmentEntry for Segment {
             .vector_data
             .get(vector_name)
             .ok_or_else(|| OperationError::vector_name_not_exists(vector_name))?;
+        Self::ensure_vector_loaded(vector_data);
         let vector_query_context = query_context.get_vector_context(vector_name);
         let internal_results = vector_data.vector_index.borrow().search(
             query_vectors,
@@ -85,6 +87,10 @@ impl NonAppendableSegmentEntry for Segment {
 
         check_stopped(&vector_query_context.is_stopped())?;
 
+        if let Some(explanation) = vector_query_context.take_explanation() {
+            query_context.record_explanation(self.uuid, vector_name, explanation);
+        }
+
         let hw_counter = vector_query_context.hardware_counter();
 
         internal_results
@@ -326,6 +332,17 @@ impl NonAppendableSegmentEntry for Segment {
         }
     }
 
+    fn read_weighted_random_filtered(
+        &self,
+        limit: usize,
+        key: &JsonPath,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<(f64, Vec<PointIdType>)> {
+        self.filtered_read_by_weighted_random(limit, key, filter, is_stopped, hw_counter)
+    }
+
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType> {
         let id_tracker = self.id_tracker.borrow();
         let iterator = id_tracker.iter_from(from).map(|x| x.0);
@@ -405,6 +422,24 @@ impl NonAppendableSegmentEntry for Segment {
         self.approximate_facet(request, is_stopped, hw_counter)
     }
 
+    fn facets(
+        &self,
+        request: &MultiFacetParams,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<HashMap<FacetValue, usize>>> {
+        self.approximate_facets(request, is_stopped, hw_counter)
+    }
+
+    fn aggregate(
+        &self,
+        request: &AggregateParams,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<AggregateResult> {
+        self.aggregate_values(request, is_stopped, hw_counter)
+    }
+
     fn segment_uuid(&self) -> Uuid {
         self.uuid
     }
@@ -739,6 +774,7 @@ impl NonAppendableSegmentEntry for Segment {
         op_num: SeqNumberType,
         key: PayloadKeyTypeRef,
         field_type: &PayloadFieldSchema,
+        is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<BuildFieldIndexResult> {
         // Check version without updating it
@@ -749,7 +785,7 @@ impl NonAppendableSegmentEntry for Segment {
         let field_index = match self
             .payload_index
             .borrow()
-            .build_index(key, field_type, hw_counter)?
+            .build_index(key, field_type, is_stopped, hw_counter)?
         {
             BuildIndexResult::Built(indexes) => indexes,
             BuildIndexResult::AlreadyBuilt => {
@@ -833,11 +869,34 @@ impl NonAppendableSegmentEntry for Segment {
             })
             .collect();
 
+        // Reads all payloads, telemetry of segments is only collected on the highest detail level
+        let hw_counter = HardwareCounterCell::disposable();
+        let payload_field_sizes = match self.payload_size_stats(&hw_counter) {
+            Ok(stats) => {
+                let mut payload_field_sizes: Vec<_> = stats
+                    .into_iter()
+                    .map(|(field_name, stats)| PayloadFieldSizeTelemetry {
+                        field_name: Some(field_name),
+                        points_count: stats.count,
+                        total_bytes: stats.total_bytes,
+                        max_bytes: stats.max_bytes,
+                    })
+                    .collect();
+                payload_field_sizes.sort_unstable_by(|a, b| a.field_name.cmp(&b.field_name));
+                payload_field_sizes
+            }
+            Err(err) => {
+                log::warn!("Failed to collect payload size statistics: {err}");
+                Vec::new()
+            }
+        };
+
         SegmentTelemetry {
             info: self.info(),
             config: self.config().clone(),
             vector_index_searches,
             payload_field_indices: self.payload_index.borrow().get_telemetry_data(),
+            payload_field_sizes,
         }
     }
 
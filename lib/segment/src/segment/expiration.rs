@@ -0,0 +1,132 @@
+use std::sync::atomic::AtomicBool;
+
+use chrono::{DateTime, Utc};
+use common::counter::hardware_counter::HardwareCounterCell;
+use ordered_float::OrderedFloat;
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::order_by::{Direction, OrderBy, OrderValue};
+use crate::entry::entry_point::NonAppendableSegmentEntry;
+use crate::json_path::JsonPath;
+use crate::types::{Condition, DateTimeWrapper, FieldCondition, PayloadSchemaType, Range};
+
+/// Payload field holding the expiration time of a point, if points expiration is enabled.
+///
+/// With an `integer` or `float` payload index the field is expected to hold a unix timestamp in
+/// seconds, otherwise - an RFC 3339 datetime.
+pub const EXPIRES_AT_KEY: &str = "expires_at";
+
+pub fn expires_at_key() -> JsonPath {
+    JsonPath::new(EXPIRES_AT_KEY)
+}
+
+/// Whether values of the `expires_at` field are unix timestamps in seconds, given its index type
+fn is_timestamp_schema(schema: Option<PayloadSchemaType>) -> bool {
+    matches!(
+        schema,
+        Some(PayloadSchemaType::Integer | PayloadSchemaType::Float),
+    )
+}
+
+/// Condition matching points which are expired at `now`
+///
+/// `schema` is the type of the payload index of the `expires_at` field, if any.
+pub fn expired_condition(schema: Option<PayloadSchemaType>, now: DateTime<Utc>) -> Condition {
+    let key = expires_at_key();
+
+    let condition = if is_timestamp_schema(schema) {
+        FieldCondition::new_range(
+            key,
+            Range {
+                lt: None,
+                gt: None,
+                gte: None,
+                lte: Some(OrderedFloat(now.timestamp() as f64)),
+            },
+        )
+    } else {
+        FieldCondition::new_datetime_range(
+            key,
+            Range {
+                lt: None,
+                gt: None,
+                gte: None,
+                lte: Some(DateTimeWrapper(now)),
+            },
+        )
+    };
+
+    Condition::Field(condition)
+}
+
+/// Extract expiration time from a value of the `expires_at` payload field
+pub fn parse_expires_at(
+    schema: Option<PayloadSchemaType>,
+    value: &serde_json::Value,
+) -> Option<DateTime<Utc>> {
+    if is_timestamp_schema(schema) {
+        let seconds = value.as_f64()?;
+        DateTime::from_timestamp(seconds.floor() as i64, 0)
+    } else {
+        value
+            .as_str()?
+            .parse::<DateTimeWrapper>()
+            .ok()
+            .map(|datetime| datetime.0)
+    }
+}
+
+/// Earliest expiration time of points in the `segment`.
+///
+/// Uses the payload index of the `expires_at` field, so it is cheap to call.
+/// Returns `Ok(None)` if no point in the segment has an expiration time,
+/// and an error if the field is not indexed with a datetime or numeric index.
+pub fn earliest_expiration<S: NonAppendableSegmentEntry + ?Sized>(
+    segment: &S,
+    is_stopped: &AtomicBool,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<Option<DateTime<Utc>>> {
+    let key = expires_at_key();
+
+    let schema = segment
+        .get_indexed_fields()
+        .get(&key)
+        .map(|schema| schema.kind());
+
+    match schema {
+        Some(
+            PayloadSchemaType::Datetime | PayloadSchemaType::Integer | PayloadSchemaType::Float,
+        ) => {}
+        _ => {
+            return Err(OperationError::service_error(format!(
+                "Field {EXPIRES_AT_KEY} is not indexed with datetime or numeric index",
+            )));
+        }
+    }
+
+    let order_by = OrderBy {
+        key,
+        direction: Some(Direction::Asc),
+        start_from: None,
+    };
+
+    let earliest = segment
+        .read_ordered_filtered(Some(1), None, &order_by, is_stopped, hw_counter)?
+        .into_iter()
+        .next()
+        .map(|(value, _)| value);
+
+    let expiration = match (schema, earliest) {
+        (_, None) => None,
+        // Datetime index holds timestamps in microseconds
+        (Some(PayloadSchemaType::Datetime), Some(OrderValue::Int(micros))) => {
+            DateTime::from_timestamp_micros(micros)
+        }
+        (_, Some(OrderValue::Int(seconds))) => DateTime::from_timestamp(seconds, 0),
+        (_, Some(OrderValue::Float(seconds))) => {
+            DateTime::from_timestamp(seconds.floor() as i64, 0)
+        }
+    };
+
+    Ok(expiration)
+}
@@ -6,14 +6,28 @@ use common::iterator_ext::IteratorExt;
 use itertools::{Either, Itertools};
 
 use super::Segment;
-use crate::common::operation_error::OperationResult;
-use crate::data_types::facets::{FacetHit, FacetParams, FacetValue};
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::facets::{
+    FacetHistogram, FacetHit, FacetParams, FacetValue, MAX_HISTOGRAM_BUCKETS, MultiFacetParams,
+};
+use crate::data_types::order_by::OrderValue;
 use crate::entry::entry_point::NonAppendableSegmentEntry;
 use crate::index::PayloadIndex;
+use crate::index::field_index::FieldIndex;
 use crate::json_path::JsonPath;
 use crate::payload_storage::FilterContext;
 use crate::types::Filter;
 
+/// If the filter selects less than this fraction of points, values are read for each filtered
+/// point. Otherwise values are iterated, checking the filter for each of their points.
+// TODO(facets): define a better estimate for this decision, the question is:
+// What is more expensive, to hash the same value excessively or to check with filter too many times?
+//
+// For now this is defined from some rudimentary benchmarking two scenarios:
+// - a collection with few keys
+// - a collection with almost a unique key per point
+const ITERATIVE_APPROACH_MAX_FILTERED_RATIO: f64 = 0.3;
+
 impl Segment {
     pub(super) fn approximate_facet(
         &self,
@@ -21,6 +35,10 @@ impl Segment {
         is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<HashMap<FacetValue, usize>> {
+        if let Some(histogram) = &request.histogram {
+            return self.histogram_facet(request, histogram, is_stopped, hw_counter);
+        }
+
         let payload_index = self.payload_index.borrow();
 
         // Shortcut if this segment has no points, prevent division by zero later
@@ -38,13 +56,8 @@ impl Segment {
 
             let percentage_filtered = filter_cardinality.exp as f64 / available_points as f64;
 
-            // TODO(facets): define a better estimate for this decision, the question is:
-            // What is more expensive, to hash the same value excessively or to check with filter too many times?
-            //
-            // For now this is defined from some rudimentary benchmarking two scenarios:
-            // - a collection with few keys
-            // - a collection with almost a unique key per point
-            let use_iterative_approach = percentage_filtered < 0.3;
+            let use_iterative_approach =
+                percentage_filtered < ITERATIVE_APPROACH_MAX_FILTERED_RATIO;
 
             let iter = if use_iterative_approach {
                 // go over the filtered points and aggregate the values
@@ -114,6 +127,148 @@ impl Segment {
         Ok(hits)
     }
 
+    /// Approximate counts for several keys, selecting the filtered points only once.
+    ///
+    /// Results are in the same order as the requested keys.
+    pub(super) fn approximate_facets(
+        &self,
+        request: &MultiFacetParams,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<HashMap<FacetValue, usize>>> {
+        let payload_index = self.payload_index.borrow();
+
+        // Shortcut if this segment has no points, prevent division by zero later
+        let available_points = self.available_point_count();
+        if available_points == 0 {
+            return Ok(vec![HashMap::new(); request.keys.len()]);
+        }
+
+        let facet_indexes: Vec<_> = request
+            .keys
+            .iter()
+            .map(|key| payload_index.get_facet_index(key))
+            .try_collect()?;
+
+        // Sharing the candidate set only helps if values are read for each filtered point
+        let filtered_points = request.filter.as_ref().and_then(|filter| {
+            let filter_cardinality = payload_index.estimate_cardinality(filter, hw_counter);
+            let percentage_filtered = filter_cardinality.exp as f64 / available_points as f64;
+            if percentage_filtered >= ITERATIVE_APPROACH_MAX_FILTERED_RATIO {
+                return None;
+            }
+
+            let id_tracker = self.id_tracker.borrow();
+            let points: Vec<_> = payload_index
+                .iter_filtered_points(
+                    filter,
+                    &*id_tracker,
+                    &filter_cardinality,
+                    hw_counter,
+                    is_stopped,
+                )
+                .filter(|point_id| !id_tracker.is_deleted_point(*point_id))
+                .collect();
+            Some(points)
+        });
+
+        let Some(filtered_points) = filtered_points else {
+            return request
+                .facet_params()
+                .map(|params| self.approximate_facet(&params, is_stopped, hw_counter))
+                .collect();
+        };
+
+        let hits = facet_indexes
+            .into_iter()
+            .map(|facet_index| {
+                filtered_points
+                    .iter()
+                    .stop_if(is_stopped)
+                    .fold(HashMap::new(), |mut map, &point_id| {
+                        facet_index
+                            .get_point_values(point_id)
+                            .unique()
+                            .for_each(|value| {
+                                *map.entry(value).or_insert(0) += 1;
+                            });
+                        map
+                    })
+                    .into_iter()
+                    .map(|(value, count)| (value.to_owned(), count))
+                    .collect()
+            })
+            .collect();
+
+        Ok(hits)
+    }
+
+    /// Count points in each numeric bucket of the key, using the range index
+    fn histogram_facet(
+        &self,
+        request: &FacetParams,
+        histogram: &FacetHistogram,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<HashMap<FacetValue, usize>> {
+        let payload_index = self.payload_index.borrow();
+        let id_tracker = self.id_tracker.borrow();
+
+        let field_indexes = payload_index.field_indexes.get(&request.key);
+        let numeric_index = field_indexes
+            .and_then(|indexes| indexes.iter().find_map(|index| index.as_numeric()))
+            .ok_or_else(|| OperationError::MissingRangeIndexForFacet {
+                key: request.key.to_string(),
+            })?;
+
+        // Datetimes are indexed as integer timestamps, but bucketed in seconds
+        let is_datetime = field_indexes.is_some_and(|indexes| {
+            indexes
+                .iter()
+                .any(|index| matches!(index, FieldIndex::DatetimeIndex(_)))
+        });
+
+        let bucket = |value: OrderValue| match value {
+            OrderValue::Int(timestamp) if is_datetime => histogram.datetime_bucket(timestamp),
+            OrderValue::Int(integer) => histogram.float_bucket(integer as f64),
+            OrderValue::Float(float) => histogram.float_bucket(float),
+        };
+
+        let point_ids = if let Some(filter) = &request.filter {
+            let filter_cardinality = payload_index.estimate_cardinality(filter, hw_counter);
+            Either::Left(payload_index.iter_filtered_points(
+                filter,
+                &*id_tracker,
+                &filter_cardinality,
+                hw_counter,
+                is_stopped,
+            ))
+        } else {
+            Either::Right(id_tracker.iter_internal().stop_if(is_stopped))
+        };
+
+        let mut hits = HashMap::new();
+        for point_id in point_ids.filter(|&point_id| !id_tracker.is_deleted_point(point_id)) {
+            // Count each point once per bucket, even if it has several values in it
+            for value in numeric_index
+                .get_ordering_values(point_id)
+                .filter_map(bucket)
+                .unique()
+            {
+                *hits.entry(value).or_insert(0) += 1;
+            }
+
+            if hits.len() > MAX_HISTOGRAM_BUCKETS {
+                return Err(OperationError::TooManyHistogramBuckets {
+                    key: request.key.to_string(),
+                    limit: MAX_HISTOGRAM_BUCKETS,
+                });
+            }
+        }
+
+        Ok(hits)
+    }
+
     pub(super) fn facet_values(
         &self,
         key: &JsonPath,
@@ -1,3 +1,4 @@
+mod aggregation;
 mod entry;
 mod facet;
 mod formula_rescore;
@@ -8,7 +9,10 @@ mod search;
 mod segment_ops;
 mod version_tracker;
 
+pub mod expiration;
 pub mod snapshot;
+pub mod vector_loading;
+pub mod vector_rebuild;
 
 #[cfg(test)]
 mod tests;
@@ -18,6 +22,7 @@ use std::collections::HashMap;
 use std::fmt;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
 use atomic_refcell::AtomicRefCell;
 use common::is_alive_lock::IsAliveLock;
@@ -99,6 +104,11 @@ pub struct VectorData {
     pub vector_index: Arc<AtomicRefCell<VectorIndexEnum>>,
     pub vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
     pub quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
+    /// In-RAM vector storage or HNSW graph was not loaded into memory on open, and will be in
+    /// background on first use.
+    ///
+    /// See [`FeatureFlags::lazy_vector_loading`](common::flags::FeatureFlags::lazy_vector_loading).
+    pub lazy_load_pending: AtomicBool,
 }
 
 impl fmt::Debug for VectorData {
@@ -126,6 +136,7 @@ impl Drop for Segment {
                 vector_index,
                 vector_storage,
                 quantized_vectors,
+                lazy_load_pending: _,
             } = vector_data;
 
             if let Err(e) = vector_index.borrow().clear_cache() {
@@ -1,13 +1,26 @@
-use std::sync::atomic::AtomicBool;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::iterator_ext::IteratorExt;
+use common::types::PointOffsetType;
+use ordered_float::OrderedFloat;
+use rand::Rng;
 use rand::seq::{IteratorRandom, SliceRandom};
 
 use super::Segment;
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::PayloadIndex;
+use crate::index::field_index::numeric_index::alias_table::AliasTable;
+use crate::json_path::JsonPath;
+use crate::spaces::tools::peek_top_largest_iterable;
 use crate::types::{Filter, PointIdType};
 
+/// How many draws from the alias table per requested point are allowed, before falling back
+/// to a full pass over the matching points.
+const ALIAS_TABLE_DRAWS_PER_POINT: usize = 8;
+
 impl Segment {
     pub(super) fn filtered_read_by_index_shuffled(
         &self,
@@ -56,6 +69,137 @@ impl Segment {
             .collect()
     }
 
+    /// Sample points which satisfy filtering condition, with probability proportional to the
+    /// value of numeric `key` field. Points without a positive value are never sampled.
+    ///
+    /// Returns total weight of the sampled population and points in the order they were drawn.
+    pub(super) fn filtered_read_by_weighted_random(
+        &self,
+        limit: usize,
+        key: &JsonPath,
+        condition: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<(f64, Vec<PointIdType>)> {
+        let payload_index = self.payload_index.borrow();
+        let id_tracker = self.id_tracker.borrow();
+
+        let numeric_index = payload_index
+            .field_indexes
+            .get(key)
+            .and_then(|indexes| indexes.iter().find_map(|index| index.as_numeric()))
+            .ok_or_else(|| OperationError::MissingRangeIndexForSample {
+                key: key.to_string(),
+            })?;
+
+        let mut rng = rand::rng();
+
+        // Immutable index keeps an alias table, draw from it and reject points which don't match
+        if let Some(alias_table) = numeric_index.alias_table() {
+            let filter_context =
+                condition.map(|condition| payload_index.filter_context(condition, hw_counter));
+            let is_sampled = |internal_id: PointOffsetType| {
+                !id_tracker.is_deleted_point(internal_id)
+                    // Point could have been removed from the index after the table was built
+                    && numeric_index.get_weight(internal_id) > 0.0
+                    && filter_context
+                        .as_ref()
+                        .is_none_or(|context| context.check(internal_id))
+            };
+
+            if let Some((accepted_ratio, sampled)) =
+                Self::sample_from_alias_table(alias_table, limit, is_sampled, is_stopped, &mut rng)
+            {
+                let sampled = sampled
+                    .into_iter()
+                    .filter_map(|internal_id| id_tracker.external_id(internal_id))
+                    .collect();
+                // Each draw is accepted with probability `filtered weight / total weight`
+                let filtered_weight = alias_table.total_weight() * accepted_ratio;
+                return Ok((filtered_weight, sampled));
+            }
+        }
+
+        // Otherwise, do a single pass of weighted reservoir sampling (Efraimidis-Spirakis)
+        let points: Box<dyn Iterator<Item = PointOffsetType> + '_> = match condition {
+            Some(condition) => {
+                let cardinality_estimation =
+                    payload_index.estimate_cardinality(condition, hw_counter);
+                Box::new(payload_index.iter_filtered_points(
+                    condition,
+                    &*id_tracker,
+                    &cardinality_estimation,
+                    hw_counter,
+                    is_stopped,
+                ))
+            }
+            None => Box::new(id_tracker.iter_internal().stop_if(is_stopped)),
+        };
+
+        let mut total_weight = 0.0;
+        let keyed_points = points.filter_map(|internal_id| {
+            let weight = numeric_index.get_weight(internal_id);
+            if weight <= 0.0 {
+                return None;
+            }
+            total_weight += weight;
+            // Largest `u^(1/w)` keys form a weighted sample, compare logarithms for precision
+            let sample_key = rng.random::<f64>().ln() / weight;
+            Some((OrderedFloat(sample_key), internal_id))
+        });
+
+        let mut sampled = peek_top_largest_iterable(keyed_points, limit);
+        sampled.sort_unstable_by_key(|(sample_key, _)| Reverse(*sample_key));
+
+        let sampled = sampled
+            .into_iter()
+            .filter_map(|(_, internal_id)| id_tracker.external_id(internal_id))
+            .collect();
+
+        Ok((total_weight, sampled))
+    }
+
+    /// Draw up to `limit` distinct points from the alias table, which pass `is_sampled` check.
+    ///
+    /// All draws of the budget are made, to estimate the ratio of the weight which passes the
+    /// check. Returns the ratio together with the sampled points.
+    /// Returns `None` if not enough points were found within the draws budget, e.g. because of
+    /// a restrictive filter or because there are fewer matching points than `limit`.
+    pub(super) fn sample_from_alias_table(
+        alias_table: &AliasTable,
+        limit: usize,
+        is_sampled: impl Fn(PointOffsetType) -> bool,
+        is_stopped: &AtomicBool,
+        rng: &mut impl Rng,
+    ) -> Option<(f64, Vec<PointOffsetType>)> {
+        // Whether each drawn point passed the check, repeated draws are counted as well
+        let mut seen = HashMap::with_capacity(limit);
+        let mut sampled = Vec::with_capacity(limit);
+        let mut draws = 0usize;
+        let mut accepted_draws = 0usize;
+
+        for _ in 0..limit.saturating_mul(ALIAS_TABLE_DRAWS_PER_POINT) {
+            if is_stopped.load(Ordering::Relaxed) {
+                break;
+            }
+            let internal_id = alias_table.sample(rng)?;
+            let is_accepted = *seen.entry(internal_id).or_insert_with(|| {
+                let is_accepted = is_sampled(internal_id);
+                if is_accepted && sampled.len() < limit {
+                    sampled.push(internal_id);
+                }
+                is_accepted
+            });
+            draws += 1;
+            accepted_draws += usize::from(is_accepted);
+        }
+
+        if draws == 0 || sampled.len() < limit {
+            return None;
+        }
+        Some((accepted_draws as f64 / draws as f64, sampled))
+    }
+
     pub(super) fn read_by_random_id(&self, limit: usize) -> Vec<PointIdType> {
         self.id_tracker
             .borrow()
@@ -18,6 +18,7 @@ use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::vectors::VectorInternal;
 use crate::entry::entry_point::NonAppendableSegmentEntry;
 use crate::index::{PayloadIndex, VectorIndex};
+use crate::payload_storage::{PayloadFieldSizeStats, PayloadStorage as _};
 use crate::types::{
     Payload, PayloadFieldSchema, PayloadKeyType, PointIdType, SegmentState, SeqNumberType,
     SnapshotFormat, VectorName,
@@ -409,6 +410,7 @@ impl Segment {
             .vector_data
             .get(vector_name)
             .ok_or_else(|| OperationError::vector_name_not_exists(vector_name))?;
+        Self::ensure_vector_loaded(vector_data);
         let vector_storage = vector_data.vector_storage.borrow();
         let total_vectors = vector_storage.total_vector_count();
 
@@ -646,6 +648,14 @@ impl Segment {
         self.id_tracker.borrow().total_point_count()
     }
 
+    /// Collect serialized size statistics of each top-level payload field in this segment.
+    pub fn payload_size_stats(
+        &self,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<HashMap<String, PayloadFieldSizeStats>> {
+        self.payload_storage.borrow().payload_size_stats(hw_counter)
+    }
+
     /// Fixes inconsistencies in the ID tracker, if any.
     /// Returns list of IDs without mappings which should be removed from segment
     pub fn fix_id_tracker_inconsistencies(&mut self) -> OperationResult<Vec<PointOffsetType>> {
@@ -1,5 +1,6 @@
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+use ahash::AHashSet;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::tar_ext;
 use common::tar_unpack::tar_unpack_file;
@@ -12,15 +13,17 @@ use super::*;
 use crate::common::operation_error::OperationError::PointIdError;
 use crate::common::{check_named_vectors, check_vector, check_vector_name};
 use crate::data_types::named_vectors::NamedVectors;
-use crate::data_types::query_context::QueryContext;
+use crate::data_types::query_context::{QueryContext, SearchExplanations};
 use crate::data_types::vectors::{DEFAULT_VECTOR_NAME, only_default_vector};
 use crate::entry::SnapshotEntry as _;
 use crate::entry::entry_point::{NonAppendableSegmentEntry as _, SegmentEntry as _};
+use crate::index::{SearchExplanation, SearchStrategy};
+use crate::segment::vector_loading::{clear_files_cache, populate_files};
 use crate::segment_constructor::load_segment;
 use crate::segment_constructor::simple_segment_constructor::{
     VECTOR1_NAME, VECTOR2_NAME, build_multivec_segment, build_simple_segment,
 };
-use crate::types::{Distance, Filter, Payload, SnapshotFormat, WithPayload, WithVector};
+use crate::types::{Condition, Distance, Filter, Payload, SnapshotFormat, WithPayload, WithVector};
 
 #[test]
 fn test_search_batch_equivalence_single() {
@@ -76,6 +79,61 @@ fn test_search_batch_equivalence_single() {
     assert_eq!(search_result, search_batch_result[0].clone());
 }
 
+#[test]
+fn test_search_explanation() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let dim = 4;
+
+    let mut segment = build_simple_segment(dir.path(), dim, Distance::Dot).unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+
+    for id in 0..10u64 {
+        let vector = vec![id as f32, 1.0, 0.0, 1.0];
+        segment
+            .upsert_point(100, id.into(), only_default_vector(&vector), &hw_counter)
+            .unwrap();
+    }
+
+    let query_vector = [1.0, 1.0, 1.0, 1.0].into();
+    let ids: AHashSet<_> = vec![1, 2].into_iter().map(|x| x.into()).collect();
+    let filter = Filter::new_must(Condition::HasId(ids.into()));
+
+    let explanations = SearchExplanations::default();
+    let query_context = QueryContext::default().with_explanations(Some(explanations.clone()));
+    let segment_query_context = query_context.get_segment_query_context();
+
+    for filter in [None, Some(&filter)] {
+        segment
+            .search_batch(
+                DEFAULT_VECTOR_NAME,
+                &[&query_vector],
+                &WithPayload::default(),
+                &false.into(),
+                filter,
+                10,
+                None,
+                &segment_query_context,
+            )
+            .unwrap();
+    }
+
+    let explanations = explanations.take();
+    assert_eq!(explanations.len(), 2);
+    assert!(explanations.iter().all(|explanation| {
+        explanation.segment_id == segment.uuid && explanation.vector == DEFAULT_VECTOR_NAME
+    }));
+
+    assert_eq!(
+        explanations[0].explanation,
+        SearchExplanation::new(SearchStrategy::PlainScan),
+    );
+
+    let filtered = &explanations[1].explanation;
+    assert_eq!(filtered.strategy, SearchStrategy::PayloadIndex);
+    assert_eq!(filtered.estimated_cardinality, Some(2));
+}
+
 #[test]
 fn test_from_filter_attributes() {
     let data = r#"
@@ -716,3 +774,93 @@ fn test_handle_point_version() {
         .unwrap();
     assert!(applied);
 }
+
+#[test]
+fn test_vector_loading() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let dim = 4;
+
+    let mut segment = build_simple_segment(dir.path(), dim, Distance::Dot).unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let vec4 = vec![1.1, 1.0, 0.0, 1.0];
+    segment
+        .upsert_point(100, 4.into(), only_default_vector(&vec4), &hw_counter)
+        .unwrap();
+
+    // Deferred loading is started by the first read
+    let vector_data = &segment.vector_data[DEFAULT_VECTOR_NAME];
+    vector_data.lazy_load_pending.store(true, Ordering::Relaxed);
+    let vector = segment
+        .vector(DEFAULT_VECTOR_NAME, 4.into(), &hw_counter)
+        .unwrap()
+        .unwrap();
+    assert_eq!(vector, vec4.clone().into());
+    assert!(!vector_data.lazy_load_pending.load(Ordering::Relaxed));
+
+    // Taking files cancels deferred loading
+    vector_data.lazy_load_pending.store(true, Ordering::Relaxed);
+    let files = segment.take_vector_files(DEFAULT_VECTOR_NAME).unwrap();
+    assert!(!vector_data.lazy_load_pending.load(Ordering::Relaxed));
+    assert!(!files.is_empty());
+    assert!(files.iter().all(|path| path.starts_with(dir.path())));
+
+    populate_files(&files).unwrap();
+    clear_files_cache(&files).unwrap();
+
+    // Vectors are still readable once evicted from memory
+    let vector = segment
+        .vector(DEFAULT_VECTOR_NAME, 4.into(), &hw_counter)
+        .unwrap()
+        .unwrap();
+    assert_eq!(vector, vec4.into());
+
+    // Removed files are skipped
+    let missing = vec![dir.path().join("missing")];
+    populate_files(&missing).unwrap();
+    clear_files_cache(&missing).unwrap();
+
+    assert!(segment.take_vector_files("unknown").is_err());
+}
+
+#[test]
+fn test_alias_table_sample_filtered_weight() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use crate::index::field_index::numeric_index::alias_table::AliasTable;
+
+    // Half of the weight passes the check
+    let alias_table = AliasTable::new((0..100).map(|internal_id| (internal_id, 1.0)));
+    let is_sampled = |internal_id| internal_id < 50;
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let (accepted_ratio, sampled) = Segment::sample_from_alias_table(
+        &alias_table,
+        10,
+        is_sampled,
+        &AtomicBool::new(false),
+        &mut rng,
+    )
+    .unwrap();
+
+    assert_eq!(sampled.len(), 10);
+    assert!(sampled.iter().all(|&internal_id| is_sampled(internal_id)));
+    // Filtered weight is estimated from draws, not the total weight of the table
+    assert!(
+        (0.3..0.7).contains(&accepted_ratio),
+        "accepted ratio: {accepted_ratio}",
+    );
+
+    // Nothing passes the check
+    let mut rng = StdRng::seed_from_u64(42);
+    let sampled = Segment::sample_from_alias_table(
+        &alias_table,
+        10,
+        |_| false,
+        &AtomicBool::new(false),
+        &mut rng,
+    );
+    assert!(sampled.is_none());
+}
@@ -0,0 +1,103 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+
+use fs_err::File;
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::segment::{Segment, VectorData};
+use crate::types::VectorName;
+
+impl Segment {
+    /// Files of storage, quantized vectors and index of `vector_name`.
+    ///
+    /// Deferred loading of the vector on first use is cancelled, callers are expected to load or
+    /// evict the files with [`populate_files`] or [`clear_files_cache`]. Both are slow, and should
+    /// be called without holding the segment lock.
+    pub fn take_vector_files(&self, vector_name: &VectorName) -> OperationResult<Vec<PathBuf>> {
+        let vector_data = self.get_vector_data(vector_name)?;
+        vector_data
+            .lazy_load_pending
+            .store(false, Ordering::Relaxed);
+        Ok(Self::vector_files(vector_data))
+    }
+
+    /// Start loading the vector into memory in background on first use, if loading was deferred
+    /// on open.
+    ///
+    /// The current request reads from disk, until the data is loaded.
+    pub(super) fn ensure_vector_loaded(vector_data: &VectorData) {
+        if !vector_data.lazy_load_pending.swap(false, Ordering::Relaxed) {
+            return;
+        }
+
+        // Only components kept in RAM are loaded, on-disk ones are read on demand
+        let mut files = Vec::new();
+        {
+            let vector_storage = vector_data.vector_storage.borrow();
+            if !vector_storage.is_on_disk() {
+                files.extend(vector_storage.files());
+            }
+            let vector_index = vector_data.vector_index.borrow();
+            if !vector_index.is_on_disk() {
+                files.extend(vector_index.files());
+            }
+        }
+
+        let spawned = std::thread::Builder::new()
+            .name("vector-loading".to_string())
+            .spawn(move || {
+                if let Err(err) = populate_files(&files) {
+                    log::warn!("Failed to load vector into memory: {err}");
+                }
+            });
+        if let Err(err) = spawned {
+            log::warn!("Failed to spawn vector loading thread: {err}");
+        }
+    }
+
+    fn vector_files(vector_data: &VectorData) -> Vec<PathBuf> {
+        let mut files = vector_data.vector_storage.borrow().files();
+        if let Some(quantized_vectors) = vector_data.quantized_vectors.borrow().as_ref() {
+            files.extend(quantized_vectors.files());
+        }
+        files.extend(vector_data.vector_index.borrow().files());
+        files
+    }
+
+    fn get_vector_data(&self, vector_name: &VectorName) -> OperationResult<&VectorData> {
+        self.vector_data
+            .get(vector_name)
+            .ok_or_else(|| OperationError::vector_name_not_exists(vector_name))
+    }
+}
+
+/// Read `files` into the disk cache, so that mmaps of them are served from memory.
+///
+/// Files, removed in the meantime, e.g. by an optimization, are skipped.
+pub fn populate_files(files: &[PathBuf]) -> OperationResult<()> {
+    for path in files {
+        populate_file(path)?;
+    }
+    Ok(())
+}
+
+fn populate_file(path: &Path) -> io::Result<()> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    if file.metadata()?.is_file() {
+        io::copy(&mut file, &mut io::sink())?;
+    }
+    Ok(())
+}
+
+/// Evict `files` from the disk cache, they are read from disk on demand afterwards.
+pub fn clear_files_cache(files: &[PathBuf]) -> OperationResult<()> {
+    for path in files {
+        common::fs::clear_disk_cache(path)?;
+    }
+    Ok(())
+}
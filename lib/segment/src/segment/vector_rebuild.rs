@@ -0,0 +1,487 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use common::fs::{atomic_save_json, read_json};
+use common::types::PointOffsetType;
+use fs_err as fs;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::Segment;
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+#[cfg(feature = "rocksdb")]
+use crate::segment_constructor::rocksdb_builder::RocksDbBuilder;
+use crate::segment_constructor::{
+    VectorIndexBuildArgs, VectorIndexOpenArgs, build_vector_index, get_vector_index_path,
+    get_vector_storage_path, open_vector_index, open_vector_storage, sp,
+};
+use crate::types::{SegmentType, VectorDataConfig, VectorName, VectorNameBuf, VectorStorageType};
+use crate::vector_storage::quantized::quantized_vectors::{
+    QuantizedVectors, QuantizedVectorsStorageType,
+};
+use crate::vector_storage::{Sequential, VectorStorage, VectorStorageEnum};
+
+/// Suffix of directories holding a vector rebuilt with [`Segment::rebuild_vector`]
+const REBUILD_DIR_SUFFIX: &str = ".rebuild";
+
+/// Suffix of directories holding replaced vector data until the rebuild is applied
+const REPLACED_DIR_SUFFIX: &str = ".replaced";
+
+/// Marker of a rebuilt vector being moved in place.
+///
+/// Written once the rebuilt data is complete, removed once the segment state is updated. If
+/// present when the segment is loaded, the interrupted swap is finished first.
+const REBUILD_MARKER_FILE: &str = "vector_rebuild.json";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct VectorRebuildMarker {
+    vector_name: VectorNameBuf,
+    vector_config: VectorDataConfig,
+}
+
+/// Vector storage, quantization and index of a single named vector, built with a new config
+/// next to the live data of the segment.
+///
+/// Swap it in with [`Segment::apply_vector_rebuild`]. If dropped without applying, the built
+/// data is removed.
+#[derive(Debug)]
+pub struct RebuiltVector {
+    vector_name: VectorNameBuf,
+    vector_config: VectorDataConfig,
+    storage_path: PathBuf,
+    index_path: PathBuf,
+}
+
+impl RebuiltVector {
+    pub fn vector_name(&self) -> &VectorName {
+        &self.vector_name
+    }
+
+    pub fn vector_config(&self) -> &VectorDataConfig {
+        &self.vector_config
+    }
+}
+
+impl Drop for RebuiltVector {
+    fn drop(&mut self) {
+        for path in [&self.storage_path, &self.index_path] {
+            if path.exists()
+                && let Err(err) = fs::remove_dir_all(path)
+            {
+                log::error!(
+                    "Failed to remove rebuilt vector data {}: {err}",
+                    path.display(),
+                );
+            }
+        }
+    }
+}
+
+impl Segment {
+    /// Rebuild storage, quantization and index of a single dense vector with a new config.
+    ///
+    /// Unlike a full optimization, payload storage, payload index and other vectors are left
+    /// untouched, which makes changes to a single named vector config (e.g. enabling
+    /// quantization for it) much cheaper to apply. Only `&self` is required, so the segment
+    /// keeps serving requests while the vector is rebuilt. Nothing changes in the segment until
+    /// the result is applied with [`Segment::apply_vector_rebuild`].
+    ///
+    /// Only non-appendable segments can be rebuilt, as points inserted during the rebuild would
+    /// be missed. Size, distance, datatype and multivector config must stay the same.
+    pub fn rebuild_vector<R: Rng + ?Sized>(
+        &self,
+        vector_name: &VectorName,
+        vector_config: VectorDataConfig,
+        build_args: VectorIndexBuildArgs<R>,
+    ) -> OperationResult<RebuiltVector> {
+        self.check_vector_rebuild(vector_name, &vector_config)?;
+
+        let vector_data = &self.vector_data[vector_name];
+        let stopped = build_args.stopped;
+
+        let rebuilt = RebuiltVector {
+            vector_name: vector_name.to_owned(),
+            vector_config,
+            storage_path: with_suffix(
+                &get_vector_storage_path(&self.segment_path, vector_name),
+                REBUILD_DIR_SUFFIX,
+            ),
+            index_path: with_suffix(
+                &get_vector_index_path(&self.segment_path, vector_name),
+                REBUILD_DIR_SUFFIX,
+            ),
+        };
+
+        // Leftovers of an interrupted rebuild
+        for path in [&rebuilt.storage_path, &rebuilt.index_path] {
+            if path.exists() {
+                fs::remove_dir_all(path)?;
+            }
+        }
+        fs::create_dir_all(&rebuilt.storage_path)?;
+        fs::create_dir_all(&rebuilt.index_path)?;
+
+        let mut vector_storage = self.open_rebuilt_vector_storage(&rebuilt, stopped)?;
+        {
+            let old_storage = vector_data.vector_storage.borrow();
+            let mut vectors = (0..old_storage.total_vector_count() as PointOffsetType).map(|key| {
+                (
+                    old_storage.get_vector::<Sequential>(key),
+                    old_storage.is_deleted_vector(key),
+                )
+            });
+            vector_storage.update_from(&mut vectors, stopped)?;
+        }
+        vector_storage.flusher()()?;
+        check_process_stopped(stopped)?;
+
+        let quantized_vectors = rebuilt
+            .vector_config
+            .quantization_config
+            .as_ref()
+            .map(|quantization_config| {
+                QuantizedVectors::create(
+                    &vector_storage,
+                    quantization_config,
+                    QuantizedVectorsStorageType::Immutable,
+                    &rebuilt.storage_path,
+                    build_args.permit.num_cpus as usize,
+                    stopped,
+                )
+            })
+            .transpose()?;
+
+        let vector_storage = sp(vector_storage);
+        let quantized_vectors = sp(quantized_vectors);
+
+        let index = build_vector_index(
+            &rebuilt.vector_config,
+            VectorIndexOpenArgs {
+                path: &rebuilt.index_path,
+                id_tracker: self.id_tracker.clone(),
+                vector_storage: vector_storage.clone(),
+                payload_index: self.payload_index.clone(),
+                quantized_vectors: quantized_vectors.clone(),
+            },
+            build_args,
+        )?;
+
+        // Built data is persisted, it is loaded back from disk when applied
+        index.clear_cache()?;
+        drop(index);
+        if let Some(quantized_vectors) = quantized_vectors.borrow().as_ref() {
+            quantized_vectors.clear_cache()?;
+        }
+        vector_storage.borrow().clear_cache()?;
+
+        Ok(rebuilt)
+    }
+
+    /// Replace storage, quantization and index of a vector with the ones built by
+    /// [`Segment::rebuild_vector`], and persist the new vector config.
+    ///
+    /// Vectors deleted while the rebuild was running are deleted in the rebuilt storage as well.
+    /// The swap is crash-safe: once started, it is finished by [`recover_vector_rebuild`] when
+    /// the segment is loaded.
+    pub fn apply_vector_rebuild(&mut self, rebuilt: RebuiltVector) -> OperationResult<()> {
+        let stopped = AtomicBool::new(false);
+        let vector_name = rebuilt.vector_name.as_str();
+        self.check_vector_rebuild(vector_name, &rebuilt.vector_config)?;
+
+        let storage_path = get_vector_storage_path(&self.segment_path, vector_name);
+        let index_path = get_vector_index_path(&self.segment_path, vector_name);
+
+        let vector_data = &self.vector_data[vector_name];
+
+        // Carry over vector deletions which happened during the rebuild
+        {
+            let mut vector_storage = self.open_rebuilt_vector_storage(&rebuilt, &stopped)?;
+            let old_storage = vector_data.vector_storage.borrow();
+            for key in 0..old_storage.total_vector_count() as PointOffsetType {
+                if old_storage.is_deleted_vector(key) && !vector_storage.is_deleted_vector(key) {
+                    vector_storage.delete_vector(key)?;
+                }
+            }
+            vector_storage.flusher()()?;
+        }
+
+        // From here on, the rebuilt data is complete, and the swap is finished on load if
+        // interrupted
+        remove_replaced_dirs(&self.segment_path, vector_name)?;
+        let marker_path = self.segment_path.join(REBUILD_MARKER_FILE);
+        atomic_save_json(
+            &marker_path,
+            &VectorRebuildMarker {
+                vector_name: rebuilt.vector_name.clone(),
+                vector_config: rebuilt.vector_config.clone(),
+            },
+        )?;
+
+        // Move rebuilt data in place. Live components keep their files open, so they remain
+        // usable until replaced below.
+        move_rebuilt_dirs(&self.segment_path, vector_name)?;
+
+        let vector_storage = open_vector_storage(
+            #[cfg(feature = "rocksdb")]
+            &mut RocksDbBuilder::new(&storage_path, &self.segment_config)?,
+            &rebuilt.vector_config,
+            #[cfg(feature = "rocksdb")]
+            &stopped,
+            &storage_path,
+            #[cfg(feature = "rocksdb")]
+            vector_name,
+        )?;
+        *vector_data.vector_storage.borrow_mut() = vector_storage;
+
+        let quantized_vectors = match &rebuilt.vector_config.quantization_config {
+            Some(quantization_config) => QuantizedVectors::load(
+                quantization_config,
+                &vector_data.vector_storage.borrow(),
+                &storage_path,
+                &stopped,
+            )?,
+            None => None,
+        };
+        *vector_data.quantized_vectors.borrow_mut() = quantized_vectors;
+
+        let vector_index = open_vector_index(
+            &rebuilt.vector_config,
+            VectorIndexOpenArgs {
+                path: &index_path,
+                id_tracker: self.id_tracker.clone(),
+                vector_storage: vector_data.vector_storage.clone(),
+                payload_index: self.payload_index.clone(),
+                quantized_vectors: vector_data.quantized_vectors.clone(),
+            },
+        )?;
+        *vector_data.vector_index.borrow_mut() = vector_index;
+
+        self.segment_config
+            .vector_data
+            .insert(rebuilt.vector_name.clone(), rebuilt.vector_config.clone());
+        self.segment_type = if self.segment_config.is_any_vector_indexed() {
+            SegmentType::Indexed
+        } else {
+            SegmentType::Plain
+        };
+        self.save_current_state()?;
+
+        remove_replaced_dirs(&self.segment_path, vector_name)?;
+        fs::remove_file(&marker_path)?;
+
+        Ok(())
+    }
+
+    /// Check that the vector can be rebuilt with the given config
+    pub fn check_vector_rebuild(
+        &self,
+        vector_name: &VectorName,
+        vector_config: &VectorDataConfig,
+    ) -> OperationResult<()> {
+        let Some(current_config) = self.segment_config.vector_data.get(vector_name) else {
+            return Err(OperationError::vector_name_not_exists(vector_name));
+        };
+
+        if self.appendable_flag {
+            return Err(OperationError::validation_error(
+                "Vectors can only be rebuilt in non-appendable segments",
+            ));
+        }
+
+        if vector_config.is_appendable() {
+            return Err(OperationError::validation_error(format!(
+                "Vector {vector_name} can't be rebuilt into appendable storage and index",
+            )));
+        }
+
+        if vector_config.storage_type == VectorStorageType::Memory {
+            return Err(OperationError::validation_error(format!(
+                "Vector {vector_name} can't be rebuilt into in-memory storage",
+            )));
+        }
+
+        if vector_config.size != current_config.size
+            || vector_config.distance != current_config.distance
+            || vector_config.datatype != current_config.datatype
+            || vector_config.multivector_config != current_config.multivector_config
+        {
+            return Err(OperationError::validation_error(format!(
+                "Vector {vector_name} can't be rebuilt with different size, distance, datatype or multivector config",
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn open_rebuilt_vector_storage(
+        &self,
+        rebuilt: &RebuiltVector,
+        #[cfg_attr(not(feature = "rocksdb"), expect(unused_variables))] stopped: &AtomicBool,
+    ) -> OperationResult<VectorStorageEnum> {
+        open_vector_storage(
+            #[cfg(feature = "rocksdb")]
+            &mut RocksDbBuilder::new(&rebuilt.storage_path, &self.segment_config)?,
+            &rebuilt.vector_config,
+            #[cfg(feature = "rocksdb")]
+            stopped,
+            &rebuilt.storage_path,
+            #[cfg(feature = "rocksdb")]
+            &rebuilt.vector_name,
+        )
+    }
+}
+
+/// Finish the swap of a rebuilt vector, interrupted by a crash, or remove leftovers of an
+/// interrupted rebuild. Must be called before the segment state is loaded.
+pub(crate) fn recover_vector_rebuild(segment_path: &Path) -> OperationResult<()> {
+    let marker_path = segment_path.join(REBUILD_MARKER_FILE);
+    if !marker_path.exists() {
+        // Rebuild was interrupted before it was applied, its data is incomplete
+        let state = Segment::load_state(segment_path)?;
+        for vector_name in state.config.vector_data.keys() {
+            for path in [
+                get_vector_storage_path(segment_path, vector_name),
+                get_vector_index_path(segment_path, vector_name),
+            ] {
+                for suffix in [REBUILD_DIR_SUFFIX, REPLACED_DIR_SUFFIX] {
+                    let leftover_path = with_suffix(&path, suffix);
+                    if leftover_path.exists() {
+                        fs::remove_dir_all(&leftover_path)?;
+                    }
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let VectorRebuildMarker {
+        vector_name,
+        vector_config,
+    } = read_json(&marker_path)?;
+    log::warn!(
+        "Finishing interrupted rebuild of vector {vector_name} in segment {}",
+        segment_path.display(),
+    );
+
+    move_rebuilt_dirs(segment_path, &vector_name)?;
+
+    let mut state = Segment::load_state(segment_path)?;
+    state
+        .config
+        .vector_data
+        .insert(vector_name.clone(), vector_config);
+    Segment::save_state(&state, segment_path)?;
+
+    remove_replaced_dirs(segment_path, &vector_name)?;
+    fs::remove_file(&marker_path)?;
+
+    Ok(())
+}
+
+/// Move rebuilt storage and index of a vector in place of the live ones.
+///
+/// Idempotent, so that an interrupted move can be repeated.
+fn move_rebuilt_dirs(segment_path: &Path, vector_name: &VectorName) -> OperationResult<()> {
+    for path in [
+        get_vector_storage_path(segment_path, vector_name),
+        get_vector_index_path(segment_path, vector_name),
+    ] {
+        let rebuilt_path = with_suffix(&path, REBUILD_DIR_SUFFIX);
+        let replaced_path = with_suffix(&path, REPLACED_DIR_SUFFIX);
+        if !rebuilt_path.exists() {
+            // Already moved
+            continue;
+        }
+        if path.exists() {
+            if replaced_path.exists() {
+                fs::remove_dir_all(&replaced_path)?;
+            }
+            fs::rename(&path, &replaced_path)?;
+        }
+        fs::rename(&rebuilt_path, &path)?;
+    }
+    Ok(())
+}
+
+fn remove_replaced_dirs(segment_path: &Path, vector_name: &VectorName) -> OperationResult<()> {
+    for path in [
+        get_vector_storage_path(segment_path, vector_name),
+        get_vector_index_path(segment_path, vector_name),
+    ] {
+        let replaced_path = with_suffix(&path, REPLACED_DIR_SUFFIX);
+        if replaced_path.exists() {
+            fs::remove_dir_all(&replaced_path)?;
+        }
+    }
+    Ok(())
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::data_types::vectors::DEFAULT_VECTOR_NAME;
+    use crate::segment_constructor::simple_segment_constructor::build_simple_segment;
+    use crate::types::Distance;
+
+    #[test]
+    fn test_recover_interrupted_swap() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segment = build_simple_segment(dir.path(), 4, Distance::Dot).unwrap();
+        let segment_path = segment.segment_path.clone();
+        let mut vector_config = segment.segment_config.vector_data[DEFAULT_VECTOR_NAME].clone();
+        vector_config.storage_type = VectorStorageType::Mmap;
+        drop(segment);
+
+        // Interrupted after the marker is written, before rebuilt data is moved in place
+        let storage_path = get_vector_storage_path(&segment_path, DEFAULT_VECTOR_NAME);
+        let rebuilt_storage_path = with_suffix(&storage_path, REBUILD_DIR_SUFFIX);
+        fs::create_dir_all(&rebuilt_storage_path).unwrap();
+        fs::write(rebuilt_storage_path.join("rebuilt"), b"").unwrap();
+        atomic_save_json(
+            &segment_path.join(REBUILD_MARKER_FILE),
+            &VectorRebuildMarker {
+                vector_name: DEFAULT_VECTOR_NAME.to_owned(),
+                vector_config: vector_config.clone(),
+            },
+        )
+        .unwrap();
+
+        recover_vector_rebuild(&segment_path).unwrap();
+
+        assert!(storage_path.join("rebuilt").exists());
+        assert!(!rebuilt_storage_path.exists());
+        assert!(!with_suffix(&storage_path, REPLACED_DIR_SUFFIX).exists());
+        assert!(!segment_path.join(REBUILD_MARKER_FILE).exists());
+
+        let state = Segment::load_state(&segment_path).unwrap();
+        assert_eq!(state.config.vector_data[DEFAULT_VECTOR_NAME], vector_config);
+    }
+
+    #[test]
+    fn test_remove_interrupted_rebuild() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segment = build_simple_segment(dir.path(), 4, Distance::Dot).unwrap();
+        let segment_path = segment.segment_path.clone();
+        let vector_config = segment.segment_config.vector_data[DEFAULT_VECTOR_NAME].clone();
+        drop(segment);
+
+        // Rebuilt data without a marker is incomplete
+        let index_path = get_vector_index_path(&segment_path, DEFAULT_VECTOR_NAME);
+        let rebuilt_index_path = with_suffix(&index_path, REBUILD_DIR_SUFFIX);
+        fs::create_dir_all(&rebuilt_index_path).unwrap();
+
+        recover_vector_rebuild(&segment_path).unwrap();
+
+        assert!(!rebuilt_index_path.exists());
+        let state = Segment::load_state(&segment_path).unwrap();
+        assert_eq!(state.config.vector_data[DEFAULT_VECTOR_NAME], vector_config);
+    }
+}
@@ -7,6 +7,7 @@ use common::small_uint::U24;
 use common::types::PointOffsetType;
 
 use crate::data_types::named_vectors::CowVector;
+use crate::id_tracker::MergedPointId;
 use crate::types::CompactExtendedPointId;
 use crate::vector_storage::{Sequential, VectorStorage, VectorStorageEnum};
 
@@ -23,6 +24,18 @@ pub struct PointData {
     pub ordering: u64,
 }
 
+impl From<MergedPointId> for PointData {
+    fn from(item: MergedPointId) -> Self {
+        PointData {
+            external_id: CompactExtendedPointId::from(item.external_id),
+            segment_index: U24::new_wrapped(item.tracker_index as u32),
+            internal_id: item.internal_id,
+            version: item.version,
+            ordering: 0,
+        }
+    }
+}
+
 /// Batched iterator over points to insert.
 /// This structure should read `BATCH_SIZE` points into a buffer,
 /// and then iterate over them.
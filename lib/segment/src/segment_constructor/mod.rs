@@ -1,6 +1,7 @@
 mod batched_reader;
+mod point_data_spill;
 #[cfg(feature = "rocksdb")]
-mod rocksdb_builder;
+pub(crate) mod rocksdb_builder;
 pub mod segment_builder;
 mod segment_constructor_base;
 #[cfg(any(test, feature = "testing"))]
@@ -0,0 +1,189 @@
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use common::small_uint::U24;
+use common::types::PointOffsetType;
+use fs_err as fs;
+use fs_err::File;
+use uuid::Uuid;
+
+use crate::common::operation_error::OperationResult;
+use crate::segment_constructor::batched_reader::PointData;
+use crate::types::{CompactExtendedPointId, ExtendedPointId};
+
+/// Size of a single serialized [`PointData`] record:
+/// id kind (1) + external id (16) + segment index (4) + internal id (4) + version (8)
+const RECORD_SIZE: usize = 1 + 16 + 4 + 4 + 8;
+
+const NUM_ID_KIND: u8 = 0;
+const UUID_KIND: u8 = 1;
+
+/// Writes [`PointData`] records into a temporary file, so that the list of points to insert
+/// does not have to be kept in memory during segment construction.
+///
+/// Ordering values are not persisted, they are computed per chunk after reading.
+pub struct PointDataSpillWriter {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    len: usize,
+}
+
+impl PointDataSpillWriter {
+    pub fn create(path: &Path) -> OperationResult<Self> {
+        let file = File::create(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: BufWriter::new(file),
+            len: 0,
+        })
+    }
+
+    pub fn push(&mut self, point: &PointData) -> OperationResult<()> {
+        let mut record = [0u8; RECORD_SIZE];
+
+        match ExtendedPointId::from(point.external_id) {
+            ExtendedPointId::NumId(num) => {
+                record[0] = NUM_ID_KIND;
+                record[1..9].copy_from_slice(&num.to_le_bytes());
+            }
+            ExtendedPointId::Uuid(uuid) => {
+                record[0] = UUID_KIND;
+                record[1..17].copy_from_slice(uuid.as_bytes());
+            }
+        }
+        record[17..21].copy_from_slice(&point.segment_index.get().to_le_bytes());
+        record[21..25].copy_from_slice(&point.internal_id.to_le_bytes());
+        record[25..33].copy_from_slice(&point.version.to_le_bytes());
+
+        self.writer.write_all(&record)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Flush all records and reopen the file for reading.
+    pub fn finish(self) -> OperationResult<PointDataSpillReader> {
+        let Self { path, writer, len } = self;
+
+        let file = writer.into_inner().map_err(|err| err.into_error())?;
+        file.sync_all()?;
+        drop(file);
+
+        let reader = BufReader::new(File::open(&path)?);
+        Ok(PointDataSpillReader {
+            path,
+            reader,
+            remaining: len,
+        })
+    }
+}
+
+/// Reads back records written by [`PointDataSpillWriter`] in chunks.
+///
+/// The underlying file is removed on drop.
+pub struct PointDataSpillReader {
+    path: PathBuf,
+    reader: BufReader<File>,
+    remaining: usize,
+}
+
+impl PointDataSpillReader {
+    /// Replace content of `chunk` with up to `max_len` next records.
+    ///
+    /// Returns `false` if there are no more records to read.
+    pub fn read_chunk(
+        &mut self,
+        chunk: &mut Vec<PointData>,
+        max_len: usize,
+    ) -> OperationResult<bool> {
+        chunk.clear();
+
+        let mut record = [0u8; RECORD_SIZE];
+        while chunk.len() < max_len && self.remaining > 0 {
+            self.reader.read_exact(&mut record)?;
+            self.remaining -= 1;
+
+            let external_id = match record[0] {
+                NUM_ID_KIND => {
+                    ExtendedPointId::NumId(u64::from_le_bytes(record[1..9].try_into().unwrap()))
+                }
+                _ => ExtendedPointId::Uuid(Uuid::from_bytes(record[1..17].try_into().unwrap())),
+            };
+
+            chunk.push(PointData {
+                external_id: CompactExtendedPointId::from(external_id),
+                segment_index: U24::new_wrapped(u32::from_le_bytes(
+                    record[17..21].try_into().unwrap(),
+                )),
+                internal_id: PointOffsetType::from_le_bytes(record[21..25].try_into().unwrap()),
+                version: u64::from_le_bytes(record[25..33].try_into().unwrap()),
+                ordering: 0,
+            });
+        }
+
+        Ok(!chunk.is_empty())
+    }
+}
+
+impl Drop for PointDataSpillReader {
+    fn drop(&mut self) {
+        if let Err(err) = fs::remove_file(&self.path) {
+            log::warn!(
+                "Failed to remove spilled point mapping {}: {err}",
+                self.path.display(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+
+    #[test]
+    fn test_spill_roundtrip() {
+        let dir = Builder::new().prefix("point_data_spill").tempdir().unwrap();
+        let path = dir.path().join("points.spill");
+
+        let points = (0..1000u64)
+            .map(|i| PointData {
+                external_id: if i % 2 == 0 {
+                    CompactExtendedPointId::from(ExtendedPointId::NumId(i * 7))
+                } else {
+                    CompactExtendedPointId::from(ExtendedPointId::Uuid(Uuid::from_u128(
+                        (u128::from(i) << 64) | 42,
+                    )))
+                },
+                segment_index: U24::new_wrapped((i % 3) as u32),
+                internal_id: i as PointOffsetType + 10,
+                version: i * 100,
+                ordering: 0,
+            })
+            .collect::<Vec<_>>();
+
+        let mut writer = PointDataSpillWriter::create(&path).unwrap();
+        for point in &points {
+            writer.push(point).unwrap();
+        }
+        let mut reader = writer.finish().unwrap();
+
+        let mut read = Vec::new();
+        let mut chunk = Vec::new();
+        while reader.read_chunk(&mut chunk, 300).unwrap() {
+            assert!(chunk.len() <= 300);
+            read.append(&mut chunk);
+        }
+
+        assert_eq!(read.len(), points.len());
+        for (expected, actual) in points.iter().zip(&read) {
+            assert_eq!(expected.external_id, actual.external_id);
+            assert_eq!(expected.segment_index.get(), actual.segment_index.get());
+            assert_eq!(expected.internal_id, actual.internal_id);
+            assert_eq!(expected.version, actual.version);
+        }
+
+        drop(reader);
+        assert!(!path.exists());
+    }
+}
@@ -7,7 +7,7 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
 use ahash::AHasher;
-use atomic_refcell::AtomicRefCell;
+use atomic_refcell::{AtomicRef, AtomicRefCell};
 use bitvec::macros::internal::funty::Integral;
 use common::budget::ResourcePermit;
 use common::counter::hardware_counter::HardwareCounterCell;
@@ -19,6 +19,7 @@ use common::types::PointOffsetType;
 use fs_err as fs;
 use itertools::Itertools;
 use rand::Rng;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use tempfile::TempDir;
 use uuid::Uuid;
 
@@ -39,23 +40,27 @@ use crate::id_tracker::{IdTracker, IdTrackerEnum, for_each_unique_point};
 use crate::index::field_index::FieldIndex;
 use crate::index::sparse_index::sparse_vector_index::SparseVectorIndexOpenArgs;
 use crate::index::struct_payload_index::StructPayloadIndex;
-use crate::index::{PayloadIndex, VectorIndexEnum};
+use crate::index::{BuildIndexResult, PayloadIndex, VectorIndexEnum};
 use crate::payload_storage::PayloadStorage;
 use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::segment::{Segment, SegmentVersion};
 use crate::segment_constructor::batched_reader::{BatchedVectorReader, PointData};
+use crate::segment_constructor::point_data_spill::PointDataSpillWriter;
 use crate::segment_constructor::{
     VectorIndexBuildArgs, VectorIndexOpenArgs, build_vector_index, load_segment,
 };
 use crate::types::{
-    CompactExtendedPointId, ExtendedPointId, HnswGlobalConfig, PayloadFieldSchema, PayloadKeyType,
-    SegmentConfig, SegmentState, SeqNumberType, VectorNameBuf,
+    ExtendedPointId, HnswGlobalConfig, PayloadFieldSchema, PayloadKeyType, SegmentConfig,
+    SegmentState, SeqNumberType, VectorNameBuf,
 };
 use crate::vector_storage::quantized::quantized_vectors::{
     QuantizedVectors, QuantizedVectorsStorageType,
 };
 use crate::vector_storage::{VectorStorage, VectorStorageEnum};
 
+/// File in the temporary segment directory, used to spill point mappings which exceed the memory budget
+const POINT_DATA_SPILL_FILE: &str = "point_data.spill";
+
 /// Structure for constructing segment out of several other segments
 pub struct SegmentBuilder {
     version: SeqNumberType,
@@ -71,6 +76,9 @@ pub struct SegmentBuilder {
 
     // Payload key to defragment data to
     defragment_keys: Vec<PayloadKeyType>,
+
+    // Memory budget in bytes for intermediate point mappings, unlimited if not set
+    memory_budget: Option<usize>,
 }
 
 struct VectorData {
@@ -159,6 +167,7 @@ impl SegmentBuilder {
             temp_dir,
             indexed_fields: Default::default(),
             defragment_keys: vec![],
+            memory_budget: None,
         })
     }
 
@@ -166,6 +175,20 @@ impl SegmentBuilder {
         self.defragment_keys = keys;
     }
 
+    /// Limit memory used for intermediate point mappings in [`SegmentBuilder::update`].
+    ///
+    /// Source points which don't fit into `bytes` are spilled to a temporary file and
+    /// streamed into the new segment in chunks.
+    pub fn set_memory_budget(&mut self, bytes: Option<usize>) {
+        self.memory_budget = bytes;
+    }
+
+    /// Number of points to keep in memory at once, according to the memory budget
+    fn max_points_in_memory(&self) -> Option<usize> {
+        self.memory_budget
+            .map(|bytes| (bytes / size_of::<PointData>()).max(1))
+    }
+
     pub fn remove_indexed_field(&mut self, field: &PayloadKeyType) {
         self.indexed_fields.remove(field);
     }
@@ -262,6 +285,14 @@ impl SegmentBuilder {
                     }
                     break;
                 }
+                FieldIndex::IpIndex(index) => {
+                    if let Some(addresses) = index.get_values(internal_id) {
+                        for address in addresses {
+                            ordering = ordering.wrapping_add(address as u64);
+                        }
+                    }
+                    break;
+                }
                 FieldIndex::GeoIndex(_) => {}
                 FieldIndex::FullTextIndex(_) => {}
                 FieldIndex::BoolIndex(_) => {}
@@ -276,6 +307,10 @@ impl SegmentBuilder {
     /// However only points in the same call get defragmented and grouped together.
     /// Therefore this function should only be called once, unless this behavior is desired.
     ///
+    /// If a memory budget is set with [`SegmentBuilder::set_memory_budget`] and the point mapping
+    /// of `segments` doesn't fit into it, the mapping is spilled to disk and points are inserted
+    /// chunk by chunk. Defragmentation then only groups points within the same chunk.
+    ///
     /// # Result
     ///
     /// * `bool` - if `true` - data successfully added, if `false` - process was interrupted
@@ -293,23 +328,87 @@ impl SegmentBuilder {
         let max_point_count = locked_id_trackers
             .iter()
             .map(|id_tracker| id_tracker.available_point_count())
-            .max();
-        let mut points_to_insert = Vec::with_capacity(max_point_count.unwrap_or_default());
-        for_each_unique_point(locked_id_trackers.iter().map(|i| i.deref()), |item| {
-            points_to_insert.push(PointData {
-                external_id: CompactExtendedPointId::from(item.external_id),
-                segment_index: U24::new_wrapped(item.tracker_index as u32),
-                internal_id: item.internal_id,
-                version: item.version,
-                ordering: 0,
-            });
-        });
-        drop(locked_id_trackers);
+            .max()
+            .unwrap_or_default();
+        let total_point_count: usize = locked_id_trackers
+            .iter()
+            .map(|id_tracker| id_tracker.available_point_count())
+            .sum();
+
+        let chunk_size = self
+            .max_points_in_memory()
+            .filter(|&chunk_size| total_point_count > chunk_size);
 
         let payloads: Vec<_> = segments.iter().map(|i| i.payload_index.borrow()).collect();
 
+        for (vector_name, vector_data) in &mut self.vector_data {
+            for segment in segments {
+                let other_vector_data = segment.vector_data.get(vector_name).ok_or_else(|| {
+                    OperationError::service_error(format!(
+                        "Cannot update from other segment because it is \
+                         missing vector name {vector_name}"
+                    ))
+                })?;
+
+                vector_data
+                    .old_indices
+                    .push(Arc::clone(&other_vector_data.vector_index));
+            }
+        }
+
+        match chunk_size {
+            None => {
+                let mut points_to_insert = Vec::with_capacity(max_point_count);
+                for_each_unique_point(locked_id_trackers.iter().map(|i| i.deref()), |item| {
+                    points_to_insert.push(PointData::from(item));
+                });
+                drop(locked_id_trackers);
+
+                self.insert_points(segments, &payloads, &mut points_to_insert, stopped)?;
+            }
+            Some(chunk_size) => {
+                let spill_path = self.temp_dir.path().join(POINT_DATA_SPILL_FILE);
+                let mut spill_writer = PointDataSpillWriter::create(&spill_path)?;
+                let mut spill_result = Ok(());
+                for_each_unique_point(locked_id_trackers.iter().map(|i| i.deref()), |item| {
+                    if spill_result.is_ok() {
+                        spill_result = spill_writer.push(&PointData::from(item));
+                    }
+                });
+                drop(locked_id_trackers);
+                spill_result?;
+
+                let mut spill_reader = spill_writer.finish()?;
+                let mut points_to_insert = Vec::with_capacity(chunk_size);
+                while spill_reader.read_chunk(&mut points_to_insert, chunk_size)? {
+                    self.insert_points(segments, &payloads, &mut points_to_insert, stopped)?;
+                }
+            }
+        }
+
+        let src_segment_max_version = segments.iter().map(|i| i.version()).max().unwrap();
+        self.version = cmp::max(self.version, src_segment_max_version);
+
+        for payload in payloads {
+            for (field, payload_schema) in payload.indexed_fields() {
+                self.indexed_fields.insert(field, payload_schema);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Insert vectors, payloads and id mappings of `points_to_insert` into the new segment,
+    /// reading them from `segments`.
+    fn insert_points(
+        &mut self,
+        segments: &[&Segment],
+        payloads: &[AtomicRef<StructPayloadIndex>],
+        points_to_insert: &mut [PointData],
+        stopped: &AtomicBool,
+    ) -> OperationResult<()> {
         for defragment_key in &self.defragment_keys {
-            for point_data in &mut points_to_insert {
+            for point_data in points_to_insert.iter_mut() {
                 let Some(payload_indices) = payloads[point_data.segment_index.get() as usize]
                     .field_indexes
                     .get(defragment_key)
@@ -328,9 +427,6 @@ impl SegmentBuilder {
             points_to_insert.sort_unstable_by_key(|i| i.ordering);
         }
 
-        let src_segment_max_version = segments.iter().map(|i| i.version()).max().unwrap();
-        self.version = cmp::max(self.version, src_segment_max_version);
-
         let vector_storages: Vec<_> = segments.iter().map(|i| &i.vector_data).collect();
 
         let internal_range_start = self.id_tracker.available_point_count() as PointOffsetType;
@@ -351,16 +447,12 @@ impl SegmentBuilder {
                         ))
                     })?;
 
-                    vector_data
-                        .old_indices
-                        .push(Arc::clone(&other_vector_data.vector_index));
-
                     Ok(other_vector_data.vector_storage.borrow())
                 })
                 .collect::<Result<Vec<_>, OperationError>>()?;
 
             let mut vectors_iter: BatchedVectorReader =
-                BatchedVectorReader::new(&points_to_insert, &other_vector_storages);
+                BatchedVectorReader::new(points_to_insert, &other_vector_storages);
 
             let internal_range = vector_data
                 .vector_storage
@@ -449,13 +541,7 @@ impl SegmentBuilder {
             }
         }
 
-        for payload in payloads {
-            for (field, payload_schema) in payload.indexed_fields() {
-                self.indexed_fields.insert(field, payload_schema);
-            }
-        }
-
-        Ok(true)
+        Ok(())
     }
 
     /// Test wrapper for [`SegmentBuilder::build`].
@@ -497,6 +583,7 @@ impl SegmentBuilder {
                 temp_dir,
                 indexed_fields,
                 defragment_keys: _,
+                memory_budget: _,
             } = self;
 
             let progress_quantization = progress_segment.subtask("quantization");
@@ -590,11 +677,13 @@ impl SegmentBuilder {
                 appendable_flag,
                 true,
             )?;
-            for (field, payload_schema, progress) in indexed_fields {
-                progress.start();
-                payload_index.set_indexed(&field, payload_schema, hw_counter)?;
-                check_process_stopped(stopped)?;
-            }
+            Self::build_payload_indices(
+                &mut payload_index,
+                indexed_fields,
+                &permit,
+                stopped,
+                hw_counter,
+            )?;
             drop(progress_payload_index);
 
             payload_index.flusher()()?;
@@ -726,6 +815,75 @@ impl SegmentBuilder {
         load_segment(&destination_path, segment_uuid, stopped)
     }
 
+    /// Build indices for all indexed payload fields.
+    ///
+    /// Fields are independent of each other, so with more than one CPU in the permit they are
+    /// built concurrently on a dedicated pool of `permit.num_cpus` threads. Built indices are
+    /// applied one by one afterwards, as that updates the shared payload index config.
+    fn build_payload_indices(
+        payload_index: &mut StructPayloadIndex,
+        indexed_fields: Vec<(PayloadKeyType, PayloadFieldSchema, ProgressTracker)>,
+        permit: &ResourcePermit,
+        stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let num_threads = cmp::min(permit.num_cpus as usize, indexed_fields.len());
+
+        if num_threads <= 1 {
+            for (field, payload_schema, progress) in indexed_fields {
+                progress.start();
+                payload_index.set_indexed(&field, payload_schema, hw_counter)?;
+                check_process_stopped(stopped)?;
+            }
+            return Ok(());
+        }
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .thread_name(|idx| format!("payload-index-build-{idx}"))
+            .num_threads(num_threads)
+            .build()?;
+
+        // Hardware counters are not shareable between threads, give each field its own
+        let tasks = indexed_fields
+            .into_iter()
+            .map(|(field, payload_schema, progress)| {
+                (field, payload_schema, progress, hw_counter.fork())
+            })
+            .collect::<Vec<_>>();
+
+        let index: &StructPayloadIndex = payload_index;
+        let built = pool.install(|| {
+            tasks
+                .into_par_iter()
+                .map(
+                    |(field, payload_schema, progress, hw_counter)| -> OperationResult<_> {
+                        check_process_stopped(stopped)?;
+                        progress.start();
+                        let result =
+                            index.build_index(&field, &payload_schema, stopped, &hw_counter)?;
+                        Ok((field, payload_schema, result))
+                    },
+                )
+                .collect::<OperationResult<Vec<_>>>()
+        })?;
+
+        for (field, payload_schema, result) in built {
+            match result {
+                BuildIndexResult::Built(field_index) => {
+                    payload_index.apply_index(field, payload_schema, field_index)?;
+                }
+                BuildIndexResult::AlreadyBuilt => {}
+                BuildIndexResult::IncompatibleSchema => {
+                    return Err(OperationError::service_error(format!(
+                        "Incompatible schema for field `{field}` in a newly built segment",
+                    )));
+                }
+            }
+        }
+
+        check_process_stopped(stopped)
+    }
+
     fn update_quantization(
         segment_config: &SegmentConfig,
         vector_storages: &HashMap<VectorNameBuf, VectorData>,
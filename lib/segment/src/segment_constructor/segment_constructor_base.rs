@@ -9,7 +9,7 @@ use common::budget::ResourcePermit;
 use common::flags::FeatureFlags;
 use common::fs::{safe_delete_with_suffix, sync_parent_dir};
 use common::is_alive_lock::IsAliveLock;
-use common::mmap::{Advice, AdviceSetting};
+use common::mmap::{Advice, AdviceSetting, MmapComponent, advice};
 use common::progress_tracker::ProgressTracker;
 use common::storage_version::StorageVersion;
 use fs_err as fs;
@@ -48,6 +48,7 @@ use crate::payload_storage::on_disk_payload_storage::OnDiskPayloadStorage;
 use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 #[cfg(feature = "rocksdb")]
 use crate::payload_storage::simple_payload_storage::SimplePayloadStorage;
+use crate::segment::vector_rebuild::recover_vector_rebuild;
 use crate::segment::{SEGMENT_STATE_FILE, Segment, SegmentVersion, VectorData};
 #[cfg(feature = "rocksdb")]
 use crate::types::MultiVectorConfig;
@@ -56,14 +57,17 @@ use crate::types::{
     SegmentType, SeqNumberType, SparseVectorStorageType, VectorDataConfig, VectorName,
     VectorStorageDatatype, VectorStorageType,
 };
+use crate::vector_storage::backend_registry::open_custom_vector_storage;
 use crate::vector_storage::dense::memmap_dense_vector_storage::{
     open_memmap_vector_storage, open_memmap_vector_storage_byte, open_memmap_vector_storage_half,
 };
 #[cfg(feature = "rocksdb")]
 use crate::vector_storage::dense::simple_dense_vector_storage::open_simple_dense_vector_storage;
 use crate::vector_storage::multi_dense::appendable_mmap_multi_dense_vector_storage::{
-    open_appendable_memmap_multi_vector_storage, open_appendable_memmap_vector_storage,
+    appendable_memmap_multi_vector_storage_exists, open_appendable_memmap_multi_vector_storage,
+    open_appendable_memmap_vector_storage,
 };
+use crate::vector_storage::multi_dense::mmap_multi_dense_vector_storage::open_memmap_multi_vector_storage;
 #[cfg(feature = "rocksdb")]
 use crate::vector_storage::multi_dense::simple_multi_dense_vector_storage::open_simple_multi_dense_vector_storage;
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
@@ -74,7 +78,7 @@ pub const PAYLOAD_INDEX_PATH: &str = "payload_index";
 pub const VECTOR_STORAGE_PATH: &str = "vector_storage";
 pub const VECTOR_INDEX_PATH: &str = "vector_index";
 
-fn sp<T>(t: T) -> Arc<AtomicRefCell<T>> {
+pub(crate) fn sp<T>(t: T) -> Arc<AtomicRefCell<T>> {
     Arc::new(AtomicRefCell::new(t))
 }
 
@@ -105,8 +109,13 @@ fn open_mmap_vector_storage(
 ) -> OperationResult<VectorStorageEnum> {
     let storage_element_type = vector_config.datatype.unwrap_or_default();
     if let Some(multi_vec_config) = &vector_config.multivector_config {
-        // there are no mmap multi vector storages, appendable only
-        open_appendable_memmap_multi_vector_storage(
+        // Segments created before the mmap multivector storage existed use the appendable one
+        let open_storage = if appendable_memmap_multi_vector_storage_exists(vector_storage_path) {
+            open_appendable_memmap_multi_vector_storage
+        } else {
+            open_memmap_multi_vector_storage
+        };
+        open_storage(
             storage_element_type,
             vector_storage_path,
             vector_config.size,
@@ -178,6 +187,10 @@ pub(crate) fn open_vector_storage(
     vector_storage_path: &Path,
     #[cfg(feature = "rocksdb")] vector_name: &VectorName,
 ) -> OperationResult<VectorStorageEnum> {
+    if let Some(backend) = &vector_config.storage_backend {
+        return open_custom_vector_storage(backend, vector_storage_path, vector_config);
+    }
+
     match vector_config.storage_type {
         // In memory - RocksDB disabled
         #[cfg(not(feature = "rocksdb"))]
@@ -215,30 +228,36 @@ pub(crate) fn open_vector_storage(
             }
         }
         // Mmap on disk, not appendable
-        VectorStorageType::Mmap => open_mmap_vector_storage(
-            vector_storage_path,
-            vector_config,
-            AdviceSetting::Global,
-            false,
-        ),
+        VectorStorageType::Mmap => {
+            let component = advice::get_component(MmapComponent::Vectors);
+            open_mmap_vector_storage(
+                vector_storage_path,
+                vector_config,
+                component.advice_or(AdviceSetting::Global),
+                component.populate_or(false),
+            )
+        }
         VectorStorageType::InRamMmap => open_mmap_vector_storage(
             vector_storage_path,
             vector_config,
             AdviceSetting::from(Advice::Normal),
-            true,
+            !common::flags::feature_flags().lazy_vector_loading,
         ),
         // Chunked mmap on disk, appendable
-        VectorStorageType::ChunkedMmap => open_chunked_mmap_vector_storage(
-            vector_storage_path,
-            vector_config,
-            AdviceSetting::Global,
-            false,
-        ),
+        VectorStorageType::ChunkedMmap => {
+            let component = advice::get_component(MmapComponent::Vectors);
+            open_chunked_mmap_vector_storage(
+                vector_storage_path,
+                vector_config,
+                component.advice_or(AdviceSetting::Global),
+                component.populate_or(false),
+            )
+        }
         VectorStorageType::InRamChunkedMmap => open_chunked_mmap_vector_storage(
             vector_storage_path,
             vector_config,
             AdviceSetting::from(Advice::Normal),
-            true,
+            !common::flags::feature_flags().lazy_vector_loading,
         ),
     }
 }
@@ -257,13 +276,25 @@ pub(crate) fn create_payload_storage(
         PayloadStorageType::OnDisk => {
             PayloadStorageEnum::from(OnDiskPayloadStorage::open(db_builder.require()?)?)
         }
-        PayloadStorageType::Mmap => PayloadStorageEnum::from(MmapPayloadStorage::open_or_create(
-            segment_path.to_path_buf(),
-            false,
-        )?),
-        PayloadStorageType::InRamMmap => PayloadStorageEnum::from(
-            MmapPayloadStorage::open_or_create(segment_path.to_path_buf(), true)?,
-        ),
+        PayloadStorageType::Mmap => {
+            let component = advice::get_component(MmapComponent::Payload);
+            let mut storage = MmapPayloadStorage::open_or_create(
+                segment_path.to_path_buf(),
+                component.populate_or(false),
+                config.payload_overflow_threshold,
+            )?;
+            if let Some(advice) = component.advice {
+                storage.set_page_advice(advice)?;
+            }
+            PayloadStorageEnum::from(storage)
+        }
+        PayloadStorageType::InRamMmap => {
+            PayloadStorageEnum::from(MmapPayloadStorage::open_or_create(
+                segment_path.to_path_buf(),
+                true,
+                config.payload_overflow_threshold,
+            )?)
+        }
     };
     Ok(payload_storage)
 }
@@ -476,8 +507,7 @@ fn create_segment(
 
     let appendable_flag = config.is_appendable();
 
-    let use_mutable_id_tracker =
-        appendable_flag || !ImmutableIdTracker::mappings_file_path(segment_path).is_file();
+    let use_mutable_id_tracker = appendable_flag || !ImmutableIdTracker::exists(segment_path);
     let id_tracker = create_segment_id_tracker(
         use_mutable_id_tracker,
         segment_path,
@@ -576,12 +606,27 @@ fn create_segment(
 
         check_process_stopped(stopped)?;
 
+        // In-RAM storages and HNSW graphs are loaded into memory on first use instead,
+        // see `open_vector_storage` and `GraphLinks::load_from_file`
+        let lazy_storage = vector_config.storage_backend.is_none()
+            && matches!(
+                vector_config.storage_type,
+                VectorStorageType::InRamMmap | VectorStorageType::InRamChunkedMmap,
+            );
+        let lazy_index = matches!(
+            &vector_config.index,
+            Indexes::Hnsw(hnsw_config) if !hnsw_config.on_disk.unwrap_or(false),
+        );
+        let lazy_load_pending =
+            common::flags::feature_flags().lazy_vector_loading && (lazy_storage || lazy_index);
+
         vector_data.insert(
             vector_name.to_owned(),
             VectorData {
                 vector_index,
                 vector_storage,
                 quantized_vectors,
+                lazy_load_pending: AtomicBool::new(lazy_load_pending),
             },
         );
     }
@@ -619,6 +664,7 @@ fn create_segment(
                 vector_storage,
                 vector_index,
                 quantized_vectors: sp(None),
+                lazy_load_pending: AtomicBool::new(false),
             },
         );
     }
@@ -808,6 +854,8 @@ pub fn load_segment(path: &Path, uuid: Uuid, stopped: &AtomicBool) -> OperationR
         SegmentVersion::save(path)?
     }
 
+    recover_vector_rebuild(path)?;
+
     #[cfg_attr(not(feature = "rocksdb"), expect(unused_mut))]
     let mut segment_state = Segment::load_state(path)?;
 
@@ -1495,6 +1543,7 @@ pub fn migrate_rocksdb_payload_storage_to_mmap(
         let mut new_storage = PayloadStorageEnum::from(MmapPayloadStorage::open_or_create(
             segment_path.to_path_buf(),
             !old_storage.is_on_disk(),
+            None,
         )?);
 
         // Copy all payloads and deletes into new storage
@@ -0,0 +1,31 @@
+use common::types::ScoreType;
+use half::f16;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeHalf};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::HammingMetric;
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeHalf> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        hamming_similarity_half(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn hamming_similarity_half(
+    v1: &[VectorElementTypeHalf],
+    v2: &[VectorElementTypeHalf],
+) -> ScoreType {
+    -(v1.iter()
+        .zip(v2)
+        .filter(|(a, b)| (**a > f16::ZERO) != (**b > f16::ZERO))
+        .count() as ScoreType)
+}
@@ -0,0 +1,87 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeByte};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::HammingMetric;
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeByte> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("popcnt") {
+                return unsafe { popcnt_hamming_similarity_bytes(v1, v2) };
+            }
+        }
+
+        hamming_similarity_bytes(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+/// Hamming similarity of bit-packed vectors, 8 bits per element.
+pub fn hamming_similarity_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    -(hamming_distance_bytes(v1, v2) as ScoreType)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "popcnt")]
+unsafe fn popcnt_hamming_similarity_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    // Same code as the fallback, but compiled with `popcnt` so that `count_ones` is a single
+    // instruction per 8 bytes.
+    -(hamming_distance_bytes(v1, v2) as ScoreType)
+}
+
+#[inline(always)]
+fn hamming_distance_bytes(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> u32 {
+    let chunks1 = v1.chunks_exact(size_of::<u64>());
+    let chunks2 = v2.chunks_exact(size_of::<u64>());
+    let tail = chunks1
+        .remainder()
+        .iter()
+        .zip(chunks2.remainder())
+        .map(|(a, b)| (a ^ b).count_ones())
+        .sum::<u32>();
+
+    chunks1
+        .zip(chunks2)
+        .map(|(a, b)| {
+            let a = u64::from_ne_bytes(a.try_into().unwrap());
+            let b = u64::from_ne_bytes(b.try_into().unwrap());
+            (a ^ b).count_ones()
+        })
+        .sum::<u32>()
+        + tail
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hamming_bytes() {
+        let v1: Vec<VectorElementTypeByte> = (0..37).collect();
+        let v2: Vec<VectorElementTypeByte> = (0..37).map(|x| x ^ 0b1010_0001).collect();
+
+        assert_eq!(hamming_similarity_bytes(&v1, &v1), 0.0);
+        assert_eq!(hamming_similarity_bytes(&v1, &v2), -(37.0 * 3.0));
+        assert_eq!(
+            <HammingMetric as Metric<VectorElementTypeByte>>::similarity(&v1, &v2),
+            hamming_similarity_bytes(&v1, &v2),
+        );
+        assert_eq!(hamming_similarity_bytes(&[0xff; 3], &[0x00; 3]), -24.0);
+    }
+}
@@ -33,6 +33,14 @@ pub struct EuclidMetric;
 #[derive(Clone)]
 pub struct ManhattanMetric;
 
+/// Number of differing bits between vectors.
+///
+/// `uint8` vectors are treated as bit-packed, 8 bits per element. For float vectors, each
+/// element is a single bit, set if the element is greater than zero. Zero, negative and NaN
+/// elements are all unset bits, so vectors are compared by the signs of their elements.
+#[derive(Clone)]
+pub struct HammingMetric;
+
 impl Metric<VectorElementType> for EuclidMetric {
     fn distance() -> Distance {
         Distance::Euclid
@@ -121,6 +129,26 @@ impl MetricPostProcessing for ManhattanMetric {
     }
 }
 
+impl Metric<VectorElementType> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+        hamming_similarity(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for HammingMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score.abs()
+    }
+}
+
 impl Metric<VectorElementType> for DotProductMetric {
     fn distance() -> Distance {
         Distance::Dot
@@ -225,6 +253,14 @@ pub fn manhattan_similarity(v1: &[VectorElementType], v2: &[VectorElementType])
         .sum::<ScoreType>()
 }
 
+/// Negated number of elements, which are greater than zero in only one of the vectors
+pub fn hamming_similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+    -(v1.iter()
+        .zip(v2)
+        .filter(|(a, b)| (**a > 0.0) != (**b > 0.0))
+        .count() as ScoreType)
+}
+
 pub fn cosine_preprocess(vector: DenseVector) -> DenseVector {
     let mut length: f32 = vector.iter().map(|x| x * x).sum();
     if is_length_zero_or_normalized(length) {
@@ -250,6 +286,20 @@ mod tests {
         assert_eq!(res, vec![0.0, 0.0, 0.0, 0.0]);
     }
 
+    #[test]
+    fn test_hamming_similarity() {
+        let v1 = vec![1.0, 0.5, -1.0, 0.0, f32::NAN, 2.0];
+        let v2 = vec![3.0, -0.5, 0.0, -0.0, -1.0, f32::NAN];
+
+        // Only the 2nd and the last elements are set in one vector and unset in the other
+        assert_eq!(hamming_similarity(&v1, &v2), -2.0);
+        assert_eq!(hamming_similarity(&v1, &v1), 0.0);
+        assert_eq!(
+            HammingMetric::postprocess(hamming_similarity(&v1, &v2)),
+            2.0
+        );
+    }
+
     /// If we preprocess a vector multiple times, we expect the same result.
     /// Renormalization should not produce something different.
     #[test]
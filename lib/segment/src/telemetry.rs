@@ -11,6 +11,7 @@ pub struct SegmentTelemetry {
     pub config: SegmentConfig,
     pub vector_index_searches: Vec<VectorIndexSearchesTelemetry>,
     pub payload_field_indices: Vec<PayloadIndexTelemetry>,
+    pub payload_field_sizes: Vec<PayloadFieldSizeTelemetry>,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
@@ -39,6 +40,22 @@ impl PayloadIndexTelemetry {
     }
 }
 
+/// Serialized size of values of a top-level payload field
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
+pub struct PayloadFieldSizeTelemetry {
+    #[anonymize(value = None)]
+    pub field_name: Option<String>,
+
+    /// The amount of points which have a value for this field.
+    pub points_count: usize,
+
+    /// Total size of all values in bytes.
+    pub total_bytes: usize,
+
+    /// Size of the largest value in bytes.
+    pub max_bytes: usize,
+}
+
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize, Default)]
 pub struct VectorIndexSearchesTelemetry {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4,7 +4,8 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::{self, Display, Formatter};
 use std::hash::{self, Hash, Hasher};
 use std::mem;
-use std::ops::Deref;
+use std::net::{IpAddr, Ipv6Addr};
+use std::ops::{Deref, RangeInclusive};
 use std::rc::Rc;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -21,6 +22,7 @@ use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::{Map, Value};
+use sparse::common::types::DimId;
 use strum::{EnumIter, EnumString};
 use uuid::Uuid;
 use validator::{Validate, ValidationError, ValidationErrors};
@@ -31,7 +33,7 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::{self, MaybeOneOrMany, MultiValue};
 use crate::data_types::index::{
     BoolIndexParams, DatetimeIndexParams, FloatIndexParams, GeoIndexParams, IntegerIndexParams,
-    KeywordIndexParams, TextIndexParams, UuidIndexParams,
+    IpIndexParams, KeywordIndexParams, TextIndexParams, UuidIndexParams,
 };
 use crate::data_types::modifier::Modifier;
 use crate::data_types::order_by::OrderValue;
@@ -40,11 +42,14 @@ use crate::data_types::tiny_map::TinyMap;
 use crate::data_types::vectors::{DenseVector, VectorStructInternal};
 use crate::index::field_index::CardinalityEstimation;
 use crate::index::sparse_index::sparse_index_config::SparseIndexConfig;
-use crate::json_path::JsonPath;
+use crate::json_path::{JsonPath, JsonPathProjection};
 use crate::spaces::metric::{Metric, MetricPostProcessing};
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, ManhattanMetric,
+};
 use crate::types::utils::unordered_hash_unique;
 use crate::utils::maybe_arc::MaybeArc;
+use crate::vector_storage::backend_registry::is_vector_storage_backend_appendable;
 
 pub type PayloadKeyType = JsonPath;
 pub type PayloadKeyTypeRef<'a> = &'a JsonPath;
@@ -60,6 +65,10 @@ pub type DateTimePayloadType = DateTimeWrapper;
 pub type UuidPayloadType = Uuid;
 /// Type of Uuid point payload key
 pub type UuidIntType = u128;
+/// Type of IP address point payload
+pub type IpPayloadType = IpAddr;
+/// Type of IP address point payload key, see [`ip_to_int`]
+pub type IpIntType = u128;
 /// Name of a vector
 pub type VectorName = str;
 /// Name of a vector (owned variant)
@@ -312,6 +321,11 @@ pub enum Distance {
     Dot,
     // <https://simple.wikipedia.org/wiki/Manhattan_distance>
     Manhattan,
+    // <https://en.wikipedia.org/wiki/Hamming_distance>
+    // `uint8` vectors are bit-packed, 8 bits per element. In float vectors, each element is a
+    // single bit, which is set if the element is greater than zero. Zero, negative and NaN
+    // elements are unset bits.
+    Hamming,
 }
 
 impl Distance {
@@ -321,6 +335,7 @@ impl Distance {
             Distance::Euclid => EuclidMetric::postprocess(score),
             Distance::Dot => DotProductMetric::postprocess(score),
             Distance::Manhattan => ManhattanMetric::postprocess(score),
+            Distance::Hamming => HammingMetric::postprocess(score),
         }
     }
 
@@ -330,19 +345,21 @@ impl Distance {
         EuclidMetric: Metric<T>,
         DotProductMetric: Metric<T>,
         ManhattanMetric: Metric<T>,
+        HammingMetric: Metric<T>,
     {
         match self {
             Distance::Cosine => CosineMetric::preprocess(vector),
             Distance::Euclid => EuclidMetric::preprocess(vector),
             Distance::Dot => DotProductMetric::preprocess(vector),
             Distance::Manhattan => ManhattanMetric::preprocess(vector),
+            Distance::Hamming => HammingMetric::preprocess(vector),
         }
     }
 
     pub fn distance_order(&self) -> Order {
         match self {
             Distance::Cosine | Distance::Dot => Order::LargeBetter,
-            Distance::Euclid | Distance::Manhattan => Order::SmallBetter,
+            Distance::Euclid | Distance::Manhattan | Distance::Hamming => Order::SmallBetter,
         }
     }
 
@@ -605,6 +622,34 @@ pub struct SearchParams {
     #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub acorn: Option<AcornSearchParams>,
+
+    /// If enabled, requests with `score_threshold` search segments in parallel waves of growing
+    /// size, starting from the ones which contributed to results most often, and stop once `limit` results above
+    /// the threshold are found. Speeds up search in collections with many near-empty segments,
+    /// but better scored points in segments which were not searched may be missed.
+    #[serde(default)]
+    pub early_exit: bool,
+
+    /// Maximal size of the beam for the dynamic ef mode. If set, HNSW search starts with
+    /// `hnsw_ef` (or `limit`) and doubles the beam until the top results stop changing between
+    /// rounds, or the beam reaches this size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_hnsw_ef: Option<usize>,
+
+    /// Truncate results at the first large score drop. If set, results are cut before the first
+    /// point which score differs from the score of the previous point by more than this fraction
+    /// of the larger absolute score of the two. Applied to the final results, before `offset`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0))]
+    pub score_gap_cutoff: Option<OrderedFloat<f64>>,
+
+    /// If enabled, searches which reach the timeout return the results found so far instead of
+    /// an error. Segment searches are stopped shortly before the timeout, and query responses
+    /// are marked with `timed_out: true` if results may be incomplete.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_partial_results: Option<bool>,
 }
 
 /// Configuration for vectors.
@@ -681,6 +726,16 @@ pub struct HnswConfig {
     /// Requires quantized vectors to be enabled. Multi-vectors are not supported.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inline_storage: Option<bool>,
+    /// Encoding of the HNSW graph links. Default: `bitpacking`.
+    /// Ignored when `inline_storage` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links_compression: Option<HnswLinksCompression>,
+    /// Link all points of each tenant of a payload field marked with `is_tenant` in the graph,
+    /// regardless of the tenant size. Searches with a filter matching a single tenant then stay
+    /// within the links of that tenant. Requires `payload_m` or `m` > 0.
+    /// Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_subgraphs: Option<bool>,
 }
 
 impl HnswConfig {
@@ -701,6 +756,8 @@ impl HnswConfig {
             payload_m,
             on_disk,
             inline_storage,
+            links_compression,
+            tenant_subgraphs,
         } = *self;
 
         m != other.m
@@ -712,9 +769,27 @@ impl HnswConfig {
             // to flip this flag
             || on_disk != other.on_disk
             || inline_storage != other.inline_storage
+            || links_compression != other.links_compression
+            || tenant_subgraphs != other.tenant_subgraphs
     }
 }
 
+/// Encoding of the HNSW graph links.
+///
+/// - `bitpacking` - Links are bitpacked. Fastest to traverse.
+///
+/// - `delta_varint` - Links are delta and varint encoded per node. Takes less space than `bitpacking` for graphs with a large `m`, but is slower to decode.
+///
+/// - `delta_varint_cached` - Same as `delta_varint`, but links are kept in RAM once decoded. Trades RAM for search speed.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HnswLinksCompression {
+    #[default]
+    Bitpacking,
+    DeltaVarint,
+    DeltaVarintCached,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone)]
 #[serde(rename_all = "snake_case", default)]
 #[anonymize(false)]
@@ -929,6 +1004,13 @@ pub struct StrictModeSparse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 1))]
     pub max_length: Option<usize>,
+    /// Max allowed index (dimension id) of sparse vector
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_index: Option<DimId>,
+    /// Max allowed absolute value of sparse vector weights
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0))]
+    pub max_abs_value: Option<OrderedFloat<f64>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Default, Hash)]
@@ -952,6 +1034,14 @@ pub struct StrictModeSparseOutput {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
     pub max_length: Option<usize>,
+    /// Max allowed index (dimension id) of sparse vector
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub max_index: Option<DimId>,
+    /// Max allowed absolute value of sparse vector weights
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub max_abs_value: Option<OrderedFloat<f64>>,
 }
 
 impl From<StrictModeSparseConfig> for StrictModeSparseConfigOutput {
@@ -969,8 +1059,16 @@ impl From<StrictModeSparseConfig> for StrictModeSparseConfigOutput {
 
 impl From<StrictModeSparse> for StrictModeSparseOutput {
     fn from(config: StrictModeSparse) -> Self {
-        let StrictModeSparse { max_length } = config;
-        StrictModeSparseOutput { max_length }
+        let StrictModeSparse {
+            max_length,
+            max_index,
+            max_abs_value,
+        } = config;
+        StrictModeSparseOutput {
+            max_length,
+            max_index,
+            max_abs_value,
+        }
     }
 }
 
@@ -1112,6 +1210,30 @@ pub struct StrictModeConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0))]
     pub max_payload_index_count: Option<usize>,
+
+    /// Payload key of the tenant.
+    /// Filtered requests (e.g. search, scroll, count, delete by filter) must match a single value
+    /// of it in a `must` condition. Points can't be retrieved by IDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_filter_key: Option<JsonPath>,
+
+    /// Max number of search requests (e.g. search, query, recommend) per minute to the
+    /// collection, counted on the peer which received the requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub search_rate_limit: Option<usize>,
+
+    /// Max number of scroll requests per minute to the collection, counted on the peer which
+    /// received the requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub scroll_rate_limit: Option<usize>,
+
+    /// Max number of update requests per minute to the collection, counted on the peer which
+    /// received the requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub update_rate_limit: Option<usize>,
 }
 
 impl Eq for StrictModeConfig {}
@@ -1139,6 +1261,10 @@ impl Hash for StrictModeConfig {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            tenant_filter_key,
+            search_rate_limit,
+            scroll_rate_limit,
+            update_rate_limit,
         } = self;
         enabled.hash(state);
         max_query_limit.hash(state);
@@ -1158,6 +1284,10 @@ impl Hash for StrictModeConfig {
         multivector_config.hash(state);
         sparse_config.hash(state);
         max_payload_index_count.hash(state);
+        tenant_filter_key.hash(state);
+        search_rate_limit.hash(state);
+        scroll_rate_limit.hash(state);
+        update_rate_limit.hash(state);
     }
 }
 
@@ -1256,6 +1386,30 @@ pub struct StrictModeConfigOutput {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0))]
     pub max_payload_index_count: Option<usize>,
+
+    /// Payload key of the tenant.
+    /// Filtered requests (e.g. search, scroll, count, delete by filter) must match a single value
+    /// of it in a `must` condition. Points can't be retrieved by IDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_filter_key: Option<JsonPath>,
+
+    /// Max number of search requests (e.g. search, query, recommend) per minute to the
+    /// collection, counted on the peer which received the requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub search_rate_limit: Option<usize>,
+
+    /// Max number of scroll requests per minute to the collection, counted on the peer which
+    /// received the requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub scroll_rate_limit: Option<usize>,
+
+    /// Max number of update requests per minute to the collection, counted on the peer which
+    /// received the requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub update_rate_limit: Option<usize>,
 }
 
 impl From<StrictModeConfig> for StrictModeConfigOutput {
@@ -1280,6 +1434,10 @@ impl From<StrictModeConfig> for StrictModeConfigOutput {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            tenant_filter_key,
+            search_rate_limit,
+            scroll_rate_limit,
+            update_rate_limit,
         } = config;
 
         Self {
@@ -1302,6 +1460,10 @@ impl From<StrictModeConfig> for StrictModeConfigOutput {
             multivector_config: multivector_config.map(StrictModeMultivectorConfigOutput::from),
             sparse_config: sparse_config.map(StrictModeSparseConfigOutput::from),
             max_payload_index_count,
+            tenant_filter_key,
+            search_rate_limit,
+            scroll_rate_limit,
+            update_rate_limit,
         }
     }
 }
@@ -1318,6 +1480,8 @@ impl Default for HnswConfig {
             on_disk: Some(false),
             payload_m: None,
             inline_storage: None,
+            links_compression: None,
+            tenant_subgraphs: None,
         }
     }
 }
@@ -1375,6 +1539,10 @@ pub struct SegmentConfig {
     pub sparse_vector_data: HashMap<VectorNameBuf, SparseVectorDataConfig>,
     /// Defines payload storage type
     pub payload_storage_type: PayloadStorageType,
+    /// Top-level payload values larger than this number of bytes are stored separately from the
+    /// rest of the payload. Only applies to mmap based payload storages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_overflow_threshold: Option<usize>,
 }
 
 impl SegmentConfig {
@@ -1440,13 +1608,14 @@ impl SegmentConfig {
         // Vector data have to be compatible between two segments.
         // Sparse vector data can be different, but a placeholder check is implemented to catch
         // and enforce compatibility check for future changes.
-        // Payload storage type can be different.
+        // Payload storage type and overflow threshold can be different.
 
         // Assert segment config fields
         let Self {
             vector_data: _,
             sparse_vector_data: _,
             payload_storage_type: _,
+            payload_overflow_threshold: _,
         } = self;
 
         let is_vector_config_compatible = is_map_compatible(
@@ -1594,6 +1763,11 @@ pub struct VectorDataConfig {
     /// Vector specific configuration to set specific storage element type
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub datatype: Option<VectorStorageDatatype>,
+    /// Name of a registered vector storage backend to store vectors with.
+    /// If set, `storage_type` is ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub storage_backend: Option<String>,
 }
 
 impl VectorDataConfig {
@@ -1605,12 +1779,15 @@ impl VectorDataConfig {
             Indexes::Plain {} => true,
             Indexes::Hnsw(_) => false,
         };
-        let is_storage_appendable = match self.storage_type {
-            VectorStorageType::Memory => true,
-            VectorStorageType::Mmap => false,
-            VectorStorageType::ChunkedMmap => true,
-            VectorStorageType::InRamChunkedMmap => true,
-            VectorStorageType::InRamMmap => false,
+        let is_storage_appendable = match &self.storage_backend {
+            Some(backend) => is_vector_storage_backend_appendable(backend),
+            None => match self.storage_type {
+                VectorStorageType::Memory => true,
+                VectorStorageType::Mmap => false,
+                VectorStorageType::ChunkedMmap => true,
+                VectorStorageType::InRamChunkedMmap => true,
+                VectorStorageType::InRamMmap => false,
+            },
         };
         is_index_appendable && is_storage_appendable
     }
@@ -1630,6 +1807,7 @@ impl VectorDataConfig {
             quantization_config: _,
             multivector_config: _,
             datatype: _,
+            storage_backend: _,
         } = self;
 
         self.size == other.size
@@ -1898,6 +2076,11 @@ impl Payload {
         JsonPath::value_set(Some(key), &mut self.0, &value.0);
     }
 
+    /// Apply `patch` to the payload, following JSON Merge Patch (RFC 7386) semantics
+    pub fn merge_patch(&mut self, patch: &Payload) {
+        utils::merge_patch_map(&mut self.0, &patch.0)
+    }
+
     pub fn remove(&mut self, path: &JsonPath) -> Vec<Value> {
         path.value_remove(&mut self.0).to_vec()
     }
@@ -2039,6 +2222,7 @@ pub enum PayloadSchemaType {
     Bool,
     Datetime,
     Uuid,
+    Ip,
 }
 
 impl PayloadSchemaType {
@@ -2057,6 +2241,7 @@ impl PayloadSchemaType {
             Self::Bool => PayloadSchemaParams::Bool(BoolIndexParams::default()),
             Self::Datetime => PayloadSchemaParams::Datetime(DatetimeIndexParams::default()),
             Self::Uuid => PayloadSchemaParams::Uuid(UuidIndexParams::default()),
+            Self::Ip => PayloadSchemaParams::Ip(IpIndexParams::default()),
         }
     }
 }
@@ -2074,6 +2259,7 @@ pub enum PayloadSchemaParams {
     Bool(BoolIndexParams),
     Datetime(DatetimeIndexParams),
     Uuid(UuidIndexParams),
+    Ip(IpIndexParams),
 }
 
 impl PayloadSchemaParams {
@@ -2092,6 +2278,7 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Bool(_) => PayloadSchemaType::Bool,
             PayloadSchemaParams::Datetime(_) => PayloadSchemaType::Datetime,
             PayloadSchemaParams::Uuid(_) => PayloadSchemaType::Uuid,
+            PayloadSchemaParams::Ip(_) => PayloadSchemaType::Ip,
         }
     }
 
@@ -2104,7 +2291,8 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Uuid(uuid) => uuid.is_tenant.unwrap_or_default(),
             PayloadSchemaParams::Geo(_)
             | PayloadSchemaParams::Text(_)
-            | PayloadSchemaParams::Bool(_) => false,
+            | PayloadSchemaParams::Bool(_)
+            | PayloadSchemaParams::Ip(_) => false,
         }
     }
 
@@ -2118,6 +2306,7 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Text(i) => i.on_disk.unwrap_or_default(),
             PayloadSchemaParams::Geo(i) => i.on_disk.unwrap_or_default(),
             PayloadSchemaParams::Bool(i) => i.on_disk.unwrap_or_default(),
+            PayloadSchemaParams::Ip(i) => i.on_disk.unwrap_or_default(),
         }
     }
 
@@ -2131,6 +2320,8 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Text(params) => params.enable_hnsw.unwrap_or(true),
             PayloadSchemaParams::Geo(params) => params.enable_hnsw.unwrap_or(true),
             PayloadSchemaParams::Bool(params) => params.enable_hnsw.unwrap_or(true),
+            // IP index doesn't provide payload blocks, which are required for HNSW links
+            PayloadSchemaParams::Ip(_) => false,
         }
     }
 }
@@ -2146,6 +2337,7 @@ impl Validate for PayloadSchemaParams {
             PayloadSchemaParams::Bool(_) => Ok(()),
             PayloadSchemaParams::Datetime(_) => Ok(()),
             PayloadSchemaParams::Uuid(_) => Ok(()),
+            PayloadSchemaParams::Ip(_) => Ok(()),
         }
     }
 }
@@ -2198,7 +2390,8 @@ impl Display for PayloadFieldSchema {
                 | PayloadSchemaParams::Geo(_)
                 | PayloadSchemaParams::Bool(_)
                 | PayloadSchemaParams::Datetime(_)
-                | PayloadSchemaParams::Uuid(_) => write!(f, "{}", params.name()),
+                | PayloadSchemaParams::Uuid(_)
+                | PayloadSchemaParams::Ip(_) => write!(f, "{}", params.name()),
                 PayloadSchemaParams::Integer(integer_params) => {
                     let range = integer_params.range.unwrap_or(true);
                     let lookup = integer_params.lookup.unwrap_or(true);
@@ -2265,6 +2458,7 @@ impl PayloadFieldSchema {
                 PayloadSchemaType::Integer => true,
                 PayloadSchemaType::Uuid => true,
                 PayloadSchemaType::Bool => true,
+                PayloadSchemaType::Ip => true,
                 PayloadSchemaType::Float => false,
                 PayloadSchemaType::Geo => false,
                 PayloadSchemaType::Text => false,
@@ -2277,6 +2471,7 @@ impl PayloadFieldSchema {
                 }
                 PayloadSchemaParams::Uuid(_) => true,
                 PayloadSchemaParams::Bool(_) => true,
+                PayloadSchemaParams::Ip(_) => true,
                 PayloadSchemaParams::Float(_) => false,
                 PayloadSchemaParams::Geo(_) => false,
                 PayloadSchemaParams::Text(_) => false,
@@ -2452,6 +2647,62 @@ impl<S: Into<String>> From<S> for MatchPhrase {
     }
 }
 
+/// Match IP addresses within the given network, in CIDR notation.
+/// For example: `10.0.0.0/8` or `2001:db8::/32`.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchCidr {
+    pub cidr: String,
+}
+
+impl MatchCidr {
+    /// Range of keys of all addresses within the network, see [`ip_to_int`].
+    ///
+    /// Address without a prefix length is a network of a single address.
+    /// Returns `None` if the network is malformed.
+    pub fn ip_range(&self) -> Option<RangeInclusive<IpIntType>> {
+        let (address, prefix_len) = match self.cidr.split_once('/') {
+            Some((address, prefix_len)) => (address, Some(prefix_len)),
+            None => (self.cidr.as_str(), None),
+        };
+
+        let address = IpAddr::from_str(address.trim()).ok()?;
+        let max_prefix_len = match address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        let prefix_len = match prefix_len {
+            Some(prefix_len) => prefix_len.trim().parse::<u32>().ok()?,
+            None => max_prefix_len,
+        };
+        if prefix_len > max_prefix_len {
+            return None;
+        }
+
+        // IPv4 addresses occupy the lowest 32 bits of the key
+        let host_bits = max_prefix_len - prefix_len;
+        let mask = IpIntType::MAX.checked_shl(host_bits).unwrap_or(0);
+        let start = ip_to_int(address) & mask;
+        Some(start..=start | !mask)
+    }
+}
+
+/// Key of an IP address in payload index.
+///
+/// IPv4 addresses are mapped into IPv6 space (`::ffff:0:0/96`), so both versions share a
+/// single ordered key space without collisions.
+pub fn ip_to_int(address: IpPayloadType) -> IpIntType {
+    match address {
+        IpAddr::V4(address) => IpIntType::from(address.to_ipv6_mapped()),
+        IpAddr::V6(address) => IpIntType::from(address),
+    }
+}
+
+/// Inverse of [`ip_to_int`]
+pub fn int_to_ip(key: IpIntType) -> IpPayloadType {
+    Ipv6Addr::from(key).to_canonical()
+}
+
 /// Exact match on any of the given values
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
@@ -2476,6 +2727,7 @@ pub enum MatchInterface {
     Phrase(MatchPhrase),
     Any(MatchAny),
     Except(MatchExcept),
+    Cidr(MatchCidr),
 }
 
 /// Match filter request
@@ -2488,6 +2740,7 @@ pub enum Match {
     Phrase(MatchPhrase),
     Any(MatchAny),
     Except(MatchExcept),
+    Cidr(MatchCidr),
 }
 
 impl Match {
@@ -2533,6 +2786,7 @@ impl From<MatchInterface> for Match {
                 except: except.except,
             }),
             MatchInterface::Phrase(MatchPhrase { phrase }) => Self::Phrase(MatchPhrase { phrase }),
+            MatchInterface::Cidr(MatchCidr { cidr }) => Self::Cidr(MatchCidr { cidr }),
         }
     }
 }
@@ -3140,18 +3394,27 @@ impl FieldCondition {
             Match::Text(_) => 0,
             Match::Phrase(_) => 0,
             Match::TextAny(_) => 0,
+            Match::Cidr(_) => 0,
         }
     }
 }
 
 pub fn validate_field_condition(field_condition: &FieldCondition) -> Result<(), ValidationError> {
     if field_condition.all_fields_none() {
-        Err(ValidationError::new(
+        return Err(ValidationError::new(
             "At least one field condition must be specified",
-        ))
-    } else {
-        Ok(())
+        ));
     }
+
+    if let Some(Match::Cidr(match_cidr)) = &field_condition.r#match
+        && match_cidr.ip_range().is_none()
+    {
+        return Err(ValidationError::new(
+            "Invalid `cidr` network, expected CIDR notation like `10.0.0.0/8`",
+        ));
+    }
+
+    Ok(())
 }
 
 /// Payload field
@@ -3214,6 +3477,49 @@ impl From<VectorNameBuf> for HasVectorCondition {
     }
 }
 
+/// Internal attribute of a point, stored outside of the payload
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PointAttribute {
+    /// Numeric id of the point. Points with UUID ids never match
+    Id,
+    /// Version of the last operation which updated the point
+    Version,
+    /// Number of named vectors assigned to the point
+    VectorsCount,
+}
+
+/// Range of a point attribute value
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct AttributeRange {
+    /// point.attribute < range.lt
+    pub lt: Option<u64>,
+    /// point.attribute > range.gt
+    pub gt: Option<u64>,
+    /// point.attribute >= range.gte
+    pub gte: Option<u64>,
+    /// point.attribute <= range.lte
+    pub lte: Option<u64>,
+}
+
+impl AttributeRange {
+    pub fn check(&self, value: u64) -> bool {
+        let Self { lt, gt, gte, lte } = self;
+        lt.is_none_or(|x| value < x)
+            && gt.is_none_or(|x| value > x)
+            && lte.is_none_or(|x| value <= x)
+            && gte.is_none_or(|x| value >= x)
+    }
+}
+
+/// Filter points by an internal attribute, like id or version, instead of a payload field
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+pub struct PointAttributeCondition {
+    pub attribute: PointAttribute,
+    pub range: AttributeRange,
+}
+
 /// Threshold determining when to use an `Arc` in `HasIdCondition` if the condition includes many points.
 /// Since we're cloning filters quite a lot, using an Arc for larger conditions reduces risk of memory leaks
 /// and potentially improves performance in some places.
@@ -3293,6 +3599,8 @@ pub enum Condition {
     HasId(HasIdCondition),
     /// Check if point has vector assigned
     HasVector(HasVectorCondition),
+    /// Check if internal attribute of the point is in a given range
+    Attribute(PointAttributeCondition),
     /// Nested filters
     Nested(NestedCondition),
     /// Nested filter
@@ -3315,6 +3623,7 @@ enum ConditionUntagged {
     IsNull(IsNullCondition),
     HasId(HasIdCondition),
     HasVector(HasVectorCondition),
+    Attribute(PointAttributeCondition),
     Nested(NestedCondition),
     Filter(Filter),
 
@@ -3330,6 +3639,7 @@ impl From<ConditionUntagged> for Condition {
             ConditionUntagged::IsNull(condition) => Condition::IsNull(condition),
             ConditionUntagged::HasId(condition) => Condition::HasId(condition),
             ConditionUntagged::HasVector(condition) => Condition::HasVector(condition),
+            ConditionUntagged::Attribute(condition) => Condition::Attribute(condition),
             ConditionUntagged::Nested(condition) => Condition::Nested(condition),
             ConditionUntagged::Filter(condition) => Condition::Filter(condition),
             ConditionUntagged::CustomIdChecker(condition) => Condition::CustomIdChecker(condition),
@@ -3416,6 +3726,7 @@ impl Condition {
             Condition::IsEmpty(_)
             | Condition::IsNull(_)
             | Condition::HasVector(_)
+            | Condition::Attribute(_)
             | Condition::CustomIdChecker(_) => 0,
         }
     }
@@ -3431,7 +3742,8 @@ impl Condition {
             | Condition::IsNull(_)
             | Condition::CustomIdChecker(_)
             | Condition::HasId(_)
-            | Condition::HasVector(_) => 1,
+            | Condition::HasVector(_)
+            | Condition::Attribute(_) => 1,
         }
     }
 
@@ -3442,7 +3754,10 @@ impl Condition {
             Condition::IsNull(is_null_condition) => Some(is_null_condition.is_null.key.clone()),
             Condition::Nested(nested_condition) => Some(nested_condition.array_key()),
             Condition::Filter(filter) => filter.iter_conditions().find_map(|c| c.targeted_key()),
-            Condition::HasId(_) | Condition::HasVector(_) | Condition::CustomIdChecker(_) => None,
+            Condition::HasId(_)
+            | Condition::HasVector(_)
+            | Condition::Attribute(_)
+            | Condition::CustomIdChecker(_) => None,
         }
     }
 }
@@ -3454,7 +3769,8 @@ impl Validate for Condition {
             Condition::HasId(_)
             | Condition::IsEmpty(_)
             | Condition::IsNull(_)
-            | Condition::HasVector(_) => Ok(()),
+            | Condition::HasVector(_)
+            | Condition::Attribute(_) => Ok(()),
             Condition::Field(field_condition) => field_condition.validate(),
             Condition::Nested(nested_condition) => nested_condition.validate(),
             Condition::Filter(filter) => filter.validate(),
@@ -3625,6 +3941,20 @@ impl PayloadSelectorExclude {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct PayloadSelectorProject {
+    /// Only include parts of the payload, matched by any of this expressions.
+    /// Supports wildcard keys and array slices, e.g. `items[0:3].name` or `meta.*.id`
+    pub project: Vec<JsonPathProjection>,
+}
+
+impl PayloadSelectorProject {
+    pub fn new(project: Vec<JsonPathProjection>) -> Self {
+        Self { project }
+    }
+}
+
 /// Specifies how to treat payload selector
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
 #[serde(untagged, rename_all = "snake_case")]
@@ -3633,6 +3963,8 @@ pub enum PayloadSelector {
     Include(PayloadSelectorInclude),
     /// Exclude this fields from result payload. Keep all other fields.
     Exclude(PayloadSelectorExclude),
+    /// Include only parts of the payload, selected by projection expressions
+    Project(PayloadSelectorProject),
 }
 
 impl From<PayloadSelectorExclude> for WithPayloadInterface {
@@ -3647,6 +3979,12 @@ impl From<PayloadSelectorInclude> for WithPayloadInterface {
     }
 }
 
+impl From<PayloadSelectorProject> for WithPayloadInterface {
+    fn from(selector: PayloadSelectorProject) -> Self {
+        WithPayloadInterface::Selector(PayloadSelector::Project(selector))
+    }
+}
+
 impl PayloadSelector {
     pub fn new_include(vecs_payload_key_type: Vec<PayloadKeyType>) -> Self {
         PayloadSelector::Include(PayloadSelectorInclude {
@@ -3677,6 +4015,9 @@ impl PayloadSelector {
                     .all(|pattern| !pattern.check_exclude_pattern(key))
             })
             .into(),
+            PayloadSelector::Project(selector) => {
+                JsonPathProjection::project(&selector.project, &x.0).into()
+            }
         }
     }
 }
@@ -4431,6 +4772,38 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_match_cidr_range() {
+        let contains = |cidr: &str, address: &str| {
+            let range = MatchCidr {
+                cidr: cidr.to_string(),
+            }
+            .ip_range()
+            .unwrap();
+            range.contains(&ip_to_int(IpAddr::from_str(address).unwrap()))
+        };
+
+        assert!(contains("10.0.0.0/8", "10.1.2.3"));
+        assert!(!contains("10.0.0.0/8", "11.0.0.1"));
+        assert!(contains("192.168.1.7", "192.168.1.7"));
+        assert!(!contains("192.168.1.7", "192.168.1.8"));
+        assert!(contains("0.0.0.0/0", "255.255.255.255"));
+        assert!(!contains("0.0.0.0/0", "::1"));
+        assert!(contains("2001:db8::/32", "2001:db8:ffff::1"));
+        assert!(!contains("2001:db8::/32", "2001:db9::1"));
+        assert!(contains("::/0", "10.0.0.1"));
+
+        for malformed in ["10.0.0.0/33", "2001:db8::/129", "10.0.0/8", "10.0.0.0/x"] {
+            let match_cidr = MatchCidr {
+                cidr: malformed.to_string(),
+            };
+            assert!(match_cidr.ip_range().is_none(), "{malformed}");
+        }
+
+        let address = IpAddr::from_str("10.1.2.3").unwrap();
+        assert_eq!(int_to_ip(ip_to_int(address)), address);
+    }
+
     #[test]
     fn test_parse_nested_match_query() {
         let query = r#"
@@ -5196,6 +5569,35 @@ mod tests {
         };
         assert_eq!(payload, expected);
     }
+
+    #[test]
+    fn test_payload_selector_project() {
+        let payload = payload_json! {
+            "a": 1,
+            "b": {
+                "c": 123,
+                "f": [
+                    {"j": 1, "k": 2},
+                    {"j": 3, "k": 4},
+                    {"j": 5, "k": 6},
+                ],
+            }
+        };
+
+        let selector: PayloadSelector =
+            serde_json::from_str(r#"{"project": ["a", "b.f[-2:].j"]}"#).unwrap();
+        assert!(matches!(selector, PayloadSelector::Project(_)));
+
+        let payload = selector.process(payload);
+
+        let expected = payload_json! {
+            "a": 1,
+            "b": {
+                "f": [{"j": 3}, {"j": 5}],
+            }
+        };
+        assert_eq!(payload, expected);
+    }
 }
 
 fn shard_key_string_example() -> String {
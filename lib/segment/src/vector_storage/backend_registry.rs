@@ -0,0 +1,151 @@
+//! Registry of vector storage backends provided by external crates.
+//!
+//! A backend is registered under a name once, on startup, and then selected per vector with
+//! [`VectorDataConfig::storage_backend`]. All nodes of a cluster must register the same backends,
+//! otherwise segments using them can't be opened.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, LazyLock};
+
+use parking_lot::RwLock;
+
+use super::VectorStorageEnum;
+use super::dense::custom_dense_vector_storage::{
+    CustomDenseVectorStorage, DynDenseVectorStorageBox,
+};
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::types::VectorDataConfig;
+
+/// Provider of a custom vector storage implementation
+pub trait VectorStorageBackend: Send + Sync {
+    /// Whether storages of this backend accept vectors after the segment is built.
+    ///
+    /// Appendable storages must implement
+    /// [`AppendableDynDenseVectorStorage`](super::dense::custom_dense_vector_storage::AppendableDynDenseVectorStorage).
+    /// Read-only backends are only used for optimized segments, appendable segments store
+    /// vectors with the regular storage type.
+    fn is_appendable(&self) -> bool;
+
+    /// Open the storage in `path`, or create an empty one if it doesn't exist yet.
+    ///
+    /// Elements of the storage must be of the datatype from `config`. The storage is flushed
+    /// with [`DynDenseVectorStorage::flusher`](super::dense::custom_dense_vector_storage::DynDenseVectorStorage::flusher)
+    /// when the segment is saved.
+    fn open(
+        &self,
+        path: &Path,
+        config: &VectorDataConfig,
+    ) -> OperationResult<DynDenseVectorStorageBox>;
+}
+
+static VECTOR_STORAGE_BACKENDS: LazyLock<RwLock<HashMap<String, Arc<dyn VectorStorageBackend>>>> =
+    LazyLock::new(Default::default);
+
+/// Register a vector storage backend under the given name.
+///
+/// Fails if a backend with this name is already registered.
+pub fn register_vector_storage_backend(
+    name: impl Into<String>,
+    backend: Arc<dyn VectorStorageBackend>,
+) -> OperationResult<()> {
+    let name = name.into();
+    let mut backends = VECTOR_STORAGE_BACKENDS.write();
+    if backends.contains_key(&name) {
+        return Err(OperationError::service_error(format!(
+            "Vector storage backend {name} is already registered",
+        )));
+    }
+    backends.insert(name, backend);
+    Ok(())
+}
+
+pub fn is_vector_storage_backend_registered(name: &str) -> bool {
+    VECTOR_STORAGE_BACKENDS.read().contains_key(name)
+}
+
+/// Whether the backend `name` is registered and its storages accept vectors after the segment is
+/// built
+pub fn is_vector_storage_backend_appendable(name: &str) -> bool {
+    VECTOR_STORAGE_BACKENDS
+        .read()
+        .get(name)
+        .is_some_and(|backend| backend.is_appendable())
+}
+
+/// Names of all registered backends, sorted
+pub fn registered_vector_storage_backends() -> Vec<String> {
+    let mut names: Vec<_> = VECTOR_STORAGE_BACKENDS.read().keys().cloned().collect();
+    names.sort_unstable();
+    names
+}
+
+/// Check that vectors described by `vector_config` can be stored with the backend `name`.
+pub fn check_vector_storage_backend(
+    name: &str,
+    vector_config: &VectorDataConfig,
+) -> OperationResult<()> {
+    if !is_vector_storage_backend_registered(name) {
+        return Err(OperationError::validation_error(format!(
+            "Vector storage backend {name} is not registered"
+        )));
+    }
+
+    if vector_config.multivector_config.is_some() {
+        return Err(OperationError::validation_error(format!(
+            "Vector storage backend {name} doesn't support multivectors"
+        )));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn open_custom_vector_storage(
+    name: &str,
+    vector_storage_path: &Path,
+    vector_config: &VectorDataConfig,
+) -> OperationResult<VectorStorageEnum> {
+    check_vector_storage_backend(name, vector_config)?;
+
+    // Don't hold the lock while the backend opens the storage
+    let backend = VECTOR_STORAGE_BACKENDS
+        .read()
+        .get(name)
+        .cloned()
+        .ok_or_else(|| {
+            OperationError::service_error(format!(
+                "Vector storage backend {name} is not registered"
+            ))
+        })?;
+
+    let storage = backend.open(vector_storage_path, vector_config)?;
+    if storage.vector_dim() != vector_config.size {
+        return Err(OperationError::service_error(format!(
+            "Vector storage backend {name} opened storage of dimension {}, expected {}",
+            storage.vector_dim(),
+            vector_config.size,
+        )));
+    }
+
+    let datatype = vector_config.datatype.unwrap_or_default();
+    if storage.datatype() != datatype {
+        return Err(OperationError::service_error(format!(
+            "Vector storage backend {name} opened storage of datatype {:?}, expected {datatype:?}",
+            storage.datatype(),
+        )));
+    }
+
+    let backend = name.to_string();
+    let storage = match storage {
+        DynDenseVectorStorageBox::Float32(storage) => VectorStorageEnum::DenseCustom(Box::new(
+            CustomDenseVectorStorage::new(backend, storage),
+        )),
+        DynDenseVectorStorageBox::Uint8(storage) => VectorStorageEnum::DenseCustomByte(Box::new(
+            CustomDenseVectorStorage::new(backend, storage),
+        )),
+        DynDenseVectorStorageBox::Float16(storage) => VectorStorageEnum::DenseCustomHalf(Box::new(
+            CustomDenseVectorStorage::new(backend, storage),
+        )),
+    };
+    Ok(storage)
+}
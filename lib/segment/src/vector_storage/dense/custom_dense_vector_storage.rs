@@ -0,0 +1,259 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+
+use bitvec::prelude::BitSlice;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+
+use crate::common::Flusher;
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::data_types::named_vectors::CowVector;
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{
+    VectorElementType, VectorElementTypeByte, VectorElementTypeHalf, VectorRef,
+};
+use crate::types::{Distance, VectorStorageDatatype};
+use crate::vector_storage::{AccessPattern, DenseVectorStorage, VectorStorage};
+
+/// Dense vector storage implemented outside of this crate
+///
+/// Unlike [`VectorStorage`], this trait is object safe, so implementations can be provided at
+/// runtime through a [`VectorStorageBackend`](crate::vector_storage::backend_registry::VectorStorageBackend).
+/// Only single dense vectors are supported, with elements of the vector datatype `T`.
+///
+/// Vectors are only appended while the segment is built, the storage is read-only afterwards.
+/// Storages of appendable segments also implement [`AppendableDynDenseVectorStorage`].
+pub trait DynDenseVectorStorage<T: PrimitiveVectorElement>: Send + Sync {
+    fn vector_dim(&self) -> usize;
+
+    fn distance(&self) -> Distance;
+
+    fn is_on_disk(&self) -> bool;
+
+    /// Number of vectors, including soft deleted ones
+    fn total_vector_count(&self) -> usize;
+
+    /// Get the vector by the given key, `None` if it is out of range
+    fn get_dense_opt(&self, key: PointOffsetType) -> Option<&[T]>;
+
+    /// Append the vector with its deleted flag to the end of the storage, while the segment is
+    /// built
+    fn append_dense(&mut self, vector: &[T], deleted: bool) -> OperationResult<()>;
+
+    /// Access to insert vectors after the segment is built, `None` if the storage is read-only
+    ///
+    /// Implementations of [`AppendableDynDenseVectorStorage`] return themselves.
+    fn as_appendable_mut(&mut self) -> Option<&mut dyn AppendableDynDenseVectorStorage<T>> {
+        None
+    }
+
+    /// Persist all changes made since the previous flush
+    fn flusher(&self) -> Flusher;
+
+    /// Files of the storage, included into segment snapshots
+    fn files(&self) -> Vec<PathBuf>;
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    /// Flag the vector by the given key as deleted
+    ///
+    /// Returns true if the vector was not deleted before and is now deleted
+    fn delete_vector(&mut self, key: PointOffsetType) -> OperationResult<bool>;
+
+    fn is_deleted_vector(&self, key: PointOffsetType) -> bool;
+
+    fn deleted_vector_count(&self) -> usize;
+
+    fn deleted_vector_bitslice(&self) -> &BitSlice;
+
+    /// Load the storage into memory, if applicable
+    fn populate(&self) -> OperationResult<()> {
+        Ok(())
+    }
+
+    /// Drop the storage from disk cache, if applicable
+    fn clear_cache(&self) -> OperationResult<()> {
+        Ok(())
+    }
+}
+
+/// Custom dense vector storage, which vectors can be inserted and updated at any time
+pub trait AppendableDynDenseVectorStorage<T: PrimitiveVectorElement>:
+    DynDenseVectorStorage<T>
+{
+    /// Store the vector at the given key and unset its deleted flag
+    ///
+    /// `key` may be equal to [`DynDenseVectorStorage::total_vector_count`], which appends the
+    /// vector to the storage.
+    fn insert_dense(
+        &mut self,
+        key: PointOffsetType,
+        vector: &[T],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()>;
+}
+
+/// Custom storage opened by a backend, with elements of the vector datatype
+pub enum DynDenseVectorStorageBox {
+    Float32(Box<dyn DynDenseVectorStorage<VectorElementType>>),
+    Uint8(Box<dyn DynDenseVectorStorage<VectorElementTypeByte>>),
+    Float16(Box<dyn DynDenseVectorStorage<VectorElementTypeHalf>>),
+}
+
+impl DynDenseVectorStorageBox {
+    pub fn datatype(&self) -> VectorStorageDatatype {
+        match self {
+            DynDenseVectorStorageBox::Float32(_) => VectorStorageDatatype::Float32,
+            DynDenseVectorStorageBox::Uint8(_) => VectorStorageDatatype::Uint8,
+            DynDenseVectorStorageBox::Float16(_) => VectorStorageDatatype::Float16,
+        }
+    }
+
+    pub fn vector_dim(&self) -> usize {
+        match self {
+            DynDenseVectorStorageBox::Float32(storage) => storage.vector_dim(),
+            DynDenseVectorStorageBox::Uint8(storage) => storage.vector_dim(),
+            DynDenseVectorStorageBox::Float16(storage) => storage.vector_dim(),
+        }
+    }
+}
+
+/// Vector storage provided by a registered backend
+pub struct CustomDenseVectorStorage<T: PrimitiveVectorElement> {
+    /// Name of the backend this storage was opened with
+    backend: String,
+    storage: Box<dyn DynDenseVectorStorage<T>>,
+}
+
+impl<T: PrimitiveVectorElement> CustomDenseVectorStorage<T> {
+    pub fn new(backend: String, storage: Box<dyn DynDenseVectorStorage<T>>) -> Self {
+        Self { backend, storage }
+    }
+
+    pub fn backend(&self) -> &str {
+        &self.backend
+    }
+
+    pub fn populate(&self) -> OperationResult<()> {
+        self.storage.populate()
+    }
+
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        self.storage.clear_cache()
+    }
+}
+
+impl<T: PrimitiveVectorElement> fmt::Debug for CustomDenseVectorStorage<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomDenseVectorStorage")
+            .field("backend", &self.backend)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: PrimitiveVectorElement> DenseVectorStorage<T> for CustomDenseVectorStorage<T> {
+    fn vector_dim(&self) -> usize {
+        self.storage.vector_dim()
+    }
+
+    fn get_dense<P: AccessPattern>(&self, key: PointOffsetType) -> &[T] {
+        self.storage
+            .get_dense_opt(key)
+            .expect("custom storage vector not found")
+    }
+}
+
+impl<T: PrimitiveVectorElement> VectorStorage for CustomDenseVectorStorage<T> {
+    fn distance(&self) -> Distance {
+        self.storage.distance()
+    }
+
+    fn datatype(&self) -> VectorStorageDatatype {
+        T::datatype()
+    }
+
+    fn is_on_disk(&self) -> bool {
+        self.storage.is_on_disk()
+    }
+
+    fn total_vector_count(&self) -> usize {
+        self.storage.total_vector_count()
+    }
+
+    fn get_vector<P: AccessPattern>(&self, key: PointOffsetType) -> CowVector<'_> {
+        self.get_vector_opt::<P>(key).expect("Vector not found")
+    }
+
+    fn get_vector_opt<P: AccessPattern>(&self, key: PointOffsetType) -> Option<CowVector<'_>> {
+        self.storage
+            .get_dense_opt(key)
+            .map(|slice| CowVector::from(T::slice_to_float_cow(slice.into())))
+    }
+
+    fn insert_vector(
+        &mut self,
+        key: PointOffsetType,
+        vector: VectorRef,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let Some(storage) = self.storage.as_appendable_mut() else {
+            return Err(OperationError::service_error(format!(
+                "Vector storage backend {} is read-only",
+                self.backend,
+            )));
+        };
+        let vector: &[VectorElementType] = vector.try_into()?;
+        let vector = T::slice_from_float_cow(Cow::from(vector));
+        storage.insert_dense(key, vector.as_ref(), hw_counter)
+    }
+
+    fn update_from<'a>(
+        &mut self,
+        other_vectors: &'a mut impl Iterator<Item = (CowVector<'a>, bool)>,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Range<PointOffsetType>> {
+        let start_index = self.storage.total_vector_count() as PointOffsetType;
+        for (other_vector, other_deleted) in other_vectors {
+            check_process_stopped(stopped)?;
+            // Do not perform preprocessing - vectors should be already processed
+            let other_vector = T::slice_from_float_cow(Cow::try_from(other_vector)?);
+            self.storage
+                .append_dense(other_vector.as_ref(), other_deleted)?;
+        }
+        let end_index = self.storage.total_vector_count() as PointOffsetType;
+        Ok(start_index..end_index)
+    }
+
+    fn flusher(&self) -> Flusher {
+        self.storage.flusher()
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        self.storage.files()
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        self.storage.immutable_files()
+    }
+
+    fn delete_vector(&mut self, key: PointOffsetType) -> OperationResult<bool> {
+        self.storage.delete_vector(key)
+    }
+
+    fn is_deleted_vector(&self, key: PointOffsetType) -> bool {
+        self.storage.is_deleted_vector(key)
+    }
+
+    fn deleted_vector_count(&self) -> usize {
+        self.storage.deleted_vector_count()
+    }
+
+    fn deleted_vector_bitslice(&self) -> &BitSlice {
+        self.storage.deleted_vector_bitslice()
+    }
+}
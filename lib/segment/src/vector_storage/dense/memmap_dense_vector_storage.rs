@@ -364,6 +364,7 @@ mod tests {
 
     use atomic_refcell::AtomicRefCell;
     use common::counter::hardware_counter::HardwareCounterCell;
+    use common::fs::{atomic_save_json, read_json};
     #[expect(deprecated, reason = "legacy code")]
     use common::mmap::transmute_to_u8_slice;
     use itertools::Itertools;
@@ -377,7 +378,8 @@ mod tests {
     use crate::types::{PointIdType, QuantizationConfig, ScalarQuantizationConfig};
     use crate::vector_storage::dense::volatile_dense_vector_storage::new_volatile_dense_vector_storage;
     use crate::vector_storage::quantized::quantized_vectors::{
-        QuantizedVectors, QuantizedVectorsStorageType,
+        QUANTIZED_CONFIG_PATH, QuantizedEncodingInfo, QuantizedVectors, QuantizedVectorsConfig,
+        QuantizedVectorsStorageType,
     };
     use crate::vector_storage::{DEFAULT_STOPPED, Random, new_raw_scorer};
 
@@ -872,7 +874,8 @@ mod tests {
         let scorer_quant = quantized_vectors
             .raw_scorer(query.clone(), hardware_counter)
             .unwrap();
-        let scorer_orig = new_raw_scorer(query, &storage, HardwareCounterCell::new()).unwrap();
+        let scorer_orig =
+            new_raw_scorer(query.clone(), &storage, HardwareCounterCell::new()).unwrap();
 
         for i in 0..5 {
             let quant = scorer_quant.score_point(i);
@@ -883,5 +886,51 @@ mod tests {
             let orig = scorer_orig.score_internal(0, i);
             assert!((orig - quant).abs() < 0.15);
         }
+        drop(scorer_quant);
+        drop(quantized_vectors);
+
+        // test re-encoding of vectors from an incompatible platform
+        let config_path = dir.path().join(QUANTIZED_CONFIG_PATH);
+        let mut quantized_config: QuantizedVectorsConfig = read_json(&config_path).unwrap();
+        quantized_config.encoding = Some(QuantizedEncodingInfo {
+            little_endian: !cfg!(target_endian = "little"),
+            ..QuantizedEncodingInfo::current()
+        });
+        atomic_save_json(&config_path, &quantized_config).unwrap();
+
+        let quantized_vectors = QuantizedVectors::load(&config, &storage, dir.path(), &stopped)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            quantized_vectors.config().encoding,
+            Some(QuantizedEncodingInfo::current()),
+        );
+        assert_eq!(quantization_files, quantized_vectors.files());
+        let scorer_quant = quantized_vectors
+            .raw_scorer(query.clone(), HardwareCounterCell::new())
+            .unwrap();
+        let scorer_orig = new_raw_scorer(query, &storage, HardwareCounterCell::new()).unwrap();
+        for i in 0..5 {
+            let quant = scorer_quant.score_point(i);
+            let orig = scorer_orig.score_point(i);
+            assert!((orig - quant).abs() < 0.15);
+        }
+        drop(scorer_quant);
+        drop(quantized_vectors);
+
+        // storages of older versions are validated against the legacy layout
+        let mut quantized_config: QuantizedVectorsConfig = read_json(&config_path).unwrap();
+        quantized_config.encoding = None;
+        atomic_save_json(&config_path, &quantized_config).unwrap();
+
+        let quantized_vectors = QuantizedVectors::load(&config, &storage, dir.path(), &stopped)
+            .unwrap()
+            .unwrap();
+        let expected_encoding = if QuantizedEncodingInfo::legacy().is_compatible_with_current() {
+            None
+        } else {
+            Some(QuantizedEncodingInfo::current())
+        };
+        assert_eq!(quantized_vectors.config().encoding, expected_encoding);
     }
 }
@@ -1,4 +1,5 @@
 pub mod appendable_dense_vector_storage;
+pub mod custom_dense_vector_storage;
 pub mod memmap_dense_vector_storage;
 pub mod mmap_dense_vectors;
 #[cfg(feature = "rocksdb")]
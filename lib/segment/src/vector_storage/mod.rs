@@ -3,6 +3,7 @@ mod async_io;
 mod async_io_mock;
 #[cfg(target_os = "linux")]
 pub mod async_raw_scorer;
+pub mod backend_registry;
 mod bitvec;
 mod chunked_mmap_vectors;
 pub mod chunked_vectors;
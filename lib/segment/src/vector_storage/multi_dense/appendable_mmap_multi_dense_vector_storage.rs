@@ -467,6 +467,11 @@ pub fn open_appendable_memmap_multi_vector_storage_impl<T: PrimitiveVectorElemen
     })
 }
 
+/// Whether an appendable multivector storage was created in the given directory
+pub(crate) fn appendable_memmap_multi_vector_storage_exists(vector_storage_path: &Path) -> bool {
+    vector_storage_path.join(OFFSETS_DIR_PATH).exists()
+}
+
 /// Find files related to this dense vector storage
 #[cfg(any(test, feature = "rocksdb"))]
 pub(crate) fn find_storage_files(vector_storage_path: &Path) -> OperationResult<Vec<PathBuf>> {
@@ -0,0 +1,492 @@
+use std::io::{self, BufWriter, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use bitvec::prelude::BitSlice;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::fs::clear_disk_cache;
+use common::mmap;
+use common::mmap::{AdviceSetting, MmapSliceReadOnly};
+use common::types::PointOffsetType;
+use fs_err as fs;
+use fs_err::{File, OpenOptions};
+use zerocopy::IntoBytes;
+
+use crate::common::Flusher;
+use crate::common::flags::bitvec_flags::BitvecFlags;
+use crate::common::flags::dynamic_mmap_flags::DynamicMmapFlags;
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::data_types::named_vectors::{CowMultiVector, CowVector};
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{TypedMultiDenseVectorRef, VectorElementType, VectorRef};
+use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
+use crate::vector_storage::{AccessPattern, MultiVectorStorage, VectorStorage, VectorStorageEnum};
+
+const VECTORS_PATH: &str = "multi_vectors.dat";
+const OFFSETS_PATH: &str = "offsets.dat";
+const DELETED_DIR_PATH: &str = "deleted";
+
+/// Stores all multivectors in mem-mapped files
+///
+/// Inner vectors of all points are stored one after another in a single values file. The offsets
+/// file holds `total_vector_count + 1` entries, so that inner vectors of the point `key` are
+/// `offsets[key]..offsets[key + 1]` in the values file.
+///
+/// It is not possible to insert new vectors into mem-mapped storage,
+/// but possible to mark some vectors as removed
+///
+/// Mem-mapped storage can only be constructed from another storage
+#[derive(Debug)]
+pub struct MmapMultiDenseVectorStorage<T: PrimitiveVectorElement> {
+    vectors_path: PathBuf,
+    offsets_path: PathBuf,
+    dim: usize,
+    /// Flattened inner vectors, `None` while the values file is empty, as it can't be mapped
+    vectors: Option<MmapSliceReadOnly<T>>,
+    /// Offsets of the first inner vector of each point, followed by the total count
+    offsets: MmapSliceReadOnly<u64>,
+    /// Flags marking deleted vectors
+    deleted: BitvecFlags,
+    deleted_count: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+}
+
+impl<T: PrimitiveVectorElement> MmapMultiDenseVectorStorage<T> {
+    /// Populate all pages in the mmap.
+    /// Block until all pages are populated.
+    pub fn populate(&self) -> OperationResult<()> {
+        // deleted bitvec is already loaded
+        if let Some(vectors) = &self.vectors {
+            vectors.populate()?;
+        }
+        self.offsets.populate()?;
+        Ok(())
+    }
+
+    /// Drop disk cache.
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        clear_disk_cache(&self.vectors_path)?;
+        clear_disk_cache(&self.offsets_path)?;
+        Ok(())
+    }
+
+    /// Range of inner vectors of the given point, `None` if the key is out of range
+    fn inner_vectors_range(&self, key: PointOffsetType) -> Option<Range<usize>> {
+        let start = *self.offsets.get(key as usize)?;
+        let end = *self.offsets.get(key as usize + 1)?;
+        Some(start as usize..end as usize)
+    }
+
+    fn flattened_vectors(&self, inner_vectors: Range<usize>) -> &[T] {
+        let flattened = inner_vectors.start * self.dim..inner_vectors.end * self.dim;
+        match &self.vectors {
+            Some(vectors) => &vectors[flattened],
+            None => &[],
+        }
+    }
+
+    fn set_deleted(&mut self, key: PointOffsetType, deleted: bool) -> bool {
+        if key as usize >= self.total_vector_count() {
+            return false;
+        }
+
+        let previous = self.deleted.set(key, deleted);
+        if !previous && deleted {
+            self.deleted_count += 1;
+        } else if previous && !deleted {
+            self.deleted_count -= 1;
+        }
+        previous
+    }
+}
+
+impl<T: PrimitiveVectorElement> MultiVectorStorage<T> for MmapMultiDenseVectorStorage<T> {
+    fn vector_dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Panics if key is not found
+    fn get_multi<P: AccessPattern>(&self, key: PointOffsetType) -> TypedMultiDenseVectorRef<'_, T> {
+        self.get_multi_opt::<P>(key)
+            .unwrap_or_else(|| panic!("vector not found: {key}"))
+    }
+
+    /// Returns None if key is not found
+    fn get_multi_opt<P: AccessPattern>(
+        &self,
+        key: PointOffsetType,
+    ) -> Option<TypedMultiDenseVectorRef<'_, T>> {
+        self.inner_vectors_range(key)
+            .map(|inner_vectors| TypedMultiDenseVectorRef {
+                flattened_vectors: self.flattened_vectors(inner_vectors),
+                dim: self.dim,
+            })
+    }
+
+    fn iterate_inner_vectors(&self) -> impl Iterator<Item = &[T]> + Clone + Send {
+        let inner_vectors = self.offsets.last().copied().unwrap_or_default() as usize;
+        self.flattened_vectors(0..inner_vectors)
+            .chunks_exact(self.dim)
+    }
+
+    fn multi_vector_config(&self) -> &MultiVectorConfig {
+        &self.multi_vector_config
+    }
+
+    fn size_of_available_vectors_in_bytes(&self) -> usize {
+        if self.total_vector_count() > 0 {
+            let inner_vectors = self.offsets.last().copied().unwrap_or_default() as usize;
+            let total_size = inner_vectors * self.dim * size_of::<T>();
+            (total_size as u128 * self.available_vector_count() as u128
+                / self.total_vector_count() as u128) as usize
+        } else {
+            0
+        }
+    }
+}
+
+impl<T: PrimitiveVectorElement> VectorStorage for MmapMultiDenseVectorStorage<T> {
+    fn distance(&self) -> Distance {
+        self.distance
+    }
+
+    fn datatype(&self) -> VectorStorageDatatype {
+        T::datatype()
+    }
+
+    fn is_on_disk(&self) -> bool {
+        true
+    }
+
+    fn total_vector_count(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    fn get_vector<P: AccessPattern>(&self, key: PointOffsetType) -> CowVector<'_> {
+        self.get_vector_opt::<P>(key).expect("vector not found")
+    }
+
+    fn get_vector_opt<P: AccessPattern>(&self, key: PointOffsetType) -> Option<CowVector<'_>> {
+        self.get_multi_opt::<P>(key).map(|multi_dense_vector| {
+            CowVector::MultiDense(T::into_float_multivector(CowMultiVector::Borrowed(
+                multi_dense_vector,
+            )))
+        })
+    }
+
+    fn insert_vector(
+        &mut self,
+        _key: PointOffsetType,
+        _vector: VectorRef,
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        panic!("Can't directly update vector in mmap storage")
+    }
+
+    fn update_from<'a>(
+        &mut self,
+        other_vectors: &'a mut impl Iterator<Item = (CowVector<'a>, bool)>,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Range<PointOffsetType>> {
+        let start_index = self.total_vector_count() as PointOffsetType;
+        let mut end_index = start_index;
+        let mut inner_vectors = self.offsets.last().copied().unwrap_or_default();
+
+        // Extend values and offsets files, write other vectors into them
+        let mut vectors_file = BufWriter::new(open_append(&self.vectors_path)?);
+        let mut offsets_file = BufWriter::new(open_append(&self.offsets_path)?);
+        let mut deleted_ids = vec![];
+        for (other_vector, other_deleted) in other_vectors {
+            check_process_stopped(stopped)?;
+            // Do not perform preprocessing - vectors should be already processed
+            let multi_vector: TypedMultiDenseVectorRef<VectorElementType> =
+                other_vector.as_vec_ref().try_into()?;
+            let multi_vector = T::from_float_multivector(CowMultiVector::Borrowed(multi_vector));
+            let multi_vector = multi_vector.as_vec_ref();
+            if multi_vector.dim != self.dim {
+                return Err(OperationError::WrongVectorDimension {
+                    expected_dim: self.dim,
+                    received_dim: multi_vector.dim,
+                });
+            }
+
+            vectors_file.write_all(multi_vector.flattened_vectors.as_bytes())?;
+            inner_vectors += multi_vector.vectors_count() as u64;
+            offsets_file.write_all(inner_vectors.as_bytes())?;
+
+            // Remember deleted IDs so we can propagate deletions later
+            if other_deleted {
+                deleted_ids.push(end_index);
+            }
+            end_index += 1;
+        }
+
+        // Explicitly fsync file contents to ensure durability
+        for file in [vectors_file, offsets_file] {
+            file.into_inner()
+                .map_err(io::IntoInnerError::into_error)?
+                .sync_data()?;
+        }
+
+        // Load storage with updated files
+        let (vectors, offsets) = open_mmaps(
+            &self.vectors_path,
+            &self.offsets_path,
+            AdviceSetting::Global,
+            false, // No need to populate
+        )?;
+        self.vectors = vectors;
+        self.offsets = offsets;
+
+        for id in deleted_ids {
+            check_process_stopped(stopped)?;
+            self.set_deleted(id, true);
+        }
+        self.deleted.flusher()()?;
+
+        Ok(start_index..end_index)
+    }
+
+    fn flusher(&self) -> Flusher {
+        self.deleted.flusher()
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = vec![self.vectors_path.clone(), self.offsets_path.clone()];
+        files.extend(self.deleted.files());
+        files
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        // Vector storage is initialized by `SegmentBuilder` during segment construction
+        // and can't be changed after
+        vec![self.vectors_path.clone(), self.offsets_path.clone()]
+    }
+
+    fn delete_vector(&mut self, key: PointOffsetType) -> OperationResult<bool> {
+        let was_deleted = self.set_deleted(key, true);
+        Ok(!was_deleted && (key as usize) < self.total_vector_count())
+    }
+
+    fn is_deleted_vector(&self, key: PointOffsetType) -> bool {
+        self.deleted.get(key)
+    }
+
+    fn deleted_vector_count(&self) -> usize {
+        self.deleted_count
+    }
+
+    fn deleted_vector_bitslice(&self) -> &BitSlice {
+        self.deleted.get_bitslice()
+    }
+}
+
+pub fn open_memmap_multi_vector_storage(
+    storage_element_type: VectorStorageDatatype,
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+    madvise: AdviceSetting,
+    populate: bool,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = match storage_element_type {
+        VectorStorageDatatype::Float32 => {
+            VectorStorageEnum::MultiDenseMemmap(open_memmap_multi_vector_storage_impl(
+                path,
+                dim,
+                distance,
+                multi_vector_config,
+                madvise,
+                populate,
+            )?)
+        }
+        VectorStorageDatatype::Uint8 => {
+            VectorStorageEnum::MultiDenseMemmapByte(open_memmap_multi_vector_storage_impl(
+                path,
+                dim,
+                distance,
+                multi_vector_config,
+                madvise,
+                populate,
+            )?)
+        }
+        VectorStorageDatatype::Float16 => {
+            VectorStorageEnum::MultiDenseMemmapHalf(open_memmap_multi_vector_storage_impl(
+                path,
+                dim,
+                distance,
+                multi_vector_config,
+                madvise,
+                populate,
+            )?)
+        }
+    };
+    Ok(storage)
+}
+
+fn open_memmap_multi_vector_storage_impl<T: PrimitiveVectorElement>(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+    madvise: AdviceSetting,
+    populate: bool,
+) -> OperationResult<Box<MmapMultiDenseVectorStorage<T>>> {
+    fs::create_dir_all(path)?;
+
+    let vectors_path = path.join(VECTORS_PATH);
+    let offsets_path = path.join(OFFSETS_PATH);
+    let deleted_path = path.join(DELETED_DIR_PATH);
+
+    if !offsets_path.exists() {
+        // Empty storage has no inner vectors, and a single offset holding their count
+        File::create(&vectors_path)?;
+        let mut offsets_file = File::create(&offsets_path)?;
+        offsets_file.write_all(0u64.as_bytes())?;
+        offsets_file.sync_all()?;
+    }
+
+    let (vectors, offsets) = open_mmaps(&vectors_path, &offsets_path, madvise, populate)?;
+
+    let deleted = BitvecFlags::new(DynamicMmapFlags::open(&deleted_path, populate)?);
+    let deleted_count = deleted.count_trues();
+
+    Ok(Box::new(MmapMultiDenseVectorStorage {
+        vectors_path,
+        offsets_path,
+        dim,
+        vectors,
+        offsets,
+        deleted,
+        deleted_count,
+        distance,
+        multi_vector_config,
+    }))
+}
+
+type MultiVectorMmaps<T> = (Option<MmapSliceReadOnly<T>>, MmapSliceReadOnly<u64>);
+
+fn open_mmaps<T: PrimitiveVectorElement>(
+    vectors_path: &Path,
+    offsets_path: &Path,
+    madvise: AdviceSetting,
+    populate: bool,
+) -> OperationResult<MultiVectorMmaps<T>> {
+    let vectors = if fs::metadata(vectors_path)?.len() > 0 {
+        let mmap = mmap::open_read_mmap(vectors_path, madvise, populate)?;
+        // Safety: values file is only written by this storage, as a sequence of `T`
+        Some(unsafe { MmapSliceReadOnly::try_from(mmap)? })
+    } else {
+        None
+    };
+
+    let mmap = mmap::open_read_mmap(offsets_path, madvise, populate)?;
+    // Safety: offsets file is only written by this storage, as a sequence of `u64`
+    let offsets = unsafe { MmapSliceReadOnly::try_from(mmap)? };
+    if offsets.is_empty() {
+        return Err(OperationError::service_error(format!(
+            "Multivector offsets file {} is empty",
+            offsets_path.display(),
+        )));
+    }
+
+    Ok((vectors, offsets))
+}
+
+/// Open a file shortly for appending
+fn open_append<P: AsRef<Path>>(path: P) -> io::Result<File> {
+    let path = path.as_ref().to_path_buf();
+    OpenOptions::new().append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::data_types::vectors::MultiDenseVectorInternal;
+    use crate::vector_storage::multi_dense::volatile_multi_dense_vector_storage::new_volatile_multi_dense_vector_storage;
+    use crate::vector_storage::{DEFAULT_STOPPED, Random};
+
+    #[test]
+    fn test_mmap_multi_dense_vector_storage() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::disposable();
+        let multi_vector_config = MultiVectorConfig::default();
+
+        let points = [
+            vec![vec![1.0, 0.0], vec![0.0, 1.0]],
+            vec![vec![1.0, 1.0]],
+            vec![vec![0.5, 0.5], vec![0.0, 0.0], vec![1.0, 0.0]],
+        ];
+
+        let mut source =
+            new_volatile_multi_dense_vector_storage(2, Distance::Dot, multi_vector_config);
+        for (key, point) in points.iter().enumerate() {
+            let multi_vector = MultiDenseVectorInternal::try_from(point.clone()).unwrap();
+            source
+                .insert_vector(key as PointOffsetType, (&multi_vector).into(), &hw_counter)
+                .unwrap();
+        }
+        source.delete_vector(1).unwrap();
+
+        {
+            let mut storage = open_memmap_multi_vector_storage(
+                VectorStorageDatatype::Float32,
+                dir.path(),
+                2,
+                Distance::Dot,
+                multi_vector_config,
+                AdviceSetting::Global,
+                false,
+            )
+            .unwrap();
+            assert_eq!(storage.total_vector_count(), 0);
+
+            let mut iter = (0..points.len() as PointOffsetType).map(|key| {
+                (
+                    source.get_vector::<Random>(key),
+                    source.is_deleted_vector(key),
+                )
+            });
+            let range = storage.update_from(&mut iter, &DEFAULT_STOPPED).unwrap();
+            assert_eq!(range, 0..3);
+
+            storage.delete_vector(2).unwrap();
+            storage.flusher()().unwrap();
+        }
+
+        // Vectors and deletions are persisted
+        let storage = open_memmap_multi_vector_storage(
+            VectorStorageDatatype::Float32,
+            dir.path(),
+            2,
+            Distance::Dot,
+            multi_vector_config,
+            AdviceSetting::Global,
+            false,
+        )
+        .unwrap();
+        assert_eq!(storage.total_vector_count(), 3);
+        assert_eq!(storage.deleted_vector_count(), 2);
+        assert!(!storage.is_deleted_vector(0));
+        assert!(storage.is_deleted_vector(1));
+        assert!(storage.is_deleted_vector(2));
+
+        for (key, point) in points.iter().enumerate() {
+            let expected = MultiDenseVectorInternal::try_from(point.clone()).unwrap();
+            let stored = storage.get_vector::<Random>(key as PointOffsetType);
+            let multi_dense: TypedMultiDenseVectorRef<_> = stored.as_vec_ref().try_into().unwrap();
+            assert_eq!(multi_dense.to_owned(), expected);
+        }
+        assert!(storage.get_vector_opt::<Random>(3).is_none());
+
+        let VectorStorageEnum::MultiDenseMemmap(storage) = storage else {
+            panic!("unexpected storage type");
+        };
+        assert_eq!(storage.iterate_inner_vectors().count(), 6);
+    }
+}
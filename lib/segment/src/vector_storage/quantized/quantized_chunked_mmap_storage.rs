@@ -1,7 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::mmap::{Advice, AdviceSetting, MmapFlusher};
+use common::mmap::{Advice, AdviceSetting, MmapComponent, MmapFlusher, advice};
 use common::types::PointOffsetType;
 
 use crate::common::operation_error::OperationResult;
@@ -14,17 +14,17 @@ pub struct QuantizedChunkedMmapStorage {
 
 impl QuantizedChunkedMmapStorage {
     pub fn new(path: &Path, quantized_vector_size: usize, in_ram: bool) -> OperationResult<Self> {
-        let advice = if in_ram {
-            AdviceSetting::from(Advice::Normal)
+        let (advice, populate) = if in_ram {
+            (AdviceSetting::from(Advice::Normal), true)
         } else {
-            AdviceSetting::Global
+            let component = advice::get_component(MmapComponent::QuantizedVectors);
+            (
+                component.advice_or(AdviceSetting::Global),
+                component.populate_or(false),
+            )
         };
-        let data = ChunkedMmapVectors::<u8>::open(
-            path,
-            quantized_vector_size,
-            advice,
-            Some(in_ram), // populate
-        )?;
+        let data =
+            ChunkedMmapVectors::<u8>::open(path, quantized_vector_size, advice, Some(populate))?;
         Ok(Self { data })
     }
 
@@ -86,17 +86,17 @@ pub struct QuantizedChunkedMmapStorageBuilder {
 impl QuantizedChunkedMmapStorageBuilder {
     #[allow(dead_code)]
     pub fn new(path: &Path, quantized_vector_size: usize, in_ram: bool) -> OperationResult<Self> {
-        let advice = if in_ram {
-            AdviceSetting::from(Advice::Normal)
+        let (advice, populate) = if in_ram {
+            (AdviceSetting::from(Advice::Normal), true)
         } else {
-            AdviceSetting::Global
+            let component = advice::get_component(MmapComponent::QuantizedVectors);
+            (
+                component.advice_or(AdviceSetting::Global),
+                component.populate_or(false),
+            )
         };
-        let data = ChunkedMmapVectors::<u8>::open(
-            path,
-            quantized_vector_size,
-            advice,
-            Some(in_ram), // populate
-        )?;
+        let data =
+            ChunkedMmapVectors::<u8>::open(path, quantized_vector_size, advice, Some(populate))?;
         Ok(Self {
             data,
             hw_counter: HardwareCounterCell::disposable(),
@@ -2,7 +2,7 @@ use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::mmap::{Madviseable, MmapFlusher, advice};
+use common::mmap::{AdviceSetting, Madviseable, MmapComponent, MmapFlusher, advice};
 use common::types::PointOffsetType;
 use fs_err as fs;
 use fs_err::OpenOptions;
@@ -35,7 +35,11 @@ impl QuantizedMmapStorage {
     ) -> std::io::Result<QuantizedMmapStorage> {
         let file = OpenOptions::new().read(true).open(path)?;
         let mmap = unsafe { Mmap::map(&file)? };
-        advice::madvise(&mmap, advice::get_global())?;
+        let component = advice::get_component(MmapComponent::QuantizedVectors);
+        advice::madvise(&mmap, component.advice_or(AdviceSetting::Global).resolve())?;
+        if component.populate_or(false) {
+            mmap.populate();
+        }
 
         let quantized_vector_size = NonZeroUsize::new(quantized_vector_size).ok_or_else(|| {
             std::io::Error::new(
@@ -2,7 +2,7 @@ use std::ops::DerefMut;
 use std::path::{Path, PathBuf};
 
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::mmap::{Advice, AdviceSetting, MmapFlusher, MmapSlice};
+use common::mmap::{Advice, AdviceSetting, MmapComponent, MmapFlusher, MmapSlice, advice};
 use common::typelevel::False;
 use common::types::{PointOffsetType, ScoreType};
 use fs_err as fs;
@@ -199,17 +199,16 @@ impl MultivectorOffsetsStorageChunkedMmap {
     }
 
     pub fn load(path: &Path, in_ram: bool) -> OperationResult<Self> {
-        let advice = if in_ram {
-            AdviceSetting::from(Advice::Normal)
+        let (advice, populate) = if in_ram {
+            (AdviceSetting::from(Advice::Normal), true)
         } else {
-            AdviceSetting::Global
+            let component = advice::get_component(MmapComponent::QuantizedVectors);
+            (
+                component.advice_or(AdviceSetting::Global),
+                component.populate_or(false),
+            )
         };
-        let data = ChunkedMmapVectors::<MultivectorOffset>::open(
-            path,
-            1,
-            advice,
-            Some(in_ram), // populate
-        )?;
+        let data = ChunkedMmapVectors::<MultivectorOffset>::open(path, 1, advice, Some(populate))?;
         Ok(Self { data })
     }
 
@@ -6,6 +6,7 @@ use std::sync::atomic::AtomicBool;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::fs::{atomic_save_json, clear_disk_cache, read_json};
 use common::types::PointOffsetType;
+use fs_err as fs;
 use quantization::encoded_vectors_binary::EncodedVectorsBin;
 use quantization::encoded_vectors_u8::ScalarQuantizationMethod;
 use quantization::{EncodedVectors, EncodedVectorsPQ, EncodedVectorsU8};
@@ -19,6 +20,7 @@ use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{QueryVector, VectorElementType, VectorRef};
+use crate::index::hnsw_index::num_rayon_threads;
 use crate::types::{
     BinaryQuantization, BinaryQuantizationConfig, BinaryQuantizationEncoding,
     BinaryQuantizationQueryEncoding, CompressionRatio, Distance, MultiVectorConfig,
@@ -59,6 +61,60 @@ pub struct QuantizedVectorsConfig {
     #[serde(default)]
     #[serde(skip_serializing_if = "QuantizedVectorsStorageType::is_immutable")]
     pub storage_type: QuantizedVectorsStorageType,
+    /// Platform the vectors were encoded on, not known for storages created by older versions,
+    /// see [`QuantizedEncodingInfo::legacy`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<QuantizedEncodingInfo>,
+}
+
+/// Properties of the platform, which the layout of the encoded vectors depends on.
+///
+/// Storages are moved between machines with snapshots, so the layout is validated on load.
+#[derive(Deserialize, Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct QuantizedEncodingInfo {
+    /// Version of the encoded data layout
+    pub version: u32,
+    /// CPU architecture, e.g. `x86_64` or `aarch64`
+    pub arch: String,
+    pub little_endian: bool,
+    pub pointer_width: u32,
+}
+
+impl QuantizedEncodingInfo {
+    /// Current version of the encoded data layout, bump on incompatible changes
+    pub const VERSION: u32 = 1;
+
+    pub fn current() -> Self {
+        Self {
+            version: Self::VERSION,
+            arch: std::env::consts::ARCH.to_string(),
+            little_endian: cfg!(target_endian = "little"),
+            pointer_width: usize::BITS,
+        }
+    }
+
+    /// Layout of storages created before the platform was recorded.
+    ///
+    /// Older versions were only built for 64-bit little-endian platforms.
+    pub fn legacy() -> Self {
+        Self {
+            version: Self::VERSION,
+            arch: "unknown".to_string(),
+            little_endian: true,
+            pointer_width: 64,
+        }
+    }
+
+    /// Whether vectors encoded on this platform can be read on the current one.
+    ///
+    /// Scorers select SIMD implementation at runtime, so a different architecture alone is fine,
+    /// as long as the binary layout is the same.
+    pub fn is_compatible_with_current(&self) -> bool {
+        let current = Self::current();
+        self.version == current.version
+            && self.little_endian == current.little_endian
+            && self.pointer_width == current.pointer_width
+    }
 }
 
 impl fmt::Debug for QuantizedVectorsConfig {
@@ -458,6 +514,12 @@ impl QuantizedVectors {
         max_threads: usize,
         stopped: &AtomicBool,
     ) -> OperationResult<Self> {
+        if vector_storage.distance() == Distance::Hamming {
+            return Err(OperationError::validation_error(
+                "Quantization is not supported for Hamming distance",
+            ));
+        }
+
         match vector_storage {
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::DenseSimple(v) => Self::create_impl(
@@ -560,6 +622,30 @@ impl QuantizedVectors {
                 max_threads,
                 stopped,
             ),
+            VectorStorageEnum::DenseCustom(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::DenseCustomByte(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::DenseCustomHalf(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => Err(OperationError::WrongSparse),
             VectorStorageEnum::SparseVolatile(_) => Err(OperationError::WrongSparse),
@@ -641,6 +727,30 @@ impl QuantizedVectors {
                 max_threads,
                 stopped,
             ),
+            VectorStorageEnum::MultiDenseMemmap(v) => Self::create_multi_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => Self::create_multi_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => Self::create_multi_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
         }
     }
 
@@ -715,6 +825,7 @@ impl QuantizedVectors {
             quantization_config: quantization_config.clone(),
             vector_parameters,
             storage_type,
+            encoding: Some(QuantizedEncodingInfo::current()),
         };
 
         let quantized_vectors = QuantizedVectors {
@@ -818,6 +929,7 @@ impl QuantizedVectors {
             quantization_config: quantization_config.clone(),
             vector_parameters,
             storage_type,
+            encoding: Some(QuantizedEncodingInfo::current()),
         };
 
         let quantized_vectors = QuantizedVectors {
@@ -841,6 +953,21 @@ impl QuantizedVectors {
         let config_path = Self::get_config_path(path);
         if config_path.exists() {
             let config: QuantizedVectorsConfig = read_json(&config_path)?;
+
+            let encoding = config
+                .encoding
+                .clone()
+                .unwrap_or_else(QuantizedEncodingInfo::legacy);
+            if !encoding.is_compatible_with_current() {
+                log::warn!(
+                    "Quantized vectors in {} were encoded on an incompatible platform \
+                     ({encoding:?}, current: {:?}), re-encoding",
+                    path.display(),
+                    QuantizedEncodingInfo::current(),
+                );
+                return Self::reencode(config, vector_storage, path, stopped).map(Some);
+            }
+
             return Ok(Some(Self::load_impl(config, vector_storage, path)?));
         }
 
@@ -860,6 +987,54 @@ impl QuantizedVectors {
         Ok(Some(quantized_vectors))
     }
 
+    /// Encode vectors again from the original `vector_storage`, replacing existing data files.
+    ///
+    /// Config file is replaced last, so if re-encoding is interrupted, it is started again on
+    /// the next load.
+    fn reencode(
+        config: QuantizedVectorsConfig,
+        vector_storage: &VectorStorageEnum,
+        path: &Path,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let QuantizedVectorsConfig {
+            quantization_config,
+            vector_parameters: _,
+            storage_type,
+            encoding: _,
+        } = config;
+
+        for storage_type in [
+            QuantizedVectorsStorageType::Immutable,
+            QuantizedVectorsStorageType::Mutable,
+        ] {
+            for data_path in [
+                Self::get_data_path(path, storage_type),
+                Self::get_offsets_path(path, storage_type),
+            ] {
+                if data_path.is_dir() {
+                    fs::remove_dir_all(&data_path)?;
+                } else if data_path.exists() {
+                    fs::remove_file(&data_path)?;
+                }
+            }
+        }
+        let meta_path = Self::get_meta_path(path);
+        if meta_path.exists() {
+            fs::remove_file(&meta_path)?;
+        }
+
+        // Segment is not available until loaded, use as many threads as for indexing
+        Self::create(
+            vector_storage,
+            &quantization_config,
+            storage_type,
+            path,
+            num_rayon_threads(0),
+            stopped,
+        )
+    }
+
     pub fn load_impl(
         config: QuantizedVectorsConfig,
         vector_storage: &VectorStorageEnum,
@@ -1789,8 +1964,13 @@ impl QuantizedVectors {
                 Distance::Euclid => quantization::DistanceType::L2,
                 Distance::Dot => quantization::DistanceType::Dot,
                 Distance::Manhattan => quantization::DistanceType::L1,
+                // Quantization is rejected for Hamming, see `QuantizedVectors::create`
+                Distance::Hamming => quantization::DistanceType::L1,
             },
-            invert: distance == Distance::Euclid || distance == Distance::Manhattan,
+            invert: matches!(
+                distance,
+                Distance::Euclid | Distance::Manhattan | Distance::Hamming
+            ),
         }
     }
 
@@ -20,7 +20,9 @@ use crate::data_types::vectors::{
     DenseVector, MultiDenseVectorInternal, QueryVector, VectorInternal,
 };
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, ManhattanMetric,
+};
 use crate::types::Distance;
 use crate::vector_storage::common::VECTOR_READ_BATCH_SIZE;
 use crate::vector_storage::query::NaiveFeedbackQuery;
@@ -94,6 +96,9 @@ pub fn new_raw_scorer<'a>(
         VectorStorageEnum::DenseAppendableMemmap(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseAppendableMemmapByte(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseAppendableMemmapHalf(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
+        VectorStorageEnum::DenseCustom(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
+        VectorStorageEnum::DenseCustomByte(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
+        VectorStorageEnum::DenseCustomHalf(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         #[cfg(feature = "rocksdb")]
         VectorStorageEnum::SparseSimple(vs) => raw_sparse_scorer_impl(query, vs, hc),
         VectorStorageEnum::SparseVolatile(vs) => raw_sparse_scorer_volatile(query, vs, hc),
@@ -118,6 +123,13 @@ pub fn new_raw_scorer<'a>(
         VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => {
             raw_multi_scorer_impl(query, vs.as_ref(), hc)
         }
+        VectorStorageEnum::MultiDenseMemmap(vs) => raw_multi_scorer_impl(query, vs.as_ref(), hc),
+        VectorStorageEnum::MultiDenseMemmapByte(vs) => {
+            raw_multi_scorer_impl(query, vs.as_ref(), hc)
+        }
+        VectorStorageEnum::MultiDenseMemmapHalf(vs) => {
+            raw_multi_scorer_impl(query, vs.as_ref(), hc)
+        }
     }
 }
 
@@ -223,6 +235,7 @@ where
     EuclidMetric: Metric<TElement>,
     DotProductMetric: Metric<TElement>,
     ManhattanMetric: Metric<TElement>,
+    HammingMetric: Metric<TElement>,
 {
     match vector_storage.distance() {
         Distance::Cosine => new_scorer_with_metric::<TElement, CosineMetric, _>(
@@ -245,6 +258,11 @@ where
             vector_storage,
             hardware_counter,
         ),
+        Distance::Hamming => new_scorer_with_metric::<TElement, HammingMetric, _>(
+            query,
+            vector_storage,
+            hardware_counter,
+        ),
     }
 }
 
@@ -336,6 +354,7 @@ where
     EuclidMetric: Metric<TElement>,
     DotProductMetric: Metric<TElement>,
     ManhattanMetric: Metric<TElement>,
+    HammingMetric: Metric<TElement>,
 {
     match vector_storage.distance() {
         Distance::Cosine => new_multi_scorer_with_metric::<_, CosineMetric, _>(
@@ -358,6 +377,11 @@ where
             vector_storage,
             hardware_counter,
         ),
+        Distance::Hamming => new_multi_scorer_with_metric::<_, HammingMetric, _>(
+            query,
+            vector_storage,
+            hardware_counter,
+        ),
     }
 }
 
@@ -90,6 +90,9 @@ fn do_test_delete_points(vector_dim: usize, vec_count: usize, storage: &mut Vect
             VectorStorageEnum::DenseAppendableMemmap(_)
             | VectorStorageEnum::DenseAppendableMemmapByte(_)
             | VectorStorageEnum::DenseAppendableMemmapHalf(_) => unreachable!(),
+            VectorStorageEnum::DenseCustom(_)
+            | VectorStorageEnum::DenseCustomByte(_)
+            | VectorStorageEnum::DenseCustomHalf(_) => unreachable!(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => unreachable!(),
             VectorStorageEnum::SparseMmap(_) => unreachable!(),
@@ -117,7 +120,10 @@ fn do_test_delete_points(vector_dim: usize, vec_count: usize, storage: &mut Vect
                 }
             }
             VectorStorageEnum::MultiDenseAppendableMemmapByte(_)
-            | VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => unreachable!(),
+            | VectorStorageEnum::MultiDenseAppendableMemmapHalf(_)
+            | VectorStorageEnum::MultiDenseMemmap(_)
+            | VectorStorageEnum::MultiDenseMemmapByte(_)
+            | VectorStorageEnum::MultiDenseMemmapHalf(_) => unreachable!(),
         };
     }
 
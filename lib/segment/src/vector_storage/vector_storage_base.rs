@@ -12,11 +12,13 @@ use common::types::PointOffsetType;
 use sparse::common::sparse_vector::SparseVector;
 use zerocopy::IntoBytes;
 
+use super::dense::custom_dense_vector_storage::CustomDenseVectorStorage;
 use super::dense::memmap_dense_vector_storage::MemmapDenseVectorStorage;
 #[cfg(feature = "rocksdb")]
 use super::dense::simple_dense_vector_storage::SimpleDenseVectorStorage;
 use super::dense::volatile_dense_vector_storage::VolatileDenseVectorStorage;
 use super::multi_dense::appendable_mmap_multi_dense_vector_storage::AppendableMmapMultiDenseVectorStorage;
+use super::multi_dense::mmap_multi_dense_vector_storage::MmapMultiDenseVectorStorage;
 #[cfg(feature = "rocksdb")]
 use super::multi_dense::simple_multi_dense_vector_storage::SimpleMultiDenseVectorStorage;
 use super::multi_dense::volatile_multi_dense_vector_storage::VolatileMultiDenseVectorStorage;
@@ -259,6 +261,9 @@ pub enum VectorStorageEnum {
     DenseAppendableMemmap(Box<AppendableMmapDenseVectorStorage<VectorElementType>>),
     DenseAppendableMemmapByte(Box<AppendableMmapDenseVectorStorage<VectorElementTypeByte>>),
     DenseAppendableMemmapHalf(Box<AppendableMmapDenseVectorStorage<VectorElementTypeHalf>>),
+    DenseCustom(Box<CustomDenseVectorStorage<VectorElementType>>),
+    DenseCustomByte(Box<CustomDenseVectorStorage<VectorElementTypeByte>>),
+    DenseCustomHalf(Box<CustomDenseVectorStorage<VectorElementTypeHalf>>),
     #[cfg(feature = "rocksdb")]
     SparseSimple(SimpleSparseVectorStorage),
     SparseVolatile(VolatileSparseVectorStorage),
@@ -281,6 +286,9 @@ pub enum VectorStorageEnum {
     MultiDenseAppendableMemmapHalf(
         Box<AppendableMmapMultiDenseVectorStorage<VectorElementTypeHalf>>,
     ),
+    MultiDenseMemmap(Box<MmapMultiDenseVectorStorage<VectorElementType>>),
+    MultiDenseMemmapByte(Box<MmapMultiDenseVectorStorage<VectorElementTypeByte>>),
+    MultiDenseMemmapHalf(Box<MmapMultiDenseVectorStorage<VectorElementTypeHalf>>),
 }
 
 impl VectorStorageEnum {
@@ -303,6 +311,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(_) => None,
             VectorStorageEnum::DenseAppendableMemmapByte(_) => None,
             VectorStorageEnum::DenseAppendableMemmapHalf(_) => None,
+            VectorStorageEnum::DenseCustom(_) => None,
+            VectorStorageEnum::DenseCustomByte(_) => None,
+            VectorStorageEnum::DenseCustomHalf(_) => None,
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => None,
             VectorStorageEnum::SparseVolatile(_) => None,
@@ -321,6 +332,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseMemmap(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseMemmapByte(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseMemmapHalf(s) => Some(s.multi_vector_config()),
         }
     }
 
@@ -361,6 +375,13 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseCustom(v) => VectorInternal::from(vec![1.0; v.vector_dim()]),
+            VectorStorageEnum::DenseCustomByte(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim()])
+            }
+            VectorStorageEnum::DenseCustomHalf(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim()])
+            }
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => VectorInternal::from(SparseVector::default()),
             VectorStorageEnum::SparseVolatile(_) => VectorInternal::from(SparseVector::default()),
@@ -397,6 +418,15 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
+            VectorStorageEnum::MultiDenseMemmap(v) => {
+                VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
+            VectorStorageEnum::MultiDenseMemmapByte(v) => {
+                VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => {
+                VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
         }
     }
 
@@ -423,6 +453,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::DenseCustom(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::DenseCustomByte(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::DenseCustomHalf(v) => v.size_of_available_vectors_in_bytes(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::SparseVolatile(v) => v.size_of_available_vectors_in_bytes(),
@@ -451,6 +484,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::MultiDenseMemmap(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.size_of_available_vectors_in_bytes(),
         }
     }
 
@@ -473,6 +509,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(vs) => vs.populate()?,
             VectorStorageEnum::DenseAppendableMemmapByte(vs) => vs.populate()?,
             VectorStorageEnum::DenseAppendableMemmapHalf(vs) => vs.populate()?,
+            VectorStorageEnum::DenseCustom(vs) => vs.populate()?,
+            VectorStorageEnum::DenseCustomByte(vs) => vs.populate()?,
+            VectorStorageEnum::DenseCustomHalf(vs) => vs.populate()?,
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => {} // Can't populate as it is not mmap
             VectorStorageEnum::SparseVolatile(_) => {} // Can't populate as it is not mmap
@@ -491,6 +530,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(vs) => vs.populate()?,
             VectorStorageEnum::MultiDenseAppendableMemmapByte(vs) => vs.populate()?,
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => vs.populate()?,
+            VectorStorageEnum::MultiDenseMemmap(vs) => vs.populate()?,
+            VectorStorageEnum::MultiDenseMemmapByte(vs) => vs.populate()?,
+            VectorStorageEnum::MultiDenseMemmapHalf(vs) => vs.populate()?,
         }
         Ok(())
     }
@@ -514,6 +556,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseAppendableMemmapByte(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseAppendableMemmapHalf(vs) => vs.clear_cache()?,
+            VectorStorageEnum::DenseCustom(vs) => vs.clear_cache()?,
+            VectorStorageEnum::DenseCustomByte(vs) => vs.clear_cache()?,
+            VectorStorageEnum::DenseCustomHalf(vs) => vs.clear_cache()?,
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => {} // Can't populate as it is not mmap
             VectorStorageEnum::SparseVolatile(_) => {} // Can't populate as it is not mmap
@@ -532,6 +577,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(vs) => vs.clear_cache()?,
             VectorStorageEnum::MultiDenseAppendableMemmapByte(vs) => vs.clear_cache()?,
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => vs.clear_cache()?,
+            VectorStorageEnum::MultiDenseMemmap(vs) => vs.clear_cache()?,
+            VectorStorageEnum::MultiDenseMemmapByte(vs) => vs.clear_cache()?,
+            VectorStorageEnum::MultiDenseMemmapHalf(vs) => vs.clear_cache()?,
         }
         Ok(())
     }
@@ -556,6 +604,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_dense_bytes_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_dense_bytes_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_dense_bytes_opt::<P>(key),
+            VectorStorageEnum::DenseCustom(v) => v.get_dense_bytes_opt::<P>(key),
+            VectorStorageEnum::DenseCustomByte(v) => v.get_dense_bytes_opt::<P>(key),
+            VectorStorageEnum::DenseCustomHalf(v) => v.get_dense_bytes_opt::<P>(key),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => None,
             VectorStorageEnum::SparseVolatile(_) => None,
@@ -574,6 +625,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(_) => None,
             VectorStorageEnum::MultiDenseAppendableMemmapByte(_) => None,
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => None,
+            VectorStorageEnum::MultiDenseMemmap(_) => None,
+            VectorStorageEnum::MultiDenseMemmapByte(_) => None,
+            VectorStorageEnum::MultiDenseMemmapHalf(_) => None,
         }
     }
 
@@ -597,6 +651,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => return v.get_dense_vector_layout(),
+            VectorStorageEnum::DenseCustom(v) => return v.get_dense_vector_layout(),
+            VectorStorageEnum::DenseCustomByte(v) => return v.get_dense_vector_layout(),
+            VectorStorageEnum::DenseCustomHalf(v) => return v.get_dense_vector_layout(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => {}
             VectorStorageEnum::SparseVolatile(_) => {}
@@ -615,6 +672,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(_) => {}
             VectorStorageEnum::MultiDenseAppendableMemmapByte(_) => {}
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => {}
+            VectorStorageEnum::MultiDenseMemmap(_) => {}
+            VectorStorageEnum::MultiDenseMemmapByte(_) => {}
+            VectorStorageEnum::MultiDenseMemmapHalf(_) => {}
         }
         Err(OperationError::service_error(
             "Vector layout is not implemented for this storage",
@@ -642,6 +702,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::DenseCustom(v) => v.distance(),
+            VectorStorageEnum::DenseCustomByte(v) => v.distance(),
+            VectorStorageEnum::DenseCustomHalf(v) => v.distance(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.distance(),
             VectorStorageEnum::SparseVolatile(v) => v.distance(),
@@ -660,6 +723,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.distance(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.distance(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.distance(),
         }
     }
 
@@ -682,6 +748,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseCustom(v) => v.datatype(),
+            VectorStorageEnum::DenseCustomByte(v) => v.datatype(),
+            VectorStorageEnum::DenseCustomHalf(v) => v.datatype(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.datatype(),
             VectorStorageEnum::SparseVolatile(v) => v.datatype(),
@@ -700,6 +769,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.datatype(),
         }
     }
 
@@ -724,6 +796,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseCustom(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseCustomByte(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseCustomHalf(v) => v.is_on_disk(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.is_on_disk(),
             VectorStorageEnum::SparseVolatile(v) => v.is_on_disk(),
@@ -742,6 +817,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.is_on_disk(),
         }
     }
 
@@ -764,6 +842,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseCustom(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseCustomByte(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseCustomHalf(v) => v.total_vector_count(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.total_vector_count(),
             VectorStorageEnum::SparseVolatile(v) => v.total_vector_count(),
@@ -782,6 +863,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.total_vector_count(),
         }
     }
 
@@ -804,6 +888,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::DenseCustom(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::DenseCustomByte(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::DenseCustomHalf(v) => v.get_vector::<P>(key),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.get_vector::<P>(key),
             VectorStorageEnum::SparseVolatile(v) => v.get_vector::<P>(key),
@@ -822,6 +909,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.get_vector::<P>(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.get_vector::<P>(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.get_vector::<P>(key),
         }
     }
 
@@ -848,6 +938,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.read_vectors::<P>(keys, callback),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.read_vectors::<P>(keys, callback),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.read_vectors::<P>(keys, callback),
+            VectorStorageEnum::DenseCustom(v) => v.read_vectors::<P>(keys, callback),
+            VectorStorageEnum::DenseCustomByte(v) => v.read_vectors::<P>(keys, callback),
+            VectorStorageEnum::DenseCustomHalf(v) => v.read_vectors::<P>(keys, callback),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.read_vectors::<P>(keys, callback),
             VectorStorageEnum::SparseVolatile(v) => v.read_vectors::<P>(keys, callback),
@@ -870,6 +963,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 v.read_vectors::<P>(keys, callback)
             }
+            VectorStorageEnum::MultiDenseMemmap(v) => v.read_vectors::<P>(keys, callback),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.read_vectors::<P>(keys, callback),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.read_vectors::<P>(keys, callback),
         }
     }
 
@@ -892,6 +988,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::DenseCustom(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::DenseCustomByte(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::DenseCustomHalf(v) => v.get_vector_opt::<P>(key),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::SparseVolatile(v) => v.get_vector_opt::<P>(key),
@@ -910,6 +1009,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.get_vector_opt::<P>(key),
         }
     }
 
@@ -941,6 +1043,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.insert_vector(key, vector, hw_counter)
             }
+            VectorStorageEnum::DenseCustom(v) => v.insert_vector(key, vector, hw_counter),
+            VectorStorageEnum::DenseCustomByte(v) => v.insert_vector(key, vector, hw_counter),
+            VectorStorageEnum::DenseCustomHalf(v) => v.insert_vector(key, vector, hw_counter),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::SparseVolatile(v) => v.insert_vector(key, vector, hw_counter),
@@ -969,6 +1074,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 v.insert_vector(key, vector, hw_counter)
             }
+            VectorStorageEnum::MultiDenseMemmap(v) => v.insert_vector(key, vector, hw_counter),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.insert_vector(key, vector, hw_counter),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.insert_vector(key, vector, hw_counter),
         }
     }
 
@@ -999,6 +1107,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.update_from(other_vectors, stopped)
             }
+            VectorStorageEnum::DenseCustom(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseCustomByte(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseCustomHalf(v) => v.update_from(other_vectors, stopped),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::SparseVolatile(v) => v.update_from(other_vectors, stopped),
@@ -1023,6 +1134,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 v.update_from(other_vectors, stopped)
             }
+            VectorStorageEnum::MultiDenseMemmap(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.update_from(other_vectors, stopped),
         }
     }
 
@@ -1045,6 +1159,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseCustom(v) => v.flusher(),
+            VectorStorageEnum::DenseCustomByte(v) => v.flusher(),
+            VectorStorageEnum::DenseCustomHalf(v) => v.flusher(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.flusher(),
             VectorStorageEnum::SparseVolatile(v) => v.flusher(),
@@ -1063,6 +1180,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.flusher(),
         }
     }
 
@@ -1085,6 +1205,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.files(),
+            VectorStorageEnum::DenseCustom(v) => v.files(),
+            VectorStorageEnum::DenseCustomByte(v) => v.files(),
+            VectorStorageEnum::DenseCustomHalf(v) => v.files(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.files(),
             VectorStorageEnum::SparseVolatile(v) => v.files(),
@@ -1103,6 +1226,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.files(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.files(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.files(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.files(),
         }
     }
 
@@ -1125,6 +1251,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.immutable_files(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.immutable_files(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.immutable_files(),
+            VectorStorageEnum::DenseCustom(v) => v.immutable_files(),
+            VectorStorageEnum::DenseCustomByte(v) => v.immutable_files(),
+            VectorStorageEnum::DenseCustomHalf(v) => v.immutable_files(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.immutable_files(),
             VectorStorageEnum::SparseVolatile(v) => v.immutable_files(),
@@ -1143,6 +1272,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.immutable_files(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.immutable_files(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.immutable_files(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.immutable_files(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.immutable_files(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.immutable_files(),
         }
     }
 
@@ -1165,6 +1297,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseCustom(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseCustomByte(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseCustomHalf(v) => v.delete_vector(key),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.delete_vector(key),
             VectorStorageEnum::SparseVolatile(v) => v.delete_vector(key),
@@ -1183,6 +1318,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.delete_vector(key),
         }
     }
 
@@ -1205,6 +1343,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseCustom(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseCustomByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseCustomHalf(v) => v.is_deleted_vector(key),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.is_deleted_vector(key),
             VectorStorageEnum::SparseVolatile(v) => v.is_deleted_vector(key),
@@ -1223,6 +1364,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.is_deleted_vector(key),
         }
     }
 
@@ -1245,6 +1389,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseCustom(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseCustomByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseCustomHalf(v) => v.deleted_vector_count(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_count(),
             VectorStorageEnum::SparseVolatile(v) => v.deleted_vector_count(),
@@ -1263,6 +1410,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.deleted_vector_count(),
         }
     }
 
@@ -1285,6 +1435,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseCustom(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseCustomByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseCustomHalf(v) => v.deleted_vector_bitslice(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::SparseVolatile(v) => v.deleted_vector_bitslice(),
@@ -1303,6 +1456,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.deleted_vector_bitslice(),
         }
     }
 }
@@ -144,6 +144,8 @@ fn test_batch_and_single_request_equivalency() {
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
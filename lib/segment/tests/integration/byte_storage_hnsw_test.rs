@@ -90,10 +90,12 @@ fn test_byte_storage_hnsw(
                 quantization_config: None,
                 multivector_config: None,
                 datatype: Some(storage_data_type),
+                storage_backend: None,
             },
         )]),
         sparse_vector_data: Default::default(),
         payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
     };
 
     let int_key = "int";
@@ -175,6 +177,8 @@ fn test_byte_storage_hnsw(
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
@@ -229,10 +229,12 @@ fn test_byte_storage_binary_quantization_hnsw(
                 quantization_config: None,
                 multivector_config: None,
                 datatype: Some(storage_data_type),
+                storage_backend: None,
             },
         )]),
         sparse_vector_data: Default::default(),
         payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
     };
 
     let int_key = "int";
@@ -328,6 +330,8 @@ fn test_byte_storage_binary_quantization_hnsw(
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
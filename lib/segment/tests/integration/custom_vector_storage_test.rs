@@ -0,0 +1,371 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use bitvec::prelude::{BitSlice, BitVec};
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+use fs_err as fs;
+use segment::common::Flusher;
+use segment::common::operation_error::OperationResult;
+use segment::data_types::named_vectors::CowVector;
+use segment::data_types::primitive::PrimitiveVectorElement;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorRef, only_default_vector};
+use segment::entry::entry_point::{NonAppendableSegmentEntry, SegmentEntry};
+use segment::segment_constructor::{build_segment, load_segment};
+use segment::types::{
+    Distance, Indexes, SegmentConfig, VectorDataConfig, VectorStorageDatatype, VectorStorageType,
+    WithPayload,
+};
+use segment::vector_storage::backend_registry::{
+    VectorStorageBackend, register_vector_storage_backend,
+};
+use segment::vector_storage::dense::custom_dense_vector_storage::{
+    AppendableDynDenseVectorStorage, DynDenseVectorStorage, DynDenseVectorStorageBox,
+};
+use segment::vector_storage::{Random, VectorStorage, VectorStorageEnum};
+use serde::{Deserialize, Serialize};
+use tempfile::Builder;
+use uuid::Uuid;
+
+const BACKEND_NAME: &str = "test_json";
+const READ_ONLY_BACKEND_NAME: &str = "test_json_read_only";
+const VECTORS_FILE: &str = "vectors.json";
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+struct StoredVectors<T: PrimitiveVectorElement> {
+    vectors: Vec<Vec<T>>,
+    deleted: Vec<bool>,
+}
+
+/// Keeps vectors in memory and writes all of them into a single JSON file on flush
+struct JsonVectorStorage<T: PrimitiveVectorElement> {
+    path: PathBuf,
+    dim: usize,
+    distance: Distance,
+    read_only: bool,
+    vectors: Vec<Vec<T>>,
+    deleted: BitVec,
+}
+
+impl<T: PrimitiveVectorElement> JsonVectorStorage<T> {
+    fn open(path: &Path, config: &VectorDataConfig, read_only: bool) -> OperationResult<Self> {
+        fs::create_dir_all(path)?;
+        let vectors_path = path.join(VECTORS_FILE);
+        let stored = if vectors_path.exists() {
+            serde_json::from_slice(&fs::read(vectors_path)?).unwrap()
+        } else {
+            StoredVectors::default()
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            dim: config.size,
+            distance: config.distance,
+            read_only,
+            vectors: stored.vectors,
+            deleted: stored.deleted.into_iter().collect(),
+        })
+    }
+}
+
+impl<T: PrimitiveVectorElement> DynDenseVectorStorage<T> for JsonVectorStorage<T> {
+    fn vector_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn distance(&self) -> Distance {
+        self.distance
+    }
+
+    fn is_on_disk(&self) -> bool {
+        false
+    }
+
+    fn total_vector_count(&self) -> usize {
+        self.vectors.len()
+    }
+
+    fn get_dense_opt(&self, key: PointOffsetType) -> Option<&[T]> {
+        self.vectors.get(key as usize).map(Vec::as_slice)
+    }
+
+    fn append_dense(&mut self, vector: &[T], deleted: bool) -> OperationResult<()> {
+        self.vectors.push(vector.to_vec());
+        self.deleted.push(deleted);
+        Ok(())
+    }
+
+    fn as_appendable_mut(&mut self) -> Option<&mut dyn AppendableDynDenseVectorStorage<T>> {
+        if self.read_only { None } else { Some(self) }
+    }
+
+    fn flusher(&self) -> Flusher {
+        let path = self.path.join(VECTORS_FILE);
+        let stored = StoredVectors {
+            vectors: self.vectors.clone(),
+            deleted: self.deleted.iter().map(|flag| *flag).collect(),
+        };
+        Box::new(move || {
+            fs::write(path, serde_json::to_vec(&stored).unwrap())?;
+            Ok(())
+        })
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        vec![self.path.join(VECTORS_FILE)]
+    }
+
+    fn delete_vector(&mut self, key: PointOffsetType) -> OperationResult<bool> {
+        let Some(mut flag) = self.deleted.get_mut(key as usize) else {
+            return Ok(false);
+        };
+        let was_deleted = flag.replace(true);
+        Ok(!was_deleted)
+    }
+
+    fn is_deleted_vector(&self, key: PointOffsetType) -> bool {
+        self.deleted.get(key as usize).is_some_and(|flag| *flag)
+    }
+
+    fn deleted_vector_count(&self) -> usize {
+        self.deleted.count_ones()
+    }
+
+    fn deleted_vector_bitslice(&self) -> &BitSlice {
+        &self.deleted
+    }
+}
+
+impl<T: PrimitiveVectorElement> AppendableDynDenseVectorStorage<T> for JsonVectorStorage<T> {
+    fn insert_dense(
+        &mut self,
+        key: PointOffsetType,
+        vector: &[T],
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let key = key as usize;
+        if key >= self.vectors.len() {
+            self.vectors.resize_with(key + 1, Vec::new);
+            self.deleted.resize(key + 1, true);
+        }
+        self.vectors[key] = vector.to_vec();
+        self.deleted.set(key, false);
+        Ok(())
+    }
+}
+
+struct JsonVectorStorageBackend {
+    read_only: bool,
+}
+
+impl VectorStorageBackend for JsonVectorStorageBackend {
+    fn is_appendable(&self) -> bool {
+        !self.read_only
+    }
+
+    fn open(
+        &self,
+        path: &Path,
+        config: &VectorDataConfig,
+    ) -> OperationResult<DynDenseVectorStorageBox> {
+        let read_only = self.read_only;
+        let storage = match config.datatype.unwrap_or_default() {
+            VectorStorageDatatype::Float32 => DynDenseVectorStorageBox::Float32(Box::new(
+                JsonVectorStorage::open(path, config, read_only)?,
+            )),
+            VectorStorageDatatype::Uint8 => DynDenseVectorStorageBox::Uint8(Box::new(
+                JsonVectorStorage::open(path, config, read_only)?,
+            )),
+            VectorStorageDatatype::Float16 => DynDenseVectorStorageBox::Float16(Box::new(
+                JsonVectorStorage::open(path, config, read_only)?,
+            )),
+        };
+        Ok(storage)
+    }
+}
+
+#[test]
+fn test_custom_vector_storage_backend() {
+    register_vector_storage_backend(
+        BACKEND_NAME,
+        Arc::new(JsonVectorStorageBackend { read_only: false }),
+    )
+    .unwrap();
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: 4,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::default(),
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+                storage_backend: Some(BACKEND_NAME.to_string()),
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
+    };
+
+    let path = {
+        let mut segment = build_segment(dir.path(), &config, true).unwrap();
+        {
+            let vector_storage = segment.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_storage
+                .borrow();
+            assert!(matches!(
+                &*vector_storage,
+                VectorStorageEnum::DenseCustom(storage) if storage.backend() == BACKEND_NAME,
+            ));
+        }
+
+        segment
+            .upsert_point(
+                1,
+                1.into(),
+                only_default_vector(&[1.0, 0.0, 0.0, 0.0]),
+                &hw_counter,
+            )
+            .unwrap();
+        segment
+            .upsert_point(
+                2,
+                2.into(),
+                only_default_vector(&[0.0, 1.0, 0.0, 0.0]),
+                &hw_counter,
+            )
+            .unwrap();
+        segment
+            .upsert_point(
+                3,
+                3.into(),
+                only_default_vector(&[1.0, 1.0, 0.0, 0.0]),
+                &hw_counter,
+            )
+            .unwrap();
+        segment.delete_point(4, 2.into(), &hw_counter).unwrap();
+        segment.flush(false).unwrap();
+        segment.segment_path.clone()
+    };
+
+    // Vectors are loaded through the backend
+    let segment = load_segment(&path, Uuid::nil(), &AtomicBool::new(false)).unwrap();
+    assert_eq!(segment.available_point_count(), 2);
+
+    let vector_storage = segment.vector_data[DEFAULT_VECTOR_NAME]
+        .vector_storage
+        .borrow();
+    assert_eq!(vector_storage.total_vector_count(), 3);
+    drop(vector_storage);
+
+    let res = segment
+        .search(
+            DEFAULT_VECTOR_NAME,
+            &[1.0, 0.5, 0.0, 0.0].into(),
+            &WithPayload::default(),
+            &false.into(),
+            None,
+            10,
+            None,
+        )
+        .unwrap();
+    let ids: Vec<_> = res.iter().map(|point| point.id).collect();
+    assert_eq!(ids, vec![3.into(), 1.into()]);
+}
+
+#[test]
+fn test_read_only_custom_vector_storage_backend() {
+    register_vector_storage_backend(
+        READ_ONLY_BACKEND_NAME,
+        Arc::new(JsonVectorStorageBackend { read_only: true }),
+    )
+    .unwrap();
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let vector_config = VectorDataConfig {
+        size: 4,
+        distance: Distance::Dot,
+        storage_type: VectorStorageType::default(),
+        index: Indexes::Plain {},
+        quantization_config: None,
+        multivector_config: None,
+        datatype: Some(VectorStorageDatatype::Uint8),
+        storage_backend: Some(READ_ONLY_BACKEND_NAME.to_string()),
+    };
+    // Vectors can't be inserted into read-only storages
+    assert!(!vector_config.is_appendable());
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(DEFAULT_VECTOR_NAME.to_owned(), vector_config)]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
+    };
+
+    let segment = build_segment(dir.path(), &config, true).unwrap();
+    let mut vector_storage = segment.vector_data[DEFAULT_VECTOR_NAME]
+        .vector_storage
+        .borrow_mut();
+    assert!(matches!(
+        &*vector_storage,
+        VectorStorageEnum::DenseCustomByte(storage) if storage.backend() == READ_ONLY_BACKEND_NAME,
+    ));
+    assert_eq!(vector_storage.datatype(), VectorStorageDatatype::Uint8);
+
+    // Vectors are appended while the segment is built
+    let vectors = [vec![1.0, 2.0, 3.0, 4.0], vec![5.0, 6.0, 7.0, 8.0]];
+    let mut other_vectors = vectors
+        .iter()
+        .map(|vector| (CowVector::from(vector.as_slice()), false));
+    let range = vector_storage
+        .update_from(&mut other_vectors, &AtomicBool::new(false))
+        .unwrap();
+    assert_eq!(range, 0..2);
+    assert_eq!(
+        vector_storage.get_vector::<Random>(1),
+        CowVector::from(vectors[1].as_slice()),
+    );
+
+    // But not afterwards
+    let result =
+        vector_storage.insert_vector(2, VectorRef::from(vectors[0].as_slice()), &hw_counter);
+    assert!(result.is_err());
+    assert_eq!(vector_storage.total_vector_count(), 2);
+}
+
+#[test]
+fn test_unregistered_vector_storage_backend() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: 4,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::default(),
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+                storage_backend: Some("missing".to_string()),
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
+    };
+
+    assert!(build_segment(dir.path(), &config, true).is_err());
+}
@@ -82,6 +82,8 @@ fn exact_search_test() {
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     payload_index_ptr
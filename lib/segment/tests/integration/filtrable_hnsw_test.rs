@@ -12,19 +12,20 @@ use ordered_float::OrderedFloat;
 use rand::prelude::StdRng;
 use rand::{Rng, SeedableRng};
 use rstest::rstest;
+use segment::data_types::index::{KeywordIndexParams, KeywordIndexType};
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, QueryVector, only_default_vector};
 use segment::entry::entry_point::SegmentEntry;
 use segment::fixtures::payload_fixtures::{random_int_payload, random_vector};
 use segment::fixtures::query_fixtures::QueryVariant;
-use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::index::hnsw_index::hnsw::{FilteredSearchStrategy, HNSWIndex, HnswIndexOpenArgs};
 use segment::index::{PayloadIndex, VectorIndex};
 use segment::json_path::JsonPath;
 use segment::payload_json;
 use segment::segment_constructor::VectorIndexBuildArgs;
 use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
 use segment::types::{
-    Condition, Distance, FieldCondition, Filter, HnswConfig, HnswGlobalConfig, PayloadSchemaType,
-    Range, SearchParams, SeqNumberType,
+    Condition, Distance, FieldCondition, Filter, HnswConfig, HnswGlobalConfig, PayloadFieldSchema,
+    PayloadSchemaParams, PayloadSchemaType, Range, SearchParams, SeqNumberType,
 };
 use tempfile::Builder;
 
@@ -102,6 +103,8 @@ fn _test_filterable_hnsw(
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let vector_storage = &segment.vector_data[DEFAULT_VECTOR_NAME].vector_storage;
@@ -195,6 +198,11 @@ fn _test_filterable_hnsw(
 
         let filter_query = Some(&filter);
 
+        let plan = hnsw_index.plan_filtered_search(&filter, &hw_counter);
+        assert_eq!(plan.strategy, FilteredSearchStrategy::Graph);
+        assert!(plan.cardinality.min <= plan.cardinality.exp);
+        assert!(plan.cardinality.exp <= plan.cardinality.max);
+
         let index_result = hnsw_index
             .search(
                 &[&query],
@@ -286,6 +294,8 @@ fn test_hnsw_search_top_zero(#[case] num_vectors: u64, #[case] full_scan_thresho
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let vector_storage = &segment.vector_data[DEFAULT_VECTOR_NAME].vector_storage;
@@ -347,3 +357,295 @@ fn test_hnsw_search_top_zero(#[case] num_vectors: u64, #[case] full_scan_thresho
         )
         .unwrap();
 }
+
+#[test]
+fn test_selective_filter_hnsw() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 8;
+    let num_vectors: u64 = 5_000;
+    let rare_every = 50; // 2% of points match the filter
+    let ef = 64;
+    let distance = Distance::Cosine;
+    let full_scan_threshold = 1; // KB
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let kind_key = "kind";
+
+    let hw_counter = HardwareCounterCell::new();
+    let mut segment = build_simple_segment(dir.path(), dim, distance).unwrap();
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_vector(&mut rng, dim);
+        let kind = if n % rare_every == 0 {
+            "rare"
+        } else {
+            "common"
+        };
+        let payload = payload_json! {kind_key: kind};
+
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, idx, &payload, &hw_counter)
+            .unwrap();
+    }
+
+    let payload_index_ptr = segment.payload_index.clone();
+    payload_index_ptr
+        .borrow_mut()
+        .set_indexed(
+            &JsonPath::new(kind_key),
+            PayloadSchemaType::Keyword,
+            &hw_counter,
+        )
+        .unwrap();
+
+    // Without additional payload links, matching points are hard to reach from the global
+    // entry point of the main graph
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 32,
+        full_scan_threshold,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: Some(0),
+        inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
+    };
+
+    let vector_storage = &segment.vector_data[DEFAULT_VECTOR_NAME].vector_storage;
+    let quantized_vectors = &segment.vector_data[DEFAULT_VECTOR_NAME].quantized_vectors;
+
+    let permit = Arc::new(ResourcePermit::dummy(1));
+    let hnsw_index = HNSWIndex::build(
+        HnswIndexOpenArgs {
+            path: hnsw_dir.path(),
+            id_tracker: segment.id_tracker.clone(),
+            vector_storage: vector_storage.clone(),
+            quantized_vectors: quantized_vectors.clone(),
+            payload_index: payload_index_ptr.clone(),
+            hnsw_config,
+        },
+        VectorIndexBuildArgs {
+            permit,
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+            progress: ProgressTracker::new_for_test(),
+        },
+    )
+    .unwrap();
+
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        JsonPath::new(kind_key),
+        "rare".to_string().into(),
+    )));
+    let plan = hnsw_index.plan_filtered_search(&filter, &hw_counter);
+    assert_eq!(plan.strategy, FilteredSearchStrategy::Graph);
+
+    let top = 10;
+    let attempts = 20;
+    for i in 0..attempts {
+        let query = random_query(&QueryVariant::Nearest, &mut rng, dim);
+
+        let index_result = hnsw_index
+            .search(
+                &[&query],
+                Some(&filter),
+                top,
+                Some(&SearchParams {
+                    hnsw_ef: Some(ef),
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap();
+
+        // check that search was performed using HNSW index
+        assert_eq!(
+            hnsw_index
+                .get_telemetry_data(TelemetryDetail::default())
+                .filtered_large_cardinality
+                .count,
+            i + 1
+        );
+
+        // Traversal starts from matching points, so enough of them are found
+        assert_eq!(index_result[0].len(), top);
+        assert!(
+            index_result[0]
+                .iter()
+                .all(|point| u64::from(point.idx) % rare_every == 0),
+            "found points don't match the filter",
+        );
+    }
+}
+
+#[test]
+fn test_tenant_subgraphs_hnsw() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 8;
+    let num_vectors: u64 = 2_000;
+    let num_tenants = 2;
+    let ef = 32;
+    let distance = Distance::Cosine;
+    let full_scan_threshold = 1; // KB
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let tenant_key = "tenant";
+
+    let hw_counter = HardwareCounterCell::new();
+    let mut segment = build_simple_segment(dir.path(), dim, distance).unwrap();
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_vector(&mut rng, dim);
+        let payload = payload_json! {tenant_key: format!("tenant_{}", n % num_tenants)};
+
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, idx, &payload, &hw_counter)
+            .unwrap();
+    }
+
+    let payload_index_ptr = segment.payload_index.clone();
+    payload_index_ptr
+        .borrow_mut()
+        .set_indexed(
+            &JsonPath::new(tenant_key),
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(KeywordIndexParams {
+                r#type: KeywordIndexType::Keyword,
+                is_tenant: Some(true),
+                on_disk: None,
+                enable_hnsw: None,
+            })),
+            &hw_counter,
+        )
+        .unwrap();
+
+    // Tenants are too large to get additional links on top of the main graph, unless they are
+    // linked as subgraphs
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 16,
+        full_scan_threshold,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: Some(8),
+        inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: Some(true),
+    };
+
+    let vector_storage = &segment.vector_data[DEFAULT_VECTOR_NAME].vector_storage;
+    let quantized_vectors = &segment.vector_data[DEFAULT_VECTOR_NAME].quantized_vectors;
+
+    let permit = Arc::new(ResourcePermit::dummy(1));
+    let hnsw_index = HNSWIndex::build(
+        HnswIndexOpenArgs {
+            path: hnsw_dir.path(),
+            id_tracker: segment.id_tracker.clone(),
+            vector_storage: vector_storage.clone(),
+            quantized_vectors: quantized_vectors.clone(),
+            payload_index: payload_index_ptr.clone(),
+            hnsw_config,
+        },
+        VectorIndexBuildArgs {
+            permit,
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+            progress: ProgressTracker::new_for_test(),
+        },
+    )
+    .unwrap();
+
+    assert!(
+        hnsw_index
+            .files()
+            .iter()
+            .any(|file| file.ends_with("tenant_subgraphs.json")),
+        "tenant subgraphs are not persisted"
+    );
+
+    let top = 3;
+    let mut hits = 0;
+    let attempts = 100;
+    for i in 0..attempts {
+        let query = random_query(&QueryVariant::Nearest, &mut rng, dim);
+
+        let tenant = format!("tenant_{}", rng.random_range(0..num_tenants));
+        let tenant_condition = Condition::Field(FieldCondition::new_match(
+            JsonPath::new(tenant_key),
+            tenant.into(),
+        ));
+        // Tenant condition may be nested into the filter
+        let filter = if i % 2 == 0 {
+            Filter::new_must(tenant_condition)
+        } else {
+            Filter::new_must(Condition::Filter(Filter::new_should(tenant_condition)))
+        };
+
+        let index_result = hnsw_index
+            .search(
+                &[&query],
+                Some(&filter),
+                top,
+                Some(&SearchParams {
+                    hnsw_ef: Some(ef),
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap();
+
+        // check that search was performed using HNSW index
+        assert_eq!(
+            hnsw_index
+                .get_telemetry_data(TelemetryDetail::default())
+                .filtered_large_cardinality
+                .count,
+            i + 1
+        );
+
+        let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], Some(&filter), top, None, &Default::default())
+            .unwrap();
+
+        if plain_result == index_result {
+            hits += 1;
+        }
+    }
+    assert!(attempts - hits <= 5, "hits: {hits} of {attempts}");
+}
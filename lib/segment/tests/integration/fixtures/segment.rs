@@ -142,6 +142,7 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         quantization_config: None,
                         multivector_config: None,
                         datatype: None,
+                        storage_backend: None,
                     },
                 ),
                 (
@@ -154,6 +155,7 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         quantization_config: None,
                         multivector_config: None,
                         datatype: None,
+                        storage_backend: None,
                     },
                 ),
                 (
@@ -166,11 +168,13 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         quantization_config: None,
                         multivector_config: None,
                         datatype: None,
+                        storage_backend: None,
                     },
                 ),
             ]),
             sparse_vector_data: Default::default(),
             payload_storage_type: Default::default(),
+            payload_overflow_threshold: None,
         },
         true,
     )
@@ -267,6 +271,7 @@ pub fn build_segment_sparse_1(path: &Path) -> Segment {
                 },
             )]),
             payload_storage_type: Default::default(),
+            payload_overflow_threshold: None,
         },
         true,
     )
@@ -360,6 +365,7 @@ pub fn build_segment_sparse_2(path: &Path) -> Segment {
                 },
             )]),
             payload_storage_type: Default::default(),
+            payload_overflow_threshold: None,
         },
         true,
     )
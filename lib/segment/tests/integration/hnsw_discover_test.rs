@@ -96,6 +96,8 @@ fn hnsw_discover_precision() {
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
@@ -224,6 +226,8 @@ fn filtered_hnsw_discover_precision() {
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
@@ -117,6 +117,8 @@ fn hnsw_quantized_search_test(
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
@@ -424,6 +426,8 @@ fn test_build_hnsw_using_quantization() {
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     });
 
     let mut builder =
@@ -1,6 +1,7 @@
 mod batch_search_test;
 mod byte_storage_hnsw_test;
 mod byte_storage_quantization_test;
+mod custom_vector_storage_test;
 mod disbalanced_vectors_test;
 mod exact_search_test;
 mod fail_recovery_test;
@@ -23,3 +24,4 @@ mod segment_on_disk_snapshot;
 mod segment_tests;
 mod sparse_discover_test;
 mod sparse_vector_index_search_tests;
+mod vector_rebuild_test;
@@ -73,10 +73,12 @@ fn test_multi_filterable_hnsw(
                 quantization_config: None,
                 multivector_config: Some(MultiVectorConfig::default()), // uses multivec config
                 datatype: None,
+                storage_backend: None,
             },
         )]),
         sparse_vector_data: Default::default(),
         payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
     };
 
     let int_key = "int";
@@ -127,6 +129,8 @@ fn test_multi_filterable_hnsw(
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
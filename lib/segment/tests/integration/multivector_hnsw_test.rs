@@ -114,6 +114,8 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     // single threaded mode to guarantee equivalency between single and multi hnsw
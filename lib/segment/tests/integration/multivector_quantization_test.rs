@@ -222,10 +222,12 @@ fn test_multivector_quantization_hnsw(
                 quantization_config: None,
                 multivector_config: Some(MultiVectorConfig::default()), // uses multivec config
                 datatype: None,
+                storage_backend: None,
             },
         )]),
         sparse_vector_data: Default::default(),
         payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
     };
 
     let int_key = "int";
@@ -317,6 +319,8 @@ fn test_multivector_quantization_hnsw(
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        links_compression: None,
+        tenant_subgraphs: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
@@ -44,7 +44,7 @@ use segment::types::{
     AnyVariants, Condition, Distance, FieldCondition, Filter, GeoBoundingBox, GeoLineString,
     GeoPoint, GeoPolygon, GeoRadius, HnswConfig, HnswGlobalConfig, Indexes, IsEmptyCondition,
     Match, Payload, PayloadField, PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType,
-    Range, SegmentConfig, ValueVariants, VectorDataConfig, VectorStorageType, WithPayload,
+    Range, SegmentConfig, VectorDataConfig, VectorStorageType, WithPayload,
 };
 use segment::utils::scored_point_ties::ScoredPointTies;
 use tempfile::{Builder, TempDir};
@@ -269,10 +269,12 @@ impl TestSegments {
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    storage_backend: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
             payload_storage_type: Default::default(),
+            payload_overflow_threshold: None,
         };
         assert_eq!(conf.is_appendable(), appendable);
         conf
@@ -1300,6 +1302,7 @@ fn keyword_facet_request() -> FacetParams {
         limit,
         filter: None,
         exact,
+        histogram: None,
     }
 }
 
@@ -1311,14 +1314,12 @@ fn validate_facet_result(
 ) -> Result<()> {
     let hw_counter = HardwareCounterCell::new();
 
+    let request = keyword_facet_request();
+
     for (value, count) in facet_hits.iter() {
         // Compare against exact count
-        let value = ValueVariants::from(value.clone());
-
-        let count_filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
-            JsonPath::new(STR_KEY),
-            Match::from(value.clone()),
-        )));
+        let count_filter =
+            Filter::new_must(Condition::Field(request.value_condition(value.clone())));
         let count_filter = Filter::merge_opts(Some(count_filter), filter.clone());
 
         let exact = segment
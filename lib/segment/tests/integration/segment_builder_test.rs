@@ -13,14 +13,17 @@ use segment::common::operation_error::OperationError;
 use segment::data_types::named_vectors::NamedVectors;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorRef, only_default_vector};
 use segment::entry::entry_point::{NonAppendableSegmentEntry, SegmentEntry};
+use segment::index::PayloadIndex;
 use segment::index::hnsw_index::num_rayon_threads;
 use segment::json_path::JsonPath;
+use segment::payload_json;
 use segment::segment::Segment;
 use segment::segment_constructor::segment_builder::SegmentBuilder;
 use segment::segment_constructor::simple_segment_constructor::build_simple_segment_with_payload_storage;
 use segment::types::{
-    Distance, HnswGlobalConfig, Indexes, PayloadContainer, PayloadFieldSchema, PayloadKeyType,
-    PayloadSchemaType, PayloadStorageType, SegmentConfig, VectorDataConfig, VectorStorageType,
+    Condition, Distance, FieldCondition, Filter, HnswGlobalConfig, Indexes, PayloadContainer,
+    PayloadFieldSchema, PayloadKeyType, PayloadSchemaType, PayloadStorageType, SegmentConfig,
+    VectorDataConfig, VectorStorageType,
 };
 use serde_json::Value;
 use sparse::common::sparse_vector::SparseVector;
@@ -99,6 +102,178 @@ fn test_building_new_segment() {
     assert_eq!(merged_segment.point_version(3.into()), Some(100));
 }
 
+#[test]
+fn test_building_new_segment_with_memory_budget() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+    let stopped = AtomicBool::new(false);
+
+    let segment1 = build_segment_1(dir.path());
+    let segment2 = build_segment_2(dir.path());
+
+    let mut builder = SegmentBuilder::new(
+        temp_dir.path(),
+        &segment1.segment_config,
+        &HnswGlobalConfig::default(),
+    )
+    .unwrap();
+
+    // Budget for just a few points, forces spilling the point mapping to disk
+    builder.set_memory_budget(Some(100));
+
+    builder.update(&[&segment1, &segment2], &stopped).unwrap();
+
+    // Spilled mapping is cleaned up after update
+    let temp_segment_count = fs::read_dir(temp_dir.path()).unwrap().count();
+    assert_eq!(temp_segment_count, 1);
+
+    let merged_segment: Segment = builder.build_for_test(dir.path());
+
+    let expected_points = segment1
+        .iter_points()
+        .chain(segment2.iter_points())
+        .unique()
+        .collect_vec();
+
+    assert_eq!(
+        merged_segment.available_point_count(),
+        expected_points.len()
+    );
+
+    let hw_counter = HardwareCounterCell::new();
+    for point_id in expected_points {
+        let source = [&segment1, &segment2]
+            .into_iter()
+            .max_by_key(|segment| segment.point_version(point_id))
+            .unwrap();
+        assert_eq!(
+            merged_segment.point_version(point_id),
+            source.point_version(point_id),
+        );
+        assert_eq!(
+            merged_segment
+                .vector(DEFAULT_VECTOR_NAME, point_id, &hw_counter)
+                .unwrap(),
+            source
+                .vector(DEFAULT_VECTOR_NAME, point_id, &hw_counter)
+                .unwrap(),
+        );
+        assert_eq!(
+            merged_segment.payload(point_id, &hw_counter).unwrap(),
+            source.payload(point_id, &hw_counter).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn test_building_new_segment_with_parallel_payload_indices() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+    let stopped = AtomicBool::new(false);
+
+    let mut segment = build_simple_segment_with_payload_storage(
+        dir.path(),
+        4,
+        Distance::Dot,
+        PayloadStorageType::Mmap,
+    )
+    .unwrap();
+
+    for i in 0..100u64 {
+        segment
+            .upsert_point(
+                i,
+                i.into(),
+                only_default_vector(&[i as f32, 0.0, 1.0, 1.0]),
+                &hw_counter,
+            )
+            .unwrap();
+        let payload = payload_json! {
+            "keyword": format!("k{}", i % 5),
+            "number": i as i64,
+            "flag": i % 2 == 0,
+        };
+        segment
+            .set_payload(100 + i, i.into(), &payload, &None, &hw_counter)
+            .unwrap();
+    }
+
+    let indexed_fields = [
+        ("keyword", PayloadSchemaType::Keyword),
+        ("number", PayloadSchemaType::Integer),
+        ("flag", PayloadSchemaType::Bool),
+    ];
+
+    let filters = [
+        Filter::new_must(Condition::Field(FieldCondition::new_match(
+            JsonPath::new("keyword"),
+            "k1".to_string().into(),
+        ))),
+        Filter::new_must(Condition::Field(FieldCondition::new_match(
+            JsonPath::new("number"),
+            42.into(),
+        ))),
+        Filter::new_must(Condition::Field(FieldCondition::new_match(
+            JsonPath::new("flag"),
+            true.into(),
+        ))),
+    ];
+
+    // Build sequentially and in parallel, results must be the same
+    let built_segments = [1, 4].map(|num_cpus| {
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+        let mut builder = SegmentBuilder::new(
+            temp_dir.path(),
+            &segment.segment_config,
+            &HnswGlobalConfig::default(),
+        )
+        .unwrap();
+
+        for (field, schema) in indexed_fields {
+            builder.add_indexed_field(JsonPath::new(field), PayloadFieldSchema::FieldType(schema));
+        }
+
+        builder.update(&[&segment], &stopped).unwrap();
+
+        builder
+            .build(
+                dir.path(),
+                Uuid::new_v4(),
+                ResourcePermit::dummy(num_cpus),
+                &stopped,
+                &mut rand::rng(),
+                &hw_counter,
+                ProgressTracker::new_for_test(),
+            )
+            .unwrap()
+    });
+
+    for built_segment in &built_segments {
+        let payload_index = built_segment.payload_index.borrow();
+        let built_fields = payload_index.indexed_fields();
+        assert_eq!(built_fields.len(), indexed_fields.len());
+        for (field, schema) in indexed_fields {
+            assert_eq!(
+                built_fields.get(&JsonPath::new(field)),
+                Some(&PayloadFieldSchema::FieldType(schema)),
+            );
+        }
+    }
+
+    for filter in &filters {
+        let [sequential, parallel] = built_segments.each_ref().map(|built_segment| {
+            built_segment
+                .payload_index
+                .borrow()
+                .query_points(filter, &hw_counter, &stopped)
+        });
+        assert!(!sequential.is_empty());
+        assert_eq!(sequential, parallel);
+    }
+}
+
 #[test]
 fn test_building_new_defragmented_segment() {
     let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
@@ -320,10 +495,12 @@ fn estimate_build_time(segment: &Segment, stop_delay_millis: Option<u64>) -> (u6
                 quantization_config: None,
                 multivector_config: None,
                 datatype: None,
+                storage_backend: None,
             },
         )]),
         sparse_vector_data: Default::default(),
         payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
     };
 
     let mut builder = SegmentBuilder::new(
@@ -120,14 +120,18 @@ fn test_on_disk_segment_snapshot(#[case] format: SnapshotFormat) {
                     on_disk: Some(true), // mmap index
                     payload_m: None,
                     inline_storage: None,
+                    links_compression: None,
+                    tenant_subgraphs: None,
                 }),
                 quantization_config: None,
                 multivector_config: None,
                 datatype: None,
+                storage_backend: None,
             },
         )]),
         sparse_vector_data: Default::default(),
         payload_storage_type: PayloadStorageType::Mmap,
+        payload_overflow_threshold: None,
     };
 
     let segment_base_dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
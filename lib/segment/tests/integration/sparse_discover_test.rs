@@ -128,12 +128,16 @@ fn sparse_index_discover_test() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                     datatype: Some(VectorStorageDatatype::Float32),
+                    similarity: None,
+                    pruning: None,
+                    block_max: None,
                 },
                 storage_type: SparseVectorStorageType::default(),
                 modifier: None,
             },
         )]),
         payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
     };
     let dense_config = SegmentConfig {
         vector_data: HashMap::from([(
@@ -146,9 +150,11 @@ fn sparse_index_discover_test() {
                 quantization_config: None,
                 multivector_config: None,
                 datatype: None,
+                storage_backend: None,
             },
         )]),
         payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
         sparse_vector_data: Default::default(),
     };
 
@@ -177,6 +183,9 @@ fn sparse_index_discover_test() {
             full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
             index_type: SparseIndexType::ImmutableRam,
             datatype: Some(VectorStorageDatatype::Float32),
+            similarity: None,
+            pruning: None,
+            block_max: None,
         },
         id_tracker: sparse_segment.id_tracker.clone(),
         vector_storage: vector_storage.clone(),
@@ -269,12 +278,16 @@ fn sparse_index_hardware_measurement_test() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                     datatype: Some(VectorStorageDatatype::Float32),
+                    similarity: None,
+                    pruning: None,
+                    block_max: None,
                 },
                 storage_type: SparseVectorStorageType::default(),
                 modifier: None,
             },
         )]),
         payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
     };
 
     let mut sparse_segment = build_segment(dir.path(), &sparse_config, true).unwrap();
@@ -297,6 +310,9 @@ fn sparse_index_hardware_measurement_test() {
             full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
             index_type: SparseIndexType::ImmutableRam,
             datatype: Some(VectorStorageDatatype::Float32),
+            similarity: None,
+            pruning: None,
+            block_max: None,
         },
         id_tracker: sparse_segment.id_tracker.clone(),
         vector_storage: vector_storage.clone(),
@@ -591,12 +591,16 @@ fn sparse_vector_index_persistence_test() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                     datatype: Some(VectorStorageDatatype::Float32),
+                    similarity: None,
+                    pruning: None,
+                    block_max: None,
                 },
                 storage_type: SparseVectorStorageType::default(),
                 modifier: None,
             },
         )]),
         payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
     };
     let mut segment = build_segment(dir.path(), &config, true).unwrap();
 
@@ -678,6 +682,9 @@ fn check_persistence<TInvertedIndex: InvertedIndex>(
                 full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                 index_type: SparseIndexType::Mmap,
                 datatype: Some(VectorStorageDatatype::Float32),
+                similarity: None,
+                pruning: None,
+                block_max: None,
             },
             id_tracker: segment.id_tracker.clone(),
             vector_storage: segment.vector_data[SPARSE_VECTOR_NAME]
@@ -763,12 +770,16 @@ fn sparse_vector_test_large_index() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                     datatype: Some(VectorStorageDatatype::Float32),
+                    similarity: None,
+                    pruning: None,
+                    block_max: None,
                 },
                 storage_type: SparseVectorStorageType::Mmap,
                 modifier: None,
             },
         )]),
         payload_storage_type: Default::default(),
+        payload_overflow_threshold: None,
     };
     let mut segment = build_segment(dir.path(), &config, true).unwrap();
 
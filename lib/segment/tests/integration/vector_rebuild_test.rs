@@ -0,0 +1,259 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::flags::FeatureFlags;
+use common::progress_tracker::ProgressTracker;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, QueryVector, only_default_vector};
+use segment::entry::entry_point::{NonAppendableSegmentEntry, SegmentEntry};
+use segment::fixtures::payload_fixtures::random_vector;
+use segment::index::PayloadIndex;
+use segment::segment::Segment;
+use segment::segment_constructor::segment_builder::SegmentBuilder;
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::segment_constructor::{VectorIndexBuildArgs, load_segment};
+use segment::types::{
+    Distance, HnswConfig, HnswGlobalConfig, Indexes, QuantizationConfig, ScalarQuantization,
+    ScalarQuantizationConfig, ScalarType, SearchParams, SegmentType, VectorStorageType,
+    WithPayload, WithVector,
+};
+use tempfile::Builder;
+use uuid::Uuid;
+
+const DIM: usize = 8;
+const NUM_POINTS: u64 = 500;
+
+#[test]
+fn test_rebuild_single_vector() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+    let stopped = AtomicBool::new(false);
+    let hw_counter = HardwareCounterCell::new();
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let mut plain_segment = build_simple_segment(dir.path(), DIM, Distance::Dot).unwrap();
+    for i in 0..NUM_POINTS {
+        let vector = random_vector(&mut rng, DIM);
+        plain_segment
+            .upsert_point(i, i.into(), only_default_vector(&vector), &hw_counter)
+            .unwrap();
+    }
+
+    // Build a non-appendable segment with an HNSW index
+    let mut segment_config = plain_segment.segment_config.clone();
+    let vector_config = segment_config
+        .vector_data
+        .get_mut(DEFAULT_VECTOR_NAME)
+        .unwrap();
+    vector_config.storage_type = VectorStorageType::Mmap;
+    vector_config.index = Indexes::Hnsw(HnswConfig {
+        full_scan_threshold: 10,
+        ..HnswConfig::default()
+    });
+
+    let mut builder = SegmentBuilder::new(
+        temp_dir.path(),
+        &segment_config,
+        &HnswGlobalConfig::default(),
+    )
+    .unwrap();
+    builder.update(&[&plain_segment], &stopped).unwrap();
+    let mut segment = builder
+        .build(
+            dir.path(),
+            Uuid::new_v4(),
+            ResourcePermit::dummy(1),
+            &stopped,
+            &mut rng,
+            &hw_counter,
+            ProgressTracker::new_for_test(),
+        )
+        .unwrap();
+    assert_eq!(segment.segment_type, SegmentType::Indexed);
+
+    let payload_index_files = segment.payload_index.borrow().files();
+
+    // Enable quantization, change HNSW parameters and move vectors to RAM
+    let mut new_vector_config = segment.segment_config.vector_data[DEFAULT_VECTOR_NAME].clone();
+    new_vector_config.storage_type = VectorStorageType::InRamMmap;
+    new_vector_config.index = Indexes::Hnsw(HnswConfig {
+        m: 8,
+        ef_construct: 64,
+        full_scan_threshold: 10,
+        ..HnswConfig::default()
+    });
+    new_vector_config.quantization_config = Some(QuantizationConfig::Scalar(ScalarQuantization {
+        scalar: ScalarQuantizationConfig {
+            r#type: ScalarType::Int8,
+            quantile: None,
+            always_ram: Some(true),
+        },
+    }));
+
+    let old_index = segment.vector_data[DEFAULT_VECTOR_NAME]
+        .vector_index
+        .clone();
+    let rebuilt = segment
+        .rebuild_vector(
+            DEFAULT_VECTOR_NAME,
+            new_vector_config.clone(),
+            VectorIndexBuildArgs {
+                permit: Arc::new(ResourcePermit::dummy(2)),
+                old_indices: &[old_index],
+                gpu_device: None,
+                rng: &mut rng,
+                stopped: &stopped,
+                hnsw_global_config: &HnswGlobalConfig::default(),
+                feature_flags: FeatureFlags::default(),
+                progress: ProgressTracker::new_for_test(),
+            },
+        )
+        .unwrap();
+
+    // Segment is not changed until the rebuild is applied
+    assert_ne!(
+        segment.segment_config.vector_data[DEFAULT_VECTOR_NAME],
+        new_vector_config,
+    );
+    assert!(
+        segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .borrow()
+            .is_none()
+    );
+
+    // Deleted during the rebuild, must stay deleted
+    segment
+        .delete_vector(NUM_POINTS + 1, 0.into(), DEFAULT_VECTOR_NAME)
+        .unwrap();
+
+    segment.apply_vector_rebuild(rebuilt).unwrap();
+
+    assert_eq!(
+        segment.segment_config.vector_data[DEFAULT_VECTOR_NAME],
+        new_vector_config,
+    );
+    assert!(
+        segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .borrow()
+            .is_some()
+    );
+    assert_eq!(segment.payload_index.borrow().files(), payload_index_files);
+    assert_eq!(segment.available_point_count(), NUM_POINTS as usize);
+
+    assert!(
+        segment
+            .vector(DEFAULT_VECTOR_NAME, 0.into(), &hw_counter)
+            .unwrap()
+            .is_none()
+    );
+    for i in 1..NUM_POINTS {
+        assert!(
+            segment
+                .vector(DEFAULT_VECTOR_NAME, i.into(), &hw_counter)
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    let check_search = |segment: &Segment| {
+        let query = QueryVector::from(random_vector(&mut StdRng::seed_from_u64(7), DIM));
+        let exact = segment
+            .search(
+                DEFAULT_VECTOR_NAME,
+                &query,
+                &WithPayload::default(),
+                &WithVector::Bool(false),
+                None,
+                10,
+                Some(&SearchParams {
+                    exact: true,
+                    ..Default::default()
+                }),
+            )
+            .unwrap();
+        let exact_ids: Vec<_> = exact.iter().map(|point| point.id).collect();
+        assert_eq!(exact_ids.len(), 10);
+        assert!(!exact_ids.contains(&0.into()));
+
+        let approximate = segment
+            .search(
+                DEFAULT_VECTOR_NAME,
+                &query,
+                &WithPayload::default(),
+                &WithVector::Bool(false),
+                None,
+                10,
+                Some(&SearchParams {
+                    hnsw_ef: Some(128),
+                    ..Default::default()
+                }),
+            )
+            .unwrap();
+        let found = approximate
+            .iter()
+            .filter(|point| exact_ids.contains(&point.id))
+            .count();
+        assert!(found >= 8, "rebuilt index found only {found} of 10 points");
+    };
+
+    check_search(&segment);
+
+    // Rebuilt vector is persisted
+    let segment_path = segment.segment_path.clone();
+    let uuid = segment.uuid;
+    drop(segment);
+
+    let segment = load_segment(&segment_path, uuid, &stopped).unwrap();
+    assert_eq!(
+        segment.segment_config.vector_data[DEFAULT_VECTOR_NAME],
+        new_vector_config,
+    );
+    assert!(
+        segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .borrow()
+            .is_some()
+    );
+    check_search(&segment);
+
+    // No rebuild leftovers in the segment directory
+    for entry in fs_err::read_dir(&segment_path).unwrap() {
+        let name = entry.unwrap().file_name();
+        let name = name.to_string_lossy();
+        assert!(
+            !name.ends_with(".rebuild") && !name.ends_with(".replaced"),
+            "unexpected {name}",
+        );
+    }
+}
+
+#[test]
+fn test_rebuild_vector_in_appendable_segment() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let stopped = AtomicBool::new(false);
+
+    let segment = build_simple_segment(dir.path(), DIM, Distance::Dot).unwrap();
+    let vector_config = segment.segment_config.vector_data[DEFAULT_VECTOR_NAME].clone();
+
+    let result = segment.rebuild_vector(
+        DEFAULT_VECTOR_NAME,
+        vector_config,
+        VectorIndexBuildArgs {
+            permit: Arc::new(ResourcePermit::dummy(1)),
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rand::rng(),
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+            progress: ProgressTracker::new_for_test(),
+        },
+    );
+    assert!(result.is_err());
+}
@@ -1,4 +1,5 @@
 use schemars::JsonSchema;
+use segment::data_types::facets::FacetHistogram;
 use segment::json_path::JsonPath;
 use segment::types::Filter;
 use serde::{Deserialize, Serialize};
@@ -23,6 +24,10 @@ pub struct FacetRequestInternal {
     /// Default: false
     #[serde(default = "FacetRequestInternal::default_exact")]
     pub exact: bool,
+    /// Count points in numeric ranges of the key instead of unique values
+    #[serde(default)]
+    #[validate(nested)]
+    pub histogram: Option<FacetHistogram>,
 }
 
 impl FacetRequestInternal {
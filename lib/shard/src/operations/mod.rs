@@ -374,6 +374,13 @@ mod tests {
                 key: None,
             });
 
+            let patch = Self::PatchPayload(SetPayloadOp {
+                payload: Payload(Default::default()),
+                points: None,
+                filter: None,
+                key: None,
+            });
+
             let delete = Self::DeletePayload(DeletePayloadOp {
                 keys: Vec::new(),
                 points: None,
@@ -392,6 +399,7 @@ mod tests {
             prop_oneof![
                 Just(set),
                 Just(overwrite),
+                Just(patch),
                 Just(delete),
                 Just(clear),
                 Just(clear_by_filter),
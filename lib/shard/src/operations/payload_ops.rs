@@ -1,6 +1,6 @@
 use std::fmt;
 
-use api::rest::ShardKeySelector;
+use api::rest::{FilterTemplateReference, ShardKeySelector};
 use schemars::JsonSchema;
 use segment::json_path::JsonPath;
 use segment::types::{Filter, Payload, PayloadKeyType, PointIdType};
@@ -24,6 +24,8 @@ pub enum PayloadOps {
     ClearPayloadByFilter(Filter),
     /// Overwrite full payload with given keys
     OverwritePayload(SetPayloadOp),
+    /// Apply JSON Merge Patch (RFC 7386) to the payload, `null` values remove nested keys
+    PatchPayload(SetPayloadOp),
 }
 
 impl PayloadOps {
@@ -34,6 +36,7 @@ impl PayloadOps {
             Self::ClearPayload { points } => Some(points.clone()),
             Self::ClearPayloadByFilter(_) => None,
             Self::OverwritePayload(op) => op.points.clone(),
+            Self::PatchPayload(op) => op.points.clone(),
         }
     }
 
@@ -47,6 +50,7 @@ impl PayloadOps {
             Self::ClearPayload { points } => points.retain(filter),
             Self::ClearPayloadByFilter(_) => (),
             Self::OverwritePayload(op) => retain_opt(op.points.as_mut(), filter),
+            Self::PatchPayload(op) => retain_opt(op.points.as_mut(), filter),
         }
     }
 }
@@ -72,6 +76,9 @@ pub struct SetPayload {
     pub filter: Option<Filter>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
     /// Assigns payload to each point that satisfy this path of property
     pub key: Option<JsonPath>,
 }
@@ -105,6 +112,9 @@ pub struct DeletePayload {
     pub filter: Option<Filter>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Filter template of the collection to apply on top of `filter`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 /// This data structure is used inside shard operations queue
@@ -128,6 +138,7 @@ struct SetPayloadShadow {
     pub points: Option<Vec<PointIdType>>,
     pub filter: Option<Filter>,
     pub shard_key: Option<ShardKeySelector>,
+    pub filter_template: Option<FilterTemplateReference>,
     pub key: Option<JsonPath>,
 }
 
@@ -140,15 +151,17 @@ impl TryFrom<SetPayloadShadow> for SetPayload {
             points,
             filter,
             shard_key,
+            filter_template,
             key,
         } = value;
 
-        if points.is_some() || filter.is_some() {
+        if points.is_some() || filter.is_some() || filter_template.is_some() {
             Ok(SetPayload {
                 payload,
                 points,
                 filter,
                 shard_key,
+                filter_template,
                 key,
             })
         } else {
@@ -163,6 +176,7 @@ struct DeletePayloadShadow {
     pub points: Option<Vec<PointIdType>>,
     pub filter: Option<Filter>,
     pub shard_key: Option<ShardKeySelector>,
+    pub filter_template: Option<FilterTemplateReference>,
 }
 
 impl TryFrom<DeletePayloadShadow> for DeletePayload {
@@ -174,13 +188,15 @@ impl TryFrom<DeletePayloadShadow> for DeletePayload {
             points,
             filter,
             shard_key,
+            filter_template,
         } = value;
-        if points.is_some() || filter.is_some() {
+        if points.is_some() || filter.is_some() || filter_template.is_some() {
             Ok(DeletePayload {
                 keys,
                 points,
                 filter,
                 shard_key,
+                filter_template,
             })
         } else {
             Err(PointsSelectorValidationError)
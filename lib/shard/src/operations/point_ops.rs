@@ -278,6 +278,26 @@ pub struct BatchPersisted {
     pub payloads: Option<Vec<Option<Payload>>>,
 }
 
+impl From<BatchPersisted> for Vec<PointStructPersisted> {
+    fn from(batch: BatchPersisted) -> Self {
+        let BatchPersisted {
+            ids,
+            vectors,
+            payloads,
+        } = batch;
+        let all_vectors = BatchVectorStructInternal::from(vectors).into_all_vectors(ids.len());
+        let mut payloads = payloads.map(Vec::into_iter);
+        ids.into_iter()
+            .zip(all_vectors)
+            .map(|(id, vector)| PointStructPersisted {
+                id,
+                vector: VectorStructPersisted::from(VectorStructInternal::from(vector)),
+                payload: payloads.as_mut().and_then(Iterator::next).flatten(),
+            })
+            .collect()
+    }
+}
+
 impl TryFrom<BatchPersisted> for Vec<api::grpc::qdrant::PointStruct> {
     type Error = Status;
 
@@ -305,6 +325,7 @@ impl TryFrom<BatchPersisted> for Vec<api::grpc::qdrant::PointStruct> {
                 id,
                 vectors: vectors.map(api::grpc::qdrant::Vectors::from),
                 payload: payload.unwrap_or_default(),
+                shard_key: None,
             };
             points.push(point);
         }
@@ -443,6 +464,8 @@ impl TryFrom<api::rest::schema::Record> for PointStructPersisted {
             vector,
             shard_key: _,
             order_value: _,
+            version: _,
+            version_peer_id: _,
         } = record;
 
         if vector.is_none() {
@@ -480,6 +503,7 @@ impl TryFrom<PointStructPersisted> for api::grpc::qdrant::PointStruct {
             id: Some(id.into()),
             vectors: Some(vectors),
             payload: converted_payload,
+            shard_key: None,
         })
     }
 }
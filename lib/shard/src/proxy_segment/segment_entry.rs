@@ -9,8 +9,9 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::TelemetryDetail;
 use segment::common::Flusher;
 use segment::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
 use segment::data_types::build_index_result::BuildFieldIndexResult;
-use segment::data_types::facets::{FacetParams, FacetValue};
+use segment::data_types::facets::{FacetParams, FacetValue, MultiFacetParams};
 use segment::data_types::named_vectors::NamedVectors;
 use segment::data_types::order_by::OrderValue;
 use segment::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
@@ -321,6 +322,37 @@ impl NonAppendableSegmentEntry for ProxySegment {
         }
     }
 
+    fn read_weighted_random_filtered(
+        &self,
+        limit: usize,
+        key: &JsonPath,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<(f64, Vec<PointIdType>)> {
+        if self.deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_weighted_random_filtered(limit, key, filter, is_stopped, hw_counter)
+        } else {
+            let wrapped_filter = Self::add_deleted_points_condition_to_filter(
+                filter,
+                self.deleted_points.keys().copied(),
+            );
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_weighted_random_filtered(
+                    limit,
+                    key,
+                    Some(&wrapped_filter),
+                    is_stopped,
+                    hw_counter,
+                )
+        }
+    }
+
     /// Read points in [from; to) range
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType> {
         let read_points = self.wrapped_segment.get().read().read_range(from, to);
@@ -378,6 +410,62 @@ impl NonAppendableSegmentEntry for ProxySegment {
         Ok(hits)
     }
 
+    fn facets(
+        &self,
+        request: &MultiFacetParams,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<HashMap<FacetValue, usize>>> {
+        let hits = if self.deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .facets(request, is_stopped, hw_counter)?
+        } else {
+            let wrapped_filter = Self::add_deleted_points_condition_to_filter(
+                request.filter.as_ref(),
+                self.deleted_points.keys().copied(),
+            );
+            let new_request = MultiFacetParams {
+                filter: Some(wrapped_filter),
+                ..request.clone()
+            };
+            self.wrapped_segment
+                .get()
+                .read()
+                .facets(&new_request, is_stopped, hw_counter)?
+        };
+
+        Ok(hits)
+    }
+
+    fn aggregate(
+        &self,
+        request: &AggregateParams,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<AggregateResult> {
+        if self.deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .aggregate(request, is_stopped, hw_counter)
+        } else {
+            let wrapped_filter = Self::add_deleted_points_condition_to_filter(
+                request.filter.as_ref(),
+                self.deleted_points.keys().copied(),
+            );
+            let new_request = AggregateParams {
+                filter: Some(wrapped_filter),
+                ..request.clone()
+            };
+            self.wrapped_segment
+                .get()
+                .read()
+                .aggregate(&new_request, is_stopped, hw_counter)
+        }
+    }
+
     fn has_point(&self, point_id: PointIdType) -> bool {
         !self.deleted_points.contains_key(&point_id)
             && self.wrapped_segment.get().read().has_point(point_id)
@@ -570,6 +658,7 @@ impl NonAppendableSegmentEntry for ProxySegment {
         op_num: SeqNumberType,
         _key: PayloadKeyTypeRef,
         field_type: &PayloadFieldSchema,
+        _is_stopped: &AtomicBool,
         _hw_counter: &HardwareCounterCell,
     ) -> OperationResult<BuildFieldIndexResult> {
         if self.version() > op_num {
@@ -2,6 +2,7 @@ pub mod formula;
 pub mod mmr;
 pub mod planned_query;
 pub mod query_enum;
+pub mod score_gap_cutoff;
 pub mod scroll;
 mod validation;
 
@@ -9,6 +10,7 @@ pub mod query_context;
 #[cfg(test)]
 mod tests;
 
+use api::conversions::json::json_path_from_proto;
 use api::{grpc, rest};
 use common::types::ScoreType;
 use itertools::Itertools;
@@ -19,6 +21,7 @@ use segment::data_types::vectors::{
     DEFAULT_VECTOR_NAME, NamedQuery, NamedVectorStruct, VectorInternal,
 };
 use segment::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
+use segment::json_path::JsonPath;
 use segment::types::*;
 use serde::Serialize;
 
@@ -181,9 +184,11 @@ pub enum FusionInternal {
     Dbsf,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
 pub enum SampleInternal {
     Random,
+    /// Random sample, weighted by the value of a numeric payload field
+    Weighted(JsonPath),
 }
 
 /// Maximal Marginal Relevance configuration
@@ -506,6 +511,7 @@ impl From<rest::Sample> for SampleInternal {
     fn from(value: rest::Sample) -> Self {
         match value {
             rest::Sample::Random => SampleInternal::Random,
+            rest::Sample::Weighted(rest::WeightedSample { key }) => SampleInternal::Weighted(key),
         }
     }
 }
@@ -530,11 +536,12 @@ impl TryFrom<i32> for SampleInternal {
     }
 }
 
-impl From<SampleInternal> for grpc::Sample {
-    fn from(value: SampleInternal) -> Self {
-        match value {
-            SampleInternal::Random => grpc::Sample::Random,
-        }
+impl TryFrom<grpc::WeightedSample> for SampleInternal {
+    type Error = tonic::Status;
+
+    fn try_from(sample: grpc::WeightedSample) -> Result<Self, Self::Error> {
+        let grpc::WeightedSample { key } = sample;
+        Ok(SampleInternal::Weighted(json_path_from_proto(&key)?))
     }
 }
 
@@ -562,6 +569,9 @@ impl ScoringQuery {
             grpc::query_shard_points::query::Score::Sample(sample) => {
                 ScoringQuery::Sample(SampleInternal::try_from(sample)?)
             }
+            grpc::query_shard_points::query::Score::WeightedSample(sample) => {
+                ScoringQuery::Sample(SampleInternal::try_from(sample)?)
+            }
             grpc::query_shard_points::query::Score::Formula(formula) => ScoringQuery::Formula(
                 ParsedFormula::try_from(FormulaInternal::try_from(formula)?).map_err(|e| {
                     tonic::Status::invalid_argument(format!("failed to parse formula: {e}"))
@@ -603,8 +613,13 @@ impl From<ScoringQuery> for grpc::query_shard_points::Query {
             ScoringQuery::Formula(parsed_formula) => Self {
                 score: Some(Score::Formula(grpc::Formula::from_parsed(parsed_formula))),
             },
-            ScoringQuery::Sample(sample) => Self {
-                score: Some(Score::Sample(grpc::Sample::from(sample) as i32)),
+            ScoringQuery::Sample(SampleInternal::Random) => Self {
+                score: Some(Score::Sample(grpc::Sample::Random as i32)),
+            },
+            ScoringQuery::Sample(SampleInternal::Weighted(key)) => Self {
+                score: Some(Score::WeightedSample(grpc::WeightedSample {
+                    key: key.to_string(),
+                })),
             },
             ScoringQuery::Mmr(MmrInternal {
                 vector,
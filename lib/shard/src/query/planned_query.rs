@@ -418,9 +418,13 @@ fn leaf_source_from_scoring_query(
                 "cannot apply Formula without prefetches".to_string(),
             ));
         }
-        Some(ScoringQuery::Sample(SampleInternal::Random)) => {
+        Some(ScoringQuery::Sample(sample)) => {
+            let scroll_order = match sample {
+                SampleInternal::Random => ScrollOrder::Random,
+                SampleInternal::Weighted(key) => ScrollOrder::WeightedRandom(key),
+            };
             let scroll = QueryScrollRequestInternal {
-                scroll_order: ScrollOrder::Random,
+                scroll_order,
                 filter,
                 with_vector: WithVector::from(false),
                 with_payload: WithPayloadInterface::from(false),
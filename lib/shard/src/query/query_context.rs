@@ -18,6 +18,7 @@ pub fn init_query_context(
     is_stopped_guard: &StoppingGuard,
     hw_measurement_acc: HwMeasurementAcc,
     check_idf_required: impl Fn(&VectorName) -> bool,
+    sparse_block_max: impl Fn(&VectorName) -> Option<bool>,
 ) -> QueryContext {
     let mut query_context = QueryContext::new(search_optimized_threshold_kb, hw_measurement_acc)
         .with_is_stopped(is_stopped_guard.get_is_stopped());
@@ -29,6 +30,9 @@ pub fn init_query_context(
                 if check_idf_required(vector_name) {
                     query_context.init_idf(vector_name, &sparse_vector.indices);
                 }
+                if let Some(block_max) = sparse_block_max(vector_name) {
+                    query_context.set_sparse_block_max(vector_name, block_max);
+                }
             })
     }
 
@@ -0,0 +1,81 @@
+use segment::types::ScoredPoint;
+
+/// Truncate sorted `points` before the first large score drop.
+///
+/// The drop between neighboring points is relative to the larger absolute score of the two, so
+/// it is defined for zero scores. Works for both orders of scores, as only the size of the drop
+/// is considered.
+///
+/// Must be applied to the final results before `offset` is skipped, so that all pages are cut
+/// at the same point.
+pub fn truncate_at_score_gap(points: &mut Vec<ScoredPoint>, max_relative_gap: f64) {
+    let cutoff = points
+        .windows(2)
+        .position(|pair| {
+            let prev = f64::from(pair[0].score);
+            let next = f64::from(pair[1].score);
+            (prev - next).abs() > max_relative_gap * prev.abs().max(next.abs())
+        })
+        .map(|pos| pos + 1);
+
+    if let Some(cutoff) = cutoff {
+        points.truncate(cutoff);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::{ExtendedPointId, ScoredPoint};
+
+    use super::*;
+
+    fn points(scores: &[f32]) -> Vec<ScoredPoint> {
+        scores
+            .iter()
+            .enumerate()
+            .map(|(idx, &score)| ScoredPoint {
+                id: ExtendedPointId::NumId(idx as u64),
+                version: 0,
+                score,
+                payload: None,
+                vector: None,
+                shard_key: None,
+                order_value: None,
+            })
+            .collect()
+    }
+
+    fn scores(points: &[ScoredPoint]) -> Vec<f32> {
+        points.iter().map(|point| point.score).collect()
+    }
+
+    #[test]
+    fn test_truncate_at_score_gap() {
+        let mut result = points(&[0.9, 0.88, 0.85, 0.4, 0.38]);
+        truncate_at_score_gap(&mut result, 0.3);
+        assert_eq!(scores(&result), vec![0.9, 0.88, 0.85]);
+
+        // No gap is large enough
+        let mut result = points(&[0.9, 0.88, 0.85, 0.4, 0.38]);
+        truncate_at_score_gap(&mut result, 0.6);
+        assert_eq!(result.len(), 5);
+
+        // Distances, smaller is better
+        let mut result = points(&[1.0, 1.1, 2.5, 2.6]);
+        truncate_at_score_gap(&mut result, 0.5);
+        assert_eq!(scores(&result), vec![1.0, 1.1]);
+
+        // Drops to and from zero
+        let mut result = points(&[0.5, 0.0, -0.1]);
+        truncate_at_score_gap(&mut result, 0.3);
+        assert_eq!(scores(&result), vec![0.5]);
+
+        let mut result = points(&[0.0, 0.0, -1.0]);
+        truncate_at_score_gap(&mut result, 0.5);
+        assert_eq!(scores(&result), vec![0.0, 0.0]);
+
+        let mut result = points(&[]);
+        truncate_at_score_gap(&mut result, 0.1);
+        assert!(result.is_empty());
+    }
+}
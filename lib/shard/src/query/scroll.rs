@@ -1,4 +1,5 @@
 use segment::data_types::order_by::OrderBy;
+use segment::json_path::JsonPath;
 use segment::types::{Filter, WithPayloadInterface, WithVector};
 
 use crate::operation_rate_cost;
@@ -38,4 +39,6 @@ pub enum ScrollOrder {
     ById,
     ByField(OrderBy),
     Random,
+    /// Random order, weighted by the value of a numeric payload field
+    WeightedRandom(JsonPath),
 }
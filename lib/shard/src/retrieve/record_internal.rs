@@ -3,8 +3,9 @@ use api::grpc::conversions::convert_shard_key_to_grpc;
 use segment::data_types::order_by::OrderValue;
 use segment::data_types::segment_record::SegmentRecord;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorRef, VectorStructInternal};
-use segment::types::{Payload, PointIdType, ShardKey, VectorName};
+use segment::types::{Payload, PointIdType, SeqNumberType, ShardKey, VectorName};
 
+use crate::PeerId;
 use crate::operations::point_ops::{PointStructPersisted, VectorStructPersisted};
 
 /// Point data
@@ -20,6 +21,13 @@ pub struct RecordInternal {
     pub shard_key: Option<ShardKey>,
     /// Order value, if used for order_by
     pub order_value: Option<OrderValue>,
+    /// Version of the last operation which modified the point.
+    ///
+    /// Assigned by each replica independently, so only comparable between records of the same
+    /// `version_peer_id`.
+    pub version: Option<SeqNumberType>,
+    /// Peer, which replica reported the `version`
+    pub version_peer_id: Option<PeerId>,
 }
 
 impl RecordInternal {
@@ -30,6 +38,8 @@ impl RecordInternal {
             vector: None,
             shard_key: None,
             order_value: None,
+            version: None,
+            version_peer_id: None,
         }
     }
 
@@ -60,6 +70,8 @@ impl From<SegmentRecord> for RecordInternal {
             vector: vectors.map(VectorStructInternal::from),
             shard_key: None,
             order_value: None,
+            version: None,
+            version_peer_id: None,
         }
     }
 }
@@ -75,6 +87,8 @@ impl TryFrom<RecordInternal> for PointStructPersisted {
             vector,
             shard_key: _,
             order_value: _,
+            version: _,
+            version_peer_id: _,
         } = record;
 
         if vector.is_none() {
@@ -97,6 +111,8 @@ impl From<RecordInternal> for api::grpc::qdrant::RetrievedPoint {
             vector,
             shard_key,
             order_value,
+            version,
+            version_peer_id,
         } = record;
         Self {
             id: Some(id.into()),
@@ -104,6 +120,8 @@ impl From<RecordInternal> for api::grpc::qdrant::RetrievedPoint {
             vectors: vector.map(api::grpc::qdrant::VectorsOutput::from),
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(From::from),
+            version,
+            version_peer_id,
         }
     }
 }
@@ -116,6 +134,8 @@ impl From<RecordInternal> for api::rest::Record {
             vector,
             shard_key,
             order_value,
+            version,
+            version_peer_id,
         } = value;
         Self {
             id,
@@ -123,6 +143,8 @@ impl From<RecordInternal> for api::rest::Record {
             vector: vector.map(api::rest::VectorStructOutput::from),
             shard_key,
             order_value,
+            version,
+            version_peer_id,
         }
     }
 }
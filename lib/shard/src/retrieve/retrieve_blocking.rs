@@ -53,7 +53,9 @@ pub fn retrieve_blocking(
             is_stopped,
         )? {
             // We expect all points to be found since we already checked their versions
-            point_records.insert(id, RecordInternal::from(record));
+            let mut record = RecordInternal::from(record);
+            record.version = point_version.get(&id).copied();
+            point_records.insert(id, record);
             applied += 1;
         }
 
@@ -1,5 +1,6 @@
 pub use api::rest::OrderByInterface;
 use schemars::JsonSchema;
+use segment::json_path::JsonPath;
 use segment::types::{Filter, PointIdType, WithPayloadInterface, WithVector};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
@@ -28,6 +29,15 @@ pub struct ScrollRequestInternal {
 
     /// Order the records by a payload field.
     pub order_by: Option<OrderByInterface>,
+
+    /// Return only the first point for each unique value of this payload key.
+    /// Requires a keyword, integer, uuid or bool payload index on the key.
+    /// Can't be used together with `order_by`.
+    pub distinct_by: Option<JsonPath>,
+
+    /// If true, return the version of the last operation which modified each point, together
+    /// with the peer which replica reported it. Default: false
+    pub with_version: Option<bool>,
 }
 
 impl Default for ScrollRequestInternal {
@@ -39,6 +49,8 @@ impl Default for ScrollRequestInternal {
             with_payload: Some(Self::default_with_payload()),
             with_vector: Self::default_with_vector(),
             order_by: None,
+            distinct_by: None,
+            with_version: None,
         }
     }
 }
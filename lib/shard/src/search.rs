@@ -182,6 +182,8 @@ impl TryFrom<api::grpc::qdrant::SearchPoints> for CoreSearchRequest {
             timeout: _,
             shard_key_selector: _,
             sparse_indices,
+            filter_template: _,
+            explain: _,
         } = value;
 
         if let Some(sparse_indices) = &sparse_indices {
@@ -5,7 +5,7 @@ use std::sync::atomic::AtomicBool;
 use ahash::{AHashMap, AHashSet};
 use common::counter::hardware_counter::HardwareCounterCell;
 use parking_lot::RwLockWriteGuard;
-use segment::common::operation_error::{OperationError, OperationResult};
+use segment::common::operation_error::{OperationError, OperationResult, check_process_stopped};
 use segment::data_types::build_index_result::BuildFieldIndexResult;
 use segment::data_types::named_vectors::NamedVectors;
 use segment::entry::entry_point::SegmentEntry;
@@ -144,13 +144,30 @@ pub fn process_payload_operation(
                 })
             }
         }
+        PayloadOps::PatchPayload(sp) => {
+            let patch: Payload = sp.payload;
+            if let Some(points) = sp.points {
+                patch_payload(segments, op_num, &patch, &points, hw_counter)
+            } else if let Some(filter) = sp.filter {
+                patch_payload_by_filter(segments, op_num, &patch, &filter, hw_counter)
+            } else {
+                Err(OperationError::ValidationError {
+                    description: "No points or filter specified".to_string(),
+                })
+            }
+        }
     }
 }
 
+/// Apply field index operation.
+///
+/// Index building is cancelled with an error, once `is_stopped` is set. Segments, in which the
+/// index was built already, keep it.
 pub fn process_field_index_operation(
     segments: &SegmentHolder,
     op_num: SeqNumberType,
     field_index_operation: &FieldIndexOperations,
+    is_stopped: &AtomicBool,
     hw_counter: &HardwareCounterCell,
 ) -> OperationResult<usize> {
     match field_index_operation {
@@ -159,6 +176,7 @@ pub fn process_field_index_operation(
             op_num,
             &index_data.field_name,
             index_data.field_schema.as_ref(),
+            is_stopped,
             hw_counter,
         ),
         FieldIndexOperations::DeleteIndex(field_name) => {
@@ -814,11 +832,60 @@ pub fn overwrite_payload_by_filter(
     Ok(points_updated)
 }
 
+pub fn patch_payload(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    patch: &Payload,
+    points: &[PointIdType],
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<usize> {
+    let mut total_updated_points = 0;
+
+    for batch in points.chunks(PAYLOAD_OP_BATCH_SIZE) {
+        let updated_points = segments.apply_points_with_conditional_move(
+            op_num,
+            batch,
+            |id, write_segment| {
+                let mut payload = write_segment.payload(id, hw_counter)?;
+                payload.merge_patch(patch);
+                write_segment.set_full_payload(op_num, id, &payload, hw_counter)
+            },
+            |_, _, old_payload| old_payload.merge_patch(patch),
+            hw_counter,
+        )?;
+
+        total_updated_points += updated_points.len();
+        check_unprocessed_points(batch, &updated_points)?;
+    }
+
+    Ok(total_updated_points)
+}
+
+pub fn patch_payload_by_filter(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    patch: &Payload,
+    filter: &Filter,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<usize> {
+    let affected_points = points_by_filter(segments, filter, hw_counter)?;
+    let points_updated = patch_payload(segments, op_num, patch, &affected_points, hw_counter)?;
+
+    if points_updated == 0 {
+        // In case we didn't hit any points, we suggest this op_num to the segment-holder to make WAL acknowledge this operation.
+        // If we don't do this, startup might take up a lot of time in some scenarios because of recovering these no-op operations.
+        segments.bump_max_segment_version_overwrite(op_num);
+    }
+
+    Ok(points_updated)
+}
+
 pub fn create_field_index(
     segments: &SegmentHolder,
     op_num: SeqNumberType,
     field_name: PayloadKeyTypeRef,
     field_schema: Option<&PayloadFieldSchema>,
+    is_stopped: &AtomicBool,
     hw_counter: &HardwareCounterCell,
 ) -> OperationResult<usize> {
     let Some(field_schema) = field_schema else {
@@ -828,27 +895,34 @@ pub fn create_field_index(
     };
 
     segments.apply_segments(|write_segment| {
+        check_process_stopped(is_stopped)?;
+
         write_segment.with_upgraded(|segment| {
             segment.delete_field_index_if_incompatible(op_num, field_name, field_schema)
         })?;
 
-        let (schema, indexes) =
-            match write_segment.build_field_index(op_num, field_name, field_schema, hw_counter)? {
-                BuildFieldIndexResult::SkippedByVersion => {
-                    return Ok(false);
-                }
-                BuildFieldIndexResult::AlreadyExists => {
-                    return Ok(false);
-                }
-                BuildFieldIndexResult::IncompatibleSchema => {
-                    // This is a service error, as we should have just removed the old index
-                    // So it should not be possible to get this error
-                    return Err(OperationError::service_error(format!(
-                        "Incompatible schema for field index on field {field_name}",
-                    )));
-                }
-                BuildFieldIndexResult::Built { schema, indexes } => (schema, indexes),
-            };
+        let (schema, indexes) = match write_segment.build_field_index(
+            op_num,
+            field_name,
+            field_schema,
+            is_stopped,
+            hw_counter,
+        )? {
+            BuildFieldIndexResult::SkippedByVersion => {
+                return Ok(false);
+            }
+            BuildFieldIndexResult::AlreadyExists => {
+                return Ok(false);
+            }
+            BuildFieldIndexResult::IncompatibleSchema => {
+                // This is a service error, as we should have just removed the old index
+                // So it should not be possible to get this error
+                return Err(OperationError::service_error(format!(
+                    "Incompatible schema for field index on field {field_name}",
+                )));
+            }
+            BuildFieldIndexResult::Built { schema, indexes } => (schema, indexes),
+        };
 
         write_segment.with_upgraded(|segment| {
             segment.apply_field_index(op_num, field_name.to_owned(), schema, indexes)
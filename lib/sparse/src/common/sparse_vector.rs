@@ -89,6 +89,65 @@ pub fn score_vectors<T: Ord + Eq>(
     if overlap { Some(score) } else { None }
 }
 
+/// Weighted Jaccard similarity of two vectors sorted by indices: sum of minimums over sum of
+/// maximums of weights in each dimension.
+///
+/// For binary weights this is the size of intersection over the size of union of the sets of
+/// dimensions. Negative weights are treated as zero.
+///
+/// Return None if the vectors do not overlap.
+pub fn jaccard_vectors<T: Ord + Eq>(
+    self_indices: &[T],
+    self_values: &[DimWeight],
+    other_indices: &[T],
+    other_values: &[DimWeight],
+) -> Option<ScoreType> {
+    let mut intersection = 0.0;
+    let mut union = 0.0;
+    // track whether there is any overlap
+    let mut overlap = false;
+    let mut i = 0;
+    let mut j = 0;
+    while i < self_indices.len() && j < other_indices.len() {
+        match self_indices[i].cmp(&other_indices[j]) {
+            std::cmp::Ordering::Less => {
+                union += self_values[i].max(0.0);
+                i += 1;
+            }
+            std::cmp::Ordering::Greater => {
+                union += other_values[j].max(0.0);
+                j += 1;
+            }
+            std::cmp::Ordering::Equal => {
+                overlap = true;
+                let self_value = self_values[i].max(0.0);
+                let other_value = other_values[j].max(0.0);
+                intersection += self_value.min(other_value);
+                union += self_value.max(other_value);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    if !overlap {
+        return None;
+    }
+    union += self_values[i..]
+        .iter()
+        .map(|v| v.max(0.0))
+        .sum::<DimWeight>();
+    union += other_values[j..]
+        .iter()
+        .map(|v| v.max(0.0))
+        .sum::<DimWeight>();
+
+    Some(if union > 0.0 {
+        intersection / union
+    } else {
+        0.0
+    })
+}
+
 impl RemappedSparseVector {
     pub fn new(indices: Vec<DimId>, values: Vec<DimWeight>) -> Result<Self, ValidationErrors> {
         let vector = Self { indices, values };
@@ -164,6 +223,17 @@ impl SparseVector {
         score_vectors(&self.indices, &self.values, &other.indices, &other.values)
     }
 
+    /// Score this vector against another vector using weighted Jaccard similarity, see
+    /// [`jaccard_vectors`].
+    /// Warning: Expects both vectors to be sorted by indices.
+    ///
+    /// Return None if the vectors do not overlap.
+    pub fn jaccard(&self, other: &SparseVector) -> Option<ScoreType> {
+        debug_assert!(self.is_sorted());
+        debug_assert!(other.is_sorted());
+        jaccard_vectors(&self.indices, &self.values, &other.indices, &other.values)
+    }
+
     /// Construct a new vector that is the result of performing all indices-wise operations.
     /// Automatically sort input vectors if necessary.
     pub fn combine_aggregate(
@@ -356,6 +426,23 @@ mod tests {
         assert!(v1.score(&v2).is_none());
     }
 
+    #[test]
+    fn test_jaccard() {
+        // Binary weights: 2 shared dimensions out of 4
+        let v1 = SparseVector::new(vec![1, 2, 3], vec![1.0, 1.0, 1.0]).unwrap();
+        let v2 = SparseVector::new(vec![2, 3, 4], vec![1.0, 1.0, 1.0]).unwrap();
+        assert_eq!(v1.jaccard(&v2), Some(0.5));
+        assert_eq!(v1.jaccard(&v1), Some(1.0));
+
+        // Weighted: (1 + 1) / (2 + 3 + 1)
+        let v1 = SparseVector::new(vec![1, 2, 3], vec![2.0, 1.0, 1.0]).unwrap();
+        let v2 = SparseVector::new(vec![1, 2], vec![1.0, 3.0]).unwrap();
+        assert_eq!(v1.jaccard(&v2), Some(2.0 / 6.0));
+
+        let v3 = SparseVector::new(vec![4, 5], vec![1.0, 1.0]).unwrap();
+        assert!(v1.jaccard(&v3).is_none());
+    }
+
     #[test]
     fn validation_test() {
         let fully_empty = SparseVector::new(vec![], vec![]);
@@ -435,6 +435,9 @@ pub struct CompressedPostingListIterator<'a, W: Weight> {
     /// Offset inside the posting list along with optional current element.
     /// Defined as a tuple to ensure that we won't forget to update the element
     pos: (usize, Option<PointOffsetType>),
+
+    /// End offset, last id and max weight of the block at the current position
+    block_max: Option<(usize, PointOffsetType, DimWeight)>,
 }
 
 impl<'a, W: Weight> CompressedPostingListIterator<'a, W> {
@@ -445,6 +448,7 @@ impl<'a, W: Weight> CompressedPostingListIterator<'a, W> {
             unpacked: false,
             decompressed_chunk: [0; CHUNK_SIZE],
             pos: (0, None),
+            block_max: None,
         }
     }
 
@@ -650,6 +654,52 @@ impl<W: Weight> PostingListIter for CompressedPostingListIterator<'_, W> {
         false
     }
 
+    /// Blocks are the compressed chunks, followed by the remainder as the last block.
+    /// Weights of a block are converted once, when the block is entered.
+    fn block_max_weight(&mut self) -> Option<(PointOffsetType, DimWeight)> {
+        let pos = self.pos.0;
+        if pos >= self.list.len() {
+            return None;
+        }
+
+        if let Some((block_end, block_last_id, max_weight)) = self.block_max
+            && pos < block_end
+        {
+            return Some((block_last_id, max_weight));
+        }
+
+        let chunk_index = pos / CHUNK_SIZE;
+        let (block_end, block_last_id, max_weight) = if chunk_index < self.list.chunks_len() {
+            let start = pos % CHUNK_SIZE;
+            let weights = self.list.weights_range(pos, CHUNK_SIZE - start);
+            let max_weight = weights
+                .iter()
+                .map(|w| w.to_f32(self.list.multiplier))
+                .fold(f32::NEG_INFINITY, f32::max);
+            // Ids are strictly increasing, so anything before the start of the next block
+            // belongs to this one
+            let block_last_id = match self.list.chunks.get(chunk_index + 1) {
+                Some(next_chunk) => next_chunk.initial - 1,
+                None => match self.list.remainders.first() {
+                    Some(first_remainder) => first_remainder.record_id - 1,
+                    None => self.list.last_id?,
+                },
+            };
+            ((chunk_index + 1) * CHUNK_SIZE, block_last_id, max_weight)
+        } else {
+            let remainder_pos = pos - self.list.chunks_len() * CHUNK_SIZE;
+            let max_weight = self
+                .list
+                .iter_remainder_from(remainder_pos)
+                .map(|e| e.weight.to_f32(self.list.multiplier))
+                .fold(f32::NEG_INFINITY, f32::max);
+            (self.list.len(), self.list.last_id?, max_weight)
+        };
+
+        self.block_max = Some((block_end, block_last_id, max_weight));
+        Some((block_last_id, max_weight))
+    }
+
     fn into_std_iter(self) -> impl Iterator<Item = PostingElement> {
         CompressedPostingListStdIterator(self)
     }
@@ -715,6 +765,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_block_max_weight() {
+        let list = CompressedPostingList::<f32>::from(mk_case(320));
+        let hw_counter = HardwareCounterCell::new();
+        let mut iter = list.iter(&hw_counter);
+
+        assert_eq!(iter.block_max_weight(), Some((10127, 127.0)));
+        iter.skip_to(10050);
+        assert_eq!(iter.block_max_weight(), Some((10127, 127.0)));
+
+        iter.skip_to(10128);
+        assert_eq!(iter.block_max_weight(), Some((10255, 255.0)));
+
+        // Remainder is the last block
+        iter.skip_to(10300);
+        assert_eq!(iter.block_max_weight(), Some((10319, 319.0)));
+
+        iter.skip_to_end();
+        assert_eq!(iter.block_max_weight(), None);
+    }
+
     #[test]
     #[allow(clippy::needless_range_loop)] // for consistency
     fn test_try_till_id() {
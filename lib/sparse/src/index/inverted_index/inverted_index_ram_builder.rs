@@ -4,6 +4,7 @@ use common::types::PointOffsetType;
 use log::debug;
 
 use crate::common::sparse_vector::RemappedSparseVector;
+use crate::common::types::DimWeight;
 use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 use crate::index::posting_list::PostingBuilder;
 use crate::index::posting_list_common::PostingElementEx;
@@ -45,6 +46,23 @@ impl InvertedIndexBuilder {
         self.total_sparse_size = self.total_sparse_size.saturating_add(sparse_size);
     }
 
+    /// Prune posting lists of all dimensions, see [`PostingBuilder::prune`].
+    pub fn prune(
+        &mut self,
+        min_weight: Option<DimWeight>,
+        max_postings_per_dimension: Option<usize>,
+    ) {
+        let pruned: usize = self
+            .posting_builders
+            .iter_mut()
+            .map(|posting_builder| posting_builder.prune(min_weight, max_postings_per_dimension))
+            .sum();
+        self.total_sparse_size = self
+            .total_sparse_size
+            .saturating_sub(pruned * size_of::<PostingElementEx>());
+        debug!("pruned {pruned} postings from inverted index");
+    }
+
     /// Consumes the builder and returns an InvertedIndexRam
     pub fn build(self) -> InvertedIndexRam {
         if self.posting_builders.is_empty() {
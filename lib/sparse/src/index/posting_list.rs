@@ -8,6 +8,9 @@ use super::posting_list_common::{
 };
 use crate::common::types::DimWeight;
 
+/// Number of consecutive elements sharing the max weight in block-max search
+const BLOCK_SIZE: usize = 128;
+
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct PostingList {
     /// List of the posting elements ordered by id
@@ -136,6 +139,29 @@ impl PostingBuilder {
         self.elements.push(PostingElementEx::new(record_id, weight));
     }
 
+    /// Drop records with absolute weight below `min_weight`, and keep at most `max_records`
+    /// records with the largest absolute weights.
+    ///
+    /// Returns the number of dropped records.
+    pub fn prune(&mut self, min_weight: Option<DimWeight>, max_records: Option<usize>) -> usize {
+        let len_before = self.elements.len();
+
+        if let Some(min_weight) = min_weight {
+            self.elements.retain(|e| e.weight.abs() >= min_weight);
+        }
+
+        if let Some(max_records) = max_records
+            && self.elements.len() > max_records
+        {
+            self.elements.select_nth_unstable_by_key(max_records, |e| {
+                std::cmp::Reverse(OrderedFloat(e.weight.abs()))
+            });
+            self.elements.truncate(max_records);
+        }
+
+        len_before - self.elements.len()
+    }
+
     /// Consume the builder and return the posting list.
     pub fn build(mut self) -> PostingList {
         // Sort by id
@@ -171,6 +197,8 @@ impl PostingBuilder {
 pub struct PostingListIterator<'a> {
     pub elements: &'a [PostingElementEx],
     pub current_index: usize,
+    /// End index, last id and max weight of the block at the current position
+    block_max: Option<(usize, PointOffsetType, DimWeight)>,
 }
 
 impl PostingListIter for PostingListIterator<'_> {
@@ -229,6 +257,30 @@ impl PostingListIter for PostingListIterator<'_> {
         true
     }
 
+    /// Blocks are aligned runs of [`BLOCK_SIZE`] elements, the max weight is computed once per
+    /// block.
+    fn block_max_weight(&mut self) -> Option<(PointOffsetType, DimWeight)> {
+        if self.current_index >= self.elements.len() {
+            return None;
+        }
+
+        if let Some((block_end, last_id, max_weight)) = self.block_max
+            && self.current_index < block_end
+        {
+            return Some((last_id, max_weight));
+        }
+
+        let block_end = (self.current_index / BLOCK_SIZE + 1) * BLOCK_SIZE;
+        let block = &self.elements[self.current_index..block_end.min(self.elements.len())];
+        let max_weight = block
+            .iter()
+            .map(|element| element.weight)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let last_id = block.last()?.record_id;
+        self.block_max = Some((block_end, last_id, max_weight));
+        Some((last_id, max_weight))
+    }
+
     fn into_std_iter(self) -> impl Iterator<Item = PostingElement> {
         self.elements.iter().cloned().map(PostingElement::from)
     }
@@ -239,6 +291,7 @@ impl<'a> PostingListIterator<'a> {
         PostingListIterator {
             elements,
             current_index: 0,
+            block_max: None,
         }
     }
 
@@ -342,6 +395,53 @@ mod tests {
         assert!(iter.peek().is_none());
     }
 
+    #[test]
+    fn test_block_max_weight() {
+        let records = (0..300)
+            .map(|id| (id * 2, if id == 200 { 10.0 } else { 1.0 }))
+            .collect();
+        let posting_list = PostingList::from(records);
+        let mut iter = PostingListIterator::new(&posting_list.elements);
+
+        assert_eq!(iter.block_max_weight(), Some((254, 1.0)));
+        // Skipping within a block keeps its bound
+        iter.skip_to(100);
+        assert_eq!(iter.block_max_weight(), Some((254, 1.0)));
+
+        iter.skip_to(256);
+        assert_eq!(iter.block_max_weight(), Some((510, 10.0)));
+        iter.skip_to(402);
+        assert_eq!(iter.block_max_weight(), Some((510, 10.0)));
+
+        iter.skip_to(512);
+        assert_eq!(iter.block_max_weight(), Some((598, 1.0)));
+
+        iter.skip_to_end();
+        assert_eq!(iter.block_max_weight(), None);
+    }
+
+    #[test]
+    fn test_posting_prune() {
+        let mut builder = PostingBuilder::new();
+        builder.add(1, 0.01);
+        builder.add(2, -3.0);
+        builder.add(3, 0.5);
+        builder.add(4, 2.0);
+        builder.add(5, -0.05);
+        builder.add(6, 1.0);
+
+        assert_eq!(builder.prune(Some(0.1), Some(3)), 3);
+
+        let posting_list = builder.build();
+        let ids = posting_list
+            .elements
+            .iter()
+            .map(|e| e.record_id)
+            .collect_vec();
+        assert_eq!(ids, vec![2, 4, 6]);
+        assert_eq!(posting_list.elements[0].max_next_weight, 2.0);
+    }
+
     #[test]
     fn test_upsert_insert_last() {
         let mut builder = PostingBuilder::new();
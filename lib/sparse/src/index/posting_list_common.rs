@@ -85,5 +85,12 @@ pub trait PostingListIter {
     /// Whether the max_next_weight is reliable.
     fn reliable_max_next_weight() -> bool;
 
+    /// Upper bound of the weights in the block of elements starting at the current position,
+    /// along with the greatest id the block may contain.
+    ///
+    /// Elements after the block have ids greater than the returned id.
+    /// Returns None if the iterator is exhausted.
+    fn block_max_weight(&mut self) -> Option<(PointOffsetType, DimWeight)>;
+
     fn into_std_iter(self) -> impl Iterator<Item = PostingElement>;
 }
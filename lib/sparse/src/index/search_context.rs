@@ -33,6 +33,7 @@ pub struct SearchContext<'a, 'b, T: PostingListIter = PostingListIterator<'a>> {
     max_record_id: PointOffsetType,         // max_record_id ids across all posting lists
     pooled: PooledScoresHandle<'b>,         // handle to pooled scores
     use_pruning: bool,
+    use_block_max: bool,
     hardware_counter: &'a HardwareCounterCell,
 }
 
@@ -89,10 +90,19 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
             max_record_id,
             pooled,
             use_pruning,
+            use_block_max: false,
             hardware_counter,
         }
     }
 
+    /// Enable block-max skipping of posting lists, see [`Self::skip_blocks`].
+    ///
+    /// Like pruning, it is only applicable to queries without negative values.
+    pub fn with_block_max(mut self, block_max: bool) -> Self {
+        self.use_block_max = block_max && self.query.values.iter().all(|v| *v >= 0.0);
+        self
+    }
+
     const DEFAULT_SCORE: f32 = 0.0;
 
     /// Plain search against the given ids without any pruning
@@ -295,6 +305,11 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
                 break;
             }
 
+            // skip blocks which cannot contribute to the top results
+            if self.use_block_max && self.top_results.len() >= self.top {
+                self.skip_blocks(self.top_results.threshold());
+            }
+
             // prepare next iterator of batched ids
             let Some(start_batch_id) = self.min_record_id else {
                 break;
@@ -354,6 +369,61 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
         queue.into_vec()
     }
 
+    /// Skip blocks of posting lists that cannot possibly contribute to the top results
+    ///
+    /// Considers the window of ids from the current min record id up to the end of the
+    /// nearest block across all posting lists. If the sum of the block max contributions
+    /// of posting lists intersecting the window is not above `min_score`,
+    /// all posting lists are advanced past the window.
+    fn skip_blocks(&mut self, min_score: f32) {
+        let mut blocks = Vec::with_capacity(self.postings_iterators.len());
+        loop {
+            if self.min_record_id.is_none() {
+                return;
+            }
+
+            blocks.clear();
+            for posting in self.postings_iterators.iter_mut() {
+                let iterator = &mut posting.posting_list_iterator;
+                let (Some(first), Some((block_last_id, block_max_weight))) =
+                    (iterator.peek(), iterator.block_max_weight())
+                else {
+                    continue;
+                };
+                blocks.push((
+                    first.record_id,
+                    block_last_id,
+                    block_max_weight * posting.query_weight,
+                ));
+            }
+
+            let Some(window_end) = blocks.iter().map(|&(_, last_id, _)| last_id).min() else {
+                return;
+            };
+            let max_score: f32 = blocks
+                .iter()
+                .filter(|&&(first_id, _, _)| first_id <= window_end)
+                .map(|&(_, _, max_contribution)| max_contribution)
+                .sum();
+            if max_score > min_score {
+                return;
+            }
+
+            for posting in self.postings_iterators.iter_mut() {
+                match window_end.checked_add(1) {
+                    Some(next_id) => {
+                        posting.posting_list_iterator.skip_to(next_id);
+                    }
+                    None => posting.posting_list_iterator.skip_to_end(),
+                }
+            }
+            self.postings_iterators.retain(|posting_iterator| {
+                posting_iterator.posting_list_iterator.len_to_end() != 0
+            });
+            self.min_record_id = Self::next_min_id(&mut self.postings_iterators);
+        }
+    }
+
     /// Prune posting lists that cannot possibly contribute to the top results
     /// Assumes longest posting list is at the head of the posting list iterators
     /// Returns true if the longest posting list was pruned
@@ -9,12 +9,15 @@ mod tests {
     use common::counter::hardware_accumulator::HwMeasurementAcc;
     use common::counter::hardware_counter::HardwareCounterCell;
     use common::types::{PointOffsetType, ScoredPointOffset};
-    use rand::Rng;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
     use tempfile::TempDir;
 
     use crate::common::scores_memory_pool::{PooledScoresHandle, ScoresMemoryPool};
     use crate::common::sparse_vector::{RemappedSparseVector, SparseVector};
-    use crate::common::sparse_vector_fixture::random_sparse_vector;
+    use crate::common::sparse_vector_fixture::{
+        random_positive_sparse_vector, random_sparse_vector,
+    };
     use crate::common::types::QuantizedU8;
     use crate::index::inverted_index::InvertedIndex;
     use crate::index::inverted_index::inverted_index_compressed_immutable_ram::InvertedIndexCompressedImmutableRam;
@@ -504,6 +507,48 @@ mod tests {
         inverted_index_ram
     }
 
+    #[test]
+    fn block_max_search_test<I: InvertedIndex>() {
+        let mut rnd_gen = StdRng::seed_from_u64(42);
+        let index = TestIndex::<I>::from_ram(random_inverted_index(&mut rnd_gen, 2_000, 100));
+
+        let is_stopped = AtomicBool::new(false);
+        let hardware_counter = HardwareCounterCell::disposable();
+        for _ in 0..10 {
+            let SparseVector { indices, values } = random_positive_sparse_vector(&mut rnd_gen, 100);
+            let query = RemappedSparseVector::new(indices, values).unwrap();
+
+            let expected = SearchContext::new(
+                query.clone(),
+                10,
+                &index.index,
+                get_pooled_scores(),
+                &is_stopped,
+                &hardware_counter,
+            )
+            .search(&match_all);
+
+            let found = SearchContext::new(
+                query,
+                10,
+                &index.index,
+                get_pooled_scores(),
+                &is_stopped,
+                &hardware_counter,
+            )
+            .with_block_max(true)
+            .search(&match_all);
+
+            let scores = |results: Vec<ScoredPointOffset>| {
+                round_scores::<I>(results)
+                    .into_iter()
+                    .map(|r| r.score)
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(scores(found), scores(expected));
+        }
+    }
+
     #[test]
     fn promote_longest_test<I: InvertedIndex>() {
         let index = TestIndex::<I>::from_ram({
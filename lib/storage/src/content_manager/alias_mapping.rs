@@ -69,6 +69,16 @@ impl AliasPersistence {
         Ok(())
     }
 
+    /// Inserts several aliases at once, the mapping is saved only once.
+    pub fn insert_many(
+        &mut self,
+        aliases: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<(), StorageError> {
+        self.alias_mapping.0.extend(aliases);
+        self.alias_mapping.save(&self.data_path)?;
+        Ok(())
+    }
+
     pub fn remove(&mut self, alias: &str) -> Result<Option<String>, StorageError> {
         let output = self.alias_mapping.0.remove(alias);
 
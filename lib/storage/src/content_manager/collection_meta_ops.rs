@@ -1,8 +1,11 @@
 use std::collections::BTreeMap;
 
-use collection::config::{CollectionConfigInternal, CollectionParams, ShardingMethod};
+use collection::collection::filter_templates::FilterTemplate;
+use collection::config::{
+    CollectionConfigInternal, CollectionParams, ShardKeyConfig, ShardingMethod,
+};
 use collection::operations::config_diff::{
-    CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
+    CollectionParamsDiff, DiffConfig, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
 };
 use collection::operations::types::{
@@ -74,6 +77,23 @@ pub struct RenameAliasOperation {
     pub rename_alias: RenameAlias,
 }
 
+/// Point a group of aliases to new collections at once.
+/// All target collections are checked before any alias is changed, so either the whole group
+/// is switched or none of its aliases are.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq, Hash, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct SwitchAliasGroup {
+    /// Aliases of the group with the collections they should point to
+    pub aliases: Vec<CreateAlias>,
+}
+
+/// Point a group of aliases to new collections at once.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq, Hash, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct SwitchAliasGroupOperation {
+    pub switch_alias_group: SwitchAliasGroup,
+}
+
 /// Group of all the possible operations related to collection aliases
 #[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq, Hash, Clone)]
 #[serde(rename_all = "snake_case")]
@@ -82,6 +102,7 @@ pub enum AliasOperations {
     CreateAlias(CreateAliasOperation),
     DeleteAlias(DeleteAliasOperation),
     RenameAlias(RenameAliasOperation),
+    SwitchAliasGroup(SwitchAliasGroupOperation),
 }
 
 impl From<CreateAlias> for AliasOperations {
@@ -102,6 +123,12 @@ impl From<RenameAlias> for AliasOperations {
     }
 }
 
+impl From<SwitchAliasGroup> for AliasOperations {
+    fn from(switch_alias_group: SwitchAliasGroup) -> Self {
+        AliasOperations::SwitchAliasGroup(SwitchAliasGroupOperation { switch_alias_group })
+    }
+}
+
 /// Operation for creating new collection and (optionally) specify index params
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, PartialEq, Eq, Hash, Clone)]
 #[serde(rename_all = "snake_case")]
@@ -177,6 +204,12 @@ pub struct CreateCollection {
     /// such as creation time, migration data, inference model info, etc.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Payload>,
+    /// Payload field which identifies the tenant of a point.
+    /// If set, the collection is configured for multitenancy:
+    ///  - keyword index with `is_tenant` is created for this field
+    ///  - strict mode is enabled by default and requires read requests to filter by this field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_field: Option<PayloadKeyType>,
 }
 
 /// Operation for creating new collection and (optionally) specify index params
@@ -191,7 +224,7 @@ pub struct CreateCollectionOperation {
 impl CreateCollectionOperation {
     pub fn new(
         collection_name: String,
-        create_collection: CreateCollection,
+        mut create_collection: CreateCollection,
     ) -> StorageResult<Self> {
         // validate vector names are unique between dense and sparse vectors
         if let Some(sparse_config) = &create_collection.sparse_vectors {
@@ -204,6 +237,17 @@ impl CreateCollectionOperation {
             }
         }
 
+        // apply multitenancy defaults
+        if let Some(tenant_field) = &create_collection.tenant_field {
+            let strict_mode_config = create_collection
+                .strict_mode_config
+                .get_or_insert_with(StrictModeConfig::default);
+            strict_mode_config.enabled.get_or_insert(true);
+            strict_mode_config
+                .tenant_filter_key
+                .get_or_insert_with(|| tenant_field.clone());
+        }
+
         Ok(Self {
             collection_name,
             create_collection,
@@ -224,6 +268,55 @@ impl CreateCollectionOperation {
     }
 }
 
+/// Operation for creating new collection as a clone of the existing one.
+/// Config, payload indexes and points are copied from the existing collection,
+/// indexes are rebuilt with the config of the new collection.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, PartialEq, Eq, Hash, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct CloneCollection {
+    /// Name of the new collection
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name")
+    )]
+    pub target_collection: String,
+    /// Custom params for HNSW index of the new collection. If none - params of the existing collection are used.
+    #[validate(nested)]
+    pub hnsw_config: Option<HnswConfigDiff>,
+    /// Custom params for Optimizers of the new collection. If none - params of the existing collection are used.
+    #[serde(alias = "optimizer_config")]
+    #[validate(nested)]
+    pub optimizers_config: Option<OptimizersConfigDiff>,
+    /// Quantization parameters of the new collection. If none - params of the existing collection are used.
+    #[serde(default, alias = "quantization")]
+    #[validate(nested)]
+    pub quantization_config: Option<QuantizationConfigDiff>,
+}
+
+impl CloneCollection {
+    /// Apply overrides of this request to the config of the existing collection
+    pub fn apply_to(&self, config: &mut CollectionConfigInternal) {
+        if let Some(hnsw_diff) = &self.hnsw_config {
+            config.hnsw_config = config.hnsw_config.update(hnsw_diff);
+        }
+
+        if let Some(optimizers_diff) = &self.optimizers_config {
+            config.optimizer_config = config.optimizer_config.update(optimizers_diff);
+        }
+
+        if let Some(quantization_diff) = &self.quantization_config {
+            config.quantization_config = match quantization_diff.clone() {
+                QuantizationConfigDiff::Scalar(scalar) => Some(QuantizationConfig::Scalar(scalar)),
+                QuantizationConfigDiff::Product(product) => {
+                    Some(QuantizationConfig::Product(product))
+                }
+                QuantizationConfigDiff::Binary(binary) => Some(QuantizationConfig::Binary(binary)),
+                QuantizationConfigDiff::Disabled(_) => None,
+            };
+        }
+    }
+}
+
 /// Operation for updating parameters of the existing collection
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, PartialEq, Eq, Hash, Clone)]
 #[serde(rename_all = "snake_case")]
@@ -238,6 +331,7 @@ pub struct UpdateCollection {
     #[validate(nested)]
     pub optimizers_config: Option<OptimizersConfigDiff>, // TODO: Allow updates for other configuration params as well
     /// Collection base params. If none - it is left unchanged.
+    #[validate(nested)]
     pub params: Option<CollectionParamsDiff>,
     /// HNSW parameters to update for the collection index. If none - it is left unchanged.
     #[validate(nested)]
@@ -377,6 +471,9 @@ pub struct CreateShardKey {
     pub shard_key: ShardKey,
     pub placement: ShardsPlacement,
     pub initial_state: Option<ReplicaState>,
+    /// Configuration overrides of the shards for this key
+    #[serde(default)]
+    pub config: Option<ShardKeyConfig>,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
@@ -398,6 +495,19 @@ pub struct DropPayloadIndex {
     pub field_name: PayloadKeyType,
 }
 
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub struct SetFilterTemplate {
+    pub collection_name: String,
+    pub template_name: String,
+    pub template: FilterTemplate,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub struct DeleteFilterTemplate {
+    pub collection_name: String,
+    pub template_name: String,
+}
+
 /// Enumeration of all possible collection update operations
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
 #[serde(rename_all = "snake_case")]
@@ -413,6 +523,8 @@ pub enum CollectionMetaOperations {
     DropShardKey(DropShardKey),
     CreatePayloadIndex(CreatePayloadIndex),
     DropPayloadIndex(DropPayloadIndex),
+    SetFilterTemplate(SetFilterTemplate),
+    DeleteFilterTemplate(DeleteFilterTemplate),
     Nop {
         token: usize,
     }, // Empty operation
@@ -444,7 +556,16 @@ impl From<CollectionConfigInternal> for CreateCollection {
             write_consistency_factor,
             read_fan_out_factor: _,
             read_fan_out_delay_ms: _,
+            read_routing: _,
             on_disk_payload,
+            payload_overflow_threshold: _,
+            duplicate_detection: _,
+            strict_payload_schema: _,
+            ingest_payload: _,
+            read_only: _,
+            trash_retention_sec: _,
+            points_expiration: _,
+            shard_key_configs: _,
             sparse_vectors,
         } = params;
 
@@ -463,6 +584,7 @@ impl From<CollectionConfigInternal> for CreateCollection {
             strict_mode_config,
             uuid,
             metadata,
+            tenant_field: None,
         }
     }
 }
@@ -0,0 +1,310 @@
+use collection::operations::types::CollectionConfig;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+
+use crate::content_manager::collection_meta_ops::CreateCollection;
+use crate::content_manager::errors::{StorageError, StorageResult};
+
+/// How a configuration change can be applied to an existing collection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigChangeKind {
+    /// Can be applied with a collection update, takes effect immediately
+    Hot,
+    /// Can be applied with a collection update, but segments have to be rebuilt by optimizers
+    RequiresOptimization,
+    /// Can not be applied to an existing collection, it has to be recreated
+    Incompatible,
+}
+
+/// Single field which differs between the live and the proposed collection configuration
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ConfigChange {
+    /// Path of the field in the collection config, e.g. `hnsw_config.m`
+    pub field: String,
+    /// Live value, absent if the field is not set
+    pub current: Option<Value>,
+    /// Proposed value, absent if the field would be removed
+    pub proposed: Option<Value>,
+    pub kind: ConfigChangeKind,
+}
+
+/// Structured difference between the live and a proposed collection configuration
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CollectionConfigChanges {
+    pub changes: Vec<ConfigChange>,
+}
+
+/// Fields which are only compared as a whole, as their variants have different structure
+const ATOMIC_FIELDS: &[&str] = &["quantization_config", "multivector_config"];
+
+/// Compare a proposed collection configuration with the live one.
+///
+/// Only fields explicitly set in the proposed configuration are compared, unset fields are
+/// considered to be unchanged. Named vectors missing from a non-empty proposed set are reported
+/// as removed.
+pub fn collection_config_changes(
+    live: &CollectionConfig,
+    proposed: &CreateCollection,
+) -> StorageResult<CollectionConfigChanges> {
+    let live = to_value(live)?;
+    let proposed = proposed_config_value(proposed)?;
+
+    let named_vectors = !matches!(
+        live.pointer("/params/vectors"),
+        Some(Value::Object(vectors)) if vectors.contains_key("size"),
+    );
+
+    let mut diff = Vec::new();
+    diff_values(&mut Vec::new(), Some(&live), &proposed, &mut diff);
+
+    // Removed vectors are not visible while walking the proposed config
+    for (path, removable) in [("vectors", named_vectors), ("sparse_vectors", true)] {
+        let (Some(Value::Object(live_vectors)), Some(Value::Object(proposed_vectors))) = (
+            live.pointer(&format!("/params/{path}")),
+            proposed.pointer(&format!("/params/{path}")),
+        ) else {
+            continue;
+        };
+
+        if !removable || proposed_vectors.is_empty() {
+            continue;
+        }
+
+        for (name, value) in live_vectors {
+            if !proposed_vectors.contains_key(name) {
+                let path = vec!["params".to_string(), path.to_string(), name.clone()];
+                diff.push((path, Some(value.clone()), None));
+            }
+        }
+    }
+
+    let changes = diff
+        .into_iter()
+        .map(|(path, current, proposed)| ConfigChange {
+            kind: classify(&path, named_vectors),
+            field: path.join("."),
+            current,
+            proposed,
+        })
+        .collect();
+
+    Ok(CollectionConfigChanges { changes })
+}
+
+/// Arrange the proposed config the same way as the live `CollectionConfig` is structured
+fn proposed_config_value(proposed: &CreateCollection) -> StorageResult<Value> {
+    let CreateCollection {
+        vectors,
+        shard_number,
+        sharding_method,
+        replication_factor,
+        write_consistency_factor,
+        on_disk_payload,
+        hnsw_config,
+        wal_config,
+        optimizers_config,
+        quantization_config,
+        sparse_vectors,
+        strict_mode_config,
+        uuid: _,
+        metadata,
+        tenant_field: _,
+    } = proposed;
+
+    Ok(json!({
+        "params": {
+            "vectors": to_value(vectors)?,
+            "shard_number": shard_number,
+            "sharding_method": to_value(sharding_method)?,
+            "replication_factor": replication_factor,
+            "write_consistency_factor": write_consistency_factor,
+            "on_disk_payload": on_disk_payload,
+            "sparse_vectors": to_value(sparse_vectors)?,
+        },
+        "hnsw_config": to_value(hnsw_config)?,
+        "optimizer_config": to_value(optimizers_config)?,
+        "wal_config": to_value(wal_config)?,
+        "quantization_config": to_value(quantization_config)?,
+        "strict_mode_config": to_value(strict_mode_config)?,
+        "metadata": to_value(metadata)?,
+    }))
+}
+
+fn to_value(value: impl Serialize) -> StorageResult<Value> {
+    serde_json::to_value(value).map_err(|err| {
+        StorageError::service_error(format!("Failed to serialize collection config: {err}"))
+    })
+}
+
+type Diff = Vec<(Vec<String>, Option<Value>, Option<Value>)>;
+
+fn diff_values(path: &mut Vec<String>, live: Option<&Value>, proposed: &Value, diff: &mut Diff) {
+    let is_atomic = path
+        .last()
+        .is_some_and(|field| ATOMIC_FIELDS.contains(&field.as_str()));
+
+    match (live, proposed) {
+        (_, Value::Null) => {}
+        (Some(Value::Object(live)), Value::Object(proposed)) if !is_atomic => {
+            diff_objects(path, live, proposed, diff)
+        }
+        (live, proposed) => {
+            if live != Some(proposed) {
+                diff.push((path.clone(), live.cloned(), Some(proposed.clone())));
+            }
+        }
+    }
+}
+
+fn diff_objects(
+    path: &mut Vec<String>,
+    live: &Map<String, Value>,
+    proposed: &Map<String, Value>,
+    diff: &mut Diff,
+) {
+    for (key, proposed_value) in proposed {
+        path.push(key.clone());
+        diff_values(path, live.get(key), proposed_value, diff);
+        path.pop();
+    }
+}
+
+fn classify(path: &[String], named_vectors: bool) -> ConfigChangeKind {
+    let path: Vec<&str> = path.iter().map(String::as_str).collect();
+
+    match path.as_slice() {
+        ["params", "vectors", vector_path @ ..] => {
+            if named_vectors {
+                // Skip the vector name
+                classify_vector(vector_path.get(1..).unwrap_or_default())
+            } else {
+                classify_vector(vector_path)
+            }
+        }
+        ["params", "sparse_vectors", _name, "modifier"] => ConfigChangeKind::Hot,
+        ["params", "sparse_vectors", _name, "index", ..] => ConfigChangeKind::RequiresOptimization,
+        [
+            "params",
+            "replication_factor" | "write_consistency_factor" | "read_fan_out_factor",
+        ] => ConfigChangeKind::Hot,
+        ["params", "on_disk_payload"] => ConfigChangeKind::RequiresOptimization,
+        ["hnsw_config" | "quantization_config", ..] => ConfigChangeKind::RequiresOptimization,
+        ["optimizer_config" | "strict_mode_config" | "metadata", ..] => ConfigChangeKind::Hot,
+        _ => ConfigChangeKind::Incompatible,
+    }
+}
+
+fn classify_vector(path: &[&str]) -> ConfigChangeKind {
+    match path {
+        ["on_disk" | "index"] | ["hnsw_config" | "quantization_config", ..] => {
+            ConfigChangeKind::RequiresOptimization
+        }
+        // Vector storage layout, adding or removing vectors
+        _ => ConfigChangeKind::Incompatible,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use collection::config::CollectionConfigInternal;
+    use serde_json::json;
+
+    use super::*;
+
+    fn live_config() -> CollectionConfig {
+        let config: CollectionConfigInternal = serde_json::from_value(json!({
+            "params": {
+                "vectors": {
+                    "image": { "size": 4, "distance": "Dot" },
+                    "text": { "size": 8, "distance": "Cosine" },
+                },
+                "shard_number": 1,
+                "replication_factor": 1,
+            },
+            "hnsw_config": { "m": 16, "ef_construct": 100, "full_scan_threshold": 10000 },
+            "optimizer_config": {
+                "deleted_threshold": 0.2,
+                "vacuum_min_vector_number": 1000,
+                "default_segment_number": 0,
+                "flush_interval_sec": 5,
+            },
+            "wal_config": { "wal_capacity_mb": 32, "wal_segments_ahead": 0 },
+        }))
+        .unwrap();
+        CollectionConfig::from(config)
+    }
+
+    fn changes(proposed: Value) -> Vec<(String, ConfigChangeKind)> {
+        let proposed: CreateCollection = serde_json::from_value(proposed).unwrap();
+        collection_config_changes(&live_config(), &proposed)
+            .unwrap()
+            .changes
+            .into_iter()
+            .map(|change| (change.field, change.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_unchanged_config() {
+        let changes = changes(json!({
+            "vectors": {
+                "image": { "size": 4, "distance": "Dot" },
+                "text": { "size": 8, "distance": "Cosine" },
+            },
+            "hnsw_config": { "m": 16 },
+            "replication_factor": 1,
+        }));
+        assert!(changes.is_empty(), "{changes:?}");
+    }
+
+    #[test]
+    fn test_config_changes_classification() {
+        let changes = changes(json!({
+            "vectors": {
+                "image": { "size": 4, "distance": "Euclid", "on_disk": true },
+            },
+            "replication_factor": 2,
+            "shard_number": 3,
+            "hnsw_config": { "m": 32 },
+            "optimizers_config": { "deleted_threshold": 0.5 },
+        }));
+
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    "params.vectors.image.distance".to_string(),
+                    ConfigChangeKind::Incompatible,
+                ),
+                (
+                    "params.vectors.image.on_disk".to_string(),
+                    ConfigChangeKind::RequiresOptimization,
+                ),
+                (
+                    "params.shard_number".to_string(),
+                    ConfigChangeKind::Incompatible
+                ),
+                (
+                    "params.replication_factor".to_string(),
+                    ConfigChangeKind::Hot
+                ),
+                (
+                    "hnsw_config.m".to_string(),
+                    ConfigChangeKind::RequiresOptimization
+                ),
+                (
+                    "optimizer_config.deleted_threshold".to_string(),
+                    ConfigChangeKind::Hot
+                ),
+                (
+                    "params.vectors.text".to_string(),
+                    ConfigChangeKind::Incompatible
+                ),
+            ],
+        );
+    }
+}
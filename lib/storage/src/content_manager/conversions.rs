@@ -14,10 +14,10 @@ use tonic::Status;
 use tonic::metadata::MetadataValue;
 
 use crate::content_manager::collection_meta_ops::{
-    AliasOperations, ChangeAliasesOperation, CollectionMetaOperations, CreateAlias,
-    CreateAliasOperation, CreateCollection, CreateCollectionOperation, DeleteAlias,
+    AliasOperations, ChangeAliasesOperation, CloneCollection, CollectionMetaOperations,
+    CreateAlias, CreateAliasOperation, CreateCollection, CreateCollectionOperation, DeleteAlias,
     DeleteAliasOperation, DeleteCollectionOperation, RenameAlias, RenameAliasOperation,
-    UpdateCollection, UpdateCollectionOperation,
+    SwitchAliasGroup, SwitchAliasGroupOperation, UpdateCollection, UpdateCollectionOperation,
 };
 use crate::content_manager::errors::StorageError;
 use crate::types::{ConsensusThreadStatus, StateRole};
@@ -51,6 +51,7 @@ impl From<StorageError> for Status {
             }
             StorageError::ShardUnavailable { .. } => tonic::Code::Unavailable,
             StorageError::EmptyPartialSnapshot { .. } => tonic::Code::FailedPrecondition,
+            StorageError::InsufficientDiskSpace { .. } => tonic::Code::Unavailable,
         };
         let mut status = Status::new(error_code, format!("{error}"));
         // add metadata headers
@@ -85,6 +86,7 @@ impl TryFrom<grpc::CreateCollection> for CollectionMetaOperations {
             sparse_vectors_config,
             strict_mode_config,
             metadata,
+            tenant_field,
         } = value;
         let op = CreateCollectionOperation::new(
             collection_name,
@@ -108,20 +110,23 @@ impl TryFrom<grpc::CreateCollection> for CollectionMetaOperations {
                 sharding_method: sharding_method
                     .map(sharding_method_from_proto)
                     .transpose()?,
-                strict_mode_config: strict_mode_config.map(strict_mode_from_api),
+                strict_mode_config: strict_mode_config.map(strict_mode_from_api).transpose()?,
                 uuid: None,
                 metadata: if metadata.is_empty() {
                     None
                 } else {
                     Some(json::proto_to_payloads(metadata)?)
                 },
+                tenant_field: tenant_field
+                    .map(|field| json::json_path_from_proto(&field))
+                    .transpose()?,
             },
         )?;
         Ok(CollectionMetaOperations::CreateCollection(op))
     }
 }
 
-pub fn strict_mode_from_api(value: grpc::StrictModeConfig) -> StrictModeConfig {
+pub fn strict_mode_from_api(value: grpc::StrictModeConfig) -> Result<StrictModeConfig, Status> {
     let grpc::StrictModeConfig {
         enabled,
         max_query_limit,
@@ -142,8 +147,12 @@ pub fn strict_mode_from_api(value: grpc::StrictModeConfig) -> StrictModeConfig {
         multivector_config,
         sparse_config,
         max_payload_index_count,
+        tenant_filter_key,
+        search_rate_limit,
+        scroll_rate_limit,
+        update_rate_limit,
     } = value;
-    StrictModeConfig {
+    Ok(StrictModeConfig {
         enabled,
         max_query_limit: max_query_limit.map(|i| i as usize),
         max_timeout: max_timeout.map(|i| i as usize),
@@ -163,7 +172,13 @@ pub fn strict_mode_from_api(value: grpc::StrictModeConfig) -> StrictModeConfig {
         multivector_config: multivector_config.map(StrictModeMultivectorConfig::from),
         sparse_config: sparse_config.map(StrictModeSparseConfig::from),
         max_payload_index_count: max_payload_index_count.map(|i| i as usize),
-    }
+        tenant_filter_key: tenant_filter_key
+            .map(|key| json::json_path_from_proto(&key))
+            .transpose()?,
+        search_rate_limit: search_rate_limit.map(|i| i as usize),
+        scroll_rate_limit: scroll_rate_limit.map(|i| i as usize),
+        update_rate_limit: update_rate_limit.map(|i| i as usize),
+    })
 }
 
 impl TryFrom<grpc::UpdateCollection> for CollectionMetaOperations {
@@ -200,7 +215,9 @@ impl TryFrom<grpc::UpdateCollection> for CollectionMetaOperations {
                 sparse_vectors: sparse_vectors_config
                     .map(SparseVectorsConfig::try_from)
                     .transpose()?,
-                strict_mode_config: strict_mode_config.map(StrictModeConfig::from),
+                strict_mode_config: strict_mode_config
+                    .map(StrictModeConfig::try_from)
+                    .transpose()?,
                 metadata: if metadata.is_empty() {
                     None
                 } else {
@@ -211,6 +228,31 @@ impl TryFrom<grpc::UpdateCollection> for CollectionMetaOperations {
     }
 }
 
+impl TryFrom<grpc::CloneCollection> for CloneCollection {
+    type Error = Status;
+
+    fn try_from(value: grpc::CloneCollection) -> Result<Self, Self::Error> {
+        let grpc::CloneCollection {
+            collection_name: _,
+            target_collection,
+            hnsw_config,
+            optimizers_config,
+            quantization_config,
+            timeout: _,
+        } = value;
+        Ok(Self {
+            target_collection,
+            hnsw_config: hnsw_config.map(HnswConfigDiff::from),
+            optimizers_config: optimizers_config
+                .map(OptimizersConfigDiff::try_from)
+                .transpose()?,
+            quantization_config: quantization_config
+                .map(QuantizationConfigDiff::try_from)
+                .transpose()?,
+        })
+    }
+}
+
 impl TryFrom<grpc::DeleteCollection> for CollectionMetaOperations {
     type Error = Status;
 
@@ -264,6 +306,28 @@ impl From<grpc::RenameAlias> for AliasOperations {
     }
 }
 
+impl From<grpc::SwitchAliasGroup> for AliasOperations {
+    fn from(value: grpc::SwitchAliasGroup) -> Self {
+        let grpc::SwitchAliasGroup { aliases } = value;
+        Self::SwitchAliasGroup(SwitchAliasGroupOperation {
+            switch_alias_group: SwitchAliasGroup {
+                aliases: aliases
+                    .into_iter()
+                    .map(
+                        |grpc::CreateAlias {
+                             collection_name,
+                             alias_name,
+                         }| CreateAlias {
+                            collection_name,
+                            alias_name,
+                        },
+                    )
+                    .collect(),
+            },
+        })
+    }
+}
+
 impl TryFrom<grpc::AliasOperations> for AliasOperations {
     type Error = Status;
 
@@ -273,6 +337,7 @@ impl TryFrom<grpc::AliasOperations> for AliasOperations {
             Some(grpc::alias_operations::Action::CreateAlias(create)) => Ok(create.into()),
             Some(grpc::alias_operations::Action::DeleteAlias(delete)) => Ok(delete.into()),
             Some(grpc::alias_operations::Action::RenameAlias(rename)) => Ok(rename.into()),
+            Some(grpc::alias_operations::Action::SwitchAliasGroup(switch)) => Ok(switch.into()),
             None => Err(Status::invalid_argument("Malformed AliasOperation type")),
         }
     }
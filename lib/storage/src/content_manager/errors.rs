@@ -47,6 +47,8 @@ pub enum StorageError {
     ShardUnavailable { description: String },
     #[error("Partial snapshot for shard {shard_id} contains no changes")]
     EmptyPartialSnapshot { shard_id: ShardId },
+    #[error("Insufficient disk space: {description}")]
+    InsufficientDiskSpace { description: String },
 }
 
 impl StorageError {
@@ -185,6 +187,9 @@ impl StorageError {
             CollectionError::ShardUnavailable { .. } => StorageError::ShardUnavailable {
                 description: overriding_description,
             },
+            CollectionError::InsufficientDiskSpace { .. } => StorageError::InsufficientDiskSpace {
+                description: overriding_description,
+            },
         }
     }
 }
@@ -247,6 +252,9 @@ impl From<CollectionError> for StorageError {
             CollectionError::ShardUnavailable { description } => {
                 StorageError::ShardUnavailable { description }
             }
+            CollectionError::InsufficientDiskSpace { description } => {
+                StorageError::InsufficientDiskSpace { description }
+            }
         }
     }
 }
@@ -8,6 +8,7 @@ pub mod alias_mapping;
 pub mod collection_meta_ops;
 pub mod collection_verification;
 mod collections_ops;
+pub mod config_changes;
 pub mod consensus;
 pub mod consensus_manager;
 pub mod conversions;
@@ -13,7 +13,7 @@ use crate::StorageError;
 use crate::content_manager::snapshots::download_result::DownloadResult;
 use crate::content_manager::snapshots::download_tar::download_and_unpack_tar;
 
-fn snapshot_prefix(url: &Url) -> OsString {
+pub(super) fn snapshot_prefix(url: &Url) -> OsString {
     Path::new(url.path())
         .file_name()
         .map(|x| OsString::from(x).tap_mut(|x| x.push("-")))
@@ -0,0 +1,309 @@
+use std::io::SeekFrom;
+use std::path::Path;
+use std::time::Duration;
+
+use collection::common::sha_256::hash_file;
+use collection::common::snapshot_bandwidth::SNAPSHOT_DOWNLOAD_LIMITER;
+use common::tempfile_ext::MaybeTempPath;
+use fs_err::tokio as tokio_fs;
+use futures::StreamExt as _;
+use reqwest::StatusCode;
+use reqwest::header::{CONTENT_RANGE, RANGE};
+use shard::snapshots::snapshot_data::SnapshotData;
+use tokio::io::{AsyncSeekExt as _, AsyncWriteExt as _};
+use url::Url;
+
+use crate::StorageError;
+use crate::content_manager::snapshots::download::snapshot_prefix;
+use crate::content_manager::snapshots::download_result::DownloadResult;
+
+/// Size of a single chunk, requested from the server with a range request.
+const CHUNK_SIZE: u64 = 64 * 1024 * 1024;
+
+/// How many times in a row the download can fail without any progress, before giving up.
+const MAX_RETRIES: usize = 10;
+
+/// Delay between retries, doubled on each consecutive failure.
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Timeout for stream reads - if no data is received within this duration, the chunk is retried.
+const STREAM_READ_TIMEOUT: Duration = Duration::from_secs(60);
+
+enum ChunkError {
+    /// Connection failed, chunk can be requested again
+    Retryable(String),
+    Fatal(StorageError),
+}
+
+impl From<std::io::Error> for ChunkError {
+    fn from(err: std::io::Error) -> Self {
+        ChunkError::Fatal(err.into())
+    }
+}
+
+/// State of a snapshot file, downloaded in chunks.
+struct ResumableDownload<'a> {
+    client: &'a reqwest::Client,
+    url: &'a Url,
+    file: tokio_fs::File,
+    /// Number of bytes written into the file
+    downloaded: u64,
+    /// Total size of the file, known after the first response
+    total_size: Option<u64>,
+}
+
+impl ResumableDownload<'_> {
+    fn is_finished(&self) -> bool {
+        self.total_size
+            .is_some_and(|total_size| self.downloaded >= total_size)
+    }
+
+    /// Request the next chunk of the file, starting at the last written byte.
+    ///
+    /// If the connection breaks in the middle, bytes received so far are kept.
+    async fn download_chunk(&mut self) -> Result<(), ChunkError> {
+        let range_end = self.downloaded + CHUNK_SIZE - 1;
+        let response = self
+            .client
+            .get(self.url.clone())
+            .header(RANGE, format!("bytes={}-{range_end}", self.downloaded))
+            .send()
+            .await
+            .map_err(|err| ChunkError::Retryable(err.to_string()))?;
+
+        match response.status() {
+            StatusCode::PARTIAL_CONTENT => {
+                let total_size = response
+                    .headers()
+                    .get(CONTENT_RANGE)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(parse_content_range_total);
+                let Some(total_size) = total_size else {
+                    return Err(ChunkError::Fatal(StorageError::service_error(format!(
+                        "Failed to download snapshot from {}: missing total size in Content-Range header",
+                        self.url,
+                    ))));
+                };
+                self.total_size = Some(total_size);
+            }
+            // Server doesn't support range requests and sends the whole file, start over
+            StatusCode::OK => {
+                self.downloaded = 0;
+                self.file.set_len(0).await?;
+                self.total_size = response.content_length();
+            }
+            // Requested range is past the end of the file, everything is downloaded
+            StatusCode::RANGE_NOT_SATISFIABLE if self.downloaded > 0 => {
+                self.total_size = Some(self.downloaded);
+                return Ok(());
+            }
+            status if status.is_server_error() => {
+                return Err(ChunkError::Retryable(format!("status - {status}")));
+            }
+            status => {
+                return Err(ChunkError::Fatal(StorageError::bad_input(format!(
+                    "Failed to download snapshot from {}: status - {status}",
+                    self.url,
+                ))));
+            }
+        }
+
+        let is_whole_file = response.status() == StatusCode::OK;
+
+        self.file.seek(SeekFrom::Start(self.downloaded)).await?;
+
+        let mut stream = response.bytes_stream();
+        loop {
+            let chunk = match tokio::time::timeout(STREAM_READ_TIMEOUT, stream.next()).await {
+                Ok(Some(Ok(chunk))) => chunk,
+                Ok(None) => break,
+                Ok(Some(Err(err))) => {
+                    self.file.flush().await?;
+                    return Err(ChunkError::Retryable(err.to_string()));
+                }
+                Err(_elapsed) => {
+                    self.file.flush().await?;
+                    return Err(ChunkError::Retryable(
+                        "no data received within timeout period".to_string(),
+                    ));
+                }
+            };
+
+            SNAPSHOT_DOWNLOAD_LIMITER.consume(chunk.len()).await;
+            self.file.write_all(&chunk).await?;
+            self.downloaded += chunk.len() as u64;
+        }
+        self.file.flush().await?;
+
+        // Size of the whole file is not always known in advance
+        if is_whole_file {
+            self.total_size = Some(self.downloaded);
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse total size from the `Content-Range` header, e.g. `bytes 0-1023/4096`.
+fn parse_content_range_total(content_range: &str) -> Option<u64> {
+    let (_range, total_size) = content_range.strip_prefix("bytes ")?.split_once('/')?;
+    total_size.parse().ok()
+}
+
+/// Download a snapshot file from `url` in chunks, using HTTP range requests.
+///
+/// If the connection breaks, the download resumes from the last received byte instead of
+/// starting from zero. The SHA-256 hash of the whole file is computed once it is downloaded,
+/// so it can be verified against the expected checksum.
+///
+/// # Cancel safety
+///
+/// This function is cancel safe. If cancelled, the partially downloaded file is deleted.
+pub async fn download_snapshot_resumable(
+    client: &reqwest::Client,
+    url: &Url,
+    snapshots_dir: &Path,
+) -> Result<DownloadResult, StorageError> {
+    let download_start_time = tokio::time::Instant::now();
+
+    let (file, path) = tempfile::Builder::new()
+        .prefix(&snapshot_prefix(url))
+        .suffix(".download")
+        .tempfile_in(snapshots_dir)?
+        .into_parts();
+
+    let mut download = ResumableDownload {
+        client,
+        url,
+        file: tokio_fs::File::from_std(fs_err::File::from_parts(file, path.to_path_buf())),
+        downloaded: 0,
+        total_size: None,
+    };
+
+    let mut retries = 0;
+    while !download.is_finished() {
+        let downloaded_before = download.downloaded;
+
+        let err = match download.download_chunk().await {
+            Ok(()) if download.is_finished() => break,
+            Ok(()) if download.downloaded > downloaded_before => {
+                retries = 0;
+                continue;
+            }
+            Ok(()) => "no data received".to_string(),
+            Err(ChunkError::Retryable(err)) => err,
+            Err(ChunkError::Fatal(err)) => return Err(err),
+        };
+
+        // Chunk was partially received before the connection broke
+        if download.downloaded > downloaded_before {
+            retries = 0;
+        }
+        if retries >= MAX_RETRIES {
+            return Err(StorageError::service_error(format!(
+                "Failed to download snapshot from {url} after {MAX_RETRIES} retries: {err}",
+            )));
+        }
+
+        log::warn!(
+            "Snapshot download from {url} interrupted at {} bytes, resuming: {err}",
+            download.downloaded,
+        );
+        tokio::time::sleep(RETRY_BACKOFF * 2u32.pow(retries as u32)).await;
+        retries += 1;
+    }
+
+    download.file.sync_all().await?;
+    drop(download);
+
+    let hash = hash_file(&path).await?;
+
+    log::debug!(
+        "Snapshot download completed: path={}, duration={:.2}s",
+        path.display(),
+        download_start_time.elapsed().as_secs_f64(),
+    );
+
+    Ok(DownloadResult {
+        snapshot: SnapshotData::Packed(MaybeTempPath::Temporary(path)),
+        hash: Some(hash),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_download_resumes_from_last_byte() {
+        let content = b"0123456789abcdefghij";
+        let checksum = "6bc14bdc4517a7a682c6910de2e2946eb8e1ecd04090728fef6d092a7ceb62c5";
+
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/test-shard.snapshot")
+            .match_header("range", format!("bytes=0-{}", CHUNK_SIZE - 1).as_str())
+            .with_status(206)
+            .with_header("content-range", "bytes 0-9/20")
+            .with_body(&content[..10])
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/test-shard.snapshot")
+            .match_header("range", format!("bytes=10-{}", CHUNK_SIZE + 9).as_str())
+            .with_status(206)
+            .with_header("content-range", "bytes 10-19/20")
+            .with_body(&content[10..])
+            .create_async()
+            .await;
+        let url = Url::parse(&format!("{}/test-shard.snapshot", server.url())).unwrap();
+
+        let client = reqwest::Client::new();
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let DownloadResult { snapshot, hash } =
+            download_snapshot_resumable(&client, &url, temp_dir.path())
+                .await
+                .unwrap();
+
+        let SnapshotData::Packed(path) = snapshot else {
+            panic!("Snapshot must be downloaded as a file");
+        };
+        assert_eq!(fs_err::read(&*path).unwrap(), content);
+        assert_eq!(hash.as_deref(), Some(checksum));
+    }
+
+    #[tokio::test]
+    async fn test_download_without_range_support() {
+        let content = b"0123456789abcdefghij";
+
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/test-shard.snapshot")
+            .with_body(content)
+            .create_async()
+            .await;
+        let url = Url::parse(&format!("{}/test-shard.snapshot", server.url())).unwrap();
+
+        let client = reqwest::Client::new();
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let DownloadResult { snapshot, .. } =
+            download_snapshot_resumable(&client, &url, temp_dir.path())
+                .await
+                .unwrap();
+
+        let SnapshotData::Packed(path) = snapshot else {
+            panic!("Snapshot must be downloaded as a file");
+        };
+        assert_eq!(fs_err::read(&*path).unwrap(), content);
+    }
+
+    #[test]
+    fn test_parse_content_range_total() {
+        assert_eq!(parse_content_range_total("bytes 0-1023/4096"), Some(4096));
+        assert_eq!(parse_content_range_total("bytes */4096"), Some(4096));
+        assert_eq!(parse_content_range_total("bytes 0-1023/*"), None);
+        assert_eq!(parse_content_range_total("0-1023/4096"), None);
+    }
+}
@@ -5,6 +5,7 @@ use std::task::{Context, Poll};
 use std::time::Duration;
 
 use cancel::CancellationToken;
+use collection::common::snapshot_bandwidth::SNAPSHOT_DOWNLOAD_LIMITER;
 use common::tar_unpack::tar_unpack_reader;
 use futures::TryStreamExt;
 use sha2::{Digest, Sha256};
@@ -172,7 +173,13 @@ pub async fn download_and_unpack_tar(
     }
 
     // Convert the response body stream into an AsyncRead with timeout
-    let stream = response.bytes_stream().map_err(std::io::Error::other);
+    let stream = response
+        .bytes_stream()
+        .map_err(std::io::Error::other)
+        .and_then(|chunk| async move {
+            SNAPSHOT_DOWNLOAD_LIMITER.consume(chunk.len()).await;
+            Ok(chunk)
+        });
     let stream_reader = StreamReader::new(stream);
     // Wrap with timeout to detect stalled downloads
     let async_reader = TimeoutReader::new(stream_reader, STREAM_READ_TIMEOUT);
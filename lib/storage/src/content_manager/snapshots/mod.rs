@@ -1,5 +1,6 @@
 pub mod download;
 pub mod download_result;
+pub mod download_resumable;
 pub mod download_tar;
 pub mod recover;
 
@@ -122,7 +123,7 @@ async fn _do_create_full_snapshot(
     let all_collections = toc.multipass_into_collections(&multipass).await;
     let mut created_snapshots: Vec<(&str, SnapshotDescription)> = vec![];
     for collection_pass in &all_collections {
-        let snapshot_details = toc.create_snapshot(collection_pass).await?;
+        let snapshot_details = toc.create_snapshot(collection_pass, None).await?;
         created_snapshots.push((collection_pass.name(), snapshot_details));
     }
     let current_time = chrono::Utc::now().format("%Y-%m-%d-%H-%M-%S").to_string();
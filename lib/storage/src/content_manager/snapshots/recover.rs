@@ -1,4 +1,8 @@
+use std::collections::{HashSet, VecDeque};
+
 use collection::collection::Collection;
+use collection::collection::filter_templates::{FILTER_TEMPLATES_FILE, FilterTemplates};
+use collection::collection::incremental_snapshot::CollectionSnapshotManifest;
 use collection::collection::payload_index_schema::{PAYLOAD_INDEX_CONFIG_FILE, PayloadIndexSchema};
 use collection::common::sha_256::hashes_equal;
 use collection::config::CollectionConfigInternal;
@@ -10,11 +14,13 @@ use collection::shards::replica_set::replica_set_state::{
 };
 use collection::shards::shard::{PeerId, ShardId};
 use common::save_on_disk::SaveOnDisk;
+use common::tempfile_ext::MaybeTempPath;
 use fs_err::tokio as tokio_fs;
+use shard::snapshots::snapshot_data::SnapshotData;
 use shard::snapshots::snapshot_manifest::RecoveryType;
 
 use crate::content_manager::collection_meta_ops::{
-    CollectionMetaOperations, CreateCollectionOperation, CreatePayloadIndex,
+    CollectionMetaOperations, CreateCollectionOperation, CreatePayloadIndex, SetFilterTemplate,
 };
 use crate::content_manager::snapshots::download::download_snapshot;
 use crate::content_manager::snapshots::download_result::DownloadResult;
@@ -22,6 +28,9 @@ use crate::dispatcher::Dispatcher;
 use crate::rbac::{AccessRequirements, Auth, CollectionPass};
 use crate::{StorageError, TableOfContent};
 
+/// Maximum number of base snapshots an incremental snapshot can be chained to
+const MAX_SNAPSHOT_CHAIN_LENGTH: usize = 64;
+
 pub async fn activate_shard(
     toc: &TableOfContent,
     collection: &Collection,
@@ -113,10 +122,6 @@ async fn _do_recover_from_snapshot(
         .running_snapshot_recovery
         .measure_scope();
 
-    let this_peer_id = toc.this_peer_id;
-
-    let is_distributed = toc.is_distributed();
-
     let DownloadResult {
         snapshot: snapshot_data,
         hash: snapshot_hash,
@@ -142,6 +147,72 @@ async fn _do_recover_from_snapshot(
         }
     }
 
+    recover_from_snapshot_data(
+        &dispatcher,
+        &auth,
+        &collection_pass,
+        snapshot_data,
+        None,
+        priority,
+    )
+    .await
+}
+
+/// Recover the existing collection from a snapshot of another collection, e.g. when cloning it.
+///
+/// The collection must have the same vectors and number of shards as the snapshot. Recovered
+/// replicas on this peer become the source of truth for other replicas of the collection.
+///
+/// # Cancel safety
+///
+/// This method is *not* cancel safe.
+pub async fn recover_from_collection_snapshot(
+    dispatcher: &Dispatcher,
+    auth: &Auth,
+    collection_pass: &CollectionPass<'static>,
+    snapshot_data: SnapshotData,
+) -> Result<bool, StorageError> {
+    recover_from_snapshot_data(
+        dispatcher,
+        auth,
+        collection_pass,
+        snapshot_data,
+        None,
+        Some(SnapshotPriority::Snapshot),
+    )
+    .await
+}
+
+/// Recover collection from downloaded snapshot
+///
+/// Incremental snapshot is recovered on top of its base snapshot. The base snapshot must be stored
+/// in snapshots of the recovered collection, and is recovered first. Shards of the incremental
+/// snapshot are then recovered as partial snapshots, replacing only changed segment files and WAL.
+///
+/// The whole chain of base snapshots is verified before any of them is recovered, see
+/// [`verify_snapshot_chain`]. `base_snapshots` are the files of the already verified chain,
+/// `None` if the chain is not verified yet.
+///
+/// # Cancel safety
+///
+/// This method is *not* cancel safe.
+async fn recover_from_snapshot_data(
+    dispatcher: &Dispatcher,
+    auth: &Auth,
+    collection_pass: &CollectionPass<'static>,
+    snapshot_data: SnapshotData,
+    base_snapshots: Option<VecDeque<MaybeTempPath>>,
+    priority: Option<SnapshotPriority>,
+) -> Result<bool, StorageError> {
+    // All checks should've been done at this point.
+    let pass = new_unchecked_verification_pass();
+
+    let toc = dispatcher.toc(auth, &pass);
+
+    let this_peer_id = toc.this_peer_id;
+
+    let is_distributed = toc.is_distributed();
+
     let temp_storage_path = toc.optional_temp_or_storage_temp_path()?;
 
     let tmp_collection_dir = tempfile::Builder::new()
@@ -162,9 +233,53 @@ async fn _do_recover_from_snapshot(
     });
     restoring.await??;
 
+    let snapshot_manifest = CollectionSnapshotManifest::load(tmp_collection_dir.path())?;
+
     let snapshot_config = CollectionConfigInternal::load(tmp_collection_dir.path())?;
     snapshot_config.validate_and_warn();
 
+    let recovery_type = match &snapshot_manifest.base_snapshot {
+        Some(base_snapshot) => {
+            let mut base_snapshots = match base_snapshots {
+                Some(base_snapshots) => base_snapshots,
+                None => {
+                    // Reject the snapshot before the existing collection is replaced by its base
+                    if let Ok(collection) = toc.get_collection(collection_pass).await {
+                        let config = collection.state().await.config;
+                        check_snapshot_config_compatibility(&snapshot_config, &config)?;
+                    }
+                    verify_snapshot_chain(toc, collection_pass.name(), &snapshot_manifest).await?
+                }
+            };
+
+            let base_snapshot_file = base_snapshots.pop_front().ok_or_else(|| {
+                StorageError::service_error(format!(
+                    "base snapshot {base_snapshot} of collection {collection_pass} is not verified",
+                ))
+            })?;
+
+            log::debug!(
+                "Recovering base snapshot {base_snapshot} of incremental snapshot \
+                 for collection {collection_pass}",
+            );
+
+            // Base snapshot is not the final state of the collection, so there is no need to sync
+            // it with other replicas
+            Box::pin(recover_from_snapshot_data(
+                dispatcher,
+                auth,
+                collection_pass,
+                SnapshotData::Packed(base_snapshot_file),
+                Some(base_snapshots),
+                Some(SnapshotPriority::NoSync),
+            ))
+            .await?;
+
+            RecoveryType::Partial
+        }
+        None => RecoveryType::Full,
+    };
+
     let payload_index_file = tmp_collection_dir.path().join(PAYLOAD_INDEX_CONFIG_FILE);
 
     let payload_schema: SaveOnDisk<PayloadIndexSchema> =
@@ -176,7 +291,18 @@ async fn _do_recover_from_snapshot(
 
     let schema = payload_schema.read().schema.clone();
 
-    let collection = match toc.get_collection(&collection_pass).await.ok() {
+    let filter_templates_file = tmp_collection_dir.path().join(FILTER_TEMPLATES_FILE);
+
+    let filter_templates: SaveOnDisk<FilterTemplates> =
+        SaveOnDisk::load_or_init_default(&filter_templates_file).map_err(|err| {
+            StorageError::service_error(format!(
+                "Failed to load filter templates from {filter_templates_file:?}: {err}"
+            ))
+        })?;
+
+    let templates = filter_templates.read().templates.clone();
+
+    let collection = match toc.get_collection(collection_pass).await.ok() {
         Some(collection) => collection,
         None => {
             log::debug!("Collection {collection_pass} does not exist, creating it");
@@ -205,27 +331,25 @@ async fn _do_recover_from_snapshot(
                     .await?;
             }
 
-            toc.get_collection(&collection_pass).await?
+            for (template_name, template) in templates {
+                let consensus_op = CollectionMetaOperations::SetFilterTemplate(SetFilterTemplate {
+                    collection_name: collection_pass.to_string(),
+                    template_name,
+                    template,
+                });
+
+                dispatcher
+                    .submit_collection_meta_op(consensus_op, auth.clone(), None)
+                    .await?;
+            }
+
+            toc.get_collection(collection_pass).await?
         }
     };
 
     let state = collection.state().await;
 
-    // Check config compatibility
-    // Check vectors config
-    if snapshot_config.params.vectors != state.config.params.vectors {
-        return Err(StorageError::bad_input(format!(
-            "Snapshot is not compatible with existing collection: Collection vectors: {:?} Snapshot Vectors: {:?}",
-            state.config.params.vectors, snapshot_config.params.vectors
-        )));
-    }
-    // Check shard number
-    if snapshot_config.params.shard_number != state.config.params.shard_number {
-        return Err(StorageError::bad_input(format!(
-            "Snapshot is not compatible with existing collection: Collection shard number: {:?} Snapshot shard number: {:?}",
-            state.config.params.shard_number, snapshot_config.params.shard_number
-        )));
-    }
+    check_snapshot_config_compatibility(&snapshot_config, &state.config)?;
 
     let is_manual_recovery_state_supported = toc
         .get_channel_service()
@@ -275,7 +399,7 @@ async fn _do_recover_from_snapshot(
         let recovered = collection
             .recover_local_shard_from(
                 &snapshot_shard_path,
-                RecoveryType::Full,
+                recovery_type,
                 *shard_id,
                 cancel::CancellationToken::new(),
             )
@@ -398,3 +522,85 @@ async fn _do_recover_from_snapshot(
 
     Ok(true)
 }
+
+fn check_snapshot_config_compatibility(
+    snapshot_config: &CollectionConfigInternal,
+    config: &CollectionConfigInternal,
+) -> Result<(), StorageError> {
+    // Check vectors config
+    if snapshot_config.params.vectors != config.params.vectors {
+        return Err(StorageError::bad_input(format!(
+            "Snapshot is not compatible with existing collection: Collection vectors: {:?} Snapshot Vectors: {:?}",
+            config.params.vectors, snapshot_config.params.vectors
+        )));
+    }
+    // Check shard number
+    if snapshot_config.params.shard_number != config.params.shard_number {
+        return Err(StorageError::bad_input(format!(
+            "Snapshot is not compatible with existing collection: Collection shard number: {:?} Snapshot shard number: {:?}",
+            config.params.shard_number, snapshot_config.params.shard_number
+        )));
+    }
+    Ok(())
+}
+
+/// Check that all base snapshots of the incremental snapshot are stored, and that each snapshot
+/// in the chain is an increment of the next one, before any of them is recovered.
+///
+/// Returns files of the base snapshots, from the direct base down to the full snapshot.
+async fn verify_snapshot_chain(
+    toc: &TableOfContent,
+    collection_name: &str,
+    manifest: &CollectionSnapshotManifest,
+) -> Result<VecDeque<MaybeTempPath>, StorageError> {
+    let snapshot_manager = toc.get_snapshots_storage_manager()?;
+    let snapshots_path = toc.snapshots_path_for_collection(collection_name);
+    let temp_path = toc.optional_temp_or_storage_temp_path()?;
+
+    let mut base_snapshots = VecDeque::new();
+    let mut visited = HashSet::new();
+    let mut increment = manifest.clone();
+
+    while let Some(base_snapshot) = increment.base_snapshot.take() {
+        if base_snapshots.len() >= MAX_SNAPSHOT_CHAIN_LENGTH
+            || !visited.insert(base_snapshot.clone())
+        {
+            return Err(StorageError::bad_input(format!(
+                "Chain of base snapshots of collection {collection_name} is cyclic \
+                 or longer than {MAX_SNAPSHOT_CHAIN_LENGTH} snapshots",
+            )));
+        }
+
+        let base_snapshot_path =
+            snapshot_manager.get_snapshot_path(&snapshots_path, &base_snapshot)?;
+        let base_snapshot_file = snapshot_manager
+            .get_snapshot_file(&base_snapshot_path, &temp_path)
+            .await?;
+
+        let base_snapshot_file_path = base_snapshot_file.to_path_buf();
+        let base_manifest = tokio::task::spawn_blocking(move || {
+            CollectionSnapshotManifest::read_from_archive(&base_snapshot_file_path)
+        })
+        .await??
+        .ok_or_else(|| {
+            StorageError::bad_input(format!(
+                "Base snapshot {base_snapshot} of collection {collection_name} has no snapshot manifest",
+            ))
+        })?;
+
+        if let Some(shard_id) = increment
+            .shards
+            .keys()
+            .find(|shard_id| !base_manifest.shards.contains_key(shard_id))
+        {
+            return Err(StorageError::bad_input(format!(
+                "Base snapshot {base_snapshot} of collection {collection_name} has no shard {shard_id}",
+            )));
+        }
+
+        base_snapshots.push_back(base_snapshot_file);
+        increment = base_manifest;
+    }
+
+    Ok(base_snapshots)
+}
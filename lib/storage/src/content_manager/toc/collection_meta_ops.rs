@@ -122,6 +122,18 @@ impl TableOfContent {
                     .await
                     .map(|()| true)
             }
+            CollectionMetaOperations::SetFilterTemplate(set_filter_template) => {
+                log::debug!("Set filter template {set_filter_template:?}");
+                self.set_filter_template(set_filter_template)
+                    .await
+                    .map(|()| true)
+            }
+            CollectionMetaOperations::DeleteFilterTemplate(delete_filter_template) => {
+                log::debug!("Delete filter template {delete_filter_template:?}");
+                self.delete_filter_template(delete_filter_template)
+                    .await
+                    .map(|()| true)
+            }
             #[cfg(feature = "staging")]
             CollectionMetaOperations::TestSlowDown(test_slow_down) => {
                 test_slow_down.execute(self.this_peer_id).await;
@@ -320,6 +332,33 @@ impl TableOfContent {
                 }) => {
                     alias_lock.rename_alias(&old_alias_name, new_alias_name)?;
                 }
+                AliasOperations::SwitchAliasGroup(SwitchAliasGroupOperation {
+                    switch_alias_group: SwitchAliasGroup { aliases },
+                }) => {
+                    // Validate the whole group first, so that it is never switched partially
+                    let mut alias_names = HashSet::with_capacity(aliases.len());
+                    for CreateAlias {
+                        collection_name,
+                        alias_name,
+                    } in &aliases
+                    {
+                        if !alias_names.insert(alias_name.as_str()) {
+                            return Err(StorageError::bad_input(format!(
+                                "Alias {alias_name} is listed more than once in alias group",
+                            )));
+                        }
+                        collection_lock.validate_collection_exists(collection_name)?;
+                        collection_lock.validate_collection_not_exists(alias_name)?;
+                    }
+
+                    log::debug!("Switching alias group of {} aliases", aliases.len());
+                    alias_lock.insert_many(aliases.into_iter().map(
+                        |CreateAlias {
+                             collection_name,
+                             alias_name,
+                         }| (alias_name, collection_name),
+                    ))?;
+                }
             };
         }
         Ok(true)
@@ -665,7 +704,12 @@ impl TableOfContent {
 
         self.get_collection_unchecked(&operation.collection_name)
             .await?
-            .create_shard_key(operation.shard_key, operation.placement, init_state)
+            .create_shard_key(
+                operation.shard_key,
+                operation.placement,
+                init_state,
+                operation.config,
+            )
             .await?;
 
         Ok(())
@@ -689,8 +733,12 @@ impl TableOfContent {
             self.get_collection_hw_metrics(operation.collection_name.clone()),
         );
 
-        self.get_collection_unchecked(&operation.collection_name)
-            .await?
+        let collection = self
+            .get_collection_unchecked(&operation.collection_name)
+            .await?;
+        collection.check_read_only().await?;
+
+        collection
             .create_payload_index(
                 operation.field_name.clone(),
                 operation.field_schema,
@@ -708,10 +756,29 @@ impl TableOfContent {
     }
 
     async fn drop_payload_index(&self, operation: DropPayloadIndex) -> Result<(), StorageError> {
+        let collection = self
+            .get_collection_unchecked(&operation.collection_name)
+            .await?;
+        collection.check_read_only().await?;
+
+        collection.drop_payload_index(operation.field_name).await?;
+        Ok(())
+    }
+
+    async fn set_filter_template(&self, operation: SetFilterTemplate) -> Result<(), StorageError> {
         self.get_collection_unchecked(&operation.collection_name)
             .await?
-            .drop_payload_index(operation.field_name)
-            .await?;
+            .set_filter_template(operation.template_name, operation.template)?;
+        Ok(())
+    }
+
+    async fn delete_filter_template(
+        &self,
+        operation: DeleteFilterTemplate,
+    ) -> Result<(), StorageError> {
+        self.get_collection_unchecked(&operation.collection_name)
+            .await?
+            .delete_filter_template(&operation.template_name)?;
         Ok(())
     }
 }
@@ -0,0 +1,58 @@
+use super::TableOfContent;
+use crate::content_manager::errors::StorageError;
+use crate::types::{CpuPools, CpuWeightsConfig};
+
+impl TableOfContent {
+    /// Current weights of search and optimization CPU pools, if configured
+    pub fn cpu_weights(&self) -> Option<CpuWeightsConfig> {
+        *self.cpu_weights.lock()
+    }
+
+    /// Current size of search and optimization CPU pools
+    pub fn cpu_pools(&self) -> CpuPools {
+        CpuPools {
+            search: self.storage_config.performance.search_thread_count(),
+            optimization: self.optimizer_resource_budget.available_cpu_budget(),
+        }
+    }
+
+    /// Whether the search runtime would be sized differently with the current weights.
+    ///
+    /// The search runtime is sized on startup, from the weights in the config file.
+    pub fn search_pool_restart_required(&self) -> bool {
+        let mut performance = self.storage_config.performance.clone();
+        performance.cpu_weights = self.cpu_weights();
+        performance.search_thread_count() != self.cpu_pools().search
+    }
+
+    /// Change weights of search and optimization CPU pools of this peer.
+    ///
+    /// Only the budget for new optimizations is changed: running optimizations keep their
+    /// permits until they finish. The size of the search runtime can't be changed without a
+    /// restart.
+    ///
+    /// Fails if `optimizer_cpu_budget` is set explicitly, as weights don't apply to it.
+    pub fn set_cpu_weights(
+        &self,
+        cpu_weights: Option<CpuWeightsConfig>,
+    ) -> Result<CpuPools, StorageError> {
+        let mut performance = self.storage_config.performance.clone();
+        if performance.optimizer_cpu_budget != 0 {
+            return Err(StorageError::bad_request(format!(
+                "CPU weights can't be changed, optimizer_cpu_budget is set to {}",
+                performance.optimizer_cpu_budget,
+            )));
+        }
+
+        let mut current = self.cpu_weights.lock();
+
+        performance.cpu_weights = cpu_weights;
+        self.optimizer_resource_budget
+            .set_cpu_budget(performance.optimizer_cpu_budget());
+        *current = cpu_weights;
+
+        log::debug!("CPU weights changed to {cpu_weights:?}");
+
+        Ok(self.cpu_pools())
+    }
+}
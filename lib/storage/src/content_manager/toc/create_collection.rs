@@ -8,6 +8,9 @@ use collection::operations::types::{CollectionResult, VectorsConfig};
 use collection::shards::collection_shard_distribution::CollectionShardDistribution;
 use collection::shards::replica_set::replica_set_state::ReplicaState;
 use collection::shards::shard::{PeerId, ShardId};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::data_types::index::KeywordIndexParams;
+use segment::types::{PayloadFieldSchema, PayloadSchemaParams};
 
 use super::{COLLECTION_DELETE_SPIN_INTERVAL, COLLECTION_DELETE_WAIT_TIMEOUT, TableOfContent};
 use crate::common::utils::try_unwrap_with_timeout_async;
@@ -43,6 +46,7 @@ impl TableOfContent {
             strict_mode_config,
             uuid,
             metadata,
+            tenant_field,
         } = operation;
 
         {
@@ -148,7 +152,18 @@ impl TableOfContent {
             )?,
             read_fan_out_factor: None,
             read_fan_out_delay_ms: None,
+            read_routing: None,
+            payload_overflow_threshold: None,
+            duplicate_detection: None,
+            strict_payload_schema: None,
+            ingest_payload: None,
+            read_only: None,
+            trash_retention_sec: None,
+            points_expiration: None,
+            shard_key_configs: Vec::new(),
         };
+        collection_params.check_storage_backends()?;
+
         let wal_config = self.storage_config.wal.update_opt(wal_config_diff.as_ref());
 
         let optimizer_config = self
@@ -170,6 +185,8 @@ impl TableOfContent {
             Some(diff) => Some(diff),
         };
 
+        collection_params.check_quantization(quantization_config.as_ref())?;
+
         let strict_mode_config = match strict_mode_config {
             Some(diff) => {
                 let default_config = self
@@ -239,6 +256,22 @@ impl TableOfContent {
 
         collection.print_warnings().await;
 
+        if let Some(tenant_field) = tenant_field {
+            let tenant_index_schema =
+                PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(KeywordIndexParams {
+                    is_tenant: Some(true),
+                    ..Default::default()
+                }));
+            let hw_acc = HwMeasurementAcc::new_with_metrics_drain(
+                self.get_collection_hw_metrics(collection_name.to_string()),
+            );
+            collection
+                .create_payload_index(tenant_field, tenant_index_schema, hw_acc)
+                .await?;
+        }
+
+        collection.create_trash_index().await?;
+
         let local_shards = collection.get_local_shards().await;
 
         {
@@ -1,10 +1,12 @@
 mod collection_container;
 mod collection_meta_ops;
+mod cpu_pools;
 mod create_collection;
 pub mod dispatcher;
 mod point_ops;
 mod point_ops_internal;
 pub mod request_hw_counter;
+mod search_degradation;
 mod snapshots;
 mod telemetry;
 mod temp_directories;
@@ -41,6 +43,7 @@ use tokio::runtime::{Handle, Runtime};
 use tokio::sync::{Mutex, RwLock, Semaphore};
 
 use self::dispatcher::TocDispatcher;
+use self::search_degradation::SearchesInFlight;
 use crate::ConsensusOperations;
 use crate::content_manager::alias_mapping::AliasPersistence;
 use crate::content_manager::collection_meta_ops::CreateCollectionOperation;
@@ -50,7 +53,7 @@ use crate::content_manager::errors::StorageError;
 use crate::content_manager::shard_distribution::ShardDistributionProposal;
 use crate::content_manager::toc::telemetry::TocTelemetryCollector;
 use crate::rbac::{Access, AccessRequirements, CollectionMultipass, CollectionPass};
-use crate::types::StorageConfig;
+use crate::types::{CpuWeightsConfig, StorageConfig};
 
 pub const ALIASES_PATH: &str = "aliases";
 pub const COLLECTIONS_DIR: &str = "collections";
@@ -73,6 +76,8 @@ pub struct TableOfContent {
     /// Global CPU budget in number of cores for all optimization tasks.
     /// Assigns CPU permits to tasks to limit overall resource utilization.
     optimizer_resource_budget: ResourceBudget,
+    /// Current weights of search and optimization CPU pools, may be changed at runtime.
+    cpu_weights: parking_lot::Mutex<Option<CpuWeightsConfig>>,
     alias_persistence: RwLock<AliasPersistence>,
     pub this_peer_id: PeerId,
     channel_service: ChannelService,
@@ -86,6 +91,8 @@ pub struct TableOfContent {
     ///
     /// If not defined - no rate limiting is applied.
     update_rate_limiter: Option<Semaphore>,
+    /// Searches executed on behalf of clients, used to degrade searches when overloaded.
+    searches_in_flight: SearchesInFlight,
     /// A lock to prevent concurrent collection creation.
     /// Effectively, this lock ensures that `create_collection` is called sequentially.
     collection_create_lock: Mutex<()>,
@@ -233,12 +240,14 @@ impl TableOfContent {
             update_runtime,
             general_runtime,
             optimizer_resource_budget,
+            cpu_weights: parking_lot::Mutex::new(storage_config.performance.cpu_weights),
             alias_persistence: RwLock::new(alias_persistence),
             this_peer_id,
             channel_service,
             consensus_proposal_sender,
             toc_dispatcher: Default::default(),
             update_rate_limiter: rate_limiter,
+            searches_in_flight: Default::default(),
             collection_create_lock: Default::default(),
             collection_hw_metrics: DashMap::new(),
             telemetry,
@@ -533,6 +542,26 @@ impl TableOfContent {
         false
     }
 
+    /// Delete expired points of all collections, which have points expiration enabled, and
+    /// points which trash retention period has passed
+    pub async fn delete_expired_points(&self) {
+        let collections: Vec<_> = self.collections.read().await.values().cloned().collect();
+        for collection in collections {
+            if let Err(err) = collection.delete_expired_points().await {
+                log::warn!(
+                    "Failed to delete expired points of collection {}: {err}",
+                    collection.name(),
+                );
+            }
+            if let Err(err) = collection.purge_trash().await {
+                log::warn!(
+                    "Failed to purge trash of collection {}: {err}",
+                    collection.name(),
+                );
+            }
+        }
+    }
+
     /// Cancels all transfers related to the current peer.
     ///
     /// Transfers whehre this peer is the source or the target will be cancelled.
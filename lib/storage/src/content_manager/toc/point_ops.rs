@@ -1,9 +1,17 @@
 use std::time::Duration;
 
 use collection::collection::Collection;
+use collection::collection::copy::{CollectionCopyRequest, CollectionCopyResponse};
 use collection::collection::distance_matrix::{
     CollectionSearchMatrixRequest, CollectionSearchMatrixResponse,
 };
+use collection::collection::join::{CollectionJoinRequest, CollectionJoinResponse};
+use collection::collection::partial_results::{SkippedShards, TimedOut};
+use collection::collection::rate_limiting::OperationClass;
+use collection::collection::sample_projection::{
+    CollectionSampleProjectionRequest, CollectionSampleProjectionResponse,
+};
+use collection::collection::search_explanations::ExplainedShards;
 use collection::config::ShardingMethod;
 use collection::grouping::GroupBy;
 use collection::grouping::group_by::GroupRequest;
@@ -17,16 +25,17 @@ use collection::{discovery, recommendations};
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::TryStreamExt as _;
 use futures::stream::FuturesUnordered;
-use segment::data_types::facets::{FacetParams, FacetResponse};
-use segment::types::{ScoredPoint, ShardKey};
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
+use segment::data_types::facets::{FacetParams, FacetResponse, MultiFacetParams};
+use segment::types::{PointIdType, ScoredPoint, ShardKey};
 use shard::retrieve::record_internal::RecordInternal;
 use shard::scroll::ScrollRequestInternal;
 use shard::search::CoreSearchRequestBatch;
 
 use super::TableOfContent;
 use crate::content_manager::errors::{StorageError, StorageResult};
-use crate::rbac::Auth;
 use crate::rbac::auditable_operation::AuditableOperation;
+use crate::rbac::{AccessRequirements, Auth};
 
 impl TableOfContent {
     /// Recommend points using positive and negative example from the request
@@ -43,7 +52,7 @@ impl TableOfContent {
     pub async fn recommend(
         &self,
         collection_name: &str,
-        request: RecommendRequestInternal,
+        mut request: RecommendRequestInternal,
         read_consistency: Option<ReadConsistency>,
         shard_selector: ShardSelectorInternal,
         auth: Auth,
@@ -53,6 +62,15 @@ impl TableOfContent {
         let collection_pass = auth.check_point_op(collection_name, &request, "recommend")?;
 
         let collection = self.get_collection(&collection_pass).await?;
+        // Only rate limit and degrade on the first node in the chain
+        if !shard_selector.is_shard_id() {
+            collection
+                .check_rate_limit(OperationClass::Search, 1)
+                .await?;
+            self.degrade_recommend_requests(&collection, [&mut request])
+                .await;
+        }
+        let _searches_in_flight = self.searches_in_flight.track(1);
         recommendations::recommend_by(
             request,
             &collection,
@@ -95,6 +113,15 @@ impl TableOfContent {
         };
 
         let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .check_rate_limit(OperationClass::Search, requests.len())
+            .await?;
+        self.degrade_recommend_requests(
+            &collection,
+            requests.iter_mut().map(|(request, _)| request),
+        )
+        .await;
+        let _searches_in_flight = self.searches_in_flight.track(requests.len());
         recommendations::recommend_batch_by(
             requests,
             &collection,
@@ -142,6 +169,15 @@ impl TableOfContent {
         };
 
         let collection = self.get_collection(&collection_pass).await?;
+        // Only rate limit and degrade on the first node in the chain
+        if !shard_selection.is_shard_id() {
+            collection
+                .check_rate_limit(OperationClass::Search, request.searches.len())
+                .await?;
+            self.degrade_core_search_requests(&collection, &mut request.searches)
+                .await;
+        }
+        let _searches_in_flight = self.searches_in_flight.track(request.searches.len());
         collection
             .core_search_batch(
                 request,
@@ -243,7 +279,14 @@ impl TableOfContent {
         let collection_pass = auth.check_point_op(collection_name, &request, "group")?;
 
         let collection = self.get_collection(&collection_pass).await?;
+        // Only rate limit on the first node in the chain
+        if !shard_selection.is_shard_id() {
+            collection
+                .check_rate_limit(OperationClass::Search, 1)
+                .await?;
+        }
 
+        let _searches_in_flight = self.searches_in_flight.track(1);
         let collection_by_name = |name| self.get_collection_opt(name);
 
         let group_by = GroupBy::new(request, &collection, collection_by_name, hw_measurement_acc)
@@ -262,7 +305,7 @@ impl TableOfContent {
     pub async fn discover(
         &self,
         collection_name: &str,
-        request: DiscoverRequestInternal,
+        mut request: DiscoverRequestInternal,
         read_consistency: Option<ReadConsistency>,
         shard_selector: ShardSelectorInternal,
         auth: Auth,
@@ -272,6 +315,15 @@ impl TableOfContent {
         let collection_pass = auth.check_point_op(collection_name, &request, "discover")?;
 
         let collection = self.get_collection(&collection_pass).await?;
+        // Only rate limit and degrade on the first node in the chain
+        if !shard_selector.is_shard_id() {
+            collection
+                .check_rate_limit(OperationClass::Search, 1)
+                .await?;
+            self.degrade_discover_requests(&collection, [&mut request])
+                .await;
+        }
+        let _searches_in_flight = self.searches_in_flight.track(1);
         discovery::discover(
             request,
             &collection,
@@ -304,6 +356,15 @@ impl TableOfContent {
         };
 
         let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .check_rate_limit(OperationClass::Search, requests.len())
+            .await?;
+        self.degrade_discover_requests(
+            &collection,
+            requests.iter_mut().map(|(request, _)| request),
+        )
+        .await;
+        let _searches_in_flight = self.searches_in_flight.track(requests.len());
 
         discovery::discover_batch(
             requests,
@@ -342,6 +403,12 @@ impl TableOfContent {
         let collection_pass = auth.check_point_op(collection_name, &request, "scroll")?;
 
         let collection = self.get_collection(&collection_pass).await?;
+        // Only rate limit on the first node in the chain
+        if !shard_selection.is_shard_id() {
+            collection
+                .check_rate_limit(OperationClass::Scroll, 1)
+                .await?;
+        }
         collection
             .scroll_by(
                 request,
@@ -354,6 +421,15 @@ impl TableOfContent {
             .map_err(|err| err.into())
     }
 
+    /// If `skipped_shards` is given, shards which fail are skipped and recorded there, results
+    /// of the other shards are returned.
+    ///
+    /// If `explained` is given, explanations of how segments executed the searches are recorded
+    /// there.
+    ///
+    /// If `timed_out` is given, searches which allow partial results return results found so far
+    /// at the deadline, and are marked as timed out there.
+    #[allow(clippy::too_many_arguments)]
     pub async fn query_batch(
         &self,
         collection_name: &str,
@@ -361,6 +437,9 @@ impl TableOfContent {
         read_consistency: Option<ReadConsistency>,
         auth: Auth,
         timeout: Option<Duration>,
+        skipped_shards: Option<&SkippedShards>,
+        explained: Option<&ExplainedShards>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> StorageResult<Vec<Vec<ScoredPoint>>> {
         let mut collection_pass = None;
@@ -373,6 +452,10 @@ impl TableOfContent {
         };
 
         let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .check_rate_limit(OperationClass::Search, requests.len())
+            .await?;
+        let _searches_in_flight = self.searches_in_flight.track(requests.len());
 
         collection
             .query_batch(
@@ -380,6 +463,9 @@ impl TableOfContent {
                 |name| self.get_collection_opt(name),
                 read_consistency,
                 timeout,
+                skipped_shards,
+                explained,
+                timed_out,
                 hw_measurement_acc,
             )
             .await
@@ -414,6 +500,62 @@ impl TableOfContent {
             .map_err(StorageError::from)
     }
 
+    // Return unique values and their counts for several payload keys, sharing the same filter.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn facets(
+        &self,
+        collection_name: &str,
+        request: MultiFacetParams,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<Vec<FacetResponse>> {
+        let collection_pass = auth.check_point_op(collection_name, &request, "facet_multi")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        collection
+            .facets(
+                request,
+                shard_selection,
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
+    // Return aggregates over values of a payload key, for points matching the filter.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn aggregate(
+        &self,
+        collection_name: &str,
+        request: AggregateParams,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<AggregateResult> {
+        let collection_pass = auth.check_point_op(collection_name, &request, "aggregate")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        collection
+            .aggregate(
+                request,
+                shard_selection,
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn search_points_matrix(
         &self,
@@ -442,6 +584,130 @@ impl TableOfContent {
             .map_err(StorageError::from)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn sample_projection(
+        &self,
+        collection_name: &str,
+        request: CollectionSampleProjectionRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> Result<CollectionSampleProjectionResponse, StorageError> {
+        let collection_pass =
+            auth.check_point_op(collection_name, &request, "sample_projection")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        collection
+            .sample_projection(
+                request,
+                shard_selection,
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
+    /// Find nearest neighbors in the target collection for each point of the collection.
+    ///
+    /// Access to the target collection is checked along with the request.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn join(
+        &self,
+        collection_name: &str,
+        request: CollectionJoinRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> Result<CollectionJoinResponse, StorageError> {
+        let collection_pass = auth.check_point_op(collection_name, &request, "join")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        let target = self
+            .get_collection_unchecked(&request.target_collection)
+            .await?;
+
+        collection
+            .join(
+                &target,
+                request,
+                shard_selection,
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
+    /// Copy points of the collection into the target collection.
+    ///
+    /// Requires read access to the source collection, and write access to the target collection.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn copy_points(
+        &self,
+        collection_name: &str,
+        request: CollectionCopyRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> Result<CollectionCopyResponse, StorageError> {
+        let collection_pass = auth.check_point_op(collection_name, &request, "copy_points")?;
+        let target_pass = auth.check_collection_access(
+            &request.target_collection,
+            AccessRequirements::new().write(),
+            "copy_points",
+        )?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        let target = self.get_collection(&target_pass).await?;
+
+        collection
+            .copy_points(
+                &target,
+                request,
+                shard_selection,
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
+    /// Restore points from the trash of the collection.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn restore_points(
+        &self,
+        collection_name: &str,
+        ids: Vec<PointIdType>,
+        wait: bool,
+        timeout: Option<Duration>,
+        ordering: WriteOrdering,
+        auth: Auth,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<UpdateResult> {
+        let collection_pass = auth.check_collection_access(
+            collection_name,
+            AccessRequirements::new().write(),
+            "restore_points",
+        )?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .restore_points(ids, wait, timeout, ordering, hw_measurement_acc)
+            .await
+            .map_err(StorageError::from)
+    }
+
     /// # Cancel safety
     ///
     /// This method is cancel safe.
@@ -548,6 +814,12 @@ impl TableOfContent {
             None => None,
         };
 
+        if !shard_selector.is_shard_id() {
+            collection
+                .check_rate_limit(OperationClass::Update, 1)
+                .await?;
+        }
+
         // TODO: `debug_assert(operation.clock_tag.is_none())` for `_update_shard_keys`/`update_from_client`!?
 
         let res = match shard_selector {
@@ -574,6 +846,7 @@ impl TableOfContent {
                             return Ok(UpdateResult {
                                 operation_id: None,
                                 status: UpdateStatus::Acknowledged,
+                                affected_points: None,
                                 clock_tag: operation.clock_tag,
                             });
                         }
@@ -667,4 +940,63 @@ impl TableOfContent {
 
         Ok(res)
     }
+
+    /// Apply operations targeting different shard keys as a single update, see
+    /// [`Collection::update_from_client_by_shard_keys`].
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_by_shard_keys(
+        &self,
+        collection_name: &str,
+        operations: Vec<(ShardKey, CollectionUpdateOperations)>,
+        wait: bool,
+        timeout: Option<Duration>,
+        ordering: WriteOrdering,
+        auth: Auth,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<UpdateResult> {
+        let mut collection_pass = None;
+        for (_, operation) in &operations {
+            collection_pass = Some(auth.check_point_op(
+                collection_name,
+                operation,
+                operation.operation_name(),
+            )?);
+        }
+
+        let Some(collection_pass) = collection_pass else {
+            return Err(StorageError::bad_input("Empty shard keys selection"));
+        };
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        let _update_rate_limiter = match &self.update_rate_limiter {
+            Some(update_rate_limiter) => Some(update_rate_limiter.acquire().await),
+            None => None,
+        };
+
+        collection
+            .check_rate_limit(OperationClass::Update, 1)
+            .await?;
+
+        let operations = operations
+            .into_iter()
+            .map(|(shard_key, operation)| (Some(shard_key), operation))
+            .collect();
+
+        let result = collection
+            .update_from_client_by_shard_keys(
+                operations,
+                wait,
+                timeout,
+                ordering,
+                hw_measurement_acc,
+            )
+            .await?;
+
+        Ok(result)
+    }
 }
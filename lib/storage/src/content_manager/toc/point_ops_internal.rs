@@ -2,11 +2,14 @@
 
 use std::time::Duration;
 
+use collection::collection::partial_results::TimedOut;
+use collection::collection::search_explanations::ExplainedShards;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::UpdateResult;
 use collection::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
 use collection::shards::shard::ShardId;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
 use segment::data_types::facets::{FacetParams, FacetResponse};
 
 use super::TableOfContent;
@@ -20,12 +23,21 @@ impl TableOfContent {
         requests: Vec<ShardQueryRequest>,
         shard_selection: ShardSelectorInternal,
         timeout: Option<Duration>,
+        explained: Option<&ExplainedShards>,
+        timed_out: Option<&TimedOut>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> StorageResult<Vec<ShardQueryResponse>> {
         let collection = self.get_collection_unchecked(collection_name).await?;
 
         let res = collection
-            .query_batch_internal(requests, &shard_selection, timeout, hw_measurement_acc)
+            .query_batch_internal(
+                requests,
+                &shard_selection,
+                timeout,
+                explained,
+                timed_out,
+                hw_measurement_acc,
+            )
             .await?;
 
         Ok(res)
@@ -48,6 +60,23 @@ impl TableOfContent {
         Ok(res)
     }
 
+    pub async fn aggregate_internal(
+        &self,
+        collection_name: &str,
+        request: AggregateParams,
+        shard_selection: ShardSelectorInternal,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<AggregateResult> {
+        let collection = self.get_collection_unchecked(collection_name).await?;
+
+        let res = collection
+            .aggregate(request, shard_selection, None, timeout, hw_measurement_acc)
+            .await?;
+
+        Ok(res)
+    }
+
     pub async fn cleanup_local_shard(
         &self,
         collection_name: &str,
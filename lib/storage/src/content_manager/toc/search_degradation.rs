@@ -0,0 +1,270 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use collection::collection::Collection;
+use collection::operations::types::{
+    DiscoverRequestInternal, RecommendRequestInternal, UsingVector,
+};
+use collection::operations::universal_query::collection_query::{
+    CollectionPrefetch, CollectionQueryRequest,
+};
+use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+use segment::types::{QuantizationSearchParams, SearchParams, VectorName};
+use shard::search::CoreSearchRequest;
+
+use super::TableOfContent;
+use crate::types::SearchDegradationConfig;
+
+/// Number of searches being executed by this peer, on behalf of its clients
+#[derive(Debug, Default)]
+pub(super) struct SearchesInFlight(AtomicUsize);
+
+/// Keeps searches counted in [`SearchesInFlight`], until dropped
+pub(super) struct SearchesInFlightGuard<'a> {
+    in_flight: &'a SearchesInFlight,
+    count: usize,
+}
+
+impl SearchesInFlight {
+    fn count(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn track(&self, count: usize) -> SearchesInFlightGuard<'_> {
+        self.0.fetch_add(count, Ordering::Relaxed);
+        SearchesInFlightGuard {
+            in_flight: self,
+            count,
+        }
+    }
+}
+
+impl Drop for SearchesInFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.in_flight.0.fetch_sub(self.count, Ordering::Relaxed);
+    }
+}
+
+impl TableOfContent {
+    /// Check if this peer is overloaded with searches according to the configured degradation
+    /// policy.
+    fn search_degradation(&self) -> Option<SearchDegradationConfig> {
+        let config = self.storage_config.performance.search_degradation?;
+        let in_flight = self.searches_in_flight.count();
+        (in_flight > config.max_searches_in_flight).then_some(config)
+    }
+
+    /// Lower search effort of the queries if this peer is overloaded.
+    ///
+    /// Returns `true` if queries were degraded, so that responses can be marked accordingly.
+    pub async fn degrade_query_requests<'a>(
+        &self,
+        collection_name: &str,
+        requests: impl IntoIterator<Item = &'a mut CollectionQueryRequest>,
+    ) -> bool {
+        if self.search_degradation().is_none() {
+            return false;
+        }
+        let Ok(collection) = self.get_collection_unchecked(collection_name).await else {
+            return false;
+        };
+
+        let mut searches = Vec::new();
+        for request in requests {
+            searches.push((request.using.as_str(), &mut request.params));
+            for prefetch in &mut request.prefetch {
+                prefetch_searches(prefetch, &mut searches);
+            }
+        }
+
+        self.degrade_searches(&collection, searches).await
+    }
+
+    pub(super) async fn degrade_core_search_requests<'a>(
+        &self,
+        collection: &Collection,
+        requests: impl IntoIterator<Item = &'a mut CoreSearchRequest>,
+    ) -> bool {
+        let searches = requests.into_iter().map(|request| {
+            let CoreSearchRequest { query, params, .. } = request;
+            (query.get_vector_name(), params)
+        });
+        self.degrade_searches(collection, searches).await
+    }
+
+    pub(super) async fn degrade_recommend_requests<'a>(
+        &self,
+        collection: &Collection,
+        requests: impl IntoIterator<Item = &'a mut RecommendRequestInternal>,
+    ) -> bool {
+        let searches = requests.into_iter().map(|request| {
+            let RecommendRequestInternal { using, params, .. } = request;
+            (using_vector_name(using.as_ref()), params)
+        });
+        self.degrade_searches(collection, searches).await
+    }
+
+    pub(super) async fn degrade_discover_requests<'a>(
+        &self,
+        collection: &Collection,
+        requests: impl IntoIterator<Item = &'a mut DiscoverRequestInternal>,
+    ) -> bool {
+        let searches = requests.into_iter().map(|request| {
+            let DiscoverRequestInternal { using, params, .. } = request;
+            (using_vector_name(using.as_ref()), params)
+        });
+        self.degrade_searches(collection, searches).await
+    }
+
+    /// Lower search effort of `searches` of the given vectors, if this peer is overloaded
+    async fn degrade_searches<'a>(
+        &self,
+        collection: &Collection,
+        searches: impl IntoIterator<Item = (&'a VectorName, &'a mut Option<SearchParams>)>,
+    ) -> bool {
+        let Some(config) = self.search_degradation() else {
+            return false;
+        };
+
+        let mut degraded = false;
+        for (vector_name, params) in searches {
+            let default_hnsw_ef = collection.default_hnsw_ef(vector_name).await;
+            degraded |= degrade_search_params(params, &config, default_hnsw_ef);
+        }
+        degraded
+    }
+}
+
+fn using_vector_name(using: Option<&UsingVector>) -> &VectorName {
+    match using {
+        Some(UsingVector::Name(name)) => name,
+        None => DEFAULT_VECTOR_NAME,
+    }
+}
+
+fn prefetch_searches<'a>(
+    prefetch: &'a mut CollectionPrefetch,
+    searches: &mut Vec<(&'a VectorName, &'a mut Option<SearchParams>)>,
+) {
+    let CollectionPrefetch {
+        prefetch,
+        using,
+        params,
+        ..
+    } = prefetch;
+
+    searches.push((using.as_str(), params));
+    for prefetch in prefetch {
+        prefetch_searches(prefetch, searches);
+    }
+}
+
+/// Reduce `hnsw_ef` and oversampling down to the configured floors.
+///
+/// `default_hnsw_ef` is used by searches, which don't set `hnsw_ef`. Searches are never made more
+/// expensive, and exact searches are left intact, as there is no cheaper alternative for them.
+fn degrade_search_params(
+    params: &mut Option<SearchParams>,
+    config: &SearchDegradationConfig,
+    default_hnsw_ef: usize,
+) -> bool {
+    let mut degraded_params = params.unwrap_or_default();
+    if degraded_params.exact {
+        return false;
+    }
+
+    let mut degraded = false;
+
+    let hnsw_ef = degraded_params.hnsw_ef.unwrap_or(default_hnsw_ef);
+    if hnsw_ef > config.min_hnsw_ef {
+        degraded_params.hnsw_ef = Some(config.min_hnsw_ef);
+        degraded = true;
+    }
+
+    // Don't let dynamic ef grow the beam back
+    if degraded_params.max_hnsw_ef.is_some() {
+        degraded_params.max_hnsw_ef = None;
+        degraded = true;
+    }
+
+    if let Some(min_oversampling) = config.min_oversampling
+        && let Some(QuantizationSearchParams {
+            oversampling: Some(oversampling),
+            ..
+        }) = &mut degraded_params.quantization
+        && *oversampling > min_oversampling
+    {
+        *oversampling = min_oversampling;
+        degraded = true;
+    }
+
+    if degraded {
+        *params = Some(degraded_params);
+    }
+    degraded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_degrade_search_params() {
+        let config = SearchDegradationConfig {
+            max_searches_in_flight: 1,
+            min_hnsw_ef: 32,
+            min_oversampling: Some(1.5),
+        };
+
+        let mut params = None;
+        assert!(degrade_search_params(&mut params, &config, 100));
+        assert_eq!(params.unwrap().hnsw_ef, Some(32));
+
+        // Default is already cheap enough, it is not raised
+        let mut params = None;
+        assert!(!degrade_search_params(&mut params, &config, 16));
+        assert_eq!(params, None);
+
+        let mut params = Some(SearchParams {
+            hnsw_ef: Some(256),
+            quantization: Some(QuantizationSearchParams {
+                oversampling: Some(3.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        assert!(degrade_search_params(&mut params, &config, 16));
+        let params = params.unwrap();
+        assert_eq!(params.hnsw_ef, Some(32));
+        assert_eq!(params.quantization.unwrap().oversampling, Some(1.5));
+
+        // Already cheap enough
+        let mut params = Some(SearchParams {
+            hnsw_ef: Some(16),
+            ..Default::default()
+        });
+        assert!(!degrade_search_params(&mut params, &config, 100));
+        assert_eq!(params.unwrap().hnsw_ef, Some(16));
+
+        // Exact search is not degraded
+        let mut params = Some(SearchParams {
+            exact: true,
+            ..Default::default()
+        });
+        assert!(!degrade_search_params(&mut params, &config, 100));
+        assert_eq!(params.unwrap().hnsw_ef, None);
+    }
+
+    #[test]
+    fn test_searches_in_flight() {
+        let in_flight = SearchesInFlight::default();
+
+        let batch = in_flight.track(3);
+        let single = in_flight.track(1);
+        assert_eq!(in_flight.count(), 4);
+
+        drop(batch);
+        assert_eq!(in_flight.count(), 1);
+        drop(single);
+        assert_eq!(in_flight.count(), 0);
+    }
+}
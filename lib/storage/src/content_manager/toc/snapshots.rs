@@ -53,6 +53,7 @@ impl TableOfContent {
     pub async fn create_snapshot(
         &self,
         collection_pass: &CollectionPass<'_>,
+        base_snapshot: Option<&str>,
     ) -> Result<SnapshotDescription, StorageError> {
         // Increment snapshot telemetry/mertic counter and account for the whole scope.
         // (This must be a named variable so it doesn't get dropped prematurely!)
@@ -67,7 +68,7 @@ impl TableOfContent {
         // snapshot directory is mounted as network share and multiple writes to it could be slow
         let temp_dir = self.optional_temp_or_storage_temp_path()?;
         Ok(collection
-            .create_snapshot(&temp_dir, self.this_peer_id)
+            .create_snapshot(&temp_dir, self.this_peer_id, base_snapshot)
             .await?)
     }
 
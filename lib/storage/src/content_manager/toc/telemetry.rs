@@ -7,6 +7,7 @@ use collection::operations::types::CollectionResult;
 use collection::telemetry::{
     CollectionSnapshotTelemetry, CollectionTelemetry, CollectionsAggregatedTelemetry,
 };
+use common::budget::JobLimitStatus;
 use common::scope_tracker::{ScopeTracker, ScopeTrackerGuard};
 use common::types::TelemetryDetail;
 use dashmap::DashMap;
@@ -121,6 +122,11 @@ impl TableOfContent {
         self.storage_config.max_collections
     }
 
+    /// State of the node-wide optimization job limit, `None` if there is no limit.
+    pub fn optimization_job_limit_status(&self) -> Option<JobLimitStatus> {
+        self.optimizer_resource_budget.job_limit_status()
+    }
+
     /// Returns atomic counters for snapshot telemetry and metadata for the given collection.
     /// If no entry with the given collection name was found, empty counters will be created and returned.
     pub fn snapshot_telemetry_collector(&self, collection: &str) -> SnapshotTelemetryCollector {
@@ -162,7 +162,9 @@ impl Dispatcher {
                 // Sync nodes when creating or renaming collection aliases
                 CollectionMetaOperations::ChangeAliases(changes) => {
                     changes.actions.iter().any(|change| match change {
-                        AliasOperations::CreateAlias(_) | AliasOperations::RenameAlias(_) => true,
+                        AliasOperations::CreateAlias(_)
+                        | AliasOperations::RenameAlias(_)
+                        | AliasOperations::SwitchAliasGroup(_) => true,
                         AliasOperations::DeleteAlias(_) => false,
                     })
                 }
@@ -178,6 +180,8 @@ impl Dispatcher {
                 | CollectionMetaOperations::DropShardKey(_)
                 | CollectionMetaOperations::CreatePayloadIndex(_)
                 | CollectionMetaOperations::DropPayloadIndex(_)
+                | CollectionMetaOperations::SetFilterTemplate(_)
+                | CollectionMetaOperations::DeleteFilterTemplate(_)
                 | CollectionMetaOperations::Nop { .. } => false,
 
                 #[cfg(feature = "staging")]
@@ -30,6 +30,7 @@ impl AuditableOperation for CollectionUpdateOperations {
                 PayloadOps::ClearPayload { .. } => "clear_payload",
                 PayloadOps::ClearPayloadByFilter(_) => "clear_payload_by_filter",
                 PayloadOps::OverwritePayload(_) => "overwrite_payload",
+                PayloadOps::PatchPayload(_) => "patch_payload",
             },
             CollectionUpdateOperations::FieldIndexOperation(op) => match op {
                 FieldIndexOperations::CreateIndex(_) => "create_field_index",
@@ -55,6 +56,8 @@ impl AuditableOperation for CollectionMetaOperations {
             CollectionMetaOperations::DropShardKey(_) => "drop_shard_key",
             CollectionMetaOperations::CreatePayloadIndex(_) => "create_payload_index",
             CollectionMetaOperations::DropPayloadIndex(_) => "drop_payload_index",
+            CollectionMetaOperations::SetFilterTemplate(_) => "set_filter_template",
+            CollectionMetaOperations::DeleteFilterTemplate(_) => "delete_filter_template",
             CollectionMetaOperations::Nop { .. } => "nop",
             #[cfg(feature = "staging")]
             CollectionMetaOperations::TestSlowDown(_) => "debug",
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
 
 use api::rest::{LookupLocation, SearchRequestInternal};
+use collection::collection::copy::CollectionCopyRequest;
 use collection::collection::distance_matrix::CollectionSearchMatrixRequest;
+use collection::collection::join::CollectionJoinRequest;
+use collection::collection::sample_projection::CollectionSampleProjectionRequest;
 use collection::grouping::group_by::{GroupRequest, SourceRequest};
 use collection::lookup::WithLookup;
 use collection::operations::CollectionUpdateOperations;
@@ -12,7 +15,8 @@ use collection::operations::types::{
 use collection::operations::universal_query::collection_query::{
     CollectionPrefetch, CollectionQueryRequest,
 };
-use segment::data_types::facets::FacetParams;
+use segment::data_types::aggregation::AggregateParams;
+use segment::data_types::facets::{FacetParams, MultiFacetParams};
 use shard::scroll::ScrollRequestInternal;
 
 use super::{Access, AccessRequirements, CollectionAccessList, CollectionPass};
@@ -67,6 +71,18 @@ impl Access {
                     AccessRequirements::new().write().extras(),
                 )?;
             }
+            CollectionMetaOperations::SetFilterTemplate(op) => {
+                self.check_collection_access(
+                    &op.collection_name,
+                    AccessRequirements::new().write().extras(),
+                )?;
+            }
+            CollectionMetaOperations::DeleteFilterTemplate(op) => {
+                self.check_collection_access(
+                    &op.collection_name,
+                    AccessRequirements::new().write().extras(),
+                )?;
+            }
             CollectionMetaOperations::Nop { token: _ } => (),
             #[cfg(feature = "staging")]
             CollectionMetaOperations::TestSlowDown(_) => {
@@ -271,6 +287,34 @@ impl CheckableCollectionOperation for FacetParams {
     }
 }
 
+impl CheckableCollectionOperation for MultiFacetParams {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+        Ok(())
+    }
+}
+
+impl CheckableCollectionOperation for AggregateParams {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+        Ok(())
+    }
+}
+
 impl CheckableCollectionOperation for CollectionSearchMatrixRequest {
     fn access_requirements(&self) -> AccessRequirements {
         AccessRequirements {
@@ -285,6 +329,51 @@ impl CheckableCollectionOperation for CollectionSearchMatrixRequest {
     }
 }
 
+impl CheckableCollectionOperation for CollectionSampleProjectionRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+        Ok(())
+    }
+}
+
+impl CheckableCollectionOperation for CollectionJoinRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: self.payload_key.is_some(),
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, access: &CollectionAccessList) -> StorageResult<()> {
+        access.find_view(&self.target_collection)?;
+        Ok(())
+    }
+}
+
+impl CheckableCollectionOperation for CollectionCopyRequest {
+    /// Points are only read from the source collection.
+    /// Write access to the target collection is checked separately.
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+        Ok(())
+    }
+}
+
 impl CheckableCollectionOperation for CollectionUpdateOperations {
     fn access_requirements(&self) -> AccessRequirements {
         match self {
@@ -477,6 +566,7 @@ mod tests_ops {
             ids: vec![PointIdType::NumId(12345)],
             with_payload: None,
             with_vector: WithVector::Bool(true),
+            with_version: None,
         };
 
         assert_allowed(&op, &Access::Global(GlobalAccessMode::Manage));
@@ -549,6 +639,7 @@ mod tests_ops {
                 collection_name: "col2".to_string(),
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vectors: Some(WithVector::Bool(true)),
+                filter: None,
             }),
         };
 
@@ -662,6 +753,8 @@ mod tests_ops {
             with_payload: Some(WithPayloadInterface::Bool(true)),
             with_vector: WithVector::Bool(true),
             order_by: Some(OrderByInterface::Key("path".parse().unwrap())),
+            distinct_by: None,
+            with_version: None,
         };
 
         assert_allowed(&op, &Access::Global(GlobalAccessMode::Manage));
@@ -870,6 +963,12 @@ mod tests_ops {
                         key: None,
                     })
                 }
+                PayloadOpsDiscriminants::PatchPayload => PayloadOps::PatchPayload(SetPayloadOp {
+                    payload: Payload::default(),
+                    points: Some(vec![ExtendedPointId::NumId(12345)]),
+                    filter: None,
+                    key: None,
+                }),
             };
 
             let op = CollectionUpdateOperations::PayloadOperation(inner);
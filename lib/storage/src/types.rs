@@ -12,6 +12,7 @@ use collection::operations::shared_storage_config::{
 };
 use collection::operations::types::{NodeType, PeerMetadata};
 use collection::optimizers_builder::OptimizersConfig;
+use collection::shards::local_shard::disk_usage_watcher::DiskWatermarksConfig;
 use collection::shards::shard::PeerId;
 use collection::shards::transfer::ShardTransferMethod;
 use common::load_concurrency::LoadConcurrencyConfig;
@@ -48,6 +49,11 @@ pub struct PerformanceConfig {
     /// Otherwise - use this exact number of IO operations.
     #[serde(default)]
     pub optimizer_io_budget: usize,
+    /// Maximum number of optimization jobs running at the same time on this node, across all
+    /// collections. Free job slots are shared fairly between collections.
+    /// If null or 0 - no node-wide limit, only per collection limits apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_optimizations: Option<usize>,
     #[serde(default = "default_io_shard_transfers_limit")]
     pub incoming_shard_transfers_limit: Option<usize>,
     #[serde(default = "default_io_shard_transfers_limit")]
@@ -56,6 +62,90 @@ pub struct PerformanceConfig {
     pub async_scorer: Option<bool>,
     #[serde(default, flatten)]
     pub load_concurrency: LoadConcurrencyConfig,
+    /// Reduce quality of queries instead of timing out, when the peer is overloaded with searches.
+    /// If null - queries are never degraded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub search_degradation: Option<SearchDegradationConfig>,
+    /// Split CPUs of the node between search and optimizations by weight.
+    /// Search runtime and optimizations get separate pools of CPUs, so that heavy optimizations
+    /// don't starve searches. Explicitly configured `max_search_threads` and
+    /// `optimizer_cpu_budget` take precedence.
+    /// If null - pools are sized independently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub cpu_weights: Option<CpuWeightsConfig>,
+}
+
+impl PerformanceConfig {
+    /// Number of threads of the search runtime
+    pub fn search_thread_count(&self) -> usize {
+        match self.cpu_weights {
+            Some(weights) if self.max_search_threads == 0 => {
+                weights.split(common::cpu::get_num_cpus()).search
+            }
+            _ => common::defaults::search_thread_count(self.max_search_threads),
+        }
+    }
+
+    /// Number of CPUs to allocate for optimizations
+    pub fn optimizer_cpu_budget(&self) -> usize {
+        match self.cpu_weights {
+            Some(weights) if self.optimizer_cpu_budget == 0 => {
+                weights.split(common::cpu::get_num_cpus()).optimization
+            }
+            _ => common::cpu::get_cpu_budget(self.optimizer_cpu_budget),
+        }
+    }
+}
+
+/// Relative weights of search and optimization CPU pools.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Validate)]
+pub struct CpuWeightsConfig {
+    #[validate(range(min = 1))]
+    pub search: usize,
+    #[validate(range(min = 1))]
+    pub optimization: usize,
+}
+
+/// Number of CPUs in each pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CpuPools {
+    pub search: usize,
+    pub optimization: usize,
+}
+
+impl CpuWeightsConfig {
+    /// Split `num_cpus` between the pools proportionally to the weights.
+    /// Each pool gets at least one CPU.
+    pub fn split(&self, num_cpus: usize) -> CpuPools {
+        let total_weight = (self.search + self.optimization).max(1);
+        let optimization = (num_cpus * self.optimization / total_weight)
+            .min(num_cpus.saturating_sub(1))
+            .max(1);
+        let search = num_cpus.saturating_sub(optimization).max(1);
+        CpuPools {
+            search,
+            optimization,
+        }
+    }
+}
+
+/// Policy of lowering search effort of queries during traffic spikes.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Validate)]
+pub struct SearchDegradationConfig {
+    /// Number of searches executed by the peer at once, above which new searches are degraded.
+    #[validate(range(min = 1))]
+    pub max_searches_in_flight: usize,
+    /// Lowest `hnsw_ef` degraded queries are reduced to. Queries with lower `hnsw_ef`, set
+    /// explicitly or by the HNSW config of the collection, are not changed.
+    #[validate(range(min = 1))]
+    pub min_hnsw_ef: usize,
+    /// Lowest quantization oversampling degraded queries are reduced to.
+    /// If null - oversampling is not changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1.0))]
+    pub min_oversampling: Option<f64>,
 }
 
 const fn default_io_shard_transfers_limit() -> Option<usize> {
@@ -84,6 +174,7 @@ pub struct StorageConfig {
     pub optimizers_overwrite: Option<OptimizersConfigDiff>,
     #[validate(nested)]
     pub wal: WalConfig,
+    #[validate(nested)]
     pub performance: PerformanceConfig,
     #[validate(nested)]
     pub hnsw_index: HnswConfig,
@@ -92,6 +183,9 @@ pub struct StorageConfig {
     pub hnsw_global_config: HnswGlobalConfig,
     #[serde(default = "default_mmap_advice")]
     pub mmap_advice: mmap::Advice,
+    /// Per-component overrides of `mmap_advice` and populate-on-load for on-disk storages
+    #[serde(default)]
+    pub mmap_components: mmap::ComponentsAdvice,
     #[serde(default)]
     pub node_type: NodeType,
     #[serde(default)]
@@ -115,6 +209,9 @@ pub struct StorageConfig {
     /// Maximum number of collections to allow in the cluster.
     #[serde(default)]
     pub max_collections: Option<usize>,
+    /// Free disk space thresholds, at which shards stop accepting updates and pause optimizations.
+    #[serde(default)]
+    pub disk_watermarks: DiskWatermarksConfig,
 }
 
 impl StorageConfig {
@@ -136,7 +233,8 @@ impl StorageConfig {
             self.snapshots_config.clone(),
             self.hnsw_global_config.clone(),
             self.performance.load_concurrency.clone(),
-            common::defaults::search_thread_count(self.performance.max_search_threads),
+            self.performance.search_thread_count(),
+            self.disk_watermarks,
         )
     }
 }
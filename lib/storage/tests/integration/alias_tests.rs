@@ -44,6 +44,8 @@ fn test_alias_operation() {
             flush_interval_sec: 2,
             max_optimization_threads: Some(2),
             prevent_unoptimized: None,
+            max_builder_memory: None,
+            flush_max_dirty: None,
         },
         optimizers_overwrite: None,
         wal: Default::default(),
@@ -52,16 +54,20 @@ fn test_alias_operation() {
             max_optimization_runtime_threads: 1,
             optimizer_cpu_budget: 0,
             optimizer_io_budget: 0,
+            max_concurrent_optimizations: None,
             update_rate_limit: None,
             search_timeout_sec: None,
             incoming_shard_transfers_limit: Some(1),
             outgoing_shard_transfers_limit: Some(1),
             async_scorer: None,
             load_concurrency: LoadConcurrencyConfig::default(),
+            search_degradation: None,
+            cpu_weights: None,
         },
         hnsw_index: Default::default(),
         hnsw_global_config: Default::default(),
         mmap_advice: mmap::Advice::Random,
+        mmap_components: Default::default(),
         node_type: Default::default(),
         update_queue_size: Default::default(),
         handle_collection_load_errors: false,
@@ -71,6 +77,7 @@ fn test_alias_operation() {
         shard_transfer_method: None,
         collection: None,
         max_collections: None,
+        disk_watermarks: Default::default(),
     };
 
     let search_runtime = Runtime::new().unwrap();
@@ -118,6 +125,7 @@ fn test_alias_operation() {
                             strict_mode_config: None,
                             uuid: None,
                             metadata: None,
+                            tenant_field: None,
                         },
                     )
                     .unwrap(),
@@ -0,0 +1,94 @@
+use actix_web::{Responder, post, web};
+use actix_web_validator::{Json, Path, Query};
+use api::rest::{AggregateRequest, AggregateResponse};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use storage::content_manager::collection_verification::check_strict_mode;
+use storage::dispatcher::Dispatcher;
+use tokio::time::Instant;
+
+use crate::actix::api::CollectionPath;
+use crate::actix::api::read_params::ReadParams;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers::{
+    get_request_hardware_counter, process_response, process_response_error,
+};
+use crate::common::filter_templates::resolve_filter_template;
+use crate::settings::ServiceConfig;
+
+#[post("/collections/{name}/aggregate")]
+async fn aggregate(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<AggregateRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let AggregateRequest {
+        mut aggregate_request,
+        shard_key,
+        filter_template,
+    } = request.into_inner();
+
+    let pass = match check_strict_mode(
+        &aggregate_request,
+        params.timeout_as_secs(),
+        &collection.name,
+        &dispatcher,
+        &auth,
+    )
+    .await
+    {
+        Ok(pass) => pass,
+        Err(err) => return process_response_error(err, timing, None),
+    };
+
+    let toc = dispatcher.toc(&auth, &pass);
+    aggregate_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        aggregate_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, timing, None),
+    };
+
+    let aggregate_params = From::from(aggregate_request);
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+
+    let response = toc
+        .aggregate(
+            &collection.name,
+            aggregate_params,
+            shard_selection,
+            params.consistency,
+            auth,
+            params.timeout(),
+            request_hw_counter.get_counter(),
+        )
+        .await
+        .map(AggregateResponse::from);
+
+    process_response(response, timing, request_hw_counter.to_rest_api())
+}
+
+pub fn config_aggregate_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(aggregate);
+}
@@ -1,12 +1,11 @@
 use std::future::Future;
+use std::sync::Arc;
 
 use actix_web::{HttpResponse, delete, get, post, put, web};
 use actix_web_validator::Query;
 use api::grpc;
 use api::grpc::transport_channel_pool::DEFAULT_GRPC_TIMEOUT;
 use collection::operations::verification::new_unchecked_verification_pass;
-use futures::stream::FuturesUnordered;
-use futures::{StreamExt, TryFutureExt};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use storage::content_manager::consensus_ops::ConsensusOperations;
@@ -17,8 +16,10 @@ use validator::Validate;
 
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers;
-use crate::common::telemetry::TelemetryData;
-use crate::common::telemetry_ops::distributed_telemetry::DistributedTelemetryData;
+use crate::common::rebalance::Rebalancer;
+use crate::common::telemetry_ops::distributed_telemetry::{
+    DistributedTelemetryData, collect_peer_telemetries,
+};
 
 /// For now, we only handle details_level >= 2
 /// TODO(cluster telemetry): Handle lower levels
@@ -235,51 +236,13 @@ async fn get_cluster_telemetry(
 
         let timeout = params.timeout.unwrap_or(DEFAULT_GRPC_TIMEOUT.as_secs());
 
-        let all_peers: Vec<_> = channel_service
-            .id_to_address
-            .read()
-            .keys()
-            .copied()
-            .collect();
-
-        let mut futures = all_peers
-            .into_iter()
-            .map(|peer_id| {
-                channel_service
-                    .with_qdrant_client(peer_id, |mut client| {
-                        let request = grpc::GetTelemetryRequest {
-                            collections_selector: collections_selector.clone(),
-                            details_level,
-                            timeout,
-                        };
-
-                        async move { client.get_telemetry(request).await }
-                    })
-                    .map_err(move |err| (peer_id, err))
-            })
-            .collect::<FuturesUnordered<_>>();
-
-        let mut telemetries = Vec::with_capacity(futures.len());
-        let mut missing_peers = Vec::new();
-
-        while let Some(result) = futures.next().await {
-            match result {
-                Ok(response) => {
-                    let telemetry =
-                        TelemetryData::try_from(response.into_inner().result.ok_or_else(|| {
-                            StorageError::service_error(
-                                "GetTelemetryResponse is missing `result` field",
-                            )
-                        })?)
-                        .map_err(|err| StorageError::service_error(err.to_string()))?;
-                    telemetries.push(telemetry);
-                }
-                Err((peer_id, err)) => {
-                    log::error!("Internal telemetry service failed for peer {peer_id}: {err:#?}");
-                    missing_peers.push(peer_id);
-                }
-            };
-        }
+        let (telemetries, missing_peers) = collect_peer_telemetries(
+            channel_service,
+            collections_selector,
+            details_level,
+            timeout,
+        )
+        .await?;
 
         let distributed_telemetry =
             DistributedTelemetryData::resolve_telemetries(access, telemetries, missing_peers)?;
@@ -289,12 +252,31 @@ async fn get_cluster_telemetry(
     .await
 }
 
+#[get("/cluster/rebalance")]
+async fn get_cluster_rebalance(
+    rebalancer: web::Data<Option<Arc<Rebalancer>>>,
+    ActixAuth(auth): ActixAuth,
+) -> HttpResponse {
+    helpers::time(async move {
+        auth.check_global_access(AccessRequirements::new().manage(), "get_cluster_rebalance")?;
+
+        let rebalancer = rebalancer
+            .as_ref()
+            .as_ref()
+            .ok_or_else(|| StorageError::service_error("Qdrant is running in standalone mode"))?;
+
+        rebalancer.plan().await
+    })
+    .await
+}
+
 // Configure services
 pub fn config_cluster_api(cfg: &mut web::ServiceConfig) {
     cfg.service(cluster_status)
         .service(remove_peer)
         .service(recover_current_peer)
         .service(get_cluster_telemetry)
+        .service(get_cluster_rebalance)
         .service(get_cluster_metadata_keys)
         .service(get_cluster_metadata_key)
         .service(update_cluster_metadata_key)
@@ -8,11 +8,12 @@ use collection::operations::types::{CollectionError, OptimizationsRequestOptions
 use collection::operations::verification::new_unchecked_verification_pass;
 use serde::Deserialize;
 use storage::content_manager::collection_meta_ops::{
-    ChangeAliasesOperation, CollectionMetaOperations, CreateCollection, CreateCollectionOperation,
-    DeleteCollectionOperation, UpdateCollection, UpdateCollectionOperation,
+    ChangeAliasesOperation, CloneCollection, CollectionMetaOperations, CreateCollection,
+    CreateCollectionOperation, DeleteCollectionOperation, UpdateCollection,
+    UpdateCollectionOperation,
 };
 use storage::dispatcher::Dispatcher;
-use storage::rbac::AccessRequirements;
+use storage::rbac::{AccessRequirements, Auth};
 use validator::Validate;
 
 use super::CollectionPath;
@@ -73,6 +74,25 @@ async fn get_collection(
     .await
 }
 
+#[post("/collections/{name}/config/diff")]
+async fn get_collection_config_changes(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    proposed: Json<CreateCollection>,
+    ActixAuth(auth): ActixAuth,
+) -> HttpResponse {
+    // No request to verify
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(do_get_collection_config_changes(
+        dispatcher.toc(&auth, &pass),
+        &auth,
+        &collection.name,
+        proposed.into_inner(),
+    ))
+    .await
+}
+
 #[get("/collections/{name}/exists")]
 async fn get_collection_existence(
     dispatcher: web::Data<Dispatcher>,
@@ -133,6 +153,28 @@ async fn create_collection(
     process_response(response, timing, None)
 }
 
+#[post("/collections/{name}/clone")]
+async fn clone_collection(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<CloneCollection>,
+    Query(query): Query<WaitTimeout>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let response = do_clone_collection(
+        &dispatcher,
+        &collection.name,
+        request.into_inner(),
+        auth,
+        query.timeout(),
+    )
+    .await;
+
+    process_response(response, timing, None)
+}
+
 #[patch("/collections/{name}")]
 async fn update_collection(
     dispatcher: web::Data<Dispatcher>,
@@ -256,6 +298,7 @@ impl TryFrom<&OptimizationsParam> for OptimizationsRequestOptions {
             queued: false,
             completed_limit: None,
             idle_segments: false,
+            decisions: false,
         };
         for field in with.as_deref().unwrap_or("").split(',') {
             match field.trim() {
@@ -263,6 +306,7 @@ impl TryFrom<&OptimizationsParam> for OptimizationsRequestOptions {
                 "queued" => options.queued = true,
                 "completed" => options.completed_limit = Some(completed_limit),
                 "idle_segments" => options.idle_segments = true,
+                "decisions" => options.decisions = true,
                 _ => Err(CollectionError::bad_input(format!(
                     "Unknown field in 'with' parameter: {field}"
                 )))?,
@@ -296,6 +340,92 @@ fn get_optimizations(
     })
 }
 
+#[derive(Deserialize, Clone, Validate)]
+struct CancelOptimizationsParam {
+    optimizer: Option<String>,
+}
+
+#[post("/collections/{name}/optimizations/cancel")]
+fn cancel_optimizations(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    ActixAuth(auth): ActixAuth,
+    params: Query<CancelOptimizationsParam>,
+) -> impl Future<Output = HttpResponse> {
+    helpers::time(async move {
+        // Nothing to verify here.
+        let pass = new_unchecked_verification_pass();
+        do_cancel_optimizations(
+            dispatcher.toc(&auth, &pass),
+            &auth,
+            &collection.name,
+            params.optimizer.as_deref(),
+        )
+        .await
+    })
+}
+
+#[post("/collections/{name}/optimizations/resume")]
+fn resume_optimizations(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Future<Output = HttpResponse> {
+    helpers::time(async move {
+        // Nothing to verify here.
+        let pass = new_unchecked_verification_pass();
+        do_resume_optimizations(dispatcher.toc(&auth, &pass), &auth, &collection.name).await?;
+        Ok(true)
+    })
+}
+
+#[derive(Deserialize, Clone, Validate)]
+struct PinVectorParam {
+    vector: Option<String>,
+}
+
+#[post("/collections/{name}/vectors/pin")]
+fn pin_vector(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    ActixAuth(auth): ActixAuth,
+    params: Query<PinVectorParam>,
+) -> impl Future<Output = HttpResponse> {
+    set_vector_pinned(dispatcher, collection, auth, params, true)
+}
+
+#[post("/collections/{name}/vectors/unpin")]
+fn unpin_vector(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    ActixAuth(auth): ActixAuth,
+    params: Query<PinVectorParam>,
+) -> impl Future<Output = HttpResponse> {
+    set_vector_pinned(dispatcher, collection, auth, params, false)
+}
+
+fn set_vector_pinned(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    auth: Auth,
+    params: Query<PinVectorParam>,
+    pinned: bool,
+) -> impl Future<Output = HttpResponse> {
+    helpers::time(async move {
+        // Nothing to verify here.
+        let pass = new_unchecked_verification_pass();
+        do_set_vector_pinned(
+            dispatcher.toc(&auth, &pass),
+            &auth,
+            &collection.name,
+            params.vector.as_deref(),
+            pinned,
+        )
+        .await?;
+        Ok(true)
+    })
+}
+
 // Configure services
 pub fn config_collections_api(cfg: &mut web::ServiceConfig) {
     // Ordering of services is important for correct path pattern matching
@@ -304,13 +434,19 @@ pub fn config_collections_api(cfg: &mut web::ServiceConfig) {
         .service(get_collections)
         .service(get_collection)
         .service(get_collection_existence)
+        .service(get_collection_config_changes)
         .service(create_collection)
+        .service(clone_collection)
         .service(update_collection)
         .service(delete_collection)
         .service(get_aliases)
         .service(get_collection_aliases)
         .service(get_cluster_info)
         .service(get_optimizations)
+        .service(cancel_optimizations)
+        .service(resume_optimizations)
+        .service(pin_vector)
+        .service(unpin_vector)
         .service(update_collection_cluster);
 }
 
@@ -10,6 +10,7 @@ use super::CollectionPath;
 use crate::actix::api::read_params::ReadParams;
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{self, get_request_hardware_counter, process_response_error};
+use crate::common::filter_templates::resolve_filter_template;
 use crate::common::query::do_count_points;
 use crate::settings::ServiceConfig;
 
@@ -23,8 +24,9 @@ async fn count_points(
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
     let CountRequest {
-        count_request,
+        mut count_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -40,6 +42,20 @@ async fn count_points(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    count_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        count_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
     let shard_selector = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => ShardSelectorInternal::from(shard_keys),
@@ -55,7 +71,7 @@ async fn count_points(
     let timing = Instant::now();
 
     let result = do_count_points(
-        dispatcher.toc(&auth, &pass),
+        toc,
         &collection.name,
         count_request,
         params.consistency,
@@ -0,0 +1,78 @@
+use actix_web::{Responder, get, post, web};
+use actix_web_validator::{Json, Query};
+use storage::content_manager::errors::StorageError;
+use storage::dispatcher::Dispatcher;
+use storage::rbac::AccessRequirements;
+
+use crate::actix::api::collections_api::WaitTimeout;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers;
+use crate::common::datasets::{Dataset, LoadDatasetRequest, do_list_datasets, do_load_dataset};
+use crate::common::http_client::HttpClient;
+use crate::settings::ServiceConfig;
+
+#[get("/datasets")]
+async fn list_datasets(ActixAuth(auth): ActixAuth) -> impl Responder {
+    helpers::time(async move { do_list_datasets(&auth) }).await
+}
+
+#[post("/datasets/load")]
+async fn load_dataset(
+    dispatcher: web::Data<Dispatcher>,
+    http_client: web::Data<HttpClient>,
+    request: Json<LoadDatasetRequest>,
+    Query(query): Query<WaitTimeout>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let LoadDatasetRequest {
+        dataset,
+        url,
+        collection_name,
+    } = request.into_inner();
+
+    let future = async move {
+        // Check access before downloading anything on behalf of the caller
+        auth.check_global_access(AccessRequirements::new().manage(), "load_dataset")?;
+
+        let (dataset, collection_name) = match (dataset, url) {
+            (Some(name), None) => {
+                let dataset = Dataset::bundled(&name)?;
+                (dataset, collection_name.unwrap_or(name))
+            }
+            (None, Some(url)) => {
+                let Some(collection_name) = collection_name else {
+                    return Err(StorageError::bad_input(
+                        "collection_name is required when loading a dataset from url",
+                    ));
+                };
+                // Never send our API key to an arbitrary dataset host
+                let client = http_client.client_without_redirects()?;
+                let dataset =
+                    Dataset::download(&client, url, &service_config.dataset_url_hosts).await?;
+                (dataset, collection_name)
+            }
+            _ => {
+                return Err(StorageError::bad_input(
+                    "Exactly one of dataset or url must be specified",
+                ));
+            }
+        };
+
+        do_load_dataset(
+            dispatcher.into_inner(),
+            collection_name,
+            dataset,
+            auth,
+            query.timeout(),
+        )
+        .await
+    };
+
+    helpers::time(future).await
+}
+
+// Configure services
+pub fn config_dataset_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_datasets).service(load_dataset);
+}
@@ -13,6 +13,7 @@ use crate::actix::api::CollectionPath;
 use crate::actix::api::read_params::ReadParams;
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{self, get_request_hardware_counter, process_response_error};
+use crate::common::filter_templates::resolve_filter_template;
 use crate::common::query::do_discover_batch_points;
 use crate::settings::ServiceConfig;
 
@@ -26,8 +27,9 @@ async fn discover_points(
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
     let DiscoverRequest {
-        discover_request,
+        mut discover_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -43,6 +45,20 @@ async fn discover_points(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    discover_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        discover_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => shard_keys.into(),
@@ -57,8 +73,7 @@ async fn discover_points(
 
     let timing = Instant::now();
 
-    let result = dispatcher
-        .toc(&auth, &pass)
+    let result = toc
         .discover(
             &collection.name,
             discover_request,
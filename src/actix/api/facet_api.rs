@@ -1,6 +1,6 @@
 use actix_web::{Responder, post, web};
 use actix_web_validator::{Json, Path, Query};
-use api::rest::{FacetRequest, FacetResponse};
+use api::rest::{FacetRequest, FacetResponse, MultiFacetRequest, MultiFacetResponse};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use storage::content_manager::collection_verification::check_strict_mode;
 use storage::dispatcher::Dispatcher;
@@ -12,6 +12,7 @@ use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{
     get_request_hardware_counter, process_response, process_response_error,
 };
+use crate::common::filter_templates::resolve_filter_template;
 use crate::settings::ServiceConfig;
 
 #[post("/collections/{name}/facet")]
@@ -26,8 +27,9 @@ async fn facet(
     let timing = Instant::now();
 
     let FacetRequest {
-        facet_request,
+        mut facet_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -43,6 +45,20 @@ async fn facet(
         Err(err) => return process_response_error(err, timing, None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    facet_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        facet_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, timing, None),
+    };
+
     let facet_params = From::from(facet_request);
 
     let shard_selection = match shard_key {
@@ -57,8 +73,7 @@ async fn facet(
         None,
     );
 
-    let response = dispatcher
-        .toc(&auth, &pass)
+    let response = toc
         .facet(
             &collection.name,
             facet_params,
@@ -74,6 +89,81 @@ async fn facet(
     process_response(response, timing, request_hw_counter.to_rest_api())
 }
 
+#[post("/collections/{name}/facet/multi")]
+async fn facet_multi(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<MultiFacetRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let MultiFacetRequest {
+        mut facet_request,
+        shard_key,
+        filter_template,
+    } = request.into_inner();
+
+    let pass = match check_strict_mode(
+        &facet_request,
+        params.timeout_as_secs(),
+        &collection.name,
+        &dispatcher,
+        &auth,
+    )
+    .await
+    {
+        Ok(pass) => pass,
+        Err(err) => return process_response_error(err, timing, None),
+    };
+
+    let toc = dispatcher.toc(&auth, &pass);
+    facet_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        facet_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, timing, None),
+    };
+
+    let keys = facet_request.keys.clone();
+    let facet_params = From::from(facet_request);
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+
+    let response = toc
+        .facets(
+            &collection.name,
+            facet_params,
+            shard_selection,
+            params.consistency,
+            auth,
+            params.timeout(),
+            request_hw_counter.get_counter(),
+        )
+        .await
+        .map(|responses| MultiFacetResponse::from_responses(keys, responses));
+
+    process_response(response, timing, request_hw_counter.to_rest_api())
+}
+
 pub fn config_facet_api(cfg: &mut web::ServiceConfig) {
-    cfg.service(facet);
+    cfg.service(facet).service(facet_multi);
 }
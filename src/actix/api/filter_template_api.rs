@@ -0,0 +1,84 @@
+use actix_web::{Responder, delete, get, put, web};
+use actix_web_validator::{Json, Path, Query};
+use collection::collection::filter_templates::FilterTemplate;
+use collection::operations::verification::new_unchecked_verification_pass;
+use serde::Deserialize;
+use storage::dispatcher::Dispatcher;
+use validator::Validate;
+
+use super::CollectionPath;
+use crate::actix::api::collections_api::WaitTimeout;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers;
+use crate::common::filter_templates::{
+    do_delete_filter_template, do_list_filter_templates, do_set_filter_template,
+};
+
+#[derive(Deserialize, Validate)]
+struct FilterTemplatePath {
+    #[serde(rename = "filter_name")]
+    #[validate(length(min = 1, max = 255))]
+    name: String,
+}
+
+#[get("/collections/{name}/filters")]
+async fn list_filter_templates(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    // No request to verify
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(do_list_filter_templates(
+        dispatcher.toc(&auth, &pass),
+        &collection.name,
+        &auth,
+    ))
+    .await
+}
+
+#[put("/collections/{name}/filters/{filter_name}")]
+async fn set_filter_template(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    filter_template: Path<FilterTemplatePath>,
+    template: web::Json<FilterTemplate>,
+    Query(query): Query<WaitTimeout>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    helpers::time(do_set_filter_template(
+        dispatcher.get_ref(),
+        collection.into_inner().name,
+        filter_template.into_inner().name,
+        template.into_inner(),
+        auth,
+        query.timeout(),
+    ))
+    .await
+}
+
+#[delete("/collections/{name}/filters/{filter_name}")]
+async fn delete_filter_template(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    filter_template: Path<FilterTemplatePath>,
+    Query(query): Query<WaitTimeout>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    helpers::time(do_delete_filter_template(
+        dispatcher.get_ref(),
+        collection.into_inner().name,
+        filter_template.into_inner().name,
+        auth,
+        query.timeout(),
+    ))
+    .await
+}
+
+// Configure services
+pub fn config_filter_template_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_filter_templates)
+        .service(set_filter_template)
+        .service(delete_filter_template);
+}
@@ -2,12 +2,15 @@ use common::validation::{validate_collection_name, validate_collection_name_lega
 use serde::Deserialize;
 use validator::Validate;
 
+pub mod aggregate_api;
 pub mod cluster_api;
 pub mod collections_api;
 pub mod count_api;
+pub mod dataset_api;
 pub mod debug_api;
 pub mod discovery_api;
 pub mod facet_api;
+pub mod filter_template_api;
 pub mod issues_api;
 pub mod local_shard_api;
 pub mod profiler_api;
@@ -2,19 +2,24 @@ use actix_web::{Responder, post, web};
 use actix_web_validator::{Json, Path, Query};
 use api::rest::models::InferenceUsage;
 use api::rest::{QueryGroupsRequest, QueryRequest, QueryRequestBatch, QueryResponse};
+use collection::collection::partial_results::{SkippedShards, TimedOut};
+use collection::collection::search_explanations::ExplainedShards;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use itertools::Itertools;
+use serde::Deserialize;
 use storage::content_manager::collection_verification::{
     check_strict_mode, check_strict_mode_batch,
 };
 use storage::content_manager::errors::StorageError;
 use storage::dispatcher::Dispatcher;
 use tokio::time::Instant;
+use validator::Validate;
 
 use super::CollectionPath;
 use super::read_params::ReadParams;
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{self, get_request_hardware_counter};
+use crate::common::filter_templates::resolve_filter_template;
 use crate::common::inference::api_keys::InferenceApiKeys;
 use crate::common::inference::params::InferenceParams;
 use crate::common::inference::query_requests_rest::{
@@ -24,6 +29,20 @@ use crate::common::inference::query_requests_rest::{
 use crate::common::query::do_query_point_groups;
 use crate::settings::ServiceConfig;
 
+#[derive(Deserialize, Validate)]
+struct PartialParams {
+    /// If true, shards which fail are skipped, and results of the remaining shards are returned
+    #[serde(default)]
+    allow_partial: bool,
+}
+
+#[derive(Deserialize, Validate)]
+struct ExplainParams {
+    /// If true, the response explains how segments of each shard executed the searches
+    #[serde(default)]
+    explain: bool,
+}
+
 #[post("/collections/{name}/points/query")]
 #[allow(clippy::too_many_arguments)]
 async fn query_points(
@@ -31,6 +50,8 @@ async fn query_points(
     collection: Path<CollectionPath>,
     request: Json<QueryRequest>,
     params: Query<ReadParams>,
+    partial_params: Query<PartialParams>,
+    explain_params: Query<ExplainParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
     api_keys: InferenceApiKeys,
@@ -38,6 +59,7 @@ async fn query_points(
     let QueryRequest {
         internal: query_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let request_hw_counter = get_request_hardware_counter(
@@ -58,7 +80,7 @@ async fn query_points(
     let inference_params = InferenceParams::new(api_keys, params.timeout());
 
     let result = async {
-        let CollectionQueryRequestWithUsage { request, usage } =
+        let CollectionQueryRequestWithUsage { mut request, usage } =
             convert_query_request_from_rest(query_request, &inference_params).await?;
 
         inference_usage.merge_opt(usage);
@@ -72,15 +94,33 @@ async fn query_points(
         )
         .await?;
 
-        let points = dispatcher
-            .toc(&auth, &pass)
+        let toc = dispatcher.toc(&auth, &pass);
+        request.filter = resolve_filter_template(
+            toc,
+            &collection.name,
+            request.filter,
+            filter_template,
+            &auth,
+        )
+        .await?;
+        let degraded = toc
+            .degrade_query_requests(&collection.name, [&mut request])
+            .await;
+        let skipped_shards = partial_params.allow_partial.then(SkippedShards::default);
+        let explained = explain_params.explain.then(ExplainedShards::default);
+        let timed_out = TimedOut::default();
+
+        let points = toc
             .query_batch(
                 &collection.name,
                 vec![(request, shard_selection)],
                 params.consistency,
                 auth,
                 params.timeout(),
-                hw_measurement_acc,
+                skipped_shards.as_ref(),
+                explained.as_ref(),
+                Some(&timed_out),
+                hw_measurement_acc.clone(),
             )
             .await?
             .pop()
@@ -91,7 +131,13 @@ async fn query_points(
             .map(api::rest::ScoredPoint::from)
             .collect_vec();
 
-        Ok(QueryResponse { points })
+        Ok(QueryResponse {
+            points,
+            degraded: degraded.then_some(true),
+            timed_out: timed_out.is_timed_out().then_some(true),
+            skipped_shards: skipped_shards.and_then(|skipped| skipped.to_rest_api()),
+            explain: explained.map(|explained| explained.to_rest_api()),
+        })
     }
     .await;
 
@@ -110,6 +156,7 @@ async fn query_points_batch(
     collection: Path<CollectionPath>,
     request: Json<QueryRequestBatch>,
     params: Query<ReadParams>,
+    partial_params: Query<PartialParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
     api_keys: InferenceApiKeys,
@@ -131,11 +178,13 @@ async fn query_points_batch(
 
     let result = async {
         let mut batch = Vec::with_capacity(searches.len());
+        let mut filter_templates = Vec::with_capacity(searches.len());
 
         for request_item in searches {
             let QueryRequest {
                 internal,
                 shard_key,
+                filter_template,
             } = request_item;
 
             let CollectionQueryRequestWithUsage { request, usage } =
@@ -149,6 +198,7 @@ async fn query_points_batch(
             };
 
             batch.push((request, shard_selection));
+            filter_templates.push(filter_template);
         }
 
         let pass = check_strict_mode_batch(
@@ -160,23 +210,55 @@ async fn query_points_batch(
         )
         .await?;
 
-        let res = dispatcher
-            .toc(&auth, &pass)
+        let toc = dispatcher.toc(&auth, &pass);
+        for ((request, _), filter_template) in batch.iter_mut().zip(filter_templates) {
+            request.filter = resolve_filter_template(
+                toc,
+                &collection.name,
+                request.filter.take(),
+                filter_template,
+                &auth,
+            )
+            .await?;
+        }
+        let degraded = toc
+            .degrade_query_requests(
+                &collection.name,
+                batch.iter_mut().map(|(request, _)| request),
+            )
+            .await;
+        let skipped_shards = partial_params.allow_partial.then(SkippedShards::default);
+        let timed_out = TimedOut::default();
+
+        let responses = toc
             .query_batch(
                 &collection.name,
                 batch,
                 params.consistency,
                 auth,
                 params.timeout(),
-                hw_measurement_acc,
+                skipped_shards.as_ref(),
+                None,
+                Some(&timed_out),
+                hw_measurement_acc.clone(),
             )
-            .await?
+            .await?;
+
+        let timed_out = timed_out.is_timed_out();
+
+        let res = responses
             .into_iter()
             .map(|response| QueryResponse {
                 points: response
                     .into_iter()
                     .map(api::rest::ScoredPoint::from)
                     .collect_vec(),
+                degraded: degraded.then_some(true),
+                timed_out: timed_out.then_some(true),
+                skipped_shards: skipped_shards
+                    .as_ref()
+                    .and_then(|skipped| skipped.to_rest_api()),
+                explain: None,
             })
             .collect_vec();
         Ok(res)
@@ -205,6 +287,7 @@ async fn query_points_groups(
     let QueryGroupsRequest {
         search_group_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let request_hw_counter = get_request_hardware_counter(
@@ -224,7 +307,7 @@ async fn query_points_groups(
             None => ShardSelectorInternal::All,
             Some(shard_keys) => shard_keys.into(),
         };
-        let CollectionQueryGroupsRequestWithUsage { request, usage } =
+        let CollectionQueryGroupsRequestWithUsage { mut request, usage } =
             convert_query_groups_request_from_rest(search_group_request, inference_params).await?;
 
         inference_usage.merge_opt(usage);
@@ -238,8 +321,18 @@ async fn query_points_groups(
         )
         .await?;
 
+        let toc = dispatcher.toc(&auth, &pass);
+        request.filter = resolve_filter_template(
+            toc,
+            &collection.name,
+            request.filter,
+            filter_template,
+            &auth,
+        )
+        .await?;
+
         let query_result = do_query_point_groups(
-            dispatcher.toc(&auth, &pass),
+            toc,
             &collection.name,
             request,
             params.consistency,
@@ -7,14 +7,50 @@ use serde::Deserialize;
 use validator::Validate;
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Deserialize, JsonSchema, Validate)]
+#[serde(try_from = "ReadParamsInternal")]
 pub struct ReadParams {
-    #[serde(default, deserialize_with = "deserialize_read_consistency")]
     #[validate(nested)]
     pub consistency: Option<ReadConsistency>,
     /// If set, overrides global timeout for this request. Unit is seconds.
     pub timeout: Option<NonZeroU64>,
 }
 
+#[derive(Deserialize)]
+struct ReadParamsInternal {
+    #[serde(default, deserialize_with = "deserialize_read_consistency")]
+    consistency: Option<ReadConsistency>,
+    /// Query parameter form of `ReadConsistency::MaxStaleness`
+    max_staleness_ms: Option<u64>,
+    timeout: Option<NonZeroU64>,
+}
+
+impl TryFrom<ReadParamsInternal> for ReadParams {
+    type Error = &'static str;
+
+    fn try_from(params: ReadParamsInternal) -> Result<Self, Self::Error> {
+        let ReadParamsInternal {
+            consistency,
+            max_staleness_ms,
+            timeout,
+        } = params;
+
+        let consistency = match (consistency, max_staleness_ms) {
+            (Some(_), Some(_)) => {
+                return Err("`consistency` and `max_staleness_ms` can't be used together");
+            }
+            (None, Some(max_staleness_ms)) => {
+                Some(ReadConsistency::MaxStaleness { max_staleness_ms })
+            }
+            (consistency, None) => consistency,
+        };
+
+        Ok(Self {
+            consistency,
+            timeout,
+        })
+    }
+}
+
 impl ReadParams {
     pub fn timeout(&self) -> Option<Duration> {
         self.timeout.map(|num| Duration::from_secs(num.get()))
@@ -68,6 +104,7 @@ mod test {
         test("all", from_type(ReadConsistencyType::All));
         test("majority", from_type(ReadConsistencyType::Majority));
         test("quorum", from_type(ReadConsistencyType::Quorum));
+        test("linearizable", from_type(ReadConsistencyType::Linearizable));
     }
 
     #[test]
@@ -82,6 +119,21 @@ mod test {
         assert!(try_deserialize(&str("0")).is_err());
     }
 
+    #[test]
+    fn deserialize_max_staleness() {
+        test_str(
+            "max_staleness_ms=500",
+            ReadParams {
+                consistency: Some(ReadConsistency::MaxStaleness {
+                    max_staleness_ms: 500,
+                }),
+                ..Default::default()
+            },
+        );
+
+        assert!(try_deserialize("consistency=all&max_staleness_ms=500").is_err());
+    }
+
     fn test(value: &str, params: ReadParams) {
         test_str(&str(value), params);
     }
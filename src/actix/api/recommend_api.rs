@@ -23,6 +23,7 @@ use super::CollectionPath;
 use super::read_params::ReadParams;
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{self, get_request_hardware_counter, process_response_error};
+use crate::common::filter_templates::resolve_filter_template;
 use crate::settings::ServiceConfig;
 
 #[post("/collections/{name}/points/recommend")]
@@ -35,8 +36,9 @@ async fn recommend_points(
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
     let RecommendRequest {
-        recommend_request,
+        mut recommend_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -52,6 +54,20 @@ async fn recommend_points(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    recommend_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        recommend_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => shard_keys.into(),
@@ -66,8 +82,7 @@ async fn recommend_points(
 
     let timing = Instant::now();
 
-    let result = dispatcher
-        .toc(&auth, &pass)
+    let result = toc
         .recommend(
             &collection.name,
             recommend_request,
@@ -97,18 +112,30 @@ async fn do_recommend_batch_points(
     timeout: Option<Duration>,
     hw_measurement_acc: HwMeasurementAcc,
 ) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
-    let requests = request
-        .searches
-        .into_iter()
-        .map(|req| {
-            let shard_selector = match req.shard_key {
-                None => ShardSelectorInternal::All,
-                Some(shard_key) => ShardSelectorInternal::from(shard_key),
-            };
-
-            (req.recommend_request, shard_selector)
-        })
-        .collect();
+    let mut requests = Vec::with_capacity(request.searches.len());
+    for req in request.searches {
+        let RecommendRequest {
+            mut recommend_request,
+            shard_key,
+            filter_template,
+        } = req;
+
+        recommend_request.filter = resolve_filter_template(
+            toc,
+            collection_name,
+            recommend_request.filter,
+            filter_template,
+            &auth,
+        )
+        .await?;
+
+        let shard_selector = match shard_key {
+            None => ShardSelectorInternal::All,
+            Some(shard_key) => ShardSelectorInternal::from(shard_key),
+        };
+
+        requests.push((recommend_request, shard_selector));
+    }
 
     toc.recommend_batch(
         collection_name,
@@ -186,8 +213,9 @@ async fn recommend_point_groups(
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
     let RecommendGroupsRequest {
-        recommend_group_request,
+        mut recommend_group_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -203,6 +231,20 @@ async fn recommend_point_groups(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    recommend_group_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        recommend_group_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => shard_keys.into(),
@@ -217,7 +259,7 @@ async fn recommend_point_groups(
     let timing = Instant::now();
 
     let result = crate::common::query::do_recommend_point_groups(
-        dispatcher.toc(&auth, &pass),
+        toc,
         &collection.name,
         recommend_group_request,
         params.consistency,
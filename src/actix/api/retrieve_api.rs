@@ -11,9 +11,7 @@ use itertools::Itertools;
 use segment::types::{PointIdType, WithPayloadInterface};
 use serde::Deserialize;
 use shard::retrieve::record_internal::RecordInternal;
-use storage::content_manager::collection_verification::{
-    check_strict_mode, check_strict_mode_timeout,
-};
+use storage::content_manager::collection_verification::check_strict_mode;
 use storage::content_manager::errors::StorageError;
 use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
@@ -27,6 +25,7 @@ use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{
     get_request_hardware_counter, process_response, process_response_error,
 };
+use crate::common::filter_templates::resolve_filter_template;
 use crate::common::query::do_get_points;
 use crate::settings::ServiceConfig;
 
@@ -40,18 +39,12 @@ struct PointPath {
 async fn do_get_point(
     toc: &TableOfContent,
     collection_name: &str,
-    point_id: PointIdType,
+    request: PointRequestInternal,
     read_consistency: Option<ReadConsistency>,
     timeout: Option<Duration>,
     auth: Auth,
     hw_counter: HwMeasurementAcc,
 ) -> Result<Option<RecordInternal>, StorageError> {
-    let request = PointRequestInternal {
-        ids: vec![point_id],
-        with_payload: Some(WithPayloadInterface::Bool(true)),
-        with_vector: true.into(),
-    };
-
     let shard_selection = ShardSelectorInternal::All;
 
     toc.retrieve(
@@ -76,7 +69,22 @@ async fn get_point(
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
-    let pass = match check_strict_mode_timeout(
+    let Ok(point_id) = point.id.parse::<PointIdType>() else {
+        let err = StorageError::BadInput {
+            description: format!("Can not recognize \"{}\" as point id", point.id),
+        };
+        return process_response_error(err, Instant::now(), None);
+    };
+
+    let request = PointRequestInternal {
+        ids: vec![point_id],
+        with_payload: Some(WithPayloadInterface::Bool(true)),
+        with_vector: true.into(),
+        with_version: None,
+    };
+
+    let pass = match check_strict_mode(
+        &request,
         params.timeout_as_secs(),
         &collection.name,
         &dispatcher,
@@ -88,13 +96,6 @@ async fn get_point(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
-    let Ok(point_id) = point.id.parse::<PointIdType>() else {
-        let err = StorageError::BadInput {
-            description: format!("Can not recognize \"{}\" as point id", point.id),
-        };
-        return process_response_error(err, Instant::now(), None);
-    };
-
     let request_hw_counter = get_request_hardware_counter(
         &dispatcher,
         collection.name.clone(),
@@ -106,7 +107,7 @@ async fn get_point(
     let res = do_get_point(
         dispatcher.toc(&auth, &pass),
         &collection.name,
-        point_id,
+        request,
         params.consistency,
         params.timeout(),
         auth,
@@ -132,7 +133,13 @@ async fn get_points(
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
-    let pass = match check_strict_mode_timeout(
+    let PointRequest {
+        point_request,
+        shard_key,
+    } = request.into_inner();
+
+    let pass = match check_strict_mode(
+        &point_request,
         params.timeout_as_secs(),
         &collection.name,
         &dispatcher,
@@ -144,11 +151,6 @@ async fn get_points(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
-    let PointRequest {
-        point_request,
-        shard_key,
-    } = request.into_inner();
-
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => ShardSelectorInternal::from(shard_keys),
@@ -193,8 +195,9 @@ async fn scroll_points(
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
     let ScrollRequest {
-        scroll_request,
+        mut scroll_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -210,6 +213,20 @@ async fn scroll_points(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    scroll_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        scroll_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => ShardSelectorInternal::from(shard_keys),
@@ -223,8 +240,7 @@ async fn scroll_points(
     );
     let timing = Instant::now();
 
-    let res = dispatcher
-        .toc(&auth, &pass)
+    let res = toc
         .scroll(
             &collection.name,
             scroll_request,
@@ -1,7 +1,12 @@
 use actix_web::{HttpResponse, Responder, post, web};
 use actix_web_validator::{Json, Path, Query};
-use api::rest::{SearchMatrixOffsetsResponse, SearchMatrixPairsResponse, SearchMatrixRequest};
+use api::rest::{
+    JoinRequest, JoinResponse, SampleProjectionRequest, SampleProjectionResponse,
+    SearchMatrixOffsetsResponse, SearchMatrixPairsResponse, SearchMatrixRequest,
+};
 use collection::collection::distance_matrix::CollectionSearchMatrixRequest;
+use collection::collection::join::CollectionJoinRequest;
+use collection::collection::sample_projection::CollectionSampleProjectionRequest;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
     CoreSearchRequest, SearchGroupsRequest, SearchRequest, SearchRequestBatch,
@@ -19,8 +24,10 @@ use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{
     get_request_hardware_counter, process_response, process_response_error,
 };
+use crate::common::filter_templates::resolve_filter_template;
 use crate::common::query::{
-    do_core_search_points, do_search_batch_points, do_search_point_groups, do_search_points_matrix,
+    do_core_search_points, do_join, do_sample_projection, do_search_batch_points,
+    do_search_point_groups, do_search_points_matrix,
 };
 use crate::settings::ServiceConfig;
 
@@ -34,8 +41,9 @@ async fn search_points(
     ActixAuth(auth): ActixAuth,
 ) -> HttpResponse {
     let SearchRequest {
-        search_request,
+        mut search_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -51,6 +59,20 @@ async fn search_points(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    search_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        search_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => shard_keys.into(),
@@ -66,7 +88,7 @@ async fn search_points(
     let timing = Instant::now();
 
     let result = do_core_search_points(
-        dispatcher.toc(&auth, &pass),
+        toc,
         &collection.name,
         search_request.into(),
         params.consistency,
@@ -95,24 +117,24 @@ async fn batch_search_points(
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
 ) -> HttpResponse {
-    let requests = request
-        .into_inner()
-        .searches
-        .into_iter()
-        .map(|req| {
-            let SearchRequest {
-                search_request,
-                shard_key,
-            } = req;
-            let shard_selection = match shard_key {
-                None => ShardSelectorInternal::All,
-                Some(shard_keys) => shard_keys.into(),
-            };
-            let core_request: CoreSearchRequest = search_request.into();
-
-            (core_request, shard_selection)
-        })
-        .collect::<Vec<_>>();
+    let mut requests = Vec::new();
+    let mut filter_templates = Vec::new();
+    for req in request.into_inner().searches {
+        let SearchRequest {
+            search_request,
+            shard_key,
+            filter_template,
+        } = req;
+
+        let shard_selection = match shard_key {
+            None => ShardSelectorInternal::All,
+            Some(shard_keys) => shard_keys.into(),
+        };
+        let core_request: CoreSearchRequest = search_request.into();
+
+        requests.push((core_request, shard_selection));
+        filter_templates.push(filter_template);
+    }
 
     let pass = match check_strict_mode_batch(
         requests.iter().map(|i| &i.0),
@@ -127,6 +149,22 @@ async fn batch_search_points(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    for ((core_request, _), filter_template) in requests.iter_mut().zip(filter_templates) {
+        core_request.filter = match resolve_filter_template(
+            toc,
+            &collection.name,
+            core_request.filter.take(),
+            filter_template,
+            &auth,
+        )
+        .await
+        {
+            Ok(filter) => filter,
+            Err(err) => return process_response_error(err, Instant::now(), None),
+        };
+    }
+
     let request_hw_counter = get_request_hardware_counter(
         &dispatcher,
         collection.name.clone(),
@@ -137,7 +175,7 @@ async fn batch_search_points(
     let timing = Instant::now();
 
     let result = do_search_batch_points(
-        dispatcher.toc(&auth, &pass),
+        toc,
         &collection.name,
         requests,
         params.consistency,
@@ -171,8 +209,9 @@ async fn search_point_groups(
     ActixAuth(auth): ActixAuth,
 ) -> HttpResponse {
     let SearchGroupsRequest {
-        search_group_request,
+        mut search_group_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -188,6 +227,20 @@ async fn search_point_groups(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    search_group_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        search_group_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => shard_keys.into(),
@@ -202,7 +255,7 @@ async fn search_point_groups(
     let timing = Instant::now();
 
     let result = do_search_point_groups(
-        dispatcher.toc(&auth, &pass),
+        toc,
         &collection.name,
         search_group_request,
         params.consistency,
@@ -226,8 +279,9 @@ async fn search_points_matrix_pairs(
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
     let SearchMatrixRequest {
-        search_request,
+        mut search_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -243,6 +297,20 @@ async fn search_points_matrix_pairs(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    search_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        search_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => shard_keys.into(),
@@ -257,7 +325,7 @@ async fn search_points_matrix_pairs(
     let timing = Instant::now();
 
     let response = do_search_points_matrix(
-        dispatcher.toc(&auth, &pass),
+        toc,
         &collection.name,
         CollectionSearchMatrixRequest::from(search_request),
         params.consistency,
@@ -282,8 +350,9 @@ async fn search_points_matrix_offsets(
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
     let SearchMatrixRequest {
-        search_request,
+        mut search_request,
         shard_key,
+        filter_template,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -299,6 +368,20 @@ async fn search_points_matrix_offsets(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
+    let toc = dispatcher.toc(&auth, &pass);
+    search_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        search_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => shard_keys.into(),
@@ -313,7 +396,7 @@ async fn search_points_matrix_offsets(
     let timing = Instant::now();
 
     let response = do_search_points_matrix(
-        dispatcher.toc(&auth, &pass),
+        toc,
         &collection.name,
         CollectionSearchMatrixRequest::from(search_request),
         params.consistency,
@@ -328,11 +411,155 @@ async fn search_points_matrix_offsets(
     process_response(response, timing, request_hw_counter.to_rest_api())
 }
 
+#[post("/collections/{name}/points/projection")]
+async fn sample_projection(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<SampleProjectionRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let SampleProjectionRequest {
+        mut projection_request,
+        shard_key,
+        filter_template,
+    } = request.into_inner();
+
+    let pass = match check_strict_mode(
+        &projection_request,
+        params.timeout_as_secs(),
+        &collection.name,
+        &dispatcher,
+        &auth,
+    )
+    .await
+    {
+        Ok(pass) => pass,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
+    let toc = dispatcher.toc(&auth, &pass);
+    projection_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        projection_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+    let timing = Instant::now();
+
+    let response = do_sample_projection(
+        toc,
+        &collection.name,
+        CollectionSampleProjectionRequest::from(projection_request),
+        params.consistency,
+        shard_selection,
+        auth,
+        params.timeout(),
+        request_hw_counter.get_counter(),
+    )
+    .await
+    .map(SampleProjectionResponse::from);
+
+    process_response(response, timing, request_hw_counter.to_rest_api())
+}
+
+#[post("/collections/{name}/points/join")]
+async fn join_points(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<JoinRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let JoinRequest {
+        mut join_request,
+        shard_key,
+        filter_template,
+    } = request.into_inner();
+
+    let pass = match check_strict_mode(
+        &join_request,
+        params.timeout_as_secs(),
+        &collection.name,
+        &dispatcher,
+        &auth,
+    )
+    .await
+    {
+        Ok(pass) => pass,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
+    let toc = dispatcher.toc(&auth, &pass);
+    join_request.filter = match resolve_filter_template(
+        toc,
+        &collection.name,
+        join_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await
+    {
+        Ok(filter) => filter,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+    let timing = Instant::now();
+
+    let response = do_join(
+        toc,
+        &collection.name,
+        CollectionJoinRequest::from(join_request),
+        params.consistency,
+        shard_selection,
+        auth,
+        params.timeout(),
+        request_hw_counter.get_counter(),
+    )
+    .await
+    .map(JoinResponse::from);
+
+    process_response(response, timing, request_hw_counter.to_rest_api())
+}
+
 // Configure services
 pub fn config_search_api(cfg: &mut web::ServiceConfig) {
     cfg.service(search_points)
         .service(batch_search_points)
         .service(search_point_groups)
         .service(search_points_matrix_pairs)
-        .service(search_points_matrix_offsets);
+        .service(search_points_matrix_offsets)
+        .service(sample_projection)
+        .service(join_points);
 }
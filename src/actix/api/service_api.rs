@@ -7,15 +7,20 @@ use actix_web::http::header::ContentType;
 use actix_web::rt::time::Instant;
 use actix_web::web::Data;
 use actix_web::{HttpResponse, Responder, get, post, web};
-use actix_web_validator::{Path, Query};
+use actix_web_validator::{Json, Path, Query};
+use collection::common::snapshot_bandwidth::{
+    set_snapshot_bandwidth_limits, snapshot_bandwidth_limits,
+};
 use collection::operations::verification::new_unchecked_verification_pass;
 use common::types::{DetailsLevel, TelemetryDetail};
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
 use serde::{Deserialize, Serialize};
 use storage::content_manager::errors::StorageError;
+use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
 use storage::rbac::AccessRequirements;
+use storage::types::{CpuPools, CpuWeightsConfig};
 use tokio::sync::Mutex;
 use validator::Validate;
 
@@ -219,6 +224,125 @@ async fn update_logger_config(
     helpers::process_response(future.await, timing, None)
 }
 
+/// Snapshot transfer bandwidth limits of this peer, in megabytes per second
+///
+/// Absent limit means unlimited.
+#[derive(Deserialize, Serialize, JsonSchema, Debug)]
+pub struct SnapshotBandwidthConfig {
+    #[serde(default)]
+    pub max_upload_mb_per_sec: Option<usize>,
+    #[serde(default)]
+    pub max_download_mb_per_sec: Option<usize>,
+}
+
+#[get("/snapshot_bandwidth")]
+async fn get_snapshot_bandwidth_config(ActixAuth(auth): ActixAuth) -> impl Responder {
+    helpers::time(async move {
+        auth.check_global_access(AccessRequirements::new(), "get_snapshot_bandwidth_config")?;
+        let (max_upload_mb_per_sec, max_download_mb_per_sec) = snapshot_bandwidth_limits();
+        Ok(SnapshotBandwidthConfig {
+            max_upload_mb_per_sec,
+            max_download_mb_per_sec,
+        })
+    })
+    .await
+}
+
+/// Change snapshot bandwidth limits of this peer
+///
+/// Changes are not persisted, the configured limits are applied again on restart.
+#[post("/snapshot_bandwidth")]
+async fn update_snapshot_bandwidth_config(
+    ActixAuth(auth): ActixAuth,
+    config: web::Json<SnapshotBandwidthConfig>,
+) -> impl Responder {
+    helpers::time(async move {
+        auth.check_global_access(
+            AccessRequirements::new().manage(),
+            "update_snapshot_bandwidth_config",
+        )?;
+        let SnapshotBandwidthConfig {
+            max_upload_mb_per_sec,
+            max_download_mb_per_sec,
+        } = config.into_inner();
+        set_snapshot_bandwidth_limits(max_upload_mb_per_sec, max_download_mb_per_sec);
+        Ok(true)
+    })
+    .await
+}
+
+/// Weights and sizes of search and optimization CPU pools of this peer
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct CpuPoolsInfo {
+    /// Configured weights of the pools, absent if pools are sized independently
+    pub weights: Option<CpuWeightsConfig>,
+    /// Number of threads of the search runtime
+    pub search_threads: usize,
+    /// Number of CPUs available for new optimizations. Running optimizations keep their CPUs
+    /// until they finish.
+    pub optimization_cpus: usize,
+    /// The search runtime is sized on startup and keeps its size until restart. True if the
+    /// current weights would size it differently. Weights changed at runtime are not persisted,
+    /// set them in the config file to apply them on restart.
+    pub search_restart_required: bool,
+}
+
+impl CpuPoolsInfo {
+    fn new(toc: &TableOfContent, pools: CpuPools) -> Self {
+        let CpuPools {
+            search,
+            optimization,
+        } = pools;
+        Self {
+            weights: toc.cpu_weights(),
+            search_threads: search,
+            optimization_cpus: optimization,
+            search_restart_required: toc.search_pool_restart_required(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Validate, Debug)]
+pub struct CpuWeightsUpdate {
+    /// New weights of the pools, null to size pools independently
+    #[serde(default)]
+    #[validate(nested)]
+    pub weights: Option<CpuWeightsConfig>,
+}
+
+#[get("/cpu_pools")]
+async fn get_cpu_pools(
+    dispatcher: web::Data<Dispatcher>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    helpers::time(async move {
+        auth.check_global_access(AccessRequirements::new(), "get_cpu_pools")?;
+        let toc = dispatcher.toc(&auth, &new_unchecked_verification_pass());
+        Ok(CpuPoolsInfo::new(toc, toc.cpu_pools()))
+    })
+    .await
+}
+
+/// Change weights of search and optimization CPU pools of this peer
+///
+/// Only the budget for new optimizations is changed at runtime, the search runtime keeps its size
+/// until restart. Changes are not persisted. Fails if `optimizer_cpu_budget` is set explicitly.
+#[post("/cpu_pools")]
+async fn update_cpu_pools(
+    dispatcher: web::Data<Dispatcher>,
+    ActixAuth(auth): ActixAuth,
+    update: Json<CpuWeightsUpdate>,
+) -> impl Responder {
+    helpers::time(async move {
+        auth.check_global_access(AccessRequirements::new().manage(), "update_cpu_pools")?;
+        let toc = dispatcher.toc(&auth, &new_unchecked_verification_pass());
+        let CpuWeightsUpdate { weights } = update.into_inner();
+        let pools = toc.set_cpu_weights(weights)?;
+        Ok(CpuPoolsInfo::new(toc, pools))
+    })
+    .await
+}
+
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct TruncateUnappliedWalParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -262,6 +386,10 @@ pub fn config_service_api(cfg: &mut web::ServiceConfig) {
         .service(readyz)
         .service(get_logger_config)
         .service(update_logger_config)
+        .service(get_snapshot_bandwidth_config)
+        .service(update_snapshot_bandwidth_config)
+        .service(get_cpu_pools)
+        .service(update_cpu_pools)
         .service(truncate_unapplied_wal);
 }
 
@@ -62,6 +62,15 @@ pub struct SnapshottingParam {
     pub wait: Option<bool>,
 }
 
+#[derive(Deserialize, Serialize, JsonSchema, Validate)]
+pub struct SnapshotCreatingParam {
+    pub wait: Option<bool>,
+
+    /// Name of the collection snapshot to create an incremental snapshot on top of.
+    /// Incremental snapshot only contains data changed since the base snapshot.
+    pub base_snapshot: Option<String>,
+}
+
 #[derive(MultipartForm)]
 pub struct SnapshottingForm {
     snapshot: TempFile,
@@ -166,24 +175,29 @@ async fn list_snapshots(
 async fn create_snapshot(
     dispatcher: web::Data<Dispatcher>,
     path: web::Path<String>,
-    params: valid::Query<SnapshottingParam>,
+    params: valid::Query<SnapshotCreatingParam>,
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
     // Nothing to verify.
     let pass = new_unchecked_verification_pass();
 
     let collection_name = path.into_inner();
+    let SnapshotCreatingParam {
+        wait,
+        base_snapshot,
+    } = params.into_inner();
 
     let future = async move {
         do_create_snapshot(
             dispatcher.toc(&auth, &pass).clone(),
             &auth,
             &collection_name,
+            base_snapshot,
         )
         .await
     };
 
-    helpers::time_or_accept(future, params.wait.unwrap_or(true)).await
+    helpers::time_or_accept(future, wait.unwrap_or(true)).await
 }
 
 #[post("/collections/{name}/snapshots/upload")]
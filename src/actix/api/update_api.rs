@@ -1,8 +1,8 @@
 use actix_web::rt::time::Instant;
-use actix_web::{Responder, delete, post, put, web};
+use actix_web::{Responder, delete, patch, post, put, web};
 use actix_web_validator::{Json, Path, Query};
-use api::rest::UpdateVectors;
 use api::rest::schema::PointInsertOperations;
+use api::rest::{CopyPointsRequest, UpdateVectors};
 use collection::operations::payload_ops::{DeletePayload, SetPayload};
 use collection::operations::point_ops::PointsSelector;
 use collection::operations::vector_ops::DeleteVectors;
@@ -13,6 +13,7 @@ use storage::dispatcher::Dispatcher;
 use validator::Validate;
 
 use super::CollectionPath;
+use crate::actix::api::read_params::ReadParams;
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{
     get_request_hardware_counter, process_response, process_response_with_inference_usage,
@@ -110,6 +111,71 @@ async fn delete_points(
     process_response(res, timing, request_hw_counter.to_rest_api())
 }
 
+#[post("/collections/{name}/points/restore")]
+async fn restore_points(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    operation: Json<RestorePoints>,
+    params: Query<UpdateParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let operation = operation.into_inner();
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        Some(params.wait),
+    );
+    let timing = Instant::now();
+
+    let res = do_restore_points(
+        StrictModeCheckedTocProvider::new(&dispatcher),
+        collection.into_inner().name,
+        operation,
+        params.into_inner(),
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await;
+
+    process_response(res, timing, request_hw_counter.to_rest_api())
+}
+
+#[post("/collections/{name}/points/delete/preview")]
+async fn preview_delete_points(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    operation: Json<PointsSelector>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let operation = operation.into_inner();
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+    let timing = Instant::now();
+
+    let res = do_preview_delete_points(
+        StrictModeCheckedTocProvider::new(&dispatcher),
+        collection.into_inner().name,
+        operation,
+        params.consistency,
+        params.timeout(),
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await;
+
+    process_response(res, timing, request_hw_counter.to_rest_api())
+}
+
 #[put("/collections/{name}/points/vectors")]
 #[allow(clippy::too_many_arguments)]
 async fn update_vectors(
@@ -224,6 +290,39 @@ async fn set_payload(
     process_response(res, timing, request_hw_counter.to_rest_api())
 }
 
+#[post("/collections/{name}/points/payload/preview")]
+async fn preview_set_payload(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    operation: Json<SetPayload>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let operation = operation.into_inner();
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+    let timing = Instant::now();
+
+    let res = do_preview_set_payload(
+        StrictModeCheckedTocProvider::new(&dispatcher),
+        collection.into_inner().name,
+        operation,
+        params.consistency,
+        params.timeout(),
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await;
+
+    process_response(res, timing, request_hw_counter.to_rest_api())
+}
+
 #[put("/collections/{name}/points/payload")]
 async fn overwrite_payload(
     dispatcher: web::Data<Dispatcher>,
@@ -257,6 +356,39 @@ async fn overwrite_payload(
     process_response(res, timing, request_hw_counter.to_rest_api())
 }
 
+#[patch("/collections/{name}/points/payload")]
+async fn patch_payload(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    operation: Json<SetPayload>,
+    params: Query<UpdateParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let operation = operation.into_inner();
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        Some(params.wait),
+    );
+    let timing = Instant::now();
+
+    let res = do_patch_payload(
+        StrictModeCheckedTocProvider::new(&dispatcher),
+        collection.into_inner().name,
+        operation,
+        InternalUpdateParams::default(),
+        params.into_inner(),
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await;
+
+    process_response(res, timing, request_hw_counter.to_rest_api())
+}
+
 #[post("/collections/{name}/points/payload/delete")]
 async fn delete_payload(
     dispatcher: web::Data<Dispatcher>,
@@ -323,6 +455,39 @@ async fn clear_payload(
     process_response(res, timing, request_hw_counter.to_rest_api())
 }
 
+#[post("/collections/{name}/points/copy")]
+async fn copy_points(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<CopyPointsRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let request = request.into_inner();
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+    let timing = Instant::now();
+
+    let res = do_copy_points(
+        StrictModeCheckedTocProvider::new(&dispatcher),
+        collection.into_inner().name,
+        request,
+        params.consistency,
+        params.timeout(),
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await;
+
+    process_response(res, timing, request_hw_counter.to_rest_api())
+}
+
 #[allow(clippy::too_many_arguments)]
 #[post("/collections/{name}/points/batch")]
 async fn update_batch(
@@ -474,12 +639,17 @@ async fn staging_operation(
 pub fn config_update_api(cfg: &mut web::ServiceConfig) {
     cfg.service(upsert_points)
         .service(delete_points)
+        .service(preview_delete_points)
+        .service(restore_points)
         .service(update_vectors)
         .service(delete_vectors)
         .service(set_payload)
+        .service(preview_set_payload)
         .service(overwrite_payload)
+        .service(patch_payload)
         .service(delete_payload)
         .service(clear_payload)
+        .service(copy_points)
         .service(create_field_index)
         .service(delete_field_index)
         .service(update_batch);
@@ -0,0 +1,273 @@
+use std::future::{Ready, ready};
+use std::io::{self, Write as _};
+use std::rc::Rc;
+
+use actix_web::body::{BodySize, EitherBody, MessageBody};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::http::header::{self, HeaderValue};
+use actix_web::web::{self, Bytes};
+use actix_web::{Error, error};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use futures_util::future::LocalBoxFuture;
+
+use crate::settings::CompressionConfig;
+
+/// Response compression with configurable codec levels.
+///
+/// Sized bodies (which is what all JSON responses are) are compressed here with `zstd` or `gzip`
+/// at the configured level. Everything else, like streamed snapshot downloads or clients
+/// preferring other encodings, falls through to actix' own `Compress` middleware, which must be
+/// wrapped right after this one.
+///
+/// Requests matching [`CompressionConfig::exclude_paths`] are marked with
+/// `Content-Encoding: identity`, which makes both this and the actix middleware skip them.
+pub struct CompressionTransform {
+    config: Rc<CompressionConfig>,
+}
+
+impl CompressionTransform {
+    pub fn new(config: CompressionConfig) -> Self {
+        Self {
+            config: Rc::new(config),
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for CompressionTransform
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B, Bytes>>;
+    type Error = Error;
+    type Transform = CompressionMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(CompressionMiddleware {
+            service,
+            config: self.config.clone(),
+        }))
+    }
+}
+
+pub struct CompressionMiddleware<S> {
+    service: S,
+    config: Rc<CompressionConfig>,
+}
+
+impl<S, B> Service<ServiceRequest> for CompressionMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B, Bytes>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, request: ServiceRequest) -> Self::Future {
+        let excluded = is_excluded(&self.config.exclude_paths, &request);
+        let codec = request
+            .headers()
+            .get(header::ACCEPT_ENCODING)
+            .and_then(|value| value.to_str().ok())
+            .and_then(negotiate);
+        let config = self.config.clone();
+
+        let future = self.service.call(request);
+
+        Box::pin(async move {
+            let mut response = future.await?;
+
+            if excluded {
+                response.headers_mut().insert(
+                    header::CONTENT_ENCODING,
+                    HeaderValue::from_static("identity"),
+                );
+                return Ok(response.map_into_left_body());
+            }
+
+            let Some(codec) = codec else {
+                return Ok(response.map_into_left_body());
+            };
+
+            // Only compress fully materialized bodies, leave streams to actix
+            let BodySize::Sized(size) = response.response().body().size() else {
+                return Ok(response.map_into_left_body());
+            };
+
+            if size == 0
+                || size < config.min_size_bytes as u64
+                || response.headers().contains_key(header::CONTENT_ENCODING)
+            {
+                return Ok(response.map_into_left_body());
+            }
+
+            let (request, response) = response.into_parts();
+            let (mut response, body) = response.into_parts();
+
+            let body = actix_web::body::to_bytes(body).await.map_err(|err| {
+                let err: Box<dyn std::error::Error> = err.into();
+                error::ErrorInternalServerError(err)
+            })?;
+
+            let level = match codec {
+                Codec::Zstd => config.zstd_level,
+                Codec::Gzip => config.gzip_level as i32,
+            };
+            let compressed = web::block(move || codec.encode(&body, level))
+                .await?
+                .map_err(error::ErrorInternalServerError)?;
+
+            let headers = response.headers_mut();
+            headers.remove(header::CONTENT_LENGTH);
+            headers.insert(header::CONTENT_ENCODING, codec.header_value());
+            headers.append(header::VARY, HeaderValue::from_static("accept-encoding"));
+
+            let response = response.set_body(Bytes::from(compressed));
+            Ok(ServiceResponse::new(request, response).map_into_right_body())
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Codec {
+    Zstd,
+    Gzip,
+}
+
+impl Codec {
+    fn header_value(self) -> HeaderValue {
+        match self {
+            Codec::Zstd => HeaderValue::from_static("zstd"),
+            Codec::Gzip => HeaderValue::from_static("gzip"),
+        }
+    }
+
+    fn encode(self, data: &[u8], level: i32) -> io::Result<Vec<u8>> {
+        match self {
+            Codec::Zstd => zstd::bulk::compress(data, level),
+            Codec::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::new(level as u32));
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+        }
+    }
+}
+
+/// Pick the codec to compress with from an `Accept-Encoding` header value.
+///
+/// Returns `None` if the client doesn't accept `zstd` or `gzip`, or prefers some other encoding.
+/// On equal quality `zstd` wins over `gzip`.
+fn negotiate(accept_encoding: &str) -> Option<Codec> {
+    let mut best: Option<(Codec, f32)> = None;
+    let mut best_other = 0.0f32;
+
+    for item in accept_encoding.split(',') {
+        let mut parts = item.split(';');
+        let name = parts.next().unwrap_or_default().trim();
+        let quality = parts
+            .find_map(|param| param.trim().strip_prefix("q="))
+            .map_or(1.0, |quality| quality.trim().parse::<f32>().unwrap_or(0.0));
+
+        if name.is_empty() || quality <= 0.0 {
+            continue;
+        }
+
+        let codec = if name.eq_ignore_ascii_case("zstd") {
+            Codec::Zstd
+        } else if name.eq_ignore_ascii_case("gzip") || name.eq_ignore_ascii_case("x-gzip") {
+            Codec::Gzip
+        } else {
+            best_other = best_other.max(quality);
+            continue;
+        };
+
+        best = match best {
+            Some((best_codec, best_quality))
+                if best_quality > quality
+                    || (best_quality == quality && best_codec == Codec::Zstd) =>
+            {
+                Some((best_codec, best_quality))
+            }
+            _ => Some((codec, quality)),
+        };
+    }
+
+    best.filter(|(_, quality)| *quality >= best_other)
+        .map(|(codec, _)| codec)
+}
+
+fn is_excluded(exclude_paths: &[String], request: &ServiceRequest) -> bool {
+    if exclude_paths.is_empty() {
+        return false;
+    }
+
+    let pattern = request.match_pattern();
+    let path = request.path();
+
+    exclude_paths.iter().any(|excluded| {
+        pattern.as_deref() == Some(excluded.as_str()) || is_path_prefix(excluded, path)
+    })
+}
+
+/// Whether `path` is `prefix` itself, or a path nested under it
+fn is_path_prefix(prefix: &str, path: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    match path.strip_prefix(prefix) {
+        Some(rest) => rest.is_empty() || rest.starts_with('/'),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(negotiate("gzip"), Some(Codec::Gzip));
+        assert_eq!(negotiate("zstd"), Some(Codec::Zstd));
+        assert_eq!(negotiate("gzip, deflate, br, zstd"), Some(Codec::Zstd));
+        assert_eq!(negotiate("zstd;q=0.5, gzip"), Some(Codec::Gzip));
+        assert_eq!(negotiate("gzip;q=0.8, zstd;q=0"), Some(Codec::Gzip));
+        assert_eq!(negotiate("br;q=1.0, gzip;q=0.5"), None);
+        assert_eq!(negotiate("identity"), None);
+        assert_eq!(negotiate(""), None);
+    }
+
+    #[test]
+    fn test_is_path_prefix() {
+        assert!(is_path_prefix("/collections", "/collections"));
+        assert!(is_path_prefix("/collections", "/collections/test/points"));
+        assert!(is_path_prefix("/collections/", "/collections/test"));
+        assert!(!is_path_prefix("/collections", "/collectionsfoo"));
+        assert!(!is_path_prefix("/collections", "/cluster"));
+    }
+
+    #[test]
+    fn test_encode_roundtrip() {
+        let data = br#"{"result":{"points":[]},"status":"ok"}"#.repeat(100);
+
+        let compressed = Codec::Zstd.encode(&data, 3).unwrap();
+        assert!(compressed.len() < data.len());
+        assert_eq!(
+            zstd::bulk::decompress(&compressed, data.len()).unwrap(),
+            data
+        );
+
+        let compressed = Codec::Gzip.encode(&data, 9).unwrap();
+        assert!(compressed.len() < data.len());
+        let mut decoder = flate2::read::GzDecoder::new(compressed.as_slice());
+        let mut decompressed = Vec::new();
+        io::Read::read_to_end(&mut decoder, &mut decompressed).unwrap();
+        assert_eq!(decompressed, data);
+    }
+}
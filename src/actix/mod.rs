@@ -2,6 +2,7 @@ pub mod actix_telemetry;
 pub mod api;
 mod auth;
 mod certificate_helpers;
+mod compression;
 mod forwarded;
 pub mod helpers;
 pub mod metrics_service;
@@ -23,11 +24,14 @@ use collection::operations::verification::new_unchecked_verification_pass;
 use storage::dispatcher::Dispatcher;
 use storage::rbac::{Access, Auth};
 
+use crate::actix::api::aggregate_api::config_aggregate_api;
 use crate::actix::api::cluster_api::config_cluster_api;
 use crate::actix::api::collections_api::config_collections_api;
 use crate::actix::api::count_api::count_points;
+use crate::actix::api::dataset_api::config_dataset_api;
 use crate::actix::api::debug_api::config_debugger_api;
 use crate::actix::api::discovery_api::config_discovery_api;
+use crate::actix::api::filter_template_api::config_filter_template_api;
 use crate::actix::api::issues_api::config_issues_api;
 use crate::actix::api::local_shard_api::config_local_shard_api;
 use crate::actix::api::profiler_api::config_profiler_api;
@@ -40,11 +44,13 @@ use crate::actix::api::shards_api::config_shards_api;
 use crate::actix::api::snapshot_api::config_snapshots_api;
 use crate::actix::api::update_api::config_update_api;
 use crate::actix::auth::{AuthTransform, WhitelistItem};
+use crate::actix::compression::CompressionTransform;
 use crate::actix::web_ui::{WEB_UI_PATH, web_ui_factory, web_ui_folder};
 use crate::common::auth::AuthKeys;
 use crate::common::debugger::DebuggerState;
 use crate::common::health;
 use crate::common::http_client::HttpClient;
+use crate::common::rebalance::Rebalancer;
 use crate::common::telemetry::TelemetryCollector;
 use crate::settings::{Settings, max_web_workers};
 use crate::tracing::LoggerHandle;
@@ -58,6 +64,7 @@ pub fn init(
     dispatcher: Arc<Dispatcher>,
     telemetry_collector: Arc<tokio::sync::Mutex<TelemetryCollector>>,
     health_checker: Option<Arc<health::HealthChecker>>,
+    rebalancer: Option<Arc<Rebalancer>>,
     settings: Settings,
     logger_handle: LoggerHandle,
 ) -> io::Result<()> {
@@ -79,6 +86,7 @@ pub fn init(
         let logger_handle_data = web::Data::new(logger_handle);
         let http_client = web::Data::new(HttpClient::from_settings(&settings)?);
         let health_checker = web::Data::new(health_checker);
+        let rebalancer = web::Data::new(rebalancer);
         let web_ui_available = web_ui_folder(&settings);
         let service_config = web::Data::new(settings.service.clone());
 
@@ -105,8 +113,17 @@ pub fn init(
                 .limit(settings.service.max_request_size_mb * 1024 * 1024)
                 .error_handler(|err, rec| validation_error_handler("JSON body", err, rec));
 
+            let compression = &settings.service.compression;
+
             let mut app = App::new()
-                .wrap(Compress::default()) // Reads the `Accept-Encoding` header to negotiate which compression codec to use.
+                // Compresses sized responses with configured `zstd`/`gzip` levels
+                .wrap(Condition::new(
+                    compression.enabled,
+                    CompressionTransform::new(compression.clone()),
+                ))
+                // Reads the `Accept-Encoding` header to negotiate which compression codec to use.
+                // Handles streamed responses and encodings not covered by the middleware above.
+                .wrap(Condition::new(compression.enabled, Compress::default()))
                 // api_key middleware
                 // note: the last call to `wrap()` or `wrap_fn()` is executed first
                 .wrap(ConditionEx::from_option(auth_keys.as_ref().map(
@@ -134,6 +151,7 @@ pub fn init(
                 .app_data(http_client.clone())
                 .app_data(debugger_state.clone())
                 .app_data(health_checker.clone())
+                .app_data(rebalancer.clone())
                 .app_data(validate_path_config)
                 .app_data(validate_query_config)
                 .app_data(validate_json_config)
@@ -151,8 +169,11 @@ pub fn init(
                 .configure(config_discovery_api)
                 .configure(config_query_api)
                 .configure(config_facet_api)
+                .configure(config_aggregate_api)
+                .configure(config_filter_template_api)
                 .configure(config_shards_api)
                 .configure(config_issues_api)
+                .configure(config_dataset_api)
                 .configure(config_debugger_api)
                 .configure(config_profiler_api)
                 .configure(config_local_shard_api)
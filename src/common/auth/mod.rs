@@ -170,6 +170,8 @@ impl AuthKeys {
             with_payload: Some(WithPayloadInterface::Bool(false)),
             with_vector: WithVector::Bool(false),
             order_by: None,
+            distinct_by: None,
+            with_version: None,
         };
 
         let res = self
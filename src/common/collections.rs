@@ -6,44 +6,60 @@ use api::grpc::qdrant::CollectionExists;
 use api::rest::models::{
     CollectionDescription, CollectionsResponse, ShardKeyDescription, ShardKeysResponse,
 };
+use collection::collection::filter_templates::FilterTemplates;
+use collection::collection::payload_index_schema::PayloadIndexSchema;
+use collection::collection_state::State;
 use collection::config::ShardingMethod;
 #[cfg(feature = "staging")]
 use collection::operations::cluster_ops::TestSlowDownOperation;
 use collection::operations::cluster_ops::{
     AbortTransferOperation, ClusterOperations, DropReplicaOperation, MoveShardOperation,
-    ReplicatePoints, ReplicatePointsOperation, ReplicateShardOperation, ReshardingDirection,
-    RestartTransfer, RestartTransferOperation, StartResharding,
+    ReplicatePoints, ReplicatePointsOperation, ReplicateShardOperation, Reshard, ReshardOperation,
+    ReshardingDirection, RestartTransfer, RestartTransferOperation, StartResharding,
 };
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::snapshot_ops::SnapshotDescription;
 use collection::operations::types::{
-    AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionsAliasesResponse,
+    AliasDescription, CollectionClusterInfo, CollectionConfig, CollectionInfo,
+    CollectionsAliasesResponse,
 };
 use collection::operations::verification::new_unchecked_verification_pass;
 use collection::shards::replica_set;
 use collection::shards::replica_set::replica_set_state;
-use collection::shards::resharding::ReshardKey;
+use collection::shards::resharding::{ReshardKey, ReshardTarget};
 use collection::shards::shard::{PeerId, ShardId, ShardsPlacement};
 use collection::shards::transfer::{
     ShardTransfer, ShardTransferKey, ShardTransferMethod, ShardTransferRestart,
 };
+use common::defaults;
 use itertools::Itertools;
 use rand::prelude::SliceRandom;
 use rand::seq::IteratorRandom;
+use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+use segment::types::ShardKey;
+use shard::snapshots::snapshot_data::SnapshotData;
 use storage::content_manager::collection_meta_ops::ShardTransferOperations::{Abort, Start};
 #[cfg(feature = "staging")]
 use storage::content_manager::collection_meta_ops::TestSlowDown;
 use storage::content_manager::collection_meta_ops::{
-    CollectionMetaOperations, CreateShardKey, DropShardKey, ReshardingOperation,
-    SetShardReplicaState, ShardTransferOperations, UpdateCollectionOperation,
+    CloneCollection, CollectionMetaOperations, CreateCollection, CreateCollectionOperation,
+    CreatePayloadIndex, CreateShardKey, DeleteCollectionOperation, DropShardKey,
+    ReshardingOperation, SetFilterTemplate, SetShardReplicaState, ShardTransferOperations,
+    UpdateCollectionOperation,
+};
+use storage::content_manager::config_changes::{
+    CollectionConfigChanges, collection_config_changes,
 };
 use storage::content_manager::errors::StorageError;
+use storage::content_manager::shard_distribution::ShardDistributionProposal;
+use storage::content_manager::snapshots::recover::recover_from_collection_snapshot;
 use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
-use storage::rbac::AccessRequirements;
+use storage::rbac::{AccessRequirements, CollectionPass};
 use uuid::Uuid;
 
 use super::auth::Auth;
+use super::resharding;
 
 pub async fn do_collection_exists(
     toc: &TableOfContent,
@@ -83,6 +99,24 @@ pub async fn do_get_collection(
     Ok(collection.info(&shard_selection).await?)
 }
 
+pub async fn do_get_collection_config_changes(
+    toc: &TableOfContent,
+    auth: &Auth,
+    name: &str,
+    proposed: CreateCollection,
+) -> Result<CollectionConfigChanges, StorageError> {
+    let collection_pass = auth.check_collection_access(
+        name,
+        AccessRequirements::new(),
+        "get_collection_config_changes",
+    )?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+    let live = CollectionConfig::from(collection.state().await.config);
+
+    collection_config_changes(&live, &proposed)
+}
+
 pub async fn do_list_collections(
     toc: &TableOfContent,
     auth: &Auth,
@@ -124,6 +158,65 @@ pub async fn do_get_collection_shard_keys(
     Ok(ShardKeysResponse { shard_keys })
 }
 
+/// Cancel ongoing optimizations and payload index builds of the collection on this peer.
+///
+/// Returns the number of cancelled optimizations.
+pub async fn do_cancel_optimizations(
+    toc: &TableOfContent,
+    auth: &Auth,
+    name: &str,
+    optimizer: Option<&str>,
+) -> Result<usize, StorageError> {
+    let collection_pass = auth.check_collection_access(
+        name,
+        AccessRequirements::new().manage(),
+        "cancel_optimizations",
+    )?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+    Ok(collection.cancel_optimizations(optimizer).await?)
+}
+
+/// Resume cancelled optimizations and payload index builds of the collection on this peer.
+pub async fn do_resume_optimizations(
+    toc: &TableOfContent,
+    auth: &Auth,
+    name: &str,
+) -> Result<(), StorageError> {
+    let collection_pass = auth.check_collection_access(
+        name,
+        AccessRequirements::new().manage(),
+        "resume_optimizations",
+    )?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+    collection.resume_optimizations().await;
+    Ok(())
+}
+
+/// Load the vector of the collection into memory on this peer if `pinned`, or evict it otherwise.
+///
+/// If `vector_name` is not specified, the default vector is used.
+pub async fn do_set_vector_pinned(
+    toc: &TableOfContent,
+    auth: &Auth,
+    name: &str,
+    vector_name: Option<&str>,
+    pinned: bool,
+) -> Result<(), StorageError> {
+    let collection_pass = auth.check_collection_access(
+        name,
+        AccessRequirements::new().manage(),
+        if pinned { "pin_vector" } else { "unpin_vector" },
+    )?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+    collection
+        .pin_vector(vector_name.unwrap_or(DEFAULT_VECTOR_NAME), pinned)
+        .await?;
+    Ok(())
+}
+
 /// Construct shards-replicas layout for the shard from the given scope of peers
 /// Example:
 ///   Shards: 3
@@ -215,6 +308,7 @@ pub async fn do_create_snapshot(
     toc: Arc<TableOfContent>,
     auth: &Auth,
     collection_name: &str,
+    base_snapshot: Option<String>,
 ) -> Result<SnapshotDescription, StorageError> {
     let collection_pass = auth
         .check_collection_access(
@@ -224,11 +318,221 @@ pub async fn do_create_snapshot(
         )?
         .into_static();
 
-    let result = tokio::spawn(async move { toc.create_snapshot(&collection_pass).await }).await??;
+    let result = tokio::spawn(async move {
+        toc.create_snapshot(&collection_pass, base_snapshot.as_deref())
+            .await
+    })
+    .await??;
+
+    Ok(result)
+}
+
+/// Create a new collection with config, payload indexes and filter templates of the existing one,
+/// and recover its points from a snapshot of the existing collection.
+///
+/// The snapshot makes the clone consistent, even if the existing collection is updated meanwhile.
+/// Indexes of the new collection are rebuilt by optimizers, so the config can be changed on the
+/// way. Shards are placed on the same peers as in the existing collection, and this peer must hold
+/// an active replica of every shard. Other replicas are synchronized from the recovered ones.
+///
+/// If cloning fails after the new collection is created, the new collection is deleted.
+pub async fn do_clone_collection(
+    dispatcher: &Dispatcher,
+    collection_name: &str,
+    request: CloneCollection,
+    auth: Auth,
+    wait_timeout: Option<Duration>,
+) -> Result<bool, StorageError> {
+    let multipass =
+        auth.check_global_access(AccessRequirements::new().manage(), "clone_collection")?;
+    let collection_pass = multipass.issue_pass(collection_name).into_static();
+    let target_pass = multipass
+        .issue_pass(&request.target_collection)
+        .into_static();
+
+    let dispatcher = dispatcher.clone();
+
+    // Recovery of the snapshot is not cancel safe, so cloning continues if the request is dropped
+    let result = tokio::spawn(async move {
+        clone_collection(
+            &dispatcher,
+            &auth,
+            &collection_pass,
+            &target_pass,
+            &request,
+            wait_timeout,
+        )
+        .await
+    })
+    .await??;
 
     Ok(result)
 }
 
+/// # Cancel safety
+///
+/// This method is *not* cancel safe.
+async fn clone_collection(
+    dispatcher: &Dispatcher,
+    auth: &Auth,
+    collection_pass: &CollectionPass<'static>,
+    target_pass: &CollectionPass<'static>,
+    request: &CloneCollection,
+    wait_timeout: Option<Duration>,
+) -> Result<bool, StorageError> {
+    // All checks should've been done at this point.
+    let pass = new_unchecked_verification_pass();
+    let toc = dispatcher.toc(auth, &pass);
+
+    let State {
+        mut config,
+        shards,
+        payload_index_schema,
+        filter_templates,
+        ..
+    } = toc.get_collection(collection_pass).await?.state().await;
+
+    if config.params.sharding_method.unwrap_or_default() == ShardingMethod::Custom {
+        return Err(StorageError::bad_request(
+            "Cloning of collections with custom sharding is not supported",
+        ));
+    }
+
+    // Snapshot of a collection contains only shards of this peer
+    let this_peer_id = toc.this_peer_id;
+    let mut distribution = Vec::with_capacity(shards.len());
+    for (shard_id, shard_info) in shards {
+        if shard_info.replicas.get(&this_peer_id) != Some(&replica_set_state::ReplicaState::Active)
+        {
+            return Err(StorageError::bad_request(format!(
+                "Collection {collection_pass} can only be cloned on a peer with an active replica \
+                 of every shard, peer {this_peer_id} has no active replica of shard {shard_id}",
+            )));
+        }
+        distribution.push((shard_id, shard_info.replicas.into_keys().collect()));
+    }
+
+    request.apply_to(&mut config);
+    // New collection must get its own UUID
+    config.uuid = None;
+
+    let mut operation = CreateCollectionOperation::new(target_pass.to_string(), config.into())?;
+    operation.set_distribution(ShardDistributionProposal { distribution });
+    dispatcher
+        .submit_collection_meta_op(
+            CollectionMetaOperations::CreateCollection(operation),
+            auth.clone(),
+            wait_timeout,
+        )
+        .await?;
+
+    let result = recover_clone(
+        dispatcher,
+        auth,
+        collection_pass,
+        target_pass,
+        payload_index_schema,
+        filter_templates,
+        wait_timeout,
+    )
+    .await;
+
+    if let Err(err) = &result {
+        log::warn!("Failed to clone collection {collection_pass} into {target_pass}: {err}");
+
+        let operation = CollectionMetaOperations::DeleteCollection(DeleteCollectionOperation(
+            target_pass.to_string(),
+        ));
+        if let Err(err) = dispatcher
+            .submit_collection_meta_op(operation, auth.clone(), wait_timeout)
+            .await
+        {
+            log::error!("Failed to delete collection {target_pass} after failed cloning: {err}");
+        }
+    }
+
+    result
+}
+
+/// Create payload indexes and filter templates of the new collection, and recover its points from
+/// a snapshot of the existing collection
+async fn recover_clone(
+    dispatcher: &Dispatcher,
+    auth: &Auth,
+    collection_pass: &CollectionPass<'static>,
+    target_pass: &CollectionPass<'static>,
+    payload_index_schema: PayloadIndexSchema,
+    filter_templates: FilterTemplates,
+    wait_timeout: Option<Duration>,
+) -> Result<bool, StorageError> {
+    let pass = new_unchecked_verification_pass();
+    let toc = dispatcher.toc(auth, &pass);
+
+    for (field_name, field_schema) in payload_index_schema.schema {
+        let operation = CollectionMetaOperations::CreatePayloadIndex(CreatePayloadIndex {
+            collection_name: target_pass.to_string(),
+            field_name,
+            field_schema,
+        });
+        dispatcher
+            .submit_collection_meta_op(operation, auth.clone(), wait_timeout)
+            .await?;
+    }
+
+    for (template_name, template) in filter_templates.templates {
+        let operation = CollectionMetaOperations::SetFilterTemplate(SetFilterTemplate {
+            collection_name: target_pass.to_string(),
+            template_name,
+            template,
+        });
+        dispatcher
+            .submit_collection_meta_op(operation, auth.clone(), wait_timeout)
+            .await?;
+    }
+
+    // Replicas of the new collection must be active, before they are recovered
+    let target = toc.get_collection(target_pass).await?;
+    let activation_timeout = wait_timeout.unwrap_or(defaults::CONSENSUS_META_OP_WAIT);
+    for shard_id in target.get_local_shards().await {
+        target
+            .wait_local_shard_replica_state(
+                shard_id,
+                replica_set_state::ReplicaState::Active,
+                activation_timeout,
+            )
+            .await?;
+    }
+
+    let snapshot = toc.create_snapshot(collection_pass, None).await?;
+
+    let snapshot_manager = toc.get_snapshots_storage_manager()?;
+    let snapshot_path = snapshot_manager.get_snapshot_path(
+        &toc.snapshots_path_for_collection(collection_pass.name()),
+        &snapshot.name,
+    )?;
+
+    let recovered = async {
+        let snapshot_file = snapshot_manager
+            .get_snapshot_file(&snapshot_path, &toc.optional_temp_or_storage_temp_path()?)
+            .await?;
+        recover_from_collection_snapshot(
+            dispatcher,
+            auth,
+            target_pass,
+            SnapshotData::Packed(snapshot_file),
+        )
+        .await
+    }
+    .await;
+
+    // Snapshot is only used for cloning, it is not kept
+    if let Err(err) = snapshot_manager.delete_snapshot(&snapshot_path).await {
+        log::warn!("Failed to delete snapshot {snapshot_path:?} of cloned collection: {err}");
+    }
+
+    recovered
+}
+
 pub async fn do_get_collection_cluster(
     toc: &TableOfContent,
     auth: &Auth,
@@ -562,6 +866,8 @@ pub async fn do_update_collection_cluster(
             let exact_placement =
                 generate_even_placement(peers_pool, shard_number, replication_factor);
 
+            let config = create_sharding_key.shard_key_config();
+
             dispatcher
                 .submit_collection_meta_op(
                     CollectionMetaOperations::CreateShardKey(CreateShardKey {
@@ -569,6 +875,7 @@ pub async fn do_update_collection_cluster(
                         shard_key: create_sharding_key.shard_key,
                         placement: exact_placement,
                         initial_state: create_sharding_key.initial_state,
+                        config,
                     }),
                     auth,
                     wait_timeout,
@@ -684,75 +991,17 @@ pub async fn do_update_collection_cluster(
                 )));
             }
 
-            let shard_id = match (direction, shard_key.as_ref()) {
-                // When scaling up, just pick the next shard ID
-                (ReshardingDirection::Up, _) => {
-                    collection_state
-                        .shards
-                        .keys()
-                        .copied()
-                        .max()
-                        .expect("collection must contain shards")
-                        + 1
-                }
-                // When scaling down without shard keys, pick the last shard ID
-                (ReshardingDirection::Down, None) => collection_state
-                    .shards
-                    .keys()
-                    .copied()
-                    .max()
-                    .expect("collection must contain shards"),
-                // When scaling down with shard keys, pick the last shard ID of that key
-                (ReshardingDirection::Down, Some(shard_key)) => collection_state
-                    .shards_key_mapping
-                    .get(shard_key)
-                    .expect("specified shard key must exist")
-                    .iter()
-                    .copied()
-                    .max()
-                    .expect("collection must contain shards"),
-            };
-
-            let peer_id = match (peer_id, direction) {
-                // Select user specified peer, but make sure it exists
-                (Some(peer_id), _) => {
-                    validate_peer_exists(peer_id)?;
-                    peer_id
-                }
-
-                // When scaling up, select peer with least number of shards for this collection
-                (None, ReshardingDirection::Up) => {
-                    let mut shards_on_peers = collection_state
-                        .shards
-                        .values()
-                        .flat_map(|shard_info| shard_info.replicas.keys())
-                        .fold(HashMap::new(), |mut counts, peer_id| {
-                            *counts.entry(*peer_id).or_insert(0) += 1;
-                            counts
-                        });
-                    for peer_id in get_all_peer_ids() {
-                        // Add registered peers not holding any shard yet
-                        shards_on_peers.entry(peer_id).or_insert(0);
-                    }
-                    shards_on_peers
-                        .into_iter()
-                        .min_by_key(|(_, count)| *count)
-                        .map(|(peer_id, _)| peer_id)
-                        .expect("expected at least one peer")
-                }
+            if let Some(peer_id) = peer_id {
+                validate_peer_exists(peer_id)?;
+            }
 
-                // When scaling down, select random peer that contains the shard we're dropping
-                // Other peers work, but are less efficient due to remote operations
-                (None, ReshardingDirection::Down) => collection_state
-                    .shards
-                    .get(&shard_id)
-                    .expect("select shard ID must always exist in collection state")
-                    .replicas
-                    .keys()
-                    .choose(&mut rand::rng())
-                    .copied()
-                    .unwrap(),
-            };
+            let (shard_id, peer_id) = select_resharding_replica(
+                &collection_state,
+                direction,
+                shard_key.as_ref(),
+                peer_id,
+                get_all_peer_ids(),
+            );
 
             if let Some(resharding) = &collection_state.resharding {
                 return Err(StorageError::bad_request(format!(
@@ -771,6 +1020,7 @@ pub async fn do_update_collection_cluster(
                             peer_id,
                             shard_id,
                             shard_key,
+                            target: None,
                         }),
                     ),
                     auth,
@@ -778,6 +1028,49 @@ pub async fn do_update_collection_cluster(
                 )
                 .await
         }
+        ClusterOperations::Reshard(ReshardOperation { reshard }) => {
+            let Reshard {
+                shard_number,
+                shard_key,
+            } = reshard;
+
+            if !dispatcher.is_resharding_enabled() {
+                return Err(StorageError::bad_request(
+                    "resharding is only supported in Qdrant Cloud",
+                ));
+            }
+
+            let collection_state = collection.state().await;
+
+            if let Some(resharding) = &collection_state.resharding {
+                return Err(StorageError::bad_request(format!(
+                    "resharding {resharding:?} is already in progress \
+                     for collection {collection_name}"
+                )));
+            }
+
+            let shard_count = resharding::shard_ids(&collection_state, shard_key.as_ref())?.len();
+            if shard_count == shard_number as usize {
+                return Ok(true);
+            }
+
+            let target = ReshardTarget {
+                shard_number,
+                driver_peer_id: consensus_state.this_peer_id(),
+            };
+
+            // Resharding takes a while, drive it in the background
+            let task = tokio::spawn(resharding::reshard_collection(
+                dispatcher.clone(),
+                collection_name,
+                target,
+                shard_key,
+                auth,
+            ));
+            drop(task);
+
+            Ok(true)
+        }
         ClusterOperations::AbortResharding(_) => {
             // TODO(reshading): Deduplicate resharding operations handling?
 
@@ -791,13 +1084,7 @@ pub async fn do_update_collection_cluster(
                 .submit_collection_meta_op(
                     CollectionMetaOperations::Resharding(
                         collection_name.clone(),
-                        ReshardingOperation::Abort(ReshardKey {
-                            uuid: state.uuid,
-                            direction: state.direction,
-                            peer_id: state.peer_id,
-                            shard_id: state.shard_id,
-                            shard_key: state.shard_key.clone(),
-                        }),
+                        ReshardingOperation::Abort(state.key()),
                     ),
                     auth,
                     wait_timeout,
@@ -891,13 +1178,7 @@ pub async fn do_update_collection_cluster(
                 .submit_collection_meta_op(
                     CollectionMetaOperations::Resharding(
                         collection_name.clone(),
-                        ReshardingOperation::CommitRead(ReshardKey {
-                            uuid: state.uuid,
-                            direction: state.direction,
-                            peer_id: state.peer_id,
-                            shard_id: state.shard_id,
-                            shard_key: state.shard_key.clone(),
-                        }),
+                        ReshardingOperation::CommitRead(state.key()),
                     ),
                     auth,
                     wait_timeout,
@@ -920,13 +1201,7 @@ pub async fn do_update_collection_cluster(
                 .submit_collection_meta_op(
                     CollectionMetaOperations::Resharding(
                         collection_name.clone(),
-                        ReshardingOperation::CommitWrite(ReshardKey {
-                            uuid: state.uuid,
-                            direction: state.direction,
-                            peer_id: state.peer_id,
-                            shard_id: state.shard_id,
-                            shard_key: state.shard_key.clone(),
-                        }),
+                        ReshardingOperation::CommitWrite(state.key()),
                     ),
                     auth,
                     wait_timeout,
@@ -957,6 +1232,86 @@ pub async fn do_update_collection_cluster(
     }
 }
 
+/// Select shard to add or remove by resharding, and peer to drive it from
+///
+/// Uses `peer_id` if specified, it must be validated by the caller.
+pub(crate) fn select_resharding_replica(
+    collection_state: &State,
+    direction: ReshardingDirection,
+    shard_key: Option<&ShardKey>,
+    peer_id: Option<PeerId>,
+    all_peer_ids: Vec<PeerId>,
+) -> (ShardId, PeerId) {
+    let shard_id = match (direction, shard_key) {
+        // When scaling up, just pick the next shard ID
+        (ReshardingDirection::Up, _) => {
+            collection_state
+                .shards
+                .keys()
+                .copied()
+                .max()
+                .expect("collection must contain shards")
+                + 1
+        }
+        // When scaling down without shard keys, pick the last shard ID
+        (ReshardingDirection::Down, None) => collection_state
+            .shards
+            .keys()
+            .copied()
+            .max()
+            .expect("collection must contain shards"),
+        // When scaling down with shard keys, pick the last shard ID of that key
+        (ReshardingDirection::Down, Some(shard_key)) => collection_state
+            .shards_key_mapping
+            .get(shard_key)
+            .expect("specified shard key must exist")
+            .iter()
+            .copied()
+            .max()
+            .expect("collection must contain shards"),
+    };
+
+    let peer_id = match (peer_id, direction) {
+        // Select user specified peer
+        (Some(peer_id), _) => peer_id,
+
+        // When scaling up, select peer with least number of shards for this collection
+        (None, ReshardingDirection::Up) => {
+            let mut shards_on_peers = collection_state
+                .shards
+                .values()
+                .flat_map(|shard_info| shard_info.replicas.keys())
+                .fold(HashMap::new(), |mut counts, peer_id| {
+                    *counts.entry(*peer_id).or_insert(0) += 1;
+                    counts
+                });
+            for peer_id in all_peer_ids {
+                // Add registered peers not holding any shard yet
+                shards_on_peers.entry(peer_id).or_insert(0);
+            }
+            shards_on_peers
+                .into_iter()
+                .min_by_key(|(_, count)| *count)
+                .map(|(peer_id, _)| peer_id)
+                .expect("expected at least one peer")
+        }
+
+        // When scaling down, select random peer that contains the shard we're dropping
+        // Other peers work, but are less efficient due to remote operations
+        (None, ReshardingDirection::Down) => collection_state
+            .shards
+            .get(&shard_id)
+            .expect("select shard ID must always exist in collection state")
+            .replicas
+            .keys()
+            .choose(&mut rand::rng())
+            .copied()
+            .unwrap(),
+    };
+
+    (shard_id, peer_id)
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
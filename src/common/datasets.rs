@@ -0,0 +1,271 @@
+//! Sample datasets to bootstrap demo collections from.
+//!
+//! Datasets are JSON documents holding a collection config, payload indexes to create and the
+//! points themselves. A couple of small ones are bundled into the binary, others can be loaded
+//! from a URL in the same format.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use api::rest::schema::PointInsertOperations;
+use api::rest::{PointStruct, PointsList};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::validation::validate_collection_name;
+use reqwest::Url;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use storage::content_manager::collection_meta_ops::{
+    CollectionMetaOperations, CreateCollection, CreateCollectionOperation,
+};
+use storage::content_manager::errors::StorageError;
+use storage::dispatcher::Dispatcher;
+use storage::rbac::{AccessRequirements, Auth};
+use validator::Validate;
+
+use crate::common::inference::api_keys::InferenceApiKeys;
+use crate::common::inference::params::InferenceParams;
+use crate::common::strict_mode::StrictModeCheckedTocProvider;
+use crate::common::update::{
+    CreateFieldIndex, InternalUpdateParams, UpdateParams, do_create_index, do_upsert_points,
+};
+
+/// Datasets shipped with the binary, by name
+const BUNDLED_DATASETS: &[(&str, &str)] = &[
+    ("cities", include_str!("datasets/cities.json")),
+    ("colors", include_str!("datasets/colors.json")),
+];
+
+/// Maximal size of a dataset downloaded from a URL
+const MAX_DOWNLOAD_SIZE_BYTES: usize = 256 * 1024 * 1024;
+
+/// Number of points to upsert in a single request
+const UPSERT_BATCH_SIZE: usize = 256;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct Dataset {
+    pub description: String,
+    #[validate(nested)]
+    pub collection: CreateCollection,
+    #[serde(default)]
+    #[validate(nested)]
+    pub payload_indexes: Vec<CreateFieldIndex>,
+    #[validate(nested)]
+    pub points: Vec<PointStruct>,
+}
+
+impl Dataset {
+    fn parse(data: &[u8]) -> Result<Self, StorageError> {
+        let dataset: Self = serde_json::from_slice(data)
+            .map_err(|err| StorageError::bad_input(format!("Invalid dataset: {err}")))?;
+        dataset
+            .validate()
+            .map_err(|err| StorageError::bad_input(format!("Invalid dataset: {err}")))?;
+        Ok(dataset)
+    }
+
+    pub fn bundled(name: &str) -> Result<Self, StorageError> {
+        let (_, data) = BUNDLED_DATASETS
+            .iter()
+            .find(|(bundled_name, _)| *bundled_name == name)
+            .ok_or_else(|| StorageError::not_found(format!("Dataset {name} not found")))?;
+        Self::parse(data.as_bytes())
+    }
+
+    /// Download a dataset from `url`, which must point to one of the `allowed_hosts`.
+    ///
+    /// The `client` is expected not to follow redirects, which could lead to any other host.
+    pub async fn download(
+        client: &reqwest::Client,
+        url: Url,
+        allowed_hosts: &[String],
+    ) -> Result<Self, StorageError> {
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(StorageError::bad_input(format!(
+                "Invalid dataset URL {url}: URLs with {} scheme are not supported",
+                url.scheme(),
+            )));
+        }
+
+        if allowed_hosts.is_empty() {
+            return Err(StorageError::forbidden(
+                "Loading datasets from URLs is disabled, \
+                 allow dataset hosts with service.dataset_url_hosts config",
+            ));
+        }
+
+        let is_allowed = url.host_str().is_some_and(|host| {
+            allowed_hosts
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(host))
+        });
+        if !is_allowed {
+            return Err(StorageError::forbidden(format!(
+                "Loading datasets from {} is not allowed",
+                url.host_str().unwrap_or_default(),
+            )));
+        }
+
+        let mut response = client.get(url.clone()).send().await?.error_for_status()?;
+        if response.status().is_redirection() {
+            return Err(StorageError::bad_input(format!(
+                "Dataset {url} redirects to another location, redirects are not followed",
+            )));
+        }
+
+        let mut data = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if data.len() + chunk.len() > MAX_DOWNLOAD_SIZE_BYTES {
+                return Err(StorageError::bad_input(format!(
+                    "Dataset {url} exceeds maximal size of {MAX_DOWNLOAD_SIZE_BYTES} bytes",
+                )));
+            }
+            data.extend_from_slice(&chunk);
+        }
+
+        Self::parse(&data)
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DatasetDescription {
+    pub name: String,
+    pub description: String,
+    pub points_count: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate)]
+pub struct LoadDatasetRequest {
+    /// Name of a bundled dataset, see `GET /datasets`. Mutually exclusive with `url`.
+    pub dataset: Option<String>,
+    /// URL of a dataset in the bundled datasets format. Mutually exclusive with `dataset`. Its host
+    /// must be listed in `service.dataset_url_hosts` config.
+    pub url: Option<Url>,
+    /// Name of the collection to create. Default is the name of the bundled dataset, required
+    /// when loading from `url`.
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "validate_collection_name")
+    )]
+    pub collection_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct LoadDatasetResponse {
+    pub collection_name: String,
+    pub points_count: usize,
+}
+
+pub fn do_list_datasets(auth: &Auth) -> Result<Vec<DatasetDescription>, StorageError> {
+    auth.check_global_access(AccessRequirements::new(), "list_datasets")?;
+
+    BUNDLED_DATASETS
+        .iter()
+        .map(|(name, data)| {
+            let dataset = Dataset::parse(data.as_bytes())?;
+            Ok(DatasetDescription {
+                name: (*name).to_string(),
+                description: dataset.description,
+                points_count: dataset.points.len(),
+            })
+        })
+        .collect()
+}
+
+/// Create a new collection with payload indexes and points from the dataset.
+///
+/// If loading fails after the collection is created, the partially filled collection is kept.
+pub async fn do_load_dataset(
+    dispatcher: Arc<Dispatcher>,
+    collection_name: String,
+    dataset: Dataset,
+    auth: Auth,
+    timeout: Option<Duration>,
+) -> Result<LoadDatasetResponse, StorageError> {
+    auth.check_global_access(AccessRequirements::new().manage(), "load_dataset")?;
+
+    let Dataset {
+        description: _,
+        collection,
+        payload_indexes,
+        points,
+    } = dataset;
+
+    let create_collection = CreateCollectionOperation::new(collection_name.clone(), collection)?;
+    dispatcher
+        .submit_collection_meta_op(
+            CollectionMetaOperations::CreateCollection(create_collection),
+            auth.clone(),
+            timeout,
+        )
+        .await?;
+
+    let params = UpdateParams {
+        wait: true,
+        ordering: Default::default(),
+        timeout,
+    };
+
+    for index in payload_indexes {
+        do_create_index(
+            dispatcher.clone(),
+            collection_name.clone(),
+            index,
+            InternalUpdateParams::default(),
+            params,
+            auth.clone(),
+            HwMeasurementAcc::disposable(),
+        )
+        .await?;
+    }
+
+    let points_count = points.len();
+    let mut points = points.into_iter();
+    loop {
+        let batch: Vec<_> = points.by_ref().take(UPSERT_BATCH_SIZE).collect();
+        if batch.is_empty() {
+            break;
+        }
+
+        let operation = PointInsertOperations::PointsList(PointsList {
+            points: batch,
+            shard_key: None,
+            update_filter: None,
+            update_mode: None,
+        });
+
+        do_upsert_points(
+            StrictModeCheckedTocProvider::new(&dispatcher),
+            collection_name.clone(),
+            operation,
+            InternalUpdateParams::default(),
+            params,
+            auth.clone(),
+            InferenceParams::new(InferenceApiKeys::default(), timeout),
+            HwMeasurementAcc::disposable(),
+        )
+        .await?;
+    }
+
+    Ok(LoadDatasetResponse {
+        collection_name,
+        points_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_datasets_are_valid() {
+        for (name, _) in BUNDLED_DATASETS {
+            let dataset = Dataset::bundled(name).unwrap();
+            assert!(!dataset.points.is_empty(), "dataset {name} has no points");
+        }
+
+        assert!(matches!(
+            Dataset::bundled("missing"),
+            Err(StorageError::NotFound { .. }),
+        ));
+    }
+}
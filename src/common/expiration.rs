@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use storage::content_manager::toc::TableOfContent;
+use tokio::time::MissedTickBehavior;
+
+/// Interval between deletions of expired points
+const EXPIRATION_CLEANUP_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Periodically delete expired points of collections, which have points expiration enabled,
+/// and points which were kept in the trash for longer than its retention period
+pub async fn run_expiration_cleanup(toc: Arc<TableOfContent>) {
+    let mut interval = tokio::time::interval(EXPIRATION_CLEANUP_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        toc.delete_expired_points().await;
+    }
+}
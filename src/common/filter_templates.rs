@@ -0,0 +1,132 @@
+//! Named filters stored in a collection and referenced from filtered requests.
+
+use std::time::Duration;
+
+use api::rest::FilterTemplateReference;
+use collection::collection::filter_templates::{FilterTemplate, FilterTemplates};
+use collection::operations::verification::new_unchecked_verification_pass;
+use segment::types::{Condition, Filter};
+use storage::content_manager::collection_meta_ops::{
+    CollectionMetaOperations, DeleteFilterTemplate, SetFilterTemplate,
+};
+use storage::content_manager::errors::StorageError;
+use storage::content_manager::toc::TableOfContent;
+use storage::dispatcher::Dispatcher;
+use storage::rbac::{AccessRequirements, Auth};
+
+pub async fn do_list_filter_templates(
+    toc: &TableOfContent,
+    collection_name: &str,
+    auth: &Auth,
+) -> Result<FilterTemplates, StorageError> {
+    let collection_pass = auth.check_collection_access(
+        collection_name,
+        AccessRequirements::new(),
+        "list_filter_templates",
+    )?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+    Ok(FilterTemplates {
+        templates: collection.filter_templates(),
+    })
+}
+
+/// Validate the template against the collection and store it in consensus.
+pub async fn do_set_filter_template(
+    dispatcher: &Dispatcher,
+    collection_name: String,
+    template_name: String,
+    template: FilterTemplate,
+    auth: Auth,
+    wait_timeout: Option<Duration>,
+) -> Result<bool, StorageError> {
+    // Access is logged when the operation is submitted
+    let collection_pass = auth
+        .unlogged_access()
+        .check_collection_access(&collection_name, AccessRequirements::new().write().extras())?;
+
+    let toc = dispatcher.toc(&auth, &new_unchecked_verification_pass());
+    toc.get_collection(&collection_pass)
+        .await?
+        .check_filter_template(&template)?;
+
+    let operation = CollectionMetaOperations::SetFilterTemplate(SetFilterTemplate {
+        collection_name,
+        template_name,
+        template,
+    });
+
+    dispatcher
+        .submit_collection_meta_op(operation, auth, wait_timeout)
+        .await
+}
+
+pub async fn do_delete_filter_template(
+    dispatcher: &Dispatcher,
+    collection_name: String,
+    template_name: String,
+    auth: Auth,
+    wait_timeout: Option<Duration>,
+) -> Result<bool, StorageError> {
+    // Access is logged when the operation is submitted
+    let collection_pass = auth
+        .unlogged_access()
+        .check_collection_access(&collection_name, AccessRequirements::new().write().extras())?;
+
+    let toc = dispatcher.toc(&auth, &new_unchecked_verification_pass());
+    let collection = toc.get_collection(&collection_pass).await?;
+    if collection.filter_template(&template_name).is_none() {
+        return Err(StorageError::not_found(format!(
+            "Filter template {template_name} not found in collection {collection_name}",
+        )));
+    }
+
+    let operation = CollectionMetaOperations::DeleteFilterTemplate(DeleteFilterTemplate {
+        collection_name,
+        template_name,
+    });
+
+    dispatcher
+        .submit_collection_meta_op(operation, auth, wait_timeout)
+        .await
+}
+
+/// Combine the request filter with the referenced filter template, if any.
+///
+/// Points must match both the request filter and the template. Templates are checked to only use
+/// indexed fields when they are stored, so they are resolved after the strict mode check of the
+/// request.
+pub async fn resolve_filter_template(
+    toc: &TableOfContent,
+    collection_name: &str,
+    filter: Option<Filter>,
+    filter_template: Option<FilterTemplateReference>,
+    auth: &Auth,
+) -> Result<Option<Filter>, StorageError> {
+    let Some(filter_template) = filter_template else {
+        return Ok(filter);
+    };
+
+    // Access is logged by the request itself
+    let collection_pass = auth
+        .unlogged_access()
+        .check_collection_access(collection_name, AccessRequirements::new())?;
+
+    let template_filter = toc
+        .get_collection(&collection_pass)
+        .await?
+        .instantiate_filter_template(&filter_template)?;
+
+    let filter = match filter {
+        None => template_filter,
+        Some(filter) => Filter {
+            must: Some(vec![
+                Condition::Filter(filter),
+                Condition::Filter(template_filter),
+            ]),
+            ..Default::default()
+        },
+    };
+
+    Ok(Some(filter))
+}
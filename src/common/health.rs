@@ -346,7 +346,7 @@ fn get_consensus_commit<'a>(
     transport_channel_pool: &'a TransportChannelPool,
     uri: &'a tonic::transport::Uri,
 ) -> impl Future<Output = GetConsensusCommitResult> + 'a {
-    transport_channel_pool.with_channel_timeout(
+    transport_channel_pool.with_unlimited_channel_timeout(
         uri,
         |channel| async {
             let mut client = QdrantInternalClient::new(channel);
@@ -4,6 +4,7 @@ use std::{io, result};
 use common::defaults::APP_USER_AGENT;
 use fs_err as fs;
 use reqwest::header::{HeaderMap, HeaderValue, InvalidHeaderValue};
+use reqwest::redirect::Policy;
 use storage::content_manager::errors::StorageError;
 
 use super::auth::HTTP_HEADER_API_KEY;
@@ -54,6 +55,20 @@ impl HttpClient {
             api_key,
             self.tls_config.as_ref(),
             self.verify_https_client_certificate,
+            Policy::default(),
+        )
+    }
+
+    /// Create a new HTTP(S) client without an API key, which does not follow redirects
+    ///
+    /// Intended for user provided URLs, which are checked against a list of allowed hosts. A
+    /// redirect could otherwise lead to any other host.
+    pub fn client_without_redirects(&self) -> Result<reqwest::Client> {
+        https_client(
+            None,
+            self.tls_config.as_ref(),
+            self.verify_https_client_certificate,
+            Policy::none(),
         )
     }
 }
@@ -62,8 +77,11 @@ fn https_client(
     api_key: Option<&str>,
     tls_config: Option<&TlsConfig>,
     verify_https_client_certificate: bool,
+    redirect_policy: Policy,
 ) -> Result<reqwest::Client> {
-    let mut builder = reqwest::Client::builder().user_agent(APP_USER_AGENT.as_str());
+    let mut builder = reqwest::Client::builder()
+        .user_agent(APP_USER_AGENT.as_str())
+        .redirect(redirect_policy);
 
     // Configure TLS root certificate and validation
     if let Some(tls_config) = tls_config {
@@ -108,6 +108,11 @@ fn collect_query(query: &Query, batch: &mut BatchAccum) {
         Query::RelevanceFeedback(relevance_feedback) => {
             collect_feedback_input(&relevance_feedback.relevance_feedback, batch)
         }
+        Query::Arithmetic(arithmetic) => {
+            for term in &arithmetic.arithmetic {
+                collect_vector_input(&term.vector, batch);
+            }
+        }
         Query::OrderBy(_)
         | Query::Fusion(_)
         | Query::Rrf(_)
@@ -186,6 +186,7 @@ pub(crate) fn collect_query(query: &Query, batch: &mut BatchAccumGrpc) -> Result
         query::Variant::Fusion(_) => {}
         query::Variant::Rrf(_) => {}
         query::Variant::Sample(_) => {}
+        query::Variant::WeightedSample(_) => {}
         query::Variant::Formula(_) => {}
         query::Variant::NearestWithMmr(nearest_with_mmr) => {
             nearest_with_mmr
@@ -195,6 +196,13 @@ pub(crate) fn collect_query(query: &Query, batch: &mut BatchAccumGrpc) -> Result
                 .transpose()?;
         }
         query::Variant::RelevanceFeedback(feedback) => collect_feedback_input(feedback, batch)?,
+        query::Variant::Arithmetic(arithmetic) => {
+            for term in &arithmetic.terms {
+                if let Some(vector) = &term.vector {
+                    collect_vector_input(vector, batch)?;
+                }
+            }
+        }
     }
 
     Ok(())
@@ -4,8 +4,9 @@ use api::grpc::qdrant::query::Variant;
 use api::grpc::{InferenceUsage, qdrant as grpc};
 use api::rest::{self, LookupLocation, RecommendStrategy};
 use collection::operations::universal_query::collection_query::{
-    CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, FeedbackInternal,
-    FeedbackStrategy, Mmr, NearestWithMmr, Query, VectorInputInternal, VectorQuery,
+    ArithmeticTerm, CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest,
+    FeedbackInternal, FeedbackStrategy, Mmr, NearestWithMmr, Query, VectorInputInternal,
+    VectorQuery,
 };
 use collection::operations::universal_query::formula::FormulaInternal;
 use collection::operations::universal_query::shard_query::{FusionInternal, SampleInternal};
@@ -48,6 +49,7 @@ pub async fn convert_query_point_groups_from_grpc(
         read_consistency: _,
         timeout: _,
         shard_key_selector: _,
+        filter_template: _,
     } = query;
 
     let mut batch = BatchAccumGrpc::new();
@@ -127,6 +129,9 @@ pub async fn convert_query_points_from_grpc(
         shard_key_selector: _,
         lookup_from,
         timeout: _,
+        allow_partial: _,
+        filter_template: _,
+        explain: _,
     } = query;
 
     let mut batch = BatchAccumGrpc::new();
@@ -296,6 +301,7 @@ fn convert_query_with_inferred(
         Variant::Rrf(rrf) => Query::Fusion(FusionInternal::try_from(rrf)?),
         Variant::Formula(formula) => Query::Formula(FormulaInternal::try_from(formula)?),
         Variant::Sample(sample) => Query::Sample(SampleInternal::try_from(sample)?),
+        Variant::WeightedSample(sample) => Query::Sample(SampleInternal::try_from(sample)?),
         Variant::NearestWithMmr(grpc::NearestInputWithMmr { nearest, mmr }) => {
             let nearest =
                 nearest.ok_or_else(|| Status::invalid_argument("nearest vector is missing"))?;
@@ -350,6 +356,25 @@ fn convert_query_with_inferred(
                 strategy,
             }))
         }
+        Variant::Arithmetic(arithmetic) => {
+            let grpc::ArithmeticInput { terms } = arithmetic;
+
+            let terms = terms
+                .into_iter()
+                .map(|term| {
+                    let grpc::ArithmeticTerm { vector, weight } = term;
+                    let vector = vector
+                        .ok_or_else(|| Status::invalid_argument("arithmetic vector is missing"))?;
+
+                    Ok(ArithmeticTerm {
+                        vector: convert_vector_input_with_inferred(vector, inferred)?,
+                        weight: weight.unwrap_or(1.0),
+                    })
+                })
+                .collect::<Result<Vec<_>, Status>>()?;
+
+            Query::Vector(VectorQuery::Arithmetic(terms))
+        }
     };
 
     Ok(query)
@@ -2,8 +2,9 @@ use api::rest::models::InferenceUsage;
 use api::rest::schema as rest;
 use collection::lookup::WithLookup;
 use collection::operations::universal_query::collection_query::{
-    CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, FeedbackInternal,
-    FeedbackStrategy, Mmr, NearestWithMmr, Query, VectorInputInternal, VectorQuery,
+    ArithmeticTerm, CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest,
+    FeedbackInternal, FeedbackStrategy, Mmr, NearestWithMmr, Query, VectorInputInternal,
+    VectorQuery,
 };
 use collection::operations::universal_query::formula::FormulaInternal;
 use collection::operations::universal_query::shard_query::{FusionInternal, SampleInternal};
@@ -299,6 +300,21 @@ fn convert_query_with_inferred(
                 strategy,
             })))
         }
+        rest::Query::Arithmetic(arithmetic) => {
+            let terms = arithmetic
+                .arithmetic
+                .into_iter()
+                .map(|term| {
+                    let rest::ArithmeticTerm { vector, weight } = term;
+                    Ok(ArithmeticTerm {
+                        vector: convert_vector_input_with_inferred(vector, inferred)?,
+                        weight: weight.unwrap_or(1.0),
+                    })
+                })
+                .collect::<StorageResult<Vec<_>>>()?;
+
+            Ok(Query::Vector(VectorQuery::Arithmetic(terms)))
+        }
     }
 }
 
@@ -57,6 +57,7 @@ pub async fn convert_point_struct(
             id,
             vector,
             payload,
+            shard_key: _,
         } = point_struct;
 
         let converted_vector_struct = match vector {
@@ -163,6 +164,7 @@ pub async fn convert_batch(
         ids,
         vectors,
         payloads,
+        shard_keys: _,
     } = batch;
 
     let mut inference_usage = InferenceUsage::default();
@@ -304,6 +306,7 @@ fn convert_point_struct_with_inferred(
                 id,
                 vector,
                 payload,
+                shard_key: _,
             } = point_struct;
             let converted_vector_struct = match vector {
                 VectorStruct::Single(single) => VectorStructPersisted::Single(single),
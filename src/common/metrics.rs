@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use api::rest::models::HardwareUsage;
+use collection::operations::types::IndexingBacklogInfo;
 use collection::shards::replica_set::replica_set_state::ReplicaState;
 use itertools::Itertools;
 use prometheus::TextEncoder;
@@ -60,6 +61,7 @@ const REST_ENDPOINT_WHITELIST: &[&str] = &[
 ///
 /// This array *must* be sorted.
 const GRPC_ENDPOINT_WHITELIST: &[&str] = &[
+    "/qdrant.Points/Aggregate",
     "/qdrant.Points/ClearPayload",
     "/qdrant.Points/Count",
     "/qdrant.Points/Delete",
@@ -67,8 +69,10 @@ const GRPC_ENDPOINT_WHITELIST: &[&str] = &[
     "/qdrant.Points/Discover",
     "/qdrant.Points/DiscoverBatch",
     "/qdrant.Points/Facet",
+    "/qdrant.Points/FacetMulti",
     "/qdrant.Points/Get",
     "/qdrant.Points/OverwritePayload",
+    "/qdrant.Points/PatchPayload",
     "/qdrant.Points/Query",
     "/qdrant.Points/QueryBatch",
     "/qdrant.Points/QueryGroups",
@@ -76,6 +80,7 @@ const GRPC_ENDPOINT_WHITELIST: &[&str] = &[
     "/qdrant.Points/RecommendBatch",
     "/qdrant.Points/RecommendGroups",
     "/qdrant.Points/Scroll",
+    "/qdrant.Points/ScrollStream",
     "/qdrant.Points/Search",
     "/qdrant.Points/SearchBatch",
     "/qdrant.Points/SearchGroups",
@@ -216,6 +221,10 @@ impl CollectionsTelemetry {
         // Vectors excluded from index-only requests.
         let mut indexed_only_excluded = Vec::with_capacity(num_collections);
 
+        // Vectors pending indexing, searched with a plain scan.
+        let mut unindexed_vectors = Vec::with_capacity(num_collections);
+        let mut search_degraded = Vec::with_capacity(num_collections);
+
         let mut total_dead_replicas = 0;
 
         // Snapshot metrics
@@ -232,6 +241,12 @@ impl CollectionsTelemetry {
         // Update queue
         let mut update_queue_length = Vec::with_capacity(num_collections);
 
+        // Replication to remote replicas
+        let mut replication_sent_requests = Vec::new();
+        let mut replication_sent_bytes = Vec::new();
+        let mut replication_lag_operations = Vec::new();
+        let mut replication_lag_seconds = Vec::new();
+
         for collection in self.collections.iter().flatten() {
             let collection = match collection {
                 CollectionTelemetryEnum::Full(collection_telemetry) => collection_telemetry,
@@ -328,6 +343,29 @@ impl CollectionsTelemetry {
                     },
                 );
 
+            let indexing_backlog = IndexingBacklogInfo::new(
+                points_excluded_from_index_only.values().sum(),
+                collection
+                    .shards
+                    .iter()
+                    .flatten()
+                    .filter_map(|shard| shard.local.as_ref())
+                    .filter_map(|local| local.num_vectors)
+                    .sum(),
+            );
+            unindexed_vectors.push(gauge(
+                indexing_backlog.unindexed_vectors_count as f64,
+                &[("id", &collection.id)],
+            ));
+            search_degraded.push(gauge(
+                if indexing_backlog.search_degraded {
+                    1.0
+                } else {
+                    0.0
+                },
+                &[("id", &collection.id)],
+            ));
+
             for (name, vector_size) in points_excluded_from_index_only {
                 indexed_only_excluded.push(gauge(
                     vector_size as f64,
@@ -378,6 +416,31 @@ impl CollectionsTelemetry {
                 .sum();
 
             update_queue_length.push(gauge(total_queue_length as f64, &[("id", &collection.id)]));
+
+            // Replication to remote replicas
+            for remote in collection
+                .shards
+                .iter()
+                .flatten()
+                .flat_map(|shard| shard.remote.iter())
+            {
+                let Some(replication) = &remote.replication else {
+                    continue;
+                };
+
+                let shard_id = remote.shard_id.to_string();
+                let remote_peer_id = remote.peer_id.to_string();
+                let labels = [
+                    ("id", collection.id.as_str()),
+                    ("shard", shard_id.as_str()),
+                    ("peer", remote_peer_id.as_str()),
+                ];
+
+                replication_sent_requests.push(counter(replication.sent_requests as f64, &labels));
+                replication_sent_bytes.push(counter(replication.sent_bytes as f64, &labels));
+                replication_lag_operations.push(gauge(replication.lag_operations as f64, &labels));
+                replication_lag_seconds.push(gauge(replication.lag_seconds, &labels));
+            }
         }
 
         for snapshot_telemetry in self.snapshots.iter().flatten() {
@@ -434,6 +497,22 @@ impl CollectionsTelemetry {
             prefix,
         ));
 
+        metrics.push_metric(metric_family(
+            "collection_unindexed_vectors",
+            "amount of vectors pending indexing, which are searched with a plain scan",
+            MetricType::GAUGE,
+            unindexed_vectors,
+            prefix,
+        ));
+
+        metrics.push_metric(metric_family(
+            "collection_search_degraded",
+            "whether too many vectors are pending indexing, so searches are slower than usual",
+            MetricType::GAUGE,
+            search_degraded,
+            prefix,
+        ));
+
         let total_min_active_replicas = if total_min_active_replicas == usize::MAX {
             0
         } else {
@@ -527,6 +606,38 @@ impl CollectionsTelemetry {
             update_queue_length,
             prefix,
         ));
+
+        metrics.push_metric(metric_family(
+            "collection_replication_sent_requests_total",
+            "total amount of update requests sent to a remote replica",
+            MetricType::COUNTER,
+            replication_sent_requests,
+            prefix,
+        ));
+
+        metrics.push_metric(metric_family(
+            "collection_replication_sent_bytes_total",
+            "total size of update requests sent to a remote replica",
+            MetricType::COUNTER,
+            replication_sent_bytes,
+            prefix,
+        ));
+
+        metrics.push_metric(metric_family(
+            "collection_replication_lag_operations",
+            "amount of updates a remote replica missed since it was up to date the last time",
+            MetricType::GAUGE,
+            replication_lag_operations,
+            prefix,
+        ));
+
+        metrics.push_metric(metric_family(
+            "collection_replication_lag_seconds",
+            "time since a remote replica is behind, because it missed an update or was marked dead",
+            MetricType::GAUGE,
+            replication_lag_seconds,
+            prefix,
+        ));
     }
 }
 
@@ -1,8 +1,11 @@
 pub mod audit;
 pub mod auth;
 pub mod collections;
+pub mod datasets;
 pub mod debugger;
 pub mod error_reporting;
+pub mod expiration;
+pub mod filter_templates;
 pub mod health;
 pub mod helpers;
 pub mod http_client;
@@ -10,6 +13,8 @@ pub mod inference;
 pub mod metrics;
 pub mod pyroscope_state;
 pub mod query;
+pub mod rebalance;
+pub mod resharding;
 pub mod snapshots;
 pub mod stacktrace;
 pub mod strict_mode;
@@ -2,6 +2,10 @@ use std::time::Duration;
 
 use api::rest::SearchGroupsRequestInternal;
 use collection::collection::distance_matrix::*;
+use collection::collection::join::*;
+use collection::collection::partial_results::{SkippedShards, TimedOut};
+use collection::collection::sample_projection::*;
+use collection::collection::search_explanations::ExplainedShards;
 use collection::common::batching::batch_requests;
 use collection::grouping::group_by::GroupRequest;
 use collection::operations::consistency_params::ReadConsistency;
@@ -17,6 +21,8 @@ use storage::content_manager::errors::StorageError;
 use storage::content_manager::toc::TableOfContent;
 use storage::rbac::Auth;
 
+use crate::common::filter_templates::resolve_filter_template;
+
 #[allow(clippy::too_many_arguments)]
 pub async fn do_core_search_points(
     toc: &TableOfContent,
@@ -174,18 +180,30 @@ pub async fn do_discover_batch_points(
     timeout: Option<Duration>,
     hw_measurement_acc: HwMeasurementAcc,
 ) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
-    let requests = request
-        .searches
-        .into_iter()
-        .map(|req| {
-            let shard_selector = match req.shard_key {
-                None => ShardSelectorInternal::All,
-                Some(shard_key) => ShardSelectorInternal::from(shard_key),
-            };
+    let mut requests = Vec::with_capacity(request.searches.len());
+    for req in request.searches {
+        let DiscoverRequest {
+            mut discover_request,
+            shard_key,
+            filter_template,
+        } = req;
+
+        discover_request.filter = resolve_filter_template(
+            toc,
+            collection_name,
+            discover_request.filter,
+            filter_template,
+            &auth,
+        )
+        .await?;
+
+        let shard_selector = match shard_key {
+            None => ShardSelectorInternal::All,
+            Some(shard_key) => ShardSelectorInternal::from(shard_key),
+        };
 
-            (req.discover_request, shard_selector)
-        })
-        .collect();
+        requests.push((discover_request, shard_selector));
+    }
 
     toc.discover_batch(
         collection_name,
@@ -276,6 +294,9 @@ pub async fn do_query_points(
     shard_selection: ShardSelectorInternal,
     auth: Auth,
     timeout: Option<Duration>,
+    skipped_shards: Option<&SkippedShards>,
+    explained: Option<&ExplainedShards>,
+    timed_out: Option<&TimedOut>,
     hw_measurement_acc: HwMeasurementAcc,
 ) -> Result<Vec<ScoredPoint>, StorageError> {
     let requests = vec![(request, shard_selection)];
@@ -286,6 +307,9 @@ pub async fn do_query_points(
             read_consistency,
             auth,
             timeout,
+            skipped_shards,
+            explained,
+            timed_out,
             hw_measurement_acc,
         )
         .await?;
@@ -303,6 +327,8 @@ pub async fn do_query_batch_points(
     read_consistency: Option<ReadConsistency>,
     auth: Auth,
     timeout: Option<Duration>,
+    skipped_shards: Option<&SkippedShards>,
+    timed_out: Option<&TimedOut>,
     hw_measurement_acc: HwMeasurementAcc,
 ) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
     toc.query_batch(
@@ -311,6 +337,9 @@ pub async fn do_query_batch_points(
         read_consistency,
         auth,
         timeout,
+        skipped_shards,
+        None,
+        timed_out,
         hw_measurement_acc,
     )
     .await
@@ -361,3 +390,49 @@ pub async fn do_search_points_matrix(
     )
     .await
 }
+
+#[allow(clippy::too_many_arguments)]
+pub async fn do_sample_projection(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: CollectionSampleProjectionRequest,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+    auth: Auth,
+    timeout: Option<Duration>,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<CollectionSampleProjectionResponse, StorageError> {
+    toc.sample_projection(
+        collection_name,
+        request,
+        read_consistency,
+        shard_selection,
+        auth,
+        timeout,
+        hw_measurement_acc,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn do_join(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: CollectionJoinRequest,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+    auth: Auth,
+    timeout: Option<Duration>,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<CollectionJoinResponse, StorageError> {
+    toc.join(
+        collection_name,
+        request,
+        read_consistency,
+        shard_selection,
+        auth,
+        timeout,
+        hw_measurement_acc,
+    )
+    .await
+}
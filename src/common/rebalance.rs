@@ -0,0 +1,616 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+
+use api::grpc::transport_channel_pool::DEFAULT_GRPC_TIMEOUT;
+use collection::operations::cluster_ops::{ClusterOperations, MoveShard, MoveShardOperation};
+use collection::operations::verification::new_unchecked_verification_pass;
+use collection::shards::replica_set::replica_set_state::ReplicaState;
+use collection::shards::shard::{PeerId, ShardId};
+use parking_lot::Mutex;
+use schemars::JsonSchema;
+use serde::Serialize;
+use storage::content_manager::errors::{StorageError, StorageResult};
+use storage::dispatcher::Dispatcher;
+use storage::rbac::Access;
+use storage::types::{ClusterStatus, StateRole};
+use tokio::runtime;
+
+use crate::common::auth::Auth;
+use crate::common::collections::do_update_collection_cluster;
+use crate::common::telemetry::TelemetryData;
+use crate::common::telemetry_ops::collections_telemetry::CollectionTelemetryEnum;
+use crate::common::telemetry_ops::distributed_telemetry::collect_peer_telemetries;
+use crate::settings::RebalanceConfig;
+
+/// Shard telemetry, including local shard sizes and read counters, starts at this level
+const REBALANCE_TELEMETRY_DETAILS_LEVEL: u32 = 3;
+
+/// Shard moves proposed to balance the cluster, along with the current load of peers
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RebalancePlan {
+    /// Load of responsive peers, ordered by peer ID
+    pub peers: Vec<PeerLoad>,
+    /// Peers which didn't respond, no shard moves are proposed until they do
+    pub missing_peers: Vec<PeerId>,
+    /// Number of ongoing shard transfers and resharding operations.
+    /// Collections with ongoing operations are not rebalanced.
+    pub ongoing_operations: usize,
+    /// Proposed shard moves, in order of execution
+    pub moves: Vec<ProposedShardMove>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, JsonSchema)]
+pub struct PeerLoad {
+    pub peer_id: PeerId,
+    /// Number of points in active replicas on this peer
+    pub num_points: usize,
+    /// Estimated size of vectors and payloads in active replicas on this peer, in bytes
+    pub disk_size_bytes: usize,
+    /// Read requests per second served by active replicas on this peer, since the previous check
+    pub read_rps: f64,
+    /// Combined load, sum of the peer shares of cluster points, disk size and reads
+    pub load: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ProposedShardMove {
+    pub collection_name: String,
+    pub shard_id: ShardId,
+    pub from_peer_id: PeerId,
+    pub to_peer_id: PeerId,
+}
+
+/// Load of a single active replica
+#[derive(Clone, Debug)]
+struct ReplicaLoad {
+    collection_name: String,
+    shard_id: ShardId,
+    peer_id: PeerId,
+    num_points: usize,
+    disk_size_bytes: usize,
+    read_rps: f64,
+}
+
+/// Read counters of replicas, to compute read rates between checks
+struct ReadSample {
+    time: Instant,
+    read_requests: HashMap<(String, ShardId, PeerId), usize>,
+}
+
+/// Proposes and, if enabled, executes shard moves between peers, to balance their number of
+/// points, disk size and read load.
+pub struct Rebalancer {
+    dispatcher: Arc<Dispatcher>,
+    config: RebalanceConfig,
+    previous_sample: Mutex<Option<ReadSample>>,
+}
+
+impl Rebalancer {
+    pub fn new(dispatcher: Arc<Dispatcher>, config: RebalanceConfig) -> Self {
+        Self {
+            dispatcher,
+            config,
+            previous_sample: Mutex::new(None),
+        }
+    }
+
+    /// Spawn the task executing proposed shard moves periodically, if enabled.
+    ///
+    /// The task stops once the rebalancer is dropped.
+    pub fn spawn(self: &Arc<Self>, runtime: &runtime::Handle) {
+        if !self.config.enabled {
+            return;
+        }
+
+        let task = runtime.spawn(Self::exec(Arc::downgrade(self)));
+        drop(task); // drop `JoinFuture` explicitly to make clippy happy
+    }
+
+    async fn exec(rebalancer: Weak<Self>) {
+        let Some(interval_sec) = rebalancer.upgrade().map(|r| r.config.interval_sec) else {
+            return;
+        };
+
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_sec));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // The first tick completes immediately, skip it to let the cluster settle after start
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let Some(rebalancer) = rebalancer.upgrade() else {
+                return;
+            };
+
+            if let Err(err) = rebalancer.rebalance().await {
+                log::error!("Failed to rebalance shards: {err}");
+            }
+        }
+    }
+
+    /// Execute proposed shard moves, only on the consensus leader so that peers don't compete
+    async fn rebalance(&self) -> StorageResult<()> {
+        let is_leader = match self.dispatcher.cluster_status() {
+            ClusterStatus::Enabled(info) => info.raft_info.role == Some(StateRole::Leader),
+            ClusterStatus::Disabled => false,
+        };
+
+        if !is_leader {
+            // Read counters sampled during an earlier leadership are outdated
+            self.previous_sample.lock().take();
+            return Ok(());
+        }
+
+        // Read rates are only known once read counters were sampled before
+        let has_read_rates = self.previous_sample.lock().is_some();
+        let plan = self.sample_and_plan(true).await?;
+
+        if !has_read_rates || plan.ongoing_operations > 0 {
+            return Ok(());
+        }
+
+        let auth = Auth::new_internal(Access::full("Shard rebalancing"));
+
+        for shard_move in plan.moves {
+            log::info!(
+                "Moving shard {}:{} from peer {} to peer {} to balance the cluster",
+                shard_move.collection_name,
+                shard_move.shard_id,
+                shard_move.from_peer_id,
+                shard_move.to_peer_id,
+            );
+
+            let operation = ClusterOperations::MoveShard(MoveShardOperation {
+                move_shard: MoveShard {
+                    shard_id: shard_move.shard_id,
+                    to_shard_id: None,
+                    to_peer_id: shard_move.to_peer_id,
+                    from_peer_id: shard_move.from_peer_id,
+                    method: None,
+                },
+            });
+
+            do_update_collection_cluster(
+                &self.dispatcher,
+                shard_move.collection_name,
+                operation,
+                auth.clone(),
+                None,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Propose shard moves based on the current telemetry of all peers.
+    ///
+    /// Read rates are computed since the previous check of the rebalancing task, the sampled read
+    /// counters are not updated.
+    pub async fn plan(&self) -> StorageResult<RebalancePlan> {
+        self.sample_and_plan(false).await
+    }
+
+    /// Propose shard moves, and store the sampled read counters for the next check, if
+    /// `update_sample` is set
+    async fn sample_and_plan(&self, update_sample: bool) -> StorageResult<RebalancePlan> {
+        let auth = Auth::new_internal(Access::full("Shard rebalancing"));
+        let pass = new_unchecked_verification_pass();
+        let channel_service = self.dispatcher.toc(&auth, &pass).get_channel_service();
+
+        let (telemetries, missing_peers) = collect_peer_telemetries(
+            channel_service,
+            None,
+            REBALANCE_TELEMETRY_DETAILS_LEVEL,
+            DEFAULT_GRPC_TIMEOUT.as_secs(),
+        )
+        .await?;
+
+        let sample = Sample::from_telemetries(&telemetries)?;
+
+        let now = Instant::now();
+        let replicas = {
+            let mut previous_sample = self.previous_sample.lock();
+            let replicas = sample.replica_loads(previous_sample.as_ref(), now);
+            if update_sample {
+                *previous_sample = Some(ReadSample {
+                    time: now,
+                    read_requests: sample.read_requests(),
+                });
+            }
+            replicas
+        };
+
+        let peer_ids: Vec<_> = sample.peers.iter().copied().collect();
+        let ongoing_operations = sample.busy_collections.values().sum();
+
+        let moves = if missing_peers.is_empty() {
+            plan_moves(
+                &replicas,
+                &peer_ids,
+                &sample.placement,
+                &sample.busy_collections.keys().cloned().collect(),
+                self.config.threshold,
+                self.config.max_moves,
+            )
+        } else {
+            Vec::new()
+        };
+
+        Ok(RebalancePlan {
+            peers: peer_loads(&replicas, &peer_ids),
+            missing_peers,
+            ongoing_operations,
+            moves,
+        })
+    }
+}
+
+/// Replicas and ongoing operations, as reported by telemetry of all peers
+struct Sample {
+    peers: BTreeSet<PeerId>,
+    /// Active replicas with their read counters
+    replicas: Vec<(ReplicaLoad, usize)>,
+    /// Peers holding a replica of a shard in any state
+    placement: HashSet<(String, ShardId, PeerId)>,
+    /// Collections with ongoing shard transfers or resharding, with the number of operations
+    busy_collections: HashMap<String, usize>,
+}
+
+impl Sample {
+    fn from_telemetries(telemetries: &[TelemetryData]) -> StorageResult<Self> {
+        let mut sample = Sample {
+            peers: Default::default(),
+            replicas: Vec::new(),
+            placement: HashSet::new(),
+            busy_collections: HashMap::new(),
+        };
+
+        for telemetry in telemetries {
+            let peer_id = telemetry
+                .cluster
+                .as_ref()
+                .and_then(|cluster| cluster.status.as_ref())
+                .and_then(|status| status.peer_id)
+                .ok_or_else(|| StorageError::service_error("Telemetry is missing peer ID"))?;
+            sample.peers.insert(peer_id);
+
+            let collections = telemetry.collections.collections.iter().flatten();
+
+            for collection in collections {
+                let CollectionTelemetryEnum::Full(collection) = collection else {
+                    continue;
+                };
+
+                let ongoing_operations = collection.transfers.as_ref().map_or(0, Vec::len)
+                    + collection.resharding.as_ref().map_or(0, Vec::len);
+                if ongoing_operations > 0 {
+                    let busy = sample
+                        .busy_collections
+                        .entry(collection.id.clone())
+                        .or_default();
+                    *busy = (*busy).max(ongoing_operations);
+                }
+
+                for shard in collection.shards.iter().flatten() {
+                    for replica_peer_id in shard.replicate_states.keys() {
+                        sample.placement.insert((
+                            collection.id.clone(),
+                            shard.id,
+                            *replica_peer_id,
+                        ));
+                    }
+
+                    let Some(local) = &shard.local else {
+                        continue;
+                    };
+
+                    if shard.replicate_states.get(&peer_id) != Some(&ReplicaState::Active) {
+                        continue;
+                    }
+
+                    let replica = ReplicaLoad {
+                        collection_name: collection.id.clone(),
+                        shard_id: shard.id,
+                        peer_id,
+                        num_points: local.num_points.unwrap_or(0),
+                        disk_size_bytes: local.vectors_size_bytes.unwrap_or(0)
+                            + local.payloads_size_bytes.unwrap_or(0),
+                        read_rps: 0.0,
+                    };
+                    sample.replicas.push((replica, local.read_requests));
+                }
+            }
+        }
+
+        Ok(sample)
+    }
+
+    fn read_requests(&self) -> HashMap<(String, ShardId, PeerId), usize> {
+        self.replicas
+            .iter()
+            .map(|(replica, read_requests)| {
+                let key = (
+                    replica.collection_name.clone(),
+                    replica.shard_id,
+                    replica.peer_id,
+                );
+                (key, *read_requests)
+            })
+            .collect()
+    }
+
+    /// Replica loads, with read rates computed against the previous sample
+    fn replica_loads(&self, previous: Option<&ReadSample>, now: Instant) -> Vec<ReplicaLoad> {
+        self.replicas
+            .iter()
+            .map(|(replica, read_requests)| {
+                let key = (
+                    replica.collection_name.clone(),
+                    replica.shard_id,
+                    replica.peer_id,
+                );
+                let read_rps = previous
+                    .and_then(|previous| {
+                        let elapsed = now.duration_since(previous.time).as_secs_f64();
+                        let previous_requests = *previous.read_requests.get(&key)?;
+                        // Counter is reset when the peer restarts
+                        let requests = read_requests
+                            .checked_sub(previous_requests)
+                            .unwrap_or(*read_requests);
+                        (elapsed > 0.0).then(|| requests as f64 / elapsed)
+                    })
+                    .unwrap_or(0.0);
+
+                ReplicaLoad {
+                    read_rps,
+                    ..replica.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+/// Cluster totals of replica metrics, to normalize loads of replicas and peers
+struct Totals {
+    num_points: f64,
+    disk_size_bytes: f64,
+    read_rps: f64,
+}
+
+impl Totals {
+    fn new(replicas: &[ReplicaLoad]) -> Self {
+        Self {
+            num_points: replicas.iter().map(|r| r.num_points as f64).sum(),
+            disk_size_bytes: replicas.iter().map(|r| r.disk_size_bytes as f64).sum(),
+            read_rps: replicas.iter().map(|r| r.read_rps).sum(),
+        }
+    }
+
+    /// Sum of shares of cluster totals, metrics without any load are ignored
+    fn load(&self, num_points: f64, disk_size_bytes: f64, read_rps: f64) -> f64 {
+        let share = |value: f64, total: f64| if total > 0.0 { value / total } else { 0.0 };
+
+        share(num_points, self.num_points)
+            + share(disk_size_bytes, self.disk_size_bytes)
+            + share(read_rps, self.read_rps)
+    }
+
+    fn replica_load(&self, replica: &ReplicaLoad) -> f64 {
+        self.load(
+            replica.num_points as f64,
+            replica.disk_size_bytes as f64,
+            replica.read_rps,
+        )
+    }
+}
+
+fn peer_loads(replicas: &[ReplicaLoad], peer_ids: &[PeerId]) -> Vec<PeerLoad> {
+    let totals = Totals::new(replicas);
+
+    peer_ids
+        .iter()
+        .map(|&peer_id| {
+            let peer_replicas = replicas.iter().filter(|r| r.peer_id == peer_id);
+
+            let mut peer_load = PeerLoad {
+                peer_id,
+                num_points: 0,
+                disk_size_bytes: 0,
+                read_rps: 0.0,
+                load: 0.0,
+            };
+            for replica in peer_replicas {
+                peer_load.num_points += replica.num_points;
+                peer_load.disk_size_bytes += replica.disk_size_bytes;
+                peer_load.read_rps += replica.read_rps;
+            }
+            peer_load.load = totals.load(
+                peer_load.num_points as f64,
+                peer_load.disk_size_bytes as f64,
+                peer_load.read_rps,
+            );
+
+            peer_load
+        })
+        .collect()
+}
+
+/// Greedily propose moves of replicas from the most to the least loaded peers, until the
+/// relative difference of their loads falls below `threshold`.
+///
+/// Each peer takes part in at most one move, to respect the default limit of concurrent shard
+/// transfers. Replicas are never moved to a peer already holding a replica of the same shard.
+fn plan_moves(
+    replicas: &[ReplicaLoad],
+    peer_ids: &[PeerId],
+    placement: &HashSet<(String, ShardId, PeerId)>,
+    busy_collections: &HashSet<String>,
+    threshold: f64,
+    max_moves: usize,
+) -> Vec<ProposedShardMove> {
+    if peer_ids.len() < 2 {
+        return Vec::new();
+    }
+
+    let totals = Totals::new(replicas);
+
+    let mut loads: HashMap<PeerId, f64> = peer_ids.iter().map(|&peer_id| (peer_id, 0.0)).collect();
+    for replica in replicas {
+        if let Some(load) = loads.get_mut(&replica.peer_id) {
+            *load += totals.replica_load(replica);
+        }
+    }
+    let average = loads.values().sum::<f64>() / loads.len() as f64;
+
+    let mut moves = Vec::new();
+
+    while moves.len() < max_moves && loads.len() >= 2 {
+        let by_load =
+            |a: &(PeerId, f64), b: &(PeerId, f64)| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0));
+        let peer_loads = || loads.iter().map(|(&peer_id, &load)| (peer_id, load));
+        let (from_peer_id, max_load) = peer_loads().max_by(by_load).unwrap();
+        let (to_peer_id, min_load) = peer_loads().min_by(by_load).unwrap();
+
+        let difference = max_load - min_load;
+        if average <= 0.0 || difference / average <= threshold {
+            break;
+        }
+
+        // Moving a replica with load below the difference brings the peers closer,
+        // a replica with half of the difference equalizes them
+        let best_replica = replicas
+            .iter()
+            .filter(|r| r.peer_id == from_peer_id)
+            .filter(|r| !busy_collections.contains(&r.collection_name))
+            .filter(|r| !placement.contains(&(r.collection_name.clone(), r.shard_id, to_peer_id)))
+            .map(|r| (r, totals.replica_load(r)))
+            .filter(|(_, load)| *load > 0.0 && *load < difference)
+            .min_by(|(_, a), (_, b)| {
+                (a - difference / 2.0)
+                    .abs()
+                    .total_cmp(&(b - difference / 2.0).abs())
+            });
+
+        let Some((replica, _)) = best_replica else {
+            break;
+        };
+
+        moves.push(ProposedShardMove {
+            collection_name: replica.collection_name.clone(),
+            shard_id: replica.shard_id,
+            from_peer_id,
+            to_peer_id,
+        });
+
+        loads.remove(&from_peer_id);
+        loads.remove(&to_peer_id);
+    }
+
+    moves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replica(
+        collection_name: &str,
+        shard_id: ShardId,
+        peer_id: PeerId,
+        num_points: usize,
+    ) -> ReplicaLoad {
+        ReplicaLoad {
+            collection_name: collection_name.to_string(),
+            shard_id,
+            peer_id,
+            num_points,
+            disk_size_bytes: num_points * 100,
+            read_rps: 0.0,
+        }
+    }
+
+    fn placement_of(replicas: &[ReplicaLoad]) -> HashSet<(String, ShardId, PeerId)> {
+        replicas
+            .iter()
+            .map(|r| (r.collection_name.clone(), r.shard_id, r.peer_id))
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_moves() {
+        let replicas = vec![
+            replica("a", 0, 1, 100),
+            replica("a", 1, 1, 100),
+            replica("a", 2, 1, 100),
+            replica("a", 3, 2, 100),
+        ];
+        let placement = placement_of(&replicas);
+
+        // Each peer takes part in a single move
+        let moves = plan_moves(&replicas, &[1, 2, 3], &placement, &HashSet::new(), 0.2, 2);
+        assert_eq!(
+            moves,
+            vec![ProposedShardMove {
+                collection_name: "a".to_string(),
+                shard_id: 0,
+                from_peer_id: 1,
+                to_peer_id: 3,
+            }],
+        );
+
+        // Collections with ongoing operations are not rebalanced
+        let busy = HashSet::from(["a".to_string()]);
+        let moves = plan_moves(&replicas, &[1, 2, 3], &placement, &busy, 0.2, 2);
+        assert!(moves.is_empty());
+
+        // Balanced cluster
+        let replicas = vec![replica("a", 0, 1, 100), replica("a", 1, 2, 110)];
+        let placement = placement_of(&replicas);
+        let moves = plan_moves(&replicas, &[1, 2], &placement, &HashSet::new(), 0.2, 2);
+        assert!(moves.is_empty());
+    }
+
+    #[test]
+    fn test_plan_moves_respects_placement() {
+        // Peer 2 already holds a replica of shard 0
+        let replicas = vec![
+            replica("a", 0, 1, 100),
+            replica("a", 1, 1, 100),
+            replica("a", 2, 1, 100),
+            replica("a", 0, 2, 100),
+        ];
+        let mut placement = placement_of(&replicas);
+
+        let moves = plan_moves(&replicas, &[1, 2], &placement, &HashSet::new(), 0.2, 1);
+        assert_eq!(moves.len(), 1);
+        assert_eq!(moves[0].shard_id, 1);
+
+        placement.insert(("a".to_string(), 1, 2));
+        placement.insert(("a".to_string(), 2, 2));
+        let moves = plan_moves(&replicas, &[1, 2], &placement, &HashSet::new(), 0.2, 1);
+        assert!(moves.is_empty());
+    }
+
+    #[test]
+    fn test_plan_moves_by_reads() {
+        // Equal sizes, but all reads go to peer 1
+        let mut replicas = vec![replica("a", 0, 1, 100), replica("a", 1, 2, 100)];
+        replicas[0].read_rps = 50.0;
+
+        let placement = placement_of(&replicas);
+        let moves = plan_moves(&replicas, &[1, 2], &placement, &HashSet::new(), 0.2, 1);
+        assert!(moves.is_empty(), "moving the only replica doesn't help");
+
+        // Small but frequently read shard is moved
+        replicas.push(replica("a", 2, 1, 0));
+        replicas[2].read_rps = 50.0;
+
+        let placement = placement_of(&replicas);
+        let moves = plan_moves(&replicas, &[1, 2], &placement, &HashSet::new(), 0.2, 1);
+        assert_eq!(moves.len(), 1);
+        assert_eq!(moves[0].shard_id, 2);
+    }
+}
@@ -0,0 +1,522 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use collection::collection::Collection;
+use collection::collection_state::State;
+use collection::operations::cluster_ops::ReshardingDirection;
+use collection::operations::verification::new_unchecked_verification_pass;
+use collection::shards::replica_set::replica_set_state::ReplicaState;
+use collection::shards::resharding::{ReshardKey, ReshardState, ReshardTarget, ReshardingStage};
+use collection::shards::shard::{PeerId, ShardId};
+use collection::shards::transfer::{ShardTransfer, ShardTransferMethod};
+use segment::types::ShardKey;
+use storage::content_manager::collection_meta_ops::{
+    CollectionMetaOperations, ReshardingOperation, SetShardReplicaState, ShardTransferOperations,
+};
+use storage::content_manager::errors::{StorageError, StorageResult};
+use storage::content_manager::toc::TableOfContent;
+use storage::dispatcher::Dispatcher;
+use storage::rbac::{Access, AccessRequirements};
+use uuid::Uuid;
+
+use super::auth::Auth;
+use super::collections::select_resharding_replica;
+
+/// Interval to poll collection state while waiting for a shard transfer to finish
+const TRANSFER_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Reshard collection to `target.shard_number` shards, adding or removing one shard at a time.
+///
+/// Every step goes through the regular resharding stages: start resharding, migrate points with
+/// resharding transfers, activate migrated replicas, commit read and write hash rings, and finish.
+/// Updates are forwarded to migrated replicas while transfers are running, so they catch up on
+/// writes made meanwhile.
+///
+/// The target is persisted in consensus with every step, so that the driving peer resumes
+/// resharding after restart, see [`resume_resharding`]. A step interrupted while migrating points
+/// is aborted and started over, a step with committed hash rings is continued.
+///
+/// Once the target is reached, every peer removes points migrated out of its local shards.
+///
+/// If a step fails, the ongoing resharding is aborted, and shards resharded by previous steps are
+/// kept. The error is reported in the collection cluster info of the driving peer.
+pub async fn reshard_collection(
+    dispatcher: Dispatcher,
+    collection_name: String,
+    target: ReshardTarget,
+    shard_key: Option<ShardKey>,
+    auth: Auth,
+) {
+    let resharder = Resharder {
+        dispatcher,
+        collection_name,
+        auth,
+    };
+
+    resharder.run(target, shard_key).await;
+}
+
+/// Resharding to a target number of shards, driven by this peer
+pub struct DrivenResharding {
+    collection_name: String,
+    target: ReshardTarget,
+    shard_key: Option<ShardKey>,
+    stage: ReshardingStage,
+}
+
+/// Find resharding driven by this peer, from resharding state persisted in consensus.
+///
+/// Must be called on startup before aborting transfers of this peer, which also aborts resharding
+/// operations they belong to.
+pub async fn driven_resharding(toc: &TableOfContent) -> Vec<DrivenResharding> {
+    let mut driven = Vec::new();
+
+    for collection_pass in toc.all_collections(&Access::full("Resharding")).await {
+        let Ok(collection) = toc.get_collection(&collection_pass).await else {
+            continue;
+        };
+        let Some(state) = collection.resharding_state().await else {
+            continue;
+        };
+        let Some(target) = state.target else {
+            continue;
+        };
+        if target.driver_peer_id == toc.this_peer_id {
+            driven.push(DrivenResharding {
+                collection_name: collection_pass.name().to_string(),
+                target,
+                shard_key: state.shard_key,
+                stage: state.stage,
+            });
+        }
+    }
+
+    driven
+}
+
+/// Resume resharding driven by this peer before restart, in the background
+pub fn resume_resharding(dispatcher: &Dispatcher, driven: Vec<DrivenResharding>) {
+    for resharding in driven {
+        let DrivenResharding {
+            collection_name,
+            target,
+            shard_key,
+            stage,
+        } = resharding;
+
+        log::info!(
+            "Resuming resharding of collection {collection_name} to {} shards",
+            target.shard_number,
+        );
+
+        let resharder = Resharder {
+            dispatcher: dispatcher.clone(),
+            collection_name,
+            auth: Auth::new_internal(Access::full("Resharding")),
+        };
+
+        let task = tokio::spawn(async move {
+            // Transfers of this peer were cancelled on restart, migration of points starts over
+            if stage == ReshardingStage::MigratingPoints
+                && let Err(err) = resharder.abort(target).await
+            {
+                log::warn!(
+                    "Failed to abort interrupted resharding of collection {}: {err}",
+                    resharder.collection_name,
+                );
+            }
+
+            resharder.run(target, shard_key).await;
+        });
+        drop(task);
+    }
+}
+
+struct Resharder {
+    dispatcher: Dispatcher,
+    collection_name: String,
+    auth: Auth,
+}
+
+impl Resharder {
+    /// Reshard to the target, abort ongoing resharding and report the error on failure
+    async fn run(&self, target: ReshardTarget, shard_key: Option<ShardKey>) {
+        if let Ok(collection) = self.collection().await {
+            collection.set_resharding_error(None);
+        }
+
+        let Err(err) = self.reshard(target, shard_key).await else {
+            return;
+        };
+
+        log::error!(
+            "Failed to reshard collection {} to {} shards: {err}",
+            self.collection_name,
+            target.shard_number,
+        );
+
+        if let Err(err) = self.abort(target).await {
+            log::error!(
+                "Failed to abort resharding of collection {}: {err}",
+                self.collection_name,
+            );
+        }
+
+        if let Ok(collection) = self.collection().await {
+            collection.set_resharding_error(Some(err.to_string()));
+        }
+    }
+
+    async fn reshard(
+        &self,
+        target: ReshardTarget,
+        shard_key: Option<ShardKey>,
+    ) -> StorageResult<()> {
+        loop {
+            let state = self.collection().await?.state().await;
+
+            if let Some(resharding) = &state.resharding {
+                if resharding.target != Some(target) || resharding.shard_key != shard_key {
+                    return Err(StorageError::bad_request(format!(
+                        "resharding {resharding:?} is already in progress",
+                    )));
+                }
+
+                self.drive(resharding).await?;
+                continue;
+            }
+
+            let shard_count = shard_ids(&state, shard_key.as_ref())?.len();
+
+            let direction = match shard_count.cmp(&(target.shard_number as usize)) {
+                Ordering::Less => ReshardingDirection::Up,
+                Ordering::Greater => ReshardingDirection::Down,
+                Ordering::Equal => return Ok(()),
+            };
+
+            log::info!(
+                "Resharding collection {} {direction:?} from {shard_count} to {} shards",
+                self.collection_name,
+                target.shard_number,
+            );
+
+            self.start(&state, direction, target, shard_key.clone())
+                .await?;
+        }
+    }
+
+    /// Start resharding operation adding or removing a single shard
+    async fn start(
+        &self,
+        state: &State,
+        direction: ReshardingDirection,
+        target: ReshardTarget,
+        shard_key: Option<ShardKey>,
+    ) -> StorageResult<()> {
+        let consensus_state = self
+            .dispatcher
+            .consensus_state()
+            .ok_or_else(|| StorageError::bad_request("Distributed mode disabled"))?;
+
+        let (shard_id, peer_id) = select_resharding_replica(
+            state,
+            direction,
+            shard_key.as_ref(),
+            None,
+            consensus_state.peers(),
+        );
+
+        self.resharding(ReshardingOperation::Start(ReshardKey {
+            uuid: Uuid::new_v4(),
+            direction,
+            peer_id,
+            shard_id,
+            shard_key,
+            target: Some(target),
+        }))
+        .await
+    }
+
+    /// Drive ongoing resharding operation to the end, continuing from its current stage
+    async fn drive(&self, resharding: &ReshardState) -> StorageResult<()> {
+        let key = resharding.key();
+
+        if resharding.stage == ReshardingStage::MigratingPoints {
+            self.migrate_points(&key).await?;
+            self.resharding(ReshardingOperation::CommitRead(key.clone()))
+                .await?;
+        }
+        if resharding.stage <= ReshardingStage::ReadHashRingCommitted {
+            self.resharding(ReshardingOperation::CommitWrite(key.clone()))
+                .await?;
+        }
+        self.resharding(ReshardingOperation::Finish(key.clone()))
+            .await?;
+
+        if key.direction == ReshardingDirection::Up {
+            self.replicate(key.shard_id, key.peer_id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Migrate points with resharding transfers, and activate migrated replicas
+    async fn migrate_points(&self, key: &ReshardKey) -> StorageResult<()> {
+        let ReshardKey {
+            direction,
+            peer_id,
+            shard_id,
+            ..
+        } = *key;
+
+        let state = self.collection().await?.state().await;
+        let other_shard_ids: Vec<_> = shard_ids(&state, key.shard_key.as_ref())?
+            .into_iter()
+            .filter(|&other_shard_id| other_shard_id != shard_id)
+            .collect();
+
+        match direction {
+            // Migrate points of every other shard into the new shard
+            ReshardingDirection::Up => {
+                for other_shard_id in other_shard_ids {
+                    let from_peer_id = active_replica(&state, other_shard_id, peer_id)?;
+                    self.transfer(
+                        resharding_transfer(other_shard_id, from_peer_id, shard_id, peer_id),
+                        ReplicaState::Resharding,
+                    )
+                    .await?;
+                }
+
+                self.finish_migrating_points(shard_id, peer_id, ReplicaState::Resharding)
+                    .await?;
+            }
+            // Migrate points of the removed shard into every replica of other shards
+            ReshardingDirection::Down => {
+                for other_shard_id in other_shard_ids {
+                    for to_peer_id in replicas(&state, other_shard_id) {
+                        self.transfer(
+                            resharding_transfer(shard_id, peer_id, other_shard_id, to_peer_id),
+                            ReplicaState::ReshardingScaleDown,
+                        )
+                        .await?;
+
+                        self.finish_migrating_points(
+                            other_shard_id,
+                            to_peer_id,
+                            ReplicaState::ReshardingScaleDown,
+                        )
+                        .await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replicate new shard to other peers, up to the replication factor of the collection
+    async fn replicate(&self, shard_id: ShardId, from_peer_id: PeerId) -> StorageResult<()> {
+        let state = self.collection().await?.state().await;
+        let replication_factor = state.config.params.replication_factor.get() as usize;
+
+        let mut shards_on_peers: Vec<(usize, PeerId)> = self
+            .dispatcher
+            .consensus_state()
+            .map(|consensus_state| consensus_state.peers())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|peer_id| !replicas(&state, shard_id).contains(peer_id))
+            .map(|peer_id| {
+                let shard_count = state
+                    .shards
+                    .values()
+                    .filter(|shard_info| shard_info.replicas.contains_key(&peer_id))
+                    .count();
+                (shard_count, peer_id)
+            })
+            .collect();
+        shards_on_peers.sort_unstable();
+
+        let missing_replicas = replication_factor.saturating_sub(replicas(&state, shard_id).len());
+
+        for (_, to_peer_id) in shards_on_peers.into_iter().take(missing_replicas) {
+            let transfer = ShardTransfer {
+                shard_id,
+                to_shard_id: None,
+                from: from_peer_id,
+                to: to_peer_id,
+                sync: true,
+                method: None,
+                filter: None,
+            };
+            self.transfer(transfer, ReplicaState::Active).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Start shard transfer, and wait for it to finish with the destination replica in
+    /// `expected_state`
+    async fn transfer(
+        &self,
+        transfer: ShardTransfer,
+        expected_state: ReplicaState,
+    ) -> StorageResult<()> {
+        let transfer_key = transfer.key();
+        let to_shard_id = transfer.to_shard_id.unwrap_or(transfer.shard_id);
+        let to_peer_id = transfer.to;
+
+        self.submit(CollectionMetaOperations::TransferShard(
+            self.collection_name.clone(),
+            ShardTransferOperations::Start(transfer),
+        ))
+        .await?;
+
+        let state = loop {
+            tokio::time::sleep(TRANSFER_POLL_INTERVAL).await;
+
+            let state = self.collection().await?.state().await;
+            if !state.transfers.iter().any(|t| t.key() == transfer_key) {
+                break state;
+            }
+        };
+
+        let replica_state = replica_state(&state, to_shard_id, to_peer_id);
+
+        if replica_state != Some(expected_state) {
+            return Err(StorageError::service_error(format!(
+                "shard transfer {transfer_key:?} failed, destination replica is {replica_state:?}",
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn finish_migrating_points(
+        &self,
+        shard_id: ShardId,
+        peer_id: PeerId,
+        from_state: ReplicaState,
+    ) -> StorageResult<()> {
+        self.submit(CollectionMetaOperations::SetShardReplicaState(
+            SetShardReplicaState {
+                collection_name: self.collection_name.clone(),
+                shard_id,
+                peer_id,
+                state: ReplicaState::Active,
+                from_state: Some(from_state),
+            },
+        ))
+        .await
+    }
+
+    /// Abort ongoing resharding operation, if it belongs to the target
+    async fn abort(&self, target: ReshardTarget) -> StorageResult<()> {
+        let Some(state) = self.collection().await?.resharding_state().await else {
+            return Ok(());
+        };
+        if state.target != Some(target) {
+            return Ok(());
+        }
+
+        self.resharding(ReshardingOperation::Abort(state.key()))
+            .await
+    }
+
+    async fn resharding(&self, operation: ReshardingOperation) -> StorageResult<()> {
+        self.submit(CollectionMetaOperations::Resharding(
+            self.collection_name.clone(),
+            operation,
+        ))
+        .await
+    }
+
+    async fn submit(&self, operation: CollectionMetaOperations) -> StorageResult<()> {
+        self.dispatcher
+            .submit_collection_meta_op(operation, self.auth.clone(), None)
+            .await?;
+        Ok(())
+    }
+
+    async fn collection(&self) -> StorageResult<Arc<Collection>> {
+        let collection_pass = self.auth.check_collection_access(
+            &self.collection_name,
+            AccessRequirements::new().write().manage().extras(),
+            "reshard_collection",
+        )?;
+
+        // All checks should've been done at this point.
+        let pass = new_unchecked_verification_pass();
+
+        self.dispatcher
+            .toc(&self.auth, &pass)
+            .get_collection(&collection_pass)
+            .await
+    }
+}
+
+fn resharding_transfer(
+    shard_id: ShardId,
+    from_peer_id: PeerId,
+    to_shard_id: ShardId,
+    to_peer_id: PeerId,
+) -> ShardTransfer {
+    ShardTransfer {
+        shard_id,
+        to_shard_id: Some(to_shard_id),
+        from: from_peer_id,
+        to: to_peer_id,
+        sync: true,
+        method: Some(ShardTransferMethod::ReshardingStreamRecords),
+        filter: None,
+    }
+}
+
+/// Shards of the given shard key, or all shards of the collection
+pub(crate) fn shard_ids(
+    state: &State,
+    shard_key: Option<&ShardKey>,
+) -> StorageResult<Vec<ShardId>> {
+    match shard_key {
+        Some(shard_key) => state
+            .shards_key_mapping
+            .get(shard_key)
+            .map(|shard_ids| shard_ids.iter().copied().collect())
+            .ok_or_else(|| {
+                StorageError::bad_request(format!("sharding key {shard_key} does not exist"))
+            }),
+        None => Ok(state.shards.keys().copied().collect()),
+    }
+}
+
+fn replica_state(state: &State, shard_id: ShardId, peer_id: PeerId) -> Option<ReplicaState> {
+    state
+        .shards
+        .get(&shard_id)
+        .and_then(|shard_info| shard_info.replicas.get(&peer_id))
+        .copied()
+}
+
+fn replicas(state: &State, shard_id: ShardId) -> Vec<PeerId> {
+    state
+        .shards
+        .get(&shard_id)
+        .map(|shard_info| shard_info.replicas.keys().copied().collect())
+        .unwrap_or_default()
+}
+
+/// Active replica of the shard, preferring the given peer to avoid a remote transfer
+fn active_replica(state: &State, shard_id: ShardId, preferred: PeerId) -> StorageResult<PeerId> {
+    state
+        .shards
+        .get(&shard_id)
+        .into_iter()
+        .flat_map(|shard_info| &shard_info.replicas)
+        .filter(|(_, replica_state)| **replica_state == ReplicaState::Active)
+        .map(|(peer_id, _)| *peer_id)
+        .min_by_key(|peer_id| *peer_id != preferred)
+        .ok_or_else(|| {
+            StorageError::service_error(format!("shard {shard_id} has no active replicas"))
+        })
+}
@@ -214,13 +214,27 @@ pub async fn recover_shard_snapshot(
                         .set_stage(RecoveryStage::Downloading);
 
                     let client = client.client(api_key.as_deref())?;
-                    snapshots::download::download_snapshot(
-                        &client,
-                        url,
-                        &download_dir,
-                        checksum.is_some(),
-                    )
-                    .await?
+
+                    // Shard transfers download the snapshot file in resumable chunks, so that a
+                    // broken connection doesn't force sending the whole snapshot again
+                    let is_resumable = matches!(snapshot_priority, SnapshotPriority::ShardTransfer)
+                        && checksum.is_some();
+                    if is_resumable {
+                        snapshots::download_resumable::download_snapshot_resumable(
+                            &client,
+                            &url,
+                            &download_dir,
+                        )
+                        .await?
+                    } else {
+                        snapshots::download::download_snapshot(
+                            &client,
+                            url,
+                            &download_dir,
+                            checksum.is_some(),
+                        )
+                        .await?
+                    }
                 }
 
                 ShardSnapshotLocation::Path(snapshot_file_name) => {
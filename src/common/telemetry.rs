@@ -180,6 +180,7 @@ impl TryFrom<grpc::PeerTelemetry> for TelemetryData {
                 max_collections: None,
                 collections: Some(collections),
                 snapshots: None,
+                optimizations: None,
             },
             cluster,
             requests: None,
@@ -31,6 +31,20 @@ pub struct CollectionsTelemetry {
     pub collections: Option<Vec<CollectionTelemetryEnum>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snapshots: Option<Vec<CollectionSnapshotTelemetry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimizations: Option<OptimizationJobsTelemetry>,
+}
+
+/// Node-wide optimization job limit, shared between all collections.
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
+#[anonymize(false)]
+pub struct OptimizationJobsTelemetry {
+    /// Maximum number of optimization jobs running at the same time on this node.
+    pub max_concurrent: usize,
+    /// Number of currently running optimization jobs.
+    pub running: usize,
+    /// Number of collections waiting for a free optimization job slot.
+    pub waiting_collections: usize,
 }
 
 impl CollectionsTelemetry {
@@ -73,11 +87,23 @@ impl CollectionsTelemetry {
 
         let max_collections = toc.max_collections();
 
+        let optimizations = if detail.level >= DetailsLevel::Level1 {
+            toc.optimization_job_limit_status()
+                .map(|status| OptimizationJobsTelemetry {
+                    max_concurrent: status.max_jobs,
+                    running: status.running_jobs,
+                    waiting_collections: status.waiting_owners,
+                })
+        } else {
+            None
+        };
+
         Ok(CollectionsTelemetry {
             number_of_collections,
             max_collections,
             collections,
             snapshots,
+            optimizations,
         })
     }
 }
@@ -1,10 +1,14 @@
 use std::collections::HashMap;
 
+use api::grpc;
 use collection::operations::types::{ReshardingInfo, ShardStatus, ShardTransferInfo};
+use collection::shards::channel_service::ChannelService;
 use collection::shards::replica_set::replica_set_state::ReplicaState;
 use collection::shards::shard::ShardId;
 use collection::shards::telemetry::PartialSnapshotTelemetry;
 use collection::telemetry::{CollectionTelemetry, ShardCleanStatusTelemetry};
+use futures::stream::FuturesUnordered;
+use futures::{StreamExt as _, TryFutureExt as _};
 use itertools::Itertools;
 use schemars::JsonSchema;
 use segment::types::ShardKey;
@@ -147,6 +151,64 @@ pub struct DistributedPeerDetails {
     consensus_thread_status: ConsensusThreadStatus,
 }
 
+/// Request telemetry from all peers of the cluster, including this one.
+///
+/// Returns telemetry of peers which responded, and IDs of peers which didn't.
+pub async fn collect_peer_telemetries(
+    channel_service: &ChannelService,
+    collections_selector: Option<grpc::CollectionsSelector>,
+    details_level: u32,
+    timeout: u64,
+) -> StorageResult<(Vec<TelemetryData>, Vec<PeerId>)> {
+    let all_peers: Vec<_> = channel_service
+        .id_to_address
+        .read()
+        .keys()
+        .copied()
+        .collect();
+
+    let mut futures = all_peers
+        .into_iter()
+        .map(|peer_id| {
+            channel_service
+                .with_qdrant_client(peer_id, |mut client| {
+                    let request = grpc::GetTelemetryRequest {
+                        collections_selector: collections_selector.clone(),
+                        details_level,
+                        timeout,
+                    };
+
+                    async move { client.get_telemetry(request).await }
+                })
+                .map_err(move |err| (peer_id, err))
+        })
+        .collect::<FuturesUnordered<_>>();
+
+    let mut telemetries = Vec::with_capacity(futures.len());
+    let mut missing_peers = Vec::new();
+
+    while let Some(result) = futures.next().await {
+        match result {
+            Ok(response) => {
+                let telemetry =
+                    TelemetryData::try_from(response.into_inner().result.ok_or_else(|| {
+                        StorageError::service_error(
+                            "GetTelemetryResponse is missing `result` field",
+                        )
+                    })?)
+                    .map_err(|err| StorageError::service_error(err.to_string()))?;
+                telemetries.push(telemetry);
+            }
+            Err((peer_id, err)) => {
+                log::error!("Internal telemetry service failed for peer {peer_id}: {err:#?}");
+                missing_peers.push(peer_id);
+            }
+        };
+    }
+
+    Ok((telemetries, missing_peers))
+}
+
 impl DistributedTelemetryData {
     pub fn resolve_telemetries(
         access: &Access,
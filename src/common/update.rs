@@ -1,20 +1,29 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use api::rest::models::InferenceUsage;
 use api::rest::*;
 use collection::collection::Collection;
+use collection::collection::copy::CollectionCopyRequest;
+use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::conversions::write_ordering_from_proto;
 use collection::operations::point_ops::*;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
-use collection::operations::types::{CollectionError, CollectionResult, UpdateResult};
+use collection::operations::types::{
+    CollectionError, CollectionResult, CountRequestInternal, CountResult, ScrollRequestInternal,
+    ScrollResult, UpdatePreview, UpdateResult,
+};
 use collection::operations::vector_ops::*;
 use collection::operations::verification::*;
 use collection::shards::shard::ShardId;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use schemars::JsonSchema;
 use segment::json_path::JsonPath;
-use segment::types::{Filter, PayloadFieldSchema, PayloadKeyType, StrictModeConfig};
+use segment::types::{
+    Condition, Filter, PayloadFieldSchema, PayloadKeyType, PointIdType, ShardKey, StrictModeConfig,
+    WithPayloadInterface, WithVector,
+};
 use serde::{Deserialize, Serialize};
 use serde_with::DurationSeconds;
 use shard::operations::payload_ops::*;
@@ -27,6 +36,7 @@ use storage::dispatcher::Dispatcher;
 use storage::rbac::{Access, Auth, AuthType};
 use validator::Validate;
 
+use crate::common::filter_templates::resolve_filter_template;
 use crate::common::inference::params::InferenceParams;
 use crate::common::inference::service::InferenceType;
 use crate::common::inference::update_requests::*;
@@ -95,6 +105,7 @@ pub enum UpdateOperation {
     Delete(DeleteOperation),
     SetPayload(SetPayloadOperation),
     OverwritePayload(OverwritePayloadOperation),
+    PatchPayload(PatchPayloadOperation),
     DeletePayload(DeletePayloadOperation),
     ClearPayload(ClearPayloadOperation),
     UpdateVectors(UpdateVectorsOperation),
@@ -108,6 +119,7 @@ impl Validate for UpdateOperation {
             UpdateOperation::Delete(op) => op.validate(),
             UpdateOperation::SetPayload(op) => op.validate(),
             UpdateOperation::OverwritePayload(op) => op.validate(),
+            UpdateOperation::PatchPayload(op) => op.validate(),
             UpdateOperation::DeletePayload(op) => op.validate(),
             UpdateOperation::ClearPayload(op) => op.validate(),
             UpdateOperation::UpdateVectors(op) => op.validate(),
@@ -163,6 +175,11 @@ impl StrictModeVerification for UpdateOperation {
                     .check_strict_mode(collection, strict_mode_config)
                     .await
             }
+            UpdateOperation::PatchPayload(op) => {
+                op.patch_payload
+                    .check_strict_mode(collection, strict_mode_config)
+                    .await
+            }
             UpdateOperation::DeletePayload(op) => {
                 op.delete_payload
                     .check_strict_mode(collection, strict_mode_config)
@@ -252,6 +269,12 @@ pub struct OverwritePayloadOperation {
     overwrite_payload: SetPayload,
 }
 
+#[derive(Deserialize, Serialize, JsonSchema, Validate)]
+pub struct PatchPayloadOperation {
+    #[validate(nested)]
+    patch_payload: SetPayload,
+}
+
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct DeletePayloadOperation {
     #[validate(nested)]
@@ -284,6 +307,35 @@ pub struct CreateFieldIndex {
     pub field_schema: Option<PayloadFieldSchema>,
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate)]
+pub struct RestorePoints {
+    /// IDs of deleted points to restore from the trash
+    #[validate(length(min = 1))]
+    pub points: Vec<PointIdType>,
+}
+
+impl StrictModeVerification for RestorePoints {
+    fn query_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
+        None
+    }
+}
+
 #[expect(clippy::too_many_arguments)]
 pub async fn do_upsert_points(
     toc_provider: impl CheckedTocProvider,
@@ -295,9 +347,6 @@ pub async fn do_upsert_points(
     inference_params: InferenceParams,
     hw_measurement_acc: HwMeasurementAcc,
 ) -> Result<(UpdateResult, Option<models::InferenceUsage>), StorageError> {
-    use point_ops::UpdateMode;
-    use segment::types::Filter;
-
     let toc = toc_provider
         .check_strict_mode(
             &operation,
@@ -310,33 +359,176 @@ pub async fn do_upsert_points(
     let (operation, shard_key, usage, update_filter, update_mode) = match operation {
         PointInsertOperations::PointsBatch(batch) => {
             let PointsBatch {
-                batch,
+                mut batch,
                 shard_key,
                 update_filter,
                 update_mode,
             } = batch;
+            let point_shard_keys = batch.shard_keys.take().unwrap_or_default();
             let (batch, usage) = convert_batch(batch, inference_params).await?;
-            let operation = PointInsertOperationsInternal::PointsBatch(batch);
             let update_mode = update_mode.map(rest_update_mode_to_internal);
+
+            if point_shard_keys.iter().any(Option::is_some) {
+                let result = upsert_points_by_shard_keys(
+                    toc,
+                    &collection_name,
+                    batch.into(),
+                    point_shard_keys,
+                    shard_key,
+                    update_filter,
+                    update_mode,
+                    internal_params,
+                    params,
+                    auth,
+                    hw_measurement_acc,
+                )
+                .await?;
+
+                return Ok((result, usage));
+            }
+
+            let operation = PointInsertOperationsInternal::PointsBatch(batch);
             (operation, shard_key, usage, update_filter, update_mode)
         }
         PointInsertOperations::PointsList(list) => {
             let PointsList {
-                points,
+                mut points,
                 shard_key,
                 update_filter,
                 update_mode,
             } = list;
+            let point_shard_keys: Vec<_> = points
+                .iter_mut()
+                .map(|point| point.shard_key.take())
+                .collect();
             let (list, usage) =
                 convert_point_struct(points, InferenceType::Update, inference_params).await?;
-            let operation = PointInsertOperationsInternal::PointsList(list);
             let update_mode = update_mode.map(rest_update_mode_to_internal);
+
+            if point_shard_keys.iter().any(Option::is_some) {
+                let result = upsert_points_by_shard_keys(
+                    toc,
+                    &collection_name,
+                    list,
+                    point_shard_keys,
+                    shard_key,
+                    update_filter,
+                    update_mode,
+                    internal_params,
+                    params,
+                    auth,
+                    hw_measurement_acc,
+                )
+                .await?;
+
+                return Ok((result, usage));
+            }
+
+            let operation = PointInsertOperationsInternal::PointsList(list);
             (operation, shard_key, usage, update_filter, update_mode)
         }
     };
 
-    // Decide which operation to use based on update_filter and update_mode
-    let operation = match (update_filter, update_mode) {
+    let operation = upsert_operation(operation, update_filter, update_mode);
+
+    let result = update(
+        toc,
+        &collection_name,
+        operation,
+        internal_params,
+        params,
+        shard_key,
+        auth,
+        hw_measurement_acc,
+    )
+    .await?;
+
+    Ok((result, usage))
+}
+
+/// Upsert points with their own shard keys, splitting them by shard key into a single update.
+///
+/// Points without a shard key use the shard key of the request, which must be a single key.
+#[expect(clippy::too_many_arguments)]
+async fn upsert_points_by_shard_keys(
+    toc: &TableOfContent,
+    collection_name: &str,
+    points: Vec<PointStructPersisted>,
+    point_shard_keys: Vec<Option<ShardKey>>,
+    shard_key: Option<ShardKeySelector>,
+    update_filter: Option<Filter>,
+    update_mode: Option<point_ops::UpdateMode>,
+    internal_params: InternalUpdateParams,
+    params: UpdateParams,
+    auth: Auth,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<UpdateResult, StorageError> {
+    if internal_params.shard_id.is_some() {
+        return Err(StorageError::bad_request(
+            "Point shard keys can't be combined with shard selection",
+        ));
+    }
+
+    let default_shard_key = match shard_key {
+        None => None,
+        Some(ShardKeySelector::ShardKey(shard_key)) => Some(shard_key),
+        Some(ShardKeySelector::ShardKeys(_) | ShardKeySelector::ShardKeyWithFallback(_)) => {
+            return Err(StorageError::bad_request(
+                "Point shard keys can only be combined with a single shard key of the request",
+            ));
+        }
+    };
+
+    let mut points_by_shard_key: HashMap<ShardKey, Vec<PointStructPersisted>> = HashMap::new();
+    for (point, point_shard_key) in points.into_iter().zip(point_shard_keys) {
+        let Some(shard_key) = point_shard_key.or_else(|| default_shard_key.clone()) else {
+            return Err(StorageError::bad_request(format!(
+                "Shard key not specified for point {}",
+                point.id,
+            )));
+        };
+        points_by_shard_key
+            .entry(shard_key)
+            .or_default()
+            .push(point);
+    }
+
+    let operations = points_by_shard_key
+        .into_iter()
+        .map(|(shard_key, points)| {
+            let operation = PointInsertOperationsInternal::PointsList(points);
+            let operation = upsert_operation(operation, update_filter.clone(), update_mode);
+            (shard_key, operation)
+        })
+        .collect();
+
+    let UpdateParams {
+        wait,
+        ordering,
+        timeout,
+    } = params;
+
+    toc.update_by_shard_keys(
+        collection_name,
+        operations,
+        wait,
+        timeout,
+        ordering,
+        auth,
+        hw_measurement_acc,
+    )
+    .await
+}
+
+/// Decide which upsert operation to use based on update filter and update mode
+fn upsert_operation(
+    operation: PointInsertOperationsInternal,
+    update_filter: Option<Filter>,
+    update_mode: Option<point_ops::UpdateMode>,
+) -> CollectionUpdateOperations {
+    use point_ops::UpdateMode;
+
+    match (update_filter, update_mode) {
         // If update_filter is provided, always use conditional upsert
         (Some(condition), mode) => CollectionUpdateOperations::PointOperation(
             PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
@@ -359,21 +551,7 @@ pub async fn do_upsert_points(
         (None, None) | (None, Some(UpdateMode::Upsert)) => {
             CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(operation))
         }
-    };
-
-    let result = update(
-        toc,
-        &collection_name,
-        operation,
-        internal_params,
-        params,
-        shard_key,
-        auth,
-        hw_measurement_acc,
-    )
-    .await?;
-
-    Ok((result, usage))
+    }
 }
 
 /// Convert REST UpdateMode to internal UpdateMode
@@ -402,7 +580,20 @@ pub async fn do_delete_points(
         PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
             (PointOperations::DeletePoints { ids: points }, shard_key)
         }
-        PointsSelector::FilterSelector(FilterSelector { filter, shard_key }) => {
+        PointsSelector::FilterSelector(FilterSelector {
+            filter,
+            shard_key,
+            filter_template,
+        }) => {
+            let filter = resolve_filter_template(
+                toc,
+                &collection_name,
+                Some(filter),
+                filter_template,
+                &auth,
+            )
+            .await?
+            .unwrap_or_default();
             (PointOperations::DeletePointsByFilter(filter), shard_key)
         }
     };
@@ -422,6 +613,42 @@ pub async fn do_delete_points(
     .await
 }
 
+/// Restore deleted points from the trash of a collection with trash retention
+pub async fn do_restore_points(
+    toc_provider: impl CheckedTocProvider,
+    collection_name: String,
+    operation: RestorePoints,
+    params: UpdateParams,
+    auth: Auth,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<UpdateResult, StorageError> {
+    let toc = toc_provider
+        .check_strict_mode(
+            &operation,
+            &collection_name,
+            params.timeout_as_secs(),
+            &auth,
+        )
+        .await?;
+
+    let UpdateParams {
+        wait,
+        ordering,
+        timeout,
+    } = params;
+
+    toc.restore_points(
+        &collection_name,
+        operation.points,
+        wait,
+        timeout,
+        ordering,
+        auth,
+        hw_measurement_acc,
+    )
+    .await
+}
+
 #[expect(clippy::too_many_arguments)]
 pub async fn do_update_vectors(
     toc_provider: impl CheckedTocProvider,
@@ -498,8 +725,12 @@ pub async fn do_delete_vectors(
         filter,
         points,
         shard_key,
+        filter_template,
     } = operation;
 
+    let filter =
+        resolve_filter_template(toc, &collection_name, filter, filter_template, &auth).await?;
+
     let vector_names: Vec<_> = vector.into_iter().collect();
 
     let mut result = None;
@@ -570,9 +801,13 @@ pub async fn do_set_payload(
         payload,
         filter,
         shard_key,
+        filter_template,
         key,
     } = operation;
 
+    let filter =
+        resolve_filter_template(toc, &collection_name, filter, filter_template, &auth).await?;
+
     let operation =
         CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(SetPayloadOp {
             payload,
@@ -617,9 +852,13 @@ pub async fn do_overwrite_payload(
         payload,
         filter,
         shard_key,
+        filter_template,
         key: _,
     } = operation;
 
+    let filter =
+        resolve_filter_template(toc, &collection_name, filter, filter_template, &auth).await?;
+
     let operation =
         CollectionUpdateOperations::PayloadOperation(PayloadOps::OverwritePayload(SetPayloadOp {
             payload,
@@ -642,6 +881,64 @@ pub async fn do_overwrite_payload(
     .await
 }
 
+pub async fn do_patch_payload(
+    toc_provider: impl CheckedTocProvider,
+    collection_name: String,
+    operation: SetPayload,
+    internal_params: InternalUpdateParams,
+    params: UpdateParams,
+    auth: Auth,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<UpdateResult, StorageError> {
+    // Patch is always applied to the whole payload
+    if operation.key.is_some() {
+        return Err(StorageError::bad_request(
+            "Payload patch can't be applied at a nested key",
+        ));
+    }
+
+    let toc = toc_provider
+        .check_strict_mode(
+            &operation,
+            &collection_name,
+            params.timeout_as_secs(),
+            &auth,
+        )
+        .await?;
+
+    let SetPayload {
+        points,
+        payload,
+        filter,
+        shard_key,
+        filter_template,
+        key: _,
+    } = operation;
+
+    let filter =
+        resolve_filter_template(toc, &collection_name, filter, filter_template, &auth).await?;
+
+    let operation =
+        CollectionUpdateOperations::PayloadOperation(PayloadOps::PatchPayload(SetPayloadOp {
+            payload,
+            points,
+            filter,
+            key: None,
+        }));
+
+    update(
+        toc,
+        &collection_name,
+        operation,
+        internal_params,
+        params,
+        shard_key,
+        auth,
+        hw_measurement_acc,
+    )
+    .await
+}
+
 pub async fn do_delete_payload(
     toc_provider: impl CheckedTocProvider,
     collection_name: String,
@@ -665,8 +962,12 @@ pub async fn do_delete_payload(
         points,
         filter,
         shard_key,
+        filter_template,
     } = operation;
 
+    let filter =
+        resolve_filter_template(toc, &collection_name, filter, filter_template, &auth).await?;
+
     let operation =
         CollectionUpdateOperations::PayloadOperation(PayloadOps::DeletePayload(DeletePayloadOp {
             keys,
@@ -704,7 +1005,20 @@ pub async fn do_clear_payload(
         PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
             (PayloadOps::ClearPayload { points }, shard_key)
         }
-        PointsSelector::FilterSelector(FilterSelector { filter, shard_key }) => {
+        PointsSelector::FilterSelector(FilterSelector {
+            filter,
+            shard_key,
+            filter_template,
+        }) => {
+            let filter = resolve_filter_template(
+                toc,
+                &collection_name,
+                Some(filter),
+                filter_template,
+                &auth,
+            )
+            .await?
+            .unwrap_or_default();
             (PayloadOps::ClearPayloadByFilter(filter), shard_key)
         }
     };
@@ -810,6 +1124,18 @@ pub async fn do_batch_update_points(
                 )
                 .await?
             }
+            UpdateOperation::PatchPayload(operation) => {
+                do_patch_payload(
+                    toc_provider.clone(),
+                    collection_name.clone(),
+                    operation.patch_payload,
+                    internal_params,
+                    params,
+                    auth.clone(),
+                    hw_measurement_acc.clone(),
+                )
+                .await?
+            }
             UpdateOperation::DeletePayload(operation) => {
                 do_delete_payload(
                     toc_provider.clone(),
@@ -1023,6 +1349,231 @@ pub async fn do_delete_index_internal(
     .await
 }
 
+/// Number of point IDs returned as a sample by update previews
+const UPDATE_PREVIEW_SAMPLE_SIZE: usize = 10;
+
+/// Preview which points would be deleted by the given selector, without deleting them
+pub async fn do_preview_delete_points(
+    toc_provider: impl CheckedTocProvider,
+    collection_name: String,
+    points: PointsSelector,
+    read_consistency: Option<ReadConsistency>,
+    timeout: Option<Duration>,
+    auth: Auth,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<UpdatePreview, StorageError> {
+    let toc = toc_provider
+        .check_strict_mode(
+            &points,
+            &collection_name,
+            timeout.map(|timeout| timeout.as_secs() as usize),
+            &auth,
+        )
+        .await?;
+
+    let (filter, shard_key) = match points {
+        PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => (
+            Filter::new_must(Condition::HasId(points.into_iter().collect())),
+            shard_key,
+        ),
+        PointsSelector::FilterSelector(FilterSelector {
+            filter,
+            shard_key,
+            filter_template,
+        }) => {
+            let filter = resolve_filter_template(
+                toc,
+                &collection_name,
+                Some(filter),
+                filter_template,
+                &auth,
+            )
+            .await?
+            .unwrap_or_default();
+            (filter, shard_key)
+        }
+    };
+
+    preview_update(
+        toc,
+        &collection_name,
+        filter,
+        shard_key,
+        read_consistency,
+        timeout,
+        auth,
+        hw_measurement_acc,
+    )
+    .await
+}
+
+/// Preview which points would be affected by setting payload, without modifying them
+pub async fn do_preview_set_payload(
+    toc_provider: impl CheckedTocProvider,
+    collection_name: String,
+    operation: SetPayload,
+    read_consistency: Option<ReadConsistency>,
+    timeout: Option<Duration>,
+    auth: Auth,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<UpdatePreview, StorageError> {
+    let toc = toc_provider
+        .check_strict_mode(
+            &operation,
+            &collection_name,
+            timeout.map(|timeout| timeout.as_secs() as usize),
+            &auth,
+        )
+        .await?;
+
+    let SetPayload {
+        points,
+        payload: _,
+        filter,
+        shard_key,
+        filter_template,
+        key: _,
+    } = operation;
+
+    let filter =
+        resolve_filter_template(toc, &collection_name, filter, filter_template, &auth).await?;
+
+    // Same precedence as in the actual operation: explicit IDs win over the filter
+    let filter = match (points, filter) {
+        (Some(points), _) => Filter::new_must(Condition::HasId(points.into_iter().collect())),
+        (None, Some(filter)) => filter,
+        (None, None) => {
+            return Err(StorageError::bad_input("No points or filter specified"));
+        }
+    };
+
+    preview_update(
+        toc,
+        &collection_name,
+        filter,
+        shard_key,
+        read_consistency,
+        timeout,
+        auth,
+        hw_measurement_acc,
+    )
+    .await
+}
+
+/// Copy points matching the filter into another collection, without sending them to the client
+pub async fn do_copy_points(
+    toc_provider: impl CheckedTocProvider,
+    collection_name: String,
+    request: CopyPointsRequest,
+    read_consistency: Option<ReadConsistency>,
+    timeout: Option<Duration>,
+    auth: Auth,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<CopyPointsResponse, StorageError> {
+    let CopyPointsRequest {
+        mut copy_request,
+        shard_key,
+        filter_template,
+    } = request;
+
+    let toc = toc_provider
+        .check_strict_mode(
+            &copy_request,
+            &collection_name,
+            timeout.map(|timeout| timeout.as_secs() as usize),
+            &auth,
+        )
+        .await?;
+
+    copy_request.filter = resolve_filter_template(
+        toc,
+        &collection_name,
+        copy_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    toc.copy_points(
+        &collection_name,
+        CollectionCopyRequest::from(copy_request),
+        read_consistency,
+        shard_selection,
+        auth,
+        timeout,
+        hw_measurement_acc,
+    )
+    .await
+    .map(CopyPointsResponse::from)
+}
+
+/// Count points matching the filter of an update, and collect a sample of their IDs.
+///
+/// Uses the same filtering path as the update itself, but only reads from the collection.
+#[expect(clippy::too_many_arguments)]
+async fn preview_update(
+    toc: &TableOfContent,
+    collection_name: &str,
+    filter: Filter,
+    shard_key: Option<ShardKeySelector>,
+    read_consistency: Option<ReadConsistency>,
+    timeout: Option<Duration>,
+    auth: Auth,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<UpdatePreview, StorageError> {
+    let shard_selector = get_shard_selector_for_update(None, shard_key);
+
+    let count_request = CountRequestInternal {
+        filter: Some(filter.clone()),
+        exact: true,
+    };
+
+    let CountResult { count } = toc
+        .count(
+            collection_name,
+            count_request,
+            read_consistency,
+            timeout,
+            shard_selector.clone(),
+            auth.clone(),
+            hw_measurement_acc.clone(),
+        )
+        .await?;
+
+    let scroll_request = ScrollRequestInternal {
+        offset: None,
+        limit: Some(UPDATE_PREVIEW_SAMPLE_SIZE),
+        filter: Some(filter),
+        with_payload: Some(WithPayloadInterface::Bool(false)),
+        with_vector: WithVector::Bool(false),
+        order_by: None,
+        distinct_by: None,
+        with_version: None,
+    };
+
+    let ScrollResult { points, .. } = toc
+        .scroll(
+            collection_name,
+            scroll_request,
+            read_consistency,
+            timeout,
+            shard_selector,
+            auth,
+            hw_measurement_acc,
+        )
+        .await?;
+
+    Ok(UpdatePreview {
+        matched: count,
+        sample: points.into_iter().map(|point| point.id).collect(),
+    })
+}
+
 #[expect(clippy::too_many_arguments)]
 pub async fn update(
     toc: &TableOfContent,
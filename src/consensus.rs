@@ -1339,7 +1339,7 @@ impl RaftMessageSender {
 
         let res = self
             .transport_channel_pool
-            .with_channel_timeout(
+            .with_unlimited_channel_timeout(
                 &uri,
                 |channel| async {
                     let mut client = RaftClient::new(channel);
@@ -1570,6 +1570,7 @@ mod tests {
                                 strict_mode_config: None,
                                 uuid: None,
                                 metadata: None,
+                                tenant_field: None,
                             },
                         )
                         .unwrap(),
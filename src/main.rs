@@ -17,14 +17,14 @@ use std::thread::JoinHandle;
 use std::time::Duration;
 
 use ::common::budget::{ResourceBudget, get_io_budget};
-use ::common::cpu::get_cpu_budget;
 use ::common::flags::{feature_flags, init_feature_flags};
 use ::common::fs::{FsCheckResult, check_fs_info, check_mmap_functionality};
 use ::common::mmap::MULTI_MMAP_SUPPORT_CHECK_RESULT;
-use ::common::mmap::advice::set_global;
+use ::common::mmap::advice::{set_components, set_global};
 use ::tonic::transport::Uri;
 use api::grpc::transport_channel_pool::TransportChannelPool;
 use clap::Parser;
+use collection::common::snapshot_bandwidth::init_snapshot_bandwidth_limits;
 use collection::profiling::interface::init_requests_profile_collector;
 use collection::shards::channel_service::ChannelService;
 use consensus::Consensus;
@@ -171,6 +171,8 @@ fn main() -> anyhow::Result<()> {
     setup_panic_hook(reporting_enabled, reporting_id.to_string());
 
     set_global(settings.storage.mmap_advice);
+    set_components(settings.storage.mmap_components);
+    init_snapshot_bandwidth_limits(&settings.storage.snapshots_config);
     segment::vector_storage::common::set_async_scorer(
         settings
             .storage
@@ -320,7 +322,7 @@ fn main() -> anyhow::Result<()> {
 
     // Create and own search runtime out of the scope of async context to ensure correct
     // destruction of it
-    let search_runtime = create_search_runtime(settings.storage.performance.max_search_threads)
+    let search_runtime = create_search_runtime(settings.storage.performance.search_thread_count())
         .expect("Can't search create runtime.");
 
     let update_runtime = create_update_runtime(
@@ -336,9 +338,10 @@ fn main() -> anyhow::Result<()> {
     let runtime_handle = general_runtime.handle().clone();
 
     // Use global CPU budget for optimizations based on settings
-    let cpu_budget = get_cpu_budget(settings.storage.performance.optimizer_cpu_budget);
+    let cpu_budget = settings.storage.performance.optimizer_cpu_budget();
     let io_budget = get_io_budget(settings.storage.performance.optimizer_io_budget, cpu_budget);
-    let optimizer_resource_budget = ResourceBudget::new(cpu_budget, io_budget);
+    let optimizer_resource_budget = ResourceBudget::new(cpu_budget, io_budget)
+        .with_max_concurrent_jobs(settings.storage.performance.max_concurrent_optimizations);
 
     // Create a signal sender and receiver. It is used to communicate with the consensus thread.
     let (propose_sender, propose_receiver) = std::sync::mpsc::channel();
@@ -368,12 +371,25 @@ fn main() -> anyhow::Result<()> {
 
         let tls_config = load_tls_client_config(&settings)?;
 
-        channel_service.channel_pool = Arc::new(TransportChannelPool::new(
-            p2p_grpc_timeout,
-            connection_timeout,
-            settings.cluster.p2p.connection_pool_size,
-            tls_config,
-        ));
+        channel_service.channel_pool = Arc::new(
+            TransportChannelPool::new(
+                p2p_grpc_timeout,
+                connection_timeout,
+                settings.cluster.p2p.connection_pool_size,
+                tls_config.clone(),
+            )
+            .with_max_concurrent_requests(settings.cluster.p2p.max_concurrent_requests),
+        );
+        channel_service.transfer_channel_pool = Arc::new(
+            TransportChannelPool::new(
+                p2p_grpc_timeout,
+                connection_timeout,
+                settings.cluster.p2p.transfer_connection_pool_size,
+                tls_config,
+            )
+            .with_max_concurrent_requests(settings.cluster.p2p.transfer_max_concurrent_requests),
+        );
+        channel_service.transfer_port = settings.cluster.p2p.transfer_port;
         channel_service.id_to_address = persistent_consensus_state.peer_address_by_id.clone();
         channel_service.id_to_metadata = persistent_consensus_state.peer_metadata_by_id.clone();
     }
@@ -410,7 +426,13 @@ fn main() -> anyhow::Result<()> {
     // It decides if query should go directly to the ToC or through the consensus.
     let mut dispatcher = Dispatcher::new(toc_arc.clone());
 
-    let (telemetry_collector, tonic_telemetry_collector, dispatcher_arc, health_checker);
+    let (
+        telemetry_collector,
+        tonic_telemetry_collector,
+        dispatcher_arc,
+        health_checker,
+        rebalancer,
+    );
     if is_distributed_deployment {
         let consensus_state: ConsensusStateRef = ConsensusManager::new(
             persistent_consensus_state,
@@ -451,6 +473,13 @@ fn main() -> anyhow::Result<()> {
             consensus_state.is_new_deployment() && bootstrap.is_some(),
         )));
 
+        let shard_rebalancer = Arc::new(common::rebalance::Rebalancer::new(
+            dispatcher_arc.clone(),
+            settings.cluster.rebalance.clone(),
+        ));
+        shard_rebalancer.spawn(&runtime_handle);
+        rebalancer = Some(shard_rebalancer);
+
         let handle = Consensus::run(
             &slog_logger,
             consensus_state.clone(),
@@ -471,8 +500,10 @@ fn main() -> anyhow::Result<()> {
 
         let toc_arc_clone = toc_arc.clone();
         let consensus_state_clone = consensus_state.clone();
+        let dispatcher_arc_clone = dispatcher_arc.clone();
         let _cancel_transfer_handle = runtime_handle.spawn(async move {
             consensus_state_clone.is_leader_established.await_ready();
+            let driven_resharding = common::resharding::driven_resharding(&toc_arc_clone).await;
             match toc_arc_clone
                 .cancel_related_transfers("Source or target peer restarted")
                 .await
@@ -484,6 +515,7 @@ fn main() -> anyhow::Result<()> {
                     log::error!("Can't cancel related transfers: {err}");
                 }
             }
+            common::resharding::resume_resharding(&dispatcher_arc_clone, driven_resharding);
         });
 
         // TODO(resharding): Remove resharding driver?
@@ -523,8 +555,12 @@ fn main() -> anyhow::Result<()> {
         tonic_telemetry_collector = telemetry.tonic_telemetry_collector.clone();
         telemetry_collector = Arc::new(tokio::sync::Mutex::new(telemetry));
         health_checker = None;
+        rebalancer = None;
     };
 
+    // Deletion of expired points, in collections with points expiration enabled
+    runtime_handle.spawn(common::expiration::run_expiration_cleanup(toc_arc.clone()));
+
     //
     // Telemetry reporting
     //
@@ -578,6 +614,7 @@ fn main() -> anyhow::Result<()> {
                         dispatcher_arc.clone(),
                         telemetry_collector,
                         health_checker,
+                        rebalancer,
                         settings,
                         logger_handle,
                     ),
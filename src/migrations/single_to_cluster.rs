@@ -61,6 +61,7 @@ pub async fn handle_existing_collections(
 
         let shards_number = params.shard_number.get();
         let sharding_method = params.sharding_method;
+        let mut shard_key_configs = params.shard_key_configs;
 
         let mut collection_create_operation = CreateCollectionOperation::new(
             collection_name.clone(),
@@ -79,6 +80,7 @@ pub async fn handle_existing_collections(
                 strict_mode_config,
                 uuid,
                 metadata,
+                tenant_field: None,
             },
         )
         .expect("Failed to create collection operation");
@@ -119,12 +121,18 @@ pub async fn handle_existing_collections(
                         placement.push(shard_info.replicas.keys().copied().collect());
                     }
 
+                    let config = shard_key_configs
+                        .iter()
+                        .position(|config| &config.shard_key == shard_key)
+                        .map(|index| shard_key_configs.swap_remove(index));
+
                     consensus_operations.push(CollectionMetaOperations::CreateShardKey(
                         CreateShardKey {
                             collection_name: collection_name.clone(),
                             shard_key: shard_key.clone(),
                             placement,
                             initial_state: None, // Initial state can't be set during migration
+                            config,
                         },
                     ))
                 }
@@ -3,10 +3,13 @@
 use api::rest::models::{CollectionsResponse, ShardKeysResponse, Usage, VersionInfo};
 use api::rest::schema::PointInsertOperations;
 use api::rest::{
-    FacetRequest, FacetResponse, QueryGroupsRequest, QueryRequest, QueryRequestBatch,
-    QueryResponse, Record, ScoredPoint, SearchMatrixOffsetsResponse, SearchMatrixPairsResponse,
-    SearchMatrixRequest, UpdateVectors,
+    AggregateRequest, AggregateResponse, CopyPointsRequest, CopyPointsResponse, FacetRequest,
+    FacetResponse, JoinRequest, JoinResponse, MultiFacetRequest, MultiFacetResponse,
+    QueryGroupsRequest, QueryRequest, QueryRequestBatch, QueryResponse, Record,
+    SampleProjectionRequest, SampleProjectionResponse, ScoredPoint, SearchMatrixOffsetsResponse,
+    SearchMatrixPairsResponse, SearchMatrixRequest, UpdateVectors,
 };
+use collection::collection::filter_templates::{FilterTemplate, FilterTemplates};
 use collection::operations::cluster_ops::ClusterOperations;
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::payload_ops::{DeletePayload, SetPayload};
@@ -19,20 +22,23 @@ use collection::operations::types::{
     CollectionsAliasesResponse, CountRequest, CountResult, DiscoverRequest, DiscoverRequestBatch,
     GroupsResult, OptimizationsResponse, PointGroup, PointRequest, RecommendGroupsRequest,
     RecommendRequest, RecommendRequestBatch, ScrollRequest, ScrollResult, SearchGroupsRequest,
-    SearchRequest, SearchRequestBatch, UpdateResult,
+    SearchRequest, SearchRequestBatch, UpdatePreview, UpdateResult,
 };
 use collection::operations::vector_ops::DeleteVectors;
 use schemars::JsonSchema;
 use schemars::r#gen::SchemaSettings;
 use serde::Serialize;
 use storage::content_manager::collection_meta_ops::{
-    ChangeAliasesOperation, CreateCollection, UpdateCollection,
+    ChangeAliasesOperation, CloneCollection, CreateCollection, UpdateCollection,
 };
+use storage::content_manager::config_changes::CollectionConfigChanges;
 use storage::types::ClusterStatus;
 
+use crate::common::datasets::{DatasetDescription, LoadDatasetRequest, LoadDatasetResponse};
+use crate::common::rebalance::RebalancePlan;
 use crate::common::telemetry::TelemetryData;
 use crate::common::telemetry_ops::distributed_telemetry::DistributedTelemetryData;
-use crate::common::update::{CreateFieldIndex, UpdateOperations};
+use crate::common::update::{CreateFieldIndex, RestorePoints, UpdateOperations};
 
 mod actix;
 mod common;
@@ -100,6 +106,26 @@ struct AllDefinitions {
     bo: ShardKeysResponse,
     bp: OptimizationsResponse,
     bq: DistributedTelemetryData,
+    br: SampleProjectionRequest,
+    bs: SampleProjectionResponse,
+    bt: DatasetDescription,
+    bu: LoadDatasetRequest,
+    bv: LoadDatasetResponse,
+    bw: FilterTemplate,
+    bx: FilterTemplates,
+    by: UpdatePreview,
+    bz: CollectionConfigChanges,
+    ca: MultiFacetRequest,
+    cb: MultiFacetResponse,
+    cc: AggregateRequest,
+    cd: AggregateResponse,
+    ce: JoinRequest,
+    cf: JoinResponse,
+    cg: CopyPointsRequest,
+    ch: CopyPointsResponse,
+    ci: CloneCollection,
+    cj: RebalancePlan,
+    ck: RestorePoints,
 }
 
 fn save_schema<T: JsonSchema>() {
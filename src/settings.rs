@@ -75,6 +75,16 @@ pub struct ServiceConfig {
     #[serde(default)]
     #[validate(custom(function = validate_metrics_prefix))]
     pub metrics_prefix: Option<String>,
+
+    /// Compression of REST API responses.
+    #[serde(default)]
+    #[validate(nested)]
+    pub compression: CompressionConfig,
+
+    /// Hosts, which sample datasets may be downloaded from with `POST /datasets/load`.
+    /// Loading datasets from URLs is disabled, if empty.
+    #[serde(default)]
+    pub dataset_url_hosts: Vec<String>,
 }
 
 impl ServiceConfig {
@@ -83,6 +93,45 @@ impl ServiceConfig {
     }
 }
 
+#[derive(Debug, Deserialize, Clone, Validate)]
+pub struct CompressionConfig {
+    /// Whether to compress REST API responses, negotiated with the `Accept-Encoding` header.
+    /// Default: true
+    #[serde(default = "default_compression_enabled")]
+    pub enabled: bool,
+    /// Compression level for `gzip` encoded responses, from 0 (none) to 9 (best).
+    /// Default: 6
+    #[serde(default = "default_gzip_level")]
+    #[validate(range(min = 0, max = 9))]
+    pub gzip_level: u32,
+    /// Compression level for `zstd` encoded responses, from 1 (fastest) to 22 (best).
+    /// Default: 3
+    #[serde(default = "default_zstd_level")]
+    #[validate(range(min = 1, max = 22))]
+    pub zstd_level: i32,
+    /// Responses smaller than this are sent uncompressed.
+    /// Default: 0
+    #[serde(default)]
+    pub min_size_bytes: usize,
+    /// Endpoints for which responses are never compressed, e.g. latency-critical search paths.
+    /// Each entry either matches a route pattern exactly, like `/collections/{name}/points/query`,
+    /// or matches the request path or one of its parent paths, like `/collections`.
+    #[serde(default)]
+    pub exclude_paths: Vec<String>,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        CompressionConfig {
+            enabled: default_compression_enabled(),
+            gzip_level: default_gzip_level(),
+            zstd_level: default_zstd_level(),
+            min_size_bytes: 0,
+            exclude_paths: Vec::new(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Default, Validate)]
 pub struct ClusterConfig {
     pub enabled: bool, // disabled by default
@@ -103,6 +152,9 @@ pub struct ClusterConfig {
     pub consensus: ConsensusConfig,
     #[serde(default)]
     pub resharding_enabled: bool, // disabled by default
+    #[serde(default)]
+    #[validate(nested)]
+    pub rebalance: RebalanceConfig,
 }
 
 #[derive(Debug, Deserialize, Clone, Validate)]
@@ -114,6 +166,22 @@ pub struct P2pConfig {
     pub connection_pool_size: usize,
     #[serde(default)]
     pub enable_tls: bool,
+    /// Maximum number of concurrent internal requests to a peer, excluding shard transfers and consensus messages
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_concurrent_requests: Option<usize>,
+    /// Separate port for shard transfer traffic between peers, must be the same on all peers.
+    /// If not set, transfers use the regular internal port.
+    #[serde(default)]
+    pub transfer_port: Option<u16>,
+    /// Number of channels per peer, used for shard transfers
+    #[serde(default = "default_connection_pool_size")]
+    #[validate(range(min = 1))]
+    pub transfer_connection_pool_size: usize,
+    /// Maximum number of concurrent shard transfer requests to a peer
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub transfer_max_concurrent_requests: Option<usize>,
 }
 
 impl Default for P2pConfig {
@@ -122,6 +190,10 @@ impl Default for P2pConfig {
             port: None,
             connection_pool_size: default_connection_pool_size(),
             enable_tls: false,
+            max_concurrent_requests: None,
+            transfer_port: None,
+            transfer_connection_pool_size: default_connection_pool_size(),
+            transfer_max_concurrent_requests: None,
         }
     }
 }
@@ -156,6 +228,36 @@ impl Default for ConsensusConfig {
     }
 }
 
+#[derive(Debug, Deserialize, Clone, Validate)]
+pub struct RebalanceConfig {
+    /// Automatically execute proposed shard moves, only proposed through the API otherwise
+    #[serde(default)]
+    pub enabled: bool,
+    /// How often to check the cluster balance
+    #[serde(default = "default_rebalance_interval_sec")]
+    #[validate(range(min = 1))]
+    pub interval_sec: u64,
+    /// Relative difference between the most and least loaded peers, above which shards are moved
+    #[serde(default = "default_rebalance_threshold")]
+    #[validate(range(min = 0.0))]
+    pub threshold: f64,
+    /// Maximum number of shard moves proposed or executed at once
+    #[serde(default = "default_rebalance_max_moves")]
+    #[validate(range(min = 1))]
+    pub max_moves: usize,
+}
+
+impl Default for RebalanceConfig {
+    fn default() -> Self {
+        RebalanceConfig {
+            enabled: false,
+            interval_sec: default_rebalance_interval_sec(),
+            threshold: default_rebalance_threshold(),
+            max_moves: default_rebalance_max_moves(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Validate)]
 pub struct TlsConfig {
     pub cert: String,
@@ -404,6 +506,18 @@ const fn default_cors() -> bool {
     true
 }
 
+const fn default_compression_enabled() -> bool {
+    true
+}
+
+const fn default_gzip_level() -> u32 {
+    6
+}
+
+const fn default_zstd_level() -> i32 {
+    3
+}
+
 const fn default_timeout_ms() -> u64 {
     DEFAULT_GRPC_TIMEOUT.as_millis() as u64
 }
@@ -437,6 +551,18 @@ const fn default_compact_wal_entries() -> u64 {
     128
 }
 
+const fn default_rebalance_interval_sec() -> u64 {
+    300
+}
+
+const fn default_rebalance_threshold() -> f64 {
+    0.2
+}
+
+const fn default_rebalance_max_moves() -> usize {
+    1
+}
+
 #[allow(clippy::unnecessary_wraps)] // Used as serde default
 const fn default_tls_cert_ttl() -> Option<u64> {
     // Default one hour
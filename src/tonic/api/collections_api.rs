@@ -3,14 +3,14 @@ use std::time::{Duration, Instant};
 
 use api::grpc::qdrant::collections_server::Collections;
 use api::grpc::qdrant::{
-    ChangeAliases, CollectionClusterInfoRequest, CollectionClusterInfoResponse,
-    CollectionExistsRequest, CollectionExistsResponse, CollectionOperationResponse,
-    CreateCollection, CreateShardKeyRequest, CreateShardKeyResponse, DeleteCollection,
-    DeleteShardKeyRequest, DeleteShardKeyResponse, GetCollectionInfoRequest,
-    GetCollectionInfoResponse, ListAliasesRequest, ListAliasesResponse,
+    CancelOptimizationsRequest, CancelOptimizationsResponse, ChangeAliases, CloneCollection,
+    CollectionClusterInfoRequest, CollectionClusterInfoResponse, CollectionExistsRequest,
+    CollectionExistsResponse, CollectionOperationResponse, CreateCollection, CreateShardKeyRequest,
+    CreateShardKeyResponse, DeleteCollection, DeleteShardKeyRequest, DeleteShardKeyResponse,
+    GetCollectionInfoRequest, GetCollectionInfoResponse, ListAliasesRequest, ListAliasesResponse,
     ListCollectionAliasesRequest, ListCollectionsRequest, ListCollectionsResponse,
-    ListShardKeysRequest, ListShardKeysResponse, UpdateCollection,
-    UpdateCollectionClusterSetupRequest, UpdateCollectionClusterSetupResponse,
+    ListShardKeysRequest, ListShardKeysResponse, PinVectorRequest, ResumeOptimizationsRequest,
+    UpdateCollection, UpdateCollectionClusterSetupRequest, UpdateCollectionClusterSetupResponse,
 };
 use collection::operations::cluster_ops::{
     ClusterOperations, CreateShardingKeyOperation, DropShardingKeyOperation,
@@ -57,6 +57,38 @@ impl CollectionsService {
         let response = CollectionOperationResponse::from((timing, result));
         Ok(Response::new(response))
     }
+
+    async fn set_vector_pinned(
+        &self,
+        mut request: Request<PinVectorRequest>,
+        pinned: bool,
+    ) -> Result<Response<CollectionOperationResponse>, Status> {
+        validate(request.get_ref())?;
+        let timing = Instant::now();
+        let auth = extract_auth(&mut request);
+
+        // Nothing to verify here.
+        let pass = new_unchecked_verification_pass();
+
+        let PinVectorRequest {
+            collection_name,
+            vector,
+        } = request.into_inner();
+
+        do_set_vector_pinned(
+            self.dispatcher.toc(&auth, &pass),
+            &auth,
+            &collection_name,
+            vector.as_deref(),
+            pinned,
+        )
+        .await?;
+
+        Ok(Response::new(CollectionOperationResponse {
+            result: true,
+            time: timing.elapsed().as_secs_f64(),
+        }))
+    }
 }
 
 #[tonic::async_trait]
@@ -264,6 +296,99 @@ impl Collections for CollectionsService {
         Ok(Response::new(response))
     }
 
+    async fn cancel_optimizations(
+        &self,
+        mut request: Request<CancelOptimizationsRequest>,
+    ) -> Result<Response<CancelOptimizationsResponse>, Status> {
+        validate(request.get_ref())?;
+        let timing = Instant::now();
+        let auth = extract_auth(&mut request);
+
+        // Nothing to verify here.
+        let pass = new_unchecked_verification_pass();
+
+        let CancelOptimizationsRequest {
+            collection_name,
+            optimizer,
+        } = request.into_inner();
+
+        let cancelled = do_cancel_optimizations(
+            self.dispatcher.toc(&auth, &pass),
+            &auth,
+            &collection_name,
+            optimizer.as_deref(),
+        )
+        .await?;
+
+        Ok(Response::new(CancelOptimizationsResponse {
+            cancelled: cancelled as u64,
+            time: timing.elapsed().as_secs_f64(),
+        }))
+    }
+
+    async fn resume_optimizations(
+        &self,
+        mut request: Request<ResumeOptimizationsRequest>,
+    ) -> Result<Response<CollectionOperationResponse>, Status> {
+        validate(request.get_ref())?;
+        let timing = Instant::now();
+        let auth = extract_auth(&mut request);
+
+        // Nothing to verify here.
+        let pass = new_unchecked_verification_pass();
+
+        do_resume_optimizations(
+            self.dispatcher.toc(&auth, &pass),
+            &auth,
+            &request.into_inner().collection_name,
+        )
+        .await?;
+
+        Ok(Response::new(CollectionOperationResponse {
+            result: true,
+            time: timing.elapsed().as_secs_f64(),
+        }))
+    }
+
+    async fn clone_collection(
+        &self,
+        mut request: Request<CloneCollection>,
+    ) -> Result<Response<CollectionOperationResponse>, Status> {
+        validate(request.get_ref())?;
+        let timing = Instant::now();
+        let auth = extract_auth(&mut request);
+
+        let request = request.into_inner();
+        let collection_name = request.collection_name.clone();
+        let wait_timeout = request.timeout.map(Duration::from_secs);
+
+        let result = do_clone_collection(
+            &self.dispatcher,
+            &collection_name,
+            request.try_into()?,
+            auth,
+            wait_timeout,
+        )
+        .await?;
+
+        let response = CollectionOperationResponse::from((timing, result));
+        Ok(Response::new(response))
+    }
+
+    async fn pin_vector(
+        &self,
+        request: Request<PinVectorRequest>,
+    ) -> Result<Response<CollectionOperationResponse>, Status> {
+        self.set_vector_pinned(request, true).await
+    }
+
+    async fn unpin_vector(
+        &self,
+        request: Request<PinVectorRequest>,
+    ) -> Result<Response<CollectionOperationResponse>, Status> {
+        self.set_vector_pinned(request, false).await
+    }
+
     async fn create_shard_key(
         &self,
         mut request: Request<CreateShardKeyRequest>,
@@ -144,6 +144,7 @@ impl CollectionsInternal for CollectionsInternalService {
         let GetShardRecoveryPointRequest {
             collection_name,
             shard_id,
+            applied,
         } = request.into_inner();
 
         let collection_read = self
@@ -157,14 +158,16 @@ impl CollectionsInternal for CollectionsInternalService {
             })?;
 
         // Get shard recovery point
-        let recovery_point = collection_read
-            .shard_recovery_point(shard_id)
-            .await
-            .map_err(|err| {
-                Status::internal(format!(
-                    "Failed to get recovery point for shard {shard_id}: {err}"
-                ))
-            })?;
+        let recovery_point = if applied.unwrap_or_default() {
+            collection_read.shard_applied_recovery_point(shard_id).await
+        } else {
+            collection_read.shard_recovery_point(shard_id).await
+        };
+        let recovery_point = recovery_point.map_err(|err| {
+            Status::internal(format!(
+                "Failed to get recovery point for shard {shard_id}: {err}"
+            ))
+        })?;
 
         let response = GetShardRecoveryPointResponse {
             recovery_point: Some(recovery_point.into()),
@@ -228,12 +231,14 @@ impl CollectionsInternal for CollectionsInternalService {
             with_queued,
             completed_limit,
             with_idle_segments,
+            with_decisions,
         } = request.into_inner();
 
         let options = OptimizationsRequestOptions {
             queued: with_queued,
             completed_limit: completed_limit.map(|l| l as usize),
             idle_segments: with_idle_segments,
+            decisions: with_decisions,
         };
 
         let collection_read = self
@@ -4,20 +4,23 @@ use std::time::{Duration, Instant};
 use api::grpc::Usage;
 use api::grpc::qdrant::points_server::Points;
 use api::grpc::qdrant::{
-    ClearPayloadPoints, CountPoints, CountResponse, CreateFieldIndexCollection,
-    DeleteFieldIndexCollection, DeletePayloadPoints, DeletePointVectors, DeletePoints,
-    DiscoverBatchPoints, DiscoverBatchResponse, DiscoverPoints, DiscoverResponse, FacetCounts,
-    FacetResponse, GetPoints, GetResponse, PointsOperationResponse, QueryBatchPoints,
-    QueryBatchResponse, QueryGroupsResponse, QueryPointGroups, QueryPoints, QueryResponse,
-    RecommendBatchPoints, RecommendBatchResponse, RecommendGroupsResponse, RecommendPointGroups,
-    RecommendPoints, RecommendResponse, ScrollPoints, ScrollResponse, SearchBatchPoints,
-    SearchBatchResponse, SearchGroupsResponse, SearchMatrixOffsets, SearchMatrixOffsetsResponse,
-    SearchMatrixPairs, SearchMatrixPairsResponse, SearchMatrixPoints, SearchPointGroups,
-    SearchPoints, SearchResponse, SetPayloadPoints, UpdateBatchPoints, UpdateBatchResponse,
-    UpdatePointVectors, UpsertPoints,
+    AggregatePoints, AggregateResponse, ClearPayloadPoints, CopyPoints, CopyPointsResponse,
+    CountPoints, CountResponse, CreateFieldIndexCollection, DeleteFieldIndexCollection,
+    DeletePayloadPoints, DeletePointVectors, DeletePoints, DiscoverBatchPoints,
+    DiscoverBatchResponse, DiscoverPoints, DiscoverResponse, FacetCounts, FacetMultiCounts,
+    FacetMultiResponse, FacetResponse, GetPoints, GetResponse, JoinPoints, JoinResponse,
+    PointsOperationResponse, QueryBatchPoints, QueryBatchResponse, QueryGroupsResponse,
+    QueryPointGroups, QueryPoints, QueryResponse, RecommendBatchPoints, RecommendBatchResponse,
+    RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
+    RestorePoints, SampleProjectionPoints, SampleProjectionResponse, ScrollPoints, ScrollResponse,
+    SearchBatchPoints, SearchBatchResponse, SearchGroupsResponse, SearchMatrixOffsets,
+    SearchMatrixOffsetsResponse, SearchMatrixPairs, SearchMatrixPairsResponse, SearchMatrixPoints,
+    SearchPointGroups, SearchPoints, SearchResponse, SetPayloadPoints, UpdateBatchPoints,
+    UpdateBatchResponse, UpdatePointVectors, UpdatePreviewResponse, UpsertPoints,
 };
 use collection::operations::types::CoreSearchRequest;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use futures::stream::BoxStream;
 use storage::content_manager::toc::request_hw_counter::RequestHwCounter;
 use storage::dispatcher::Dispatcher;
 use tonic::{Request, Response, Status};
@@ -110,6 +113,48 @@ impl Points for PointsService {
         .map(|resp| resp.map(PointsOperationResponse::from))
     }
 
+    async fn preview_delete(
+        &self,
+        mut request: Request<DeletePoints>,
+    ) -> Result<Response<UpdatePreviewResponse>, Status> {
+        validate(request.get_ref())?;
+
+        let auth = extract_auth(&mut request);
+        let hw_metrics = self.get_request_collection_hw_usage_counter(
+            request.get_ref().collection_name.clone(),
+            None,
+        );
+
+        preview_delete(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            auth,
+            hw_metrics,
+        )
+        .await
+    }
+
+    async fn restore_points(
+        &self,
+        mut request: Request<RestorePoints>,
+    ) -> Result<Response<PointsOperationResponse>, Status> {
+        validate(request.get_ref())?;
+
+        let auth = extract_auth(&mut request);
+        let collection_name = request.get_ref().collection_name.clone();
+        let wait = Some(request.get_ref().wait.unwrap_or(false));
+        let hw_metrics = self.get_request_collection_hw_usage_counter(collection_name, wait);
+
+        restore_points(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            auth,
+            hw_metrics,
+        )
+        .await
+        .map(|resp| resp.map(PointsOperationResponse::from))
+    }
+
     async fn get(&self, mut request: Request<GetPoints>) -> Result<Response<GetResponse>, Status> {
         validate(request.get_ref())?;
 
@@ -204,6 +249,27 @@ impl Points for PointsService {
         .map(|resp| resp.map(Into::into))
     }
 
+    async fn preview_set_payload(
+        &self,
+        mut request: Request<SetPayloadPoints>,
+    ) -> Result<Response<UpdatePreviewResponse>, Status> {
+        validate(request.get_ref())?;
+
+        let auth = extract_auth(&mut request);
+        let hw_metrics = self.get_request_collection_hw_usage_counter(
+            request.get_ref().collection_name.clone(),
+            None,
+        );
+
+        preview_set_payload(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            auth,
+            hw_metrics,
+        )
+        .await
+    }
+
     async fn overwrite_payload(
         &self,
         mut request: Request<SetPayloadPoints>,
@@ -227,6 +293,29 @@ impl Points for PointsService {
         .map(|resp| resp.map(Into::into))
     }
 
+    async fn patch_payload(
+        &self,
+        mut request: Request<SetPayloadPoints>,
+    ) -> Result<Response<PointsOperationResponse>, Status> {
+        validate(request.get_ref())?;
+
+        let auth = extract_auth(&mut request);
+
+        let collection_name = request.get_ref().collection_name.clone();
+        let wait = Some(request.get_ref().wait.unwrap_or(false));
+        let hw_metrics = self.get_request_collection_hw_usage_counter(collection_name, wait);
+
+        patch_payload(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            InternalUpdateParams::default(),
+            auth,
+            hw_metrics,
+        )
+        .await
+        .map(|resp| resp.map(Into::into))
+    }
+
     async fn delete_payload(
         &self,
         mut request: Request<DeletePayloadPoints>,
@@ -382,11 +471,12 @@ impl Points for PointsService {
 
         for mut search_point in search_points {
             let shard_key = search_point.shard_key_selector.take();
+            let filter_template = search_point.filter_template.take();
 
             let shard_selector = convert_shard_selector_for_read(None, shard_key)?;
             let core_search_request = CoreSearchRequest::try_from(search_point)?;
 
-            requests.push((core_search_request, shard_selector));
+            requests.push((core_search_request, shard_selector, filter_template));
         }
 
         let hw_metrics =
@@ -449,6 +539,69 @@ impl Points for PointsService {
         .await
     }
 
+    type ScrollStreamStream = BoxStream<'static, Result<ScrollResponse, Status>>;
+
+    async fn scroll_stream(
+        &self,
+        mut request: Request<ScrollPoints>,
+    ) -> Result<Response<Self::ScrollStreamStream>, Status> {
+        validate(request.get_ref())?;
+
+        let auth = extract_auth(&mut request);
+
+        let inner_request = request.into_inner();
+
+        // Chunks are chained by the offset of the next page, which is not returned with ordering
+        if inner_request.order_by.is_some() {
+            return Err(Status::invalid_argument(
+                "order_by is not supported in scroll stream",
+            ));
+        }
+
+        let dispatcher = self.dispatcher.clone();
+        let hardware_reporting = self.service_config.hardware_reporting();
+
+        let stream = futures::stream::try_unfold(Some(inner_request), move |scroll_points| {
+            let dispatcher = dispatcher.clone();
+            let auth = auth.clone();
+            async move {
+                let Some(scroll_points) = scroll_points else {
+                    return Ok(None);
+                };
+
+                // Each chunk reports its own hardware usage
+                let hw_metrics = RequestHwCounter::new(
+                    HwMeasurementAcc::new_with_metrics_drain(
+                        dispatcher.get_collection_hw_metrics(scroll_points.collection_name.clone()),
+                    ),
+                    hardware_reporting,
+                );
+
+                let response = scroll(
+                    StrictModeCheckedTocProvider::new(&dispatcher),
+                    scroll_points.clone(),
+                    None,
+                    auth,
+                    hw_metrics,
+                )
+                .await?
+                .into_inner();
+
+                let next_request = response
+                    .next_page_offset
+                    .clone()
+                    .map(|offset| ScrollPoints {
+                        offset: Some(offset),
+                        ..scroll_points
+                    });
+
+                Ok(Some((response, next_request)))
+            }
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+
     async fn recommend(
         &self,
         mut request: Request<RecommendPoints>,
@@ -629,6 +782,7 @@ impl Points for PointsService {
             query_points,
             read_consistency,
             timeout,
+            allow_partial,
         } = request;
         let timeout = timeout.map(Duration::from_secs);
         let hw_metrics =
@@ -640,6 +794,7 @@ impl Points for PointsService {
             read_consistency,
             auth,
             timeout,
+            allow_partial.unwrap_or(false),
             hw_metrics,
             inference_params,
         )
@@ -691,6 +846,82 @@ impl Points for PointsService {
         .await
     }
 
+    async fn facet_multi(
+        &self,
+        mut request: Request<FacetMultiCounts>,
+    ) -> Result<Response<FacetMultiResponse>, Status> {
+        validate(request.get_ref())?;
+        let auth = extract_auth(&mut request);
+        let hw_metrics = self.get_request_collection_hw_usage_counter(
+            request.get_ref().collection_name.clone(),
+            None,
+        );
+        facet_multi(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            auth,
+            hw_metrics,
+        )
+        .await
+    }
+
+    async fn aggregate(
+        &self,
+        mut request: Request<AggregatePoints>,
+    ) -> Result<Response<AggregateResponse>, Status> {
+        validate(request.get_ref())?;
+        let auth = extract_auth(&mut request);
+        let hw_metrics = self.get_request_collection_hw_usage_counter(
+            request.get_ref().collection_name.clone(),
+            None,
+        );
+        aggregate(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            auth,
+            hw_metrics,
+        )
+        .await
+    }
+
+    async fn join(
+        &self,
+        mut request: Request<JoinPoints>,
+    ) -> Result<Response<JoinResponse>, Status> {
+        validate(request.get_ref())?;
+        let auth = extract_auth(&mut request);
+        let hw_metrics = self.get_request_collection_hw_usage_counter(
+            request.get_ref().collection_name.clone(),
+            None,
+        );
+        join(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            auth,
+            hw_metrics,
+        )
+        .await
+    }
+
+    async fn sample_projection(
+        &self,
+        mut request: Request<SampleProjectionPoints>,
+    ) -> Result<Response<SampleProjectionResponse>, Status> {
+        validate(request.get_ref())?;
+        let auth = extract_auth(&mut request);
+        let hw_metrics = self.get_request_collection_hw_usage_counter(
+            request.get_ref().collection_name.clone(),
+            None,
+        );
+        sample_projection(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            auth,
+            hw_metrics,
+        )
+        .await
+    }
+
     async fn search_matrix_pairs(
         &self,
         mut request: Request<SearchMatrixPoints>,
@@ -742,4 +973,23 @@ impl Points for PointsService {
 
         Ok(Response::new(offsets_response))
     }
+
+    async fn copy_points(
+        &self,
+        mut request: Request<CopyPoints>,
+    ) -> Result<Response<CopyPointsResponse>, Status> {
+        validate(request.get_ref())?;
+        let auth = extract_auth(&mut request);
+        let hw_metrics = self.get_request_collection_hw_usage_counter(
+            request.get_ref().collection_name.clone(),
+            None,
+        );
+        copy_points(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            auth,
+            hw_metrics,
+        )
+        .await
+    }
 }
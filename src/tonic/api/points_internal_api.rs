@@ -6,7 +6,8 @@ use std::time::{Duration, Instant};
 use api::grpc::HardwareUsage;
 use api::grpc::qdrant::points_internal_server::PointsInternal;
 use api::grpc::qdrant::{
-    ClearPayloadPointsInternal, CoreSearchBatchPointsInternal, CountPointsInternal, CountResponse,
+    AggregatePointsInternal, AggregateResponseInternal, ClearPayloadPointsInternal,
+    CoreSearchBatchPointsInternal, CountPointsInternal, CountResponse,
     CreateFieldIndexCollectionInternal, DeleteFieldIndexCollectionInternal,
     DeletePayloadPointsInternal, DeletePointsInternal, DeleteVectorsInternal, FacetCountsInternal,
     FacetResponseInternal, GetPointsInternal, GetResponse, IntermediateResult,
@@ -16,11 +17,14 @@ use api::grpc::qdrant::{
     SyncPointsInternal, UpdateBatchInternal, UpdateVectorsInternal, UpsertPointsInternal,
 };
 use api::grpc::update_operation::Update;
+use collection::collection::partial_results::TimedOut;
+use collection::collection::search_explanations::ExplainedShards;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::universal_query::shard_query::ShardQueryRequest;
 use collection::shards::shard::ShardId;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use itertools::Itertools;
+use segment::data_types::aggregation::{AggregateParams, AggregateResult};
 use segment::data_types::facets::{FacetParams, FacetResponse};
 use segment::json_path::JsonPath;
 use segment::types::Filter;
@@ -243,6 +247,32 @@ impl PointsInternalService {
         .await
     }
 
+    async fn patch_payload_internal(
+        &self,
+        patch_payload_internal: SetPayloadPointsInternal,
+    ) -> Result<Response<PointsOperationResponseInternal>, Status> {
+        let SetPayloadPointsInternal {
+            set_payload_points,
+            shard_id,
+            clock_tag,
+        } = patch_payload_internal;
+
+        let set_payload_points = extract_internal_request(set_payload_points)?;
+
+        let hw_metrics = self.get_request_collection_hw_usage_counter_for_internal(
+            set_payload_points.collection_name.clone(),
+        );
+
+        patch_payload(
+            StrictModeCheckedInternalTocProvider::new(&self.toc),
+            set_payload_points,
+            InternalUpdateParams::from_grpc(shard_id, clock_tag),
+            full_internal_auth(),
+            hw_metrics,
+        )
+        .await
+    }
+
     async fn delete_payload_internal(
         &self,
         delete_payload_internal: DeletePayloadPointsInternal,
@@ -338,6 +368,7 @@ pub async fn query_batch_internal(
     query_points: Vec<QueryShardPoints>,
     shard_selection: Option<ShardId>,
     timeout: Option<Duration>,
+    explain: bool,
     request_hw_data: RequestHwCounter,
 ) -> Result<Response<QueryBatchResponseInternal>, Status> {
     let batch_requests: Vec<_> = query_points
@@ -357,12 +388,17 @@ pub async fn query_batch_internal(
         Some(shard_id) => ShardSelectorInternal::ShardId(shard_id),
     };
 
+    let explained = explain.then(ExplainedShards::default);
+    let timed_out = TimedOut::default();
+
     let batch_response = toc
         .query_batch_internal(
             &collection_name,
             batch_requests,
             shard_selection,
             timeout,
+            explained.as_ref(),
+            Some(&timed_out),
             request_hw_data.get_counter(),
         )
         .await?;
@@ -382,6 +418,10 @@ pub async fn query_batch_internal(
         time: timing.elapsed().as_secs_f64(),
         hardware_usage: request_hw_data.to_grpc_api(),
         inference_usage: None, // No inference in internal API
+        timed_out: timed_out.is_timed_out().then_some(true),
+        explanations: explained
+            .map(|explained| explained.to_grpc_api())
+            .unwrap_or_default(),
     };
 
     Ok(Response::new(response))
@@ -402,6 +442,7 @@ async fn facet_counts_internal(
         exact,
         shard_id,
         timeout,
+        histogram,
     } = request;
 
     let shard_selection = ShardSelectorInternal::ShardId(shard_id);
@@ -412,6 +453,7 @@ async fn facet_counts_internal(
         limit: limit as usize,
         filter: filter.map(Filter::try_from).transpose()?,
         exact,
+        histogram: histogram.map(TryInto::try_into).transpose()?,
     };
 
     let response = toc
@@ -435,6 +477,62 @@ async fn facet_counts_internal(
     Ok(Response::new(response))
 }
 
+async fn aggregate_internal(
+    toc: &TableOfContent,
+    request: AggregatePointsInternal,
+    request_hw_data: RequestHwCounter,
+) -> Result<Response<AggregateResponseInternal>, Status> {
+    let timing = Instant::now();
+
+    let AggregatePointsInternal {
+        collection_name,
+        key,
+        filter,
+        shard_id,
+        timeout,
+    } = request;
+
+    let shard_selection = ShardSelectorInternal::ShardId(shard_id);
+
+    let request = AggregateParams {
+        key: JsonPath::from_str(&key)
+            .map_err(|_| Status::invalid_argument("Failed to parse aggregate key"))?,
+        filter: filter.map(Filter::try_from).transpose()?,
+    };
+
+    let result = toc
+        .aggregate_internal(
+            &collection_name,
+            request,
+            shard_selection,
+            timeout.map(Duration::from_secs),
+            request_hw_data.get_counter(),
+        )
+        .await?;
+
+    let AggregateResult {
+        count,
+        sum,
+        min,
+        max,
+        distinct_values,
+        distinct_values_exceeded,
+    } = result;
+
+    let response = AggregateResponseInternal {
+        count: count as u64,
+        sum,
+        min,
+        max,
+        distinct_values: distinct_values.into_iter().map(From::from).collect_vec(),
+        distinct_values_exceeded,
+        time: timing.elapsed().as_secs_f64(),
+        usage: request_hw_data.to_grpc_api(),
+    };
+
+    Ok(Response::new(response))
+}
+
 impl PointsInternalService {
     /// Generates a new `RequestHwCounter` for the request.
     /// This counter is indented to be used for internal requests.
@@ -514,6 +612,15 @@ impl PointsInternal for PointsInternalService {
         self.overwrite_payload_internal(request.into_inner()).await
     }
 
+    async fn patch_payload(
+        &self,
+        request: Request<SetPayloadPointsInternal>,
+    ) -> Result<Response<PointsOperationResponseInternal>, Status> {
+        validate_and_log(request.get_ref());
+
+        self.patch_payload_internal(request.into_inner()).await
+    }
+
     async fn delete_payload(
         &self,
         request: Request<DeletePayloadPointsInternal>,
@@ -610,6 +717,9 @@ impl PointsInternal for PointsInternalService {
                     Update::DeleteFieldIndex(delete_field_index) => {
                         self.delete_field_index_internal(delete_field_index).await?
                     }
+                    Update::PatchPayload(patch_payload) => {
+                        self.patch_payload_internal(patch_payload).await?
+                    }
                 },
             };
             let mut response = result.into_inner();
@@ -816,6 +926,7 @@ impl PointsInternal for PointsInternalService {
             shard_id,
             query_points,
             timeout,
+            explain,
         } = request.into_inner();
 
         let timeout = timeout.map(Duration::from_secs);
@@ -829,6 +940,7 @@ impl PointsInternal for PointsInternalService {
             query_points,
             shard_id,
             timeout,
+            explain.unwrap_or_default(),
             hw_data,
         )
         .await
@@ -846,6 +958,19 @@ impl PointsInternal for PointsInternalService {
         );
         facet_counts_internal(self.toc.as_ref(), request_inner, hw_data).await
     }
+
+    async fn aggregate(
+        &self,
+        request: Request<AggregatePointsInternal>,
+    ) -> Result<Response<AggregateResponseInternal>, Status> {
+        validate_and_log(request.get_ref());
+
+        let request_inner = request.into_inner();
+        let hw_data = self.get_request_collection_hw_usage_counter_for_internal(
+            request_inner.collection_name.clone(),
+        );
+        aggregate_internal(self.toc.as_ref(), request_inner, hw_data).await
+    }
 }
 
 fn extract_internal_request<T>(request: Option<T>) -> Result<T, tonic::Status> {
@@ -2,28 +2,44 @@ use std::time::{Duration, Instant};
 
 use api::conversions::json::json_path_from_proto;
 use api::grpc::qdrant::{
-    BatchResult, CoreSearchPoints, CountPoints, CountResponse, DiscoverBatchResponse,
-    DiscoverPoints, DiscoverResponse, FacetCounts, FacetResponse, GetPoints, GetResponse,
-    GroupsResult, QueryBatchResponse, QueryGroupsResponse, QueryPointGroups, QueryPoints,
-    QueryResponse, ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse,
+    AggregatePoints, AggregateResponse, BatchResult, CoreSearchPoints, CountPoints, CountResponse,
+    DiscoverBatchResponse, DiscoverPoints, DiscoverResponse, FacetCounts, FacetMultiCounts,
+    FacetMultiResponse, FacetResponse, GetPoints, GetResponse, GroupsResult, JoinPoints,
+    JoinResponse, KeyFacetHits, QueryBatchResponse, QueryGroupsResponse, QueryPointGroups,
+    QueryPoints, QueryResponse, ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse,
     RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
-    ScrollPoints, ScrollResponse, SearchBatchResponse, SearchGroupsResponse, SearchMatrixPoints,
-    SearchPointGroups, SearchPoints, SearchResponse,
+    SampleProjectionPoints, SampleProjectionResponse, ScrollPoints, ScrollResponse,
+    SearchBatchResponse, SearchGroupsResponse, SearchMatrixPoints, SearchPointGroups, SearchPoints,
+    SearchResponse,
 };
 use api::grpc::{InferenceUsage, Usage};
-use api::rest::OrderByInterface;
+use api::rest::{
+    FilterTemplateReference, JoinRequestInternal, OrderByInterface,
+    SampleProjectionRequestInternal, SearchGroupsRequestInternal,
+};
 use collection::collection::distance_matrix::{
     CollectionSearchMatrixRequest, CollectionSearchMatrixResponse,
 };
+use collection::collection::join::CollectionJoinRequest;
+use collection::collection::partial_results::{SkippedShards, TimedOut};
+use collection::collection::sample_projection::CollectionSampleProjectionRequest;
+use collection::collection::search_explanations::ExplainedShards;
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::conversions::try_discover_request_from_grpc;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
-use collection::operations::types::{CoreSearchRequest, PointRequestInternal};
+use collection::operations::types::{
+    CoreSearchRequest, PointRequestInternal, RecommendGroupsRequestInternal,
+};
+use collection::operations::universal_query::collection_query::{
+    CollectionQueryRequest, Query, VectorInputInternal, VectorQuery,
+};
 use collection::shards::shard::ShardId;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use segment::data_types::facets::FacetParams;
+use segment::data_types::aggregation::AggregateParams;
+use segment::data_types::facets::{FacetParams, MultiFacetParams};
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorInternal};
+use segment::types::{Filter, PointIdType};
 use shard::count::CountRequestInternal;
 use shard::query::query_enum::QueryEnum;
 use shard::scroll::ScrollRequestInternal;
@@ -33,6 +49,7 @@ use storage::content_manager::toc::request_hw_counter::RequestHwCounter;
 use storage::rbac::Auth;
 use tonic::{Response, Status};
 
+use crate::common::filter_templates::resolve_filter_template;
 use crate::common::inference::params::InferenceParams;
 use crate::common::inference::query_requests_grpc::{
     convert_query_point_groups_from_grpc, convert_query_points_from_grpc,
@@ -59,6 +76,22 @@ pub(crate) fn convert_shard_selector_for_read(
     Ok(res)
 }
 
+/// Combine the request filter with the filter template referenced by a gRPC request, if any
+async fn resolve_filter_template_grpc(
+    toc: &TableOfContent,
+    collection_name: &str,
+    filter: Option<Filter>,
+    filter_template: Option<api::grpc::qdrant::FilterTemplateReference>,
+    auth: &Auth,
+) -> Result<Option<Filter>, Status> {
+    let filter_template = filter_template
+        .map(FilterTemplateReference::try_from)
+        .transpose()?;
+    let filter =
+        resolve_filter_template(toc, collection_name, filter, filter_template, auth).await?;
+    Ok(filter)
+}
+
 pub async fn search(
     toc_provider: impl CheckedTocProvider,
     search_points: SearchPoints,
@@ -81,6 +114,8 @@ pub async fn search(
         timeout,
         shard_key_selector,
         sparse_indices,
+        filter_template,
+        explain,
     } = search_points;
 
     let vector_internal =
@@ -91,7 +126,7 @@ pub async fn search(
 
     let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector)?;
 
-    let search_request = CoreSearchRequest {
+    let mut search_request = CoreSearchRequest {
         query: QueryEnum::Nearest(NamedQuery::from(vector_struct)),
         filter: filter.map(|f| f.try_into()).transpose()?,
         params: params.map(|p| p.into()),
@@ -115,21 +150,52 @@ pub async fn search(
         )
         .await?;
 
-    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
-
-    let timing = Instant::now();
-    let scored_points = do_core_search_points(
+    search_request.filter = resolve_filter_template_grpc(
         toc,
         &collection_name,
-        search_request,
-        read_consistency,
-        shard_selector,
-        auth,
-        timeout.map(Duration::from_secs),
-        hw_measurement_acc.get_counter(),
+        search_request.filter,
+        filter_template,
+        &auth,
     )
     .await?;
 
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+    let explained = explain.unwrap_or(false).then(ExplainedShards::default);
+
+    let timing = Instant::now();
+    let scored_points = match &explained {
+        None => {
+            do_core_search_points(
+                toc,
+                &collection_name,
+                search_request,
+                read_consistency,
+                shard_selector,
+                auth,
+                timeout.map(Duration::from_secs),
+                hw_measurement_acc.get_counter(),
+            )
+            .await?
+        }
+        // Core searches are not explained, run the search as an equivalent query instead
+        Some(explained) => {
+            do_query_points(
+                toc,
+                &collection_name,
+                search_as_query_request(search_request)?,
+                read_consistency,
+                shard_selector,
+                auth,
+                timeout.map(Duration::from_secs),
+                None,
+                Some(explained),
+                None,
+                hw_measurement_acc.get_counter(),
+            )
+            .await?
+        }
+    };
+
     let response = SearchResponse {
         result: scored_points
             .into_iter()
@@ -137,15 +203,58 @@ pub async fn search(
             .collect(),
         time: timing.elapsed().as_secs_f64(),
         usage: Usage::from_hardware_usage(hw_measurement_acc.to_grpc_api()).into_non_empty(),
+        explain: explained
+            .map(|explained| explained.to_grpc_shards())
+            .unwrap_or_default(),
     };
 
     Ok(Response::new(response))
 }
 
+/// Convert a nearest neighbors search into a query request with the same results
+fn search_as_query_request(request: CoreSearchRequest) -> Result<CollectionQueryRequest, Status> {
+    let CoreSearchRequest {
+        query,
+        filter,
+        params,
+        limit,
+        offset,
+        with_payload,
+        with_vector,
+        score_threshold,
+    } = request;
+
+    let QueryEnum::Nearest(NamedQuery { query, using }) = query else {
+        return Err(Status::invalid_argument(
+            "Only nearest neighbors searches can be explained",
+        ));
+    };
+
+    Ok(CollectionQueryRequest {
+        prefetch: vec![],
+        query: Some(Query::Vector(VectorQuery::Nearest(
+            VectorInputInternal::Vector(query),
+        ))),
+        using: using.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned()),
+        filter,
+        score_threshold,
+        limit,
+        offset,
+        params,
+        with_vector: with_vector.unwrap_or(CollectionQueryRequest::DEFAULT_WITH_VECTOR),
+        with_payload: with_payload.unwrap_or(CollectionQueryRequest::DEFAULT_WITH_PAYLOAD),
+        lookup_from: None,
+    })
+}
+
 pub async fn core_search_batch(
     toc_provider: impl CheckedTocProvider,
     collection_name: &str,
-    requests: Vec<(CoreSearchRequest, ShardSelectorInternal)>,
+    requests: Vec<(
+        CoreSearchRequest,
+        ShardSelectorInternal,
+        Option<api::grpc::qdrant::FilterTemplateReference>,
+    )>,
     read_consistency: Option<ReadConsistencyGrpc>,
     auth: Auth,
     timeout: Option<Duration>,
@@ -161,6 +270,19 @@ pub async fn core_search_batch(
         )
         .await?;
 
+    let mut resolved_requests = Vec::with_capacity(requests.len());
+    for (mut request, shard_selector, filter_template) in requests {
+        request.filter = resolve_filter_template_grpc(
+            toc,
+            collection_name,
+            request.filter,
+            filter_template,
+            &auth,
+        )
+        .await?;
+        resolved_requests.push((request, shard_selector));
+    }
+
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let timing = Instant::now();
@@ -168,7 +290,7 @@ pub async fn core_search_batch(
     let scored_points = do_search_batch_points(
         toc,
         collection_name,
-        requests,
+        resolved_requests,
         read_consistency,
         auth,
         timeout,
@@ -257,13 +379,15 @@ pub async fn search_groups(
     auth: Auth,
     request_hw_counter: RequestHwCounter,
 ) -> Result<Response<SearchGroupsResponse>, Status> {
-    let search_groups_request = search_point_groups.clone().try_into()?;
+    let mut search_groups_request: SearchGroupsRequestInternal =
+        search_point_groups.clone().try_into()?;
 
     let SearchPointGroups {
         collection_name,
         read_consistency,
         timeout,
         shard_key_selector,
+        filter_template,
         ..
     } = search_point_groups;
 
@@ -276,6 +400,15 @@ pub async fn search_groups(
         )
         .await?;
 
+    search_groups_request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        search_groups_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector)?;
@@ -315,9 +448,10 @@ pub async fn recommend(
     let collection_name = recommend_points.collection_name.clone();
     let read_consistency = recommend_points.read_consistency.clone();
     let shard_key_selector = recommend_points.shard_key_selector.clone();
+    let filter_template = recommend_points.filter_template.clone();
     let timeout = recommend_points.timeout;
 
-    let request =
+    let mut request =
         collection::operations::types::RecommendRequestInternal::try_from(recommend_points)?;
 
     let toc = toc_provider
@@ -329,6 +463,15 @@ pub async fn recommend(
         )
         .await?;
 
+    request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
     let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
     let timeout = timeout.map(Duration::from_secs);
@@ -369,9 +512,12 @@ pub async fn recommend_batch(
 ) -> Result<Response<RecommendBatchResponse>, Status> {
     let mut requests = Vec::with_capacity(recommend_points.len());
 
+    let mut filter_templates = Vec::with_capacity(recommend_points.len());
+
     for mut request in recommend_points {
         let shard_selector =
             convert_shard_selector_for_read(None, request.shard_key_selector.take())?;
+        filter_templates.push(request.filter_template.take());
         let internal_request: collection::operations::types::RecommendRequestInternal =
             request.try_into()?;
         requests.push((internal_request, shard_selector));
@@ -387,6 +533,17 @@ pub async fn recommend_batch(
         )
         .await?;
 
+    for ((request, _), filter_template) in requests.iter_mut().zip(filter_templates) {
+        request.filter = resolve_filter_template_grpc(
+            toc,
+            collection_name,
+            request.filter.take(),
+            filter_template,
+            &auth,
+        )
+        .await?;
+    }
+
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let timing = Instant::now();
@@ -421,13 +578,15 @@ pub async fn recommend_groups(
     auth: Auth,
     request_hw_counter: RequestHwCounter,
 ) -> Result<Response<RecommendGroupsResponse>, Status> {
-    let recommend_groups_request = recommend_point_groups.clone().try_into()?;
+    let mut recommend_groups_request: RecommendGroupsRequestInternal =
+        recommend_point_groups.clone().try_into()?;
 
     let RecommendPointGroups {
         collection_name,
         read_consistency,
         timeout,
         shard_key_selector,
+        filter_template,
         ..
     } = recommend_point_groups;
 
@@ -440,6 +599,15 @@ pub async fn recommend_groups(
         )
         .await?;
 
+    recommend_groups_request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        recommend_groups_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
@@ -475,7 +643,8 @@ pub async fn discover(
     auth: Auth,
     request_hw_counter: RequestHwCounter,
 ) -> Result<Response<DiscoverResponse>, Status> {
-    let (request, collection_name, read_consistency, timeout, shard_key_selector) =
+    let filter_template = discover_points.filter_template.clone();
+    let (mut request, collection_name, read_consistency, timeout, shard_key_selector) =
         try_discover_request_from_grpc(discover_points)?;
 
     let toc = toc_provider
@@ -487,6 +656,15 @@ pub async fn discover(
         )
         .await?;
 
+    request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
     let timing = Instant::now();
 
     let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
@@ -526,7 +704,10 @@ pub async fn discover_batch(
 ) -> Result<Response<DiscoverBatchResponse>, Status> {
     let mut requests = Vec::with_capacity(discover_points.len());
 
-    for discovery_request in discover_points {
+    let mut filter_templates = Vec::with_capacity(discover_points.len());
+
+    for mut discovery_request in discover_points {
+        filter_templates.push(discovery_request.filter_template.take());
         let (internal_request, _collection_name, _consistency, _timeout, shard_key_selector) =
             try_discover_request_from_grpc(discovery_request)?;
         let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
@@ -545,6 +726,17 @@ pub async fn discover_batch(
         )
         .await?;
 
+    for ((request, _), filter_template) in requests.iter_mut().zip(filter_templates) {
+        request.filter = resolve_filter_template_grpc(
+            toc,
+            collection_name,
+            request.filter.take(),
+            filter_template,
+            &auth,
+        )
+        .await?;
+    }
+
     let timing = Instant::now();
     let scored_points = toc
         .discover_batch(
@@ -589,9 +781,12 @@ pub async fn scroll(
         shard_key_selector,
         order_by,
         timeout,
+        with_version,
+        distinct_by,
+        filter_template,
     } = scroll_points;
 
-    let scroll_request = ScrollRequestInternal {
+    let mut scroll_request = ScrollRequestInternal {
         offset: offset.map(|o| o.try_into()).transpose()?,
         limit: limit.map(|l| l as usize),
         filter: filter.map(|f| f.try_into()).transpose()?,
@@ -603,6 +798,10 @@ pub async fn scroll(
             .map(OrderBy::try_from)
             .transpose()?
             .map(OrderByInterface::Struct),
+        distinct_by: distinct_by
+            .map(|key| json_path_from_proto(&key))
+            .transpose()?,
+        with_version,
     };
 
     let toc = toc_provider
@@ -614,6 +813,15 @@ pub async fn scroll(
         )
         .await?;
 
+    scroll_request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        scroll_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
     let timeout = timeout.map(Duration::from_secs);
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
@@ -664,9 +872,10 @@ pub async fn count(
         read_consistency,
         shard_key_selector,
         timeout,
+        filter_template,
     } = count_points;
 
-    let count_request = CountRequestInternal {
+    let mut count_request = CountRequestInternal {
         filter: filter.map(|f| f.try_into()).transpose()?,
         exact: exact.unwrap_or_else(CountRequestInternal::default_exact),
     };
@@ -680,6 +889,15 @@ pub async fn count(
         )
         .await?;
 
+    count_request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        count_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
     let timeout = timeout.map(Duration::from_secs);
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
@@ -723,6 +941,7 @@ pub async fn get(
         read_consistency,
         shard_key_selector,
         timeout,
+        with_version,
     } = get_points;
 
     let point_request = PointRequestInternal {
@@ -734,6 +953,7 @@ pub async fn get(
         with_vector: with_vectors
             .map(|selector| selector.into())
             .unwrap_or_default(),
+        with_version,
     };
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
@@ -790,7 +1010,16 @@ pub async fn query(
         .transpose()?;
     let collection_name = query_points.collection_name.clone();
     let timeout = query_points.timeout;
-    let (request, inference_usage) =
+    let filter_template = query_points.filter_template.clone();
+    let skipped_shards = query_points
+        .allow_partial
+        .unwrap_or(false)
+        .then(SkippedShards::default);
+    let explained = query_points
+        .explain
+        .unwrap_or(false)
+        .then(ExplainedShards::default);
+    let (mut request, inference_usage) =
         convert_query_points_from_grpc(query_points, inference_params).await?;
 
     let toc = toc_provider
@@ -802,7 +1031,21 @@ pub async fn query(
         )
         .await?;
 
+    request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
     let timeout = timeout.map(Duration::from_secs);
+    let degraded = toc
+        .degrade_query_requests(&collection_name, [&mut request])
+        .await;
+
+    let timed_out = TimedOut::default();
 
     let timing = Instant::now();
     let scored_points = do_query_points(
@@ -813,6 +1056,9 @@ pub async fn query(
         shard_selector,
         auth,
         timeout,
+        skipped_shards.as_ref(),
+        explained.as_ref(),
+        Some(&timed_out),
         request_hw_counter.get_counter(),
     )
     .await?;
@@ -824,6 +1070,14 @@ pub async fn query(
             .collect(),
         time: timing.elapsed().as_secs_f64(),
         usage: Usage::new(request_hw_counter.to_grpc_api(), Some(inference_usage)).into_non_empty(),
+        degraded: degraded.then_some(true),
+        timed_out: timed_out.is_timed_out().then_some(true),
+        skipped_shards: skipped_shards
+            .map(|skipped_shards| skipped_shards.to_grpc_api())
+            .unwrap_or_default(),
+        explain: explained
+            .map(|explained| explained.to_grpc_shards())
+            .unwrap_or_default(),
     };
 
     Ok(Response::new(response))
@@ -837,6 +1091,7 @@ pub async fn query_batch(
     read_consistency: Option<ReadConsistencyGrpc>,
     auth: Auth,
     timeout: Option<Duration>,
+    allow_partial: bool,
     request_hw_counter: RequestHwCounter,
     inference_params: InferenceParams,
 ) -> Result<Response<QueryBatchResponse>, Status> {
@@ -844,9 +1099,12 @@ pub async fn query_batch(
     let mut requests = Vec::with_capacity(points.len());
     let mut total_inference_usage = InferenceUsage::default();
 
-    for query_points in points {
+    let mut filter_templates = Vec::with_capacity(points.len());
+
+    for mut query_points in points {
         let shard_key_selector = query_points.shard_key_selector.clone();
         let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
+        filter_templates.push(query_points.filter_template.take());
         let (request, usage) =
             convert_query_points_from_grpc(query_points, inference_params.clone()).await?;
         total_inference_usage.merge(usage);
@@ -863,6 +1121,28 @@ pub async fn query_batch(
         )
         .await?;
 
+    for ((request, _), filter_template) in requests.iter_mut().zip(filter_templates) {
+        request.filter = resolve_filter_template_grpc(
+            toc,
+            collection_name,
+            request.filter.take(),
+            filter_template,
+            &auth,
+        )
+        .await?;
+    }
+
+    let degraded = toc
+        .degrade_query_requests(
+            collection_name,
+            requests.iter_mut().map(|(request, _)| request),
+        )
+        .await;
+
+    let skipped_shards = allow_partial.then(SkippedShards::default);
+
+    let timed_out = TimedOut::default();
+
     let timing = Instant::now();
     let scored_points = do_query_batch_points(
         toc,
@@ -871,6 +1151,8 @@ pub async fn query_batch(
         read_consistency,
         auth,
         timeout,
+        skipped_shards.as_ref(),
+        Some(&timed_out),
         request_hw_counter.get_counter(),
     )
     .await?;
@@ -888,6 +1170,11 @@ pub async fn query_batch(
             total_inference_usage.into_non_empty(),
         )
         .into_non_empty(),
+        degraded: degraded.then_some(true),
+        timed_out: timed_out.is_timed_out().then_some(true),
+        skipped_shards: skipped_shards
+            .map(|skipped_shards| skipped_shards.to_grpc_api())
+            .unwrap_or_default(),
     };
 
     Ok(Response::new(response))
@@ -910,7 +1197,8 @@ pub async fn query_groups(
         .transpose()?;
     let timeout = query_points.timeout;
     let collection_name = query_points.collection_name.clone();
-    let (request, inference_usage) =
+    let filter_template = query_points.filter_template.clone();
+    let (mut request, inference_usage) =
         convert_query_point_groups_from_grpc(query_points, inference_params).await?;
 
     let toc = toc_provider
@@ -922,6 +1210,15 @@ pub async fn query_groups(
         )
         .await?;
 
+    request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
     let timeout = timeout.map(Duration::from_secs);
     let timing = Instant::now();
 
@@ -964,9 +1261,11 @@ pub async fn facet(
         read_consistency,
         shard_key_selector,
         timeout,
+        histogram,
+        filter_template,
     } = facet_counts;
 
-    let facet_request = FacetParams {
+    let mut facet_request = FacetParams {
         key: json_path_from_proto(&key)?,
         filter: filter.map(TryInto::try_into).transpose()?,
         limit: limit
@@ -975,6 +1274,7 @@ pub async fn facet(
             .map_err(|_| Status::invalid_argument("could not parse limit param into usize"))?
             .unwrap_or(FacetParams::DEFAULT_LIMIT),
         exact: exact.unwrap_or(FacetParams::DEFAULT_EXACT),
+        histogram: histogram.map(TryInto::try_into).transpose()?,
     };
 
     let toc = toc_provider
@@ -986,6 +1286,15 @@ pub async fn facet(
         )
         .await?;
 
+    facet_request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        facet_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
     let timeout = timeout.map(Duration::from_secs);
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
@@ -1015,6 +1324,335 @@ pub async fn facet(
     Ok(Response::new(response))
 }
 
+pub async fn facet_multi(
+    toc_provider: impl CheckedTocProvider,
+    facet_counts: FacetMultiCounts,
+    auth: Auth,
+    request_hw_counter: RequestHwCounter,
+) -> Result<Response<FacetMultiResponse>, Status> {
+    let FacetMultiCounts {
+        collection_name,
+        keys,
+        filter,
+        exact,
+        limit,
+        read_consistency,
+        shard_key_selector,
+        timeout,
+        filter_template,
+    } = facet_counts;
+
+    let mut facet_request = MultiFacetParams {
+        keys: keys
+            .iter()
+            .map(|key| json_path_from_proto(key))
+            .collect::<Result<_, _>>()?,
+        filter: filter.map(TryInto::try_into).transpose()?,
+        limit: limit
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("could not parse limit param into usize"))?
+            .unwrap_or(FacetParams::DEFAULT_LIMIT),
+        exact: exact.unwrap_or(FacetParams::DEFAULT_EXACT),
+    };
+
+    let toc = toc_provider
+        .check_strict_mode(
+            &facet_request,
+            &collection_name,
+            timeout.map(|i| i as usize),
+            &auth,
+        )
+        .await?;
+
+    facet_request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        facet_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
+    let timeout = timeout.map(Duration::from_secs);
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
+
+    let timing = Instant::now();
+    let facet_responses = toc
+        .facets(
+            &collection_name,
+            facet_request,
+            shard_selector,
+            read_consistency,
+            auth,
+            timeout,
+            request_hw_counter.get_counter(),
+        )
+        .await?;
+
+    let facets = keys
+        .into_iter()
+        .zip(facet_responses)
+        .map(|(key, response)| KeyFacetHits {
+            key,
+            hits: response.hits.into_iter().map(From::from).collect(),
+        })
+        .collect();
+
+    let response = FacetMultiResponse {
+        facets,
+        time: timing.elapsed().as_secs_f64(),
+        usage: Usage::from_hardware_usage(request_hw_counter.to_grpc_api()).into_non_empty(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn aggregate(
+    toc_provider: impl CheckedTocProvider,
+    aggregate_points: AggregatePoints,
+    auth: Auth,
+    request_hw_counter: RequestHwCounter,
+) -> Result<Response<AggregateResponse>, Status> {
+    let AggregatePoints {
+        collection_name,
+        key,
+        filter,
+        timeout,
+        read_consistency,
+        shard_key_selector,
+        filter_template,
+    } = aggregate_points;
+
+    let mut aggregate_request = AggregateParams {
+        key: json_path_from_proto(&key)?,
+        filter: filter.map(TryInto::try_into).transpose()?,
+    };
+
+    let toc = toc_provider
+        .check_strict_mode(
+            &aggregate_request,
+            &collection_name,
+            timeout.map(|i| i as usize),
+            &auth,
+        )
+        .await?;
+
+    aggregate_request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        aggregate_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
+    let timeout = timeout.map(Duration::from_secs);
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
+
+    let timing = Instant::now();
+    let aggregate_result = toc
+        .aggregate(
+            &collection_name,
+            aggregate_request,
+            shard_selector,
+            read_consistency,
+            auth,
+            timeout,
+            request_hw_counter.get_counter(),
+        )
+        .await?;
+
+    let response = AggregateResponse {
+        count: aggregate_result.count as u64,
+        sum: aggregate_result.sum,
+        min: aggregate_result.min,
+        max: aggregate_result.max,
+        avg: aggregate_result.avg(),
+        distinct_count: aggregate_result
+            .distinct_count()
+            .map(|distinct_count| distinct_count as u64),
+        time: timing.elapsed().as_secs_f64(),
+        usage: Usage::from_hardware_usage(request_hw_counter.to_grpc_api()).into_non_empty(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn join(
+    toc_provider: impl CheckedTocProvider,
+    join_points: JoinPoints,
+    auth: Auth,
+    request_hw_counter: RequestHwCounter,
+) -> Result<Response<JoinResponse>, Status> {
+    let JoinPoints {
+        collection_name,
+        target_collection,
+        using,
+        target_using,
+        filter,
+        target_filter,
+        limit,
+        params,
+        score_threshold,
+        offset,
+        points_limit,
+        payload_key,
+        read_consistency,
+        shard_key_selector,
+        timeout,
+        filter_template,
+    } = join_points;
+
+    let mut join_request = JoinRequestInternal {
+        target_collection,
+        using,
+        target_using,
+        filter: filter.map(TryInto::try_into).transpose()?,
+        target_filter: target_filter.map(TryInto::try_into).transpose()?,
+        limit: limit.map(|limit| limit as usize),
+        params: params.map(From::from),
+        score_threshold,
+        offset: offset.map(PointIdType::try_from).transpose()?,
+        points_limit: points_limit.map(|points_limit| points_limit as usize),
+        payload_key,
+    };
+
+    let toc = toc_provider
+        .check_strict_mode(
+            &join_request,
+            &collection_name,
+            timeout.map(|i| i as usize),
+            &auth,
+        )
+        .await?;
+
+    join_request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        join_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
+    let timeout = timeout.map(Duration::from_secs);
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
+
+    let timing = Instant::now();
+    let join_result = do_join(
+        toc,
+        &collection_name,
+        CollectionJoinRequest::from(join_request),
+        read_consistency,
+        shard_selector,
+        auth,
+        timeout,
+        request_hw_counter.get_counter(),
+    )
+    .await?;
+
+    let api::rest::JoinResponse {
+        joined,
+        points,
+        next_page_offset,
+        error,
+    } = api::rest::JoinResponse::from(join_result);
+
+    let response = JoinResponse {
+        joined: joined as u64,
+        points: points.into_iter().map(From::from).collect(),
+        next_page_offset: next_page_offset.map(From::from),
+        error,
+        time: timing.elapsed().as_secs_f64(),
+        usage: Usage::from_hardware_usage(request_hw_counter.to_grpc_api()).into_non_empty(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn sample_projection(
+    toc_provider: impl CheckedTocProvider,
+    sample_projection_points: SampleProjectionPoints,
+    auth: Auth,
+    request_hw_counter: RequestHwCounter,
+) -> Result<Response<SampleProjectionResponse>, Status> {
+    let SampleProjectionPoints {
+        collection_name,
+        filter,
+        sample,
+        using,
+        with_payload,
+        read_consistency,
+        shard_key_selector,
+        timeout,
+        filter_template,
+    } = sample_projection_points;
+
+    let mut projection_request = SampleProjectionRequestInternal {
+        filter: filter.map(TryInto::try_into).transpose()?,
+        sample: sample.map(|sample| sample as usize),
+        using,
+        with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
+    };
+
+    let toc = toc_provider
+        .check_strict_mode(
+            &projection_request,
+            &collection_name,
+            timeout.map(|i| i as usize),
+            &auth,
+        )
+        .await?;
+
+    projection_request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        projection_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
+    let timeout = timeout.map(Duration::from_secs);
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
+
+    let timing = Instant::now();
+    let projection_result = do_sample_projection(
+        toc,
+        &collection_name,
+        CollectionSampleProjectionRequest::from(projection_request),
+        read_consistency,
+        shard_selector,
+        auth,
+        timeout,
+        request_hw_counter.get_counter(),
+    )
+    .await?;
+
+    let api::rest::SampleProjectionResponse {
+        points,
+        explained_variance,
+    } = api::rest::SampleProjectionResponse::from(projection_result);
+
+    let response = SampleProjectionResponse {
+        points: points.into_iter().map(From::from).collect(),
+        explained_variance,
+        time: timing.elapsed().as_secs_f64(),
+        usage: Usage::from_hardware_usage(request_hw_counter.to_grpc_api()).into_non_empty(),
+    };
+
+    Ok(Response::new(response))
+}
+
 pub async fn search_points_matrix(
     toc_provider: impl CheckedTocProvider,
     search_matrix_points: SearchMatrixPoints,
@@ -1030,9 +1668,10 @@ pub async fn search_points_matrix(
         read_consistency,
         shard_key_selector,
         timeout,
+        filter_template,
     } = search_matrix_points;
 
-    let search_matrix_request = CollectionSearchMatrixRequest {
+    let mut search_matrix_request = CollectionSearchMatrixRequest {
         filter: filter.map(TryInto::try_into).transpose()?,
         sample_size: sample
             .map(usize::try_from)
@@ -1056,6 +1695,15 @@ pub async fn search_points_matrix(
         )
         .await?;
 
+    search_matrix_request.filter = resolve_filter_template_grpc(
+        toc,
+        &collection_name,
+        search_matrix_request.filter,
+        filter_template,
+        &auth,
+    )
+    .await?;
+
     let timeout = timeout.map(Duration::from_secs);
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
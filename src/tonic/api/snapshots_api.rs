@@ -43,7 +43,10 @@ impl Snapshots for SnapshotsService {
     ) -> Result<Response<CreateSnapshotResponse>, Status> {
         validate(request.get_ref())?;
         let auth = extract_auth(&mut request);
-        let collection_name = request.into_inner().collection_name;
+        let CreateSnapshotRequest {
+            collection_name,
+            base_snapshot,
+        } = request.into_inner();
         let timing = Instant::now();
         let dispatcher = self.dispatcher.clone();
 
@@ -54,6 +57,7 @@ impl Snapshots for SnapshotsService {
             Arc::clone(dispatcher.toc(&auth, &pass)),
             &auth,
             &collection_name,
+            base_snapshot,
         )
         .await?;
 
@@ -1,21 +1,26 @@
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use api::conversions::json::{json_path_from_proto, proto_to_payloads};
 use api::grpc;
+use api::grpc::conversions::convert_shard_key_from_grpc;
 use api::grpc::qdrant::payload_index_params::IndexParams;
 use api::grpc::qdrant::points_update_operation::{ClearPayload, Operation, PointStructList};
 use api::grpc::qdrant::{
-    ClearPayloadPoints, CreateFieldIndexCollection, DeleteFieldIndexCollection,
-    DeletePayloadPoints, DeletePointVectors, DeletePoints, FieldType, PayloadIndexParams,
-    PointsOperationResponseInternal, PointsSelector, SetPayloadPoints, SyncPoints,
-    UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors, UpsertPoints,
-    points_update_operation,
+    ClearPayloadPoints, CopyPoints, CopyPointsResponse, CreateFieldIndexCollection,
+    DeleteFieldIndexCollection, DeletePayloadPoints, DeletePointVectors, DeletePoints, FieldType,
+    PayloadIndexParams, PointsOperationResponseInternal, PointsSelector, RestorePoints,
+    SetPayloadPoints, SyncPoints, UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors,
+    UpdatePreviewResponse, UpsertPoints, points_update_operation,
 };
 use api::grpc::{HardwareUsage, InferenceUsage, Usage};
 use api::rest::schema::{PointInsertOperations, PointsList};
-use api::rest::{PointStruct, PointVectors, ShardKeySelector, UpdateVectors, VectorStruct};
+use api::rest::{
+    CopyPointsRequest, CopyPointsRequestInternal, FilterTemplateReference, PointStruct,
+    PointVectors, ShardKeySelector, UpdateVectors, VectorStruct,
+};
 use collection::operations::CollectionUpdateOperations;
+use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::conversions::try_points_selector_from_grpc;
 use collection::operations::payload_ops::DeletePayload;
 use collection::operations::point_ops::{self, PointOperations, PointSyncOperation};
@@ -24,6 +29,7 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use itertools::Itertools;
 use segment::types::{
     ExtendedPointId, Filter, PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType,
+    PointIdType,
 };
 use storage::content_manager::toc::TableOfContent;
 use storage::content_manager::toc::request_hw_counter::RequestHwCounter;
@@ -138,6 +144,79 @@ pub async fn delete(
     Ok(Response::new(response))
 }
 
+pub async fn preview_delete(
+    toc_provider: impl CheckedTocProvider,
+    delete_points: DeletePoints,
+    auth: Auth,
+    request_hw_counter: RequestHwCounter,
+) -> Result<Response<UpdatePreviewResponse>, Status> {
+    let DeletePoints {
+        collection_name,
+        wait: _,
+        points,
+        ordering: _,
+        shard_key_selector,
+        timeout,
+    } = delete_points;
+
+    let points_selector = match points {
+        None => return Err(Status::invalid_argument("PointSelector is missing")),
+        Some(p) => try_points_selector_from_grpc(p, shard_key_selector)?,
+    };
+
+    let timing = Instant::now();
+    let result = do_preview_delete_points(
+        toc_provider,
+        collection_name,
+        points_selector,
+        None,
+        timeout.map(Duration::from_secs),
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await?;
+
+    let response = update_preview_response(timing, result, request_hw_counter);
+    Ok(Response::new(response))
+}
+
+pub async fn restore_points(
+    toc_provider: impl CheckedTocProvider,
+    restore_points: RestorePoints,
+    auth: Auth,
+    request_hw_counter: RequestHwCounter,
+) -> Result<Response<PointsOperationResponseInternal>, Status> {
+    let RestorePoints {
+        collection_name,
+        wait,
+        points,
+        ordering,
+        timeout,
+    } = restore_points;
+
+    let operation = crate::common::update::RestorePoints {
+        points: points
+            .into_iter()
+            .map(PointIdType::try_from)
+            .collect::<Result<_, _>>()?,
+    };
+
+    let timing = Instant::now();
+    let result = do_restore_points(
+        toc_provider,
+        collection_name,
+        operation,
+        UpdateParams::from_grpc(wait, ordering, timeout)?,
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await?;
+
+    let response =
+        points_operation_response_internal(timing, result, request_hw_counter.to_grpc_api());
+    Ok(Response::new(response))
+}
+
 pub async fn update_vectors(
     toc_provider: impl CheckedTocProvider,
     update_point_vectors: UpdatePointVectors,
@@ -219,7 +298,7 @@ pub async fn delete_vectors(
         timeout,
     } = delete_point_vectors;
 
-    let (points, filter) = extract_points_selector(points_selector)?;
+    let (points, filter, filter_template) = extract_points_selector(points_selector)?;
     let vector_names = match vectors {
         Some(vectors) => vectors.names,
         None => return Err(Status::invalid_argument("vectors is expected")),
@@ -232,6 +311,7 @@ pub async fn delete_vectors(
         shard_key: shard_key_selector
             .map(ShardKeySelector::try_from)
             .transpose()?,
+        filter_template,
     };
 
     let timing = Instant::now();
@@ -270,7 +350,7 @@ pub async fn set_payload(
     } = set_payload_points;
     let key = key.map(|k| json_path_from_proto(&k)).transpose()?;
 
-    let (points, filter) = extract_points_selector(points_selector)?;
+    let (points, filter, filter_template) = extract_points_selector(points_selector)?;
     let operation = collection::operations::payload_ops::SetPayload {
         payload: proto_to_payloads(payload)?,
         points,
@@ -278,6 +358,7 @@ pub async fn set_payload(
         shard_key: shard_key_selector
             .map(ShardKeySelector::try_from)
             .transpose()?,
+        filter_template,
         key,
     };
 
@@ -298,6 +379,52 @@ pub async fn set_payload(
     Ok(Response::new(response))
 }
 
+pub async fn preview_set_payload(
+    toc_provider: impl CheckedTocProvider,
+    set_payload_points: SetPayloadPoints,
+    auth: Auth,
+    request_hw_counter: RequestHwCounter,
+) -> Result<Response<UpdatePreviewResponse>, Status> {
+    let SetPayloadPoints {
+        collection_name,
+        wait: _,
+        payload,
+        points_selector,
+        ordering: _,
+        shard_key_selector,
+        key,
+        timeout,
+    } = set_payload_points;
+    let key = key.map(|k| json_path_from_proto(&k)).transpose()?;
+
+    let (points, filter, filter_template) = extract_points_selector(points_selector)?;
+    let operation = collection::operations::payload_ops::SetPayload {
+        payload: proto_to_payloads(payload)?,
+        points,
+        filter,
+        shard_key: shard_key_selector
+            .map(ShardKeySelector::try_from)
+            .transpose()?,
+        filter_template,
+        key,
+    };
+
+    let timing = Instant::now();
+    let result = do_preview_set_payload(
+        toc_provider,
+        collection_name,
+        operation,
+        None,
+        timeout.map(Duration::from_secs),
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await?;
+
+    let response = update_preview_response(timing, result, request_hw_counter);
+    Ok(Response::new(response))
+}
+
 pub async fn overwrite_payload(
     toc_provider: impl CheckedTocProvider,
     set_payload_points: SetPayloadPoints,
@@ -316,7 +443,7 @@ pub async fn overwrite_payload(
         ..
     } = set_payload_points;
 
-    let (points, filter) = extract_points_selector(points_selector)?;
+    let (points, filter, filter_template) = extract_points_selector(points_selector)?;
     let operation = collection::operations::payload_ops::SetPayload {
         payload: proto_to_payloads(payload)?,
         points,
@@ -324,6 +451,7 @@ pub async fn overwrite_payload(
         shard_key: shard_key_selector
             .map(ShardKeySelector::try_from)
             .transpose()?,
+        filter_template,
         // overwrite operation don't support indicate path of property
         key: None,
     };
@@ -345,6 +473,54 @@ pub async fn overwrite_payload(
     Ok(Response::new(response))
 }
 
+pub async fn patch_payload(
+    toc_provider: impl CheckedTocProvider,
+    set_payload_points: SetPayloadPoints,
+    internal_params: InternalUpdateParams,
+    auth: Auth,
+    request_hw_counter: RequestHwCounter,
+) -> Result<Response<PointsOperationResponseInternal>, Status> {
+    let SetPayloadPoints {
+        collection_name,
+        wait,
+        payload,
+        points_selector,
+        ordering,
+        shard_key_selector,
+        timeout,
+        ..
+    } = set_payload_points;
+
+    let (points, filter, filter_template) = extract_points_selector(points_selector)?;
+    let operation = collection::operations::payload_ops::SetPayload {
+        payload: proto_to_payloads(payload)?,
+        points,
+        filter,
+        shard_key: shard_key_selector
+            .map(ShardKeySelector::try_from)
+            .transpose()?,
+        filter_template,
+        // patch is always applied to the whole payload
+        key: None,
+    };
+
+    let timing = Instant::now();
+    let result = do_patch_payload(
+        toc_provider,
+        collection_name,
+        operation,
+        internal_params,
+        UpdateParams::from_grpc(wait, ordering, timeout)?,
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await?;
+
+    let response =
+        points_operation_response_internal(timing, result, request_hw_counter.to_grpc_api());
+    Ok(Response::new(response))
+}
+
 pub async fn delete_payload(
     toc_provider: impl CheckedTocProvider,
     delete_payload_points: DeletePayloadPoints,
@@ -363,7 +539,7 @@ pub async fn delete_payload(
     } = delete_payload_points;
     let keys = keys.iter().map(|k| json_path_from_proto(k)).try_collect()?;
 
-    let (points, filter) = extract_points_selector(points_selector)?;
+    let (points, filter, filter_template) = extract_points_selector(points_selector)?;
     let operation = DeletePayload {
         keys,
         points,
@@ -371,6 +547,7 @@ pub async fn delete_payload(
         shard_key: shard_key_selector
             .map(ShardKeySelector::try_from)
             .transpose()?,
+        filter_template,
     };
 
     let timing = Instant::now();
@@ -428,6 +605,61 @@ pub async fn clear_payload(
     Ok(Response::new(response))
 }
 
+pub async fn copy_points(
+    toc_provider: impl CheckedTocProvider,
+    copy_points: CopyPoints,
+    auth: Auth,
+    request_hw_counter: RequestHwCounter,
+) -> Result<Response<CopyPointsResponse>, Status> {
+    let CopyPoints {
+        collection_name,
+        target_collection,
+        filter,
+        target_shard_key,
+        offset,
+        shard_key_selector,
+        read_consistency,
+        timeout,
+        filter_template,
+    } = copy_points;
+
+    let request = CopyPointsRequest {
+        copy_request: CopyPointsRequestInternal {
+            target_collection,
+            filter: filter.map(Filter::try_from).transpose()?,
+            target_shard_key: target_shard_key.and_then(convert_shard_key_from_grpc),
+            offset: offset.map(PointIdType::try_from).transpose()?,
+        },
+        shard_key: shard_key_selector
+            .map(ShardKeySelector::try_from)
+            .transpose()?,
+        filter_template: filter_template
+            .map(FilterTemplateReference::try_from)
+            .transpose()?,
+    };
+
+    let timing = Instant::now();
+    let result = do_copy_points(
+        toc_provider,
+        collection_name,
+        request,
+        ReadConsistency::try_from_optional(read_consistency)?,
+        timeout.map(Duration::from_secs),
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await?;
+
+    let response = CopyPointsResponse {
+        copied: result.copied as u64,
+        next_page_offset: result.next_page_offset.map(From::from),
+        error: result.error,
+        time: timing.elapsed().as_secs_f64(),
+        usage: Usage::from_hardware_usage(request_hw_counter.to_grpc_api()).into_non_empty(),
+    };
+    Ok(Response::new(response))
+}
+
 pub async fn update_batch(
     dispatcher: &Dispatcher,
     update_batch_points: UpdateBatchPoints,
@@ -550,6 +782,31 @@ pub async fn update_batch(
                 )
                 .await
             }
+            points_update_operation::Operation::PatchPayload(
+                points_update_operation::PatchPayload {
+                    payload,
+                    points_selector,
+                    shard_key_selector,
+                },
+            ) => {
+                patch_payload(
+                    StrictModeCheckedTocProvider::new(dispatcher),
+                    SetPayloadPoints {
+                        collection_name,
+                        wait,
+                        payload,
+                        points_selector,
+                        ordering,
+                        shard_key_selector,
+                        key: None,
+                        timeout,
+                    },
+                    internal_params,
+                    auth.clone(),
+                    request_hw_counter.clone(),
+                )
+                .await
+            }
             points_update_operation::Operation::DeletePayload(
                 points_update_operation::DeletePayload {
                     keys,
@@ -917,19 +1174,43 @@ pub fn points_operation_response_internal(
     )
 }
 
+fn update_preview_response(
+    timing: Instant,
+    preview: collection::operations::types::UpdatePreview,
+    request_hw_counter: RequestHwCounter,
+) -> UpdatePreviewResponse {
+    let collection::operations::types::UpdatePreview { matched, sample } = preview;
+    UpdatePreviewResponse {
+        matched: matched as u64,
+        sample: sample.into_iter().map(From::from).collect(),
+        time: timing.elapsed().as_secs_f64(),
+        usage: Usage::from_hardware_usage(request_hw_counter.to_grpc_api()).into_non_empty(),
+    }
+}
+
+type ExtractedPointsSelector = (
+    Option<Vec<ExtendedPointId>>,
+    Option<Filter>,
+    Option<FilterTemplateReference>,
+);
+
 fn extract_points_selector(
     points_selector: Option<PointsSelector>,
-) -> Result<(Option<Vec<ExtendedPointId>>, Option<Filter>), Status> {
-    let (points, filter) = if let Some(points_selector) = points_selector {
+) -> Result<ExtractedPointsSelector, Status> {
+    let (points, filter, filter_template) = if let Some(points_selector) = points_selector {
         let points_selector = try_points_selector_from_grpc(points_selector, None)?;
         match points_selector {
-            point_ops::PointsSelector::PointIdsSelector(points) => (Some(points.points), None),
-            point_ops::PointsSelector::FilterSelector(filter) => (None, Some(filter.filter)),
+            point_ops::PointsSelector::PointIdsSelector(points) => {
+                (Some(points.points), None, None)
+            }
+            point_ops::PointsSelector::FilterSelector(filter) => {
+                (None, Some(filter.filter), filter.filter_template)
+            }
         }
     } else {
         return Err(Status::invalid_argument("points_selector is expected"));
     };
-    Ok((points, filter))
+    Ok((points, filter, filter_template))
 }
 
 fn convert_field_type(
@@ -993,6 +1274,9 @@ fn convert_field_type(
                         TryFrom::try_from(uuid_index_params).map(PayloadSchemaParams::Uuid)
                     })
                 }
+                // Parameterized Ip type
+                IndexParams::IpIndexParams(ip_index_params) => matches!(field_type, FieldType::Ip)
+                    .then(|| TryFrom::try_from(ip_index_params).map(PayloadSchemaParams::Ip)),
             }
             .ok_or_else(|| {
                 Status::invalid_argument(format!(
@@ -1012,6 +1296,7 @@ fn convert_field_type(
             FieldType::Bool => Some(PayloadSchemaType::Bool.into()),
             FieldType::Datetime => Some(PayloadSchemaType::Datetime.into()),
             FieldType::Uuid => Some(PayloadSchemaType::Uuid.into()),
+            FieldType::Ip => Some(PayloadSchemaType::Ip.into()),
         },
         (None, Some(_)) => return Err(Status::invalid_argument("field type is missing")),
         (None, None) => None,
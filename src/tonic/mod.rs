@@ -41,7 +41,7 @@ use crate::common::helpers;
 use crate::common::http_client::HttpClient;
 use crate::common::telemetry::TelemetryCollector;
 use crate::common::telemetry_ops::requests_telemetry::TonicTelemetryCollector;
-use crate::settings::Settings;
+use crate::settings::{ServiceConfig, Settings};
 use crate::tonic::api::collections_api::CollectionsService;
 use crate::tonic::api::collections_internal_api::CollectionsInternalService;
 use crate::tonic::api::points_api::PointsService;
@@ -204,6 +204,39 @@ pub fn init(
     Ok(())
 }
 
+/// Internal gRPC servers, which are used by shard transfers.
+///
+/// Served on the internal port, and on the separate shard transfer port, if configured.
+fn transfer_servers(
+    toc: &Arc<TableOfContent>,
+    service_config: ServiceConfig,
+    http_client: HttpClient,
+) -> (
+    QdrantServer<QdrantService>,
+    CollectionsInternalServer<CollectionsInternalService>,
+    PointsInternalServer<PointsInternalService>,
+    ShardSnapshotsServer<ShardSnapshotsService>,
+) {
+    (
+        QdrantServer::new(QdrantService::default())
+            .send_compressed(CompressionEncoding::Gzip)
+            .accept_compressed(CompressionEncoding::Gzip)
+            .max_decoding_message_size(usize::MAX),
+        CollectionsInternalServer::new(CollectionsInternalService::new(toc.clone()))
+            .send_compressed(CompressionEncoding::Gzip)
+            .accept_compressed(CompressionEncoding::Gzip)
+            .max_decoding_message_size(usize::MAX),
+        PointsInternalServer::new(PointsInternalService::new(toc.clone(), service_config))
+            .send_compressed(CompressionEncoding::Gzip)
+            .accept_compressed(CompressionEncoding::Gzip)
+            .max_decoding_message_size(usize::MAX),
+        ShardSnapshotsServer::new(ShardSnapshotsService::new(toc.clone(), http_client))
+            .send_compressed(CompressionEncoding::Gzip)
+            .accept_compressed(CompressionEncoding::Gzip)
+            .max_decoding_message_size(usize::MAX),
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn init_internal(
     toc: Arc<TableOfContent>,
@@ -225,15 +258,13 @@ pub fn init_internal(
 
     runtime
         .block_on(async {
-            let socket = SocketAddr::from((host.parse::<IpAddr>().unwrap(), internal_grpc_port));
+            let host = host.parse::<IpAddr>().unwrap();
+            let socket = SocketAddr::from((host, internal_grpc_port));
+            let transfer_grpc_port = settings.cluster.p2p.transfer_port;
+            let service_config = settings.service.clone();
 
-            let qdrant_service = QdrantService::default();
-            let points_internal_service =
-                PointsInternalService::new(toc.clone(), settings.service.clone());
             let qdrant_internal_service =
                 QdrantInternalService::new(telemetry_collector, settings, consensus_state.clone());
-            let collections_internal_service = CollectionsInternalService::new(toc.clone());
-            let shard_snapshots_service = ShardSnapshotsService::new(toc.clone(), http_client);
             let raft_service =
                 RaftService::new(to_consensus, consensus_state, tls_config.is_some());
 
@@ -265,38 +296,45 @@ pub fn init_internal(
                 ))
                 .into_inner();
 
-            server
+            // Shard transfer traffic, served on its own port if configured, so that it doesn't
+            // compete with internal searches for the connections
+            let transfer_server = transfer_grpc_port.map(|transfer_grpc_port| {
+                let socket = SocketAddr::from((host, transfer_grpc_port));
+
+                log::debug!(
+                    "Qdrant internal gRPC for shard transfers listening on {transfer_grpc_port}"
+                );
+
+                let (qdrant_server, collections_server, points_server, shard_snapshots_server) =
+                    transfer_servers(&toc, service_config.clone(), http_client.clone());
+
+                server
+                    .clone()
+                    .layer(middleware_layer.clone())
+                    .add_service(qdrant_server)
+                    .add_service(collections_server)
+                    .add_service(points_server)
+                    .add_service(shard_snapshots_server)
+                    .serve_with_shutdown(socket, async {
+                        wait_stop_signal("internal gRPC for shard transfers").await;
+                    })
+            });
+
+            let (qdrant_server, collections_server, points_server, shard_snapshots_server) =
+                transfer_servers(&toc, service_config, http_client);
+
+            let internal_server = server
                 .layer(middleware_layer)
-                .add_service(
-                    QdrantServer::new(qdrant_service)
-                        .send_compressed(CompressionEncoding::Gzip)
-                        .accept_compressed(CompressionEncoding::Gzip)
-                        .max_decoding_message_size(usize::MAX),
-                )
+                .add_service(qdrant_server)
                 .add_service(
                     QdrantInternalServer::new(qdrant_internal_service)
                         .send_compressed(CompressionEncoding::Gzip)
                         .accept_compressed(CompressionEncoding::Gzip)
                         .max_decoding_message_size(usize::MAX),
                 )
-                .add_service(
-                    CollectionsInternalServer::new(collections_internal_service)
-                        .send_compressed(CompressionEncoding::Gzip)
-                        .accept_compressed(CompressionEncoding::Gzip)
-                        .max_decoding_message_size(usize::MAX),
-                )
-                .add_service(
-                    PointsInternalServer::new(points_internal_service)
-                        .send_compressed(CompressionEncoding::Gzip)
-                        .accept_compressed(CompressionEncoding::Gzip)
-                        .max_decoding_message_size(usize::MAX),
-                )
-                .add_service(
-                    ShardSnapshotsServer::new(shard_snapshots_service)
-                        .send_compressed(CompressionEncoding::Gzip)
-                        .accept_compressed(CompressionEncoding::Gzip)
-                        .max_decoding_message_size(usize::MAX),
-                )
+                .add_service(collections_server)
+                .add_service(points_server)
+                .add_service(shard_snapshots_server)
                 .add_service(
                     RaftServer::new(raft_service)
                         .send_compressed(CompressionEncoding::Gzip)
@@ -305,8 +343,14 @@ pub fn init_internal(
                 )
                 .serve_with_shutdown(socket, async {
                     wait_stop_signal("internal gRPC").await;
-                })
-                .await
+                });
+
+            match transfer_server {
+                Some(transfer_server) => {
+                    tokio::try_join!(internal_server, transfer_server).map(|((), ())| ())
+                }
+                None => internal_server.await,
+            }
         })
         .unwrap();
     Ok(())
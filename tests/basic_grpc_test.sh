@@ -159,6 +159,13 @@ fi
   "limit": 3
 }' $QDRANT_HOST qdrant.Points/Search
 
+"${docker_grpcurl[@]}" -d '{
+  "collection_name": "test_collection",
+  "vector": [0.2,0.1,0.9,0.7],
+  "limit": 3,
+  "explain": true
+}' $QDRANT_HOST qdrant.Points/Search
+
 "${docker_grpcurl[@]}" -d '{
   "collection_name": "test_collection",
   "filter": {
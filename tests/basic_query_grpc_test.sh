@@ -68,6 +68,19 @@ fi
   "limit": 3
 }' $QDRANT_HOST qdrant.Points/Query
 
+"${docker_grpcurl[@]}" -d '{
+  "collection_name": "test_collection",
+  "query": {
+    "nearest": {
+      "dense": {
+        "data": [0.2,0.1,0.9,0.7]
+      }
+    }
+  },
+  "limit": 3,
+  "explain": true
+}' $QDRANT_HOST qdrant.Points/Query
+
 "${docker_grpcurl[@]}" -d '{
   "collection_name": "test_collection",
   "query_points": [
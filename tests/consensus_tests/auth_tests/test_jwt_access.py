@@ -1,7 +1,7 @@
 import json
 import tempfile
 from inspect import isfunction
-from typing import Callable, List, Optional, Tuple, Union
+from typing import Callable, Iterator, List, Optional, Tuple, Union
 
 import grpc
 import grpc_requests
@@ -116,6 +116,12 @@ ACTION_ACCESS = {
         "GET /collections/{collection_name}",
         "qdrant.Collections/Get",
     ),
+    "get_collection_config_changes": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/config/diff",
+    ),
     "create_collection": EndpointAccess(
         False, False, True, "PUT /collections/{collection_name}", "qdrant.Collections/Create"
     ),
@@ -125,6 +131,9 @@ ACTION_ACCESS = {
     "update_collection_params": EndpointAccess(
         False, False, True, "PATCH /collections/{collection_name}", "qdrant.Collections/Update"
     ),
+    "clone_collection": EndpointAccess(
+        False, False, True, "POST /collections/{collection_name}/clone", "qdrant.Collections/CloneCollection"
+    ),
     "get_collection_cluster_info": EndpointAccess(
         True,
         True,
@@ -145,6 +154,34 @@ ACTION_ACCESS = {
         True,
         "GET /collections/{collection_name}/optimizations",
     ),
+    "cancel_optimizations": EndpointAccess(
+        False,
+        False,
+        True,
+        "POST /collections/{collection_name}/optimizations/cancel",
+        "qdrant.Collections/CancelOptimizations",
+    ),
+    "resume_optimizations": EndpointAccess(
+        False,
+        False,
+        True,
+        "POST /collections/{collection_name}/optimizations/resume",
+        "qdrant.Collections/ResumeOptimizations",
+    ),
+    "pin_vector": EndpointAccess(
+        False,
+        False,
+        True,
+        "POST /collections/{collection_name}/vectors/pin",
+        "qdrant.Collections/PinVector",
+    ),
+    "unpin_vector": EndpointAccess(
+        False,
+        False,
+        True,
+        "POST /collections/{collection_name}/vectors/unpin",
+        "qdrant.Collections/UnpinVector",
+    ),
     "replicate_shard_operation": EndpointAccess(
         False,
         False,
@@ -279,6 +316,27 @@ ACTION_ACCESS = {
         "qdrant.Points/DeleteFieldIndex",
         coll_prw=False,
     ),
+    ### Filter Templates ###
+    "list_filter_templates": EndpointAccess(
+        True,
+        True,
+        True,
+        "GET /collections/{collection_name}/filters",
+    ),
+    "set_filter_template": EndpointAccess(
+        False,
+        True,
+        True,
+        "PUT /collections/{collection_name}/filters/{filter_name}",
+        coll_prw=False,
+    ),
+    "delete_filter_template": EndpointAccess(
+        False,
+        True,
+        True,
+        "DELETE /collections/{collection_name}/filters/{filter_name}",
+        coll_prw=False,
+    ),
     ### Collection Snapshots ###
     "list_collection_snapshots": EndpointAccess(
         True,
@@ -385,6 +443,7 @@ ACTION_ACCESS = {
     ### Cluster ###
     "get_cluster": EndpointAccess(True, False, True, "GET /cluster", coll_r=False),
     "cluster_telemetry": EndpointAccess(True, True, True, "GET /cluster/telemetry"),
+    "cluster_rebalance": EndpointAccess(False, False, True, "GET /cluster/rebalance"),
     "recover_raft_state": EndpointAccess(False, False, True, "POST /cluster/recover"),
     "delete_peer": EndpointAccess(False, False, True, "DELETE /cluster/peer/{peer_id}"),
     ### Points ###
@@ -426,6 +485,21 @@ ACTION_ACCESS = {
         "qdrant.Points/Delete",
         coll_prw=True,
     ),
+    "preview_delete_points": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/delete/preview",
+        "qdrant.Points/PreviewDelete",
+    ),
+    "restore_points": EndpointAccess(
+        False,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/restore",
+        "qdrant.Points/RestorePoints",
+        coll_prw=True,
+    ),
     "update_vectors": EndpointAccess(
         False,
         True,
@@ -450,6 +524,13 @@ ACTION_ACCESS = {
         "qdrant.Points/SetPayload",
         coll_prw=True,
     ),
+    "preview_set_payload": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/payload/preview",
+        "qdrant.Points/PreviewSetPayload",
+    ),
     "overwrite_payload": EndpointAccess(
         False,
         True,
@@ -458,6 +539,14 @@ ACTION_ACCESS = {
         "qdrant.Points/OverwritePayload",
         coll_prw=True,
     ),
+    "patch_payload": EndpointAccess(
+        False,
+        True,
+        True,
+        "PATCH /collections/{collection_name}/points/payload",
+        "qdrant.Points/PatchPayload",
+        coll_prw=True,
+    ),
     "delete_payload": EndpointAccess(
         False,
         True,
@@ -482,6 +571,14 @@ ACTION_ACCESS = {
         "qdrant.Points/Scroll",
         coll_prw=True,
     ),
+    "scroll_points_stream": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/scroll",
+        "qdrant.Points/ScrollStream",
+        coll_prw=True,
+    ),
     "search_points": EndpointAccess(
         True,
         True,
@@ -575,9 +672,37 @@ ACTION_ACCESS = {
         True,
         "POST /collections/{collection_name}/points/search/matrix/pairs", "qdrant.Points/SearchMatrixPairs"
     ),
+    "sample_projection": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/projection",
+        "qdrant.Points/SampleProjection",
+    ),
+    "join_points": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/join",
+        "qdrant.Points/Join",
+    ),
+    "copy_points": EndpointAccess(
+        False,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/copy",
+        "qdrant.Points/CopyPoints",
+        coll_prw=True,
+    ),
     "facet": EndpointAccess(
         True, True, True, "POST /collections/{collection_name}/facet", "qdrant.Points/Facet"
     ),
+    "facet_multi": EndpointAccess(
+        True, True, True, "POST /collections/{collection_name}/facet/multi", "qdrant.Points/FacetMulti"
+    ),
+    "aggregate": EndpointAccess(
+        True, True, True, "POST /collections/{collection_name}/aggregate", "qdrant.Points/Aggregate"
+    ),
     ### Service ###
     "root": EndpointAccess(True, True, True, "GET /", "qdrant.Qdrant/HealthCheck", everything=True),
     "readyz": EndpointAccess(True, True, True, "GET /readyz", "grpc.health.v1.Health/Check", everything=True),
@@ -589,6 +714,13 @@ ACTION_ACCESS = {
     "clear_issues": EndpointAccess(False, False, True, "DELETE /issues"),
     "get_logger_config": EndpointAccess(True, False, True, "GET /logger", coll_r=False),
     "update_logger_config": EndpointAccess(False, False, True, "POST /logger"),
+    "get_snapshot_bandwidth_config": EndpointAccess(True, False, True, "GET /snapshot_bandwidth", coll_r=False),
+    "update_snapshot_bandwidth_config": EndpointAccess(False, False, True, "POST /snapshot_bandwidth"),
+    "get_cpu_pools": EndpointAccess(True, False, True, "GET /cpu_pools", coll_r=False),
+    "update_cpu_pools": EndpointAccess(False, False, True, "POST /cpu_pools"),
+    ### Datasets ###
+    "list_datasets": EndpointAccess(True, False, True, "GET /datasets", coll_r=False),
+    "load_dataset": EndpointAccess(False, False, True, "POST /datasets/load"),
 }
 
 
@@ -711,6 +843,9 @@ def check_grpc_access(
 
     try:
         _res = client.request(service=service, method=method, request=request)
+        # streaming responses are lazy, errors are only raised while reading them
+        if isinstance(_res, Iterator):
+            _res = list(_res)
     except grpc.RpcError as e:
         if should_succeed:
             if e.code() not in [grpc.StatusCode.INVALID_ARGUMENT, grpc.StatusCode.NOT_FOUND]:
@@ -871,6 +1006,14 @@ def test_get_collection():
     )
 
 
+def test_get_collection_config_changes():
+    check_access(
+        "get_collection_config_changes",
+        rest_request={"hnsw_config": {"m": 32}},
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
 def test_create_collection():
     coll_names = [random_str() for _ in range(MAX_CALLS_IN_CHECK_ACCESS)]
 
@@ -892,6 +1035,15 @@ def test_create_collection():
             requests.delete(f"{REST_URI}/collections/{collection_name}", headers=API_KEY_HEADERS)
 
 
+def test_clone_collection():
+    check_access(
+        "clone_collection",
+        rest_request=lambda: {"target_collection": random_str()},
+        grpc_request=lambda: {"collection_name": COLL_NAME, "target_collection": random_str()},
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
 def test_delete_collection():
     fake_name = random_str()
     check_access(
@@ -999,6 +1151,38 @@ def test_get_optimizations():
     )
 
 
+def test_cancel_optimizations():
+    check_access(
+        "cancel_optimizations",
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={"collection_name": COLL_NAME},
+    )
+
+
+def test_resume_optimizations():
+    check_access(
+        "resume_optimizations",
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={"collection_name": COLL_NAME},
+    )
+
+
+def test_pin_vector():
+    check_access(
+        "pin_vector",
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={"collection_name": COLL_NAME},
+    )
+
+
+def test_unpin_vector():
+    check_access(
+        "unpin_vector",
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={"collection_name": COLL_NAME},
+    )
+
+
 def test_replicate_shard_operation():
     peer_ids = [PEER_ID + 5, PEER_ID + 3]
     replicate_shard = {
@@ -1247,6 +1431,28 @@ def test_delete_index():
     )
 
 
+def test_list_filter_templates():
+    check_access(
+        "list_filter_templates",
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
+def test_set_filter_template():
+    check_access(
+        "set_filter_template",
+        rest_request={"filter": {"must": [{"key": FIELD_NAME, "match": {"value": "a"}}]}},
+        path_params={"collection_name": COLL_NAME, "filter_name": "fake_filter_name"},
+    )
+
+
+def test_delete_filter_template():
+    check_access(
+        "delete_filter_template",
+        path_params={"collection_name": COLL_NAME, "filter_name": "fake_filter_name"},
+    )
+
+
 def test_list_collection_snapshots():
     check_access(
         "list_collection_snapshots",
@@ -1446,6 +1652,10 @@ def test_cluster_telemetry():
     check_access("cluster_telemetry")
 
 
+def test_cluster_rebalance():
+    check_access("cluster_rebalance")
+
+
 def test_recover_raft_state():
     check_access("recover_raft_state")
 
@@ -1495,6 +1705,13 @@ def test_update_points_batch():
                 "payload": {"key": "value"},
             }
         },
+        {
+            "patch_payload": {
+                "shard_key": SHARD_KEY,
+                "points": [1],
+                "payload": {"key": None},
+            }
+        },
         {"delete_payload": {"shard_key": SHARD_KEY, "points": [1], "keys": ["key"]}},
         {"clear_payload": {"shard_key": SHARD_KEY, "points": [1]}},
         {
@@ -1534,6 +1751,13 @@ def test_update_points_batch():
                 "payload": {"my_key": {"string_value": "value"}},
             }
         },
+        {
+            "patch_payload": {
+                **SHARD_KEY_SELECTOR,
+                "points_selector": {"points": {"ids": [{"num": 11}]}},
+                "payload": {"my_key": {"null_value": 0}},
+            }
+        },
         {
             "delete_payload": {
                 **SHARD_KEY_SELECTOR,
@@ -1582,6 +1806,28 @@ def test_delete_points():
     )
 
 
+def test_preview_delete_points():
+    check_access(
+        "preview_delete_points",
+        rest_request={"points": [3], "shard_key": SHARD_KEY},
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={
+            "collection_name": COLL_NAME,
+            "points": {"points": {"ids": [{"num": 3}]}},
+            **SHARD_KEY_SELECTOR,
+        },
+    )
+
+
+def test_restore_points():
+    check_access(
+        "restore_points",
+        rest_request={"points": [3]},
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={"collection_name": COLL_NAME, "points": [{"num": 3}]},
+    )
+
+
 def test_update_vectors():
     check_access(
         "update_vectors",
@@ -1623,6 +1869,20 @@ def test_set_payload():
     )
 
 
+def test_preview_set_payload():
+    check_access(
+        "preview_set_payload",
+        rest_request={"points": [1], "payload": {"my_key": "value"}, "shard_key": SHARD_KEY},
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={
+            "collection_name": COLL_NAME,
+            "points_selector": {"points": {"ids": [{"num": 1}]}},
+            "payload": {"my_key": {"string_value": "value"}},
+            **SHARD_KEY_SELECTOR,
+        },
+    )
+
+
 def test_overwrite_payload():
     check_access(
         "overwrite_payload",
@@ -1637,6 +1897,20 @@ def test_overwrite_payload():
     )
 
 
+def test_patch_payload():
+    check_access(
+        "patch_payload",
+        rest_request={"points": [1], "payload": {"my_key": "value"}, "shard_key": SHARD_KEY},
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={
+            "collection_name": COLL_NAME,
+            "points_selector": {"points": {"ids": [{"num": 1}]}},
+            "payload": {"my_key": {"string_value": "value"}},
+            **SHARD_KEY_SELECTOR,
+        },
+    )
+
+
 def test_delete_payload():
     check_access(
         "delete_payload",
@@ -1673,6 +1947,15 @@ def test_scroll_points():
     )
 
 
+def test_scroll_points_stream():
+    check_access(
+        "scroll_points_stream",
+        rest_request={"limit": 10},
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={"collection_name": COLL_NAME, "limit": 10},
+    )
+
+
 def test_search_points():
     check_access(
         "search_points",
@@ -1871,6 +2154,38 @@ def test_search_points_matrix_pairs():
     )
 
 
+def test_sample_projection():
+    check_access(
+        "sample_projection",
+        rest_request={"sample": 10},
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={"collection_name": COLL_NAME, "sample": 10},
+    )
+
+
+def test_join_points():
+    check_access(
+        "join_points",
+        rest_request={"target_collection": COLL_NAME, "limit": 1, "points_limit": 1},
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={
+            "collection_name": COLL_NAME,
+            "target_collection": COLL_NAME,
+            "limit": 1,
+            "points_limit": 1,
+        },
+    )
+
+
+def test_copy_points():
+    check_access(
+        "copy_points",
+        rest_request={"target_collection": COLL_NAME},
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={"collection_name": COLL_NAME, "target_collection": COLL_NAME},
+    )
+
+
 def test_facet():
     check_access(
         "facet",
@@ -1885,6 +2200,34 @@ def test_facet():
     )
 
 
+def test_facet_multi():
+    check_access(
+        "facet_multi",
+        path_params={"collection_name": COLL_NAME},
+        rest_request={
+            "keys": [FACET_KEY],
+        },
+        grpc_request={
+            "collection_name": COLL_NAME,
+            "keys": [FACET_KEY],
+        },
+    )
+
+
+def test_aggregate():
+    check_access(
+        "aggregate",
+        path_params={"collection_name": COLL_NAME},
+        rest_request={
+            "key": FACET_KEY,
+        },
+        grpc_request={
+            "collection_name": COLL_NAME,
+            "key": FACET_KEY,
+        },
+    )
+
+
 def test_root():
     check_access("root")
 
@@ -1922,3 +2265,22 @@ def test_get_logger_config():
 
 def test_update_logger_config():
     check_access("update_logger_config", {})
+
+def test_get_snapshot_bandwidth_config():
+    check_access("get_snapshot_bandwidth_config")
+
+def test_update_snapshot_bandwidth_config():
+    check_access("update_snapshot_bandwidth_config", {})
+
+def test_get_cpu_pools():
+    check_access("get_cpu_pools")
+
+def test_update_cpu_pools():
+    check_access("update_cpu_pools", {})
+
+def test_list_datasets():
+    check_access("list_datasets")
+
+def test_load_dataset():
+    # The collection already exists, so an authorized request fails only after the access check
+    check_access("load_dataset", {"dataset": "colors", "collection_name": COLL_NAME})
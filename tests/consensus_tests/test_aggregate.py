@@ -0,0 +1,35 @@
+import requests
+
+from .assertions import assert_http_ok
+from .fixtures import create_collection, upsert_random_points
+from .utils import every_test, start_cluster, wait_for_uniform_collection_existence
+
+COLL_NAME = "test_collection"
+
+
+def test_aggregate_over_remote_shards(tmp_path, every_test):
+    peer_api_uris, peer_dirs, bootstrap_uri = start_cluster(tmp_path, num_peers=2, port_seed=10000)
+
+    uri = peer_api_uris[0]
+
+    create_collection(uri, collection=COLL_NAME, shard_number=2, replication_factor=1)
+    wait_for_uniform_collection_existence(COLL_NAME, peer_api_uris)
+
+    upsert_random_points(uri, 50, collection_name=COLL_NAME, extra_payload={"price": 1})
+    upsert_random_points(uri, 50, collection_name=COLL_NAME, offset=50, extra_payload={"price": 3})
+
+    # Each peer aggregates its local shard, and the shard of the other peer over internal gRPC
+    for peer_uri in peer_api_uris:
+        res = requests.post(
+            f"{peer_uri}/collections/{COLL_NAME}/aggregate",
+            json={"key": "price"},
+        )
+        assert_http_ok(res)
+
+        result = res.json()["result"]
+        assert result["count"] == 100
+        assert result["sum"] == 200
+        assert result["min"] == 1
+        assert result["max"] == 3
+        assert result["avg"] == 2
+        assert result["distinct_count"] == 2
@@ -169,6 +169,155 @@ def test_shard_consistency(tmp_path: pathlib.Path):
         assert point["shard_key"] in ["dogs", "birds"]
 
 
+# Test that a single upsert is split by shard keys of its points
+def test_upsert_points_with_shard_keys(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, peer_dirs, bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    create_collection_with_custom_sharding(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=N_REPLICAS)
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLLECTION_NAME, peer_api_uris=peer_api_uris)
+
+    # Wait until all peers submit their metadata to consensus
+    time.sleep(2)
+
+    for shard_key in ["cats", "dogs"]:
+        create_shard(
+            peer_api_uris[0],
+            COLLECTION_NAME,
+            shard_key=shard_key,
+            shard_number=1,
+            replication_factor=1
+        )
+
+    # Points without own shard key use shard key of the request
+    r = requests.put(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points?wait=true", json={
+            "shard_key": "cats",
+            "points": [
+                {"id": 1, "vector": [0.29, 0.81, 0.75, 0.11], "payload": {"name": "Barsik"}},
+                {"id": 2, "vector": [0.19, 0.11, 0.15, 0.21], "payload": {"name": "Sharik"}, "shard_key": "dogs"},
+                {"id": 3, "vector": [0.99, 0.81, 0.75, 0.31], "payload": {"name": "Vaska"}, "shard_key": "cats"},
+                {"id": 4, "vector": [0.29, 0.01, 0.05, 0.91], "payload": {"name": "Tuzik"}, "shard_key": "dogs"},
+            ]
+        })
+    assert_http_ok(r)
+
+    for shard_key, names in [("cats", {"Barsik", "Vaska"}), ("dogs", {"Sharik", "Tuzik"})]:
+        r = requests.post(
+            f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points/scroll",
+            json={
+                "shard_key": shard_key,
+                "limit": 10,
+                "with_payload": True,
+            }
+        )
+        assert_http_ok(r)
+        points = r.json()["result"]["points"]
+        assert {point["payload"]["name"] for point in points} == names
+        assert all(point["shard_key"] == shard_key for point in points)
+
+    # Points without any shard key are rejected
+    r = requests.put(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points?wait=true", json={
+            "points": [
+                {"id": 5, "vector": [0.29, 0.81, 0.75, 0.11], "shard_key": "cats"},
+                {"id": 6, "vector": [0.19, 0.11, 0.15, 0.21]},
+            ]
+        })
+    assert r.status_code == 400
+
+    # Batch format has shard keys of the points in a separate list
+    r = requests.put(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points?wait=true", json={
+            "shard_key": "cats",
+            "batch": {
+                "ids": [7, 8],
+                "vectors": [[0.29, 0.81, 0.75, 0.11], [0.19, 0.11, 0.15, 0.21]],
+                "payloads": [{"name": "Murzik"}, {"name": "Bobik"}],
+                "shard_keys": [None, "dogs"],
+            }
+        })
+    assert_http_ok(r)
+
+    for shard_key, point_id in [("cats", 7), ("dogs", 8)]:
+        r = requests.post(
+            f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points",
+            json={"shard_key": shard_key, "ids": [point_id]}
+        )
+        assert_http_ok(r)
+        assert [point["id"] for point in r.json()["result"]] == [point_id]
+
+
+def test_shard_key_config(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, peer_dirs, bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    create_collection_with_custom_sharding(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=N_REPLICAS)
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLLECTION_NAME, peer_api_uris=peer_api_uris)
+
+    # Wait until all peers submit their metadata to consensus
+    time.sleep(2)
+
+    r = requests.put(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/shards?timeout=10", json={
+            "shard_key": "tenant",
+            "shards_number": 1,
+            "replication_factor": 1,
+            "on_disk": True,
+            "hnsw_config": {"m": 32},
+            "max_points_count": 2,
+        })
+    assert_http_ok(r)
+
+    for peer_api_uri in peer_api_uris:
+        wait_for(
+            lambda: get_collection_info(peer_api_uri, COLLECTION_NAME)["config"]["params"].get("shard_key_configs") == [
+                {"shard_key": "tenant", "on_disk": True, "hnsw_config": {"m": 32}, "max_points_count": 2},
+            ],
+        )
+
+    r = requests.put(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points?wait=true", json={
+            "shard_key": "tenant",
+            "points": [
+                {"id": 1, "vector": [0.29, 0.81, 0.75, 0.11]},
+                {"id": 2, "vector": [0.19, 0.11, 0.15, 0.21]},
+            ]
+        })
+    assert_http_ok(r)
+
+    # Shard key is full, further upserts are rejected
+    r = requests.put(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points?wait=true", json={
+            "shard_key": "tenant",
+            "points": [
+                {"id": 3, "vector": [0.99, 0.81, 0.75, 0.31]},
+            ]
+        })
+    assert r.status_code == 400
+    assert "Max points count limit of 2 reached" in r.json()["status"]["error"]
+
+    # Existing points can still be overwritten
+    r = requests.put(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points?wait=true", json={
+            "shard_key": "tenant",
+            "points": [
+                {"id": 2, "vector": [0.99, 0.81, 0.75, 0.31]},
+            ]
+        })
+    assert_http_ok(r)
+
+    # Configuration is removed together with the shard key
+    delete_shard(peer_api_uris[0], COLLECTION_NAME, shard_key="tenant")
+
+    for peer_api_uri in peer_api_uris:
+        wait_for(
+            lambda: "shard_key_configs" not in get_collection_info(peer_api_uri, COLLECTION_NAME)["config"]["params"],
+        )
+
+
 def test_shard_key_storage(tmp_path: pathlib.Path):
     """
     Creates cluster with custom sharding. Asserts custom sharding keys are
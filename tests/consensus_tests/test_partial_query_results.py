@@ -0,0 +1,51 @@
+import pathlib
+
+from .fixtures import create_collection, random_dense_vector, upsert_random_points
+from .utils import *
+
+N_PEERS = 2
+N_SHARDS = 2
+N_REPLICA = 1
+
+
+def query_points(peer_api_uri, allow_partial):
+    return requests.post(
+        f"{peer_api_uri}/collections/test_collection/points/query",
+        params={"allow_partial": str(allow_partial).lower()},
+        json={
+            "query": random_dense_vector(),
+            "using": "",
+            "limit": 10,
+        },
+    )
+
+
+# Test that a query with allowed partial results skips shards of a dead peer
+def test_partial_query_results(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, _peer_dirs, _bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    create_collection(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=N_REPLICA)
+    wait_collection_exists_and_active_on_all_peers(
+        collection_name="test_collection",
+        peer_api_uris=peer_api_uris,
+    )
+    upsert_random_points(peer_api_uris[0], 100)
+
+    response = query_points(peer_api_uris[0], allow_partial=True)
+    assert response.ok, response.text
+    assert "skipped_shards" not in response.json()["result"]
+
+    # Kill the peer holding the second shard
+    processes.pop().kill()
+    peer_api_uris.pop()
+
+    response = query_points(peer_api_uris[0], allow_partial=False)
+    assert not response.ok, response.text
+
+    response = query_points(peer_api_uris[0], allow_partial=True)
+    assert response.ok, response.text
+    result = response.json()["result"]
+    assert len(result["points"]) > 0
+    assert len(result["skipped_shards"]) == 1
@@ -3,7 +3,7 @@ import multiprocessing
 import pathlib
 import random
 
-from .fixtures import create_collection, upsert_random_points
+from .fixtures import create_collection, random_dense_vector, upsert_random_points
 from .utils import *
 
 logging.basicConfig(level=logging.DEBUG)
@@ -74,3 +74,73 @@ def test_shard_consistency(tmp_path: pathlib.Path):
     for res in results:
         for idx, row in enumerate(res['points']):
             assert row == results[0]['points'][idx]
+
+
+def test_linearizable_read(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, peer_dirs, bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    create_collection(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=N_REPLICAS)
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLLECTION_NAME, peer_api_uris=peer_api_uris)
+
+    upload_process = run_update_points_in_background(peer_api_uris[0], COLLECTION_NAME)
+
+    try:
+        for point_id in range(1000, 1020):
+            res = requests.put(
+                f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points?wait=true",
+                json={
+                    "points": [
+                        {"id": point_id, "vector": random_dense_vector(), "payload": {"linearizable": True}},
+                    ]
+                },
+            )
+            assert_http_ok(res)
+
+            # Read own write from any replica
+            peer_url = peer_api_uris[point_id % len(peer_api_uris)]
+            res = requests.post(
+                f"{peer_url}/collections/{COLLECTION_NAME}/points?consistency=linearizable",
+                json={"ids": [point_id], "with_payload": True},
+                timeout=10,
+            )
+            assert_http_ok(res)
+            assert [point["id"] for point in res.json()["result"]] == [point_id]
+    finally:
+        upload_process.kill()
+
+
+def test_max_staleness_read(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, peer_dirs, bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    create_collection(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=N_REPLICAS)
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLLECTION_NAME, peer_api_uris=peer_api_uris)
+
+    res = requests.put(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points?wait=true&ordering=medium",
+        json={
+            "points": [
+                {"id": point_id, "vector": random_dense_vector(), "payload": {"bounded": True}}
+                for point_id in range(1000, 1020)
+            ]
+        },
+    )
+    assert_http_ok(res)
+
+    for peer_url in peer_api_uris:
+        res = requests.post(
+            f"{peer_url}/collections/{COLLECTION_NAME}/points?max_staleness_ms=1000",
+            json={"ids": list(range(1000, 1020)), "with_payload": True},
+            timeout=10,
+        )
+        assert_http_ok(res)
+        assert sorted(point["id"] for point in res.json()["result"]) == list(range(1000, 1020))
+
+    res = requests.post(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points?consistency=all&max_staleness_ms=1000",
+        json={"ids": [1000]},
+    )
+    assert res.status_code == 400, res.text
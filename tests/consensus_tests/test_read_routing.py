@@ -0,0 +1,64 @@
+import pathlib
+
+from .fixtures import create_collection, random_dense_vector, upsert_random_points
+from .utils import *
+
+N_PEERS = 3
+N_SHARDS = 1
+N_REPLICAS = 2
+COLLECTION_NAME = "test_collection"
+
+
+def search(peer_api_uri, vector):
+    res = requests.post(
+        f"{peer_api_uri}/collections/{COLLECTION_NAME}/points/query",
+        json={
+            "query": vector,
+            "using": "",
+            "limit": 10,
+        },
+        timeout=10,
+    )
+    assert_http_ok(res)
+    return [point["id"] for point in res.json()["result"]["points"]]
+
+
+# Test that reads with latency based routing are served by remote replicas
+def test_latency_read_routing(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, _peer_dirs, _bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    create_collection(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=N_REPLICAS)
+    wait_collection_exists_and_active_on_all_peers(
+        collection_name=COLLECTION_NAME,
+        peer_api_uris=peer_api_uris,
+    )
+    upsert_random_points(peer_api_uris[0], 100)
+
+    res = requests.patch(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}",
+        json={"params": {"read_routing": "latency", "read_fan_out_delay_ms": 50}},
+    )
+    assert_http_ok(res)
+
+    for peer_api_uri in peer_api_uris:
+        wait_for(
+            lambda: get_collection_info(peer_api_uri, COLLECTION_NAME)["config"]["params"].get("read_routing") == "latency",
+        )
+
+    # Peer without a local replica reads from remote replicas only
+    remote_peer_api_uri = next(
+        peer_api_uri
+        for peer_api_uri in peer_api_uris
+        if len(get_collection_cluster_info(peer_api_uri, COLLECTION_NAME)["local_shards"]) == 0
+    )
+    replica_peer_api_uri = next(
+        peer_api_uri
+        for peer_api_uri in peer_api_uris
+        if len(get_collection_cluster_info(peer_api_uri, COLLECTION_NAME)["local_shards"]) == 1
+    )
+
+    for _ in range(20):
+        vector = random_dense_vector()
+        assert search(remote_peer_api_uri, vector) == search(replica_peer_api_uri, vector)
@@ -0,0 +1,58 @@
+import pathlib
+
+from .fixtures import create_collection, upsert_random_points
+from .utils import *
+
+N_PEERS = 3
+N_SHARDS = 3
+N_REPLICA = 1
+COLLECTION_NAME = "test_collection"
+
+
+def get_rebalance_plan(peer_api_uri):
+    r = requests.get(f"{peer_api_uri}/cluster/rebalance")
+    assert_http_ok(r)
+    return r.json()["result"]
+
+
+# Test that shard moves are proposed from the most to the least loaded peer
+def test_rebalance_plan(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, _peer_dirs, _bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    create_collection(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=N_REPLICA)
+    wait_collection_exists_and_active_on_all_peers(
+        collection_name=COLLECTION_NAME,
+        peer_api_uris=peer_api_uris,
+    )
+    upsert_random_points(peer_api_uris[0], 300)
+
+    # One shard per peer is balanced
+    plan = get_rebalance_plan(peer_api_uris[0])
+    assert len(plan["peers"]) == N_PEERS
+    assert plan["missing_peers"] == []
+    assert plan["moves"] == []
+
+    # Move the shard of the last peer to the first one
+    source_info = get_collection_cluster_info(peer_api_uris[-1], COLLECTION_NAME)
+    target_info = get_collection_cluster_info(peer_api_uris[0], COLLECTION_NAME)
+    shard_id = source_info["local_shards"][0]["shard_id"]
+    source_peer_id = source_info["peer_id"]
+    target_peer_id = target_info["peer_id"]
+
+    move_shard(peer_api_uris[0], COLLECTION_NAME, shard_id, source_peer_id, target_peer_id)
+    wait_for_collection_shard_transfers_count(peer_api_uris[0], COLLECTION_NAME, 0)
+
+    plan = get_rebalance_plan(peer_api_uris[0])
+    assert plan["ongoing_operations"] == 0
+
+    loads = {peer["peer_id"]: peer for peer in plan["peers"]}
+    assert loads[source_peer_id]["num_points"] == 0
+    assert loads[target_peer_id]["load"] > loads[source_peer_id]["load"]
+
+    assert len(plan["moves"]) == 1
+    shard_move = plan["moves"][0]
+    assert shard_move["collection_name"] == COLLECTION_NAME
+    assert shard_move["from_peer_id"] == target_peer_id
+    assert shard_move["to_peer_id"] == source_peer_id
@@ -315,6 +315,62 @@ def test_resharding_down_abort_cleanup(tmp_path: pathlib.Path, peers: int):
         assert resharding_points_count == 0
 
 
+@pytest.mark.parametrize("shard_numbers", [(2, 4), (4, 2)])
+def test_reshard_to_shard_number(tmp_path: pathlib.Path, shard_numbers: tuple[int, int]):
+    """
+    Tests that collection is resharded to the target number of shards in one operation
+    """
+
+    shard_number, target_shard_number = shard_numbers
+
+    # Bootstrap cluster
+    peer_uris, _ = bootstrap_cluster(
+        tmp_path,
+        shard_number=shard_number,
+        replication_factor=1,
+        upsert_points=1000,
+    )
+
+    # Reshard collection
+    resp = requests.post(f"{peer_uris[0]}/collections/{COLLECTION_NAME}/cluster", json={
+        "reshard": {
+            "shard_number": target_shard_number,
+        }
+    })
+    assert_http_ok(resp)
+
+    # Target is reported while resharding is in progress
+    info = get_collection_cluster_info(peer_uris[0], COLLECTION_NAME)
+    for resharding in info.get("resharding_operations") or []:
+        assert resharding["target_shard_number"] == target_shard_number
+
+    # Wait for all resharding steps to finish
+    def check_shard_number():
+        info = get_collection_cluster_info(peer_uris[0], COLLECTION_NAME)
+        shard_ids = {replica["shard_id"] for replica in all_replicas(info)}
+        return len(shard_ids) == target_shard_number and len(info.get("resharding_operations") or []) == 0
+
+    wait_for(check_shard_number, wait_for_timeout=120)
+
+    info = get_collection_cluster_info(peer_uris[0], COLLECTION_NAME)
+    assert "resharding_error" not in info
+
+    # Points migrated out of existing shards are removed once the target is reached
+    def check_local_points_count():
+        local_points_count = 0
+        for peer_uri in peer_uris:
+            info = get_collection_cluster_info(peer_uri, COLLECTION_NAME)
+            local_points_count += sum(shard["points_count"] for shard in info["local_shards"])
+        return local_points_count == 1000
+
+    wait_for(check_local_points_count, wait_for_timeout=60)
+
+    # Assert that no points were lost or duplicated
+    resp = requests.post(f"{peer_uris[0]}/collections/{COLLECTION_NAME}/points/count", json={"exact": True})
+    assert_http_ok(resp)
+    assert resp.json()["result"]["count"] == 1000
+
+
 def bootstrap_resharding(
     tmp_path: pathlib.Path,
     collection: str = COLLECTION_NAME,
@@ -0,0 +1,110 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(collection_name):
+    drop_collection(collection_name=collection_name)
+    request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+            },
+        }
+    ).raise_for_status()
+
+    payloads = [
+        {"city": "Berlin", "price": 10},
+        {"city": "Berlin", "price": [2.5, 4]},
+        {"city": "London", "price": -3},
+        {"city": "London", "price": "free"},
+        {"city": "Moscow"},
+    ]
+    request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": idx, "vector": [0.1, 0.2, 0.3, 0.4], "payload": payload}
+                for idx, payload in enumerate(payloads)
+            ]
+        }
+    ).raise_for_status()
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def aggregate(collection_name, key, aggregate_filter=None):
+    response = request_with_validation(
+        api='/collections/{collection_name}/aggregate',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "key": key,
+            "filter": aggregate_filter,
+        },
+    )
+    assert response.ok, response.text
+    return response.json()['result']
+
+
+def create_index(collection_name, key, schema):
+    request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": key,
+            "field_schema": schema,
+        }
+    ).raise_for_status()
+
+
+def test_aggregate_payload(collection_name):
+    result = aggregate(collection_name, "price")
+
+    assert result['count'] == 4
+    assert result['sum'] == 13.5
+    assert result['min'] == -3
+    assert result['max'] == 10
+    assert result['avg'] == 13.5 / 4
+    # 10, 2.5, 4, -3 and "free"
+    assert result['distinct_count'] == 5
+
+
+def test_aggregate_filtered(collection_name):
+    berlin_filter = {"must": [{"key": "city", "match": {"value": "Berlin"}}]}
+
+    result = aggregate(collection_name, "price", berlin_filter)
+    assert result['count'] == 3
+    assert result['sum'] == 16.5
+    assert result['min'] == 2.5
+    assert result['max'] == 10
+
+    # Same values are read from the range index
+    create_index(collection_name, "price", "float")
+    create_index(collection_name, "city", "keyword")
+
+    indexed_result = aggregate(collection_name, "price", berlin_filter)
+    assert indexed_result == result
+
+
+def test_aggregate_keywords(collection_name):
+    create_index(collection_name, "city", "keyword")
+
+    result = aggregate(collection_name, "city")
+    assert result['count'] == 0
+    assert result['sum'] == 0
+    assert result['min'] is None
+    assert result['max'] is None
+    assert result['avg'] is None
+    assert result['distinct_count'] == 3
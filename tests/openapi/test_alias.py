@@ -179,3 +179,88 @@ def test_alias_operations(collection_name):
         }
     )
     assert response.status_code == 404
+
+
+def change_aliases(actions):
+    return request_with_validation(
+        api='/collections/aliases',
+        method="POST",
+        body={"actions": actions}
+    )
+
+
+def get_aliases():
+    response = request_with_validation(
+        api='/aliases',
+        method="GET"
+    )
+    assert response.ok
+    return {
+        alias['alias_name']: alias['collection_name']
+        for alias in response.json()['result']['aliases']
+    }
+
+
+def test_switch_alias_group(on_disk_vectors, collection_name):
+    second_collection_name = f'{collection_name}_2'
+    basic_collection_setup(collection_name=second_collection_name, on_disk_vectors=on_disk_vectors)
+
+    response = change_aliases([
+        {
+            "switch_alias_group": {
+                "aliases": [
+                    {"alias_name": "products", "collection_name": collection_name},
+                    {"alias_name": "reviews", "collection_name": collection_name},
+                ]
+            }
+        }
+    ])
+    assert response.ok, response.text
+    assert get_aliases() == {"products": collection_name, "reviews": collection_name}
+
+    response = change_aliases([
+        {
+            "switch_alias_group": {
+                "aliases": [
+                    {"alias_name": "products", "collection_name": second_collection_name},
+                    {"alias_name": "reviews", "collection_name": second_collection_name},
+                ]
+            }
+        }
+    ])
+    assert response.ok, response.text
+    assert get_aliases() == {"products": second_collection_name, "reviews": second_collection_name}
+
+    # A missing collection must not switch any alias of the group
+    response = change_aliases([
+        {
+            "switch_alias_group": {
+                "aliases": [
+                    {"alias_name": "products", "collection_name": collection_name},
+                    {"alias_name": "reviews", "collection_name": "missing_collection"},
+                ]
+            }
+        }
+    ])
+    assert response.status_code == 404
+    assert get_aliases() == {"products": second_collection_name, "reviews": second_collection_name}
+
+    # Aliases can't be listed twice in a group
+    response = change_aliases([
+        {
+            "switch_alias_group": {
+                "aliases": [
+                    {"alias_name": "products", "collection_name": collection_name},
+                    {"alias_name": "products", "collection_name": second_collection_name},
+                ]
+            }
+        }
+    ])
+    assert response.status_code == 400
+    assert get_aliases() == {"products": second_collection_name, "reviews": second_collection_name}
+
+    response = change_aliases([
+        {"delete_alias": {"alias_name": "products"}},
+        {"delete_alias": {"alias_name": "reviews"}},
+    ])
+    assert response.ok, response.text
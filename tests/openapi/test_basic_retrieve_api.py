@@ -83,6 +83,44 @@ def test_points_retrieve(collection_name):
     assert len(response.json()['result']['points']) == 2
 
 
+def test_points_retrieve_with_version(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"ids": [1, 2]}
+    )
+    assert response.ok
+    assert all('version' not in point for point in response.json()['result'])
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"ids": [1, 2], "with_version": True}
+    )
+    assert response.ok
+    versions = {point['id']: point['version'] for point in response.json()['result']}
+    assert len(versions) == 2
+    peer_ids = {point['version_peer_id'] for point in response.json()['result']}
+    assert len(peer_ids) == 1
+
+    # Versions are consistent with the scroll API and usable for incremental pulls
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "with_version": True,
+            "filter": {"must": [{"has_id": [1, 2]}]},
+        }
+    )
+    assert response.ok
+    for point in response.json()['result']['points']:
+        assert point['version'] == versions[point['id']]
+        assert point['version_peer_id'] in peer_ids
+
+
 def test_exclude_payload(collection_name):
     response = request_with_validation(
         api='/collections/{collection_name}/points/search',
@@ -0,0 +1,95 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "city",
+            "field_schema": "keyword",
+        }
+    ).raise_for_status()
+    target_collection = f"{collection_name}_clone"
+    drop_collection(collection_name=target_collection)
+    yield
+    drop_collection(collection_name=target_collection)
+    drop_collection(collection_name=collection_name)
+
+
+def clone_collection(collection_name, body):
+    return request_with_validation(
+        api='/collections/{collection_name}/clone',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body=body,
+    )
+
+
+def get_collection(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok, response.text
+    return response.json()['result']
+
+
+def scroll_all(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"limit": 100, "with_payload": True, "with_vector": True},
+    )
+    assert response.ok, response.text
+    return response.json()['result']['points']
+
+
+def test_clone_collection(collection_name):
+    target_collection = f"{collection_name}_clone"
+
+    response = clone_collection(collection_name, {"target_collection": target_collection})
+    assert response.ok, response.text
+    assert response.json()['result'] is True
+
+    source_info = get_collection(collection_name)
+    target_info = get_collection(target_collection)
+    assert target_info['config']['params'] == source_info['config']['params']
+    assert target_info['payload_schema'].keys() == {"city"}
+
+    assert scroll_all(target_collection) == scroll_all(collection_name)
+
+
+def test_clone_collection_with_quantization(collection_name):
+    target_collection = f"{collection_name}_clone"
+
+    response = clone_collection(collection_name, {
+        "target_collection": target_collection,
+        "hnsw_config": {"m": 32},
+        "quantization_config": {"scalar": {"type": "int8", "always_ram": True}},
+    })
+    assert response.ok, response.text
+
+    config = get_collection(target_collection)['config']
+    assert config['hnsw_config']['m'] == 32
+    assert config['quantization_config']['scalar']['type'] == "int8"
+
+
+def test_clone_collection_errors(collection_name):
+    response = clone_collection(collection_name, {"target_collection": collection_name})
+    assert response.status_code == 409
+
+    # Existing collection is not deleted, if it can't be created as a clone
+    assert get_collection(collection_name)['points_count'] == 10
+
+    response = clone_collection("missing_collection", {"target_collection": f"{collection_name}_clone"})
+    assert response.status_code == 404
@@ -0,0 +1,62 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(collection_name):
+    basic_collection_setup(collection_name=collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def test_collection_config_diff(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/config/diff',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Cosine",
+                "on_disk": True,
+            },
+            "replication_factor": 1,
+            "hnsw_config": {
+                "m": 32,
+            },
+            "optimizers_config": {
+                "indexing_threshold": 100,
+            },
+        }
+    )
+    assert response.ok, response.text
+
+    changes = {
+        change['field']: change['kind']
+        for change in response.json()['result']['changes']
+    }
+
+    assert changes == {
+        "params.vectors.distance": "incompatible",
+        "params.vectors.on_disk": "requires_optimization",
+        "hnsw_config.m": "requires_optimization",
+        "optimizer_config.indexing_threshold": "hot",
+    }
+
+
+def test_collection_config_diff_unchanged(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/config/diff',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+            },
+        }
+    )
+    assert response.ok, response.text
+    assert response.json()['result']['changes'] == []
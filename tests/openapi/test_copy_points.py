@@ -0,0 +1,107 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+from .test_strictmode import set_strict_mode
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    target_collection = f"{collection_name}_copy"
+    drop_collection(collection_name=target_collection)
+    request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': target_collection},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+            },
+            "sparse_vectors": {
+                "sparse-text": {},
+            },
+        }
+    ).raise_for_status()
+    yield
+    drop_collection(collection_name=target_collection)
+    drop_collection(collection_name=collection_name)
+
+
+def copy_points(collection_name, body):
+    return request_with_validation(
+        api='/collections/{collection_name}/points/copy',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body=body,
+    )
+
+
+def scroll_all(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"limit": 100, "with_payload": True, "with_vector": True},
+    )
+    assert response.ok, response.text
+    return response.json()['result']['points']
+
+
+def test_copy_points(collection_name):
+    target_collection = f"{collection_name}_copy"
+
+    response = copy_points(collection_name, {"target_collection": target_collection})
+    assert response.ok, response.text
+    assert response.json()['result']['copied'] == 10
+
+    # vectors and payload are copied as is
+    assert scroll_all(target_collection) == scroll_all(collection_name)
+
+
+def test_copy_points_filtered(collection_name):
+    target_collection = f"{collection_name}_copy"
+
+    response = copy_points(collection_name, {
+        "target_collection": target_collection,
+        "filter": {"must": [{"key": "city", "match": {"value": "Berlin"}}]},
+    })
+    assert response.ok, response.text
+    assert response.json()['result']['copied'] == 3
+
+    assert [point['id'] for point in scroll_all(target_collection)] == [1, 2, 3]
+
+
+def test_copy_points_offset(collection_name):
+    target_collection = f"{collection_name}_copy"
+
+    response = copy_points(collection_name, {"target_collection": target_collection, "offset": 6})
+    assert response.ok, response.text
+    result = response.json()['result']
+    assert result['copied'] == 5
+    assert result['next_page_offset'] is None
+    assert result['error'] is None
+
+    assert [point['id'] for point in scroll_all(target_collection)] == [6, 7, 8, 9, 10]
+
+
+def test_copy_points_target_strict_mode(collection_name):
+    target_collection = f"{collection_name}_copy"
+
+    # Points are copied in batches, which fit into the upsert limit of the target
+    set_strict_mode(target_collection, {"enabled": True, "upsert_max_batchsize": 3})
+
+    response = copy_points(collection_name, {"target_collection": target_collection})
+    assert response.ok, response.text
+    assert response.json()['result']['copied'] == 10
+
+    assert len(scroll_all(target_collection)) == 10
+
+
+def test_copy_points_errors(collection_name):
+    response = copy_points(collection_name, {"target_collection": "missing_collection"})
+    assert response.status_code == 404
+
+    response = copy_points(collection_name, {"target_collection": collection_name})
+    assert response.status_code == 400
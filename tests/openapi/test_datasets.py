@@ -0,0 +1,101 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(collection_name):
+    drop_collection(collection_name=collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def test_list_datasets():
+    response = request_with_validation(
+        api="/datasets",
+        method="GET",
+    )
+    assert response.ok
+    datasets = {dataset["name"]: dataset for dataset in response.json()["result"]}
+    assert "cities" in datasets
+    assert "colors" in datasets
+    assert datasets["colors"]["points_count"] > 0
+
+
+def test_load_bundled_dataset(collection_name):
+    response = request_with_validation(
+        api="/datasets/load",
+        method="POST",
+        body={
+            "dataset": "colors",
+            "collection_name": collection_name,
+        },
+    )
+    assert response.ok
+    result = response.json()["result"]
+    assert result["collection_name"] == collection_name
+    points_count = result["points_count"]
+
+    response = request_with_validation(
+        api="/collections/{collection_name}",
+        method="GET",
+        path_params={"collection_name": collection_name},
+    )
+    assert response.ok
+    info = response.json()["result"]
+    assert info["points_count"] == points_count
+    assert info["payload_schema"]["family"]["data_type"] == "keyword"
+
+    # collection already exists
+    response = request_with_validation(
+        api="/datasets/load",
+        method="POST",
+        body={
+            "dataset": "colors",
+            "collection_name": collection_name,
+        },
+    )
+    assert response.status_code == 409
+
+
+def test_load_dataset_invalid_source(collection_name):
+    response = request_with_validation(
+        api="/datasets/load",
+        method="POST",
+        body={
+            "dataset": "missing",
+            "collection_name": collection_name,
+        },
+    )
+    assert response.status_code == 404
+
+    response = request_with_validation(
+        api="/datasets/load",
+        method="POST",
+        body={
+            "collection_name": collection_name,
+        },
+    )
+    assert response.status_code == 400
+
+    response = request_with_validation(
+        api="/datasets/load",
+        method="POST",
+        body={
+            "url": "file:///etc/passwd",
+            "collection_name": collection_name,
+        },
+    )
+    assert response.status_code == 400
+
+    # hosts are not allowed by default
+    response = request_with_validation(
+        api="/datasets/load",
+        method="POST",
+        body={
+            "url": "http://127.0.0.1:6333/datasets",
+            "collection_name": collection_name,
+        },
+    )
+    assert response.status_code == 403
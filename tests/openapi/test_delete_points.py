@@ -27,6 +27,7 @@ def test_delete_points(collection_name):
         }
     )
     assert response.ok
+    assert response.json()['result']['affected_points'] == 1
 
     # quantity check if the above point id was deleted
     response = request_with_validation(
@@ -47,6 +48,7 @@ def test_delete_points(collection_name):
         }
     )
     assert response.ok
+    assert response.json()['result']['affected_points'] == 4
 
     # quantity check if the above point id was deleted
     response = request_with_validation(
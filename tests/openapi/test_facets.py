@@ -230,3 +230,156 @@ def test_boolean_facet(collection_name):
             {"value": False, "count": 3},
         ]
     }
+
+
+@pytest.mark.parametrize("exact", [False, True])
+def test_integer_histogram_facet(collection_name, exact):
+    response = request_with_validation(
+        api="/collections/{collection_name}/facet",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "key": "integer",
+            "histogram": {"interval": 2},
+            "exact": exact,
+        },
+    )
+
+    assert response.ok, response.json()
+
+    integer_facet = response.json()["result"]
+    assert integer_facet == {
+        "hits": [
+            # Sorted by bucket lower bound
+            {"value": 0.0, "count": 3},
+            {"value": 2.0, "count": 5},
+        ]
+    }
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/facet",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "key": "integer",
+            "histogram": {"bounds": [1, 3]},
+            "exact": exact,
+        },
+    )
+
+    assert response.ok, response.json()
+
+    integer_facet = response.json()["result"]
+    assert integer_facet == {
+        "hits": [
+            {"value": 1.0, "count": 2},
+        ]
+    }
+
+
+def test_histogram_facet_validation(collection_name):
+    for histogram in [
+        {"interval": 0},
+        {"bounds": [1]},
+        {"bounds": [3, 1]},
+        # Too many buckets
+        {"bounds": list(range(10_002))},
+    ]:
+        response = request_with_validation(
+            api="/collections/{collection_name}/facet",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={
+                "key": "integer",
+                "histogram": histogram,
+            },
+        )
+        assert response.status_code == 422, response.json()
+
+    # Histogram requires a range index on the key
+    response = request_with_validation(
+        api="/collections/{collection_name}/facet",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "key": "city",
+            "histogram": {"interval": 1},
+        },
+    )
+    assert response.status_code == 400, response.json()
+
+
+@pytest.mark.parametrize("exact", [False, True])
+def test_multi_facet(collection_name, exact):
+    response = request_with_validation(
+        api="/collections/{collection_name}/facet/multi",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "keys": ["city", "integer", "boolean"],
+            "exact": exact,
+        },
+    )
+
+    assert response.ok, response.json()
+
+    # Same hits as faceting each key separately, in the order of the keys
+    facets = response.json()["result"]["facets"]
+    assert facets == [
+        {
+            "key": "city",
+            "hits": [
+                {"value": "Berlin", "count": 3},
+                {"value": "London", "count": 2},
+                {"value": "Moscow", "count": 2},
+            ],
+        },
+        {
+            "key": "integer",
+            "hits": [
+                {"value": 3, "count": 4},
+                {"value": 0, "count": 2},
+                {"value": 1, "count": 1},
+                {"value": 2, "count": 1},
+            ],
+        },
+        {
+            "key": "boolean",
+            "hits": [
+                {"value": True, "count": 5},
+                {"value": False, "count": 3},
+            ],
+        },
+    ]
+
+
+def test_multi_facet_with_filter(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/facet/multi",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "keys": ["integer", "boolean"],
+            "filter": {"must": [{"key": "boolean", "match": {"value": False}}]},
+            "limit": 2,
+        },
+    )
+
+    assert response.ok, response.json()
+
+    facets = response.json()["result"]["facets"]
+    assert facets == [
+        {
+            "key": "integer",
+            "hits": [
+                {"value": 1, "count": 1},
+                {"value": 2, "count": 1},
+            ],
+        },
+        {
+            "key": "boolean",
+            "hits": [
+                {"value": False, "count": 3},
+            ],
+        },
+    ]
@@ -0,0 +1,245 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+TEMPLATE = {
+    "filter": {
+        "must": [
+            {"key": "city", "match": {"value": "$city"}},
+            {"key": "price", "range": {"lte": "$max_price"}},
+        ]
+    },
+    "params": {
+        "city": {"type": "keyword"},
+        "max_price": {"type": "float", "default": 10},
+    },
+}
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    for field_name, field_schema in [("city", "keyword"), ("price", "float")]:
+        response = request_with_validation(
+            api="/collections/{collection_name}/index",
+            method="PUT",
+            path_params={"collection_name": collection_name},
+            query_params={"wait": "true"},
+            body={
+                "field_name": field_name,
+                "field_schema": field_schema,
+            },
+        )
+        assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def set_template(collection_name, filter_name, template):
+    return request_with_validation(
+        api="/collections/{collection_name}/filters/{filter_name}",
+        method="PUT",
+        path_params={"collection_name": collection_name, "filter_name": filter_name},
+        body=template,
+    )
+
+
+def scroll_ids(collection_name, body):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/scroll",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body=body,
+    )
+    assert response.ok, response.text
+    return [point["id"] for point in response.json()["result"]["points"]]
+
+
+def test_filter_template(collection_name):
+    response = set_template(collection_name, "cheap_in_city", TEMPLATE)
+    assert response.ok
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/filters",
+        method="GET",
+        path_params={"collection_name": collection_name},
+    )
+    assert response.ok
+    assert response.json()["result"]["templates"]["cheap_in_city"]["params"]["city"]["type"] == "keyword"
+
+    ids = scroll_ids(collection_name, {
+        "filter_template": {"name": "cheap_in_city", "params": {"city": "Berlin"}},
+    })
+    assert ids == [1, 3]
+
+    ids = scroll_ids(collection_name, {
+        "filter_template": {"name": "cheap_in_city", "params": {"city": "Berlin", "max_price": 11}},
+    })
+    assert ids == [1, 2, 3]
+
+    # Combined with the request filter
+    ids = scroll_ids(collection_name, {
+        "filter": {"must": [{"has_id": [1, 2]}]},
+        "filter_template": {"name": "cheap_in_city", "params": {"city": "Berlin"}},
+    })
+    assert ids == [1]
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/search",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "vector": [0.2, 0.1, 0.9, 0.7],
+            "limit": 10,
+            "filter_template": {"name": "cheap_in_city", "params": {"city": "Moscow"}},
+        },
+    )
+    assert response.ok
+    assert sorted(point["id"] for point in response.json()["result"]) == [3, 4]
+
+
+def test_filter_template_in_other_requests(collection_name):
+    response = set_template(collection_name, "cheap_in_city", TEMPLATE)
+    assert response.ok
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/count",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "exact": True,
+            "filter_template": {"name": "cheap_in_city", "params": {"city": "Berlin"}},
+        },
+    )
+    assert response.ok, response.text
+    assert response.json()["result"]["count"] == 2
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "query": [0.2, 0.1, 0.9, 0.7],
+            "limit": 10,
+            "filter_template": {"name": "cheap_in_city", "params": {"city": "Moscow"}},
+        },
+    )
+    assert response.ok, response.text
+    assert sorted(point["id"] for point in response.json()["result"]["points"]) == [3, 4]
+
+
+def test_filter_template_in_updates(collection_name):
+    response = set_template(collection_name, "cheap_in_city", TEMPLATE)
+    assert response.ok
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/payload",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        query_params={"wait": "true"},
+        body={
+            "payload": {"cheap": True},
+            "filter_template": {"name": "cheap_in_city", "params": {"city": "Berlin"}},
+        },
+    )
+    assert response.ok, response.text
+
+    ids = scroll_ids(collection_name, {"filter": {"must": [{"key": "cheap", "match": {"value": True}}]}})
+    assert ids == [1, 3]
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/delete",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        query_params={"wait": "true"},
+        body={
+            "filter": {},
+            "filter_template": {"name": "cheap_in_city", "params": {"city": "Moscow"}},
+        },
+    )
+    assert response.ok, response.text
+
+    ids = scroll_ids(collection_name, {"filter_template": {"name": "cheap_in_city", "params": {"city": "Moscow"}}})
+    assert ids == []
+    ids = scroll_ids(collection_name, {"filter": {"must": [{"has_id": [1, 3, 4]}]}})
+    assert ids == [1]
+
+
+def test_filter_template_invalid_reference(collection_name):
+    response = set_template(collection_name, "cheap_in_city", TEMPLATE)
+    assert response.ok
+
+    for filter_template in [
+        # missing required parameter
+        {"name": "cheap_in_city"},
+        # wrong parameter type
+        {"name": "cheap_in_city", "params": {"city": 42}},
+        # unknown parameter
+        {"name": "cheap_in_city", "params": {"city": "Berlin", "country": "Germany"}},
+    ]:
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/scroll",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={"filter_template": filter_template},
+        )
+        assert response.status_code == 400
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/scroll",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={"filter_template": {"name": "missing"}},
+    )
+    assert response.status_code == 404
+
+
+def test_filter_template_validation(collection_name):
+    # Field is not indexed
+    response = set_template(collection_name, "counted", {
+        "filter": {"must": [{"key": "count", "match": {"value": "$count"}}]},
+        "params": {"count": {"type": "integer"}},
+    })
+    assert response.status_code == 400
+
+    # Parameter is not used
+    response = set_template(collection_name, "unused", {
+        **TEMPLATE,
+        "params": {**TEMPLATE["params"], "country": {"type": "keyword"}},
+    })
+    assert response.status_code == 400
+
+    # Keyword parameter used as a range bound
+    response = set_template(collection_name, "invalid", {
+        "filter": {"must": [{"key": "price", "range": {"gte": "$city"}}]},
+        "params": {"city": {"type": "keyword"}},
+    })
+    assert response.status_code == 400
+
+
+def test_delete_filter_template(collection_name):
+    response = set_template(collection_name, "cheap_in_city", TEMPLATE)
+    assert response.ok
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/filters/{filter_name}",
+        method="DELETE",
+        path_params={"collection_name": collection_name, "filter_name": "cheap_in_city"},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/filters/{filter_name}",
+        method="DELETE",
+        path_params={"collection_name": collection_name, "filter_name": "cheap_in_city"},
+    )
+    assert response.status_code == 404
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/filters",
+        method="GET",
+        path_params={"collection_name": collection_name},
+    )
+    assert response.ok
+    assert response.json()["result"]["templates"] == {}
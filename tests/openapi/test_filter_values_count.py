@@ -89,3 +89,51 @@ def test_filter_values_count(collection_name):
     json = response.json()
     assert len(json['result']) == 3
     assert json['result'][0]['id'] == 1
+
+
+def test_filter_values_count_indexed(collection_name):
+    conditions = [
+        {"gt": 2},
+        {"gte": 2},
+        {"lt": 2},
+        {"gte": 1, "lte": 1},
+        {"lt": 1},
+    ]
+
+    def scroll_ids(values_count):
+        response = request_with_validation(
+            api='/collections/{collection_name}/points/scroll',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={
+                "limit": 100,
+                "filter": {
+                    "must": [
+                        {
+                            "key": "city",
+                            "values_count": values_count
+                        }
+                    ]
+                }
+            }
+        )
+        assert response.ok
+        return sorted(point['id'] for point in response.json()['result']['points'])
+
+    expected = [scroll_ids(values_count) for values_count in conditions]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "city",
+            "field_schema": "keyword"
+        }
+    )
+    assert response.ok
+
+    # Values count is served by the index now, it must match the payload based result
+    for values_count, expected_ids in zip(conditions, expected):
+        assert scroll_ids(values_count) == expected_ids, values_count
@@ -115,6 +115,48 @@ def delete_payload(collection_name, payload_value):
     assert response.ok
 
 
+def test_preview_delete_by_payload_filter(collection_name):
+    payload_filter = {
+        "must": [
+            {
+                "key": "a",
+                "match": {"value": "keyword_0"}
+            }
+        ]
+    }
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/delete/preview',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"filter": payload_filter}
+    )
+    assert response.ok
+    result = response.json()['result']
+    assert result['matched'] == 600
+    assert len(result['sample']) == 10
+    assert all(point_id % 5 == 0 for point_id in result['sample'])
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload/preview',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"payload": {"b": 1}, "filter": payload_filter}
+    )
+    assert response.ok
+    assert response.json()['result']['matched'] == 600
+
+    # Nothing is actually deleted by the preview
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/count',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"filter": payload_filter, "exact": True}
+    )
+    assert response.ok
+    assert response.json()['result']['count'] == 600
+
+
 def test_delete_by_payload_filter(collection_name):
     delete_payload(collection_name, "keyword_0")
     delete_payload(collection_name, "keyword_1")
@@ -509,3 +509,42 @@ def test_search_groups_with_full_lookup(collection_name, lookup_collection_name)
         lookup = group["lookup"]
         assert lookup["payload"]
         assert lookup["vector"]
+
+
+def test_search_groups_with_filtered_lookup(collection_name, lookup_collection_name):
+    lookup_ids = list(range(0, 50, 2))
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/search/groups",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "vector": [1.0, 0.0, 0.0, 0.0],
+            "limit": 10,
+            "with_payload": True,
+            "group_by": "docId",
+            "group_size": 3,
+            "with_lookup": {
+                "collection": lookup_collection_name,
+                "with_payload": ["body"],
+                "with_vectors": False,
+                "filter": {"must": [{"has_id": lookup_ids}]},
+            },
+        },
+    )
+
+    assert response.ok
+
+    groups = response.json()["result"]["groups"]
+
+    assert len(groups) == 10
+    for group in groups:
+        assert len(group["hits"]) == 3
+
+        lookup = group.get("lookup")
+        if group["id"] not in lookup_ids:
+            assert lookup is None
+            continue
+
+        assert lookup["id"] == group["id"]
+        assert lookup["payload"] == {"body": f"doc body {group['id']}"}
+        assert not lookup.get("vector")
@@ -0,0 +1,94 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+
+def basic_collection_setup(collection_name='test_collection'):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Hamming",
+            }
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 1, "vector": [1.0, 1.0, -1.0, -1.0]},
+                {"id": 2, "vector": [0.5, -0.5, 0.0, 2.0]},
+                {"id": 3, "vector": [-1.0, -1.0, 1.0, 1.0]},
+            ]
+        }
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(collection_name):
+    basic_collection_setup(collection_name=collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def test_search_by_element_signs(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": [2.0, 3.0, 0.0, -4.0],
+            "limit": 3,
+        }
+    )
+    assert response.ok
+
+    # Positive elements are set bits, zero and negative ones are unset
+    result = response.json()['result']
+    assert [(point['id'], point['score']) for point in result] == [(1, 0.0), (2, 2.0), (3, 4.0)]
+
+
+def test_quantization_is_rejected(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "quantization_config": {
+                "scalar": {
+                    "type": "int8",
+                }
+            }
+        }
+    )
+    assert not response.ok
+    assert "quantization is not supported for Hamming distance" in response.json()["status"]["error"]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name + '_quantized'},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Hamming",
+            },
+            "quantization_config": {
+                "binary": {}
+            }
+        }
+    )
+    assert not response.ok
+    assert "quantization is not supported for Hamming distance" in response.json()["status"]["error"]
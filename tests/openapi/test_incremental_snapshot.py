@@ -0,0 +1,109 @@
+import time
+
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+from .test_snapshot import drop_snapshots
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_snapshots(collection_name)
+    drop_collection(collection_name=collection_name)
+
+
+def create_snapshot(collection_name, base_snapshot=None):
+    query_params = {'wait': 'true'}
+    if base_snapshot is not None:
+        query_params['base_snapshot'] = base_snapshot
+
+    return request_with_validation(
+        api='/collections/{collection_name}/snapshots',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params=query_params,
+    )
+
+
+def scroll_ids(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"limit": 100},
+    )
+    assert response.ok, response.text
+    return sorted(point['id'] for point in response.json()['result']['points'])
+
+
+def test_incremental_snapshot_unknown_base(collection_name):
+    response = create_snapshot(collection_name, base_snapshot="missing.snapshot")
+    assert response.status_code == 404
+
+
+def test_incremental_snapshot_recovery(http_server, collection_name):
+    (srv_dir, srv_url) = http_server
+
+    response = create_snapshot(collection_name)
+    assert response.ok, response.text
+    base_snapshot = response.json()['result']['name']
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": [{"id": 100, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"city": "Paris"}}]},
+    )
+    assert response.ok, response.text
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/delete',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": [1]},
+    )
+    assert response.ok, response.text
+
+    expected_ids = scroll_ids(collection_name)
+    assert 100 in expected_ids
+    assert 1 not in expected_ids
+
+    # Snapshot names have a one second resolution
+    time.sleep(1)
+
+    response = create_snapshot(collection_name, base_snapshot=base_snapshot)
+    assert response.ok, response.text
+    incremental_snapshot = response.json()['result']['name']
+    assert incremental_snapshot != base_snapshot
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/snapshots/{snapshot_name}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'snapshot_name': incremental_snapshot},
+    )
+    assert response.ok
+    with open(srv_dir / "incremental.snapshot", 'wb') as f:
+        f.write(response.content)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="DELETE",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok, response.text
+
+    # Base snapshot is taken from the collection snapshots, and recovered first
+    response = request_with_validation(
+        api='/collections/{collection_name}/snapshots/recover',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={"location": f"{srv_url}/incremental.snapshot"},
+    )
+    assert response.ok, response.text
+
+    assert scroll_ids(collection_name) == expected_ids
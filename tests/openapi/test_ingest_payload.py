@@ -0,0 +1,60 @@
+from datetime import datetime
+
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def test_ingest_payload(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "params": {
+                "ingest_payload": {
+                    "defaults": {"source": "api", "priority": 0},
+                    "computed": {"ingested_at": "now", "ingested_at_unix": "now_unix"},
+                }
+            }
+        },
+    )
+    assert response.ok, response.text
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 100, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"priority": 5}},
+                {"id": 101, "vector": [0.4, 0.3, 0.2, 0.1]},
+            ]
+        },
+    )
+    assert response.ok, response.text
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"ids": [100, 101], "with_payload": True},
+    )
+    assert response.ok, response.text
+    payloads = {point['id']: point['payload'] for point in response.json()['result']}
+
+    assert payloads[100]['priority'] == 5
+    assert payloads[101]['priority'] == 0
+    for payload in payloads.values():
+        assert payload['source'] == "api"
+        ingested_at = datetime.fromisoformat(payload['ingested_at'].replace("Z", "+00:00"))
+        assert int(ingested_at.timestamp()) == payload['ingested_at_unix']
@@ -0,0 +1,121 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+# points with the default dense vector
+DENSE_POINT_IDS = list(range(1, 9))
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def join(collection_name, body):
+    return request_with_validation(
+        api="/collections/{collection_name}/points/join",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body=body,
+    )
+
+
+def test_join_missing_target(collection_name):
+    response = join(collection_name, {"target_collection": "missing_collection"})
+    assert response.status_code == 404
+
+
+def test_join_wrong_vector_name(collection_name):
+    response = join(collection_name, {
+        "target_collection": collection_name,
+        "using": "wrong_vector_name",
+    })
+    assert response.status_code == 400
+
+
+def test_self_join(collection_name):
+    response = join(collection_name, {
+        "target_collection": collection_name,
+        "limit": 2,
+    })
+    assert response.ok, response.text
+    result = response.json()["result"]
+
+    assert result["joined"] == len(DENSE_POINT_IDS)
+    assert result["next_page_offset"] is None
+    assert [point["id"] for point in result["points"]] == DENSE_POINT_IDS
+
+    for point in result["points"]:
+        neighbors = point["neighbors"]
+        assert len(neighbors) == 2
+        # a point is never its own neighbor
+        assert point["id"] not in [neighbor["id"] for neighbor in neighbors]
+        assert neighbors[0]["score"] >= neighbors[1]["score"]
+
+
+def test_join_filters(collection_name):
+    response = join(collection_name, {
+        "target_collection": collection_name,
+        "filter": {"must": [{"key": "city", "match": {"value": "Berlin"}}]},
+        "target_filter": {"must": [{"key": "city", "match": {"value": "London"}}]},
+        "limit": 5,
+    })
+    assert response.ok, response.text
+    result = response.json()["result"]
+
+    assert [point["id"] for point in result["points"]] == [1, 2, 3]
+    for point in result["points"]:
+        neighbor_ids = {neighbor["id"] for neighbor in point["neighbors"]}
+        assert neighbor_ids == {2, 4} - {point["id"]}
+
+
+def test_join_pages(collection_name):
+    joined_ids = []
+    offset = None
+    while True:
+        response = join(collection_name, {
+            "target_collection": collection_name,
+            "limit": 1,
+            "points_limit": 3,
+            "offset": offset,
+        })
+        assert response.ok, response.text
+        result = response.json()["result"]
+        assert result["joined"] <= 3
+
+        joined_ids.extend(point["id"] for point in result["points"])
+        offset = result["next_page_offset"]
+        if offset is None:
+            break
+
+    assert joined_ids == DENSE_POINT_IDS
+
+
+def test_join_into_payload(collection_name):
+    response = join(collection_name, {
+        "target_collection": collection_name,
+        "limit": 3,
+        "payload_key": "neighbors",
+    })
+    assert response.ok, response.text
+    result = response.json()["result"]
+    assert result["joined"] == len(DENSE_POINT_IDS)
+    assert result["points"] == []
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/{id}",
+        method="GET",
+        path_params={"collection_name": collection_name, "id": 1},
+    )
+    assert response.ok
+    payload = response.json()["result"]["payload"]
+
+    # existing payload is kept
+    assert payload["city"] == "Berlin"
+    neighbors = payload["neighbors"]
+    assert len(neighbors) == 3
+    assert 1 not in [neighbor["id"] for neighbor in neighbors]
+    assert all("score" in neighbor for neighbor in neighbors)
@@ -53,3 +53,23 @@ def test_optimizations_with_all_spaces(collection_name):
     assert result["queued"] == []
     assert result["completed"] == []
     assert len(result["idle_segments"]) > 0
+
+
+def test_optimizations_with_decisions(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/optimizations",
+        method="GET",
+        path_params={"collection_name": collection_name},
+        query_params={"with": "decisions"},
+    )
+    assert response.ok
+    result = response.json()["result"]
+    assert "queued" not in result
+    for decision in result["decisions"]:
+        assert decision["action"] in ("started", "skipped")
+        assert decision["repeated"] >= 1
+        assert decision["trigger"]
+        if decision["action"] == "started":
+            assert "uuid" in decision
+        else:
+            assert "reason" in decision
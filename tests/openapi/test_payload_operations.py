@@ -680,4 +680,110 @@ def test_payload_index_overwrite(collection_name):
         path_params={'collection_name': collection_name, 'id': 1},
     )
     assert response.ok
-    assert len(response.json()['result']['payload']) == 0
\ No newline at end of file
+    assert len(response.json()['result']['payload']) == 0
+
+def test_patch_payload(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "payload": {
+                "title": "Hello",
+                "author": {"name": "John", "email": "john@example.com"},
+                "tags": ["a", "b"],
+            },
+            "points": [1, 2]
+        }
+    )
+    assert response.ok
+
+    # nested keys are merged, null removes keys at any level
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "payload": {
+                "title": "Goodbye",
+                "author": {"email": None, "phone": "+01-123-456"},
+                "tags": ["c"],
+            },
+            "filter": {
+                "must": [
+                    {"has_id": [1]}
+                ]
+            }
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 1},
+    )
+    assert response.ok
+    assert response.json()['result']['payload'] == {
+        "title": "Goodbye",
+        "author": {"name": "John", "phone": "+01-123-456"},
+        "tags": ["c"],
+    }
+
+    # other points are not changed
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 2},
+    )
+    assert response.ok
+    assert response.json()['result']['payload'] == {
+        "title": "Hello",
+        "author": {"name": "John", "email": "john@example.com"},
+        "tags": ["a", "b"],
+    }
+
+    # patch can't be applied at a nested key
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "payload": {"email": None},
+            "points": [1],
+            "key": "author",
+        }
+    )
+    assert response.status_code == 400
+
+
+def test_set_payload_by_filter_affected_points(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "payload": {"country": "Germany"},
+            "filter": {"must": [{"key": "city", "match": {"value": "Berlin"}}]},
+        }
+    )
+    assert response.ok, response.text
+    assert response.json()['result']['affected_points'] == 3
+
+    # not known without waiting for the operation
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'false'},
+        body={
+            "payload": {"country": "Germany"},
+            "filter": {"must": [{"key": "city", "match": {"value": "Berlin"}}]},
+        }
+    )
+    assert response.ok, response.text
+    assert 'affected_points' not in response.json()['result']
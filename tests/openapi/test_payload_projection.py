@@ -0,0 +1,88 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(collection_name):
+    drop_collection(collection_name=collection_name)
+    request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+            },
+        }
+    ).raise_for_status()
+
+    request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 1,
+                    "vector": [0.1, 0.2, 0.3, 0.4],
+                    "payload": {
+                        "title": "order",
+                        "meta": {
+                            "author": {"id": 1, "name": "Alice"},
+                            "editor": {"id": 2, "name": "Bob"},
+                        },
+                        "items": [
+                            {"name": "a", "price": 1, "tags": ["x", "y", "z"]},
+                            {"name": "b", "price": 2, "tags": ["y"]},
+                            {"name": "c", "price": 3, "tags": []},
+                        ],
+                    },
+                },
+            ]
+        }
+    ).raise_for_status()
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def get_payload(collection_name, with_payload):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"with_payload": with_payload},
+    )
+    assert response.ok, response.text
+    return response.json()['result']['points'][0]['payload']
+
+
+def test_project_wildcard_key(collection_name):
+    payload = get_payload(collection_name, {"project": ["meta.*.id"]})
+    assert payload == {"meta": {"author": {"id": 1}, "editor": {"id": 2}}}
+
+
+def test_project_array_slice(collection_name):
+    payload = get_payload(collection_name, {"project": ["title", "items[0:2].name"]})
+    assert payload == {"title": "order", "items": [{"name": "a"}, {"name": "b"}]}
+
+    payload = get_payload(collection_name, {"project": ["items[-1:]"]})
+    assert payload == {"items": [{"name": "c", "price": 3, "tags": []}]}
+
+
+def test_project_nested_arrays(collection_name):
+    payload = get_payload(collection_name, {"project": ["items[].tags[0]"]})
+    assert payload == {"items": [{"tags": ["x"]}, {"tags": ["y"]}]}
+
+
+def test_project_invalid_expression(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"with_payload": {"project": ["items[1:2:3]"]}},
+    )
+    assert response.status_code == 400
@@ -0,0 +1,43 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(collection_name):
+    basic_collection_setup(collection_name=collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+@pytest.mark.parametrize("action", ["pin", "unpin"])
+def test_pin_vector(collection_name, action):
+    response = request_with_validation(
+        api=f"/collections/{{collection_name}}/vectors/{action}",
+        method="POST",
+        path_params={"collection_name": collection_name},
+    )
+    assert response.ok
+    assert response.json()["result"] is True
+
+    # Points are still searchable
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/search",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={"vector": [0.2, 0.1, 0.9, 0.7], "limit": 3},
+    )
+    assert response.ok
+    assert len(response.json()["result"]) == 3
+
+
+@pytest.mark.parametrize("action", ["pin", "unpin"])
+def test_pin_unknown_vector(collection_name, action):
+    response = request_with_validation(
+        api=f"/collections/{{collection_name}}/vectors/{action}",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        query_params={"vector": "unknown"},
+    )
+    assert response.status_code == 400
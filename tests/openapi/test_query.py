@@ -664,3 +664,47 @@ def test_score_threshold(body, collection_name):
     assert len(points) < 8
     for point in points:
         assert point["score"] >= score_threshold
+
+
+def test_query_explain(collection_name):
+    # Explanation is omitted, unless requested
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={"query": [0.1, 0.2, 0.3, 0.4]},
+    )
+    assert response.ok, response.json()
+    assert "explain" not in response.json()["result"]
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        query_params={"explain": "true"},
+        body={
+            "prefetch": [
+                {"query": [0.1, 0.2, 0.3, 0.4]},
+                {
+                    "query": [0.5, 0.6, 0.7, 0.8],
+                    "filter": {"must": [{"key": "price", "range": {"gte": 10.0}}]},
+                },
+            ],
+            "query": {"fusion": "rrf"},
+        },
+    )
+    assert response.ok, response.json()
+    result = response.json()["result"]
+    assert len(result["points"]) > 0
+
+    explain = result["explain"]
+    assert [shard["shard_id"] for shard in explain] == [0]
+
+    segments = explain[0]["segments"]
+    assert len(segments) > 0
+    strategies = {segment["strategy"] for segment in segments}
+    # Both prefetches are explained, the collection is too small to be indexed
+    assert strategies == {"plain_scan", "payload_index"}
+    for segment in segments:
+        if segment["strategy"] == "payload_index":
+            assert segment["estimated_cardinality"] >= 0
@@ -0,0 +1,96 @@
+from math import isclose
+
+import pytest
+import requests
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import qdrant_host_headers, request_with_validation
+from .helpers.settings import QDRANT_HOST
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def query_points(collection_name, body):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body=body,
+    )
+    assert response.ok, response.text
+    return response.json()["result"]["points"]
+
+
+def get_vectors(collection_name, ids):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={"ids": ids, "with_vector": True},
+    )
+    assert response.ok, response.text
+    return {point["id"]: point["vector"] for point in response.json()["result"]}
+
+
+def test_query_arithmetic(collection_name):
+    arithmetic_result = query_points(collection_name, {
+        "query": {
+            "arithmetic": [
+                {"vector": 1},
+                {"vector": 2, "weight": -1.0},
+                {"vector": 3},
+            ]
+        },
+        "limit": 10,
+    })
+
+    vectors = get_vectors(collection_name, [1, 2, 3])
+    expected_vector = [
+        a - b + c for a, b, c in zip(vectors[1], vectors[2], vectors[3])
+    ]
+
+    nearest_result = query_points(collection_name, {
+        "query": expected_vector,
+        "filter": {"must_not": [{"has_id": [1, 2, 3]}]},
+        "limit": 10,
+    })
+
+    assert len(arithmetic_result) == len(nearest_result)
+    for arithmetic, nearest in zip(arithmetic_result, nearest_result):
+        assert arithmetic["id"] == nearest["id"]
+        assert isclose(arithmetic["score"], nearest["score"], abs_tol=1e-4)
+
+
+def test_query_arithmetic_raw_vectors(collection_name):
+    arithmetic_result = query_points(collection_name, {
+        "query": {
+            "arithmetic": [
+                {"vector": [0.1, 0.2, 0.3, 0.4], "weight": 2.0},
+                {"vector": [0.1, 0.1, 0.1, 0.1], "weight": -1.0},
+            ]
+        },
+        "limit": 10,
+    })
+
+    nearest_result = query_points(collection_name, {
+        "query": [0.1, 0.3, 0.5, 0.7],
+        "limit": 10,
+    })
+
+    assert [point["id"] for point in arithmetic_result] == [point["id"] for point in nearest_result]
+
+
+def test_query_arithmetic_validation(collection_name):
+    # raw query to bypass local validation
+    response = requests.post(
+        f"{QDRANT_HOST}/collections/{collection_name}/points/query",
+        headers=qdrant_host_headers(),
+        json={"query": {"arithmetic": []}},
+    )
+    assert not response.ok, response.text
+    assert "Validation error in JSON body" in response.json()["status"]["error"]
@@ -0,0 +1,80 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def set_read_only(collection_name, read_only):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={"params": {"read_only": read_only}},
+    )
+    assert response.ok, response.text
+
+
+def upsert_point(collection_name, point_id):
+    return request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": [{"id": point_id, "vector": [0.1, 0.2, 0.3, 0.4]}]},
+    )
+
+
+def test_read_only_collection(collection_name):
+    set_read_only(collection_name, True)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok, response.text
+    assert response.json()['result']['config']['params']['read_only'] is True
+
+    response = upsert_point(collection_name, 100)
+    assert response.status_code == 400
+    assert "is read-only" in response.json()['status']['error']
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/delete',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": [1]},
+    )
+    assert response.status_code == 400
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"field_name": "city", "field_schema": "keyword"},
+    )
+    assert response.status_code == 400
+
+    # Reads are still served
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/query',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"query": [0.1, 0.2, 0.3, 0.4], "limit": 3},
+    )
+    assert response.ok, response.text
+    assert len(response.json()['result']['points']) == 3
+
+    set_read_only(collection_name, False)
+
+    response = upsert_point(collection_name, 100)
+    assert response.ok, response.text
@@ -261,3 +261,63 @@ def test_raw_vectors(collection_name):
     assert len(response_raw.json()["result"]) == 4
 
     assert response_ids.json()["result"] == response_raw.json()["result"]
+
+
+def upsert_point(collection_name, point_id, vector):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        query_params={"wait": "true"},
+        body={"points": [{"id": point_id, "vector": vector}]},
+    )
+    assert response.ok, response.text
+
+
+def test_recommend_uses_updated_example(collection_name):
+    example_id = 100
+
+    def recommend():
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/recommend",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={"positive": [example_id], "limit": 3},
+        )
+        assert response.ok, response.text
+        return [point["id"] for point in response.json()["result"]]
+
+    def search(vector):
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/search",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={
+                "vector": vector,
+                "limit": 3,
+                "filter": {"must_not": [{"has_id": [example_id]}]},
+            },
+        )
+        assert response.ok, response.text
+        return [point["id"] for point in response.json()["result"]]
+
+    first_vector = [0.9, 0.1, 0.1, 0.1]
+    second_vector = [0.1, 0.1, 0.1, 0.9]
+
+    upsert_point(collection_name, example_id, first_vector)
+    # Repeat to make sure the example vector is served from cache
+    assert recommend() == search(first_vector)
+    assert recommend() == search(first_vector)
+
+    # Updated example vector must be used right away
+    upsert_point(collection_name, example_id, second_vector)
+    assert recommend() == search(second_vector)
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/delete",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        query_params={"wait": "true"},
+        body={"points": [example_id]},
+    )
+    assert response.ok, response.text
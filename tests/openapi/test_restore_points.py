@@ -0,0 +1,95 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def set_trash_retention(collection_name, trash_retention_sec):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={"params": {"trash_retention_sec": trash_retention_sec}},
+    )
+    assert response.ok, response.text
+
+
+def restore_points(collection_name, points):
+    return request_with_validation(
+        api='/collections/{collection_name}/points/restore',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": points},
+    )
+
+
+def retrieve_points(collection_name, ids):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"ids": ids, "with_payload": True, "with_vector": True},
+    )
+    assert response.ok, response.text
+    return response.json()['result']
+
+
+def test_restore_without_trash_retention(collection_name):
+    response = restore_points(collection_name, [1])
+    assert response.status_code == 400
+    assert "Trash retention is not enabled" in response.json()['status']['error']
+
+
+def test_restore_deleted_points(collection_name):
+    set_trash_retention(collection_name, 3600)
+
+    before = retrieve_points(collection_name, [1, 2])
+    assert len(before) == 2
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/delete',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": [1]},
+    )
+    assert response.ok, response.text
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/delete',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"filter": {"must": [{"has_id": [2]}]}},
+    )
+    assert response.ok, response.text
+
+    assert retrieve_points(collection_name, [1, 2]) == []
+
+    # Points in the trash are not visible to other reads either
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"limit": 100},
+    )
+    assert response.ok, response.text
+    scrolled_ids = [point['id'] for point in response.json()['result']['points']]
+    assert 1 not in scrolled_ids
+    assert 2 not in scrolled_ids
+
+    # Points which are not in the trash are ignored
+    response = restore_points(collection_name, [1, 2, 3])
+    assert response.ok, response.text
+
+    after = retrieve_points(collection_name, [1, 2])
+    assert sorted(after, key=lambda point: point['id']) == sorted(before, key=lambda point: point['id'])
+    assert len(retrieve_points(collection_name, [3])) == 1
@@ -0,0 +1,81 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def test_wrong_vector_name(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/projection",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "sample": 10,
+            "using": "wrong_vector_name",
+        },
+    )
+    assert response.status_code == 400
+
+
+def test_sample_projection(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/projection",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "sample": 10,
+            "with_payload": ["city"],
+        },
+    )
+    assert response.ok
+    result = response.json()["result"]
+
+    points = result["points"]
+    assert [point["id"] for point in points] == [1, 2, 3, 4, 5, 6, 7, 8]
+    for point in points:
+        assert isinstance(point["x"], float)
+        assert isinstance(point["y"], float)
+        assert set(point["payload"].keys()) <= {"city"}
+
+    # projection is centered
+    assert abs(sum(point["x"] for point in points)) < 1e-3
+    assert abs(sum(point["y"] for point in points)) < 1e-3
+
+    first, second = result["explained_variance"]
+    assert first >= second >= 0.0
+    assert first + second <= 1.0 + 1e-4
+
+    # same sample produces the same picture
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/projection",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "sample": 10,
+            "with_payload": ["city"],
+        },
+    )
+    assert response.ok
+    assert response.json()["result"] == result
+
+
+def test_sample_projection_filtered(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/projection",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "filter": {"must": [{"has_id": [1, 2, 3]}]},
+        },
+    )
+    assert response.ok
+    points = response.json()["result"]["points"]
+    assert [point["id"] for point in points] == [1, 2, 3]
+    assert all("payload" not in point for point in points)
@@ -0,0 +1,91 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def scroll(collection_name, body):
+    return request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body=body,
+    )
+
+
+def create_index(collection_name, key, schema):
+    request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": key,
+            "field_schema": schema,
+        }
+    ).raise_for_status()
+
+
+def test_scroll_distinct(collection_name):
+    create_index(collection_name, "city", "keyword")
+
+    response = scroll(collection_name, {"distinct_by": "city", "with_payload": True})
+    assert response.ok, response.text
+    result = response.json()['result']
+
+    # 1 is first for Berlin, 2 for London, 3 for Moscow
+    assert [point['id'] for point in result['points']] == [1, 2, 3]
+    assert result['next_page_offset'] is None
+    # requested payload is returned, not only the distinct key
+    assert result['points'][0]['payload'] == {"city": "Berlin", "price": 10.0}
+
+
+def test_scroll_distinct_pages(collection_name):
+    create_index(collection_name, "city", "keyword")
+
+    response = scroll(collection_name, {"distinct_by": "city", "limit": 2})
+    assert response.ok, response.text
+    result = response.json()['result']
+
+    assert [point['id'] for point in result['points']] == [1, 2]
+    assert result['next_page_offset'] == 3
+
+    # Values of the previous page are not repeated
+    response = scroll(collection_name, {"distinct_by": "city", "limit": 2, "offset": 3})
+    assert response.ok, response.text
+    result = response.json()['result']
+
+    assert [point['id'] for point in result['points']] == [3]
+    assert result['next_page_offset'] is None
+
+
+def test_scroll_distinct_filtered(collection_name):
+    create_index(collection_name, "city", "keyword")
+
+    response = scroll(collection_name, {
+        "distinct_by": "city",
+        "filter": {"must_not": [{"has_id": [1, 2]}]},
+    })
+    assert response.ok, response.text
+    result = response.json()['result']
+
+    assert [point['id'] for point in result['points']] == [3, 4]
+
+
+def test_scroll_distinct_without_index(collection_name):
+    response = scroll(collection_name, {"distinct_by": "city"})
+    assert response.status_code == 400
+
+
+def test_scroll_distinct_with_order_by(collection_name):
+    create_index(collection_name, "price", "float")
+
+    response = scroll(collection_name, {"distinct_by": "city", "order_by": "price"})
+    assert response.status_code == 400
@@ -0,0 +1,89 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    for field_name, field_schema in [("city", "keyword"), ("count", "integer")]:
+        request_with_validation(
+            api='/collections/{collection_name}/index',
+            method="PUT",
+            path_params={'collection_name': collection_name},
+            query_params={'wait': 'true'},
+            body={
+                "field_name": field_name,
+                "field_schema": field_schema,
+            }
+        ).raise_for_status()
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def set_strict_payload_schema(collection_name, mode):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={"params": {"strict_payload_schema": mode}},
+    )
+    assert response.ok, response.text
+
+
+def upsert_point(collection_name, payload):
+    return request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 100, "vector": [0.1, 0.2, 0.3, 0.4], "payload": payload},
+            ]
+        },
+    )
+
+
+def get_payload(collection_name, point_id):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': point_id},
+    )
+    assert response.ok, response.text
+    return response.json()['result']['payload']
+
+
+def test_strict_payload_schema_reject(collection_name):
+    set_strict_payload_schema(collection_name, "reject")
+
+    response = upsert_point(collection_name, {"city": "Berlin", "country": "Germany"})
+    assert response.status_code == 400, response.text
+    assert "country" in response.json()['status']['error']
+
+    response = upsert_point(collection_name, {"city": "Berlin", "count": "many"})
+    assert response.status_code == 400, response.text
+    assert "count" in response.json()['status']['error']
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"payload": {"country": "Germany"}, "points": [1]},
+    )
+    assert response.status_code == 400, response.text
+
+    response = upsert_point(collection_name, {"city": ["Berlin", "London"], "count": 3})
+    assert response.ok, response.text
+    assert get_payload(collection_name, 100) == {"city": ["Berlin", "London"], "count": 3}
+
+
+def test_strict_payload_schema_drop(collection_name):
+    set_strict_payload_schema(collection_name, "drop")
+
+    response = upsert_point(collection_name, {"city": "Berlin", "country": "Germany", "count": 1.5})
+    assert response.ok, response.text
+    assert get_payload(collection_name, 100) == {"city": "Berlin"}
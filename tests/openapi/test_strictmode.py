@@ -784,6 +784,69 @@ def test_strict_mode_max_sparse_length_upsert(collection_name):
     assert "Sparse vector 'sparse-vector' has a limit of 4 indices" in failed_upsert.json()['status']['error']
 
 
+def test_strict_mode_sparse_index_and_value_limits_upsert(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="DELETE",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "sparse_vectors": {
+                "sparse-vector": {}
+            }
+        }
+    )
+    assert response.ok
+
+    set_strict_mode(collection_name, {
+        "enabled": True,
+        "sparse_config": {
+            "sparse-vector": {
+                "max_index": 1000,
+                "max_abs_value": 10.0
+            }
+        }
+    })
+
+    def upsert_sparse(indices, values):
+        return request_with_validation(
+            api='/collections/{collection_name}/points',
+            method="PUT",
+            path_params={'collection_name': collection_name},
+            query_params={'wait': 'true'},
+            body={
+                "points": [
+                    {
+                        "id": 1,
+                        "vector": {
+                            "sparse-vector": {
+                                "indices": indices,
+                                "values": values
+                            }
+                        }
+                    }
+                ]
+            }
+        )
+
+    response = upsert_sparse([1, 500, 1000], [-10.0, 0.5, 10.0])
+    assert response.ok
+
+    failed_upsert = upsert_sparse([1, 1001], [0.1, 0.2])
+    assert not failed_upsert.ok
+    assert "Sparse vector 'sparse-vector' has a limit of 1000 for index value" in failed_upsert.json()['status']['error']
+
+    failed_upsert = upsert_sparse([1, 2], [0.1, -10.5])
+    assert not failed_upsert.ok
+    assert "Sparse vector 'sparse-vector' has a limit of 10 for absolute weight value" in failed_upsert.json()['status']['error']
+
+
 def test_strict_mode_max_collection_size_upsert_batch(collection_name):
     basic_collection_setup(collection_name=collection_name)  # Clear collection to not depend on other tests
 
@@ -2564,4 +2627,55 @@ def test_strict_mode_payload_index_count(collection_name):
                 "type": "integer",
             }
         }
-    ).raise_for_status()
\ No newline at end of file
+    ).raise_for_status()
+
+def test_strict_mode_operation_class_rate_limiting(collection_name):
+    set_strict_mode(collection_name, {
+        "enabled": True,
+        "search_rate_limit": 1,
+        "scroll_rate_limit": 2,
+    })
+
+    def search():
+        return request_with_validation(
+            api='/collections/{collection_name}/points/query',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={"query": [0.2, 0.1, 0.9, 0.7], "limit": 3},
+        )
+
+    def scroll():
+        return request_with_validation(
+            api='/collections/{collection_name}/points/scroll',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={"limit": 3},
+        )
+
+    assert search().ok
+    response = search()
+    assert response.status_code == 429
+    assert "Search rate limit exceeded" in response.json()['status']['error']
+    assert 55 < int(response.headers['Retry-After']) <= 60
+
+    # Scroll is limited separately
+    assert scroll().ok
+    assert scroll().ok
+    response = scroll()
+    assert response.status_code == 429
+    assert "Scroll rate limit exceeded" in response.json()['status']['error']
+
+    # Updates are not limited
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": [{"id": 1, "vector": [0.05, 0.61, 0.76, 0.74]}]},
+    )
+    assert response.ok
+
+    set_strict_mode(collection_name, {
+        "enabled": False,
+    })
+    assert search().ok
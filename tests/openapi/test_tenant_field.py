@@ -0,0 +1,133 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True)
+def setup(collection_name):
+    drop_collection(collection_name=collection_name)
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+            },
+            "tenant_field": "tenant",
+        }
+    )
+    assert response.ok, response.text
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def get_collection_info(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    return response.json()['result']
+
+
+def scroll(collection_name, tenant_filter):
+    return request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": tenant_filter,
+            "limit": 10,
+        },
+    )
+
+
+def test_tenant_field_config(collection_name):
+    info = get_collection_info(collection_name)
+
+    # Tenants are colocated by the tenant index, sharding method is not changed
+    assert info['config']['params'].get('sharding_method', "auto") == "auto"
+
+    strict_mode_config = info['config']['strict_mode_config']
+    assert strict_mode_config['enabled']
+    assert strict_mode_config['tenant_filter_key'] == "tenant"
+
+    tenant_index = info['payload_schema']['tenant']
+    assert tenant_index['data_type'] == "keyword"
+    assert tenant_index['params']['is_tenant']
+
+
+def test_tenant_filter_required(collection_name):
+    response = scroll(collection_name, None)
+    assert response.status_code == 400
+    assert "Filter by tenant key \"tenant\" is required" in response.json()['status']['error']
+
+    response = scroll(collection_name, {
+        "should": [{"key": "tenant", "match": {"value": "a"}}],
+    })
+    assert response.status_code == 400
+
+    response = scroll(collection_name, {
+        "must": [{"key": "tenant", "match": {"any": ["a", "b"]}}],
+    })
+    assert response.status_code == 400
+
+    response = scroll(collection_name, {
+        "must": [{"key": "tenant", "match": {"value": "a"}}],
+    })
+    assert response.ok, response.text
+
+    # Tenant filter is no longer required once strict mode is disabled
+    request_with_validation(
+        api="/collections/{collection_name}",
+        method="PATCH",
+        path_params={"collection_name": collection_name},
+        body={
+            "strict_mode_config": {"enabled": False},
+        },
+    ).raise_for_status()
+
+    response = scroll(collection_name, None)
+    assert response.ok, response.text
+
+
+def delete_by_filter(collection_name, tenant_filter):
+    return request_with_validation(
+        api='/collections/{collection_name}/points/delete',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"filter": tenant_filter},
+    )
+
+
+def test_tenant_filter_required_for_updates(collection_name):
+    response = delete_by_filter(collection_name, {
+        "must": [{"has_id": [1]}],
+    })
+    assert response.status_code == 400
+
+    response = delete_by_filter(collection_name, {
+        "must": [{"has_id": [1]}, {"key": "tenant", "match": {"value": "a"}}],
+    })
+    assert response.ok, response.text
+
+    # Points of other tenants can't be retrieved by IDs
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"ids": [1]},
+    )
+    assert response.status_code == 400
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 1},
+    )
+    assert response.status_code == 400
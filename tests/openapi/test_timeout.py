@@ -202,3 +202,46 @@ def test_discover_timeout(collection_name):
     assert not response.ok
     assert response.status_code == 500
     assert response.json()['status']['error'].__contains__("Timeout error: Operation 'Search' timed out after 1 seconds")
+
+def test_query_partial_results_on_timeout(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/query',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'timeout': 1},
+        body={
+            "query": [random() for _ in range(dims)],
+            "limit": 100000,
+            "filter": {
+                "must": [
+                    {
+                        "key": "a",
+                        "match": {
+                            "text": "keyword_1"
+                        }
+                    }
+                ]
+            },
+            "params": {"exact": True, "allow_partial_results": True}
+        }
+    )
+
+    assert response.ok, response.text
+    assert response.json()['result']['timed_out'] is True
+
+
+def test_query_partial_results_in_time(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/query',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'timeout': 60},
+        body={
+            "query": [random() for _ in range(dims)],
+            "limit": 10,
+            "params": {"allow_partial_results": True}
+        }
+    )
+
+    assert response.ok, response.text
+    assert 'timed_out' not in response.json()['result']
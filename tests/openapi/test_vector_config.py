@@ -221,6 +221,106 @@ def test_disable_indexing(on_disk_vectors):
     drop_collection(collection_name=unindexed_name)
 
 
+@pytest.mark.timeout(20)
+def test_store_only_vector(on_disk_vectors):
+    collection_name = 'test_collection_store_only'
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "dense": {
+                    "size": 256,
+                    "distance": "Dot",
+                    "on_disk": on_disk_vectors,
+                },
+                "stored": {
+                    "size": 256,
+                    "distance": "Dot",
+                    "on_disk": on_disk_vectors,
+                    "index": "none",
+                },
+            },
+            "optimizers_config": {
+                "indexing_threshold": 10
+            }
+        }
+    )
+    assert response.ok
+
+    amount_of_vectors = 100
+    vectors = [[random.random() for _ in range(256)] for _ in range(amount_of_vectors)]
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method='PUT',
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": idx, "vector": {"dense": vector, "stored": vector}}
+                for idx, vector in enumerate(vectors)
+            ]
+        }
+    )
+    assert response.ok
+
+    while True:
+        response = request_with_validation(
+            method='GET',
+            api='/collections/{collection_name}',
+            path_params={'collection_name': collection_name},
+        )
+        assert response.ok
+        result = response.json()['result']
+        if result['indexed_vectors_count'] > 0:
+            break
+        sleep(0.1)
+
+    assert result['config']['params']['vectors']['stored']['index'] == "none"
+    assert 'index' not in result['config']['params']['vectors']['dense']
+
+    # Store-only vectors are never indexed
+    assert result['indexed_vectors_count'] <= amount_of_vectors
+
+    # Store-only vectors are still searchable with a plain scan
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/query',
+        method='POST',
+        path_params={'collection_name': collection_name},
+        body={
+            "query": vectors[0],
+            "using": "stored",
+            "limit": 3,
+        }
+    )
+    assert response.ok
+    assert len(response.json()['result']['points']) == 3
+
+    # Store-only vectors can be used for rescoring
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/query',
+        method='POST',
+        path_params={'collection_name': collection_name},
+        body={
+            "prefetch": {
+                "query": vectors[0],
+                "using": "dense",
+                "limit": 10,
+            },
+            "query": vectors[0],
+            "using": "stored",
+            "limit": 3,
+        }
+    )
+    assert response.ok
+    assert response.json()['result']['points'][0]['id'] == 0
+
+    drop_collection(collection_name=collection_name)
+
+
 @pytest.mark.parametrize(
     "config_name,vector_config,expected_warnings",
     [